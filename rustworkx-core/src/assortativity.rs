@@ -0,0 +1,584 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+use std::hash::Hash;
+
+use hashbrown::HashMap;
+use petgraph::visit::{EdgeRef, GraphProp, IntoEdgeReferences, IntoNodeIdentifiers, NodeIndexable};
+use petgraph::Direction::{self, Incoming, Outgoing};
+
+use crate::dictmap::*;
+
+/// Sums for the Pearson correlation of the pairs of values at the ends of
+/// the edges of a graph, accumulated with one call to `add` per pair.
+#[derive(Default)]
+struct Moments {
+    count: f64,
+    sum_x: f64,
+    sum_y: f64,
+    sum_xy: f64,
+    sum_xx: f64,
+    sum_yy: f64,
+}
+
+impl Moments {
+    fn add(&mut self, x: f64, y: f64) {
+        self.count += 1.;
+        self.sum_x += x;
+        self.sum_y += y;
+        self.sum_xy += x * y;
+        self.sum_xx += x * x;
+        self.sum_yy += y * y;
+    }
+
+    fn correlation(&self) -> f64 {
+        let covariance = self.count * self.sum_xy - self.sum_x * self.sum_y;
+        let variance_x = self.count * self.sum_xx - self.sum_x * self.sum_x;
+        let variance_y = self.count * self.sum_yy - self.sum_y * self.sum_y;
+        covariance / (variance_x * variance_y).sqrt()
+    }
+}
+
+/// Return the out-degree and the in-degree of every node index of a graph,
+/// weighted with `weight_fn`. Both are the degree for an undirected graph.
+fn weighted_degrees<G, F, E>(graph: G, mut weight_fn: F) -> Result<(Vec<f64>, Vec<f64>), E>
+where
+    G: IntoEdgeReferences + NodeIndexable + GraphProp,
+    F: FnMut(G::EdgeRef) -> Result<f64, E>,
+{
+    let mut out_degree = vec![0.; graph.node_bound()];
+    let mut in_degree = vec![0.; graph.node_bound()];
+    for edge in graph.edge_references() {
+        let weight = weight_fn(edge)?;
+        let source = graph.to_index(edge.source());
+        let target = graph.to_index(edge.target());
+        out_degree[source] += weight;
+        in_degree[target] += weight;
+        if !graph.is_directed() {
+            out_degree[target] += weight;
+            in_degree[source] += weight;
+        }
+    }
+    Ok((out_degree, in_degree))
+}
+
+/// Compute the degree assortativity coefficient of a graph.
+///
+/// The degree assortativity coefficient is the Pearson correlation
+/// coefficient of the degrees of the nodes at the two ends of every edge.
+/// For a directed graph the degree of the source and of the target of every
+/// edge can each be either the in-degree or the out-degree, while for an
+/// undirected graph every edge is counted in both directions and
+/// `source_degree` and `target_degree` are ignored. With a weight function
+/// the weighted degree, the sum of the weights of the edges of a node, is
+/// used instead of the degree.
+///
+/// The coefficient is `NaN` if it isn't defined, which is the case if the
+/// graph has no edges or all the degrees at one end of the edges are equal.
+///
+/// The edges are iterated over twice: once to compute the degrees and once to
+/// correlate them.
+///
+/// Arguments:
+///
+/// * `graph` - The graph to compute the coefficient for.
+/// * `source_degree` - The degree used for the source of every edge of a
+///   directed graph, [`Outgoing`] for the out-degree and [`Incoming`] for the
+///   in-degree.
+/// * `target_degree` - The degree used for the target of every edge of a
+///   directed graph.
+/// * `weight_fn` - A callable that will be passed the `EdgeRef` of every edge
+///   and is expected to return a `Result` with the weight of that edge. It's
+///   called once for every edge.
+///
+/// # Example
+/// ```rust
+/// use rustworkx_core::assortativity::degree_assortativity;
+/// use rustworkx_core::petgraph;
+/// use rustworkx_core::petgraph::Direction::{Incoming, Outgoing};
+/// use rustworkx_core::Result;
+///
+/// // In a star graph every edge joins the hub to a leaf
+/// let graph = petgraph::graph::UnGraph::<(), ()>::from_edges(&[(0, 1), (0, 2), (0, 3)]);
+/// let res: Result<f64> = degree_assortativity(&graph, Outgoing, Incoming, |_| Ok(1.));
+/// assert_eq!(res.unwrap(), -1.);
+/// ```
+pub fn degree_assortativity<G, F, E>(
+    graph: G,
+    source_degree: Direction,
+    target_degree: Direction,
+    weight_fn: F,
+) -> Result<f64, E>
+where
+    G: IntoEdgeReferences + NodeIndexable + GraphProp,
+    F: FnMut(G::EdgeRef) -> Result<f64, E>,
+{
+    let (out_degree, in_degree) = weighted_degrees(graph, weight_fn)?;
+    let degree = |node: usize, direction: Direction| match direction {
+        Outgoing => out_degree[node],
+        Incoming => in_degree[node],
+    };
+
+    let mut moments = Moments::default();
+    for edge in graph.edge_references() {
+        let source = graph.to_index(edge.source());
+        let target = graph.to_index(edge.target());
+        if graph.is_directed() {
+            moments.add(degree(source, source_degree), degree(target, target_degree));
+        } else {
+            moments.add(out_degree[source], out_degree[target]);
+            moments.add(out_degree[target], out_degree[source]);
+        }
+    }
+    Ok(moments.correlation())
+}
+
+/// Compute the attribute assortativity coefficient of a graph.
+///
+/// The attribute assortativity coefficient measures how much more often
+/// edges join nodes with the same categorical attribute than they would if
+/// the edges were placed at random. It's `1` if every edge joins nodes with
+/// the same attribute, and negative if edges mostly join nodes with
+/// different attributes. Every edge of an undirected graph is counted in
+/// both directions.
+///
+/// The coefficient is `NaN` if it isn't defined, which is the case if the
+/// graph has no edges or the nodes at the ends of every edge all have the
+/// same attribute.
+///
+/// Arguments:
+///
+/// * `graph` - The graph to compute the coefficient for.
+/// * `attribute_fn` - A callable that will be passed every node of the graph
+///   and is expected to return a `Result` with the attribute of that node.
+///
+/// # Example
+/// ```rust
+/// use rustworkx_core::assortativity::attribute_assortativity;
+/// use rustworkx_core::petgraph;
+/// use rustworkx_core::Result;
+///
+/// // Two triangles of nodes with the same attribute joined by a single edge
+/// let graph = petgraph::graph::UnGraph::<(), ()>::from_edges(&[
+///     (0, 1), (1, 2), (2, 0), (3, 4), (4, 5), (5, 3), (0, 3),
+/// ]);
+/// let res: Result<f64> = attribute_assortativity(&graph, |node| Ok(node.index() < 3));
+/// assert!((res.unwrap() - 5. / 7.).abs() < 1e-12);
+/// ```
+pub fn attribute_assortativity<G, F, K, E>(graph: G, mut attribute_fn: F) -> Result<f64, E>
+where
+    G: IntoEdgeReferences + IntoNodeIdentifiers + NodeIndexable + GraphProp,
+    F: FnMut(G::NodeId) -> Result<K, E>,
+    K: Hash + Eq,
+{
+    let mut categories: HashMap<K, usize> = HashMap::new();
+    let mut category = vec![0; graph.node_bound()];
+    for node in graph.node_identifiers() {
+        let next = categories.len();
+        category[graph.to_index(node)] = *categories.entry(attribute_fn(node)?).or_insert(next);
+    }
+
+    let mut source_count = vec![0.; categories.len()];
+    let mut target_count = vec![0.; categories.len()];
+    let mut same = 0.;
+    let mut total = 0.;
+    for edge in graph.edge_references() {
+        let source = category[graph.to_index(edge.source())];
+        let target = category[graph.to_index(edge.target())];
+        let count = if graph.is_directed() { 1. } else { 2. };
+        source_count[source] += 1.;
+        target_count[target] += 1.;
+        if !graph.is_directed() {
+            source_count[target] += 1.;
+            target_count[source] += 1.;
+        }
+        if source == target {
+            same += count;
+        }
+        total += count;
+    }
+    let expected: f64 = source_count
+        .iter()
+        .zip(target_count.iter())
+        .map(|(a, b)| (a / total) * (b / total))
+        .sum();
+    Ok((same / total - expected) / (1. - expected))
+}
+
+/// Return the degree histogram of a graph.
+///
+/// The degree of a node is the number of edges incident to it, the sum of
+/// its in-degree and its out-degree for a directed graph. A self loop adds
+/// 2 to the degree of its node.
+///
+/// Arguments:
+///
+/// * `graph` - The graph to compute the degree histogram of.
+///
+/// Returns a `Vec` whose element `k` is the number of nodes with degree `k`,
+/// up to the largest degree of the graph. It's empty if the graph has no
+/// nodes.
+///
+/// # Example
+/// ```rust
+/// use rustworkx_core::assortativity::degree_histogram;
+/// use rustworkx_core::petgraph;
+///
+/// let graph = petgraph::graph::UnGraph::<(), ()>::from_edges(&[(0, 1), (0, 2), (0, 3)]);
+/// assert_eq!(degree_histogram(&graph), vec![0, 3, 0, 1]);
+/// ```
+pub fn degree_histogram<G>(graph: G) -> Vec<usize>
+where
+    G: IntoEdgeReferences + IntoNodeIdentifiers + NodeIndexable,
+{
+    let mut degree = vec![0; graph.node_bound()];
+    for edge in graph.edge_references() {
+        degree[graph.to_index(edge.source())] += 1;
+        degree[graph.to_index(edge.target())] += 1;
+    }
+    let mut histogram: Vec<usize> = Vec::new();
+    for node in graph.node_identifiers() {
+        let degree = degree[graph.to_index(node)];
+        if degree >= histogram.len() {
+            histogram.resize(degree + 1, 0);
+        }
+        histogram[degree] += 1;
+    }
+    histogram
+}
+
+/// Return the joint degree matrix of a graph.
+///
+/// The joint degree matrix counts the edges of the graph by the degrees of
+/// the nodes at their two ends: the entry `(k1, k2)` is the number of edges
+/// whose source has degree `k1` and whose target has degree `k2`. For a
+/// directed graph the degree of the source and of the target of every edge
+/// can each be either the in-degree or the out-degree, while for an
+/// undirected graph every edge is counted in both directions and
+/// `source_degree` and `target_degree` are ignored, so the matrix is
+/// symmetric. Dividing the entries by their sum gives the joint degree
+/// distribution `P(k1, k2)`.
+///
+/// Arguments:
+///
+/// * `graph` - The graph to compute the joint degree matrix of.
+/// * `source_degree` - The degree used for the source of every edge of a
+///   directed graph, [`Outgoing`] for the out-degree and [`Incoming`] for the
+///   in-degree.
+/// * `target_degree` - The degree used for the target of every edge of a
+///   directed graph.
+///
+/// Returns the nonzero entries of the matrix sorted by their degrees.
+///
+/// # Example
+/// ```rust
+/// use rustworkx_core::assortativity::joint_degree_matrix;
+/// use rustworkx_core::petgraph;
+/// use rustworkx_core::petgraph::Direction::{Incoming, Outgoing};
+///
+/// // A path on four nodes has degrees 1, 2, 2, 1
+/// let graph = petgraph::graph::UnGraph::<(), ()>::from_edges(&[(0, 1), (1, 2), (2, 3)]);
+/// let matrix = joint_degree_matrix(&graph, Outgoing, Incoming);
+/// assert_eq!(matrix[&(1, 2)], 2);
+/// assert_eq!(matrix[&(2, 1)], 2);
+/// assert_eq!(matrix[&(2, 2)], 2);
+/// assert_eq!(matrix.len(), 3);
+/// ```
+pub fn joint_degree_matrix<G>(
+    graph: G,
+    source_degree: Direction,
+    target_degree: Direction,
+) -> DictMap<(usize, usize), usize>
+where
+    G: IntoEdgeReferences + NodeIndexable + GraphProp,
+{
+    let mut out_degree = vec![0; graph.node_bound()];
+    let mut in_degree = vec![0; graph.node_bound()];
+    for edge in graph.edge_references() {
+        let source = graph.to_index(edge.source());
+        let target = graph.to_index(edge.target());
+        out_degree[source] += 1;
+        in_degree[target] += 1;
+        if !graph.is_directed() {
+            out_degree[target] += 1;
+            in_degree[source] += 1;
+        }
+    }
+    let degree = |node: usize, direction: Direction| match direction {
+        Outgoing => out_degree[node],
+        Incoming => in_degree[node],
+    };
+
+    let mut counts: HashMap<(usize, usize), usize> = HashMap::new();
+    for edge in graph.edge_references() {
+        let source = graph.to_index(edge.source());
+        let target = graph.to_index(edge.target());
+        if graph.is_directed() {
+            *counts
+                .entry((degree(source, source_degree), degree(target, target_degree)))
+                .or_insert(0) += 1;
+        } else {
+            *counts
+                .entry((out_degree[source], out_degree[target]))
+                .or_insert(0) += 1;
+            *counts
+                .entry((out_degree[target], out_degree[source]))
+                .or_insert(0) += 1;
+        }
+    }
+    let mut entries: Vec<((usize, usize), usize)> = counts.into_iter().collect();
+    entries.sort_unstable();
+    entries.into_iter().collect()
+}
+
+/// Compute the average degree of the neighbors of every node of a graph.
+///
+/// The average neighbor degree of a node `i` is `sum(w_ij * k_j) / s_i`,
+/// where the sum is over the edges between `i` and its neighbors `j`, `w_ij`
+/// is the weight of the edge, `k_j` is the degree of `j` and `s_i` is the
+/// sum of the weights of the edges of `i`. Without weights it's the mean
+/// degree of the neighbors, counting a neighbor once for every edge to it.
+/// For a directed graph the neighbors of a node can be either its
+/// successors or its predecessors, and the degree of the neighbors either
+/// their out-degree or their in-degree, while for an undirected graph
+/// `source_degree` and `target_degree` are ignored.
+///
+/// Arguments:
+///
+/// * `graph` - The graph to compute the average neighbor degrees for.
+/// * `source_degree` - The edges used for the neighbors of every node of a
+///   directed graph, [`Outgoing`] for its successors and [`Incoming`] for
+///   its predecessors.
+/// * `target_degree` - The degree used for the neighbors of a directed
+///   graph, [`Outgoing`] for the out-degree and [`Incoming`] for the
+///   in-degree.
+/// * `weight_fn` - A callable that will be passed the `EdgeRef` of every edge
+///   and is expected to return a `Result` with the weight of that edge. It's
+///   called once for every edge.
+///
+/// Returns the average neighbor degree of every node, which is `0` for nodes
+/// without neighbors.
+///
+/// # Example
+/// ```rust
+/// use rustworkx_core::assortativity::average_neighbor_degree;
+/// use rustworkx_core::petgraph;
+/// use rustworkx_core::petgraph::graph::NodeIndex;
+/// use rustworkx_core::petgraph::Direction::Outgoing;
+/// use rustworkx_core::Result;
+///
+/// // The leaves of a star only neighbor the hub, which neighbors the leaves
+/// let graph = petgraph::graph::UnGraph::<(), ()>::from_edges(&[(0, 1), (0, 2), (0, 3)]);
+/// let res: Result<_> = average_neighbor_degree(&graph, Outgoing, Outgoing, |_| Ok(1.));
+/// let degrees = res.unwrap();
+/// assert_eq!(degrees[&NodeIndex::new(0)], 1.);
+/// assert_eq!(degrees[&NodeIndex::new(1)], 3.);
+/// ```
+pub fn average_neighbor_degree<G, F, E>(
+    graph: G,
+    source_degree: Direction,
+    target_degree: Direction,
+    mut weight_fn: F,
+) -> Result<DictMap<G::NodeId, f64>, E>
+where
+    G: IntoEdgeReferences + IntoNodeIdentifiers + NodeIndexable + GraphProp,
+    G::NodeId: Eq + Hash,
+    F: FnMut(G::EdgeRef) -> Result<f64, E>,
+{
+    let (out_degree, in_degree) = weighted_degrees(graph, |_| Ok(1.))?;
+    let neighbor_degree = match target_degree {
+        Outgoing => &out_degree,
+        Incoming => &in_degree,
+    };
+    let mut strength = vec![0.; graph.node_bound()];
+    let mut total = vec![0.; graph.node_bound()];
+    for edge in graph.edge_references() {
+        let weight = weight_fn(edge)?;
+        let source = graph.to_index(edge.source());
+        let target = graph.to_index(edge.target());
+        if !graph.is_directed() {
+            strength[source] += weight;
+            total[source] += weight * out_degree[target];
+            strength[target] += weight;
+            total[target] += weight * out_degree[source];
+        } else {
+            let (node, neighbor) = match source_degree {
+                Outgoing => (source, target),
+                Incoming => (target, source),
+            };
+            strength[node] += weight;
+            total[node] += weight * neighbor_degree[neighbor];
+        }
+    }
+    Ok(graph
+        .node_identifiers()
+        .map(|node| {
+            let index = graph.to_index(node);
+            let average = if strength[index] == 0. {
+                0.
+            } else {
+                total[index] / strength[index]
+            };
+            (node, average)
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod test_assortativity {
+    use super::{
+        attribute_assortativity, average_neighbor_degree, degree_assortativity, degree_histogram,
+        joint_degree_matrix,
+    };
+    use crate::petgraph::graph::{DiGraph, NodeIndex, UnGraph};
+    use crate::petgraph::Direction::{Incoming, Outgoing};
+    use crate::Result;
+
+    fn assert_almost_equal(x: f64, y: f64) {
+        assert!((x - y).abs() < 1e-10, "{} != {}", x, y);
+    }
+
+    #[test]
+    fn test_degree_path() {
+        // Degrees 1, 2, 2, 1: the pairs are (1, 2), (2, 2), (2, 1) and
+        // their reverses
+        let graph = UnGraph::<(), ()>::from_edges([(0, 1), (1, 2), (2, 3)]);
+        let res: Result<f64> = degree_assortativity(&graph, Outgoing, Incoming, |_| Ok(1.));
+        assert_almost_equal(res.unwrap(), -0.5);
+    }
+
+    #[test]
+    fn test_degree_regular_is_nan() {
+        let graph = UnGraph::<(), ()>::from_edges([(0, 1), (1, 2), (2, 0)]);
+        let res: Result<f64> = degree_assortativity(&graph, Outgoing, Incoming, |_| Ok(1.));
+        assert!(res.unwrap().is_nan());
+    }
+
+    #[test]
+    fn test_degree_directed() {
+        let graph = DiGraph::<(), ()>::from_edges([(0, 1), (0, 2), (1, 2), (2, 3), (3, 0)]);
+        // Out-degrees 2, 1, 1, 1 and in-degrees 1, 1, 2, 1
+        let res: Result<f64> = degree_assortativity(&graph, Outgoing, Incoming, |_| Ok(1.));
+        // The pairs are (2, 1), (2, 2), (1, 2), (1, 1) and (1, 1)
+        assert_almost_equal(res.unwrap(), 1. / 6.);
+        let res: Result<f64> = degree_assortativity(&graph, Incoming, Outgoing, |_| Ok(1.));
+        // The pairs are (1, 1), (1, 1), (1, 1), (2, 1) and (1, 2)
+        assert_almost_equal(res.unwrap(), -0.25);
+    }
+
+    #[test]
+    fn test_degree_weighted() {
+        let graph = UnGraph::<(), f64>::from_edges([(0, 1, 1.), (1, 2, 3.)]);
+        // Weighted degrees 1, 4, 3: the pairs are (1, 4), (4, 3) and reverses
+        let res: Result<f64> =
+            degree_assortativity(&graph, Outgoing, Incoming, |e| Ok(*e.weight()));
+        assert_almost_equal(res.unwrap(), -2. / 3.);
+    }
+
+    #[test]
+    fn test_attribute_bipartite() {
+        let graph = UnGraph::<(), ()>::from_edges([(0, 1), (1, 2), (2, 3)]);
+        let res: Result<f64> = attribute_assortativity(&graph, |node| Ok(node.index() % 2));
+        assert_almost_equal(res.unwrap(), -1.);
+    }
+
+    #[test]
+    fn test_attribute_directed() {
+        // Node 0 is red and nodes 1 and 2 are blue
+        let graph = DiGraph::<&str, ()>::from_edges([(0, 1), (1, 2), (2, 1)]);
+        let res: Result<f64> = attribute_assortativity(&graph, |node| Ok(node.index() == 0));
+        // e = [[0, 1/3], [0, 2/3]], a = [1/3, 2/3] and b = [0, 1]
+        assert_almost_equal(res.unwrap(), 0.);
+    }
+
+    #[test]
+    fn test_attribute_no_edges_is_nan() {
+        let mut graph = UnGraph::<(), ()>::default();
+        graph.add_node(());
+        let res: Result<f64> = attribute_assortativity(&graph, |_| Ok(0));
+        assert!(res.unwrap().is_nan());
+    }
+
+    #[test]
+    fn test_degree_histogram() {
+        let mut graph = UnGraph::<(), ()>::from_edges([(0, 1), (1, 2), (2, 0), (2, 3), (3, 3)]);
+        graph.add_node(());
+        // Degrees 2, 2, 3, 3 with the self loop and 0
+        assert_eq!(degree_histogram(&graph), vec![1, 0, 2, 2]);
+        assert!(degree_histogram(&UnGraph::<(), ()>::default()).is_empty());
+    }
+
+    #[test]
+    fn test_degree_histogram_directed() {
+        let graph = DiGraph::<(), ()>::from_edges([(0, 1), (0, 2), (1, 2)]);
+        assert_eq!(degree_histogram(&graph), vec![0, 0, 3]);
+    }
+
+    #[test]
+    fn test_joint_degree_matrix_star() {
+        let graph = UnGraph::<(), ()>::from_edges([(0, 1), (0, 2), (0, 3)]);
+        let matrix = joint_degree_matrix(&graph, Outgoing, Incoming);
+        assert_eq!(
+            matrix.into_iter().collect::<Vec<_>>(),
+            vec![((1, 3), 3), ((3, 1), 3)]
+        );
+    }
+
+    #[test]
+    fn test_joint_degree_matrix_directed() {
+        // Out-degrees 2, 1, 1, 1 and in-degrees 1, 1, 2, 1
+        let graph = DiGraph::<(), ()>::from_edges([(0, 1), (0, 2), (1, 2), (2, 3), (3, 0)]);
+        let matrix = joint_degree_matrix(&graph, Outgoing, Incoming);
+        assert_eq!(
+            matrix.into_iter().collect::<Vec<_>>(),
+            vec![((1, 1), 2), ((1, 2), 1), ((2, 1), 1), ((2, 2), 1)]
+        );
+        let matrix = joint_degree_matrix(&graph, Incoming, Incoming);
+        assert_eq!(
+            matrix.into_iter().collect::<Vec<_>>(),
+            vec![((1, 1), 2), ((1, 2), 2), ((2, 1), 1)]
+        );
+    }
+
+    #[test]
+    fn test_average_neighbor_degree_path() {
+        let graph = UnGraph::<(), ()>::from_edges([(0, 1), (1, 2), (2, 3)]);
+        let res: Result<_> = average_neighbor_degree(&graph, Outgoing, Outgoing, |_| Ok(1.));
+        let values: Vec<f64> = res.unwrap().into_values().collect();
+        assert_eq!(values, vec![2., 1.5, 1.5, 2.]);
+    }
+
+    #[test]
+    fn test_average_neighbor_degree_weighted() {
+        let mut graph = UnGraph::<(), f64>::from_edges([(0, 1, 1.), (0, 2, 3.), (2, 3, 1.)]);
+        graph.add_node(());
+        let res: Result<_> =
+            average_neighbor_degree(&graph, Outgoing, Outgoing, |e| Ok(*e.weight()));
+        let degrees = res.unwrap();
+        // Node 0 neighbors node 1 with degree 1 and node 2 with degree 2
+        assert_almost_equal(degrees[&NodeIndex::new(0)], (1. + 3. * 2.) / 4.);
+        assert_almost_equal(degrees[&NodeIndex::new(2)], (3. * 2. + 1.) / 4.);
+        assert_eq!(degrees[&NodeIndex::new(4)], 0.);
+    }
+
+    #[test]
+    fn test_average_neighbor_degree_directed() {
+        // Out-degrees 2, 1, 1, 0 and in-degrees 0, 1, 2, 1
+        let graph = DiGraph::<(), ()>::from_edges([(0, 1), (0, 2), (1, 2), (2, 3)]);
+        let res: Result<_> = average_neighbor_degree(&graph, Outgoing, Outgoing, |_| Ok(1.));
+        let values: Vec<f64> = res.unwrap().into_values().collect();
+        assert_eq!(values, vec![1., 1., 0., 0.]);
+        let res: Result<_> = average_neighbor_degree(&graph, Incoming, Incoming, |_| Ok(1.));
+        let values: Vec<f64> = res.unwrap().into_values().collect();
+        assert_eq!(values, vec![0., 0., 0.5, 2.]);
+    }
+}
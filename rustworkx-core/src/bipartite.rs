@@ -0,0 +1,397 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+use std::collections::VecDeque;
+use std::error::Error;
+use std::fmt::{Debug, Display, Formatter};
+
+use hashbrown::HashSet;
+use petgraph::visit::{
+    EdgeRef, GraphProp, IntoEdgeReferences, IntoNeighborsDirected, IntoNodeIdentifiers,
+    NodeIndexable,
+};
+use petgraph::{Incoming, Outgoing, Undirected};
+
+use crate::flow::FlowNetwork;
+
+/// The error returned when a graph isn't bipartite, with an odd cycle of
+/// the graph as a certificate.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NotBipartite<N> {
+    /// The nodes of an odd cycle in the order they're visited. The cycle
+    /// closes with an edge from the last node back to the first, and is a
+    /// single node for a self-loop.
+    pub odd_cycle: Vec<N>,
+}
+
+impl<N: Debug> Display for NotBipartite<N> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Graph is not bipartite, it has the odd cycle {:?}",
+            self.odd_cycle
+        )
+    }
+}
+
+impl<N: Debug> Error for NotBipartite<N> {}
+
+/// Find the depth of every node in a breadth-first search forest of the
+/// graph, ignoring the direction of edges, or an odd cycle.
+fn bipartite_depths<G>(graph: G) -> Result<Vec<usize>, NotBipartite<G::NodeId>>
+where
+    G: IntoNodeIdentifiers + IntoNeighborsDirected + NodeIndexable,
+{
+    let mut depth: Vec<usize> = vec![usize::MAX; graph.node_bound()];
+    let mut parent: Vec<usize> = vec![usize::MAX; graph.node_bound()];
+    for start in graph.node_identifiers() {
+        let start = graph.to_index(start);
+        if depth[start] != usize::MAX {
+            continue;
+        }
+        depth[start] = 0;
+        let mut queue: VecDeque<usize> = VecDeque::from([start]);
+        while let Some(node) = queue.pop_front() {
+            let id = graph.from_index(node);
+            for neighbor in graph
+                .neighbors_directed(id, Outgoing)
+                .chain(graph.neighbors_directed(id, Incoming))
+            {
+                let neighbor = graph.to_index(neighbor);
+                if depth[neighbor] == usize::MAX {
+                    depth[neighbor] = depth[node] + 1;
+                    parent[neighbor] = node;
+                    queue.push_back(neighbor);
+                } else if depth[neighbor] % 2 == depth[node] % 2 {
+                    // Both nodes are at the same depth, so their paths to
+                    // the lowest common ancestor have the same length
+                    let mut first: Vec<usize> = vec![node];
+                    let mut second: Vec<usize> = vec![neighbor];
+                    let (mut a, mut b) = (node, neighbor);
+                    while a != b {
+                        a = parent[a];
+                        b = parent[b];
+                        first.push(a);
+                        second.push(b);
+                    }
+                    second.pop();
+                    first.reverse();
+                    first.extend(second);
+                    return Err(NotBipartite {
+                        odd_cycle: first.into_iter().map(|n| graph.from_index(n)).collect(),
+                    });
+                }
+            }
+        }
+    }
+    Ok(depth)
+}
+
+/// Split the nodes of a bipartite graph into its two sides.
+///
+/// A graph is bipartite if its nodes can be split into two sets such that
+/// every edge joins a node of one set to a node of the other. The sets are
+/// found with a breadth-first search, which also finds an odd cycle if the
+/// graph isn't bipartite. The direction of edges is ignored.
+///
+/// If the graph isn't connected the split isn't unique. In every connected
+/// component the first node returned by
+/// [`IntoNodeIdentifiers::node_identifiers`] is put in the first set.
+///
+/// This function runs in `O(|V| + |E|)` time.
+///
+/// Arguments:
+///
+/// * `graph` - The graph to split.
+///
+/// Returns the two sets of nodes, each in the order of
+/// [`IntoNodeIdentifiers::node_identifiers`], or a [`NotBipartite`] error
+/// with an odd cycle of the graph.
+///
+/// # Example
+/// ```rust
+/// use rustworkx_core::bipartite::bipartite_sets;
+/// use rustworkx_core::petgraph::graph::{NodeIndex, UnGraph};
+///
+/// let graph = UnGraph::<(), ()>::from_edges([(0, 1), (1, 2), (2, 3)]);
+/// let (first, second) = bipartite_sets(&graph).unwrap();
+/// assert_eq!(first, [NodeIndex::new(0), NodeIndex::new(2)]);
+/// assert_eq!(second, [NodeIndex::new(1), NodeIndex::new(3)]);
+///
+/// let triangle = UnGraph::<(), ()>::from_edges([(0, 1), (1, 2), (2, 0)]);
+/// let err = bipartite_sets(&triangle).unwrap_err();
+/// assert_eq!(err.odd_cycle.len(), 3);
+/// ```
+#[allow(clippy::type_complexity)]
+pub fn bipartite_sets<G>(
+    graph: G,
+) -> Result<(Vec<G::NodeId>, Vec<G::NodeId>), NotBipartite<G::NodeId>>
+where
+    G: IntoNodeIdentifiers + IntoNeighborsDirected + NodeIndexable,
+{
+    let depth = bipartite_depths(graph)?;
+    Ok(graph
+        .node_identifiers()
+        .partition(|node| depth[graph.to_index(*node)] % 2 == 0))
+}
+
+/// Compute a maximum weight matching of a bipartite graph.
+///
+/// A matching is a set of edges in which no two edges share a node, and its
+/// weight is the sum of the weights of its edges. This function is an
+/// alternative to [`crate::max_weight_matching::max_weight_matching`] for
+/// bipartite graphs: it reduces the problem to a minimum cost flow from a
+/// source joined to one side of the graph to a sink joined to the other,
+/// where every edge has a cost of minus its weight, and solves it with
+/// successive shortest paths. Every path grows the matching by one edge, so
+/// this runs in `O(k |E| log |V|)` time for a matching of `k` edges, which
+/// is faster than the blossom algorithm for sparse graphs.
+///
+/// Arguments:
+///
+/// * `graph` - The undirected graph to compute the matching of.
+/// * `max_cardinality` - If `true`, compute the matching with the maximum
+///   weight among the matchings with the maximum number of edges.
+/// * `weight_fn` - A callable that will be passed an edge reference for each
+///   edge in the graph and is expected to return a `Result<i128>` which if
+///   it doesn't error represents the weight of that edge.
+///
+/// Returns the matched edges as pairs of node indices, the first of which is
+/// in the first set returned by [`bipartite_sets`], or `None` if the graph
+/// isn't bipartite.
+///
+/// # Example
+/// ```rust
+/// use std::convert::Infallible;
+/// use hashbrown::HashSet;
+/// use rustworkx_core::bipartite::max_weight_bipartite_matching;
+/// use rustworkx_core::petgraph::graph::UnGraph;
+///
+/// let graph = UnGraph::<(), i128>::from_edges([(0, 1, 2), (1, 2, 5), (2, 3, 2)]);
+/// let res: Result<_, Infallible> =
+///     max_weight_bipartite_matching(&graph, false, |e| Ok(*e.weight()));
+/// assert_eq!(res.unwrap().unwrap(), HashSet::from([(2, 1)]));
+/// let res: Result<_, Infallible> =
+///     max_weight_bipartite_matching(&graph, true, |e| Ok(*e.weight()));
+/// assert_eq!(res.unwrap().unwrap(), HashSet::from([(0, 1), (2, 3)]));
+/// ```
+pub fn max_weight_bipartite_matching<G, F, E>(
+    graph: G,
+    max_cardinality: bool,
+    mut weight_fn: F,
+) -> Result<Option<HashSet<(usize, usize)>>, E>
+where
+    G: GraphProp<EdgeType = Undirected>
+        + IntoEdgeReferences
+        + IntoNodeIdentifiers
+        + IntoNeighborsDirected
+        + NodeIndexable,
+    F: FnMut(G::EdgeRef) -> Result<i128, E>,
+{
+    let depth = match bipartite_depths(graph) {
+        Ok(depth) => depth,
+        Err(_) => return Ok(None),
+    };
+    let is_first = |node: usize| depth[node] % 2 == 0;
+    // The source is node 0, the sink node 1 and node n of the graph is
+    // node n + 2 of the network
+    let mut network = FlowNetwork::new(graph.node_bound() + 2);
+    for node in graph.node_identifiers() {
+        let node = graph.to_index(node);
+        if is_first(node) {
+            network.add_arc(0, node + 2, 1, 0);
+        }
+    }
+    let first_edge_arc = network.arcs.len();
+    for edge in graph.edge_references() {
+        let weight = weight_fn(edge)?;
+        let source = graph.to_index(edge.source());
+        let target = graph.to_index(edge.target());
+        let (first, second) = if is_first(source) {
+            (source, target)
+        } else {
+            (target, source)
+        };
+        network.add_arc(first + 2, second + 2, 1, -weight);
+    }
+    let last_edge_arc = network.arcs.len();
+    for node in graph.node_identifiers() {
+        let node = graph.to_index(node);
+        if !is_first(node) {
+            network.add_arc(node + 2, 1, 1, 0);
+        }
+    }
+
+    network.init_potentials(0);
+    while network.augment(0, 1, |cost| max_cardinality || cost < 0) {}
+
+    let mut matching = HashSet::new();
+    for arc in (first_edge_arc..last_edge_arc).step_by(2) {
+        if network.arcs[arc].1 == 0 {
+            let first = network.arcs[arc ^ 1].0 - 2;
+            let second = network.arcs[arc].0 - 2;
+            matching.insert((first, second));
+        }
+    }
+    Ok(Some(matching))
+}
+
+#[cfg(test)]
+mod test_bipartite {
+    use super::{bipartite_sets, max_weight_bipartite_matching};
+    use crate::generators::{complete_graph, cycle_graph, petersen_graph};
+    use crate::max_weight_matching::max_weight_matching;
+    use crate::petgraph::graph::{DiGraph, NodeIndex, UnGraph};
+    use crate::petgraph::visit::EdgeRef;
+    use hashbrown::HashSet;
+    use std::convert::Infallible;
+
+    fn matching_weight(graph: &UnGraph<(), i128>, matching: &HashSet<(usize, usize)>) -> i128 {
+        matching
+            .iter()
+            .map(|(a, b)| {
+                let edge = graph
+                    .find_edge(NodeIndex::new(*a), NodeIndex::new(*b))
+                    .unwrap();
+                graph[edge]
+            })
+            .sum()
+    }
+
+    fn assert_odd_cycle<E>(graph: &UnGraph<(), E>, cycle: &[NodeIndex]) {
+        assert_eq!(cycle.len() % 2, 1);
+        let unique: HashSet<&NodeIndex> = cycle.iter().collect();
+        assert_eq!(unique.len(), cycle.len());
+        for (i, node) in cycle.iter().enumerate() {
+            let next = cycle[(i + 1) % cycle.len()];
+            assert!(graph.contains_edge(*node, next));
+        }
+    }
+
+    #[test]
+    fn test_even_cycle() {
+        let graph: UnGraph<(), ()> = cycle_graph(Some(6), None, || (), || (), false).unwrap();
+        let (first, second) = bipartite_sets(&graph).unwrap();
+        let indices = |nodes: Vec<NodeIndex>| nodes.iter().map(|n| n.index()).collect::<Vec<_>>();
+        assert_eq!(indices(first), [0, 2, 4]);
+        assert_eq!(indices(second), [1, 3, 5]);
+    }
+
+    #[test]
+    fn test_odd_cycle_certificate() {
+        for graph in [
+            cycle_graph(Some(7), None, || (), || (), false).unwrap(),
+            petersen_graph(5, 2, || (), || ()).unwrap(),
+            complete_graph(Some(4), None, || (), || ()).unwrap(),
+        ] {
+            let graph: UnGraph<(), ()> = graph;
+            let err = bipartite_sets(&graph).unwrap_err();
+            assert_odd_cycle(&graph, &err.odd_cycle);
+        }
+    }
+
+    #[test]
+    fn test_self_loop() {
+        let graph = UnGraph::<(), ()>::from_edges([(0, 1), (1, 1)]);
+        let err = bipartite_sets(&graph).unwrap_err();
+        assert_eq!(err.odd_cycle, [NodeIndex::new(1)]);
+    }
+
+    #[test]
+    fn test_directed_ignores_direction() {
+        let graph = DiGraph::<(), ()>::from_edges([(0, 1), (2, 1), (2, 3), (4, 3)]);
+        let (first, second) = bipartite_sets(&graph).unwrap();
+        assert_eq!(first.len(), 3);
+        assert_eq!(second.len(), 2);
+        let graph = DiGraph::<(), ()>::from_edges([(0, 1), (1, 2), (0, 2)]);
+        assert!(bipartite_sets(&graph).is_err());
+    }
+
+    #[test]
+    fn test_disconnected() {
+        let mut graph = UnGraph::<(), ()>::from_edges([(0, 1), (2, 3)]);
+        graph.add_node(());
+        let (first, second) = bipartite_sets(&graph).unwrap();
+        assert_eq!(first.len(), 3);
+        assert_eq!(second.len(), 2);
+    }
+
+    #[test]
+    fn test_matching_matches_blossom() {
+        // Grid-like bipartite graphs with pseudo-random weights
+        for seed in 0..20_i128 {
+            let mut edges: Vec<(u32, u32, i128)> = Vec::new();
+            for row in 0..4_u32 {
+                for col in 0..5_u32 {
+                    let node = row * 5 + col;
+                    let weight = |k: u32| (seed * 31 + (node * 7 + k) as i128 * 13) % 11 - 2;
+                    if col + 1 < 5 {
+                        edges.push((node, node + 1, weight(0)));
+                    }
+                    if row + 1 < 4 {
+                        edges.push((node, node + 5, weight(1)));
+                    }
+                }
+            }
+            let graph = UnGraph::<(), i128>::from_edges(edges);
+            for max_cardinality in [false, true] {
+                let res: Result<_, Infallible> =
+                    max_weight_bipartite_matching(&graph, max_cardinality, |e| Ok(*e.weight()));
+                let flow = res.unwrap().unwrap();
+                let res: Result<_, Infallible> =
+                    max_weight_matching(&graph, max_cardinality, |e| Ok(*e.weight()), true);
+                let blossom = res.unwrap();
+                assert_eq!(
+                    matching_weight(&graph, &flow),
+                    matching_weight(&graph, &blossom)
+                );
+                if max_cardinality {
+                    assert_eq!(flow.len(), blossom.len());
+                }
+                let mut seen: HashSet<usize> = HashSet::new();
+                for (a, b) in &flow {
+                    assert!(seen.insert(*a));
+                    assert!(seen.insert(*b));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_matching_not_bipartite() {
+        let graph = UnGraph::<(), i128>::from_edges([(0, 1, 1), (1, 2, 1), (2, 0, 1)]);
+        let res: Result<_, Infallible> =
+            max_weight_bipartite_matching(&graph, false, |e| Ok(*e.weight()));
+        assert_eq!(res.unwrap(), None);
+    }
+
+    #[test]
+    fn test_matching_error() {
+        let graph = UnGraph::<(), i128>::from_edges([(0, 1, 1), (1, 2, 1)]);
+        let res = max_weight_bipartite_matching(&graph, false, |e| {
+            if e.id().index() == 1 {
+                Err("bad weight")
+            } else {
+                Ok(*e.weight())
+            }
+        });
+        assert_eq!(res, Err("bad weight"));
+    }
+
+    #[test]
+    fn test_matching_empty() {
+        let graph = UnGraph::<(), i128>::default();
+        let res: Result<_, Infallible> =
+            max_weight_bipartite_matching(&graph, true, |e| Ok(*e.weight()));
+        assert!(res.unwrap().unwrap().is_empty());
+    }
+}
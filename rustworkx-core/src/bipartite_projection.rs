@@ -0,0 +1,244 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+use std::hash::Hash;
+
+use hashbrown::{HashMap, HashSet};
+use petgraph::data::{Build, Create};
+use petgraph::visit::{Data, GraphBase, IntoNeighbors, IntoNodeIdentifiers};
+
+use crate::dictmap::*;
+
+type ProjectionResult<G, N, E> = Result<(G, HashMap<N, <G as GraphBase>::NodeId>), E>;
+
+/// The scheme used to weight the edges of a bipartite projection.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ProjectionWeight {
+    /// The number of neighbors the two nodes have in common.
+    #[default]
+    Count,
+    /// Newman's collaboration weight, the sum of `1 / (d - 1)` over the
+    /// common neighbors of the two nodes, where `d` is the degree of the
+    /// common neighbor.
+    Newman,
+    /// The Jaccard overlap of the neighborhoods of the two nodes, the number
+    /// of common neighbors divided by the number of neighbors of either node.
+    Jaccard,
+}
+
+/// Constructs the projection of a bipartite graph onto one of its parts.
+///
+/// The projection has a node for every node of `nodes` and an edge between
+/// two of them if they have at least one neighbor in common in `graph`. The
+/// weight of every edge is computed with the given [`ProjectionWeight`]
+/// scheme. Neighbors are counted once no matter how many parallel edges join
+/// them, and edges that don't join a node of `nodes` to a node outside it are
+/// ignored.
+///
+/// The nodes of the projection are created in the order of the nodes of
+/// `graph`, and the edges in the order of their endpoints.
+///
+/// Arguments:
+///
+/// * `graph` - The undirected input graph.
+/// * `nodes` - The nodes of the part of the bipartition to project onto. The
+///   [`two_color`](crate::coloring::two_color) function can be used to find a
+///   bipartition of `graph`.
+/// * `weight` - The scheme used to weight the edges of the projection.
+/// * `node_weight_fn` - A callable that will be passed every node of `nodes`
+///   and is expected to return a `Result` with the weight of its node in the
+///   projection.
+/// * `edge_weight_fn` - A callable that will be passed the computed weight of
+///   every edge of the projection and is expected to return a `Result` with
+///   the weight of that edge.
+///
+/// Returns the projection and a map from the nodes of `nodes` to the nodes
+/// of the projection.
+///
+/// # Example
+/// ```rust
+/// use std::convert::Infallible;
+/// use hashbrown::HashSet;
+/// use rustworkx_core::bipartite_projection::{bipartite_projection, ProjectionWeight};
+/// use rustworkx_core::petgraph::graph::{NodeIndex, UnGraph};
+///
+/// // People 0, 1 and 2 are members of the groups 3 and 4
+/// let graph = UnGraph::<(), ()>::from_edges(&[(0, 3), (1, 3), (1, 4), (2, 4), (0, 4)]);
+/// let people: HashSet<NodeIndex> = (0..3).map(NodeIndex::new).collect();
+/// let res: Result<(UnGraph<(), f64>, _), Infallible> =
+///     bipartite_projection(&graph, &people, ProjectionWeight::Count, |_| Ok(()), Ok);
+/// let (projection, _) = res.unwrap();
+/// let weights: Vec<f64> = projection.edge_weights().copied().collect();
+/// assert_eq!(weights, vec![2., 1., 1.]);
+/// ```
+pub fn bipartite_projection<K, G, F, H, E>(
+    graph: K,
+    nodes: &HashSet<K::NodeId>,
+    weight: ProjectionWeight,
+    mut node_weight_fn: F,
+    mut edge_weight_fn: H,
+) -> ProjectionResult<G, K::NodeId, E>
+where
+    K: IntoNodeIdentifiers + IntoNeighbors,
+    K::NodeId: Hash + Eq,
+    G: Build + Create + Data,
+    F: FnMut(K::NodeId) -> Result<G::NodeWeight, E>,
+    H: FnMut(f64) -> Result<G::EdgeWeight, E>,
+{
+    let neighbors_of = |node: K::NodeId, inside: bool| -> Vec<K::NodeId> {
+        let mut seen: HashSet<K::NodeId> = HashSet::new();
+        graph
+            .neighbors(node)
+            .filter(|neighbor| nodes.contains(neighbor) == inside && seen.insert(*neighbor))
+            .collect()
+    };
+
+    let mut out_graph = G::with_capacity(nodes.len(), 0);
+    let mut node_map: HashMap<K::NodeId, G::NodeId> = HashMap::with_capacity(nodes.len());
+    let mut order: Vec<K::NodeId> = Vec::with_capacity(nodes.len());
+    let mut position: HashMap<K::NodeId, usize> = HashMap::with_capacity(nodes.len());
+    for node in graph.node_identifiers() {
+        if nodes.contains(&node) {
+            node_map.insert(node, out_graph.add_node(node_weight_fn(node)?));
+            position.insert(node, order.len());
+            order.push(node);
+        }
+    }
+
+    // The neighbors in `nodes` of every node on the other side of the
+    // bipartition, computed the first time that node is reached
+    let mut other_neighbors: HashMap<K::NodeId, Vec<K::NodeId>> = HashMap::new();
+    let degrees: Vec<usize> = order
+        .iter()
+        .map(|node| neighbors_of(*node, false).len())
+        .collect();
+    for (pos, node) in order.iter().enumerate() {
+        let mut weights: DictMap<usize, f64> = DictMap::new();
+        for middle in neighbors_of(*node, false) {
+            let middle_neighbors = other_neighbors
+                .entry(middle)
+                .or_insert_with(|| neighbors_of(middle, true));
+            if middle_neighbors.len() < 2 {
+                continue;
+            }
+            let contribution = match weight {
+                ProjectionWeight::Newman => 1. / (middle_neighbors.len() - 1) as f64,
+                _ => 1.,
+            };
+            for other in middle_neighbors.iter() {
+                let other_pos = position[other];
+                if other_pos > pos {
+                    *weights.entry(other_pos).or_insert(0.) += contribution;
+                }
+            }
+        }
+        weights.sort_keys();
+        for (other_pos, edge_weight) in weights {
+            let edge_weight = match weight {
+                ProjectionWeight::Jaccard => {
+                    edge_weight / ((degrees[pos] + degrees[other_pos]) as f64 - edge_weight)
+                }
+                _ => edge_weight,
+            };
+            let edge_weight = edge_weight_fn(edge_weight)?;
+            out_graph.add_edge(node_map[node], node_map[&order[other_pos]], edge_weight);
+        }
+    }
+    Ok((out_graph, node_map))
+}
+
+#[cfg(test)]
+mod test_bipartite_projection {
+    use super::{bipartite_projection, ProjectionWeight};
+    use crate::coloring::two_color;
+    use hashbrown::HashSet;
+    use petgraph::graph::{NodeIndex, UnGraph};
+    use petgraph::visit::EdgeRef;
+    use std::convert::Infallible;
+
+    fn project(
+        graph: &UnGraph<(), ()>,
+        nodes: &[usize],
+        weight: ProjectionWeight,
+    ) -> Vec<(usize, usize, f64)> {
+        let nodes: HashSet<NodeIndex> = nodes.iter().map(|n| NodeIndex::new(*n)).collect();
+        let res: Result<(UnGraph<(), f64>, _), Infallible> =
+            bipartite_projection(graph, &nodes, weight, |_| Ok(()), Ok);
+        let (projection, _) = res.unwrap();
+        projection
+            .edge_references()
+            .map(|e| (e.source().index(), e.target().index(), *e.weight()))
+            .collect()
+    }
+
+    // People 0, 1 and 2 and the groups 3, 4 and 5, where group 5 only has
+    // person 2 as a member
+    fn membership() -> UnGraph<(), ()> {
+        UnGraph::from_edges([(0, 3), (1, 3), (1, 4), (2, 4), (0, 4), (2, 5)])
+    }
+
+    #[test]
+    fn test_count() {
+        let edges = project(&membership(), &[0, 1, 2], ProjectionWeight::Count);
+        assert_eq!(edges, vec![(0, 1, 2.), (0, 2, 1.), (1, 2, 1.)]);
+    }
+
+    #[test]
+    fn test_newman() {
+        let edges = project(&membership(), &[0, 1, 2], ProjectionWeight::Newman);
+        assert_eq!(edges, vec![(0, 1, 1.5), (0, 2, 0.5), (1, 2, 0.5)]);
+    }
+
+    #[test]
+    fn test_jaccard() {
+        let edges = project(&membership(), &[0, 1, 2], ProjectionWeight::Jaccard);
+        assert_eq!(edges, vec![(0, 1, 1.), (0, 2, 1. / 3.), (1, 2, 1. / 3.)]);
+    }
+
+    #[test]
+    fn test_other_part() {
+        let edges = project(&membership(), &[3, 4, 5], ProjectionWeight::Count);
+        assert_eq!(edges, vec![(0, 1, 2.), (1, 2, 1.)]);
+    }
+
+    #[test]
+    fn test_parallel_edges_counted_once() {
+        let graph = UnGraph::<(), ()>::from_edges([(0, 2), (0, 2), (1, 2)]);
+        let edges = project(&graph, &[0, 1], ProjectionWeight::Count);
+        assert_eq!(edges, vec![(0, 1, 1.)]);
+    }
+
+    #[test]
+    fn test_two_color_bipartition() {
+        let graph = membership();
+        let colors = two_color(&graph).unwrap();
+        let nodes: HashSet<NodeIndex> = colors
+            .iter()
+            .filter(|(_, color)| **color == colors[&NodeIndex::new(0)])
+            .map(|(node, _)| *node)
+            .collect();
+        let res: Result<(UnGraph<usize, f64>, _), Infallible> = bipartite_projection(
+            &graph,
+            &nodes,
+            ProjectionWeight::Count,
+            |n| Ok(n.index()),
+            Ok,
+        );
+        let (projection, node_map) = res.unwrap();
+        assert_eq!(
+            projection.node_weights().copied().collect::<Vec<_>>(),
+            vec![0, 1, 2]
+        );
+        assert_eq!(node_map[&NodeIndex::new(2)], NodeIndex::new(2));
+        assert_eq!(projection.edge_count(), 3);
+    }
+}
@@ -0,0 +1,340 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+use std::hash::Hash;
+
+use hashbrown::HashSet;
+use indexmap::IndexSet;
+use petgraph::visit::{EdgeRef, GraphProp, IntoEdges, IntoNodeIdentifiers};
+
+/// Whether `node` is in the target set, which is everything outside of the
+/// source set if there is no target set.
+fn in_targets<N: Hash + Eq>(node: &N, sources: &HashSet<N>, targets: Option<&HashSet<N>>) -> bool {
+    match targets {
+        Some(targets) => targets.contains(node),
+        None => !sources.contains(node),
+    }
+}
+
+/// Compute the node boundary of a set of nodes.
+///
+/// The node boundary of a set of nodes `S` is the set of nodes outside of
+/// `S` that are adjacent to a node of `S`. If `targets` is set only the
+/// nodes of `targets` that are adjacent to a node of `S` and not in `S` are
+/// returned. For directed graphs only the outgoing edges of the nodes of `S`
+/// are followed.
+///
+/// Arguments:
+///
+/// * `graph` - The graph to compute the boundary in.
+/// * `nodes` - The set of nodes `S`.
+/// * `targets` - An optional set of nodes to restrict the boundary to.
+///
+/// Returns the nodes of the boundary in the order they are found by
+/// following the edges of the nodes of `S` in the order of `nodes`.
+///
+/// # Example
+/// ```rust
+/// use rustworkx_core::petgraph::graph::{NodeIndex, UnGraph};
+/// use rustworkx_core::boundary::node_boundary;
+///
+/// let graph = UnGraph::<(), ()>::from_edges([(0, 1), (1, 2), (2, 3), (0, 4)]);
+/// let nodes = [NodeIndex::new(0), NodeIndex::new(1)];
+/// let boundary = node_boundary(&graph, nodes, None::<Vec<NodeIndex>>);
+/// assert_eq!(boundary, vec![NodeIndex::new(4), NodeIndex::new(2)]);
+/// let targets = [NodeIndex::new(2), NodeIndex::new(3)];
+/// let boundary = node_boundary(&graph, nodes, Some(targets));
+/// assert_eq!(boundary, vec![NodeIndex::new(2)]);
+/// ```
+pub fn node_boundary<G, I, J>(graph: G, nodes: I, targets: Option<J>) -> Vec<G::NodeId>
+where
+    G: IntoEdges,
+    G::NodeId: Hash + Eq,
+    I: IntoIterator<Item = G::NodeId>,
+    J: IntoIterator<Item = G::NodeId>,
+{
+    let nodes: IndexSet<G::NodeId, ahash::RandomState> = nodes.into_iter().collect();
+    let sources: HashSet<G::NodeId> = nodes.iter().copied().collect();
+    let targets: Option<HashSet<G::NodeId>> = targets.map(|t| t.into_iter().collect());
+    let mut boundary: IndexSet<G::NodeId, ahash::RandomState> = IndexSet::default();
+    for node in &nodes {
+        for edge in graph.edges(*node) {
+            let next = edge.target();
+            if !sources.contains(&next) && in_targets(&next, &sources, targets.as_ref()) {
+                boundary.insert(next);
+            }
+        }
+    }
+    boundary.into_iter().collect()
+}
+
+/// Compute the edge boundary of a set of nodes.
+///
+/// The edge boundary of a set of nodes `S` is the set of edges with one
+/// endpoint in `S` and the other one outside of `S`. If `targets` is set
+/// only the edges whose other endpoint is in `targets` (and not in `S`) are
+/// returned. For directed graphs only the outgoing edges of the nodes of `S`
+/// are in the boundary.
+///
+/// Arguments:
+///
+/// * `graph` - The graph to compute the boundary in.
+/// * `nodes` - The set of nodes `S`.
+/// * `targets` - An optional set of nodes to restrict the boundary to.
+///
+/// Returns the edges of the boundary, with their source in `S`, in the order
+/// of the nodes of `S` in `nodes`.
+///
+/// # Example
+/// ```rust
+/// use rustworkx_core::petgraph::graph::{NodeIndex, UnGraph};
+/// use rustworkx_core::petgraph::visit::EdgeRef;
+/// use rustworkx_core::boundary::edge_boundary;
+///
+/// let graph = UnGraph::<(), ()>::from_edges([(0, 1), (1, 2), (2, 3), (0, 4)]);
+/// let nodes = [NodeIndex::new(0), NodeIndex::new(1)];
+/// let boundary: Vec<(usize, usize)> = edge_boundary(&graph, nodes, None::<Vec<NodeIndex>>)
+///     .into_iter()
+///     .map(|edge| (edge.source().index(), edge.target().index()))
+///     .collect();
+/// assert_eq!(boundary, vec![(0, 4), (1, 2)]);
+/// ```
+pub fn edge_boundary<G, I, J>(graph: G, nodes: I, targets: Option<J>) -> Vec<G::EdgeRef>
+where
+    G: IntoEdges,
+    G::NodeId: Hash + Eq,
+    I: IntoIterator<Item = G::NodeId>,
+    J: IntoIterator<Item = G::NodeId>,
+{
+    let nodes: IndexSet<G::NodeId, ahash::RandomState> = nodes.into_iter().collect();
+    let sources: HashSet<G::NodeId> = nodes.iter().copied().collect();
+    let targets: Option<HashSet<G::NodeId>> = targets.map(|t| t.into_iter().collect());
+    let mut boundary: Vec<G::EdgeRef> = Vec::new();
+    for node in &nodes {
+        for edge in graph.edges(*node) {
+            let next = edge.target();
+            if !sources.contains(&next) && in_targets(&next, &sources, targets.as_ref()) {
+                boundary.push(edge);
+            }
+        }
+    }
+    boundary
+}
+
+/// Compute the size of the cut between two sets of nodes.
+///
+/// The size of the cut between a set of nodes `S` and a set of nodes `T`,
+/// which is everything outside of `S` if `targets` is `None`, is the total
+/// weight of the edges between a node of `S` and a node of `T`, which for
+/// directed graphs includes the edges in both directions. This is the total
+/// weight of the [`edge_boundary`] of `S` (and of `T` for directed graphs).
+///
+/// Arguments:
+///
+/// * `graph` - The graph to compute the cut size in.
+/// * `nodes` - The set of nodes `S`.
+/// * `targets` - The optional set of nodes `T`.
+/// * `weight_fn` - A callable that is passed an edge and returns its weight.
+///
+/// # Example
+/// ```rust
+/// use std::convert::Infallible;
+/// use rustworkx_core::petgraph::graph::{DiGraph, NodeIndex};
+/// use rustworkx_core::boundary::cut_size;
+///
+/// let graph = DiGraph::<(), f64>::from_edges([(0, 1, 2.0), (1, 2, 1.0), (2, 0, 0.5)]);
+/// let nodes = [NodeIndex::new(0)];
+/// let size = cut_size(&graph, nodes, None::<Vec<NodeIndex>>, |e| {
+///     Ok::<f64, Infallible>(*e.weight())
+/// });
+/// assert_eq!(size, Ok(2.5));
+/// ```
+pub fn cut_size<G, I, J, F, E>(
+    graph: G,
+    nodes: I,
+    targets: Option<J>,
+    mut weight_fn: F,
+) -> Result<f64, E>
+where
+    G: IntoEdges + IntoNodeIdentifiers + GraphProp,
+    G::NodeId: Hash + Eq,
+    I: IntoIterator<Item = G::NodeId>,
+    J: IntoIterator<Item = G::NodeId>,
+    F: FnMut(G::EdgeRef) -> Result<f64, E>,
+{
+    let nodes: Vec<G::NodeId> = nodes.into_iter().collect();
+    let targets: Vec<G::NodeId> = match targets {
+        Some(targets) => targets.into_iter().collect(),
+        None => {
+            let sources: HashSet<G::NodeId> = nodes.iter().copied().collect();
+            graph
+                .node_identifiers()
+                .filter(|node| !sources.contains(node))
+                .collect()
+        }
+    };
+    let mut size = 0.0;
+    for edge in edge_boundary(graph, nodes.iter().copied(), Some(targets.iter().copied())) {
+        size += weight_fn(edge)?;
+    }
+    if graph.is_directed() {
+        for edge in edge_boundary(graph, targets, Some(nodes)) {
+            size += weight_fn(edge)?;
+        }
+    }
+    Ok(size)
+}
+
+/// Compute the volume of a set of nodes.
+///
+/// The volume of a set of nodes is the sum of their weighted degrees, where
+/// a self-loop counts twice for undirected graphs, and only the outgoing
+/// edges are counted for directed graphs.
+///
+/// Arguments:
+///
+/// * `graph` - The graph to compute the volume in.
+/// * `nodes` - The set of nodes. Duplicates are ignored.
+/// * `weight_fn` - A callable that is passed an edge and returns its weight.
+///
+/// # Example
+/// ```rust
+/// use std::convert::Infallible;
+/// use rustworkx_core::petgraph::graph::{NodeIndex, UnGraph};
+/// use rustworkx_core::boundary::volume;
+///
+/// let graph = UnGraph::<(), ()>::from_edges([(0, 1), (1, 2), (2, 3), (0, 4)]);
+/// let nodes = [NodeIndex::new(0), NodeIndex::new(1)];
+/// assert_eq!(volume(&graph, nodes, |_| Ok::<f64, Infallible>(1.0)), Ok(4.0));
+/// ```
+pub fn volume<G, I, F, E>(graph: G, nodes: I, mut weight_fn: F) -> Result<f64, E>
+where
+    G: IntoEdges + GraphProp,
+    G::NodeId: Hash + Eq,
+    I: IntoIterator<Item = G::NodeId>,
+    F: FnMut(G::EdgeRef) -> Result<f64, E>,
+{
+    let nodes: IndexSet<G::NodeId, ahash::RandomState> = nodes.into_iter().collect();
+    let mut volume = 0.0;
+    for node in nodes {
+        for edge in graph.edges(node) {
+            let weight = weight_fn(edge)?;
+            if !graph.is_directed() && edge.source() == edge.target() {
+                volume += 2.0 * weight;
+            } else {
+                volume += weight;
+            }
+        }
+    }
+    Ok(volume)
+}
+
+#[cfg(test)]
+mod test_boundary {
+    use super::{cut_size, edge_boundary, node_boundary, volume};
+    use crate::petgraph::graph::{DiGraph, NodeIndex, UnGraph};
+    use crate::petgraph::visit::EdgeRef;
+    use std::convert::Infallible;
+
+    fn nodes(indices: &[usize]) -> Vec<NodeIndex> {
+        indices.iter().map(|i| NodeIndex::new(*i)).collect()
+    }
+
+    fn unit<T>(_: T) -> Result<f64, Infallible> {
+        Ok(1.0)
+    }
+
+    #[test]
+    fn test_node_boundary_undirected() {
+        let graph = UnGraph::<(), ()>::from_edges([(0, 1), (1, 2), (2, 3), (3, 0), (1, 1)]);
+        assert_eq!(
+            node_boundary(&graph, nodes(&[0, 1]), None::<Vec<NodeIndex>>),
+            nodes(&[3, 2])
+        );
+        assert_eq!(
+            node_boundary(&graph, nodes(&[0, 1]), Some(nodes(&[1, 2]))),
+            nodes(&[2])
+        );
+        assert_eq!(
+            node_boundary(&graph, nodes(&[0, 1, 2, 3]), None::<Vec<NodeIndex>>),
+            vec![]
+        );
+    }
+
+    #[test]
+    fn test_node_boundary_directed() {
+        let graph = DiGraph::<(), ()>::from_edges([(0, 1), (2, 0), (1, 3)]);
+        assert_eq!(
+            node_boundary(&graph, nodes(&[0]), None::<Vec<NodeIndex>>),
+            nodes(&[1])
+        );
+    }
+
+    #[test]
+    fn test_edge_boundary() {
+        let graph = UnGraph::<(), ()>::from_edges([(0, 1), (1, 2), (2, 3), (3, 0), (0, 2), (0, 2)]);
+        let boundary: Vec<(usize, usize)> = edge_boundary(&graph, nodes(&[0]), Some(nodes(&[2])))
+            .into_iter()
+            .map(|e| (e.source().index(), e.target().index()))
+            .collect();
+        assert_eq!(boundary, vec![(0, 2), (0, 2)]);
+        let directed = DiGraph::<(), ()>::from_edges([(0, 1), (2, 0), (1, 3)]);
+        let boundary: Vec<(usize, usize)> =
+            edge_boundary(&directed, nodes(&[0, 1]), None::<Vec<NodeIndex>>)
+                .into_iter()
+                .map(|e| (e.source().index(), e.target().index()))
+                .collect();
+        assert_eq!(boundary, vec![(1, 3)]);
+    }
+
+    #[test]
+    fn test_cut_size() {
+        let graph = UnGraph::<(), ()>::from_edges([(0, 1), (1, 2), (2, 3), (3, 0), (0, 2)]);
+        assert_eq!(
+            cut_size(&graph, nodes(&[0, 1]), None::<Vec<NodeIndex>>, unit),
+            Ok(3.0)
+        );
+        assert_eq!(
+            cut_size(&graph, nodes(&[0]), Some(nodes(&[1, 3])), unit),
+            Ok(2.0)
+        );
+        let directed = DiGraph::<(), ()>::from_edges([(0, 1), (2, 0), (1, 3)]);
+        assert_eq!(
+            cut_size(&directed, nodes(&[0]), None::<Vec<NodeIndex>>, unit),
+            Ok(2.0)
+        );
+        assert_eq!(
+            cut_size(&directed, nodes(&[0]), Some(nodes(&[2])), unit),
+            Ok(1.0)
+        );
+    }
+
+    #[test]
+    fn test_volume() {
+        let graph = UnGraph::<(), f64>::from_edges([(0, 1, 1.0), (1, 2, 2.0), (1, 1, 0.5)]);
+        let weight = |e: petgraph::graph::EdgeReference<f64>| Ok::<f64, Infallible>(*e.weight());
+        assert_eq!(volume(&graph, nodes(&[1]), weight), Ok(4.0));
+        assert_eq!(volume(&graph, nodes(&[0, 1, 1]), weight), Ok(5.0));
+        let directed = DiGraph::<(), ()>::from_edges([(0, 1), (2, 0), (1, 3), (1, 1)]);
+        assert_eq!(volume(&directed, nodes(&[1]), unit), Ok(2.0));
+    }
+
+    #[test]
+    fn test_weight_fn_error() {
+        let graph = UnGraph::<(), ()>::from_edges([(0, 1)]);
+        assert_eq!(
+            cut_size(&graph, nodes(&[0]), None::<Vec<NodeIndex>>, |_| Err("bad")),
+            Err("bad")
+        );
+        assert_eq!(volume(&graph, nodes(&[0]), |_| Err("bad")), Err("bad"));
+    }
+}
@@ -0,0 +1,779 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+use hashbrown::HashMap;
+use petgraph::unionfind::UnionFind;
+use petgraph::visit::{EdgeRef, GraphProp, IntoEdgeReferences, IntoNodeIdentifiers, NodeIndexable};
+
+/// The node labels by position and the sorted edges of a graph relabeled
+/// by a discrete partition, which are compared to pick the canonical one.
+type Certificate = (Vec<usize>, Vec<(usize, usize, usize)>);
+
+/// A neighbor of a node as a (neighbor, edge label, direction) triple,
+/// where the direction is 1 for the incoming edges of a directed graph and
+/// 0 otherwise.
+type Neighbor = (usize, usize, u8);
+
+/// Replace every key by its rank among the distinct keys.
+fn rank<T: Ord>(keys: &[T]) -> (Vec<usize>, usize) {
+    let mut order: Vec<usize> = (0..keys.len()).collect();
+    order.sort_by(|a, b| keys[*a].cmp(&keys[*b]));
+    let mut ranks: Vec<usize> = vec![0; keys.len()];
+    let mut count = 0;
+    for (i, node) in order.iter().enumerate() {
+        if i > 0 && keys[order[i - 1]] != keys[*node] {
+            count += 1;
+        }
+        ranks[*node] = count;
+    }
+    (ranks, if keys.is_empty() { 0 } else { count + 1 })
+}
+
+/// A graph with compact node indices whose labels are replaced by their
+/// rank among the distinct labels.
+struct LabeledGraph {
+    directed: bool,
+    node_labels: Vec<usize>,
+    neighbors: Vec<Vec<Neighbor>>,
+    edges: Vec<(usize, usize, usize)>,
+}
+
+impl LabeledGraph {
+    fn node_count(&self) -> usize {
+        self.node_labels.len()
+    }
+
+    /// Refine the ordered partition `cells`, given as the position of the
+    /// cell of every node, until it is equitable. Cells are only split, and
+    /// the parts of a cell keep its place in the order.
+    fn refine(&self, cells: &mut Vec<usize>) {
+        let mut count = cells.iter().max().map_or(0, |max| max + 1);
+        loop {
+            let signatures: Vec<(usize, Vec<Neighbor>)> = (0..self.node_count())
+                .map(|node| {
+                    let mut neighbors: Vec<Neighbor> = self.neighbors[node]
+                        .iter()
+                        .map(|(neighbor, label, direction)| (cells[*neighbor], *label, *direction))
+                        .collect();
+                    neighbors.sort_unstable();
+                    (cells[node], neighbors)
+                })
+                .collect();
+            let (next, next_count) = rank(&signatures);
+            *cells = next;
+            if next_count == count {
+                return;
+            }
+            count = next_count;
+        }
+    }
+
+    /// Split `node` off the front of its cell and refine the partition.
+    fn individualize(&self, cells: &[usize], node: usize) -> Vec<usize> {
+        let keys: Vec<(usize, bool)> = (0..self.node_count())
+            .map(|other| (cells[other], other != node))
+            .collect();
+        let mut cells = rank(&keys).0;
+        self.refine(&mut cells);
+        cells
+    }
+
+    fn certificate(&self, cells: &[usize]) -> Certificate {
+        let mut labels: Vec<usize> = vec![0; self.node_count()];
+        for (node, label) in self.node_labels.iter().enumerate() {
+            labels[cells[node]] = *label;
+        }
+        let mut edges: Vec<(usize, usize, usize)> = self
+            .edges
+            .iter()
+            .map(|(source, target, label)| {
+                let (source, target) = (cells[*source], cells[*target]);
+                if self.directed || source <= target {
+                    (source, target, *label)
+                } else {
+                    (target, source, *label)
+                }
+            })
+            .collect();
+        edges.sort_unstable();
+        (labels, edges)
+    }
+
+    /// Check if swapping `first` and `second` is an automorphism.
+    fn is_twin(&self, first: usize, second: usize) -> bool {
+        if self.node_labels[first] != self.node_labels[second] {
+            return false;
+        }
+        let swap = |node: usize| match node {
+            node if node == first => second,
+            node if node == second => first,
+            node => node,
+        };
+        let mut swapped: Vec<Neighbor> = self.neighbors[first]
+            .iter()
+            .map(|(neighbor, label, direction)| (swap(*neighbor), *label, *direction))
+            .collect();
+        let mut neighbors = self.neighbors[second].clone();
+        swapped.sort_unstable();
+        neighbors.sort_unstable();
+        swapped == neighbors
+    }
+
+    /// Find transpositions of nodes with the same neighbors, which are
+    /// automorphisms that can be used to prune the search from the start.
+    fn twin_automorphisms(&self) -> Vec<Vec<usize>> {
+        let mut automorphisms = Vec::new();
+        for closed in [false, true] {
+            let mut groups: HashMap<Vec<usize>, Vec<usize>> = HashMap::new();
+            for node in 0..self.node_count() {
+                let mut key: Vec<usize> = self.neighbors[node].iter().map(|n| n.0).collect();
+                if closed {
+                    key.push(node);
+                }
+                key.sort_unstable();
+                key.dedup();
+                groups.entry(key).or_default().push(node);
+            }
+            let mut groups: Vec<Vec<usize>> = groups.into_values().collect();
+            groups.sort_unstable();
+            for group in groups {
+                for pair in group.windows(2) {
+                    if self.is_twin(pair[0], pair[1]) {
+                        let mut automorphism: Vec<usize> = (0..self.node_count()).collect();
+                        automorphism.swap(pair[0], pair[1]);
+                        automorphisms.push(automorphism);
+                    }
+                }
+            }
+        }
+        automorphisms
+    }
+}
+
+struct Leaf {
+    path: Vec<usize>,
+    cells: Vec<usize>,
+    certificate: Certificate,
+}
+
+/// A node of the search tree of individualizations.
+struct Frame {
+    cells: Vec<usize>,
+    candidates: Vec<usize>,
+    explored: Vec<usize>,
+    next: usize,
+}
+
+impl Frame {
+    fn new(cells: Vec<usize>) -> Self {
+        // The first cell with more than one node, in order
+        let mut sizes: Vec<usize> = vec![0; cells.len()];
+        for cell in &cells {
+            sizes[*cell] += 1;
+        }
+        let candidates: Vec<usize> = match sizes.iter().position(|size| *size > 1) {
+            Some(target) => (0..cells.len())
+                .filter(|node| cells[*node] == target)
+                .collect(),
+            None => Vec::new(),
+        };
+        Frame {
+            cells,
+            candidates,
+            explored: Vec::new(),
+            next: 0,
+        }
+    }
+}
+
+/// Find the discrete partition of the canonical labeling of a graph with
+/// individualization and refinement, pruned with the automorphisms found
+/// along the way.
+fn canonical_cells(graph: &LabeledGraph) -> Vec<usize> {
+    let mut cells = rank(&graph.node_labels).0;
+    graph.refine(&mut cells);
+    let mut automorphisms = graph.twin_automorphisms();
+    let mut first: Option<Leaf> = None;
+    let mut best: Option<Leaf> = None;
+    let mut path: Vec<usize> = Vec::new();
+    let mut stack: Vec<Frame> = vec![Frame::new(cells)];
+    if stack[0].candidates.is_empty() {
+        return stack.pop().unwrap().cells;
+    }
+
+    while let Some(frame) = stack.last_mut() {
+        // Skip the candidates in the orbit of an explored candidate under
+        // the automorphisms that fix the current path
+        let mut orbits: Option<UnionFind<usize>> = None;
+        let mut chosen: Option<usize> = None;
+        while frame.next < frame.candidates.len() {
+            let candidate = frame.candidates[frame.next];
+            frame.next += 1;
+            if !frame.explored.is_empty() {
+                let orbits = orbits.get_or_insert_with(|| {
+                    let mut orbits = UnionFind::new(graph.node_count());
+                    for automorphism in &automorphisms {
+                        if path.iter().all(|node| automorphism[*node] == *node) {
+                            for (node, image) in automorphism.iter().enumerate() {
+                                orbits.union(node, *image);
+                            }
+                        }
+                    }
+                    orbits
+                });
+                if frame
+                    .explored
+                    .iter()
+                    .any(|explored| orbits.equiv(*explored, candidate))
+                {
+                    continue;
+                }
+            }
+            chosen = Some(candidate);
+            break;
+        }
+        let candidate = match chosen {
+            Some(candidate) => candidate,
+            None => {
+                stack.pop();
+                path.pop();
+                continue;
+            }
+        };
+        frame.explored.push(candidate);
+        let cells = graph.individualize(&frame.cells, candidate);
+        path.push(candidate);
+        let child = Frame::new(cells);
+        if !child.candidates.is_empty() {
+            stack.push(child);
+            continue;
+        }
+
+        // A leaf of the search tree
+        let certificate = graph.certificate(&child.cells);
+        let mut equivalent: Option<&Leaf> = None;
+        match (&first, &best) {
+            (Some(first), _) if first.certificate == certificate => equivalent = Some(first),
+            (_, Some(best)) if best.certificate == certificate => equivalent = Some(best),
+            _ => (),
+        }
+        if let Some(leaf) = equivalent {
+            // Both leaves relabel the graph the same way, so mapping one
+            // labeling to the other is an automorphism and the subtree of
+            // this leaf below the point where the paths diverge is an image
+            // of the subtree of the other leaf
+            let mut inverse: Vec<usize> = vec![0; graph.node_count()];
+            for (node, position) in child.cells.iter().enumerate() {
+                inverse[*position] = node;
+            }
+            automorphisms.push(
+                leaf.cells
+                    .iter()
+                    .map(|position| inverse[*position])
+                    .collect(),
+            );
+            let common = leaf
+                .path
+                .iter()
+                .zip(&path)
+                .take_while(|(a, b)| a == b)
+                .count();
+            stack.truncate(common + 1);
+            path.truncate(common);
+            continue;
+        }
+        let leaf = Leaf {
+            path: path.clone(),
+            cells: child.cells,
+            certificate,
+        };
+        if first.is_none() {
+            first = Some(Leaf {
+                path: leaf.path.clone(),
+                cells: leaf.cells.clone(),
+                certificate: leaf.certificate.clone(),
+            });
+        }
+        if best
+            .as_ref()
+            .map_or(true, |best| leaf.certificate < best.certificate)
+        {
+            best = Some(leaf);
+        }
+        path.pop();
+    }
+    best.unwrap().cells
+}
+
+/// Build the labeled graph, returning the node ids in compact order and the
+/// distinct node and edge labels in sorted order.
+#[allow(clippy::type_complexity)]
+fn labeled_graph<G, F, H, L, M, E>(
+    graph: G,
+    mut node_label: F,
+    mut edge_label: H,
+) -> Result<(LabeledGraph, Vec<G::NodeId>, Vec<L>, Vec<M>), E>
+where
+    G: GraphProp + IntoEdgeReferences + IntoNodeIdentifiers + NodeIndexable,
+    F: FnMut(G::NodeId) -> Result<L, E>,
+    H: FnMut(G::EdgeRef) -> Result<M, E>,
+    L: Ord + Clone,
+    M: Ord + Clone,
+{
+    let nodes: Vec<G::NodeId> = graph.node_identifiers().collect();
+    let mut compact: Vec<usize> = vec![usize::MAX; graph.node_bound()];
+    for (index, node) in nodes.iter().enumerate() {
+        compact[graph.to_index(*node)] = index;
+    }
+    let node_labels: Vec<L> = nodes
+        .iter()
+        .map(|node| node_label(*node))
+        .collect::<Result<_, E>>()?;
+    let mut edges: Vec<(usize, usize)> = Vec::new();
+    let mut edge_labels: Vec<M> = Vec::new();
+    for edge in graph.edge_references() {
+        edges.push((
+            compact[graph.to_index(edge.source())],
+            compact[graph.to_index(edge.target())],
+        ));
+        edge_labels.push(edge_label(edge)?);
+    }
+
+    let (node_ranks, _) = rank(&node_labels);
+    let (edge_ranks, _) = rank(&edge_labels);
+    let mut neighbors: Vec<Vec<Neighbor>> = vec![Vec::new(); nodes.len()];
+    let directed = graph.is_directed();
+    for ((source, target), label) in edges.iter().zip(&edge_ranks) {
+        neighbors[*source].push((*target, *label, 0));
+        neighbors[*target].push((*source, *label, u8::from(directed)));
+    }
+    let mut distinct_nodes = node_labels;
+    distinct_nodes.sort();
+    distinct_nodes.dedup();
+    let mut distinct_edges = edge_labels;
+    distinct_edges.sort();
+    distinct_edges.dedup();
+    Ok((
+        LabeledGraph {
+            directed,
+            node_labels: node_ranks,
+            neighbors,
+            edges: edges
+                .into_iter()
+                .zip(edge_ranks)
+                .map(|((source, target), label)| (source, target, label))
+                .collect(),
+        },
+        nodes,
+        distinct_nodes,
+        distinct_edges,
+    ))
+}
+
+/// Compute a canonical labeling of a graph.
+///
+/// A canonical labeling orders the nodes of a graph such that two graphs
+/// are isomorphic, with an isomorphism that preserves the node and edge
+/// labels, if and only if relabeling them by their canonical orders gives
+/// the same graph. It's computed with color refinement, the one-dimensional
+/// Weisfeiler-Lehman algorithm, and a search over the ways of breaking the
+/// ties it leaves, as in McKay's algorithm [1]. The search is pruned with
+/// the automorphisms of the graph it finds, which includes swapping nodes
+/// with the same neighbors, so it's fast for most graphs but can take
+/// exponential time for some highly symmetric ones.
+///
+/// Arguments:
+///
+/// * `graph` - The graph to compute the canonical labeling of.
+/// * `node_label` - A callable that will be passed the id of each node and
+///   is expected to return a `Result` with the label of the node.
+/// * `edge_label` - A callable that will be passed an edge reference for
+///   each edge and is expected to return a `Result` with the label of the
+///   edge. Parallel edges are kept as they are.
+///
+/// Returns the node ids in canonical order.
+///
+/// # Example
+/// ```rust
+/// use std::convert::Infallible;
+/// use rustworkx_core::canonical::canonical_labeling;
+/// use rustworkx_core::petgraph::graph::UnGraph;
+///
+/// // The center of a star is always first or always last
+/// let star = UnGraph::<(), ()>::from_edges([(0, 1), (0, 2), (0, 3)]);
+/// let other = UnGraph::<(), ()>::from_edges([(0, 3), (1, 3), (2, 3)]);
+/// let res: Result<_, Infallible> = canonical_labeling(&star, |_| Ok(()), |_| Ok(()));
+/// let star_order = res.unwrap();
+/// let res: Result<_, Infallible> = canonical_labeling(&other, |_| Ok(()), |_| Ok(()));
+/// let other_order = res.unwrap();
+/// let position = |order: &[_], node| order.iter().position(|n| *n == node).unwrap();
+/// assert_eq!(position(&star_order, 0.into()), position(&other_order, 3.into()));
+/// ```
+///
+/// [1]: McKay, Brendan D., and Adolfo Piperno. "Practical graph isomorphism,
+///   II." Journal of Symbolic Computation 60 (2014): 94-112.
+///   <https://doi.org/10.1016/j.jsc.2013.09.003>
+pub fn canonical_labeling<G, F, H, L, M, E>(
+    graph: G,
+    node_label: F,
+    edge_label: H,
+) -> Result<Vec<G::NodeId>, E>
+where
+    G: GraphProp + IntoEdgeReferences + IntoNodeIdentifiers + NodeIndexable,
+    F: FnMut(G::NodeId) -> Result<L, E>,
+    H: FnMut(G::EdgeRef) -> Result<M, E>,
+    L: Ord + Clone,
+    M: Ord + Clone,
+{
+    let (labeled, nodes, _, _) = labeled_graph(graph, node_label, edge_label)?;
+    let cells = canonical_cells(&labeled);
+    let mut order: Vec<G::NodeId> = nodes.clone();
+    for (index, node) in nodes.into_iter().enumerate() {
+        order[cells[index]] = node;
+    }
+    Ok(order)
+}
+
+/// The 128-bit FNV-1a hash function.
+struct Fnv128(u128);
+
+impl Fnv128 {
+    const OFFSET: u128 = 0x6c62272e07bb014262b821756295c58d;
+    const PRIME: u128 = 0x0000000001000000000000000000013b;
+
+    fn new() -> Self {
+        Fnv128(Self::OFFSET)
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.0 ^= *byte as u128;
+            self.0 = self.0.wrapping_mul(Self::PRIME);
+        }
+    }
+
+    fn write_usize(&mut self, value: usize) {
+        self.write(&(value as u64).to_le_bytes());
+    }
+
+    /// Write a byte string prefixed with its length, so that concatenated
+    /// strings can't be confused.
+    fn write_bytes(&mut self, bytes: &[u8]) {
+        self.write_usize(bytes.len());
+        self.write(bytes);
+    }
+}
+
+/// Compute a hash of a graph that doesn't depend on its node indices.
+///
+/// The graph is relabeled by its [`canonical_labeling`] and the result is
+/// hashed with the 128-bit FNV-1a hash function, so isomorphic graphs, with
+/// an isomorphism that preserves the node and edge labels, always have the
+/// same hash, and graphs that aren't isomorphic only have the same hash in
+/// the unlikely case of a hash collision. The hash doesn't depend on the
+/// order the nodes and edges were added in, on the platform or on the run,
+/// so it can be used as a key to cache results computed for the graph.
+///
+/// Arguments:
+///
+/// * `graph` - The graph to hash.
+/// * `node_label` - A callable that will be passed the id of each node and
+///   is expected to return a `Result` with the label of the node as bytes.
+/// * `edge_label` - A callable that will be passed an edge reference for
+///   each edge and is expected to return a `Result` with the label of the
+///   edge as bytes.
+///
+/// # Example
+/// ```rust
+/// use std::convert::Infallible;
+/// use rustworkx_core::canonical::canonical_hash;
+/// use rustworkx_core::petgraph::graph::UnGraph;
+///
+/// let path = UnGraph::<&str, ()>::from_edges([(0, 1), (1, 2)]);
+/// let reversed = UnGraph::<&str, ()>::from_edges([(2, 1), (1, 0)]);
+/// let triangle = UnGraph::<&str, ()>::from_edges([(0, 1), (1, 2), (2, 0)]);
+/// let hash = |graph: &UnGraph<&str, ()>| {
+///     let res: Result<u128, Infallible> = canonical_hash(graph, |_| Ok(b""), |_| Ok(b""));
+///     res.unwrap()
+/// };
+/// assert_eq!(hash(&path), hash(&reversed));
+/// assert_ne!(hash(&path), hash(&triangle));
+/// ```
+pub fn canonical_hash<G, F, H, L, M, E>(graph: G, node_label: F, edge_label: H) -> Result<u128, E>
+where
+    G: GraphProp + IntoEdgeReferences + IntoNodeIdentifiers + NodeIndexable,
+    F: FnMut(G::NodeId) -> Result<L, E>,
+    H: FnMut(G::EdgeRef) -> Result<M, E>,
+    L: AsRef<[u8]>,
+    M: AsRef<[u8]>,
+{
+    let mut node_label = node_label;
+    let mut edge_label = edge_label;
+    let (labeled, _, node_labels, edge_labels) = labeled_graph(
+        graph,
+        |node| node_label(node).map(|label| label.as_ref().to_vec()),
+        |edge| edge_label(edge).map(|label| label.as_ref().to_vec()),
+    )?;
+    let cells = canonical_cells(&labeled);
+    let (labels, edges) = labeled.certificate(&cells);
+
+    let mut hasher = Fnv128::new();
+    hasher.write(&[u8::from(labeled.directed)]);
+    hasher.write_usize(labels.len());
+    for label in labels {
+        hasher.write_bytes(&node_labels[label]);
+    }
+    hasher.write_usize(edges.len());
+    for (source, target, label) in edges {
+        hasher.write_usize(source);
+        hasher.write_usize(target);
+        hasher.write_bytes(&edge_labels[label]);
+    }
+    Ok(hasher.0)
+}
+
+#[cfg(test)]
+mod test_canonical {
+    use super::{canonical_hash, canonical_labeling};
+    use crate::generators::{
+        complete_graph, gnp_random_graph, grid_graph, hexagonal_lattice_graph, petersen_graph,
+        star_graph,
+    };
+    use crate::petgraph::graph::{DiGraph, NodeIndex, UnGraph};
+    use crate::petgraph::visit::EdgeRef;
+    use crate::rng::rng_from_seed;
+    use rand::prelude::*;
+    use std::convert::Infallible;
+
+    fn hash<N: AsRef<[u8]>, E: AsRef<[u8]>>(graph: &UnGraph<N, E>) -> u128 {
+        let res: Result<u128, Infallible> =
+            canonical_hash(graph, |n| Ok(&graph[n]), |e| Ok(e.weight()));
+        res.unwrap()
+    }
+
+    fn unlabeled<E>(graph: &UnGraph<(), E>) -> UnGraph<String, String> {
+        graph.map(|_, _| String::new(), |_, _| String::new())
+    }
+
+    /// Relabel the nodes of a graph randomly and shuffle its edges.
+    fn shuffled<N: Clone, E: Clone>(graph: &UnGraph<N, E>, seed: u64) -> UnGraph<N, E> {
+        let mut rng = rng_from_seed(Some(seed));
+        let mut permutation: Vec<usize> = (0..graph.node_count()).collect();
+        permutation.shuffle(&mut rng);
+        let mut inverse = vec![0; permutation.len()];
+        for (node, image) in permutation.iter().enumerate() {
+            inverse[*image] = node;
+        }
+        let mut out = UnGraph::with_capacity(graph.node_count(), graph.edge_count());
+        for image in 0..graph.node_count() {
+            out.add_node(graph[NodeIndex::new(inverse[image])].clone());
+        }
+        let mut edges: Vec<_> = graph.edge_references().collect();
+        edges.shuffle(&mut rng);
+        for edge in edges {
+            let source = permutation[edge.source().index()];
+            let target = permutation[edge.target().index()];
+            let (source, target) = if rng.gen() {
+                (source, target)
+            } else {
+                (target, source)
+            };
+            out.add_edge(
+                NodeIndex::new(source),
+                NodeIndex::new(target),
+                edge.weight().clone(),
+            );
+        }
+        out
+    }
+
+    #[test]
+    fn test_invariant_under_relabeling() {
+        let graphs: Vec<UnGraph<(), ()>> = vec![
+            petersen_graph(5, 2, || (), || ()).unwrap(),
+            grid_graph(Some(4), Some(5), None, || (), || (), false).unwrap(),
+            hexagonal_lattice_graph(3, 3, || (), || (), false, false).unwrap(),
+            complete_graph(Some(7), None, || (), || ()).unwrap(),
+            star_graph(Some(9), None, || (), || (), false, false).unwrap(),
+        ];
+        for graph in graphs {
+            let graph = unlabeled(&graph);
+            let expected = hash(&graph);
+            for seed in 0..5 {
+                assert_eq!(hash(&shuffled(&graph, seed)), expected);
+            }
+        }
+    }
+
+    #[test]
+    fn test_regular_graphs_differ() {
+        // Both graphs are 3-regular with 6 nodes, so color refinement alone
+        // can't tell them apart
+        let prism = UnGraph::<(), ()>::from_edges([
+            (0, 1),
+            (1, 2),
+            (2, 0),
+            (3, 4),
+            (4, 5),
+            (5, 3),
+            (0, 3),
+            (1, 4),
+            (2, 5),
+        ]);
+        let bipartite = UnGraph::<(), ()>::from_edges([
+            (0, 3),
+            (0, 4),
+            (0, 5),
+            (1, 3),
+            (1, 4),
+            (1, 5),
+            (2, 3),
+            (2, 4),
+            (2, 5),
+        ]);
+        let prism = unlabeled(&prism);
+        let bipartite = unlabeled(&bipartite);
+        assert_ne!(hash(&prism), hash(&bipartite));
+        assert_eq!(hash(&prism), hash(&shuffled(&prism, 3)));
+        assert_eq!(hash(&bipartite), hash(&shuffled(&bipartite, 3)));
+    }
+
+    #[test]
+    fn test_labels() {
+        let mut first: UnGraph<String, String> = UnGraph::default();
+        let a = first.add_node("a".to_string());
+        let b = first.add_node("b".to_string());
+        let c = first.add_node("a".to_string());
+        first.add_edge(a, b, "x".to_string());
+        first.add_edge(b, c, "y".to_string());
+        let mut second = first.clone();
+        *second.edge_weight_mut(0.into()).unwrap() = "y".to_string();
+        *second.edge_weight_mut(1.into()).unwrap() = "x".to_string();
+        // Swapping the edge labels is undone by swapping the two "a" nodes
+        assert_eq!(hash(&first), hash(&second));
+        second[a] = "c".to_string();
+        assert_ne!(hash(&first), hash(&second));
+        let mut third = first.clone();
+        *third.edge_weight_mut(0.into()).unwrap() = "y".to_string();
+        assert_ne!(hash(&first), hash(&third));
+        // Labels are length-prefixed so they can't run into each other
+        let mut fourth = first.clone();
+        fourth[a] = "ab".to_string();
+        fourth[b] = String::new();
+        assert_ne!(hash(&first), hash(&fourth));
+    }
+
+    #[test]
+    fn test_directed() {
+        let forward = DiGraph::<(), ()>::from_edges([(0, 1), (1, 2)]);
+        let backward = DiGraph::<(), ()>::from_edges([(2, 1), (1, 0)]);
+        let inward = DiGraph::<(), ()>::from_edges([(0, 1), (2, 1)]);
+        let hash = |graph: &DiGraph<(), ()>| {
+            let res: Result<u128, Infallible> = canonical_hash(graph, |_| Ok(b""), |_| Ok(b""));
+            res.unwrap()
+        };
+        assert_eq!(hash(&forward), hash(&backward));
+        assert_ne!(hash(&forward), hash(&inward));
+        let undirected = UnGraph::<String, String>::from_edges([(0, 1), (1, 2)]);
+        assert_ne!(hash(&forward), super::test_canonical::hash(&undirected));
+    }
+
+    #[test]
+    fn test_parallel_edges_and_self_loops() {
+        let single = unlabeled(&UnGraph::<(), ()>::from_edges([(0, 1), (1, 2)]));
+        let parallel = unlabeled(&UnGraph::<(), ()>::from_edges([(0, 1), (1, 0), (1, 2)]));
+        let looped = unlabeled(&UnGraph::<(), ()>::from_edges([(0, 1), (1, 2), (2, 2)]));
+        let other_loop = unlabeled(&UnGraph::<(), ()>::from_edges([(0, 1), (1, 2), (1, 1)]));
+        let hashes = [
+            hash(&single),
+            hash(&parallel),
+            hash(&looped),
+            hash(&other_loop),
+        ];
+        for i in 0..hashes.len() {
+            for j in 0..i {
+                assert_ne!(hashes[i], hashes[j]);
+            }
+        }
+    }
+
+    #[test]
+    fn test_canonical_labeling_is_isomorphism() {
+        let graph = unlabeled(&petersen_graph(5, 2, || (), || ()).unwrap());
+        let other = shuffled(&graph, 11);
+        let res: Result<_, Infallible> = canonical_labeling(&graph, |_| Ok(()), |_| Ok(()));
+        let first = res.unwrap();
+        let res: Result<_, Infallible> = canonical_labeling(&other, |_| Ok(()), |_| Ok(()));
+        let second = res.unwrap();
+        for (i, a) in first.iter().enumerate() {
+            for (j, b) in first.iter().enumerate() {
+                assert_eq!(
+                    graph.contains_edge(*a, *b),
+                    other.contains_edge(second[i], second[j])
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_random_labeled_graphs() {
+        let mut hashes = Vec::new();
+        for seed in 0..10 {
+            let graph: UnGraph<(), ()> =
+                gnp_random_graph(30, 0.15, Some(seed), || (), || ()).unwrap();
+            let graph = graph.map(
+                |node, _| (node.index() % 3).to_string(),
+                |edge, _| (edge.index() % 2).to_string(),
+            );
+            let expected = hash(&graph);
+            for shuffle_seed in 0..3 {
+                assert_eq!(hash(&shuffled(&graph, shuffle_seed)), expected);
+            }
+            hashes.push(expected);
+        }
+        hashes.sort_unstable();
+        hashes.dedup();
+        assert_eq!(hashes.len(), 10);
+    }
+
+    #[test]
+    fn test_symmetric_graphs() {
+        // Twin nodes and automorphisms keep the search small
+        let complete = unlabeled(&complete_graph(Some(60), None, || (), || ()).unwrap());
+        assert_eq!(hash(&complete), hash(&shuffled(&complete, 1)));
+        let mut hypercube = UnGraph::<String, String>::default();
+        for _ in 0..64 {
+            hypercube.add_node(String::new());
+        }
+        for node in 0..64_usize {
+            for bit in 0..6 {
+                let other = node ^ (1 << bit);
+                if node < other {
+                    hypercube.add_edge(NodeIndex::new(node), NodeIndex::new(other), String::new());
+                }
+            }
+        }
+        assert_eq!(hash(&hypercube), hash(&shuffled(&hypercube, 2)));
+    }
+
+    #[test]
+    fn test_empty_and_error() {
+        assert_eq!(
+            hash(&UnGraph::<String, String>::default()),
+            hash(&UnGraph::<String, String>::default())
+        );
+        let graph = UnGraph::<(), ()>::from_edges([(0, 1)]);
+        let res: Result<u128, &str> =
+            canonical_hash(&graph, |_| Err::<&[u8], _>("bad label"), |_| Ok(b""));
+        assert_eq!(res, Err("bad label"));
+    }
+}
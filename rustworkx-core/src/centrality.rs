@@ -10,10 +10,15 @@
 // License for the specific language governing permissions and limitations
 // under the License.
 
-use std::collections::VecDeque;
+use std::collections::{BinaryHeap, VecDeque};
+use std::convert::Infallible;
 use std::hash::Hash;
 use std::sync::RwLock;
 
+use crate::dictmap::DictMap;
+use crate::min_scored::MinScored;
+use crate::par::CondIterator;
+use crate::shortest_path;
 use hashbrown::HashMap;
 use petgraph::algo::dijkstra;
 use petgraph::visit::{
@@ -22,6 +27,7 @@ use petgraph::visit::{
     EdgeRef,
     GraphBase,
     GraphProp, // allows is_directed
+    IntoEdgeReferences,
     IntoEdges,
     IntoEdgesDirected,
     IntoNeighbors,
@@ -32,7 +38,7 @@ use petgraph::visit::{
     Reversed,
     Visitable,
 };
-use rayon_cond::CondIterator;
+use petgraph::Direction;
 
 /// Compute the betweenness centrality of all nodes in a graph.
 ///
@@ -96,6 +102,86 @@ where
     // + IntoNeighborsDirected // for neighbors()
     // + NodeCount // for node_count
     // + GraphProp // for is_directed
+{
+    let res: Result<Vec<Option<f64>>, Infallible> = betweenness_centrality_with_progress(
+        graph,
+        include_endpoints,
+        normalized,
+        parallel_threshold,
+        usize::MAX,
+        |_, _| Ok(()),
+    );
+    res.unwrap()
+}
+
+/// Compute the betweenness centrality of all nodes in a graph, reporting the
+/// progress of the computation.
+///
+/// This is [`betweenness_centrality`] with a `progress` callback, which is
+/// called on the calling thread as `progress(done, total)` after every
+/// `progress_interval` of the `total` source nodes are searched from. If it
+/// returns an error the computation is stopped and the error is returned,
+/// so it can also be used to cancel a long computation.
+///
+/// Arguments:
+///
+/// * `graph` - The graph object to run the algorithm on
+/// * `include_endpoints` - Whether to include the endpoints of paths in the path
+///   lengths used to compute the betweenness
+/// * `normalized` - Whether to normalize the betweenness scores by the number
+///   of distinct paths between all pairs of nodes
+/// * `parallel_threshold` - The number of nodes to calculate the betweenness
+///   centrality in parallel at, if the number of nodes in `graph` is less
+///   than this value it will run in a single thread
+/// * `progress_interval` - The number of source nodes between the calls to
+///   `progress`
+/// * `progress` - The callback which is passed the number of source nodes
+///   done and the total number of source nodes
+///
+/// # Example
+/// ```rust
+/// use rustworkx_core::petgraph;
+/// use rustworkx_core::centrality::betweenness_centrality_with_progress;
+///
+/// let g = petgraph::graph::UnGraph::<i32, ()>::from_edges(&[
+///   (0, 4), (1, 2), (2, 3), (3, 4), (1, 4)
+/// ]);
+/// let mut reports = Vec::new();
+/// let output: Result<_, ()> = betweenness_centrality_with_progress(
+///   &g, true, true, 200, 2, |done, total| {
+///       reports.push((done, total));
+///       Ok(())
+///   },
+/// );
+/// assert_eq!(
+///   vec![Some(0.4), Some(0.5), Some(0.45), Some(0.5), Some(0.75)],
+///   output.unwrap()
+/// );
+/// assert_eq!(reports, vec![(2, 5), (4, 5), (5, 5)]);
+/// ```
+pub fn betweenness_centrality_with_progress<G, F, E>(
+    graph: G,
+    include_endpoints: bool,
+    normalized: bool,
+    parallel_threshold: usize,
+    progress_interval: usize,
+    mut progress: F,
+) -> Result<Vec<Option<f64>>, E>
+where
+    G: NodeIndexable
+        + IntoNodeIdentifiers
+        + IntoNeighborsDirected
+        + NodeCount
+        + GraphProp
+        + GraphBase
+        + std::marker::Sync,
+    <G as GraphBase>::NodeId: std::cmp::Eq + Hash + Send,
+    // rustfmt deletes the following comments if placed inline above
+    // + IntoNodeIdentifiers // for node_identifiers()
+    // + IntoNeighborsDirected // for neighbors()
+    // + NodeCount // for node_count
+    // + GraphProp // for is_directed
+    F: FnMut(usize, usize) -> Result<(), E>,
 {
     // Correspondence of variable names to quantities in the paper is as follows:
     //
@@ -116,18 +202,24 @@ where
     let locked_betweenness = RwLock::new(&mut betweenness);
     let node_indices: Vec<G::NodeId> = graph.node_identifiers().collect();
 
-    CondIterator::new(node_indices, graph.node_count() >= parallel_threshold)
-        .map(|node_s| (shortest_path_for_centrality(&graph, &node_s), node_s))
-        .for_each(|(mut shortest_path_calc, node_s)| {
-            _accumulate_vertices(
-                &locked_betweenness,
-                max_index,
-                &mut shortest_path_calc,
-                node_s,
-                &graph,
-                include_endpoints,
-            );
-        });
+    let parallel = graph.node_count() >= parallel_threshold;
+    let mut done = 0;
+    for chunk in node_indices.chunks(progress_interval.max(1)) {
+        CondIterator::new(chunk.to_vec(), parallel)
+            .map(|node_s| (shortest_path_for_centrality(&graph, &node_s), node_s))
+            .for_each(|(mut shortest_path_calc, node_s)| {
+                _accumulate_vertices(
+                    &locked_betweenness,
+                    max_index,
+                    &mut shortest_path_calc,
+                    node_s,
+                    &graph,
+                    include_endpoints,
+                );
+            });
+        done += chunk.len();
+        progress(done, node_indices.len())?;
+    }
 
     _rescale(
         &mut betweenness,
@@ -137,7 +229,7 @@ where
         include_endpoints,
     );
 
-    betweenness
+    Ok(betweenness)
 }
 
 /// Compute the edge betweenness centrality of all edges in a graph.
@@ -197,6 +289,74 @@ where
         + Sync,
     G::NodeId: Eq + Hash + Send,
     G::EdgeId: Eq + Hash + Send,
+{
+    let res: Result<Vec<Option<f64>>, Infallible> = edge_betweenness_centrality_with_progress(
+        graph,
+        normalized,
+        parallel_threshold,
+        usize::MAX,
+        |_, _| Ok(()),
+    );
+    res.unwrap()
+}
+
+/// Compute the edge betweenness centrality of all edges in a graph,
+/// reporting the progress of the computation.
+///
+/// This is [`edge_betweenness_centrality`] with a `progress` callback, which
+/// is called on the calling thread as `progress(done, total)` after every
+/// `progress_interval` of the `total` source nodes are searched from. If it
+/// returns an error the computation is stopped and the error is returned.
+///
+/// Arguments:
+///
+/// * `graph` - The graph object to run the algorithm on
+/// * `normalized` - Whether to normalize the betweenness scores by the number
+///   of distinct paths between all pairs of nodes
+/// * `parallel_threshold` - The number of nodes to calculate the betweenness
+///   centrality in parallel at, if the number of nodes in `graph` is less
+///   than this value it will run in a single thread
+/// * `progress_interval` - The number of source nodes between the calls to
+///   `progress`
+/// * `progress` - The callback which is passed the number of source nodes
+///   done and the total number of source nodes
+///
+/// # Example
+/// ```rust
+/// use rustworkx_core::petgraph;
+/// use rustworkx_core::centrality::edge_betweenness_centrality_with_progress;
+///
+/// let g = petgraph::graph::UnGraph::<i32, ()>::from_edges(&[(0, 1), (1, 2), (2, 3)]);
+/// // Stop after the first source node
+/// let output = edge_betweenness_centrality_with_progress(&g, false, 200, 1, |done, _| {
+///   if done >= 1 {
+///       Err("cancelled")
+///   } else {
+///       Ok(())
+///   }
+/// });
+/// assert_eq!(output, Err("cancelled"));
+/// ```
+pub fn edge_betweenness_centrality_with_progress<G, F, E>(
+    graph: G,
+    normalized: bool,
+    parallel_threshold: usize,
+    progress_interval: usize,
+    mut progress: F,
+) -> Result<Vec<Option<f64>>, E>
+where
+    G: NodeIndexable
+        + EdgeIndexable
+        + IntoEdges
+        + IntoNodeIdentifiers
+        + IntoNeighborsDirected
+        + NodeCount
+        + EdgeCount
+        + GraphProp
+        + Sync,
+    G::NodeId: Eq + Hash + Send,
+    G::EdgeId: Eq + Hash + Send,
+    F: FnMut(usize, usize) -> Result<(), E>,
 {
     let max_index = graph.node_bound();
     let mut betweenness = vec![None; graph.edge_bound()];
@@ -206,16 +366,22 @@ where
     }
     let locked_betweenness = RwLock::new(&mut betweenness);
     let node_indices: Vec<G::NodeId> = graph.node_identifiers().collect();
-    CondIterator::new(node_indices, graph.node_count() >= parallel_threshold)
-        .map(|node_s| shortest_path_for_edge_centrality(&graph, &node_s))
-        .for_each(|mut shortest_path_calc| {
-            accumulate_edges(
-                &locked_betweenness,
-                max_index,
-                &mut shortest_path_calc,
-                &graph,
-            );
-        });
+    let parallel = graph.node_count() >= parallel_threshold;
+    let mut done = 0;
+    for chunk in node_indices.chunks(progress_interval.max(1)) {
+        CondIterator::new(chunk.to_vec(), parallel)
+            .map(|node_s| shortest_path_for_edge_centrality(&graph, &node_s))
+            .for_each(|mut shortest_path_calc| {
+                accumulate_edges(
+                    &locked_betweenness,
+                    max_index,
+                    &mut shortest_path_calc,
+                    &graph,
+                );
+            });
+        done += chunk.len();
+        progress(done, node_indices.len())?;
+    }
 
     _rescale(
         &mut betweenness,
@@ -224,7 +390,322 @@ where
         graph.is_directed(),
         true,
     );
-    betweenness
+    Ok(betweenness)
+}
+
+/// Compute the weighted betweenness centrality of all nodes in a graph.
+///
+/// This is [`betweenness_centrality`] where the length of a path is the sum
+/// of the weights of its edges instead of its number of edges, so the
+/// shortest paths are found with Dijkstra's algorithm. The weight of every
+/// edge is computed once with `weight_fn` before the shortest paths from the
+/// different nodes are searched for, possibly in parallel.
+///
+/// Arguments:
+///
+/// * `graph` - The graph object to run the algorithm on
+/// * `include_endpoints` - Whether to include the endpoints of paths in the path
+///   lengths used to compute the betweenness
+/// * `normalized` - Whether to normalize the betweenness scores by the number
+///   of distinct paths between all pairs of nodes
+/// * `parallel_threshold` - The number of nodes to calculate the betweenness
+///   centrality in parallel at, if the number of nodes in `graph` is less
+///   than this value it will run in a single thread
+/// * `weight_fn` - A callable that is passed the `EdgeRef` of every edge and
+///   returns its weight, which must be non-negative
+///
+/// # Example
+/// ```rust
+/// use rustworkx_core::petgraph;
+/// use rustworkx_core::centrality::weighted_betweenness_centrality;
+///
+/// // The path through node 1 is shorter than the edge from 0 to 2
+/// let g = petgraph::graph::UnGraph::<(), f64>::from_edges([
+///     (0, 1, 1.0), (1, 2, 1.0), (0, 2, 3.0)
+/// ]);
+/// let output: Result<_, ()> =
+///     weighted_betweenness_centrality(&g, false, false, 200, |e| Ok(*e.weight()));
+/// assert_eq!(vec![Some(0.0), Some(1.0), Some(0.0)], output.unwrap());
+/// ```
+pub fn weighted_betweenness_centrality<G, F, E>(
+    graph: G,
+    include_endpoints: bool,
+    normalized: bool,
+    parallel_threshold: usize,
+    weight_fn: F,
+) -> Result<Vec<Option<f64>>, E>
+where
+    G: NodeIndexable
+        + EdgeIndexable
+        + IntoEdges
+        + IntoNodeIdentifiers
+        + IntoNeighborsDirected
+        + NodeCount
+        + GraphProp
+        + Sync,
+    G::NodeId: Eq + Hash + Send,
+    G::EdgeId: Eq + Hash + Send,
+    F: FnMut(G::EdgeRef) -> Result<f64, E>,
+{
+    weighted_betweenness_centrality_with_progress(
+        graph,
+        include_endpoints,
+        normalized,
+        parallel_threshold,
+        weight_fn,
+        usize::MAX,
+        |_, _| Ok(()),
+    )
+}
+
+/// Compute the weighted betweenness centrality of all nodes in a graph,
+/// reporting the progress of the computation.
+///
+/// This is [`weighted_betweenness_centrality`] with a `progress` callback,
+/// which is called like for [`betweenness_centrality_with_progress`].
+///
+/// Arguments:
+///
+/// * `graph` - The graph object to run the algorithm on
+/// * `include_endpoints` - Whether to include the endpoints of paths in the path
+///   lengths used to compute the betweenness
+/// * `normalized` - Whether to normalize the betweenness scores by the number
+///   of distinct paths between all pairs of nodes
+/// * `parallel_threshold` - The number of nodes to calculate the betweenness
+///   centrality in parallel at, if the number of nodes in `graph` is less
+///   than this value it will run in a single thread
+/// * `weight_fn` - A callable that is passed the `EdgeRef` of every edge and
+///   returns its weight, which must be non-negative
+/// * `progress_interval` - The number of source nodes between the calls to
+///   `progress`
+/// * `progress` - The callback which is passed the number of source nodes
+///   done and the total number of source nodes
+pub fn weighted_betweenness_centrality_with_progress<G, F, P, E>(
+    graph: G,
+    include_endpoints: bool,
+    normalized: bool,
+    parallel_threshold: usize,
+    weight_fn: F,
+    progress_interval: usize,
+    mut progress: P,
+) -> Result<Vec<Option<f64>>, E>
+where
+    G: NodeIndexable
+        + EdgeIndexable
+        + IntoEdges
+        + IntoNodeIdentifiers
+        + IntoNeighborsDirected
+        + NodeCount
+        + GraphProp
+        + Sync,
+    G::NodeId: Eq + Hash + Send,
+    G::EdgeId: Eq + Hash + Send,
+    F: FnMut(G::EdgeRef) -> Result<f64, E>,
+    P: FnMut(usize, usize) -> Result<(), E>,
+{
+    let weights = edge_weights(graph, weight_fn)?;
+    let max_index = graph.node_bound();
+
+    let mut betweenness: Vec<Option<f64>> = vec![None; max_index];
+    for node_s in graph.node_identifiers() {
+        let is: usize = NodeIndexable::to_index(&graph, node_s);
+        betweenness[is] = Some(0.0);
+    }
+    let locked_betweenness = RwLock::new(&mut betweenness);
+    let node_indices: Vec<G::NodeId> = graph.node_identifiers().collect();
+
+    let parallel = graph.node_count() >= parallel_threshold;
+    let mut done = 0;
+    for chunk in node_indices.chunks(progress_interval.max(1)) {
+        CondIterator::new(chunk.to_vec(), parallel)
+            .map(|node_s| {
+                let path_calc = weighted_shortest_path_for_centrality(&graph, &node_s, &weights);
+                let shortest_path_calc = ShortestPathData::<&G> {
+                    verts_sorted_by_distance: path_calc.verts_sorted_by_distance,
+                    predecessors: path_calc.predecessors,
+                    sigma: path_calc.sigma,
+                };
+                (shortest_path_calc, node_s)
+            })
+            .for_each(|(mut shortest_path_calc, node_s)| {
+                _accumulate_vertices(
+                    &locked_betweenness,
+                    max_index,
+                    &mut shortest_path_calc,
+                    node_s,
+                    &graph,
+                    include_endpoints,
+                );
+            });
+        done += chunk.len();
+        progress(done, node_indices.len())?;
+    }
+
+    _rescale(
+        &mut betweenness,
+        graph.node_count(),
+        normalized,
+        graph.is_directed(),
+        include_endpoints,
+    );
+
+    Ok(betweenness)
+}
+
+/// Compute the weighted edge betweenness centrality of all edges in a graph.
+///
+/// This is [`edge_betweenness_centrality`] where the length of a path is the
+/// sum of the weights of its edges instead of its number of edges, so the
+/// shortest paths are found with Dijkstra's algorithm. The weight of every
+/// edge is computed once with `weight_fn` before the shortest paths from the
+/// different nodes are searched for, possibly in parallel.
+///
+/// Arguments:
+///
+/// * `graph` - The graph object to run the algorithm on
+/// * `normalized` - Whether to normalize the betweenness scores by the number
+///   of distinct paths between all pairs of nodes
+/// * `parallel_threshold` - The number of nodes to calculate the betweenness
+///   centrality in parallel at, if the number of nodes in `graph` is less
+///   than this value it will run in a single thread
+/// * `weight_fn` - A callable that is passed the `EdgeRef` of every edge and
+///   returns its weight, which must be non-negative
+///
+/// # Example
+/// ```rust
+/// use rustworkx_core::petgraph;
+/// use rustworkx_core::centrality::weighted_edge_betweenness_centrality;
+///
+/// // The path through node 1 is shorter than the edge from 0 to 2
+/// let g = petgraph::graph::UnGraph::<(), f64>::from_edges([
+///     (0, 1, 1.0), (1, 2, 1.0), (0, 2, 3.0)
+/// ]);
+/// let output: Result<_, ()> =
+///     weighted_edge_betweenness_centrality(&g, false, 200, |e| Ok(*e.weight()));
+/// assert_eq!(vec![Some(2.0), Some(2.0), Some(0.0)], output.unwrap());
+/// ```
+pub fn weighted_edge_betweenness_centrality<G, F, E>(
+    graph: G,
+    normalized: bool,
+    parallel_threshold: usize,
+    weight_fn: F,
+) -> Result<Vec<Option<f64>>, E>
+where
+    G: NodeIndexable
+        + EdgeIndexable
+        + IntoEdges
+        + IntoNodeIdentifiers
+        + IntoNeighborsDirected
+        + NodeCount
+        + EdgeCount
+        + GraphProp
+        + Sync,
+    G::NodeId: Eq + Hash + Send,
+    G::EdgeId: Eq + Hash + Send,
+    F: FnMut(G::EdgeRef) -> Result<f64, E>,
+{
+    weighted_edge_betweenness_centrality_with_progress(
+        graph,
+        normalized,
+        parallel_threshold,
+        weight_fn,
+        usize::MAX,
+        |_, _| Ok(()),
+    )
+}
+
+/// Compute the weighted edge betweenness centrality of all edges in a graph,
+/// reporting the progress of the computation.
+///
+/// This is [`weighted_edge_betweenness_centrality`] with a `progress`
+/// callback, which is called like for
+/// [`edge_betweenness_centrality_with_progress`].
+///
+/// Arguments:
+///
+/// * `graph` - The graph object to run the algorithm on
+/// * `normalized` - Whether to normalize the betweenness scores by the number
+///   of distinct paths between all pairs of nodes
+/// * `parallel_threshold` - The number of nodes to calculate the betweenness
+///   centrality in parallel at, if the number of nodes in `graph` is less
+///   than this value it will run in a single thread
+/// * `weight_fn` - A callable that is passed the `EdgeRef` of every edge and
+///   returns its weight, which must be non-negative
+/// * `progress_interval` - The number of source nodes between the calls to
+///   `progress`
+/// * `progress` - The callback which is passed the number of source nodes
+///   done and the total number of source nodes
+pub fn weighted_edge_betweenness_centrality_with_progress<G, F, P, E>(
+    graph: G,
+    normalized: bool,
+    parallel_threshold: usize,
+    weight_fn: F,
+    progress_interval: usize,
+    mut progress: P,
+) -> Result<Vec<Option<f64>>, E>
+where
+    G: NodeIndexable
+        + EdgeIndexable
+        + IntoEdges
+        + IntoNodeIdentifiers
+        + IntoNeighborsDirected
+        + NodeCount
+        + EdgeCount
+        + GraphProp
+        + Sync,
+    G::NodeId: Eq + Hash + Send,
+    G::EdgeId: Eq + Hash + Send,
+    F: FnMut(G::EdgeRef) -> Result<f64, E>,
+    P: FnMut(usize, usize) -> Result<(), E>,
+{
+    let weights = edge_weights(graph, weight_fn)?;
+    let max_index = graph.node_bound();
+    let mut betweenness = vec![None; graph.edge_bound()];
+    for edge in graph.edge_references() {
+        let is: usize = EdgeIndexable::to_index(&graph, edge.id());
+        betweenness[is] = Some(0.0);
+    }
+    let locked_betweenness = RwLock::new(&mut betweenness);
+    let node_indices: Vec<G::NodeId> = graph.node_identifiers().collect();
+    let parallel = graph.node_count() >= parallel_threshold;
+    let mut done = 0;
+    for chunk in node_indices.chunks(progress_interval.max(1)) {
+        CondIterator::new(chunk.to_vec(), parallel)
+            .map(|node_s| weighted_shortest_path_for_centrality(&graph, &node_s, &weights))
+            .for_each(|mut shortest_path_calc| {
+                accumulate_edges(
+                    &locked_betweenness,
+                    max_index,
+                    &mut shortest_path_calc,
+                    &graph,
+                );
+            });
+        done += chunk.len();
+        progress(done, node_indices.len())?;
+    }
+
+    _rescale(
+        &mut betweenness,
+        graph.node_count(),
+        normalized,
+        graph.is_directed(),
+        true,
+    );
+    Ok(betweenness)
+}
+
+/// Compute the weight of every edge once, indexed by edge index, so the
+/// shortest path searches don't need to call `weight_fn`.
+fn edge_weights<G, F, E>(graph: G, mut weight_fn: F) -> Result<Vec<f64>, E>
+where
+    G: IntoEdgeReferences + EdgeIndexable,
+    F: FnMut(G::EdgeRef) -> Result<f64, E>,
+{
+    let mut weights = vec![0.0; graph.edge_bound()];
+    for edge in graph.edge_references() {
+        weights[EdgeIndexable::to_index(&graph, edge.id())] = weight_fn(edge)?;
+    }
+    Ok(weights)
 }
 
 fn _rescale(
@@ -460,12 +941,83 @@ where
     }
 }
 
+fn weighted_shortest_path_for_centrality<G>(
+    graph: G,
+    node_s: &G::NodeId,
+    weights: &[f64],
+) -> ShortestPathDataWithEdges<G>
+where
+    G: NodeIndexable + EdgeIndexable + IntoNodeIdentifiers + NodeCount + IntoEdges,
+    G::NodeId: Eq + Hash,
+    G::EdgeId: Eq + Hash,
+{
+    let mut verts_sorted_by_distance: Vec<G::NodeId> = Vec::new(); // a stack
+    let c = graph.node_count();
+    let mut predecessors = HashMap::<G::NodeId, Vec<G::NodeId>>::with_capacity(c);
+    let mut predecessor_edges = HashMap::<G::NodeId, Vec<G::EdgeId>>::with_capacity(c);
+    let mut sigma = HashMap::<G::NodeId, f64>::with_capacity(c);
+    let mut distance = HashMap::<G::NodeId, f64>::with_capacity(c);
+    let mut done = vec![false; graph.node_bound()];
+    let mut heap: BinaryHeap<MinScored<f64, G::NodeId>> = BinaryHeap::new();
+
+    for node in graph.node_identifiers() {
+        predecessors.insert(node, Vec::new());
+        predecessor_edges.insert(node, Vec::new());
+        sigma.insert(node, 0.0);
+    }
+    sigma.insert(*node_s, 1.0);
+    distance.insert(*node_s, 0.0);
+    heap.push(MinScored(0.0, *node_s));
+    while let Some(MinScored(distance_v, v)) = heap.pop() {
+        let iv = NodeIndexable::to_index(&graph, v);
+        if done[iv] {
+            continue;
+        }
+        done[iv] = true;
+        verts_sorted_by_distance.push(v);
+        for edge in graph.edges(v) {
+            let w = edge.target();
+            if done[NodeIndexable::to_index(&graph, w)] {
+                continue;
+            }
+            let distance_w = distance_v + weights[EdgeIndexable::to_index(&graph, edge.id())];
+            let shorter = match distance.get(&w) {
+                Some(current) if distance_w > *current => continue,
+                Some(current) => distance_w < *current,
+                None => true,
+            };
+            if shorter {
+                distance.insert(w, distance_w);
+                heap.push(MinScored(distance_w, w));
+                sigma.insert(w, 0.0);
+                predecessors.get_mut(&w).unwrap().clear();
+                predecessor_edges.get_mut(&w).unwrap().clear();
+            }
+            sigma.insert(w, sigma[&w] + sigma[&v]);
+            predecessors.get_mut(&w).unwrap().push(v);
+            predecessor_edges.get_mut(&w).unwrap().push(edge.id());
+        }
+    }
+    verts_sorted_by_distance.reverse(); // will be effectively popping from the stack
+    ShortestPathDataWithEdges {
+        verts_sorted_by_distance,
+        predecessors,
+        predecessor_edges,
+        sigma,
+    }
+}
+
 #[cfg(test)]
 mod test_edge_betweenness_centrality {
-    use crate::centrality::edge_betweenness_centrality;
+    use crate::centrality::{
+        betweenness_centrality, betweenness_centrality_with_progress, edge_betweenness_centrality,
+        edge_betweenness_centrality_with_progress, weighted_betweenness_centrality,
+        weighted_betweenness_centrality_with_progress, weighted_edge_betweenness_centrality,
+    };
     use petgraph::graph::edge_index;
     use petgraph::prelude::StableGraph;
     use petgraph::Undirected;
+    use std::convert::Infallible;
 
     macro_rules! assert_almost_equal {
         ($x:expr, $y:expr, $d:expr) => {
@@ -584,6 +1136,161 @@ mod test_edge_betweenness_centrality {
         let expected_values = vec![Some(3.0), None, Some(3.0), Some(4.0)];
         assert_eq!(result, expected_values);
     }
+
+    #[test]
+    fn test_progress_matches_without_progress() {
+        let graph = petgraph::graph::DiGraph::<(), ()>::from_edges([
+            (0, 1),
+            (1, 2),
+            (2, 3),
+            (3, 0),
+            (1, 3),
+            (0, 4),
+        ]);
+        let mut calls = 0;
+        let result: Result<_, ()> =
+            edge_betweenness_centrality_with_progress(&graph, true, 1, 2, |done, total| {
+                calls += 1;
+                assert_eq!(total, 5);
+                assert!(done <= total);
+                Ok(())
+            });
+        assert_eq!(
+            result.unwrap(),
+            edge_betweenness_centrality(&graph, true, 1)
+        );
+        assert_eq!(calls, 3);
+        let result: Result<_, ()> =
+            betweenness_centrality_with_progress(&graph, false, true, 1, 2, |_, _| Ok(()));
+        assert_eq!(
+            result.unwrap(),
+            betweenness_centrality(&graph, false, true, 1)
+        );
+    }
+
+    #[test]
+    fn test_progress_cancel() {
+        let graph = petgraph::graph::UnGraph::<(), ()>::from_edges([(0, 1), (1, 2), (2, 3)]);
+        let mut calls = 0;
+        let result = betweenness_centrality_with_progress(&graph, false, false, 200, 1, |_, _| {
+            calls += 1;
+            if calls == 2 {
+                Err("cancelled")
+            } else {
+                Ok(())
+            }
+        });
+        assert_eq!(result, Err("cancelled"));
+        assert_eq!(calls, 2);
+    }
+
+    #[test]
+    fn test_weighted_unit_weights_match_unweighted() {
+        let graph = petgraph::graph::DiGraph::<(), ()>::from_edges([
+            (0, 1),
+            (1, 2),
+            (2, 3),
+            (3, 0),
+            (1, 3),
+            (0, 4),
+            (4, 3),
+        ]);
+        for parallel_threshold in [1, 50] {
+            let result: Result<_, Infallible> =
+                weighted_betweenness_centrality(&graph, true, true, parallel_threshold, |_| {
+                    Ok(1.0)
+                });
+            assert_eq!(
+                result.unwrap(),
+                betweenness_centrality(&graph, true, true, parallel_threshold)
+            );
+            let result: Result<_, Infallible> =
+                weighted_edge_betweenness_centrality(&graph, false, parallel_threshold, |_| {
+                    Ok(2.0)
+                });
+            assert_eq!(
+                result.unwrap(),
+                edge_betweenness_centrality(&graph, false, parallel_threshold)
+            );
+        }
+    }
+
+    #[test]
+    fn test_weighted_paths() {
+        // The square 0-1-2-3 with a heavy edge from 0 to 1, so the shortest
+        // path from 0 to 1 goes around the square and there are two shortest
+        // paths between 0 and 2
+        let graph = petgraph::graph::UnGraph::<(), f64>::from_edges([
+            (0, 1, 5.0),
+            (1, 2, 1.0),
+            (2, 3, 1.0),
+            (3, 0, 1.0),
+            (0, 2, 2.0),
+        ]);
+        let result: Result<_, Infallible> =
+            weighted_betweenness_centrality(&graph, false, false, 50, |e| Ok(*e.weight()));
+        let expected = [0.0, 0.0, 2.0, 1.0];
+        for (value, expected) in result.unwrap().into_iter().zip(expected) {
+            assert_almost_equal!(value.unwrap(), expected, 1e-12);
+        }
+        let result: Result<_, Infallible> =
+            weighted_edge_betweenness_centrality(&graph, false, 50, |e| Ok(*e.weight()));
+        let expected = [0.0, 3.0, 3.0, 2.0, 1.0];
+        for (value, expected) in result.unwrap().into_iter().zip(expected) {
+            assert_almost_equal!(value.unwrap(), expected, 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_weighted_zero_weight_self_loop() {
+        let graph = petgraph::graph::UnGraph::<(), f64>::from_edges([
+            (0, 0, 0.0),
+            (0, 1, 1.0),
+            (1, 2, 1.0),
+        ]);
+        let result: Result<_, Infallible> =
+            weighted_betweenness_centrality(&graph, false, false, 50, |e| Ok(*e.weight()));
+        assert_eq!(result.unwrap(), vec![Some(0.0), Some(1.0), Some(0.0)]);
+    }
+
+    #[test]
+    fn test_weighted_weight_fn_error() {
+        let graph = petgraph::graph::UnGraph::<(), ()>::from_edges([(0, 1), (1, 2)]);
+        let mut calls = 0;
+        let result = weighted_betweenness_centrality_with_progress(
+            &graph,
+            false,
+            false,
+            50,
+            |_| Err("bad weight"),
+            1,
+            |_, _| {
+                calls += 1;
+                Ok(())
+            },
+        );
+        assert_eq!(result, Err("bad weight"));
+        assert_eq!(calls, 0);
+    }
+}
+
+/// The outcome of the power iteration used by
+/// [`eigenvector_centrality_iteration`] and [`katz_centrality_iteration`].
+///
+/// When the iteration doesn't converge within `max_iter` iterations `values`
+/// holds the normalized vector of the last iteration, so callers can decide
+/// whether the approximation is good enough for them.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PowerIteration {
+    /// The centrality of each node, indexed by the node index.
+    pub values: Vec<f64>,
+    /// The number of iterations run.
+    pub iterations: usize,
+    /// The sum of the absolute differences between the vectors of the last
+    /// two iterations.
+    pub residual: f64,
+    /// Whether the residual dropped below the tolerance.
+    pub converged: bool,
 }
 
 /// Compute the eigenvector centrality of a graph
@@ -631,10 +1338,64 @@ mod test_edge_betweenness_centrality {
 /// ```
 pub fn eigenvector_centrality<G, F, E>(
     graph: G,
-    mut weight_fn: F,
+    weight_fn: F,
     max_iter: Option<usize>,
     tol: Option<f64>,
 ) -> Result<Option<Vec<f64>>, E>
+where
+    G: NodeIndexable + IntoNodeIdentifiers + IntoNeighbors + IntoEdges + NodeCount,
+    G::NodeId: Eq + std::hash::Hash,
+    F: FnMut(G::EdgeRef) -> Result<f64, E>,
+{
+    Ok(
+        eigenvector_centrality_iteration(graph, weight_fn, max_iter, tol)?
+            .filter(|result| result.converged)
+            .map(|result| result.values),
+    )
+}
+
+/// Compute the eigenvector centrality of a graph, keeping the result of the
+/// last iteration if the power method doesn't converge
+///
+/// This runs the same power method as [`eigenvector_centrality`] but returns
+/// a [`PowerIteration`] with the number of iterations and the final residual,
+/// whether or not it converged. `None` is returned if the centrality of every
+/// node drops to zero, which happens when the graph has no edges.
+///
+/// Arguments:
+///
+/// * `graph` - The graph object to run the algorithm on
+/// * `weight_fn` - An input callable that will be passed the `EdgeRef` for
+///   an edge in the graph and is expected to return a `Result<f64>` of
+///   the weight of that edge.
+/// * `max_iter` - The maximum number of iterations in the power method. If
+///   set to `None` a default value of 100 is used.
+/// * `tol` - The error tolerance used when checking for convergence in the
+///   power method. If set to `None` a default value of 1e-6 is used.
+///
+/// # Example
+/// ```rust
+/// use rustworkx_core::Result;
+/// use rustworkx_core::petgraph;
+/// use rustworkx_core::centrality::eigenvector_centrality_iteration;
+///
+/// let g = petgraph::graph::UnGraph::<i32, ()>::from_edges(&[
+///     (0, 1), (1, 2)
+/// ]);
+/// // Stop the power method after two iterations
+/// let result = eigenvector_centrality_iteration(&g, |_| -> Result<f64> {Ok(1.)}, Some(2), None)
+///     .unwrap()
+///     .unwrap();
+/// assert!(!result.converged);
+/// assert_eq!(result.iterations, 2);
+/// assert!(result.values[1] > result.values[0]);
+/// ```
+pub fn eigenvector_centrality_iteration<G, F, E>(
+    graph: G,
+    mut weight_fn: F,
+    max_iter: Option<usize>,
+    tol: Option<f64>,
+) -> Result<Option<PowerIteration>, E>
 where
     G: NodeIndexable + IntoNodeIdentifiers + IntoNeighbors + IntoEdges + NodeCount,
     G::NodeId: Eq + std::hash::Hash,
@@ -644,7 +1405,9 @@ where
     let max_iter = max_iter.unwrap_or(100);
     let mut x: Vec<f64> = vec![1.; graph.node_bound()];
     let node_count = graph.node_count();
-    for _ in 0..max_iter {
+    let mut iterations = 0;
+    let mut residual = f64::INFINITY;
+    while iterations < max_iter {
         let x_last = x.clone();
         for node_index in graph.node_identifiers() {
             let node = graph.to_index(node_index);
@@ -661,15 +1424,20 @@ where
         for v in x.iter_mut() {
             *v /= norm;
         }
-        if (0..x.len())
+        iterations += 1;
+        residual = (0..x.len())
             .map(|node| (x[node] - x_last[node]).abs())
-            .sum::<f64>()
-            < node_count as f64 * tol
-        {
-            return Ok(Some(x));
+            .sum::<f64>();
+        if residual < node_count as f64 * tol {
+            break;
         }
     }
-    Ok(None)
+    Ok(Some(PowerIteration {
+        values: x,
+        iterations,
+        residual,
+        converged: residual < node_count as f64 * tol,
+    }))
 }
 
 /// Compute the Katz centrality of a graph
@@ -724,13 +1492,82 @@ where
 /// ```
 pub fn katz_centrality<G, F, E>(
     graph: G,
-    mut weight_fn: F,
+    weight_fn: F,
     alpha: Option<f64>,
     beta_map: Option<HashMap<usize, f64>>,
     beta_scalar: Option<f64>,
     max_iter: Option<usize>,
     tol: Option<f64>,
 ) -> Result<Option<Vec<f64>>, E>
+where
+    G: NodeIndexable + IntoNodeIdentifiers + IntoNeighbors + IntoEdges + NodeCount,
+    G::NodeId: Eq + std::hash::Hash,
+    F: FnMut(G::EdgeRef) -> Result<f64, E>,
+{
+    Ok(katz_centrality_iteration(
+        graph,
+        weight_fn,
+        alpha,
+        beta_map,
+        beta_scalar,
+        max_iter,
+        tol,
+    )?
+    .filter(|result| result.converged)
+    .map(|result| result.values))
+}
+
+/// Compute the Katz centrality of a graph, keeping the result of the last
+/// iteration if the power method doesn't converge
+///
+/// This runs the same power method as [`katz_centrality`] but returns a
+/// [`PowerIteration`] with the number of iterations and the final residual,
+/// whether or not it converged. The values are normalized in either case.
+/// `None` is returned if `beta_map` doesn't contain every node of the graph
+/// or if the centrality of every node is zero.
+///
+/// Arguments:
+///
+/// * `graph` - The graph object to run the algorithm on
+/// * `weight_fn` - An input callable that will be passed the `EdgeRef` for
+///   an edge in the graph and is expected to return a `Result<f64>` of
+///   the weight of that edge.
+/// * `alpha` - Attenuation factor. If set to `None`, a default value of 0.1 is used.
+/// * `beta_map` - Immediate neighbourhood weights. Must contain all node indices or be `None`.
+/// * `beta_scalar` - Immediate neighbourhood scalar that replaces `beta_map` in case `beta_map` is None.
+///   Defaults to 1.0 in case `None` is provided.
+/// * `max_iter` - The maximum number of iterations in the power method. If
+///   set to `None` a default value of 1000 is used.
+/// * `tol` - The error tolerance used when checking for convergence in the
+///   power method. If set to `None` a default value of 1e-6 is used.
+///
+/// # Example
+/// ```rust
+/// use rustworkx_core::Result;
+/// use rustworkx_core::petgraph;
+/// use rustworkx_core::centrality::katz_centrality_iteration;
+///
+/// let g = petgraph::graph::UnGraph::<i32, ()>::from_edges(&[
+///     (0, 1), (1, 2)
+/// ]);
+/// // Stop the power method after two iterations
+/// let result =
+///     katz_centrality_iteration(&g, |_| -> Result<f64> {Ok(1.)}, None, None, None, Some(2), None)
+///         .unwrap()
+///         .unwrap();
+/// assert!(!result.converged);
+/// assert_eq!(result.iterations, 2);
+/// assert!(result.values[1] > result.values[0]);
+/// ```
+pub fn katz_centrality_iteration<G, F, E>(
+    graph: G,
+    mut weight_fn: F,
+    alpha: Option<f64>,
+    beta_map: Option<HashMap<usize, f64>>,
+    beta_scalar: Option<f64>,
+    max_iter: Option<usize>,
+    tol: Option<f64>,
+) -> Result<Option<PowerIteration>, E>
 where
     G: NodeIndexable + IntoNodeIdentifiers + IntoNeighbors + IntoEdges + NodeCount,
     G::NodeId: Eq + std::hash::Hash,
@@ -758,7 +1595,9 @@ where
 
     let mut x: Vec<f64> = vec![0.; graph.node_bound()];
     let node_count = graph.node_count();
-    for _ in 0..max_iter {
+    let mut iterations = 0;
+    let mut residual = f64::INFINITY;
+    while iterations < max_iter {
         let x_last = x.clone();
         x = vec![0.; graph.node_bound()];
         for node_index in graph.node_identifiers() {
@@ -773,31 +1612,37 @@ where
             let node = graph.to_index(node_index);
             x[node] = alpha * x[node] + beta_v[node];
         }
-        if (0..x.len())
+        iterations += 1;
+        residual = (0..x.len())
             .map(|node| (x[node] - x_last[node]).abs())
-            .sum::<f64>()
-            < node_count as f64 * tol
-        {
-            // Normalize vector
-            let norm: f64 = x.iter().map(|val| val.powi(2)).sum::<f64>().sqrt();
-            if norm == 0. {
-                return Ok(None);
-            }
-            for v in x.iter_mut() {
-                *v /= norm;
-            }
-
-            return Ok(Some(x));
+            .sum::<f64>();
+        if residual < node_count as f64 * tol {
+            break;
         }
     }
 
-    Ok(None)
+    // Normalize vector
+    let norm: f64 = x.iter().map(|val| val.powi(2)).sum::<f64>().sqrt();
+    if norm == 0. {
+        return Ok(None);
+    }
+    for v in x.iter_mut() {
+        *v /= norm;
+    }
+    Ok(Some(PowerIteration {
+        values: x,
+        iterations,
+        residual,
+        converged: residual < node_count as f64 * tol,
+    }))
 }
 
 #[cfg(test)]
 mod test_eigenvector_centrality {
 
-    use crate::centrality::eigenvector_centrality;
+    use crate::centrality::{
+        eigenvector_centrality, eigenvector_centrality_iteration, PowerIteration,
+    };
     use crate::petgraph;
     use crate::Result;
 
@@ -817,6 +1662,34 @@ mod test_eigenvector_centrality {
         assert_eq!(None, result);
     }
 
+    #[test]
+    fn test_no_convergence_partial_result() {
+        let g = petgraph::graph::UnGraph::<i32, ()>::from_edges([(0, 1), (1, 2), (2, 3)]);
+        let output: Result<Option<PowerIteration>> =
+            eigenvector_centrality_iteration(&g, |_| Ok(1.), Some(3), Some(1e-12));
+        let result = output.unwrap().unwrap();
+        assert!(!result.converged);
+        assert_eq!(result.iterations, 3);
+        assert!(result.residual > 0.);
+        let norm: f64 = result.values.iter().map(|v| v.powi(2)).sum::<f64>().sqrt();
+        assert_almost_equal!(norm, 1., 1e-12);
+        let converged: Result<Option<Vec<f64>>> =
+            eigenvector_centrality(&g, |_| Ok(1.), None, None);
+        assert_ne!(converged.unwrap().unwrap(), result.values);
+    }
+
+    #[test]
+    fn test_converged_iteration() {
+        let g = petgraph::graph::UnGraph::<i32, ()>::from_edges([(0, 1), (1, 2)]);
+        let output: Result<Option<PowerIteration>> =
+            eigenvector_centrality_iteration(&g, |_| Ok(1.), None, None);
+        let result = output.unwrap().unwrap();
+        assert!(result.converged);
+        assert!(result.residual < 3. * 1e-6);
+        let expected: Result<Option<Vec<f64>>> = eigenvector_centrality(&g, |_| Ok(1.), None, None);
+        assert_eq!(Some(result.values), expected.unwrap());
+    }
+
     #[test]
     fn test_undirected_complete_graph() {
         let g = petgraph::graph::UnGraph::<i32, ()>::from_edges([
@@ -886,7 +1759,7 @@ mod test_eigenvector_centrality {
 #[cfg(test)]
 mod test_katz_centrality {
 
-    use crate::centrality::katz_centrality;
+    use crate::centrality::{katz_centrality, katz_centrality_iteration, PowerIteration};
     use crate::petgraph;
     use crate::Result;
     use hashbrown::HashMap;
@@ -898,6 +1771,34 @@ mod test_katz_centrality {
             }
         };
     }
+    #[test]
+    fn test_no_convergence_partial_result() {
+        let g = petgraph::graph::UnGraph::<i32, ()>::from_edges([(0, 1), (1, 2), (2, 3)]);
+        let output: Result<Option<PowerIteration>> =
+            katz_centrality_iteration(&g, |_| Ok(1.), None, None, None, Some(2), None);
+        let result = output.unwrap().unwrap();
+        assert!(!result.converged);
+        assert_eq!(result.iterations, 2);
+        assert!(result.residual > 0.);
+        let norm: f64 = result.values.iter().map(|v| v.powi(2)).sum::<f64>().sqrt();
+        assert_almost_equal!(norm, 1., 1e-12);
+        let expected: Result<Option<Vec<f64>>> =
+            katz_centrality(&g, |_| Ok(1.), None, None, None, Some(2), None);
+        assert_eq!(None, expected.unwrap());
+    }
+
+    #[test]
+    fn test_converged_iteration() {
+        let g = petgraph::graph::UnGraph::<i32, ()>::from_edges([(0, 1), (1, 2)]);
+        let output: Result<Option<PowerIteration>> =
+            katz_centrality_iteration(&g, |_| Ok(1.), None, None, None, None, None);
+        let result = output.unwrap().unwrap();
+        assert!(result.converged);
+        let expected: Result<Option<Vec<f64>>> =
+            katz_centrality(&g, |_| Ok(1.), None, None, None, None, None);
+        assert_eq!(Some(result.values), expected.unwrap());
+    }
+
     #[test]
     fn test_no_convergence() {
         let g = petgraph::graph::UnGraph::<i32, ()>::from_edges([(0, 1), (1, 2)]);
@@ -1072,3 +1973,249 @@ where
     }
     closeness
 }
+
+/// Compute the shortest path distances between `source` and all the nodes
+/// it is connected to in `direction`, so from them to `source` for
+/// [`Direction::Incoming`].
+fn closeness_distances<G, F, E>(
+    graph: G,
+    source: G::NodeId,
+    direction: Direction,
+    weight_fn: &mut F,
+) -> Result<DictMap<G::NodeId, f64>, E>
+where
+    G: NodeIndexable + IntoEdges + IntoEdgesDirected + Visitable,
+    G::NodeId: Hash + Eq,
+    F: FnMut(G::EdgeRef) -> Result<f64, E>,
+{
+    match direction {
+        Direction::Outgoing => shortest_path::dijkstra(graph, source, None, &mut *weight_fn, None),
+        Direction::Incoming => shortest_path::dijkstra(
+            Reversed(graph),
+            source,
+            None,
+            |edge| weight_fn(edge.into_unreversed()),
+            None,
+        ),
+    }
+}
+
+/// Compute the closeness centrality of each node in a graph with weighted
+/// edges.
+///
+/// This is the same as [`closeness_centrality`] with the shortest path
+/// distances computed with Dijkstra's algorithm from the edge weights
+/// returned by `weight_fn`, which must not be negative. For directed graphs
+/// `direction` picks whether the distances from the other nodes to a node
+/// ([`Direction::Incoming`], as in [`closeness_centrality`]) or from a node to
+/// the other nodes ([`Direction::Outgoing`]) are used. It has no effect on
+/// undirected graphs.
+///
+/// Arguments:
+///
+/// * `graph` - The graph object to run the algorithm on
+/// * `wf_improved` - If `true`, scale by the fraction of nodes reachable.
+/// * `direction` - The direction of the paths to use for directed graphs.
+/// * `weight_fn` - An input callable that will be passed the `EdgeRef` for
+///   an edge in the graph and is expected to return a `Result<f64>` of
+///   the length of the edge.
+///
+/// # Example
+/// ```rust
+/// use std::convert::Infallible;
+/// use rustworkx_core::petgraph;
+/// use rustworkx_core::petgraph::visit::EdgeRef;
+/// use rustworkx_core::petgraph::Direction;
+/// use rustworkx_core::centrality::weighted_closeness_centrality;
+///
+/// let g = petgraph::graph::DiGraph::<(), f64>::from_edges(&[
+///     (0, 1, 1.), (1, 2, 3.), (0, 2, 2.)
+/// ]);
+/// let weight_fn = |e: petgraph::graph::EdgeReference<f64>| Ok::<f64, Infallible>(*e.weight());
+/// let incoming = weighted_closeness_centrality(&g, false, Direction::Incoming, weight_fn);
+/// assert_eq!(vec![Some(0.), Some(1.), Some(2. / 5.)], incoming.unwrap());
+/// let outgoing = weighted_closeness_centrality(&g, false, Direction::Outgoing, weight_fn);
+/// assert_eq!(vec![Some(2. / 3.), Some(1. / 3.), Some(0.)], outgoing.unwrap());
+/// ```
+pub fn weighted_closeness_centrality<G, F, E>(
+    graph: G,
+    wf_improved: bool,
+    direction: Direction,
+    mut weight_fn: F,
+) -> Result<Vec<Option<f64>>, E>
+where
+    G: NodeIndexable + IntoNodeIdentifiers + IntoEdges + IntoEdgesDirected + Visitable + NodeCount,
+    G::NodeId: Hash + Eq,
+    F: FnMut(G::EdgeRef) -> Result<f64, E>,
+{
+    let node_count = graph.node_count();
+    let mut closeness: Vec<Option<f64>> = vec![None; graph.node_bound()];
+    for node in graph.node_identifiers() {
+        let distances = closeness_distances(graph, node, direction, &mut weight_fn)?;
+        let reachable_nodes_count = distances.len();
+        if reachable_nodes_count == 1 {
+            closeness[graph.to_index(node)] = Some(0.0);
+            continue;
+        }
+        let dists_sum: f64 = distances.into_values().sum();
+        let mut value = (reachable_nodes_count - 1) as f64 / dists_sum;
+        if wf_improved {
+            value *= (reachable_nodes_count - 1) as f64 / (node_count - 1) as f64;
+        }
+        closeness[graph.to_index(node)] = Some(value);
+    }
+    Ok(closeness)
+}
+
+/// Compute the harmonic centrality of each node in a graph.
+///
+/// The harmonic centrality of a node `u` is the sum of the reciprocals of
+/// the shortest path distances to `u` from all other nodes, where nodes that
+/// can't reach `u` add `0` [^BV]. Unlike closeness centrality it needs no
+/// correction for disconnected graphs.
+///
+/// The distances are computed with Dijkstra's algorithm from the edge
+/// weights returned by `weight_fn`, which must not be negative, and nodes at
+/// distance `0` are skipped. For directed graphs `direction` picks whether
+/// the distances from the other nodes to a node ([`Direction::Incoming`]) or
+/// from a node to the other nodes ([`Direction::Outgoing`]) are used.
+///
+/// [^BV] Boldi, P., & Vigna, S. (2014). Axioms for Centrality.
+///   Internet Mathematics, 10(3-4), 222-262. doi:10.1080/15427951.2013.865686
+///
+/// Arguments:
+///
+/// * `graph` - The graph object to run the algorithm on
+/// * `direction` - The direction of the paths to use for directed graphs.
+/// * `weight_fn` - An input callable that will be passed the `EdgeRef` for
+///   an edge in the graph and is expected to return a `Result<f64>` of
+///   the length of the edge.
+///
+/// # Example
+/// ```rust
+/// use std::convert::Infallible;
+/// use rustworkx_core::petgraph;
+/// use rustworkx_core::petgraph::Direction;
+/// use rustworkx_core::centrality::harmonic_centrality;
+///
+/// // Two disconnected paths
+/// let g = petgraph::graph::UnGraph::<(), ()>::from_edges(&[(0, 1), (1, 2), (3, 4)]);
+/// let output = harmonic_centrality(&g, Direction::Incoming, |_| Ok::<f64, Infallible>(1.));
+/// assert_eq!(
+///     vec![Some(1.5), Some(2.), Some(1.5), Some(1.), Some(1.)],
+///     output.unwrap()
+/// );
+/// ```
+pub fn harmonic_centrality<G, F, E>(
+    graph: G,
+    direction: Direction,
+    mut weight_fn: F,
+) -> Result<Vec<Option<f64>>, E>
+where
+    G: NodeIndexable + IntoNodeIdentifiers + IntoEdges + IntoEdgesDirected + Visitable,
+    G::NodeId: Hash + Eq,
+    F: FnMut(G::EdgeRef) -> Result<f64, E>,
+{
+    let mut harmonic: Vec<Option<f64>> = vec![None; graph.node_bound()];
+    for node in graph.node_identifiers() {
+        let distances = closeness_distances(graph, node, direction, &mut weight_fn)?;
+        harmonic[graph.to_index(node)] = Some(
+            distances
+                .into_values()
+                .filter(|distance| *distance > 0.0)
+                .map(|distance| 1.0 / distance)
+                .sum(),
+        );
+    }
+    Ok(harmonic)
+}
+
+#[cfg(test)]
+mod test_closeness_centrality {
+    use super::{closeness_centrality, harmonic_centrality, weighted_closeness_centrality};
+    use crate::petgraph::graph::{DiGraph, UnGraph};
+    use crate::petgraph::Direction;
+    use std::convert::Infallible;
+
+    fn unit<T>(_: T) -> Result<f64, Infallible> {
+        Ok(1.0)
+    }
+
+    #[test]
+    fn test_unit_weights_match_unweighted() {
+        let edges = [(0, 4), (1, 2), (2, 3), (3, 4), (1, 4), (5, 6)];
+        let g = UnGraph::<(), ()>::from_edges(edges);
+        let dg = DiGraph::<(), ()>::from_edges(edges);
+        for wf_improved in [true, false] {
+            assert_eq!(
+                closeness_centrality(&g, wf_improved),
+                weighted_closeness_centrality(&g, wf_improved, Direction::Incoming, unit).unwrap()
+            );
+            assert_eq!(
+                closeness_centrality(&dg, wf_improved),
+                weighted_closeness_centrality(&dg, wf_improved, Direction::Incoming, unit).unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn test_outgoing_is_incoming_of_reversed() {
+        let edges = [(0, 1), (1, 2), (2, 0), (2, 3), (3, 4)];
+        let dg = DiGraph::<(), ()>::from_edges(edges);
+        let mut reversed = dg.clone();
+        reversed.reverse();
+        assert_eq!(
+            weighted_closeness_centrality(&dg, true, Direction::Outgoing, unit).unwrap(),
+            closeness_centrality(&reversed, true)
+        );
+        assert_eq!(
+            harmonic_centrality(&dg, Direction::Outgoing, unit).unwrap(),
+            harmonic_centrality(&reversed, Direction::Incoming, unit).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_weighted_closeness() {
+        // A path 0 - 1 - 2 with lengths 1 and 3 and a node 3 on its own
+        let mut g = UnGraph::<(), f64>::from_edges([(0, 1, 1.0), (1, 2, 3.0)]);
+        g.add_node(());
+        let weight_fn =
+            |e: petgraph::graph::EdgeReference<f64>| -> Result<f64, Infallible> { Ok(*e.weight()) };
+        let closeness =
+            weighted_closeness_centrality(&g, false, Direction::Incoming, weight_fn).unwrap();
+        assert_eq!(
+            closeness,
+            vec![Some(2.0 / 5.0), Some(2.0 / 4.0), Some(2.0 / 7.0), Some(0.0)]
+        );
+        let closeness =
+            weighted_closeness_centrality(&g, true, Direction::Incoming, weight_fn).unwrap();
+        assert_eq!(
+            closeness,
+            vec![
+                Some(2.0 / 5.0 * 2.0 / 3.0),
+                Some(2.0 / 4.0 * 2.0 / 3.0),
+                Some(2.0 / 7.0 * 2.0 / 3.0),
+                Some(0.0)
+            ]
+        );
+        let harmonic = harmonic_centrality(&g, Direction::Incoming, weight_fn).unwrap();
+        assert_eq!(
+            harmonic,
+            vec![
+                Some(1.0 + 1.0 / 4.0),
+                Some(1.0 + 1.0 / 3.0),
+                Some(1.0 / 4.0 + 1.0 / 3.0),
+                Some(0.0)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_weight_fn_error() {
+        let g = UnGraph::<(), ()>::from_edges([(0, 1)]);
+        let result = weighted_closeness_centrality(&g, true, Direction::Incoming, |_| Err("bad"));
+        assert_eq!(result, Err("bad"));
+        let result = harmonic_centrality(&g, Direction::Incoming, |_| Err("bad"));
+        assert_eq!(result, Err("bad"));
+    }
+}
@@ -0,0 +1,314 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+use hashbrown::{HashMap, HashSet};
+use petgraph::visit::{
+    EdgeRef, IntoEdgeReferences, IntoNeighbors, IntoNodeIdentifiers, NodeIndexable,
+};
+
+/// The definition used for the weighted clustering coefficient.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum WeightedClustering {
+    /// The definition of Onnela et al., the average over the pairs of
+    /// neighbors of a node of the geometric mean of the weights of the
+    /// triangle they form with the node, normalized by the largest weight
+    /// of the graph.
+    #[default]
+    Onnela,
+    /// The definition of Barrat et al., where every triangle of a node is
+    /// weighted by the average weight of the two edges of the node in it,
+    /// normalized by the strength of the node.
+    Barrat,
+}
+
+/// Compute the square clustering coefficient of every node of a graph.
+///
+/// The square clustering coefficient of a node is the fraction of the
+/// possible squares, cycles of length four, through the node that exist in
+/// the graph. It's the analogue of the triangle-based clustering
+/// coefficient for graphs with few triangles, such as bipartite graphs. For
+/// every pair of neighbors `u` and `w` of a node `v`, `q(u, w)` is the number
+/// of common neighbors of `u` and `w` other than `v`, and the coefficient is
+///
+/// ```text
+/// C4(v) = sum q(u, w) / sum (a(u, w) + q(u, w))
+/// ```
+///
+/// where `a(u, w)` is the number of neighbors of `u` and `w` that aren't `v`,
+/// the other node of the pair or a common neighbor of the pair. The
+/// coefficient is `0` for nodes without any possible squares.
+///
+/// The graph is treated as a simple undirected graph: self-loops are
+/// ignored and parallel edges are counted once.
+///
+/// Arguments:
+///
+/// * `graph` - The undirected graph to compute the coefficients for.
+///
+/// Returns a `Vec` indexed by the node indices of the graph, with `None`
+/// for the indices that aren't nodes of the graph.
+///
+/// # Example
+/// ```rust
+/// use rustworkx_core::clustering::square_clustering;
+/// use rustworkx_core::petgraph::graph::UnGraph;
+///
+/// // A square with a pendant path on node 0
+/// let graph = UnGraph::<(), ()>::from_edges(&[(0, 1), (0, 2), (1, 3), (2, 3), (0, 4), (4, 5)]);
+/// let clustering = square_clustering(&graph);
+/// assert_eq!(
+///     clustering,
+///     vec![Some(0.2), Some(0.5), Some(0.5), Some(1.), Some(0.), Some(0.)]
+/// );
+/// ```
+pub fn square_clustering<G>(graph: G) -> Vec<Option<f64>>
+where
+    G: IntoNeighbors + IntoNodeIdentifiers + NodeIndexable,
+{
+    let mut neighbors: Vec<HashSet<usize>> = vec![HashSet::new(); graph.node_bound()];
+    for node in graph.node_identifiers() {
+        let index = graph.to_index(node);
+        neighbors[index] = graph
+            .neighbors(node)
+            .map(|neighbor| graph.to_index(neighbor))
+            .filter(|neighbor| *neighbor != index)
+            .collect();
+    }
+
+    let mut clustering = vec![None; graph.node_bound()];
+    for node in graph.node_identifiers() {
+        let v = graph.to_index(node);
+        let node_neighbors: Vec<usize> = neighbors[v].iter().copied().collect();
+        let mut squares = 0;
+        let mut potential = 0;
+        for (i, u) in node_neighbors.iter().enumerate() {
+            for w in &node_neighbors[i + 1..] {
+                let common = neighbors[*u]
+                    .intersection(&neighbors[*w])
+                    .filter(|common| **common != v)
+                    .count();
+                let mut shared = common + 1;
+                if neighbors[*u].contains(w) {
+                    shared += 1;
+                }
+                squares += common;
+                potential +=
+                    (neighbors[*u].len() - shared) + (neighbors[*w].len() - shared) + common;
+            }
+        }
+        clustering[v] = Some(if potential > 0 {
+            squares as f64 / potential as f64
+        } else {
+            0.
+        });
+    }
+    clustering
+}
+
+/// Compute the weighted clustering coefficient of every node of a graph.
+///
+/// The weighted clustering coefficient generalizes the clustering
+/// coefficient, the fraction of the pairs of neighbors of a node that are
+/// adjacent, to weighted graphs. For a node `u` with degree `k` and
+/// strength `s`, the sum of the weights of its edges, the
+/// [`WeightedClustering::Onnela`] definition is
+///
+/// ```text
+/// C(u) = 2 / (k (k - 1)) sum (w(u, v) w(u, w) w(v, w))^(1/3) / max(w)
+/// ```
+///
+/// and the [`WeightedClustering::Barrat`] definition is
+///
+/// ```text
+/// C(u) = 1 / (s (k - 1)) sum (w(u, v) + w(u, w))
+/// ```
+///
+/// where both sums are over the pairs of adjacent neighbors `v` and `w` of
+/// `u`. With equal weights both are the unweighted clustering coefficient.
+/// The coefficient is `0` for nodes with a degree less than two.
+///
+/// The graph is treated as a simple undirected graph: self-loops are
+/// ignored and the weights of parallel edges are summed.
+///
+/// Arguments:
+///
+/// * `graph` - The undirected graph to compute the coefficients for.
+/// * `method` - The definition of the weighted clustering coefficient.
+/// * `weight_fn` - A callable that will be passed the `EdgeRef` of every edge
+///   and is expected to return a `Result` with the non-negative weight of
+///   that edge.
+///
+/// Returns a `Vec` indexed by the node indices of the graph, with `None`
+/// for the indices that aren't nodes of the graph.
+///
+/// # Example
+/// ```rust
+/// use rustworkx_core::clustering::{weighted_clustering, WeightedClustering};
+/// use rustworkx_core::petgraph::graph::UnGraph;
+/// use rustworkx_core::Result;
+///
+/// let graph = UnGraph::<(), f64>::from_edges(&[(0, 1, 1.), (1, 2, 2.), (2, 0, 4.), (0, 3, 1.)]);
+/// let res: Result<Vec<Option<f64>>> =
+///     weighted_clustering(&graph, WeightedClustering::Barrat, |e| Ok(*e.weight()));
+/// assert_eq!(res.unwrap(), vec![Some(5. / 12.), Some(1.), Some(1.), Some(0.)]);
+/// ```
+pub fn weighted_clustering<G, F, E>(
+    graph: G,
+    method: WeightedClustering,
+    mut weight_fn: F,
+) -> Result<Vec<Option<f64>>, E>
+where
+    G: IntoEdgeReferences + IntoNodeIdentifiers + NodeIndexable,
+    F: FnMut(G::EdgeRef) -> Result<f64, E>,
+{
+    let mut adjacency: Vec<HashMap<usize, f64>> = vec![HashMap::new(); graph.node_bound()];
+    for edge in graph.edge_references() {
+        let weight = weight_fn(edge)?;
+        let source = graph.to_index(edge.source());
+        let target = graph.to_index(edge.target());
+        if source != target {
+            *adjacency[source].entry(target).or_insert(0.) += weight;
+            *adjacency[target].entry(source).or_insert(0.) += weight;
+        }
+    }
+    let max_weight = adjacency
+        .iter()
+        .flat_map(|neighbors| neighbors.values())
+        .fold(0., |max: f64, weight| max.max(*weight));
+
+    let mut clustering = vec![None; graph.node_bound()];
+    for node in graph.node_identifiers() {
+        let u = graph.to_index(node);
+        let neighbors: Vec<(usize, f64)> = adjacency[u].iter().map(|(v, w)| (*v, *w)).collect();
+        let degree = neighbors.len();
+        if degree < 2 {
+            clustering[u] = Some(0.);
+            continue;
+        }
+        let mut total = 0.;
+        for (i, (v, weight_uv)) in neighbors.iter().enumerate() {
+            for (w, weight_uw) in &neighbors[i + 1..] {
+                let Some(weight_vw) = adjacency[*v].get(w) else {
+                    continue;
+                };
+                total += match method {
+                    WeightedClustering::Onnela => {
+                        (weight_uv * weight_uw * weight_vw).cbrt() / max_weight
+                    }
+                    WeightedClustering::Barrat => weight_uv + weight_uw,
+                };
+            }
+        }
+        let normalization = match method {
+            WeightedClustering::Onnela => (degree * (degree - 1)) as f64 / 2.,
+            WeightedClustering::Barrat => {
+                let strength: f64 = neighbors.iter().map(|(_, weight)| weight).sum();
+                strength * (degree - 1) as f64
+            }
+        };
+        clustering[u] = Some(if total > 0. {
+            total / normalization
+        } else {
+            0.
+        });
+    }
+    Ok(clustering)
+}
+
+#[cfg(test)]
+mod test_clustering {
+    use super::{square_clustering, weighted_clustering, WeightedClustering};
+    use crate::generators::{complete_graph, cycle_graph};
+    use crate::petgraph::graph::UnGraph;
+    use crate::petgraph::stable_graph::StableUnGraph;
+    use crate::Result;
+
+    fn assert_almost_equal(left: &[Option<f64>], right: &[Option<f64>]) {
+        assert_eq!(left.len(), right.len());
+        for (x, y) in left.iter().zip(right) {
+            assert!(
+                (x.unwrap() - y.unwrap()).abs() < 1e-12,
+                "{:?} != {:?}",
+                left,
+                right
+            );
+        }
+    }
+
+    #[test]
+    fn test_square_complete_graph() {
+        let graph: UnGraph<(), ()> = complete_graph(Some(4), None, || (), || ()).unwrap();
+        assert_eq!(square_clustering(&graph), vec![Some(1.); 4]);
+    }
+
+    #[test]
+    fn test_square_cycle() {
+        let graph: UnGraph<(), ()> = cycle_graph(Some(4), None, || (), || (), false).unwrap();
+        assert_eq!(square_clustering(&graph), vec![Some(1.); 4]);
+        let graph: UnGraph<(), ()> = cycle_graph(Some(5), None, || (), || (), false).unwrap();
+        assert_eq!(square_clustering(&graph), vec![Some(0.); 5]);
+    }
+
+    #[test]
+    fn test_square_complete_bipartite() {
+        let graph = UnGraph::<(), ()>::from_edges([(0, 2), (0, 3), (0, 4), (1, 2), (1, 3), (1, 4)]);
+        assert_eq!(square_clustering(&graph), vec![Some(1.); 5]);
+    }
+
+    #[test]
+    fn test_square_ignores_self_loops_and_parallel_edges() {
+        let graph = UnGraph::<(), ()>::from_edges([(0, 1), (1, 2), (2, 3), (3, 0), (0, 0), (0, 1)]);
+        assert_eq!(square_clustering(&graph), vec![Some(1.); 4]);
+    }
+
+    #[test]
+    fn test_square_removed_node() {
+        let mut graph = StableUnGraph::<(), ()>::from_edges([(0, 1), (1, 2), (2, 3), (3, 0)]);
+        graph.remove_node(1.into());
+        assert_eq!(
+            square_clustering(&graph),
+            vec![Some(0.), None, Some(0.), Some(0.)]
+        );
+    }
+
+    #[test]
+    fn test_onnela() {
+        let graph =
+            UnGraph::<(), f64>::from_edges([(0, 1, 1.), (1, 2, 2.), (2, 0, 4.), (0, 3, 1.)]);
+        let res: Result<Vec<Option<f64>>> =
+            weighted_clustering(&graph, WeightedClustering::Onnela, |e| Ok(*e.weight()));
+        assert_almost_equal(
+            &res.unwrap(),
+            &[Some(1. / 6.), Some(0.5), Some(0.5), Some(0.)],
+        );
+    }
+
+    #[test]
+    fn test_unit_weights() {
+        // A triangle with a pendant node gives the unweighted clustering
+        // coefficient for both definitions
+        let graph = UnGraph::<(), ()>::from_edges([(0, 1), (1, 2), (2, 0), (0, 3)]);
+        let expected = [Some(1. / 3.), Some(1.), Some(1.), Some(0.)];
+        for method in [WeightedClustering::Onnela, WeightedClustering::Barrat] {
+            let res: Result<Vec<Option<f64>>> = weighted_clustering(&graph, method, |_| Ok(1.));
+            assert_almost_equal(&res.unwrap(), &expected);
+        }
+    }
+
+    #[test]
+    fn test_weight_fn_error() {
+        let graph = UnGraph::<(), ()>::from_edges([(0, 1)]);
+        let res: Result<Vec<Option<f64>>, &str> =
+            weighted_clustering(&graph, WeightedClustering::Barrat, |_| Err("error"));
+        assert_eq!(res, Err("error"));
+    }
+}
@@ -20,6 +20,7 @@ use std::hash::Hash;
 use crate::dictmap::*;
 use crate::line_graph::line_graph;
 
+use crate::par::*;
 use hashbrown::{HashMap, HashSet};
 use indexmap::IndexSet;
 use petgraph::graph::NodeIndex;
@@ -28,7 +29,6 @@ use petgraph::visit::{
     IntoNodeIdentifiers, NodeCount, NodeIndexable,
 };
 use petgraph::{Incoming, Outgoing};
-use rayon::prelude::*;
 
 /// Compute a two-coloring of a graph
 ///
@@ -0,0 +1,340 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+//! Community quality measures and helpers.
+//!
+//! The functions in this module support multigraphs: the weights of parallel
+//! edges add up as if they were a single edge with the total weight, and a
+//! self-loop with weight `w` is an edge inside the community of its node
+//! that adds `2w` to the weighted degree of an undirected node, as it does
+//! for the degree. [`sum_parallel_edges`] collapses a multigraph into a
+//! simple graph when a different way of combining parallel edges is needed.
+
+use std::hash::Hash;
+
+use petgraph::visit::{
+    EdgeRef, GraphProp, IntoEdgeReferences, IntoNodeIdentifiers, NodeCount, NodeIndexable,
+};
+
+use crate::dictmap::*;
+
+/// Compute the modularity of a partition of the nodes of a graph.
+///
+/// For an undirected graph the modularity is
+///
+/// ```text
+/// Q = sum_c (L_c / m - resolution * (d_c / 2m)^2)
+/// ```
+///
+/// where `m` is the total weight of the edges, and for every community `c`,
+/// `L_c` is the total weight of the edges inside the community and `d_c` is
+/// the sum of the weighted degrees of its nodes. For a directed graph the
+/// second term is `resolution * d_c^out * d_c^in / m^2`, with the sums of
+/// the weighted out-degrees and in-degrees of the community [^Newman2004].
+///
+/// Parallel edges are summed and self-loops are handled as described in the
+/// [module documentation](self).
+///
+/// Arguments:
+///
+/// * `graph` - The graph to compute the modularity for.
+/// * `communities` - The communities of the partition, every node of the
+///   graph has to be in exactly one of them.
+/// * `weight_fn` - A callable that will be passed the `EdgeRef` of every edge
+///   and is expected to return a `Result` with the weight of that edge.
+/// * `resolution` - The resolution parameter, values smaller than `1` favor
+///   larger communities.
+///
+/// Returns `None` if `communities` isn't a partition of the nodes of the
+/// graph, and `NaN` if the total weight of the edges is `0`.
+///
+/// # Example
+/// ```rust
+/// use rustworkx_core::community::modularity;
+/// use rustworkx_core::petgraph::graph::{NodeIndex, UnGraph};
+/// use rustworkx_core::Result;
+///
+/// // Two triangles joined by an edge
+/// let graph = UnGraph::<(), ()>::from_edges(&[(0, 1), (1, 2), (2, 0), (3, 4), (4, 5), (5, 3), (2, 3)]);
+/// let communities: Vec<Vec<NodeIndex>> = vec![
+///     (0..3).map(NodeIndex::new).collect(),
+///     (3..6).map(NodeIndex::new).collect(),
+/// ];
+/// let res: Result<Option<f64>> = modularity(&graph, &communities, |_| Ok(1.), 1.);
+/// assert!((res.unwrap().unwrap() - 5. / 14.).abs() < 1e-12);
+/// ```
+///
+/// [^Newman2004]: M. E. J. Newman, "Analysis of weighted networks",
+///     Physical Review E 70, 056131 (2004).
+pub fn modularity<G, F, E>(
+    graph: G,
+    communities: &[Vec<G::NodeId>],
+    mut weight_fn: F,
+    resolution: f64,
+) -> Result<Option<f64>, E>
+where
+    G: IntoEdgeReferences + IntoNodeIdentifiers + NodeCount + NodeIndexable + GraphProp,
+    F: FnMut(G::EdgeRef) -> Result<f64, E>,
+{
+    let mut label = vec![usize::MAX; graph.node_bound()];
+    let mut num_labeled = 0;
+    for (community, nodes) in communities.iter().enumerate() {
+        for node in nodes {
+            let index = graph.to_index(*node);
+            if index >= label.len() || label[index] != usize::MAX {
+                return Ok(None);
+            }
+            label[index] = community;
+            num_labeled += 1;
+        }
+    }
+    if num_labeled != graph.node_count()
+        || graph
+            .node_identifiers()
+            .any(|node| label[graph.to_index(node)] == usize::MAX)
+    {
+        return Ok(None);
+    }
+
+    let mut internal = vec![0.; communities.len()];
+    let mut out_degree = vec![0.; communities.len()];
+    let mut in_degree = vec![0.; communities.len()];
+    let mut total = 0.;
+    for edge in graph.edge_references() {
+        let weight = weight_fn(edge)?;
+        let source = label[graph.to_index(edge.source())];
+        let target = label[graph.to_index(edge.target())];
+        total += weight;
+        out_degree[source] += weight;
+        in_degree[target] += weight;
+        if source == target {
+            internal[source] += weight;
+        }
+    }
+    let expected: f64 = if graph.is_directed() {
+        out_degree
+            .iter()
+            .zip(&in_degree)
+            .map(|(out_degree, in_degree)| out_degree * in_degree)
+            .sum::<f64>()
+            / (total * total)
+    } else {
+        out_degree
+            .iter()
+            .zip(&in_degree)
+            .map(|(out_degree, in_degree)| (out_degree + in_degree).powi(2))
+            .sum::<f64>()
+            / (4. * total * total)
+    };
+    Ok(Some(
+        internal.iter().sum::<f64>() / total - resolution * expected,
+    ))
+}
+
+/// Combine the weights of the parallel edges of a multigraph.
+///
+/// This collapses a multigraph into a weighted simple graph: the weights of
+/// all the edges between the same pair of nodes are combined into a single
+/// weight with `combine`, for example by summing them or taking their
+/// maximum. For an undirected graph the edges `(a, b)` and `(b, a)` are
+/// parallel, while for a directed graph they are kept apart. Self-loops are
+/// kept, with the parallel self-loops of a node combined.
+///
+/// Arguments:
+///
+/// * `graph` - The multigraph to collapse.
+/// * `weight_fn` - A callable that will be passed the `EdgeRef` of every edge
+///   and is expected to return a `Result` with the weight of that edge.
+/// * `combine` - A callable combining two weights into one.
+///
+/// Returns a map from the endpoints of every edge of the simple graph to its
+/// weight, in the order of the first edge between them. The endpoints are
+/// the ones of that first edge.
+///
+/// # Example
+/// ```rust
+/// use rustworkx_core::community::sum_parallel_edges;
+/// use rustworkx_core::dictmap::DictMap;
+/// use rustworkx_core::petgraph::graph::{NodeIndex, UnGraph};
+/// use rustworkx_core::Result;
+///
+/// let graph = UnGraph::<(), f64>::from_edges(&[(0, 1, 1.), (1, 2, 2.), (1, 0, 3.)]);
+/// let res: Result<DictMap<(NodeIndex, NodeIndex), f64>> =
+///     sum_parallel_edges(&graph, |e| Ok(*e.weight()), |a, b| a + b);
+/// let weights: Vec<f64> = res.unwrap().into_values().collect();
+/// assert_eq!(weights, vec![4., 2.]);
+/// ```
+#[allow(clippy::type_complexity)]
+pub fn sum_parallel_edges<G, K, F, C, E>(
+    graph: G,
+    mut weight_fn: F,
+    mut combine: C,
+) -> Result<DictMap<(G::NodeId, G::NodeId), K>, E>
+where
+    G: IntoEdgeReferences + GraphProp,
+    G::NodeId: Eq + Hash,
+    F: FnMut(G::EdgeRef) -> Result<K, E>,
+    C: FnMut(K, K) -> K,
+{
+    // The weights are taken out of the map while they are combined
+    let mut weights: DictMap<(G::NodeId, G::NodeId), Option<K>> = DictMap::new();
+    for edge in graph.edge_references() {
+        let weight = weight_fn(edge)?;
+        let mut key = (edge.source(), edge.target());
+        if !graph.is_directed() && !weights.contains_key(&key) {
+            let reversed = (edge.target(), edge.source());
+            if weights.contains_key(&reversed) {
+                key = reversed;
+            }
+        }
+        match weights.get_mut(&key) {
+            Some(current) => *current = Some(combine(current.take().unwrap(), weight)),
+            None => {
+                weights.insert(key, Some(weight));
+            }
+        }
+    }
+    Ok(weights
+        .into_iter()
+        .map(|(key, weight)| (key, weight.unwrap()))
+        .collect())
+}
+
+#[cfg(test)]
+mod test_community {
+    use super::{modularity, sum_parallel_edges};
+    use crate::dictmap::DictMap;
+    use crate::Result;
+    use petgraph::graph::{DiGraph, NodeIndex, UnGraph};
+    use petgraph::stable_graph::StableUnGraph;
+
+    fn nodes(indices: &[usize]) -> Vec<NodeIndex> {
+        indices.iter().copied().map(NodeIndex::new).collect()
+    }
+
+    fn unweighted<G>(graph: G, communities: &[Vec<NodeIndex>]) -> Option<f64>
+    where
+        G: petgraph::visit::IntoEdgeReferences
+            + petgraph::visit::IntoNodeIdentifiers
+            + petgraph::visit::NodeCount
+            + petgraph::visit::NodeIndexable<NodeId = NodeIndex>
+            + petgraph::visit::GraphProp,
+    {
+        let res: Result<Option<f64>> = modularity(graph, communities, |_| Ok(1.), 1.);
+        res.unwrap()
+    }
+
+    #[test]
+    fn test_not_a_partition() {
+        let graph = UnGraph::<(), ()>::from_edges([(0, 1), (1, 2)]);
+        assert_eq!(unweighted(&graph, &[nodes(&[0, 1])]), None);
+        assert_eq!(unweighted(&graph, &[nodes(&[0, 1]), nodes(&[1, 2])]), None);
+        assert_eq!(unweighted(&graph, &[nodes(&[0, 1, 2, 3])]), None);
+    }
+
+    #[test]
+    fn test_single_community() {
+        let graph = UnGraph::<(), ()>::from_edges([(0, 1), (1, 2)]);
+        assert_eq!(unweighted(&graph, &[nodes(&[0, 1, 2])]), Some(0.));
+    }
+
+    #[test]
+    fn test_no_edges() {
+        let mut graph = UnGraph::<(), ()>::new_undirected();
+        graph.add_node(());
+        assert!(unweighted(&graph, &[nodes(&[0])]).unwrap().is_nan());
+    }
+
+    #[test]
+    fn test_parallel_edges_match_weights() {
+        // A multigraph and the simple graph with the parallel edges summed
+        let multigraph =
+            UnGraph::<(), ()>::from_edges([(0, 1), (1, 0), (1, 2), (2, 3), (3, 2), (3, 2), (3, 0)]);
+        let simple =
+            UnGraph::<(), f64>::from_edges([(0, 1, 2.), (1, 2, 1.), (2, 3, 3.), (3, 0, 1.)]);
+        let communities = [nodes(&[0, 1]), nodes(&[2, 3])];
+        let expected: Result<Option<f64>> =
+            modularity(&simple, &communities, |e| Ok(*e.weight()), 1.);
+        let expected = expected.unwrap().unwrap();
+        let res = unweighted(&multigraph, &communities).unwrap();
+        assert!((res - expected).abs() < 1e-12);
+        // (2 + 3) / 7 - ((6 / 14)^2 + (8 / 14)^2)
+        assert!((res - (5. / 7. - 100. / 196.)).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_self_loops() {
+        let graph = UnGraph::<(), ()>::from_edges([(0, 0), (0, 1), (1, 2)]);
+        let res = unweighted(&graph, &[nodes(&[0]), nodes(&[1, 2])]).unwrap();
+        // Node 0 has degree 3, nodes 1 and 2 have degree 2 and 1
+        assert!((res - (2. / 3. - (9. + 9.) / 36.)).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_directed() {
+        let graph = DiGraph::<(), ()>::from_edges([(0, 1), (1, 0), (1, 2), (2, 3), (3, 2)]);
+        let res = unweighted(&graph, &[nodes(&[0, 1]), nodes(&[2, 3])]).unwrap();
+        // Out-degrees 3 and 2, in-degrees 2 and 3
+        assert!((res - (4. / 5. - 12. / 25.)).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_resolution() {
+        let graph = UnGraph::<(), ()>::from_edges([(0, 1), (1, 2)]);
+        let res: Result<Option<f64>> = modularity(&graph, &[nodes(&[0, 1, 2])], |_| Ok(1.), 0.5);
+        assert_eq!(res.unwrap(), Some(0.5));
+    }
+
+    #[test]
+    fn test_removed_nodes() {
+        let mut graph = StableUnGraph::<(), ()>::default();
+        let a = graph.add_node(());
+        let b = graph.add_node(());
+        let c = graph.add_node(());
+        graph.add_edge(a, c, ());
+        graph.remove_node(b);
+        assert_eq!(unweighted(&graph, &[vec![a], vec![b, c]]), None);
+        assert!(unweighted(&graph, &[vec![a], vec![c]]).is_some());
+    }
+
+    #[test]
+    fn test_sum_parallel_edges_undirected() {
+        let graph = UnGraph::<(), f64>::from_edges([
+            (0, 1, 1.),
+            (2, 2, 1.),
+            (1, 2, 2.),
+            (1, 0, 3.),
+            (2, 2, 5.),
+        ]);
+        let res: Result<DictMap<(NodeIndex, NodeIndex), f64>> =
+            sum_parallel_edges(&graph, |e| Ok(*e.weight()), f64::max);
+        let res: Vec<((usize, usize), f64)> = res
+            .unwrap()
+            .into_iter()
+            .map(|((a, b), w)| ((a.index(), b.index()), w))
+            .collect();
+        assert_eq!(res, vec![((0, 1), 3.), ((2, 2), 5.), ((1, 2), 2.)]);
+    }
+
+    #[test]
+    fn test_sum_parallel_edges_directed() {
+        let graph = DiGraph::<(), f64>::from_edges([(0, 1, 1.), (1, 0, 2.), (0, 1, 3.)]);
+        let res: Result<DictMap<(NodeIndex, NodeIndex), f64>> =
+            sum_parallel_edges(&graph, |e| Ok(*e.weight()), |a, b| a + b);
+        let res: Vec<((usize, usize), f64)> = res
+            .unwrap()
+            .into_iter()
+            .map(|((a, b), w)| ((a.index(), b.index()), w))
+            .collect();
+        assert_eq!(res, vec![((0, 1), 4.), ((1, 0), 2.)]);
+    }
+}
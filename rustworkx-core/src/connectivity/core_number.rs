@@ -12,10 +12,10 @@
 
 use std::hash::Hash;
 
+use crate::par::*;
 use hashbrown::{HashMap, HashSet};
 use petgraph::visit::{GraphBase, IntoNeighborsDirected, IntoNodeIdentifiers, NodeCount};
 use petgraph::Direction::{Incoming, Outgoing};
-use rayon::prelude::*;
 
 use crate::dictmap::*;
 
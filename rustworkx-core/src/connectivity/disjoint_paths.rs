@@ -0,0 +1,338 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+use petgraph::visit::{EdgeRef, GraphProp, IntoEdgeReferences, NodeIndexable};
+
+use crate::flow::FlowNetwork;
+
+/// Send up to `k` units of flow from `source` to `sink` and split the flow
+/// into paths of network nodes.
+///
+/// Every arc of a graph edge must have a positive cost, so that the flow
+/// has no cycles.
+fn flow_paths(
+    mut network: FlowNetwork,
+    source: usize,
+    sink: usize,
+    k: Option<usize>,
+) -> Vec<Vec<usize>> {
+    let mut count = 0;
+    while k.map_or(true, |k| count < k) && network.augment(source, sink, |_| true) {
+        count += 1;
+    }
+    // The arcs of every node that carry flow, which are the forward arcs
+    // whose reverse arcs have a residual capacity
+    let mut flow_arcs: Vec<Vec<usize>> = vec![Vec::new(); network.adjacency.len()];
+    for arc in (0..network.arcs.len()).step_by(2) {
+        for _ in 0..network.arcs[arc ^ 1].1 {
+            flow_arcs[network.arcs[arc ^ 1].0].push(arc);
+        }
+    }
+    for arcs in flow_arcs.iter_mut() {
+        arcs.reverse();
+    }
+    (0..count)
+        .map(|_| {
+            let mut path = vec![source];
+            let mut node = source;
+            while node != sink {
+                node = network.arcs[flow_arcs[node].pop().unwrap()].0;
+                path.push(node);
+            }
+            path
+        })
+        .collect()
+}
+
+/// Find a largest set of edge-disjoint paths between two nodes.
+///
+/// The paths don't share any edge, but they can share nodes. By Menger's
+/// theorem the largest number of such paths is the size of a minimum edge
+/// cut between the two nodes. The paths are found as a minimum cost flow
+/// from `source` to `target` where every edge has a capacity of 1 and a cost
+/// of 1, solved with successive shortest paths, so among the sets of
+/// edge-disjoint paths of their size they have the smallest total number of
+/// edges. This runs in `O(k |E| log |V|)` time for `k` paths.
+///
+/// The edges of an undirected graph can be used in either direction.
+///
+/// Arguments:
+///
+/// * `graph` - The graph to find the paths in.
+/// * `source` - The node the paths start at.
+/// * `target` - The node the paths end at.
+/// * `k` - The largest number of paths to find. If it's `None` as many
+///   paths as possible are found.
+///
+/// Returns the paths as lists of nodes from `source` to `target`. If
+/// `source` and `target` are the same node there are no paths.
+///
+/// # Example
+/// ```rust
+/// use rustworkx_core::connectivity::edge_disjoint_paths;
+/// use rustworkx_core::petgraph::graph::{NodeIndex, UnGraph};
+///
+/// // Two triangles that share node 2
+/// let graph = UnGraph::<(), ()>::from_edges([(0, 1), (1, 2), (2, 0), (2, 3), (3, 4), (4, 2)]);
+/// let paths = edge_disjoint_paths(&graph, NodeIndex::new(0), NodeIndex::new(4), None);
+/// assert_eq!(paths.len(), 2);
+/// ```
+pub fn edge_disjoint_paths<G>(
+    graph: G,
+    source: G::NodeId,
+    target: G::NodeId,
+    k: Option<usize>,
+) -> Vec<Vec<G::NodeId>>
+where
+    G: IntoEdgeReferences + NodeIndexable + GraphProp,
+{
+    let source = graph.to_index(source);
+    let target = graph.to_index(target);
+    if source == target {
+        return Vec::new();
+    }
+    let mut network = FlowNetwork::new(graph.node_bound());
+    for edge in graph.edge_references() {
+        let tail = graph.to_index(edge.source());
+        let head = graph.to_index(edge.target());
+        network.add_arc(tail, head, 1, 1);
+        if !graph.is_directed() {
+            network.add_arc(head, tail, 1, 1);
+        }
+    }
+    flow_paths(network, source, target, k)
+        .into_iter()
+        .map(|path| {
+            path.into_iter()
+                .map(|node| graph.from_index(node))
+                .collect()
+        })
+        .collect()
+}
+
+/// Find a largest set of node-disjoint paths between two nodes.
+///
+/// The paths don't share any node other than `source` and `target`, which
+/// are also called internally disjoint paths. By Menger's theorem the
+/// largest number of such paths is the size of a minimum node cut between
+/// the two nodes, plus one if they're adjacent. Every node is split into an
+/// entry and an exit joined by an arc of capacity 1, and the paths are found
+/// as a minimum cost flow from `source` to `target` where every edge has a
+/// cost of 1, solved with successive shortest paths, so among the sets of
+/// node-disjoint paths of their size they have the smallest total number of
+/// edges. This runs in `O(k |E| log |V|)` time for `k` paths.
+///
+/// The edges of an undirected graph can be used in either direction. If
+/// there are parallel edges between `source` and `target` only one path
+/// uses them.
+///
+/// Arguments:
+///
+/// * `graph` - The graph to find the paths in.
+/// * `source` - The node the paths start at.
+/// * `target` - The node the paths end at.
+/// * `k` - The largest number of paths to find. If it's `None` as many
+///   paths as possible are found.
+///
+/// Returns the paths as lists of nodes from `source` to `target`. If
+/// `source` and `target` are the same node there are no paths.
+///
+/// # Example
+/// ```rust
+/// use rustworkx_core::connectivity::node_disjoint_paths;
+/// use rustworkx_core::petgraph::graph::{NodeIndex, UnGraph};
+///
+/// // Two triangles that share node 2
+/// let graph = UnGraph::<(), ()>::from_edges([(0, 1), (1, 2), (2, 0), (2, 3), (3, 4), (4, 2)]);
+/// let paths = node_disjoint_paths(&graph, NodeIndex::new(0), NodeIndex::new(4), None);
+/// assert_eq!(paths.len(), 1);
+/// ```
+pub fn node_disjoint_paths<G>(
+    graph: G,
+    source: G::NodeId,
+    target: G::NodeId,
+    k: Option<usize>,
+) -> Vec<Vec<G::NodeId>>
+where
+    G: IntoEdgeReferences + NodeIndexable + GraphProp,
+{
+    let source = graph.to_index(source);
+    let target = graph.to_index(target);
+    if source == target {
+        return Vec::new();
+    }
+    // Node n of the graph is split into the entry 2n and the exit 2n + 1 of
+    // the network. The paths leave the exit of the source and end at the
+    // entry of the target, which aren't split.
+    let mut network = FlowNetwork::new(2 * graph.node_bound());
+    for node in 0..graph.node_bound() {
+        if node != source && node != target {
+            network.add_arc(2 * node, 2 * node + 1, 1, 0);
+        }
+    }
+    let mut direct = false;
+    let mut add_edge = |network: &mut FlowNetwork, tail: usize, head: usize| {
+        if tail == source && head == target {
+            if direct {
+                return;
+            }
+            direct = true;
+        }
+        network.add_arc(2 * tail + 1, 2 * head, 1, 1);
+    };
+    for edge in graph.edge_references() {
+        let tail = graph.to_index(edge.source());
+        let head = graph.to_index(edge.target());
+        add_edge(&mut network, tail, head);
+        if !graph.is_directed() {
+            add_edge(&mut network, head, tail);
+        }
+    }
+    flow_paths(network, 2 * source + 1, 2 * target, k)
+        .into_iter()
+        .map(|path| {
+            // Every node of the graph other than the ends appears as its
+            // entry followed by its exit
+            let mut nodes: Vec<usize> = path.into_iter().map(|node| node / 2).collect();
+            nodes.dedup();
+            nodes
+                .into_iter()
+                .map(|node| graph.from_index(node))
+                .collect()
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test_disjoint_paths {
+    use super::{edge_disjoint_paths, node_disjoint_paths};
+    use crate::generators::{complete_graph, grid_graph, petersen_graph};
+    use crate::petgraph::graph::{DiGraph, NodeIndex, UnGraph};
+    use crate::petgraph::visit::{EdgeRef, GraphProp, IntoEdgeReferences, NodeIndexable};
+    use hashbrown::HashSet;
+
+    /// Check that the paths are paths of the graph between the two nodes
+    /// with no repeated edges (or internal nodes if `nodes` is set).
+    fn check_paths<G>(graph: G, paths: &[Vec<G::NodeId>], source: usize, target: usize, nodes: bool)
+    where
+        G: IntoEdgeReferences + NodeIndexable + GraphProp,
+    {
+        let mut edges: HashSet<(usize, usize)> = HashSet::new();
+        for edge in graph.edge_references() {
+            let (a, b) = (graph.to_index(edge.source()), graph.to_index(edge.target()));
+            edges.insert((a, b));
+            if !graph.is_directed() {
+                edges.insert((b, a));
+            }
+        }
+        let mut used_edges: HashSet<(usize, usize)> = HashSet::new();
+        let mut used_nodes: HashSet<usize> = HashSet::new();
+        for path in paths {
+            let path: Vec<usize> = path.iter().map(|n| graph.to_index(*n)).collect();
+            assert_eq!(path[0], source);
+            assert_eq!(path[path.len() - 1], target);
+            for pair in path.windows(2) {
+                assert!(edges.contains(&(pair[0], pair[1])));
+                let key = if graph.is_directed() {
+                    (pair[0], pair[1])
+                } else {
+                    (pair[0].min(pair[1]), pair[0].max(pair[1]))
+                };
+                assert!(used_edges.insert(key));
+            }
+            if nodes {
+                for node in &path[1..path.len() - 1] {
+                    assert!(used_nodes.insert(*node));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_complete_graph() {
+        let graph: UnGraph<(), ()> = complete_graph(Some(6), None, || (), || ()).unwrap();
+        let (s, t) = (NodeIndex::new(0), NodeIndex::new(5));
+        let paths = edge_disjoint_paths(&graph, s, t, None);
+        assert_eq!(paths.len(), 5);
+        check_paths(&graph, &paths, 0, 5, false);
+        let paths = node_disjoint_paths(&graph, s, t, None);
+        assert_eq!(paths.len(), 5);
+        check_paths(&graph, &paths, 0, 5, true);
+        // The direct edge and then paths through one other node
+        let lengths: Vec<usize> = paths.iter().map(|path| path.len()).collect();
+        assert_eq!(lengths.iter().sum::<usize>(), 2 + 4 * 3);
+    }
+
+    #[test]
+    fn test_bowtie() {
+        let graph = UnGraph::<(), ()>::from_edges([(0, 1), (1, 2), (2, 0), (2, 3), (3, 4), (4, 2)]);
+        let (s, t) = (NodeIndex::new(0), NodeIndex::new(4));
+        let paths = edge_disjoint_paths(&graph, s, t, None);
+        assert_eq!(paths.len(), 2);
+        check_paths(&graph, &paths, 0, 4, false);
+        let paths = node_disjoint_paths(&graph, s, t, None);
+        assert_eq!(paths.len(), 1);
+        check_paths(&graph, &paths, 0, 4, true);
+    }
+
+    #[test]
+    fn test_petersen_and_grid() {
+        let graph: UnGraph<(), ()> = petersen_graph(5, 2, || (), || ()).unwrap();
+        for target in 1..10 {
+            let (s, t) = (NodeIndex::new(0), NodeIndex::new(target));
+            let paths = node_disjoint_paths(&graph, s, t, None);
+            assert_eq!(paths.len(), 3);
+            check_paths(&graph, &paths, 0, target, true);
+        }
+        let graph: UnGraph<(), ()> =
+            grid_graph(Some(4), Some(4), None, || (), || (), false).unwrap();
+        let (s, t) = (NodeIndex::new(5), NodeIndex::new(10));
+        let paths = edge_disjoint_paths(&graph, s, t, None);
+        assert_eq!(paths.len(), 4);
+        check_paths(&graph, &paths, 5, 10, false);
+        let paths = edge_disjoint_paths(&graph, s, t, Some(2));
+        assert_eq!(paths.len(), 2);
+        // The two shortest paths have 2 edges
+        assert!(paths.iter().all(|path| path.len() == 3));
+    }
+
+    #[test]
+    fn test_directed() {
+        let graph = DiGraph::<(), ()>::from_edges([(0, 1), (1, 3), (0, 2), (2, 3), (3, 0), (1, 2)]);
+        let (s, t) = (NodeIndex::new(0), NodeIndex::new(3));
+        let paths = edge_disjoint_paths(&graph, s, t, None);
+        assert_eq!(paths.len(), 2);
+        check_paths(&graph, &paths, 0, 3, false);
+        assert!(edge_disjoint_paths(&graph, t, s, None).len() == 1);
+        assert!(node_disjoint_paths(&graph, NodeIndex::new(2), NodeIndex::new(1), None).len() == 1);
+    }
+
+    #[test]
+    fn test_parallel_edges() {
+        let graph = UnGraph::<(), ()>::from_edges([(0, 1), (0, 1), (0, 1), (0, 2), (2, 1)]);
+        let (s, t) = (NodeIndex::new(0), NodeIndex::new(1));
+        assert_eq!(edge_disjoint_paths(&graph, s, t, None).len(), 4);
+        let paths = node_disjoint_paths(&graph, s, t, None);
+        assert_eq!(paths.len(), 2);
+        check_paths(&graph, &paths, 0, 1, true);
+    }
+
+    #[test]
+    fn test_disconnected_and_same_node() {
+        let graph = UnGraph::<(), ()>::from_edges([(0, 1), (2, 3)]);
+        let (s, t) = (NodeIndex::new(0), NodeIndex::new(3));
+        assert!(edge_disjoint_paths(&graph, s, t, None).is_empty());
+        assert!(node_disjoint_paths(&graph, s, t, None).is_empty());
+        assert!(edge_disjoint_paths(&graph, s, s, None).is_empty());
+        assert!(node_disjoint_paths(&graph, s, s, Some(3)).is_empty());
+    }
+}
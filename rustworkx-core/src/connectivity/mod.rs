@@ -18,9 +18,13 @@ mod chain;
 mod conn_components;
 mod core_number;
 mod cycle_basis;
+mod disjoint_paths;
 mod find_cycle;
 mod isolates;
 mod min_cut;
+mod onion;
+mod s_core;
+mod truss;
 
 pub use all_simple_paths::{
     all_simple_paths_multiple_targets, longest_simple_path_multiple_targets,
@@ -33,6 +37,10 @@ pub use conn_components::connected_components;
 pub use conn_components::number_connected_components;
 pub use core_number::core_number;
 pub use cycle_basis::cycle_basis;
+pub use disjoint_paths::{edge_disjoint_paths, node_disjoint_paths};
 pub use find_cycle::find_cycle;
 pub use isolates::isolates;
 pub use min_cut::stoer_wagner_min_cut;
+pub use onion::onion_decomposition;
+pub use s_core::s_core_number;
+pub use truss::{k_truss, truss_number};
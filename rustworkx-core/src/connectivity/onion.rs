@@ -0,0 +1,239 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+use std::hash::Hash;
+
+use hashbrown::HashSet;
+use petgraph::visit::{IntoNeighborsDirected, IntoNodeIdentifiers, NodeIndexable};
+use petgraph::Direction::{Incoming, Outgoing};
+
+use crate::dictmap::*;
+
+/// Return the core number and the onion layer of every node in the graph.
+///
+/// The onion decomposition refines the k-core decomposition: the nodes are
+/// removed in layers, where every layer is made up of all the nodes whose
+/// degree in the remaining graph is at most the current core number. The
+/// core number of a node is the core number at the time it's removed, and
+/// its layer is the number of the round it's removed in, starting at 1.
+/// Isolated nodes make up the first layer and have a core number of 0.
+/// Nodes in higher layers of the same core are more deeply embedded in it,
+/// which makes the layers useful to show the core-periphery structure of
+/// a graph.
+///
+/// The degree of a node is the number of distinct nodes adjacent to it,
+/// ignoring the direction of the edges, so self loops and parallel edges
+/// don't contribute to it.
+///
+/// Arguments:
+///
+/// * `graph` - The graph to compute the onion decomposition of.
+///
+/// Returns a tuple of the core number and the onion layer of every node.
+///
+/// # Example
+/// ```rust
+/// use rustworkx_core::connectivity::onion_decomposition;
+/// use rustworkx_core::petgraph::graph::{NodeIndex, UnGraph};
+///
+/// // A triangle with a path of two nodes attached to node 2
+/// let graph = UnGraph::<(), ()>::from_edges(&[(0, 1), (1, 2), (2, 0), (2, 3), (3, 4)]);
+/// let (cores, layers) = onion_decomposition(&graph);
+/// let cores: Vec<usize> = (0..5).map(|n| cores[&NodeIndex::new(n)]).collect();
+/// let layers: Vec<usize> = (0..5).map(|n| layers[&NodeIndex::new(n)]).collect();
+/// assert_eq!(cores, vec![2, 2, 2, 1, 1]);
+/// assert_eq!(layers, vec![3, 3, 3, 2, 1]);
+/// ```
+pub fn onion_decomposition<G>(graph: G) -> (DictMap<G::NodeId, usize>, DictMap<G::NodeId, usize>)
+where
+    G: IntoNodeIdentifiers + IntoNeighborsDirected + NodeIndexable,
+    G::NodeId: Eq + Hash,
+{
+    let node_bound = graph.node_bound();
+    let mut neighbors: Vec<HashSet<usize>> = vec![HashSet::new(); node_bound];
+    for node in graph.node_identifiers() {
+        let index = graph.to_index(node);
+        neighbors[index] = graph
+            .neighbors_directed(node, Outgoing)
+            .chain(graph.neighbors_directed(node, Incoming))
+            .map(|neighbor| graph.to_index(neighbor))
+            .filter(|neighbor| *neighbor != index)
+            .collect();
+    }
+    let mut degree: Vec<usize> = neighbors.iter().map(|n| n.len()).collect();
+    let max_degree = degree.iter().copied().max().unwrap_or(0);
+    // Nodes are added to the bucket of every degree they have while they're
+    // in the graph, the stale entries are skipped when a bucket is read
+    let mut buckets: Vec<Vec<usize>> = vec![Vec::new(); max_degree + 1];
+    let mut removed: Vec<bool> = vec![true; node_bound];
+    for node in graph.node_identifiers() {
+        let index = graph.to_index(node);
+        removed[index] = false;
+        buckets[degree[index]].push(index);
+    }
+    let mut remaining = removed.iter().filter(|r| !**r).count();
+
+    let mut core: Vec<usize> = vec![0; node_bound];
+    let mut layer: Vec<usize> = vec![0; node_bound];
+    let mut current_layer = 1;
+    // Isolated nodes are their own layer
+    let mut this_layer: Vec<usize> = std::mem::take(&mut buckets[0]);
+    let mut current_core = 0;
+    let mut min_degree = 1;
+    while remaining > 0 {
+        if this_layer.is_empty() {
+            // No remaining node has a degree of at most the current core, so
+            // the next core is the minimum degree of the remaining nodes
+            while !buckets[min_degree]
+                .iter()
+                .any(|node| !removed[*node] && degree[*node] == min_degree)
+            {
+                buckets[min_degree].clear();
+                min_degree += 1;
+            }
+            current_core = min_degree;
+            this_layer = std::mem::take(&mut buckets[min_degree]);
+        }
+        // Decide the whole layer before removing any of its nodes
+        this_layer.retain(|node| !removed[*node] && degree[*node] <= current_core);
+        for node in this_layer.iter() {
+            removed[*node] = true;
+        }
+        this_layer.sort_unstable();
+        this_layer.dedup();
+        let mut next_layer: Vec<usize> = Vec::new();
+        for node in this_layer {
+            core[node] = current_core;
+            layer[node] = current_layer;
+            remaining -= 1;
+            for neighbor in std::mem::take(&mut neighbors[node]) {
+                if removed[neighbor] {
+                    continue;
+                }
+                neighbors[neighbor].remove(&node);
+                degree[neighbor] -= 1;
+                if degree[neighbor] <= current_core {
+                    next_layer.push(neighbor);
+                } else {
+                    buckets[degree[neighbor]].push(neighbor);
+                }
+            }
+        }
+        current_layer += 1;
+        this_layer = next_layer;
+        if current_core == 0 {
+            current_core = 1;
+        }
+    }
+
+    let mut cores: DictMap<G::NodeId, usize> = DictMap::with_capacity(node_bound);
+    let mut layers: DictMap<G::NodeId, usize> = DictMap::with_capacity(node_bound);
+    for node in graph.node_identifiers() {
+        let index = graph.to_index(node);
+        cores.insert(node, core[index]);
+        layers.insert(node, layer[index]);
+    }
+    (cores, layers)
+}
+
+#[cfg(test)]
+mod test_onion {
+    use crate::connectivity::{core_number, onion_decomposition};
+    use petgraph::prelude::*;
+
+    fn values(map: &crate::dictmap::DictMap<NodeIndex, usize>) -> Vec<usize> {
+        map.values().copied().collect()
+    }
+
+    #[test]
+    fn test_empty() {
+        let graph = UnGraph::<(), ()>::default();
+        let (cores, layers) = onion_decomposition(&graph);
+        assert!(cores.is_empty());
+        assert!(layers.is_empty());
+    }
+
+    #[test]
+    fn test_isolated_nodes() {
+        let mut graph = UnGraph::<(), ()>::from_edges([(0, 1)]);
+        graph.add_node(());
+        let (cores, layers) = onion_decomposition(&graph);
+        assert_eq!(values(&cores), vec![1, 1, 0]);
+        assert_eq!(values(&layers), vec![2, 2, 1]);
+    }
+
+    #[test]
+    fn test_path() {
+        // The ends of a path are peeled first, then the next nodes in
+        let graph = UnGraph::<(), ()>::from_edges([(0, 1), (1, 2), (2, 3), (3, 4)]);
+        let (cores, layers) = onion_decomposition(&graph);
+        assert_eq!(values(&cores), vec![1; 5]);
+        assert_eq!(values(&layers), vec![1, 2, 3, 2, 1]);
+    }
+
+    #[test]
+    fn test_paper_example() {
+        // The example graph of figure 1 in Batagelj and Zaversnik's paper
+        // An O(m) Algorithm for Cores Decomposition of Networks, 2003
+        let edge_list = [
+            (0, 2),
+            (0, 3),
+            (0, 5),
+            (1, 4),
+            (1, 6),
+            (1, 7),
+            (2, 3),
+            (3, 5),
+            (2, 5),
+            (5, 6),
+            (4, 6),
+            (4, 7),
+            (6, 7),
+            (5, 8),
+            (6, 8),
+            (6, 9),
+            (8, 9),
+            (0, 10),
+            (1, 10),
+            (1, 11),
+            (10, 11),
+            (12, 13),
+            (13, 15),
+            (14, 15),
+            (12, 14),
+            (8, 19),
+            (11, 16),
+            (11, 17),
+            (12, 18),
+        ];
+        let mut graph = UnGraph::<(), ()>::from_edges(edge_list);
+        graph.add_node(());
+        let (cores, layers) = onion_decomposition(&graph);
+        assert_eq!(cores, core_number(&graph));
+        assert_eq!(
+            values(&layers),
+            vec![5, 5, 5, 5, 5, 6, 6, 5, 4, 3, 4, 3, 3, 3, 3, 3, 2, 2, 2, 2, 1]
+        );
+    }
+
+    #[test]
+    fn test_directed_and_removed_nodes() {
+        let mut graph = StableDiGraph::<(), ()>::from_edges([(0, 1), (1, 2), (2, 0), (2, 3)]);
+        graph.add_edge(NodeIndex::new(1), NodeIndex::new(0), ());
+        graph.add_edge(NodeIndex::new(1), NodeIndex::new(1), ());
+        graph.add_node(());
+        graph.remove_node(NodeIndex::new(3));
+        let (cores, layers) = onion_decomposition(&graph);
+        assert_eq!(values(&cores), vec![2, 2, 2, 0]);
+        assert_eq!(values(&layers), vec![2, 2, 2, 1]);
+    }
+}
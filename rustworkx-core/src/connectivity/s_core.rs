@@ -0,0 +1,217 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+use std::collections::BinaryHeap;
+use std::hash::Hash;
+
+use petgraph::visit::{EdgeRef, IntoEdgeReferences, IntoNodeIdentifiers, NodeIndexable};
+
+use crate::dictmap::*;
+use crate::min_scored::MinScored;
+
+/// Return the s-core number of every node in a weighted graph.
+///
+/// The s-core decomposition [^Eidsaa2013] generalizes the k-core
+/// decomposition to weighted graphs by using the strength of a node, the
+/// total weight of its edges, instead of its degree. An s-core is a maximal
+/// subgraph in which every node has a strength of at least `s`, and the
+/// s-core number of a node is the largest `s` of an s-core that contains it.
+/// Nodes are removed in increasing order of their strength in the remaining
+/// graph, so the decomposition takes `O((|V| + |E|) log |V|)` time.
+///
+/// The strength of a node ignores the direction of the edges, the weights
+/// of parallel edges add up, and self loops don't contribute to it. The
+/// weights have to be non-negative, with unit weights the s-core numbers
+/// of a simple graph are its core numbers.
+///
+/// Arguments:
+///
+/// * `graph` - The graph to compute the s-core numbers of.
+/// * `weight_fn` - A callable that returns the non-negative weight of an
+///   edge, or an error that is returned by this function.
+///
+/// Returns the s-core number of every node.
+///
+/// # Example
+/// ```rust
+/// use rustworkx_core::connectivity::s_core_number;
+/// use rustworkx_core::petgraph::graph::{NodeIndex, UnGraph};
+/// use std::convert::Infallible;
+///
+/// // A heavy triangle with a light edge to node 3
+/// let graph = UnGraph::<(), f64>::from_edges(&[(0, 1, 3.), (1, 2, 3.), (2, 0, 3.), (2, 3, 1.)]);
+/// let cores = s_core_number(&graph, |edge| Ok::<f64, Infallible>(*edge.weight())).unwrap();
+/// let cores: Vec<f64> = (0..4).map(|n| cores[&NodeIndex::new(n)]).collect();
+/// assert_eq!(cores, vec![6., 6., 6., 1.]);
+/// ```
+///
+/// [^Eidsaa2013]: M. Eidsaa and E. Almaas, "s-core network decomposition: A
+///     generalization of k-core analysis to weighted networks", Physical
+///     Review E 88, 062819 (2013).
+pub fn s_core_number<G, F, E>(graph: G, mut weight_fn: F) -> Result<DictMap<G::NodeId, f64>, E>
+where
+    G: IntoEdgeReferences + IntoNodeIdentifiers + NodeIndexable,
+    G::NodeId: Eq + Hash,
+    F: FnMut(G::EdgeRef) -> Result<f64, E>,
+{
+    let node_bound = graph.node_bound();
+    let mut neighbors: Vec<Vec<(usize, f64)>> = vec![Vec::new(); node_bound];
+    let mut strength: Vec<f64> = vec![0.; node_bound];
+    for edge in graph.edge_references() {
+        let weight = weight_fn(edge)?;
+        let source = graph.to_index(edge.source());
+        let target = graph.to_index(edge.target());
+        if source == target {
+            continue;
+        }
+        neighbors[source].push((target, weight));
+        neighbors[target].push((source, weight));
+        strength[source] += weight;
+        strength[target] += weight;
+    }
+
+    // Nodes are pushed again every time their strength drops, the stale
+    // entries are skipped when they're popped
+    let mut heap: BinaryHeap<MinScored<f64, usize>> = BinaryHeap::with_capacity(node_bound);
+    let mut removed: Vec<bool> = vec![true; node_bound];
+    for node in graph.node_identifiers() {
+        let index = graph.to_index(node);
+        removed[index] = false;
+        heap.push(MinScored(strength[index], index));
+    }
+    let mut core: Vec<f64> = vec![0.; node_bound];
+    let mut current_core: f64 = 0.;
+    while let Some(MinScored(node_strength, node)) = heap.pop() {
+        if removed[node] || node_strength != strength[node] {
+            continue;
+        }
+        removed[node] = true;
+        current_core = current_core.max(node_strength);
+        core[node] = current_core;
+        for &(neighbor, weight) in neighbors[node].iter() {
+            if !removed[neighbor] {
+                strength[neighbor] -= weight;
+                heap.push(MinScored(strength[neighbor], neighbor));
+            }
+        }
+    }
+
+    let mut cores: DictMap<G::NodeId, f64> = DictMap::with_capacity(node_bound);
+    for node in graph.node_identifiers() {
+        cores.insert(node, core[graph.to_index(node)]);
+    }
+    Ok(cores)
+}
+
+#[cfg(test)]
+mod test_s_core {
+    use crate::connectivity::{core_number, s_core_number};
+    use petgraph::prelude::*;
+    use std::convert::Infallible;
+
+    fn unit_weight<E>(_: E) -> Result<f64, Infallible> {
+        Ok(1.)
+    }
+
+    fn values(map: &crate::dictmap::DictMap<NodeIndex, f64>) -> Vec<f64> {
+        map.values().copied().collect()
+    }
+
+    #[test]
+    fn test_empty() {
+        let graph = UnGraph::<(), ()>::default();
+        assert!(s_core_number(&graph, unit_weight).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_isolated_nodes() {
+        let mut graph = UnGraph::<(), ()>::from_edges([(0, 1)]);
+        graph.add_node(());
+        let cores = s_core_number(&graph, unit_weight).unwrap();
+        assert_eq!(values(&cores), vec![1., 1., 0.]);
+    }
+
+    #[test]
+    fn test_unit_weights_match_core_number() {
+        let graph = UnGraph::<(), ()>::from_edges([
+            (0, 1),
+            (0, 2),
+            (0, 3),
+            (1, 2),
+            (1, 3),
+            (2, 3),
+            (3, 4),
+            (4, 5),
+            (4, 6),
+            (5, 6),
+            (6, 7),
+        ]);
+        let cores = s_core_number(&graph, unit_weight).unwrap();
+        let expected = core_number(&graph);
+        for (node, core) in cores {
+            assert_eq!(core, expected[&node] as f64);
+        }
+    }
+
+    #[test]
+    fn test_weights() {
+        // A light triangle with a heavy edge between nodes 1 and 2
+        let graph = UnGraph::<(), f64>::from_edges([(0, 1, 1.), (1, 2, 5.), (2, 0, 1.)]);
+        let cores = s_core_number(&graph, |edge| Ok::<f64, Infallible>(*edge.weight())).unwrap();
+        assert_eq!(values(&cores), vec![2., 5., 5.]);
+    }
+
+    #[test]
+    fn test_core_is_monotone() {
+        // Removing the light leaves 2 and 3 drops the strength of both
+        // nodes 0 and 1 to 3, and removing one of them leaves the other with
+        // a strength of 0, which is still in the 3-core
+        let graph = UnGraph::<(), f64>::from_edges([(0, 1, 3.), (1, 2, 1.), (0, 3, 1.)]);
+        let cores = s_core_number(&graph, |edge| Ok::<f64, Infallible>(*edge.weight())).unwrap();
+        assert_eq!(values(&cores), vec![3., 3., 1., 1.]);
+    }
+
+    #[test]
+    fn test_parallel_edges_and_self_loops() {
+        let graph = UnGraph::<(), f64>::from_edges([(0, 1, 2.), (0, 1, 3.), (1, 1, 10.)]);
+        let cores = s_core_number(&graph, |edge| Ok::<f64, Infallible>(*edge.weight())).unwrap();
+        assert_eq!(values(&cores), vec![5., 5.]);
+    }
+
+    #[test]
+    fn test_directed_ignores_direction() {
+        let graph = DiGraph::<(), f64>::from_edges([(0, 1, 2.), (1, 2, 2.), (2, 0, 2.)]);
+        let cores = s_core_number(&graph, |edge| Ok::<f64, Infallible>(*edge.weight())).unwrap();
+        assert_eq!(values(&cores), vec![4., 4., 4.]);
+    }
+
+    #[test]
+    fn test_removed_nodes() {
+        let mut graph = StableUnGraph::<(), f64>::default();
+        let nodes: Vec<NodeIndex> = (0..4).map(|_| graph.add_node(())).collect();
+        graph.add_edge(nodes[1], nodes[2], 2.);
+        graph.add_edge(nodes[2], nodes[3], 2.);
+        graph.remove_node(nodes[0]);
+        let cores = s_core_number(&graph, |edge| Ok::<f64, Infallible>(*edge.weight())).unwrap();
+        assert_eq!(cores.len(), 3);
+        assert_eq!(values(&cores), vec![2., 2., 2.]);
+    }
+
+    #[test]
+    fn test_weight_error() {
+        let graph = UnGraph::<(), ()>::from_edges([(0, 1)]);
+        assert_eq!(
+            s_core_number(&graph, |_| Err("bad weight")),
+            Err("bad weight")
+        );
+    }
+}
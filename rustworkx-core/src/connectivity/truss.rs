@@ -0,0 +1,283 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+use std::hash::Hash;
+
+use crate::par::*;
+use hashbrown::HashMap;
+use petgraph::visit::{EdgeRef, IntoEdgeReferences, NodeIndexable};
+
+use crate::dictmap::*;
+
+/// Return the truss number of every edge of an undirected graph.
+///
+/// A k-truss is a maximal subgraph in which every edge is in at least
+/// `k - 2` triangles of the subgraph, and the truss number of an edge is the
+/// largest `k` for which the edge is in the k-truss. Every edge is in the
+/// 2-truss, so edges that aren't in any triangle have a truss number of 2.
+///
+/// The truss numbers are computed by peeling: at every level `k` the edges
+/// with at most `k - 2` triangles left are removed in batches until none are
+/// left. The initial triangle counts and the search for every batch are
+/// computed in parallel.
+///
+/// Self-loops are ignored and get a truss number of 2, and parallel edges are
+/// treated as a single edge that they all share the truss number of.
+///
+/// Arguments:
+///
+/// * `graph` - The undirected graph to compute the truss numbers for.
+///
+/// # Example
+/// ```rust
+/// use rustworkx_core::connectivity::truss_number;
+/// use rustworkx_core::petgraph::graph::{EdgeIndex, UnGraph};
+///
+/// // A complete graph on the nodes 0 to 3 with a pendant edge to node 4
+/// let graph = UnGraph::<(), ()>::from_edges(&[
+///     (0, 1), (0, 2), (0, 3), (1, 2), (1, 3), (2, 3), (3, 4),
+/// ]);
+/// let truss = truss_number(&graph);
+/// assert_eq!(truss[&EdgeIndex::new(0)], 4);
+/// assert_eq!(truss[&EdgeIndex::new(6)], 2);
+/// ```
+pub fn truss_number<G>(graph: G) -> DictMap<G::EdgeId, usize>
+where
+    G: IntoEdgeReferences + NodeIndexable,
+    G::EdgeId: Eq + Hash,
+{
+    // Map every edge of the graph to a simple edge between node indices
+    let mut adjacency: Vec<HashMap<usize, usize>> = vec![HashMap::new(); graph.node_bound()];
+    let mut endpoints: Vec<(usize, usize)> = Vec::new();
+    let mut simple_edge: Vec<Option<usize>> = Vec::new();
+    for edge in graph.edge_references() {
+        let source = graph.to_index(edge.source());
+        let target = graph.to_index(edge.target());
+        if source == target {
+            simple_edge.push(None);
+            continue;
+        }
+        let id = *adjacency[source].entry(target).or_insert_with(|| {
+            endpoints.push((source, target));
+            endpoints.len() - 1
+        });
+        adjacency[target].insert(source, id);
+        simple_edge.push(Some(id));
+    }
+
+    let common_neighbors = |adjacency: &[HashMap<usize, usize>], u: usize, v: usize| {
+        let (small, large) = if adjacency[u].len() <= adjacency[v].len() {
+            (u, v)
+        } else {
+            (v, u)
+        };
+        adjacency[small]
+            .iter()
+            .filter_map(|(w, small_edge)| {
+                adjacency[large]
+                    .get(w)
+                    .map(|large_edge| (*small_edge, *large_edge))
+            })
+            .collect::<Vec<(usize, usize)>>()
+    };
+    let mut support: Vec<usize> = endpoints
+        .par_iter()
+        .map(|(u, v)| common_neighbors(&adjacency, *u, *v).len())
+        .collect();
+
+    let mut truss: Vec<usize> = vec![2; endpoints.len()];
+    let mut removed: Vec<bool> = vec![false; endpoints.len()];
+    let mut remaining = endpoints.len();
+    let mut k = 2;
+    while remaining > 0 {
+        // Edges with at most k - 2 triangles are in the k-truss but not in
+        // the (k + 1)-truss
+        let mut batch: Vec<usize> = (0..endpoints.len())
+            .into_par_iter()
+            .filter(|edge| !removed[*edge] && support[*edge] + 2 <= k)
+            .collect();
+        while !batch.is_empty() {
+            let mut candidates: Vec<usize> = Vec::new();
+            for edge in batch {
+                if removed[edge] {
+                    continue;
+                }
+                let (u, v) = endpoints[edge];
+                for (first, second) in common_neighbors(&adjacency, u, v) {
+                    for other in [first, second] {
+                        support[other] -= 1;
+                        candidates.push(other);
+                    }
+                }
+                adjacency[u].remove(&v);
+                adjacency[v].remove(&u);
+                removed[edge] = true;
+                truss[edge] = k;
+                remaining -= 1;
+            }
+            // Only the edges whose triangles were removed can join the batch
+            batch = candidates
+                .into_par_iter()
+                .filter(|edge| !removed[*edge] && support[*edge] + 2 <= k)
+                .collect();
+            batch.par_sort_unstable();
+            batch.dedup();
+        }
+        k += 1;
+    }
+
+    graph
+        .edge_references()
+        .zip(simple_edge)
+        .map(|(edge, id)| (edge.id(), id.map_or(2, |id| truss[id])))
+        .collect()
+}
+
+/// Return the edges of the k-truss of an undirected graph.
+///
+/// The k-truss is the maximal subgraph in which every edge is in at least
+/// `k - 2` triangles of the subgraph. See [`truss_number`] for how self-loops
+/// and parallel edges are handled.
+///
+/// Arguments:
+///
+/// * `graph` - The undirected graph to find the k-truss of.
+/// * `k` - The order of the truss.
+///
+/// Returns the edges of the k-truss in the order of the edges of the graph.
+///
+/// # Example
+/// ```rust
+/// use rustworkx_core::connectivity::k_truss;
+/// use rustworkx_core::petgraph::graph::UnGraph;
+///
+/// // Two triangles sharing the edge (1, 2) and a pendant edge
+/// let graph = UnGraph::<(), ()>::from_edges(&[(0, 1), (0, 2), (1, 2), (1, 3), (2, 3), (3, 4)]);
+/// assert_eq!(k_truss(&graph, 3).len(), 5);
+/// assert!(k_truss(&graph, 4).is_empty());
+/// ```
+pub fn k_truss<G>(graph: G, k: usize) -> Vec<G::EdgeId>
+where
+    G: IntoEdgeReferences + NodeIndexable,
+    G::EdgeId: Eq + Hash,
+{
+    truss_number(graph)
+        .into_iter()
+        .filter(|(_, truss)| *truss >= k)
+        .map(|(edge, _)| edge)
+        .collect()
+}
+
+#[cfg(test)]
+mod test_truss {
+    use crate::connectivity::{k_truss, truss_number};
+    use crate::generators::complete_graph;
+    use petgraph::graph::{EdgeIndex, UnGraph};
+    use petgraph::stable_graph::StableUnGraph;
+
+    fn truss_values(graph: &UnGraph<(), ()>) -> Vec<usize> {
+        truss_number(graph).into_values().collect()
+    }
+
+    #[test]
+    fn test_empty() {
+        let graph = UnGraph::<(), ()>::default();
+        assert!(truss_number(&graph).is_empty());
+    }
+
+    #[test]
+    fn test_complete_graph() {
+        let graph: UnGraph<(), ()> = complete_graph(Some(6), None, || (), || ()).unwrap();
+        assert_eq!(truss_values(&graph), vec![6; 15]);
+    }
+
+    #[test]
+    fn test_tree() {
+        let graph = UnGraph::<(), ()>::from_edges([(0, 1), (1, 2), (1, 3), (3, 4)]);
+        assert_eq!(truss_values(&graph), vec![2; 4]);
+    }
+
+    #[test]
+    fn test_mixed() {
+        // A complete graph on 0 to 3, a triangle 3, 4, 5 and a pendant edge
+        let graph = UnGraph::<(), ()>::from_edges([
+            (0, 1),
+            (0, 2),
+            (0, 3),
+            (1, 2),
+            (1, 3),
+            (2, 3),
+            (3, 4),
+            (4, 5),
+            (5, 3),
+            (5, 6),
+        ]);
+        assert_eq!(truss_values(&graph), vec![4, 4, 4, 4, 4, 4, 3, 3, 3, 2]);
+    }
+
+    #[test]
+    fn test_cascading_removal() {
+        // A wheel with 5 spokes: every spoke is in two triangles and every
+        // rim edge in one, so all edges are removed at level 3
+        let graph = UnGraph::<(), ()>::from_edges([
+            (0, 1),
+            (0, 2),
+            (0, 3),
+            (0, 4),
+            (0, 5),
+            (1, 2),
+            (2, 3),
+            (3, 4),
+            (4, 5),
+            (5, 1),
+        ]);
+        assert_eq!(truss_values(&graph), vec![3; 10]);
+    }
+
+    #[test]
+    fn test_self_loops_and_parallel_edges() {
+        let graph = UnGraph::<(), ()>::from_edges([(0, 1), (1, 2), (2, 0), (0, 1), (2, 2)]);
+        assert_eq!(truss_values(&graph), vec![3, 3, 3, 3, 2]);
+    }
+
+    #[test]
+    fn test_removed_nodes() {
+        let mut graph =
+            StableUnGraph::<(), ()>::from_edges([(0, 1), (1, 2), (2, 0), (0, 3), (3, 1)]);
+        graph.remove_node(3.into());
+        let truss = truss_number(&graph);
+        assert_eq!(truss.len(), 3);
+        assert!(truss.values().all(|t| *t == 3));
+    }
+
+    #[test]
+    fn test_k_truss() {
+        let graph = UnGraph::<(), ()>::from_edges([
+            (0, 1),
+            (0, 2),
+            (0, 3),
+            (1, 2),
+            (1, 3),
+            (2, 3),
+            (3, 4),
+            (4, 5),
+            (5, 3),
+        ]);
+        assert_eq!(k_truss(&graph, 2).len(), 9);
+        assert_eq!(k_truss(&graph, 3).len(), 9);
+        assert_eq!(
+            k_truss(&graph, 4),
+            (0..6).map(EdgeIndex::new).collect::<Vec<_>>()
+        );
+        assert!(k_truss(&graph, 5).is_empty());
+    }
+}
@@ -0,0 +1,628 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+//! A compact, read-only graph stored in compressed sparse row (CSR) form.
+//!
+//! The adjacency lists of all the nodes are stored back to back in a few
+//! flat arrays, so traversing a [`CsrGraph`] reads contiguous memory instead
+//! of following the linked lists of edges of a petgraph graph. Converting a
+//! graph is linear in its size, which makes it worthwhile before running an
+//! algorithm that traverses a large graph many times, such as betweenness
+//! centrality. A [`CsrGraph`] keeps the node and edge indices of the graph
+//! it was built from, so the results of an algorithm on it can be used with
+//! the original graph directly.
+
+use std::iter::Copied;
+use std::marker::PhantomData;
+use std::ops::Range;
+use std::slice::Iter;
+
+use fixedbitset::FixedBitSet;
+use petgraph::visit::{
+    Data, EdgeCount, EdgeIndexable, EdgeRef, GraphBase, GraphProp, IntoEdgeReferences, IntoEdges,
+    IntoEdgesDirected, IntoNeighbors, IntoNeighborsDirected, IntoNodeIdentifiers, NodeCount,
+    NodeIndexable, Visitable,
+};
+use petgraph::{Directed, Direction, EdgeType};
+
+/// A compressed sparse row copy of the structure of a graph.
+///
+/// Every node of the original graph is identified by its index, and every
+/// edge by its index as given by [`EdgeIndexable`]. The weight of every edge
+/// is computed once when the graph is built, it can for example be a
+/// reference to the weight of the edge in the original graph. A directed
+/// graph stores both its outgoing and its incoming adjacency lists, so it
+/// supports [`IntoNeighborsDirected`] and [`IntoEdgesDirected`]. Every edge
+/// of an undirected graph is stored in the adjacency lists of both of its
+/// endpoints, except self loops which are only stored once.
+///
+/// # Example
+/// ```rust
+/// use rustworkx_core::csr::CsrGraph;
+/// use rustworkx_core::petgraph;
+/// use rustworkx_core::petgraph::visit::EdgeRef;
+/// use rustworkx_core::shortest_path::dijkstra;
+/// use rustworkx_core::Result;
+///
+/// let graph = petgraph::graph::UnGraph::<(), u32>::from_edges(&[(0, 1, 1), (1, 2, 2), (0, 2, 5)]);
+/// let csr: Result<CsrGraph<u32, _>> = CsrGraph::from_graph(&graph, |e| Ok(*e.weight()));
+/// let csr = csr.unwrap();
+/// assert_eq!(csr.indptr(), &[0, 2, 4, 6]);
+/// let res: Result<Vec<Option<u32>>> = dijkstra(&csr, 0, None, |e| Ok(*e.weight()), None);
+/// assert_eq!(res.unwrap(), vec![Some(0), Some(1), Some(3)]);
+/// ```
+#[derive(Clone, Debug)]
+pub struct CsrGraph<E, Ty = Directed> {
+    nodes: FixedBitSet,
+    node_count: usize,
+    out_offsets: Vec<usize>,
+    out_neighbors: Vec<usize>,
+    out_edges: Vec<usize>,
+    in_offsets: Vec<usize>,
+    in_neighbors: Vec<usize>,
+    in_edges: Vec<usize>,
+    endpoints: Vec<(usize, usize)>,
+    edge_ids: Vec<usize>,
+    weights: Vec<E>,
+    edge_bound: usize,
+    ty: PhantomData<Ty>,
+}
+
+/// Sort the entries `(node, neighbor, edge)` into the adjacency lists of
+/// their nodes, keeping their order, and return the offsets of the lists,
+/// the neighbor of every entry and the position of its edge.
+fn adjacency_lists<I>(node_bound: usize, entries: I) -> (Vec<usize>, Vec<usize>, Vec<usize>)
+where
+    I: Iterator<Item = (usize, usize, usize)> + Clone,
+{
+    let mut offsets = vec![0; node_bound + 1];
+    for (node, _, _) in entries.clone() {
+        offsets[node + 1] += 1;
+    }
+    for node in 0..node_bound {
+        offsets[node + 1] += offsets[node];
+    }
+    let mut next = offsets.clone();
+    let mut neighbors = vec![0; offsets[node_bound]];
+    let mut edges = vec![0; offsets[node_bound]];
+    for (node, neighbor, edge) in entries {
+        neighbors[next[node]] = neighbor;
+        edges[next[node]] = edge;
+        next[node] += 1;
+    }
+    (offsets, neighbors, edges)
+}
+
+impl<E, Ty: EdgeType> CsrGraph<E, Ty> {
+    /// Build the compressed sparse row form of `graph`.
+    ///
+    /// Arguments:
+    ///
+    /// * `graph` - The graph to convert.
+    /// * `weight_fn` - A callable that will be passed the `EdgeRef` of every
+    ///   edge and is expected to return a `Result` with the weight of that
+    ///   edge in the new graph. It's called once for every edge.
+    ///
+    /// The edges of every node are in the order they're returned by
+    /// `edge_references` on `graph`.
+    pub fn from_graph<G, F, Err>(graph: G, mut weight_fn: F) -> Result<Self, Err>
+    where
+        G: IntoEdgeReferences
+            + IntoNodeIdentifiers
+            + NodeIndexable
+            + EdgeIndexable
+            + GraphProp<EdgeType = Ty>,
+        F: FnMut(G::EdgeRef) -> Result<E, Err>,
+    {
+        let node_bound = NodeIndexable::node_bound(&graph);
+        let mut nodes = FixedBitSet::with_capacity(node_bound);
+        for node in graph.node_identifiers() {
+            nodes.insert(NodeIndexable::to_index(&graph, node));
+        }
+        let mut endpoints: Vec<(usize, usize)> = Vec::new();
+        let mut edge_ids: Vec<usize> = Vec::new();
+        let mut weights: Vec<E> = Vec::new();
+        for edge in graph.edge_references() {
+            endpoints.push((
+                NodeIndexable::to_index(&graph, edge.source()),
+                NodeIndexable::to_index(&graph, edge.target()),
+            ));
+            edge_ids.push(EdgeIndexable::to_index(&graph, edge.id()));
+            weights.push(weight_fn(edge)?);
+        }
+
+        let outgoing = endpoints
+            .iter()
+            .enumerate()
+            .map(|(edge, (source, target))| (*source, *target, edge));
+        let (out_offsets, out_neighbors, out_edges, in_offsets, in_neighbors, in_edges) =
+            if Ty::is_directed() {
+                let (out_offsets, out_neighbors, out_edges) = adjacency_lists(node_bound, outgoing);
+                let incoming = endpoints
+                    .iter()
+                    .enumerate()
+                    .map(|(edge, (source, target))| (*target, *source, edge));
+                let (in_offsets, in_neighbors, in_edges) = adjacency_lists(node_bound, incoming);
+                (
+                    out_offsets,
+                    out_neighbors,
+                    out_edges,
+                    in_offsets,
+                    in_neighbors,
+                    in_edges,
+                )
+            } else {
+                let both = endpoints
+                    .iter()
+                    .enumerate()
+                    .flat_map(|(edge, (source, target))| {
+                        let reverse = (source != target).then_some((*target, *source, edge));
+                        std::iter::once((*source, *target, edge)).chain(reverse)
+                    });
+                let (out_offsets, out_neighbors, out_edges) = adjacency_lists(node_bound, both);
+                (
+                    out_offsets,
+                    out_neighbors,
+                    out_edges,
+                    Vec::new(),
+                    Vec::new(),
+                    Vec::new(),
+                )
+            };
+        Ok(CsrGraph {
+            node_count: nodes.count_ones(..),
+            nodes,
+            out_offsets,
+            out_neighbors,
+            out_edges,
+            in_offsets,
+            in_neighbors,
+            in_edges,
+            endpoints,
+            edge_ids,
+            weights,
+            edge_bound: EdgeIndexable::edge_bound(&graph),
+            ty: PhantomData,
+        })
+    }
+
+    /// Return the offsets of the adjacency lists: the neighbors of node `i`
+    /// are `indices()[indptr()[i]..indptr()[i + 1]]`. There's an empty list
+    /// for every index below the node bound that isn't a node.
+    pub fn indptr(&self) -> &[usize] {
+        &self.out_offsets
+    }
+
+    /// Return the neighbors of every node, the targets of its outgoing edges
+    /// for a directed graph, in the order of [`CsrGraph::indptr`].
+    pub fn indices(&self) -> &[usize] {
+        &self.out_neighbors
+    }
+
+    /// Return the index of the edge of every entry of [`CsrGraph::indices`].
+    pub fn edge_indices(&self) -> Vec<usize> {
+        self.out_edges
+            .iter()
+            .map(|edge| self.edge_ids[*edge])
+            .collect()
+    }
+
+    fn edge_range(&self, node: usize, dir: Direction) -> Range<usize> {
+        let offsets = if Ty::is_directed() && dir == Direction::Incoming {
+            &self.in_offsets
+        } else {
+            &self.out_offsets
+        };
+        if node + 1 < offsets.len() {
+            offsets[node]..offsets[node + 1]
+        } else {
+            0..0
+        }
+    }
+
+    fn adjacency(&self, dir: Direction) -> (&[usize], &[usize]) {
+        if Ty::is_directed() && dir == Direction::Incoming {
+            (&self.in_neighbors, &self.in_edges)
+        } else {
+            (&self.out_neighbors, &self.out_edges)
+        }
+    }
+}
+
+/// A reference to an edge of a [`CsrGraph`].
+#[derive(Debug)]
+pub struct CsrEdgeReference<'a, E> {
+    source: usize,
+    target: usize,
+    id: usize,
+    weight: &'a E,
+}
+
+impl<'a, E> Clone for CsrEdgeReference<'a, E> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'a, E> Copy for CsrEdgeReference<'a, E> {}
+
+impl<'a, E> EdgeRef for CsrEdgeReference<'a, E> {
+    type NodeId = usize;
+    type EdgeId = usize;
+    type Weight = E;
+
+    fn source(&self) -> usize {
+        self.source
+    }
+
+    fn target(&self) -> usize {
+        self.target
+    }
+
+    fn weight(&self) -> &E {
+        self.weight
+    }
+
+    fn id(&self) -> usize {
+        self.id
+    }
+}
+
+/// An iterator over the edge references of a [`CsrGraph`].
+#[derive(Clone, Debug)]
+pub struct CsrEdgeReferences<'a, E, Ty> {
+    graph: &'a CsrGraph<E, Ty>,
+    edges: Range<usize>,
+}
+
+impl<'a, E, Ty> Iterator for CsrEdgeReferences<'a, E, Ty> {
+    type Item = CsrEdgeReference<'a, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.edges.next().map(|edge| {
+            let (source, target) = self.graph.endpoints[edge];
+            CsrEdgeReference {
+                source,
+                target,
+                id: self.graph.edge_ids[edge],
+                weight: &self.graph.weights[edge],
+            }
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.edges.size_hint()
+    }
+}
+
+/// An iterator over the edges of a node of a [`CsrGraph`].
+///
+/// The node is the source of every edge, except for the incoming edges of a
+/// directed graph where it's the target.
+#[derive(Clone, Debug)]
+pub struct CsrEdges<'a, E, Ty> {
+    graph: &'a CsrGraph<E, Ty>,
+    node: usize,
+    neighbors: Iter<'a, usize>,
+    edges: Iter<'a, usize>,
+    incoming: bool,
+}
+
+impl<'a, E, Ty> Iterator for CsrEdges<'a, E, Ty> {
+    type Item = CsrEdgeReference<'a, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let neighbor = *self.neighbors.next()?;
+        let edge = *self.edges.next()?;
+        let (source, target) = if self.incoming {
+            (neighbor, self.node)
+        } else {
+            (self.node, neighbor)
+        };
+        Some(CsrEdgeReference {
+            source,
+            target,
+            id: self.graph.edge_ids[edge],
+            weight: &self.graph.weights[edge],
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.neighbors.size_hint()
+    }
+}
+
+impl<E, Ty> GraphBase for CsrGraph<E, Ty> {
+    type NodeId = usize;
+    type EdgeId = usize;
+}
+
+impl<E, Ty> Data for CsrGraph<E, Ty> {
+    type NodeWeight = ();
+    type EdgeWeight = E;
+}
+
+impl<E, Ty: EdgeType> GraphProp for CsrGraph<E, Ty> {
+    type EdgeType = Ty;
+}
+
+impl<E, Ty> NodeCount for CsrGraph<E, Ty> {
+    fn node_count(&self) -> usize {
+        self.node_count
+    }
+}
+
+impl<E, Ty> EdgeCount for CsrGraph<E, Ty> {
+    fn edge_count(&self) -> usize {
+        self.endpoints.len()
+    }
+}
+
+impl<E, Ty> NodeIndexable for CsrGraph<E, Ty> {
+    fn node_bound(&self) -> usize {
+        self.nodes.len()
+    }
+
+    fn to_index(&self, a: usize) -> usize {
+        a
+    }
+
+    fn from_index(&self, i: usize) -> usize {
+        i
+    }
+}
+
+impl<E, Ty> EdgeIndexable for CsrGraph<E, Ty> {
+    fn edge_bound(&self) -> usize {
+        self.edge_bound
+    }
+
+    fn to_index(&self, a: usize) -> usize {
+        a
+    }
+
+    fn from_index(&self, i: usize) -> usize {
+        i
+    }
+}
+
+impl<E, Ty> Visitable for CsrGraph<E, Ty> {
+    type Map = FixedBitSet;
+
+    fn visit_map(&self) -> FixedBitSet {
+        FixedBitSet::with_capacity(self.nodes.len())
+    }
+
+    fn reset_map(&self, map: &mut FixedBitSet) {
+        map.clear();
+        map.grow(self.nodes.len());
+    }
+}
+
+impl<'a, E, Ty> IntoNodeIdentifiers for &'a CsrGraph<E, Ty> {
+    type NodeIdentifiers = fixedbitset::Ones<'a>;
+
+    fn node_identifiers(self) -> Self::NodeIdentifiers {
+        self.nodes.ones()
+    }
+}
+
+impl<'a, E, Ty: EdgeType> IntoNeighbors for &'a CsrGraph<E, Ty> {
+    type Neighbors = Copied<Iter<'a, usize>>;
+
+    fn neighbors(self, a: usize) -> Self::Neighbors {
+        self.neighbors_directed(a, Direction::Outgoing)
+    }
+}
+
+impl<'a, E, Ty: EdgeType> IntoNeighborsDirected for &'a CsrGraph<E, Ty> {
+    type NeighborsDirected = Copied<Iter<'a, usize>>;
+
+    fn neighbors_directed(self, a: usize, dir: Direction) -> Self::NeighborsDirected {
+        let (neighbors, _) = self.adjacency(dir);
+        neighbors[self.edge_range(a, dir)].iter().copied()
+    }
+}
+
+impl<'a, E, Ty> IntoEdgeReferences for &'a CsrGraph<E, Ty> {
+    type EdgeRef = CsrEdgeReference<'a, E>;
+    type EdgeReferences = CsrEdgeReferences<'a, E, Ty>;
+
+    fn edge_references(self) -> Self::EdgeReferences {
+        CsrEdgeReferences {
+            graph: self,
+            edges: 0..self.endpoints.len(),
+        }
+    }
+}
+
+impl<'a, E, Ty: EdgeType> IntoEdges for &'a CsrGraph<E, Ty> {
+    type Edges = CsrEdges<'a, E, Ty>;
+
+    fn edges(self, a: usize) -> Self::Edges {
+        self.edges_directed(a, Direction::Outgoing)
+    }
+}
+
+impl<'a, E, Ty: EdgeType> IntoEdgesDirected for &'a CsrGraph<E, Ty> {
+    type EdgesDirected = CsrEdges<'a, E, Ty>;
+
+    fn edges_directed(self, a: usize, dir: Direction) -> Self::EdgesDirected {
+        let range = self.edge_range(a, dir);
+        let (neighbors, edges) = self.adjacency(dir);
+        CsrEdges {
+            graph: self,
+            node: a,
+            neighbors: neighbors[range.clone()].iter(),
+            edges: edges[range].iter(),
+            incoming: Ty::is_directed() && dir == Direction::Incoming,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test_csr {
+    use super::CsrGraph;
+    use crate::centrality::betweenness_centrality;
+    use crate::connectivity::connected_components;
+    use crate::petgraph::graph::{DiGraph, UnGraph};
+    use crate::petgraph::stable_graph::{NodeIndex, StableDiGraph, StableUnGraph};
+    use crate::petgraph::visit::{
+        EdgeCount, EdgeRef, IntoEdgeReferences, IntoEdges, IntoEdgesDirected, IntoNeighbors,
+        IntoNeighborsDirected, IntoNodeIdentifiers, NodeCount, NodeIndexable,
+    };
+    use crate::petgraph::{Directed, Direction, Undirected};
+    use crate::shortest_path::dijkstra;
+    use crate::Result;
+
+    fn unweighted<G>(graph: G) -> CsrGraph<(), G::EdgeType>
+    where
+        G: IntoEdgeReferences
+            + IntoNodeIdentifiers
+            + NodeIndexable
+            + crate::petgraph::visit::EdgeIndexable
+            + crate::petgraph::visit::GraphProp,
+    {
+        let res: Result<_> = CsrGraph::from_graph(graph, |_| Ok(()));
+        res.unwrap()
+    }
+
+    fn sorted(iter: impl Iterator<Item = usize>) -> Vec<usize> {
+        let mut values: Vec<usize> = iter.collect();
+        values.sort_unstable();
+        values
+    }
+
+    #[test]
+    fn test_empty() {
+        let graph = UnGraph::<(), ()>::default();
+        let csr = unweighted(&graph);
+        assert_eq!(csr.node_count(), 0);
+        assert_eq!(csr.edge_count(), 0);
+        assert_eq!(csr.indptr(), &[0]);
+        assert!(csr.indices().is_empty());
+    }
+
+    #[test]
+    fn test_undirected_arrays() {
+        let graph = UnGraph::<(), ()>::from_edges([(0, 1), (1, 2), (2, 2), (0, 1)]);
+        let csr: CsrGraph<(), Undirected> = unweighted(&graph);
+        assert_eq!(csr.indptr(), &[0, 2, 5, 7]);
+        assert_eq!(csr.indices(), &[1, 1, 0, 2, 0, 1, 2]);
+        assert_eq!(csr.edge_indices(), vec![0, 3, 0, 1, 3, 1, 2]);
+        assert_eq!(sorted(csr.neighbors(1)), vec![0, 0, 2]);
+        assert_eq!(csr.neighbors(2).count(), 2);
+    }
+
+    #[test]
+    fn test_directed_neighbors() {
+        let graph = DiGraph::<(), ()>::from_edges([(0, 1), (2, 1), (1, 3), (3, 1)]);
+        let csr: CsrGraph<(), Directed> = unweighted(&graph);
+        assert_eq!(sorted(csr.neighbors(1)), vec![3]);
+        assert_eq!(
+            sorted(csr.neighbors_directed(1, Direction::Incoming)),
+            vec![0, 2, 3]
+        );
+        for edge in csr.edges_directed(1, Direction::Incoming) {
+            assert_eq!(edge.target(), 1);
+        }
+        for edge in csr.edges(1) {
+            assert_eq!(edge.source(), 1);
+        }
+    }
+
+    #[test]
+    fn test_removed_nodes_and_edges() {
+        let mut graph = StableDiGraph::<(), u32>::from_edges([(0, 1, 1), (1, 2, 2), (2, 3, 3)]);
+        graph.remove_node(NodeIndex::new(1));
+        graph.add_edge(NodeIndex::new(3), NodeIndex::new(0), 4);
+        let res: Result<CsrGraph<u32, Directed>> =
+            CsrGraph::from_graph(&graph, |e| Ok(*e.weight()));
+        let csr = res.unwrap();
+        assert_eq!(csr.node_count(), 3);
+        assert_eq!(csr.node_bound(), 4);
+        assert_eq!(csr.node_identifiers().collect::<Vec<_>>(), vec![0, 2, 3]);
+        let edges: Vec<(usize, usize, usize, u32)> = csr
+            .edge_references()
+            .map(|e| (e.source(), e.target(), e.id(), *e.weight()))
+            .collect();
+        // The new edge reuses the index of a removed one
+        assert_eq!(edges, vec![(3, 0, 0, 4), (2, 3, 2, 3)]);
+        assert_eq!(csr.neighbors(1).count(), 0);
+    }
+
+    #[test]
+    fn test_weight_error() {
+        let graph = UnGraph::<(), i32>::from_edges([(0, 1, 1), (1, 2, -1)]);
+        let res: std::result::Result<CsrGraph<i32, Undirected>, &str> =
+            CsrGraph::from_graph(&graph, |e| {
+                if *e.weight() < 0 {
+                    Err("negative")
+                } else {
+                    Ok(*e.weight())
+                }
+            });
+        assert_eq!(res.unwrap_err(), "negative");
+    }
+
+    #[test]
+    fn test_algorithms_match_graph() {
+        let mut graph = StableUnGraph::<(), f64>::default();
+        for _ in 0..8 {
+            graph.add_node(());
+        }
+        for (source, target, weight) in [
+            (0, 1, 1.),
+            (1, 2, 2.),
+            (2, 3, 1.),
+            (3, 0, 5.),
+            (1, 3, 1.5),
+            (4, 5, 1.),
+            (5, 6, 1.),
+            (6, 7, 1.),
+        ] {
+            graph.add_edge(NodeIndex::new(source), NodeIndex::new(target), weight);
+        }
+        graph.remove_node(NodeIndex::new(6));
+        let res: Result<CsrGraph<f64, Undirected>> =
+            CsrGraph::from_graph(&graph, |e| Ok(*e.weight()));
+        let csr = res.unwrap();
+
+        assert_eq!(
+            betweenness_centrality(&csr, false, true, 200),
+            betweenness_centrality(&graph, false, true, 200)
+        );
+        let expected: Vec<Vec<usize>> = connected_components(&graph)
+            .into_iter()
+            .map(|component| sorted(component.into_iter().map(|n| n.index())))
+            .collect();
+        let components: Vec<Vec<usize>> = connected_components(&csr)
+            .into_iter()
+            .map(|component| sorted(component.into_iter()))
+            .collect();
+        assert_eq!(components, expected);
+        let lengths: Result<Vec<Option<f64>>> = dijkstra(&csr, 0, None, |e| Ok(*e.weight()), None);
+        let expected: Result<Vec<Option<f64>>> =
+            dijkstra(&graph, NodeIndex::new(0), None, |e| Ok(*e.weight()), None);
+        assert_eq!(lengths.unwrap(), expected.unwrap());
+    }
+
+    #[test]
+    fn test_directed_betweenness_matches_graph() {
+        let graph = DiGraph::<(), ()>::from_edges([(0, 1), (1, 2), (2, 0), (2, 3), (3, 4), (1, 4)]);
+        let csr = unweighted(&graph);
+        assert_eq!(
+            betweenness_centrality(&csr, true, true, 200),
+            betweenness_centrality(&graph, true, true, 200)
+        );
+    }
+}
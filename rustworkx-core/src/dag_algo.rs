@@ -11,11 +11,12 @@
 // under the License.
 
 use std::cmp::{Eq, Ordering};
-use std::collections::BinaryHeap;
+use std::collections::{BinaryHeap, VecDeque};
 use std::error::Error;
 use std::fmt::{Display, Formatter};
 use std::hash::Hash;
 
+use fixedbitset::FixedBitSet;
 use hashbrown::{HashMap, HashSet};
 use std::fmt::Debug;
 use std::mem::swap;
@@ -30,6 +31,7 @@ use petgraph::Directed;
 
 use num_traits::{Num, Zero};
 
+use crate::dictmap::DictMap;
 use crate::err::LayersError;
 
 /// Return a pair of [`petgraph::Direction`] values corresponding to the "forwards" and "backwards"
@@ -337,6 +339,181 @@ where
     Ok(Some((path, path_weight)))
 }
 
+/// The schedule of a single task computed by [`critical_path`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TaskSchedule<T> {
+    /// The earliest time the task can start
+    pub earliest_start: T,
+    /// The earliest time the task can finish
+    pub earliest_finish: T,
+    /// The latest time the task can start without delaying the project
+    pub latest_start: T,
+    /// The latest time the task can finish without delaying the project
+    pub latest_finish: T,
+    /// How much the task can be delayed without delaying the project, this is
+    /// zero for the tasks on a critical path
+    pub slack: T,
+}
+
+/// The result of the critical path analysis of a DAG computed by
+/// [`critical_path`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct CriticalPath<N: Hash + Eq, T> {
+    /// A critical path, the chain of tasks which determines the duration
+    pub path: Vec<N>,
+    /// The duration of the whole project, which is the total weight of `path`
+    pub duration: T,
+    /// The schedule of every task in the DAG
+    pub schedule: DictMap<N, TaskSchedule<T>>,
+}
+
+type CriticalPathResult<G, T, E> = Result<Option<CriticalPath<NodeId<G>, T>>, E>;
+
+/// Run the critical path method (CPM) on a directed acyclic graph.
+///
+/// Every node of the DAG is treated as a task with a duration given by
+/// `node_weight_fn`, and every edge `(u, v)` as a dependency requiring that
+/// task `v` starts at least the lag given by `edge_weight_fn` after task `u`
+/// finishes. A forward pass in topological order finds the earliest start and
+/// finish time of every task, assuming the tasks without predecessors start at
+/// time zero, and a backward pass finds the latest start and finish time of
+/// every task that doesn't delay the whole project. The slack of a task is the
+/// difference between its latest and earliest start.
+///
+/// With a node weight of zero for every node this computes the weighted longest
+/// path of the DAG, like [`longest_path`], together with the slack of every
+/// node.
+///
+/// # Arguments
+/// * `graph`: Reference to a directed graph.
+/// * `node_weight_fn` - A callable that will be passed the id of each node in
+///   the graph and returns the duration of that node as `Result<T, E>`.
+/// * `edge_weight_fn` - A callable that will be passed the `EdgeRef` for each
+///   edge in the graph and returns the lag of that edge as `Result<T, E>`.
+///
+/// # Returns
+/// * `None` if the graph contains a cycle.
+/// * `Some(CriticalPath)` with a critical path, the project duration and the
+///   schedule of every node.
+/// * `Err(E)` if there is an error computing the weight of any node or edge.
+///
+/// # Example
+/// ```
+/// use petgraph::graph::DiGraph;
+/// use rustworkx_core::dag_algo::critical_path;
+///
+/// // Task durations are the node weights
+/// let mut graph: DiGraph<f64, ()> = DiGraph::new();
+/// let a = graph.add_node(3.);
+/// let b = graph.add_node(2.);
+/// let c = graph.add_node(4.);
+/// let d = graph.add_node(1.);
+/// graph.extend_with_edges([(a, b), (a, c), (b, d), (c, d)]);
+///
+/// let res = critical_path(&graph, |n| Ok::<f64, ()>(graph[n]), |_| Ok(0.));
+/// let cpm = res.unwrap().unwrap();
+/// assert_eq!(cpm.path, vec![a, c, d]);
+/// assert_eq!(cpm.duration, 8.);
+/// assert_eq!(cpm.schedule[&b].slack, 2.);
+/// assert_eq!(cpm.schedule[&c].slack, 0.);
+/// ```
+pub fn critical_path<G, F, H, T, E>(
+    graph: G,
+    mut node_weight_fn: F,
+    mut edge_weight_fn: H,
+) -> CriticalPathResult<G, T, E>
+where
+    G: GraphProp<EdgeType = Directed> + IntoNodeIdentifiers + IntoEdgesDirected + Visitable,
+    F: FnMut(G::NodeId) -> Result<T, E>,
+    H: FnMut(G::EdgeRef) -> Result<T, E>,
+    T: Num + Zero + PartialOrd + Copy,
+    <G as GraphBase>::NodeId: Hash + Eq,
+{
+    let nodes = match algo::toposort(graph, None) {
+        Ok(nodes) => nodes,
+        Err(_) => return Ok(None),
+    };
+    let mut durations: HashMap<G::NodeId, T> = HashMap::with_capacity(nodes.len());
+    // The earliest start of each node and the predecessor that determines it
+    let mut earliest: HashMap<G::NodeId, (T, Option<G::NodeId>)> =
+        HashMap::with_capacity(nodes.len());
+    let mut successors: HashMap<G::NodeId, Vec<(G::NodeId, T)>> =
+        HashMap::with_capacity(nodes.len());
+    for node in &nodes {
+        let mut start = (T::zero(), None);
+        for edge in graph.edges_directed(*node, petgraph::Direction::Incoming) {
+            let lag = edge_weight_fn(edge)?;
+            let parent = edge.source();
+            let parent_start = earliest[&parent].0;
+            let candidate = parent_start + durations[&parent] + lag;
+            if start.1.is_none() || candidate > start.0 {
+                start = (candidate, Some(parent));
+            }
+            successors.entry(parent).or_default().push((*node, lag));
+        }
+        durations.insert(*node, node_weight_fn(*node)?);
+        earliest.insert(*node, start);
+    }
+
+    let mut last: Option<(T, G::NodeId)> = None;
+    for node in &nodes {
+        let finish = earliest[node].0 + durations[node];
+        if !matches!(last, Some((duration, _)) if duration >= finish) {
+            last = Some((finish, *node));
+        }
+    }
+    let duration = match last {
+        Some((duration, _)) => duration,
+        None => T::zero(),
+    };
+
+    let mut latest_start: HashMap<G::NodeId, T> = HashMap::with_capacity(nodes.len());
+    for node in nodes.iter().rev() {
+        let mut finish = duration;
+        if let Some(children) = successors.get(node) {
+            for (child, lag) in children {
+                let candidate = latest_start[child] - *lag;
+                if candidate < finish {
+                    finish = candidate;
+                }
+            }
+        }
+        latest_start.insert(*node, finish - durations[node]);
+    }
+
+    let mut path: Vec<G::NodeId> = Vec::new();
+    let mut current = last.map(|(_, node)| node);
+    while let Some(node) = current {
+        path.push(node);
+        current = earliest[&node].1;
+    }
+    path.reverse();
+
+    let schedule: DictMap<G::NodeId, TaskSchedule<T>> = nodes
+        .into_iter()
+        .map(|node| {
+            let earliest_start = earliest[&node].0;
+            let latest_start = latest_start[&node];
+            let duration = durations[&node];
+            (
+                node,
+                TaskSchedule {
+                    earliest_start,
+                    earliest_finish: earliest_start + duration,
+                    latest_start,
+                    latest_finish: latest_start + duration,
+                    slack: latest_start - earliest_start,
+                },
+            )
+        })
+        .collect();
+    Ok(Some(CriticalPath {
+        path,
+        duration,
+        schedule,
+    }))
+}
+
 /// Return an iterator of graph layers
 ///
 /// A layer is a subgraph whose nodes are disjoint, i.e.,
@@ -647,6 +824,386 @@ where
     Ok(Some(block_list))
 }
 
+/// Return an iterator over the topological generations of a DAG
+///
+/// A topological generation is a set of nodes where every node's
+/// predecessors are all in earlier generations, and every node is in the
+/// earliest generation it can belong to. The first generation is made up of
+/// the nodes without any predecessors. The nodes within a generation form an
+/// antichain: there is no path between any two of them, which makes the
+/// generations the natural unit of work when scheduling the tasks of a DAG in
+/// parallel.
+///
+/// The generations are computed lazily, each call to `next` takes time
+/// proportional to the number of edges leaving the generation returned.
+///
+/// # Arguments:
+///
+/// * `graph`: The DAG to get the topological generations of
+///
+/// # Returns:
+///
+/// * An iterator over the generations, each of type `Vec<G::NodeId>`.
+/// * `None` if a cycle is found in the graph.
+///
+/// # Example
+///
+/// ```rust
+/// use petgraph::graph::{DiGraph, NodeIndex};
+/// use rustworkx_core::dag_algo::topological_generations;
+///
+/// let graph = DiGraph::<(), ()>::from_edges([(0, 2), (1, 3), (2, 4), (3, 4)]);
+/// let generations: Vec<Vec<NodeIndex>> = topological_generations(&graph)
+///     .expect("Graph is a DAG")
+///     .collect();
+/// let expected: Vec<Vec<NodeIndex>> = vec![
+///     vec![0.into(), 1.into()],
+///     vec![2.into(), 3.into()],
+///     vec![4.into()],
+/// ];
+/// assert_eq!(generations, expected);
+/// ```
+pub fn topological_generations<G>(graph: G) -> Option<impl Iterator<Item = Vec<G::NodeId>>>
+where
+    G: GraphProp<EdgeType = Directed> + IntoNeighborsDirected + IntoNodeIdentifiers + Visitable,
+    <G as GraphBase>::NodeId: Hash + Eq,
+{
+    if algo::is_cyclic_directed(graph) {
+        return None;
+    }
+    let mut in_degree: HashMap<G::NodeId, usize> = HashMap::new();
+    let mut first: Vec<G::NodeId> = Vec::new();
+    for node in graph.node_identifiers() {
+        let degree = graph
+            .neighbors_directed(node, petgraph::Direction::Incoming)
+            .count();
+        if degree == 0 {
+            first.push(node);
+        } else {
+            in_degree.insert(node, degree);
+        }
+    }
+    Some(Generations {
+        graph,
+        in_degree,
+        current: first,
+    })
+}
+
+/// Auxiliary struct to make the output of [`topological_generations`] iterable
+struct Generations<G, N> {
+    graph: G,
+    in_degree: HashMap<N, usize>,
+    current: Vec<N>,
+}
+
+impl<G, N> Iterator for Generations<G, N>
+where
+    G: IntoNeighborsDirected + GraphBase<NodeId = N>,
+    N: Copy + Hash + Eq,
+{
+    type Item = Vec<N>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.current.is_empty() {
+            return None;
+        }
+        let mut next_generation: Vec<N> = Vec::new();
+        for node in &self.current {
+            for child in self
+                .graph
+                .neighbors_directed(*node, petgraph::Direction::Outgoing)
+            {
+                let degree = self.in_degree.get_mut(&child).unwrap();
+                *degree -= 1;
+                if *degree == 0 {
+                    self.in_degree.remove(&child);
+                    next_generation.push(child);
+                }
+            }
+        }
+        Some(std::mem::replace(&mut self.current, next_generation))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.current.is_empty() {
+            (0, Some(0))
+        } else {
+            // Every remaining node could be in its own generation
+            (1, Some(self.in_degree.len() + 1))
+        }
+    }
+}
+
+/// Return an iterator over all the topological sorts of a DAG
+///
+/// The topological sorts are generated with a backtracking algorithm in the
+/// style of Knuth and Szwarcfiter [1] and Varol and Rotem [2]: every node
+/// without unsorted predecessors is tried in turn at each position of the
+/// sort, and the choice is undone once all the sorts starting with it have
+/// been returned. The candidates for every position are copied from the
+/// ones of the previous position, so each sort is generated in
+/// `O(|V|^2 + |E|)` time, but note that the number of topological sorts can
+/// grow factorially with the number of nodes. Use [`count_topological_sorts`]
+/// to count the sorts without enumerating them.
+///
+/// The graph is copied into the returned iterator, so it doesn't borrow the
+/// input graph.
+///
+/// # Arguments:
+///
+/// * `graph`: The DAG to get the topological sorts of
+///
+/// # Returns:
+///
+/// * An iterator over the topological sorts, each of type `Vec<G::NodeId>`.
+/// * `None` if a cycle is found in the graph.
+///
+/// # Example
+///
+/// ```rust
+/// use petgraph::graph::{DiGraph, NodeIndex};
+/// use rustworkx_core::dag_algo::all_topological_sorts;
+///
+/// let graph = DiGraph::<(), ()>::from_edges([(0, 1), (0, 2)]);
+/// let sorts: Vec<Vec<NodeIndex>> = all_topological_sorts(&graph)
+///     .expect("Graph is a DAG")
+///     .collect();
+/// let expected: Vec<Vec<NodeIndex>> = vec![
+///     vec![0.into(), 2.into(), 1.into()],
+///     vec![0.into(), 1.into(), 2.into()],
+/// ];
+/// assert_eq!(sorts, expected);
+/// ```
+///
+/// [1]: Knuth, Donald E. and Szwarcfiter, Jayme L. "A structured program to
+///     generate all topological sorting arrangements." Information Processing
+///     Letters 2.6 (1974): 153-157.
+///     <https://doi.org/10.1016/0020-0190(74)90001-5>
+/// [2]: Varol, Yaohan L. and Rotem, Doron. "An algorithm to generate all
+///     topological sorting arrangements." The Computer Journal 24.1 (1981):
+///     83-84. <https://doi.org/10.1093/comjnl/24.1.83>
+pub fn all_topological_sorts<G>(graph: G) -> Option<AllTopologicalSorts<G::NodeId>>
+where
+    G: GraphProp<EdgeType = Directed>
+        + IntoNeighborsDirected
+        + IntoNodeIdentifiers
+        + NodeIndexable
+        + Visitable,
+{
+    if algo::is_cyclic_directed(graph) {
+        return None;
+    }
+    let nodes: Vec<G::NodeId> = graph.node_identifiers().collect();
+    let mut position: Vec<usize> = vec![usize::MAX; graph.node_bound()];
+    for (index, node) in nodes.iter().enumerate() {
+        position[graph.to_index(*node)] = index;
+    }
+    let successors: Vec<Vec<usize>> = nodes
+        .iter()
+        .map(|node| {
+            graph
+                .neighbors_directed(*node, petgraph::Direction::Outgoing)
+                .map(|child| position[graph.to_index(child)])
+                .collect()
+        })
+        .collect();
+    let mut in_degree: Vec<usize> = vec![0; nodes.len()];
+    for children in &successors {
+        for child in children {
+            in_degree[*child] += 1;
+        }
+    }
+    let sources: Vec<usize> = (0..nodes.len())
+        .filter(|index| in_degree[*index] == 0)
+        .collect();
+    Some(AllTopologicalSorts {
+        nodes,
+        successors,
+        in_degree,
+        order: Vec::new(),
+        frames: vec![(sources, 0)],
+        done: false,
+    })
+}
+
+/// An iterator over all the topological sorts of a DAG, created by
+/// [`all_topological_sorts`]
+pub struct AllTopologicalSorts<N> {
+    nodes: Vec<N>,
+    successors: Vec<Vec<usize>>,
+    in_degree: Vec<usize>,
+    // The partial sort built so far
+    order: Vec<usize>,
+    // The candidates for every position of the partial sort and the index of
+    // the candidate currently used
+    frames: Vec<(Vec<usize>, usize)>,
+    done: bool,
+}
+
+impl<N> AllTopologicalSorts<N> {
+    fn place(&mut self) {
+        let (candidates, chosen) = self.frames.last().unwrap();
+        let node = candidates[*chosen];
+        let mut next: Vec<usize> = candidates
+            .iter()
+            .filter(|candidate| **candidate != node)
+            .copied()
+            .collect();
+        for child in &self.successors[node] {
+            self.in_degree[*child] -= 1;
+            if self.in_degree[*child] == 0 {
+                next.push(*child);
+            }
+        }
+        self.order.push(node);
+        self.frames.push((next, 0));
+    }
+
+    fn unplace(&mut self) {
+        let node = self.order.pop().unwrap();
+        for child in &self.successors[node] {
+            self.in_degree[*child] += 1;
+        }
+    }
+}
+
+impl<N: Copy> Iterator for AllTopologicalSorts<N> {
+    type Item = Vec<N>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        while self.order.len() < self.nodes.len() {
+            self.place();
+        }
+        let sort: Vec<N> = self.order.iter().map(|index| self.nodes[*index]).collect();
+        // Backtrack to the last position which has an untried candidate
+        self.frames.pop();
+        loop {
+            match self.frames.last_mut() {
+                None => {
+                    self.done = true;
+                    break;
+                }
+                Some((candidates, chosen)) => {
+                    *chosen += 1;
+                    let exhausted = *chosen == candidates.len();
+                    self.unplace();
+                    if !exhausted {
+                        break;
+                    }
+                    self.frames.pop();
+                }
+            }
+        }
+        Some(sort)
+    }
+}
+
+/// An error returned by [`count_topological_sorts`].
+#[derive(Debug, PartialEq, Eq)]
+pub enum TopologicalSortCountError {
+    /// The graph contains a cycle.
+    Cycle,
+    /// The number of topological sorts doesn't fit in a `u128`.
+    Overflow,
+}
+
+impl Display for TopologicalSortCountError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TopologicalSortCountError::Cycle => write!(f, "The graph contains a cycle"),
+            TopologicalSortCountError::Overflow => {
+                write!(f, "The number of topological sorts doesn't fit in a u128")
+            }
+        }
+    }
+}
+
+impl Error for TopologicalSortCountError {}
+
+/// Count the topological sorts of a DAG
+///
+/// The sorts are counted with dynamic programming over the sets of nodes
+/// that can form the start of a topological sort (the down-sets of the
+/// partial order defined by the DAG): the number of ways to sort a down-set
+/// is the sum of the number of ways to sort it without each of its maximal
+/// nodes. This avoids enumerating the sorts, but the number of down-sets can
+/// still be exponential in the width of the DAG so this is only feasible for
+/// small or narrow graphs. The memory used is proportional to the largest
+/// number of down-sets of the same size.
+///
+/// # Arguments:
+///
+/// * `graph`: The DAG to count the topological sorts of
+///
+/// # Returns:
+///
+/// * The number of topological sorts of the graph.
+/// * [`TopologicalSortCountError::Cycle`] if a cycle is found in the graph.
+/// * [`TopologicalSortCountError::Overflow`] if the number of sorts doesn't
+///   fit in a `u128`.
+///
+/// # Example
+///
+/// ```rust
+/// use petgraph::graph::DiGraph;
+/// use rustworkx_core::dag_algo::count_topological_sorts;
+///
+/// let graph = DiGraph::<(), ()>::from_edges([(0, 1), (0, 2), (1, 3), (2, 3)]);
+/// assert_eq!(count_topological_sorts(&graph), Ok(2));
+/// ```
+pub fn count_topological_sorts<G>(graph: G) -> Result<u128, TopologicalSortCountError>
+where
+    G: GraphProp<EdgeType = Directed>
+        + IntoNeighborsDirected
+        + IntoNodeIdentifiers
+        + NodeIndexable
+        + Visitable,
+{
+    if algo::is_cyclic_directed(graph) {
+        return Err(TopologicalSortCountError::Cycle);
+    }
+    let nodes: Vec<G::NodeId> = graph.node_identifiers().collect();
+    let mut position: Vec<usize> = vec![usize::MAX; graph.node_bound()];
+    for (index, node) in nodes.iter().enumerate() {
+        position[graph.to_index(*node)] = index;
+    }
+    let predecessors: Vec<FixedBitSet> = nodes
+        .iter()
+        .map(|node| {
+            let mut parents = FixedBitSet::with_capacity(nodes.len());
+            for parent in graph.neighbors_directed(*node, petgraph::Direction::Incoming) {
+                parents.insert(position[graph.to_index(parent)]);
+            }
+            parents
+        })
+        .collect();
+    // The number of ways to sort every down-set with a given number of nodes
+    let mut counts: HashMap<FixedBitSet, u128> = HashMap::new();
+    counts.insert(FixedBitSet::with_capacity(nodes.len()), 1);
+    for _ in 0..nodes.len() {
+        let mut next_counts: HashMap<FixedBitSet, u128> = HashMap::with_capacity(counts.len());
+        for (sorted, count) in counts {
+            for (node, parents) in predecessors.iter().enumerate() {
+                if sorted.contains(node) || !parents.is_subset(&sorted) {
+                    continue;
+                }
+                let mut next = sorted.clone();
+                next.insert(node);
+                let total = next_counts.entry(next).or_insert(0);
+                *total = total
+                    .checked_add(count)
+                    .ok_or(TopologicalSortCountError::Overflow)?;
+            }
+        }
+        counts = next_counts;
+    }
+    Ok(counts.into_values().sum())
+}
+
 /// Collect runs that match a filter function
 ///
 /// A run is a path of nodes where there is only a single successor and all
@@ -802,6 +1359,454 @@ where
     }
 }
 
+/// A compact index for answering reachability queries on a DAG.
+///
+/// The index decomposes the DAG into a set of disjoint chains (paths in the
+/// graph) and stores, for every node and every chain, the earliest position
+/// on that chain which is reachable from the node. Since every node after a
+/// reachable position on a chain is also reachable, a query only needs to
+/// compare one stored position, which takes constant time. Building the
+/// index takes `O(|E| k)` time and `O(|V| k)` memory, where `k` is the number
+/// of chains, which is much less than materializing the full transitive
+/// closure for graphs with few long dependency chains.
+///
+/// Nodes are identified by their [`NodeIndexable::to_index`] value in the
+/// graph the index was built from.
+///
+/// # Example
+/// ```
+/// use rustworkx_core::petgraph::graph::DiGraph;
+/// use rustworkx_core::dag_algo::ReachabilityIndex;
+///
+/// let graph = DiGraph::<(), ()>::from_edges([(0, 1), (1, 2), (0, 3), (3, 2), (4, 2)]);
+/// let index = ReachabilityIndex::new(&graph).unwrap();
+/// assert!(index.can_reach(0, 2));
+/// assert!(index.can_reach(4, 2));
+/// assert!(!index.can_reach(2, 0));
+/// assert!(!index.can_reach(1, 3));
+/// ```
+#[derive(Debug, Clone)]
+pub struct ReachabilityIndex {
+    /// The chain each node belongs to, `usize::MAX` for indices not in the graph
+    chain: Vec<usize>,
+    /// The position of each node on its chain
+    position: Vec<usize>,
+    /// The nodes on each chain in order
+    chains: Vec<Vec<usize>>,
+    /// For every node, the first position reachable on every chain stored as
+    /// a row of `chains.len()` entries, `usize::MAX` if no position is
+    /// reachable
+    reach: Vec<usize>,
+}
+
+impl ReachabilityIndex {
+    /// Build a reachability index for a directed acyclic graph.
+    ///
+    /// Arguments:
+    ///
+    /// * `graph` - The DAG to index.
+    ///
+    /// Returns `None` if the graph contains a cycle.
+    pub fn new<G>(graph: G) -> Option<Self>
+    where
+        G: GraphProp<EdgeType = Directed>
+            + IntoNodeIdentifiers
+            + IntoNeighborsDirected
+            + NodeIndexable
+            + Visitable,
+    {
+        let order = algo::toposort(graph, None).ok()?;
+        let node_bound = graph.node_bound();
+        let mut chain: Vec<usize> = vec![usize::MAX; node_bound];
+        let mut position: Vec<usize> = vec![0; node_bound];
+        let mut chains: Vec<Vec<usize>> = Vec::new();
+        // Greedily extend the chain of a predecessor that is still the last
+        // node on its chain, otherwise start a new chain.
+        for node in &order {
+            let index = graph.to_index(*node);
+            let extendable = graph
+                .neighbors_directed(*node, petgraph::Direction::Incoming)
+                .map(|pred| graph.to_index(pred))
+                .find(|pred| chains[chain[*pred]].last() == Some(pred));
+            let chain_id = match extendable {
+                Some(pred) => chain[pred],
+                None => {
+                    chains.push(Vec::new());
+                    chains.len() - 1
+                }
+            };
+            chain[index] = chain_id;
+            position[index] = chains[chain_id].len();
+            chains[chain_id].push(index);
+        }
+        let num_chains = chains.len();
+        let mut reach: Vec<usize> = vec![usize::MAX; node_bound * num_chains];
+        for node in order.iter().rev() {
+            let index = graph.to_index(*node);
+            let row = index * num_chains;
+            reach[row + chain[index]] = position[index];
+            for succ in graph.neighbors_directed(*node, petgraph::Direction::Outgoing) {
+                let succ_row = graph.to_index(succ) * num_chains;
+                for chain_id in 0..num_chains {
+                    let succ_reach = reach[succ_row + chain_id];
+                    if succ_reach < reach[row + chain_id] {
+                        reach[row + chain_id] = succ_reach;
+                    }
+                }
+            }
+        }
+        Some(ReachabilityIndex {
+            chain,
+            position,
+            chains,
+            reach,
+        })
+    }
+
+    /// Check whether there is a path from `source` to `target`.
+    ///
+    /// Every node can reach itself. Will `panic!` if either index is not a
+    /// node in the indexed graph.
+    pub fn can_reach(&self, source: usize, target: usize) -> bool {
+        assert!(
+            self.contains_node(source) && self.contains_node(target),
+            "The node indices must be in the indexed graph"
+        );
+        self.reach[source * self.chains.len() + self.chain[target]] <= self.position[target]
+    }
+
+    /// Return an iterator over the indices of the nodes reachable from
+    /// `source`, not including `source` itself.
+    ///
+    /// Will `panic!` if `source` is not a node in the indexed graph.
+    pub fn descendants(&self, source: usize) -> impl Iterator<Item = usize> + '_ {
+        assert!(
+            self.contains_node(source),
+            "The node index must be in the indexed graph"
+        );
+        let row = &self.reach[source * self.chains.len()..(source + 1) * self.chains.len()];
+        self.chains
+            .iter()
+            .zip(row)
+            .filter(|(_, first)| **first != usize::MAX)
+            .flat_map(|(chain, first)| chain[*first..].iter().copied())
+            .filter(move |node| *node != source)
+    }
+
+    /// Check whether `node` is the index of a node in the indexed graph.
+    pub fn contains_node(&self, node: usize) -> bool {
+        self.chain
+            .get(node)
+            .is_some_and(|chain| *chain != usize::MAX)
+    }
+
+    /// The number of chains the DAG was decomposed into.
+    pub fn num_chains(&self) -> usize {
+        self.chains.len()
+    }
+}
+
+/// Compute the transitive closure of a directed acyclic graph.
+///
+/// The transitive closure contains an edge `(u, v)` for every pair of distinct
+/// nodes where `v` is reachable from `u`. The edges are found with a
+/// [`ReachabilityIndex`] so the running time is `O(|E| k + |C|)`, where `k` is
+/// the number of chains in the index and `|C|` is the number of edges in the
+/// closure.
+///
+/// Arguments:
+///
+/// * `graph` - The DAG to compute the transitive closure of.
+///
+/// Returns `None` if the graph contains a cycle, otherwise the edges of the
+/// transitive closure.
+///
+/// # Example
+/// ```
+/// use rustworkx_core::petgraph::graph::{DiGraph, NodeIndex};
+/// use rustworkx_core::dag_algo::transitive_closure_dag;
+///
+/// let graph = DiGraph::<(), ()>::from_edges([(0, 1), (1, 2)]);
+/// let mut closure = transitive_closure_dag(&graph).unwrap();
+/// closure.sort();
+/// let expected: Vec<(NodeIndex, NodeIndex)> = vec![
+///     (0.into(), 1.into()),
+///     (0.into(), 2.into()),
+///     (1.into(), 2.into()),
+/// ];
+/// assert_eq!(closure, expected);
+/// ```
+pub fn transitive_closure_dag<G>(graph: G) -> Option<Vec<(G::NodeId, G::NodeId)>>
+where
+    G: GraphProp<EdgeType = Directed>
+        + IntoNodeIdentifiers
+        + IntoNeighborsDirected
+        + NodeIndexable
+        + Visitable,
+{
+    let index = ReachabilityIndex::new(graph)?;
+    let mut closure: Vec<(G::NodeId, G::NodeId)> = Vec::new();
+    for node in graph.node_identifiers() {
+        closure.extend(
+            index
+                .descendants(graph.to_index(node))
+                .map(|target| (node, graph.from_index(target))),
+        );
+    }
+    Some(closure)
+}
+
+/// Find a maximum matching of a bipartite graph with the Hopcroft-Karp
+/// algorithm.
+///
+/// `adjacency[u]` lists the right vertices adjacent to the left vertex `u`.
+/// Returns the vertex each left vertex is matched to and the vertex each
+/// right vertex is matched to.
+fn hopcroft_karp(
+    adjacency: &[Vec<usize>],
+    num_right: usize,
+) -> (Vec<Option<usize>>, Vec<Option<usize>>) {
+    let num_left = adjacency.len();
+    let mut match_left: Vec<Option<usize>> = vec![None; num_left];
+    let mut match_right: Vec<Option<usize>> = vec![None; num_right];
+    let mut dist: Vec<usize> = vec![usize::MAX; num_left];
+    loop {
+        // Build the layers of alternating paths starting at free left vertices
+        let mut queue: VecDeque<usize> = VecDeque::new();
+        for u in 0..num_left {
+            if match_left[u].is_none() {
+                dist[u] = 0;
+                queue.push_back(u);
+            } else {
+                dist[u] = usize::MAX;
+            }
+        }
+        let mut found = false;
+        while let Some(u) = queue.pop_front() {
+            for v in &adjacency[u] {
+                match match_right[*v] {
+                    None => found = true,
+                    Some(w) => {
+                        if dist[w] == usize::MAX {
+                            dist[w] = dist[u] + 1;
+                            queue.push_back(w);
+                        }
+                    }
+                }
+            }
+        }
+        if !found {
+            break;
+        }
+        // Find a maximal set of vertex disjoint shortest augmenting paths with
+        // an iterative depth first search.
+        let mut next_edge: Vec<usize> = vec![0; num_left];
+        for start in 0..num_left {
+            if match_left[start].is_some() || dist[start] != 0 {
+                continue;
+            }
+            let mut stack: Vec<usize> = vec![start];
+            let mut path: Vec<usize> = Vec::new();
+            while let Some(u) = stack.last().copied() {
+                if next_edge[u] == adjacency[u].len() {
+                    // Dead end, don't visit this vertex again in this phase
+                    dist[u] = usize::MAX;
+                    stack.pop();
+                    path.pop();
+                    continue;
+                }
+                let v = adjacency[u][next_edge[u]];
+                next_edge[u] += 1;
+                match match_right[v] {
+                    None => {
+                        path.push(v);
+                        for (u, v) in stack.iter().zip(&path) {
+                            match_left[*u] = Some(*v);
+                            match_right[*v] = Some(*u);
+                        }
+                        break;
+                    }
+                    Some(w) => {
+                        if dist[w] != usize::MAX && dist[w] == dist[u] + 1 {
+                            path.push(v);
+                            stack.push(w);
+                        }
+                    }
+                }
+            }
+        }
+    }
+    (match_left, match_right)
+}
+
+type DilworthMatching = (ReachabilityIndex, Vec<Option<usize>>, Vec<Option<usize>>);
+
+/// Compute a maximum matching of the bipartite graph with an edge from the
+/// left copy of `u` to the right copy of `v` for every pair of nodes where
+/// `v` is reachable from `u`, which is the reduction used by Fulkerson's
+/// proof of Dilworth's theorem.
+fn dilworth_matching<G>(graph: G) -> Option<DilworthMatching>
+where
+    G: GraphProp<EdgeType = Directed>
+        + IntoNodeIdentifiers
+        + IntoNeighborsDirected
+        + NodeIndexable
+        + Visitable,
+{
+    let index = ReachabilityIndex::new(graph)?;
+    let adjacency: Vec<Vec<usize>> = (0..graph.node_bound())
+        .map(|node| {
+            if index.contains_node(node) {
+                index.descendants(node).collect()
+            } else {
+                Vec::new()
+            }
+        })
+        .collect();
+    let (match_left, match_right) = hopcroft_karp(&adjacency, graph.node_bound());
+    Some((index, match_left, match_right))
+}
+
+/// Find a minimum chain decomposition of a directed acyclic graph.
+///
+/// A DAG defines a partial order where `u < v` if there is a path from `u`
+/// to `v`. A chain is a set of nodes which are pairwise comparable, so the
+/// nodes of a chain can be ordered such that each node is reachable from the
+/// previous one. A chain decomposition partitions the nodes into chains, and
+/// by Dilworth's theorem the minimum number of chains needed equals the size
+/// of a maximum antichain (see [`maximum_antichain`]).
+///
+/// The decomposition is found with the reduction to maximum bipartite matching
+/// on the transitive closure of the graph, solved with the Hopcroft-Karp
+/// algorithm in `O(|C| sqrt(|V|))` time, where `|C|` is the number of edges in
+/// the transitive closure.
+///
+/// # Arguments:
+///
+/// * `graph`: The DAG to decompose into chains
+///
+/// # Returns:
+///
+/// * `None` if the graph contains a cycle, otherwise the chains with the nodes
+///   of each chain in topological order. Consecutive nodes of a chain are
+///   connected by a path, but not necessarily by an edge.
+///
+/// # Example
+///
+/// ```rust
+/// use petgraph::graph::{DiGraph, NodeIndex};
+/// use rustworkx_core::dag_algo::minimum_chain_decomposition;
+///
+/// let graph = DiGraph::<(), ()>::from_edges([(0, 1), (0, 2), (1, 3), (2, 3)]);
+/// let chains = minimum_chain_decomposition(&graph).unwrap();
+/// assert_eq!(chains.len(), 2);
+/// ```
+pub fn minimum_chain_decomposition<G>(graph: G) -> Option<Vec<Vec<G::NodeId>>>
+where
+    G: GraphProp<EdgeType = Directed>
+        + IntoNodeIdentifiers
+        + IntoNeighborsDirected
+        + NodeIndexable
+        + Visitable,
+{
+    let (_, match_left, match_right) = dilworth_matching(graph)?;
+    let mut chains: Vec<Vec<G::NodeId>> = Vec::new();
+    for node in graph.node_identifiers() {
+        let mut current = graph.to_index(node);
+        if match_right[current].is_some() {
+            continue;
+        }
+        let mut chain: Vec<G::NodeId> = vec![node];
+        while let Some(next) = match_left[current] {
+            chain.push(graph.from_index(next));
+            current = next;
+        }
+        chains.push(chain);
+    }
+    Some(chains)
+}
+
+/// Find a maximum antichain of a directed acyclic graph.
+///
+/// A DAG defines a partial order where `u < v` if there is a path from `u`
+/// to `v`. An antichain is a set of nodes where no node is reachable from any
+/// other node in the set. By Dilworth's theorem the size of a maximum
+/// antichain equals the minimum number of chains needed to cover the nodes
+/// (see [`minimum_chain_decomposition`]); this is the width of the partial
+/// order and bounds how many tasks of a DAG can ever run concurrently.
+///
+/// The antichain is found from a minimum vertex cover of the bipartite
+/// matching used for [`minimum_chain_decomposition`] using König's theorem.
+///
+/// # Arguments:
+///
+/// * `graph`: The DAG to find a maximum antichain of
+///
+/// # Returns:
+///
+/// * `None` if the graph contains a cycle, otherwise the nodes of a maximum
+///   antichain.
+///
+/// # Example
+///
+/// ```rust
+/// use petgraph::graph::{DiGraph, NodeIndex};
+/// use rustworkx_core::dag_algo::maximum_antichain;
+///
+/// let graph = DiGraph::<(), ()>::from_edges([(0, 1), (0, 2), (0, 3), (1, 4), (2, 4)]);
+/// let mut antichain = maximum_antichain(&graph).unwrap();
+/// antichain.sort();
+/// let expected: Vec<NodeIndex> = vec![1.into(), 2.into(), 3.into()];
+/// assert_eq!(antichain, expected);
+/// ```
+pub fn maximum_antichain<G>(graph: G) -> Option<Vec<G::NodeId>>
+where
+    G: GraphProp<EdgeType = Directed>
+        + IntoNodeIdentifiers
+        + IntoNeighborsDirected
+        + NodeIndexable
+        + Visitable,
+{
+    let (index, match_left, match_right) = dilworth_matching(graph)?;
+    // Find the vertices reachable with alternating paths from the free left
+    // vertices, the left vertices not reached and the right vertices reached
+    // form a minimum vertex cover.
+    let node_bound = graph.node_bound();
+    let mut left_reached: Vec<bool> = vec![false; node_bound];
+    let mut right_reached: Vec<bool> = vec![false; node_bound];
+    let mut stack: Vec<usize> = Vec::new();
+    for node in graph.node_identifiers() {
+        let node = graph.to_index(node);
+        if match_left[node].is_none() {
+            left_reached[node] = true;
+            stack.push(node);
+        }
+    }
+    while let Some(u) = stack.pop() {
+        for v in index.descendants(u) {
+            if match_left[u] == Some(v) || right_reached[v] {
+                continue;
+            }
+            right_reached[v] = true;
+            if let Some(w) = match_right[v] {
+                if !left_reached[w] {
+                    left_reached[w] = true;
+                    stack.push(w);
+                }
+            }
+        }
+    }
+    Some(
+        graph
+            .node_identifiers()
+            .filter(|node| {
+                let node = graph.to_index(*node);
+                left_reached[node] && !right_reached[node]
+            })
+            .collect(),
+    )
+}
+
 // Tests for longest_path
 #[cfg(test)]
 mod test_longest_path {
@@ -1516,3 +2521,420 @@ mod test_collect_runs {
         assert_eq!(runs.next(), None);
     }
 }
+
+#[cfg(test)]
+mod test_reachability {
+    use super::*;
+    use petgraph::graph::{DiGraph, NodeIndex};
+    use petgraph::stable_graph::StableDiGraph;
+
+    fn brute_force_closure(graph: &DiGraph<(), ()>) -> Vec<(NodeIndex, NodeIndex)> {
+        let mut closure = Vec::new();
+        for node in graph.node_indices() {
+            let mut dfs = petgraph::visit::Dfs::new(graph, node);
+            while let Some(target) = dfs.next(graph) {
+                if target != node {
+                    closure.push((node, target));
+                }
+            }
+        }
+        closure.sort();
+        closure
+    }
+
+    #[test]
+    fn test_empty_graph() {
+        let graph: DiGraph<(), ()> = DiGraph::new();
+        assert_eq!(transitive_closure_dag(&graph), Some(vec![]));
+        assert_eq!(ReachabilityIndex::new(&graph).unwrap().num_chains(), 0);
+    }
+
+    #[test]
+    fn test_cycle() {
+        let graph = DiGraph::<(), ()>::from_edges([(0, 1), (1, 2), (2, 0)]);
+        assert!(transitive_closure_dag(&graph).is_none());
+        assert!(ReachabilityIndex::new(&graph).is_none());
+    }
+
+    #[test]
+    fn test_closure_matches_dfs() {
+        let graph = DiGraph::<(), ()>::from_edges([
+            (0, 1),
+            (0, 2),
+            (1, 3),
+            (2, 3),
+            (3, 4),
+            (5, 4),
+            (5, 6),
+            (6, 7),
+            (2, 7),
+            (0, 1),
+        ]);
+        let mut closure = transitive_closure_dag(&graph).unwrap();
+        closure.sort();
+        assert_eq!(closure, brute_force_closure(&graph));
+        let index = ReachabilityIndex::new(&graph).unwrap();
+        for source in graph.node_indices() {
+            for target in graph.node_indices() {
+                assert_eq!(
+                    index.can_reach(source.index(), target.index()),
+                    source == target || closure.contains(&(source, target))
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_path_is_single_chain() {
+        let graph = DiGraph::<(), ()>::from_edges([(0, 1), (1, 2), (2, 3)]);
+        let index = ReachabilityIndex::new(&graph).unwrap();
+        assert_eq!(index.num_chains(), 1);
+        assert_eq!(index.descendants(1).collect::<Vec<_>>(), vec![2, 3]);
+    }
+
+    #[test]
+    fn test_stable_graph_with_removed_nodes() {
+        let mut graph = StableDiGraph::<(), ()>::new();
+        let nodes: Vec<NodeIndex> = (0..4).map(|_| graph.add_node(())).collect();
+        graph.add_edge(nodes[0], nodes[1], ());
+        graph.add_edge(nodes[1], nodes[3], ());
+        graph.remove_node(nodes[2]);
+        let mut closure = transitive_closure_dag(&graph).unwrap();
+        closure.sort();
+        assert_eq!(
+            closure,
+            vec![
+                (nodes[0], nodes[1]),
+                (nodes[0], nodes[3]),
+                (nodes[1], nodes[3])
+            ]
+        );
+        let index = ReachabilityIndex::new(&graph).unwrap();
+        assert!(index.can_reach(0, 3));
+        assert!(!index.can_reach(3, 0));
+    }
+}
+
+#[cfg(test)]
+mod test_critical_path {
+    use super::*;
+    use petgraph::graph::DiGraph;
+
+    #[test]
+    fn test_empty_graph() {
+        let graph: DiGraph<f64, f64> = DiGraph::new();
+        let res = critical_path(&graph, |_| Ok::<f64, ()>(1.), |_| Ok(0.));
+        let cpm = res.unwrap().unwrap();
+        assert!(cpm.path.is_empty());
+        assert_eq!(cpm.duration, 0.);
+        assert!(cpm.schedule.is_empty());
+    }
+
+    #[test]
+    fn test_cycle() {
+        let graph = DiGraph::<(), ()>::from_edges([(0, 1), (1, 0)]);
+        let res = critical_path(&graph, |_| Ok::<i32, ()>(1), |_| Ok(0));
+        assert_eq!(res, Ok(None));
+    }
+
+    #[test]
+    fn test_schedule() {
+        // A classic activity-on-node network
+        let mut graph: DiGraph<i32, i32> = DiGraph::new();
+        let a = graph.add_node(2);
+        let b = graph.add_node(3);
+        let c = graph.add_node(4);
+        let d = graph.add_node(1);
+        let e = graph.add_node(5);
+        graph.add_edge(a, c, 0);
+        graph.add_edge(b, c, 0);
+        graph.add_edge(b, d, 0);
+        graph.add_edge(c, e, 0);
+        graph.add_edge(d, e, 1);
+        let res = critical_path(&graph, |n| Ok::<i32, ()>(graph[n]), |e| Ok(*e.weight()));
+        let cpm = res.unwrap().unwrap();
+        assert_eq!(cpm.path, vec![b, c, e]);
+        assert_eq!(cpm.duration, 12);
+        assert_eq!(
+            cpm.schedule[&a],
+            TaskSchedule {
+                earliest_start: 0,
+                earliest_finish: 2,
+                latest_start: 1,
+                latest_finish: 3,
+                slack: 1
+            }
+        );
+        assert_eq!(
+            cpm.schedule[&d],
+            TaskSchedule {
+                earliest_start: 3,
+                earliest_finish: 4,
+                latest_start: 5,
+                latest_finish: 6,
+                slack: 2
+            }
+        );
+        for node in [b, c, e] {
+            assert_eq!(cpm.schedule[&node].slack, 0);
+        }
+    }
+
+    #[test]
+    fn test_matches_longest_path() {
+        let graph = DiGraph::<(), i32>::from_edges([
+            (0, 1, 2),
+            (0, 2, 5),
+            (1, 3, 5),
+            (2, 3, 1),
+            (3, 4, 3),
+            (5, 4, 1),
+        ]);
+        let res = critical_path(&graph, |_| Ok::<i32, ()>(0), |e| Ok(*e.weight()));
+        let cpm = res.unwrap().unwrap();
+        let longest = longest_path(&graph, |e| Ok::<i32, ()>(*e.weight()))
+            .unwrap()
+            .unwrap();
+        assert_eq!(cpm.duration, longest.1);
+        assert_eq!(cpm.path, longest.0);
+        assert_eq!(cpm.schedule[&petgraph::graph::NodeIndex::new(5)].slack, 9);
+    }
+
+    #[test]
+    fn test_weight_fn_error() {
+        let graph = DiGraph::<(), ()>::from_edges([(0, 1)]);
+        let res = critical_path(&graph, |_| Ok(1), |_| Err("error"));
+        assert_eq!(res, Err("error"));
+        let res = critical_path(&graph, |_| Err("error"), |_| Ok(1));
+        assert_eq!(res, Err("error"));
+    }
+}
+
+#[cfg(test)]
+mod test_topological_generations {
+    use super::*;
+    use petgraph::graph::{DiGraph, NodeIndex};
+    use petgraph::stable_graph::StableDiGraph;
+
+    #[test]
+    fn test_empty_graph() {
+        let graph: DiGraph<(), ()> = DiGraph::new();
+        let mut generations = topological_generations(&graph).unwrap();
+        assert_eq!(generations.next(), None);
+    }
+
+    #[test]
+    fn test_cycle() {
+        let graph = DiGraph::<(), ()>::from_edges([(0, 1), (1, 2), (2, 1)]);
+        assert!(topological_generations(&graph).is_none());
+    }
+
+    #[test]
+    fn test_generations() {
+        let graph = DiGraph::<(), ()>::from_edges([
+            (0, 1),
+            (0, 2),
+            (1, 3),
+            (2, 3),
+            (3, 4),
+            (0, 4),
+            (5, 4),
+            (0, 1),
+        ]);
+        let generations: Vec<Vec<usize>> = topological_generations(&graph)
+            .unwrap()
+            .map(|generation| generation.into_iter().map(|n| n.index()).collect())
+            .collect();
+        // Neighbors are visited in the reverse order of edge insertion
+        assert_eq!(generations, vec![vec![0, 5], vec![2, 1], vec![3], vec![4]]);
+    }
+
+    #[test]
+    fn test_stable_graph_with_removed_node() {
+        let mut graph = StableDiGraph::<(), ()>::new();
+        let nodes: Vec<NodeIndex> = (0..4).map(|_| graph.add_node(())).collect();
+        graph.add_edge(nodes[0], nodes[1], ());
+        graph.add_edge(nodes[1], nodes[2], ());
+        graph.add_edge(nodes[3], nodes[2], ());
+        graph.remove_node(nodes[1]);
+        let generations: Vec<Vec<NodeIndex>> = topological_generations(&graph).unwrap().collect();
+        assert_eq!(generations, vec![vec![nodes[0], nodes[3]], vec![nodes[2]]]);
+    }
+}
+
+#[cfg(test)]
+mod test_dilworth {
+    use super::*;
+    use petgraph::graph::{DiGraph, NodeIndex};
+
+    fn assert_decomposition(graph: &DiGraph<(), ()>, width: usize) {
+        let index = ReachabilityIndex::new(graph).unwrap();
+        let chains = minimum_chain_decomposition(graph).unwrap();
+        assert_eq!(chains.len(), width);
+        let mut seen: Vec<NodeIndex> = chains.iter().flatten().copied().collect();
+        seen.sort();
+        assert_eq!(seen, graph.node_indices().collect::<Vec<_>>());
+        for chain in &chains {
+            for pair in chain.windows(2) {
+                assert!(pair[0] != pair[1] && index.can_reach(pair[0].index(), pair[1].index()));
+            }
+        }
+        let antichain = maximum_antichain(graph).unwrap();
+        assert_eq!(antichain.len(), width);
+        for a in &antichain {
+            for b in &antichain {
+                assert!(a == b || !index.can_reach(a.index(), b.index()));
+            }
+        }
+    }
+
+    #[test]
+    fn test_empty_graph() {
+        let graph: DiGraph<(), ()> = DiGraph::new();
+        assert_eq!(minimum_chain_decomposition(&graph), Some(vec![]));
+        assert_eq!(maximum_antichain(&graph), Some(vec![]));
+    }
+
+    #[test]
+    fn test_cycle() {
+        let graph = DiGraph::<(), ()>::from_edges([(0, 1), (1, 0)]);
+        assert!(minimum_chain_decomposition(&graph).is_none());
+        assert!(maximum_antichain(&graph).is_none());
+    }
+
+    #[test]
+    fn test_path() {
+        let graph = DiGraph::<(), ()>::from_edges([(0, 1), (1, 2), (2, 3)]);
+        assert_decomposition(&graph, 1);
+    }
+
+    #[test]
+    fn test_isolated_nodes() {
+        let mut graph = DiGraph::<(), ()>::new();
+        for _ in 0..4 {
+            graph.add_node(());
+        }
+        assert_decomposition(&graph, 4);
+    }
+
+    #[test]
+    fn test_chains_use_transitive_edges() {
+        // The two chains 0 -> 2 -> 4 and 1 -> 3 -> 5 need the reachability
+        // of 2 from 0 through 1 to cover the graph with 2 chains
+        let graph =
+            DiGraph::<(), ()>::from_edges([(0, 1), (1, 2), (1, 3), (2, 4), (3, 5), (0, 6), (6, 4)]);
+        assert_decomposition(&graph, 3);
+    }
+
+    #[test]
+    fn test_grid_poset() {
+        // The product of two chains of length 4 has width 4
+        let mut edges = Vec::new();
+        for i in 0..4u32 {
+            for j in 0..4u32 {
+                if i < 3 {
+                    edges.push((i * 4 + j, (i + 1) * 4 + j));
+                }
+                if j < 3 {
+                    edges.push((i * 4 + j, i * 4 + j + 1));
+                }
+            }
+        }
+        let graph = DiGraph::<(), ()>::from_edges(edges);
+        assert_decomposition(&graph, 4);
+    }
+}
+
+#[cfg(test)]
+mod test_all_topological_sorts {
+    use super::*;
+    use petgraph::graph::{DiGraph, NodeIndex};
+    use petgraph::stable_graph::StableDiGraph;
+
+    fn sorts(graph: &DiGraph<(), ()>) -> Vec<Vec<usize>> {
+        all_topological_sorts(graph)
+            .unwrap()
+            .map(|sort| sort.into_iter().map(|node| node.index()).collect())
+            .collect()
+    }
+
+    #[test]
+    fn test_empty_graph() {
+        let graph: DiGraph<(), ()> = DiGraph::new();
+        assert_eq!(sorts(&graph), vec![Vec::<usize>::new()]);
+        assert_eq!(count_topological_sorts(&graph), Ok(1));
+    }
+
+    #[test]
+    fn test_cycle() {
+        let graph = DiGraph::<(), ()>::from_edges([(0, 1), (1, 2), (2, 0)]);
+        assert!(all_topological_sorts(&graph).is_none());
+        assert_eq!(
+            count_topological_sorts(&graph),
+            Err(TopologicalSortCountError::Cycle)
+        );
+    }
+
+    #[test]
+    fn test_path() {
+        let graph = DiGraph::<(), ()>::from_edges([(0, 1), (1, 2), (2, 3)]);
+        assert_eq!(sorts(&graph), vec![vec![0, 1, 2, 3]]);
+        assert_eq!(count_topological_sorts(&graph), Ok(1));
+    }
+
+    #[test]
+    fn test_all_sorts() {
+        let graph = DiGraph::<(), ()>::from_edges([(0, 1), (0, 2), (1, 3), (2, 3), (4, 3)]);
+        let mut all = sorts(&graph);
+        assert_eq!(all.len(), 8);
+        all.sort();
+        all.dedup();
+        assert_eq!(all.len(), 8);
+        for sort in &all {
+            let position = |node: usize| sort.iter().position(|n| *n == node).unwrap();
+            for edge in graph.edge_indices() {
+                let (source, target) = graph.edge_endpoints(edge).unwrap();
+                assert!(position(source.index()) < position(target.index()));
+            }
+        }
+        assert_eq!(count_topological_sorts(&graph), Ok(8));
+    }
+
+    #[test]
+    fn test_antichain() {
+        let mut graph: DiGraph<(), ()> = DiGraph::new();
+        for _ in 0..5 {
+            graph.add_node(());
+        }
+        assert_eq!(sorts(&graph).len(), 120);
+        assert_eq!(count_topological_sorts(&graph), Ok(120));
+    }
+
+    #[test]
+    fn test_parallel_edges_and_removed_nodes() {
+        let mut graph: StableDiGraph<(), ()> = StableDiGraph::new();
+        let nodes: Vec<NodeIndex> = (0..4).map(|_| graph.add_node(())).collect();
+        graph.add_edge(nodes[0], nodes[2], ());
+        graph.add_edge(nodes[0], nodes[2], ());
+        graph.add_edge(nodes[0], nodes[3], ());
+        graph.remove_node(nodes[1]);
+        assert_eq!(all_topological_sorts(&graph).unwrap().count(), 2);
+        assert_eq!(count_topological_sorts(&graph), Ok(2));
+    }
+
+    #[test]
+    fn test_count_overflow() {
+        // Two disjoint chains of 70 nodes have binomial(140, 70) > 2^128
+        // sorts, but only 71^2 down-sets
+        let mut edges: Vec<(u32, u32)> = Vec::new();
+        for i in 0..69 {
+            edges.push((i, i + 1));
+            edges.push((70 + i, 70 + i + 1));
+        }
+        let graph = DiGraph::<(), ()>::from_edges(edges);
+        assert_eq!(
+            count_topological_sorts(&graph),
+            Err(TopologicalSortCountError::Overflow)
+        );
+    }
+}
@@ -0,0 +1,840 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+use std::collections::{BinaryHeap, VecDeque};
+use std::hash::Hash;
+
+use crate::par::*;
+use hashbrown::HashMap;
+use petgraph::visit::{
+    EdgeRef, GraphProp, IntoEdgeReferences, IntoNeighborsDirected, IntoNodeIdentifiers,
+    NodeIndexable,
+};
+use petgraph::Direction::{Incoming, Outgoing};
+
+use crate::dictmap::*;
+use crate::min_scored::MinScored;
+
+/// An undirected adjacency list of a graph with the nodes numbered in the
+/// order of `node_identifiers()`.
+struct Adjacency {
+    neighbors: Vec<Vec<usize>>,
+}
+
+impl Adjacency {
+    fn new<G>(graph: G) -> Self
+    where
+        G: IntoNodeIdentifiers + IntoNeighborsDirected + NodeIndexable,
+    {
+        let mut position = vec![usize::MAX; graph.node_bound()];
+        for (i, node) in graph.node_identifiers().enumerate() {
+            position[graph.to_index(node)] = i;
+        }
+        let neighbors = graph
+            .node_identifiers()
+            .map(|node| {
+                graph
+                    .neighbors_directed(node, Outgoing)
+                    .chain(graph.neighbors_directed(node, Incoming))
+                    .map(|neighbor| position[graph.to_index(neighbor)])
+                    .collect()
+            })
+            .collect();
+        Adjacency { neighbors }
+    }
+
+    fn len(&self) -> usize {
+        self.neighbors.len()
+    }
+
+    /// Return the distance from `source` to every node, `None` for the
+    /// nodes that can't be reached.
+    fn bfs(&self, source: usize) -> Vec<Option<usize>> {
+        let mut distance = vec![None; self.len()];
+        distance[source] = Some(0);
+        let mut queue = VecDeque::from([source]);
+        while let Some(node) = queue.pop_front() {
+            let next = distance[node].unwrap() + 1;
+            for neighbor in &self.neighbors[node] {
+                if distance[*neighbor].is_none() {
+                    distance[*neighbor] = Some(next);
+                    queue.push_back(*neighbor);
+                }
+            }
+        }
+        distance
+    }
+
+    /// Return the distance from `source` to every node and the eccentricity
+    /// of `source`, or `None` if the graph isn't connected.
+    fn distances(&self, source: usize) -> Option<(Vec<usize>, usize)> {
+        let distance: Vec<usize> = self.bfs(source).into_iter().collect::<Option<_>>()?;
+        let eccentricity = distance.iter().copied().max().unwrap_or(0);
+        Some((distance, eccentricity))
+    }
+
+    /// Return a node in the middle of a shortest path between two nodes,
+    /// given the distances from the first one.
+    fn midpoint(&self, distance: &[usize], target: usize) -> usize {
+        let mut node = target;
+        for _ in 0..distance[target] / 2 {
+            node = *self.neighbors[node]
+                .iter()
+                .find(|neighbor| distance[**neighbor] + 1 == distance[node])
+                .unwrap();
+        }
+        node
+    }
+}
+
+/// Return the node farthest from the source of a search, given the distances
+/// found by the search.
+fn farthest(distance: &[usize]) -> usize {
+    distance
+        .iter()
+        .enumerate()
+        .max_by_key(|(node, d)| (**d, std::cmp::Reverse(*node)))
+        .map(|(node, _)| node)
+        .unwrap()
+}
+
+/// Compute the diameter of a graph with the iFUB algorithm.
+///
+/// The diameter is the largest eccentricity of the nodes of the graph, the
+/// largest distance between any two nodes. The iFUB (iterative Fringe Upper
+/// Bound) algorithm [1] computes it exactly with a breadth-first search from
+/// a central node chosen by the 4-sweep heuristic, followed by searches from
+/// the nodes at the fringe of that search, starting at the farthest ones,
+/// until the lower bound found by the searches meets the upper bound given
+/// by the distance of the remaining nodes from the central node. On large
+/// sparse graphs it usually only needs a few searches instead of one from
+/// every node. The searches from the nodes at the same distance from the
+/// central node are run in parallel.
+///
+/// The direction of the edges is ignored, and every edge has a length of 1.
+///
+/// Arguments:
+///
+/// * `graph` - The graph to compute the diameter of.
+///
+/// Returns `None` if the graph is empty or isn't connected.
+///
+/// [1] Crescenzi, Pierluigi, et al. "On computing the diameter of real-world
+///     undirected graphs." Theoretical Computer Science 514 (2013): 84-95.
+///
+/// # Example
+/// ```rust
+/// use rustworkx_core::distance::diameter;
+/// use rustworkx_core::generators::grid_graph;
+/// use rustworkx_core::petgraph::graph::UnGraph;
+///
+/// let graph: UnGraph<(), ()> =
+///     grid_graph(Some(4), Some(6), None, || (), || (), false).unwrap();
+/// assert_eq!(diameter(&graph), Some(8));
+/// ```
+pub fn diameter<G>(graph: G) -> Option<usize>
+where
+    G: IntoNodeIdentifiers + IntoNeighborsDirected + NodeIndexable,
+{
+    let adjacency = Adjacency::new(graph);
+    if adjacency.len() == 0 {
+        return None;
+    }
+    // The 4-sweep heuristic: two double sweeps from a node of maximum degree,
+    // each sweep giving a lower bound on the diameter, and the node in the
+    // middle of the longest path found
+    let start = (0..adjacency.len())
+        .max_by_key(|node| (adjacency.neighbors[*node].len(), std::cmp::Reverse(*node)))
+        .unwrap();
+    let mut lower_bound = 0;
+    let mut center = start;
+    for _ in 0..2 {
+        let (distance, _) = adjacency.distances(center)?;
+        let first = farthest(&distance);
+        let (distance, eccentricity) = adjacency.distances(first)?;
+        lower_bound = lower_bound.max(eccentricity);
+        center = adjacency.midpoint(&distance, farthest(&distance));
+    }
+
+    let (distance, eccentricity) = adjacency.distances(center)?;
+    let mut fringes: Vec<Vec<usize>> = vec![Vec::new(); eccentricity + 1];
+    for (node, d) in distance.iter().enumerate() {
+        fringes[*d].push(node);
+    }
+    lower_bound = lower_bound.max(eccentricity);
+    // Any two nodes at most i away from the center are at most 2i apart
+    let mut level = eccentricity;
+    while level > 0 && lower_bound < 2 * level {
+        // Search from the fringe in parallel chunks, stopping early if the
+        // lower bound reaches the upper bound
+        let chunk_size = 4 * crate::par::current_num_threads();
+        for chunk in fringes[level].chunks(chunk_size) {
+            let fringe_eccentricity = chunk
+                .par_iter()
+                .map(|node| adjacency.distances(*node).unwrap().1)
+                .max()
+                .unwrap_or(0);
+            lower_bound = lower_bound.max(fringe_eccentricity);
+            if lower_bound >= 2 * level {
+                return Some(lower_bound);
+            }
+        }
+        // The remaining nodes are at most 2 (level - 1) apart
+        if lower_bound > 2 * (level - 1) {
+            break;
+        }
+        level -= 1;
+    }
+    Some(lower_bound)
+}
+
+/// Which extreme of the eccentricities to compute with the bounding
+/// algorithm.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Extrema {
+    All,
+    Radius,
+}
+
+/// The bounding algorithm of Takes and Kosters, returning the eccentricity
+/// bounds of every node once they're tight enough for `extrema`.
+fn bounding(adjacency: &Adjacency, extrema: Extrema) -> Option<(Vec<usize>, Vec<usize>)> {
+    let num_nodes = adjacency.len();
+    let mut lower = vec![0; num_nodes];
+    let mut upper = vec![usize::MAX; num_nodes];
+    let mut candidates: Vec<usize> = (0..num_nodes).collect();
+    let mut high = false;
+    while !candidates.is_empty() {
+        // Alternate between the candidates with the largest upper bound and
+        // the smallest lower bound, they tighten the bounds the most
+        let source = if high {
+            *candidates
+                .iter()
+                .max_by_key(|node| (upper[**node], std::cmp::Reverse(**node)))
+                .unwrap()
+        } else {
+            *candidates
+                .iter()
+                .min_by_key(|node| (lower[**node], **node))
+                .unwrap()
+        };
+        high = !high;
+        let (distance, eccentricity) = adjacency.distances(source)?;
+        for node in candidates.iter() {
+            let d = distance[*node];
+            lower[*node] = lower[*node].max(d.max(eccentricity - d));
+            upper[*node] = upper[*node].min(eccentricity + d);
+        }
+        let min_upper = upper.iter().copied().min().unwrap();
+        candidates.retain(|node| {
+            lower[*node] != upper[*node]
+                && !(extrema == Extrema::Radius && lower[*node] > min_upper)
+        });
+    }
+    Some((lower, upper))
+}
+
+/// Compute the radius of a graph with the bounding algorithm.
+///
+/// The radius is the smallest eccentricity of the nodes of the graph. The
+/// bounding algorithm of Takes and Kosters [1] keeps a lower and an upper
+/// bound on the eccentricity of every node, tightens them with a
+/// breadth-first search from a few well chosen nodes, and stops once the
+/// smallest upper bound is known to be the radius.
+///
+/// The direction of the edges is ignored, and every edge has a length of 1.
+///
+/// Arguments:
+///
+/// * `graph` - The graph to compute the radius of.
+///
+/// Returns `None` if the graph is empty or isn't connected.
+///
+/// [1] Takes, Frank W., and Walter A. Kosters. "Computing the eccentricity
+///     distribution of large graphs." Algorithms 6.1 (2013): 100-118.
+///
+/// # Example
+/// ```rust
+/// use rustworkx_core::distance::radius;
+/// use rustworkx_core::generators::grid_graph;
+/// use rustworkx_core::petgraph::graph::UnGraph;
+///
+/// let graph: UnGraph<(), ()> =
+///     grid_graph(Some(4), Some(6), None, || (), || (), false).unwrap();
+/// assert_eq!(radius(&graph), Some(5));
+/// ```
+pub fn radius<G>(graph: G) -> Option<usize>
+where
+    G: IntoNodeIdentifiers + IntoNeighborsDirected + NodeIndexable,
+{
+    let adjacency = Adjacency::new(graph);
+    if adjacency.len() == 0 {
+        return None;
+    }
+    let (_, upper) = bounding(&adjacency, Extrema::Radius)?;
+    upper.into_iter().min()
+}
+
+/// Compute the eccentricity of every node of a graph with the bounding
+/// algorithm.
+///
+/// The eccentricity of a node is its largest distance to any other node.
+/// The bounding algorithm of Takes and Kosters [1] keeps a lower and an
+/// upper bound on the eccentricity of every node and tightens them with a
+/// breadth-first search from a few well chosen nodes until they meet. On
+/// large sparse graphs it usually needs far fewer searches than one from
+/// every node.
+///
+/// The direction of the edges is ignored, and every edge has a length of 1.
+///
+/// Arguments:
+///
+/// * `graph` - The graph to compute the eccentricities of.
+///
+/// Returns `None` if the graph isn't connected.
+///
+/// [1] Takes, Frank W., and Walter A. Kosters. "Computing the eccentricity
+///     distribution of large graphs." Algorithms 6.1 (2013): 100-118.
+///
+/// # Example
+/// ```rust
+/// use rustworkx_core::distance::bounding_eccentricity;
+/// use rustworkx_core::petgraph::graph::{NodeIndex, UnGraph};
+///
+/// let graph = UnGraph::<(), ()>::from_edges(&[(0, 1), (1, 2), (2, 3), (1, 4)]);
+/// let eccentricity = bounding_eccentricity(&graph).unwrap();
+/// let eccentricity: Vec<usize> = (0..5).map(|n| eccentricity[&NodeIndex::new(n)]).collect();
+/// assert_eq!(eccentricity, vec![3, 2, 2, 3, 3]);
+/// ```
+pub fn bounding_eccentricity<G>(graph: G) -> Option<DictMap<G::NodeId, usize>>
+where
+    G: IntoNodeIdentifiers + IntoNeighborsDirected + NodeIndexable,
+    G::NodeId: Eq + Hash,
+{
+    let adjacency = Adjacency::new(graph);
+    let (lower, _) = bounding(&adjacency, Extrema::All)?;
+    Some(graph.node_identifiers().zip(lower).collect())
+}
+
+/// Compute the weighted distances from every node in parallel, returning the
+/// eccentricity of every node in the order of `node_identifiers()`, or `None`
+/// if some node can't reach every other node.
+fn weighted_eccentricities<G, F, E>(graph: G, mut weight_fn: F) -> Result<Option<Vec<f64>>, E>
+where
+    G: IntoEdgeReferences + IntoNodeIdentifiers + NodeIndexable + GraphProp,
+    F: FnMut(G::EdgeRef) -> Result<f64, E>,
+{
+    let mut position = vec![usize::MAX; graph.node_bound()];
+    let mut num_nodes = 0;
+    for node in graph.node_identifiers() {
+        position[graph.to_index(node)] = num_nodes;
+        num_nodes += 1;
+    }
+    let mut neighbors: Vec<Vec<(usize, f64)>> = vec![Vec::new(); num_nodes];
+    for edge in graph.edge_references() {
+        let weight = weight_fn(edge)?;
+        let source = position[graph.to_index(edge.source())];
+        let target = position[graph.to_index(edge.target())];
+        neighbors[source].push((target, weight));
+        if !graph.is_directed() {
+            neighbors[target].push((source, weight));
+        }
+    }
+    let eccentricities: Option<Vec<f64>> = (0..num_nodes)
+        .into_par_iter()
+        .map(|source| {
+            let mut distance: Vec<Option<f64>> = vec![None; num_nodes];
+            let mut visited = vec![false; num_nodes];
+            let mut heap = BinaryHeap::new();
+            distance[source] = Some(0.);
+            heap.push(MinScored(0., source));
+            let mut eccentricity = 0.;
+            let mut reached = 0;
+            while let Some(MinScored(score, node)) = heap.pop() {
+                if visited[node] {
+                    continue;
+                }
+                visited[node] = true;
+                reached += 1;
+                eccentricity = score;
+                for (neighbor, weight) in &neighbors[node] {
+                    let next = score + weight;
+                    if distance[*neighbor].map_or(true, |d| next < d) {
+                        distance[*neighbor] = Some(next);
+                        heap.push(MinScored(next, *neighbor));
+                    }
+                }
+            }
+            (reached == num_nodes).then_some(eccentricity)
+        })
+        .collect();
+    Ok(eccentricities)
+}
+
+/// Compute the eccentricity of every node of a graph.
+///
+/// The eccentricity of a node is the largest shortest path length from the
+/// node to any other node. The shortest path lengths from every node are
+/// computed with Dijkstra's algorithm, with one search per node run in
+/// parallel. For a directed graph the paths follow the direction of the
+/// edges.
+///
+/// Arguments:
+///
+/// * `graph` - The graph to compute the eccentricities of.
+/// * `weight_fn` - A callable that will be passed the `EdgeRef` of every edge
+///   and is expected to return a `Result` with the non-negative weight of that
+///   edge. It's called once for every edge, before the searches.
+///
+/// Returns `None` if some node can't reach every other node.
+///
+/// # Example
+/// ```rust
+/// use rustworkx_core::distance::eccentricity;
+/// use rustworkx_core::petgraph::graph::{NodeIndex, UnGraph};
+/// use rustworkx_core::Result;
+///
+/// let graph = UnGraph::<(), f64>::from_edges(&[(0, 1, 1.), (1, 2, 2.), (2, 3, 0.5)]);
+/// let res: Result<_> = eccentricity(&graph, |e| Ok(*e.weight()));
+/// let eccentricity = res.unwrap().unwrap();
+/// let eccentricity: Vec<f64> = (0..4).map(|n| eccentricity[&NodeIndex::new(n)]).collect();
+/// assert_eq!(eccentricity, vec![3.5, 2.5, 3., 3.5]);
+/// ```
+pub fn eccentricity<G, F, E>(graph: G, weight_fn: F) -> Result<Option<DictMap<G::NodeId, f64>>, E>
+where
+    G: IntoEdgeReferences + IntoNodeIdentifiers + NodeIndexable + GraphProp,
+    G::NodeId: Eq + Hash,
+    F: FnMut(G::EdgeRef) -> Result<f64, E>,
+{
+    let eccentricities = weighted_eccentricities(graph, weight_fn)?;
+    Ok(eccentricities.map(|eccentricities| graph.node_identifiers().zip(eccentricities).collect()))
+}
+
+/// Return the nodes with the eccentricity picked by `select` from all the
+/// eccentricities.
+fn extremal_nodes<G, F, E, S>(
+    graph: G,
+    weight_fn: F,
+    select: S,
+) -> Result<Option<Vec<G::NodeId>>, E>
+where
+    G: IntoEdgeReferences + IntoNodeIdentifiers + NodeIndexable + GraphProp,
+    F: FnMut(G::EdgeRef) -> Result<f64, E>,
+    S: Fn(f64, f64) -> f64,
+{
+    let Some(eccentricities) = weighted_eccentricities(graph, weight_fn)? else {
+        return Ok(None);
+    };
+    let Some(extreme) = eccentricities.iter().copied().reduce(select) else {
+        return Ok(Some(Vec::new()));
+    };
+    Ok(Some(
+        graph
+            .node_identifiers()
+            .zip(eccentricities)
+            .filter(|(_, eccentricity)| *eccentricity == extreme)
+            .map(|(node, _)| node)
+            .collect(),
+    ))
+}
+
+/// Return the center of a graph, the nodes whose eccentricity is the
+/// radius of the graph.
+///
+/// The eccentricities are computed like in [`eccentricity`], with one
+/// search per node run in parallel.
+///
+/// Arguments:
+///
+/// * `graph` - The graph to find the center of.
+/// * `weight_fn` - A callable that will be passed the `EdgeRef` of every edge
+///   and is expected to return a `Result` with the non-negative weight of that
+///   edge.
+///
+/// Returns the center in the order of `node_identifiers()`, or `None` if some
+/// node can't reach every other node.
+///
+/// # Example
+/// ```rust
+/// use rustworkx_core::distance::center;
+/// use rustworkx_core::petgraph::graph::{NodeIndex, UnGraph};
+/// use rustworkx_core::Result;
+///
+/// let graph = UnGraph::<(), ()>::from_edges(&[(0, 1), (1, 2), (2, 3)]);
+/// let res: Result<_> = center(&graph, |_| Ok(1.));
+/// assert_eq!(res.unwrap(), Some(vec![NodeIndex::new(1), NodeIndex::new(2)]));
+/// ```
+pub fn center<G, F, E>(graph: G, weight_fn: F) -> Result<Option<Vec<G::NodeId>>, E>
+where
+    G: IntoEdgeReferences + IntoNodeIdentifiers + NodeIndexable + GraphProp,
+    F: FnMut(G::EdgeRef) -> Result<f64, E>,
+{
+    extremal_nodes(graph, weight_fn, f64::min)
+}
+
+/// Return the periphery of a graph, the nodes whose eccentricity is the
+/// diameter of the graph.
+///
+/// The eccentricities are computed like in [`eccentricity`], with one
+/// search per node run in parallel.
+///
+/// Arguments:
+///
+/// * `graph` - The graph to find the periphery of.
+/// * `weight_fn` - A callable that will be passed the `EdgeRef` of every edge
+///   and is expected to return a `Result` with the non-negative weight of that
+///   edge.
+///
+/// Returns the periphery in the order of `node_identifiers()`, or `None` if
+/// some node can't reach every other node.
+///
+/// # Example
+/// ```rust
+/// use rustworkx_core::distance::periphery;
+/// use rustworkx_core::petgraph::graph::{NodeIndex, UnGraph};
+/// use rustworkx_core::Result;
+///
+/// let graph = UnGraph::<(), ()>::from_edges(&[(0, 1), (1, 2), (2, 3)]);
+/// let res: Result<_> = periphery(&graph, |_| Ok(1.));
+/// assert_eq!(res.unwrap(), Some(vec![NodeIndex::new(0), NodeIndex::new(3)]));
+/// ```
+pub fn periphery<G, F, E>(graph: G, weight_fn: F) -> Result<Option<Vec<G::NodeId>>, E>
+where
+    G: IntoEdgeReferences + IntoNodeIdentifiers + NodeIndexable + GraphProp,
+    F: FnMut(G::EdgeRef) -> Result<f64, E>,
+{
+    extremal_nodes(graph, weight_fn, f64::max)
+}
+
+/// Return the sum of the inverse distances between every pair of nodes of
+/// an adjacency list, searching from every node in parallel.
+fn inverse_distance_sum(neighbors: &[Vec<usize>]) -> f64 {
+    (0..neighbors.len())
+        .into_par_iter()
+        .map(|source| {
+            let mut distance = vec![0; neighbors.len()];
+            let mut seen = vec![false; neighbors.len()];
+            seen[source] = true;
+            let mut queue = VecDeque::from([source]);
+            let mut total = 0.;
+            while let Some(node) = queue.pop_front() {
+                for neighbor in &neighbors[node] {
+                    if !seen[*neighbor] {
+                        seen[*neighbor] = true;
+                        distance[*neighbor] = distance[node] + 1;
+                        total += 1. / distance[*neighbor] as f64;
+                        queue.push_back(*neighbor);
+                    }
+                }
+            }
+            total
+        })
+        .sum()
+}
+
+/// Compute the global efficiency of a graph.
+///
+/// The efficiency of a pair of nodes is the inverse of the length of the
+/// shortest path between them, and `0` if there's no path between them. The
+/// global efficiency is the average efficiency of all the pairs of distinct
+/// nodes of the graph, `1` for a complete graph. Unlike the average shortest
+/// path length it's also defined for disconnected graphs. The searches from
+/// every node are run in parallel.
+///
+/// The direction of the edges is ignored, and every edge has a length of 1.
+///
+/// Arguments:
+///
+/// * `graph` - The graph to compute the global efficiency of.
+///
+/// Returns `0` if the graph has fewer than two nodes.
+///
+/// # Example
+/// ```rust
+/// use rustworkx_core::distance::global_efficiency;
+/// use rustworkx_core::petgraph::graph::UnGraph;
+///
+/// // A path on three nodes has two pairs at distance 1 and one at distance 2
+/// let graph = UnGraph::<(), ()>::from_edges(&[(0, 1), (1, 2)]);
+/// assert_eq!(global_efficiency(&graph), 2.5 / 3.);
+/// ```
+pub fn global_efficiency<G>(graph: G) -> f64
+where
+    G: IntoNodeIdentifiers + IntoNeighborsDirected + NodeIndexable,
+{
+    let adjacency = Adjacency::new(graph);
+    let num_nodes = adjacency.len();
+    if num_nodes < 2 {
+        return 0.;
+    }
+    inverse_distance_sum(&adjacency.neighbors) / (num_nodes * (num_nodes - 1)) as f64
+}
+
+/// Compute the local efficiency of a graph.
+///
+/// The local efficiency of a node is the global efficiency, as computed by
+/// [`global_efficiency`], of the subgraph induced by its neighbors, which
+/// measures how well the neighbors of the node stay connected if it's
+/// removed. The local efficiency of the graph is the average local
+/// efficiency of its nodes. The local efficiencies of the nodes are computed
+/// in parallel.
+///
+/// The direction of the edges is ignored, and every edge has a length of 1.
+///
+/// Arguments:
+///
+/// * `graph` - The graph to compute the local efficiency of.
+///
+/// Returns `0` if the graph has no nodes.
+///
+/// # Example
+/// ```rust
+/// use rustworkx_core::distance::local_efficiency;
+/// use rustworkx_core::petgraph::graph::UnGraph;
+///
+/// // The neighbors of nodes 0 and 2 are joined by a path of length 2 and
+/// // the neighbors of nodes 1 and 3 by an edge
+/// let graph = UnGraph::<(), ()>::from_edges(&[(0, 1), (0, 2), (0, 3), (1, 2), (2, 3)]);
+/// assert_eq!(local_efficiency(&graph), (2. * 2.5 / 3. + 2.) / 4.);
+/// ```
+pub fn local_efficiency<G>(graph: G) -> f64
+where
+    G: IntoNodeIdentifiers + IntoNeighborsDirected + NodeIndexable,
+{
+    let adjacency = Adjacency::new(graph);
+    let num_nodes = adjacency.len();
+    if num_nodes == 0 {
+        return 0.;
+    }
+    let total: f64 = (0..num_nodes)
+        .into_par_iter()
+        .map(|node| {
+            let mut position: HashMap<usize, usize> = HashMap::new();
+            for neighbor in &adjacency.neighbors[node] {
+                if *neighbor != node {
+                    let next = position.len();
+                    position.entry(*neighbor).or_insert(next);
+                }
+            }
+            let degree = position.len();
+            if degree < 2 {
+                return 0.;
+            }
+            let mut neighbors: Vec<Vec<usize>> = vec![Vec::new(); degree];
+            for (neighbor, local) in position.iter() {
+                neighbors[*local] = adjacency.neighbors[*neighbor]
+                    .iter()
+                    .filter_map(|other| position.get(other).copied())
+                    .collect();
+            }
+            // The searches from every neighbor are already run in parallel
+            // across the nodes
+            inverse_distance_sum(&neighbors) / (degree * (degree - 1)) as f64
+        })
+        .sum();
+    total / num_nodes as f64
+}
+
+#[cfg(test)]
+mod test_distance {
+    use super::{
+        bounding_eccentricity, center, diameter, eccentricity, global_efficiency, local_efficiency,
+        periphery, radius, Adjacency,
+    };
+    use crate::generators::{
+        barabasi_albert_graph, complete_graph, cycle_graph, grid_graph, path_graph, star_graph,
+    };
+    use crate::Result;
+    use petgraph::graph::{DiGraph, UnGraph};
+    use petgraph::stable_graph::StableUnGraph;
+
+    /// The eccentricity of every node with a search from every node.
+    fn brute_force(graph: &UnGraph<(), ()>) -> Vec<usize> {
+        let adjacency = Adjacency::new(graph);
+        (0..adjacency.len())
+            .map(|node| adjacency.distances(node).unwrap().1)
+            .collect()
+    }
+
+    fn check(graph: &UnGraph<(), ()>) {
+        let expected = brute_force(graph);
+        let eccentricity: Vec<usize> = bounding_eccentricity(graph)
+            .unwrap()
+            .into_values()
+            .collect();
+        assert_eq!(eccentricity, expected);
+        assert_eq!(diameter(graph), expected.iter().copied().max());
+        assert_eq!(radius(graph), expected.iter().copied().min());
+    }
+
+    #[test]
+    fn test_empty() {
+        let graph = UnGraph::<(), ()>::default();
+        assert_eq!(diameter(&graph), None);
+        assert_eq!(radius(&graph), None);
+        assert!(bounding_eccentricity(&graph).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_single_node() {
+        let mut graph = UnGraph::<(), ()>::default();
+        graph.add_node(());
+        check(&graph);
+        assert_eq!(diameter(&graph), Some(0));
+    }
+
+    #[test]
+    fn test_disconnected() {
+        let graph = UnGraph::<(), ()>::from_edges([(0, 1), (2, 3)]);
+        assert_eq!(diameter(&graph), None);
+        assert_eq!(radius(&graph), None);
+        assert_eq!(bounding_eccentricity(&graph), None);
+    }
+
+    #[test]
+    fn test_generators() {
+        check(&path_graph(Some(9), None, || (), || (), false).unwrap());
+        check(&cycle_graph(Some(11), None, || (), || (), false).unwrap());
+        check(&star_graph(Some(7), None, || (), || (), false, false).unwrap());
+        check(&grid_graph(Some(5), Some(8), None, || (), || (), false).unwrap());
+    }
+
+    #[test]
+    fn test_random_graphs() {
+        for seed in 0..30 {
+            let graph: UnGraph<(), ()> =
+                barabasi_albert_graph(80, 2, Some(seed), None, || (), || ()).unwrap();
+            check(&graph);
+        }
+    }
+
+    #[test]
+    fn test_directed_ignores_direction() {
+        let graph = DiGraph::<(), ()>::from_edges([(0, 1), (2, 1), (2, 3)]);
+        assert_eq!(diameter(&graph), Some(3));
+        assert_eq!(radius(&graph), Some(2));
+    }
+
+    #[test]
+    fn test_removed_nodes() {
+        let mut graph = StableUnGraph::<(), ()>::from_edges([(0, 1), (1, 2), (2, 3), (3, 4)]);
+        graph.remove_node(4.into());
+        let eccentricity = bounding_eccentricity(&graph).unwrap();
+        assert_eq!(
+            eccentricity.into_values().collect::<Vec<_>>(),
+            vec![3, 2, 2, 3]
+        );
+        assert_eq!(diameter(&graph), Some(3));
+        assert_eq!(radius(&graph), Some(2));
+    }
+
+    #[test]
+    fn test_eccentricity_matches_bfs() {
+        for seed in 0..5 {
+            let graph: UnGraph<(), ()> =
+                barabasi_albert_graph(50, 2, Some(seed), None, || (), || ()).unwrap();
+            let res: Result<_> = eccentricity(&graph, |_| Ok(1.));
+            let weighted: Vec<usize> = res
+                .unwrap()
+                .unwrap()
+                .into_values()
+                .map(|e| e as usize)
+                .collect();
+            assert_eq!(weighted, brute_force(&graph));
+        }
+    }
+
+    #[test]
+    fn test_weighted() {
+        // A triangle where the heavy edge is never on a shortest path
+        let graph =
+            UnGraph::<(), f64>::from_edges([(0, 1, 1.), (1, 2, 1.), (2, 0, 5.), (2, 3, 2.)]);
+        let res: Result<_> = eccentricity(&graph, |e| Ok(*e.weight()));
+        let values: Vec<f64> = res.unwrap().unwrap().into_values().collect();
+        assert_eq!(values, vec![4., 3., 2., 4.]);
+        let res: Result<_> = center(&graph, |e| Ok(*e.weight()));
+        assert_eq!(res.unwrap(), Some(vec![2.into()]));
+        let res: Result<_> = periphery(&graph, |e| Ok(*e.weight()));
+        assert_eq!(res.unwrap(), Some(vec![0.into(), 3.into()]));
+    }
+
+    #[test]
+    fn test_directed() {
+        let graph = DiGraph::<(), ()>::from_edges([(0, 1), (1, 2), (2, 0), (1, 0)]);
+        let res: Result<_> = eccentricity(&graph, |_| Ok(1.));
+        let values: Vec<f64> = res.unwrap().unwrap().into_values().collect();
+        assert_eq!(values, vec![2., 1., 2.]);
+        let res: Result<_> = center(&graph, |_| Ok(1.));
+        assert_eq!(res.unwrap(), Some(vec![1.into()]));
+        // Not strongly connected
+        let graph = DiGraph::<(), ()>::from_edges([(0, 1), (1, 2)]);
+        let res: Result<_> = eccentricity(&graph, |_| Ok(1.));
+        assert_eq!(res.unwrap(), None);
+        let res: Result<_> = periphery(&graph, |_| Ok(1.));
+        assert_eq!(res.unwrap(), None);
+    }
+
+    #[test]
+    fn test_weight_fn_error() {
+        let graph = UnGraph::<(), ()>::from_edges([(0, 1)]);
+        let res: std::result::Result<_, &str> = eccentricity(&graph, |_| Err("error"));
+        assert_eq!(res, Err("error"));
+    }
+
+    #[test]
+    fn test_center_empty() {
+        let graph = UnGraph::<(), ()>::default();
+        let res: Result<_> = center(&graph, |_| Ok(1.));
+        assert_eq!(res.unwrap(), Some(vec![]));
+    }
+
+    #[test]
+    fn test_efficiency_complete_graph() {
+        let graph: UnGraph<(), ()> = complete_graph(Some(6), None, || (), || ()).unwrap();
+        assert_eq!(global_efficiency(&graph), 1.);
+        assert_eq!(local_efficiency(&graph), 1.);
+    }
+
+    #[test]
+    fn test_efficiency_small_graphs() {
+        let mut graph = UnGraph::<(), ()>::default();
+        assert_eq!(global_efficiency(&graph), 0.);
+        assert_eq!(local_efficiency(&graph), 0.);
+        graph.add_node(());
+        assert_eq!(global_efficiency(&graph), 0.);
+        assert_eq!(local_efficiency(&graph), 0.);
+    }
+
+    #[test]
+    fn test_efficiency_disconnected() {
+        // Two disjoint edges: 2 of the 12 ordered pairs are at distance 1
+        let graph = UnGraph::<(), ()>::from_edges([(0, 1), (2, 3)]);
+        assert_eq!(global_efficiency(&graph), 4. / 12.);
+        assert_eq!(local_efficiency(&graph), 0.);
+    }
+
+    #[test]
+    fn test_efficiency_cycle() {
+        // In a cycle of 5 every node has two pairs at distance 1 and two at
+        // distance 2, and the neighbors of a node aren't adjacent
+        let graph: UnGraph<(), ()> = cycle_graph(Some(5), None, || (), || (), false).unwrap();
+        assert!((global_efficiency(&graph) - 0.75).abs() < 1e-12);
+        assert_eq!(local_efficiency(&graph), 0.);
+    }
+
+    #[test]
+    fn test_local_efficiency_ignores_self_loops_and_parallel_edges() {
+        let graph = UnGraph::<(), ()>::from_edges([(0, 1), (0, 1), (0, 2), (1, 2), (0, 0)]);
+        assert_eq!(local_efficiency(&graph), 1.);
+        assert_eq!(global_efficiency(&graph), 1.);
+    }
+}
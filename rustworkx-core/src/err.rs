@@ -55,6 +55,30 @@ fn fmt_merge_error<E: Error>(f: &mut Formatter<'_>, inner: &E) -> std::fmt::Resu
     write!(f, "The merge callback failed with: {:?}", inner)
 }
 
+/// Error returned by [`EdgeStreamBuilder`](crate::graph_builder::EdgeStreamBuilder)
+/// when adding edges.
+#[derive(Debug, PartialEq, Eq)]
+pub enum EdgeStreamError<E> {
+    /// An edge refers to this node index, which isn't below the node limit
+    /// of the builder.
+    NodeLimit(usize),
+    /// The callback merging the weights of parallel edges failed.
+    CombineError(E),
+}
+
+impl<E: Error> Display for EdgeStreamError<E> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            EdgeStreamError::NodeLimit(index) => {
+                write!(f, "Node index {} is above the node limit.", index)
+            }
+            EdgeStreamError::CombineError(ref e) => fmt_merge_error(f, e),
+        }
+    }
+}
+
+impl<E: Error> Error for EdgeStreamError<E> {}
+
 /// Error returned by Layers function when an index is not part of the graph.
 #[derive(Debug, PartialEq, Eq)]
 pub struct LayersError(pub String);
@@ -0,0 +1,983 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+use std::cmp::{Ordering, Reverse};
+use std::collections::BinaryHeap;
+
+use petgraph::visit::{EdgeRef, GraphProp, IntoEdgeReferences, IntoNodeIdentifiers, NodeIndexable};
+
+/// A feedback arc set of a directed graph and the ordering of the nodes it
+/// was derived from, as returned by [`greedy_feedback_arc_set`] and
+/// [`local_search_feedback_arc_set`].
+pub type FeedbackArcSet<N, E> = (Vec<E>, Vec<N>);
+
+/// The arcs of a directed graph relabeled to `0..n`, without self-loops.
+struct Arcs<N, E> {
+    nodes: Vec<N>,
+    edges: Vec<(E, usize, usize)>,
+    outgoing: Vec<Vec<(usize, f64)>>,
+    incoming: Vec<Vec<(usize, f64)>>,
+}
+
+impl<N: Copy, E: Copy> Arcs<N, E> {
+    fn new<G, F, Err>(graph: G, mut weight_fn: F) -> Result<Self, Err>
+    where
+        G: IntoEdgeReferences<NodeId = N, EdgeId = E> + IntoNodeIdentifiers + NodeIndexable,
+        F: FnMut(G::EdgeRef) -> Result<f64, Err>,
+    {
+        let mut compact: Vec<usize> = vec![usize::MAX; graph.node_bound()];
+        let nodes: Vec<N> = graph.node_identifiers().collect();
+        for (position, node) in nodes.iter().enumerate() {
+            compact[graph.to_index(*node)] = position;
+        }
+        let mut edges: Vec<(E, usize, usize)> = Vec::new();
+        let mut outgoing: Vec<Vec<(usize, f64)>> = vec![Vec::new(); nodes.len()];
+        let mut incoming: Vec<Vec<(usize, f64)>> = vec![Vec::new(); nodes.len()];
+        for edge in graph.edge_references() {
+            let source = compact[graph.to_index(edge.source())];
+            let target = compact[graph.to_index(edge.target())];
+            edges.push((edge.id(), source, target));
+            if source != target {
+                let weight = weight_fn(edge)?;
+                outgoing[source].push((target, weight));
+                incoming[target].push((source, weight));
+            }
+        }
+        Ok(Arcs {
+            nodes,
+            edges,
+            outgoing,
+            incoming,
+        })
+    }
+
+    /// Return the arcs that don't go forward in `ordering`, together with the
+    /// ordering of the original nodes.
+    fn feedback_arcs(&self, ordering: &[usize]) -> FeedbackArcSet<N, E> {
+        let mut position: Vec<usize> = vec![0; ordering.len()];
+        for (index, node) in ordering.iter().enumerate() {
+            position[*node] = index;
+        }
+        let arcs = self
+            .edges
+            .iter()
+            .filter(|(_, source, target)| position[*source] >= position[*target])
+            .map(|(id, _, _)| *id)
+            .collect();
+        let nodes = ordering.iter().map(|node| self.nodes[*node]).collect();
+        (arcs, nodes)
+    }
+}
+
+/// A candidate node for removal in the greedy heuristic, ordered by the
+/// difference of its remaining outgoing and incoming weight and then by the
+/// smallest position.
+#[derive(PartialEq)]
+struct Candidate(f64, Reverse<usize>);
+
+impl Eq for Candidate {}
+
+impl PartialOrd for Candidate {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Candidate {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.total_cmp(&other.0).then(self.1.cmp(&other.1))
+    }
+}
+
+/// The ordering found by the greedy heuristic of Eades, Lin and Smyth.
+fn greedy_ordering<N, E>(arcs: &Arcs<N, E>) -> Vec<usize> {
+    let size = arcs.nodes.len();
+    let mut out_count: Vec<usize> = arcs.outgoing.iter().map(|a| a.len()).collect();
+    let mut in_count: Vec<usize> = arcs.incoming.iter().map(|a| a.len()).collect();
+    let mut delta: Vec<f64> = (0..size)
+        .map(|node| {
+            arcs.outgoing[node].iter().map(|(_, w)| w).sum::<f64>()
+                - arcs.incoming[node].iter().map(|(_, w)| w).sum::<f64>()
+        })
+        .collect();
+    let mut removed: Vec<bool> = vec![false; size];
+    let mut sinks: Vec<usize> = (0..size).rev().filter(|n| out_count[*n] == 0).collect();
+    let mut sources: Vec<usize> = (0..size).rev().filter(|n| in_count[*n] == 0).collect();
+    let mut heap: BinaryHeap<Candidate> = (0..size)
+        .map(|node| Candidate(delta[node], Reverse(node)))
+        .collect();
+    let mut head: Vec<usize> = Vec::with_capacity(size);
+    let mut tail: Vec<usize> = Vec::new();
+    let mut remaining = size;
+    while remaining > 0 {
+        let (node, to_head) = if let Some(node) = sinks.pop() {
+            (node, false)
+        } else if let Some(node) = sources.pop() {
+            (node, true)
+        } else {
+            match heap.pop() {
+                Some(Candidate(value, Reverse(node))) => {
+                    // Skip entries that were superseded by a later push
+                    if removed[node] || value != delta[node] {
+                        continue;
+                    }
+                    (node, true)
+                }
+                None => break,
+            }
+        };
+        if removed[node] {
+            continue;
+        }
+        removed[node] = true;
+        remaining -= 1;
+        if to_head {
+            head.push(node);
+        } else {
+            tail.push(node);
+        }
+        for (other, weight) in &arcs.incoming[node] {
+            if !removed[*other] {
+                out_count[*other] -= 1;
+                delta[*other] -= weight;
+                if out_count[*other] == 0 {
+                    sinks.push(*other);
+                }
+                heap.push(Candidate(delta[*other], Reverse(*other)));
+            }
+        }
+        for (other, weight) in &arcs.outgoing[node] {
+            if !removed[*other] {
+                in_count[*other] -= 1;
+                delta[*other] += weight;
+                if in_count[*other] == 0 {
+                    sources.push(*other);
+                }
+                heap.push(Candidate(delta[*other], Reverse(*other)));
+            }
+        }
+    }
+    head.extend(tail.into_iter().rev());
+    head
+}
+
+/// Find a small feedback arc set of a directed graph with the greedy
+/// heuristic of Eades, Lin and Smyth [1].
+///
+/// A feedback arc set is a set of edges whose removal makes the graph
+/// acyclic. Finding one of minimum weight is NP-hard, so this builds an
+/// ordering of the nodes instead: it repeatedly moves sinks to the end and
+/// sources to the start of the ordering, and otherwise moves the node with
+/// the largest difference of its outgoing and incoming edge weight to the
+/// start. The feedback arcs are the edges that don't point forward in the
+/// ordering. This runs in `O(m log n)` time.
+///
+/// Self-loops are always feedback arcs, and parallel edges are either all
+/// feedback arcs or none.
+///
+/// Arguments:
+///
+/// * `graph` - The directed graph to find a feedback arc set of.
+/// * `weight_fn` - A callable that will be passed an edge reference for each
+///   edge in the graph and is expected to return a `Result<f64>` which if it
+///   doesn't error represents the non-negative weight of that edge.
+///
+/// Returns the feedback arcs, in the order of `edge_references()`, and the
+/// ordering of the nodes. Every edge that isn't a feedback arc points forward
+/// in the ordering, so it's a topological ordering of the graph without the
+/// feedback arcs.
+///
+/// # Example
+/// ```rust
+/// use std::convert::Infallible;
+/// use rustworkx_core::feedback::greedy_feedback_arc_set;
+/// use rustworkx_core::petgraph::graph::{DiGraph, EdgeIndex, NodeIndex};
+///
+/// // A path with one edge back to the start
+/// let graph = DiGraph::<(), ()>::from_edges([(0, 1), (1, 2), (2, 3), (3, 0)]);
+/// let res: Result<_, Infallible> = greedy_feedback_arc_set(&graph, |_| Ok(1.));
+/// let (arcs, ordering) = res.unwrap();
+/// assert_eq!(arcs.len(), 1);
+/// assert_eq!(ordering.len(), 4);
+/// ```
+///
+/// [1]: Eades, Peter, Xuemin Lin, and William F. Smyth. "A fast and effective
+///     heuristic for the feedback arc set problem." Information Processing
+///     Letters 47.6 (1993): 319-323.
+///     <https://doi.org/10.1016/0020-0190(93)90079-O>
+pub fn greedy_feedback_arc_set<G, F, E>(
+    graph: G,
+    weight_fn: F,
+) -> Result<FeedbackArcSet<G::NodeId, G::EdgeId>, E>
+where
+    G: IntoEdgeReferences + IntoNodeIdentifiers + NodeIndexable,
+    F: FnMut(G::EdgeRef) -> Result<f64, E>,
+{
+    let arcs = Arcs::new(graph, weight_fn)?;
+    let ordering = greedy_ordering(&arcs);
+    Ok(arcs.feedback_arcs(&ordering))
+}
+
+/// Move single nodes of `ordering` to the position that minimizes the weight
+/// of the arcs that point backward, until no move improves it or
+/// `max_passes` passes over the nodes were made.
+fn improve_ordering<N, E>(arcs: &Arcs<N, E>, ordering: &mut Vec<usize>, max_passes: usize) {
+    let size = ordering.len();
+    let mut position: Vec<usize> = vec![0; size];
+    for (index, node) in ordering.iter().enumerate() {
+        position[*node] = index;
+    }
+    for _ in 0..max_passes {
+        let mut improved = false;
+        let nodes = ordering.clone();
+        for node in nodes {
+            let current = position[node];
+            // The change of the backward weight of the arcs of the node when
+            // it's moved past every neighbor, keyed by the position of the
+            // neighbor once the node is taken out of the ordering
+            let mut events: Vec<(usize, f64)> = Vec::new();
+            let mut cost = 0.;
+            let mut current_cost = 0.;
+            for (other, weight) in &arcs.outgoing[node] {
+                let slot = position[*other] - usize::from(position[*other] > current);
+                events.push((slot, *weight));
+                if position[*other] < current {
+                    current_cost += weight;
+                }
+            }
+            for (other, weight) in &arcs.incoming[node] {
+                let slot = position[*other] - usize::from(position[*other] > current);
+                events.push((slot, -weight));
+                cost += weight;
+                if position[*other] > current {
+                    current_cost += weight;
+                }
+            }
+            events.sort_by_key(|(slot, _)| *slot);
+            // Inserting the node right after a neighbor costs the same as
+            // inserting it anywhere up to the next neighbor
+            let (mut best_slot, mut best_cost) = (0, cost);
+            let mut events = events.into_iter().peekable();
+            while let Some((slot, change)) = events.next() {
+                cost += change;
+                if events.peek().is_some_and(|(next, _)| *next == slot) {
+                    continue;
+                }
+                if cost < best_cost {
+                    best_cost = cost;
+                    best_slot = slot + 1;
+                }
+            }
+            if best_cost < current_cost - 1e-12 * current_cost.abs().max(1.) {
+                ordering.remove(current);
+                ordering.insert(best_slot, node);
+                let (low, high) = (current.min(best_slot), current.max(best_slot));
+                for (index, moved) in ordering.iter().enumerate().take(high + 1).skip(low) {
+                    position[*moved] = index;
+                }
+                improved = true;
+            }
+        }
+        if !improved {
+            break;
+        }
+    }
+}
+
+/// Find a small feedback arc set of a directed graph with a local search.
+///
+/// This starts from the ordering of the nodes found by
+/// [`greedy_feedback_arc_set`] and improves it by repeatedly moving single
+/// nodes to the position in the ordering that minimizes the weight of the
+/// edges pointing backward, until no such move improves the ordering or
+/// `max_passes` passes over all the nodes were made. Every pass takes
+/// `O(m log n + k n)` time, where `k` is the number of nodes moved. The result
+/// is never worse than the one of [`greedy_feedback_arc_set`].
+///
+/// Self-loops are always feedback arcs, and parallel edges are either all
+/// feedback arcs or none.
+///
+/// Arguments:
+///
+/// * `graph` - The directed graph to find a feedback arc set of.
+/// * `weight_fn` - A callable that will be passed an edge reference for each
+///   edge in the graph and is expected to return a `Result<f64>` which if it
+///   doesn't error represents the non-negative weight of that edge.
+/// * `max_passes` - The maximum number of passes of the local search.
+///
+/// Returns the feedback arcs, in the order of `edge_references()`, and the
+/// ordering of the nodes. Every edge that isn't a feedback arc points forward
+/// in the ordering, so it's a topological ordering of the graph without the
+/// feedback arcs.
+///
+/// # Example
+/// ```rust
+/// use std::convert::Infallible;
+/// use rustworkx_core::feedback::local_search_feedback_arc_set;
+/// use rustworkx_core::petgraph::graph::{DiGraph, EdgeIndex};
+///
+/// // Two 2-cycles sharing the node 1
+/// let graph = DiGraph::<(), ()>::from_edges([(0, 1), (1, 0), (1, 2), (2, 1)]);
+/// let res: Result<_, Infallible> = local_search_feedback_arc_set(&graph, |_| Ok(1.), 10);
+/// let (arcs, ordering) = res.unwrap();
+/// assert_eq!(arcs.len(), 2);
+/// assert_eq!(ordering.len(), 3);
+/// ```
+pub fn local_search_feedback_arc_set<G, F, E>(
+    graph: G,
+    weight_fn: F,
+    max_passes: usize,
+) -> Result<FeedbackArcSet<G::NodeId, G::EdgeId>, E>
+where
+    G: IntoEdgeReferences + IntoNodeIdentifiers + NodeIndexable,
+    F: FnMut(G::EdgeRef) -> Result<f64, E>,
+{
+    let arcs = Arcs::new(graph, weight_fn)?;
+    let mut ordering = greedy_ordering(&arcs);
+    improve_ordering(&arcs, &mut ordering, max_passes);
+    Ok(arcs.feedback_arcs(&ordering))
+}
+
+/// The nodes of a graph relabeled to `0..n` with their neighbors, without
+/// self-loops. Parallel edges list a neighbor once per edge. For an
+/// undirected graph `incoming` is the same as `outgoing`.
+struct Neighbors {
+    outgoing: Vec<Vec<usize>>,
+    incoming: Vec<Vec<usize>>,
+    self_loop: Vec<bool>,
+}
+
+impl Neighbors {
+    fn new<G>(graph: G, nodes: &[G::NodeId]) -> Self
+    where
+        G: IntoEdgeReferences + NodeIndexable + GraphProp,
+    {
+        let mut compact: Vec<usize> = vec![usize::MAX; graph.node_bound()];
+        for (position, node) in nodes.iter().enumerate() {
+            compact[graph.to_index(*node)] = position;
+        }
+        let mut outgoing: Vec<Vec<usize>> = vec![Vec::new(); nodes.len()];
+        let mut incoming: Vec<Vec<usize>> = vec![Vec::new(); nodes.len()];
+        let mut self_loop: Vec<bool> = vec![false; nodes.len()];
+        for edge in graph.edge_references() {
+            let source = compact[graph.to_index(edge.source())];
+            let target = compact[graph.to_index(edge.target())];
+            if source == target {
+                self_loop[source] = true;
+            } else if graph.is_directed() {
+                outgoing[source].push(target);
+                incoming[target].push(source);
+            } else {
+                outgoing[source].push(target);
+                outgoing[target].push(source);
+            }
+        }
+        if !graph.is_directed() {
+            incoming.clone_from(&outgoing);
+        }
+        Neighbors {
+            outgoing,
+            incoming,
+            self_loop,
+        }
+    }
+}
+
+/// The nodes of a graph that are left after removing some of them, with the
+/// number of edges from and to the nodes that are left.
+struct Remaining<'a> {
+    neighbors: &'a Neighbors,
+    alive: Vec<bool>,
+    out_degree: Vec<usize>,
+    in_degree: Vec<usize>,
+}
+
+impl<'a> Remaining<'a> {
+    fn new(neighbors: &'a Neighbors) -> Self {
+        Remaining {
+            neighbors,
+            alive: vec![true; neighbors.outgoing.len()],
+            out_degree: neighbors.outgoing.iter().map(|n| n.len()).collect(),
+            in_degree: neighbors.incoming.iter().map(|n| n.len()).collect(),
+        }
+    }
+
+    fn remove(&mut self, node: usize) {
+        self.alive[node] = false;
+        for other in &self.neighbors.outgoing[node] {
+            self.in_degree[*other] -= 1;
+        }
+        for other in &self.neighbors.incoming[node] {
+            self.out_degree[*other] -= 1;
+        }
+    }
+
+    /// Repeatedly remove the nodes that can't be on a cycle, which are the
+    /// nodes without outgoing or without incoming edges for a directed graph
+    /// and the nodes with at most one edge for an undirected graph.
+    fn prune(&mut self, directed: bool) {
+        let removable = |remaining: &Self, node: usize| {
+            remaining.alive[node]
+                && if directed {
+                    remaining.out_degree[node] == 0 || remaining.in_degree[node] == 0
+                } else {
+                    remaining.out_degree[node] <= 1
+                }
+        };
+        let mut stack: Vec<usize> = (0..self.alive.len())
+            .filter(|node| removable(self, *node))
+            .collect();
+        while let Some(node) = stack.pop() {
+            if !removable(self, node) {
+                continue;
+            }
+            self.remove(node);
+            let neighbors = self.neighbors;
+            for other in neighbors.outgoing[node]
+                .iter()
+                .chain(neighbors.incoming[node].iter())
+            {
+                if removable(self, *other) {
+                    stack.push(*other);
+                }
+            }
+        }
+    }
+
+    /// The two neighbors of a node with two edges, listed once per edge.
+    fn ends(&self, node: usize) -> [usize; 2] {
+        let mut ends = self.neighbors.outgoing[node]
+            .iter()
+            .filter(|other| self.alive[**other]);
+        [*ends.next().unwrap(), *ends.next().unwrap()]
+    }
+
+    /// Follow the path of nodes with two edges that starts with the edge from
+    /// `node` to `next`, returning the nodes with two edges on it and the
+    /// node the path ends at.
+    fn follow(&self, node: usize, mut next: usize) -> (Vec<usize>, usize) {
+        let mut path: Vec<usize> = Vec::new();
+        let mut previous = node;
+        while next != node && self.out_degree[next] == 2 {
+            path.push(next);
+            let [first, second] = self.ends(next);
+            let after = if first == previous { second } else { first };
+            previous = next;
+            next = after;
+        }
+        (path, next)
+    }
+
+    /// Find a cycle of an undirected graph without nodes with less than two
+    /// edges on which at most one node has more than two edges.
+    fn semidisjoint_cycle(&self) -> Option<Vec<usize>> {
+        let mut visited: Vec<bool> = vec![false; self.alive.len()];
+        for node in 0..self.alive.len() {
+            if !self.alive[node] || self.out_degree[node] != 2 || visited[node] {
+                continue;
+            }
+            let [first, second] = self.ends(node);
+            let (mut cycle, first_end) = self.follow(node, first);
+            cycle.push(node);
+            if first_end == node {
+                return Some(cycle);
+            }
+            let (path, second_end) = self.follow(node, second);
+            cycle.extend(path);
+            if first_end == second_end {
+                cycle.push(first_end);
+                return Some(cycle);
+            }
+            for other in cycle {
+                visited[other] = true;
+            }
+        }
+        None
+    }
+}
+
+/// Disjoint sets of nodes with path compression.
+fn find(parent: &mut [usize], mut node: usize) -> usize {
+    while parent[node] != node {
+        parent[node] = parent[parent[node]];
+        node = parent[node];
+    }
+    node
+}
+
+/// The feedback vertex set of an undirected graph found with the local ratio
+/// algorithm of Bafna, Berman and Fujito.
+fn undirected_feedback_vertex_set(neighbors: &Neighbors, weights: &[f64]) -> Vec<usize> {
+    let size = weights.len();
+    let mut weights = weights.to_vec();
+    let mut remaining = Remaining::new(neighbors);
+    let mut solution: Vec<usize> = Vec::new();
+    for (node, weight) in weights.iter().enumerate() {
+        if neighbors.self_loop[node] || *weight <= 0. {
+            solution.push(node);
+            remaining.remove(node);
+        }
+    }
+    loop {
+        remaining.prune(false);
+        let active: Vec<usize> = (0..size).filter(|node| remaining.alive[*node]).collect();
+        if active.is_empty() {
+            break;
+        }
+        // Lower the weights by a multiple of a weight function for which
+        // every minimal feedback vertex set is within a factor of 2 of the
+        // optimum, until the weight of some node drops to zero
+        if let Some(cycle) = remaining.semidisjoint_cycle() {
+            let lightest = *cycle
+                .iter()
+                .min_by(|a, b| weights[**a].total_cmp(&weights[**b]).then(a.cmp(b)))
+                .unwrap();
+            let gamma = weights[lightest];
+            for node in cycle {
+                weights[node] -= gamma;
+            }
+            weights[lightest] = 0.;
+        } else {
+            let ratio = |node: usize| weights[node] / (remaining.out_degree[node] - 1) as f64;
+            let lightest = *active
+                .iter()
+                .min_by(|a, b| ratio(**a).total_cmp(&ratio(**b)).then(a.cmp(b)))
+                .unwrap();
+            let gamma = ratio(lightest);
+            for node in &active {
+                weights[*node] -= gamma * (remaining.out_degree[*node] - 1) as f64;
+            }
+            weights[lightest] = 0.;
+        }
+        for node in active {
+            if weights[node] <= 0. {
+                solution.push(node);
+                remaining.remove(node);
+            }
+        }
+    }
+    // Drop the nodes that aren't needed, in the reverse order they were
+    // added, by adding them back to the forest that is left if it stays one
+    let mut in_solution: Vec<bool> = vec![false; size];
+    for node in &solution {
+        in_solution[*node] = true;
+    }
+    let mut parent: Vec<usize> = (0..size).collect();
+    for node in 0..size {
+        for other in &neighbors.outgoing[node] {
+            if node < *other && !in_solution[node] && !in_solution[*other] {
+                let (a, b) = (find(&mut parent, node), find(&mut parent, *other));
+                parent[a] = b;
+            }
+        }
+    }
+    for node in solution.iter().rev() {
+        if neighbors.self_loop[*node] {
+            continue;
+        }
+        let mut roots: Vec<usize> = neighbors.outgoing[*node]
+            .iter()
+            .filter(|other| !in_solution[**other])
+            .map(|other| find(&mut parent, *other))
+            .collect();
+        let count = roots.len();
+        roots.sort_unstable();
+        roots.dedup();
+        if roots.len() == count {
+            in_solution[*node] = false;
+            for root in roots {
+                parent[root] = *node;
+            }
+        }
+    }
+    (0..size).filter(|node| in_solution[*node]).collect()
+}
+
+/// The feedback vertex set of a directed graph found by repeatedly removing
+/// the node with the largest product of in and out degree per weight.
+fn directed_feedback_vertex_set(neighbors: &Neighbors, weights: &[f64]) -> Vec<usize> {
+    let size = weights.len();
+    let mut remaining = Remaining::new(neighbors);
+    let mut solution: Vec<usize> = Vec::new();
+    for (node, weight) in weights.iter().enumerate() {
+        if neighbors.self_loop[node] || *weight <= 0. {
+            solution.push(node);
+            remaining.remove(node);
+        }
+    }
+    loop {
+        remaining.prune(true);
+        let score = |node: usize| {
+            (remaining.in_degree[node] * remaining.out_degree[node]) as f64 / weights[node]
+        };
+        let best = (0..size)
+            .filter(|node| remaining.alive[*node])
+            .max_by(|a, b| score(*a).total_cmp(&score(*b)).then(b.cmp(a)));
+        match best {
+            Some(node) => {
+                solution.push(node);
+                remaining.remove(node);
+            }
+            None => break,
+        }
+    }
+    // Drop the nodes that aren't needed, in the reverse order they were
+    // added, by adding them back to the DAG that is left if no cycle through
+    // them is formed
+    let mut in_solution: Vec<bool> = vec![false; size];
+    for node in &solution {
+        in_solution[*node] = true;
+    }
+    let mut seen: Vec<usize> = vec![usize::MAX; size];
+    for node in solution.iter().rev() {
+        if neighbors.self_loop[*node] {
+            continue;
+        }
+        let mut stack: Vec<usize> = vec![*node];
+        let mut on_cycle = false;
+        seen[*node] = *node;
+        'search: while let Some(current) = stack.pop() {
+            for other in &neighbors.outgoing[current] {
+                if *other == *node {
+                    on_cycle = true;
+                    break 'search;
+                }
+                if !in_solution[*other] && seen[*other] != *node {
+                    seen[*other] = *node;
+                    stack.push(*other);
+                }
+            }
+        }
+        if !on_cycle {
+            in_solution[*node] = false;
+        }
+    }
+    (0..size).filter(|node| in_solution[*node]).collect()
+}
+
+/// Find a small feedback vertex set of a graph.
+///
+/// A feedback vertex set is a set of nodes whose removal makes the graph
+/// acyclic. Finding one of minimum weight is NP-hard for both undirected and
+/// directed graphs.
+///
+/// For an undirected graph this uses the local ratio algorithm of Bafna,
+/// Berman and Fujito [1], which finds a feedback vertex set whose weight is
+/// at most twice the minimum. It runs in `O(n (n + m))` time.
+///
+/// For a directed graph this uses a heuristic without an approximation
+/// guarantee: after removing the nodes that can't be on a cycle, it
+/// repeatedly adds the node with the largest product of its in and out
+/// degree divided by its weight to the set, until the graph is acyclic.
+/// It runs in `O(n (n + m))` time.
+///
+/// In both cases the nodes that aren't needed are dropped from the set
+/// afterwards, so it's minimal: removing any node from it leaves a cycle.
+/// Nodes with self-loops are always in the set and parallel edges form a
+/// cycle.
+///
+/// Arguments:
+///
+/// * `graph` - The graph to find a feedback vertex set of.
+/// * `weight_fn` - A callable that will be passed the id of each node in the
+///   graph and is expected to return a `Result<f64>` which if it doesn't
+///   error represents the non-negative weight of that node. Nodes with zero
+///   weight are added to the set unless they aren't needed.
+///
+/// Returns the nodes of the feedback vertex set in the order of
+/// `node_identifiers()`.
+///
+/// # Example
+/// ```rust
+/// use std::convert::Infallible;
+/// use rustworkx_core::feedback::feedback_vertex_set;
+/// use rustworkx_core::petgraph::graph::{NodeIndex, UnGraph};
+///
+/// // Two triangles sharing the node 2
+/// let graph = UnGraph::<(), ()>::from_edges([(0, 1), (1, 2), (2, 0), (2, 3), (3, 4), (4, 2)]);
+/// let res: Result<_, Infallible> = feedback_vertex_set(&graph, |_| Ok(1.));
+/// assert_eq!(res.unwrap(), vec![NodeIndex::new(2)]);
+/// ```
+///
+/// [1]: Bafna, Vineet, Piotr Berman, and Toshihiro Fujito. "A 2-approximation
+///     algorithm for the undirected feedback vertex set problem." SIAM Journal
+///     on Discrete Mathematics 12.3 (1999): 289-297.
+///     <https://doi.org/10.1137/S0895480196305124>
+pub fn feedback_vertex_set<G, F, E>(graph: G, mut weight_fn: F) -> Result<Vec<G::NodeId>, E>
+where
+    G: IntoEdgeReferences + IntoNodeIdentifiers + NodeIndexable + GraphProp,
+    F: FnMut(G::NodeId) -> Result<f64, E>,
+{
+    let nodes: Vec<G::NodeId> = graph.node_identifiers().collect();
+    let weights: Vec<f64> = nodes
+        .iter()
+        .map(|node| weight_fn(*node))
+        .collect::<Result<_, E>>()?;
+    let neighbors = Neighbors::new(graph, &nodes);
+    let solution = if graph.is_directed() {
+        directed_feedback_vertex_set(&neighbors, &weights)
+    } else {
+        undirected_feedback_vertex_set(&neighbors, &weights)
+    };
+    Ok(solution.into_iter().map(|node| nodes[node]).collect())
+}
+
+#[cfg(test)]
+mod test_feedback_arc_set {
+    use super::{greedy_feedback_arc_set, local_search_feedback_arc_set, FeedbackArcSet};
+    use crate::petgraph::graph::{DiGraph, EdgeIndex, NodeIndex};
+    use crate::petgraph::visit::EdgeRef;
+    use std::convert::Infallible;
+
+    fn assert_valid(graph: &DiGraph<(), f64>, result: &FeedbackArcSet<NodeIndex, EdgeIndex>) {
+        let (arcs, ordering) = result;
+        assert_eq!(ordering.len(), graph.node_count());
+        let mut position = vec![usize::MAX; graph.node_count()];
+        for (index, node) in ordering.iter().enumerate() {
+            position[node.index()] = index;
+        }
+        assert!(position.iter().all(|p| *p != usize::MAX));
+        for edge in graph.edge_references() {
+            let backward = position[edge.source().index()] >= position[edge.target().index()];
+            assert_eq!(backward, arcs.contains(&edge.id()));
+        }
+    }
+
+    fn weight(arcs: &[EdgeIndex], graph: &DiGraph<(), f64>) -> f64 {
+        arcs.iter().map(|e| graph[*e]).sum()
+    }
+
+    #[test]
+    fn test_empty() {
+        let graph = DiGraph::<(), f64>::new();
+        let res: Result<_, Infallible> = greedy_feedback_arc_set(&graph, |e| Ok(*e.weight()));
+        assert_eq!(res.unwrap(), (vec![], vec![]));
+        let res: Result<_, Infallible> =
+            local_search_feedback_arc_set(&graph, |e| Ok(*e.weight()), 10);
+        assert_eq!(res.unwrap(), (vec![], vec![]));
+    }
+
+    #[test]
+    fn test_dag_has_no_feedback_arcs() {
+        let graph = DiGraph::<(), f64>::from_edges([
+            (0, 1, 1.),
+            (0, 2, 1.),
+            (1, 3, 1.),
+            (2, 3, 1.),
+            (3, 4, 1.),
+        ]);
+        let res: Result<_, Infallible> = greedy_feedback_arc_set(&graph, |e| Ok(*e.weight()));
+        let res = res.unwrap();
+        assert!(res.0.is_empty());
+        assert_valid(&graph, &res);
+    }
+
+    #[test]
+    fn test_self_loop_is_feedback_arc() {
+        let graph = DiGraph::<(), f64>::from_edges([(0, 1, 1.), (1, 1, 1.)]);
+        let res: Result<_, Infallible> = greedy_feedback_arc_set(&graph, |e| Ok(*e.weight()));
+        let res = res.unwrap();
+        assert_eq!(res.0, vec![EdgeIndex::new(1)]);
+        assert_valid(&graph, &res);
+    }
+
+    #[test]
+    fn test_weights_pick_lightest_arc() {
+        let graph =
+            DiGraph::<(), f64>::from_edges([(0, 1, 5.), (1, 2, 5.), (2, 3, 5.), (3, 0, 1.)]);
+        let res: Result<_, Infallible> =
+            local_search_feedback_arc_set(&graph, |e| Ok(*e.weight()), 10);
+        let res = res.unwrap();
+        assert_eq!(res.0, vec![EdgeIndex::new(3)]);
+        assert_valid(&graph, &res);
+    }
+
+    #[test]
+    fn test_local_search_not_worse_than_greedy() {
+        let mut graph = DiGraph::<(), f64>::new();
+        for _ in 0..12 {
+            graph.add_node(());
+        }
+        for i in 0..12_usize {
+            for j in [1, 3, 7] {
+                let weight = ((i * 7 + j * 3) % 5 + 1) as f64;
+                graph.add_edge(NodeIndex::new(i), NodeIndex::new((i + j) % 12), weight);
+            }
+        }
+        let greedy: Result<_, Infallible> = greedy_feedback_arc_set(&graph, |e| Ok(*e.weight()));
+        let greedy = greedy.unwrap();
+        let local: Result<_, Infallible> =
+            local_search_feedback_arc_set(&graph, |e| Ok(*e.weight()), 100);
+        let local = local.unwrap();
+        assert_valid(&graph, &greedy);
+        assert_valid(&graph, &local);
+        assert!(weight(&local.0, &graph) <= weight(&greedy.0, &graph));
+    }
+
+    #[test]
+    fn test_local_search_improves_ordering() {
+        // Greedy removes node 0 first since it has the largest out - in
+        // weight, which forces all of the heavy arcs into it backward
+        let graph = DiGraph::<(), f64>::from_edges([
+            (0, 1, 1.),
+            (0, 2, 1.),
+            (0, 3, 1.),
+            (1, 0, 2.9),
+            (1, 2, 1.),
+            (2, 1, 1.),
+        ]);
+        let greedy: Result<_, Infallible> = greedy_feedback_arc_set(&graph, |e| Ok(*e.weight()));
+        let greedy = greedy.unwrap();
+        let local: Result<_, Infallible> =
+            local_search_feedback_arc_set(&graph, |e| Ok(*e.weight()), 100);
+        let local = local.unwrap();
+        assert_valid(&graph, &greedy);
+        assert_valid(&graph, &local);
+        assert!(weight(&local.0, &graph) <= weight(&greedy.0, &graph));
+    }
+
+    #[test]
+    fn test_error_propagates() {
+        let graph = DiGraph::<(), f64>::from_edges([(0, 1, 1.), (1, 0, 1.)]);
+        let res: Result<_, &str> = greedy_feedback_arc_set(&graph, |_| Err("bad"));
+        assert_eq!(res, Err("bad"));
+    }
+}
+
+#[cfg(test)]
+mod test_feedback_vertex_set {
+    use super::feedback_vertex_set;
+    use crate::petgraph::algo::{is_cyclic_directed, is_cyclic_undirected};
+    use crate::petgraph::graph::{DiGraph, NodeIndex, UnGraph};
+    use crate::petgraph::visit::NodeFiltered;
+    use std::convert::Infallible;
+
+    fn undirected_acyclic_without(graph: &UnGraph<f64, ()>, nodes: &[NodeIndex]) -> bool {
+        !is_cyclic_undirected(&NodeFiltered::from_fn(graph, |n| !nodes.contains(&n)))
+    }
+
+    fn directed_acyclic_without(graph: &DiGraph<f64, ()>, nodes: &[NodeIndex]) -> bool {
+        !is_cyclic_directed(&NodeFiltered::from_fn(graph, |n| !nodes.contains(&n)))
+    }
+
+    /// The weight of a minimum feedback vertex set, by trying every subset.
+    fn optimum(graph: &UnGraph<f64, ()>) -> f64 {
+        let size = graph.node_count();
+        (0..1_u32 << size)
+            .filter_map(|mask| {
+                let nodes: Vec<NodeIndex> = (0..size)
+                    .filter(|i| mask & (1 << i) != 0)
+                    .map(NodeIndex::new)
+                    .collect();
+                undirected_acyclic_without(graph, &nodes)
+                    .then(|| nodes.iter().map(|n| graph[*n]).sum::<f64>())
+            })
+            .fold(f64::INFINITY, f64::min)
+    }
+
+    #[test]
+    fn test_empty() {
+        let graph = UnGraph::<f64, ()>::default();
+        let res: Result<_, Infallible> = feedback_vertex_set(&graph, |n| Ok(graph[n]));
+        assert_eq!(res.unwrap(), vec![]);
+        let graph = DiGraph::<f64, ()>::default();
+        let res: Result<_, Infallible> = feedback_vertex_set(&graph, |n| Ok(graph[n]));
+        assert_eq!(res.unwrap(), vec![]);
+    }
+
+    #[test]
+    fn test_forest_and_dag() {
+        let graph = UnGraph::<f64, ()>::from_edges([(0, 1), (1, 2), (1, 3), (4, 5)]);
+        let res: Result<_, Infallible> = feedback_vertex_set(&graph, |_| Ok(1.));
+        assert_eq!(res.unwrap(), vec![]);
+        let graph = DiGraph::<f64, ()>::from_edges([(0, 1), (1, 2), (0, 2), (2, 3)]);
+        let res: Result<_, Infallible> = feedback_vertex_set(&graph, |_| Ok(1.));
+        assert_eq!(res.unwrap(), vec![]);
+    }
+
+    #[test]
+    fn test_self_loop_and_parallel_edges() {
+        let graph = UnGraph::<f64, ()>::from_edges([(0, 0), (1, 2), (1, 2)]);
+        let res: Result<_, Infallible> = feedback_vertex_set(&graph, |_| Ok(1.));
+        let res = res.unwrap();
+        assert_eq!(res.len(), 2);
+        assert!(res.contains(&NodeIndex::new(0)));
+        assert!(undirected_acyclic_without(&graph, &res));
+    }
+
+    #[test]
+    fn test_weights() {
+        // A cycle where node 3 is much cheaper than the others
+        let mut graph = UnGraph::<f64, ()>::from_edges([(0, 1), (1, 2), (2, 3), (3, 0)]);
+        for node in 0..4 {
+            graph[NodeIndex::new(node)] = if node == 3 { 0.5 } else { 4. };
+        }
+        let res: Result<_, Infallible> = feedback_vertex_set(&graph, |n| Ok(graph[n]));
+        assert_eq!(res.unwrap(), vec![NodeIndex::new(3)]);
+    }
+
+    #[test]
+    fn test_undirected_within_factor_two() {
+        let mut graph = UnGraph::<f64, ()>::default();
+        for node in 0..10 {
+            graph.add_node(((node * 7) % 5 + 1) as f64);
+        }
+        for (i, j) in [
+            (0, 1),
+            (1, 2),
+            (2, 0),
+            (2, 3),
+            (3, 4),
+            (4, 5),
+            (5, 2),
+            (5, 6),
+            (6, 7),
+            (7, 8),
+            (8, 9),
+            (9, 6),
+            (1, 8),
+            (0, 4),
+        ] {
+            graph.add_edge(NodeIndex::new(i), NodeIndex::new(j), ());
+        }
+        let res: Result<_, Infallible> = feedback_vertex_set(&graph, |n| Ok(graph[n]));
+        let res = res.unwrap();
+        assert!(undirected_acyclic_without(&graph, &res));
+        let weight: f64 = res.iter().map(|n| graph[*n]).sum();
+        assert!(weight <= 2. * optimum(&graph));
+    }
+
+    #[test]
+    fn test_directed() {
+        let graph = DiGraph::<f64, ()>::from_edges([
+            (0, 1),
+            (1, 2),
+            (2, 0),
+            (2, 3),
+            (3, 4),
+            (4, 2),
+            (4, 5),
+            (5, 5),
+            (1, 6),
+            (6, 1),
+        ]);
+        let res: Result<_, Infallible> = feedback_vertex_set(&graph, |_| Ok(1.));
+        let res = res.unwrap();
+        assert!(res.contains(&NodeIndex::new(5)));
+        assert!(directed_acyclic_without(&graph, &res));
+        // Every node is needed
+        for node in &res {
+            let smaller: Vec<NodeIndex> = res.iter().filter(|n| *n != node).copied().collect();
+            assert!(!directed_acyclic_without(&graph, &smaller));
+        }
+    }
+}
@@ -0,0 +1,119 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+// A minimum cost flow network shared by the algorithms reduced to flows.
+
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+
+/// A minimum cost flow network solved with successive shortest paths.
+pub(crate) struct FlowNetwork {
+    /// The arcs as pairs of the head and the residual capacity. The reverse
+    /// of arc `i` is arc `i ^ 1`.
+    pub(crate) arcs: Vec<(usize, usize)>,
+    pub(crate) costs: Vec<i128>,
+    pub(crate) adjacency: Vec<Vec<usize>>,
+    pub(crate) potentials: Vec<i128>,
+}
+
+impl FlowNetwork {
+    pub(crate) fn new(num_nodes: usize) -> Self {
+        FlowNetwork {
+            arcs: Vec::new(),
+            costs: Vec::new(),
+            adjacency: vec![Vec::new(); num_nodes],
+            potentials: vec![0; num_nodes],
+        }
+    }
+
+    pub(crate) fn add_arc(&mut self, tail: usize, head: usize, capacity: usize, cost: i128) {
+        self.adjacency[tail].push(self.arcs.len());
+        self.arcs.push((head, capacity));
+        self.costs.push(cost);
+        self.adjacency[head].push(self.arcs.len());
+        self.arcs.push((tail, 0));
+        self.costs.push(-cost);
+    }
+
+    /// Set the potentials to the distances from `source`, which makes the
+    /// reduced costs non-negative. The arcs must have been added in a
+    /// topological order of the network.
+    pub(crate) fn init_potentials(&mut self, source: usize) {
+        let mut distance: Vec<Option<i128>> = vec![None; self.adjacency.len()];
+        distance[source] = Some(0);
+        for arc in (0..self.arcs.len()).step_by(2) {
+            let tail = self.arcs[arc ^ 1].0;
+            let head = self.arcs[arc].0;
+            if let Some(dist) = distance[tail] {
+                let dist = dist + self.costs[arc];
+                if distance[head].map_or(true, |current| dist < current) {
+                    distance[head] = Some(dist);
+                }
+            }
+        }
+        for (potential, dist) in self.potentials.iter_mut().zip(distance) {
+            *potential = dist.unwrap_or(0);
+        }
+    }
+
+    /// Send one unit of flow along a cheapest path from `source` to `sink`
+    /// if its cost is accepted by `accept`. Returns whether a unit was sent.
+    pub(crate) fn augment<A>(&mut self, source: usize, sink: usize, accept: A) -> bool
+    where
+        A: FnOnce(i128) -> bool,
+    {
+        let num_nodes = self.adjacency.len();
+        let mut distance: Vec<Option<i128>> = vec![None; num_nodes];
+        let mut via: Vec<usize> = vec![usize::MAX; num_nodes];
+        let mut done: Vec<bool> = vec![false; num_nodes];
+        let mut heap = BinaryHeap::from([Reverse((0, source))]);
+        distance[source] = Some(0);
+        while let Some(Reverse((dist, node))) = heap.pop() {
+            if done[node] {
+                continue;
+            }
+            done[node] = true;
+            for arc in &self.adjacency[node] {
+                let (head, capacity) = self.arcs[*arc];
+                if capacity == 0 || done[head] {
+                    continue;
+                }
+                let reduced = self.costs[*arc] + self.potentials[node] - self.potentials[head];
+                let next = dist + reduced;
+                if distance[head].map_or(true, |current| next < current) {
+                    distance[head] = Some(next);
+                    via[head] = *arc;
+                    heap.push(Reverse((next, head)));
+                }
+            }
+        }
+        if distance[sink].is_none() {
+            return false;
+        }
+        for (potential, dist) in self.potentials.iter_mut().zip(&distance) {
+            if let Some(dist) = dist {
+                *potential += dist;
+            }
+        }
+        if !accept(self.potentials[sink] - self.potentials[source]) {
+            return false;
+        }
+        let mut node = sink;
+        while node != source {
+            let arc = via[node];
+            self.arcs[arc].1 -= 1;
+            self.arcs[arc ^ 1].1 += 1;
+            node = self.arcs[arc ^ 1].0;
+        }
+        true
+    }
+}
@@ -22,6 +22,7 @@ use petgraph::visit::{
 };
 use petgraph::{Incoming, Outgoing};
 
+use crate::rng::{rng_from_seed, UniformIndex};
 use hashbrown::HashSet;
 use rand::distributions::{Distribution, Uniform};
 use rand::prelude::*;
@@ -93,10 +94,7 @@ where
     if num_nodes == 0 {
         return Err(InvalidInputError {});
     }
-    let mut rng: Pcg64 = match seed {
-        Some(seed) => Pcg64::seed_from_u64(seed),
-        None => Pcg64::from_entropy(),
-    };
+    let mut rng: Pcg64 = rng_from_seed(seed);
     let mut graph = G::with_capacity(num_nodes, num_nodes);
     let directed = graph.is_directed();
 
@@ -263,10 +261,7 @@ where
         found
     }
 
-    let mut rng: Pcg64 = match seed {
-        Some(seed) => Pcg64::seed_from_u64(seed),
-        None => Pcg64::from_entropy(),
-    };
+    let mut rng: Pcg64 = rng_from_seed(seed);
     let mut graph = G::with_capacity(num_nodes, num_edges);
     let directed = graph.is_directed();
 
@@ -290,7 +285,7 @@ where
         }
     } else {
         let mut created_edges: usize = 0;
-        let between = Uniform::new(0, num_nodes);
+        let between = UniformIndex::new(num_nodes);
         while created_edges < num_edges {
             let u = between.sample(&mut rng);
             let v = between.sample(&mut rng);
@@ -380,10 +375,7 @@ where
     for _ in 0..num_nodes {
         graph.add_node(default_node_weight());
     }
-    let mut rng: Pcg64 = match seed {
-        Some(seed) => Pcg64::seed_from_u64(seed),
-        None => Pcg64::from_entropy(),
-    };
+    let mut rng: Pcg64 = rng_from_seed(seed);
     let mut blocks = Vec::new();
     {
         let mut block = 0;
@@ -512,10 +504,7 @@ where
     if num_nodes == 0 {
         return Err(InvalidInputError {});
     }
-    let mut rng: Pcg64 = match seed {
-        Some(seed) => Pcg64::seed_from_u64(seed),
-        None => Pcg64::from_entropy(),
-    };
+    let mut rng: Pcg64 = rng_from_seed(seed);
     let mut graph = G::with_capacity(num_nodes, num_nodes);
 
     let radius_p = pnorm(radius, p);
@@ -615,10 +604,7 @@ where
     if m < 1 || m >= n {
         return Err(InvalidInputError {});
     }
-    let mut rng: Pcg64 = match seed {
-        Some(seed) => Pcg64::seed_from_u64(seed),
-        None => Pcg64::from_entropy(),
-    };
+    let mut rng: Pcg64 = rng_from_seed(seed);
     let mut graph = match initial_graph {
         Some(initial_graph) => initial_graph,
         None => star_graph(
@@ -719,10 +705,7 @@ where
         return Err(InvalidInputError {});
     }
 
-    let mut rng: Pcg64 = match seed {
-        Some(seed) => Pcg64::seed_from_u64(seed),
-        None => Pcg64::from_entropy(),
-    };
+    let mut rng: Pcg64 = rng_from_seed(seed);
     let mut graph = G::with_capacity(num_l_nodes + num_r_nodes, num_l_nodes + num_r_nodes);
 
     for _ in 0..num_l_nodes + num_r_nodes {
@@ -821,10 +804,7 @@ where
         return Err(InvalidInputError {});
     }
 
-    let mut rng: Pcg64 = match seed {
-        Some(seed) => Pcg64::seed_from_u64(seed),
-        None => Pcg64::from_entropy(),
-    };
+    let mut rng: Pcg64 = rng_from_seed(seed);
     let mut graph = G::with_capacity(num_nodes, num_nodes);
     if graph.is_directed() {
         return Err(InvalidInputError {});
@@ -0,0 +1,290 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+//! This module contains [`EdgeStreamBuilder`], which builds a graph from a
+//! stream of edges.
+//!
+//! The edges are added in chunks, as they are read from a file or produced
+//! by a generator, so the whole edge list never has to be in memory. Nodes
+//! are added as the edges refer to them, and parallel edges can be merged
+//! as they arrive instead of in a second pass over the graph.
+//!
+//! ```rust
+//! use rustworkx_core::graph_builder::EdgeStreamBuilder;
+//! use rustworkx_core::petgraph::graph::UnGraph;
+//!
+//! let mut builder: EdgeStreamBuilder<UnGraph<(), u32>, _> =
+//!     EdgeStreamBuilder::new(4, 4, || ()).deduplicate();
+//! for chunk in [[(0, 1, 1), (1, 2, 1)], [(2, 1, 5), (2, 3, 1)]] {
+//!     builder.add_edges(chunk).unwrap();
+//! }
+//! let graph = builder.finish();
+//! assert_eq!(graph.node_count(), 4);
+//! assert_eq!(graph.edge_count(), 3);
+//! ```
+
+use std::convert::Infallible;
+use std::hash::Hash;
+
+use hashbrown::HashMap;
+use petgraph::data::{Build, Create, DataMapMut};
+use petgraph::visit::{GraphProp, NodeIndexable};
+
+use crate::err::EdgeStreamError;
+
+/// A builder for a graph from a stream of edges given by node index.
+///
+/// The endpoints of the edges are node indices in the built graph. When an
+/// edge refers to a node index that isn't in the graph yet, nodes are added
+/// up to that index with the weight from the node weight callback. Since the
+/// indices may come from an untrusted source, [`EdgeStreamBuilder::node_limit`]
+/// can bound the number of nodes instead.
+///
+/// By default every edge is added, so parallel edges are kept. After
+/// [`EdgeStreamBuilder::deduplicate`] only the first edge between two nodes
+/// is added and the weights of later parallel edges are merged into it. In
+/// an undirected graph the edges `(a, b)` and `(b, a)` are parallel.
+pub struct EdgeStreamBuilder<G, F>
+where
+    G: Build + DataMapMut,
+{
+    graph: G,
+    default_node_weight: F,
+    edge_ids: Option<HashMap<(usize, usize), G::EdgeId>>,
+    edge_capacity: usize,
+    node_limit: Option<usize>,
+}
+
+impl<G, F> EdgeStreamBuilder<G, F>
+where
+    G: Build + Create + DataMapMut + GraphProp + NodeIndexable,
+    G::EdgeId: Copy + Eq + Hash,
+    F: FnMut() -> G::NodeWeight,
+{
+    /// Create a builder for an empty graph.
+    ///
+    /// Arguments:
+    ///
+    /// * `num_nodes` - The expected number of nodes, which is preallocated.
+    /// * `num_edges` - The expected number of edges, which is preallocated.
+    /// * `default_node_weight` - A callback which returns the weight of the
+    ///   nodes added for the edges.
+    pub fn new(num_nodes: usize, num_edges: usize, default_node_weight: F) -> Self {
+        EdgeStreamBuilder {
+            graph: G::with_capacity(num_nodes, num_edges),
+            default_node_weight,
+            edge_ids: None,
+            edge_capacity: num_edges,
+            node_limit: None,
+        }
+    }
+
+    /// Reject edges with an endpoint whose index isn't below `node_limit`
+    /// instead of adding nodes up to that index.
+    pub fn node_limit(mut self, node_limit: usize) -> Self {
+        self.node_limit = Some(node_limit);
+        self
+    }
+
+    /// Merge parallel edges as they are added instead of keeping them.
+    pub fn deduplicate(mut self) -> Self {
+        self.edge_ids = Some(HashMap::with_capacity(self.edge_capacity));
+        self
+    }
+
+    /// Add a chunk of edges.
+    ///
+    /// If the builder deduplicates edges, the weight of an edge parallel
+    /// to an edge which was already added replaces the weight of that edge.
+    ///
+    /// Arguments:
+    ///
+    /// * `edges` - The edges to add as `(source, target, weight)` tuples.
+    ///
+    /// Returns an [`EdgeStreamError::NodeLimit`] error with the index of the
+    /// first endpoint which isn't below the node limit, with the edges before
+    /// it added.
+    pub fn add_edges<I>(&mut self, edges: I) -> Result<(), EdgeStreamError<Infallible>>
+    where
+        I: IntoIterator<Item = (usize, usize, G::EdgeWeight)>,
+    {
+        self.add_edges_with(edges, |weight, new_weight| {
+            *weight = new_weight;
+            Ok(())
+        })
+    }
+
+    /// Add a chunk of edges, merging the weights of parallel edges with a
+    /// callback.
+    ///
+    /// If the builder doesn't deduplicate edges `combine` is never called.
+    ///
+    /// Arguments:
+    ///
+    /// * `edges` - The edges to add as `(source, target, weight)` tuples.
+    /// * `combine` - A callback which is passed the weight of an edge which
+    ///   was already added and the weight of a parallel edge, and merges the
+    ///   latter into the former. If it returns an error, adding the chunk is
+    ///   stopped and the error is returned as an
+    ///   [`EdgeStreamError::CombineError`], with the edges before it added.
+    ///
+    /// Adding the chunk is also stopped, with an [`EdgeStreamError::NodeLimit`]
+    /// error, at the first endpoint which isn't below the node limit.
+    ///
+    /// # Example
+    /// ```rust
+    /// use rustworkx_core::graph_builder::EdgeStreamBuilder;
+    /// use rustworkx_core::petgraph::graph::{DiGraph, EdgeIndex};
+    ///
+    /// let mut builder: EdgeStreamBuilder<DiGraph<(), f64>, _> =
+    ///     EdgeStreamBuilder::new(0, 0, || ()).deduplicate();
+    /// let res = builder.add_edges_with(
+    ///     [(0, 1, 1.0), (1, 0, 2.0), (0, 1, 3.0)],
+    ///     |weight, new_weight| {
+    ///         *weight += new_weight;
+    ///         Ok::<(), ()>(())
+    ///     },
+    /// );
+    /// res.unwrap();
+    /// let graph = builder.finish();
+    /// assert_eq!(graph.edge_count(), 2);
+    /// assert_eq!(graph[EdgeIndex::new(0)], 4.0);
+    /// ```
+    pub fn add_edges_with<I, C, E>(
+        &mut self,
+        edges: I,
+        mut combine: C,
+    ) -> Result<(), EdgeStreamError<E>>
+    where
+        I: IntoIterator<Item = (usize, usize, G::EdgeWeight)>,
+        C: FnMut(&mut G::EdgeWeight, G::EdgeWeight) -> Result<(), E>,
+    {
+        let directed = self.graph.is_directed();
+        for (source, target, weight) in edges {
+            let max_index = source.max(target);
+            if self.node_limit.is_some_and(|limit| max_index >= limit) {
+                return Err(EdgeStreamError::NodeLimit(max_index));
+            }
+            while self.graph.node_bound() <= max_index {
+                self.graph.add_node((self.default_node_weight)());
+            }
+            let key = if directed || source <= target {
+                (source, target)
+            } else {
+                (target, source)
+            };
+            if let Some(edge) = self
+                .edge_ids
+                .as_ref()
+                .and_then(|edge_ids| edge_ids.get(&key))
+            {
+                let old_weight = self.graph.edge_weight_mut(*edge).unwrap();
+                combine(old_weight, weight).map_err(EdgeStreamError::CombineError)?;
+                continue;
+            }
+            let edge = self
+                .graph
+                .add_edge(
+                    self.graph.from_index(source),
+                    self.graph.from_index(target),
+                    weight,
+                )
+                .unwrap();
+            if let Some(edge_ids) = self.edge_ids.as_mut() {
+                edge_ids.insert(key, edge);
+            }
+        }
+        Ok(())
+    }
+
+    /// Get the graph built so far.
+    pub fn graph(&self) -> &G {
+        &self.graph
+    }
+
+    /// Finish building and return the graph.
+    pub fn finish(self) -> G {
+        self.graph
+    }
+}
+
+#[cfg(test)]
+mod test_graph_builder {
+    use super::*;
+    use petgraph::graph::{DiGraph, EdgeIndex, NodeIndex, UnGraph};
+    use petgraph::stable_graph::StableDiGraph;
+
+    #[test]
+    fn test_multigraph() {
+        let mut builder: EdgeStreamBuilder<UnGraph<u32, u32>, _> =
+            EdgeStreamBuilder::new(0, 0, || 7);
+        builder.add_edges([(0, 1, 1), (1, 0, 2)]).unwrap();
+        builder.add_edges([(0, 3, 3)]).unwrap();
+        assert_eq!(builder.graph().edge_count(), 3);
+        let graph = builder.finish();
+        assert_eq!(graph.node_count(), 4);
+        assert_eq!(graph[NodeIndex::new(2)], 7);
+        assert_eq!(graph.edges_connecting(0.into(), 1.into()).count(), 2);
+    }
+
+    #[test]
+    fn test_deduplicate_undirected() {
+        let mut builder: EdgeStreamBuilder<UnGraph<(), u32>, _> =
+            EdgeStreamBuilder::new(3, 3, || ()).deduplicate();
+        builder.add_edges([(0, 1, 1), (1, 2, 2)]).unwrap();
+        builder
+            .add_edges([(1, 0, 3), (2, 2, 4), (2, 2, 5)])
+            .unwrap();
+        let graph = builder.finish();
+        assert_eq!(graph.edge_count(), 3);
+        assert_eq!(graph[EdgeIndex::new(0)], 3);
+        assert_eq!(graph[EdgeIndex::new(2)], 5);
+    }
+
+    #[test]
+    fn test_deduplicate_directed_sum() {
+        let mut builder: EdgeStreamBuilder<StableDiGraph<(), u32>, _> =
+            EdgeStreamBuilder::new(0, 0, || ()).deduplicate();
+        for chunk in [[(0, 1, 1), (1, 0, 1)], [(0, 1, 2), (0, 1, 3)]] {
+            let res: Result<(), EdgeStreamError<Infallible>> =
+                builder.add_edges_with(chunk, |w, new_w| {
+                    *w += new_w;
+                    Ok(())
+                });
+            res.unwrap();
+        }
+        let graph = builder.finish();
+        assert_eq!(graph.edge_count(), 2);
+        assert_eq!(graph[EdgeIndex::new(0)], 6);
+        assert_eq!(graph[EdgeIndex::new(1)], 1);
+    }
+
+    #[test]
+    fn test_combine_error() {
+        let mut builder: EdgeStreamBuilder<DiGraph<(), u32>, _> =
+            EdgeStreamBuilder::new(0, 0, || ()).deduplicate();
+        let res = builder.add_edges_with([(0, 1, 1), (0, 1, 2), (1, 2, 3)], |_, _| Err("fail"));
+        assert_eq!(res, Err(EdgeStreamError::CombineError("fail")));
+        assert_eq!(builder.graph().edge_count(), 1);
+    }
+
+    #[test]
+    fn test_node_limit() {
+        let mut builder: EdgeStreamBuilder<DiGraph<(), u32>, _> =
+            EdgeStreamBuilder::new(0, 0, || ()).node_limit(3);
+        let res = builder.add_edges([(0, 1, 1), (2, 1, 2), (1, 1_000_000_000_000, 3), (0, 2, 4)]);
+        assert_eq!(res, Err(EdgeStreamError::NodeLimit(1_000_000_000_000)));
+        let graph = builder.finish();
+        assert_eq!(graph.node_count(), 3);
+        assert_eq!(graph.edge_count(), 2);
+    }
+}
@@ -10,7 +10,7 @@
 // License for the specific language governing permissions and limitations
 // under the License.
 
-//! This module defines graph traits for node contraction.
+//! This module defines graph traits for node and edge contraction.
 
 use crate::dictmap::{DictMap, InitWithHasher};
 use crate::err::{ContractError, ContractSimpleError};
@@ -21,7 +21,7 @@ use petgraph::data::Build;
 use petgraph::graphmap;
 use petgraph::stable_graph;
 use petgraph::visit::{Data, Dfs, EdgeRef, GraphBase, GraphProp, IntoEdgesDirected, Visitable};
-use petgraph::{Directed, Direction, Undirected};
+use petgraph::{Directed, Direction, EdgeType, Undirected};
 use std::convert::Infallible;
 use std::error::Error;
 use std::hash::Hash;
@@ -450,6 +450,415 @@ where
     }
 }
 
+/// How the parallel edges created by merging two nodes are handled.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ParallelEdges {
+    /// Keep every edge, which may create parallel edges.
+    #[default]
+    Keep,
+    /// Merge the edges that would be parallel into a single edge with the
+    /// edge merge function.
+    Merge,
+}
+
+/// How the edges between two merged nodes are handled.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum SelfLoops {
+    /// Remove the edges between the merged nodes.
+    #[default]
+    Remove,
+    /// Turn the edges between the merged nodes into self-loops on the
+    /// merged node.
+    Keep,
+}
+
+/// The policy used when merging two nodes or contracting an edge in place.
+///
+/// The default policy keeps parallel edges and removes the edges between the
+/// merged nodes.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ContractionPolicy {
+    pub parallel_edges: ParallelEdges,
+    pub self_loops: SelfLoops,
+}
+
+pub trait ContractEdgeDirected: Data {
+    /// Merge the node `remove` into the node `keep` in place.
+    ///
+    /// The edges of `remove` are moved to `keep` and `remove` is removed
+    /// from the graph, so unlike [ContractNodesDirected::contract_nodes] the
+    /// indices of the other nodes and of `keep` stay valid, which makes it
+    /// suitable for repeated contraction. The weight of `keep` is replaced
+    /// by the result of `node_weight_fn`, which is passed the weights of
+    /// `keep` and `remove`. Parallel edges and the edges between `keep` and
+    /// `remove` are handled according to `policy`; parallel edges are merged
+    /// with `edge_merge_fn`, which is passed the weight of the edge already
+    /// at `keep` and the weight of the edge moved from `remove`. Self-loops
+    /// that `keep` or `remove` already have are kept on the merged node.
+    ///
+    /// If `check_cycle` is enabled and merging the nodes would introduce a
+    /// cycle, an error is returned. The graph is not modified if an error is
+    /// returned.
+    ///
+    /// The `NodeId` of the merged node, which is `keep`, is returned.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `keep` or `remove` isn't in the graph.
+    fn merge_nodes<F, H, E: Error>(
+        &mut self,
+        keep: Self::NodeId,
+        remove: Self::NodeId,
+        policy: ContractionPolicy,
+        check_cycle: bool,
+        node_weight_fn: F,
+        edge_merge_fn: H,
+    ) -> Result<Self::NodeId, ContractSimpleError<E>>
+    where
+        F: FnMut(&Self::NodeWeight, &Self::NodeWeight) -> Result<Self::NodeWeight, E>,
+        H: FnMut(&Self::EdgeWeight, &Self::EdgeWeight) -> Result<Self::EdgeWeight, E>;
+
+    /// Contract an edge in place.
+    ///
+    /// The target of the edge is merged into its source with
+    /// [ContractEdgeDirected::merge_nodes], so the edge itself, and every
+    /// other edge between its endpoints, is handled as an edge between the
+    /// merged nodes. Contracting a self-loop only removes it if `policy`
+    /// removes the edges between merged nodes.
+    ///
+    /// The `NodeId` of the merged node, which is the source of the edge, is
+    /// returned.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `edge` isn't in the graph.
+    ///
+    /// # Example
+    /// ```
+    /// use std::convert::Infallible;
+    /// use petgraph::prelude::*;
+    /// use rustworkx_core::graph_ext::*;
+    ///
+    /// // Contract b -> c in the path a -> b -> c -> d
+    /// let mut dag: StableDiGraph<char, usize> = StableDiGraph::default();
+    /// let a = dag.add_node('a');
+    /// let b = dag.add_node('b');
+    /// let c = dag.add_node('c');
+    /// let d = dag.add_node('d');
+    /// dag.add_edge(a, b, 0);
+    /// let e = dag.add_edge(b, c, 1);
+    /// dag.add_edge(c, d, 2);
+    ///
+    /// let m = dag
+    ///     .contract_edge(
+    ///         e,
+    ///         ContractionPolicy::default(),
+    ///         true,
+    ///         |_, _| Ok::<_, Infallible>('m'),
+    ///         |w1, _| Ok(*w1),
+    ///     )
+    ///     .unwrap();
+    /// assert_eq!(m, b);
+    /// assert_eq!(dag[m], 'm');
+    /// assert_eq!(dag.node_count(), 3);
+    /// assert_eq!(dag.edge_weight(dag.find_edge(m, d).unwrap()).unwrap(), &2);
+    /// ```
+    fn contract_edge<F, H, E: Error>(
+        &mut self,
+        edge: Self::EdgeId,
+        policy: ContractionPolicy,
+        check_cycle: bool,
+        node_weight_fn: F,
+        edge_merge_fn: H,
+    ) -> Result<Self::NodeId, ContractSimpleError<E>>
+    where
+        F: FnMut(&Self::NodeWeight, &Self::NodeWeight) -> Result<Self::NodeWeight, E>,
+        H: FnMut(&Self::EdgeWeight, &Self::EdgeWeight) -> Result<Self::EdgeWeight, E>;
+}
+
+impl<N, E, Ix> ContractEdgeDirected for stable_graph::StableGraph<N, E, Directed, Ix>
+where
+    Ix: stable_graph::IndexType,
+    E: Clone,
+{
+    fn merge_nodes<F, H, C: Error>(
+        &mut self,
+        keep: Self::NodeId,
+        remove: Self::NodeId,
+        policy: ContractionPolicy,
+        check_cycle: bool,
+        node_weight_fn: F,
+        edge_merge_fn: H,
+    ) -> Result<Self::NodeId, ContractSimpleError<C>>
+    where
+        F: FnMut(&Self::NodeWeight, &Self::NodeWeight) -> Result<Self::NodeWeight, C>,
+        H: FnMut(&Self::EdgeWeight, &Self::EdgeWeight) -> Result<Self::EdgeWeight, C>,
+    {
+        if check_cycle && !can_contract(self.deref(), &IndexSet::from_iter([keep, remove])) {
+            return Err(ContractSimpleError::DAGWouldCycle);
+        }
+        merge_stable(self, keep, remove, policy, node_weight_fn, edge_merge_fn)
+            .map_err(ContractSimpleError::MergeError)
+    }
+
+    fn contract_edge<F, H, C: Error>(
+        &mut self,
+        edge: Self::EdgeId,
+        policy: ContractionPolicy,
+        check_cycle: bool,
+        node_weight_fn: F,
+        edge_merge_fn: H,
+    ) -> Result<Self::NodeId, ContractSimpleError<C>>
+    where
+        F: FnMut(&Self::NodeWeight, &Self::NodeWeight) -> Result<Self::NodeWeight, C>,
+        H: FnMut(&Self::EdgeWeight, &Self::EdgeWeight) -> Result<Self::EdgeWeight, C>,
+    {
+        let (source, target) = self
+            .edge_endpoints(edge)
+            .expect("The edge to contract is not in the graph");
+        if source == target {
+            return Ok(contract_self_loop(self, edge, source, policy));
+        }
+        self.merge_nodes(
+            source,
+            target,
+            policy,
+            check_cycle,
+            node_weight_fn,
+            edge_merge_fn,
+        )
+    }
+}
+
+pub trait ContractEdgeUndirected: Data {
+    /// Merge the node `remove` into the node `keep` in place.
+    ///
+    /// The edges of `remove` are moved to `keep` and `remove` is removed
+    /// from the graph, so unlike [ContractNodesUndirected::contract_nodes]
+    /// the indices of the other nodes and of `keep` stay valid, which makes
+    /// it suitable for repeated contraction. The weight of `keep` is replaced
+    /// by the result of `node_weight_fn`, which is passed the weights of
+    /// `keep` and `remove`. Parallel edges and the edges between `keep` and
+    /// `remove` are handled according to `policy`; parallel edges are merged
+    /// with `edge_merge_fn`, which is passed the weight of the edge already
+    /// at `keep` and the weight of the edge moved from `remove`. Self-loops
+    /// that `keep` or `remove` already have are kept on the merged node.
+    ///
+    /// The graph is not modified if an error is returned.
+    ///
+    /// The `NodeId` of the merged node, which is `keep`, is returned.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `keep` or `remove` isn't in the graph.
+    fn merge_nodes<F, H, E>(
+        &mut self,
+        keep: Self::NodeId,
+        remove: Self::NodeId,
+        policy: ContractionPolicy,
+        node_weight_fn: F,
+        edge_merge_fn: H,
+    ) -> Result<Self::NodeId, E>
+    where
+        F: FnMut(&Self::NodeWeight, &Self::NodeWeight) -> Result<Self::NodeWeight, E>,
+        H: FnMut(&Self::EdgeWeight, &Self::EdgeWeight) -> Result<Self::EdgeWeight, E>;
+
+    /// Contract an edge in place.
+    ///
+    /// The second endpoint of the edge is merged into the first one with
+    /// [ContractEdgeUndirected::merge_nodes], so the edge itself, and every
+    /// other edge between its endpoints, is handled as an edge between the
+    /// merged nodes. Contracting a self-loop only removes it if `policy`
+    /// removes the edges between merged nodes.
+    ///
+    /// The `NodeId` of the merged node is returned.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `edge` isn't in the graph.
+    ///
+    /// # Example
+    /// ```
+    /// use std::convert::Infallible;
+    /// use petgraph::prelude::*;
+    /// use rustworkx_core::graph_ext::*;
+    ///
+    /// // Contract an edge of the triangle a - b - c, merging the two
+    /// // edges to c into one
+    /// let mut graph: StableUnGraph<u32, u32> = StableUnGraph::default();
+    /// let a = graph.add_node(1);
+    /// let b = graph.add_node(2);
+    /// let c = graph.add_node(3);
+    /// let e = graph.add_edge(a, b, 1);
+    /// graph.add_edge(b, c, 2);
+    /// graph.add_edge(c, a, 3);
+    ///
+    /// let policy = ContractionPolicy {
+    ///     parallel_edges: ParallelEdges::Merge,
+    ///     self_loops: SelfLoops::Remove,
+    /// };
+    /// let m = graph
+    ///     .contract_edge(
+    ///         e,
+    ///         policy,
+    ///         |w1, w2| Ok::<_, Infallible>(w1 + w2),
+    ///         |w1, w2| Ok(w1 + w2),
+    ///     )
+    ///     .unwrap();
+    /// assert_eq!(graph[m], 3);
+    /// assert_eq!(graph.edge_count(), 1);
+    /// assert_eq!(graph.edge_weight(graph.find_edge(m, c).unwrap()).unwrap(), &5);
+    /// ```
+    fn contract_edge<F, H, E>(
+        &mut self,
+        edge: Self::EdgeId,
+        policy: ContractionPolicy,
+        node_weight_fn: F,
+        edge_merge_fn: H,
+    ) -> Result<Self::NodeId, E>
+    where
+        F: FnMut(&Self::NodeWeight, &Self::NodeWeight) -> Result<Self::NodeWeight, E>,
+        H: FnMut(&Self::EdgeWeight, &Self::EdgeWeight) -> Result<Self::EdgeWeight, E>;
+}
+
+impl<N, E, Ix> ContractEdgeUndirected for stable_graph::StableGraph<N, E, Undirected, Ix>
+where
+    Ix: stable_graph::IndexType,
+    E: Clone,
+{
+    fn merge_nodes<F, H, C>(
+        &mut self,
+        keep: Self::NodeId,
+        remove: Self::NodeId,
+        policy: ContractionPolicy,
+        node_weight_fn: F,
+        edge_merge_fn: H,
+    ) -> Result<Self::NodeId, C>
+    where
+        F: FnMut(&Self::NodeWeight, &Self::NodeWeight) -> Result<Self::NodeWeight, C>,
+        H: FnMut(&Self::EdgeWeight, &Self::EdgeWeight) -> Result<Self::EdgeWeight, C>,
+    {
+        merge_stable(self, keep, remove, policy, node_weight_fn, edge_merge_fn)
+    }
+
+    fn contract_edge<F, H, C>(
+        &mut self,
+        edge: Self::EdgeId,
+        policy: ContractionPolicy,
+        node_weight_fn: F,
+        edge_merge_fn: H,
+    ) -> Result<Self::NodeId, C>
+    where
+        F: FnMut(&Self::NodeWeight, &Self::NodeWeight) -> Result<Self::NodeWeight, C>,
+        H: FnMut(&Self::EdgeWeight, &Self::EdgeWeight) -> Result<Self::EdgeWeight, C>,
+    {
+        let (source, target) = self
+            .edge_endpoints(edge)
+            .expect("The edge to contract is not in the graph");
+        if source == target {
+            return Ok(contract_self_loop(self, edge, source, policy));
+        }
+        self.merge_nodes(source, target, policy, node_weight_fn, edge_merge_fn)
+    }
+}
+
+fn contract_self_loop<N, E, Ty, Ix>(
+    graph: &mut stable_graph::StableGraph<N, E, Ty, Ix>,
+    edge: stable_graph::EdgeIndex<Ix>,
+    node: stable_graph::NodeIndex<Ix>,
+    policy: ContractionPolicy,
+) -> stable_graph::NodeIndex<Ix>
+where
+    Ty: EdgeType,
+    Ix: stable_graph::IndexType,
+{
+    if policy.self_loops == SelfLoops::Remove {
+        graph.remove_edge(edge);
+    }
+    node
+}
+
+fn merge_stable<N, E, Ty, Ix, F, H, C>(
+    graph: &mut stable_graph::StableGraph<N, E, Ty, Ix>,
+    keep: stable_graph::NodeIndex<Ix>,
+    remove: stable_graph::NodeIndex<Ix>,
+    policy: ContractionPolicy,
+    mut node_weight_fn: F,
+    mut edge_merge_fn: H,
+) -> Result<stable_graph::NodeIndex<Ix>, C>
+where
+    Ty: EdgeType,
+    Ix: stable_graph::IndexType,
+    E: Clone,
+    F: FnMut(&N, &N) -> Result<N, C>,
+    H: FnMut(&E, &E) -> Result<E, C>,
+{
+    if keep == remove {
+        return Ok(keep);
+    }
+    let weight = node_weight_fn(&graph[keep], &graph[remove])?;
+
+    // The edges of `remove` with their endpoints moved to `keep`. Every edge
+    // is only seen once, even self-loops or undirected edges.
+    let mut moved_edges: DictMap<stable_graph::EdgeIndex<Ix>, _> = DictMap::default();
+    for direction in [Direction::Outgoing, Direction::Incoming] {
+        for edge in graph.edges_directed(remove, direction) {
+            moved_edges.entry(edge.id()).or_insert_with(|| {
+                let (source, target) = graph.edge_endpoints(edge.id()).unwrap();
+                let was_loop = source == target;
+                let source = if source == remove { keep } else { source };
+                let target = if target == remove { keep } else { target };
+                (source, target, was_loop)
+            });
+        }
+    }
+
+    // Work out all the changes before modifying the graph so an error from
+    // a callback leaves it untouched.
+    let mut updated: DictMap<stable_graph::EdgeIndex<Ix>, E> = DictMap::default();
+    let mut merged: DictMap<_, E> = DictMap::default();
+    let mut added: Vec<(stable_graph::NodeIndex<Ix>, stable_graph::NodeIndex<Ix>, E)> = Vec::new();
+    for (edge, (source, target, was_loop)) in moved_edges {
+        if source == target && !was_loop && policy.self_loops == SelfLoops::Remove {
+            continue;
+        }
+        let edge_weight = &graph[edge];
+        if policy.parallel_edges == ParallelEdges::Keep {
+            added.push((source, target, edge_weight.clone()));
+            continue;
+        }
+        let key = if graph.is_directed() || source <= target {
+            (source, target)
+        } else {
+            (target, source)
+        };
+        if let Some(existing) = merged.get_mut(&key) {
+            *existing = edge_merge_fn(existing, edge_weight)?;
+        } else if let Some(existing) = graph.find_edge(source, target) {
+            let merged_weight = edge_merge_fn(
+                updated.get(&existing).unwrap_or(&graph[existing]),
+                edge_weight,
+            )?;
+            updated.insert(existing, merged_weight);
+        } else {
+            merged.insert(key, edge_weight.clone());
+        }
+    }
+
+    graph[keep] = weight;
+    for (edge, edge_weight) in updated {
+        graph[edge] = edge_weight;
+    }
+    graph.remove_node(remove);
+    for (source, target, edge_weight) in added {
+        graph.add_edge(source, target, edge_weight);
+    }
+    for ((source, target), edge_weight) in merged {
+        graph.add_edge(source, target, edge_weight);
+    }
+    Ok(keep)
+}
+
 fn merge_duplicates<K, V, F, E>(xs: Vec<(K, V)>, mut merge_fn: F) -> Result<Vec<(K, V)>, E>
 where
     K: Hash + Eq,
@@ -0,0 +1,198 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+//! This module defines graph traits for degree-preserving edge swaps.
+
+use crate::rng::{gen_index, rng_from_seed};
+use petgraph::graph::{EdgeIndex, IndexType, NodeIndex};
+use petgraph::stable_graph::StableGraph;
+use petgraph::{Directed, Undirected};
+use rand::prelude::*;
+use rand_pcg::Pcg64;
+
+pub trait DoubleEdgeSwapUndirected {
+    /// Randomize the edges of a simple undirected graph in place while
+    /// preserving the degree of every node.
+    ///
+    /// Every swap picks two edges `(u, v)` and `(x, y)` uniformly at random
+    /// and replaces them with the edges `(u, x)` and `(v, y)`. A swap is only
+    /// done if the four nodes are distinct and neither of the new edges is
+    /// already in the graph, so a simple graph stays simple. The new edges
+    /// take the weights of the edges they replace.
+    ///
+    /// Arguments:
+    ///
+    /// * `num_swaps` - The number of swaps to do.
+    /// * `max_tries` - The maximum number of attempted swaps, including the
+    ///   ones that are rejected.
+    /// * `seed` - An optional seed to use for the random number generator.
+    ///
+    /// Returns the number of swaps done, which is less than `num_swaps` if
+    /// `max_tries` was reached first or if the graph has fewer than two edges.
+    ///
+    /// # Example
+    /// ```rust
+    /// use rustworkx_core::graph_ext::DoubleEdgeSwapUndirected;
+    /// use rustworkx_core::petgraph::stable_graph::StableUnGraph;
+    ///
+    /// let mut graph = StableUnGraph::<(), ()>::from_edges(&[(0, 1), (2, 3), (4, 5)]);
+    /// let swaps = graph.double_edge_swap(10, 100, Some(42));
+    /// assert_eq!(swaps, 10);
+    /// assert_eq!(graph.edge_count(), 3);
+    /// assert!(graph.node_indices().all(|n| graph.neighbors(n).count() == 1));
+    /// ```
+    fn double_edge_swap(&mut self, num_swaps: usize, max_tries: usize, seed: Option<u64>) -> usize;
+}
+
+impl<N, E, Ix> DoubleEdgeSwapUndirected for StableGraph<N, E, Undirected, Ix>
+where
+    Ix: IndexType,
+{
+    fn double_edge_swap(&mut self, num_swaps: usize, max_tries: usize, seed: Option<u64>) -> usize {
+        double_edge_swap_undirected_filtered(self, num_swaps, max_tries, seed, |_, _| true)
+    }
+}
+
+/// Do the double edge swaps of [`DoubleEdgeSwapUndirected::double_edge_swap`],
+/// but only keep a swap if `keep` returns `true` for the graph after the
+/// swap and the nodes `[u, v, x, y]` of the swap, which replaced `(u, v)` and
+/// `(x, y)` with `(u, x)` and `(v, y)`. Rejected swaps are undone and count
+/// towards `max_tries`.
+pub(crate) fn double_edge_swap_undirected_filtered<N, E, Ix, F>(
+    graph: &mut StableGraph<N, E, Undirected, Ix>,
+    num_swaps: usize,
+    max_tries: usize,
+    seed: Option<u64>,
+    mut keep: F,
+) -> usize
+where
+    Ix: IndexType,
+    F: FnMut(&StableGraph<N, E, Undirected, Ix>, [NodeIndex<Ix>; 4]) -> bool,
+{
+    let mut edges: Vec<EdgeIndex<Ix>> = graph.edge_indices().collect();
+    if edges.len() < 2 {
+        return 0;
+    }
+    let mut rng: Pcg64 = rng_from_seed(seed);
+    let mut swaps = 0;
+    let mut tries = 0;
+    while swaps < num_swaps && tries < max_tries {
+        tries += 1;
+        let first = gen_index(&mut rng, edges.len());
+        let second = gen_index(&mut rng, edges.len());
+        if first == second {
+            continue;
+        }
+        let (u, v) = graph.edge_endpoints(edges[first]).unwrap();
+        let (mut x, mut y) = graph.edge_endpoints(edges[second]).unwrap();
+        // Both orientations of the second edge give a valid swap
+        if rng.gen::<bool>() {
+            std::mem::swap(&mut x, &mut y);
+        }
+        if u == x || u == y || v == x || v == y {
+            continue;
+        }
+        if graph.find_edge(u, x).is_some() || graph.find_edge(v, y).is_some() {
+            continue;
+        }
+        let first_weight = graph.remove_edge(edges[first]).unwrap();
+        let second_weight = graph.remove_edge(edges[second]).unwrap();
+        let new_first = graph.add_edge(u, x, first_weight);
+        let new_second = graph.add_edge(v, y, second_weight);
+        if keep(graph, [u, v, x, y]) {
+            edges[first] = new_first;
+            edges[second] = new_second;
+            swaps += 1;
+        } else {
+            let first_weight = graph.remove_edge(new_first).unwrap();
+            let second_weight = graph.remove_edge(new_second).unwrap();
+            edges[first] = graph.add_edge(u, v, first_weight);
+            edges[second] = graph.add_edge(x, y, second_weight);
+        }
+    }
+    swaps
+}
+
+pub trait DoubleEdgeSwapDirected {
+    /// Randomize the edges of a simple directed graph in place while
+    /// preserving the in-degree and out-degree of every node.
+    ///
+    /// Every swap picks two edges `(u, v)` and `(x, y)` uniformly at random
+    /// and replaces them with the edges `(u, y)` and `(x, v)`. A swap is only
+    /// done if the four nodes are distinct and neither of the new edges is
+    /// already in the graph, so a simple graph stays simple. The new edges
+    /// take the weights of the edges they replace.
+    ///
+    /// Arguments:
+    ///
+    /// * `num_swaps` - The number of swaps to do.
+    /// * `max_tries` - The maximum number of attempted swaps, including the
+    ///   ones that are rejected.
+    /// * `seed` - An optional seed to use for the random number generator.
+    ///
+    /// Returns the number of swaps done, which is less than `num_swaps` if
+    /// `max_tries` was reached first or if the graph has fewer than two edges.
+    ///
+    /// # Example
+    /// ```rust
+    /// use rustworkx_core::graph_ext::DoubleEdgeSwapDirected;
+    /// use rustworkx_core::petgraph::stable_graph::StableDiGraph;
+    /// use rustworkx_core::petgraph::Direction;
+    ///
+    /// let mut graph = StableDiGraph::<(), ()>::from_edges(&[(0, 1), (2, 3), (4, 5)]);
+    /// let swaps = graph.double_edge_swap(10, 100, Some(42));
+    /// assert_eq!(swaps, 10);
+    /// for node in [0, 2, 4] {
+    ///     let node = node.into();
+    ///     assert_eq!(graph.neighbors_directed(node, Direction::Outgoing).count(), 1);
+    ///     assert_eq!(graph.neighbors_directed(node, Direction::Incoming).count(), 0);
+    /// }
+    /// ```
+    fn double_edge_swap(&mut self, num_swaps: usize, max_tries: usize, seed: Option<u64>) -> usize;
+}
+
+impl<N, E, Ix> DoubleEdgeSwapDirected for StableGraph<N, E, Directed, Ix>
+where
+    Ix: IndexType,
+{
+    fn double_edge_swap(&mut self, num_swaps: usize, max_tries: usize, seed: Option<u64>) -> usize {
+        let mut edges: Vec<EdgeIndex<Ix>> = self.edge_indices().collect();
+        if edges.len() < 2 {
+            return 0;
+        }
+        let mut rng: Pcg64 = rng_from_seed(seed);
+        let mut swaps = 0;
+        let mut tries = 0;
+        while swaps < num_swaps && tries < max_tries {
+            tries += 1;
+            let first = gen_index(&mut rng, edges.len());
+            let second = gen_index(&mut rng, edges.len());
+            if first == second {
+                continue;
+            }
+            let (u, v) = self.edge_endpoints(edges[first]).unwrap();
+            let (x, y) = self.edge_endpoints(edges[second]).unwrap();
+            if u == x || u == y || v == x || v == y {
+                continue;
+            }
+            if self.find_edge(u, y).is_some() || self.find_edge(x, v).is_some() {
+                continue;
+            }
+            let first_weight = self.remove_edge(edges[first]).unwrap();
+            let second_weight = self.remove_edge(edges[second]).unwrap();
+            edges[first] = self.add_edge(u, y, first_weight);
+            edges[second] = self.add_edge(x, v, second_weight);
+            swaps += 1;
+        }
+        swaps
+    }
+}
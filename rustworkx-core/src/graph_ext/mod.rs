@@ -37,11 +37,28 @@
 //! preserve this property, or on multi-graphs to ensure that the contraction
 //! does not introduce additional parallel edges.
 //!
+//! ### Edge Contraction
+//!
+//! The [`ContractEdgeDirected`] and [`ContractEdgeUndirected`] traits provide
+//! `contract_edge` and `merge_nodes` methods, which merge two nodes in place
+//! instead of replacing them with a new node, so they can be applied
+//! repeatedly. A [`ContractionPolicy`] controls whether the resulting
+//! parallel edges are merged and whether the edges between the merged nodes
+//! are kept as self-loops.
+//!
 //! The other `ContractNodes-` traits provide a `contract_nodes` method, which
 //! happily introduces parallel edges when multiple nodes in the contraction
 //! have an incoming edge from the same source node or when multiple nodes in
 //! the contraction have an outgoing edge to the same target node.
 //!
+//! ### Edge Swaps
+//!
+//! The [`DoubleEdgeSwapDirected`] and [`DoubleEdgeSwapUndirected`] traits
+//! provide a `double_edge_swap` method, which randomizes the edges of a
+//! simple graph in place while preserving the degree of every node (the
+//! in-degree and out-degree for directed graphs). This is the rewiring of
+//! Maslov and Sneppen, used to generate the random graphs of a null model.
+//!
 //! ### Multi-graph Extensions
 //!
 //! These traits provide additional helper methods for use with multi-graphs,
@@ -58,6 +75,10 @@
 //! | ContractNodesSimpleDirected   |       |  x          |    x     |             |       |       |
 //! | ContractNodesUndirected       |       |  x          |    x     |             |       |       |
 //! | ContractNodesSimpleUndirected |       |  x          |    x     |             |       |       |
+//! | ContractEdgeDirected          |       |  x          |          |             |       |       |
+//! | ContractEdgeUndirected        |       |  x          |          |             |       |       |
+//! | DoubleEdgeSwapDirected        |       |  x          |          |             |       |       |
+//! | DoubleEdgeSwapUndirected      |       |  x          |          |             |       |       |
 //! | HasParallelEdgesDirected      | x     |  x          |    x     | x           | x     | x     |
 //! | HasParallelEdgesUndirected    | x     |  x          |    x     | x           | x     | x     |
 //! | NodeRemovable                 | x     |  x          |    x     | x           |       |       |
@@ -70,12 +91,15 @@ use petgraph::visit::{Data, IntoNodeIdentifiers};
 use petgraph::{EdgeType, Graph};
 
 pub mod contraction;
+pub mod edge_swap;
 pub mod multigraph;
 
 pub use contraction::{
-    ContractNodesDirected, ContractNodesSimpleDirected, ContractNodesSimpleUndirected,
-    ContractNodesUndirected,
+    ContractEdgeDirected, ContractEdgeUndirected, ContractNodesDirected,
+    ContractNodesSimpleDirected, ContractNodesSimpleUndirected, ContractNodesUndirected,
+    ContractionPolicy, ParallelEdges, SelfLoops,
 };
+pub use edge_swap::{DoubleEdgeSwapDirected, DoubleEdgeSwapUndirected};
 pub use multigraph::{HasParallelEdgesDirected, HasParallelEdgesUndirected};
 
 /// A graph whose nodes may be removed.
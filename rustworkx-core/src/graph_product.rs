@@ -0,0 +1,598 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+use std::hash::Hash;
+
+use hashbrown::HashMap;
+use petgraph::data::{Build, Create};
+use petgraph::visit::{
+    Data, EdgeRef, GraphProp, IntoEdgeReferences, IntoNodeReferences, NodeCount, NodeRef,
+};
+
+/// The edge (or edges) of the factor graphs an edge of a product graph is
+/// formed from. This is passed to the edge weight callback of the graph
+/// products.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProductEdge<'a, A, B> {
+    /// The edge comes from an edge of the first graph and a single node of
+    /// the second graph.
+    First(&'a A),
+    /// The edge comes from a single node of the first graph and an edge of
+    /// the second graph.
+    Second(&'a B),
+    /// The edge comes from an edge of the first graph and an edge of the
+    /// second graph.
+    Both(&'a A, &'a B),
+}
+
+type ProductResult<G, N1, N2, N, E> = Result<(G, HashMap<(N1, N2), N>), E>;
+
+/// Add a node to the output graph for every pair of nodes of the factor graphs.
+fn product_nodes<G1, G2, G, F, E>(
+    first: G1,
+    second: G2,
+    mut node_weight_fn: F,
+) -> ProductResult<G, G1::NodeId, G2::NodeId, G::NodeId, E>
+where
+    G1: IntoNodeReferences + NodeCount,
+    G2: IntoNodeReferences + NodeCount,
+    G1::NodeId: Eq + Hash,
+    G2::NodeId: Eq + Hash,
+    G: Build + Create,
+    F: FnMut(&G1::NodeWeight, &G2::NodeWeight) -> Result<G::NodeWeight, E>,
+{
+    let num_nodes = first.node_count() * second.node_count();
+    let mut out_graph = G::with_capacity(num_nodes, 0);
+    let mut node_map = HashMap::with_capacity(num_nodes);
+    for x in first.node_references() {
+        for y in second.node_references() {
+            let node = out_graph.add_node(node_weight_fn(x.weight(), y.weight())?);
+            node_map.insert((x.id(), y.id()), node);
+        }
+    }
+    Ok((out_graph, node_map))
+}
+
+/// Add the edges of the cartesian product to `out_graph`.
+fn add_cartesian_edges<G1, G2, G, H, E>(
+    first: G1,
+    second: G2,
+    out_graph: &mut G,
+    node_map: &HashMap<(G1::NodeId, G2::NodeId), G::NodeId>,
+    edge_weight_fn: &mut H,
+) -> Result<(), E>
+where
+    G1: IntoNodeReferences + IntoEdgeReferences,
+    G2: IntoNodeReferences + IntoEdgeReferences,
+    G1::NodeId: Eq + Hash,
+    G2::NodeId: Eq + Hash,
+    G: Build,
+    H: FnMut(ProductEdge<G1::EdgeWeight, G2::EdgeWeight>) -> Result<G::EdgeWeight, E>,
+{
+    for edge in first.edge_references() {
+        for y in second.node_references() {
+            let source = node_map[&(edge.source(), y.id())];
+            let target = node_map[&(edge.target(), y.id())];
+            out_graph.add_edge(
+                source,
+                target,
+                edge_weight_fn(ProductEdge::First(edge.weight()))?,
+            );
+        }
+    }
+    for x in first.node_references() {
+        for edge in second.edge_references() {
+            let source = node_map[&(x.id(), edge.source())];
+            let target = node_map[&(x.id(), edge.target())];
+            out_graph.add_edge(
+                source,
+                target,
+                edge_weight_fn(ProductEdge::Second(edge.weight()))?,
+            );
+        }
+    }
+    Ok(())
+}
+
+/// Add the edges of the tensor product to `out_graph`.
+fn add_tensor_edges<G1, G2, G, H, E>(
+    first: G1,
+    second: G2,
+    out_graph: &mut G,
+    node_map: &HashMap<(G1::NodeId, G2::NodeId), G::NodeId>,
+    edge_weight_fn: &mut H,
+) -> Result<(), E>
+where
+    G1: IntoEdgeReferences + GraphProp,
+    G2: IntoEdgeReferences,
+    G1::NodeId: Eq + Hash,
+    G2::NodeId: Eq + Hash,
+    G: Build,
+    H: FnMut(ProductEdge<G1::EdgeWeight, G2::EdgeWeight>) -> Result<G::EdgeWeight, E>,
+{
+    for edge_first in first.edge_references() {
+        for edge_second in second.edge_references() {
+            let source = node_map[&(edge_first.source(), edge_second.source())];
+            let target = node_map[&(edge_first.target(), edge_second.target())];
+            let weight =
+                edge_weight_fn(ProductEdge::Both(edge_first.weight(), edge_second.weight()))?;
+            out_graph.add_edge(source, target, weight);
+        }
+    }
+    if !first.is_directed() {
+        // An undirected edge goes both ways, so every pair of edges also
+        // connects the endpoints crosswise unless one of them is a self-loop
+        for edge_first in first.edge_references() {
+            for edge_second in second.edge_references() {
+                if edge_first.source() == edge_first.target()
+                    || edge_second.source() == edge_second.target()
+                {
+                    continue;
+                }
+                let source = node_map[&(edge_first.source(), edge_second.target())];
+                let target = node_map[&(edge_first.target(), edge_second.source())];
+                let weight =
+                    edge_weight_fn(ProductEdge::Both(edge_first.weight(), edge_second.weight()))?;
+                out_graph.add_edge(source, target, weight);
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Return the cartesian product of two graphs.
+///
+/// The cartesian product has a node `(x, y)` for every node `x` of `first`
+/// and every node `y` of `second`. There is an edge from `(x1, y)` to
+/// `(x2, y)` for every edge from `x1` to `x2` in `first` and every node `y`
+/// of `second`, and an edge from `(x, y1)` to `(x, y2)` for every node `x`
+/// of `first` and every edge from `y1` to `y2` in `second`.
+///
+/// Both input graphs and the output graph are expected to have the same
+/// directionality.
+///
+/// Arguments:
+///
+/// * `first` - The first factor graph.
+/// * `second` - The second factor graph.
+/// * `node_weight_fn` - A callable that will be passed the weights of `x` and
+///   `y` for every node `(x, y)` of the product and is expected to return a
+///   `Result` with the weight of that node.
+/// * `edge_weight_fn` - A callable that will be passed a [`ProductEdge`] with
+///   the weight of the factor edge of every edge of the product and is
+///   expected to return a `Result` with the weight of that edge.
+///
+/// Returns the product graph and a map from the pairs of nodes of the factor
+/// graphs to the nodes of the product graph.
+///
+/// # Example
+/// ```rust
+/// use std::convert::Infallible;
+/// use rustworkx_core::petgraph;
+/// use rustworkx_core::graph_product::cartesian_product;
+///
+/// let first = petgraph::graph::UnGraph::<(), ()>::from_edges(&[(0, 1)]);
+/// let second = petgraph::graph::UnGraph::<(), ()>::from_edges(&[(0, 1), (1, 2)]);
+/// let res: Result<(petgraph::graph::UnGraph<(), ()>, _), Infallible> =
+///     cartesian_product(&first, &second, |_, _| Ok(()), |_| Ok(()));
+/// let (product, node_map) = res.unwrap();
+/// assert_eq!(product.node_count(), 6);
+/// assert_eq!(product.edge_count(), 7);
+/// assert!(product.contains_edge(node_map[&(0.into(), 1.into())], node_map[&(1.into(), 1.into())]));
+/// ```
+pub fn cartesian_product<G1, G2, G, F, H, E>(
+    first: G1,
+    second: G2,
+    node_weight_fn: F,
+    mut edge_weight_fn: H,
+) -> ProductResult<G, G1::NodeId, G2::NodeId, G::NodeId, E>
+where
+    G1: IntoNodeReferences + IntoEdgeReferences + NodeCount,
+    G2: IntoNodeReferences + IntoEdgeReferences + NodeCount,
+    G1::NodeId: Eq + Hash,
+    G2::NodeId: Eq + Hash,
+    G: Build + Create + Data,
+    F: FnMut(&G1::NodeWeight, &G2::NodeWeight) -> Result<G::NodeWeight, E>,
+    H: FnMut(ProductEdge<G1::EdgeWeight, G2::EdgeWeight>) -> Result<G::EdgeWeight, E>,
+{
+    let (mut out_graph, node_map) = product_nodes(first, second, node_weight_fn)?;
+    add_cartesian_edges(
+        first,
+        second,
+        &mut out_graph,
+        &node_map,
+        &mut edge_weight_fn,
+    )?;
+    Ok((out_graph, node_map))
+}
+
+/// Return the tensor product of two graphs.
+///
+/// The tensor product, also known as the categorical or direct product, has
+/// a node `(x, y)` for every node `x` of `first` and every node `y` of
+/// `second`. There is an edge from `(x1, y1)` to `(x2, y2)` for every edge
+/// from `x1` to `x2` in `first` and every edge from `y1` to `y2` in `second`.
+/// For undirected graphs, every pair of edges that aren't self-loops also
+/// gives an edge from `(x1, y2)` to `(x2, y1)`.
+///
+/// Both input graphs and the output graph are expected to have the same
+/// directionality.
+///
+/// Arguments:
+///
+/// * `first` - The first factor graph.
+/// * `second` - The second factor graph.
+/// * `node_weight_fn` - A callable that will be passed the weights of `x` and
+///   `y` for every node `(x, y)` of the product and is expected to return a
+///   `Result` with the weight of that node.
+/// * `edge_weight_fn` - A callable that will be passed a [`ProductEdge`] with
+///   the weights of the factor edges of every edge of the product and is
+///   expected to return a `Result` with the weight of that edge.
+///
+/// Returns the product graph and a map from the pairs of nodes of the factor
+/// graphs to the nodes of the product graph.
+///
+/// # Example
+/// ```rust
+/// use std::convert::Infallible;
+/// use rustworkx_core::petgraph;
+/// use rustworkx_core::graph_product::{tensor_product, ProductEdge};
+///
+/// let first = petgraph::graph::DiGraph::<(), u32>::from_edges(&[(0, 1, 2)]);
+/// let second = petgraph::graph::DiGraph::<(), u32>::from_edges(&[(0, 1, 3), (1, 2, 5)]);
+/// let res: Result<(petgraph::graph::DiGraph<(), u32>, _), Infallible> =
+///     tensor_product(&first, &second, |_, _| Ok(()), |edge| match edge {
+///         ProductEdge::Both(a, b) => Ok(a * b),
+///         _ => unreachable!(),
+///     });
+/// let (product, _) = res.unwrap();
+/// assert_eq!(product.node_count(), 6);
+/// let weights: Vec<u32> = product.edge_weights().copied().collect();
+/// assert_eq!(weights, vec![6, 10]);
+/// ```
+pub fn tensor_product<G1, G2, G, F, H, E>(
+    first: G1,
+    second: G2,
+    node_weight_fn: F,
+    mut edge_weight_fn: H,
+) -> ProductResult<G, G1::NodeId, G2::NodeId, G::NodeId, E>
+where
+    G1: IntoNodeReferences + IntoEdgeReferences + NodeCount + GraphProp,
+    G2: IntoNodeReferences + IntoEdgeReferences + NodeCount,
+    G1::NodeId: Eq + Hash,
+    G2::NodeId: Eq + Hash,
+    G: Build + Create + Data,
+    F: FnMut(&G1::NodeWeight, &G2::NodeWeight) -> Result<G::NodeWeight, E>,
+    H: FnMut(ProductEdge<G1::EdgeWeight, G2::EdgeWeight>) -> Result<G::EdgeWeight, E>,
+{
+    let (mut out_graph, node_map) = product_nodes(first, second, node_weight_fn)?;
+    add_tensor_edges(
+        first,
+        second,
+        &mut out_graph,
+        &node_map,
+        &mut edge_weight_fn,
+    )?;
+    Ok((out_graph, node_map))
+}
+
+/// Return the strong product of two graphs.
+///
+/// The strong product has a node `(x, y)` for every node `x` of `first` and
+/// every node `y` of `second`, and its edges are the edges of the
+/// [`cartesian_product`] followed by the edges of the [`tensor_product`] of
+/// the two graphs.
+///
+/// Both input graphs and the output graph are expected to have the same
+/// directionality.
+///
+/// Arguments:
+///
+/// * `first` - The first factor graph.
+/// * `second` - The second factor graph.
+/// * `node_weight_fn` - A callable that will be passed the weights of `x` and
+///   `y` for every node `(x, y)` of the product and is expected to return a
+///   `Result` with the weight of that node.
+/// * `edge_weight_fn` - A callable that will be passed a [`ProductEdge`] with
+///   the weights of the factor edges of every edge of the product and is
+///   expected to return a `Result` with the weight of that edge.
+///
+/// Returns the product graph and a map from the pairs of nodes of the factor
+/// graphs to the nodes of the product graph.
+///
+/// # Example
+/// ```rust
+/// use std::convert::Infallible;
+/// use rustworkx_core::petgraph;
+/// use rustworkx_core::graph_product::strong_product;
+///
+/// // The strong product of two paths with 2 nodes is a complete graph
+/// let first = petgraph::graph::UnGraph::<(), ()>::from_edges(&[(0, 1)]);
+/// let res: Result<(petgraph::graph::UnGraph<(), ()>, _), Infallible> =
+///     strong_product(&first, &first, |_, _| Ok(()), |_| Ok(()));
+/// let (product, _) = res.unwrap();
+/// assert_eq!(product.node_count(), 4);
+/// assert_eq!(product.edge_count(), 6);
+/// ```
+pub fn strong_product<G1, G2, G, F, H, E>(
+    first: G1,
+    second: G2,
+    node_weight_fn: F,
+    mut edge_weight_fn: H,
+) -> ProductResult<G, G1::NodeId, G2::NodeId, G::NodeId, E>
+where
+    G1: IntoNodeReferences + IntoEdgeReferences + NodeCount + GraphProp,
+    G2: IntoNodeReferences + IntoEdgeReferences + NodeCount,
+    G1::NodeId: Eq + Hash,
+    G2::NodeId: Eq + Hash,
+    G: Build + Create + Data,
+    F: FnMut(&G1::NodeWeight, &G2::NodeWeight) -> Result<G::NodeWeight, E>,
+    H: FnMut(ProductEdge<G1::EdgeWeight, G2::EdgeWeight>) -> Result<G::EdgeWeight, E>,
+{
+    let (mut out_graph, node_map) = product_nodes(first, second, node_weight_fn)?;
+    add_cartesian_edges(
+        first,
+        second,
+        &mut out_graph,
+        &node_map,
+        &mut edge_weight_fn,
+    )?;
+    add_tensor_edges(
+        first,
+        second,
+        &mut out_graph,
+        &node_map,
+        &mut edge_weight_fn,
+    )?;
+    Ok((out_graph, node_map))
+}
+
+/// Return the lexicographic product of two graphs.
+///
+/// The lexicographic product, also known as the composition of the graphs,
+/// has a node `(x, y)` for every node `x` of `first` and every node `y` of
+/// `second`. There is an edge from `(x1, y1)` to `(x2, y2)` for every edge
+/// from `x1` to `x2` in `first` and every pair of nodes `y1` and `y2` of
+/// `second`, and an edge from `(x, y1)` to `(x, y2)` for every node `x` of
+/// `first` and every edge from `y1` to `y2` in `second`. So every node `x` of
+/// `first` is replaced by a copy of `second`, and every edge of `first`
+/// connects all the nodes of the two copies at its endpoints. For an
+/// undirected self-loop in `first` every unordered pair of nodes of `second`
+/// is only connected once.
+///
+/// Both input graphs and the output graph are expected to have the same
+/// directionality.
+///
+/// Arguments:
+///
+/// * `first` - The first factor graph.
+/// * `second` - The second factor graph.
+/// * `node_weight_fn` - A callable that will be passed the weights of `x` and
+///   `y` for every node `(x, y)` of the product and is expected to return a
+///   `Result` with the weight of that node.
+/// * `edge_weight_fn` - A callable that will be passed a [`ProductEdge`] with
+///   the weight of the factor edge of every edge of the product and is
+///   expected to return a `Result` with the weight of that edge.
+///
+/// Returns the product graph and a map from the pairs of nodes of the factor
+/// graphs to the nodes of the product graph.
+///
+/// # Example
+/// ```rust
+/// use std::convert::Infallible;
+/// use rustworkx_core::petgraph;
+/// use rustworkx_core::graph_product::lexicographic_product;
+///
+/// let first = petgraph::graph::UnGraph::<(), ()>::from_edges(&[(0, 1)]);
+/// let second = petgraph::graph::UnGraph::<(), ()>::from_edges(&[(0, 1), (1, 2)]);
+/// let res: Result<(petgraph::graph::UnGraph<(), ()>, _), Infallible> =
+///     lexicographic_product(&first, &second, |_, _| Ok(()), |_| Ok(()));
+/// let (product, _) = res.unwrap();
+/// assert_eq!(product.node_count(), 6);
+/// // 3 * 3 edges between the copies of second and 2 edges within each copy
+/// assert_eq!(product.edge_count(), 13);
+/// ```
+pub fn lexicographic_product<G1, G2, G, F, H, E>(
+    first: G1,
+    second: G2,
+    node_weight_fn: F,
+    mut edge_weight_fn: H,
+) -> ProductResult<G, G1::NodeId, G2::NodeId, G::NodeId, E>
+where
+    G1: IntoNodeReferences + IntoEdgeReferences + NodeCount + GraphProp,
+    G2: IntoNodeReferences + IntoEdgeReferences + NodeCount,
+    G1::NodeId: Eq + Hash,
+    G2::NodeId: Eq + Hash,
+    G: Build + Create + Data,
+    F: FnMut(&G1::NodeWeight, &G2::NodeWeight) -> Result<G::NodeWeight, E>,
+    H: FnMut(ProductEdge<G1::EdgeWeight, G2::EdgeWeight>) -> Result<G::EdgeWeight, E>,
+{
+    let (mut out_graph, node_map): (G, _) = product_nodes(first, second, node_weight_fn)?;
+    let second_nodes: Vec<G2::NodeId> = second.node_references().map(|y| y.id()).collect();
+    for edge in first.edge_references() {
+        let self_loop = !first.is_directed() && edge.source() == edge.target();
+        for (i, y1) in second_nodes.iter().enumerate() {
+            let start = if self_loop { i } else { 0 };
+            for y2 in &second_nodes[start..] {
+                let source = node_map[&(edge.source(), *y1)];
+                let target = node_map[&(edge.target(), *y2)];
+                out_graph.add_edge(
+                    source,
+                    target,
+                    edge_weight_fn(ProductEdge::First(edge.weight()))?,
+                );
+            }
+        }
+    }
+    for x in first.node_references() {
+        for edge in second.edge_references() {
+            let source = node_map[&(x.id(), edge.source())];
+            let target = node_map[&(x.id(), edge.target())];
+            out_graph.add_edge(
+                source,
+                target,
+                edge_weight_fn(ProductEdge::Second(edge.weight()))?,
+            );
+        }
+    }
+    Ok((out_graph, node_map))
+}
+
+#[cfg(test)]
+mod test_graph_product {
+    use super::{
+        cartesian_product, lexicographic_product, strong_product, tensor_product, ProductEdge,
+    };
+    use petgraph::graph::{DiGraph, UnGraph};
+    use petgraph::visit::EdgeRef;
+    use std::convert::Infallible;
+
+    fn edge_list<E>(graph: &UnGraph<(), E>) -> Vec<(usize, usize)> {
+        let mut edges: Vec<(usize, usize)> = graph
+            .edge_references()
+            .map(|e| {
+                let (s, t) = (e.source().index(), e.target().index());
+                (s.min(t), s.max(t))
+            })
+            .collect();
+        edges.sort_unstable();
+        edges
+    }
+
+    #[test]
+    fn test_cartesian_weights() {
+        let first = DiGraph::<char, u32>::from_edges([(0, 1, 1)]);
+        let first = first.map(|i, _| ['a', 'b'][i.index()], |_, w| *w);
+        let second = DiGraph::<char, u32>::from_edges([(0, 1, 10), (1, 2, 20)]);
+        let second = second.map(|i, _| ['x', 'y', 'z'][i.index()], |_, w| *w);
+        let res: Result<(DiGraph<String, u32>, _), Infallible> = cartesian_product(
+            &first,
+            &second,
+            |a, b| Ok(format!("{a}{b}")),
+            |edge| match edge {
+                ProductEdge::First(w) => Ok(*w),
+                ProductEdge::Second(w) => Ok(*w),
+                ProductEdge::Both(_, _) => unreachable!(),
+            },
+        );
+        let (product, node_map) = res.unwrap();
+        assert_eq!(product[node_map[&(1.into(), 2.into())]], "bz");
+        let mut weights: Vec<u32> = product.edge_weights().copied().collect();
+        weights.sort_unstable();
+        assert_eq!(weights, vec![1, 1, 1, 10, 10, 20, 20]);
+        for edge in product.edge_references() {
+            assert!(edge.source().index() < edge.target().index());
+        }
+    }
+
+    #[test]
+    fn test_tensor_undirected() {
+        // The tensor product of two paths with 2 nodes is 2 disjoint edges
+        let path = UnGraph::<(), ()>::from_edges([(0, 1)]);
+        let res: Result<(UnGraph<(), ()>, _), Infallible> =
+            tensor_product(&path, &path, |_, _| Ok(()), |_| Ok(()));
+        let (product, _) = res.unwrap();
+        assert_eq!(edge_list(&product), vec![(0, 3), (1, 2)]);
+    }
+
+    #[test]
+    fn test_tensor_self_loop() {
+        let first = UnGraph::<(), ()>::from_edges([(0, 0)]);
+        let second = UnGraph::<(), ()>::from_edges([(0, 1)]);
+        let res: Result<(UnGraph<(), ()>, _), Infallible> =
+            tensor_product(&first, &second, |_, _| Ok(()), |_| Ok(()));
+        let (product, _) = res.unwrap();
+        assert_eq!(edge_list(&product), vec![(0, 1)]);
+    }
+
+    #[test]
+    fn test_strong_complete() {
+        // The strong product of complete graphs is a complete graph
+        let first = UnGraph::<(), ()>::from_edges([(0, 1), (1, 2), (0, 2)]);
+        let second = UnGraph::<(), ()>::from_edges([(0, 1)]);
+        let res: Result<(UnGraph<(), ()>, _), Infallible> =
+            strong_product(&first, &second, |_, _| Ok(()), |_| Ok(()));
+        let (product, _) = res.unwrap();
+        assert_eq!(product.node_count(), 6);
+        assert_eq!(product.edge_count(), 15);
+    }
+
+    #[test]
+    fn test_strong_directed() {
+        let path = DiGraph::<(), ()>::from_edges([(0, 1)]);
+        let res: Result<(DiGraph<(), ()>, _), Infallible> =
+            strong_product(&path, &path, |_, _| Ok(()), |_| Ok(()));
+        let (product, node_map) = res.unwrap();
+        assert_eq!(product.edge_count(), 5);
+        assert!(product.contains_edge(
+            node_map[&(0.into(), 0.into())],
+            node_map[&(1.into(), 1.into())]
+        ));
+        assert!(!product.contains_edge(
+            node_map[&(0.into(), 1.into())],
+            node_map[&(1.into(), 0.into())]
+        ));
+    }
+
+    #[test]
+    fn test_lexicographic_directed() {
+        let first = DiGraph::<(), ()>::from_edges([(0, 1)]);
+        let second = DiGraph::<(), ()>::from_edges([(0, 1)]);
+        let res: Result<(DiGraph<(), ()>, _), Infallible> =
+            lexicographic_product(&first, &second, |_, _| Ok(()), |_| Ok(()));
+        let (product, node_map) = res.unwrap();
+        assert_eq!(product.edge_count(), 6);
+        assert!(product.contains_edge(
+            node_map[&(0.into(), 1.into())],
+            node_map[&(1.into(), 0.into())]
+        ));
+        assert!(!product.contains_edge(
+            node_map[&(1.into(), 0.into())],
+            node_map[&(0.into(), 1.into())]
+        ));
+    }
+
+    #[test]
+    fn test_lexicographic_not_commutative() {
+        let path = UnGraph::<(), ()>::from_edges([(0, 1), (1, 2)]);
+        let mut empty = UnGraph::<(), ()>::default();
+        empty.add_node(());
+        empty.add_node(());
+        let res: Result<(UnGraph<(), ()>, _), Infallible> =
+            lexicographic_product(&path, &empty, |_, _| Ok(()), |_| Ok(()));
+        assert_eq!(res.unwrap().0.edge_count(), 8);
+        let res: Result<(UnGraph<(), ()>, _), Infallible> =
+            lexicographic_product(&empty, &path, |_, _| Ok(()), |_| Ok(()));
+        assert_eq!(res.unwrap().0.edge_count(), 4);
+    }
+
+    #[test]
+    fn test_lexicographic_self_loop() {
+        let first = UnGraph::<(), ()>::from_edges([(0, 0)]);
+        let second = UnGraph::<(), ()>::from_edges([(0, 1)]);
+        let res: Result<(UnGraph<(), ()>, _), Infallible> =
+            lexicographic_product(&first, &second, |_, _| Ok(()), |_| Ok(()));
+        let (product, _) = res.unwrap();
+        assert_eq!(edge_list(&product), vec![(0, 0), (0, 1), (0, 1), (1, 1)]);
+    }
+
+    #[test]
+    fn test_callback_error() {
+        let path = UnGraph::<(), ()>::from_edges([(0, 1)]);
+        let res: Result<(UnGraph<(), ()>, _), &str> =
+            cartesian_product(&path, &path, |_, _| Ok(()), |_| Err("error"));
+        assert_eq!(res.err(), Some("error"));
+        let res: Result<(UnGraph<(), ()>, _), &str> =
+            tensor_product(&path, &path, |_, _| Err("error"), |_| Ok(()));
+        assert_eq!(res.err(), Some("error"));
+    }
+}
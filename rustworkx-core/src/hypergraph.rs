@@ -0,0 +1,632 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+//! A hypergraph type.
+//!
+//! A hypergraph generalizes a graph by letting every edge, called a
+//! hyperedge, connect any number of nodes. [`Hypergraph`] stores the nodes
+//! and the hyperedges with their payloads, and can be converted to and from
+//! its bipartite incidence graph, or expanded to an ordinary graph with a
+//! clique for every hyperedge, so the algorithms working on `petgraph`
+//! graphs can be applied after the conversion.
+
+use std::hash::Hash;
+
+use petgraph::data::{Build, Create};
+use petgraph::visit::{Data, EdgeRef, IntoEdgeReferences, IntoNodeReferences, NodeRef};
+
+use crate::dictmap::*;
+
+#[derive(Clone, Debug)]
+struct Node<N> {
+    weight: N,
+    hyperedges: Vec<usize>,
+}
+
+#[derive(Clone, Debug)]
+struct Hyperedge<E> {
+    weight: E,
+    nodes: Vec<usize>,
+}
+
+/// A hypergraph with node weights `N` and hyperedge weights `E`.
+///
+/// Nodes and hyperedges are identified by `usize` indices which stay valid
+/// when other nodes or hyperedges are removed. The indices of removed nodes
+/// and hyperedges are not reused. A hyperedge is a set of nodes: adding a
+/// node to a hyperedge more than once has no effect, and removing a node
+/// removes it from all of its hyperedges.
+///
+/// # Example
+/// ```rust
+/// use rustworkx_core::hypergraph::Hypergraph;
+///
+/// let mut hypergraph = Hypergraph::new();
+/// let a = hypergraph.add_node("a");
+/// let b = hypergraph.add_node("b");
+/// let c = hypergraph.add_node("c");
+/// let e = hypergraph.add_hyperedge([a, b, c], 1.5);
+/// assert_eq!(hypergraph.hyperedge_nodes(e), Some(&[a, b, c][..]));
+/// hypergraph.remove_node(b);
+/// assert_eq!(hypergraph.hyperedge_nodes(e), Some(&[a, c][..]));
+/// ```
+#[derive(Clone, Debug)]
+pub struct Hypergraph<N, E> {
+    nodes: Vec<Option<Node<N>>>,
+    hyperedges: Vec<Option<Hyperedge<E>>>,
+    node_count: usize,
+    hyperedge_count: usize,
+}
+
+impl<N, E> Default for Hypergraph<N, E> {
+    fn default() -> Self {
+        Hypergraph {
+            nodes: Vec::new(),
+            hyperedges: Vec::new(),
+            node_count: 0,
+            hyperedge_count: 0,
+        }
+    }
+}
+
+impl<N, E> Hypergraph<N, E> {
+    /// Create an empty hypergraph.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create an empty hypergraph with room for the given number of nodes
+    /// and hyperedges.
+    pub fn with_capacity(nodes: usize, hyperedges: usize) -> Self {
+        Hypergraph {
+            nodes: Vec::with_capacity(nodes),
+            hyperedges: Vec::with_capacity(hyperedges),
+            node_count: 0,
+            hyperedge_count: 0,
+        }
+    }
+
+    /// Return the number of nodes.
+    pub fn node_count(&self) -> usize {
+        self.node_count
+    }
+
+    /// Return the number of hyperedges.
+    pub fn hyperedge_count(&self) -> usize {
+        self.hyperedge_count
+    }
+
+    /// Return an upper bound of the node indices.
+    pub fn node_bound(&self) -> usize {
+        self.nodes.len()
+    }
+
+    /// Return an upper bound of the hyperedge indices.
+    pub fn hyperedge_bound(&self) -> usize {
+        self.hyperedges.len()
+    }
+
+    /// Add a node and return its index.
+    pub fn add_node(&mut self, weight: N) -> usize {
+        self.nodes.push(Some(Node {
+            weight,
+            hyperedges: Vec::new(),
+        }));
+        self.node_count += 1;
+        self.nodes.len() - 1
+    }
+
+    /// Add a hyperedge connecting the given nodes and return its index.
+    ///
+    /// # Panics
+    ///
+    /// Panics if one of the nodes isn't in the hypergraph.
+    pub fn add_hyperedge<I>(&mut self, nodes: I, weight: E) -> usize
+    where
+        I: IntoIterator<Item = usize>,
+    {
+        let index = self.hyperedges.len();
+        let mut members = Vec::new();
+        for node in nodes {
+            let node_data = self.nodes[node]
+                .as_mut()
+                .expect("The nodes of a hyperedge must be in the hypergraph");
+            if node_data.hyperedges.last() != Some(&index) {
+                node_data.hyperedges.push(index);
+                members.push(node);
+            }
+        }
+        self.hyperedges.push(Some(Hyperedge {
+            weight,
+            nodes: members,
+        }));
+        self.hyperedge_count += 1;
+        index
+    }
+
+    /// Remove a node and return its weight, or `None` if it wasn't in the
+    /// hypergraph. The node is removed from all of its hyperedges.
+    pub fn remove_node(&mut self, node: usize) -> Option<N> {
+        let node_data = self.nodes.get_mut(node)?.take()?;
+        for hyperedge in &node_data.hyperedges {
+            if let Some(hyperedge) = self.hyperedges[*hyperedge].as_mut() {
+                hyperedge.nodes.retain(|member| *member != node);
+            }
+        }
+        self.node_count -= 1;
+        Some(node_data.weight)
+    }
+
+    /// Remove a hyperedge and return its weight, or `None` if it wasn't in
+    /// the hypergraph.
+    pub fn remove_hyperedge(&mut self, hyperedge: usize) -> Option<E> {
+        let hyperedge_data = self.hyperedges.get_mut(hyperedge)?.take()?;
+        for node in &hyperedge_data.nodes {
+            if let Some(node) = self.nodes[*node].as_mut() {
+                node.hyperedges.retain(|other| *other != hyperedge);
+            }
+        }
+        self.hyperedge_count -= 1;
+        Some(hyperedge_data.weight)
+    }
+
+    /// Return `true` if the node is in the hypergraph.
+    pub fn contains_node(&self, node: usize) -> bool {
+        matches!(self.nodes.get(node), Some(Some(_)))
+    }
+
+    /// Return `true` if the hyperedge is in the hypergraph.
+    pub fn contains_hyperedge(&self, hyperedge: usize) -> bool {
+        matches!(self.hyperedges.get(hyperedge), Some(Some(_)))
+    }
+
+    /// Return the weight of a node.
+    pub fn node_weight(&self, node: usize) -> Option<&N> {
+        self.nodes.get(node)?.as_ref().map(|node| &node.weight)
+    }
+
+    /// Return the weight of a node mutably.
+    pub fn node_weight_mut(&mut self, node: usize) -> Option<&mut N> {
+        self.nodes
+            .get_mut(node)?
+            .as_mut()
+            .map(|node| &mut node.weight)
+    }
+
+    /// Return the weight of a hyperedge.
+    pub fn hyperedge_weight(&self, hyperedge: usize) -> Option<&E> {
+        self.hyperedges
+            .get(hyperedge)?
+            .as_ref()
+            .map(|hyperedge| &hyperedge.weight)
+    }
+
+    /// Return the weight of a hyperedge mutably.
+    pub fn hyperedge_weight_mut(&mut self, hyperedge: usize) -> Option<&mut E> {
+        self.hyperedges
+            .get_mut(hyperedge)?
+            .as_mut()
+            .map(|hyperedge| &mut hyperedge.weight)
+    }
+
+    /// Return the nodes of a hyperedge in the order they were added.
+    pub fn hyperedge_nodes(&self, hyperedge: usize) -> Option<&[usize]> {
+        self.hyperedges
+            .get(hyperedge)?
+            .as_ref()
+            .map(|hyperedge| hyperedge.nodes.as_slice())
+    }
+
+    /// Return the hyperedges containing a node in the order they were added.
+    pub fn incident_hyperedges(&self, node: usize) -> Option<&[usize]> {
+        self.nodes
+            .get(node)?
+            .as_ref()
+            .map(|node| node.hyperedges.as_slice())
+    }
+
+    /// Return the nodes sharing at least one hyperedge with a node, without
+    /// duplicates and not including the node itself.
+    pub fn neighbors(&self, node: usize) -> Option<Vec<usize>> {
+        let hyperedges = self.incident_hyperedges(node)?;
+        let mut seen = vec![false; self.nodes.len()];
+        seen[node] = true;
+        let mut neighbors = Vec::new();
+        for hyperedge in hyperedges {
+            for member in self.hyperedge_nodes(*hyperedge).unwrap() {
+                if !seen[*member] {
+                    seen[*member] = true;
+                    neighbors.push(*member);
+                }
+            }
+        }
+        Some(neighbors)
+    }
+
+    /// Return an iterator over the node indices.
+    pub fn node_indices(&self) -> impl Iterator<Item = usize> + '_ {
+        self.nodes
+            .iter()
+            .enumerate()
+            .filter_map(|(index, node)| node.as_ref().map(|_| index))
+    }
+
+    /// Return an iterator over the hyperedge indices.
+    pub fn hyperedge_indices(&self) -> impl Iterator<Item = usize> + '_ {
+        self.hyperedges
+            .iter()
+            .enumerate()
+            .filter_map(|(index, hyperedge)| hyperedge.as_ref().map(|_| index))
+    }
+
+    /// Build the bipartite incidence graph of the hypergraph.
+    ///
+    /// The incidence graph has a node for every node and every hyperedge of
+    /// the hypergraph, and an edge between a node and each of its
+    /// hyperedges. The nodes of the hypergraph are added to the graph
+    /// first, followed by the hyperedges, both in index order.
+    ///
+    /// Arguments:
+    ///
+    /// * `node_fn` - A callback returning the weight of the graph node for a
+    ///   node of the hypergraph.
+    /// * `hyperedge_fn` - A callback returning the weight of the graph node
+    ///   for a hyperedge.
+    /// * `default_edge_weight` - A callback returning the weight of every
+    ///   edge of the graph.
+    ///
+    /// Returns the graph and the maps of the node indices and the hyperedge
+    /// indices to the graph nodes.
+    ///
+    /// # Example
+    /// ```rust
+    /// use rustworkx_core::hypergraph::Hypergraph;
+    /// use rustworkx_core::petgraph::graph::UnGraph;
+    ///
+    /// let mut hypergraph = Hypergraph::new();
+    /// let nodes: Vec<usize> = (0..3).map(|i| hypergraph.add_node(i)).collect();
+    /// hypergraph.add_hyperedge(nodes.clone(), 10);
+    /// let (graph, _, hyperedges): (UnGraph<i32, ()>, _, _) =
+    ///     hypergraph.incidence_graph(|node| *node, |hyperedge| *hyperedge, || ());
+    /// assert_eq!(graph.node_count(), 4);
+    /// assert_eq!(graph.neighbors(hyperedges[&0]).count(), 3);
+    /// ```
+    #[allow(clippy::type_complexity)]
+    pub fn incidence_graph<G, T, M, F, H, W>(
+        &self,
+        mut node_fn: F,
+        mut hyperedge_fn: H,
+        mut default_edge_weight: W,
+    ) -> (G, DictMap<usize, G::NodeId>, DictMap<usize, G::NodeId>)
+    where
+        G: Build + Create + Data<NodeWeight = T, EdgeWeight = M>,
+        F: FnMut(&N) -> T,
+        H: FnMut(&E) -> T,
+        W: FnMut() -> M,
+    {
+        let num_edges = self
+            .hyperedges
+            .iter()
+            .flatten()
+            .map(|hyperedge| hyperedge.nodes.len())
+            .sum();
+        let mut graph = G::with_capacity(self.node_count + self.hyperedge_count, num_edges);
+        let mut node_map = DictMap::with_capacity(self.node_count);
+        for (index, node) in self.nodes.iter().enumerate() {
+            if let Some(node) = node {
+                node_map.insert(index, graph.add_node(node_fn(&node.weight)));
+            }
+        }
+        let mut hyperedge_map = DictMap::with_capacity(self.hyperedge_count);
+        for (index, hyperedge) in self.hyperedges.iter().enumerate() {
+            if let Some(hyperedge) = hyperedge {
+                let hyperedge_node = graph.add_node(hyperedge_fn(&hyperedge.weight));
+                for node in &hyperedge.nodes {
+                    graph.add_edge(node_map[node], hyperedge_node, default_edge_weight());
+                }
+                hyperedge_map.insert(index, hyperedge_node);
+            }
+        }
+        (graph, node_map, hyperedge_map)
+    }
+
+    /// Build a hypergraph from a bipartite incidence graph.
+    ///
+    /// Every node of the graph for which `is_hyperedge` returns `true`
+    /// becomes a hyperedge containing its neighbors, and every other node
+    /// becomes a node of the hypergraph. Nodes and hyperedges are numbered
+    /// in the order of the graph's node references. Edges between two nodes
+    /// or between two hyperedges are ignored.
+    ///
+    /// Arguments:
+    ///
+    /// * `graph` - The incidence graph.
+    /// * `is_hyperedge` - A callback returning whether a graph node is a
+    ///   hyperedge.
+    /// * `node_fn` - A callback returning the weight of a node of the
+    ///   hypergraph from the weight of the graph node.
+    /// * `hyperedge_fn` - A callback returning the weight of a hyperedge
+    ///   from the weight of the graph node.
+    ///
+    /// # Example
+    /// ```rust
+    /// use rustworkx_core::hypergraph::Hypergraph;
+    /// use rustworkx_core::petgraph::graph::UnGraph;
+    ///
+    /// // A star whose center is a hyperedge
+    /// let graph = UnGraph::<(), ()>::from_edges(&[(0, 1), (0, 2), (0, 3)]);
+    /// let hypergraph: Hypergraph<(), ()> =
+    ///     Hypergraph::from_incidence_graph(&graph, |node| node.index() == 0, |_| (), |_| ());
+    /// assert_eq!(hypergraph.node_count(), 3);
+    /// assert_eq!(hypergraph.hyperedge_nodes(0), Some(&[0, 1, 2][..]));
+    /// ```
+    pub fn from_incidence_graph<G, C, F, H>(
+        graph: G,
+        mut is_hyperedge: C,
+        mut node_fn: F,
+        mut hyperedge_fn: H,
+    ) -> Self
+    where
+        G: IntoNodeReferences + IntoEdgeReferences,
+        G::NodeId: Eq + Hash,
+        C: FnMut(G::NodeId) -> bool,
+        F: FnMut(&G::NodeWeight) -> N,
+        H: FnMut(&G::NodeWeight) -> E,
+    {
+        let mut hypergraph = Hypergraph::new();
+        let mut node_map: DictMap<G::NodeId, usize> = DictMap::new();
+        let mut hyperedge_map: DictMap<G::NodeId, usize> = DictMap::new();
+        for node in graph.node_references() {
+            if is_hyperedge(node.id()) {
+                hyperedge_map.insert(node.id(), hyperedge_map.len());
+                hypergraph.hyperedges.push(Some(Hyperedge {
+                    weight: hyperedge_fn(node.weight()),
+                    nodes: Vec::new(),
+                }));
+                hypergraph.hyperedge_count += 1;
+            } else {
+                node_map.insert(node.id(), hypergraph.add_node(node_fn(node.weight())));
+            }
+        }
+        let mut members: Vec<Vec<usize>> = vec![Vec::new(); hyperedge_map.len()];
+        for edge in graph.edge_references() {
+            let (node, hyperedge) = match (
+                node_map.get(&edge.source()),
+                hyperedge_map.get(&edge.target()),
+                node_map.get(&edge.target()),
+                hyperedge_map.get(&edge.source()),
+            ) {
+                (Some(node), Some(hyperedge), _, _) | (_, _, Some(node), Some(hyperedge)) => {
+                    (*node, *hyperedge)
+                }
+                _ => continue,
+            };
+            members[hyperedge].push(node);
+        }
+        for (hyperedge, nodes) in members.into_iter().enumerate() {
+            for node in nodes {
+                let node_data = hypergraph.nodes[node].as_mut().unwrap();
+                if node_data.hyperedges.last() != Some(&hyperedge) {
+                    node_data.hyperedges.push(hyperedge);
+                    hypergraph.hyperedges[hyperedge]
+                        .as_mut()
+                        .unwrap()
+                        .nodes
+                        .push(node);
+                }
+            }
+        }
+        hypergraph
+    }
+
+    /// Build the clique expansion of the hypergraph.
+    ///
+    /// The clique expansion is the graph on the nodes of the hypergraph
+    /// with a single edge between every pair of distinct nodes that share
+    /// at least one hyperedge. The nodes are added to the graph in index
+    /// order. Unlike the incidence graph, the hypergraph can't be recovered
+    /// from its clique expansion.
+    ///
+    /// Arguments:
+    ///
+    /// * `node_fn` - A callback returning the weight of the graph node for a
+    ///   node of the hypergraph.
+    /// * `edge_fn` - A callback returning the weight of an edge from the
+    ///   indices of the hyperedges shared by its endpoints.
+    ///
+    /// Returns the graph and the map of the node indices to the graph nodes.
+    ///
+    /// # Example
+    /// ```rust
+    /// use rustworkx_core::hypergraph::Hypergraph;
+    /// use rustworkx_core::petgraph::graph::UnGraph;
+    ///
+    /// let mut hypergraph = Hypergraph::new();
+    /// let nodes: Vec<usize> = (0..4).map(|_| hypergraph.add_node(())).collect();
+    /// hypergraph.add_hyperedge([0, 1, 2], ());
+    /// hypergraph.add_hyperedge([2, 3], ());
+    /// // The edge weights are the number of shared hyperedges
+    /// let (graph, _): (UnGraph<(), usize>, _) =
+    ///     hypergraph.clique_expansion(|_| (), |shared| shared.len());
+    /// assert_eq!(graph.edge_count(), 4);
+    /// ```
+    pub fn clique_expansion<G, T, M, F, W>(
+        &self,
+        mut node_fn: F,
+        mut edge_fn: W,
+    ) -> (G, DictMap<usize, G::NodeId>)
+    where
+        G: Build + Create + Data<NodeWeight = T, EdgeWeight = M>,
+        F: FnMut(&N) -> T,
+        W: FnMut(&[usize]) -> M,
+    {
+        let mut shared: DictMap<(usize, usize), Vec<usize>> = DictMap::new();
+        for (index, node) in self.nodes.iter().enumerate() {
+            if let Some(node) = node {
+                for hyperedge in &node.hyperedges {
+                    for member in self.hyperedge_nodes(*hyperedge).unwrap() {
+                        if *member > index {
+                            shared.entry((index, *member)).or_default().push(*hyperedge);
+                        }
+                    }
+                }
+            }
+        }
+        let mut graph = G::with_capacity(self.node_count, shared.len());
+        let mut node_map = DictMap::with_capacity(self.node_count);
+        for (index, node) in self.nodes.iter().enumerate() {
+            if let Some(node) = node {
+                node_map.insert(index, graph.add_node(node_fn(&node.weight)));
+            }
+        }
+        for ((source, target), hyperedges) in shared {
+            graph.add_edge(node_map[&source], node_map[&target], edge_fn(&hyperedges));
+        }
+        (graph, node_map)
+    }
+}
+
+#[cfg(test)]
+mod test_hypergraph {
+    use super::Hypergraph;
+    use petgraph::graph::{NodeIndex, UnGraph};
+    use petgraph::stable_graph::StableUnGraph;
+
+    fn example() -> Hypergraph<usize, char> {
+        let mut hypergraph = Hypergraph::new();
+        for i in 0..5 {
+            hypergraph.add_node(i);
+        }
+        hypergraph.add_hyperedge([0, 1, 2], 'a');
+        hypergraph.add_hyperedge([2, 3], 'b');
+        hypergraph.add_hyperedge([0, 2, 3, 4], 'c');
+        hypergraph
+    }
+
+    #[test]
+    fn test_accessors() {
+        let hypergraph = example();
+        assert_eq!(hypergraph.node_count(), 5);
+        assert_eq!(hypergraph.hyperedge_count(), 3);
+        assert_eq!(hypergraph.incident_hyperedges(2), Some(&[0, 1, 2][..]));
+        assert_eq!(hypergraph.hyperedge_nodes(1), Some(&[2, 3][..]));
+        assert_eq!(hypergraph.hyperedge_weight(2), Some(&'c'));
+        assert_eq!(hypergraph.node_weight(4), Some(&4));
+        assert_eq!(hypergraph.neighbors(3), Some(vec![2, 0, 4]));
+        assert_eq!(hypergraph.neighbors(5), None);
+        assert_eq!(hypergraph.hyperedge_nodes(3), None);
+    }
+
+    #[test]
+    fn test_duplicate_members() {
+        let mut hypergraph = Hypergraph::new();
+        let a = hypergraph.add_node(());
+        let b = hypergraph.add_node(());
+        let e = hypergraph.add_hyperedge([a, b, a, b], ());
+        assert_eq!(hypergraph.hyperedge_nodes(e), Some(&[a, b][..]));
+        assert_eq!(hypergraph.incident_hyperedges(a), Some(&[e][..]));
+    }
+
+    #[test]
+    fn test_empty_hyperedge() {
+        let mut hypergraph: Hypergraph<(), ()> = Hypergraph::new();
+        let e = hypergraph.add_hyperedge([], ());
+        assert_eq!(hypergraph.hyperedge_nodes(e), Some(&[][..]));
+        assert_eq!(hypergraph.hyperedge_count(), 1);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_missing_node_panics() {
+        let mut hypergraph: Hypergraph<(), ()> = Hypergraph::new();
+        hypergraph.add_hyperedge([0], ());
+    }
+
+    #[test]
+    fn test_remove() {
+        let mut hypergraph = example();
+        assert_eq!(hypergraph.remove_node(2), Some(2));
+        assert_eq!(hypergraph.remove_node(2), None);
+        assert_eq!(hypergraph.hyperedge_nodes(0), Some(&[0, 1][..]));
+        assert_eq!(hypergraph.hyperedge_nodes(1), Some(&[3][..]));
+        assert_eq!(hypergraph.remove_hyperedge(0), Some('a'));
+        assert_eq!(hypergraph.incident_hyperedges(0), Some(&[2][..]));
+        assert_eq!(hypergraph.incident_hyperedges(1), Some(&[][..]));
+        assert_eq!(hypergraph.node_indices().collect::<Vec<_>>(), [0, 1, 3, 4]);
+        assert_eq!(hypergraph.hyperedge_indices().collect::<Vec<_>>(), [1, 2]);
+        assert_eq!(hypergraph.node_count(), 4);
+        assert_eq!(hypergraph.hyperedge_count(), 2);
+        // Indices aren't reused
+        assert_eq!(hypergraph.add_node(5), 5);
+        assert_eq!(hypergraph.add_hyperedge([5], 'd'), 3);
+    }
+
+    #[test]
+    fn test_incidence_round_trip() {
+        let mut hypergraph = example();
+        hypergraph.remove_node(1);
+        hypergraph.remove_hyperedge(1);
+        let (graph, node_map, hyperedge_map): (StableUnGraph<Option<usize>, ()>, _, _) =
+            hypergraph.incidence_graph(|node| Some(*node), |_| None, || ());
+        assert_eq!(graph.node_count(), 6);
+        assert_eq!(graph.edge_count(), 6);
+        assert_eq!(graph.neighbors(hyperedge_map[&2]).count(), 4);
+        assert_eq!(graph[node_map[&4]], Some(4));
+        let round_trip: Hypergraph<usize, ()> = Hypergraph::from_incidence_graph(
+            &graph,
+            |node| graph[node].is_none(),
+            |weight| weight.unwrap(),
+            |_| (),
+        );
+        assert_eq!(round_trip.node_count(), 4);
+        assert_eq!(round_trip.hyperedge_count(), 2);
+        let weights = |e: usize| -> Vec<usize> {
+            let mut weights: Vec<usize> = round_trip
+                .hyperedge_nodes(e)
+                .unwrap()
+                .iter()
+                .map(|node| *round_trip.node_weight(*node).unwrap())
+                .collect();
+            weights.sort();
+            weights
+        };
+        assert_eq!(weights(0), [0, 2]);
+        assert_eq!(weights(1), [0, 2, 3, 4]);
+    }
+
+    #[test]
+    fn test_from_incidence_ignores_other_edges() {
+        let graph = UnGraph::<(), ()>::from_edges([(0, 1), (1, 2), (0, 3), (1, 3)]);
+        let hypergraph: Hypergraph<(), ()> = Hypergraph::from_incidence_graph(
+            &graph,
+            |node: NodeIndex| node.index() < 2,
+            |_| (),
+            |_| (),
+        );
+        assert_eq!(hypergraph.hyperedge_count(), 2);
+        assert_eq!(hypergraph.hyperedge_nodes(0), Some(&[1][..]));
+        assert_eq!(hypergraph.hyperedge_nodes(1), Some(&[0, 1][..]));
+    }
+
+    #[test]
+    fn test_clique_expansion() {
+        let hypergraph = example();
+        let (graph, node_map): (UnGraph<usize, Vec<usize>>, _) =
+            hypergraph.clique_expansion(|node| *node, |shared| shared.to_vec());
+        assert_eq!(graph.node_count(), 5);
+        // Pairs of the hyperedge of size 4 plus 0-1 and 1-2
+        assert_eq!(graph.edge_count(), 8);
+        let edge = graph.find_edge(node_map[&2], node_map[&3]).unwrap();
+        assert_eq!(graph[edge], vec![1, 2]);
+        assert!(graph.find_edge(node_map[&1], node_map[&3]).is_none());
+    }
+}
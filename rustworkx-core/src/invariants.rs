@@ -0,0 +1,409 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+
+use hashbrown::HashSet;
+use petgraph::visit::{EdgeRef, GraphProp, IntoEdgeReferences, IntoNodeIdentifiers, NodeIndexable};
+
+/// An isomorphism invariant of a graph, in the order they are compared by
+/// [`distinguishing_invariant`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Invariant {
+    /// The number of nodes.
+    NodeCount,
+    /// The number of edges.
+    EdgeCount,
+    /// The sorted sequence of the (out-degree, in-degree) pairs of the nodes.
+    DegreeSequence,
+    /// The sorted sequence of the number of triangles through every node.
+    TriangleCounts,
+    /// The sorted multiset of the Weisfeiler-Lehman colors of the nodes.
+    WeisfeilerLehmanColors,
+}
+
+/// The isomorphism invariants of a graph, as computed by [`graph_invariants`].
+///
+/// Isomorphic graphs always have equal invariants, so two graphs with
+/// different invariants can't be isomorphic. Since the struct implements
+/// [`Hash`] and [`Eq`] it can be used to bucket a collection of graphs so
+/// only the graphs in the same bucket have to be checked for isomorphism.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct GraphInvariants {
+    /// The number of nodes.
+    pub node_count: usize,
+    /// The number of edges.
+    pub edge_count: usize,
+    /// The sorted (out-degree, in-degree) pairs of the nodes. Both entries
+    /// are the degree of the node for undirected graphs.
+    pub degree_sequence: Vec<(usize, usize)>,
+    /// The sorted number of triangles through every node of the underlying
+    /// simple undirected graph.
+    pub triangle_counts: Vec<usize>,
+    /// The sorted Weisfeiler-Lehman colors of the nodes after the last round.
+    pub weisfeiler_lehman_colors: Vec<u64>,
+}
+
+impl GraphInvariants {
+    /// Return the first invariant, in the order of [`Invariant`], that
+    /// differs between `self` and `other`, or `None` if they are all equal.
+    pub fn distinguishing(&self, other: &GraphInvariants) -> Option<Invariant> {
+        if self.node_count != other.node_count {
+            Some(Invariant::NodeCount)
+        } else if self.edge_count != other.edge_count {
+            Some(Invariant::EdgeCount)
+        } else if self.degree_sequence != other.degree_sequence {
+            Some(Invariant::DegreeSequence)
+        } else if self.triangle_counts != other.triangle_counts {
+            Some(Invariant::TriangleCounts)
+        } else if self.weisfeiler_lehman_colors != other.weisfeiler_lehman_colors {
+            Some(Invariant::WeisfeilerLehmanColors)
+        } else {
+            None
+        }
+    }
+}
+
+/// The adjacency of a graph with its nodes relabeled to `0..n`, with every
+/// edge counted as often as it appears.
+struct Adjacency {
+    outgoing: Vec<Vec<usize>>,
+    incoming: Vec<Vec<usize>>,
+    edge_count: usize,
+}
+
+impl Adjacency {
+    fn new<G>(graph: G) -> Self
+    where
+        G: IntoEdgeReferences + IntoNodeIdentifiers + NodeIndexable + GraphProp,
+    {
+        let mut compact: Vec<usize> = vec![usize::MAX; graph.node_bound()];
+        let mut node_count = 0;
+        for node in graph.node_identifiers() {
+            compact[graph.to_index(node)] = node_count;
+            node_count += 1;
+        }
+        let mut outgoing: Vec<Vec<usize>> = vec![Vec::new(); node_count];
+        let mut incoming: Vec<Vec<usize>> = vec![Vec::new(); node_count];
+        let mut edge_count = 0;
+        for edge in graph.edge_references() {
+            let source = compact[graph.to_index(edge.source())];
+            let target = compact[graph.to_index(edge.target())];
+            outgoing[source].push(target);
+            incoming[target].push(source);
+            if !graph.is_directed() {
+                outgoing[target].push(source);
+                incoming[source].push(target);
+            }
+            edge_count += 1;
+        }
+        Adjacency {
+            outgoing,
+            incoming,
+            edge_count,
+        }
+    }
+
+    fn node_count(&self) -> usize {
+        self.outgoing.len()
+    }
+
+    fn degree_sequence(&self) -> Vec<(usize, usize)> {
+        let mut degrees: Vec<(usize, usize)> = self
+            .outgoing
+            .iter()
+            .zip(&self.incoming)
+            .map(|(out, inc)| (out.len(), inc.len()))
+            .collect();
+        degrees.sort_unstable();
+        degrees
+    }
+
+    fn triangle_counts(&self) -> Vec<usize> {
+        let neighbors: Vec<HashSet<usize>> = (0..self.node_count())
+            .map(|node| {
+                self.outgoing[node]
+                    .iter()
+                    .chain(&self.incoming[node])
+                    .copied()
+                    .filter(|neighbor| *neighbor != node)
+                    .collect()
+            })
+            .collect();
+        let mut counts: Vec<usize> = vec![0; self.node_count()];
+        for (u, u_neighbors) in neighbors.iter().enumerate() {
+            for &v in u_neighbors.iter().filter(|v| **v > u) {
+                for &w in neighbors[v].iter().filter(|w| **w > v) {
+                    if u_neighbors.contains(&w) {
+                        counts[u] += 1;
+                        counts[v] += 1;
+                        counts[w] += 1;
+                    }
+                }
+            }
+        }
+        counts.sort_unstable();
+        counts
+    }
+
+    fn weisfeiler_lehman_colors(&self, iterations: usize) -> Vec<u64> {
+        let mut colors: Vec<u64> = self
+            .outgoing
+            .iter()
+            .zip(&self.incoming)
+            .map(|(out, inc)| hash_color(&(out.len(), inc.len())))
+            .collect();
+        let mut class_count = distinct_count(&colors);
+        for _ in 0..iterations {
+            let next: Vec<u64> = (0..self.node_count())
+                .map(|node| {
+                    let mut out: Vec<u64> =
+                        self.outgoing[node].iter().map(|n| colors[*n]).collect();
+                    let mut inc: Vec<u64> =
+                        self.incoming[node].iter().map(|n| colors[*n]).collect();
+                    out.sort_unstable();
+                    inc.sort_unstable();
+                    hash_color(&(colors[node], out, inc))
+                })
+                .collect();
+            colors = next;
+            // The partition of the nodes by color only gets finer, so once
+            // a round doesn't split any class neither will the later ones.
+            let next_count = distinct_count(&colors);
+            if next_count == class_count {
+                break;
+            }
+            class_count = next_count;
+        }
+        colors.sort_unstable();
+        colors
+    }
+}
+
+fn hash_color<T: Hash>(value: &T) -> u64 {
+    // DefaultHasher::new() always uses the same keys, so the colors can be
+    // compared between graphs.
+    let mut hasher = DefaultHasher::new();
+    value.hash(&mut hasher);
+    hasher.finish()
+}
+
+fn distinct_count(colors: &[u64]) -> usize {
+    colors.iter().collect::<HashSet<_>>().len()
+}
+
+/// Compute the isomorphism invariants of a graph.
+///
+/// The invariants are, from cheapest to most expensive to compute, the
+/// number of nodes and edges, the degree sequence, the number of triangles
+/// through every node and the colors of the nodes after `wl_iterations`
+/// rounds of Weisfeiler-Lehman color refinement. Edges are counted with
+/// their multiplicity, self-loops add two to the degree of their node in
+/// undirected graphs and the triangles are counted in the underlying simple
+/// undirected graph. The color refinement starts from the degrees of the
+/// nodes, uses the successors and predecessors of every node separately for
+/// directed graphs and stops early once a round doesn't split any color
+/// class.
+///
+/// The colors are hashes, so graphs that aren't isomorphic can have equal
+/// invariants, but isomorphic graphs never have different invariants.
+///
+/// Arguments:
+///
+/// * `graph` - The graph to compute the invariants of.
+/// * `wl_iterations` - The largest number of Weisfeiler-Lehman rounds.
+///
+/// # Example
+/// ```rust
+/// use rustworkx_core::invariants::graph_invariants;
+/// use rustworkx_core::petgraph::graph::UnGraph;
+///
+/// let graph = UnGraph::<(), ()>::from_edges([(0, 1), (1, 2), (2, 0), (2, 3)]);
+/// let invariants = graph_invariants(&graph, 3);
+/// assert_eq!(invariants.degree_sequence, vec![(1, 1), (2, 2), (2, 2), (3, 3)]);
+/// assert_eq!(invariants.triangle_counts, vec![0, 1, 1, 1]);
+/// ```
+pub fn graph_invariants<G>(graph: G, wl_iterations: usize) -> GraphInvariants
+where
+    G: IntoEdgeReferences + IntoNodeIdentifiers + NodeIndexable + GraphProp,
+{
+    let adjacency = Adjacency::new(graph);
+    GraphInvariants {
+        node_count: adjacency.node_count(),
+        edge_count: adjacency.edge_count,
+        degree_sequence: adjacency.degree_sequence(),
+        triangle_counts: adjacency.triangle_counts(),
+        weisfeiler_lehman_colors: adjacency.weisfeiler_lehman_colors(wl_iterations),
+    }
+}
+
+/// Find an isomorphism invariant that differs between two graphs.
+///
+/// This compares the invariants described in [`graph_invariants`] from the
+/// cheapest to the most expensive and stops at the first one that differs,
+/// so it is a fast way to rule out an isomorphism before running a full
+/// isomorphism test such as VF2. The graphs must be both directed or both
+/// undirected.
+///
+/// Arguments:
+///
+/// * `first` - The first graph to compare.
+/// * `second` - The second graph to compare.
+/// * `wl_iterations` - The largest number of Weisfeiler-Lehman rounds.
+///
+/// Returns the first [`Invariant`] that differs, or `None` if the graphs
+/// agree on all of them and may be isomorphic.
+///
+/// # Example
+/// ```rust
+/// use rustworkx_core::invariants::{distinguishing_invariant, Invariant};
+/// use rustworkx_core::petgraph::graph::UnGraph;
+///
+/// // A hexagon and two triangles have the same degree sequence
+/// let hexagon = UnGraph::<(), ()>::from_edges([(0, 1), (1, 2), (2, 3), (3, 4), (4, 5), (5, 0)]);
+/// let triangles = UnGraph::<(), ()>::from_edges([(0, 1), (1, 2), (2, 0), (3, 4), (4, 5), (5, 3)]);
+/// assert_eq!(
+///     distinguishing_invariant(&hexagon, &triangles, 3),
+///     Some(Invariant::TriangleCounts)
+/// );
+/// assert_eq!(distinguishing_invariant(&hexagon, &hexagon, 3), None);
+/// ```
+pub fn distinguishing_invariant<G, H>(
+    first: G,
+    second: H,
+    wl_iterations: usize,
+) -> Option<Invariant>
+where
+    G: IntoEdgeReferences + IntoNodeIdentifiers + NodeIndexable + GraphProp,
+    H: IntoEdgeReferences + IntoNodeIdentifiers + NodeIndexable + GraphProp,
+{
+    let first = Adjacency::new(first);
+    let second = Adjacency::new(second);
+    if first.node_count() != second.node_count() {
+        return Some(Invariant::NodeCount);
+    }
+    if first.edge_count != second.edge_count {
+        return Some(Invariant::EdgeCount);
+    }
+    if first.degree_sequence() != second.degree_sequence() {
+        return Some(Invariant::DegreeSequence);
+    }
+    if first.triangle_counts() != second.triangle_counts() {
+        return Some(Invariant::TriangleCounts);
+    }
+    if first.weisfeiler_lehman_colors(wl_iterations)
+        != second.weisfeiler_lehman_colors(wl_iterations)
+    {
+        return Some(Invariant::WeisfeilerLehmanColors);
+    }
+    None
+}
+
+#[cfg(test)]
+mod test_invariants {
+    use super::{distinguishing_invariant, graph_invariants, Invariant};
+    use petgraph::prelude::*;
+
+    #[test]
+    fn test_relabeled_graphs_agree() {
+        let first = UnGraph::<(), ()>::from_edges([(0, 1), (1, 2), (2, 0), (2, 3), (3, 4)]);
+        let second = UnGraph::<(), ()>::from_edges([(4, 3), (3, 2), (2, 4), (4, 1), (1, 0)]);
+        assert_eq!(distinguishing_invariant(&first, &second, 5), None);
+        assert_eq!(graph_invariants(&first, 5), graph_invariants(&second, 5));
+    }
+
+    #[test]
+    fn test_counts() {
+        let first = UnGraph::<(), ()>::from_edges([(0, 1), (1, 2)]);
+        let mut second = first.clone();
+        second.add_node(());
+        assert_eq!(
+            distinguishing_invariant(&first, &second, 3),
+            Some(Invariant::NodeCount)
+        );
+        let second = UnGraph::<(), ()>::from_edges([(0, 1), (1, 2), (1, 2)]);
+        assert_eq!(
+            distinguishing_invariant(&first, &second, 3),
+            Some(Invariant::EdgeCount)
+        );
+    }
+
+    #[test]
+    fn test_degree_sequence() {
+        let path = UnGraph::<(), ()>::from_edges([(0, 1), (1, 2), (2, 3)]);
+        let star = UnGraph::<(), ()>::from_edges([(0, 1), (0, 2), (0, 3)]);
+        assert_eq!(
+            distinguishing_invariant(&path, &star, 3),
+            Some(Invariant::DegreeSequence)
+        );
+        // Reversing an edge keeps the undirected degrees but not the
+        // directed ones
+        let forward = DiGraph::<(), ()>::from_edges([(0, 1), (1, 2)]);
+        let reversed = DiGraph::<(), ()>::from_edges([(0, 1), (2, 1)]);
+        assert_eq!(
+            distinguishing_invariant(&forward, &reversed, 3),
+            Some(Invariant::DegreeSequence)
+        );
+    }
+
+    #[test]
+    fn test_weisfeiler_lehman() {
+        // A path of six nodes with a pendant node on the second or third
+        // node: same degrees and no triangles.
+        let first = UnGraph::<(), ()>::from_edges([(0, 1), (1, 2), (2, 3), (3, 4), (4, 5), (1, 6)]);
+        let second =
+            UnGraph::<(), ()>::from_edges([(0, 1), (1, 2), (2, 3), (3, 4), (4, 5), (2, 6)]);
+        assert_eq!(distinguishing_invariant(&first, &second, 0), None);
+        assert_eq!(
+            distinguishing_invariant(&first, &second, 3),
+            Some(Invariant::WeisfeilerLehmanColors)
+        );
+        let invariants = graph_invariants(&first, 3);
+        assert_eq!(
+            invariants.distinguishing(&graph_invariants(&second, 3)),
+            Some(Invariant::WeisfeilerLehmanColors)
+        );
+    }
+
+    #[test]
+    fn test_regular_graphs_agree() {
+        // Weisfeiler-Lehman refinement can't tell regular graphs of the
+        // same degree apart once the triangles match.
+        let cycle = UnGraph::<(), ()>::from_edges((0..8).map(|i| (i, (i + 1) % 8)));
+        let cycles = UnGraph::<(), ()>::from_edges(
+            (0..4)
+                .map(|i| (i, (i + 1) % 4))
+                .chain((0..4).map(|i| (i + 4, (i + 1) % 4 + 4))),
+        );
+        assert_eq!(distinguishing_invariant(&cycle, &cycles, 10), None);
+    }
+
+    #[test]
+    fn test_self_loops_and_empty() {
+        let mut first = UnGraph::<(), ()>::new_undirected();
+        let mut second = UnGraph::<(), ()>::new_undirected();
+        assert_eq!(distinguishing_invariant(&first, &second, 3), None);
+        let a = first.add_node(());
+        let b = first.add_node(());
+        first.add_edge(a, a, ());
+        first.add_edge(a, b, ());
+        let c = second.add_node(());
+        let d = second.add_node(());
+        second.add_edge(c, d, ());
+        second.add_edge(c, d, ());
+        assert_eq!(
+            distinguishing_invariant(&first, &second, 3),
+            Some(Invariant::DegreeSequence)
+        );
+    }
+}
@@ -22,7 +22,7 @@
 //!
 //! ```toml
 //! [dependencies]
-//! rustworkx-core = "0.11"
+//! rustworkx-core = "0.16"
 //! ```
 //!
 //! Then in your code, it may be used something like this:
@@ -50,6 +50,7 @@
 //! * [`connectivity`](./connectivity/index.html)
 //! * [`max_weight_matching`](./max_weight_matching/index.html)
 //! * [`shortest_path`](./shortest_path/index.html)
+//! * [`spanning_tree`](./spanning_tree/index.html)
 //! * [`token_swapper`](./token_swapper/index.html)
 //! * [`traversal`](./traversal/index.html)
 //! * [`generators`](./generators/index.html)
@@ -75,6 +76,25 @@
 //! See the documentation of [`graph_ext`] for a full listing of the available
 //! extensions and their compatibility with petgraph types.
 //!
+//! ## Graph Views
+//!
+//! The [`views`] module provides views of a graph, such as
+//! [`FilteredGraph`][views::FilteredGraph], which can be passed to any
+//! algorithm in place of the graph without copying it first. For graphs
+//! that are traversed many times, [`CsrGraph`][csr::CsrGraph] is a compact
+//! copy of a graph in compressed sparse row form that can be passed to any
+//! algorithm the same way.
+//!
+//! ## Features
+//!
+//! The `parallel` feature, which is enabled by default, runs the algorithms
+//! which support it in parallel with [rayon](https://docs.rs/rayon) and
+//! provides the [`parallel`] module to control the number of threads. With
+//! `default-features = false` the crate doesn't depend on rayon and every
+//! algorithm runs on the calling thread, which is useful for targets without
+//! threads such as `wasm32-unknown-unknown`. The crate requires `std`,
+//! because `petgraph` does.
+//!
 //! ## Release Notes
 //!
 //! The release notes for rustworkx-core are included as part of the rustworkx
@@ -92,26 +112,88 @@ use std::convert::Infallible;
 pub type Result<T, E = Infallible> = core::result::Result<T, E>;
 pub mod err;
 
+/// Module for assortativity coefficients.
+pub mod assortativity;
+/// Module for bipartite graph recognition and matching.
+pub mod bipartite;
 pub mod bipartite_coloring;
+/// Module for bipartite projections.
+pub mod bipartite_projection;
+/// Module for node and edge boundaries, cut sizes and volumes.
+pub mod boundary;
+/// Module for canonical labeling and hashing of graphs.
+pub mod canonical;
 /// Module for centrality algorithms.
 pub mod centrality;
+/// Module for clustering coefficients.
+pub mod clustering;
 /// Module for coloring algorithms.
 pub mod coloring;
+pub mod community;
 pub mod connectivity;
 /// Module for algorithms that work on DAGs.
 pub mod dag_algo;
+/// Module for distance measures.
+pub mod distance;
+/// Module for feedback arc and vertex sets.
+pub mod feedback;
 pub mod generators;
+pub mod graph_builder;
 pub mod graph_ext;
+/// Module for graph products.
+pub mod graph_product;
+/// Module for graph isomorphism invariants.
+pub mod invariants;
 pub mod line_graph;
+/// Module for link analysis algorithms.
+pub mod link_analysis;
+pub mod link_prediction;
 /// Module for maximum weight matching algorithms.
 pub mod max_weight_matching;
+/// Module for graph minors.
+pub mod minor;
+/// Module for modular decomposition.
+pub mod modular_decomposition;
+/// Module for network motifs.
+pub mod motifs;
+#[cfg(feature = "parallel")]
+pub mod parallel;
+/// Module for percolation and robustness analysis.
+pub mod percolation;
 pub mod planar;
+pub mod quotient_graph;
+/// Module for the rich-club coefficient.
+pub mod rich_club;
+pub mod rng;
+/// Module for random graph samplers.
+pub mod sampling;
 pub mod shortest_path;
+/// Module for node and graph similarity algorithms.
+pub mod similarity;
+/// Module for small-world coefficients.
+pub mod small_world;
+pub mod spanning_tree;
+/// Module for spectral graph theory.
+pub mod spectral;
+/// Module for structural role feature extraction.
+pub mod structural_roles;
+pub mod temporal;
 pub mod traversal;
+/// Module for tree centers, centroids and rooting.
+pub mod tree;
+/// Module for treewidth heuristics and tree decompositions.
+pub mod treewidth;
+/// Module for lightweight graph views.
+pub mod views;
 // These modules define additional data structures
+pub mod csr;
 pub mod dictmap;
 pub mod distancemap;
+mod flow;
+pub mod hypergraph;
 mod min_scored;
+mod par;
+pub mod property_map;
 /// Module for swapping tokens
 pub mod token_swapper;
 pub mod utils;
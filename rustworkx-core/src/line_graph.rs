@@ -14,7 +14,10 @@ use std::hash::Hash;
 
 use hashbrown::HashMap;
 use petgraph::data::Create;
-use petgraph::visit::{Data, EdgeCount, EdgeRef, IntoEdges, IntoNodeIdentifiers};
+use petgraph::visit::{
+    Data, EdgeCount, EdgeRef, IntoEdgeReferences, IntoEdges, IntoEdgesDirected, IntoNodeIdentifiers,
+};
+use petgraph::Direction::Outgoing;
 
 /// Constructs the line graph of an undirected graph.
 ///
@@ -103,14 +106,88 @@ where
     (output_graph, output_edge_map)
 }
 
+/// Constructs the line graph of a directed graph.
+///
+/// The line graph `L(G)` of a directed graph `G` contains a vertex for every edge in `G`,
+/// and `L(G)` contains an edge from the vertex of an edge `(u, v)` to the vertex of an edge
+/// `(v, w)` for every pair of edges of `G` where the head of the first edge is the tail of
+/// the second. A self-loop of `G` gives a self-loop in `L(G)`.
+///
+/// Arguments:
+///
+/// * `input_graph` - The input graph `G`.
+/// * `default_node_weight` - A callable that will return the weight to use
+///   for newly created nodes.
+/// * `default_edge_weight` - A callable that will return the weight object
+///   to use for newly created edges.
+///
+/// Returns the constructed line graph `L(G)`, and the map from the edges of `G` to
+/// the vertices of `L(G)`.
+///
+/// # Example
+/// ```rust
+/// use rustworkx_core::line_graph::directed_line_graph;
+/// use rustworkx_core::petgraph::visit::EdgeRef;
+/// use rustworkx_core::petgraph::graph::DiGraph;
+/// use hashbrown::HashMap;
+/// use petgraph::graph::{EdgeIndex, NodeIndex};
+///
+/// let input_graph = DiGraph::<(), ()>::from_edges(&[(0, 1), (1, 2), (2, 0), (1, 3)]);
+///
+/// let (output_graph, output_edge_map): (
+///     DiGraph<(), ()>,
+///     HashMap<EdgeIndex, NodeIndex>,
+/// ) = directed_line_graph(&input_graph, || (), || ());
+///
+/// let mut output_edge_list = output_graph
+///     .edge_references()
+///     .map(|edge| (edge.source().index(), edge.target().index()))
+///     .collect::<Vec<(usize, usize)>>();
+/// output_edge_list.sort();
+///
+/// assert_eq!(output_edge_list, vec![(0, 1), (0, 3), (1, 2), (2, 0)]);
+/// assert_eq!(output_edge_map[&EdgeIndex::new(3)], NodeIndex::new(3));
+/// ```
+pub fn directed_line_graph<K, G, T, F, H, M>(
+    input_graph: K,
+    mut default_node_weight: F,
+    mut default_edge_weight: H,
+) -> (G, HashMap<K::EdgeId, G::NodeId>)
+where
+    K: EdgeCount + IntoEdgeReferences + IntoEdgesDirected,
+    G: Create + Data<NodeWeight = T, EdgeWeight = M>,
+    F: FnMut() -> T,
+    H: FnMut() -> M,
+    K::EdgeId: Hash + Eq,
+{
+    let num_edges = input_graph.edge_count();
+    let mut output_graph = G::with_capacity(num_edges, 0);
+    let mut output_edge_map =
+        HashMap::<K::EdgeId, G::NodeId>::with_capacity(input_graph.edge_count());
+
+    for edge in input_graph.edge_references() {
+        let new_node = output_graph.add_node(default_node_weight());
+        output_edge_map.insert(edge.id(), new_node);
+    }
+
+    for edge in input_graph.edge_references() {
+        let node0 = output_edge_map[&edge.id()];
+        for next_edge in input_graph.edges_directed(edge.target(), Outgoing) {
+            let node1 = output_edge_map[&next_edge.id()];
+            output_graph.add_edge(node0, node1, default_edge_weight());
+        }
+    }
+    (output_graph, output_edge_map)
+}
+
 #[cfg(test)]
 
 mod test_line_graph {
-    use crate::line_graph::line_graph;
+    use crate::line_graph::{directed_line_graph, line_graph};
     use crate::petgraph::visit::EdgeRef;
     use crate::petgraph::Graph;
     use hashbrown::HashMap;
-    use petgraph::graph::{EdgeIndex, NodeIndex};
+    use petgraph::graph::{DiGraph, EdgeIndex, NodeIndex};
     use petgraph::Undirected;
 
     #[test]
@@ -141,4 +218,38 @@ mod test_line_graph {
         assert_eq!(output_edge_list, expected_edge_list);
         assert_eq!(output_edge_map, expected_edge_map);
     }
+
+    #[test]
+    fn test_directed_graph() {
+        let input_graph = DiGraph::<(), ()>::from_edges([(0, 1), (1, 2), (2, 1), (2, 3)]);
+
+        let (output_graph, output_edge_map): (DiGraph<(), ()>, HashMap<EdgeIndex, NodeIndex>) =
+            directed_line_graph(&input_graph, || (), || ());
+
+        let mut output_edge_list = output_graph
+            .edge_references()
+            .map(|edge| (edge.source().index(), edge.target().index()))
+            .collect::<Vec<(usize, usize)>>();
+        output_edge_list.sort();
+
+        // Only edges that follow each other head-to-tail are adjacent
+        assert_eq!(output_edge_list, vec![(0, 1), (1, 2), (1, 3), (2, 1)]);
+        assert_eq!(output_edge_map.len(), 4);
+    }
+
+    #[test]
+    fn test_directed_self_loop() {
+        let input_graph = DiGraph::<(), ()>::from_edges([(0, 0), (0, 1)]);
+
+        let (output_graph, _): (DiGraph<(), ()>, HashMap<EdgeIndex, NodeIndex>) =
+            directed_line_graph(&input_graph, || (), || ());
+
+        let mut output_edge_list = output_graph
+            .edge_references()
+            .map(|edge| (edge.source().index(), edge.target().index()))
+            .collect::<Vec<(usize, usize)>>();
+        output_edge_list.sort();
+
+        assert_eq!(output_edge_list, vec![(0, 0), (0, 1)]);
+    }
 }
@@ -0,0 +1,244 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+use hashbrown::HashMap;
+use petgraph::visit::{EdgeRef, IntoEdges, IntoNodeIdentifiers, NodeIndexable};
+
+/// Compute the random walk with restart proximity of every node to a set of
+/// seed nodes.
+///
+/// A random walker starts at one of the `seeds`, and at every step either
+/// moves along one of the edges out of its current node, chosen with a
+/// probability proportional to the edge weights, or with probability
+/// `restart_prob` jumps back to one of the `seeds`, chosen uniformly at
+/// random. A walker at a node without any edges out of it always jumps back
+/// to the seeds. The proximity of a node is the probability of finding the
+/// walker there in the stationary distribution of this process, which is the
+/// same as the personalized PageRank of the nodes with the seeds as the
+/// personalization set. See [1] for details.
+///
+/// The stationary distribution is computed with power iteration on the
+/// sparse transition matrix. The iteration stops when the L1 norm of the
+/// difference of the distributions of two consecutive iterations is less than
+/// `tol`. For undirected graphs the walker can move along every edge of a
+/// node, and parallel edges have their weights summed.
+///
+/// Arguments:
+///
+/// * `graph` - The graph to run the random walks on.
+/// * `seeds` - The nodes the walker restarts from. A node that occurs
+///   multiple times is restarted from proportionally more often.
+/// * `weight_fn` - A callable that will be passed an edge reference for each
+///   edge in the graph and is expected to return a `Result<f64>` which if it
+///   doesn't error represents the non-negative weight of that edge.
+/// * `restart_prob` - The probability of jumping back to the seeds at every
+///   step, which should be greater than `0` and at most `1`.
+/// * `max_iter` - The maximum number of iterations in the power method.
+/// * `tol` - The error tolerance used when checking for convergence.
+///
+/// Returns `None` if the iteration didn't converge within `max_iter`
+/// iterations, otherwise a vector of the proximities indexed by node index.
+/// The proximities sum to `1`, and are `0` for indices that aren't in the
+/// graph. If `seeds` is empty every proximity is `0`.
+///
+/// # Example
+/// ```rust
+/// use std::convert::Infallible;
+/// use rustworkx_core::petgraph;
+/// use rustworkx_core::link_analysis::random_walk_with_restart;
+///
+/// let graph = petgraph::graph::UnGraph::<(), ()>::from_edges(&[(0, 1), (1, 2), (2, 3)]);
+/// let res: Result<_, Infallible> =
+///     random_walk_with_restart(&graph, &[0.into()], |_| Ok(1.), 0.2, 1000, 1e-10);
+/// let proximity = res.unwrap().unwrap();
+/// assert!((proximity.iter().sum::<f64>() - 1.).abs() < 1e-9);
+/// assert!(proximity[1] > proximity[2]);
+/// assert!(proximity[2] > proximity[3]);
+/// ```
+///
+/// [1]: Tong, Hanghang, Christos Faloutsos, and Jia-Yu Pan. "Fast random walk
+///     with restart and its applications." Sixth International Conference on
+///     Data Mining (2006): 613-622. <https://doi.org/10.1109/ICDM.2006.70>
+pub fn random_walk_with_restart<G, F, E>(
+    graph: G,
+    seeds: &[G::NodeId],
+    mut weight_fn: F,
+    restart_prob: f64,
+    max_iter: usize,
+    tol: f64,
+) -> Result<Option<Vec<f64>>, E>
+where
+    G: IntoEdges + IntoNodeIdentifiers + NodeIndexable,
+    F: FnMut(G::EdgeRef) -> Result<f64, E>,
+{
+    let node_bound = graph.node_bound();
+    if seeds.is_empty() {
+        return Ok(Some(vec![0.; node_bound]));
+    }
+    let mut restart: Vec<f64> = vec![0.; node_bound];
+    let seed_prob = (seeds.len() as f64).recip();
+    for seed in seeds {
+        restart[graph.to_index(*seed)] += seed_prob;
+    }
+    // The sparse transition matrix, as the probabilities of moving from each
+    // node to its neighbors
+    let mut transitions: Vec<Vec<(usize, f64)>> = vec![Vec::new(); node_bound];
+    for node in graph.node_identifiers() {
+        let index = graph.to_index(node);
+        let mut weights: HashMap<usize, f64> = HashMap::new();
+        for edge in graph.edges(node) {
+            let weight = weight_fn(edge)?;
+            let other = if edge.source() == node {
+                edge.target()
+            } else {
+                edge.source()
+            };
+            *weights.entry(graph.to_index(other)).or_insert(0.) += weight;
+        }
+        let total: f64 = weights.values().sum();
+        if total > 0. {
+            transitions[index] = weights
+                .into_iter()
+                .filter(|(_, weight)| *weight > 0.)
+                .map(|(other, weight)| (other, weight / total))
+                .collect();
+        }
+    }
+
+    let mut proximity = restart.clone();
+    for _ in 0..max_iter {
+        let mut next: Vec<f64> = restart.iter().map(|p| restart_prob * p).collect();
+        let mut dangling: f64 = 0.;
+        for (node, probs) in transitions.iter().enumerate() {
+            if proximity[node] == 0. {
+                continue;
+            }
+            if probs.is_empty() {
+                dangling += proximity[node];
+                continue;
+            }
+            let walk = (1. - restart_prob) * proximity[node];
+            for (other, prob) in probs {
+                next[*other] += walk * prob;
+            }
+        }
+        if dangling > 0. {
+            let restarts = (1. - restart_prob) * dangling;
+            for (p, r) in next.iter_mut().zip(&restart) {
+                *p += restarts * r;
+            }
+        }
+        let diff: f64 = next
+            .iter()
+            .zip(&proximity)
+            .map(|(new, old)| (new - old).abs())
+            .sum();
+        proximity = next;
+        if diff < tol {
+            return Ok(Some(proximity));
+        }
+    }
+    Ok(None)
+}
+
+#[cfg(test)]
+mod test_random_walk_with_restart {
+    use super::random_walk_with_restart;
+    use petgraph::graph::{DiGraph, UnGraph};
+    use petgraph::stable_graph::StableUnGraph;
+    use std::convert::Infallible;
+
+    #[test]
+    fn test_only_restarts() {
+        let graph = UnGraph::<(), ()>::from_edges([(0, 1), (1, 2)]);
+        let res: Result<_, Infallible> =
+            random_walk_with_restart(&graph, &[0.into(), 2.into()], |_| Ok(1.), 1., 10, 1e-12);
+        assert_eq!(res.unwrap().unwrap(), vec![0.5, 0., 0.5]);
+    }
+
+    #[test]
+    fn test_two_nodes() {
+        // The walker alternates between the nodes, so p0 = c + (1 - c) p1
+        // and p1 = (1 - c) p0
+        let graph = UnGraph::<(), ()>::from_edges([(0, 1)]);
+        let c = 0.3;
+        let res: Result<_, Infallible> =
+            random_walk_with_restart(&graph, &[0.into()], |_| Ok(1.), c, 1000, 1e-14);
+        let proximity = res.unwrap().unwrap();
+        let expected_0 = 1. / (2. - c);
+        assert!((proximity[0] - expected_0).abs() < 1e-12);
+        assert!((proximity[1] - (1. - c) * expected_0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_directed_dangling() {
+        // Node 2 has no successors so the walker always restarts from it
+        let graph = DiGraph::<(), ()>::from_edges([(0, 1), (1, 2), (3, 0)]);
+        let c = 0.5;
+        let res: Result<_, Infallible> =
+            random_walk_with_restart(&graph, &[0.into()], |_| Ok(1.), c, 1000, 1e-14);
+        let proximity = res.unwrap().unwrap();
+        assert!((proximity.iter().sum::<f64>() - 1.).abs() < 1e-12);
+        assert!((proximity[1] - 0.5 * proximity[0]).abs() < 1e-12);
+        assert!((proximity[2] - 0.5 * proximity[1]).abs() < 1e-12);
+        assert_eq!(proximity[3], 0.);
+    }
+
+    #[test]
+    fn test_weights() {
+        let graph =
+            DiGraph::<(), f64>::from_edges([(0, 1, 3.), (0, 2, 1.), (1, 0, 1.), (2, 0, 1.)]);
+        let res: Result<_, Infallible> =
+            random_walk_with_restart(&graph, &[0.into()], |e| Ok(*e.weight()), 0.2, 1000, 1e-14);
+        let proximity = res.unwrap().unwrap();
+        assert!((proximity[1] - 3. * proximity[2]).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_removed_nodes() {
+        let mut graph = StableUnGraph::<(), ()>::default();
+        let a = graph.add_node(());
+        let b = graph.add_node(());
+        let c = graph.add_node(());
+        graph.add_edge(a, c, ());
+        graph.remove_node(b);
+        let res: Result<_, Infallible> =
+            random_walk_with_restart(&graph, &[a], |_| Ok(1.), 0.5, 1000, 1e-14);
+        let proximity = res.unwrap().unwrap();
+        assert_eq!(proximity.len(), 3);
+        assert_eq!(proximity[1], 0.);
+        assert!((proximity[0] + proximity[2] - 1.).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_no_seeds() {
+        let graph = UnGraph::<(), ()>::from_edges([(0, 1)]);
+        let res: Result<_, Infallible> =
+            random_walk_with_restart(&graph, &[], |_| Ok(1.), 0.5, 100, 1e-6);
+        assert_eq!(res.unwrap().unwrap(), vec![0., 0.]);
+    }
+
+    #[test]
+    fn test_no_convergence() {
+        let graph = UnGraph::<(), ()>::from_edges([(0, 1), (1, 2), (2, 3)]);
+        let res: Result<_, Infallible> =
+            random_walk_with_restart(&graph, &[0.into()], |_| Ok(1.), 0.01, 2, 1e-12);
+        assert_eq!(res.unwrap(), None);
+    }
+
+    #[test]
+    fn test_weight_fn_error() {
+        let graph = UnGraph::<(), ()>::from_edges([(0, 1)]);
+        let res = random_walk_with_restart(&graph, &[0.into()], |_| Err("error"), 0.5, 100, 1e-6);
+        assert_eq!(res, Err("error"));
+    }
+}
@@ -0,0 +1,662 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+//! Link prediction scores for pairs of nodes in undirected graphs.
+//!
+//! Every function in this module takes an explicit list of node pairs (an
+//! "ebunch") and returns one score per pair, in the same order. The
+//! neighborhoods of the nodes are computed once, and the pairs are then
+//! scored in parallel if there are at least `parallel_threshold` of them. If
+//! the function will be running in parallel the env var `RAYON_NUM_THREADS`
+//! can be used to adjust how many threads will be used.
+//!
+//! Parallel edges are ignored, so the degree of a node is its number of
+//! distinct neighbors. A node with a self loop is its own neighbor. All the
+//! nodes in the ebunch must be in the graph.
+
+use std::collections::VecDeque;
+
+use crate::par::CondIterator;
+use petgraph::visit::{GraphProp, IntoNeighbors, IntoNodeIdentifiers, NodeCount, NodeIndexable};
+use petgraph::Undirected;
+
+/// The sorted and deduplicated neighbors of every node, indexed by
+/// [`NodeIndexable::to_index`].
+fn neighborhoods<G>(graph: G) -> Vec<Vec<usize>>
+where
+    G: IntoNeighbors + IntoNodeIdentifiers + NodeIndexable,
+{
+    let mut neighbors: Vec<Vec<usize>> = vec![Vec::new(); graph.node_bound()];
+    for node in graph.node_identifiers() {
+        let mut adjacent: Vec<usize> = graph
+            .neighbors(node)
+            .map(|neighbor| graph.to_index(neighbor))
+            .collect();
+        adjacent.sort_unstable();
+        adjacent.dedup();
+        neighbors[graph.to_index(node)] = adjacent;
+    }
+    neighbors
+}
+
+/// Iterate over the common elements of two sorted slices.
+fn common_neighbors<'a>(a: &'a [usize], b: &'a [usize]) -> impl Iterator<Item = usize> + 'a {
+    let mut j = 0;
+    a.iter().copied().filter(move |node| {
+        while j < b.len() && b[j] < *node {
+            j += 1;
+        }
+        j < b.len() && b[j] == *node
+    })
+}
+
+/// Score every pair in `ebunch`, in parallel if there are at least
+/// `parallel_threshold` pairs.
+fn score_pairs<G, S>(
+    graph: G,
+    ebunch: &[(G::NodeId, G::NodeId)],
+    parallel_threshold: usize,
+    score: S,
+) -> Vec<f64>
+where
+    G: NodeIndexable,
+    S: Fn(usize, usize) -> f64 + Sync + Send,
+{
+    let pairs: Vec<(usize, usize)> = ebunch
+        .iter()
+        .map(|(u, v)| (graph.to_index(*u), graph.to_index(*v)))
+        .collect();
+    CondIterator::new(pairs, ebunch.len() >= parallel_threshold)
+        .map(|(u, v)| score(u, v))
+        .collect()
+}
+
+/// Compute the Jaccard coefficient of pairs of nodes
+///
+/// The Jaccard coefficient of the nodes `u` and `v` is
+/// `|N(u) ∩ N(v)| / |N(u) ∪ N(v)|`, where `N(u)` is the set of neighbors of
+/// `u`. It is `0` if neither node has any neighbors.
+///
+/// Arguments:
+///
+/// * `graph` - The undirected graph the nodes are in.
+/// * `ebunch` - The pairs of nodes to score.
+/// * `parallel_threshold` - The number of pairs to score in parallel at, if
+///   `ebunch` has fewer pairs than this they are scored in a single thread.
+///
+/// Returns the score of every pair in `ebunch`, in the same order.
+///
+/// # Example
+/// ```rust
+/// use rustworkx_core::petgraph;
+/// use rustworkx_core::link_prediction::jaccard_coefficient;
+///
+/// let graph = petgraph::graph::UnGraph::<(), ()>::from_edges(&[(0, 1), (0, 2), (1, 3)]);
+/// let scores = jaccard_coefficient(&graph, &[(0.into(), 3.into())], 100);
+/// assert_eq!(scores, vec![0.5]);
+/// ```
+pub fn jaccard_coefficient<G>(
+    graph: G,
+    ebunch: &[(G::NodeId, G::NodeId)],
+    parallel_threshold: usize,
+) -> Vec<f64>
+where
+    G: GraphProp<EdgeType = Undirected> + IntoNeighbors + IntoNodeIdentifiers + NodeIndexable,
+{
+    let neighbors = neighborhoods(graph);
+    score_pairs(graph, ebunch, parallel_threshold, |u, v| {
+        let common = common_neighbors(&neighbors[u], &neighbors[v]).count();
+        let union = neighbors[u].len() + neighbors[v].len() - common;
+        if union == 0 {
+            0.
+        } else {
+            common as f64 / union as f64
+        }
+    })
+}
+
+/// Compute the Adamic-Adar index of pairs of nodes
+///
+/// The Adamic-Adar index of the nodes `u` and `v` is the sum of
+/// `1 / ln(|N(w)|)` over the common neighbors `w` of `u` and `v`, where
+/// `N(w)` is the set of neighbors of `w`. A common neighbor with a single
+/// neighbor (which is only possible with self loops) contributes infinity.
+///
+/// Arguments:
+///
+/// * `graph` - The undirected graph the nodes are in.
+/// * `ebunch` - The pairs of nodes to score.
+/// * `parallel_threshold` - The number of pairs to score in parallel at, if
+///   `ebunch` has fewer pairs than this they are scored in a single thread.
+///
+/// Returns the score of every pair in `ebunch`, in the same order.
+///
+/// # Example
+/// ```rust
+/// use rustworkx_core::petgraph;
+/// use rustworkx_core::link_prediction::adamic_adar_index;
+///
+/// let graph = petgraph::graph::UnGraph::<(), ()>::from_edges(&[(0, 1), (1, 2)]);
+/// let scores = adamic_adar_index(&graph, &[(0.into(), 2.into())], 100);
+/// assert_eq!(scores, vec![1. / 2f64.ln()]);
+/// ```
+pub fn adamic_adar_index<G>(
+    graph: G,
+    ebunch: &[(G::NodeId, G::NodeId)],
+    parallel_threshold: usize,
+) -> Vec<f64>
+where
+    G: GraphProp<EdgeType = Undirected> + IntoNeighbors + IntoNodeIdentifiers + NodeIndexable,
+{
+    let neighbors = neighborhoods(graph);
+    score_pairs(graph, ebunch, parallel_threshold, |u, v| {
+        common_neighbors(&neighbors[u], &neighbors[v])
+            .map(|w| 1. / (neighbors[w].len() as f64).ln())
+            .sum()
+    })
+}
+
+/// Compute the resource allocation index of pairs of nodes
+///
+/// The resource allocation index of the nodes `u` and `v` is the sum of
+/// `1 / |N(w)|` over the common neighbors `w` of `u` and `v`, where `N(w)` is
+/// the set of neighbors of `w`.
+///
+/// Arguments:
+///
+/// * `graph` - The undirected graph the nodes are in.
+/// * `ebunch` - The pairs of nodes to score.
+/// * `parallel_threshold` - The number of pairs to score in parallel at, if
+///   `ebunch` has fewer pairs than this they are scored in a single thread.
+///
+/// Returns the score of every pair in `ebunch`, in the same order.
+///
+/// # Example
+/// ```rust
+/// use rustworkx_core::petgraph;
+/// use rustworkx_core::link_prediction::resource_allocation_index;
+///
+/// let graph = petgraph::graph::UnGraph::<(), ()>::from_edges(&[(0, 1), (1, 2), (0, 3), (3, 2), (3, 4)]);
+/// let scores = resource_allocation_index(&graph, &[(0.into(), 2.into())], 100);
+/// assert_eq!(scores, vec![1. / 2. + 1. / 3.]);
+/// ```
+pub fn resource_allocation_index<G>(
+    graph: G,
+    ebunch: &[(G::NodeId, G::NodeId)],
+    parallel_threshold: usize,
+) -> Vec<f64>
+where
+    G: GraphProp<EdgeType = Undirected> + IntoNeighbors + IntoNodeIdentifiers + NodeIndexable,
+{
+    let neighbors = neighborhoods(graph);
+    score_pairs(graph, ebunch, parallel_threshold, |u, v| {
+        common_neighbors(&neighbors[u], &neighbors[v])
+            .map(|w| 1. / neighbors[w].len() as f64)
+            .sum()
+    })
+}
+
+/// Compute the preferential attachment score of pairs of nodes
+///
+/// The preferential attachment score of the nodes `u` and `v` is
+/// `|N(u)| |N(v)|`, where `N(u)` is the set of neighbors of `u`.
+///
+/// Arguments:
+///
+/// * `graph` - The undirected graph the nodes are in.
+/// * `ebunch` - The pairs of nodes to score.
+/// * `parallel_threshold` - The number of pairs to score in parallel at, if
+///   `ebunch` has fewer pairs than this they are scored in a single thread.
+///
+/// Returns the score of every pair in `ebunch`, in the same order.
+///
+/// # Example
+/// ```rust
+/// use rustworkx_core::petgraph;
+/// use rustworkx_core::link_prediction::preferential_attachment;
+///
+/// let graph = petgraph::graph::UnGraph::<(), ()>::from_edges(&[(0, 1), (0, 2), (3, 4)]);
+/// let scores = preferential_attachment(&graph, &[(0.into(), 3.into())], 100);
+/// assert_eq!(scores, vec![2.]);
+/// ```
+pub fn preferential_attachment<G>(
+    graph: G,
+    ebunch: &[(G::NodeId, G::NodeId)],
+    parallel_threshold: usize,
+) -> Vec<f64>
+where
+    G: GraphProp<EdgeType = Undirected> + IntoNeighbors + IntoNodeIdentifiers + NodeIndexable,
+{
+    let neighbors = neighborhoods(graph);
+    score_pairs(graph, ebunch, parallel_threshold, |u, v| {
+        (neighbors[u].len() * neighbors[v].len()) as f64
+    })
+}
+
+/// The length of the shortest path between `source` and `target`.
+fn bfs_distance(neighbors: &[Vec<usize>], source: usize, target: usize) -> Option<usize> {
+    if source == target {
+        return Some(0);
+    }
+    let mut distance: Vec<usize> = vec![usize::MAX; neighbors.len()];
+    let mut queue: VecDeque<usize> = VecDeque::new();
+    distance[source] = 0;
+    queue.push_back(source);
+    while let Some(node) = queue.pop_front() {
+        for neighbor in &neighbors[node] {
+            if distance[*neighbor] == usize::MAX {
+                distance[*neighbor] = distance[node] + 1;
+                if *neighbor == target {
+                    return Some(distance[*neighbor]);
+                }
+                queue.push_back(*neighbor);
+            }
+        }
+    }
+    None
+}
+
+/// Compute the common neighbor and centrality based parameterized algorithm
+/// (CCPA) score of pairs of nodes
+///
+/// The CCPA score [1] of the nodes `u` and `v` is
+/// `alpha |N(u) ∩ N(v)| + (1 - alpha) |V| / d(u, v)`, where `N(u)` is the set
+/// of neighbors of `u` and `d(u, v)` is the length of the shortest path
+/// between `u` and `v`. The second term is `0` if there is no path between
+/// `u` and `v`, and infinite if `u` and `v` are the same node.
+///
+/// A breadth first search is run for every pair of nodes, so this takes
+/// `O(|V| + |E|)` time per pair.
+///
+/// Arguments:
+///
+/// * `graph` - The undirected graph the nodes are in.
+/// * `ebunch` - The pairs of nodes to score.
+/// * `alpha` - The weight of the number of common neighbors, between `0` and
+///   `1`.
+/// * `parallel_threshold` - The number of pairs to score in parallel at, if
+///   `ebunch` has fewer pairs than this they are scored in a single thread.
+///
+/// Returns the score of every pair in `ebunch`, in the same order.
+///
+/// # Example
+/// ```rust
+/// use rustworkx_core::petgraph;
+/// use rustworkx_core::link_prediction::common_neighbor_centrality;
+///
+/// let graph = petgraph::graph::UnGraph::<(), ()>::from_edges(&[(0, 1), (1, 2), (2, 3)]);
+/// let scores = common_neighbor_centrality(&graph, &[(0.into(), 2.into())], 0.5, 100);
+/// assert_eq!(scores, vec![0.5 * 1. + 0.5 * 4. / 2.]);
+/// ```
+///
+/// [1]: Ahmad, Iftikhar, et al. "Missing Link Prediction using Common
+///     Neighbor and Centrality based Parameterized Algorithm." Scientific
+///     Reports 10.1 (2020): 364. <https://doi.org/10.1038/s41598-019-57304-y>
+pub fn common_neighbor_centrality<G>(
+    graph: G,
+    ebunch: &[(G::NodeId, G::NodeId)],
+    alpha: f64,
+    parallel_threshold: usize,
+) -> Vec<f64>
+where
+    G: GraphProp<EdgeType = Undirected>
+        + IntoNeighbors
+        + IntoNodeIdentifiers
+        + NodeIndexable
+        + NodeCount,
+{
+    let neighbors = neighborhoods(graph);
+    let node_count = graph.node_count() as f64;
+    score_pairs(graph, ebunch, parallel_threshold, |u, v| {
+        let common = common_neighbors(&neighbors[u], &neighbors[v]).count() as f64;
+        if alpha == 1. {
+            return common;
+        }
+        let centrality = match bfs_distance(&neighbors, u, v) {
+            Some(distance) => node_count / distance as f64,
+            None => 0.,
+        };
+        alpha * common + (1. - alpha) * centrality
+    })
+}
+
+/// Evaluate `community` for every node, indexed by [`NodeIndexable::to_index`].
+fn node_communities<G, F, E>(graph: G, mut community: F) -> Result<Vec<Option<usize>>, E>
+where
+    G: IntoNodeIdentifiers + NodeIndexable,
+    F: FnMut(G::NodeId) -> Result<usize, E>,
+{
+    let mut communities: Vec<Option<usize>> = vec![None; graph.node_bound()];
+    for node in graph.node_identifiers() {
+        communities[graph.to_index(node)] = Some(community(node)?);
+    }
+    Ok(communities)
+}
+
+/// Compute the community aware common neighbor score of pairs of nodes
+///
+/// This is the number of common neighbors of the nodes `u` and `v`, where
+/// common neighbors in the same community as `u` and `v` count twice, as
+/// proposed by Soundarajan and Hopcroft [1]. If `u` and `v` are in different
+/// communities this is just the number of common neighbors.
+///
+/// Arguments:
+///
+/// * `graph` - The undirected graph the nodes are in.
+/// * `ebunch` - The pairs of nodes to score.
+/// * `community` - A callable that will be passed every node in the graph and
+///   is expected to return a `Result<usize>` which if it doesn't error is the
+///   community the node belongs to.
+/// * `parallel_threshold` - The number of pairs to score in parallel at, if
+///   `ebunch` has fewer pairs than this they are scored in a single thread.
+///
+/// Returns the score of every pair in `ebunch`, in the same order.
+///
+/// # Example
+/// ```rust
+/// use std::convert::Infallible;
+/// use rustworkx_core::petgraph;
+/// use rustworkx_core::link_prediction::cn_soundarajan_hopcroft;
+///
+/// let graph = petgraph::graph::UnGraph::<(), ()>::from_edges(&[(0, 1), (1, 2), (0, 3), (3, 2)]);
+/// let community = [0, 0, 0, 1];
+/// let res: Result<_, Infallible> = cn_soundarajan_hopcroft(
+///     &graph, &[(0.into(), 2.into())], |n| Ok(community[n.index()]), 100
+/// );
+/// assert_eq!(res.unwrap(), vec![3.]);
+/// ```
+///
+/// [1]: Soundarajan, Sucheta and Hopcroft, John. "Using community information
+///     to improve the precision of link prediction methods." Proceedings of
+///     the 21st International Conference on World Wide Web (2012): 607-608.
+///     <https://doi.org/10.1145/2187980.2188150>
+pub fn cn_soundarajan_hopcroft<G, F, E>(
+    graph: G,
+    ebunch: &[(G::NodeId, G::NodeId)],
+    community: F,
+    parallel_threshold: usize,
+) -> Result<Vec<f64>, E>
+where
+    G: GraphProp<EdgeType = Undirected> + IntoNeighbors + IntoNodeIdentifiers + NodeIndexable,
+    F: FnMut(G::NodeId) -> Result<usize, E>,
+{
+    let neighbors = neighborhoods(graph);
+    let communities = node_communities(graph, community)?;
+    Ok(score_pairs(graph, ebunch, parallel_threshold, |u, v| {
+        common_neighbors(&neighbors[u], &neighbors[v])
+            .map(|w| {
+                if communities[u] == communities[v] && communities[w] == communities[u] {
+                    2.
+                } else {
+                    1.
+                }
+            })
+            .sum()
+    }))
+}
+
+/// Compute the community aware resource allocation index of pairs of nodes
+///
+/// This is the resource allocation index of the nodes `u` and `v` (see
+/// [`resource_allocation_index`]) where only the common neighbors in the same
+/// community as `u` and `v` are counted, as proposed by Soundarajan and
+/// Hopcroft [1]. It is `0` if `u` and `v` are in different communities.
+///
+/// Arguments:
+///
+/// * `graph` - The undirected graph the nodes are in.
+/// * `ebunch` - The pairs of nodes to score.
+/// * `community` - A callable that will be passed every node in the graph and
+///   is expected to return a `Result<usize>` which if it doesn't error is the
+///   community the node belongs to.
+/// * `parallel_threshold` - The number of pairs to score in parallel at, if
+///   `ebunch` has fewer pairs than this they are scored in a single thread.
+///
+/// Returns the score of every pair in `ebunch`, in the same order.
+///
+/// # Example
+/// ```rust
+/// use std::convert::Infallible;
+/// use rustworkx_core::petgraph;
+/// use rustworkx_core::link_prediction::ra_index_soundarajan_hopcroft;
+///
+/// let graph = petgraph::graph::UnGraph::<(), ()>::from_edges(&[(0, 1), (1, 2), (0, 3), (3, 2)]);
+/// let community = [0, 0, 0, 1];
+/// let res: Result<_, Infallible> = ra_index_soundarajan_hopcroft(
+///     &graph, &[(0.into(), 2.into())], |n| Ok(community[n.index()]), 100
+/// );
+/// assert_eq!(res.unwrap(), vec![0.5]);
+/// ```
+///
+/// [1]: Soundarajan, Sucheta and Hopcroft, John. "Using community information
+///     to improve the precision of link prediction methods." Proceedings of
+///     the 21st International Conference on World Wide Web (2012): 607-608.
+///     <https://doi.org/10.1145/2187980.2188150>
+pub fn ra_index_soundarajan_hopcroft<G, F, E>(
+    graph: G,
+    ebunch: &[(G::NodeId, G::NodeId)],
+    community: F,
+    parallel_threshold: usize,
+) -> Result<Vec<f64>, E>
+where
+    G: GraphProp<EdgeType = Undirected> + IntoNeighbors + IntoNodeIdentifiers + NodeIndexable,
+    F: FnMut(G::NodeId) -> Result<usize, E>,
+{
+    let neighbors = neighborhoods(graph);
+    let communities = node_communities(graph, community)?;
+    Ok(score_pairs(graph, ebunch, parallel_threshold, |u, v| {
+        if communities[u] != communities[v] {
+            return 0.;
+        }
+        common_neighbors(&neighbors[u], &neighbors[v])
+            .filter(|w| communities[*w] == communities[u])
+            .map(|w| 1. / neighbors[w].len() as f64)
+            .sum()
+    }))
+}
+
+/// Compute the ratio of within- and inter-cluster common neighbors of pairs
+/// of nodes
+///
+/// If the nodes `u` and `v` are in the same community, this is
+/// `|W| / (|I| + delta)` where `W` are the common neighbors of `u` and `v` in
+/// the same community as them and `I` are their other common neighbors, as
+/// proposed by Rebaza et al. [1]. It is `0` if `u` and `v` are in different
+/// communities.
+///
+/// Arguments:
+///
+/// * `graph` - The undirected graph the nodes are in.
+/// * `ebunch` - The pairs of nodes to score.
+/// * `community` - A callable that will be passed every node in the graph and
+///   is expected to return a `Result<usize>` which if it doesn't error is the
+///   community the node belongs to.
+/// * `delta` - A positive value added to the number of inter-cluster common
+///   neighbors to avoid dividing by zero.
+/// * `parallel_threshold` - The number of pairs to score in parallel at, if
+///   `ebunch` has fewer pairs than this they are scored in a single thread.
+///
+/// Returns the score of every pair in `ebunch`, in the same order.
+///
+/// # Example
+/// ```rust
+/// use std::convert::Infallible;
+/// use rustworkx_core::petgraph;
+/// use rustworkx_core::link_prediction::within_inter_cluster;
+///
+/// let graph = petgraph::graph::UnGraph::<(), ()>::from_edges(&[(0, 1), (1, 2), (0, 3), (3, 2)]);
+/// let community = [0, 0, 0, 1];
+/// let res: Result<_, Infallible> = within_inter_cluster(
+///     &graph, &[(0.into(), 2.into())], |n| Ok(community[n.index()]), 0.001, 100
+/// );
+/// assert_eq!(res.unwrap(), vec![1. / 1.001]);
+/// ```
+///
+/// [1]: Rebaza, Jorge Carlos Valverde and Zhao, Liang. "Link prediction in
+///     complex networks based on cluster information." Advances in
+///     Artificial Intelligence - SBIA 2012: 92-101.
+///     <https://doi.org/10.1007/978-3-642-34459-6_10>
+pub fn within_inter_cluster<G, F, E>(
+    graph: G,
+    ebunch: &[(G::NodeId, G::NodeId)],
+    community: F,
+    delta: f64,
+    parallel_threshold: usize,
+) -> Result<Vec<f64>, E>
+where
+    G: GraphProp<EdgeType = Undirected> + IntoNeighbors + IntoNodeIdentifiers + NodeIndexable,
+    F: FnMut(G::NodeId) -> Result<usize, E>,
+{
+    let neighbors = neighborhoods(graph);
+    let communities = node_communities(graph, community)?;
+    Ok(score_pairs(graph, ebunch, parallel_threshold, |u, v| {
+        if communities[u] != communities[v] {
+            return 0.;
+        }
+        let mut within = 0;
+        let mut inter = 0;
+        for w in common_neighbors(&neighbors[u], &neighbors[v]) {
+            if communities[w] == communities[u] {
+                within += 1;
+            } else {
+                inter += 1;
+            }
+        }
+        within as f64 / (inter as f64 + delta)
+    }))
+}
+
+#[cfg(test)]
+mod test_link_prediction {
+    use super::*;
+    use petgraph::graph::{NodeIndex, UnGraph};
+    use petgraph::stable_graph::StableUnGraph;
+    use std::convert::Infallible;
+
+    fn pairs(indices: &[(usize, usize)]) -> Vec<(NodeIndex, NodeIndex)> {
+        indices
+            .iter()
+            .map(|(u, v)| (NodeIndex::new(*u), NodeIndex::new(*v)))
+            .collect()
+    }
+
+    fn test_graph() -> UnGraph<(), ()> {
+        // Two triangles joined by the edge (2, 3), with a parallel edge and a
+        // pendant node
+        UnGraph::from_edges([
+            (0, 1),
+            (1, 2),
+            (2, 0),
+            (2, 3),
+            (3, 4),
+            (4, 5),
+            (5, 3),
+            (0, 1),
+            (5, 6),
+        ])
+    }
+
+    #[test]
+    fn test_empty_ebunch() {
+        let graph = test_graph();
+        assert!(jaccard_coefficient(&graph, &[], 1).is_empty());
+    }
+
+    #[test]
+    fn test_jaccard_coefficient() {
+        let graph = test_graph();
+        let ebunch = pairs(&[(0, 1), (0, 3), (1, 6), (4, 6)]);
+        for threshold in [1, usize::MAX] {
+            let scores = jaccard_coefficient(&graph, &ebunch, threshold);
+            assert_eq!(scores, vec![1. / 3., 1. / 4., 0., 1. / 2.]);
+        }
+    }
+
+    #[test]
+    fn test_isolated_nodes() {
+        let mut graph = StableUnGraph::<(), ()>::with_capacity(3, 0);
+        for _ in 0..3 {
+            graph.add_node(());
+        }
+        graph.remove_node(NodeIndex::new(1));
+        let ebunch = pairs(&[(0, 2)]);
+        assert_eq!(jaccard_coefficient(&graph, &ebunch, 1), vec![0.]);
+        assert_eq!(adamic_adar_index(&graph, &ebunch, 1), vec![0.]);
+        assert_eq!(
+            common_neighbor_centrality(&graph, &ebunch, 0.5, 1),
+            vec![0.]
+        );
+    }
+
+    #[test]
+    fn test_adamic_adar_and_resource_allocation() {
+        let graph = test_graph();
+        let ebunch = pairs(&[(0, 3), (3, 6), (1, 5)]);
+        let adamic_adar = adamic_adar_index(&graph, &ebunch, 1);
+        assert_eq!(adamic_adar, vec![1. / 3f64.ln(), 1. / 3f64.ln(), 0.]);
+        let resource_allocation = resource_allocation_index(&graph, &ebunch, 1);
+        assert_eq!(resource_allocation, vec![1. / 3., 1. / 3., 0.]);
+    }
+
+    #[test]
+    fn test_preferential_attachment() {
+        let graph = test_graph();
+        let ebunch = pairs(&[(0, 1), (2, 5), (6, 6)]);
+        assert_eq!(
+            preferential_attachment(&graph, &ebunch, 1),
+            vec![4., 9., 1.]
+        );
+    }
+
+    #[test]
+    fn test_common_neighbor_centrality() {
+        let graph = test_graph();
+        let ebunch = pairs(&[(0, 3), (0, 6), (1, 2)]);
+        let scores = common_neighbor_centrality(&graph, &ebunch, 0.8, 1);
+        let expected = [0.8 * 1. + 0.2 * 7. / 2., 0.2 * 7. / 4., 0.8 * 1. + 0.2 * 7.];
+        for (score, expected) in scores.iter().zip(expected) {
+            assert!((score - expected).abs() < 1e-12);
+        }
+        let scores = common_neighbor_centrality(&graph, &ebunch, 1., 1);
+        assert_eq!(scores, vec![1., 0., 1.]);
+    }
+
+    #[test]
+    fn test_common_neighbor_centrality_disconnected() {
+        let graph = UnGraph::<(), ()>::from_edges([(0, 1), (2, 3)]);
+        let scores = common_neighbor_centrality(&graph, &pairs(&[(0, 3)]), 0.5, 1);
+        assert_eq!(scores, vec![0.]);
+    }
+
+    #[test]
+    fn test_community_scores() {
+        let graph = test_graph();
+        let community = [0, 0, 0, 1, 1, 1, 1];
+        let ebunch = pairs(&[(0, 1), (0, 3), (4, 6), (3, 6)]);
+        let res: Result<_, Infallible> =
+            cn_soundarajan_hopcroft(&graph, &ebunch, |n| Ok(community[n.index()]), 1);
+        assert_eq!(res.unwrap(), vec![2., 1., 2., 2.]);
+        let res: Result<_, Infallible> =
+            ra_index_soundarajan_hopcroft(&graph, &ebunch, |n| Ok(community[n.index()]), 1);
+        assert_eq!(res.unwrap(), vec![1. / 3., 0., 1. / 3., 1. / 3.]);
+        let res: Result<_, Infallible> =
+            within_inter_cluster(&graph, &ebunch, |n| Ok(community[n.index()]), 0.5, 1);
+        assert_eq!(res.unwrap(), vec![2., 0., 2., 2.]);
+    }
+
+    #[test]
+    fn test_community_error() {
+        let graph = test_graph();
+        let res = cn_soundarajan_hopcroft(&graph, &pairs(&[(0, 1)]), |_| Err("error"), 1);
+        assert_eq!(res, Err("error"));
+    }
+}
@@ -0,0 +1,403 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+use std::collections::VecDeque;
+use std::hash::Hash;
+
+use hashbrown::{HashMap, HashSet};
+use petgraph::visit::{EdgeRef, IntoEdgeReferences, IntoNodeIdentifiers};
+
+/// Build the adjacency lists of the simple undirected graph underlying
+/// `graph`, ignoring edge directions, self-loops and parallel edges.
+fn simple_adjacency<G>(graph: G) -> (Vec<G::NodeId>, Vec<Vec<usize>>)
+where
+    G: IntoNodeIdentifiers + IntoEdgeReferences,
+    G::NodeId: Hash + Eq,
+{
+    let nodes: Vec<G::NodeId> = graph.node_identifiers().collect();
+    let index: HashMap<G::NodeId, usize> = nodes
+        .iter()
+        .enumerate()
+        .map(|(i, node)| (*node, i))
+        .collect();
+    let mut neighbors: Vec<HashSet<usize>> = vec![HashSet::new(); nodes.len()];
+    for edge in graph.edge_references() {
+        let source = index[&edge.source()];
+        let target = index[&edge.target()];
+        if source != target {
+            neighbors[source].insert(target);
+            neighbors[target].insert(source);
+        }
+    }
+    let adjacency = neighbors
+        .into_iter()
+        .map(|set| {
+            let mut list: Vec<usize> = set.into_iter().collect();
+            list.sort_unstable();
+            list
+        })
+        .collect();
+    (nodes, adjacency)
+}
+
+/// Backtracking search for the branch sets of a minor model.
+///
+/// The pattern nodes are placed one at a time in `order`. The branch set of
+/// every pattern node is grown as a connected set of free host nodes, and
+/// is accepted once it is adjacent to the branch sets of all the pattern
+/// neighbors placed before it.
+struct MinorSearch<'a> {
+    host: &'a [Vec<usize>],
+    pattern: &'a [Vec<usize>],
+    order: Vec<usize>,
+    placed: Vec<bool>,
+    owner: Vec<Option<usize>>,
+    branch_sets: Vec<Vec<usize>>,
+    free: usize,
+}
+
+impl<'a> MinorSearch<'a> {
+    fn new(host: &'a [Vec<usize>], pattern: &'a [Vec<usize>]) -> Self {
+        // Place the pattern nodes in breadth-first order, starting every
+        // connected component at its node of highest degree, so every node
+        // but the first of a component has a neighbor placed before it.
+        let mut order = Vec::with_capacity(pattern.len());
+        let mut seen = vec![false; pattern.len()];
+        let mut starts: Vec<usize> = (0..pattern.len()).collect();
+        starts.sort_by_key(|node| std::cmp::Reverse(pattern[*node].len()));
+        for start in starts {
+            if seen[start] {
+                continue;
+            }
+            seen[start] = true;
+            let mut queue = VecDeque::from([start]);
+            while let Some(node) = queue.pop_front() {
+                order.push(node);
+                for &neighbor in &pattern[node] {
+                    if !seen[neighbor] {
+                        seen[neighbor] = true;
+                        queue.push_back(neighbor);
+                    }
+                }
+            }
+        }
+        MinorSearch {
+            host,
+            pattern,
+            order,
+            placed: vec![false; pattern.len()],
+            owner: vec![None; host.len()],
+            branch_sets: vec![Vec::new(); pattern.len()],
+            free: host.len(),
+        }
+    }
+
+    fn place(&mut self, position: usize) -> bool {
+        if position == self.order.len() {
+            return true;
+        }
+        if self.free < self.order.len() - position {
+            return false;
+        }
+        let node = self.order[position];
+        let earlier: Vec<usize> = self.pattern[node]
+            .iter()
+            .copied()
+            .filter(|neighbor| self.placed[*neighbor])
+            .collect();
+        // A branch set adjacent to the branch set of an earlier neighbor
+        // contains a free host node next to it, so start from those.
+        let seeds: Vec<usize> = match earlier.first() {
+            Some(&first) => {
+                let mut seeds: Vec<usize> = self.branch_sets[first]
+                    .iter()
+                    .flat_map(|host_node| self.host[*host_node].iter().copied())
+                    .filter(|host_node| self.owner[*host_node].is_none())
+                    .collect();
+                seeds.sort_unstable();
+                seeds.dedup();
+                seeds
+            }
+            None => (0..self.host.len())
+                .filter(|host_node| self.owner[*host_node].is_none())
+                .collect(),
+        };
+        self.placed[node] = true;
+        let mut banned = vec![false; self.host.len()];
+        for seed in seeds {
+            self.add(node, seed);
+            let mut frontier = Vec::new();
+            self.extend_frontier(seed, &banned, &mut frontier);
+            if (self.is_valid(node, &earlier) && self.place(position + 1))
+                || self.grow(node, position, &earlier, &mut frontier, &mut banned)
+            {
+                return true;
+            }
+            self.remove(node, seed);
+            // Every connected set containing this seed has been tried
+            banned[seed] = true;
+        }
+        self.placed[node] = false;
+        false
+    }
+
+    /// Try every connected extension of the branch set of `node` by the
+    /// host nodes in `frontier`, each exactly once.
+    fn grow(
+        &mut self,
+        node: usize,
+        position: usize,
+        earlier: &[usize],
+        frontier: &mut Vec<usize>,
+        banned: &mut Vec<bool>,
+    ) -> bool {
+        let Some(next) = frontier.pop() else {
+            return false;
+        };
+        // Leave enough free host nodes for the pattern nodes still to place
+        if self.free > self.order.len() - position - 1 {
+            self.add(node, next);
+            let mut extended = frontier.clone();
+            self.extend_frontier(next, banned, &mut extended);
+            if (self.is_valid(node, earlier) && self.place(position + 1))
+                || self.grow(node, position, earlier, &mut extended, banned)
+            {
+                return true;
+            }
+            self.remove(node, next);
+        }
+        banned[next] = true;
+        let found = self.grow(node, position, earlier, frontier, banned);
+        banned[next] = false;
+        frontier.push(next);
+        found
+    }
+
+    fn extend_frontier(&self, host_node: usize, banned: &[bool], frontier: &mut Vec<usize>) {
+        for &neighbor in &self.host[host_node] {
+            if self.owner[neighbor].is_none() && !banned[neighbor] && !frontier.contains(&neighbor)
+            {
+                frontier.push(neighbor);
+            }
+        }
+    }
+
+    fn is_valid(&self, node: usize, earlier: &[usize]) -> bool {
+        earlier.iter().all(|neighbor| {
+            self.branch_sets[node].iter().any(|host_node| {
+                self.host[*host_node]
+                    .iter()
+                    .any(|other| self.owner[*other] == Some(*neighbor))
+            })
+        })
+    }
+
+    fn add(&mut self, node: usize, host_node: usize) {
+        self.owner[host_node] = Some(node);
+        self.branch_sets[node].push(host_node);
+        self.free -= 1;
+    }
+
+    fn remove(&mut self, node: usize, host_node: usize) {
+        self.owner[host_node] = None;
+        self.branch_sets[node].pop();
+        self.free += 1;
+    }
+}
+
+/// Find a minor model of `pattern` in `graph`.
+///
+/// A graph `H` is a minor of a graph `G` if `H` can be obtained from `G` by
+/// deleting nodes, deleting edges and contracting edges. Equivalently, there
+/// are disjoint connected sets of nodes of `G`, called branch sets, one for
+/// every node of `H`, such that there is an edge of `G` between the branch
+/// sets of every pair of adjacent nodes of `H`.
+///
+/// Both graphs are treated as simple undirected graphs, so the directions of
+/// edges, self-loops and parallel edges are ignored.
+///
+/// The search is a backtracking search over the branch sets, which takes
+/// exponential time in the worst case. It is meant for small pattern graphs
+/// and host graphs of moderate size.
+///
+/// Arguments:
+///
+/// * `pattern` - The graph to find as a minor.
+/// * `graph` - The graph to search in.
+///
+/// Returns a map from every node of `pattern` to its branch set, or `None` if
+/// `pattern` isn't a minor of `graph`.
+///
+/// # Example
+/// ```rust
+/// use rustworkx_core::minor::find_minor;
+/// use rustworkx_core::petgraph::graph::UnGraph;
+///
+/// // A triangle is a minor of a 5-cycle
+/// let pattern = UnGraph::<(), ()>::from_edges([(0, 1), (1, 2), (2, 0)]);
+/// let graph = UnGraph::<(), ()>::from_edges([(0, 1), (1, 2), (2, 3), (3, 4), (4, 0)]);
+/// let model = find_minor(&pattern, &graph).unwrap();
+/// let mut host_nodes: Vec<usize> = model
+///     .values()
+///     .flat_map(|branch_set| branch_set.iter().map(|node| node.index()))
+///     .collect();
+/// host_nodes.sort_unstable();
+/// assert_eq!(host_nodes, vec![0, 1, 2, 3, 4]);
+/// ```
+pub fn find_minor<H, G>(pattern: H, graph: G) -> Option<HashMap<H::NodeId, Vec<G::NodeId>>>
+where
+    H: IntoNodeIdentifiers + IntoEdgeReferences,
+    H::NodeId: Hash + Eq,
+    G: IntoNodeIdentifiers + IntoEdgeReferences,
+    G::NodeId: Hash + Eq,
+{
+    let (pattern_nodes, pattern_adjacency) = simple_adjacency(pattern);
+    let (host_nodes, host_adjacency) = simple_adjacency(graph);
+    let count_edges = |adjacency: &[Vec<usize>]| -> usize {
+        adjacency.iter().map(|list| list.len()).sum::<usize>() / 2
+    };
+    if pattern_nodes.len() > host_nodes.len()
+        || count_edges(&pattern_adjacency) > count_edges(&host_adjacency)
+    {
+        return None;
+    }
+    let mut search = MinorSearch::new(&host_adjacency, &pattern_adjacency);
+    if !search.place(0) {
+        return None;
+    }
+    Some(
+        search
+            .branch_sets
+            .into_iter()
+            .enumerate()
+            .map(|(node, branch_set)| {
+                (
+                    pattern_nodes[node],
+                    branch_set.into_iter().map(|n| host_nodes[n]).collect(),
+                )
+            })
+            .collect(),
+    )
+}
+
+/// Check if `pattern` is a minor of `graph`.
+///
+/// See [`find_minor`] for the definition of a minor and the cost of the
+/// search.
+///
+/// # Example
+/// ```rust
+/// use rustworkx_core::minor::is_minor_of;
+/// use rustworkx_core::petgraph::graph::UnGraph;
+///
+/// let triangle = UnGraph::<(), ()>::from_edges([(0, 1), (1, 2), (2, 0)]);
+/// let k4 = UnGraph::<(), ()>::from_edges([(0, 1), (0, 2), (0, 3), (1, 2), (1, 3), (2, 3)]);
+/// let cycle = UnGraph::<(), ()>::from_edges([(0, 1), (1, 2), (2, 3), (3, 4), (4, 0)]);
+/// assert!(is_minor_of(&triangle, &cycle));
+/// assert!(!is_minor_of(&k4, &cycle));
+/// ```
+pub fn is_minor_of<H, G>(pattern: H, graph: G) -> bool
+where
+    H: IntoNodeIdentifiers + IntoEdgeReferences,
+    H::NodeId: Hash + Eq,
+    G: IntoNodeIdentifiers + IntoEdgeReferences,
+    G::NodeId: Hash + Eq,
+{
+    find_minor(pattern, graph).is_some()
+}
+
+#[cfg(test)]
+mod test_minor {
+    use super::{find_minor, is_minor_of};
+    use crate::generators::{complete_graph, cycle_graph, grid_graph, petersen_graph};
+    use crate::petgraph::graph::{DiGraph, UnGraph};
+    use crate::petgraph::visit::EdgeRef;
+
+    fn complete(n: usize) -> UnGraph<(), ()> {
+        complete_graph(Some(n), None, || (), || ()).unwrap()
+    }
+
+    fn cycle(n: usize) -> UnGraph<(), ()> {
+        cycle_graph(Some(n), None, || (), || (), false).unwrap()
+    }
+
+    #[test]
+    fn test_cycle_minors() {
+        assert!(is_minor_of(&cycle(3), &cycle(6)));
+        assert!(is_minor_of(&cycle(6), &cycle(6)));
+        assert!(!is_minor_of(&cycle(7), &cycle(6)));
+    }
+
+    #[test]
+    fn test_path_has_no_cycle_minor() {
+        let path = UnGraph::<(), ()>::from_edges([(0, 1), (1, 2), (2, 3), (3, 4)]);
+        assert!(!is_minor_of(&cycle(3), &path));
+    }
+
+    #[test]
+    fn test_grid_has_k4_minor() {
+        let grid: UnGraph<(), ()> =
+            grid_graph(Some(3), Some(3), None, || (), || (), false).unwrap();
+        assert!(is_minor_of(&complete(4), &grid));
+        // Grids are planar
+        assert!(!is_minor_of(&complete(5), &grid));
+    }
+
+    #[test]
+    fn test_petersen_has_k5_minor() {
+        let petersen: UnGraph<(), ()> = petersen_graph(5, 2, || (), || ()).unwrap();
+        let model = find_minor(&complete(5), &petersen).unwrap();
+        // Check the model: branch sets are disjoint and pairwise adjacent
+        let mut owner = [None; 10];
+        for (node, branch_set) in &model {
+            for host_node in branch_set {
+                assert!(owner[host_node.index()].is_none());
+                owner[host_node.index()] = Some(node.index());
+            }
+        }
+        let mut adjacent = [[false; 5]; 5];
+        for edge in petersen.edge_references() {
+            if let (Some(a), Some(b)) = (owner[edge.source().index()], owner[edge.target().index()])
+            {
+                adjacent[a][b] = true;
+                adjacent[b][a] = true;
+            }
+        }
+        for (a, row) in adjacent.iter().enumerate() {
+            for (b, is_adjacent) in row.iter().enumerate() {
+                assert!(a == b || *is_adjacent);
+            }
+        }
+    }
+
+    #[test]
+    fn test_disconnected_pattern() {
+        // Two disjoint edges need 4 nodes
+        let pattern = UnGraph::<(), ()>::from_edges([(0, 1), (2, 3)]);
+        assert!(is_minor_of(&pattern, &cycle(4)));
+        assert!(!is_minor_of(&pattern, &cycle(3)));
+    }
+
+    #[test]
+    fn test_directed_edges_are_ignored() {
+        let pattern = DiGraph::<(), ()>::from_edges([(0, 1), (1, 2), (2, 0)]);
+        let graph = DiGraph::<(), ()>::from_edges([(0, 1), (2, 1), (2, 3), (0, 3)]);
+        assert!(is_minor_of(&pattern, &graph));
+    }
+
+    #[test]
+    fn test_empty_pattern() {
+        let pattern = UnGraph::<(), ()>::default();
+        let model = find_minor(&pattern, &cycle(3)).unwrap();
+        assert!(model.is_empty());
+    }
+}
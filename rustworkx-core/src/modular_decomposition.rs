@@ -0,0 +1,428 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+use std::hash::Hash;
+
+use hashbrown::{HashMap, HashSet};
+use petgraph::visit::{EdgeRef, IntoEdgeReferences, IntoNodeIdentifiers};
+
+/// A node of the modular decomposition tree of a graph.
+///
+/// Every node of the tree is a strong module of the graph, which is a set of
+/// nodes that all have the same neighbors outside of the set and that doesn't
+/// overlap any other module. The children of an inner node are its maximal
+/// strong submodules, in the order of their first graph node in
+/// [`IntoNodeIdentifiers::node_identifiers`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ModularDecomposition<N> {
+    /// A module made of a single node of the graph.
+    Node(N),
+    /// A module whose subgraph is disconnected, with the connected
+    /// components as children.
+    Parallel(Vec<ModularDecomposition<N>>),
+    /// A module whose subgraph has a disconnected complement, so every node
+    /// of a child is adjacent to every node of the other children.
+    Series(Vec<ModularDecomposition<N>>),
+    /// A module which is neither parallel nor series, so the graph of the
+    /// adjacencies between its children is prime.
+    Prime(Vec<ModularDecomposition<N>>),
+}
+
+impl<N: Copy> ModularDecomposition<N> {
+    /// The children of the module, which is empty for a single node.
+    pub fn children(&self) -> &[ModularDecomposition<N>] {
+        match self {
+            ModularDecomposition::Node(_) => &[],
+            ModularDecomposition::Parallel(children)
+            | ModularDecomposition::Series(children)
+            | ModularDecomposition::Prime(children) => children,
+        }
+    }
+
+    /// The nodes of the graph in the module, in the order of the leaves of
+    /// the tree.
+    pub fn nodes(&self) -> Vec<N> {
+        let mut nodes = Vec::new();
+        let mut stack = vec![self];
+        while let Some(module) = stack.pop() {
+            match module {
+                ModularDecomposition::Node(node) => nodes.push(*node),
+                _ => stack.extend(module.children().iter().rev()),
+            }
+        }
+        nodes
+    }
+}
+
+struct Decomposer {
+    neighbors: Vec<HashSet<usize>>,
+}
+
+impl Decomposer {
+    /// Split `nodes` into the connected components of the subgraph they
+    /// induce, or of its complement if `complement` is set.
+    fn components(&self, nodes: &[usize], complement: bool) -> Vec<Vec<usize>> {
+        let mut remaining: Vec<usize> = nodes.iter().rev().copied().collect();
+        let mut components = Vec::new();
+        while let Some(start) = remaining.pop() {
+            let mut component = vec![start];
+            let mut stack = vec![start];
+            while let Some(node) = stack.pop() {
+                let (next, rest): (Vec<usize>, Vec<usize>) = remaining
+                    .iter()
+                    .partition(|other| self.neighbors[node].contains(*other) != complement);
+                remaining = rest;
+                component.extend(&next);
+                stack.extend(next);
+            }
+            component.sort_unstable();
+            components.push(component);
+        }
+        components
+    }
+
+    /// The smallest module of the subgraph induced by `nodes` that contains
+    /// both `a` and `b`.
+    fn minimal_module(&self, nodes: &[usize], a: usize, b: usize) -> HashSet<usize> {
+        let member: HashSet<usize> = nodes.iter().copied().collect();
+        let mut module: HashSet<usize> = HashSet::with_capacity(nodes.len());
+        let mut adjacent: HashMap<usize, usize> = HashMap::new();
+        let mut new_nodes = vec![a, b];
+        while !new_nodes.is_empty() {
+            for node in new_nodes {
+                module.insert(node);
+                for neighbor in &self.neighbors[node] {
+                    if member.contains(neighbor) {
+                        *adjacent.entry(*neighbor).or_insert(0) += 1;
+                    }
+                }
+            }
+            // A node outside of the module that is adjacent to some but not
+            // all of its nodes has to be in it
+            new_nodes = nodes
+                .iter()
+                .copied()
+                .filter(|node| {
+                    !module.contains(node) && {
+                        let count = adjacent.get(node).copied().unwrap_or(0);
+                        count > 0 && count < module.len()
+                    }
+                })
+                .collect();
+        }
+        module
+    }
+
+    /// Split the nodes of a prime module into its maximal strong submodules.
+    ///
+    /// The children of a prime module are the only proper modules that can
+    /// contain a node besides the nodes themselves, so two nodes are in the
+    /// same child exactly when the smallest module containing them isn't the
+    /// whole module.
+    fn prime_children(&self, nodes: &[usize]) -> Vec<Vec<usize>> {
+        let mut assigned: HashSet<usize> = HashSet::with_capacity(nodes.len());
+        let mut children = Vec::new();
+        for (i, node) in nodes.iter().enumerate() {
+            if assigned.contains(node) {
+                continue;
+            }
+            let mut child: HashSet<usize> = HashSet::from([*node]);
+            for other in &nodes[i + 1..] {
+                if assigned.contains(other) || child.contains(other) {
+                    continue;
+                }
+                let module = self.minimal_module(nodes, *node, *other);
+                if module.len() < nodes.len() {
+                    child.extend(module);
+                }
+            }
+            let mut child: Vec<usize> = child.into_iter().collect();
+            child.sort_unstable();
+            assigned.extend(&child);
+            children.push(child);
+        }
+        children
+    }
+
+    fn decompose(&self, nodes: Vec<usize>) -> ModularDecomposition<usize> {
+        if nodes.len() == 1 {
+            return ModularDecomposition::Node(nodes[0]);
+        }
+        let decompose_all = |children: Vec<Vec<usize>>| -> Vec<ModularDecomposition<usize>> {
+            children
+                .into_iter()
+                .map(|child| self.decompose(child))
+                .collect()
+        };
+        let components = self.components(&nodes, false);
+        if components.len() > 1 {
+            return ModularDecomposition::Parallel(decompose_all(components));
+        }
+        let co_components = self.components(&nodes, true);
+        if co_components.len() > 1 {
+            return ModularDecomposition::Series(decompose_all(co_components));
+        }
+        ModularDecomposition::Prime(decompose_all(self.prime_children(&nodes)))
+    }
+}
+
+fn map_nodes<N: Copy>(module: ModularDecomposition<usize>, nodes: &[N]) -> ModularDecomposition<N> {
+    let map_children = |children: Vec<ModularDecomposition<usize>>| {
+        children
+            .into_iter()
+            .map(|child| map_nodes(child, nodes))
+            .collect()
+    };
+    match module {
+        ModularDecomposition::Node(node) => ModularDecomposition::Node(nodes[node]),
+        ModularDecomposition::Parallel(children) => {
+            ModularDecomposition::Parallel(map_children(children))
+        }
+        ModularDecomposition::Series(children) => {
+            ModularDecomposition::Series(map_children(children))
+        }
+        ModularDecomposition::Prime(children) => {
+            ModularDecomposition::Prime(map_children(children))
+        }
+    }
+}
+
+/// Compute the modular decomposition of an undirected graph.
+///
+/// A module of a graph is a set of nodes that all have the same neighbors
+/// outside of the set. The modular decomposition is the tree of the strong
+/// modules of the graph, the modules that don't overlap any other module,
+/// where every inner node is labelled [`ModularDecomposition::Parallel`],
+/// [`ModularDecomposition::Series`] or [`ModularDecomposition::Prime`]. A
+/// graph is a cograph exactly when its decomposition has no prime node,
+/// and many problems that are hard in general can be solved on the tree of
+/// a cograph in linear time.
+///
+/// The decomposition is computed top down: a module is split into the
+/// connected components of its subgraph or of the complement of its
+/// subgraph, and the children of a prime module are found by growing the
+/// smallest module containing each pair of its nodes. This takes
+/// `O(n^4)` time in the worst case, although graphs with few prime modules
+/// are decomposed much faster.
+///
+/// Edge directions, self-loops and parallel edges are ignored.
+///
+/// Arguments:
+///
+/// * `graph` - The graph to decompose.
+///
+/// Returns the root of the decomposition tree, or `None` if the graph has no
+/// nodes.
+///
+/// # Example
+/// ```rust
+/// use rustworkx_core::petgraph::graph::{NodeIndex, UnGraph};
+/// use rustworkx_core::modular_decomposition::{modular_decomposition, ModularDecomposition};
+///
+/// // The complete bipartite graph K_{2,2} is the series composition of
+/// // two pairs of independent nodes
+/// let graph = UnGraph::<(), ()>::from_edges([(0, 2), (0, 3), (1, 2), (1, 3)]);
+/// let tree = modular_decomposition(&graph).unwrap();
+/// let node = |index| ModularDecomposition::Node(NodeIndex::new(index));
+/// assert_eq!(
+///     tree,
+///     ModularDecomposition::Series(vec![
+///         ModularDecomposition::Parallel(vec![node(0), node(1)]),
+///         ModularDecomposition::Parallel(vec![node(2), node(3)]),
+///     ])
+/// );
+/// ```
+pub fn modular_decomposition<G>(graph: G) -> Option<ModularDecomposition<G::NodeId>>
+where
+    G: IntoNodeIdentifiers + IntoEdgeReferences,
+    G::NodeId: Hash + Eq,
+{
+    let nodes: Vec<G::NodeId> = graph.node_identifiers().collect();
+    if nodes.is_empty() {
+        return None;
+    }
+    let index: HashMap<G::NodeId, usize> = nodes
+        .iter()
+        .enumerate()
+        .map(|(i, node)| (*node, i))
+        .collect();
+    let mut neighbors: Vec<HashSet<usize>> = vec![HashSet::new(); nodes.len()];
+    for edge in graph.edge_references() {
+        let source = index[&edge.source()];
+        let target = index[&edge.target()];
+        if source != target {
+            neighbors[source].insert(target);
+            neighbors[target].insert(source);
+        }
+    }
+    let decomposer = Decomposer { neighbors };
+    let tree = decomposer.decompose((0..nodes.len()).collect());
+    Some(map_nodes(tree, &nodes))
+}
+
+/// Check if an undirected graph is a cograph.
+///
+/// A cograph is a graph that can be built from single nodes by disjoint
+/// unions and complements, which is the same as having no induced path on
+/// four nodes or having a [`modular_decomposition`] without prime nodes.
+/// The graph with no nodes is a cograph.
+///
+/// # Example
+/// ```rust
+/// use rustworkx_core::petgraph::graph::UnGraph;
+/// use rustworkx_core::modular_decomposition::is_cograph;
+///
+/// let star = UnGraph::<(), ()>::from_edges([(0, 1), (0, 2), (0, 3)]);
+/// let path = UnGraph::<(), ()>::from_edges([(0, 1), (1, 2), (2, 3)]);
+/// assert!(is_cograph(&star));
+/// assert!(!is_cograph(&path));
+/// ```
+pub fn is_cograph<G>(graph: G) -> bool
+where
+    G: IntoNodeIdentifiers + IntoEdgeReferences,
+    G::NodeId: Hash + Eq,
+{
+    let Some(tree) = modular_decomposition(graph) else {
+        return true;
+    };
+    let mut stack = vec![&tree];
+    while let Some(module) = stack.pop() {
+        if let ModularDecomposition::Prime(_) = module {
+            return false;
+        }
+        stack.extend(module.children());
+    }
+    true
+}
+
+#[cfg(test)]
+mod test_modular_decomposition {
+    use super::{is_cograph, modular_decomposition, ModularDecomposition};
+    use crate::generators::{complete_graph, cycle_graph, path_graph, petersen_graph};
+    use crate::petgraph::graph::{NodeIndex, UnGraph};
+
+    type Tree = ModularDecomposition<NodeIndex>;
+
+    fn node(index: usize) -> Tree {
+        ModularDecomposition::Node(NodeIndex::new(index))
+    }
+
+    fn nodes(indices: &[usize]) -> Vec<Tree> {
+        indices.iter().map(|index| node(*index)).collect()
+    }
+
+    #[test]
+    fn test_empty_and_single_node() {
+        let mut graph = UnGraph::<(), ()>::default();
+        assert_eq!(modular_decomposition(&graph), None);
+        assert!(is_cograph(&graph));
+        graph.add_node(());
+        assert_eq!(modular_decomposition(&graph), Some(node(0)));
+    }
+
+    #[test]
+    fn test_complete_graph() {
+        let graph: UnGraph<(), ()> = complete_graph(Some(4), None, || (), || ()).unwrap();
+        assert_eq!(
+            modular_decomposition(&graph),
+            Some(ModularDecomposition::Series(nodes(&[0, 1, 2, 3])))
+        );
+        assert!(is_cograph(&graph));
+    }
+
+    #[test]
+    fn test_disconnected() {
+        let graph = UnGraph::<(), ()>::from_edges([(0, 1), (2, 3), (3, 4), (2, 4)]);
+        assert_eq!(
+            modular_decomposition(&graph),
+            Some(ModularDecomposition::Parallel(vec![
+                ModularDecomposition::Series(nodes(&[0, 1])),
+                ModularDecomposition::Series(nodes(&[2, 3, 4])),
+            ]))
+        );
+    }
+
+    #[test]
+    fn test_prime_path_and_cycle() {
+        let path: UnGraph<(), ()> = path_graph(Some(4), None, || (), || (), false).unwrap();
+        assert_eq!(
+            modular_decomposition(&path),
+            Some(ModularDecomposition::Prime(nodes(&[0, 1, 2, 3])))
+        );
+        assert!(!is_cograph(&path));
+        let cycle: UnGraph<(), ()> = cycle_graph(Some(5), None, || (), || (), false).unwrap();
+        assert_eq!(
+            modular_decomposition(&cycle),
+            Some(ModularDecomposition::Prime(nodes(&[0, 1, 2, 3, 4])))
+        );
+        let petersen: UnGraph<(), ()> = petersen_graph(5, 2, || (), || ()).unwrap();
+        let tree = modular_decomposition(&petersen).unwrap();
+        assert_eq!(tree.children().len(), 10);
+        assert!(matches!(tree, ModularDecomposition::Prime(_)));
+    }
+
+    #[test]
+    fn test_prime_with_modules() {
+        // A path 0 - {1, 2} - 3 - 4 where 1 and 2 are an independent module,
+        // and 4 is replaced by the edge 4 - 5
+        let graph =
+            UnGraph::<(), ()>::from_edges([(0, 1), (0, 2), (1, 3), (2, 3), (3, 4), (3, 5), (4, 5)]);
+        assert_eq!(
+            modular_decomposition(&graph),
+            Some(ModularDecomposition::Prime(vec![
+                node(0),
+                ModularDecomposition::Parallel(nodes(&[1, 2])),
+                node(3),
+                ModularDecomposition::Series(nodes(&[4, 5])),
+            ]))
+        );
+    }
+
+    #[test]
+    fn test_nested_cograph() {
+        // The complement of a disjoint union of a triangle and an edge
+        // joined to a single node
+        let mut graph = UnGraph::<(), ()>::from_edges([(0, 1), (1, 2), (2, 0), (3, 4)]);
+        let center = graph.add_node(());
+        for other in 0..5 {
+            graph.add_edge(center, NodeIndex::new(other), ());
+        }
+        let tree = modular_decomposition(&graph).unwrap();
+        assert_eq!(
+            tree,
+            ModularDecomposition::Series(vec![
+                ModularDecomposition::Parallel(vec![
+                    ModularDecomposition::Series(nodes(&[0, 1, 2])),
+                    ModularDecomposition::Series(nodes(&[3, 4])),
+                ]),
+                node(5),
+            ])
+        );
+        assert_eq!(
+            tree.nodes(),
+            (0..6).map(NodeIndex::new).collect::<Vec<NodeIndex>>()
+        );
+        assert!(is_cograph(&graph));
+    }
+
+    #[test]
+    fn test_ignores_self_loops_and_parallel_edges() {
+        let graph = UnGraph::<(), ()>::from_edges([(0, 1), (0, 1), (1, 1), (1, 2)]);
+        assert_eq!(
+            modular_decomposition(&graph),
+            Some(ModularDecomposition::Series(vec![
+                ModularDecomposition::Parallel(nodes(&[0, 2])),
+                node(1),
+            ]))
+        );
+    }
+}
@@ -0,0 +1,854 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+//! This module contains the counting of network motifs, the small connected
+//! subgraphs a graph is built from, and their significance compared to
+//! random graphs with the same degrees.
+//!
+//! The graphs are treated as simple graphs: self-loops are ignored and
+//! parallel edges are counted once. A motif of a directed graph is weakly
+//! connected and its class takes the direction of its edges into account.
+
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+use hashbrown::{HashMap, HashSet};
+use petgraph::stable_graph::{NodeIndex, StableDiGraph, StableGraph, StableUnGraph};
+use petgraph::visit::{EdgeRef, GraphProp, IntoEdgeReferences, IntoNodeIdentifiers, NodeIndexable};
+use petgraph::EdgeType;
+use rand::prelude::*;
+use rand_pcg::Pcg64;
+
+use crate::dictmap::*;
+use crate::graph_ext::{DoubleEdgeSwapDirected, DoubleEdgeSwapUndirected};
+use crate::rng::rng_from_seed;
+
+/// The isomorphism class of a motif, given by the edges of its canonical
+/// form on the nodes `0..size`.
+///
+/// For an undirected motif every edge `(a, b)` has `a < b`.
+pub type MotifClass = Vec<(usize, usize)>;
+
+/// Error returned by the motif functions for invalid arguments.
+#[derive(Debug, PartialEq, Eq)]
+pub enum MotifError {
+    /// The motif size isn't `3` or `4`.
+    InvalidSize(usize),
+    /// The sampling probabilities aren't one in `(0, 1]` per motif node.
+    InvalidProbabilities,
+    /// A significance profile was requested from no random graphs.
+    NoRandomizations,
+}
+
+impl Display for MotifError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MotifError::InvalidSize(size) => {
+                write!(f, "Motifs of size {} aren't supported, only 3 or 4", size)
+            }
+            MotifError::InvalidProbabilities => write!(
+                f,
+                "There must be a sampling probability in (0, 1] for every node of a motif"
+            ),
+            MotifError::NoRandomizations => write!(
+                f,
+                "The significance profile needs at least one random graph"
+            ),
+        }
+    }
+}
+
+impl Error for MotifError {}
+
+/// The statistics of a motif class compared to random graphs.
+#[derive(Clone, Debug, PartialEq)]
+pub struct MotifSignificance {
+    /// The number of occurrences of the motif in the graph.
+    pub count: f64,
+    /// The mean number of occurrences in the random graphs.
+    pub random_mean: f64,
+    /// The standard deviation of the number of occurrences in the random
+    /// graphs.
+    pub random_std: f64,
+    /// The z-score of `count`, `NaN` if `random_std` is `0`.
+    pub z_score: f64,
+    /// The z-score normalized by the length of the vector of all the
+    /// z-scores, the entry of the significance profile for the motif.
+    pub significance: f64,
+}
+
+/// The names of the 16 isomorphism classes of directed triads, in the
+/// MAN notation of Holland and Leinhardt.
+pub const TRIAD_TYPES: [&str; 16] = [
+    "003", "012", "102", "021D", "021U", "021C", "111D", "111U", "030T", "030C", "201", "120D",
+    "120U", "120C", "210", "300",
+];
+
+// The triad type, as one more than the index into TRIAD_TYPES, of every
+// triad code computed by `triad_code`
+const TRIAD_CODES: [usize; 64] = [
+    1, 2, 2, 3, 2, 4, 6, 8, 2, 6, 5, 7, 3, 8, 7, 11, 2, 6, 4, 8, 5, 9, 9, 13, 6, 10, 9, 14, 7, 14,
+    12, 15, 2, 5, 6, 7, 6, 9, 10, 14, 4, 9, 9, 12, 8, 13, 14, 15, 3, 7, 8, 11, 7, 12, 14, 15, 8,
+    14, 13, 15, 11, 15, 15, 16,
+];
+
+/// The simple graph underlying a graph, by node index.
+struct Adjacency {
+    directed: bool,
+    node_bound: usize,
+    nodes: Vec<usize>,
+    // The neighbors of every node, ignoring the direction of the edges
+    neighbors: Vec<Vec<usize>>,
+    // The edges, in both directions for an undirected graph
+    edges: HashSet<(usize, usize)>,
+}
+
+impl Adjacency {
+    fn new<G>(graph: G) -> Self
+    where
+        G: IntoEdgeReferences + IntoNodeIdentifiers + NodeIndexable + GraphProp,
+    {
+        let directed = graph.is_directed();
+        let mut neighbors = vec![Vec::new(); graph.node_bound()];
+        let mut edges = HashSet::new();
+        for edge in graph.edge_references() {
+            let source = graph.to_index(edge.source());
+            let target = graph.to_index(edge.target());
+            if source == target {
+                continue;
+            }
+            edges.insert((source, target));
+            if !directed {
+                edges.insert((target, source));
+            }
+            neighbors[source].push(target);
+            neighbors[target].push(source);
+        }
+        for node_neighbors in neighbors.iter_mut() {
+            node_neighbors.sort_unstable();
+            node_neighbors.dedup();
+        }
+        Adjacency {
+            directed,
+            node_bound: graph.node_bound(),
+            nodes: graph
+                .node_identifiers()
+                .map(|node| graph.to_index(node))
+                .collect(),
+            neighbors,
+            edges,
+        }
+    }
+
+    #[inline]
+    fn has_edge(&self, source: usize, target: usize) -> bool {
+        self.edges.contains(&(source, target))
+    }
+
+    #[inline]
+    fn is_neighbor(&self, a: usize, b: usize) -> bool {
+        self.has_edge(a, b) || self.has_edge(b, a)
+    }
+
+    fn to_graph<Ty: EdgeType>(&self) -> StableGraph<(), (), Ty> {
+        let mut graph = StableGraph::with_capacity(self.node_bound, self.edges.len());
+        for _ in 0..self.node_bound {
+            graph.add_node(());
+        }
+        // The edges are added in order so the rewiring only depends on its seed
+        let mut edges: Vec<(usize, usize)> = self
+            .edges
+            .iter()
+            .copied()
+            .filter(|(source, target)| self.directed || source < target)
+            .collect();
+        edges.sort_unstable();
+        for (source, target) in edges {
+            graph.add_edge(NodeIndex::new(source), NodeIndex::new(target), ());
+        }
+        graph
+    }
+}
+
+/// Finds the canonical form of the subgraphs induced by `size` nodes.
+struct Classifier {
+    size: usize,
+    directed: bool,
+    permutations: Vec<Vec<usize>>,
+    canonical: HashMap<u32, u32>,
+}
+
+impl Classifier {
+    fn new(size: usize, directed: bool) -> Self {
+        let mut permutations = vec![vec![]];
+        for n in 0..size {
+            permutations = permutations
+                .into_iter()
+                .flat_map(|perm: Vec<usize>| {
+                    (0..=n).map(move |i| {
+                        let mut perm = perm.clone();
+                        perm.insert(i, n);
+                        perm
+                    })
+                })
+                .collect();
+        }
+        Classifier {
+            size,
+            directed,
+            permutations,
+            canonical: HashMap::new(),
+        }
+    }
+
+    /// The adjacency matrix of the subgraph induced by `nodes` as a bit set,
+    /// where the bit `i * size + j` is set for an edge from `nodes[i]` to
+    /// `nodes[j]`.
+    fn code(&self, adjacency: &Adjacency, nodes: &[usize]) -> u32 {
+        let mut code = 0;
+        for (i, &a) in nodes.iter().enumerate() {
+            for (j, &b) in nodes.iter().enumerate() {
+                if i != j && adjacency.has_edge(a, b) {
+                    code |= 1 << (i * self.size + j);
+                }
+            }
+        }
+        code
+    }
+
+    /// The smallest code of the relabelings of the subgraph with `code`.
+    fn canonical_code(&mut self, code: u32) -> u32 {
+        let size = self.size;
+        let permutations = &self.permutations;
+        *self.canonical.entry(code).or_insert_with(|| {
+            permutations
+                .iter()
+                .map(|perm| {
+                    let mut relabeled = 0;
+                    for i in 0..size {
+                        for j in 0..size {
+                            if code & (1 << (i * size + j)) != 0 {
+                                relabeled |= 1 << (perm[i] * size + perm[j]);
+                            }
+                        }
+                    }
+                    relabeled
+                })
+                .min()
+                .unwrap()
+        })
+    }
+
+    fn class(&self, code: u32) -> MotifClass {
+        let mut class = Vec::new();
+        for i in 0..self.size {
+            for j in 0..self.size {
+                if i != j && (self.directed || i < j) && code & (1 << (i * self.size + j)) != 0 {
+                    class.push((i, j));
+                }
+            }
+        }
+        class
+    }
+}
+
+fn validate(size: usize, probabilities: Option<&[f64]>) -> Result<(), MotifError> {
+    if size != 3 && size != 4 {
+        return Err(MotifError::InvalidSize(size));
+    }
+    if let Some(probabilities) = probabilities {
+        if probabilities.len() != size || probabilities.iter().any(|p| !(*p > 0. && *p <= 1.)) {
+            return Err(MotifError::InvalidProbabilities);
+        }
+    }
+    Ok(())
+}
+
+#[inline]
+fn keep(probabilities: Option<&[f64]>, depth: usize, rng: &mut Pcg64) -> bool {
+    match probabilities {
+        Some(probabilities) if probabilities[depth] < 1. => rng.gen::<f64>() < probabilities[depth],
+        _ => true,
+    }
+}
+
+/// Count the connected induced subgraphs with `size` nodes by canonical
+/// code, with the ESU algorithm, or its sampling variant RAND-ESU if
+/// `probabilities` is set.
+fn count_codes(
+    adjacency: &Adjacency,
+    classifier: &mut Classifier,
+    probabilities: Option<&[f64]>,
+    rng: &mut Pcg64,
+) -> HashMap<u32, f64> {
+    // Every subgraph is found with the product of the probabilities
+    let weight = 1. / probabilities.map_or(1., |p| p.iter().product());
+    let mut counts: HashMap<u32, f64> = HashMap::new();
+    let mut subgraph = Vec::with_capacity(classifier.size);
+    for &root in &adjacency.nodes {
+        if !keep(probabilities, 0, rng) {
+            continue;
+        }
+        subgraph.push(root);
+        let extension: Vec<usize> = adjacency.neighbors[root]
+            .iter()
+            .copied()
+            .filter(|&node| node > root)
+            .collect();
+        extend_subgraph(
+            adjacency,
+            &mut subgraph,
+            extension,
+            root,
+            probabilities,
+            rng,
+            &mut |subgraph: &[usize]| {
+                let code = classifier.canonical_code(classifier.code(adjacency, subgraph));
+                *counts.entry(code).or_insert(0.) += weight;
+            },
+        );
+        subgraph.pop();
+    }
+    counts
+}
+
+fn extend_subgraph<F>(
+    adjacency: &Adjacency,
+    subgraph: &mut Vec<usize>,
+    mut extension: Vec<usize>,
+    root: usize,
+    probabilities: Option<&[f64]>,
+    rng: &mut Pcg64,
+    visit: &mut F,
+) where
+    F: FnMut(&[usize]),
+{
+    if subgraph.len() == subgraph.capacity() {
+        visit(subgraph);
+        return;
+    }
+    while let Some(node) = extension.pop() {
+        if !keep(probabilities, subgraph.len(), rng) {
+            continue;
+        }
+        // The exclusive neighbors of node, which aren't in the subgraph or
+        // adjacent to it, are added to the extension
+        let mut next_extension = extension.clone();
+        for &neighbor in &adjacency.neighbors[node] {
+            if neighbor > root
+                && !subgraph.contains(&neighbor)
+                && !subgraph.iter().any(|&n| adjacency.is_neighbor(n, neighbor))
+            {
+                next_extension.push(neighbor);
+            }
+        }
+        subgraph.push(node);
+        extend_subgraph(
+            adjacency,
+            subgraph,
+            next_extension,
+            root,
+            probabilities,
+            rng,
+            visit,
+        );
+        subgraph.pop();
+    }
+}
+
+fn sorted_classes<T>(classifier: &Classifier, values: HashMap<u32, T>) -> DictMap<MotifClass, T> {
+    let mut values: Vec<(MotifClass, T)> = values
+        .into_iter()
+        .map(|(code, value)| (classifier.class(code), value))
+        .collect();
+    values.sort_by(|a, b| a.0.len().cmp(&b.0.len()).then_with(|| a.0.cmp(&b.0)));
+    values.into_iter().collect()
+}
+
+/// Count the motifs with `size` nodes of a graph.
+///
+/// The motifs are the connected induced subgraphs of the graph, which are
+/// enumerated with the ESU algorithm of Wernicke [1]. The result maps the
+/// isomorphism class of every motif found in the graph to its number of
+/// occurrences.
+///
+/// With `probabilities` the motifs are sampled with the RAND-ESU algorithm
+/// instead, which is much faster on large graphs. The subgraphs are
+/// enumerated as a tree, and a subtree at depth `d` (the subgraphs growing
+/// from `d + 1` nodes) is only visited with probability `probabilities[d]`.
+/// Every sampled motif is counted as `1 / p` motifs, where `p` is the
+/// product of the probabilities, so the counts are unbiased estimates.
+///
+/// Arguments:
+///
+/// * `graph` - The graph to count the motifs of.
+/// * `size` - The number of nodes of the motifs, `3` or `4`.
+/// * `probabilities` - The optional sampling probability at every depth,
+///   `size` values in `(0, 1]`.
+/// * `seed` - An optional seed to use for the random number generator of the
+///   sampling.
+///
+/// # Example
+/// ```rust
+/// use rustworkx_core::motifs::motif_counts;
+/// use rustworkx_core::petgraph::graph::UnGraph;
+///
+/// // A triangle with a pendant node
+/// let graph = UnGraph::<(), ()>::from_edges(&[(0, 1), (1, 2), (2, 0), (2, 3)]);
+/// let counts = motif_counts(&graph, 3, None, None).unwrap();
+/// assert_eq!(counts[&vec![(0, 1), (0, 2)]], 2.);
+/// assert_eq!(counts[&vec![(0, 1), (0, 2), (1, 2)]], 1.);
+/// ```
+///
+/// [1] S. Wernicke, "Efficient detection of network motifs", IEEE/ACM
+/// Transactions on Computational Biology and Bioinformatics 3(4), 347-359
+/// (2006).
+pub fn motif_counts<G>(
+    graph: G,
+    size: usize,
+    probabilities: Option<&[f64]>,
+    seed: Option<u64>,
+) -> Result<DictMap<MotifClass, f64>, MotifError>
+where
+    G: IntoEdgeReferences + IntoNodeIdentifiers + NodeIndexable + GraphProp,
+{
+    validate(size, probabilities)?;
+    let adjacency = Adjacency::new(graph);
+    let mut classifier = Classifier::new(size, adjacency.directed);
+    let mut rng = rng_from_seed(seed);
+    let counts = count_codes(&adjacency, &mut classifier, probabilities, &mut rng);
+    Ok(sorted_classes(&classifier, counts))
+}
+
+/// Compute the significance profile of the motifs with `size` nodes of a
+/// graph.
+///
+/// The motifs are counted as with [`motif_counts`] in the graph and in
+/// `num_randomizations` random graphs with the same degrees, which are made
+/// by rewiring the graph with
+/// [`DoubleEdgeSwapUndirected::double_edge_swap`] or
+/// [`DoubleEdgeSwapDirected::double_edge_swap`]. Every class of motif found
+/// in any of the graphs gets the z-score of its count in the graph against
+/// the counts in the random graphs, and the significance profile of Milo et
+/// al. [1] is the vector of z-scores normalized to length `1`. A positive
+/// significance means the motif is more common than expected from the
+/// degrees alone. Classes whose z-score is `NaN` are left out of the
+/// normalization.
+///
+/// Arguments:
+///
+/// * `graph` - The graph to compute the significance profile of.
+/// * `size` - The number of nodes of the motifs, `3` or `4`.
+/// * `num_randomizations` - The number of random graphs. It must be
+///   positive.
+/// * `swaps_per_edge` - The number of double edge swaps done to randomize
+///   the graph, per edge of the graph.
+/// * `probabilities` - The optional sampling probabilities of RAND-ESU, as in
+///   [`motif_counts`].
+/// * `seed` - An optional seed to use for the random number generator.
+///
+/// # Example
+/// ```rust
+/// use rustworkx_core::motifs::motif_significance_profile;
+/// use rustworkx_core::petgraph::graph::UnGraph;
+///
+/// // Two triangles joined by a path
+/// let graph = UnGraph::<(), ()>::from_edges(&[
+///     (0, 1), (1, 2), (2, 0), (2, 3), (3, 4), (4, 5), (5, 6), (6, 4),
+/// ]);
+/// let profile = motif_significance_profile(&graph, 3, 20, 10, None, Some(1)).unwrap();
+/// let triangle = &profile[&vec![(0, 1), (0, 2), (1, 2)]];
+/// assert_eq!(triangle.count, 2.);
+/// assert!(triangle.count >= triangle.random_mean);
+/// ```
+///
+/// [1] R. Milo et al., "Superfamilies of evolved and designed networks",
+/// Science 303, 1538-1542 (2004).
+pub fn motif_significance_profile<G>(
+    graph: G,
+    size: usize,
+    num_randomizations: usize,
+    swaps_per_edge: usize,
+    probabilities: Option<&[f64]>,
+    seed: Option<u64>,
+) -> Result<DictMap<MotifClass, MotifSignificance>, MotifError>
+where
+    G: IntoEdgeReferences + IntoNodeIdentifiers + NodeIndexable + GraphProp,
+{
+    validate(size, probabilities)?;
+    if num_randomizations == 0 {
+        return Err(MotifError::NoRandomizations);
+    }
+    let adjacency = Adjacency::new(graph);
+    let mut classifier = Classifier::new(size, adjacency.directed);
+    let mut rng = rng_from_seed(seed);
+    let counts = count_codes(&adjacency, &mut classifier, probabilities, &mut rng);
+
+    let mut random_counts: Vec<HashMap<u32, f64>> = Vec::with_capacity(num_randomizations);
+    // Each randomization continues from the previous one, which is already a
+    // random graph with the same degrees
+    if adjacency.directed {
+        let mut random_graph: StableDiGraph<(), ()> = adjacency.to_graph();
+        let num_swaps = swaps_per_edge * random_graph.edge_count();
+        for _ in 0..num_randomizations {
+            random_graph.double_edge_swap(num_swaps, 10 * num_swaps, Some(rng.gen()));
+            let random_adjacency = Adjacency::new(&random_graph);
+            random_counts.push(count_codes(
+                &random_adjacency,
+                &mut classifier,
+                probabilities,
+                &mut rng,
+            ));
+        }
+    } else {
+        let mut random_graph: StableUnGraph<(), ()> = adjacency.to_graph();
+        let num_swaps = swaps_per_edge * random_graph.edge_count();
+        for _ in 0..num_randomizations {
+            random_graph.double_edge_swap(num_swaps, 10 * num_swaps, Some(rng.gen()));
+            let random_adjacency = Adjacency::new(&random_graph);
+            random_counts.push(count_codes(
+                &random_adjacency,
+                &mut classifier,
+                probabilities,
+                &mut rng,
+            ));
+        }
+    }
+
+    let mut codes: HashSet<u32> = counts.keys().copied().collect();
+    for random in &random_counts {
+        codes.extend(random.keys().copied());
+    }
+    let mut profile: HashMap<u32, MotifSignificance> = codes
+        .into_iter()
+        .map(|code| {
+            let count = counts.get(&code).copied().unwrap_or(0.);
+            let samples: Vec<f64> = random_counts
+                .iter()
+                .map(|random| random.get(&code).copied().unwrap_or(0.))
+                .collect();
+            let num_samples = samples.len() as f64;
+            let random_mean = samples.iter().sum::<f64>() / num_samples;
+            let random_std = (samples
+                .iter()
+                .map(|x| (x - random_mean).powi(2))
+                .sum::<f64>()
+                / num_samples)
+                .sqrt();
+            let z_score = if random_std > 0. {
+                (count - random_mean) / random_std
+            } else {
+                f64::NAN
+            };
+            let stats = MotifSignificance {
+                count,
+                random_mean,
+                random_std,
+                z_score,
+                significance: z_score,
+            };
+            (code, stats)
+        })
+        .collect();
+    let norm = profile
+        .values()
+        .filter(|stats| !stats.z_score.is_nan())
+        .map(|stats| stats.z_score.powi(2))
+        .sum::<f64>()
+        .sqrt();
+    if norm > 0. {
+        for stats in profile.values_mut() {
+            stats.significance /= norm;
+        }
+    }
+    Ok(sorted_classes(&classifier, profile))
+}
+
+/// The code of the triad of `v`, `u` and `w`, a bit set of its edges.
+fn triad_code(adjacency: &Adjacency, v: usize, u: usize, w: usize) -> usize {
+    [
+        (v, u, 1),
+        (u, v, 2),
+        (v, w, 4),
+        (w, v, 8),
+        (u, w, 16),
+        (w, u, 32),
+    ]
+    .into_iter()
+    .filter(|(source, target, _)| adjacency.has_edge(*source, *target))
+    .map(|(_, _, bit)| bit)
+    .sum()
+}
+
+/// Compute the triad census of a directed graph.
+///
+/// The triad census counts the triples of nodes of the graph by the
+/// isomorphism class of the subgraph they induce, including the triples
+/// which aren't connected. The 16 classes are named as in [`TRIAD_TYPES`],
+/// and the result has a count for each of them in that order. The counts
+/// are computed with the algorithm of Batagelj and Mrvar [1], which only
+/// visits the connected triples.
+///
+/// In an undirected graph every edge is counted as a pair of edges in both
+/// directions.
+///
+/// Arguments:
+///
+/// * `graph` - The graph to compute the triad census of.
+///
+/// # Example
+/// ```rust
+/// use rustworkx_core::motifs::triad_census;
+/// use rustworkx_core::petgraph::graph::DiGraph;
+///
+/// let graph = DiGraph::<(), ()>::from_edges(&[(0, 1), (1, 2), (2, 0), (2, 3)]);
+/// let census = triad_census(&graph);
+/// assert_eq!(census["030C"], 1);
+/// assert_eq!(census["021C"], 1);
+/// assert_eq!(census["021D"], 1);
+/// assert_eq!(census["012"], 1);
+/// assert_eq!(census.values().sum::<usize>(), 4);
+/// ```
+///
+/// [1] V. Batagelj and A. Mrvar, "A subquadratic triad census algorithm for
+/// large sparse networks with small maximum degree", Social Networks 23,
+/// 237-243 (2001).
+pub fn triad_census<G>(graph: G) -> DictMap<&'static str, usize>
+where
+    G: IntoEdgeReferences + IntoNodeIdentifiers + NodeIndexable + GraphProp,
+{
+    let adjacency = Adjacency::new(graph);
+    let num_nodes = adjacency.nodes.len();
+    let mut census = [0usize; 16];
+    for &v in &adjacency.nodes {
+        for &u in &adjacency.neighbors[v] {
+            if u <= v {
+                continue;
+            }
+            let mut neighbors: Vec<usize> = adjacency.neighbors[u]
+                .iter()
+                .chain(adjacency.neighbors[v].iter())
+                .copied()
+                .filter(|&w| w != u && w != v)
+                .collect();
+            neighbors.sort_unstable();
+            neighbors.dedup();
+            // The triads with a single dyad between v and u
+            let dyad = if adjacency.has_edge(v, u) && adjacency.has_edge(u, v) {
+                2
+            } else {
+                1
+            };
+            census[dyad] += num_nodes - neighbors.len() - 2;
+            // The connected triads, each counted once
+            for &w in &neighbors {
+                if u < w || (v < w && w < u && !adjacency.is_neighbor(v, w)) {
+                    census[TRIAD_CODES[triad_code(&adjacency, v, u, w)] - 1] += 1;
+                }
+            }
+        }
+    }
+    let num_nodes = num_nodes as u128;
+    let num_triads = if num_nodes < 3 {
+        0
+    } else {
+        num_nodes * (num_nodes - 1) * (num_nodes - 2) / 6
+    };
+    census[0] = (num_triads - census[1..].iter().map(|&x| x as u128).sum::<u128>()) as usize;
+    TRIAD_TYPES.into_iter().zip(census).collect()
+}
+
+#[cfg(test)]
+mod test_motifs {
+    use super::*;
+    use crate::generators::{complete_graph, cycle_graph, path_graph};
+    use crate::petgraph::graph::{DiGraph, UnGraph};
+
+    #[test]
+    fn test_undirected_size_3() {
+        let graph: UnGraph<(), ()> = complete_graph(Some(5), None, || (), || ()).unwrap();
+        let counts = motif_counts(&graph, 3, None, None).unwrap();
+        assert_eq!(counts.len(), 1);
+        assert_eq!(counts[&vec![(0, 1), (0, 2), (1, 2)]], 10.);
+        let graph: UnGraph<(), ()> = path_graph(Some(6), None, || (), || (), false).unwrap();
+        let counts = motif_counts(&graph, 3, None, None).unwrap();
+        assert_eq!(counts.len(), 1);
+        assert_eq!(counts[&vec![(0, 1), (0, 2)]], 4.);
+    }
+
+    #[test]
+    fn test_undirected_size_4() {
+        let graph: UnGraph<(), ()> = complete_graph(Some(5), None, || (), || ()).unwrap();
+        let counts = motif_counts(&graph, 4, None, None).unwrap();
+        assert_eq!(counts.len(), 1);
+        assert_eq!(counts.values().next(), Some(&5.));
+        let graph: UnGraph<(), ()> = cycle_graph(Some(4), None, || (), || (), false).unwrap();
+        let counts = motif_counts(&graph, 4, None, None).unwrap();
+        assert_eq!(counts.len(), 1);
+        assert_eq!(counts.keys().next().unwrap().len(), 4);
+        // A star with 4 leaves has 4 stars with 3 leaves as subgraphs
+        let graph = UnGraph::<(), ()>::from_edges([(0, 1), (0, 2), (0, 3), (0, 4), (4, 4)]);
+        let counts = motif_counts(&graph, 4, None, None).unwrap();
+        assert_eq!(counts[&vec![(0, 1), (0, 2), (0, 3)]], 4.);
+    }
+
+    #[test]
+    fn test_directed_classes() {
+        // All 13 connected directed triads appear in a complete directed
+        // graph with some edges removed, so count them by brute force
+        let graph = DiGraph::<(), ()>::from_edges([
+            (0, 1),
+            (1, 0),
+            (1, 2),
+            (2, 3),
+            (3, 1),
+            (3, 4),
+            (4, 0),
+            (0, 2),
+            (4, 2),
+            (2, 4),
+        ]);
+        let counts = motif_counts(&graph, 3, None, None).unwrap();
+        let total: f64 = counts.values().sum();
+        let adjacency = Adjacency::new(&graph);
+        let mut connected = 0;
+        for a in 0..5 {
+            for b in a + 1..5 {
+                for c in b + 1..5 {
+                    let links = [(a, b), (a, c), (b, c)]
+                        .iter()
+                        .filter(|(x, y)| adjacency.is_neighbor(*x, *y))
+                        .count();
+                    if links >= 2 {
+                        connected += 1;
+                    }
+                }
+            }
+        }
+        assert_eq!(total, connected as f64);
+        assert!(counts.keys().all(|class| class.len() >= 2));
+    }
+
+    #[test]
+    fn test_sampling() {
+        let graph: UnGraph<(), ()> = complete_graph(Some(12), None, || (), || ()).unwrap();
+        let probabilities = [1., 1., 0.5];
+        let first = motif_counts(&graph, 3, Some(&probabilities), Some(4)).unwrap();
+        let second = motif_counts(&graph, 3, Some(&probabilities), Some(4)).unwrap();
+        assert_eq!(first, second);
+        let estimate = first[&vec![(0, 1), (0, 2), (1, 2)]];
+        assert!((estimate - 220.).abs() < 60.);
+        assert_eq!(estimate % 2., 0.);
+    }
+
+    #[test]
+    fn test_invalid_arguments() {
+        let graph = UnGraph::<(), ()>::from_edges([(0, 1)]);
+        assert_eq!(
+            motif_counts(&graph, 5, None, None),
+            Err(MotifError::InvalidSize(5))
+        );
+        assert_eq!(
+            motif_counts(&graph, 3, Some(&[1., 0.]), None),
+            Err(MotifError::InvalidProbabilities)
+        );
+        assert_eq!(
+            motif_counts(&graph, 3, Some(&[1., 1., 0.]), None),
+            Err(MotifError::InvalidProbabilities)
+        );
+        assert_eq!(
+            motif_significance_profile(&graph, 3, 0, 10, None, None),
+            Err(MotifError::NoRandomizations)
+        );
+    }
+
+    #[test]
+    fn test_significance_profile_normalized() {
+        let graph = DiGraph::<(), ()>::from_edges([
+            (0, 1),
+            (1, 2),
+            (2, 0),
+            (2, 3),
+            (3, 4),
+            (4, 5),
+            (5, 3),
+            (5, 6),
+            (6, 0),
+            (1, 4),
+        ]);
+        let profile = motif_significance_profile(&graph, 3, 30, 10, None, Some(2)).unwrap();
+        let norm: f64 = profile
+            .values()
+            .filter(|stats| !stats.significance.is_nan())
+            .map(|stats| stats.significance.powi(2))
+            .sum();
+        assert!(norm == 0. || (norm - 1.).abs() < 1e-9);
+        let counts = motif_counts(&graph, 3, None, None).unwrap();
+        for (class, count) in counts {
+            assert_eq!(profile[&class].count, count);
+        }
+        assert_eq!(
+            profile,
+            motif_significance_profile(&graph, 3, 30, 10, None, Some(2)).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_triad_census_matches_brute_force() {
+        let graph = DiGraph::<(), ()>::from_edges([
+            (0, 1),
+            (1, 0),
+            (1, 2),
+            (2, 3),
+            (3, 1),
+            (3, 4),
+            (4, 0),
+            (0, 2),
+            (4, 2),
+            (2, 4),
+            (5, 6),
+        ]);
+        let census = triad_census(&graph);
+        let adjacency = Adjacency::new(&graph);
+        let mut expected = [0usize; 16];
+        for a in 0..7 {
+            for b in a + 1..7 {
+                for c in b + 1..7 {
+                    expected[TRIAD_CODES[triad_code(&adjacency, a, b, c)] - 1] += 1;
+                }
+            }
+        }
+        let census: Vec<usize> = census.values().copied().collect();
+        assert_eq!(census, expected);
+        assert_eq!(census.iter().sum::<usize>(), 35);
+    }
+
+    #[test]
+    fn test_triad_census_types() {
+        let cases: [(&[(u32, u32)], &str); 6] = [
+            (&[(1, 0), (1, 2)], "021D"),
+            (&[(0, 1), (2, 1)], "021U"),
+            (&[(0, 1), (1, 2)], "021C"),
+            (&[(0, 1), (1, 2), (0, 2)], "030T"),
+            (&[(0, 1), (1, 0), (1, 2), (2, 1)], "201"),
+            (&[(0, 1), (1, 0), (1, 2), (2, 1), (0, 2), (2, 0)], "300"),
+        ];
+        for (edges, name) in cases {
+            let graph = DiGraph::<(), ()>::from_edges(edges);
+            let census = triad_census(&graph);
+            assert_eq!(census[name], 1, "{}", name);
+        }
+        assert!(triad_census(&DiGraph::<(), ()>::new())
+            .values()
+            .all(|&x| x == 0));
+    }
+}
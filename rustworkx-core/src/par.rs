@@ -0,0 +1,171 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+// The parallel iterators used by the algorithms of this crate. With the
+// `parallel` feature they are rayon's, otherwise they are sequential
+// iterators with the subset of rayon's interface the algorithms use, so the
+// algorithms are written once for both builds.
+
+#[cfg(feature = "parallel")]
+pub(crate) use rayon::prelude::*;
+#[cfg(feature = "parallel")]
+pub(crate) use rayon_cond::CondIterator;
+
+#[cfg(not(feature = "parallel"))]
+pub(crate) use sequential::*;
+
+/// The number of threads the parallel iterators run on.
+#[inline]
+pub(crate) fn current_num_threads() -> usize {
+    #[cfg(feature = "parallel")]
+    {
+        rayon::current_num_threads()
+    }
+    #[cfg(not(feature = "parallel"))]
+    {
+        1
+    }
+}
+
+#[cfg(not(feature = "parallel"))]
+mod sequential {
+    use std::cmp::Ordering;
+    use std::slice::ChunksMut;
+
+    /// A sequential iterator whose adaptors have the signatures of rayon's
+    /// parallel iterator adaptors where they differ from [`Iterator`]'s.
+    pub(crate) struct SeqIter<I>(I);
+
+    impl<I: Iterator> Iterator for SeqIter<I> {
+        type Item = I::Item;
+
+        #[inline]
+        fn next(&mut self) -> Option<I::Item> {
+            self.0.next()
+        }
+
+        #[inline]
+        fn size_hint(&self) -> (usize, Option<usize>) {
+            self.0.size_hint()
+        }
+    }
+
+    impl<I: Iterator> SeqIter<I> {
+        #[inline]
+        pub(crate) fn map<B, F: FnMut(I::Item) -> B>(self, f: F) -> SeqIter<std::iter::Map<I, F>> {
+            SeqIter(self.0.map(f))
+        }
+
+        #[inline]
+        pub(crate) fn filter<P: FnMut(&I::Item) -> bool>(
+            self,
+            predicate: P,
+        ) -> SeqIter<std::iter::Filter<I, P>> {
+            SeqIter(self.0.filter(predicate))
+        }
+
+        #[inline]
+        pub(crate) fn enumerate(self) -> SeqIter<std::iter::Enumerate<I>> {
+            SeqIter(self.0.enumerate())
+        }
+
+        #[inline]
+        pub(crate) fn reduce<ID, OP>(self, identity: ID, op: OP) -> I::Item
+        where
+            ID: Fn() -> I::Item,
+            OP: Fn(I::Item, I::Item) -> I::Item,
+        {
+            self.0.fold(identity(), op)
+        }
+    }
+
+    pub(crate) trait IntoParallelIterator: IntoIterator + Sized {
+        #[inline]
+        fn into_par_iter(self) -> SeqIter<Self::IntoIter> {
+            SeqIter(self.into_iter())
+        }
+    }
+
+    impl<T: IntoIterator> IntoParallelIterator for T {}
+
+    pub(crate) trait IntoParallelRefIterator<'a> {
+        type Iter: Iterator;
+
+        fn par_iter(&'a self) -> SeqIter<Self::Iter>;
+    }
+
+    impl<'a, T: 'a + ?Sized> IntoParallelRefIterator<'a> for T
+    where
+        &'a T: IntoIterator,
+    {
+        type Iter = <&'a T as IntoIterator>::IntoIter;
+
+        #[inline]
+        fn par_iter(&'a self) -> SeqIter<Self::Iter> {
+            SeqIter(self.into_iter())
+        }
+    }
+
+    pub(crate) trait ParallelSliceMut<T> {
+        fn as_mut_slice(&mut self) -> &mut [T];
+
+        #[inline]
+        fn par_sort(&mut self)
+        where
+            T: Ord,
+        {
+            self.as_mut_slice().sort()
+        }
+
+        #[inline]
+        fn par_sort_unstable(&mut self)
+        where
+            T: Ord,
+        {
+            self.as_mut_slice().sort_unstable()
+        }
+
+        #[inline]
+        fn par_sort_by_key<K: Ord, F: Fn(&T) -> K>(&mut self, f: F) {
+            self.as_mut_slice().sort_by_key(f)
+        }
+
+        #[inline]
+        fn par_sort_unstable_by<F: Fn(&T, &T) -> Ordering>(&mut self, compare: F) {
+            self.as_mut_slice().sort_unstable_by(compare)
+        }
+
+        #[inline]
+        fn par_chunks_mut(&mut self, chunk_size: usize) -> SeqIter<ChunksMut<'_, T>> {
+            SeqIter(self.as_mut_slice().chunks_mut(chunk_size))
+        }
+    }
+
+    impl<T> ParallelSliceMut<T> for [T] {
+        #[inline]
+        fn as_mut_slice(&mut self) -> &mut [T] {
+            self
+        }
+    }
+
+    /// The sequential counterpart of `rayon_cond::CondIterator`, which
+    /// always iterates sequentially.
+    pub(crate) struct CondIterator;
+
+    impl CondIterator {
+        #[inline]
+        #[allow(clippy::new_ret_no_self)]
+        pub(crate) fn new<I: IntoIterator>(iterable: I, _parallel: bool) -> SeqIter<I::IntoIter> {
+            SeqIter(iterable.into_iter())
+        }
+    }
+}
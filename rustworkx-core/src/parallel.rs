@@ -0,0 +1,167 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+//! This module contains the control of the number of threads used by the
+//! parallel functions of this crate.
+//!
+//! The parallel functions run on the global [`rayon`] thread pool, which has
+//! a thread per core unless the `RAYON_NUM_THREADS` environment variable is
+//! set. [`with_num_threads`] runs a computation in a thread pool with a given
+//! number of threads instead, so an application can cap the threads used by
+//! a call without changing the global thread pool.
+//!
+//! ```rust
+//! use rustworkx_core::centrality::betweenness_centrality;
+//! use rustworkx_core::parallel::with_num_threads;
+//! use rustworkx_core::petgraph::graph::UnGraph;
+//!
+//! let graph = UnGraph::<(), ()>::from_edges([(0, 1), (1, 2), (2, 3)]);
+//! let output = with_num_threads(Some(2), || betweenness_centrality(&graph, false, false, 1));
+//! assert_eq!(output.unwrap(), vec![Some(0.), Some(2.), Some(2.), Some(0.)]);
+//! ```
+
+use std::sync::{Arc, Mutex};
+
+use rayon::{ThreadPool, ThreadPoolBuildError, ThreadPoolBuilder};
+
+// The thread pool built for the last number of threads requested, so
+// repeated calls with the same number of threads don't start new threads
+// every time. Only one pool is kept, so that calls with many different
+// numbers of threads don't leave their threads running.
+static THREAD_POOL: Mutex<Option<Arc<ThreadPool>>> = Mutex::new(None);
+
+/// Get a thread pool with `num_threads` threads.
+///
+/// The thread pool for the last number of threads requested is kept and
+/// shared by all the callers asking for the same number of threads. A
+/// request for a different number of threads replaces it, and its threads
+/// stop once no caller holds it anymore. If `num_threads` is `0` the pool
+/// has a thread per core, or the number of threads in the
+/// `RAYON_NUM_THREADS` environment variable if it is set.
+///
+/// Arguments:
+///
+/// * `num_threads` - The number of threads in the pool.
+///
+/// # Example
+/// ```rust
+/// use rustworkx_core::parallel::thread_pool;
+///
+/// let pool = thread_pool(3).unwrap();
+/// assert_eq!(pool.current_num_threads(), 3);
+/// ```
+pub fn thread_pool(num_threads: usize) -> Result<Arc<ThreadPool>, ThreadPoolBuildError> {
+    let mut cache = THREAD_POOL.lock().unwrap_or_else(|err| err.into_inner());
+    cached_thread_pool(&mut cache, num_threads)
+}
+
+fn cached_thread_pool(
+    cache: &mut Option<Arc<ThreadPool>>,
+    num_threads: usize,
+) -> Result<Arc<ThreadPool>, ThreadPoolBuildError> {
+    let num_threads = if num_threads == 0 {
+        rayon::current_num_threads()
+    } else {
+        num_threads
+    };
+    if let Some(pool) = cache
+        .as_ref()
+        .filter(|pool| pool.current_num_threads() == num_threads)
+    {
+        return Ok(pool.clone());
+    }
+    let pool = Arc::new(ThreadPoolBuilder::new().num_threads(num_threads).build()?);
+    *cache = Some(pool.clone());
+    Ok(pool)
+}
+
+/// Run `op` with the parallel functions it calls limited to `num_threads`
+/// threads.
+///
+/// `op` runs in the thread pool from [`thread_pool`] for `num_threads` and
+/// its result is returned. If `num_threads` is `None` it runs on the
+/// current thread, and the parallel functions use the global thread pool.
+///
+/// Arguments:
+///
+/// * `num_threads` - The maximum number of threads to use, or `None` to use
+///   the global thread pool.
+/// * `op` - The computation to run.
+///
+/// # Example
+/// ```rust
+/// use rustworkx_core::parallel::with_num_threads;
+///
+/// let threads = with_num_threads(Some(2), rayon::current_num_threads);
+/// assert_eq!(threads.unwrap(), 2);
+/// ```
+pub fn with_num_threads<OP, R>(
+    num_threads: Option<usize>,
+    op: OP,
+) -> Result<R, ThreadPoolBuildError>
+where
+    OP: FnOnce() -> R + Send,
+    R: Send,
+{
+    match num_threads {
+        Some(num_threads) => Ok(thread_pool(num_threads)?.install(op)),
+        None => Ok(op()),
+    }
+}
+
+#[cfg(test)]
+mod test_parallel {
+    use super::*;
+    use rayon::prelude::*;
+
+    #[test]
+    fn test_pools_are_shared() {
+        let mut cache = None;
+        let first = cached_thread_pool(&mut cache, 3).unwrap();
+        let second = cached_thread_pool(&mut cache, 3).unwrap();
+        assert!(Arc::ptr_eq(&first, &second));
+        assert_eq!(first.current_num_threads(), 3);
+        assert_eq!(thread_pool(1).unwrap().current_num_threads(), 1);
+    }
+
+    #[test]
+    fn test_only_last_pool_is_kept() {
+        let mut cache = None;
+        let first = cached_thread_pool(&mut cache, 3).unwrap();
+        let second = cached_thread_pool(&mut cache, 2).unwrap();
+        assert!(Arc::ptr_eq(cache.as_ref().unwrap(), &second));
+        // Only the caller still holds the pool for 3 threads
+        assert_eq!(Arc::strong_count(&first), 1);
+        let third = cached_thread_pool(&mut cache, 3).unwrap();
+        assert!(!Arc::ptr_eq(&first, &third));
+    }
+
+    #[test]
+    fn test_default_size() {
+        assert_eq!(
+            thread_pool(0).unwrap().current_num_threads(),
+            rayon::current_num_threads()
+        );
+    }
+
+    #[test]
+    fn test_with_num_threads() {
+        let res = with_num_threads(Some(2), || {
+            let threads = rayon::current_num_threads();
+            let total: usize = (0..100usize).into_par_iter().sum();
+            (threads, total)
+        });
+        assert_eq!(res.unwrap(), (2, 4950));
+        let res = with_num_threads(None, rayon::current_num_threads);
+        assert_eq!(res.unwrap(), rayon::current_num_threads());
+    }
+}
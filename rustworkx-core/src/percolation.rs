@@ -0,0 +1,470 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+use std::hash::Hash;
+
+use petgraph::unionfind::UnionFind;
+use petgraph::visit::{
+    EdgeCount, EdgeIndexable, EdgeRef, GraphProp, IntoEdgeReferences, IntoEdges,
+    IntoNeighborsDirected, IntoNodeIdentifiers, NodeCount, NodeIndexable,
+};
+use rand::prelude::*;
+use rand_pcg::Pcg64;
+
+use crate::centrality::{betweenness_centrality, edge_betweenness_centrality};
+use crate::rng::rng_from_seed;
+
+/// The number of nodes at which betweenness centrality is computed in
+/// parallel for [`RemovalStrategy::Betweenness`].
+const PARALLEL_THRESHOLD: usize = 50;
+
+/// The order in which [`node_removal_order`] and [`edge_removal_order`]
+/// remove the nodes or edges of a graph.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RemovalStrategy {
+    /// A uniformly random order, with an optional seed for the random
+    /// number generator.
+    Random(Option<u64>),
+    /// By decreasing degree in the original graph. An edge is ranked by the
+    /// product of the degrees of its endpoints.
+    Degree,
+    /// By decreasing betweenness centrality in the original graph.
+    Betweenness,
+}
+
+/// Sort `items` by decreasing score, keeping the order of `items` for ties.
+fn sort_by_score<T, F>(items: &mut [T], score: F)
+where
+    F: Fn(&T) -> f64,
+{
+    items.sort_by(|a, b| score(b).partial_cmp(&score(a)).unwrap());
+}
+
+/// Compute the degree of every node, ignoring the direction of edges.
+fn degrees<G>(graph: G) -> Vec<usize>
+where
+    G: IntoEdgeReferences + NodeIndexable,
+{
+    let mut degrees = vec![0; graph.node_bound()];
+    for edge in graph.edge_references() {
+        degrees[graph.to_index(edge.source())] += 1;
+        degrees[graph.to_index(edge.target())] += 1;
+    }
+    degrees
+}
+
+/// Return the nodes of a graph in the order given by a [`RemovalStrategy`].
+///
+/// Ties between nodes with the same degree or betweenness are broken by the
+/// order of [`IntoNodeIdentifiers::node_identifiers`]. For directed graphs
+/// the degree is the sum of the in and out degree, and betweenness is
+/// computed along directed paths.
+///
+/// Arguments:
+///
+/// * `graph` - The graph to order the nodes of.
+/// * `strategy` - The order of the nodes.
+///
+/// # Example
+/// ```rust
+/// use rustworkx_core::petgraph::graph::{NodeIndex, UnGraph};
+/// use rustworkx_core::percolation::{node_removal_order, RemovalStrategy};
+///
+/// let graph = UnGraph::<(), ()>::from_edges([(0, 1), (1, 2), (1, 3), (3, 4)]);
+/// let order = node_removal_order(&graph, RemovalStrategy::Degree);
+/// assert_eq!(order[..2], [NodeIndex::new(1), NodeIndex::new(3)]);
+/// ```
+pub fn node_removal_order<G>(graph: G, strategy: RemovalStrategy) -> Vec<G::NodeId>
+where
+    G: IntoEdgeReferences
+        + IntoNodeIdentifiers
+        + IntoNeighborsDirected
+        + NodeIndexable
+        + NodeCount
+        + GraphProp
+        + Sync,
+    G::NodeId: Eq + Hash + Send,
+{
+    let mut nodes: Vec<G::NodeId> = graph.node_identifiers().collect();
+    match strategy {
+        RemovalStrategy::Random(seed) => {
+            let mut rng: Pcg64 = rng_from_seed(seed);
+            nodes.shuffle(&mut rng);
+        }
+        RemovalStrategy::Degree => {
+            let degrees = degrees(graph);
+            sort_by_score(&mut nodes, |node| degrees[graph.to_index(*node)] as f64);
+        }
+        RemovalStrategy::Betweenness => {
+            let betweenness = betweenness_centrality(graph, false, false, PARALLEL_THRESHOLD);
+            sort_by_score(&mut nodes, |node| {
+                betweenness[graph.to_index(*node)].unwrap_or(0.)
+            });
+        }
+    }
+    nodes
+}
+
+/// Return the edges of a graph in the order given by a [`RemovalStrategy`].
+///
+/// Ties between edges with the same score are broken by the order of
+/// [`IntoEdgeReferences::edge_references`]. For directed graphs the degree
+/// of a node is the sum of its in and out degree, and betweenness is
+/// computed along directed paths.
+///
+/// Arguments:
+///
+/// * `graph` - The graph to order the edges of.
+/// * `strategy` - The order of the edges.
+///
+/// # Example
+/// ```rust
+/// use rustworkx_core::petgraph::graph::{EdgeIndex, UnGraph};
+/// use rustworkx_core::percolation::{edge_removal_order, RemovalStrategy};
+///
+/// // Two triangles joined by a bridge
+/// let graph = UnGraph::<(), ()>::from_edges([
+///     (0, 1), (1, 2), (2, 0), (2, 3), (3, 4), (4, 5), (5, 3),
+/// ]);
+/// let order = edge_removal_order(&graph, RemovalStrategy::Betweenness);
+/// assert_eq!(order[0], EdgeIndex::new(3));
+/// ```
+pub fn edge_removal_order<G>(graph: G, strategy: RemovalStrategy) -> Vec<G::EdgeId>
+where
+    G: IntoEdgeReferences
+        + IntoEdges
+        + IntoNodeIdentifiers
+        + IntoNeighborsDirected
+        + NodeIndexable
+        + EdgeIndexable
+        + NodeCount
+        + EdgeCount
+        + GraphProp
+        + Sync,
+    G::NodeId: Eq + Hash + Send,
+    G::EdgeId: Eq + Hash + Send,
+{
+    let mut edges: Vec<G::EdgeRef> = graph.edge_references().collect();
+    match strategy {
+        RemovalStrategy::Random(seed) => {
+            let mut rng: Pcg64 = rng_from_seed(seed);
+            edges.shuffle(&mut rng);
+        }
+        RemovalStrategy::Degree => {
+            let degrees = degrees(graph);
+            sort_by_score(&mut edges, |edge| {
+                let source = NodeIndexable::to_index(&graph, edge.source());
+                let target = NodeIndexable::to_index(&graph, edge.target());
+                (degrees[source] * degrees[target]) as f64
+            });
+        }
+        RemovalStrategy::Betweenness => {
+            let betweenness = edge_betweenness_centrality(graph, false, PARALLEL_THRESHOLD);
+            sort_by_score(&mut edges, |edge| {
+                betweenness[EdgeIndexable::to_index(&graph, edge.id())].unwrap_or(0.)
+            });
+        }
+    }
+    edges.into_iter().map(|edge| edge.id()).collect()
+}
+
+/// Connected components that only grow, tracking the size of the largest.
+struct GrowingComponents {
+    components: UnionFind<usize>,
+    sizes: Vec<usize>,
+    largest: usize,
+}
+
+impl GrowingComponents {
+    fn new(bound: usize) -> Self {
+        GrowingComponents {
+            components: UnionFind::new(bound),
+            sizes: vec![0; bound],
+            largest: 0,
+        }
+    }
+
+    fn add_node(&mut self, node: usize) {
+        self.sizes[node] = 1;
+        self.largest = self.largest.max(1);
+    }
+
+    fn add_edge(&mut self, source: usize, target: usize) {
+        let source = self.components.find_mut(source);
+        let target = self.components.find_mut(target);
+        if source != target {
+            let size = self.sizes[source] + self.sizes[target];
+            self.components.union(source, target);
+            self.sizes[self.components.find_mut(source)] = size;
+            self.largest = self.largest.max(size);
+        }
+    }
+}
+
+/// Compute the size of the largest connected component of a graph as its
+/// nodes are removed one by one.
+///
+/// Instead of recomputing the connected components after every removal,
+/// the nodes are added back in reverse order and the components are merged
+/// with a union-find data structure, as proposed by Newman and Ziff [1], so
+/// this runs in `O((|V| + |E|) α(|V|))` time. Directed graphs are treated as
+/// undirected, so the components are the weakly connected components.
+///
+/// Arguments:
+///
+/// * `graph` - The graph to remove the nodes of.
+/// * `order` - The nodes in the order they are removed. It doesn't have to
+///   contain every node of the graph, and nodes that appear more than once
+///   are removed when they first appear.
+///
+/// Returns a vector of `order.len() + 1` sizes, where the entry at position
+/// `k` is the number of nodes of the largest connected component after the
+/// first `k` nodes of `order` were removed.
+///
+/// # Example
+/// ```rust
+/// use rustworkx_core::petgraph::graph::{NodeIndex, UnGraph};
+/// use rustworkx_core::percolation::node_percolation;
+///
+/// let graph = UnGraph::<(), ()>::from_edges([(0, 1), (1, 2), (2, 3), (3, 4)]);
+/// let order = [NodeIndex::new(2), NodeIndex::new(0), NodeIndex::new(3)];
+/// assert_eq!(node_percolation(&graph, &order), vec![5, 2, 2, 1]);
+/// ```
+///
+/// [1]: Newman, M. E. J., and R. M. Ziff. "Efficient Monte Carlo algorithm
+///   and high-precision results for percolation." Physical Review Letters
+///   85.19 (2000): 4104. <https://doi.org/10.1103/PhysRevLett.85.4104>
+pub fn node_percolation<G>(graph: G, order: &[G::NodeId]) -> Vec<usize>
+where
+    G: IntoEdgeReferences + IntoNodeIdentifiers + NodeIndexable,
+{
+    // removed_at[node] is the position in `order` where the node is removed
+    let mut removed_at: Vec<usize> = vec![usize::MAX; graph.node_bound()];
+    for (position, node) in order.iter().enumerate() {
+        let index = graph.to_index(*node);
+        if removed_at[index] == usize::MAX {
+            removed_at[index] = position;
+        }
+    }
+    // An edge is added back with the endpoint that is removed first
+    let mut added_edges: Vec<Vec<(usize, usize)>> = vec![Vec::new(); order.len()];
+    let mut kept_edges: Vec<(usize, usize)> = Vec::new();
+    for edge in graph.edge_references() {
+        let source = graph.to_index(edge.source());
+        let target = graph.to_index(edge.target());
+        match removed_at[source].min(removed_at[target]) {
+            usize::MAX => kept_edges.push((source, target)),
+            position => added_edges[position].push((source, target)),
+        }
+    }
+
+    let mut components = GrowingComponents::new(graph.node_bound());
+    for node in graph.node_identifiers() {
+        let index = graph.to_index(node);
+        if removed_at[index] == usize::MAX {
+            components.add_node(index);
+        }
+    }
+    for (source, target) in kept_edges {
+        components.add_edge(source, target);
+    }
+    let mut sizes: Vec<usize> = vec![0; order.len() + 1];
+    sizes[order.len()] = components.largest;
+    for position in (0..order.len()).rev() {
+        let index = graph.to_index(order[position]);
+        if removed_at[index] == position {
+            components.add_node(index);
+            for (source, target) in &added_edges[position] {
+                components.add_edge(*source, *target);
+            }
+        }
+        sizes[position] = components.largest;
+    }
+    sizes
+}
+
+/// Compute the size of the largest connected component of a graph as its
+/// edges are removed one by one.
+///
+/// Like [`node_percolation`] the edges are added back in reverse order with
+/// a union-find data structure, so this runs in `O((|V| + |E|) α(|V|))`
+/// time. Directed graphs are treated as undirected, so the components are
+/// the weakly connected components.
+///
+/// Arguments:
+///
+/// * `graph` - The graph to remove the edges of.
+/// * `order` - The edges in the order they are removed. It doesn't have to
+///   contain every edge of the graph, and edges that appear more than once
+///   are removed when they first appear.
+///
+/// Returns a vector of `order.len() + 1` sizes, where the entry at position
+/// `k` is the number of nodes of the largest connected component after the
+/// first `k` edges of `order` were removed.
+///
+/// # Example
+/// ```rust
+/// use rustworkx_core::petgraph::graph::{EdgeIndex, UnGraph};
+/// use rustworkx_core::percolation::edge_percolation;
+///
+/// let graph = UnGraph::<(), ()>::from_edges([(0, 1), (1, 2), (2, 0), (2, 3)]);
+/// let order = [EdgeIndex::new(0), EdgeIndex::new(3), EdgeIndex::new(1)];
+/// assert_eq!(edge_percolation(&graph, &order), vec![4, 4, 3, 2]);
+/// ```
+pub fn edge_percolation<G>(graph: G, order: &[G::EdgeId]) -> Vec<usize>
+where
+    G: IntoEdgeReferences + IntoNodeIdentifiers + NodeIndexable + EdgeIndexable,
+{
+    let mut endpoints: Vec<Option<(usize, usize)>> = vec![None; graph.edge_bound()];
+    for edge in graph.edge_references() {
+        endpoints[EdgeIndexable::to_index(&graph, edge.id())] = Some((
+            NodeIndexable::to_index(&graph, edge.source()),
+            NodeIndexable::to_index(&graph, edge.target()),
+        ));
+    }
+    let mut removed_at: Vec<usize> = vec![usize::MAX; graph.edge_bound()];
+    for (position, edge) in order.iter().enumerate() {
+        let index = EdgeIndexable::to_index(&graph, *edge);
+        if removed_at[index] == usize::MAX {
+            removed_at[index] = position;
+        }
+    }
+
+    let mut components = GrowingComponents::new(graph.node_bound());
+    for node in graph.node_identifiers() {
+        components.add_node(NodeIndexable::to_index(&graph, node));
+    }
+    for (index, edge) in endpoints.iter().enumerate() {
+        if let Some((source, target)) = edge {
+            if removed_at[index] == usize::MAX {
+                components.add_edge(*source, *target);
+            }
+        }
+    }
+    let mut sizes: Vec<usize> = vec![0; order.len() + 1];
+    sizes[order.len()] = components.largest;
+    for position in (0..order.len()).rev() {
+        let index = EdgeIndexable::to_index(&graph, order[position]);
+        if removed_at[index] == position {
+            if let Some((source, target)) = endpoints[index] {
+                components.add_edge(source, target);
+            }
+        }
+        sizes[position] = components.largest;
+    }
+    sizes
+}
+
+#[cfg(test)]
+mod test_percolation {
+    use super::{
+        edge_percolation, edge_removal_order, node_percolation, node_removal_order, RemovalStrategy,
+    };
+    use crate::connectivity::connected_components;
+    use crate::generators::{barabasi_albert_graph, star_graph};
+    use crate::petgraph::graph::{DiGraph, EdgeIndex, NodeIndex, UnGraph};
+    use crate::petgraph::stable_graph::StableUnGraph;
+
+    fn largest_component(graph: &StableUnGraph<(), ()>) -> usize {
+        connected_components(graph)
+            .iter()
+            .map(|component| component.len())
+            .max()
+            .unwrap_or(0)
+    }
+
+    #[test]
+    fn test_node_percolation_matches_recomputing() {
+        let graph: StableUnGraph<(), ()> =
+            barabasi_albert_graph(60, 2, Some(3), None, || (), || ()).unwrap();
+        for strategy in [
+            RemovalStrategy::Random(Some(11)),
+            RemovalStrategy::Degree,
+            RemovalStrategy::Betweenness,
+        ] {
+            let order = node_removal_order(&graph, strategy);
+            assert_eq!(order.len(), 60);
+            let sizes = node_percolation(&graph, &order);
+            let mut removed = graph.clone();
+            assert_eq!(sizes[0], largest_component(&removed));
+            for (position, node) in order.iter().enumerate() {
+                removed.remove_node(*node);
+                assert_eq!(sizes[position + 1], largest_component(&removed));
+            }
+        }
+    }
+
+    #[test]
+    fn test_edge_percolation_matches_recomputing() {
+        let graph: StableUnGraph<(), ()> =
+            barabasi_albert_graph(40, 2, Some(5), None, || (), || ()).unwrap();
+        for strategy in [
+            RemovalStrategy::Random(Some(2)),
+            RemovalStrategy::Degree,
+            RemovalStrategy::Betweenness,
+        ] {
+            let order = edge_removal_order(&graph, strategy);
+            assert_eq!(order.len(), graph.edge_count());
+            let sizes = edge_percolation(&graph, &order);
+            let mut removed = graph.clone();
+            for (position, edge) in order.iter().enumerate() {
+                removed.remove_edge(*edge);
+                assert_eq!(sizes[position + 1], largest_component(&removed));
+            }
+            assert_eq!(sizes[order.len()], 1);
+        }
+    }
+
+    #[test]
+    fn test_degree_order() {
+        let graph: UnGraph<(), ()> = star_graph(Some(6), None, || (), || (), false, false).unwrap();
+        let order = node_removal_order(&graph, RemovalStrategy::Degree);
+        assert_eq!(order[0], NodeIndex::new(0));
+        assert_eq!(node_percolation(&graph, &order[..1]), vec![6, 1]);
+    }
+
+    #[test]
+    fn test_random_order_is_seeded() {
+        let graph: UnGraph<(), ()> =
+            star_graph(Some(20), None, || (), || (), false, false).unwrap();
+        let first = node_removal_order(&graph, RemovalStrategy::Random(Some(4)));
+        let second = node_removal_order(&graph, RemovalStrategy::Random(Some(4)));
+        assert_eq!(first, second);
+        let mut sorted = first.clone();
+        sorted.sort();
+        assert_eq!(sorted, graph.node_indices().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_partial_and_repeated_order() {
+        let graph = UnGraph::<(), ()>::from_edges([(0, 1), (1, 2), (2, 3)]);
+        let order = [NodeIndex::new(1), NodeIndex::new(1), NodeIndex::new(3)];
+        assert_eq!(node_percolation(&graph, &order), vec![4, 2, 2, 1]);
+        let order = [EdgeIndex::new(1), EdgeIndex::new(1)];
+        assert_eq!(edge_percolation(&graph, &order), vec![4, 2, 2]);
+    }
+
+    #[test]
+    fn test_directed_is_weak() {
+        let graph = DiGraph::<(), ()>::from_edges([(0, 1), (2, 1), (3, 2)]);
+        let order = [NodeIndex::new(1)];
+        assert_eq!(node_percolation(&graph, &order), vec![4, 2]);
+    }
+
+    #[test]
+    fn test_empty_graph() {
+        let graph = UnGraph::<(), ()>::default();
+        assert_eq!(node_percolation(&graph, &[]), vec![0]);
+        assert_eq!(edge_percolation(&graph, &[]), vec![0]);
+        assert!(node_removal_order(&graph, RemovalStrategy::Betweenness).is_empty());
+    }
+}
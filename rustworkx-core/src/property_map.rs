@@ -0,0 +1,281 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+//! This module contains the [`PropertyMap`] trait for node and edge data
+//! stored outside of a graph.
+//!
+//! Algorithms in this crate read edge weights and other per-edge or per-node
+//! values through callbacks. A property map keeps these values in a separate
+//! container indexed by node or edge id, such as a `Vec` indexed by
+//! [`EdgeIndex`](petgraph::graph::EdgeIndex) or a [`DictMap`], and
+//! [`edge_property_fn`] and [`node_property_fn`] turn it into a callback
+//! that any of these algorithms accept, without a graph payload lookup.
+//!
+//! ```rust
+//! use rustworkx_core::petgraph::graph::{NodeIndex, UnGraph};
+//! use rustworkx_core::property_map::{edge_property_fn, MissingProperty};
+//! use rustworkx_core::shortest_path::dijkstra;
+//! use rustworkx_core::dictmap::DictMap;
+//! use rustworkx_core::petgraph::graph::EdgeIndex;
+//!
+//! let graph = UnGraph::<(), ()>::from_edges([(0, 1), (1, 2), (0, 2)]);
+//! let weights = vec![1.0, 2.0, 5.0];
+//! let res: Result<DictMap<NodeIndex, f64>, MissingProperty<EdgeIndex>> = dijkstra(
+//!     &graph,
+//!     NodeIndex::new(0),
+//!     None,
+//!     edge_property_fn(&weights),
+//!     None,
+//! );
+//! assert_eq!(res.unwrap()[&NodeIndex::new(2)], 3.0);
+//! ```
+
+use std::error::Error;
+use std::fmt::{Debug, Display, Formatter};
+use std::hash::{BuildHasher, Hash};
+
+use hashbrown::HashMap;
+use indexmap::IndexMap;
+use petgraph::graph::GraphIndex;
+use petgraph::visit::EdgeRef;
+
+/// A read-only mapping from node or edge ids to values stored outside of
+/// a graph.
+pub trait PropertyMap<K> {
+    /// The type of the values in the map.
+    type Value;
+
+    /// Get the value for `key`. If the map has no value for `key`, the
+    /// function returns `None`.
+    fn get_property(&self, key: K) -> Option<&Self::Value>;
+}
+
+impl<K: GraphIndex, V> PropertyMap<K> for [V] {
+    type Value = V;
+
+    #[inline]
+    fn get_property(&self, key: K) -> Option<&V> {
+        self.get(key.index())
+    }
+}
+
+impl<K: GraphIndex, V> PropertyMap<K> for Vec<V> {
+    type Value = V;
+
+    #[inline]
+    fn get_property(&self, key: K) -> Option<&V> {
+        self.get(key.index())
+    }
+}
+
+impl<K: Eq + Hash, V, S: BuildHasher> PropertyMap<K> for HashMap<K, V, S> {
+    type Value = V;
+
+    #[inline]
+    fn get_property(&self, key: K) -> Option<&V> {
+        self.get(&key)
+    }
+}
+
+impl<K: Eq + Hash, V, S: BuildHasher> PropertyMap<K> for IndexMap<K, V, S> {
+    type Value = V;
+
+    #[inline]
+    fn get_property(&self, key: K) -> Option<&V> {
+        self.get(&key)
+    }
+}
+
+impl<K, M: PropertyMap<K> + ?Sized> PropertyMap<K> for &M {
+    type Value = M::Value;
+
+    #[inline]
+    fn get_property(&self, key: K) -> Option<&M::Value> {
+        (**self).get_property(key)
+    }
+}
+
+/// Error returned by the callbacks of [`edge_property_fn`] and
+/// [`node_property_fn`] when the property map has no value for an id.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MissingProperty<K>(pub K);
+
+impl<K: Debug> Display for MissingProperty<K> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "The property map has no value for {:?}", self.0)
+    }
+}
+
+impl<K: Debug> Error for MissingProperty<K> {}
+
+/// Create an edge callback that looks up the value of every edge in a
+/// property map.
+///
+/// The returned callback can be passed to any algorithm which takes a
+/// `FnMut(G::EdgeRef) -> Result<K, E>` weight function. It returns a copy
+/// of the value for the id of the edge, or a [`MissingProperty`] error with
+/// the edge id if the map has no value for it.
+///
+/// Arguments:
+///
+/// * `map` - The property map keyed by edge id
+///
+/// # Example
+/// ```rust
+/// use rustworkx_core::petgraph::graph::{EdgeIndex, NodeIndex, UnGraph};
+/// use rustworkx_core::dictmap::*;
+/// use rustworkx_core::property_map::{edge_property_fn, MissingProperty};
+/// use rustworkx_core::shortest_path::dijkstra;
+///
+/// let graph = UnGraph::<(), ()>::from_edges([(0, 1), (1, 2)]);
+/// let mut weights = DictMap::new();
+/// weights.insert(EdgeIndex::new(0), 2);
+/// let res: Result<DictMap<NodeIndex, usize>, MissingProperty<EdgeIndex>> = dijkstra(
+///     &graph,
+///     NodeIndex::new(0),
+///     None,
+///     edge_property_fn(&weights),
+///     None,
+/// );
+/// assert_eq!(res, Err(MissingProperty(EdgeIndex::new(1))));
+/// ```
+pub fn edge_property_fn<R, M>(
+    map: M,
+) -> impl FnMut(R) -> Result<M::Value, MissingProperty<R::EdgeId>>
+where
+    R: EdgeRef,
+    R::EdgeId: Copy,
+    M: PropertyMap<R::EdgeId>,
+    M::Value: Clone,
+{
+    move |edge| {
+        let id = edge.id();
+        map.get_property(id).cloned().ok_or(MissingProperty(id))
+    }
+}
+
+/// Create a node callback that looks up the value of every node in a
+/// property map.
+///
+/// The returned callback can be passed to any algorithm which takes a
+/// `FnMut(G::NodeId) -> Result<K, E>` callback. It returns a copy of the
+/// value for the node, or a [`MissingProperty`] error with the node id if
+/// the map has no value for it.
+///
+/// Arguments:
+///
+/// * `map` - The property map keyed by node id
+///
+/// # Example
+/// ```rust
+/// use rustworkx_core::petgraph::graph::NodeIndex;
+/// use rustworkx_core::property_map::{node_property_fn, MissingProperty};
+///
+/// let labels = vec!["a", "b"];
+/// let mut label_fn = node_property_fn(&labels);
+/// assert_eq!(label_fn(NodeIndex::<u32>::new(1)), Ok("b"));
+/// assert_eq!(label_fn(NodeIndex::new(2)), Err(MissingProperty(NodeIndex::new(2))));
+/// ```
+pub fn node_property_fn<N, M>(map: M) -> impl FnMut(N) -> Result<M::Value, MissingProperty<N>>
+where
+    N: Copy,
+    M: PropertyMap<N>,
+    M::Value: Clone,
+{
+    move |node| map.get_property(node).cloned().ok_or(MissingProperty(node))
+}
+
+#[cfg(test)]
+mod test_property_map {
+    use super::*;
+    use crate::dictmap::*;
+    use crate::shortest_path::dijkstra;
+    use crate::spanning_tree::minimum_spanning_edges;
+    use petgraph::graph::{EdgeIndex, NodeIndex, UnGraph};
+
+    #[test]
+    fn test_vec_and_slice() {
+        let weights = vec![1.0, 2.0];
+        assert_eq!(weights.get_property(EdgeIndex::<u32>::new(1)), Some(&2.0));
+        assert_eq!(weights.get_property(EdgeIndex::<u32>::new(2)), None);
+        let slice: &[f64] = &weights;
+        assert_eq!(slice.get_property(NodeIndex::<u32>::new(0)), Some(&1.0));
+    }
+
+    #[test]
+    fn test_hash_maps() {
+        let mut dict: DictMap<&str, usize> = DictMap::new();
+        dict.insert("a", 1);
+        assert_eq!(dict.get_property("a"), Some(&1));
+        assert_eq!(dict.get_property("b"), None);
+        let mut map: HashMap<usize, usize> = HashMap::new();
+        map.insert(3, 4);
+        let mut lookup = node_property_fn(&map);
+        assert_eq!(lookup(3), Ok(4));
+    }
+
+    #[test]
+    fn test_dijkstra_with_edge_map() {
+        // The graph payloads are ignored in favor of the property map
+        let graph = UnGraph::<(), f64>::from_edges([(0, 1, 100.), (1, 2, 100.), (0, 2, 100.)]);
+        let weights = vec![1.0, 2.0, 5.0];
+        let res: Result<DictMap<NodeIndex, f64>, MissingProperty<EdgeIndex>> = dijkstra(
+            &graph,
+            NodeIndex::new(0),
+            None,
+            edge_property_fn(&weights),
+            None,
+        );
+        let res = res.unwrap();
+        assert_eq!(res[&NodeIndex::new(1)], 1.0);
+        assert_eq!(res[&NodeIndex::new(2)], 3.0);
+    }
+
+    #[test]
+    fn test_missing_edge() {
+        let graph = UnGraph::<(), ()>::from_edges([(0, 1), (1, 2)]);
+        let weights = vec![1.0];
+        let res: Result<DictMap<NodeIndex, f64>, MissingProperty<EdgeIndex>> = dijkstra(
+            &graph,
+            NodeIndex::new(0),
+            None,
+            edge_property_fn(&weights),
+            None,
+        );
+        assert_eq!(res, Err(MissingProperty(EdgeIndex::new(1))));
+        assert_eq!(
+            MissingProperty(EdgeIndex::<u32>::new(1)).to_string(),
+            "The property map has no value for EdgeIndex(1)"
+        );
+    }
+
+    #[test]
+    fn test_spanning_tree_with_edge_map() {
+        let graph = UnGraph::<(), ()>::from_edges([(0, 1), (1, 2), (0, 2)]);
+        let weights = vec![3.0, 1.0, 2.0];
+        let tree = minimum_spanning_edges(&graph, edge_property_fn(&weights), 1000).unwrap();
+        assert_eq!(tree.len(), 2);
+        assert!(!tree.contains(&EdgeIndex::new(0)));
+    }
+
+    #[test]
+    fn test_node_map() {
+        let mut labels: DictMap<NodeIndex, &str> = DictMap::new();
+        labels.insert(NodeIndex::new(1), "b");
+        let mut label_fn = node_property_fn(labels);
+        assert_eq!(label_fn(NodeIndex::new(1)), Ok("b"));
+        assert_eq!(
+            label_fn(NodeIndex::new(0)),
+            Err(MissingProperty(NodeIndex::new(0)))
+        );
+    }
+}
@@ -0,0 +1,228 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+use std::hash::Hash;
+
+use hashbrown::HashMap;
+use petgraph::data::{Build, Create};
+use petgraph::visit::{Data, EdgeRef, GraphProp, IntoEdgeReferences, IntoNodeIdentifiers};
+
+use crate::dictmap::*;
+
+type QuotientResult<G, N, E> =
+    Result<(G, HashMap<N, <G as petgraph::visit::GraphBase>::NodeId>), E>;
+
+/// Constructs the quotient graph of a graph for an equivalence relation on
+/// its nodes.
+///
+/// The nodes of the graph are grouped into blocks by the key `block_fn`
+/// returns for each of them, and every block becomes a single node of the
+/// quotient graph. All the edges between the nodes of two blocks are merged
+/// into a single edge between the two nodes of the quotient graph. For
+/// directed graphs the edges from the first block to the second block and
+/// the edges from the second block to the first block are merged separately.
+///
+/// The nodes of the quotient graph are created in the order the blocks are
+/// first seen when iterating over the nodes of `graph`, and the edges in the
+/// order the pairs of blocks are first seen when iterating over its edges.
+///
+/// Arguments:
+///
+/// * `graph` - The input graph.
+/// * `block_fn` - A callable that will be passed every node of `graph` and is
+///   expected to return a `Result` with the key of the block of that node.
+///   Nodes with equal keys are in the same block.
+/// * `node_weight_fn` - A callable that will be passed the nodes of a block
+///   and is expected to return a `Result` with the weight of the node of the
+///   quotient graph for that block.
+/// * `edge_weight_fn` - A callable that will be passed the edges between two
+///   blocks and is expected to return a `Result` with the weight of the edge
+///   of the quotient graph between them.
+/// * `self_loops` - If `true` the edges within a block are merged into a
+///   self-loop on the node of the block, otherwise they are dropped.
+///
+/// Returns the quotient graph and a map from the nodes of `graph` to the
+/// nodes of the quotient graph.
+///
+/// # Example
+/// ```rust
+/// use std::convert::Infallible;
+/// use rustworkx_core::petgraph;
+/// use rustworkx_core::petgraph::graph::NodeIndex;
+/// use rustworkx_core::quotient_graph::quotient_graph;
+///
+/// // A path 0 - 1 - 2 - 3 with the blocks {0, 1} and {2, 3}
+/// let graph = petgraph::graph::UnGraph::<(), f64>::from_edges(&[
+///     (0, 1, 1.),
+///     (1, 2, 2.),
+///     (2, 3, 3.),
+/// ]);
+/// let res: Result<(petgraph::graph::UnGraph<usize, f64>, _), Infallible> = quotient_graph(
+///     &graph,
+///     |node| Ok(node.index() / 2),
+///     |nodes| Ok(nodes.len()),
+///     |edges| Ok(edges.iter().map(|e| *e.weight()).sum()),
+///     true,
+/// );
+/// let (quotient, node_map) = res.unwrap();
+/// assert_eq!(quotient.node_count(), 2);
+/// assert_eq!(node_map[&NodeIndex::new(1)], node_map[&NodeIndex::new(0)]);
+/// let weights: Vec<f64> = quotient.edge_weights().copied().collect();
+/// assert_eq!(weights, vec![1., 2., 3.]);
+/// ```
+pub fn quotient_graph<K, G, B, P, F, H, E>(
+    graph: K,
+    mut block_fn: P,
+    mut node_weight_fn: F,
+    mut edge_weight_fn: H,
+    self_loops: bool,
+) -> QuotientResult<G, K::NodeId, E>
+where
+    K: IntoNodeIdentifiers + IntoEdgeReferences + GraphProp,
+    K::NodeId: Hash + Eq,
+    G: Build + Create + Data,
+    B: Hash + Eq,
+    P: FnMut(K::NodeId) -> Result<B, E>,
+    F: FnMut(&[K::NodeId]) -> Result<G::NodeWeight, E>,
+    H: FnMut(&[K::EdgeRef]) -> Result<G::EdgeWeight, E>,
+{
+    let mut blocks: DictMap<B, Vec<K::NodeId>> = DictMap::default();
+    let mut block_of: HashMap<K::NodeId, usize> = HashMap::new();
+    for node in graph.node_identifiers() {
+        let entry = blocks.entry(block_fn(node)?);
+        block_of.insert(node, entry.index());
+        entry.or_default().push(node);
+    }
+
+    let mut block_edges: DictMap<(usize, usize), Vec<K::EdgeRef>> = DictMap::default();
+    for edge in graph.edge_references() {
+        let source = block_of[&edge.source()];
+        let target = block_of[&edge.target()];
+        if source == target && !self_loops {
+            continue;
+        }
+        let key = if graph.is_directed() || source <= target {
+            (source, target)
+        } else {
+            (target, source)
+        };
+        block_edges.entry(key).or_default().push(edge);
+    }
+
+    let mut out_graph = G::with_capacity(blocks.len(), block_edges.len());
+    let mut block_nodes: Vec<G::NodeId> = Vec::with_capacity(blocks.len());
+    for nodes in blocks.values() {
+        block_nodes.push(out_graph.add_node(node_weight_fn(nodes)?));
+    }
+    for ((source, target), edges) in block_edges {
+        let weight = edge_weight_fn(&edges)?;
+        out_graph.add_edge(block_nodes[source], block_nodes[target], weight);
+    }
+    let node_map = block_of
+        .into_iter()
+        .map(|(node, block)| (node, block_nodes[block]))
+        .collect();
+    Ok((out_graph, node_map))
+}
+
+#[cfg(test)]
+mod test_quotient_graph {
+    use super::quotient_graph;
+    use petgraph::graph::{DiGraph, NodeIndex, UnGraph};
+    use petgraph::visit::EdgeRef;
+    use std::convert::Infallible;
+
+    #[test]
+    fn test_undirected_merges_both_directions() {
+        // The edges 0 - 1 and 3 - 2 join the same blocks, and the self-loop
+        // on 2 is dropped
+        let graph = UnGraph::<(), u32>::from_edges([(0, 1, 1), (3, 2, 2), (2, 2, 4)]);
+        let res: Result<(UnGraph<Vec<usize>, u32>, _), Infallible> = quotient_graph(
+            &graph,
+            |node| Ok(node.index() % 2),
+            |nodes| Ok(nodes.iter().map(|n| n.index()).collect()),
+            |edges| Ok(edges.iter().map(|e| *e.weight()).sum()),
+            false,
+        );
+        let (quotient, node_map) = res.unwrap();
+        assert_eq!(
+            quotient.node_weights().collect::<Vec<_>>(),
+            vec![&vec![0, 2], &vec![1, 3]]
+        );
+        assert_eq!(quotient.edge_count(), 1);
+        assert_eq!(
+            quotient.edge_weights().copied().collect::<Vec<_>>(),
+            vec![3]
+        );
+        assert_eq!(node_map[&NodeIndex::new(3)], NodeIndex::new(1));
+    }
+
+    #[test]
+    fn test_directed_keeps_directions() {
+        let graph = DiGraph::<(), ()>::from_edges([(0, 2), (2, 0), (1, 3), (0, 1)]);
+        let res: Result<(DiGraph<usize, usize>, _), Infallible> = quotient_graph(
+            &graph,
+            |node| Ok(node.index() < 2),
+            |nodes| Ok(nodes.len()),
+            |edges| Ok(edges.len()),
+            false,
+        );
+        let (quotient, _) = res.unwrap();
+        let mut edges: Vec<(usize, usize, usize)> = quotient
+            .edge_references()
+            .map(|e| (e.source().index(), e.target().index(), *e.weight()))
+            .collect();
+        edges.sort_unstable();
+        assert_eq!(edges, vec![(0, 1, 2), (1, 0, 1)]);
+    }
+
+    #[test]
+    fn test_self_loops() {
+        let graph = UnGraph::<(), ()>::from_edges([(0, 1), (1, 2), (2, 3)]);
+        let res: Result<(UnGraph<(), usize>, _), Infallible> = quotient_graph(
+            &graph,
+            |node| Ok(node.index() / 2),
+            |_| Ok(()),
+            |edges| Ok(edges.len()),
+            true,
+        );
+        let (quotient, _) = res.unwrap();
+        let edges: Vec<(usize, usize, usize)> = quotient
+            .edge_references()
+            .map(|e| (e.source().index(), e.target().index(), *e.weight()))
+            .collect();
+        assert_eq!(edges, vec![(0, 0, 1), (0, 1, 1), (1, 1, 1)]);
+    }
+
+    #[test]
+    fn test_singleton_blocks() {
+        let graph = UnGraph::<(), ()>::from_edges([(0, 1), (1, 2)]);
+        let res: Result<(UnGraph<(), ()>, _), Infallible> =
+            quotient_graph(&graph, Ok, |_| Ok(()), |_| Ok(()), false);
+        let (quotient, _) = res.unwrap();
+        assert_eq!(quotient.node_count(), 3);
+        assert_eq!(quotient.edge_count(), 2);
+    }
+
+    #[test]
+    fn test_callback_error() {
+        let graph = UnGraph::<(), ()>::from_edges([(0, 1)]);
+        let res: Result<(UnGraph<(), ()>, _), &str> = quotient_graph(
+            &graph,
+            |_| Err::<usize, _>("error"),
+            |_| Ok(()),
+            |_| Ok(()),
+            false,
+        );
+        assert_eq!(res.err(), Some("error"));
+    }
+}
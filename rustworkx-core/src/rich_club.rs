@@ -0,0 +1,251 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+use crate::rng::rng_from_seed;
+use petgraph::stable_graph::{NodeIndex, StableUnGraph};
+use petgraph::visit::{EdgeRef, IntoEdgeReferences, IntoNodeIdentifiers, NodeIndexable};
+use rand::prelude::*;
+use rand_pcg::Pcg64;
+
+use crate::dictmap::*;
+use crate::graph_ext::DoubleEdgeSwapUndirected;
+
+/// Compute the degree of every node, ignoring self-loops.
+fn degrees<G>(graph: G) -> Vec<usize>
+where
+    G: IntoEdgeReferences + NodeIndexable,
+{
+    let mut degrees = vec![0; graph.node_bound()];
+    for edge in graph.edge_references() {
+        if edge.source() != edge.target() {
+            degrees[graph.to_index(edge.source())] += 1;
+            degrees[graph.to_index(edge.target())] += 1;
+        }
+    }
+    degrees
+}
+
+/// Compute the rich-club coefficient of a graph for every degree `k`.
+///
+/// The rich-club coefficient for a degree `k` is the density of the subgraph
+/// of the nodes with a degree greater than `k`,
+///
+/// ```text
+/// phi(k) = 2 E_k / (N_k (N_k - 1))
+/// ```
+///
+/// where `N_k` is the number of nodes with a degree greater than `k` and
+/// `E_k` is the number of edges between them. It's only defined when there
+/// are at least two such nodes, so the result has a value for every `k` from
+/// `0` up to, but not including, the second largest degree of the graph.
+///
+/// The graph is treated as a simple undirected graph: self-loops are ignored
+/// and parallel edges aren't expected.
+///
+/// Arguments:
+///
+/// * `graph` - The undirected graph to compute the coefficients for.
+///
+/// # Example
+/// ```rust
+/// use rustworkx_core::petgraph::graph::UnGraph;
+/// use rustworkx_core::rich_club::rich_club_coefficient;
+///
+/// // A triangle with a pendant node on each corner
+/// let graph = UnGraph::<(), ()>::from_edges(&[
+///     (0, 1), (1, 2), (2, 0), (0, 3), (1, 4), (2, 5),
+/// ]);
+/// let rich_club = rich_club_coefficient(&graph);
+/// assert_eq!(rich_club[&0], 0.4);
+/// assert_eq!(rich_club[&1], 1.);
+/// assert_eq!(rich_club[&2], 1.);
+/// assert_eq!(rich_club.len(), 3);
+/// ```
+pub fn rich_club_coefficient<G>(graph: G) -> DictMap<usize, f64>
+where
+    G: IntoEdgeReferences + IntoNodeIdentifiers + NodeIndexable,
+{
+    let degrees = degrees(graph);
+    let max_degree = degrees.iter().copied().max().unwrap_or(0);
+    // nodes[k] is the number of nodes with degree k and edges[k] is the
+    // number of edges whose endpoints have a minimum degree of k
+    let mut nodes = vec![0usize; max_degree + 1];
+    for node in graph.node_identifiers() {
+        nodes[degrees[graph.to_index(node)]] += 1;
+    }
+    let mut edges = vec![0usize; max_degree + 1];
+    for edge in graph.edge_references() {
+        if edge.source() != edge.target() {
+            let source = degrees[graph.to_index(edge.source())];
+            let target = degrees[graph.to_index(edge.target())];
+            edges[source.min(target)] += 1;
+        }
+    }
+
+    let mut rich_club = DictMap::new();
+    let mut num_nodes = 0;
+    let mut num_edges = 0;
+    let mut suffix: Vec<(usize, usize)> = Vec::with_capacity(max_degree + 1);
+    for k in (0..=max_degree).rev() {
+        // Nodes and edges with a degree greater than k
+        suffix.push((num_nodes, num_edges));
+        num_nodes += nodes[k];
+        num_edges += edges[k];
+    }
+    for (k, (num_nodes, num_edges)) in suffix.into_iter().rev().enumerate() {
+        if num_nodes < 2 {
+            break;
+        }
+        let pairs = (num_nodes * (num_nodes - 1)) as f64;
+        rich_club.insert(k, 2. * num_edges as f64 / pairs);
+    }
+    rich_club
+}
+
+/// Compute the normalized rich-club coefficient of a graph for every
+/// degree `k`.
+///
+/// The normalized rich-club coefficient is the rich-club coefficient of the
+/// graph, as computed by [`rich_club_coefficient`], divided by the average
+/// rich-club coefficient of randomizations of the graph that preserve the
+/// degree of every node. The randomizations are done with
+/// [`DoubleEdgeSwapUndirected::double_edge_swap`], and a value greater than
+/// `1` means the high degree nodes of the graph are more densely connected
+/// than expected from the degrees alone.
+///
+/// The coefficient is only returned for the degrees it's defined for in the
+/// graph, and is `NaN` or infinite for a degree `k` if none of the random
+/// graphs has an edge between nodes with a degree greater than `k`.
+///
+/// Arguments:
+///
+/// * `graph` - The undirected graph to compute the coefficients for. It's
+///   treated as a simple graph.
+/// * `num_randomizations` - The number of random graphs to average over. If
+///   it's `0` every coefficient is `NaN`.
+/// * `swaps_per_edge` - The number of double edge swaps done to randomize the
+///   graph, per edge of the graph.
+/// * `seed` - An optional seed to use for the random number generator.
+///
+/// # Example
+/// ```rust
+/// use rustworkx_core::generators::barabasi_albert_graph;
+/// use rustworkx_core::petgraph::graph::UnGraph;
+/// use rustworkx_core::rich_club::{normalized_rich_club_coefficient, rich_club_coefficient};
+///
+/// let graph: UnGraph<(), ()> =
+///     barabasi_albert_graph(100, 3, Some(1), None, || (), || ()).unwrap();
+/// let normalized = normalized_rich_club_coefficient(&graph, 5, 10, Some(7));
+/// assert_eq!(normalized.len(), rich_club_coefficient(&graph).len());
+/// ```
+pub fn normalized_rich_club_coefficient<G>(
+    graph: G,
+    num_randomizations: usize,
+    swaps_per_edge: usize,
+    seed: Option<u64>,
+) -> DictMap<usize, f64>
+where
+    G: IntoEdgeReferences + IntoNodeIdentifiers + NodeIndexable,
+{
+    let mut rich_club = rich_club_coefficient(graph);
+    let mut random_graph: StableUnGraph<(), ()> =
+        StableUnGraph::with_capacity(graph.node_bound(), 0);
+    for _ in 0..graph.node_bound() {
+        random_graph.add_node(());
+    }
+    for edge in graph.edge_references() {
+        let source = graph.to_index(edge.source());
+        let target = graph.to_index(edge.target());
+        if source != target {
+            random_graph.add_edge(NodeIndex::new(source), NodeIndex::new(target), ());
+        }
+    }
+    let num_swaps = swaps_per_edge * random_graph.edge_count();
+    let mut rng: Pcg64 = rng_from_seed(seed);
+
+    let mut random_rich_club: Vec<f64> = vec![0.; rich_club.len()];
+    for _ in 0..num_randomizations {
+        // Each randomization continues from the previous one, which is
+        // already a random graph with the same degrees
+        random_graph.double_edge_swap(num_swaps, 10 * num_swaps, Some(rng.gen()));
+        let random = rich_club_coefficient(&random_graph);
+        for (k, value) in random_rich_club.iter_mut().enumerate() {
+            *value += random.get(&k).copied().unwrap_or(0.);
+        }
+    }
+    for (k, value) in rich_club.values_mut().enumerate() {
+        *value /= random_rich_club[k] / num_randomizations as f64;
+    }
+    rich_club
+}
+
+#[cfg(test)]
+mod test_rich_club {
+    use super::{normalized_rich_club_coefficient, rich_club_coefficient};
+    use crate::generators::{barabasi_albert_graph, complete_graph, star_graph};
+    use crate::petgraph::graph::UnGraph;
+    use crate::petgraph::stable_graph::StableUnGraph;
+
+    #[test]
+    fn test_complete_graph() {
+        let graph: UnGraph<(), ()> = complete_graph(Some(5), None, || (), || ()).unwrap();
+        let rich_club = rich_club_coefficient(&graph);
+        assert_eq!(rich_club.len(), 4);
+        assert!(rich_club.values().all(|value| *value == 1.));
+    }
+
+    #[test]
+    fn test_star_graph() {
+        // Only the hub has a degree greater than 1
+        let graph: UnGraph<(), ()> = star_graph(Some(5), None, || (), || (), false, false).unwrap();
+        let rich_club = rich_club_coefficient(&graph);
+        assert_eq!(rich_club.len(), 1);
+        assert_eq!(rich_club[&0], 0.4);
+    }
+
+    #[test]
+    fn test_ignores_self_loops() {
+        let graph = UnGraph::<(), ()>::from_edges([(0, 1), (1, 2), (2, 2)]);
+        let rich_club = rich_club_coefficient(&graph);
+        assert_eq!(rich_club.len(), 1);
+        assert_eq!(rich_club[&0], 2. / 3.);
+    }
+
+    #[test]
+    fn test_graph_with_holes() {
+        let mut graph = StableUnGraph::<(), ()>::from_edges([(0, 1), (1, 2), (2, 0), (2, 3)]);
+        graph.remove_node(3.into());
+        let rich_club = rich_club_coefficient(&graph);
+        assert_eq!(rich_club.len(), 2);
+        assert_eq!(rich_club[&1], 1.);
+    }
+
+    #[test]
+    fn test_empty_graph() {
+        let graph = UnGraph::<(), ()>::default();
+        assert!(rich_club_coefficient(&graph).is_empty());
+        assert!(normalized_rich_club_coefficient(&graph, 3, 10, Some(1)).is_empty());
+    }
+
+    #[test]
+    fn test_normalized_seed() {
+        let graph: UnGraph<(), ()> =
+            barabasi_albert_graph(60, 2, Some(3), None, || (), || ()).unwrap();
+        let first = normalized_rich_club_coefficient(&graph, 3, 10, Some(11));
+        let second = normalized_rich_club_coefficient(&graph, 3, 10, Some(11));
+        assert_eq!(first, second);
+        assert_eq!(first.len(), rich_club_coefficient(&graph).len());
+        // The coefficient for k = 0 only depends on the number of nodes and
+        // edges, which the randomizations preserve
+        assert!((first[&0] - 1.).abs() < 1e-12);
+    }
+}
@@ -0,0 +1,525 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+//! Random samplers that pick a representative set of nodes of a large graph.
+//!
+//! Every sampler takes an optional `seed` and returns the same sample for
+//! the same seed. The induced subgraph of the sampled nodes can then be
+//! built with any subgraph function of the graph type.
+
+use std::collections::VecDeque;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::hash::Hash;
+
+use hashbrown::HashSet;
+use petgraph::visit::{IntoEdgeReferences, IntoNeighbors, IntoNodeIdentifiers, NodeCount};
+use rand::prelude::*;
+
+use crate::rng::{gen_index, rng_from_seed};
+
+/// The number of steps a random walk may take without visiting a new node
+/// before it's restarted from a new node.
+const STUCK_STEPS: usize = 100;
+
+/// Error returned by the samplers for invalid arguments.
+#[derive(Debug, PartialEq, Eq)]
+pub enum SamplingError {
+    /// More nodes were requested than there are in the graph.
+    NotEnoughNodes { requested: usize, available: usize },
+    /// More edges were requested than there are in the graph.
+    NotEnoughEdges { requested: usize, available: usize },
+    /// A probability is outside of its allowed range.
+    InvalidProbability,
+}
+
+impl Display for SamplingError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SamplingError::NotEnoughNodes {
+                requested,
+                available,
+            } => write!(
+                f,
+                "Can't sample {} nodes from a graph with {} nodes",
+                requested, available
+            ),
+            SamplingError::NotEnoughEdges {
+                requested,
+                available,
+            } => write!(
+                f,
+                "Can't sample {} edges from a graph with {} edges",
+                requested, available
+            ),
+            SamplingError::InvalidProbability => {
+                write!(f, "The probability is outside of its allowed range")
+            }
+        }
+    }
+}
+
+impl Error for SamplingError {}
+
+/// Move a uniformly random sample of `k` items to the front of `items`.
+fn partial_shuffle<T, R: Rng>(items: &mut [T], k: usize, rng: &mut R) {
+    for i in 0..k {
+        let j = i + gen_index(rng, items.len() - i);
+        items.swap(i, j);
+    }
+}
+
+/// Pick a random node that isn't in `visited`.
+///
+/// There must be such a node.
+fn random_unvisited<N: Copy + Hash + Eq, R: Rng>(
+    nodes: &[N],
+    visited: &HashSet<N>,
+    rng: &mut R,
+) -> N {
+    loop {
+        let node = nodes[gen_index(rng, nodes.len())];
+        if !visited.contains(&node) {
+            return node;
+        }
+    }
+}
+
+/// Sample nodes of a graph uniformly at random.
+///
+/// Every set of `num_nodes` nodes is equally likely to be sampled.
+///
+/// Arguments:
+///
+/// * `graph` - The graph to sample.
+/// * `num_nodes` - The number of nodes to sample.
+/// * `seed` - An optional seed for the random number generator.
+///
+/// Returns the sampled nodes in the order they were sampled, or an error if
+/// the graph has fewer than `num_nodes` nodes.
+///
+/// # Example
+/// ```rust
+/// use rustworkx_core::petgraph::graph::UnGraph;
+/// use rustworkx_core::sampling::sample_nodes;
+///
+/// let graph = UnGraph::<(), ()>::from_edges([(0, 1), (1, 2), (2, 3), (3, 4)]);
+/// let nodes = sample_nodes(&graph, 3, Some(42)).unwrap();
+/// assert_eq!(nodes.len(), 3);
+/// assert_eq!(nodes, sample_nodes(&graph, 3, Some(42)).unwrap());
+/// ```
+pub fn sample_nodes<G>(
+    graph: G,
+    num_nodes: usize,
+    seed: Option<u64>,
+) -> Result<Vec<G::NodeId>, SamplingError>
+where
+    G: IntoNodeIdentifiers,
+{
+    let mut nodes: Vec<G::NodeId> = graph.node_identifiers().collect();
+    if num_nodes > nodes.len() {
+        return Err(SamplingError::NotEnoughNodes {
+            requested: num_nodes,
+            available: nodes.len(),
+        });
+    }
+    let mut rng = rng_from_seed(seed);
+    partial_shuffle(&mut nodes, num_nodes, &mut rng);
+    nodes.truncate(num_nodes);
+    Ok(nodes)
+}
+
+/// Sample edges of a graph uniformly at random.
+///
+/// Every set of `num_edges` edges is equally likely to be sampled. The
+/// endpoints of the sampled edges are a sample of nodes which favors nodes
+/// of high degree, and their induced subgraph is the induced edge sample of
+/// Ahmed, Neville and Kompella [1].
+///
+/// Arguments:
+///
+/// * `graph` - The graph to sample.
+/// * `num_edges` - The number of edges to sample.
+/// * `seed` - An optional seed for the random number generator.
+///
+/// Returns the sampled edges in the order they were sampled, or an error if
+/// the graph has fewer than `num_edges` edges.
+///
+/// # Example
+/// ```rust
+/// use rustworkx_core::petgraph::graph::UnGraph;
+/// use rustworkx_core::sampling::sample_edges;
+///
+/// let graph = UnGraph::<(), ()>::from_edges([(0, 1), (1, 2), (2, 3), (3, 4)]);
+/// let edges = sample_edges(&graph, 2, Some(42)).unwrap();
+/// assert_eq!(edges.len(), 2);
+/// ```
+///
+/// [1]: Ahmed, Nesreen K., Jennifer Neville, and Ramana Kompella.
+///   "Network sampling: From static to streaming graphs."
+///   ACM Transactions on Knowledge Discovery from Data 8.2 (2014): 1-56.
+pub fn sample_edges<G>(
+    graph: G,
+    num_edges: usize,
+    seed: Option<u64>,
+) -> Result<Vec<G::EdgeRef>, SamplingError>
+where
+    G: IntoEdgeReferences,
+{
+    let mut edges: Vec<G::EdgeRef> = graph.edge_references().collect();
+    if num_edges > edges.len() {
+        return Err(SamplingError::NotEnoughEdges {
+            requested: num_edges,
+            available: edges.len(),
+        });
+    }
+    let mut rng = rng_from_seed(seed);
+    partial_shuffle(&mut edges, num_edges, &mut rng);
+    edges.truncate(num_edges);
+    Ok(edges)
+}
+
+/// Sample nodes of a graph with a random walk with restarts.
+///
+/// The walk starts at a random node, and at every step either goes back to
+/// its starting node with probability `restart_probability` or moves to a
+/// random neighbor of its current node, following the direction of edges in
+/// a directed graph. A walk that has no neighbor to move to goes back to its
+/// starting node. If a walk hasn't visited a new node in 100 steps it's
+/// stuck, for example in a small connected component, and the walk starts
+/// again from a random node it hasn't visited. This is the random walk
+/// sampler of Leskovec and Faloutsos [1].
+///
+/// Arguments:
+///
+/// * `graph` - The graph to sample.
+/// * `num_nodes` - The number of nodes to sample.
+/// * `restart_probability` - The probability in `[0, 1]` of going back to
+///   the starting node at every step.
+/// * `seed` - An optional seed for the random number generator.
+///
+/// Returns the sampled nodes in the order they were first visited, or an
+/// error if the graph has fewer than `num_nodes` nodes or the probability
+/// is invalid.
+///
+/// # Example
+/// ```rust
+/// use rustworkx_core::petgraph::graph::UnGraph;
+/// use rustworkx_core::sampling::random_walk_sample;
+///
+/// let graph = UnGraph::<(), ()>::from_edges([(0, 1), (1, 2), (2, 3), (3, 4)]);
+/// let nodes = random_walk_sample(&graph, 3, 0.15, Some(42)).unwrap();
+/// assert_eq!(nodes.len(), 3);
+/// ```
+///
+/// [1]: Leskovec, Jure, and Christos Faloutsos.
+///   "Sampling from large graphs."
+///   Proceedings of the 12th ACM SIGKDD International Conference on
+///   Knowledge Discovery and Data Mining (2006): 631-636.
+pub fn random_walk_sample<G>(
+    graph: G,
+    num_nodes: usize,
+    restart_probability: f64,
+    seed: Option<u64>,
+) -> Result<Vec<G::NodeId>, SamplingError>
+where
+    G: IntoNeighbors + IntoNodeIdentifiers + NodeCount,
+    G::NodeId: Hash + Eq,
+{
+    if !(0. ..=1.).contains(&restart_probability) {
+        return Err(SamplingError::InvalidProbability);
+    }
+    if num_nodes > graph.node_count() {
+        return Err(SamplingError::NotEnoughNodes {
+            requested: num_nodes,
+            available: graph.node_count(),
+        });
+    }
+    let mut sample: Vec<G::NodeId> = Vec::with_capacity(num_nodes);
+    if num_nodes == 0 {
+        return Ok(sample);
+    }
+    let nodes: Vec<G::NodeId> = graph.node_identifiers().collect();
+    let mut visited: HashSet<G::NodeId> = HashSet::with_capacity(num_nodes);
+    let mut rng = rng_from_seed(seed);
+    let mut start = nodes[gen_index(&mut rng, nodes.len())];
+    let mut current = start;
+    visited.insert(start);
+    sample.push(start);
+    let mut steps_without_new = 0;
+    while sample.len() < num_nodes {
+        if steps_without_new >= STUCK_STEPS {
+            start = random_unvisited(&nodes, &visited, &mut rng);
+            current = start;
+            visited.insert(start);
+            sample.push(start);
+            steps_without_new = 0;
+            continue;
+        }
+        steps_without_new += 1;
+        if rng.gen::<f64>() < restart_probability {
+            current = start;
+            continue;
+        }
+        let neighbors: Vec<G::NodeId> = graph.neighbors(current).collect();
+        if neighbors.is_empty() {
+            current = start;
+            continue;
+        }
+        current = neighbors[gen_index(&mut rng, neighbors.len())];
+        if visited.insert(current) {
+            sample.push(current);
+            steps_without_new = 0;
+        }
+    }
+    Ok(sample)
+}
+
+/// Sample nodes of a graph with a forest fire.
+///
+/// The fire starts at a random node. Every burning node sets fire to a
+/// random number of its neighbors that aren't burned yet, following the
+/// direction of edges in a directed graph, and the number is drawn from a
+/// geometric distribution with mean `p / (1 - p)` for the burn probability
+/// `p`. When the fire dies out it starts again from a random node that
+/// isn't burned. This is the forest fire sampler of Leskovec and
+/// Faloutsos [1], with forward burning only.
+///
+/// Arguments:
+///
+/// * `graph` - The graph to sample.
+/// * `num_nodes` - The number of nodes to sample.
+/// * `burn_probability` - The forward burning probability in `[0, 1)`.
+/// * `seed` - An optional seed for the random number generator.
+///
+/// Returns the sampled nodes in the order they were burned, or an error if
+/// the graph has fewer than `num_nodes` nodes or the probability is invalid.
+///
+/// # Example
+/// ```rust
+/// use rustworkx_core::petgraph::graph::UnGraph;
+/// use rustworkx_core::sampling::forest_fire_sample;
+///
+/// let graph = UnGraph::<(), ()>::from_edges([(0, 1), (1, 2), (2, 3), (3, 4)]);
+/// let nodes = forest_fire_sample(&graph, 3, 0.7, Some(42)).unwrap();
+/// assert_eq!(nodes.len(), 3);
+/// ```
+///
+/// [1]: Leskovec, Jure, and Christos Faloutsos.
+///   "Sampling from large graphs."
+///   Proceedings of the 12th ACM SIGKDD International Conference on
+///   Knowledge Discovery and Data Mining (2006): 631-636.
+pub fn forest_fire_sample<G>(
+    graph: G,
+    num_nodes: usize,
+    burn_probability: f64,
+    seed: Option<u64>,
+) -> Result<Vec<G::NodeId>, SamplingError>
+where
+    G: IntoNeighbors + IntoNodeIdentifiers + NodeCount,
+    G::NodeId: Hash + Eq,
+{
+    if !(0. ..1.).contains(&burn_probability) {
+        return Err(SamplingError::InvalidProbability);
+    }
+    if num_nodes > graph.node_count() {
+        return Err(SamplingError::NotEnoughNodes {
+            requested: num_nodes,
+            available: graph.node_count(),
+        });
+    }
+    let mut sample: Vec<G::NodeId> = Vec::with_capacity(num_nodes);
+    let nodes: Vec<G::NodeId> = graph.node_identifiers().collect();
+    let mut burned: HashSet<G::NodeId> = HashSet::with_capacity(num_nodes);
+    let mut rng = rng_from_seed(seed);
+    let mut burning: VecDeque<G::NodeId> = VecDeque::new();
+    while sample.len() < num_nodes {
+        let node = match burning.pop_front() {
+            Some(node) => node,
+            None => {
+                let node = random_unvisited(&nodes, &burned, &mut rng);
+                burned.insert(node);
+                sample.push(node);
+                node
+            }
+        };
+        let mut count = 0;
+        while rng.gen::<f64>() < burn_probability {
+            count += 1;
+        }
+        let mut neighbors: Vec<G::NodeId> = graph
+            .neighbors(node)
+            .filter(|neighbor| !burned.contains(neighbor))
+            .collect();
+        // Parallel edges would make a neighbor more likely to be burned
+        let mut seen = HashSet::with_capacity(neighbors.len());
+        neighbors.retain(|neighbor| seen.insert(*neighbor));
+        let count = count.min(neighbors.len());
+        partial_shuffle(&mut neighbors, count, &mut rng);
+        for neighbor in neighbors.into_iter().take(count) {
+            if sample.len() == num_nodes {
+                break;
+            }
+            burned.insert(neighbor);
+            sample.push(neighbor);
+            burning.push_back(neighbor);
+        }
+    }
+    Ok(sample)
+}
+
+#[cfg(test)]
+mod test_sampling {
+    use super::*;
+    use crate::generators::{complete_graph, grid_graph, path_graph};
+    use petgraph::graph::{DiGraph, NodeIndex, UnGraph};
+    use petgraph::visit::EdgeRef;
+
+    fn assert_distinct(nodes: &[NodeIndex]) {
+        let set: HashSet<NodeIndex> = nodes.iter().copied().collect();
+        assert_eq!(set.len(), nodes.len());
+    }
+
+    #[test]
+    fn test_sample_nodes() {
+        let graph: UnGraph<(), ()> = path_graph(Some(20), None, || (), || (), false).unwrap();
+        let nodes = sample_nodes(&graph, 8, Some(7)).unwrap();
+        assert_eq!(nodes.len(), 8);
+        assert_distinct(&nodes);
+        assert_eq!(nodes, sample_nodes(&graph, 8, Some(7)).unwrap());
+        let mut all = sample_nodes(&graph, 20, Some(7)).unwrap();
+        all.sort();
+        assert_eq!(all, graph.node_indices().collect::<Vec<_>>());
+        assert_eq!(
+            sample_nodes(&graph, 21, None),
+            Err(SamplingError::NotEnoughNodes {
+                requested: 21,
+                available: 20
+            })
+        );
+    }
+
+    #[test]
+    fn test_sample_nodes_is_uniform() {
+        let graph: UnGraph<(), ()> = path_graph(Some(4), None, || (), || (), false).unwrap();
+        let mut counts = [0; 4];
+        for seed in 0..4000 {
+            for node in sample_nodes(&graph, 1, Some(seed)).unwrap() {
+                counts[node.index()] += 1;
+            }
+        }
+        assert!(counts.iter().all(|count| (850..1150).contains(count)));
+    }
+
+    #[test]
+    fn test_sample_edges() {
+        let graph: UnGraph<(), ()> =
+            grid_graph(Some(4), Some(4), None, || (), || (), false).unwrap();
+        let edges = sample_edges(&graph, 10, Some(3)).unwrap();
+        let ids: HashSet<_> = edges.iter().map(|edge| edge.id()).collect();
+        assert_eq!(ids.len(), 10);
+        assert_eq!(
+            sample_edges(&graph, 25, None).map(|edges| edges.len()),
+            Err(SamplingError::NotEnoughEdges {
+                requested: 25,
+                available: 24
+            })
+        );
+    }
+
+    #[test]
+    fn test_random_walk_connected() {
+        let graph: UnGraph<(), ()> =
+            grid_graph(Some(5), Some(5), None, || (), || (), false).unwrap();
+        let nodes = random_walk_sample(&graph, 10, 0.15, Some(1)).unwrap();
+        assert_eq!(nodes.len(), 10);
+        assert_distinct(&nodes);
+        // Without being stuck every new node is adjacent to an earlier one
+        for (i, node) in nodes.iter().enumerate().skip(1) {
+            assert!(nodes[..i]
+                .iter()
+                .any(|other| graph.find_edge(*node, *other).is_some()));
+        }
+        assert_eq!(
+            nodes,
+            random_walk_sample(&graph, 10, 0.15, Some(1)).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_random_walk_disconnected() {
+        // Isolated nodes force the walk to restart from new nodes
+        let mut graph: UnGraph<(), ()> = UnGraph::default();
+        for _ in 0..6 {
+            graph.add_node(());
+        }
+        let mut nodes = random_walk_sample(&graph, 6, 0.15, Some(2)).unwrap();
+        nodes.sort();
+        assert_eq!(nodes, graph.node_indices().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_random_walk_directed_sink() {
+        let graph = DiGraph::<(), ()>::from_edges([(0, 1), (1, 2)]);
+        let nodes = random_walk_sample(&graph, 3, 0., Some(5)).unwrap();
+        assert_eq!(nodes.len(), 3);
+    }
+
+    #[test]
+    fn test_forest_fire() {
+        let graph: UnGraph<(), ()> = complete_graph(Some(30), None, || (), || ()).unwrap();
+        let nodes = forest_fire_sample(&graph, 12, 0.7, Some(4)).unwrap();
+        assert_eq!(nodes.len(), 12);
+        assert_distinct(&nodes);
+        assert_eq!(nodes, forest_fire_sample(&graph, 12, 0.7, Some(4)).unwrap());
+        // A burn probability of 0 never spreads the fire
+        let graph: UnGraph<(), ()> = path_graph(Some(5), None, || (), || (), false).unwrap();
+        let mut nodes = forest_fire_sample(&graph, 5, 0., Some(4)).unwrap();
+        nodes.sort();
+        assert_eq!(nodes, graph.node_indices().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_invalid_probability() {
+        let graph: UnGraph<(), ()> = path_graph(Some(5), None, || (), || (), false).unwrap();
+        assert_eq!(
+            random_walk_sample(&graph, 2, 1.5, None),
+            Err(SamplingError::InvalidProbability)
+        );
+        assert_eq!(
+            random_walk_sample(&graph, 2, f64::NAN, None),
+            Err(SamplingError::InvalidProbability)
+        );
+        assert_eq!(
+            forest_fire_sample(&graph, 2, 1., None),
+            Err(SamplingError::InvalidProbability)
+        );
+        assert_eq!(
+            forest_fire_sample(&graph, 6, 0.5, None),
+            Err(SamplingError::NotEnoughNodes {
+                requested: 6,
+                available: 5
+            })
+        );
+    }
+
+    #[test]
+    fn test_empty_sample() {
+        let graph: UnGraph<(), ()> = UnGraph::default();
+        assert!(sample_nodes(&graph, 0, None).unwrap().is_empty());
+        assert!(sample_edges(&graph, 0, None).unwrap().is_empty());
+        assert!(random_walk_sample(&graph, 0, 0.15, None)
+            .unwrap()
+            .is_empty());
+        assert!(forest_fire_sample(&graph, 0, 0.7, None).unwrap().is_empty());
+    }
+}
@@ -0,0 +1,313 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+use std::collections::BinaryHeap;
+
+use hashbrown::HashMap;
+use petgraph::visit::{EdgeIndexable, EdgeRef, IntoEdges, IntoNodeIdentifiers, NodeIndexable};
+
+use crate::min_scored::MinScored;
+use crate::par::CondIterator;
+use crate::spectral::CsrMatrix;
+
+/// The number of source nodes searched from between the rows found so far
+/// are appended to the output, which bounds the memory used for rows that
+/// aren't in the output yet.
+const CHUNK_SIZE: usize = 4096;
+
+/// Find the nodes at a distance of at most `cutoff` from `start`, as
+/// `(node index, distance)` pairs sorted by node index without `start`.
+///
+/// Only the nodes that are reached are stored, so the search takes time and
+/// memory proportional to the size of the neighborhood instead of the graph.
+fn dijkstra_within<G>(graph: G, start: G::NodeId, cutoff: f64, weights: &[f64]) -> Vec<(usize, f64)>
+where
+    G: IntoEdges + NodeIndexable + EdgeIndexable,
+{
+    let start_index = NodeIndexable::to_index(&graph, start);
+    let mut scores: HashMap<usize, f64> = HashMap::new();
+    let mut done: Vec<(usize, f64)> = Vec::new();
+    let mut visit_next = BinaryHeap::new();
+    scores.insert(start_index, 0.0);
+    visit_next.push(MinScored(0.0, start));
+    while let Some(MinScored(score, node)) = visit_next.pop() {
+        let index = NodeIndexable::to_index(&graph, node);
+        if scores[&index] < score {
+            continue;
+        }
+        if index != start_index {
+            done.push((index, score));
+        }
+        for edge in graph.edges(node) {
+            let next = edge.target();
+            let next_score = score + weights[EdgeIndexable::to_index(&graph, edge.id())];
+            if next_score > cutoff {
+                continue;
+            }
+            let next_index = NodeIndexable::to_index(&graph, next);
+            match scores.get(&next_index) {
+                Some(current) if *current <= next_score => (),
+                _ => {
+                    scores.insert(next_index, next_score);
+                    visit_next.push(MinScored(next_score, next));
+                }
+            }
+        }
+    }
+    done.sort_unstable_by_key(|(index, _)| *index);
+    done
+}
+
+/// Compute the lengths of the shortest paths between all pairs of nodes
+/// that are at most a distance apart.
+///
+/// Instead of the length of the shortest path between every pair of nodes,
+/// which takes memory quadratic in the number of nodes, only the pairs of
+/// nodes at a distance of at most `cutoff` are stored, in a [`CsrMatrix`].
+/// Every search from a source node only visits the nodes within the cutoff,
+/// and the searches run in parallel in chunks of source nodes whose results
+/// are appended to the matrix as they complete.
+///
+/// Arguments:
+///
+/// * `graph` - The graph to compute the shortest path lengths of.
+/// * `cutoff` - The largest distance between the pairs of nodes to store.
+///   If it's `None` the distances of all pairs of nodes connected by a path
+///   are stored.
+/// * `parallel_threshold` - The number of nodes of `graph` at which the
+///   searches run in parallel.
+/// * `weight_fn` - A callable that is passed the `EdgeRef` of every edge and
+///   returns its weight, which must be non-negative.
+///
+/// Returns a matrix with a row and a column for every node index up to
+/// [`NodeIndexable::node_bound`], where the entry in row `i` and column `j`
+/// is the length of the shortest path from `i` to `j`, if it's at most
+/// `cutoff`. The diagonal entries aren't stored, but entries of zero for
+/// other pairs of nodes are.
+///
+/// # Example
+/// ```rust
+/// use rustworkx_core::petgraph::graph::UnGraph;
+/// use rustworkx_core::shortest_path::all_pairs_dijkstra_within;
+///
+/// let graph = UnGraph::<(), f64>::from_edges([(0, 1, 1.0), (1, 2, 2.0), (2, 3, 1.0)]);
+/// let res: Result<_, ()> =
+///     all_pairs_dijkstra_within(&graph, Some(2.0), 50, |e| Ok(*e.weight()));
+/// let distances = res.unwrap();
+/// assert_eq!(distances.indptr, vec![0, 1, 3, 5, 6]);
+/// assert_eq!(distances.indices, vec![1, 0, 2, 1, 3, 2]);
+/// assert_eq!(distances.data, vec![1.0, 1.0, 2.0, 2.0, 1.0, 1.0]);
+/// ```
+pub fn all_pairs_dijkstra_within<G, F, E>(
+    graph: G,
+    cutoff: Option<f64>,
+    parallel_threshold: usize,
+    weight_fn: F,
+) -> Result<CsrMatrix, E>
+where
+    G: IntoEdges + IntoNodeIdentifiers + NodeIndexable + EdgeIndexable + Sync,
+    G::NodeId: Send,
+    F: FnMut(G::EdgeRef) -> Result<f64, E>,
+{
+    all_pairs_dijkstra_within_with_progress(
+        graph,
+        cutoff,
+        parallel_threshold,
+        weight_fn,
+        usize::MAX,
+        |_, _| Ok(()),
+    )
+}
+
+/// Compute the lengths of the shortest paths between all pairs of nodes
+/// that are at most a distance apart, reporting the progress of the
+/// computation.
+///
+/// This is [`all_pairs_dijkstra_within`] with a `progress` callback.
+///
+/// Arguments:
+///
+/// * `graph` - The graph to compute the shortest path lengths of.
+/// * `cutoff` - The largest distance between the pairs of nodes to store.
+///   If it's `None` the distances of all pairs of nodes connected by a path
+///   are stored.
+/// * `parallel_threshold` - The number of nodes of `graph` at which the
+///   searches run in parallel.
+/// * `weight_fn` - A callable that is passed the `EdgeRef` of every edge and
+///   returns its weight, which must be non-negative.
+/// * `progress_interval` - The number of source nodes between the calls to
+///   `progress`
+/// * `progress` - The callback which is passed the number of source nodes
+///   done and the total number of source nodes
+pub fn all_pairs_dijkstra_within_with_progress<G, F, P, E>(
+    graph: G,
+    cutoff: Option<f64>,
+    parallel_threshold: usize,
+    mut weight_fn: F,
+    progress_interval: usize,
+    mut progress: P,
+) -> Result<CsrMatrix, E>
+where
+    G: IntoEdges + IntoNodeIdentifiers + NodeIndexable + EdgeIndexable + Sync,
+    G::NodeId: Send,
+    F: FnMut(G::EdgeRef) -> Result<f64, E>,
+    P: FnMut(usize, usize) -> Result<(), E>,
+{
+    let mut weights = vec![0.0; graph.edge_bound()];
+    for edge in graph.edge_references() {
+        weights[EdgeIndexable::to_index(&graph, edge.id())] = weight_fn(edge)?;
+    }
+    let cutoff = cutoff.unwrap_or(f64::INFINITY);
+    let size = graph.node_bound();
+    let mut indptr: Vec<usize> = vec![0; size + 1];
+    let mut indices: Vec<usize> = Vec::new();
+    let mut data: Vec<f64> = Vec::new();
+    let mut nodes: Vec<G::NodeId> = graph.node_identifiers().collect();
+    nodes.sort_unstable_by_key(|node| NodeIndexable::to_index(&graph, *node));
+    let parallel = nodes.len() >= parallel_threshold;
+    let progress_interval = progress_interval.max(1);
+    let mut next_report = progress_interval;
+    let mut done = 0;
+    let mut row = 0;
+    for chunk in nodes.chunks(progress_interval.min(CHUNK_SIZE)) {
+        let mut rows: Vec<(usize, Vec<(usize, f64)>)> = CondIterator::new(chunk.to_vec(), parallel)
+            .map(|node| {
+                (
+                    NodeIndexable::to_index(&graph, node),
+                    dijkstra_within(graph, node, cutoff, &weights),
+                )
+            })
+            .collect();
+        // Every chunk covers the rows after the rows of the previous chunks
+        rows.sort_unstable_by_key(|(index, _)| *index);
+        for (index, entries) in rows {
+            while row < index {
+                row += 1;
+                indptr[row] = indices.len();
+            }
+            for (column, distance) in entries {
+                indices.push(column);
+                data.push(distance);
+            }
+            row += 1;
+            indptr[row] = indices.len();
+        }
+        done += chunk.len();
+        if done >= next_report || done == nodes.len() {
+            progress(done, nodes.len())?;
+            next_report = done.saturating_add(progress_interval);
+        }
+    }
+    while row < size {
+        row += 1;
+        indptr[row] = indices.len();
+    }
+    Ok(CsrMatrix {
+        size,
+        indptr,
+        indices,
+        data,
+    })
+}
+
+#[cfg(test)]
+mod test_all_pairs_dijkstra_within {
+    use super::{all_pairs_dijkstra_within, all_pairs_dijkstra_within_with_progress};
+    use crate::generators::{grid_graph, path_graph};
+    use crate::petgraph::graph::{DiGraph, UnGraph};
+    use crate::petgraph::stable_graph::StableGraph;
+    use crate::petgraph::visit::EdgeRef;
+    use crate::shortest_path::dijkstra;
+
+    #[test]
+    fn test_matches_dijkstra() {
+        let graph: UnGraph<(), ()> =
+            grid_graph(Some(6), Some(7), None, || (), || (), false).unwrap();
+        let weight = |e: petgraph::graph::EdgeReference<()>| -> Result<f64, ()> {
+            Ok(1.0 + (e.id().index() % 3) as f64)
+        };
+        for cutoff in [0.0, 2.5, 4.0, 100.0] {
+            let distances = all_pairs_dijkstra_within(&graph, Some(cutoff), 10, weight).unwrap();
+            assert_eq!(distances.size, 42);
+            for source in graph.node_indices() {
+                let expected: Result<Vec<Option<f64>>, ()> =
+                    dijkstra(&graph, source, None, weight, None);
+                let expected: Vec<(usize, f64)> = expected
+                    .unwrap()
+                    .into_iter()
+                    .enumerate()
+                    .filter_map(|(target, d)| d.map(|d| (target, d)))
+                    .filter(|(target, d)| *target != source.index() && *d <= cutoff)
+                    .collect();
+                let row: Vec<(usize, f64)> = distances.row(source.index()).collect();
+                assert_eq!(row, expected);
+            }
+        }
+    }
+
+    #[test]
+    fn test_no_cutoff_directed() {
+        let graph = DiGraph::<(), f64>::from_edges([(0, 1, 1.0), (1, 2, 1.5), (2, 0, 0.0)]);
+        let res: Result<_, ()> = all_pairs_dijkstra_within(&graph, None, 50, |e| Ok(*e.weight()));
+        let distances = res.unwrap();
+        assert_eq!(distances.indptr, vec![0, 2, 4, 6]);
+        assert_eq!(distances.indices, vec![1, 2, 0, 2, 0, 1]);
+        // Zero distances between different nodes are stored
+        assert_eq!(distances.data, vec![1.0, 2.5, 1.5, 1.5, 0.0, 1.0]);
+    }
+
+    #[test]
+    fn test_removed_nodes() {
+        let mut graph: StableGraph<(), f64, petgraph::Undirected> =
+            StableGraph::from(UnGraph::<(), f64>::from_edges([
+                (0, 1, 1.0),
+                (1, 2, 1.0),
+                (2, 3, 1.0),
+                (3, 4, 1.0),
+            ]));
+        graph.remove_node(2.into());
+        graph.remove_node(4.into());
+        let res: Result<_, ()> =
+            all_pairs_dijkstra_within(&graph, Some(1.0), 50, |e| Ok(*e.weight()));
+        let distances = res.unwrap();
+        assert_eq!(distances.size, 4);
+        assert_eq!(distances.indptr, vec![0, 1, 2, 2, 2]);
+        assert_eq!(distances.indices, vec![1, 0]);
+    }
+
+    #[test]
+    fn test_chunks_and_progress() {
+        let graph: UnGraph<(), ()> = path_graph(Some(10), None, || (), || (), false).unwrap();
+        let expected: Result<_, ()> = all_pairs_dijkstra_within(&graph, Some(3.0), 50, |_| Ok(1.0));
+        let mut reports = Vec::new();
+        let res: Result<_, ()> = all_pairs_dijkstra_within_with_progress(
+            &graph,
+            Some(3.0),
+            2,
+            |_| Ok(1.0),
+            4,
+            |done, total| {
+                reports.push((done, total));
+                Ok(())
+            },
+        );
+        assert_eq!(res, expected);
+        assert_eq!(reports, vec![(4, 10), (8, 10), (10, 10)]);
+    }
+
+    #[test]
+    fn test_error() {
+        let graph = UnGraph::<(), ()>::from_edges([(0, 1)]);
+        let res: Result<_, &str> = all_pairs_dijkstra_within(&graph, None, 50, |_| Err("bad"));
+        assert_eq!(res, Err("bad"));
+    }
+}
@@ -26,6 +26,7 @@ use petgraph::visit::{EdgeRef, IntoEdges, NodeIndexable, VisitMap, Visitable};
 use crate::dictmap::*;
 use crate::distancemap::DistanceMap;
 use crate::min_scored::MinScored;
+use crate::property_map::{edge_property_fn, MissingProperty, PropertyMap};
 
 /// Dijkstra's shortest path algorithm.
 ///
@@ -165,10 +166,181 @@ where
     Ok(scores)
 }
 
+/// Dijkstra's shortest path algorithm with edge weights from a property map.
+///
+/// This is [`dijkstra`] with the cost of every edge read from `weights`, a
+/// [`PropertyMap`] keyed by edge id, such as a `Vec` indexed by
+/// [`EdgeIndex`](petgraph::graph::EdgeIndex), instead of from a callback.
+/// Edge costs must be non-negative.
+///
+/// If `goal` is not [`None`], then the algorithm terminates once the `goal` node's
+/// cost is calculated.
+///
+/// If `path` is not [`None`], then the algorithm will mutate the input
+/// [`DictMap`] to insert an entry where the index is the dest node index
+/// the value is a Vec of node indices of the path starting with `start` and
+/// ending at the index.
+///
+/// Returns a [`DistanceMap`] that maps `NodeId` to path cost, or a
+/// [`MissingProperty`] error with the id of the first edge reached which has
+/// no value in `weights`.
+/// # Example
+/// ```rust
+/// use rustworkx_core::petgraph::prelude::*;
+/// use rustworkx_core::dictmap::DictMap;
+/// use rustworkx_core::property_map::MissingProperty;
+/// use rustworkx_core::shortest_path::dijkstra_with_property_map;
+///
+/// let graph = UnGraph::<(), ()>::from_edges([(0, 1), (1, 2), (0, 2)]);
+/// let weights = vec![1.0, 2.0, 5.0];
+/// let res: Result<DictMap<NodeIndex, f64>, MissingProperty<EdgeIndex>> =
+///     dijkstra_with_property_map(&graph, NodeIndex::new(0), None, &weights, None);
+/// assert_eq!(res.unwrap()[&NodeIndex::new(2)], 3.0);
+/// ```
+pub fn dijkstra_with_property_map<G, M, K, S>(
+    graph: G,
+    start: G::NodeId,
+    goal: Option<G::NodeId>,
+    weights: M,
+    path: Option<&mut DictMap<G::NodeId, Vec<G::NodeId>>>,
+) -> Result<S, MissingProperty<G::EdgeId>>
+where
+    G: IntoEdges + Visitable + NodeIndexable,
+    G::NodeId: Eq + Hash,
+    G::EdgeId: Copy,
+    M: PropertyMap<G::EdgeId, Value = K>,
+    K: Measure + Copy,
+    S: DistanceMap<G::NodeId, K>,
+{
+    dijkstra(graph, start, goal, edge_property_fn(weights), path)
+}
+
+/// Dijkstra's shortest path algorithm from several sources.
+///
+/// Compute the length of the shortest path from the nearest node of
+/// `sources` to every node reachable from one of them.
+///
+/// The graph should be [`Visitable`] and implement [`IntoEdges`]. The function
+/// `edge_cost` should return the cost for a particular edge, which is used
+/// to compute path costs. Edge costs must be non-negative.
+///
+/// If `path` is not [`None`], then the algorithm will mutate the input
+/// [`DictMap`] to insert an entry where the index is the dest node index
+/// the value is a Vec of node indices of the path starting with the nearest
+/// source and ending at the index. When a node is as close to several
+/// sources, the path from the first of them in `sources` is used.
+///
+/// Returns a [`DistanceMap`] that maps `NodeId` to path cost.
+/// # Example
+/// ```rust
+/// use rustworkx_core::petgraph::prelude::*;
+/// use rustworkx_core::dictmap::*;
+/// use rustworkx_core::shortest_path::multi_source_dijkstra;
+/// use rustworkx_core::Result;
+///
+/// let graph = UnGraph::<(), ()>::from_edges([(0, 1), (1, 2), (2, 3), (3, 4)]);
+/// let mut paths = DictMap::new();
+/// let res: Result<DictMap<NodeIndex, usize>> = multi_source_dijkstra(
+///     &graph, [NodeIndex::new(0), NodeIndex::new(4)], |_| Ok(1), Some(&mut paths)
+/// );
+/// let lengths: Vec<usize> = (0..5).map(|n| res.as_ref().unwrap()[&NodeIndex::new(n)]).collect();
+/// assert_eq!(lengths, vec![0, 1, 2, 1, 0]);
+/// // Node 2 is as close to both sources and gets the path from the first one
+/// assert_eq!(paths[&NodeIndex::new(2)][0], NodeIndex::new(0));
+/// ```
+pub fn multi_source_dijkstra<G, I, F, K, E, S>(
+    graph: G,
+    sources: I,
+    edge_cost: F,
+    path: Option<&mut DictMap<G::NodeId, Vec<G::NodeId>>>,
+) -> Result<S, E>
+where
+    G: IntoEdges + Visitable + NodeIndexable,
+    G::NodeId: Eq + Hash,
+    I: IntoIterator<Item = G::NodeId>,
+    F: FnMut(G::EdgeRef) -> Result<K, E>,
+    K: Measure + Copy,
+    S: DistanceMap<G::NodeId, K>,
+{
+    let (scores, _) = nearest_source_dijkstra(graph, sources, edge_cost, path)?;
+    Ok(scores)
+}
+
+/// The implementation of [`multi_source_dijkstra`], which also returns the
+/// position in `sources` of the nearest source of every node reached.
+pub(crate) fn nearest_source_dijkstra<G, I, F, K, E, S>(
+    graph: G,
+    sources: I,
+    mut edge_cost: F,
+    mut path: Option<&mut DictMap<G::NodeId, Vec<G::NodeId>>>,
+) -> Result<(S, DictMap<G::NodeId, usize>), E>
+where
+    G: IntoEdges + Visitable + NodeIndexable,
+    G::NodeId: Eq + Hash,
+    I: IntoIterator<Item = G::NodeId>,
+    F: FnMut(G::EdgeRef) -> Result<K, E>,
+    K: Measure + Copy,
+    S: DistanceMap<G::NodeId, K>,
+{
+    let mut visited = graph.visit_map();
+    let mut scores: S = S::build(graph.node_bound());
+    let mut origins: DictMap<G::NodeId, usize> = DictMap::new();
+    let mut visit_next = BinaryHeap::new();
+    let zero_score = K::default();
+    for (rank, start) in sources.into_iter().enumerate() {
+        if origins.contains_key(&start) {
+            continue;
+        }
+        scores.put_item(start, zero_score);
+        origins.insert(start, rank);
+        visit_next.push(MinScored((zero_score, rank), start));
+        if let Some(path_mut) = &mut path {
+            path_mut.insert(start, vec![start]);
+        }
+    }
+    // Nodes are ranked by their distance and then the position of their
+    // nearest source, so ties between sources are broken the same way for
+    // every node
+    while let Some(MinScored((node_score, rank), node)) = visit_next.pop() {
+        if visited.is_visited(&node) {
+            continue;
+        }
+        for edge in graph.edges(node) {
+            let next = edge.target();
+            if visited.is_visited(&next) {
+                continue;
+            }
+            let cost = edge_cost(edge)?;
+            let next_score = node_score + cost;
+            let improves = match scores.get_item(next) {
+                Some(current_score) => {
+                    next_score < *current_score
+                        || (next_score == *current_score && rank < origins[&next])
+                }
+                None => true,
+            };
+            if improves {
+                scores.put_item(next, next_score);
+                origins.insert(next, rank);
+                visit_next.push(MinScored((next_score, rank), next));
+                if let Some(path_mut) = &mut path {
+                    let mut node_path = path_mut.get(&node).unwrap().clone();
+                    node_path.push(next);
+                    path_mut.insert(next, node_path);
+                }
+            }
+        }
+        visited.visit(node);
+    }
+
+    Ok((scores, origins))
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::dictmap::DictMap;
-    use crate::shortest_path::dijkstra;
+    use crate::dictmap::*;
+    use crate::property_map::MissingProperty;
+    use crate::shortest_path::{dijkstra, dijkstra_with_property_map};
     use crate::Result;
     use petgraph::prelude::*;
     use petgraph::Graph;
@@ -226,4 +398,33 @@ mod tests {
             dijkstra(&g, a, Some(c), |e| Ok(*e.weight()), None);
         assert_eq!(scores.unwrap()[&c], 9);
     }
+
+    #[test]
+    fn test_dijk_with_property_map() {
+        let g = UnGraph::<(), ()>::from_edges([(0, 1), (1, 2), (0, 2), (2, 3)]);
+        let weights = vec![1, 2, 5, 4];
+        let mut paths = DictMap::new();
+        let scores: std::result::Result<DictMap<NodeIndex, usize>, MissingProperty<EdgeIndex>> =
+            dijkstra_with_property_map(&g, NodeIndex::new(0), None, &weights, Some(&mut paths));
+        let scores = scores.unwrap();
+        assert_eq!(scores[&NodeIndex::new(2)], 3);
+        assert_eq!(scores[&NodeIndex::new(3)], 7);
+        assert_eq!(
+            paths[&NodeIndex::new(3)],
+            vec![0, 1, 2, 3]
+                .into_iter()
+                .map(NodeIndex::new)
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_dijk_with_property_map_missing_edge() {
+        let g = UnGraph::<(), ()>::from_edges([(0, 1), (1, 2)]);
+        let mut weights: DictMap<EdgeIndex, f64> = DictMap::new();
+        weights.insert(EdgeIndex::new(0), 1.0);
+        let scores: std::result::Result<DictMap<NodeIndex, f64>, MissingProperty<EdgeIndex>> =
+            dijkstra_with_property_map(&g, NodeIndex::new(0), None, &weights, None);
+        assert_eq!(scores, Err(MissingProperty(EdgeIndex::new(1))));
+    }
 }
@@ -15,14 +15,18 @@
 //! This module contains functions for various algorithms that compute the
 //! shortest path of a graph.
 
+mod all_pairs_dijkstra;
 mod all_shortest_paths;
 mod astar;
 mod bellman_ford;
 mod dijkstra;
 mod k_shortest_path;
+mod voronoi;
 
+pub use all_pairs_dijkstra::{all_pairs_dijkstra_within, all_pairs_dijkstra_within_with_progress};
 pub use all_shortest_paths::all_shortest_paths;
 pub use astar::astar;
 pub use bellman_ford::{bellman_ford, negative_cycle_finder};
-pub use dijkstra::dijkstra;
+pub use dijkstra::{dijkstra, dijkstra_with_property_map, multi_source_dijkstra};
 pub use k_shortest_path::k_shortest_path;
+pub use voronoi::voronoi_cells;
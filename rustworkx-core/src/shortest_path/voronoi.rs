@@ -0,0 +1,165 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+use std::hash::Hash;
+
+use petgraph::algo::Measure;
+use petgraph::visit::{IntoEdges, IntoNodeIdentifiers, NodeIndexable, Visitable};
+
+use super::dijkstra::nearest_source_dijkstra;
+use crate::dictmap::*;
+
+/// The cells of the Voronoi partition of a graph and the distance of every
+/// node to its nearest source, as returned by [`voronoi_cells`].
+type VoronoiCells<N, K> = (DictMap<N, Vec<N>>, DictMap<N, K>);
+
+/// Partition the nodes of a graph by their nearest node of `sources`.
+///
+/// The Voronoi cell of a source is the set of nodes that are closer to it
+/// than to any other source, where the distance from a source to a node is
+/// the length of the shortest path between them computed with
+/// [`multi_source_dijkstra`](super::multi_source_dijkstra). For directed
+/// graphs the paths go from the sources to the nodes. A node that is as
+/// close to several sources is put in the cell of the first of them in
+/// `sources`, and nodes that can't be reached from any source aren't in any
+/// cell.
+///
+/// Arguments:
+///
+/// * `graph` - The graph to partition.
+/// * `sources` - The nodes to partition the graph around. Duplicates are
+///   ignored.
+/// * `edge_cost` - A callable that is passed an edge and returns its cost,
+///   which must be non-negative.
+///
+/// Returns a map from every source to the nodes of its cell, in the order of
+/// [`IntoNodeIdentifiers::node_identifiers`], with the sources in the order
+/// of `sources`, and a map from every node reached to its distance to the
+/// nearest source.
+///
+/// # Example
+/// ```rust
+/// use rustworkx_core::petgraph::prelude::*;
+/// use rustworkx_core::shortest_path::voronoi_cells;
+/// use rustworkx_core::Result;
+///
+/// let graph = UnGraph::<(), ()>::from_edges([(0, 1), (1, 2), (2, 3), (3, 4)]);
+/// let res: Result<_> = voronoi_cells(
+///     &graph, &[NodeIndex::new(0), NodeIndex::new(4)], |_| Ok(1)
+/// );
+/// let (cells, distances) = res.unwrap();
+/// assert_eq!(
+///     cells[&NodeIndex::new(0)],
+///     vec![NodeIndex::new(0), NodeIndex::new(1), NodeIndex::new(2)]
+/// );
+/// assert_eq!(cells[&NodeIndex::new(4)], vec![NodeIndex::new(3), NodeIndex::new(4)]);
+/// assert_eq!(distances[&NodeIndex::new(2)], 2);
+/// ```
+pub fn voronoi_cells<G, F, K, E>(
+    graph: G,
+    sources: &[G::NodeId],
+    edge_cost: F,
+) -> Result<VoronoiCells<G::NodeId, K>, E>
+where
+    G: IntoEdges + IntoNodeIdentifiers + Visitable + NodeIndexable,
+    G::NodeId: Eq + Hash,
+    F: FnMut(G::EdgeRef) -> Result<K, E>,
+    K: Measure + Copy,
+{
+    let (scores, origins): (DictMap<G::NodeId, K>, DictMap<G::NodeId, usize>) =
+        nearest_source_dijkstra(graph, sources.iter().copied(), edge_cost, None)?;
+    let mut cells: DictMap<G::NodeId, Vec<G::NodeId>> = DictMap::with_capacity(sources.len());
+    for source in sources {
+        cells.entry(*source).or_default();
+    }
+    let mut distances: DictMap<G::NodeId, K> = DictMap::with_capacity(scores.len());
+    for node in graph.node_identifiers() {
+        if let Some(rank) = origins.get(&node) {
+            cells[&sources[*rank]].push(node);
+            distances.insert(node, scores[&node]);
+        }
+    }
+    Ok((cells, distances))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::dictmap::*;
+    use crate::shortest_path::{multi_source_dijkstra, voronoi_cells};
+    use crate::Result;
+    use petgraph::prelude::*;
+
+    fn nodes(indices: &[usize]) -> Vec<NodeIndex> {
+        indices.iter().map(|i| NodeIndex::new(*i)).collect()
+    }
+
+    #[test]
+    fn test_weighted_cells() {
+        // 0 -1- 1 -1- 2 -3- 3 -1- 4, with sources 0 and 4
+        let graph = UnGraph::<(), u32>::from_edges([(0, 1, 1), (1, 2, 1), (2, 3, 3), (3, 4, 1)]);
+        let sources = nodes(&[0, 4]);
+        let res: Result<_> = voronoi_cells(&graph, &sources, |e| Ok(*e.weight()));
+        let (cells, distances) = res.unwrap();
+        assert_eq!(cells[&sources[0]], nodes(&[0, 1, 2]));
+        assert_eq!(cells[&sources[1]], nodes(&[3, 4]));
+        let distances: Vec<u32> = (0..5).map(|n| distances[&NodeIndex::new(n)]).collect();
+        assert_eq!(distances, vec![0, 1, 2, 1, 0]);
+    }
+
+    #[test]
+    fn test_ties_follow_source_order() {
+        let graph = UnGraph::<(), ()>::from_edges([(0, 1), (1, 2), (0, 3), (3, 2)]);
+        for sources in [nodes(&[0, 2]), nodes(&[2, 0])] {
+            let res: Result<_> = voronoi_cells(&graph, &sources, |_| Ok(1));
+            let (cells, _) = res.unwrap();
+            let mut first = vec![sources[0], NodeIndex::new(1), NodeIndex::new(3)];
+            first.sort();
+            assert_eq!(cells[&sources[0]], first);
+            assert_eq!(cells[&sources[1]], vec![sources[1]]);
+        }
+    }
+
+    #[test]
+    fn test_directed_and_unreachable() {
+        let mut graph = DiGraph::<(), ()>::from_edges([(0, 1), (1, 2), (3, 2)]);
+        graph.add_node(());
+        let sources = nodes(&[3, 0, 3]);
+        let res: Result<_> = voronoi_cells(&graph, &sources, |_| Ok(1));
+        let (cells, distances) = res.unwrap();
+        assert_eq!(cells.len(), 2);
+        assert_eq!(cells.keys().copied().collect::<Vec<_>>(), nodes(&[3, 0]));
+        assert_eq!(cells[&NodeIndex::new(3)], nodes(&[2, 3]));
+        assert_eq!(cells[&NodeIndex::new(0)], nodes(&[0, 1]));
+        assert!(!distances.contains_key(&NodeIndex::new(4)));
+    }
+
+    #[test]
+    fn test_multi_source_paths() {
+        let graph = UnGraph::<(), ()>::from_edges([(0, 1), (1, 2), (2, 3)]);
+        let mut paths = DictMap::new();
+        let res: Result<DictMap<NodeIndex, usize>> =
+            multi_source_dijkstra(&graph, nodes(&[3, 0]), |_| Ok(1), Some(&mut paths));
+        assert_eq!(res.unwrap().len(), 4);
+        assert_eq!(paths[&NodeIndex::new(1)], nodes(&[0, 1]));
+        assert_eq!(paths[&NodeIndex::new(2)], nodes(&[3, 2]));
+        assert_eq!(paths[&NodeIndex::new(3)], nodes(&[3]));
+    }
+
+    #[test]
+    fn test_no_sources() {
+        let graph = UnGraph::<(), ()>::from_edges([(0, 1)]);
+        let res: Result<_> = voronoi_cells(&graph, &[], |_| Ok(1));
+        let (cells, distances) = res.unwrap();
+        assert!(cells.is_empty());
+        assert!(distances.is_empty());
+    }
+}
@@ -0,0 +1,1071 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+use std::cmp::Ordering;
+use std::hash::Hash;
+
+use crate::par::*;
+use hashbrown::HashMap;
+use petgraph::visit::{
+    EdgeRef, IntoEdgesDirected, IntoNeighbors, IntoNodeIdentifiers, IntoNodeReferences,
+    NodeIndexable, NodeRef,
+};
+use petgraph::Direction::{Incoming, Outgoing};
+
+/// Run the SimRank iteration with partial sums.
+///
+/// `in_neighbors[a]` lists the in-neighbors `i` of `a` together with the
+/// factor `W(i, a)` that `s(i, j)` is multiplied by, and `evidence(a, b)`
+/// scales the similarity of every pair. Only the rows of nodes in `nodes`
+/// are computed. Returns the flattened similarity matrix or `None` if it
+/// didn't converge in `max_iter` iterations.
+fn simrank_iterate<V>(
+    node_bound: usize,
+    nodes: &[usize],
+    in_neighbors: &[Vec<(usize, f64)>],
+    evidence: V,
+    decay: f64,
+    max_iter: usize,
+    tol: f64,
+) -> Option<Vec<f64>>
+where
+    V: Fn(usize, usize) -> f64 + Sync,
+{
+    if node_bound == 0 {
+        return Some(Vec::new());
+    }
+    let mut scores: Vec<f64> = vec![0.; node_bound * node_bound];
+    for node in nodes {
+        scores[node * node_bound + node] = 1.;
+    }
+    // Every iteration writes the same entries of `next`, and the others keep
+    // their initial values, so the two matrices are swapped instead of
+    // allocating a new one per iteration
+    let mut next = scores.clone();
+    for _ in 0..max_iter {
+        next.par_chunks_mut(node_bound)
+            .enumerate()
+            .filter(|(a, _)| !in_neighbors[*a].is_empty())
+            .for_each(|(a, row)| {
+                // partial[j] is the sum of W(i, a) * s(i, j) over the
+                // in-neighbors i of a, which is shared by every b
+                let mut partial: Vec<f64> = vec![0.; node_bound];
+                for (i, factor) in &in_neighbors[a] {
+                    let previous = &scores[i * node_bound..(i + 1) * node_bound];
+                    for (total, score) in partial.iter_mut().zip(previous) {
+                        *total += factor * score;
+                    }
+                }
+                for b in nodes {
+                    if in_neighbors[*b].is_empty() {
+                        continue;
+                    }
+                    row[*b] = if a == *b {
+                        1.
+                    } else {
+                        let sum: f64 = in_neighbors[*b]
+                            .iter()
+                            .map(|(j, factor)| factor * partial[*j])
+                            .sum();
+                        decay * evidence(a, *b) * sum
+                    };
+                }
+            });
+        let delta = scores
+            .iter()
+            .zip(&next)
+            .map(|(old, new)| (old - new).abs())
+            .fold(0., f64::max);
+        std::mem::swap(&mut scores, &mut next);
+        if delta < tol {
+            return Some(scores);
+        }
+    }
+    None
+}
+
+fn into_rows(scores: Vec<f64>, node_bound: usize) -> Vec<Vec<f64>> {
+    scores
+        .chunks(node_bound.max(1))
+        .map(|row| row.to_vec())
+        .collect()
+}
+
+/// Compute the SimRank similarity of every pair of nodes in a graph
+///
+/// SimRank [1] is based on the idea that two nodes are similar if they are
+/// referenced by similar nodes. Every node is maximally similar to itself
+/// with a score of `1`, and the similarity of two distinct nodes `a` and `b`
+/// is defined recursively as
+///
+/// ```text
+///            C           ---      ---
+/// s(a, b) = ----------   \        \      s(i, j)
+///           |I(a)||I(b)| /__      /__
+///                        i ∈ I(a) j ∈ I(b)
+/// ```
+///
+/// where `I(a)` is the set of in-neighbors of `a` (the neighbors of `a` for
+/// an undirected graph) and `C` is the decay factor. The similarity is `0`
+/// if either node has no in-neighbors.
+///
+/// The scores are computed with the iterative method from the definition,
+/// using the partial sums memoization of Lizorkin et al. [2] so that every
+/// iteration takes `O(|V| |E|)` time. The iteration stops when no score
+/// changed by more than `tol`. It keeps two dense `|V| x |V|` matrices of
+/// scores, so it needs `O(|V|^2)` memory.
+///
+/// Arguments:
+///
+/// * `graph` - The graph to compute the similarities of.
+/// * `decay` - The decay factor `C`, which should be between `0` and `1`.
+/// * `max_iter` - The maximum number of iterations.
+/// * `tol` - The tolerance used to check for convergence.
+///
+/// Returns `None` if the scores didn't converge within `max_iter` iterations,
+/// otherwise a matrix of similarities where the entry at `[a][b]` is the
+/// similarity of the nodes with indices `a` and `b` (as returned by
+/// [`NodeIndexable::to_index`]). Rows and columns for indices that aren't
+/// nodes in the graph are `0`.
+///
+/// # Example
+/// ```rust
+/// use rustworkx_core::petgraph;
+/// use rustworkx_core::similarity::simrank_similarity;
+///
+/// // Nodes 1 and 2 are both only referenced by node 0
+/// let graph = petgraph::graph::DiGraph::<(), ()>::from_edges(&[(0, 1), (0, 2)]);
+/// let scores = simrank_similarity(&graph, 0.8, 100, 1e-6).unwrap();
+/// assert_eq!(scores[1][2], 0.8);
+/// assert_eq!(scores[0][1], 0.);
+/// ```
+///
+/// [1]: Jeh, Glen and Widom, Jennifer. "SimRank: a measure of
+///     structural-context similarity." Proceedings of the 8th ACM SIGKDD
+///     (2002): 538-543. <https://doi.org/10.1145/775047.775126>
+/// [2]: Lizorkin, Dmitry, et al. "Accuracy estimate and optimization
+///     techniques for SimRank computation." The VLDB Journal 19.1 (2010):
+///     45-66. <https://doi.org/10.1007/s00778-009-0168-8>
+pub fn simrank_similarity<G>(
+    graph: G,
+    decay: f64,
+    max_iter: usize,
+    tol: f64,
+) -> Option<Vec<Vec<f64>>>
+where
+    G: IntoEdgesDirected + IntoNodeIdentifiers + NodeIndexable,
+{
+    let node_bound = graph.node_bound();
+    let nodes: Vec<usize> = graph
+        .node_identifiers()
+        .map(|node| graph.to_index(node))
+        .collect();
+    let mut in_neighbors: Vec<Vec<(usize, f64)>> = vec![Vec::new(); node_bound];
+    for node in graph.node_identifiers() {
+        let index = graph.to_index(node);
+        let mut neighbors: Vec<usize> = graph
+            .edges_directed(node, Incoming)
+            .map(|edge| graph.to_index(opposite(graph, edge.source(), edge.target(), index)))
+            .collect();
+        neighbors.sort_unstable();
+        neighbors.dedup();
+        let factor = 1. / neighbors.len() as f64;
+        in_neighbors[index] = neighbors.into_iter().map(|i| (i, factor)).collect();
+    }
+    let scores = simrank_iterate(
+        node_bound,
+        &nodes,
+        &in_neighbors,
+        |_, _| 1.,
+        decay,
+        max_iter,
+        tol,
+    )?;
+    Some(into_rows(scores, node_bound))
+}
+
+/// Return the endpoint of an edge incident to the node with index `node`
+/// which isn't that node, or the node itself for a self loop.
+#[inline]
+fn opposite<G: NodeIndexable>(
+    graph: G,
+    source: G::NodeId,
+    target: G::NodeId,
+    node: usize,
+) -> G::NodeId {
+    if graph.to_index(target) == node {
+        source
+    } else {
+        target
+    }
+}
+
+/// Compute the weighted SimRank++ similarity of every pair of nodes in a graph
+///
+/// SimRank++ [1] extends [`simrank_similarity`] to weighted graphs. The
+/// similarity of two distinct nodes `a` and `b` is
+///
+/// ```text
+///                         ---      ---
+/// s(a, b) = evidence(a, b) C  \        \      W(i, a) W(j, b) s(i, j)
+///                         /__      /__
+///                         i ∈ I(a) j ∈ I(b)
+/// ```
+///
+/// where the transition weight `W(i, a) = spread(i) w(i, a) / w(a)` is the
+/// weight of the edge from `i` to `a` normalized by the total weight `w(a)`
+/// of the edges into `a`, scaled by `spread(i) = exp(-variance(i))` where
+/// `variance(i)` is the variance of the weights of the edges leaving `i`.
+/// The evidence factor `evidence(a, b) = 1 - 2^-n`, where `n` is the number
+/// of in-neighbors `a` and `b` have in common, makes pairs with more shared
+/// in-neighbors more similar. The weights of parallel edges are summed.
+///
+/// The time and memory used are the same as for [`simrank_similarity`].
+///
+/// Arguments:
+///
+/// * `graph` - The graph to compute the similarities of.
+/// * `weight_fn` - A callable that will be passed an edge reference for each
+///   edge in the graph and is expected to return a `Result<f64>` which if it
+///   doesn't error represents the non-negative weight of that edge.
+/// * `decay` - The decay factor `C`, which should be between `0` and `1`.
+/// * `max_iter` - The maximum number of iterations.
+/// * `tol` - The tolerance used to check for convergence.
+///
+/// Returns `None` if the scores didn't converge within `max_iter` iterations,
+/// otherwise a matrix of similarities indexed in the same way as the output of
+/// [`simrank_similarity`].
+///
+/// # Example
+/// ```rust
+/// use std::convert::Infallible;
+/// use rustworkx_core::petgraph;
+/// use rustworkx_core::similarity::weighted_simrank_similarity;
+///
+/// let graph = petgraph::graph::DiGraph::<(), f64>::from_edges(&[
+///     (0, 2, 1.), (0, 3, 1.), (1, 2, 1.), (1, 3, 1.)
+/// ]);
+/// let res: Result<_, Infallible> =
+///     weighted_simrank_similarity(&graph, |e| Ok(*e.weight()), 0.8, 100, 1e-9);
+/// let scores = res.unwrap().unwrap();
+/// assert!((scores[2][3] - 0.3).abs() < 1e-9);
+/// ```
+///
+/// [1]: Antonellis, Ioannis, et al. "Simrank++: query rewriting through link
+///     analysis of the click graph." Proceedings of the VLDB Endowment 1.1
+///     (2008): 408-421. <https://doi.org/10.14778/1453856.1453903>
+pub fn weighted_simrank_similarity<G, F, E>(
+    graph: G,
+    mut weight_fn: F,
+    decay: f64,
+    max_iter: usize,
+    tol: f64,
+) -> Result<Option<Vec<Vec<f64>>>, E>
+where
+    G: IntoEdgesDirected + IntoNodeIdentifiers + NodeIndexable,
+    F: FnMut(G::EdgeRef) -> Result<f64, E>,
+{
+    let node_bound = graph.node_bound();
+    let nodes: Vec<usize> = graph
+        .node_identifiers()
+        .map(|node| graph.to_index(node))
+        .collect();
+    // The summed weights of the edges into and out of every node
+    let mut weights_in: Vec<HashMap<usize, f64>> = vec![HashMap::new(); node_bound];
+    let mut weights_out: Vec<HashMap<usize, f64>> = vec![HashMap::new(); node_bound];
+    for node in graph.node_identifiers() {
+        let index = graph.to_index(node);
+        for edge in graph.edges_directed(node, Outgoing) {
+            let weight = weight_fn(edge)?;
+            let other = graph.to_index(opposite(graph, edge.source(), edge.target(), index));
+            *weights_out[index].entry(other).or_insert(0.) += weight;
+            *weights_in[other].entry(index).or_insert(0.) += weight;
+        }
+    }
+    let spread: Vec<f64> = weights_out
+        .iter()
+        .map(|weights| {
+            if weights.is_empty() {
+                return 1.;
+            }
+            let count = weights.len() as f64;
+            let mean = weights.values().sum::<f64>() / count;
+            let variance = weights
+                .values()
+                .map(|weight| (weight - mean).powi(2))
+                .sum::<f64>()
+                / count;
+            (-variance).exp()
+        })
+        .collect();
+    let in_neighbors: Vec<Vec<(usize, f64)>> = weights_in
+        .iter()
+        .map(|weights| {
+            let total: f64 = weights.values().sum();
+            let mut neighbors: Vec<(usize, f64)> = weights
+                .iter()
+                .filter(|(_, weight)| **weight != 0.)
+                .map(|(i, weight)| (*i, spread[*i] * weight / total))
+                .collect();
+            neighbors.sort_unstable_by_key(|(i, _)| *i);
+            neighbors
+        })
+        .collect();
+    let evidence = |a: usize, b: usize| {
+        1. - 0.5_f64.powi(common_neighbors(&in_neighbors[a], &in_neighbors[b]) as i32)
+    };
+    let scores = simrank_iterate(
+        node_bound,
+        &nodes,
+        &in_neighbors,
+        evidence,
+        decay,
+        max_iter,
+        tol,
+    );
+    Ok(scores.map(|scores| into_rows(scores, node_bound)))
+}
+
+/// Count the nodes in both of two lists of in-neighbors sorted by node index.
+fn common_neighbors(a: &[(usize, f64)], b: &[(usize, f64)]) -> usize {
+    let mut count = 0;
+    let (mut i, mut j) = (0, 0);
+    while i < a.len() && j < b.len() {
+        match a[i].0.cmp(&b[j].0) {
+            Ordering::Less => i += 1,
+            Ordering::Greater => j += 1,
+            Ordering::Equal => {
+                count += 1;
+                i += 1;
+                j += 1;
+            }
+        }
+    }
+    count
+}
+
+/// Build the rows of the weighted adjacency matrix indexed by node index, so
+/// that `rows[a]` lists the nodes `b` with an edge from `a` to `b` together
+/// with the summed weight of those edges.
+fn weighted_out_neighbors<G, F, E>(graph: G, mut weight_fn: F) -> Result<Vec<Vec<(usize, f64)>>, E>
+where
+    G: IntoEdgesDirected + IntoNodeIdentifiers + NodeIndexable,
+    F: FnMut(G::EdgeRef) -> Result<f64, E>,
+{
+    let mut rows: Vec<Vec<(usize, f64)>> = vec![Vec::new(); graph.node_bound()];
+    for node in graph.node_identifiers() {
+        let index = graph.to_index(node);
+        let mut weights: HashMap<usize, f64> = HashMap::new();
+        for edge in graph.edges_directed(node, Outgoing) {
+            let weight = weight_fn(edge)?;
+            let other = graph.to_index(opposite(graph, edge.source(), edge.target(), index));
+            *weights.entry(other).or_insert(0.) += weight;
+        }
+        rows[index] = weights.into_iter().filter(|(_, w)| *w != 0.).collect();
+    }
+    Ok(rows)
+}
+
+/// Compute the Katz similarity of every pair of nodes in a graph
+///
+/// The Katz index [1] of a pair of nodes `a` and `b` counts the walks from
+/// `a` to `b`, with a walk of length `k` weighted by `alpha^k`. So the
+/// similarity matrix is
+///
+/// ```text
+/// S = alpha A + alpha^2 A^2 + alpha^3 A^3 + ... = (I - alpha A)^-1 - I
+/// ```
+///
+/// where `A` is the weighted adjacency matrix of the graph, with the weights
+/// of parallel edges summed. For directed graphs the walks follow the
+/// directions of the edges. The series only converges if `alpha` is smaller
+/// than the reciprocal of the spectral radius of `A`.
+///
+/// This inverts `I - alpha A` as a dense matrix in `O(|V|^3)` time, so it's
+/// meant for small graphs. Use [`katz_similarity_from`] for the similarities
+/// of a single node in larger graphs.
+///
+/// Arguments:
+///
+/// * `graph` - The graph to compute the similarities of.
+/// * `weight_fn` - A callable that will be passed an edge reference for each
+///   edge in the graph and is expected to return a `Result<f64>` which if it
+///   doesn't error represents the non-negative weight of that edge.
+/// * `alpha` - The attenuation factor, which should be positive.
+///
+/// Returns `None` if the series doesn't converge for `alpha`, otherwise a
+/// matrix of similarities where the entry at `[a][b]` is the similarity of
+/// the nodes with indices `a` and `b` (as returned by
+/// [`NodeIndexable::to_index`]). Rows and columns for indices that aren't
+/// nodes in the graph are `0`.
+///
+/// # Example
+/// ```rust
+/// use std::convert::Infallible;
+/// use rustworkx_core::petgraph;
+/// use rustworkx_core::similarity::katz_similarity;
+///
+/// // The only walk from 0 to 2 has length 2
+/// let graph = petgraph::graph::DiGraph::<(), ()>::from_edges(&[(0, 1), (1, 2)]);
+/// let res: Result<_, Infallible> = katz_similarity(&graph, |_| Ok(1.), 0.5);
+/// let scores = res.unwrap().unwrap();
+/// assert_eq!(scores[0], vec![0., 0.5, 0.25]);
+/// assert_eq!(scores[2], vec![0., 0., 0.]);
+/// ```
+///
+/// [1]: Katz, Leo. "A new status index derived from sociometric analysis."
+///     Psychometrika 18.1 (1953): 39-43. <https://doi.org/10.1007/BF02289026>
+pub fn katz_similarity<G, F, E>(
+    graph: G,
+    weight_fn: F,
+    alpha: f64,
+) -> Result<Option<Vec<Vec<f64>>>, E>
+where
+    G: IntoEdgesDirected + IntoNodeIdentifiers + NodeIndexable,
+    F: FnMut(G::EdgeRef) -> Result<f64, E>,
+{
+    let node_bound = graph.node_bound();
+    let rows = weighted_out_neighbors(graph, weight_fn)?;
+    let nodes: Vec<usize> = graph
+        .node_identifiers()
+        .map(|node| graph.to_index(node))
+        .collect();
+    let size = nodes.len();
+    let mut positions: Vec<usize> = vec![usize::MAX; node_bound];
+    for (position, node) in nodes.iter().enumerate() {
+        positions[*node] = position;
+    }
+
+    // Invert I - alpha A with Gauss-Jordan elimination, with the inverse
+    // built up in the right half of every row
+    let width = 2 * size;
+    let mut matrix: Vec<f64> = vec![0.; size * width];
+    for (i, node) in nodes.iter().enumerate() {
+        matrix[i * width + i] = 1.;
+        matrix[i * width + size + i] = 1.;
+        for (other, weight) in &rows[*node] {
+            matrix[i * width + positions[*other]] -= alpha * weight;
+        }
+    }
+    for column in 0..size {
+        let pivot = (column..size)
+            .max_by(|a, b| {
+                matrix[a * width + column]
+                    .abs()
+                    .total_cmp(&matrix[b * width + column].abs())
+            })
+            .unwrap();
+        if matrix[pivot * width + column] == 0. {
+            return Ok(None);
+        }
+        if pivot != column {
+            for k in 0..width {
+                matrix.swap(pivot * width + k, column * width + k);
+            }
+        }
+        let scale = matrix[column * width + column].recip();
+        for k in 0..width {
+            matrix[column * width + k] *= scale;
+        }
+        for row in 0..size {
+            let factor = matrix[row * width + column];
+            if row == column || factor == 0. {
+                continue;
+            }
+            for k in 0..width {
+                matrix[row * width + k] -= factor * matrix[column * width + k];
+            }
+        }
+    }
+
+    // I - alpha A is an M-matrix, so the series converges if and only if its
+    // inverse is non-negative
+    let largest = matrix.iter().fold(1_f64, |acc, value| acc.max(value.abs()));
+    let mut scores: Vec<Vec<f64>> = vec![vec![0.; node_bound]; node_bound];
+    for (i, a) in nodes.iter().enumerate() {
+        for (j, b) in nodes.iter().enumerate() {
+            let value = matrix[i * width + size + j];
+            if value < -1e-12 * largest {
+                return Ok(None);
+            }
+            let value = if i == j { value - 1. } else { value };
+            scores[*a][*b] = value.max(0.);
+        }
+    }
+    Ok(Some(scores))
+}
+
+/// Compute the Katz similarity of a node to every node in a graph
+///
+/// This computes the row of `source` of the similarity matrix of
+/// [`katz_similarity`], by summing the series `alpha x A + alpha^2 x A^2 +
+/// ...` where `x` is the indicator vector of `source`. Every term takes
+/// `O(|E|)` time, and the summation stops when the sum of a term is less than
+/// `tol`.
+///
+/// Arguments:
+///
+/// * `graph` - The graph to compute the similarities of.
+/// * `source` - The node to compute the similarities to.
+/// * `weight_fn` - A callable that will be passed an edge reference for each
+///   edge in the graph and is expected to return a `Result<f64>` which if it
+///   doesn't error represents the non-negative weight of that edge.
+/// * `alpha` - The attenuation factor, which should be positive.
+/// * `max_iter` - The maximum number of terms of the series.
+/// * `tol` - The tolerance used to check for convergence.
+///
+/// Returns `None` if the series didn't converge within `max_iter` terms,
+/// otherwise a vector of the similarities of `source` to the other nodes
+/// indexed by node index. The similarities are `0` for indices that aren't
+/// in the graph.
+///
+/// # Example
+/// ```rust
+/// use std::convert::Infallible;
+/// use rustworkx_core::petgraph;
+/// use rustworkx_core::similarity::katz_similarity_from;
+///
+/// let graph = petgraph::graph::UnGraph::<(), ()>::from_edges(&[(0, 1)]);
+/// let res: Result<_, Infallible> =
+///     katz_similarity_from(&graph, 0.into(), |_| Ok(1.), 0.5, 1000, 1e-12);
+/// let scores = res.unwrap().unwrap();
+/// // The walks from 0 to 1 have odd lengths: 1/2 + 1/8 + 1/32 + ... = 2/3
+/// assert!((scores[1] - 2. / 3.).abs() < 1e-9);
+/// ```
+pub fn katz_similarity_from<G, F, E>(
+    graph: G,
+    source: G::NodeId,
+    weight_fn: F,
+    alpha: f64,
+    max_iter: usize,
+    tol: f64,
+) -> Result<Option<Vec<f64>>, E>
+where
+    G: IntoEdgesDirected + IntoNodeIdentifiers + NodeIndexable,
+    F: FnMut(G::EdgeRef) -> Result<f64, E>,
+{
+    let node_bound = graph.node_bound();
+    let rows = weighted_out_neighbors(graph, weight_fn)?;
+    let mut term: Vec<f64> = vec![0.; node_bound];
+    term[graph.to_index(source)] = 1.;
+    let mut scores: Vec<f64> = vec![0.; node_bound];
+    for _ in 0..max_iter {
+        let mut next: Vec<f64> = vec![0.; node_bound];
+        for (a, row) in rows.iter().enumerate() {
+            if term[a] == 0. {
+                continue;
+            }
+            for (b, weight) in row {
+                next[*b] += alpha * weight * term[a];
+            }
+        }
+        let total: f64 = next.iter().sum();
+        for (score, value) in scores.iter_mut().zip(&next) {
+            *score += value;
+        }
+        if total < tol {
+            return Ok(Some(scores));
+        }
+        term = next;
+    }
+    Ok(None)
+}
+
+/// Compute the Weisfeiler-Lehman subtree features of a collection of graphs.
+///
+/// Every node starts out with the label returned by `node_label`. In each of
+/// the `iterations` rounds the label of every node is replaced by a
+/// compressed label for the pair of its current label and the sorted multiset
+/// of the current labels of its neighbors. The compressed labels are shared
+/// between all the graphs, so the same subtree pattern gets the same feature
+/// index in every graph, and labels from different rounds never collide. For
+/// directed graphs only the successors of a node are used as its neighbors.
+///
+/// The feature vector of a graph counts how many times each compressed label
+/// (including the original labels) appears in it over all the rounds, see
+/// [1] for details.
+///
+/// Arguments:
+///
+/// * `graphs` - The graphs to compute the features of.
+/// * `node_label` - A callable that will be passed a node reference for each
+///   node in each graph and is expected to return a `Result` which if it
+///   doesn't error is the initial label of that node.
+/// * `iterations` - The number of relabeling rounds.
+///
+/// Returns a sparse feature vector for each graph, mapping feature indices to
+/// counts, in the same order as `graphs`.
+///
+/// # Example
+/// ```rust
+/// use std::convert::Infallible;
+/// use rustworkx_core::petgraph;
+/// use rustworkx_core::similarity::weisfeiler_lehman_subtree_features;
+///
+/// let path = petgraph::graph::UnGraph::<(), ()>::from_edges(&[(0, 1), (1, 2)]);
+/// let triangle = petgraph::graph::UnGraph::<(), ()>::from_edges(&[(0, 1), (1, 2), (2, 0)]);
+/// let res: Result<_, Infallible> =
+///     weisfeiler_lehman_subtree_features(&[&path, &triangle], |_| Ok(()), 1);
+/// let features = res.unwrap();
+/// // Every node starts with the same label
+/// assert_eq!(features[0][&0], 3);
+/// assert_eq!(features[1][&0], 3);
+/// // The path has two distinct subtree patterns after one round
+/// assert_eq!(features[0].len(), 3);
+/// assert_eq!(features[1].len(), 2);
+/// ```
+///
+/// [1]: Shervashidze, Nino, et al. "Weisfeiler-Lehman graph kernels."
+///     Journal of Machine Learning Research 12.77 (2011): 2539-2561.
+///     <https://www.jmlr.org/papers/v12/shervashidze11a.html>
+pub fn weisfeiler_lehman_subtree_features<G, F, L, E>(
+    graphs: &[G],
+    mut node_label: F,
+    iterations: usize,
+) -> Result<Vec<HashMap<usize, usize>>, E>
+where
+    G: IntoNodeReferences + IntoNeighbors + NodeIndexable,
+    F: FnMut(G::NodeRef) -> Result<L, E>,
+    L: Hash + Eq,
+{
+    let mut features: Vec<HashMap<usize, usize>> = vec![HashMap::new(); graphs.len()];
+    let mut initial_labels: HashMap<L, usize> = HashMap::new();
+    let mut labels: Vec<Vec<usize>> = Vec::with_capacity(graphs.len());
+    for (graph, counts) in graphs.iter().zip(features.iter_mut()) {
+        let mut graph_labels: Vec<usize> = vec![usize::MAX; graph.node_bound()];
+        for node in graph.node_references() {
+            let next_label = initial_labels.len();
+            let label = *initial_labels
+                .entry(node_label(node)?)
+                .or_insert(next_label);
+            graph_labels[graph.to_index(node.id())] = label;
+            *counts.entry(label).or_insert(0) += 1;
+        }
+        labels.push(graph_labels);
+    }
+    let mut label_count = initial_labels.len();
+    for _ in 0..iterations {
+        let mut compressed: HashMap<(usize, Vec<usize>), usize> = HashMap::new();
+        for ((graph, counts), graph_labels) in graphs
+            .iter()
+            .zip(features.iter_mut())
+            .zip(labels.iter_mut())
+        {
+            let mut new_labels: Vec<usize> = vec![usize::MAX; graph_labels.len()];
+            for node in graph.node_identifiers() {
+                let index = graph.to_index(node);
+                let mut neighbor_labels: Vec<usize> = graph
+                    .neighbors(node)
+                    .map(|neighbor| graph_labels[graph.to_index(neighbor)])
+                    .collect();
+                neighbor_labels.sort_unstable();
+                let label = *compressed
+                    .entry((graph_labels[index], neighbor_labels))
+                    .or_insert_with(|| {
+                        label_count += 1;
+                        label_count - 1
+                    });
+                new_labels[index] = label;
+                *counts.entry(label).or_insert(0) += 1;
+            }
+            *graph_labels = new_labels;
+        }
+    }
+    Ok(features)
+}
+
+/// Compute the Weisfeiler-Lehman subtree kernel between every pair of graphs
+/// in a collection.
+///
+/// The kernel value of two graphs is the dot product of their feature
+/// vectors as computed by [`weisfeiler_lehman_subtree_features`]. If
+/// `normalize` is `true` the kernel values are divided by the square root of
+/// the product of the kernel values of each graph with itself, so they are
+/// between `0` and `1` and every graph has a kernel value of `1` with itself.
+///
+/// The rows of the kernel matrix are computed in parallel.
+///
+/// Arguments:
+///
+/// * `graphs` - The graphs to compute the kernel between.
+/// * `node_label` - A callable that will be passed a node reference for each
+///   node in each graph and is expected to return a `Result` which if it
+///   doesn't error is the initial label of that node.
+/// * `iterations` - The number of relabeling rounds.
+/// * `normalize` - Whether to normalize the kernel values.
+///
+/// Returns the symmetric kernel matrix with a row and column for each graph
+/// in the same order as `graphs`.
+///
+/// # Example
+/// ```rust
+/// use std::convert::Infallible;
+/// use rustworkx_core::petgraph;
+/// use rustworkx_core::similarity::weisfeiler_lehman_subtree_kernel;
+///
+/// let path = petgraph::graph::UnGraph::<(), ()>::from_edges(&[(0, 1), (1, 2)]);
+/// let triangle = petgraph::graph::UnGraph::<(), ()>::from_edges(&[(0, 1), (1, 2), (2, 0)]);
+/// let res: Result<_, Infallible> =
+///     weisfeiler_lehman_subtree_kernel(&[&path, &triangle], |_| Ok(()), 1, false);
+/// let kernel = res.unwrap();
+/// assert_eq!(kernel, vec![vec![14., 12.], vec![12., 18.]]);
+/// ```
+pub fn weisfeiler_lehman_subtree_kernel<G, F, L, E>(
+    graphs: &[G],
+    node_label: F,
+    iterations: usize,
+    normalize: bool,
+) -> Result<Vec<Vec<f64>>, E>
+where
+    G: IntoNodeReferences + IntoNeighbors + NodeIndexable,
+    F: FnMut(G::NodeRef) -> Result<L, E>,
+    L: Hash + Eq,
+{
+    let features = weisfeiler_lehman_subtree_features(graphs, node_label, iterations)?;
+    let dot = |a: &HashMap<usize, usize>, b: &HashMap<usize, usize>| -> f64 {
+        let (small, large) = if a.len() <= b.len() { (a, b) } else { (b, a) };
+        small
+            .iter()
+            .filter_map(|(label, count)| large.get(label).map(|other| (count * other) as f64))
+            .sum()
+    };
+    let mut kernel: Vec<Vec<f64>> = features
+        .par_iter()
+        .map(|a| features.iter().map(|b| dot(a, b)).collect())
+        .collect();
+    if normalize {
+        let norms: Vec<f64> = (0..kernel.len()).map(|i| kernel[i][i].sqrt()).collect();
+        for (row, norm_a) in kernel.iter_mut().zip(&norms) {
+            for (value, norm_b) in row.iter_mut().zip(&norms) {
+                if *value != 0. {
+                    *value /= norm_a * norm_b;
+                }
+            }
+        }
+    }
+    Ok(kernel)
+}
+
+#[cfg(test)]
+mod test_simrank {
+    use super::{simrank_similarity, weighted_simrank_similarity};
+    use petgraph::graph::{DiGraph, UnGraph};
+    use petgraph::stable_graph::StableDiGraph;
+    use std::convert::Infallible;
+
+    fn assert_symmetric(scores: &[Vec<f64>]) {
+        for (a, row) in scores.iter().enumerate() {
+            for (b, score) in row.iter().enumerate() {
+                assert!((score - scores[b][a]).abs() < 1e-12);
+            }
+        }
+    }
+
+    #[test]
+    fn test_empty_graph() {
+        let graph = DiGraph::<(), ()>::new();
+        assert_eq!(simrank_similarity(&graph, 0.8, 100, 1e-6), Some(vec![]));
+    }
+
+    #[test]
+    fn test_path_graph() {
+        // Every node of an undirected path is only similar to itself and the
+        // nodes at an even distance from it
+        let graph = UnGraph::<(), ()>::from_edges([(0, 1), (1, 2), (2, 3), (3, 4)]);
+        let scores = simrank_similarity(&graph, 0.9, 1000, 1e-10).unwrap();
+        assert_symmetric(&scores);
+        for (a, row) in scores.iter().enumerate() {
+            assert_eq!(row[a], 1.);
+            for (b, score) in row.iter().enumerate() {
+                if (a + b) % 2 == 1 {
+                    assert_eq!(*score, 0.);
+                } else {
+                    assert!(*score > 0.);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_matches_naive_iteration() {
+        let graph = DiGraph::<(), ()>::from_edges([
+            (0, 1),
+            (0, 2),
+            (1, 2),
+            (2, 0),
+            (3, 1),
+            (3, 2),
+            (1, 3),
+            (2, 2),
+            (0, 1),
+        ]);
+        let scores = simrank_similarity(&graph, 0.6, 1000, 1e-12).unwrap();
+        let in_neighbors: Vec<Vec<usize>> = (0..4)
+            .map(|node| {
+                let mut neighbors: Vec<usize> = graph
+                    .neighbors_directed(node.into(), petgraph::Direction::Incoming)
+                    .map(|n| n.index())
+                    .collect();
+                neighbors.sort();
+                neighbors.dedup();
+                neighbors
+            })
+            .collect();
+        let mut expected = vec![vec![0.; 4]; 4];
+        for _ in 0..200 {
+            let mut next = vec![vec![0.; 4]; 4];
+            for a in 0..4 {
+                for b in 0..4 {
+                    if a == b {
+                        next[a][b] = 1.;
+                        continue;
+                    }
+                    let mut sum = 0.;
+                    for i in &in_neighbors[a] {
+                        for j in &in_neighbors[b] {
+                            sum += expected[*i][*j];
+                        }
+                    }
+                    let count = (in_neighbors[a].len() * in_neighbors[b].len()) as f64;
+                    next[a][b] = 0.6 * sum / count;
+                }
+            }
+            expected = next;
+        }
+        for a in 0..4 {
+            for b in 0..4 {
+                assert!((scores[a][b] - expected[a][b]).abs() < 1e-9);
+            }
+        }
+    }
+
+    #[test]
+    fn test_removed_nodes() {
+        let mut graph = StableDiGraph::<(), ()>::from_edges([(0, 1), (0, 2), (3, 1), (3, 2)]);
+        graph.remove_node(0.into());
+        let scores = simrank_similarity(&graph, 0.8, 100, 1e-6).unwrap();
+        assert_eq!(scores.len(), 4);
+        assert_eq!(scores[1][2], 0.8);
+        assert_eq!(scores[0], vec![0.; 4]);
+    }
+
+    #[test]
+    fn test_not_converged() {
+        let graph = UnGraph::<(), ()>::from_edges([(0, 1), (1, 2), (2, 0)]);
+        assert!(simrank_similarity(&graph, 0.9, 2, 1e-12).is_none());
+    }
+
+    #[test]
+    fn test_weighted_spread_and_evidence() {
+        let graph = DiGraph::<(), f64>::from_edges([
+            (0, 2, 1.),
+            (0, 3, 1.),
+            (1, 2, 1.),
+            (1, 3, 1.),
+            (4, 5, 1.),
+            (4, 6, 3.),
+        ]);
+        let res: Result<_, Infallible> =
+            weighted_simrank_similarity(&graph, |e| Ok(*e.weight()), 0.8, 100, 1e-12);
+        let scores = res.unwrap().unwrap();
+        // Two shared in-neighbors give an evidence of 0.75
+        assert!((scores[2][3] - 0.3).abs() < 1e-12);
+        // The uneven weights out of node 4 have a variance of 1
+        let expected = 0.5 * 0.8 * (-1.0_f64).exp().powi(2);
+        assert!((scores[5][6] - expected).abs() < 1e-12);
+        assert_eq!(scores[2][5], 0.);
+    }
+
+    #[test]
+    fn test_weighted_undirected() {
+        let graph = UnGraph::<(), f64>::from_edges([(0, 1, 2.), (0, 2, 2.), (1, 1, 1.)]);
+        let res: Result<_, Infallible> =
+            weighted_simrank_similarity(&graph, |e| Ok(*e.weight()), 0.8, 1000, 1e-12);
+        let scores = res.unwrap().unwrap();
+        assert_symmetric(&scores);
+        assert!(scores[1][2] > 0.);
+    }
+
+    #[test]
+    fn test_weight_fn_error() {
+        let graph = DiGraph::<(), f64>::from_edges([(0, 1, 1.)]);
+        let res = weighted_simrank_similarity(&graph, |_| Err("error"), 0.8, 100, 1e-6);
+        assert_eq!(res, Err("error"));
+    }
+}
+
+#[cfg(test)]
+mod test_weisfeiler_lehman {
+    use super::{weisfeiler_lehman_subtree_features, weisfeiler_lehman_subtree_kernel};
+    use petgraph::graph::{DiGraph, UnGraph};
+    use petgraph::visit::NodeRef;
+    use std::convert::Infallible;
+
+    #[test]
+    fn test_no_iterations_counts_labels() {
+        let graph = UnGraph::<&str, ()>::from_edges([(0, 1), (1, 2)]);
+        let mut graph = graph.map(|i, _| if i.index() == 1 { "b" } else { "a" }, |_, _| ());
+        graph.add_node("c");
+        let res: Result<_, Infallible> =
+            weisfeiler_lehman_subtree_features(&[&graph], |n| Ok(*n.weight()), 0);
+        let features = res.unwrap();
+        assert_eq!(features[0].len(), 3);
+        let mut counts: Vec<usize> = features[0].values().copied().collect();
+        counts.sort();
+        assert_eq!(counts, vec![1, 1, 2]);
+    }
+
+    #[test]
+    fn test_isomorphic_graphs_have_same_features() {
+        let a = UnGraph::<(), ()>::from_edges([(0, 1), (1, 2), (2, 3), (1, 3)]);
+        let b = UnGraph::<(), ()>::from_edges([(3, 2), (2, 0), (0, 1), (2, 1)]);
+        let res: Result<_, Infallible> =
+            weisfeiler_lehman_subtree_features(&[&a, &b], |_| Ok(0), 3);
+        let features = res.unwrap();
+        assert_eq!(features[0], features[1]);
+    }
+
+    #[test]
+    fn test_labels_distinguish_graphs() {
+        let graph = UnGraph::<u32, ()>::from_edges([(0, 1)]);
+        let other = graph.map(|i, _| i.index() as u32, |_, _| ());
+        let res: Result<_, Infallible> =
+            weisfeiler_lehman_subtree_kernel(&[&graph, &other], |n| Ok(*n.weight()), 2, false);
+        let kernel = res.unwrap();
+        assert_eq!(kernel[0][0], 12.);
+        assert_eq!(kernel[1][1], 6.);
+        // Only the original label 0 is shared, after relabeling the
+        // neighborhoods are different
+        assert_eq!(kernel[0][1], 2.);
+    }
+
+    #[test]
+    fn test_directed_uses_successors() {
+        let out_star = DiGraph::<(), ()>::from_edges([(0, 1), (0, 2)]);
+        let in_star = DiGraph::<(), ()>::from_edges([(1, 0), (2, 0)]);
+        let res: Result<_, Infallible> =
+            weisfeiler_lehman_subtree_features(&[&out_star, &in_star], |_| Ok(()), 1);
+        let features = res.unwrap();
+        assert_ne!(features[0], features[1]);
+    }
+
+    #[test]
+    fn test_normalized_kernel() {
+        let path = UnGraph::<(), ()>::from_edges([(0, 1), (1, 2)]);
+        let triangle = UnGraph::<(), ()>::from_edges([(0, 1), (1, 2), (2, 0)]);
+        let empty = UnGraph::<(), ()>::default();
+        let res: Result<_, Infallible> =
+            weisfeiler_lehman_subtree_kernel(&[&path, &triangle, &empty], |_| Ok(()), 1, true);
+        let kernel = res.unwrap();
+        assert!((kernel[0][0] - 1.).abs() < 1e-12);
+        assert!((kernel[1][1] - 1.).abs() < 1e-12);
+        assert!((kernel[0][1] - 12. / (14_f64 * 18.).sqrt()).abs() < 1e-12);
+        assert_eq!(kernel[0][1], kernel[1][0]);
+        assert_eq!(kernel[2], vec![0., 0., 0.]);
+    }
+
+    #[test]
+    fn test_node_label_error() {
+        let graph = UnGraph::<(), ()>::from_edges([(0, 1)]);
+        let res = weisfeiler_lehman_subtree_kernel(&[&graph], |_| Err::<(), _>("error"), 1, false);
+        assert_eq!(res, Err("error"));
+    }
+}
+
+#[cfg(test)]
+mod test_katz {
+    use super::{katz_similarity, katz_similarity_from};
+    use petgraph::graph::{DiGraph, UnGraph};
+    use petgraph::stable_graph::StableUnGraph;
+    use std::convert::Infallible;
+
+    #[test]
+    fn test_two_nodes() {
+        // The walks between the nodes have odd lengths and the walks from a
+        // node to itself have even lengths
+        let graph = UnGraph::<(), ()>::from_edges([(0, 1)]);
+        let res: Result<_, Infallible> = katz_similarity(&graph, |_| Ok(1.), 0.5);
+        let scores = res.unwrap().unwrap();
+        assert!((scores[0][1] - 2. / 3.).abs() < 1e-12);
+        assert!((scores[0][0] - 1. / 3.).abs() < 1e-12);
+        assert_eq!(scores[0][1], scores[1][0]);
+    }
+
+    #[test]
+    fn test_dense_matches_single_source() {
+        let graph = UnGraph::<(), f64>::from_edges([
+            (0, 1, 1.),
+            (1, 2, 2.),
+            (2, 0, 0.5),
+            (2, 3, 1.),
+            (3, 3, 1.),
+        ]);
+        let res: Result<_, Infallible> = katz_similarity(&graph, |e| Ok(*e.weight()), 0.2);
+        let scores = res.unwrap().unwrap();
+        for source in 0..4 {
+            let res: Result<_, Infallible> =
+                katz_similarity_from(&graph, source.into(), |e| Ok(*e.weight()), 0.2, 1000, 1e-14);
+            let row = res.unwrap().unwrap();
+            for (a, b) in row.iter().zip(&scores[source as usize]) {
+                assert!((a - b).abs() < 1e-10);
+            }
+        }
+    }
+
+    #[test]
+    fn test_directed() {
+        let graph = DiGraph::<(), ()>::from_edges([(0, 1), (1, 2), (2, 0)]);
+        let res: Result<_, Infallible> = katz_similarity(&graph, |_| Ok(1.), 0.5);
+        let scores = res.unwrap().unwrap();
+        // Walks from 0 to 1 have lengths 1, 4, 7, ...
+        assert!((scores[0][1] - 0.5 / (1. - 0.125)).abs() < 1e-12);
+        assert!((scores[1][0] - 0.25 / (1. - 0.125)).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_divergent() {
+        // The spectral radius of a triangle is 2
+        let graph = UnGraph::<(), ()>::from_edges([(0, 1), (1, 2), (2, 0)]);
+        let res: Result<_, Infallible> = katz_similarity(&graph, |_| Ok(1.), 0.6);
+        assert_eq!(res.unwrap(), None);
+        let res: Result<_, Infallible> = katz_similarity(&graph, |_| Ok(1.), 0.5);
+        assert_eq!(res.unwrap(), None);
+        let res: Result<_, Infallible> =
+            katz_similarity_from(&graph, 0.into(), |_| Ok(1.), 0.6, 100, 1e-6);
+        assert_eq!(res.unwrap(), None);
+        let res: Result<_, Infallible> = katz_similarity(&graph, |_| Ok(1.), 0.4);
+        assert!(res.unwrap().is_some());
+    }
+
+    #[test]
+    fn test_removed_node() {
+        let mut graph = StableUnGraph::<(), ()>::default();
+        let a = graph.add_node(());
+        let b = graph.add_node(());
+        let c = graph.add_node(());
+        graph.add_edge(a, c, ());
+        graph.remove_node(b);
+        let res: Result<_, Infallible> = katz_similarity(&graph, |_| Ok(1.), 0.5);
+        let scores = res.unwrap().unwrap();
+        assert_eq!(scores.len(), 3);
+        assert_eq!(scores[1], vec![0.; 3]);
+        assert!((scores[0][2] - 2. / 3.).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_weight_fn_error() {
+        let graph = UnGraph::<(), ()>::from_edges([(0, 1)]);
+        assert_eq!(katz_similarity(&graph, |_| Err("error"), 0.5), Err("error"));
+        let res = katz_similarity_from(&graph, 0.into(), |_| Err("error"), 0.5, 100, 1e-6);
+        assert_eq!(res, Err("error"));
+    }
+}
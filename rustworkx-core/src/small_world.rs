@@ -0,0 +1,499 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+use std::collections::VecDeque;
+
+use crate::graph_ext::edge_swap::double_edge_swap_undirected_filtered;
+use crate::par::*;
+use crate::rng::{rng_from_seed, task_seeds};
+use hashbrown::HashMap;
+use petgraph::stable_graph::{NodeIndex, StableUnGraph};
+use petgraph::visit::{EdgeRef, IntoEdgeReferences, IntoNodeIdentifiers, NodeIndexable};
+use rand::prelude::*;
+
+/// Copy a graph into a simple undirected graph whose nodes are numbered in
+/// the order of `node_identifiers()`, without self-loops or parallel edges.
+fn simple_copy<G>(graph: G) -> StableUnGraph<(), ()>
+where
+    G: IntoEdgeReferences + IntoNodeIdentifiers + NodeIndexable,
+{
+    let position: HashMap<usize, usize> = graph
+        .node_identifiers()
+        .enumerate()
+        .map(|(position, node)| (graph.to_index(node), position))
+        .collect();
+    let mut simple: StableUnGraph<(), ()> = StableUnGraph::with_capacity(position.len(), 0);
+    for _ in 0..position.len() {
+        simple.add_node(());
+    }
+    for edge in graph.edge_references() {
+        let source = NodeIndex::new(position[&graph.to_index(edge.source())]);
+        let target = NodeIndex::new(position[&graph.to_index(edge.target())]);
+        if source != target && simple.find_edge(source, target).is_none() {
+            simple.add_edge(source, target, ());
+        }
+    }
+    simple
+}
+
+/// Return whether there is a path between two nodes.
+fn has_path(graph: &StableUnGraph<(), ()>, source: NodeIndex, target: NodeIndex) -> bool {
+    let mut seen = vec![false; graph.node_bound()];
+    let mut queue = VecDeque::from([source]);
+    seen[source.index()] = true;
+    while let Some(node) = queue.pop_front() {
+        if node == target {
+            return true;
+        }
+        for neighbor in graph.neighbors(node) {
+            if !seen[neighbor.index()] {
+                seen[neighbor.index()] = true;
+                queue.push_back(neighbor);
+            }
+        }
+    }
+    false
+}
+
+/// Rewire a simple graph in place with double edge swaps that keep it
+/// connected, only doing the swaps that `accept` returns `true` for.
+///
+/// Every swap replaces two edges `(u, v)` and `(x, y)` with `(u, x)` and
+/// `(v, y)`. `num_iterations` swaps are done per edge, with up to the average
+/// degree of the graph attempts per swap.
+fn rewire<F>(graph: &mut StableUnGraph<(), ()>, num_iterations: usize, seed: u64, accept: F)
+where
+    F: Fn(NodeIndex, NodeIndex, NodeIndex, NodeIndex) -> bool,
+{
+    let num_edges = graph.edge_count();
+    let num_nodes = graph.node_count();
+    if num_edges < 2 || num_nodes < 4 {
+        return;
+    }
+    let max_tries = (2 * num_edges / (num_nodes - 1)).max(1);
+    let num_swaps = num_iterations * num_edges;
+    double_edge_swap_undirected_filtered(
+        graph,
+        num_swaps,
+        num_swaps * max_tries,
+        Some(seed),
+        |graph, [u, v, x, y]| {
+            // The new edges join u to x and v to y, so the graph is still
+            // connected if u and v are
+            accept(u, v, x, y) && has_path(graph, u, v)
+        },
+    );
+}
+
+/// Return a random graph with the same degrees as a graph.
+///
+/// The random graph is made by rewiring a copy of the graph with double edge
+/// swaps, the Maslov-Sneppen algorithm, where every swap replaces two edges
+/// `(u, v)` and `(x, y)` with `(u, x)` and `(v, y)`. Swaps that would
+/// disconnect the graph are undone, so a connected graph stays connected.
+///
+/// The graph is treated as a simple undirected graph: self-loops are
+/// ignored and parallel edges are counted once.
+///
+/// Arguments:
+///
+/// * `graph` - The graph to make a random reference of.
+/// * `num_iterations` - The number of swaps to attempt per edge.
+/// * `seed` - An optional seed to use for the random number generator.
+///
+/// Returns a new graph where node `i` is the `i`-th node of
+/// `graph.node_identifiers()`.
+///
+/// # Example
+/// ```rust
+/// use rustworkx_core::generators::cycle_graph;
+/// use rustworkx_core::petgraph::graph::UnGraph;
+/// use rustworkx_core::small_world::random_reference;
+///
+/// let graph: UnGraph<(), ()> = cycle_graph(Some(10), None, || (), || (), false).unwrap();
+/// let random = random_reference(&graph, 5, Some(42));
+/// assert_eq!(random.edge_count(), 10);
+/// assert!(random.node_indices().all(|n| random.neighbors(n).count() == 2));
+/// ```
+pub fn random_reference<G>(
+    graph: G,
+    num_iterations: usize,
+    seed: Option<u64>,
+) -> StableUnGraph<(), ()>
+where
+    G: IntoEdgeReferences + IntoNodeIdentifiers + NodeIndexable,
+{
+    let seed = seed.unwrap_or_else(|| rng_from_seed(None).gen());
+    let mut reference = simple_copy(graph);
+    rewire(&mut reference, num_iterations, seed, |_, _, _, _| true);
+    reference
+}
+
+/// Return a lattice-like graph with the same degrees as a graph.
+///
+/// The lattice reference is made by rewiring a copy of the graph with
+/// double edge swaps, like [`random_reference`], but a swap is only done if
+/// it doesn't move the edges away from a ring lattice: the nodes are placed
+/// on a ring in the order of `graph.node_identifiers()`, and the new edges
+/// can't join nodes that are further apart on the ring in total than the
+/// edges they replace. Swaps that would disconnect the graph are undone.
+///
+/// The graph is treated as a simple undirected graph: self-loops are
+/// ignored and parallel edges are counted once.
+///
+/// Arguments:
+///
+/// * `graph` - The graph to make a lattice reference of.
+/// * `num_iterations` - The number of swaps to attempt per edge.
+/// * `seed` - An optional seed to use for the random number generator.
+///
+/// Returns a new graph where node `i` is the `i`-th node of
+/// `graph.node_identifiers()`.
+///
+/// # Example
+/// ```rust
+/// use rustworkx_core::generators::cycle_graph;
+/// use rustworkx_core::petgraph::graph::UnGraph;
+/// use rustworkx_core::small_world::lattice_reference;
+///
+/// // A cycle is already a ring lattice
+/// let graph: UnGraph<(), ()> = cycle_graph(Some(10), None, || (), || (), false).unwrap();
+/// let lattice = lattice_reference(&graph, 5, Some(42));
+/// assert!(lattice.edge_indices().all(|e| {
+///     let (u, v) = lattice.edge_endpoints(e).unwrap();
+///     let distance = u.index().abs_diff(v.index());
+///     distance == 1 || distance == 9
+/// }));
+/// ```
+pub fn lattice_reference<G>(
+    graph: G,
+    num_iterations: usize,
+    seed: Option<u64>,
+) -> StableUnGraph<(), ()>
+where
+    G: IntoEdgeReferences + IntoNodeIdentifiers + NodeIndexable,
+{
+    let seed = seed.unwrap_or_else(|| rng_from_seed(None).gen());
+    let mut reference = simple_copy(graph);
+    let num_nodes = reference.node_count();
+    let ring_distance = |a: NodeIndex, b: NodeIndex| {
+        let distance = a.index().abs_diff(b.index());
+        distance.min(num_nodes - distance)
+    };
+    rewire(&mut reference, num_iterations, seed, |u, v, x, y| {
+        ring_distance(u, x) + ring_distance(v, y) <= ring_distance(u, v) + ring_distance(x, y)
+    });
+    reference
+}
+
+/// Return the average clustering coefficient of a simple graph, counting
+/// nodes with fewer than two neighbors as `0`.
+fn average_clustering(graph: &StableUnGraph<(), ()>) -> f64 {
+    let total: f64 = graph
+        .node_indices()
+        .collect::<Vec<NodeIndex>>()
+        .par_iter()
+        .map(|node| {
+            let neighbors: Vec<NodeIndex> = graph.neighbors(*node).collect();
+            let degree = neighbors.len();
+            if degree < 2 {
+                return 0.;
+            }
+            let mut links = 0;
+            for (i, first) in neighbors.iter().enumerate() {
+                for second in &neighbors[i + 1..] {
+                    if graph.find_edge(*first, *second).is_some() {
+                        links += 1;
+                    }
+                }
+            }
+            2. * links as f64 / (degree * (degree - 1)) as f64
+        })
+        .sum();
+    total / graph.node_count() as f64
+}
+
+/// Return the average shortest path length of a simple graph, or `None` if
+/// it isn't connected.
+fn average_shortest_path_length(graph: &StableUnGraph<(), ()>) -> Option<f64> {
+    let num_nodes = graph.node_count();
+    let total: Option<usize> = graph
+        .node_indices()
+        .collect::<Vec<NodeIndex>>()
+        .par_iter()
+        .map(|source| {
+            let mut distance: Vec<Option<usize>> = vec![None; graph.node_bound()];
+            let mut queue = VecDeque::from([*source]);
+            distance[source.index()] = Some(0);
+            let mut reached = 1;
+            let mut total = 0;
+            while let Some(node) = queue.pop_front() {
+                let next = distance[node.index()].unwrap() + 1;
+                for neighbor in graph.neighbors(node) {
+                    if distance[neighbor.index()].is_none() {
+                        distance[neighbor.index()] = Some(next);
+                        reached += 1;
+                        total += next;
+                        queue.push_back(neighbor);
+                    }
+                }
+            }
+            (reached == num_nodes).then_some(total)
+        })
+        .sum();
+    total.map(|total| total as f64 / (num_nodes * (num_nodes - 1)) as f64)
+}
+
+/// Compute the small-world coefficient sigma of a graph.
+///
+/// The small-world coefficient sigma is
+///
+/// ```text
+/// sigma = (C / C_r) / (L / L_r)
+/// ```
+///
+/// where `C` and `L` are the average clustering coefficient and the average
+/// shortest path length of the graph, and `C_r` and `L_r` are their averages
+/// over random graphs with the same degrees made by [`random_reference`]. A
+/// graph is commonly considered small-world if sigma is greater than `1`.
+/// The random graphs are made in parallel.
+///
+/// The graph is treated as a simple undirected graph: self-loops are
+/// ignored and parallel edges are counted once.
+///
+/// Arguments:
+///
+/// * `graph` - The graph to compute sigma for.
+/// * `num_iterations` - The number of swaps to attempt per edge when making
+///   each random graph.
+/// * `num_randomizations` - The number of random graphs to average over. If
+///   it's `0` the result is `NaN`.
+/// * `seed` - An optional seed to use for the random number generator.
+///
+/// Returns `None` if the graph has fewer than four nodes or isn't connected.
+///
+/// # Example
+/// ```rust
+/// use rustworkx_core::generators::complete_graph;
+/// use rustworkx_core::petgraph::graph::UnGraph;
+/// use rustworkx_core::small_world::sigma;
+///
+/// // No swap can be done in a complete graph, so its references are itself
+/// let graph: UnGraph<(), ()> = complete_graph(Some(6), None, || (), || ()).unwrap();
+/// assert_eq!(sigma(&graph, 5, 3, Some(42)), Some(1.));
+/// ```
+pub fn sigma<G>(
+    graph: G,
+    num_iterations: usize,
+    num_randomizations: usize,
+    seed: Option<u64>,
+) -> Option<f64>
+where
+    G: IntoEdgeReferences + IntoNodeIdentifiers + NodeIndexable,
+{
+    let simple = simple_copy(graph);
+    if simple.node_count() < 4 {
+        return None;
+    }
+    let path_length = average_shortest_path_length(&simple)?;
+    let clustering = average_clustering(&simple);
+    let (random_clustering, random_path_length) = task_seeds(seed, num_randomizations)
+        .into_par_iter()
+        .map(|seed| {
+            let random = random_reference(&simple, num_iterations, Some(seed));
+            // Rewiring keeps the random graph connected
+            let path_length = average_shortest_path_length(&random).unwrap();
+            (average_clustering(&random), path_length)
+        })
+        .reduce(|| (0., 0.), |a, b| (a.0 + b.0, a.1 + b.1));
+    let num_randomizations = num_randomizations as f64;
+    Some(
+        (clustering / (random_clustering / num_randomizations))
+            / (path_length / (random_path_length / num_randomizations)),
+    )
+}
+
+/// Compute the small-world coefficient omega of a graph.
+///
+/// The small-world coefficient omega is
+///
+/// ```text
+/// omega = L_r / L - C / C_l
+/// ```
+///
+/// where `C` and `L` are the average clustering coefficient and the average
+/// shortest path length of the graph, `L_r` is the average of the shortest
+/// path length over random graphs with the same degrees made by
+/// [`random_reference`], and `C_l` is the largest clustering coefficient of
+/// the graph and of lattice graphs with the same degrees made by
+/// [`lattice_reference`]. Omega ranges from `-1` to `1`: values close to `0`
+/// mean the graph is small-world, negative values mean it's more like a
+/// lattice and positive values mean it's more like a random graph. The
+/// reference graphs are made in parallel.
+///
+/// The graph is treated as a simple undirected graph: self-loops are
+/// ignored and parallel edges are counted once.
+///
+/// Arguments:
+///
+/// * `graph` - The graph to compute omega for.
+/// * `num_iterations` - The number of swaps to attempt per edge when making
+///   each reference graph.
+/// * `num_randomizations` - The number of random and lattice graphs to make. If
+///   it's `0` the result is `NaN`.
+/// * `seed` - An optional seed to use for the random number generator.
+///
+/// Returns `None` if the graph has fewer than four nodes or isn't connected.
+///
+/// # Example
+/// ```rust
+/// use rustworkx_core::generators::complete_graph;
+/// use rustworkx_core::petgraph::graph::UnGraph;
+/// use rustworkx_core::small_world::omega;
+///
+/// // No swap can be done in a complete graph, so its references are itself
+/// let graph: UnGraph<(), ()> = complete_graph(Some(6), None, || (), || ()).unwrap();
+/// assert_eq!(omega(&graph, 5, 3, Some(42)), Some(0.));
+/// ```
+pub fn omega<G>(
+    graph: G,
+    num_iterations: usize,
+    num_randomizations: usize,
+    seed: Option<u64>,
+) -> Option<f64>
+where
+    G: IntoEdgeReferences + IntoNodeIdentifiers + NodeIndexable,
+{
+    let simple = simple_copy(graph);
+    if simple.node_count() < 4 {
+        return None;
+    }
+    let path_length = average_shortest_path_length(&simple)?;
+    let clustering = average_clustering(&simple);
+    let (lattice_clustering, random_path_length) = task_seeds(seed, num_randomizations)
+        .into_par_iter()
+        .map(|seed| {
+            let lattice = lattice_reference(&simple, num_iterations, Some(seed));
+            let random = random_reference(&simple, num_iterations, Some(seed));
+            let path_length = average_shortest_path_length(&random).unwrap();
+            (average_clustering(&lattice), path_length)
+        })
+        .reduce(|| (0., 0.), |a, b| (a.0.max(b.0), a.1 + b.1));
+    let lattice_clustering = lattice_clustering.max(clustering);
+    Some(
+        random_path_length / num_randomizations as f64 / path_length
+            - clustering / lattice_clustering,
+    )
+}
+
+#[cfg(test)]
+mod test_small_world {
+    use super::{lattice_reference, omega, random_reference, sigma};
+    use crate::generators::{complete_graph, cycle_graph, path_graph};
+    use petgraph::graph::UnGraph;
+    use petgraph::stable_graph::StableUnGraph;
+
+    fn degrees(graph: &StableUnGraph<(), ()>) -> Vec<usize> {
+        graph
+            .node_indices()
+            .map(|n| graph.neighbors(n).count())
+            .collect()
+    }
+
+    /// A ring lattice where every node is joined to its `k` nearest
+    /// neighbors on each side
+    fn ring_lattice(n: usize, k: usize) -> UnGraph<(), ()> {
+        let mut edges = Vec::new();
+        for i in 0..n {
+            for j in 1..=k {
+                edges.push((i as u32, ((i + j) % n) as u32));
+            }
+        }
+        UnGraph::from_edges(edges)
+    }
+
+    #[test]
+    fn test_random_reference_preserves_degrees() {
+        let graph = ring_lattice(30, 3);
+        let random = random_reference(&graph, 10, Some(7));
+        assert_eq!(random.edge_count(), graph.edge_count());
+        assert_eq!(degrees(&random), vec![6; 30]);
+        // Some edges are no longer between nearby nodes on the ring
+        assert!(random.edge_indices().any(|e| {
+            let (u, v) = random.edge_endpoints(e).unwrap();
+            let distance = u.index().abs_diff(v.index());
+            distance.min(30 - distance) > 3
+        }));
+    }
+
+    #[test]
+    fn test_random_reference_seed() {
+        let graph = ring_lattice(20, 2);
+        let first = random_reference(&graph, 5, Some(3));
+        let second = random_reference(&graph, 5, Some(3));
+        let endpoints = |g: &StableUnGraph<(), ()>| {
+            g.edge_indices()
+                .map(|e| g.edge_endpoints(e).unwrap())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(endpoints(&first), endpoints(&second));
+    }
+
+    #[test]
+    fn test_random_reference_stays_connected() {
+        // A path can only be rewired into a disconnected graph, so every
+        // swap must be undone
+        let graph: UnGraph<(), ()> = path_graph(Some(8), None, || (), || (), false).unwrap();
+        let random = random_reference(&graph, 20, Some(1));
+        assert_eq!(degrees(&random), vec![1, 2, 2, 2, 2, 2, 2, 1]);
+        assert!(super::average_shortest_path_length(&random).is_some());
+    }
+
+    #[test]
+    fn test_lattice_reference_preserves_degrees() {
+        let graph = random_reference(&ring_lattice(30, 2), 10, Some(5));
+        let lattice = lattice_reference(&graph, 10, Some(5));
+        assert_eq!(degrees(&lattice), vec![4; 30]);
+        assert!(super::average_clustering(&lattice) >= super::average_clustering(&graph));
+    }
+
+    #[test]
+    fn test_too_small_or_disconnected() {
+        let graph: UnGraph<(), ()> = complete_graph(Some(3), None, || (), || ()).unwrap();
+        assert_eq!(sigma(&graph, 5, 3, Some(1)), None);
+        assert_eq!(omega(&graph, 5, 3, Some(1)), None);
+        let graph = UnGraph::<(), ()>::from_edges([(0, 1), (2, 3), (3, 4)]);
+        assert_eq!(sigma(&graph, 5, 3, Some(1)), None);
+        assert_eq!(omega(&graph, 5, 3, Some(1)), None);
+    }
+
+    #[test]
+    fn test_lattice_is_not_small_world() {
+        let graph = ring_lattice(40, 3);
+        let omega = omega(&graph, 5, 3, Some(11)).unwrap();
+        assert!(omega < -0.4, "{}", omega);
+        let cycle: UnGraph<(), ()> = cycle_graph(Some(12), None, || (), || (), false).unwrap();
+        assert!(sigma(&cycle, 5, 3, Some(11)).unwrap().is_nan());
+    }
+
+    #[test]
+    fn test_small_world_graph() {
+        // A ring lattice with a few shortcuts keeps a high clustering and
+        // gets a short average path length
+        let mut graph = ring_lattice(60, 3);
+        for (u, v) in [(0, 30), (10, 45), (20, 50), (5, 35), (15, 40)] {
+            graph.add_edge(u.into(), v.into(), ());
+        }
+        let sigma = sigma(&graph, 10, 5, Some(2)).unwrap();
+        assert!(sigma > 1., "{}", sigma);
+    }
+}
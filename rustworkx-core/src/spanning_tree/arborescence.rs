@@ -0,0 +1,396 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+use petgraph::visit::{EdgeRef, GraphProp, IntoEdgeReferences, IntoNodeIdentifiers, NodeIndexable};
+use petgraph::Directed;
+
+/// An edge in one level of the contraction hierarchy used by Edmonds' algorithm.
+#[derive(Clone, Copy)]
+struct LevelEdge {
+    source: usize,
+    target: usize,
+    weight: f64,
+    /// Index of the edge in the previous level (or in the input edge list for level 0)
+    parent: usize,
+}
+
+/// The state recorded for a level which contained at least one cycle
+struct ContractedLevel {
+    edges: Vec<LevelEdge>,
+    in_edge: Vec<Option<usize>>,
+    cycle: Vec<Option<usize>>,
+    cycle_count: usize,
+}
+
+/// Run the Chu-Liu/Edmonds algorithm on a compact edge list.
+///
+/// Returns the indices into `edges` of the selected arborescence edges or
+/// `None` if some node can't be reached from `root`.
+fn edmonds(node_count: usize, root: usize, edges: Vec<LevelEdge>) -> Option<Vec<usize>> {
+    let mut levels: Vec<ContractedLevel> = Vec::new();
+    let mut node_count = node_count;
+    let mut root = root;
+    let mut edges = edges;
+    let mut selected: Vec<usize> = loop {
+        // Find the cheapest incoming edge for every node except the root
+        let mut in_edge: Vec<Option<usize>> = vec![None; node_count];
+        for (index, edge) in edges.iter().enumerate() {
+            if edge.source == edge.target || edge.target == root {
+                continue;
+            }
+            match in_edge[edge.target] {
+                Some(current) if edges[current].weight <= edge.weight => (),
+                _ => in_edge[edge.target] = Some(index),
+            }
+        }
+        if (0..node_count).any(|node| node != root && in_edge[node].is_none()) {
+            return None;
+        }
+        // Find the cycles formed by the selected edges
+        let mut cycle: Vec<Option<usize>> = vec![None; node_count];
+        let mut visited_from: Vec<Option<usize>> = vec![None; node_count];
+        let mut cycle_count = 0;
+        for start in 0..node_count {
+            let mut node = start;
+            while node != root && visited_from[node].is_none() && cycle[node].is_none() {
+                visited_from[node] = Some(start);
+                node = edges[in_edge[node].unwrap()].source;
+            }
+            if node != root && visited_from[node] == Some(start) && cycle[node].is_none() {
+                let mut cycle_node = node;
+                loop {
+                    cycle[cycle_node] = Some(cycle_count);
+                    cycle_node = edges[in_edge[cycle_node].unwrap()].source;
+                    if cycle_node == node {
+                        break;
+                    }
+                }
+                cycle_count += 1;
+            }
+        }
+        if cycle_count == 0 {
+            break in_edge.into_iter().flatten().collect();
+        }
+        // Contract every cycle into a single node
+        let mut component: Vec<usize> = vec![0; node_count];
+        let mut next_node = cycle_count;
+        for node in 0..node_count {
+            component[node] = match cycle[node] {
+                Some(cycle_index) => cycle_index,
+                None => {
+                    next_node += 1;
+                    next_node - 1
+                }
+            };
+        }
+        let contracted_edges: Vec<LevelEdge> = edges
+            .iter()
+            .enumerate()
+            .filter(|(_, edge)| component[edge.source] != component[edge.target])
+            .map(|(index, edge)| {
+                let offset = match in_edge[edge.target] {
+                    Some(in_index) if cycle[edge.target].is_some() => edges[in_index].weight,
+                    _ => 0.,
+                };
+                LevelEdge {
+                    source: component[edge.source],
+                    target: component[edge.target],
+                    weight: edge.weight - offset,
+                    parent: index,
+                }
+            })
+            .collect();
+        root = component[root];
+        node_count = next_node;
+        levels.push(ContractedLevel {
+            edges,
+            in_edge,
+            cycle,
+            cycle_count,
+        });
+        edges = contracted_edges;
+    };
+    // Expand the contracted cycles back out level by level
+    while let Some(level) = levels.pop() {
+        let mut entered: Vec<Option<usize>> = vec![None; level.cycle_count];
+        let mut expanded: Vec<usize> = Vec::with_capacity(level.in_edge.len());
+        for index in selected {
+            let parent = edges[index].parent;
+            let target = level.edges[parent].target;
+            if let Some(cycle_index) = level.cycle[target] {
+                entered[cycle_index] = Some(target);
+            }
+            expanded.push(parent);
+        }
+        for (node, in_edge) in level.in_edge.iter().enumerate() {
+            if let Some(cycle_index) = level.cycle[node] {
+                if entered[cycle_index] != Some(node) {
+                    expanded.push(in_edge.unwrap());
+                }
+            }
+        }
+        selected = expanded;
+        edges = level.edges;
+    }
+    Some(
+        selected
+            .into_iter()
+            .map(|index| edges[index].parent)
+            .collect(),
+    )
+}
+
+/// Find a minimum spanning arborescence of a directed graph.
+///
+/// A spanning arborescence is a directed spanning tree in which every node
+/// other than the root has exactly one incoming edge and every node is
+/// reachable from the root. This function uses the Chu-Liu/Edmonds
+/// algorithm to find the spanning arborescence with the minimum total edge
+/// weight in `O(|E||V|)` time.
+///
+/// Arguments:
+///
+/// * `graph` - The directed graph to find the arborescence of.
+/// * `root` - The root of the arborescence. If `None` the root which yields
+///   the arborescence with the minimum total weight among all possible
+///   roots is used.
+/// * `weight_fn` - A callable that will be passed an edge reference for each
+///   edge in the graph and is expected to return a `Result<f64>` which if
+///   it doesn't error represents the weight of that edge.
+///
+/// Returns the edge ids of the arborescence, or `None` if no spanning
+/// arborescence exists (when no single node can reach every other node,
+/// or when `root` can't reach every node if it is specified).
+///
+/// # Example
+/// ```rust
+/// use std::convert::Infallible;
+/// use rustworkx_core::petgraph;
+/// use rustworkx_core::petgraph::visit::EdgeRef;
+/// use rustworkx_core::spanning_tree::minimum_spanning_arborescence;
+///
+/// let graph = petgraph::graph::DiGraph::<(), f64>::from_edges(&[
+///     (0, 1, 5.), (0, 2, 1.), (2, 1, 1.), (1, 3, 2.), (2, 3, 4.), (3, 2, 1.)
+/// ]);
+/// let res: Result<_, Infallible> =
+///     minimum_spanning_arborescence(&graph, Some(0.into()), |e| Ok(*e.weight()));
+/// let mut edges: Vec<(usize, usize)> = res
+///     .unwrap()
+///     .unwrap()
+///     .into_iter()
+///     .map(|e| {
+///         let (source, target) = graph.edge_endpoints(e).unwrap();
+///         (source.index(), target.index())
+///     })
+///     .collect();
+/// edges.sort();
+/// assert_eq!(edges, vec![(0, 2), (1, 3), (2, 1)]);
+/// ```
+pub fn minimum_spanning_arborescence<G, F, E>(
+    graph: G,
+    root: Option<G::NodeId>,
+    mut weight_fn: F,
+) -> Result<Option<Vec<G::EdgeId>>, E>
+where
+    G: GraphProp<EdgeType = Directed> + IntoEdgeReferences + IntoNodeIdentifiers + NodeIndexable,
+    F: FnMut(G::EdgeRef) -> Result<f64, E>,
+{
+    let mut node_map: Vec<Option<usize>> = vec![None; graph.node_bound()];
+    let mut node_count = 0;
+    for node in graph.node_identifiers() {
+        node_map[graph.to_index(node)] = Some(node_count);
+        node_count += 1;
+    }
+    if node_count == 0 {
+        return Ok(Some(Vec::new()));
+    }
+    let mut edge_ids: Vec<G::EdgeId> = Vec::new();
+    let mut edges: Vec<LevelEdge> = Vec::new();
+    for edge in graph.edge_references() {
+        let weight = weight_fn(edge)?;
+        edges.push(LevelEdge {
+            source: node_map[graph.to_index(edge.source())].unwrap(),
+            target: node_map[graph.to_index(edge.target())].unwrap(),
+            weight,
+            parent: edges.len(),
+        });
+        edge_ids.push(edge.id());
+    }
+    let selected = match root {
+        Some(root) => match edmonds(node_count, node_map[graph.to_index(root)].unwrap(), edges) {
+            Some(selected) => selected,
+            None => return Ok(None),
+        },
+        None => {
+            // Add a virtual root with an expensive edge to every node. The
+            // optimal solution will use exactly one of these edges if any
+            // spanning arborescence exists in the input graph.
+            let total: f64 = edges.iter().map(|edge| edge.weight.abs()).sum();
+            let virtual_weight = 2. * total + 1.;
+            let virtual_root = node_count;
+            let edge_count = edges.len();
+            edges.extend((0..node_count).map(|node| LevelEdge {
+                source: virtual_root,
+                target: node,
+                weight: virtual_weight,
+                parent: edge_count + node,
+            }));
+            let selected = match edmonds(node_count + 1, virtual_root, edges) {
+                Some(selected) => selected,
+                None => return Ok(None),
+            };
+            if selected
+                .iter()
+                .filter(|index| **index >= edge_count)
+                .count()
+                > 1
+            {
+                return Ok(None);
+            }
+            selected
+                .into_iter()
+                .filter(|index| *index < edge_count)
+                .collect()
+        }
+    };
+    Ok(Some(
+        selected.into_iter().map(|index| edge_ids[index]).collect(),
+    ))
+}
+
+#[cfg(test)]
+mod test_minimum_spanning_arborescence {
+    use super::minimum_spanning_arborescence;
+    use petgraph::graph::{DiGraph, EdgeIndex};
+    use petgraph::stable_graph::StableDiGraph;
+    use petgraph::visit::EdgeRef;
+    use std::convert::Infallible;
+
+    fn total_weight(graph: &DiGraph<(), f64>, edges: &[EdgeIndex]) -> f64 {
+        edges.iter().map(|e| graph[*e]).sum()
+    }
+
+    #[test]
+    fn test_empty_graph() {
+        let graph = DiGraph::<(), f64>::new();
+        let res: Result<_, Infallible> =
+            minimum_spanning_arborescence(&graph, None, |e| Ok(*e.weight()));
+        assert_eq!(res.unwrap(), Some(vec![]));
+    }
+
+    #[test]
+    fn test_single_cycle() {
+        // The cheapest incoming edges form the cycle 1 -> 2 -> 3 -> 1
+        let graph = DiGraph::<(), f64>::from_edges([
+            (0, 1, 10.),
+            (0, 2, 12.),
+            (0, 3, 11.),
+            (1, 2, 1.),
+            (2, 3, 1.),
+            (3, 1, 1.),
+        ]);
+        let res: Result<_, Infallible> =
+            minimum_spanning_arborescence(&graph, Some(0.into()), |e| Ok(*e.weight()));
+        let edges = res.unwrap().unwrap();
+        assert_eq!(edges.len(), 3);
+        assert_eq!(total_weight(&graph, &edges), 12.);
+    }
+
+    #[test]
+    fn test_nested_cycles() {
+        let graph = DiGraph::<(), f64>::from_edges([
+            (0, 1, 20.),
+            (0, 4, 30.),
+            (1, 2, 1.),
+            (2, 1, 1.),
+            (2, 3, 2.),
+            (3, 2, 5.),
+            (3, 4, 1.),
+            (4, 3, 1.),
+            (4, 1, 3.),
+        ]);
+        let res: Result<_, Infallible> =
+            minimum_spanning_arborescence(&graph, Some(0.into()), |e| Ok(*e.weight()));
+        let edges = res.unwrap().unwrap();
+        assert_eq!(edges.len(), 4);
+        assert_eq!(total_weight(&graph, &edges), 24.);
+        let mut targets: Vec<usize> = edges
+            .iter()
+            .map(|e| graph.edge_endpoints(*e).unwrap().1.index())
+            .collect();
+        targets.sort();
+        assert_eq!(targets, vec![1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn test_unreachable_node() {
+        let graph = DiGraph::<(), f64>::from_edges([(0, 1, 1.), (2, 1, 1.)]);
+        let res: Result<_, Infallible> =
+            minimum_spanning_arborescence(&graph, Some(0.into()), |e| Ok(*e.weight()));
+        assert_eq!(res.unwrap(), None);
+        let res: Result<_, Infallible> =
+            minimum_spanning_arborescence(&graph, None, |e| Ok(*e.weight()));
+        assert_eq!(res.unwrap(), None);
+    }
+
+    #[test]
+    fn test_best_root() {
+        let graph =
+            DiGraph::<(), f64>::from_edges([(0, 1, 5.), (1, 0, 1.), (1, 2, 1.), (2, 1, 7.)]);
+        let res: Result<_, Infallible> =
+            minimum_spanning_arborescence(&graph, None, |e| Ok(*e.weight()));
+        let edges = res.unwrap().unwrap();
+        assert_eq!(total_weight(&graph, &edges), 2.);
+        let res: Result<_, Infallible> =
+            minimum_spanning_arborescence(&graph, Some(0.into()), |e| Ok(*e.weight()));
+        assert_eq!(total_weight(&graph, &res.unwrap().unwrap()), 6.);
+    }
+
+    #[test]
+    fn test_stable_graph_with_holes() {
+        let mut graph = StableDiGraph::<(), f64>::new();
+        let a = graph.add_node(());
+        let b = graph.add_node(());
+        let c = graph.add_node(());
+        let d = graph.add_node(());
+        graph.add_edge(a, c, 2.);
+        graph.add_edge(c, d, 1.);
+        graph.add_edge(a, d, 5.);
+        graph.remove_node(b);
+        let res: Result<_, Infallible> =
+            minimum_spanning_arborescence(&graph, Some(a), |e| Ok(*e.weight()));
+        let mut edges: Vec<(usize, usize)> = res
+            .unwrap()
+            .unwrap()
+            .into_iter()
+            .map(|e| {
+                let (s, t) = graph.edge_endpoints(e).unwrap();
+                (s.index(), t.index())
+            })
+            .collect();
+        edges.sort();
+        assert_eq!(edges, vec![(0, 2), (2, 3)]);
+    }
+
+    #[test]
+    fn test_weight_fn_error() {
+        let graph = DiGraph::<(), f64>::from_edges([(0, 1, 1.)]);
+        let res = minimum_spanning_arborescence(&graph, None, |e| {
+            if e.id().index() == 0 {
+                Err("bad weight")
+            } else {
+                Ok(1.)
+            }
+        });
+        assert_eq!(res, Err("bad weight"));
+    }
+}
@@ -0,0 +1,231 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+use std::cmp::Ordering;
+use std::sync::atomic::{AtomicUsize, Ordering as AtomicOrdering};
+
+use crate::par::CondIterator;
+use petgraph::unionfind::UnionFind;
+use petgraph::visit::{EdgeRef, GraphProp, IntoEdgeReferences, NodeIndexable};
+use petgraph::Undirected;
+
+/// Compare two edges by weight, breaking ties by their position in the edge
+/// list so that every edge has a distinct rank.
+#[inline]
+fn is_lighter(edges: &[(usize, usize, f64)], a: usize, b: usize) -> bool {
+    match edges[a].2.partial_cmp(&edges[b].2) {
+        Some(Ordering::Less) => true,
+        Some(Ordering::Greater) => false,
+        _ => a < b,
+    }
+}
+
+/// Find the edges in the minimum spanning tree or forest of an undirected
+/// graph using Borůvka's algorithm.
+///
+/// Borůvka's algorithm runs in rounds: in each round every connected
+/// component of the forest built so far selects the lightest edge leaving it,
+/// and all the selected edges are added to the forest at once. Each round at
+/// least halves the number of components, so at most `log2(|V|)` rounds are
+/// needed and the overall running time is `O(|E| log |V|)`. Ties between
+/// equal weights are broken by the order of the edges in
+/// [`edge_references`](petgraph::visit::IntoEdgeReferences::edge_references).
+///
+/// This function is multithreaded and the search for the lightest edges in
+/// each round will run in parallel if the number of edges in the graph is
+/// above the value of ``parallel_threshold``. If the function will be running
+/// in parallel the env var ``RAYON_NUM_THREADS`` can be used to adjust how
+/// many threads will be used.
+///
+/// Arguments:
+///
+/// * `graph` - The undirected graph to find the minimum spanning tree of.
+/// * `weight_fn` - A callable that will be passed an edge reference for each
+///   edge in the graph and is expected to return a `Result<f64>` which if it
+///   doesn't error represents the weight of that edge. It is called exactly
+///   once for every edge, before any parallel work is started.
+/// * `parallel_threshold` - The number of edges to find the minimum spanning
+///   tree in parallel at, if the number of edges in `graph` is less than this
+///   value it will run in a single thread.
+///
+/// Returns the edge ids of the minimum spanning tree (or forest, if the graph
+/// is not connected).
+///
+/// # Example
+/// ```rust
+/// use std::convert::Infallible;
+/// use rustworkx_core::petgraph;
+/// use rustworkx_core::spanning_tree::minimum_spanning_edges;
+///
+/// let graph = petgraph::graph::UnGraph::<(), f64>::from_edges(&[
+///     (0, 1, 1.), (1, 2, 2.), (2, 3, 1.), (3, 0, 3.), (0, 2, 4.)
+/// ]);
+/// let res: Result<_, Infallible> = minimum_spanning_edges(&graph, |e| Ok(*e.weight()), 1000);
+/// let weight: f64 = res.unwrap().into_iter().map(|e| graph[e]).sum();
+/// assert_eq!(weight, 4.);
+/// ```
+pub fn minimum_spanning_edges<G, F, E>(
+    graph: G,
+    mut weight_fn: F,
+    parallel_threshold: usize,
+) -> Result<Vec<G::EdgeId>, E>
+where
+    G: GraphProp<EdgeType = Undirected> + IntoEdgeReferences + NodeIndexable,
+    F: FnMut(G::EdgeRef) -> Result<f64, E>,
+{
+    let node_bound = graph.node_bound();
+    let mut edge_ids: Vec<G::EdgeId> = Vec::new();
+    let mut edges: Vec<(usize, usize, f64)> = Vec::new();
+    for edge in graph.edge_references() {
+        edges.push((
+            graph.to_index(edge.source()),
+            graph.to_index(edge.target()),
+            weight_fn(edge)?,
+        ));
+        edge_ids.push(edge.id());
+    }
+    let parallel = edges.len() >= parallel_threshold;
+
+    // The component every node currently belongs to
+    let mut component: Vec<usize> = (0..node_bound).collect();
+    let mut union_find = UnionFind::<usize>::new(node_bound);
+    // The edges which still connect two distinct components
+    let mut candidates: Vec<usize> = (0..edges.len())
+        .filter(|index| edges[*index].0 != edges[*index].1)
+        .collect();
+    let cheapest: Vec<AtomicUsize> = (0..node_bound)
+        .map(|_| AtomicUsize::new(usize::MAX))
+        .collect();
+    let mut tree: Vec<G::EdgeId> = Vec::new();
+    while !candidates.is_empty() {
+        let update = |comp: usize, index: usize| {
+            let mut current = cheapest[comp].load(AtomicOrdering::Relaxed);
+            while current == usize::MAX || is_lighter(&edges, index, current) {
+                match cheapest[comp].compare_exchange_weak(
+                    current,
+                    index,
+                    AtomicOrdering::Relaxed,
+                    AtomicOrdering::Relaxed,
+                ) {
+                    Ok(_) => break,
+                    Err(actual) => current = actual,
+                }
+            }
+        };
+        CondIterator::new(&candidates, parallel).for_each(|index| {
+            let (source, target, _) = edges[*index];
+            update(component[source], *index);
+            update(component[target], *index);
+        });
+        for best in &cheapest {
+            let index = best.swap(usize::MAX, AtomicOrdering::Relaxed);
+            if index != usize::MAX {
+                let (source, target, _) = edges[index];
+                if union_find.union(source, target) {
+                    tree.push(edge_ids[index]);
+                }
+            }
+        }
+        component = CondIterator::new(0..node_bound, parallel)
+            .map(|node| union_find.find(node))
+            .collect();
+        candidates = CondIterator::new(candidates, parallel)
+            .filter(|index| {
+                let (source, target, _) = edges[*index];
+                component[source] != component[target]
+            })
+            .collect();
+    }
+    Ok(tree)
+}
+
+#[cfg(test)]
+mod test_boruvka {
+    use super::minimum_spanning_edges;
+    use petgraph::graph::{NodeIndex, UnGraph};
+    use std::convert::Infallible;
+
+    #[test]
+    fn test_empty_graph() {
+        let graph = UnGraph::<(), f64>::new_undirected();
+        let res: Result<_, Infallible> = minimum_spanning_edges(&graph, |_| Ok(1.), 1);
+        assert!(res.unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_minimum_spanning_tree() {
+        let graph = UnGraph::<(), f64>::from_edges([
+            (0, 1, 7.),
+            (0, 3, 5.),
+            (1, 2, 8.),
+            (1, 3, 9.),
+            (1, 4, 7.),
+            (2, 4, 5.),
+            (3, 4, 15.),
+            (3, 5, 6.),
+            (4, 5, 8.),
+            (4, 6, 9.),
+            (5, 6, 11.),
+        ]);
+        for parallel_threshold in [1, usize::MAX] {
+            let res: Result<_, Infallible> =
+                minimum_spanning_edges(&graph, |e| Ok(*e.weight()), parallel_threshold);
+            let mut tree: Vec<usize> = res.unwrap().into_iter().map(|e| e.index()).collect();
+            tree.sort();
+            assert_eq!(tree, vec![0, 1, 4, 5, 7, 9]);
+        }
+    }
+
+    #[test]
+    fn test_disconnected_graph_with_self_loops() {
+        let graph = UnGraph::<(), f64>::from_edges([
+            (0, 0, -1.),
+            (0, 1, 1.),
+            (1, 2, 2.),
+            (2, 0, 3.),
+            (3, 4, 1.),
+            (3, 4, 0.5),
+            (5, 5, 1.),
+        ]);
+        let res: Result<_, Infallible> = minimum_spanning_edges(&graph, |e| Ok(*e.weight()), 1);
+        let mut tree: Vec<usize> = res.unwrap().into_iter().map(|e| e.index()).collect();
+        tree.sort();
+        assert_eq!(tree, vec![1, 2, 5]);
+    }
+
+    #[test]
+    fn test_equal_weights() {
+        // A complete graph where every edge has the same weight
+        let mut graph = UnGraph::<(), f64>::new_undirected();
+        let nodes: Vec<NodeIndex> = (0..30).map(|_| graph.add_node(())).collect();
+        for i in 0..30 {
+            for j in i + 1..30 {
+                graph.add_edge(nodes[i], nodes[j], 1.);
+            }
+        }
+        let res: Result<_, Infallible> = minimum_spanning_edges(&graph, |_| Ok(1.), 1);
+        let tree = res.unwrap();
+        assert_eq!(tree.len(), 29);
+        let mut union_find = petgraph::unionfind::UnionFind::<usize>::new(30);
+        for edge in tree {
+            let (a, b) = graph.edge_endpoints(edge).unwrap();
+            assert!(union_find.union(a.index(), b.index()));
+        }
+    }
+
+    #[test]
+    fn test_weight_fn_error() {
+        let graph = UnGraph::<(), f64>::from_edges([(0, 1, 1.)]);
+        let res = minimum_spanning_edges(&graph, |_| Err("error"), 1);
+        assert_eq!(res, Err("error"));
+    }
+}
@@ -0,0 +1,417 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+use std::collections::VecDeque;
+
+use petgraph::unionfind::UnionFind;
+use petgraph::visit::{EdgeRef, GraphProp, IntoEdgeReferences, IntoNodeIdentifiers, NodeIndexable};
+use petgraph::Undirected;
+
+/// A spanning forest under construction, with the edges of the graph
+/// relabeled to their position in the order of nondecreasing weight.
+struct Forest {
+    edges: Vec<(usize, usize, f64)>,
+    in_tree: Vec<bool>,
+    /// The tree edges at every node, as pairs of the other endpoint and the
+    /// position of the edge.
+    adjacency: Vec<Vec<(usize, usize)>>,
+    caps: Vec<usize>,
+}
+
+impl Forest {
+    fn has_room(&self, node: usize) -> bool {
+        self.adjacency[node].len() < self.caps[node]
+    }
+
+    fn add(&mut self, edge: usize) {
+        let (source, target, _) = self.edges[edge];
+        self.in_tree[edge] = true;
+        self.adjacency[source].push((target, edge));
+        self.adjacency[target].push((source, edge));
+    }
+
+    fn remove(&mut self, edge: usize) {
+        let (source, target, _) = self.edges[edge];
+        self.in_tree[edge] = false;
+        self.adjacency[source].retain(|(_, e)| *e != edge);
+        self.adjacency[target].retain(|(_, e)| *e != edge);
+    }
+
+    fn components(&self) -> UnionFind<usize> {
+        let mut components = UnionFind::new(self.adjacency.len());
+        for (edge, (source, target, _)) in self.edges.iter().enumerate() {
+            if self.in_tree[edge] {
+                components.union(*source, *target);
+            }
+        }
+        components
+    }
+
+    /// Return the nodes reachable from `start` in the forest without using
+    /// the edge `skip`.
+    fn reachable(&self, start: usize, skip: usize) -> Vec<bool> {
+        let mut seen: Vec<bool> = vec![false; self.adjacency.len()];
+        let mut stack: Vec<usize> = vec![start];
+        seen[start] = true;
+        while let Some(node) = stack.pop() {
+            for (other, edge) in &self.adjacency[node] {
+                if *edge != skip && !seen[*other] {
+                    seen[*other] = true;
+                    stack.push(*other);
+                }
+            }
+        }
+        seen
+    }
+
+    /// Return the positions of the edges on the path of the forest from
+    /// `source` to `target`, if they are in the same tree.
+    fn path(&self, source: usize, target: usize) -> Option<Vec<usize>> {
+        let mut via: Vec<Option<(usize, usize)>> = vec![None; self.adjacency.len()];
+        let mut queue: VecDeque<usize> = VecDeque::from([source]);
+        via[source] = Some((source, usize::MAX));
+        while let Some(node) = queue.pop_front() {
+            if node == target {
+                let mut path: Vec<usize> = Vec::new();
+                let mut current = target;
+                while current != source {
+                    let (previous, edge) = via[current].unwrap();
+                    path.push(edge);
+                    current = previous;
+                }
+                return Some(path);
+            }
+            for (other, edge) in &self.adjacency[node] {
+                if via[*other].is_none() {
+                    via[*other] = Some((node, *edge));
+                    queue.push_back(*other);
+                }
+            }
+        }
+        None
+    }
+
+    /// Join two trees of the forest with the edge `edge` whose endpoint
+    /// `full` has no room left, by replacing a tree edge at `full` with an
+    /// edge that reconnects the part of the tree it cut off.
+    fn join_through_full_node(&mut self, edge: usize, full: usize) -> bool {
+        let (source, target, _) = self.edges[edge];
+        let other = if source == full { target } else { source };
+        if !self.has_room(other) {
+            return false;
+        }
+        for (neighbor, removed) in self.adjacency[full].clone() {
+            let cut_off = self.reachable(neighbor, removed);
+            // The cut off part is reconnected to the rest of the forest by an
+            // edge with room at both endpoints once `edge` is added, which
+            // leaves no room at `full` and one less at `other`
+            let replacement = (0..self.edges.len()).find(|candidate| {
+                let (a, b, _) = self.edges[*candidate];
+                if self.in_tree[*candidate] || *candidate == edge || cut_off[a] == cut_off[b] {
+                    return false;
+                }
+                let room = |node: usize| {
+                    let degree = self.adjacency[node].len() - usize::from(node == neighbor)
+                        + usize::from(node == other);
+                    node != full && degree < self.caps[node]
+                };
+                room(a) && room(b)
+            });
+            if let Some(replacement) = replacement {
+                self.remove(removed);
+                self.add(edge);
+                self.add(replacement);
+                return true;
+            }
+        }
+        false
+    }
+}
+
+/// Find a spanning tree of an undirected graph with a small total weight
+/// whose nodes don't exceed a maximum degree.
+///
+/// Finding a minimum spanning tree with degree bounds is NP-hard, and even
+/// deciding if any spanning tree satisfies them is (with a bound of 2 for
+/// every node it's the Hamiltonian path problem). This heuristic first runs
+/// Kruskal's algorithm, skipping the edges at nodes that already reached
+/// their bound. If that doesn't connect the graph, it joins the remaining
+/// trees with edges at nodes without room by replacing one of their tree
+/// edges with an edge that reconnects the part of the tree it cut off.
+/// Finally it repeatedly exchanges a tree edge for a lighter edge that closes
+/// a cycle through it, as long as the degree bounds are respected, until no
+/// exchange reduces the weight or `max_passes` passes over the edges were
+/// made. Each pass takes `O(|E| |V|)` time.
+///
+/// If the graph isn't connected this finds a spanning forest with one tree
+/// per connected component instead. Self-loops are ignored.
+///
+/// Arguments:
+///
+/// * `graph` - The undirected graph to find a spanning tree of.
+/// * `degree_fn` - A callable that will be passed the id of each node in the
+///   graph and is expected to return the largest degree that node can have
+///   in the spanning tree.
+/// * `weight_fn` - A callable that will be passed an edge reference for each
+///   edge in the graph and is expected to return a `Result<f64>` which if it
+///   doesn't error represents the weight of that edge.
+/// * `max_passes` - The maximum number of passes of edge exchanges.
+///
+/// Returns the edge ids of the spanning tree (or forest), or `None` if the
+/// heuristic didn't find one that respects the degree bounds.
+///
+/// # Example
+/// ```rust
+/// use std::convert::Infallible;
+/// use rustworkx_core::petgraph::graph::UnGraph;
+/// use rustworkx_core::spanning_tree::degree_constrained_spanning_tree;
+///
+/// // A star is the minimum spanning tree, but the center can have 2 edges
+/// let graph = UnGraph::<(), f64>::from_edges([
+///     (0, 1, 1.), (0, 2, 1.), (0, 3, 1.), (0, 4, 1.),
+///     (1, 2, 3.), (2, 3, 3.), (3, 4, 3.), (4, 1, 3.),
+/// ]);
+/// let res: Result<_, Infallible> =
+///     degree_constrained_spanning_tree(&graph, |_| 2, |e| Ok(*e.weight()), 100);
+/// let tree = res.unwrap().unwrap();
+/// let weight: f64 = tree.iter().map(|e| graph[*e]).sum();
+/// assert_eq!(tree.len(), 4);
+/// assert_eq!(weight, 8.);
+/// ```
+pub fn degree_constrained_spanning_tree<G, D, F, E>(
+    graph: G,
+    mut degree_fn: D,
+    mut weight_fn: F,
+    max_passes: usize,
+) -> Result<Option<Vec<G::EdgeId>>, E>
+where
+    G: GraphProp<EdgeType = Undirected> + IntoEdgeReferences + IntoNodeIdentifiers + NodeIndexable,
+    D: FnMut(G::NodeId) -> usize,
+    F: FnMut(G::EdgeRef) -> Result<f64, E>,
+{
+    let node_bound = graph.node_bound();
+    let mut caps: Vec<usize> = vec![0; node_bound];
+    for node in graph.node_identifiers() {
+        caps[graph.to_index(node)] = degree_fn(node);
+    }
+    let mut edges: Vec<(usize, usize, f64, G::EdgeId)> = Vec::new();
+    for edge in graph.edge_references() {
+        let source = graph.to_index(edge.source());
+        let target = graph.to_index(edge.target());
+        if source != target {
+            edges.push((source, target, weight_fn(edge)?, edge.id()));
+        }
+    }
+    edges.sort_by(|a, b| a.2.total_cmp(&b.2));
+    let mut forest = Forest {
+        edges: edges.iter().map(|(s, t, w, _)| (*s, *t, *w)).collect(),
+        in_tree: vec![false; edges.len()],
+        adjacency: vec![Vec::new(); node_bound],
+        caps,
+    };
+
+    // Kruskal's algorithm with the degree bounds
+    let mut tree_components: UnionFind<usize> = UnionFind::new(node_bound);
+    for edge in 0..forest.edges.len() {
+        let (source, target, _) = forest.edges[edge];
+        if forest.has_room(source)
+            && forest.has_room(target)
+            && tree_components.union(source, target)
+        {
+            forest.add(edge);
+        }
+    }
+
+    // Join the trees that are in the same connected component of the graph
+    loop {
+        let components = forest.components();
+        let joining = (0..forest.edges.len()).find_map(|edge| {
+            let (source, target, _) = forest.edges[edge];
+            if components.equiv(source, target) {
+                return None;
+            }
+            if forest.has_room(source) && forest.has_room(target) {
+                forest.add(edge);
+                return Some(());
+            }
+            let full = if forest.has_room(source) {
+                target
+            } else {
+                source
+            };
+            forest.join_through_full_node(edge, full).then_some(())
+        });
+        if joining.is_none() {
+            break;
+        }
+    }
+    let tree_components = forest.components();
+    if (0..forest.edges.len()).any(|edge| {
+        let (source, target, _) = forest.edges[edge];
+        !tree_components.equiv(source, target)
+    }) {
+        return Ok(None);
+    }
+
+    // Exchange tree edges for lighter edges closing a cycle through them
+    for _ in 0..max_passes {
+        let mut improved = false;
+        for edge in 0..forest.edges.len() {
+            if forest.in_tree[edge] {
+                continue;
+            }
+            let (source, target, weight) = forest.edges[edge];
+            let path = match forest.path(source, target) {
+                Some(path) => path,
+                None => continue,
+            };
+            let fits = |removed: usize| {
+                let (a, b, _) = forest.edges[removed];
+                [source, target].iter().all(|node| {
+                    let freed = usize::from(a == *node || b == *node);
+                    forest.adjacency[*node].len() + 1 - freed <= forest.caps[*node]
+                })
+            };
+            let heaviest = path
+                .into_iter()
+                .filter(|removed| forest.edges[*removed].2 > weight && fits(*removed))
+                .max_by(|a, b| forest.edges[*a].2.total_cmp(&forest.edges[*b].2));
+            if let Some(removed) = heaviest {
+                forest.remove(removed);
+                forest.add(edge);
+                improved = true;
+            }
+        }
+        if !improved {
+            break;
+        }
+    }
+    Ok(Some(
+        (0..edges.len())
+            .filter(|edge| forest.in_tree[*edge])
+            .map(|edge| edges[edge].3)
+            .collect(),
+    ))
+}
+
+#[cfg(test)]
+mod test_degree_constrained_spanning_tree {
+    use super::degree_constrained_spanning_tree;
+    use crate::petgraph::graph::{NodeIndex, UnGraph};
+    use crate::petgraph::visit::EdgeRef;
+    use std::convert::Infallible;
+
+    fn check(graph: &UnGraph<(), f64>, caps: &[usize], tree: &[crate::petgraph::graph::EdgeIndex]) {
+        let mut degree: Vec<usize> = vec![0; graph.node_count()];
+        let mut components = petgraph::unionfind::UnionFind::new(graph.node_count());
+        for edge in tree {
+            let (a, b) = graph.edge_endpoints(*edge).unwrap();
+            degree[a.index()] += 1;
+            degree[b.index()] += 1;
+            assert!(components.union(a.index(), b.index()));
+        }
+        for node in graph.node_indices() {
+            assert!(degree[node.index()] <= caps[node.index()]);
+        }
+    }
+
+    #[test]
+    fn test_unconstrained_is_minimum_spanning_tree() {
+        let graph = UnGraph::<(), f64>::from_edges([
+            (0, 1, 4.),
+            (0, 2, 1.),
+            (1, 2, 2.),
+            (1, 3, 5.),
+            (2, 3, 8.),
+            (3, 4, 3.),
+        ]);
+        let res: Result<_, Infallible> =
+            degree_constrained_spanning_tree(&graph, |_| usize::MAX, |e| Ok(*e.weight()), 10);
+        let tree = res.unwrap().unwrap();
+        let weight: f64 = tree.iter().map(|e| graph[*e]).sum();
+        assert_eq!(tree.len(), 4);
+        assert_eq!(weight, 11.);
+    }
+
+    #[test]
+    fn test_degree_bound_of_two_on_complete_graph() {
+        let mut graph = UnGraph::<(), f64>::default();
+        for _ in 0..8 {
+            graph.add_node(());
+        }
+        for i in 0..8_usize {
+            for j in (i + 1)..8 {
+                let weight = if i == 0 { 1. } else { (i * j % 7 + 2) as f64 };
+                graph.add_edge(NodeIndex::new(i), NodeIndex::new(j), weight);
+            }
+        }
+        let res: Result<_, Infallible> =
+            degree_constrained_spanning_tree(&graph, |_| 2, |e| Ok(*e.weight()), 100);
+        let tree = res.unwrap().unwrap();
+        assert_eq!(tree.len(), 7);
+        check(&graph, &[2; 8], &tree);
+    }
+
+    #[test]
+    fn test_repair_through_full_node() {
+        // Kruskal connects 0 to 1 and 2 first, which leaves no room at 0 for
+        // the only edge to 3
+        let graph =
+            UnGraph::<(), f64>::from_edges([(0, 1, 1.), (0, 2, 1.), (1, 2, 5.), (0, 3, 10.)]);
+        let res: Result<_, Infallible> =
+            degree_constrained_spanning_tree(&graph, |_| 2, |e| Ok(*e.weight()), 10);
+        let tree = res.unwrap().unwrap();
+        assert_eq!(tree.len(), 3);
+        check(&graph, &[2; 4], &tree);
+        let weight: f64 = tree.iter().map(|e| graph[*e]).sum();
+        assert_eq!(weight, 16.);
+    }
+
+    #[test]
+    fn test_infeasible() {
+        // A star can't have a spanning tree with a maximum degree of 2
+        let graph = UnGraph::<(), f64>::from_edges([(0, 1, 1.), (0, 2, 1.), (0, 3, 1.)]);
+        let res: Result<_, Infallible> =
+            degree_constrained_spanning_tree(&graph, |_| 2, |e| Ok(*e.weight()), 10);
+        assert_eq!(res.unwrap(), None);
+    }
+
+    #[test]
+    fn test_per_node_bounds_and_forest() {
+        let graph = UnGraph::<(), f64>::from_edges([
+            (0, 1, 1.),
+            (0, 2, 1.),
+            (0, 3, 1.),
+            (1, 2, 2.),
+            (2, 3, 2.),
+            (4, 5, 1.),
+            (5, 5, 0.),
+        ]);
+        let caps = [1, 3, 3, 3, 1, 1];
+        let res: Result<_, Infallible> =
+            degree_constrained_spanning_tree(&graph, |n| caps[n.index()], |e| Ok(*e.weight()), 10);
+        let tree = res.unwrap().unwrap();
+        assert_eq!(tree.len(), 4);
+        check(&graph, &caps, &tree);
+        assert!(tree
+            .iter()
+            .all(|e| graph.edge_references().any(|r| r.id() == *e)));
+    }
+
+    #[test]
+    fn test_error_propagates() {
+        let graph = UnGraph::<(), f64>::from_edges([(0, 1, 1.)]);
+        let res: Result<_, &str> =
+            degree_constrained_spanning_tree(&graph, |_| 2, |_| Err("bad"), 10);
+        assert_eq!(res, Err("bad"));
+    }
+}
@@ -0,0 +1,332 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+use std::collections::BinaryHeap;
+
+use petgraph::unionfind::UnionFind;
+use petgraph::visit::{EdgeRef, GraphProp, IntoEdgeReferences, NodeIndexable};
+use petgraph::Undirected;
+
+use crate::min_scored::MinScored;
+
+type WeightedTrees<T, E> = Result<Vec<(f64, Vec<T>)>, E>;
+
+/// A subset of the spanning trees of a graph, defined by a set of edges which
+/// all its trees must contain and a set of edges none of its trees contain.
+struct Partition {
+    included: Vec<usize>,
+    excluded: Vec<usize>,
+    tree: Vec<usize>,
+}
+
+/// Run Kruskal's algorithm on the edges (sorted by weight) while forcing
+/// the `included` edges into the tree and skipping the `excluded` edges.
+///
+/// Returns the total weight and the edges of the tree, or `None` if the
+/// constraints don't allow a tree with `tree_size` edges.
+fn constrained_kruskal(
+    node_bound: usize,
+    edges: &[(usize, usize, f64)],
+    tree_size: usize,
+    included: &[usize],
+    excluded: &[usize],
+) -> Option<(f64, Vec<usize>)> {
+    let mut skip: Vec<bool> = vec![false; edges.len()];
+    let mut union_find = UnionFind::<usize>::new(node_bound);
+    let mut tree: Vec<usize> = Vec::with_capacity(tree_size);
+    let mut weight = 0.;
+    for index in included {
+        let (source, target, edge_weight) = edges[*index];
+        union_find.union(source, target);
+        skip[*index] = true;
+        weight += edge_weight;
+        tree.push(*index);
+    }
+    for index in excluded {
+        skip[*index] = true;
+    }
+    for (index, (source, target, edge_weight)) in edges.iter().enumerate() {
+        if tree.len() == tree_size {
+            break;
+        }
+        if !skip[index] && union_find.union(*source, *target) {
+            weight += edge_weight;
+            tree.push(index);
+        }
+    }
+    if tree.len() == tree_size {
+        Some((weight, tree))
+    } else {
+        None
+    }
+}
+
+/// Find the `k` spanning trees of an undirected graph with the smallest
+/// total weight.
+///
+/// The spanning trees are enumerated in order of nondecreasing weight using
+/// the partitioning scheme of Sörensen and Janssens [1]: after the
+/// minimum spanning tree of a subset of the spanning trees is found, the
+/// remaining trees in that subset are split into disjoint subsets by
+/// alternately forcing and forbidding the tree's edges, and each new subset is
+/// bounded by its own minimum spanning tree. Each step requires at most
+/// `|V| - 1` constrained runs of Kruskal's algorithm, so the overall running
+/// time is `O(k |V| |E| α(|V|))`.
+///
+/// If the graph is disconnected the spanning forests with the smallest total
+/// weights are found instead. Parallel edges are treated as distinct, so
+/// trees which differ only by which of a set of parallel edges they use are
+/// returned separately.
+///
+/// Arguments:
+///
+/// * `graph` - The undirected graph to find spanning trees of.
+/// * `k` - The maximum number of spanning trees to return.
+/// * `weight_fn` - A callable that will be passed an edge reference for each
+///   edge in the graph and is expected to return a `Result<f64>` which if it
+///   doesn't error represents the weight of that edge. Edges are ordered
+///   with [`f64::total_cmp`], so `NaN` weights don't cause a panic, but the
+///   order of the trees which contain them is unspecified.
+///
+/// Returns a vector of up to `k` tuples of the total weight and the edge ids
+/// of a spanning tree, in nondecreasing order of weight. Fewer than `k` trees
+/// are returned if the graph doesn't have `k` distinct spanning trees.
+///
+/// # Example
+/// ```rust
+/// use std::convert::Infallible;
+/// use rustworkx_core::petgraph;
+/// use rustworkx_core::spanning_tree::k_smallest_spanning_trees;
+///
+/// let graph = petgraph::graph::UnGraph::<(), f64>::from_edges(&[
+///     (0, 1, 1.), (1, 2, 2.), (2, 0, 3.)
+/// ]);
+/// let res: Result<_, Infallible> =
+///     k_smallest_spanning_trees(&graph, 5, |e| Ok(*e.weight()));
+/// let weights: Vec<f64> = res.unwrap().into_iter().map(|(weight, _)| weight).collect();
+/// assert_eq!(weights, vec![3., 4., 5.]);
+/// ```
+///
+/// [1]: Sörensen, Kenneth and Janssens, Gerrit K.
+///     "An algorithm to generate all spanning trees of a graph in order of
+///     increasing cost." Pesquisa Operacional 25 (2005): 219-229.
+///     <https://doi.org/10.1590/S0101-74382005000200004>
+pub fn k_smallest_spanning_trees<G, F, E>(
+    graph: G,
+    k: usize,
+    mut weight_fn: F,
+) -> WeightedTrees<G::EdgeId, E>
+where
+    G: GraphProp<EdgeType = Undirected> + IntoEdgeReferences + NodeIndexable,
+    F: FnMut(G::EdgeRef) -> Result<f64, E>,
+{
+    let node_bound = graph.node_bound();
+    let mut edge_ids: Vec<G::EdgeId> = Vec::new();
+    let mut edges: Vec<(usize, usize, f64)> = Vec::new();
+    for edge in graph.edge_references() {
+        edges.push((
+            graph.to_index(edge.source()),
+            graph.to_index(edge.target()),
+            weight_fn(edge)?,
+        ));
+        edge_ids.push(edge.id());
+    }
+    let mut order: Vec<usize> = (0..edges.len()).collect();
+    order.sort_by(|a, b| edges[*a].2.total_cmp(&edges[*b].2));
+    let edge_ids: Vec<G::EdgeId> = order.iter().map(|index| edge_ids[*index]).collect();
+    let edges: Vec<(usize, usize, f64)> = order.into_iter().map(|index| edges[index]).collect();
+
+    // k can be far larger than the number of trees, for example to ask for
+    // all of them, so the output isn't preallocated
+    let mut out: Vec<(f64, Vec<G::EdgeId>)> = Vec::new();
+    if k == 0 {
+        return Ok(out);
+    }
+    // The unconstrained minimum spanning forest defines how many edges every
+    // other spanning forest has.
+    let mut union_find = UnionFind::<usize>::new(node_bound);
+    let mut weight = 0.;
+    let mut tree: Vec<usize> = Vec::new();
+    for (index, (source, target, edge_weight)) in edges.iter().enumerate() {
+        if union_find.union(*source, *target) {
+            weight += edge_weight;
+            tree.push(index);
+        }
+    }
+    let tree_size = tree.len();
+    let mut heap: BinaryHeap<MinScored<f64, Partition>> = BinaryHeap::new();
+    heap.push(MinScored(
+        weight,
+        Partition {
+            included: Vec::new(),
+            excluded: Vec::new(),
+            tree,
+        },
+    ));
+    while let Some(MinScored(weight, partition)) = heap.pop() {
+        let free: Vec<usize> = partition
+            .tree
+            .iter()
+            .filter(|index| !partition.included.contains(index))
+            .copied()
+            .collect();
+        out.push((
+            weight,
+            partition
+                .tree
+                .iter()
+                .map(|index| edge_ids[*index])
+                .collect(),
+        ));
+        if out.len() == k {
+            break;
+        }
+        let mut included = partition.included.clone();
+        for edge in free {
+            let mut excluded = partition.excluded.clone();
+            excluded.push(edge);
+            if let Some((weight, tree)) =
+                constrained_kruskal(node_bound, &edges, tree_size, &included, &excluded)
+            {
+                heap.push(MinScored(
+                    weight,
+                    Partition {
+                        included: included.clone(),
+                        excluded,
+                        tree,
+                    },
+                ));
+            }
+            included.push(edge);
+        }
+    }
+    Ok(out)
+}
+
+#[cfg(test)]
+mod test_k_smallest_spanning_trees {
+    use super::k_smallest_spanning_trees;
+    use hashbrown::HashSet;
+    use petgraph::graph::UnGraph;
+    use std::convert::Infallible;
+
+    #[test]
+    fn test_empty_graph() {
+        let graph = UnGraph::<(), f64>::new_undirected();
+        let res: Result<_, Infallible> = k_smallest_spanning_trees(&graph, 3, |_| Ok(1.));
+        assert_eq!(res.unwrap(), vec![(0., vec![])]);
+    }
+
+    #[test]
+    fn test_k_zero() {
+        let graph = UnGraph::<(), f64>::from_edges([(0, 1, 1.)]);
+        let res: Result<_, Infallible> = k_smallest_spanning_trees(&graph, 0, |_| Ok(1.));
+        assert!(res.unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_all_spanning_trees_of_k4() {
+        // K4 has 4^(4-2) = 16 spanning trees
+        let graph = UnGraph::<(), f64>::from_edges([
+            (0, 1, 1.),
+            (0, 2, 2.),
+            (0, 3, 3.),
+            (1, 2, 4.),
+            (1, 3, 5.),
+            (2, 3, 6.),
+        ]);
+        let res: Result<_, Infallible> =
+            k_smallest_spanning_trees(&graph, 100, |e| Ok(*e.weight()));
+        let trees = res.unwrap();
+        assert_eq!(trees.len(), 16);
+        let distinct: HashSet<Vec<usize>> = trees
+            .iter()
+            .map(|(_, tree)| {
+                let mut tree: Vec<usize> = tree.iter().map(|e| e.index()).collect();
+                tree.sort();
+                tree
+            })
+            .collect();
+        assert_eq!(distinct.len(), 16);
+        for window in trees.windows(2) {
+            assert!(window[0].0 <= window[1].0);
+        }
+        for (weight, tree) in &trees {
+            assert_eq!(tree.len(), 3);
+            assert_eq!(*weight, tree.iter().map(|e| graph[*e]).sum::<f64>());
+        }
+        assert_eq!(trees[0].0, 6.);
+        assert_eq!(trees[15].0, 14.);
+    }
+
+    #[test]
+    fn test_k_limits_output() {
+        let graph = UnGraph::<(), f64>::from_edges([
+            (0, 1, 1.),
+            (1, 2, 1.),
+            (2, 3, 1.),
+            (3, 0, 2.),
+            (0, 2, 5.),
+        ]);
+        let res: Result<_, Infallible> = k_smallest_spanning_trees(&graph, 3, |e| Ok(*e.weight()));
+        let weights: Vec<f64> = res.unwrap().into_iter().map(|(w, _)| w).collect();
+        assert_eq!(weights, vec![3., 4., 4.]);
+    }
+
+    #[test]
+    fn test_huge_k() {
+        let graph = UnGraph::<(), f64>::from_edges([(0, 1, 1.), (1, 2, 2.), (2, 0, 3.)]);
+        let res: Result<_, Infallible> =
+            k_smallest_spanning_trees(&graph, usize::MAX, |e| Ok(*e.weight()));
+        assert_eq!(res.unwrap().len(), 3);
+    }
+
+    #[test]
+    fn test_disconnected_graph() {
+        let graph = UnGraph::<(), f64>::from_edges([
+            (0, 1, 1.),
+            (1, 2, 2.),
+            (2, 0, 3.),
+            (3, 4, 1.),
+            (3, 4, 2.),
+        ]);
+        let res: Result<_, Infallible> = k_smallest_spanning_trees(&graph, 10, |e| Ok(*e.weight()));
+        let trees = res.unwrap();
+        // 3 spanning trees of the triangle times 2 choices of parallel edge
+        assert_eq!(trees.len(), 6);
+        assert!(trees.iter().all(|(_, tree)| tree.len() == 3));
+        let weights: Vec<f64> = trees.into_iter().map(|(w, _)| w).collect();
+        assert_eq!(weights, vec![4., 5., 5., 6., 6., 7.]);
+    }
+
+    #[test]
+    fn test_weight_fn_error() {
+        let graph = UnGraph::<(), f64>::from_edges([(0, 1, 1.)]);
+        let res = k_smallest_spanning_trees(&graph, 3, |_| Err("error"));
+        assert_eq!(res, Err("error"));
+    }
+
+    #[test]
+    fn test_nan_weight() {
+        let graph = UnGraph::<(), f64>::from_edges([
+            (0, 1, 1.),
+            (1, 2, f64::NAN),
+            (2, 0, 2.),
+            (0, 3, -f64::NAN),
+            (3, 1, 3.),
+        ]);
+        let res: Result<_, Infallible> = k_smallest_spanning_trees(&graph, 10, |e| Ok(*e.weight()));
+        let trees = res.unwrap();
+        assert_eq!(trees.len(), 8);
+        assert!(trees.iter().all(|(_, tree)| tree.len() == 3));
+    }
+}
@@ -0,0 +1,27 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+//! Module for spanning tree, arborescence, and graph spanner algorithms.
+
+mod arborescence;
+mod boruvka;
+mod degree_constrained;
+mod k_smallest;
+mod random_spanning_tree;
+mod spanner;
+
+pub use arborescence::minimum_spanning_arborescence;
+pub use boruvka::minimum_spanning_edges;
+pub use degree_constrained::degree_constrained_spanning_tree;
+pub use k_smallest::k_smallest_spanning_trees;
+pub use random_spanning_tree::random_spanning_tree;
+pub use spanner::greedy_spanner;
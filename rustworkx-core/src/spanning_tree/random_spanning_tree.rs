@@ -0,0 +1,253 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+use std::collections::VecDeque;
+
+use crate::rng::rng_from_seed;
+use petgraph::visit::{EdgeRef, GraphProp, IntoEdgeReferences, IntoNodeIdentifiers, NodeIndexable};
+use petgraph::Undirected;
+use rand::prelude::*;
+use rand_pcg::Pcg64;
+
+/// Sample a random spanning tree of an undirected graph using Wilson's algorithm.
+///
+/// Wilson's algorithm builds the tree out of loop-erased random walks, each of
+/// which starts at a node not yet in the tree and ends as soon as it hits the
+/// tree. Each step of a walk moves along an incident edge chosen with
+/// probability proportional to its weight, which results in a spanning tree
+/// being sampled with probability proportional to the product of its edge
+/// weights. When all the weights are equal this is a uniformly random
+/// spanning tree.
+///
+/// If the graph is not connected a random spanning forest is returned,
+/// consisting of an independently sampled spanning tree for each connected
+/// component. Edges with a weight of zero are never used, so components are
+/// determined only by the edges with a positive weight.
+///
+/// Arguments:
+///
+/// * `graph` - The undirected graph to sample a spanning tree from.
+/// * `weight_fn` - A callable that will be passed an edge reference for each
+///   edge in the graph and is expected to return a `Result<f64>` which if it
+///   doesn't error represents the non-negative weight of that edge.
+/// * `seed` - An optional seed to use for the random number generator.
+///
+/// Returns the edge ids of the sampled spanning tree (or forest).
+///
+/// # Example
+/// ```rust
+/// use std::convert::Infallible;
+/// use rustworkx_core::petgraph;
+/// use rustworkx_core::spanning_tree::random_spanning_tree;
+///
+/// let graph = petgraph::graph::UnGraph::<(), ()>::from_edges(&[
+///     (0, 1), (1, 2), (2, 3), (3, 0), (0, 2)
+/// ]);
+/// let res: Result<_, Infallible> = random_spanning_tree(&graph, |_| Ok(1.), Some(42));
+/// let tree = res.unwrap();
+/// assert_eq!(tree.len(), 3);
+/// ```
+pub fn random_spanning_tree<G, F, E>(
+    graph: G,
+    mut weight_fn: F,
+    seed: Option<u64>,
+) -> Result<Vec<G::EdgeId>, E>
+where
+    G: GraphProp<EdgeType = Undirected> + IntoEdgeReferences + IntoNodeIdentifiers + NodeIndexable,
+    F: FnMut(G::EdgeRef) -> Result<f64, E>,
+{
+    let mut rng: Pcg64 = rng_from_seed(seed);
+    let node_bound = graph.node_bound();
+    // For each node the neighbors reachable through a positive weight edge,
+    // the edge used and the cumulative weight used for sampling a step.
+    let mut adjacency: Vec<Vec<(usize, G::EdgeId, f64)>> = vec![Vec::new(); node_bound];
+    let mut totals: Vec<f64> = vec![0.; node_bound];
+    for edge in graph.edge_references() {
+        let weight = weight_fn(edge)?;
+        if weight > 0. {
+            let source = graph.to_index(edge.source());
+            let target = graph.to_index(edge.target());
+            totals[source] += weight;
+            adjacency[source].push((target, edge.id(), totals[source]));
+            if source != target {
+                totals[target] += weight;
+                adjacency[target].push((source, edge.id(), totals[target]));
+            }
+        }
+    }
+
+    let mut in_tree: Vec<bool> = vec![false; node_bound];
+    // Pick the root of every connected component
+    let mut seen: Vec<bool> = vec![false; node_bound];
+    let mut queue: VecDeque<usize> = VecDeque::new();
+    for node in graph.node_identifiers() {
+        let root = graph.to_index(node);
+        if seen[root] {
+            continue;
+        }
+        seen[root] = true;
+        in_tree[root] = true;
+        queue.push_back(root);
+        while let Some(current) = queue.pop_front() {
+            for (neighbor, _, _) in &adjacency[current] {
+                if !seen[*neighbor] {
+                    seen[*neighbor] = true;
+                    queue.push_back(*neighbor);
+                }
+            }
+        }
+    }
+
+    let mut next: Vec<Option<(usize, G::EdgeId)>> = vec![None; node_bound];
+    let mut tree: Vec<G::EdgeId> = Vec::new();
+    for node in graph.node_identifiers() {
+        let start = graph.to_index(node);
+        // Random walk until the tree is hit. Overwriting `next` on every
+        // visit implicitly erases any loops from the walk.
+        let mut current = start;
+        while !in_tree[current] {
+            let neighbors = &adjacency[current];
+            let total = neighbors.last().unwrap().2;
+            let target = rng.gen::<f64>() * total;
+            let pos = neighbors
+                .partition_point(|(_, _, cumulative)| *cumulative <= target)
+                .min(neighbors.len() - 1);
+            let (neighbor, edge, _) = neighbors[pos];
+            next[current] = Some((neighbor, edge));
+            current = neighbor;
+        }
+        // Add the loop-erased path to the tree
+        current = start;
+        while !in_tree[current] {
+            in_tree[current] = true;
+            let (neighbor, edge) = next[current].unwrap();
+            tree.push(edge);
+            current = neighbor;
+        }
+    }
+    Ok(tree)
+}
+
+#[cfg(test)]
+mod test_random_spanning_tree {
+    use super::random_spanning_tree;
+    use hashbrown::HashMap;
+    use petgraph::graph::{EdgeIndex, UnGraph};
+    use petgraph::unionfind::UnionFind;
+    use std::convert::Infallible;
+
+    fn is_spanning_forest(graph: &UnGraph<(), f64>, edges: &[EdgeIndex]) -> bool {
+        let mut union_find = UnionFind::<usize>::new(graph.node_count());
+        edges.iter().all(|e| {
+            let (a, b) = graph.edge_endpoints(*e).unwrap();
+            union_find.union(a.index(), b.index())
+        })
+    }
+
+    #[test]
+    fn test_empty_graph() {
+        let graph = UnGraph::<(), f64>::new_undirected();
+        let res: Result<_, Infallible> = random_spanning_tree(&graph, |_| Ok(1.), Some(1));
+        assert!(res.unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_spanning_tree_of_complete_graph() {
+        let mut edges = Vec::new();
+        for i in 0..6 {
+            for j in i + 1..6 {
+                edges.push((i, j, 1.));
+            }
+        }
+        let graph = UnGraph::<(), f64>::from_edges(edges);
+        for seed in 0..20 {
+            let res: Result<_, Infallible> = random_spanning_tree(&graph, |_| Ok(1.), Some(seed));
+            let tree = res.unwrap();
+            assert_eq!(tree.len(), 5);
+            assert!(is_spanning_forest(&graph, &tree));
+        }
+    }
+
+    #[test]
+    fn test_disconnected_graph() {
+        let graph =
+            UnGraph::<(), f64>::from_edges([(0, 1, 1.), (1, 2, 1.), (2, 0, 1.), (3, 4, 1.)]);
+        let res: Result<_, Infallible> = random_spanning_tree(&graph, |_| Ok(1.), Some(4));
+        let tree = res.unwrap();
+        assert_eq!(tree.len(), 3);
+        assert!(is_spanning_forest(&graph, &tree));
+    }
+
+    #[test]
+    fn test_seed_is_reproducible() {
+        let graph = UnGraph::<(), f64>::from_edges([
+            (0, 1, 1.),
+            (1, 2, 1.),
+            (2, 3, 1.),
+            (3, 0, 1.),
+            (0, 2, 1.),
+        ]);
+        let first: Result<_, Infallible> = random_spanning_tree(&graph, |_| Ok(1.), Some(7));
+        let second: Result<_, Infallible> = random_spanning_tree(&graph, |_| Ok(1.), Some(7));
+        assert_eq!(first.unwrap(), second.unwrap());
+    }
+
+    #[test]
+    fn test_uniform_distribution_on_cycle() {
+        // Each of the 4 spanning trees of a 4-cycle omits exactly one edge
+        let graph =
+            UnGraph::<(), f64>::from_edges([(0, 1, 1.), (1, 2, 1.), (2, 3, 1.), (3, 0, 1.)]);
+        let mut counts: HashMap<usize, usize> = HashMap::new();
+        for seed in 0..4000 {
+            let res: Result<_, Infallible> = random_spanning_tree(&graph, |_| Ok(1.), Some(seed));
+            let tree = res.unwrap();
+            let missing = graph
+                .edge_indices()
+                .find(|e| !tree.contains(e))
+                .unwrap()
+                .index();
+            *counts.entry(missing).or_insert(0) += 1;
+        }
+        for edge in 0..4 {
+            assert!((850..1150).contains(&counts[&edge]));
+        }
+    }
+
+    #[test]
+    fn test_weighted_distribution() {
+        // A triangle whose spanning trees have probability proportional to
+        // the product of their weights: 2 * 1, 2 * 1, and 1 * 1
+        let graph = UnGraph::<(), f64>::from_edges([(0, 1, 2.), (1, 2, 1.), (0, 2, 1.)]);
+        let mut omitted_heavy = 0;
+        for seed in 0..5000 {
+            let res: Result<_, Infallible> =
+                random_spanning_tree(&graph, |e| Ok(*e.weight()), Some(seed));
+            if !res.unwrap().contains(&EdgeIndex::new(0)) {
+                omitted_heavy += 1;
+            }
+        }
+        // Expected probability of omitting the heavy edge is 1 / 5
+        assert!((850..1150).contains(&omitted_heavy));
+    }
+
+    #[test]
+    fn test_zero_weight_edges_are_not_used() {
+        let graph = UnGraph::<(), f64>::from_edges([(0, 1, 1.), (1, 2, 0.), (0, 2, 1.)]);
+        for seed in 0..10 {
+            let res: Result<_, Infallible> =
+                random_spanning_tree(&graph, |e| Ok(*e.weight()), Some(seed));
+            let tree = res.unwrap();
+            assert_eq!(tree.len(), 2);
+            assert!(!tree.contains(&EdgeIndex::new(1)));
+        }
+    }
+}
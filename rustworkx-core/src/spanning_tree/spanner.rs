@@ -0,0 +1,261 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+use std::collections::BinaryHeap;
+
+use petgraph::visit::{EdgeRef, GraphProp, IntoEdgeReferences, NodeIndexable};
+use petgraph::Undirected;
+
+use crate::min_scored::MinScored;
+
+/// Check whether the spanner built so far has a path between `source` and
+/// `target` with a total weight of at most `limit`.
+///
+/// This is a Dijkstra search which is cut off as soon as the distance
+/// exceeds `limit`, `dist` and `visited` are scratch space which are reset
+/// before returning.
+fn within_distance(
+    adjacency: &[Vec<(usize, f64)>],
+    source: usize,
+    target: usize,
+    limit: f64,
+    dist: &mut [f64],
+    visited: &mut Vec<usize>,
+) -> bool {
+    let mut heap: BinaryHeap<MinScored<f64, usize>> = BinaryHeap::new();
+    dist[source] = 0.;
+    visited.push(source);
+    heap.push(MinScored(0., source));
+    let mut found = false;
+    while let Some(MinScored(score, node)) = heap.pop() {
+        if score > dist[node] {
+            continue;
+        }
+        if node == target {
+            found = true;
+            break;
+        }
+        for (neighbor, weight) in &adjacency[node] {
+            let next_score = score + weight;
+            if next_score <= limit && next_score < dist[*neighbor] {
+                if dist[*neighbor] == f64::INFINITY {
+                    visited.push(*neighbor);
+                }
+                dist[*neighbor] = next_score;
+                heap.push(MinScored(next_score, *neighbor));
+            }
+        }
+    }
+    for node in visited.drain(..) {
+        dist[node] = f64::INFINITY;
+    }
+    found
+}
+
+/// Find a `t`-spanner of an undirected graph using the greedy algorithm.
+///
+/// A `t`-spanner of a graph is a subgraph containing all of its nodes in which
+/// the shortest path distance between any two nodes is at most `t` times their
+/// shortest path distance in the original graph, where `t >= 1` is called the
+/// stretch factor. The greedy algorithm [1] considers the edges in order of
+/// nondecreasing weight and keeps an edge `(u, v)` with weight `w` only if the
+/// edges kept so far don't already contain a path from `u` to `v` with a
+/// total weight of at most `t * w`. Larger stretch factors give sparser
+/// spanners, and if `t` is large enough the spanner is a minimum spanning
+/// tree (or forest) of the graph.
+///
+/// The running time is `O(|E| (|S| + |V|) log |V|)`, where `|S|` is the number
+/// of edges in the spanner.
+///
+/// Arguments:
+///
+/// * `graph` - The undirected graph to find a spanner of.
+/// * `stretch` - The stretch factor `t`, which should be at least `1`.
+/// * `weight_fn` - A callable that will be passed an edge reference for each
+///   edge in the graph and is expected to return a `Result<f64>` which if it
+///   doesn't error represents the non-negative weight of that edge. Edges are
+///   ordered with [`f64::total_cmp`], so `NaN` weights don't cause a panic,
+///   but whether the edges with them are kept is unspecified.
+///
+/// Returns the edge ids of the edges kept in the spanner.
+///
+/// # Example
+/// ```rust
+/// use std::convert::Infallible;
+/// use rustworkx_core::petgraph;
+/// use rustworkx_core::spanning_tree::greedy_spanner;
+///
+/// let graph = petgraph::graph::UnGraph::<(), f64>::from_edges(&[
+///     (0, 1, 1.), (1, 2, 1.), (2, 3, 1.), (3, 0, 1.), (0, 2, 1.5)
+/// ]);
+/// // The diagonal is kept for a stretch of 1 but not for a stretch of 2
+/// let res: Result<_, Infallible> = greedy_spanner(&graph, 1., |e| Ok(*e.weight()));
+/// assert_eq!(res.unwrap().len(), 5);
+/// let res: Result<_, Infallible> = greedy_spanner(&graph, 2., |e| Ok(*e.weight()));
+/// assert_eq!(res.unwrap().len(), 4);
+/// ```
+///
+/// [1]: Althöfer, Ingo, et al. "On sparse spanners of weighted graphs."
+///     Discrete & Computational Geometry 9.1 (1993): 81-100.
+///     <https://doi.org/10.1007/BF02189308>
+pub fn greedy_spanner<G, F, E>(
+    graph: G,
+    stretch: f64,
+    mut weight_fn: F,
+) -> Result<Vec<G::EdgeId>, E>
+where
+    G: GraphProp<EdgeType = Undirected> + IntoEdgeReferences + NodeIndexable,
+    F: FnMut(G::EdgeRef) -> Result<f64, E>,
+{
+    let node_bound = graph.node_bound();
+    let mut edges: Vec<(usize, usize, f64, G::EdgeId)> = Vec::new();
+    for edge in graph.edge_references() {
+        edges.push((
+            graph.to_index(edge.source()),
+            graph.to_index(edge.target()),
+            weight_fn(edge)?,
+            edge.id(),
+        ));
+    }
+    edges.sort_by(|a, b| a.2.total_cmp(&b.2));
+
+    let mut adjacency: Vec<Vec<(usize, f64)>> = vec![Vec::new(); node_bound];
+    let mut dist: Vec<f64> = vec![f64::INFINITY; node_bound];
+    let mut visited: Vec<usize> = Vec::new();
+    let mut spanner: Vec<G::EdgeId> = Vec::new();
+    for (source, target, weight, id) in edges {
+        if source == target {
+            continue;
+        }
+        if !within_distance(
+            &adjacency,
+            source,
+            target,
+            stretch * weight,
+            &mut dist,
+            &mut visited,
+        ) {
+            adjacency[source].push((target, weight));
+            adjacency[target].push((source, weight));
+            spanner.push(id);
+        }
+    }
+    Ok(spanner)
+}
+
+#[cfg(test)]
+mod test_greedy_spanner {
+    use super::greedy_spanner;
+    use crate::shortest_path::dijkstra;
+    use petgraph::graph::{NodeIndex, UnGraph};
+    use std::convert::Infallible;
+
+    #[test]
+    fn test_empty_graph() {
+        let graph = UnGraph::<(), f64>::new_undirected();
+        let res: Result<_, Infallible> = greedy_spanner(&graph, 2., |_| Ok(1.));
+        assert!(res.unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_stretch_one_keeps_shortest_paths() {
+        let graph = UnGraph::<(), f64>::from_edges([
+            (0, 1, 1.),
+            (1, 2, 1.),
+            (0, 2, 2.),
+            (2, 3, 1.),
+            (0, 3, 4.),
+        ]);
+        let res: Result<_, Infallible> = greedy_spanner(&graph, 1., |e| Ok(*e.weight()));
+        let mut spanner: Vec<usize> = res.unwrap().into_iter().map(|e| e.index()).collect();
+        spanner.sort();
+        // Edges 2 and 4 are redundant since equally short paths exist
+        assert_eq!(spanner, vec![0, 1, 3]);
+    }
+
+    #[test]
+    fn test_large_stretch_is_spanning_forest() {
+        let graph = UnGraph::<(), f64>::from_edges([
+            (0, 1, 1.),
+            (1, 2, 2.),
+            (2, 0, 3.),
+            (3, 4, 1.),
+            (4, 5, 1.),
+            (5, 3, 1.),
+            (3, 3, 1.),
+        ]);
+        let res: Result<_, Infallible> = greedy_spanner(&graph, 100., |e| Ok(*e.weight()));
+        let mut spanner: Vec<usize> = res.unwrap().into_iter().map(|e| e.index()).collect();
+        spanner.sort();
+        assert_eq!(spanner, vec![0, 1, 3, 4]);
+    }
+
+    #[test]
+    fn test_stretch_is_respected() {
+        // A wheel graph with varying weights
+        let mut edges = Vec::new();
+        for i in 1..=12 {
+            edges.push((0, i, 1. + (i % 4) as f64));
+            edges.push((i, i % 12 + 1, 0.5 + (i % 3) as f64));
+        }
+        let graph = UnGraph::<(), f64>::from_edges(edges);
+        for stretch in [1., 1.5, 2., 3.] {
+            let res: Result<_, Infallible> = greedy_spanner(&graph, stretch, |e| Ok(*e.weight()));
+            let spanner = res.unwrap();
+            let mut subgraph = UnGraph::<(), f64>::new_undirected();
+            for _ in 0..graph.node_count() {
+                subgraph.add_node(());
+            }
+            for edge in &spanner {
+                let (a, b) = graph.edge_endpoints(*edge).unwrap();
+                subgraph.add_edge(a, b, graph[*edge]);
+            }
+            for node in graph.node_indices() {
+                let original: Result<_, Infallible> =
+                    dijkstra(&graph, node, None, |e| Ok(*e.weight()), None);
+                let sparse: Result<_, Infallible> =
+                    dijkstra(&subgraph, node, None, |e| Ok(*e.weight()), None);
+                let original: hashbrown::HashMap<NodeIndex, f64> = original.unwrap();
+                let sparse: hashbrown::HashMap<NodeIndex, f64> = sparse.unwrap();
+                for (target, distance) in original {
+                    assert!(sparse[&target] <= stretch * distance + 1e-9);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_weight_fn_error() {
+        let graph = UnGraph::<(), f64>::from_edges([(0, 1, 1.)]);
+        let res = greedy_spanner(&graph, 2., |_| Err("error"));
+        assert_eq!(res, Err("error"));
+    }
+
+    #[test]
+    fn test_nan_weight() {
+        // Enough edges that the sort doesn't fall back to insertion sort
+        let mut graph = UnGraph::<(), f64>::new_undirected();
+        let nodes: Vec<NodeIndex> = (0..30).map(|_| graph.add_node(())).collect();
+        for i in 0..30 {
+            for j in (i + 1)..30 {
+                let weight = if (i + j) % 3 == 0 {
+                    f64::NAN
+                } else {
+                    (i * j) as f64
+                };
+                graph.add_edge(nodes[i], nodes[j], weight);
+            }
+        }
+        let res: Result<_, Infallible> = greedy_spanner(&graph, 2., |e| Ok(*e.weight()));
+        assert!(!res.unwrap().is_empty());
+    }
+}
@@ -0,0 +1,1818 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+use std::collections::VecDeque;
+
+use crate::rng::rng_from_seed;
+use ndarray::Array2;
+use petgraph::visit::{EdgeRef, GraphProp, IntoEdgeReferences, IntoNodeIdentifiers, NodeIndexable};
+use rand::prelude::*;
+use rand_pcg::Pcg64;
+
+/// A square matrix in compressed sparse row (CSR) format.
+///
+/// The column indices and values of the stored entries of row `i` are
+/// `indices[indptr[i]..indptr[i + 1]]` and `data[indptr[i]..indptr[i + 1]]`,
+/// with the column indices of every row sorted in increasing order. This is
+/// the same layout used by `scipy.sparse.csr_array`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CsrMatrix {
+    /// The number of rows and columns of the matrix.
+    pub size: usize,
+    /// The offsets of the rows in `indices` and `data`.
+    pub indptr: Vec<usize>,
+    /// The column index of every stored entry.
+    pub indices: Vec<usize>,
+    /// The value of every stored entry.
+    pub data: Vec<f64>,
+}
+
+impl CsrMatrix {
+    /// Build a matrix from rows of `(column, value)` pairs, which are sorted
+    /// by column with the values of duplicate columns summed.
+    fn from_rows(rows: Vec<Vec<(usize, f64)>>) -> Self {
+        let size = rows.len();
+        let mut indptr: Vec<usize> = Vec::with_capacity(size + 1);
+        let mut indices: Vec<usize> = Vec::new();
+        let mut data: Vec<f64> = Vec::new();
+        indptr.push(0);
+        for mut row in rows {
+            row.sort_by_key(|(column, _)| *column);
+            for (column, value) in row {
+                if indices.len() > indptr[indptr.len() - 1] && indices[indices.len() - 1] == column
+                {
+                    *data.last_mut().unwrap() += value;
+                } else {
+                    indices.push(column);
+                    data.push(value);
+                }
+            }
+            indptr.push(indices.len());
+        }
+        CsrMatrix {
+            size,
+            indptr,
+            indices,
+            data,
+        }
+    }
+
+    /// Iterate over the `(column, value)` pairs of the stored entries of a
+    /// row.
+    pub fn row(&self, row: usize) -> impl Iterator<Item = (usize, f64)> + '_ {
+        let range = self.indptr[row]..self.indptr[row + 1];
+        self.indices[range.clone()]
+            .iter()
+            .copied()
+            .zip(self.data[range].iter().copied())
+    }
+
+    /// Multiply the matrix by a vector.
+    pub fn dot(&self, vector: &[f64]) -> Vec<f64> {
+        (0..self.size)
+            .map(|i| self.row(i).map(|(j, value)| value * vector[j]).sum())
+            .collect()
+    }
+
+    /// Return the matrix as a dense array.
+    pub fn to_dense(&self) -> Array2<f64> {
+        let mut dense = Array2::<f64>::zeros((self.size, self.size));
+        for i in 0..self.size {
+            for (j, value) in self.row(i) {
+                dense[[i, j]] = value;
+            }
+        }
+        dense
+    }
+}
+
+/// Build the rows of the weighted adjacency matrix of `graph`, with the
+/// nodes numbered in the order of `node_identifiers()`.
+fn adjacency_rows<G, F, E>(graph: G, mut weight_fn: F) -> Result<Vec<Vec<(usize, f64)>>, E>
+where
+    G: GraphProp + IntoEdgeReferences + IntoNodeIdentifiers + NodeIndexable,
+    F: FnMut(G::EdgeRef) -> Result<f64, E>,
+{
+    let mut positions: Vec<usize> = vec![usize::MAX; graph.node_bound()];
+    let mut count = 0;
+    for (position, node) in graph.node_identifiers().enumerate() {
+        positions[graph.to_index(node)] = position;
+        count += 1;
+    }
+    let mut rows: Vec<Vec<(usize, f64)>> = vec![Vec::new(); count];
+    for edge in graph.edge_references() {
+        let weight = weight_fn(edge)?;
+        let i = positions[graph.to_index(edge.source())];
+        let j = positions[graph.to_index(edge.target())];
+        rows[i].push((j, weight));
+        if !graph.is_directed() && i != j {
+            rows[j].push((i, weight));
+        }
+    }
+    Ok(rows)
+}
+
+/// Build the rows of the Laplacian `D - A` from the rows of `A`, returning
+/// them together with the degrees.
+fn laplacian_rows(adjacency: Vec<Vec<(usize, f64)>>) -> (Vec<Vec<(usize, f64)>>, Vec<f64>) {
+    let degrees: Vec<f64> = adjacency
+        .iter()
+        .map(|row| row.iter().map(|(_, weight)| weight).sum())
+        .collect();
+    let rows = adjacency
+        .into_iter()
+        .zip(&degrees)
+        .enumerate()
+        .map(|(i, (row, degree))| {
+            std::iter::once((i, *degree))
+                .chain(row.into_iter().map(|(j, weight)| (j, -weight)))
+                .collect()
+        })
+        .collect();
+    (rows, degrees)
+}
+
+/// Scale the rows of the Laplacian `D - A` to the rows of the normalized
+/// Laplacian `D^-1/2 (D - A) D^-1/2`.
+fn normalize_rows(rows: Vec<Vec<(usize, f64)>>, degrees: &[f64]) -> Vec<Vec<(usize, f64)>> {
+    rows.into_iter()
+        .enumerate()
+        .map(|(i, row)| {
+            row.into_iter()
+                .map(|(j, value)| {
+                    let scale = degrees[i] * degrees[j];
+                    if scale == 0. {
+                        (j, 0.)
+                    } else {
+                        (j, value / scale.sqrt())
+                    }
+                })
+                .collect()
+        })
+        .collect()
+}
+
+/// Compute the Laplacian matrix of a graph.
+///
+/// The Laplacian matrix is `L = D - A`, where `A` is the weighted adjacency
+/// matrix of the graph and `D` is the diagonal matrix of the row sums of `A`.
+/// For directed graphs this uses the out-degrees. The weights of parallel
+/// edges are summed and self-loops have no effect on `L`.
+///
+/// Arguments:
+///
+/// * `graph` - The graph to compute the Laplacian of.
+/// * `weight_fn` - A callable that will be passed an edge reference for each
+///   edge in the graph and is expected to return a `Result<f64>` which if it
+///   doesn't error represents the weight of that edge.
+///
+/// Returns the Laplacian as a sparse matrix, with a row and a column for each
+/// node in the order of `node_identifiers()`. Every diagonal entry is stored,
+/// even if it is zero.
+///
+/// # Example
+/// ```rust
+/// use std::convert::Infallible;
+/// use rustworkx_core::petgraph;
+/// use rustworkx_core::spectral::laplacian_matrix;
+///
+/// let graph = petgraph::graph::UnGraph::<(), ()>::from_edges(&[(0, 1), (1, 2)]);
+/// let res: Result<_, Infallible> = laplacian_matrix(&graph, |_| Ok(1.));
+/// let laplacian = res.unwrap().to_dense();
+/// assert_eq!(
+///     laplacian,
+///     ndarray::array![[1., -1., 0.], [-1., 2., -1.], [0., -1., 1.]]
+/// );
+/// ```
+pub fn laplacian_matrix<G, F, E>(graph: G, weight_fn: F) -> Result<CsrMatrix, E>
+where
+    G: GraphProp + IntoEdgeReferences + IntoNodeIdentifiers + NodeIndexable,
+    F: FnMut(G::EdgeRef) -> Result<f64, E>,
+{
+    let (rows, _) = laplacian_rows(adjacency_rows(graph, weight_fn)?);
+    Ok(CsrMatrix::from_rows(rows))
+}
+
+/// Compute the normalized Laplacian matrix of a graph.
+///
+/// The normalized Laplacian matrix is `D^-1/2 (D - A) D^-1/2`, where `A` is
+/// the weighted adjacency matrix of the graph and `D` is the diagonal matrix
+/// of the row sums of `A`. For directed graphs this uses the out-degrees. The
+/// rows and columns of nodes with a degree of `0` are all zero. The weights
+/// of parallel edges are summed.
+///
+/// Arguments:
+///
+/// * `graph` - The graph to compute the normalized Laplacian of.
+/// * `weight_fn` - A callable that will be passed an edge reference for each
+///   edge in the graph and is expected to return a `Result<f64>` which if it
+///   doesn't error represents the weight of that edge.
+///
+/// Returns the normalized Laplacian as a sparse matrix, with a row and a
+/// column for each node in the order of `node_identifiers()`. Every diagonal
+/// entry is stored, even if it is zero.
+///
+/// # Example
+/// ```rust
+/// use std::convert::Infallible;
+/// use rustworkx_core::petgraph;
+/// use rustworkx_core::spectral::normalized_laplacian_matrix;
+///
+/// let graph = petgraph::graph::UnGraph::<(), ()>::from_edges(&[(0, 1), (1, 2), (2, 0)]);
+/// let res: Result<_, Infallible> = normalized_laplacian_matrix(&graph, |_| Ok(1.));
+/// let laplacian = res.unwrap().to_dense();
+/// assert_eq!(laplacian[[0, 0]], 1.);
+/// assert_eq!(laplacian[[0, 1]], -0.5);
+/// ```
+pub fn normalized_laplacian_matrix<G, F, E>(graph: G, weight_fn: F) -> Result<CsrMatrix, E>
+where
+    G: GraphProp + IntoEdgeReferences + IntoNodeIdentifiers + NodeIndexable,
+    F: FnMut(G::EdgeRef) -> Result<f64, E>,
+{
+    let (rows, degrees) = laplacian_rows(adjacency_rows(graph, weight_fn)?);
+    Ok(CsrMatrix::from_rows(normalize_rows(rows, &degrees)))
+}
+
+/// The random walk used to define the directed Laplacian.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum WalkType {
+    /// The walker moves along an edge out of its current node, chosen with a
+    /// probability proportional to the edge weights. The graph must be
+    /// strongly connected.
+    Random,
+    /// Like [`WalkType::Random`], except that the walker stays at its current
+    /// node with probability `1/2`. The graph must be strongly connected.
+    Lazy,
+    /// The walker moves like in [`WalkType::Random`] with the given
+    /// probability, and otherwise (or if its current node has no edges out of
+    /// it) jumps to a node chosen uniformly at random.
+    PageRank(f64),
+}
+
+/// Return whether the graph with the given adjacency rows is strongly
+/// connected and whether it is aperiodic if so.
+fn strongly_connected_aperiodic(adjacency: &[Vec<(usize, f64)>]) -> (bool, bool) {
+    let size = adjacency.len();
+    if size == 0 {
+        return (false, false);
+    }
+    let mut reverse: Vec<Vec<usize>> = vec![Vec::new(); size];
+    for (i, row) in adjacency.iter().enumerate() {
+        for (j, _) in row {
+            reverse[*j].push(i);
+        }
+    }
+    // Breadth first search levels from node 0 along the edges
+    let mut levels: Vec<Option<usize>> = vec![None; size];
+    levels[0] = Some(0);
+    let mut queue: VecDeque<usize> = VecDeque::from([0]);
+    while let Some(i) = queue.pop_front() {
+        for (j, _) in &adjacency[i] {
+            if levels[*j].is_none() {
+                levels[*j] = Some(levels[i].unwrap() + 1);
+                queue.push_back(*j);
+            }
+        }
+    }
+    let mut reached: Vec<bool> = vec![false; size];
+    reached[0] = true;
+    let mut stack: Vec<usize> = vec![0];
+    while let Some(j) = stack.pop() {
+        for i in &reverse[j] {
+            if !reached[*i] {
+                reached[*i] = true;
+                stack.push(*i);
+            }
+        }
+    }
+    if levels.iter().any(|level| level.is_none()) || reached.iter().any(|r| !r) {
+        return (false, false);
+    }
+    // The graph is aperiodic exactly if the gcd of the level differences
+    // around every edge is 1
+    let gcd = |mut a: usize, mut b: usize| {
+        while b != 0 {
+            (a, b) = (b, a % b);
+        }
+        a
+    };
+    let mut period = 0;
+    for (i, row) in adjacency.iter().enumerate() {
+        for (j, _) in row {
+            let level_i = levels[i].unwrap();
+            let level_j = levels[*j].unwrap();
+            period = gcd(period, (level_i + 1).abs_diff(level_j));
+        }
+    }
+    (true, period == 1)
+}
+
+/// Compute the directed Laplacian matrix of a graph.
+///
+/// The directed Laplacian matrix as defined by Chung [1] is
+///
+/// ```text
+/// L = I - (Φ^1/2 P Φ^-1/2 + Φ^-1/2 P^T Φ^1/2) / 2
+/// ```
+///
+/// where `P` is the transition matrix of a random walk on the graph and `Φ`
+/// is the diagonal matrix of the stationary distribution of the walk. The
+/// transition probabilities are proportional to the edge weights, with the
+/// weights of parallel edges summed. The stationary distribution is computed
+/// with power iteration, which stops when the L1 norm of the difference of
+/// the distributions of two consecutive iterations is less than `tol`.
+///
+/// Arguments:
+///
+/// * `graph` - The graph to compute the directed Laplacian of.
+/// * `weight_fn` - A callable that will be passed an edge reference for each
+///   edge in the graph and is expected to return a `Result<f64>` which if it
+///   doesn't error represents the non-negative weight of that edge.
+/// * `walk_type` - The random walk to use. If `None` this is
+///   [`WalkType::Random`] if the graph is strongly connected and aperiodic,
+///   [`WalkType::Lazy`] if it is strongly connected but periodic, and
+///   [`WalkType::PageRank`] with a probability of `0.95` otherwise.
+/// * `max_iter` - The maximum number of iterations in the power method.
+/// * `tol` - The error tolerance used when checking for convergence.
+///
+/// Returns `None` if `walk_type` is [`WalkType::Random`] or
+/// [`WalkType::Lazy`] and the graph isn't strongly connected, or if the power
+/// iteration didn't converge within `max_iter` iterations. Otherwise returns
+/// the dense directed Laplacian, with a row and a column for each node in the
+/// order of `node_identifiers()`.
+///
+/// # Example
+/// ```rust
+/// use std::convert::Infallible;
+/// use rustworkx_core::petgraph;
+/// use rustworkx_core::spectral::directed_laplacian_matrix;
+///
+/// // A directed cycle, where the walker moves around the cycle
+/// let graph = petgraph::graph::DiGraph::<(), ()>::from_edges(&[(0, 1), (1, 2), (2, 0)]);
+/// let res: Result<_, Infallible> =
+///     directed_laplacian_matrix(&graph, |_| Ok(1.), None, 1000, 1e-12);
+/// let laplacian = res.unwrap().unwrap();
+/// // The cycle is periodic so the lazy walk is used
+/// assert!((laplacian[[0, 0]] - 0.5).abs() < 1e-9);
+/// assert!((laplacian[[0, 1]] + 0.25).abs() < 1e-9);
+/// assert!((laplacian[[1, 0]] + 0.25).abs() < 1e-9);
+/// ```
+///
+/// [1]: Chung, Fan. "Laplacians and the Cheeger inequality for directed
+///     graphs." Annals of Combinatorics 9.1 (2005): 1-19.
+///     <https://doi.org/10.1007/s00026-005-0237-z>
+pub fn directed_laplacian_matrix<G, F, E>(
+    graph: G,
+    weight_fn: F,
+    walk_type: Option<WalkType>,
+    max_iter: usize,
+    tol: f64,
+) -> Result<Option<Array2<f64>>, E>
+where
+    G: GraphProp + IntoEdgeReferences + IntoNodeIdentifiers + NodeIndexable,
+    F: FnMut(G::EdgeRef) -> Result<f64, E>,
+{
+    let adjacency = adjacency_rows(graph, weight_fn)?;
+    let size = adjacency.len();
+    if size == 0 {
+        return Ok(Some(Array2::zeros((0, 0))));
+    }
+    let (strongly_connected, aperiodic) = strongly_connected_aperiodic(&adjacency);
+    let walk_type = match walk_type {
+        Some(walk_type) => walk_type,
+        None if strongly_connected && aperiodic => WalkType::Random,
+        None if strongly_connected => WalkType::Lazy,
+        None => WalkType::PageRank(0.95),
+    };
+    if !strongly_connected && matches!(walk_type, WalkType::Random | WalkType::Lazy) {
+        return Ok(None);
+    }
+
+    let mut transition = Array2::<f64>::zeros((size, size));
+    for (i, row) in adjacency.iter().enumerate() {
+        let total: f64 = row.iter().map(|(_, weight)| weight).sum();
+        for (j, weight) in row {
+            if total > 0. {
+                transition[[i, *j]] += weight / total;
+            }
+        }
+        if total == 0. {
+            // Only possible for the PageRank walk
+            transition.row_mut(i).fill((size as f64).recip());
+        }
+    }
+    match walk_type {
+        WalkType::Random => (),
+        WalkType::Lazy => {
+            transition *= 0.5;
+            for i in 0..size {
+                transition[[i, i]] += 0.5;
+            }
+        }
+        WalkType::PageRank(alpha) => {
+            transition *= alpha;
+            transition += (1. - alpha) / size as f64;
+        }
+    }
+
+    let mut stationary = vec![(size as f64).recip(); size];
+    let mut converged = false;
+    for _ in 0..max_iter {
+        let next: Vec<f64> = transition.t().dot(&ndarray::arr1(&stationary)).to_vec();
+        let diff: f64 = next
+            .iter()
+            .zip(&stationary)
+            .map(|(new, old)| (new - old).abs())
+            .sum();
+        stationary = next;
+        if diff < tol {
+            converged = true;
+            break;
+        }
+    }
+    if !converged {
+        return Ok(None);
+    }
+    let sqrt_stationary: Vec<f64> = stationary.iter().map(|p| p.sqrt()).collect();
+    let mut laplacian = Array2::<f64>::eye(size);
+    for i in 0..size {
+        for j in 0..size {
+            let forward = sqrt_stationary[i] * transition[[i, j]] / sqrt_stationary[j];
+            let backward = sqrt_stationary[j] * transition[[j, i]] / sqrt_stationary[i];
+            laplacian[[i, j]] -= (forward + backward) / 2.;
+        }
+    }
+    Ok(Some(laplacian))
+}
+
+/// The maximum dimension of the Krylov subspace built by the Lanczos method
+/// before it is restarted.
+const LANCZOS_DIM: usize = 64;
+
+fn inner(a: &[f64], b: &[f64]) -> f64 {
+    a.iter().zip(b).map(|(x, y)| x * y).sum()
+}
+
+fn normalize(vector: &mut [f64]) {
+    let norm = inner(vector, vector).sqrt();
+    if norm > 0. {
+        vector.iter_mut().for_each(|value| *value /= norm);
+    }
+}
+
+/// Subtract the projections of `vector` on the orthonormal `basis` vectors.
+fn orthogonalize<'a>(vector: &mut [f64], basis: impl Iterator<Item = &'a [f64]>) {
+    for other in basis {
+        let projection = inner(vector, other);
+        for (value, other_value) in vector.iter_mut().zip(other) {
+            *value -= projection * other_value;
+        }
+    }
+}
+
+/// Compute the eigenvalues and eigenvectors of a dense symmetric matrix with
+/// the cyclic Jacobi method.
+///
+/// Returns the eigenvalues in increasing order, and a matrix with the
+/// corresponding unit eigenvectors as its columns.
+fn symmetric_eigen(matrix: Array2<f64>) -> (Vec<f64>, Array2<f64>) {
+    let size = matrix.nrows();
+    // Work on row-major vectors, which is a lot faster than indexing into
+    // the arrays
+    let mut a: Vec<f64> = matrix.iter().copied().collect();
+    let mut v: Vec<f64> = Array2::<f64>::eye(size).iter().copied().collect();
+    let total: f64 = a.iter().map(|value| value * value).sum();
+    for _ in 0..100 {
+        let mut off_diagonal = 0.;
+        for p in 0..size {
+            for q in p + 1..size {
+                off_diagonal += a[p * size + q] * a[p * size + q];
+            }
+        }
+        if off_diagonal <= f64::EPSILON * f64::EPSILON * total {
+            break;
+        }
+        for p in 0..size {
+            for q in p + 1..size {
+                let apq = a[p * size + q];
+                if apq == 0. {
+                    continue;
+                }
+                // Rotate by the angle that zeroes out the (p, q) entry
+                let theta = (a[q * size + q] - a[p * size + p]) / (2. * apq);
+                let t = theta.signum() / (theta.abs() + (theta * theta + 1.).sqrt());
+                let c = (t * t + 1.).sqrt().recip();
+                let s = t * c;
+                for k in 0..size {
+                    let (kp, kq) = (a[k * size + p], a[k * size + q]);
+                    a[k * size + p] = c * kp - s * kq;
+                    a[k * size + q] = s * kp + c * kq;
+                }
+                for k in 0..size {
+                    let (pk, qk) = (a[p * size + k], a[q * size + k]);
+                    a[p * size + k] = c * pk - s * qk;
+                    a[q * size + k] = s * pk + c * qk;
+                }
+                for k in 0..size {
+                    let (kp, kq) = (v[k * size + p], v[k * size + q]);
+                    v[k * size + p] = c * kp - s * kq;
+                    v[k * size + q] = s * kp + c * kq;
+                }
+            }
+        }
+    }
+    let mut order: Vec<usize> = (0..size).collect();
+    order.sort_by(|x, y| a[x * size + x].total_cmp(&a[y * size + y]));
+    let values = order.iter().map(|i| a[i * size + i]).collect();
+    let vectors = Array2::from_shape_fn((size, size), |(k, i)| v[k * size + order[i]]);
+    (values, vectors)
+}
+
+/// Compute the smallest eigenvalue of a symmetric matrix, and a unit
+/// eigenvector for it, on the subspace orthogonal to the unit vector `null`.
+///
+/// This uses the thick restart Lanczos method with full reorthogonalization:
+/// the Krylov subspace is grown to [`LANCZOS_DIM`] vectors, and then shrunk
+/// to the best approximations of the smallest eigenvectors, from which it's
+/// grown again. Returns `None` if it didn't converge after `max_iter`
+/// restarts.
+fn smallest_eigenpair(
+    matrix: &CsrMatrix,
+    null: &[f64],
+    max_iter: usize,
+    tol: f64,
+    seed: Option<u64>,
+) -> Option<(f64, Vec<f64>)> {
+    let size = matrix.size;
+    // An upper bound on the absolute values of the eigenvalues, which is the
+    // scale of the convergence criterion
+    let scale = (0..size)
+        .map(|i| matrix.row(i).map(|(_, value)| value.abs()).sum())
+        .fold(0., f64::max);
+    let mut rng: Pcg64 = rng_from_seed(seed);
+    let mut start: Vec<f64> = (0..size).map(|_| rng.gen_range(-1.0..1.0)).collect();
+    orthogonalize(&mut start, std::iter::once(null));
+    normalize(&mut start);
+    let dim = (size - 1).min(LANCZOS_DIM);
+    let keep = (dim / 2).max(1);
+    let mut products: Vec<Vec<f64>> = vec![matrix.dot(&start)];
+    let mut basis: Vec<Vec<f64>> = vec![start];
+    for _ in 0..max_iter {
+        while basis.len() < dim {
+            let mut next = products[products.len() - 1].clone();
+            // Orthogonalizing twice keeps the basis orthogonal in floating
+            // point arithmetic
+            for _ in 0..2 {
+                orthogonalize(
+                    &mut next,
+                    std::iter::once(null).chain(basis.iter().map(|v| v.as_slice())),
+                );
+            }
+            let norm = inner(&next, &next).sqrt();
+            // The basis spans an invariant subspace, so the approximations
+            // from it are exact
+            if norm <= 1e-12 * scale {
+                break;
+            }
+            next.iter_mut().for_each(|value| *value /= norm);
+            products.push(matrix.dot(&next));
+            basis.push(next);
+        }
+
+        let steps = basis.len();
+        let mut projected = Array2::<f64>::zeros((steps, steps));
+        for i in 0..steps {
+            for j in i..steps {
+                let value = 0.5 * (inner(&basis[i], &products[j]) + inner(&basis[j], &products[i]));
+                projected[[i, j]] = value;
+                projected[[j, i]] = value;
+            }
+        }
+        let (values, vectors) = symmetric_eigen(projected);
+        let combine = |column: usize, vectors_in: &[Vec<f64>]| -> Vec<f64> {
+            let mut combined: Vec<f64> = vec![0.; size];
+            for (coefficient, vector) in vectors.column(column).iter().zip(vectors_in) {
+                for (entry, vector_entry) in combined.iter_mut().zip(vector) {
+                    *entry += coefficient * vector_entry;
+                }
+            }
+            combined
+        };
+        let vector = combine(0, &basis);
+        let product = combine(0, &products);
+        let residual: f64 = product
+            .iter()
+            .zip(&vector)
+            .map(|(product_entry, entry)| (product_entry - values[0] * entry).powi(2))
+            .sum::<f64>()
+            .sqrt();
+        if residual <= tol * scale {
+            return Some((values[0], vector));
+        }
+        // Restart from the approximate eigenvectors, with the smallest one
+        // last so the subspace grows from its residual
+        let restart: Vec<usize> = (0..keep.min(steps)).rev().collect();
+        let new_basis = restart.iter().map(|i| combine(*i, &basis)).collect();
+        products = restart.iter().map(|i| combine(*i, &products)).collect();
+        basis = new_basis;
+    }
+    None
+}
+
+/// Build the rows of the weighted adjacency matrix like [`adjacency_rows`],
+/// with the directions of the edges of directed graphs ignored.
+fn undirected_adjacency_rows<G, F, E>(graph: G, weight_fn: F) -> Result<Vec<Vec<(usize, f64)>>, E>
+where
+    G: GraphProp + IntoEdgeReferences + IntoNodeIdentifiers + NodeIndexable,
+    F: FnMut(G::EdgeRef) -> Result<f64, E>,
+{
+    let mut adjacency = adjacency_rows(graph, weight_fn)?;
+    if graph.is_directed() {
+        let mut mirrored: Vec<Vec<(usize, f64)>> = vec![Vec::new(); adjacency.len()];
+        for (i, row) in adjacency.iter().enumerate() {
+            for (j, weight) in row {
+                if i != *j {
+                    mirrored[*j].push((i, *weight));
+                }
+            }
+        }
+        for (row, extra) in adjacency.iter_mut().zip(mirrored) {
+            row.extend(extra);
+        }
+    }
+    Ok(adjacency)
+}
+
+/// Build the Laplacian or normalized Laplacian used for the Fiedler vector,
+/// together with a unit vector spanning its null space when `graph` is
+/// connected, and whether `graph` is connected.
+fn fiedler_laplacian<G, F, E>(
+    graph: G,
+    weight_fn: F,
+    normalized: bool,
+) -> Result<(CsrMatrix, Vec<f64>, bool), E>
+where
+    G: GraphProp + IntoEdgeReferences + IntoNodeIdentifiers + NodeIndexable,
+    F: FnMut(G::EdgeRef) -> Result<f64, E>,
+{
+    let adjacency = undirected_adjacency_rows(graph, weight_fn)?;
+
+    let size = adjacency.len();
+    let mut seen: Vec<bool> = vec![false; size];
+    let mut queue: VecDeque<usize> = VecDeque::new();
+    if size > 0 {
+        seen[0] = true;
+        queue.push_back(0);
+    }
+    while let Some(i) = queue.pop_front() {
+        for (j, weight) in &adjacency[i] {
+            if *weight != 0. && !seen[*j] {
+                seen[*j] = true;
+                queue.push_back(*j);
+            }
+        }
+    }
+    let connected = seen.iter().all(|s| *s);
+
+    let (rows, degrees) = laplacian_rows(adjacency);
+    let (rows, mut null) = if normalized {
+        let null: Vec<f64> = degrees.iter().map(|d| d.max(0.).sqrt()).collect();
+        (normalize_rows(rows, &degrees), null)
+    } else {
+        (rows, vec![1.; size])
+    };
+    if null.iter().all(|value| *value == 0.) {
+        null = vec![1.; size];
+    }
+    normalize(&mut null);
+    Ok((CsrMatrix::from_rows(rows), null, connected))
+}
+
+/// Compute the algebraic connectivity of a graph.
+///
+/// The algebraic connectivity is the second smallest eigenvalue of the
+/// Laplacian matrix of the graph [1]. It is greater than `0` if and only if
+/// the graph is connected, and larger values indicate a graph that is harder
+/// to cut into pieces. The eigenvalue is computed with the Lanczos method,
+/// which only needs products of the sparse Laplacian with vectors.
+///
+/// The graph is treated as undirected, and the weights of parallel edges are
+/// summed.
+///
+/// Arguments:
+///
+/// * `graph` - The graph to compute the algebraic connectivity of.
+/// * `weight_fn` - A callable that will be passed an edge reference for each
+///   edge in the graph and is expected to return a `Result<f64>` which if it
+///   doesn't error represents the non-negative weight of that edge.
+/// * `normalized` - Whether to use the normalized Laplacian matrix instead of
+///   the Laplacian matrix.
+/// * `max_iter` - The maximum number of restarts of the Lanczos method.
+/// * `tol` - The error tolerance used when checking for convergence, relative
+///   to the largest absolute row sum of the Laplacian.
+/// * `seed` - An optional seed for the random start vector of the Lanczos
+///   method.
+///
+/// Returns `None` if the graph has fewer than two nodes or the Lanczos method
+/// didn't converge within `max_iter` restarts. The algebraic connectivity of
+/// a disconnected graph is `0`.
+///
+/// # Example
+/// ```rust
+/// use std::convert::Infallible;
+/// use rustworkx_core::petgraph;
+/// use rustworkx_core::spectral::algebraic_connectivity;
+///
+/// // The eigenvalues of the Laplacian of a 4-cycle are 0, 2, 2 and 4
+/// let graph = petgraph::graph::UnGraph::<(), ()>::from_edges(&[(0, 1), (1, 2), (2, 3), (3, 0)]);
+/// let res: Result<_, Infallible> =
+///     algebraic_connectivity(&graph, |_| Ok(1.), false, 100, 1e-10, Some(42));
+/// assert!((res.unwrap().unwrap() - 2.).abs() < 1e-8);
+/// ```
+///
+/// [1]: Fiedler, Miroslav. "Algebraic connectivity of graphs." Czechoslovak
+///     Mathematical Journal 23.2 (1973): 298-305.
+///     <https://doi.org/10.21136/CMJ.1973.101168>
+pub fn algebraic_connectivity<G, F, E>(
+    graph: G,
+    weight_fn: F,
+    normalized: bool,
+    max_iter: usize,
+    tol: f64,
+    seed: Option<u64>,
+) -> Result<Option<f64>, E>
+where
+    G: GraphProp + IntoEdgeReferences + IntoNodeIdentifiers + NodeIndexable,
+    F: FnMut(G::EdgeRef) -> Result<f64, E>,
+{
+    let (laplacian, null, connected) = fiedler_laplacian(graph, weight_fn, normalized)?;
+    if laplacian.size < 2 {
+        return Ok(None);
+    }
+    if !connected {
+        return Ok(Some(0.));
+    }
+    Ok(smallest_eigenpair(&laplacian, &null, max_iter, tol, seed).map(|(value, _)| value))
+}
+
+/// Compute the Fiedler vector of a graph.
+///
+/// The Fiedler vector is a unit eigenvector for the second smallest
+/// eigenvalue of the Laplacian matrix of the graph, which is the algebraic
+/// connectivity (see [`algebraic_connectivity`]). Splitting the nodes by the
+/// sign of their entry in the Fiedler vector gives a spectral bisection of
+/// the graph. The eigenvector is computed with the Lanczos method, which only
+/// needs products of the sparse Laplacian with vectors.
+///
+/// The graph is treated as undirected, and the weights of parallel edges are
+/// summed. The Fiedler vector is only unique up to sign, and not at all if
+/// the eigenvalue has multiplicity greater than one. For a disconnected
+/// graph this is a vector in the null space of the Laplacian that is
+/// orthogonal to the all ones vector, or for the normalized Laplacian to the
+/// square roots of the degrees.
+///
+/// Arguments:
+///
+/// * `graph` - The graph to compute the Fiedler vector of.
+/// * `weight_fn` - A callable that will be passed an edge reference for each
+///   edge in the graph and is expected to return a `Result<f64>` which if it
+///   doesn't error represents the non-negative weight of that edge.
+/// * `normalized` - Whether to use the normalized Laplacian matrix instead of
+///   the Laplacian matrix.
+/// * `max_iter` - The maximum number of restarts of the Lanczos method.
+/// * `tol` - The error tolerance used when checking for convergence, relative
+///   to the largest absolute row sum of the Laplacian.
+/// * `seed` - An optional seed for the random start vector of the Lanczos
+///   method.
+///
+/// Returns `None` if the graph has fewer than two nodes or the Lanczos method
+/// didn't converge within `max_iter` restarts, otherwise the Fiedler vector
+/// with an entry for each node in the order of `node_identifiers()`.
+///
+/// # Example
+/// ```rust
+/// use std::convert::Infallible;
+/// use rustworkx_core::petgraph;
+/// use rustworkx_core::spectral::fiedler_vector;
+///
+/// // Two triangles joined by the edge (2, 3)
+/// let graph = petgraph::graph::UnGraph::<(), ()>::from_edges(&[
+///     (0, 1), (1, 2), (2, 0), (2, 3), (3, 4), (4, 5), (5, 3),
+/// ]);
+/// let res: Result<_, Infallible> = fiedler_vector(&graph, |_| Ok(1.), false, 100, 1e-10, Some(42));
+/// let fiedler = res.unwrap().unwrap();
+/// assert!(fiedler[..3].iter().all(|x| x.signum() == fiedler[0].signum()));
+/// assert!(fiedler[3..].iter().all(|x| x.signum() == -fiedler[0].signum()));
+/// ```
+pub fn fiedler_vector<G, F, E>(
+    graph: G,
+    weight_fn: F,
+    normalized: bool,
+    max_iter: usize,
+    tol: f64,
+    seed: Option<u64>,
+) -> Result<Option<Vec<f64>>, E>
+where
+    G: GraphProp + IntoEdgeReferences + IntoNodeIdentifiers + NodeIndexable,
+    F: FnMut(G::EdgeRef) -> Result<f64, E>,
+{
+    let (laplacian, null, _) = fiedler_laplacian(graph, weight_fn, normalized)?;
+    if laplacian.size < 2 {
+        return Ok(None);
+    }
+    Ok(smallest_eigenpair(&laplacian, &null, max_iter, tol, seed).map(|(_, vector)| vector))
+}
+
+type Partition<N, E> = Result<Option<(Vec<Vec<N>>, f64)>, E>;
+
+/// The weight of the edge between two nodes in rows of `(column, weight)`
+/// pairs sorted by column.
+fn edge_weight(rows: &[Vec<(usize, f64)>], a: usize, b: usize) -> f64 {
+    match rows[a].binary_search_by_key(&b, |(column, _)| *column) {
+        Ok(position) => rows[a][position].1,
+        Err(_) => 0.,
+    }
+}
+
+/// Improve a bisection of the nodes of a graph with the Kernighan-Lin
+/// heuristic, which swaps pairs of nodes between the two sides while that
+/// reduces the weight of the edges between them.
+///
+/// `rows` is the weighted adjacency matrix of the graph, with the columns of
+/// every row sorted and without self-loops, and `side` is the side of every
+/// node.
+fn kernighan_lin(rows: &[Vec<(usize, f64)>], side: &mut [bool]) {
+    let size = rows.len();
+    loop {
+        // The external minus the internal edge weight of every node
+        let mut gain: Vec<f64> = (0..size)
+            .map(|a| {
+                rows[a]
+                    .iter()
+                    .map(|(b, weight)| {
+                        if side[a] == side[*b] {
+                            -weight
+                        } else {
+                            *weight
+                        }
+                    })
+                    .sum()
+            })
+            .collect();
+        let mut locked: Vec<bool> = vec![false; size];
+        let mut swaps: Vec<(usize, usize)> = Vec::new();
+        let mut total = 0.;
+        let mut best_total = 0.;
+        let mut best_count = 0;
+        loop {
+            let candidates = |on_side: bool| -> Vec<usize> {
+                let mut nodes: Vec<usize> = (0..size)
+                    .filter(|a| !locked[*a] && side[*a] == on_side)
+                    .collect();
+                nodes.sort_by(|a, b| gain[*b].total_cmp(&gain[*a]));
+                nodes
+            };
+            let (first, second) = (candidates(true), candidates(false));
+            if first.is_empty() || second.is_empty() {
+                break;
+            }
+            let mut best: Option<(f64, usize, usize)> = None;
+            for a in &first {
+                if matches!(best, Some((g, _, _)) if gain[*a] + gain[second[0]] <= g) {
+                    break;
+                }
+                for b in &second {
+                    let bound = gain[*a] + gain[*b];
+                    if matches!(best, Some((g, _, _)) if bound <= g) {
+                        break;
+                    }
+                    let swap_gain = bound - 2. * edge_weight(rows, *a, *b);
+                    if best.map_or(true, |(g, _, _)| swap_gain > g) {
+                        best = Some((swap_gain, *a, *b));
+                    }
+                }
+            }
+            let (swap_gain, a, b) = match best {
+                Some(best) => best,
+                None => break,
+            };
+            locked[a] = true;
+            locked[b] = true;
+            for node in [a, b] {
+                for (other, weight) in &rows[node] {
+                    if side[*other] == side[node] {
+                        gain[*other] += 2. * weight;
+                    } else {
+                        gain[*other] -= 2. * weight;
+                    }
+                }
+            }
+            swaps.push((a, b));
+            total += swap_gain;
+            if total > best_total + 1e-12 {
+                best_total = total;
+                best_count = swaps.len();
+            }
+        }
+        if best_count == 0 {
+            return;
+        }
+        for (a, b) in &swaps[..best_count] {
+            side[*a] = !side[*a];
+            side[*b] = !side[*b];
+        }
+    }
+}
+
+/// Split `nodes` into `parts` parts by recursive spectral bisection.
+#[allow(clippy::too_many_arguments)]
+fn bisect(
+    adjacency: &[Vec<(usize, f64)>],
+    nodes: Vec<usize>,
+    parts: usize,
+    refine: bool,
+    max_iter: usize,
+    tol: f64,
+    rng: &mut Pcg64,
+    output: &mut Vec<Vec<usize>>,
+) -> Option<()> {
+    if parts == 1 || nodes.len() < 2 {
+        output.push(nodes);
+        output.extend((1..parts).map(|_| Vec::new()));
+        return Some(());
+    }
+    let mut local: Vec<usize> = vec![usize::MAX; adjacency.len()];
+    for (i, node) in nodes.iter().enumerate() {
+        local[*node] = i;
+    }
+    let rows: Vec<Vec<(usize, f64)>> = nodes
+        .iter()
+        .map(|node| {
+            adjacency[*node]
+                .iter()
+                .filter(|(other, _)| local[*other] != usize::MAX && other != node)
+                .map(|(other, weight)| (local[*other], *weight))
+                .collect()
+        })
+        .collect();
+    // Sort the rows and sum the weights of parallel edges
+    let rows: Vec<Vec<(usize, f64)>> = {
+        let matrix = CsrMatrix::from_rows(rows);
+        (0..matrix.size).map(|i| matrix.row(i).collect()).collect()
+    };
+
+    let (laplacian, _) = laplacian_rows(rows.clone());
+    let size = nodes.len();
+    let null: Vec<f64> = vec![(size as f64).sqrt().recip(); size];
+    let seed = Some(rng.gen());
+    let (_, fiedler) =
+        smallest_eigenpair(&CsrMatrix::from_rows(laplacian), &null, max_iter, tol, seed)?;
+    // Split at the quantile of the Fiedler vector that balances the parts
+    let first_parts = parts / 2;
+    let first_size = size * first_parts / parts;
+    let mut order: Vec<usize> = (0..size).collect();
+    order.sort_by(|a, b| fiedler[*a].total_cmp(&fiedler[*b]));
+    let mut side: Vec<bool> = vec![false; size];
+    for i in &order[..first_size] {
+        side[*i] = true;
+    }
+    if refine {
+        kernighan_lin(&rows, &mut side);
+    }
+    let (first, second): (Vec<usize>, Vec<usize>) = (0..size).partition(|i| side[*i]);
+    let first = first.into_iter().map(|i| nodes[i]).collect();
+    let second = second.into_iter().map(|i| nodes[i]).collect();
+    bisect(
+        adjacency,
+        first,
+        first_parts,
+        refine,
+        max_iter,
+        tol,
+        rng,
+        output,
+    )?;
+    bisect(
+        adjacency,
+        second,
+        parts - first_parts,
+        refine,
+        max_iter,
+        tol,
+        rng,
+        output,
+    )
+}
+
+/// Partition the nodes of a graph into balanced parts by recursive spectral
+/// bisection.
+///
+/// The nodes are split in two by their entries in the Fiedler vector (see
+/// [`fiedler_vector`]), with the split point chosen so that the sizes of the
+/// two halves are proportional to the number of parts each of them is split
+/// into next, and then each half is split again recursively until there are
+/// `num_parts` parts [1]. So the sizes of any two parts differ by at most
+/// one when `num_parts` is a power of two, and by a small amount otherwise.
+/// If `refine` is `true` every bisection is improved with the Kernighan-Lin
+/// heuristic [2], which swaps pairs of nodes between the two halves while
+/// that reduces the weight of the edges between them.
+///
+/// The graph is treated as undirected, and the weights of parallel edges are
+/// summed. This is a lighter-weight alternative to multilevel partitioners
+/// like METIS, which usually find smaller edge cuts on large graphs.
+///
+/// Arguments:
+///
+/// * `graph` - The graph to partition.
+/// * `num_parts` - The number of parts, which should be at least `1`. If it's
+///   larger than the number of nodes some parts are empty.
+/// * `weight_fn` - A callable that will be passed an edge reference for each
+///   edge in the graph and is expected to return a `Result<f64>` which if it
+///   doesn't error represents the non-negative weight of that edge.
+/// * `refine` - Whether to refine the bisections with the Kernighan-Lin
+///   heuristic.
+/// * `max_iter` - The maximum number of restarts of the Lanczos method used
+///   to compute every Fiedler vector.
+/// * `tol` - The error tolerance used when checking for convergence of the
+///   Lanczos method, relative to the largest absolute row sum of the
+///   Laplacian.
+/// * `seed` - An optional seed for the random start vectors of the Lanczos
+///   method.
+///
+/// Returns `None` if a Fiedler vector didn't converge, otherwise the parts
+/// and the total weight of the edges between different parts.
+///
+/// # Example
+/// ```rust
+/// use std::convert::Infallible;
+/// use rustworkx_core::petgraph;
+/// use rustworkx_core::spectral::spectral_partition;
+///
+/// // Two triangles joined by the edge (2, 3)
+/// let graph = petgraph::graph::UnGraph::<(), ()>::from_edges(&[
+///     (0, 1), (1, 2), (2, 0), (2, 3), (3, 4), (4, 5), (5, 3),
+/// ]);
+/// let res: Result<_, Infallible> =
+///     spectral_partition(&graph, 2, |_| Ok(1.), true, 100, 1e-8, Some(42));
+/// let (mut parts, edge_cut) = res.unwrap().unwrap();
+/// for part in parts.iter_mut() {
+///     part.sort();
+/// }
+/// parts.sort();
+/// assert_eq!(
+///     parts,
+///     vec![
+///         vec![0.into(), 1.into(), 2.into()],
+///         vec![3.into(), 4.into(), 5.into()],
+///     ]
+/// );
+/// assert_eq!(edge_cut, 1.);
+/// ```
+///
+/// [1]: Pothen, Alex, Horst D. Simon, and Kang-Pu Liou. "Partitioning sparse
+///     matrices with eigenvectors of graphs." SIAM Journal on Matrix Analysis
+///     and Applications 11.3 (1990): 430-452. <https://doi.org/10.1137/0611030>
+/// [2]: Kernighan, Brian W., and Shen Lin. "An efficient heuristic procedure
+///     for partitioning graphs." The Bell System Technical Journal 49.2
+///     (1970): 291-307. <https://doi.org/10.1002/j.1538-7305.1970.tb01770.x>
+pub fn spectral_partition<G, F, E>(
+    graph: G,
+    num_parts: usize,
+    weight_fn: F,
+    refine: bool,
+    max_iter: usize,
+    tol: f64,
+    seed: Option<u64>,
+) -> Partition<G::NodeId, E>
+where
+    G: GraphProp + IntoEdgeReferences + IntoNodeIdentifiers + NodeIndexable,
+    F: FnMut(G::EdgeRef) -> Result<f64, E>,
+{
+    let adjacency = undirected_adjacency_rows(graph, weight_fn)?;
+    let node_ids: Vec<G::NodeId> = graph.node_identifiers().collect();
+    let mut rng = rng_from_seed(seed);
+    let mut parts: Vec<Vec<usize>> = Vec::with_capacity(num_parts);
+    if num_parts > 0 {
+        let nodes: Vec<usize> = (0..node_ids.len()).collect();
+        let res = bisect(
+            &adjacency, nodes, num_parts, refine, max_iter, tol, &mut rng, &mut parts,
+        );
+        if res.is_none() {
+            return Ok(None);
+        }
+    }
+    let mut part_of: Vec<usize> = vec![0; node_ids.len()];
+    for (index, part) in parts.iter().enumerate() {
+        for node in part {
+            part_of[*node] = index;
+        }
+    }
+    let edge_cut: f64 = adjacency
+        .iter()
+        .enumerate()
+        .flat_map(|(i, row)| row.iter().map(move |(j, weight)| (i, *j, *weight)))
+        .filter(|(i, j, _)| part_of[*i] != part_of[*j])
+        .map(|(_, _, weight)| weight)
+        .sum::<f64>()
+        / 2.;
+    let parts = parts
+        .into_iter()
+        .map(|part| part.into_iter().map(|node| node_ids[node]).collect())
+        .collect();
+    Ok(Some((parts, edge_cut)))
+}
+
+/// Solve `L x = rhs` for the Laplacian `L` of a graph with the conjugate
+/// gradient method, preconditioned with the diagonal of `L`.
+///
+/// `rhs` must sum to zero over every connected component, which makes the
+/// system solvable even though `L` is singular.
+fn solve_laplacian(laplacian: &CsrMatrix, diagonal: &[f64], rhs: &[f64]) -> Vec<f64> {
+    let precondition = |r: &[f64]| -> Vec<f64> {
+        r.iter()
+            .zip(diagonal)
+            .map(|(value, d)| if *d > 0. { value / d } else { 0. })
+            .collect()
+    };
+    let size = laplacian.size;
+    let threshold = SOLVER_TOL * inner(rhs, rhs).sqrt();
+    let mut x: Vec<f64> = vec![0.; size];
+    let mut r: Vec<f64> = rhs.to_vec();
+    let mut z = precondition(&r);
+    let mut p = z.clone();
+    let mut rz = inner(&r, &z);
+    for _ in 0..2 * size + 10 {
+        if inner(&r, &r).sqrt() <= threshold {
+            break;
+        }
+        let ap = laplacian.dot(&p);
+        let pap = inner(&p, &ap);
+        if pap <= 0. {
+            break;
+        }
+        let step = rz / pap;
+        for i in 0..size {
+            x[i] += step * p[i];
+            r[i] -= step * ap[i];
+        }
+        z = precondition(&r);
+        let rz_next = inner(&r, &z);
+        let beta = rz_next / rz;
+        for i in 0..size {
+            p[i] = z[i] + beta * p[i];
+        }
+        rz = rz_next;
+    }
+    x
+}
+
+/// The relative residual at which [`solve_laplacian`] stops.
+const SOLVER_TOL: f64 = 1e-10;
+
+/// Sparsify a graph by sampling its edges by their effective resistance.
+///
+/// This is the spectral sparsification of Spielman and Srivastava [1]: every
+/// edge `e` of weight `w` is kept with a probability `p` proportional to
+/// `w * R`, where `R` is the effective resistance between the endpoints of
+/// the edge when the graph is seen as an electrical network with
+/// conductances given by the weights, and a kept edge gets the weight
+/// `w / p`. The probabilities are capped at `1` and scaled so that the
+/// expected number of kept edges is `target_edges`. The Laplacian of the
+/// sparsified graph is then equal to the Laplacian of the graph in
+/// expectation, so it approximates its cuts and spectrum, and the
+/// approximation gets better the larger `target_edges` is. Edges that are
+/// bridges have an effective resistance of `1 / w`, so they are more likely
+/// to be kept than edges in dense parts of the graph.
+///
+/// The effective resistances are computed by solving linear systems in the
+/// Laplacian with the conjugate gradient method. For graphs with more edges
+/// than `24 ln(n)`, where `n` is the number of nodes, the resistances are
+/// approximated with that many solves using random projections, otherwise
+/// they are exact.
+///
+/// The graph is treated as undirected. Self-loops, which have no effect on
+/// the Laplacian, and edges with a weight of `0` are never kept.
+///
+/// Arguments:
+///
+/// * `graph` - The graph to sparsify.
+/// * `target_edges` - The expected number of edges to keep. If it is at least
+///   the number of edges that can be kept, all of them are kept with their
+///   weights.
+/// * `weight_fn` - A callable that will be passed an edge reference for each
+///   edge in the graph and is expected to return a `Result<f64>` which if it
+///   doesn't error represents the non-negative weight of that edge.
+/// * `seed` - An optional seed for the random number generator.
+///
+/// Returns the kept edges with their new weights, in the order of
+/// `edge_references()`.
+///
+/// # Example
+/// ```rust
+/// use std::convert::Infallible;
+/// use rustworkx_core::petgraph;
+/// use rustworkx_core::spectral::spectral_sparsify;
+///
+/// // A complete graph on 20 nodes has 190 edges
+/// let mut graph = petgraph::graph::UnGraph::<(), ()>::new_undirected();
+/// let nodes: Vec<_> = (0..20).map(|_| graph.add_node(())).collect();
+/// for (i, a) in nodes.iter().enumerate() {
+///     for b in &nodes[i + 1..] {
+///         graph.add_edge(*a, *b, ());
+///     }
+/// }
+/// let res: Result<_, Infallible> = spectral_sparsify(&graph, 60, |_| Ok(1.), Some(42));
+/// let edges = res.unwrap();
+/// assert!(edges.len() < 100);
+/// // The kept edges are reweighted for the ones that were dropped
+/// assert!(edges.iter().all(|(_, weight)| *weight > 1.));
+/// ```
+///
+/// [1]: Spielman, Daniel A., and Nikhil Srivastava. "Graph sparsification by
+///     effective resistances." SIAM Journal on Computing 40.6 (2011):
+///     1913-1926. <https://doi.org/10.1137/080734029>
+pub fn spectral_sparsify<G, F, E>(
+    graph: G,
+    target_edges: usize,
+    mut weight_fn: F,
+    seed: Option<u64>,
+) -> Result<Vec<(G::EdgeId, f64)>, E>
+where
+    G: GraphProp + IntoEdgeReferences + IntoNodeIdentifiers + NodeIndexable,
+    F: FnMut(G::EdgeRef) -> Result<f64, E>,
+{
+    let mut positions: Vec<usize> = vec![usize::MAX; graph.node_bound()];
+    let mut size = 0;
+    for (position, node) in graph.node_identifiers().enumerate() {
+        positions[graph.to_index(node)] = position;
+        size += 1;
+    }
+    // The edges that can be kept, with their endpoints and weights
+    let mut edges: Vec<(G::EdgeId, usize, usize, f64)> = Vec::new();
+    let mut adjacency: Vec<Vec<(usize, f64)>> = vec![Vec::new(); size];
+    for edge in graph.edge_references() {
+        let weight = weight_fn(edge)?;
+        let i = positions[graph.to_index(edge.source())];
+        let j = positions[graph.to_index(edge.target())];
+        if i != j && weight > 0. {
+            edges.push((edge.id(), i, j, weight));
+            adjacency[i].push((j, weight));
+            adjacency[j].push((i, weight));
+        }
+    }
+    if target_edges >= edges.len() {
+        return Ok(edges
+            .into_iter()
+            .map(|(id, _, _, weight)| (id, weight))
+            .collect());
+    }
+    if target_edges == 0 {
+        return Ok(Vec::new());
+    }
+
+    let (rows, degrees) = laplacian_rows(adjacency);
+    let laplacian = CsrMatrix::from_rows(rows);
+    let mut rng = rng_from_seed(seed);
+    let projections = (24. * (size as f64).ln()).ceil() as usize;
+    let mut resistances: Vec<f64> = vec![0.; edges.len()];
+    if projections >= edges.len() {
+        for (resistance, (_, i, j, _)) in resistances.iter_mut().zip(&edges) {
+            let mut rhs: Vec<f64> = vec![0.; size];
+            rhs[*i] = 1.;
+            rhs[*j] = -1.;
+            let x = solve_laplacian(&laplacian, &degrees, &rhs);
+            *resistance = x[*i] - x[*j];
+        }
+    } else {
+        // The rows of a random projection of W^1/2 B L^+, whose columns have
+        // the square norms of the effective resistances, see [1]
+        let scale = (projections as f64).recip();
+        for _ in 0..projections {
+            let mut rhs: Vec<f64> = vec![0.; size];
+            for (_, i, j, weight) in &edges {
+                let value = if rng.gen::<bool>() {
+                    weight.sqrt()
+                } else {
+                    -weight.sqrt()
+                };
+                rhs[*i] += value;
+                rhs[*j] -= value;
+            }
+            let x = solve_laplacian(&laplacian, &degrees, &rhs);
+            for (resistance, (_, i, j, _)) in resistances.iter_mut().zip(&edges) {
+                let difference = x[*i] - x[*j];
+                *resistance += scale * difference * difference;
+            }
+        }
+    }
+
+    // Find the scale of the probabilities that makes their sum, after
+    // capping them at 1, equal to target_edges
+    let scores: Vec<f64> = edges
+        .iter()
+        .zip(&resistances)
+        .map(|((_, _, _, weight), resistance)| (weight * resistance).max(0.))
+        .collect();
+    let mut sorted_scores = scores.clone();
+    sorted_scores.sort_by(|a, b| b.total_cmp(a));
+    let mut rest: f64 = sorted_scores.iter().sum();
+    let mut factor = 0.;
+    for (capped, score) in sorted_scores.iter().enumerate() {
+        if rest <= 0. {
+            break;
+        }
+        factor = (target_edges - capped) as f64 / rest;
+        if factor * score <= 1. {
+            break;
+        }
+        rest -= score;
+    }
+    Ok(edges
+        .into_iter()
+        .zip(scores)
+        .filter_map(|((id, _, _, weight), score)| {
+            let probability = (factor * score).min(1.);
+            if probability > 0. && rng.gen::<f64>() < probability {
+                Some((id, weight / probability))
+            } else {
+                None
+            }
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod test_laplacian {
+    use super::{
+        directed_laplacian_matrix, laplacian_matrix, normalized_laplacian_matrix, WalkType,
+    };
+    use ndarray::array;
+    use petgraph::graph::{DiGraph, UnGraph};
+    use petgraph::stable_graph::StableUnGraph;
+    use std::convert::Infallible;
+
+    #[test]
+    fn test_laplacian_csr() {
+        let graph = UnGraph::<(), f64>::from_edges([(0, 1, 2.), (1, 2, 3.), (1, 2, 1.)]);
+        let res: Result<_, Infallible> = laplacian_matrix(&graph, |e| Ok(*e.weight()));
+        let laplacian = res.unwrap();
+        assert_eq!(laplacian.size, 3);
+        assert_eq!(laplacian.indptr, vec![0, 2, 5, 7]);
+        assert_eq!(laplacian.indices, vec![0, 1, 0, 1, 2, 1, 2]);
+        assert_eq!(laplacian.data, vec![2., -2., -2., 6., -4., -4., 4.]);
+    }
+
+    #[test]
+    fn test_laplacian_self_loop_and_isolated_node() {
+        let mut graph = UnGraph::<(), ()>::from_edges([(0, 1), (1, 1)]);
+        graph.add_node(());
+        let res: Result<_, Infallible> = laplacian_matrix(&graph, |_| Ok(1.));
+        assert_eq!(
+            res.unwrap().to_dense(),
+            array![[1., -1., 0.], [-1., 1., 0.], [0., 0., 0.]]
+        );
+    }
+
+    #[test]
+    fn test_laplacian_directed() {
+        let graph = DiGraph::<(), ()>::from_edges([(0, 1), (0, 2), (2, 0)]);
+        let res: Result<_, Infallible> = laplacian_matrix(&graph, |_| Ok(1.));
+        assert_eq!(
+            res.unwrap().to_dense(),
+            array![[2., -1., -1.], [0., 0., 0.], [-1., 0., 1.]]
+        );
+    }
+
+    #[test]
+    fn test_laplacian_removed_node() {
+        let mut graph = StableUnGraph::<(), ()>::default();
+        let a = graph.add_node(());
+        let b = graph.add_node(());
+        let c = graph.add_node(());
+        graph.add_edge(a, c, ());
+        graph.remove_node(b);
+        let res: Result<_, Infallible> = laplacian_matrix(&graph, |_| Ok(1.));
+        assert_eq!(res.unwrap().to_dense(), array![[1., -1.], [-1., 1.]]);
+    }
+
+    #[test]
+    fn test_normalized_laplacian() {
+        let mut graph = UnGraph::<(), ()>::from_edges([(0, 1), (1, 2)]);
+        graph.add_node(());
+        let res: Result<_, Infallible> = normalized_laplacian_matrix(&graph, |_| Ok(1.));
+        let laplacian = res.unwrap().to_dense();
+        let off = -(0.5_f64).sqrt();
+        let expected = array![
+            [1., off, 0., 0.],
+            [off, 1., off, 0.],
+            [0., off, 1., 0.],
+            [0., 0., 0., 0.]
+        ];
+        for (value, expected) in laplacian.iter().zip(expected.iter()) {
+            assert!((value - expected).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_weight_fn_error() {
+        let graph = UnGraph::<(), ()>::from_edges([(0, 1)]);
+        let res = laplacian_matrix(&graph, |_| Err("error"));
+        assert_eq!(res, Err("error"));
+        let res = normalized_laplacian_matrix(&graph, |_| Err("error"));
+        assert_eq!(res, Err("error"));
+    }
+
+    #[test]
+    fn test_directed_laplacian_symmetric_rows_sum() {
+        // A strongly connected aperiodic graph
+        let graph = DiGraph::<(), ()>::from_edges([(0, 1), (1, 2), (2, 0), (1, 0)]);
+        let res: Result<_, Infallible> =
+            directed_laplacian_matrix(&graph, |_| Ok(1.), None, 1000, 1e-14);
+        let laplacian = res.unwrap().unwrap();
+        for i in 0..3 {
+            for j in 0..3 {
+                assert!((laplacian[[i, j]] - laplacian[[j, i]]).abs() < 1e-12);
+            }
+        }
+        // The square root of the stationary distribution is in the kernel
+        // of L, which for this walk is (2, 2, 1) / 5
+        let root = [0.4_f64.sqrt(), 0.4_f64.sqrt(), 0.2_f64.sqrt()];
+        for i in 0..3 {
+            let value: f64 = (0..3).map(|j| laplacian[[i, j]] * root[j]).sum();
+            assert!(value.abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_directed_laplacian_not_strongly_connected() {
+        let graph = DiGraph::<(), ()>::from_edges([(0, 1), (1, 2)]);
+        let res: Result<_, Infallible> =
+            directed_laplacian_matrix(&graph, |_| Ok(1.), Some(WalkType::Random), 1000, 1e-12);
+        assert_eq!(res.unwrap(), None);
+        let res: Result<_, Infallible> =
+            directed_laplacian_matrix(&graph, |_| Ok(1.), None, 1000, 1e-12);
+        let laplacian = res.unwrap().unwrap();
+        assert_eq!(laplacian.dim(), (3, 3));
+        for i in 0..3 {
+            for j in 0..3 {
+                assert!((laplacian[[i, j]] - laplacian[[j, i]]).abs() < 1e-12);
+            }
+        }
+    }
+
+    #[test]
+    fn test_directed_laplacian_no_convergence() {
+        let graph = DiGraph::<(), ()>::from_edges([(0, 1), (1, 2), (2, 0), (1, 0)]);
+        let res: Result<_, Infallible> =
+            directed_laplacian_matrix(&graph, |_| Ok(1.), Some(WalkType::PageRank(0.99)), 1, 1e-12);
+        assert_eq!(res.unwrap(), None);
+    }
+}
+
+#[cfg(test)]
+mod test_fiedler {
+    use super::{algebraic_connectivity, fiedler_vector, laplacian_matrix};
+    use petgraph::graph::{DiGraph, UnGraph};
+    use std::convert::Infallible;
+
+    fn path(size: u32) -> UnGraph<(), ()> {
+        UnGraph::from_edges((1..size).map(|i| (i - 1, i)))
+    }
+
+    #[test]
+    fn test_path_connectivity() {
+        let graph = path(10);
+        let res: Result<_, Infallible> =
+            algebraic_connectivity(&graph, |_| Ok(1.), false, 100, 1e-12, Some(1));
+        let expected = 2. * (1. - (std::f64::consts::PI / 10.).cos());
+        assert!((res.unwrap().unwrap() - expected).abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_complete_graph() {
+        let mut graph = UnGraph::<(), ()>::default();
+        let nodes: Vec<_> = (0..5).map(|_| graph.add_node(())).collect();
+        for (i, a) in nodes.iter().enumerate() {
+            for b in &nodes[i + 1..] {
+                graph.add_edge(*a, *b, ());
+            }
+        }
+        let res: Result<_, Infallible> =
+            algebraic_connectivity(&graph, |_| Ok(1.), false, 100, 1e-12, Some(1));
+        assert!((res.unwrap().unwrap() - 5.).abs() < 1e-10);
+        let res: Result<_, Infallible> =
+            algebraic_connectivity(&graph, |_| Ok(1.), true, 100, 1e-12, Some(1));
+        assert!((res.unwrap().unwrap() - 1.25).abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_weights() {
+        let graph = UnGraph::<(), f64>::from_edges([(0, 1, 3.)]);
+        let res: Result<_, Infallible> =
+            algebraic_connectivity(&graph, |e| Ok(*e.weight()), false, 100, 1e-12, Some(1));
+        assert!((res.unwrap().unwrap() - 6.).abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_disconnected() {
+        let graph = UnGraph::<(), ()>::from_edges([(0, 1), (2, 3)]);
+        let res: Result<_, Infallible> =
+            algebraic_connectivity(&graph, |_| Ok(1.), false, 100, 1e-12, Some(1));
+        assert_eq!(res.unwrap(), Some(0.));
+        let res: Result<_, Infallible> =
+            fiedler_vector(&graph, |_| Ok(1.), false, 100, 1e-12, Some(1));
+        let fiedler = res.unwrap().unwrap();
+        assert!(fiedler.iter().sum::<f64>().abs() < 1e-10);
+        assert!((fiedler[0] - fiedler[1]).abs() < 1e-10);
+        assert!((fiedler[2] - fiedler[3]).abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_too_few_nodes() {
+        let mut graph = UnGraph::<(), ()>::default();
+        graph.add_node(());
+        let res: Result<_, Infallible> =
+            algebraic_connectivity(&graph, |_| Ok(1.), false, 100, 1e-12, None);
+        assert_eq!(res.unwrap(), None);
+        let res: Result<_, Infallible> =
+            fiedler_vector(&graph, |_| Ok(1.), false, 100, 1e-12, None);
+        assert_eq!(res.unwrap(), None);
+    }
+
+    #[test]
+    fn test_path_fiedler_vector() {
+        let graph = path(8);
+        let res: Result<_, Infallible> =
+            fiedler_vector(&graph, |_| Ok(1.), false, 100, 1e-12, Some(1));
+        let fiedler = res.unwrap().unwrap();
+        // The Fiedler vector of a path is monotone along the path
+        let increasing = fiedler[0] < fiedler[7];
+        assert!(fiedler
+            .windows(2)
+            .all(|pair| (pair[0] < pair[1]) == increasing));
+        assert!((fiedler.iter().map(|x| x * x).sum::<f64>() - 1.).abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_restarts() {
+        // Larger than the dimension of the Krylov subspace
+        let graph = path(100);
+        let res: Result<_, Infallible> =
+            fiedler_vector(&graph, |_| Ok(1.), false, 1000, 1e-10, Some(7));
+        let fiedler = res.unwrap().unwrap();
+        let res: Result<_, Infallible> = laplacian_matrix(&graph, |_| Ok(1.));
+        let product = res.unwrap().dot(&fiedler);
+        let value = 2. * (1. - (std::f64::consts::PI / 100.).cos());
+        for (p, x) in product.iter().zip(&fiedler) {
+            assert!((p - value * x).abs() < 1e-8);
+        }
+    }
+
+    #[test]
+    fn test_directed_is_undirected() {
+        let directed = DiGraph::<(), ()>::from_edges([(0, 1), (2, 1), (2, 3)]);
+        let res: Result<_, Infallible> =
+            algebraic_connectivity(&directed, |_| Ok(1.), false, 100, 1e-12, Some(1));
+        let expected = 2. * (1. - (std::f64::consts::PI / 4.).cos());
+        assert!((res.unwrap().unwrap() - expected).abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_no_convergence() {
+        let graph = path(100);
+        let res: Result<_, Infallible> =
+            fiedler_vector(&graph, |_| Ok(1.), false, 1, 1e-14, Some(1));
+        assert_eq!(res.unwrap(), None);
+    }
+
+    #[test]
+    fn test_weight_fn_error() {
+        let graph = path(3);
+        let res = algebraic_connectivity(&graph, |_| Err("error"), false, 100, 1e-12, None);
+        assert_eq!(res, Err("error"));
+    }
+}
+
+#[cfg(test)]
+mod test_spectral_partition {
+    use super::spectral_partition;
+    use petgraph::graph::{NodeIndex, UnGraph};
+    use std::convert::Infallible;
+
+    fn sorted(parts: Vec<Vec<NodeIndex>>) -> Vec<Vec<usize>> {
+        let mut parts: Vec<Vec<usize>> = parts
+            .into_iter()
+            .map(|part| {
+                let mut part: Vec<usize> = part.into_iter().map(|n| n.index()).collect();
+                part.sort_unstable();
+                part
+            })
+            .collect();
+        parts.sort();
+        parts
+    }
+
+    /// `count` cliques of `size` nodes, with clique `i` joined to clique
+    /// `i + 1` by a single edge.
+    fn clique_chain(count: u32, size: u32) -> UnGraph<(), ()> {
+        let mut edges: Vec<(u32, u32)> = Vec::new();
+        for c in 0..count {
+            let start = c * size;
+            for a in start..start + size {
+                for b in a + 1..start + size {
+                    edges.push((a, b));
+                }
+            }
+            if c > 0 {
+                edges.push((start - 1, start));
+            }
+        }
+        UnGraph::from_edges(edges)
+    }
+
+    #[test]
+    fn test_four_cliques() {
+        let graph = clique_chain(4, 5);
+        for refine in [false, true] {
+            let res: Result<_, Infallible> =
+                spectral_partition(&graph, 4, |_| Ok(1.), refine, 100, 1e-8, Some(1));
+            let (parts, edge_cut) = res.unwrap().unwrap();
+            let expected: Vec<Vec<usize>> = (0..4).map(|c| (c * 5..c * 5 + 5).collect()).collect();
+            assert_eq!(sorted(parts), expected);
+            assert_eq!(edge_cut, 3.);
+        }
+    }
+
+    #[test]
+    fn test_balanced_sizes() {
+        let graph = clique_chain(1, 10);
+        let res: Result<_, Infallible> =
+            spectral_partition(&graph, 3, |_| Ok(1.), true, 100, 1e-8, Some(1));
+        let (parts, edge_cut) = res.unwrap().unwrap();
+        let mut sizes: Vec<usize> = parts.iter().map(|part| part.len()).collect();
+        sizes.sort_unstable();
+        assert_eq!(sizes, vec![3, 3, 4]);
+        // 3 * 3 + 3 * 4 + 3 * 4 edges of the clique are cut
+        assert_eq!(edge_cut, 33.);
+    }
+
+    #[test]
+    fn test_refinement_improves_cut() {
+        // A path with heavy edges (0, 5), (1, 4) and (2, 3) that the
+        // spectral split of the path doesn't account for well
+        let mut edges: Vec<(u32, u32, f64)> = (1..6).map(|i| (i - 1, i, 1.)).collect();
+        edges.extend([(0, 5, 10.), (1, 4, 10.)]);
+        let graph = UnGraph::<(), f64>::from_edges(edges);
+        let res: Result<_, Infallible> =
+            spectral_partition(&graph, 2, |e| Ok(*e.weight()), false, 100, 1e-8, Some(1));
+        let (_, unrefined) = res.unwrap().unwrap();
+        let res: Result<_, Infallible> =
+            spectral_partition(&graph, 2, |e| Ok(*e.weight()), true, 100, 1e-8, Some(1));
+        let (parts, refined) = res.unwrap().unwrap();
+        assert!(refined <= unrefined);
+        assert!(parts.iter().all(|part| part.len() == 3));
+    }
+
+    #[test]
+    fn test_more_parts_than_nodes() {
+        let graph = UnGraph::<(), ()>::from_edges([(0, 1)]);
+        let res: Result<_, Infallible> =
+            spectral_partition(&graph, 3, |_| Ok(1.), true, 100, 1e-8, Some(1));
+        let (parts, edge_cut) = res.unwrap().unwrap();
+        assert_eq!(parts.len(), 3);
+        assert_eq!(sorted(parts), vec![vec![], vec![0], vec![1]]);
+        assert_eq!(edge_cut, 1.);
+    }
+
+    #[test]
+    fn test_single_part() {
+        let graph = clique_chain(2, 3);
+        let res: Result<_, Infallible> =
+            spectral_partition(&graph, 1, |_| Ok(1.), true, 100, 1e-8, None);
+        let (parts, edge_cut) = res.unwrap().unwrap();
+        assert_eq!(sorted(parts), vec![(0..6).collect::<Vec<usize>>()]);
+        assert_eq!(edge_cut, 0.);
+    }
+
+    #[test]
+    fn test_disconnected() {
+        let graph = UnGraph::<(), ()>::from_edges([(0, 1), (1, 2), (3, 4), (4, 5)]);
+        let res: Result<_, Infallible> =
+            spectral_partition(&graph, 2, |_| Ok(1.), true, 100, 1e-8, Some(1));
+        let (parts, edge_cut) = res.unwrap().unwrap();
+        assert_eq!(sorted(parts), vec![vec![0, 1, 2], vec![3, 4, 5]]);
+        assert_eq!(edge_cut, 0.);
+    }
+
+    #[test]
+    fn test_weight_fn_error() {
+        let graph = UnGraph::<(), ()>::from_edges([(0, 1)]);
+        let res = spectral_partition(&graph, 2, |_| Err("error"), true, 100, 1e-8, None);
+        assert_eq!(res, Err("error"));
+    }
+}
+
+#[cfg(test)]
+mod test_spectral_sparsify {
+    use super::spectral_sparsify;
+    use crate::Result;
+    use petgraph::graph::{EdgeIndex, NodeIndex, UnGraph};
+
+    fn complete_graph(size: usize) -> UnGraph<(), ()> {
+        let mut graph = UnGraph::<(), ()>::new_undirected();
+        let nodes: Vec<_> = (0..size).map(|_| graph.add_node(())).collect();
+        for (i, a) in nodes.iter().enumerate() {
+            for b in &nodes[i + 1..] {
+                graph.add_edge(*a, *b, ());
+            }
+        }
+        graph
+    }
+
+    #[test]
+    fn test_keep_everything() {
+        let graph =
+            UnGraph::<(), f64>::from_edges([(0, 1, 2.), (1, 1, 1.), (1, 2, 0.), (2, 0, 3.)]);
+        let res: Result<_> = spectral_sparsify(&graph, 10, |e| Ok(*e.weight()), Some(1));
+        assert_eq!(
+            res.unwrap(),
+            vec![(EdgeIndex::new(0), 2.), (EdgeIndex::new(3), 3.)]
+        );
+        let res: Result<_> = spectral_sparsify(&graph, 0, |e| Ok(*e.weight()), Some(1));
+        assert!(res.unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_tree_edges_are_reweighted_equally() {
+        // Every edge of a tree is a bridge with a score of 1, so they are
+        // all kept with a probability of 1/2
+        let graph = UnGraph::<(), ()>::from_edges((0..10).map(|i| (i, i + 1)));
+        let res: Result<_> = spectral_sparsify(&graph, 5, |_| Ok(1.), Some(7));
+        let edges = res.unwrap();
+        assert!(!edges.is_empty());
+        assert!(edges.iter().all(|(_, weight)| (weight - 2.).abs() < 1e-9));
+    }
+
+    #[test]
+    fn test_bridge_is_kept() {
+        // Two complete graphs on 6 nodes joined by a bridge: the scores sum
+        // to the number of nodes minus one, so with 15 edges the bridge is
+        // always kept
+        let mut graph = complete_graph(6);
+        let other = complete_graph(6);
+        let offset = graph.node_count();
+        for _ in 0..offset {
+            graph.add_node(());
+        }
+        for edge in other.raw_edges() {
+            graph.add_edge(
+                NodeIndex::new(edge.source().index() + offset),
+                NodeIndex::new(edge.target().index() + offset),
+                (),
+            );
+        }
+        let bridge = graph.add_edge(NodeIndex::new(0), NodeIndex::new(offset), ());
+        for seed in 0..10 {
+            let res: Result<_> = spectral_sparsify(&graph, 15, |_| Ok(1.), Some(seed));
+            let edges = res.unwrap();
+            let kept = edges.iter().find(|(id, _)| *id == bridge).unwrap();
+            assert!((kept.1 - 1.).abs() < 1e-6);
+        }
+    }
+
+    #[test]
+    fn test_random_projections_preserve_cut() {
+        let graph = complete_graph(40);
+        let res: Result<_> = spectral_sparsify(&graph, 300, |_| Ok(1.), Some(42));
+        let edges = res.unwrap();
+        assert!(edges.len() > 200 && edges.len() < 400);
+        // The cut between the first 20 nodes and the rest has 400 edges
+        let cut: f64 = edges
+            .iter()
+            .filter(|(id, _)| {
+                let (a, b) = graph.edge_endpoints(*id).unwrap();
+                (a.index() < 20) != (b.index() < 20)
+            })
+            .map(|(_, weight)| weight)
+            .sum();
+        assert!((cut - 400.).abs() < 80., "cut of {cut}");
+        let again: Result<_> = spectral_sparsify(&graph, 300, |_| Ok(1.), Some(42));
+        assert_eq!(again.unwrap(), edges);
+    }
+
+    #[test]
+    fn test_weight_fn_error() {
+        let graph = UnGraph::<(), ()>::from_edges([(0, 1)]);
+        let res: std::result::Result<_, &str> = spectral_sparsify(&graph, 1, |_| Err("bad"), None);
+        assert_eq!(res, Err("bad"));
+    }
+}
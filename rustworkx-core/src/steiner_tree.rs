@@ -14,8 +14,8 @@ use std::cmp::{Eq, Ordering};
 use std::convert::Infallible;
 use std::hash::Hash;
 
+use crate::par::*;
 use hashbrown::{HashMap, HashSet};
-use rayon::prelude::*;
 
 use petgraph::stable_graph::{EdgeIndex, NodeIndex, StableGraph};
 use petgraph::unionfind::UnionFind;
@@ -570,3 +570,62 @@ where
         used_edge_endpoints: out_edges,
     }))
 }
+
+#[cfg(test)]
+mod test_steiner_tree {
+    use super::steiner_tree;
+    use hashbrown::HashSet;
+    use petgraph::graph::{NodeIndex, UnGraph};
+    use std::convert::Infallible;
+
+    fn undirected_edges(edges: &HashSet<(usize, usize)>) -> HashSet<(usize, usize)> {
+        edges.iter().map(|(a, b)| (*a.min(b), *a.max(b))).collect()
+    }
+
+    #[test]
+    fn test_steiner_tree_is_pruned_tree() {
+        let graph = UnGraph::<(), f64>::from_edges([
+            (0, 1, 10.),
+            (1, 2, 10.),
+            (2, 3, 10.),
+            (3, 4, 10.),
+            (4, 5, 10.),
+            (1, 6, 1.),
+            (6, 4, 1.),
+        ]);
+        let terminals: Vec<NodeIndex> = [0, 1, 2, 3, 4, 5].map(NodeIndex::new).to_vec();
+        let res: Result<_, Infallible> = steiner_tree(&graph, &terminals, |e| Ok(*e.weight()));
+        let tree = res.unwrap().unwrap();
+        let edges = undirected_edges(&tree.used_edge_endpoints);
+        let expected: HashSet<(usize, usize)> = [(0, 1), (1, 2), (2, 3), (1, 6), (4, 6), (4, 5)]
+            .into_iter()
+            .collect();
+        assert_eq!(edges, expected);
+        assert_eq!(edges.len() + 1, tree.used_node_indices.len());
+    }
+
+    #[test]
+    fn test_steiner_tree_skips_unneeded_nodes() {
+        let graph = UnGraph::<(), f64>::from_edges([
+            (0, 1, 1.),
+            (1, 2, 1.),
+            (2, 3, 1.),
+            (1, 4, 1.),
+            (4, 5, 1.),
+        ]);
+        let terminals = [NodeIndex::new(0), NodeIndex::new(3)];
+        let res: Result<_, Infallible> = steiner_tree(&graph, &terminals, |e| Ok(*e.weight()));
+        let tree = res.unwrap().unwrap();
+        let nodes: HashSet<usize> = tree.used_node_indices.iter().copied().collect();
+        assert_eq!(nodes, [0, 1, 2, 3].into_iter().collect());
+        assert_eq!(undirected_edges(&tree.used_edge_endpoints).len(), 3);
+    }
+
+    #[test]
+    fn test_steiner_tree_disconnected_terminals() {
+        let graph = UnGraph::<(), f64>::from_edges([(0, 1, 1.), (2, 3, 1.)]);
+        let terminals = [NodeIndex::new(0), NodeIndex::new(3)];
+        let res: Result<_, Infallible> = steiner_tree(&graph, &terminals, |e| Ok(*e.weight()));
+        assert!(res.unwrap().is_none());
+    }
+}
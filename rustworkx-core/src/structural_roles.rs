@@ -0,0 +1,269 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+use std::hash::Hash;
+
+use crate::par::*;
+use hashbrown::HashSet;
+use petgraph::visit::{EdgeRef, GraphProp, IntoEdgesDirected, IntoNodeIdentifiers, NodeIndexable};
+use petgraph::Direction::{Incoming, Outgoing};
+
+/// Compute the local and egonet features of a single node.
+fn base_features<G>(graph: G, node: G::NodeId, neighbors: &[usize]) -> Vec<f64>
+where
+    G: GraphProp + IntoEdgesDirected + NodeIndexable,
+    G::EdgeId: Eq + Hash,
+{
+    let index = graph.to_index(node);
+    let in_egonet = |other: usize| other == index || neighbors.binary_search(&other).is_ok();
+    let mut seen: HashSet<G::EdgeId> = HashSet::new();
+    let mut internal = 0;
+    let mut leaving = 0;
+    let mut entering = 0;
+    for member in std::iter::once(node).chain(neighbors.iter().map(|n| graph.from_index(*n))) {
+        for edge in graph.edges_directed(member, Outgoing) {
+            if !seen.insert(edge.id()) {
+                continue;
+            }
+            let other = if edge.source() == member {
+                edge.target()
+            } else {
+                edge.source()
+            };
+            if in_egonet(graph.to_index(other)) {
+                internal += 1;
+            } else {
+                leaving += 1;
+            }
+        }
+        if graph.is_directed() {
+            for edge in graph.edges_directed(member, Incoming) {
+                if !in_egonet(graph.to_index(edge.source())) {
+                    entering += 1;
+                }
+            }
+        }
+    }
+    if graph.is_directed() {
+        vec![
+            graph.edges_directed(node, Incoming).count() as f64,
+            graph.edges_directed(node, Outgoing).count() as f64,
+            internal as f64,
+            leaving as f64,
+            entering as f64,
+        ]
+    } else {
+        vec![
+            graph.edges_directed(node, Outgoing).count() as f64,
+            internal as f64,
+            leaving as f64,
+        ]
+    }
+}
+
+/// Compute recursive structural features of the nodes of a graph.
+///
+/// This is the feature extraction step of ReFeX [1], which is also the
+/// expensive part of role discovery with RolX [2]. Every node starts out with
+/// local and egonet features, where the egonet of a node is the node together
+/// with its neighbors:
+///
+/// * For undirected graphs these are the degree of the node, the number of
+///   edges within its egonet and the number of edges leaving its egonet.
+/// * For directed graphs these are the in-degree and out-degree of the node,
+///   the number of edges within its egonet, the number of edges leaving its
+///   egonet and the number of edges entering its egonet. The neighbors of a
+///   node are both its predecessors and its successors.
+///
+/// Then in each of the `rounds` recursive rounds, the sum and the mean over
+/// the neighbors of a node of every feature added in the previous round are
+/// added as new features. So with `f` base features there are
+/// `f * (2^(rounds + 1) - 1)` features in total. The features are ordered by round, and within a round
+/// the sum and then the mean of each feature of the previous round are listed
+/// in the order of the features of the previous round. Self-loops count
+/// towards the degree and the edges of the egonet, but a node is never its
+/// own neighbor.
+///
+/// The features of the nodes are aggregated in parallel in every round.
+///
+/// Arguments:
+///
+/// * `graph` - The graph to compute the features of.
+/// * `rounds` - The number of recursive aggregation rounds.
+///
+/// Returns a vector with the features of every node, indexed by node index.
+/// Indices that aren't in the graph have all their features set to `0`.
+///
+/// # Example
+/// ```rust
+/// use rustworkx_core::petgraph;
+/// use rustworkx_core::structural_roles::recursive_structural_features;
+///
+/// // A star with a center node 0 and leaves 1, 2 and 3
+/// let graph = petgraph::graph::UnGraph::<(), ()>::from_edges(&[(0, 1), (0, 2), (0, 3)]);
+/// let features = recursive_structural_features(&graph, 1);
+/// // degree, egonet edges, leaving edges, then the sum and mean of each
+/// assert_eq!(features[0], vec![3., 3., 0., 3., 1., 3., 1., 6., 2.]);
+/// assert_eq!(features[1], vec![1., 1., 2., 3., 3., 3., 3., 0., 0.]);
+/// ```
+///
+/// [1]: Henderson, Keith, et al. "It's who you know: graph mining using
+///     recursive structural features." Proceedings of the 17th ACM SIGKDD
+///     International Conference on Knowledge Discovery and Data Mining (2011):
+///     663-671. <https://doi.org/10.1145/2020408.2020512>
+/// [2]: Henderson, Keith, et al. "RolX: structural role extraction & mining in
+///     large graphs." Proceedings of the 18th ACM SIGKDD International
+///     Conference on Knowledge Discovery and Data Mining (2012): 1231-1239.
+///     <https://doi.org/10.1145/2339530.2339723>
+pub fn recursive_structural_features<G>(graph: G, rounds: usize) -> Vec<Vec<f64>>
+where
+    G: GraphProp + IntoEdgesDirected + IntoNodeIdentifiers + NodeIndexable + Sync,
+    G::NodeId: Send + Sync,
+    G::EdgeId: Eq + Hash,
+{
+    let node_bound = graph.node_bound();
+    let nodes: Vec<G::NodeId> = graph.node_identifiers().collect();
+    let mut neighbors: Vec<Vec<usize>> = vec![Vec::new(); node_bound];
+    for node in &nodes {
+        let index = graph.to_index(*node);
+        let mut node_neighbors: Vec<usize> = graph
+            .edges_directed(*node, Outgoing)
+            .map(|edge| edge.target())
+            .chain(
+                graph
+                    .edges_directed(*node, Incoming)
+                    .map(|edge| edge.source()),
+            )
+            .map(|other| graph.to_index(other))
+            .filter(|other| *other != index)
+            .collect();
+        node_neighbors.sort_unstable();
+        node_neighbors.dedup();
+        neighbors[index] = node_neighbors;
+    }
+
+    let base_count = if graph.is_directed() { 5 } else { 3 };
+    let mut features: Vec<Vec<f64>> = vec![vec![0.; base_count]; node_bound];
+    let base: Vec<(usize, Vec<f64>)> = nodes
+        .par_iter()
+        .map(|node| {
+            let index = graph.to_index(*node);
+            (index, base_features(graph, *node, &neighbors[index]))
+        })
+        .collect();
+    for (index, node_features) in base {
+        features[index] = node_features;
+    }
+
+    // The features added in the previous round
+    let mut start = 0;
+    for _ in 0..rounds {
+        let end = features.first().map_or(0, |row| row.len());
+        let new_features: Vec<Vec<f64>> = neighbors
+            .par_iter()
+            .map(|node_neighbors| {
+                let mut sums: Vec<f64> = vec![0.; end - start];
+                for neighbor in node_neighbors {
+                    for (sum, value) in sums.iter_mut().zip(&features[*neighbor][start..end]) {
+                        *sum += value;
+                    }
+                }
+                // The sums are all 0 for nodes without neighbors
+                let count = node_neighbors.len().max(1) as f64;
+                sums.into_iter()
+                    .flat_map(|sum| [sum, sum / count])
+                    .collect()
+            })
+            .collect();
+        for (row, new_row) in features.iter_mut().zip(new_features) {
+            row.extend(new_row);
+        }
+        start = end;
+    }
+    features
+}
+
+#[cfg(test)]
+mod test_recursive_structural_features {
+    use super::recursive_structural_features;
+    use petgraph::graph::{DiGraph, UnGraph};
+    use petgraph::stable_graph::StableUnGraph;
+
+    #[test]
+    fn test_no_rounds() {
+        // A triangle with a pendant node 3 on node 2
+        let graph = UnGraph::<(), ()>::from_edges([(0, 1), (1, 2), (2, 0), (2, 3)]);
+        let features = recursive_structural_features(&graph, 0);
+        assert_eq!(
+            features,
+            vec![
+                vec![2., 3., 1.],
+                vec![2., 3., 1.],
+                vec![3., 4., 0.],
+                vec![1., 1., 2.],
+            ]
+        );
+    }
+
+    #[test]
+    fn test_feature_count() {
+        let graph = UnGraph::<(), ()>::from_edges([(0, 1), (1, 2)]);
+        let features = recursive_structural_features(&graph, 3);
+        assert!(features.iter().all(|row| row.len() == 3 * 15));
+    }
+
+    #[test]
+    fn test_isolated_node() {
+        let mut graph = UnGraph::<(), ()>::from_edges([(0, 1)]);
+        graph.add_node(());
+        let features = recursive_structural_features(&graph, 1);
+        assert_eq!(features[2], vec![0.; 9]);
+    }
+
+    #[test]
+    fn test_directed() {
+        let graph = DiGraph::<(), ()>::from_edges([(0, 1), (1, 2), (2, 0), (3, 0)]);
+        let features = recursive_structural_features(&graph, 1);
+        // Node 0 has predecessors 2 and 3 and successor 1, so its egonet has
+        // every node and all the edges
+        assert_eq!(features[0][..5], [2., 1., 4., 0., 0.]);
+        // Node 3 has the egonet {0, 3}, the edge 0 -> 1 leaves it and 2 -> 0
+        // enters it
+        assert_eq!(features[3][..5], [0., 1., 1., 1., 1.]);
+        assert_eq!(features[3].len(), 15);
+        // The sum and mean of the in-degrees of the neighbors of node 3
+        assert_eq!(features[3][5..7], [2., 2.]);
+    }
+
+    #[test]
+    fn test_self_loop_and_parallel_edges() {
+        let graph = UnGraph::<(), ()>::from_edges([(0, 0), (0, 1), (0, 1)]);
+        let features = recursive_structural_features(&graph, 1);
+        assert_eq!(features[0][..3], [3., 3., 0.]);
+        // Node 0 isn't its own neighbor
+        assert_eq!(features[0][3..5], [2., 2.]);
+    }
+
+    #[test]
+    fn test_removed_node() {
+        let mut graph = StableUnGraph::<(), ()>::default();
+        let a = graph.add_node(());
+        let b = graph.add_node(());
+        let c = graph.add_node(());
+        graph.add_edge(a, c, ());
+        graph.remove_node(b);
+        let features = recursive_structural_features(&graph, 1);
+        assert_eq!(features.len(), 3);
+        assert_eq!(features[1], vec![0.; 9]);
+        assert_eq!(features[0], features[2]);
+    }
+}
@@ -0,0 +1,457 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+//! Algorithms for temporal graphs.
+//!
+//! A temporal graph is a graph whose edges are only available at given
+//! times. Rather than a separate graph type, the functions in this module
+//! take a `time_fn` callback that returns the time interval
+//! `(departure, arrival)` of an edge: the edge can be entered at the
+//! departure time and the other endpoint is reached at the arrival time.
+//! Contacts without a duration, like the timestamped edges of a contact
+//! network, have equal departure and arrival times. Parallel edges model an
+//! edge that is available at several times.
+//!
+//! A path is time-respecting if every edge departs no earlier than the
+//! arrival time of the previous edge. Edges whose arrival time is earlier
+//! than their departure time are never traversed.
+
+use std::collections::BinaryHeap;
+use std::hash::Hash;
+
+use crate::par::*;
+use petgraph::visit::{EdgeRef, GraphProp, IntoEdgeReferences, IntoNodeIdentifiers, NodeIndexable};
+
+use crate::dictmap::*;
+use crate::min_scored::MinScored;
+
+/// An adjacency list of a temporal graph indexed by the node indices, with
+/// the target, departure and arrival time of every edge.
+struct TemporalAdjacency {
+    neighbors: Vec<Vec<(usize, f64, f64)>>,
+}
+
+impl TemporalAdjacency {
+    fn new<G, F, E>(graph: G, mut time_fn: F) -> Result<Self, E>
+    where
+        G: IntoEdgeReferences + NodeIndexable + GraphProp,
+        F: FnMut(G::EdgeRef) -> Result<(f64, f64), E>,
+    {
+        let mut neighbors = vec![Vec::new(); graph.node_bound()];
+        for edge in graph.edge_references() {
+            let source = graph.to_index(edge.source());
+            let target = graph.to_index(edge.target());
+            let (departure, arrival) = time_fn(edge)?;
+            if arrival < departure {
+                continue;
+            }
+            neighbors[source].push((target, departure, arrival));
+            if !graph.is_directed() && source != target {
+                neighbors[target].push((source, departure, arrival));
+            }
+        }
+        Ok(TemporalAdjacency { neighbors })
+    }
+
+    /// Return the earliest arrival time at every node when leaving `source`
+    /// at `start_time`, `INFINITY` for the nodes that can't be reached by
+    /// `end_time`, and the nodes in the order they were reached. If
+    /// `predecessors` is given it's filled with the node each node is
+    /// reached from.
+    fn arrival_times(
+        &self,
+        source: usize,
+        start_time: f64,
+        end_time: f64,
+        mut predecessors: Option<&mut Vec<usize>>,
+    ) -> (Vec<f64>, Vec<usize>) {
+        let mut arrival = vec![f64::INFINITY; self.neighbors.len()];
+        let mut done = vec![false; self.neighbors.len()];
+        let mut order = Vec::new();
+        let mut heap = BinaryHeap::new();
+        arrival[source] = start_time;
+        heap.push(MinScored(start_time, source));
+        while let Some(MinScored(time, node)) = heap.pop() {
+            if done[node] {
+                continue;
+            }
+            done[node] = true;
+            order.push(node);
+            for &(target, departure, edge_arrival) in &self.neighbors[node] {
+                if departure >= time && edge_arrival <= end_time && edge_arrival < arrival[target] {
+                    arrival[target] = edge_arrival;
+                    if let Some(predecessors) = predecessors.as_mut() {
+                        predecessors[target] = node;
+                    }
+                    heap.push(MinScored(edge_arrival, target));
+                }
+            }
+        }
+        (arrival, order)
+    }
+}
+
+/// Compute the earliest arrival time at every node reachable from a source
+/// node with a time-respecting path.
+///
+/// The search leaves `source` at `start_time` and only uses edges that
+/// depart no earlier than the arrival at their source and that arrive no
+/// later than `end_time`. Like Dijkstra's algorithm it runs in
+/// `O((m + n) log n)` time. The nodes in the returned map are the nodes that
+/// are temporally reachable from `source`, including `source` itself.
+///
+/// Arguments:
+///
+/// * `graph` - The temporal graph to search.
+/// * `source` - The node to start from.
+/// * `start_time` - The time the search leaves `source`.
+/// * `end_time` - The latest allowed arrival time.
+/// * `time_fn` - A callback returning the `(departure, arrival)` times of
+///   an edge. It's called once for every edge of the graph.
+/// * `path` - An optional mutable map that will be filled with an earliest
+///   arrival path to every reachable node, starting with `source`.
+///
+/// # Example
+/// ```rust
+/// use rustworkx_core::dictmap::*;
+/// use rustworkx_core::petgraph::graph::{DiGraph, NodeIndex};
+/// use rustworkx_core::temporal::earliest_arrival;
+/// use rustworkx_core::Result;
+///
+/// // The edge weights are the times of the contacts
+/// let graph = DiGraph::<(), f64>::from_edges(&[(0, 1, 3.), (1, 2, 1.), (1, 2, 5.)]);
+/// let mut paths = DictMap::new();
+/// let arrival: Result<DictMap<NodeIndex, f64>> = earliest_arrival(
+///     &graph,
+///     0.into(),
+///     0.,
+///     f64::INFINITY,
+///     |e| Ok((*e.weight(), *e.weight())),
+///     Some(&mut paths),
+/// );
+/// let arrival = arrival.unwrap();
+/// // Node 1 is reached at time 3 so the contact at time 1 is missed
+/// assert_eq!(arrival[&NodeIndex::new(2)], 5.);
+/// let path: Vec<usize> = paths[&NodeIndex::new(2)].iter().map(|n| n.index()).collect();
+/// assert_eq!(path, vec![0, 1, 2]);
+/// ```
+pub fn earliest_arrival<G, F, E>(
+    graph: G,
+    source: G::NodeId,
+    start_time: f64,
+    end_time: f64,
+    time_fn: F,
+    mut path: Option<&mut DictMap<G::NodeId, Vec<G::NodeId>>>,
+) -> Result<DictMap<G::NodeId, f64>, E>
+where
+    G: IntoEdgeReferences + NodeIndexable + GraphProp,
+    G::NodeId: Eq + Hash,
+    F: FnMut(G::EdgeRef) -> Result<(f64, f64), E>,
+{
+    let adjacency = TemporalAdjacency::new(graph, time_fn)?;
+    let source_index = graph.to_index(source);
+    let mut predecessors = vec![usize::MAX; graph.node_bound()];
+    let (arrival, order) = adjacency.arrival_times(
+        source_index,
+        start_time,
+        end_time,
+        path.as_ref().map(|_| &mut predecessors),
+    );
+    if let Some(path) = path.as_mut() {
+        path.clear();
+        for &node in &order {
+            let mut node_path = if node == source_index {
+                Vec::new()
+            } else {
+                path[&graph.from_index(predecessors[node])].clone()
+            };
+            node_path.push(graph.from_index(node));
+            path.insert(graph.from_index(node), node_path);
+        }
+    }
+    Ok(order
+        .into_iter()
+        .map(|node| (graph.from_index(node), arrival[node]))
+        .collect())
+}
+
+/// Compute the duration of the fastest time-respecting path from a source
+/// node to every node reachable from it.
+///
+/// The duration of a path is the arrival time at its last node minus the
+/// departure time of its first edge, so unlike [`earliest_arrival`] the
+/// search may wait at `source` for a later departure. The edges used must
+/// depart no earlier than `start_time` and arrive no later than `end_time`.
+/// One earliest arrival search is run for every distinct departure time of
+/// the edges leaving `source`, in parallel. The duration for `source`
+/// itself is `0`.
+///
+/// Arguments:
+///
+/// * `graph` - The temporal graph to search.
+/// * `source` - The node to start from.
+/// * `start_time` - The earliest allowed departure time.
+/// * `end_time` - The latest allowed arrival time.
+/// * `time_fn` - A callback returning the `(departure, arrival)` times of
+///   an edge. It's called once for every edge of the graph.
+///
+/// # Example
+/// ```rust
+/// use rustworkx_core::dictmap::DictMap;
+/// use rustworkx_core::petgraph::graph::{DiGraph, NodeIndex};
+/// use rustworkx_core::temporal::fastest_path_durations;
+/// use rustworkx_core::Result;
+///
+/// let graph = DiGraph::<(), f64>::from_edges(&[(0, 1, 1.), (0, 1, 6.), (1, 2, 7.)]);
+/// let durations: Result<DictMap<NodeIndex, f64>> = fastest_path_durations(
+///     &graph,
+///     0.into(),
+///     f64::NEG_INFINITY,
+///     f64::INFINITY,
+///     |e| Ok((*e.weight(), *e.weight())),
+/// );
+/// // Leaving at time 6 instead of time 1 reaches node 2 after 1 time unit
+/// assert_eq!(durations.unwrap()[&NodeIndex::new(2)], 1.);
+/// ```
+pub fn fastest_path_durations<G, F, E>(
+    graph: G,
+    source: G::NodeId,
+    start_time: f64,
+    end_time: f64,
+    time_fn: F,
+) -> Result<DictMap<G::NodeId, f64>, E>
+where
+    G: IntoEdgeReferences + IntoNodeIdentifiers + NodeIndexable + GraphProp,
+    G::NodeId: Eq + Hash,
+    F: FnMut(G::EdgeRef) -> Result<(f64, f64), E>,
+{
+    let adjacency = TemporalAdjacency::new(graph, time_fn)?;
+    let source_index = graph.to_index(source);
+    let mut departures: Vec<f64> = adjacency.neighbors[source_index]
+        .iter()
+        .filter(|(_, departure, arrival)| *departure >= start_time && *arrival <= end_time)
+        .map(|(_, departure, _)| *departure)
+        .collect();
+    departures.sort_by(|a, b| a.total_cmp(b));
+    departures.dedup();
+    let node_bound = graph.node_bound();
+    let mut durations = departures
+        .par_iter()
+        .map(|departure| {
+            let (arrival, _) = adjacency.arrival_times(source_index, *departure, end_time, None);
+            arrival
+                .into_iter()
+                .map(|time| time - departure)
+                .collect::<Vec<f64>>()
+        })
+        .reduce(
+            || vec![f64::INFINITY; node_bound],
+            |a, b| a.into_iter().zip(b).map(|(a, b)| a.min(b)).collect(),
+        );
+    durations[source_index] = 0.;
+    Ok(graph
+        .node_identifiers()
+        .filter(|node| durations[graph.to_index(*node)].is_finite())
+        .map(|node| (node, durations[graph.to_index(node)]))
+        .collect())
+}
+
+/// Return the edges of a temporal graph that are active during a time
+/// window.
+///
+/// An edge is active if its `(departure, arrival)` interval intersects the
+/// window `[start_time, end_time]`, so with `start_time == end_time` this
+/// gives the snapshot of the graph at a single time.
+///
+/// Arguments:
+///
+/// * `graph` - The temporal graph.
+/// * `start_time` - The start of the time window.
+/// * `end_time` - The end of the time window.
+/// * `time_fn` - A callback returning the `(departure, arrival)` times of
+///   an edge.
+///
+/// # Example
+/// ```rust
+/// use rustworkx_core::petgraph::graph::{EdgeIndex, UnGraph};
+/// use rustworkx_core::temporal::snapshot_edges;
+/// use rustworkx_core::Result;
+///
+/// let graph = UnGraph::<(), (f64, f64)>::from_edges(&[
+///     (0, 1, (0., 2.)),
+///     (1, 2, (1., 4.)),
+///     (2, 0, (3., 5.)),
+/// ]);
+/// let edges: Result<Vec<EdgeIndex>> = snapshot_edges(&graph, 2., 2., |e| Ok(*e.weight()));
+/// assert_eq!(edges.unwrap(), vec![EdgeIndex::new(0), EdgeIndex::new(1)]);
+/// ```
+pub fn snapshot_edges<G, F, E>(
+    graph: G,
+    start_time: f64,
+    end_time: f64,
+    mut time_fn: F,
+) -> Result<Vec<G::EdgeId>, E>
+where
+    G: IntoEdgeReferences,
+    F: FnMut(G::EdgeRef) -> Result<(f64, f64), E>,
+{
+    let mut edges = Vec::new();
+    for edge in graph.edge_references() {
+        let (departure, arrival) = time_fn(edge)?;
+        if departure <= end_time && arrival >= start_time && departure <= arrival {
+            edges.push(edge.id());
+        }
+    }
+    Ok(edges)
+}
+
+#[cfg(test)]
+mod test_temporal {
+    use super::{earliest_arrival, fastest_path_durations, snapshot_edges};
+    use crate::dictmap::*;
+    use crate::Result;
+    use petgraph::graph::{DiGraph, NodeIndex, UnGraph};
+    use petgraph::stable_graph::StableDiGraph;
+
+    fn arrival_times(graph: &DiGraph<(), (f64, f64)>, start_time: f64) -> Vec<Option<f64>> {
+        let arrival: Result<DictMap<NodeIndex, f64>> = earliest_arrival(
+            graph,
+            NodeIndex::new(0),
+            start_time,
+            f64::INFINITY,
+            |e| Ok(*e.weight()),
+            None,
+        );
+        let arrival = arrival.unwrap();
+        graph
+            .node_indices()
+            .map(|node| arrival.get(&node).copied())
+            .collect()
+    }
+
+    #[test]
+    fn test_earliest_arrival_respects_time() {
+        let graph = DiGraph::<(), (f64, f64)>::from_edges([
+            (0, 1, (2., 3.)),
+            (1, 2, (1., 2.)),
+            (1, 3, (3., 4.)),
+            (3, 2, (4., 6.)),
+        ]);
+        assert_eq!(
+            arrival_times(&graph, 0.),
+            vec![Some(0.), Some(3.), Some(6.), Some(4.)]
+        );
+        // Leaving after the only edge out of the source reaches nothing
+        assert_eq!(
+            arrival_times(&graph, 2.5),
+            vec![Some(2.5), None, None, None]
+        );
+    }
+
+    #[test]
+    fn test_earliest_arrival_zero_duration_chain() {
+        let graph = DiGraph::<(), (f64, f64)>::from_edges([
+            (2, 3, (5., 5.)),
+            (1, 2, (5., 5.)),
+            (0, 1, (5., 5.)),
+        ]);
+        assert_eq!(
+            arrival_times(&graph, 0.),
+            vec![Some(0.), Some(5.), Some(5.), Some(5.)]
+        );
+    }
+
+    #[test]
+    fn test_earliest_arrival_end_time() {
+        let graph = DiGraph::<(), (f64, f64)>::from_edges([(0, 1, (1., 2.)), (1, 2, (3., 4.))]);
+        let arrival: Result<DictMap<NodeIndex, f64>> =
+            earliest_arrival(&graph, 0.into(), 0., 3., |e| Ok(*e.weight()), None);
+        let arrival = arrival.unwrap();
+        assert_eq!(arrival.len(), 2);
+        assert!(!arrival.contains_key(&NodeIndex::new(2)));
+    }
+
+    #[test]
+    fn test_earliest_arrival_undirected_paths() {
+        let graph = UnGraph::<(), f64>::from_edges([(1, 0, 1.), (2, 1, 2.), (0, 2, 1.5)]);
+        let mut paths = DictMap::new();
+        let arrival: Result<DictMap<NodeIndex, f64>> = earliest_arrival(
+            &graph,
+            0.into(),
+            0.,
+            f64::INFINITY,
+            |e| Ok((*e.weight(), *e.weight())),
+            Some(&mut paths),
+        );
+        let arrival = arrival.unwrap();
+        assert_eq!(arrival[&NodeIndex::new(2)], 1.5);
+        assert_eq!(paths[&NodeIndex::new(0)], vec![NodeIndex::new(0)]);
+        assert_eq!(
+            paths[&NodeIndex::new(2)],
+            vec![NodeIndex::new(0), NodeIndex::new(2)]
+        );
+    }
+
+    #[test]
+    fn test_invalid_interval_ignored() {
+        let graph = DiGraph::<(), (f64, f64)>::from_edges([(0, 1, (2., 1.))]);
+        assert_eq!(arrival_times(&graph, 0.), vec![Some(0.), None]);
+    }
+
+    #[test]
+    fn test_fastest_path_durations() {
+        let graph = DiGraph::<(), (f64, f64)>::from_edges([
+            (0, 1, (0., 1.)),
+            (0, 1, (5., 6.)),
+            (1, 2, (6., 8.)),
+            (0, 3, (9., 9.)),
+        ]);
+        let durations: Result<DictMap<NodeIndex, f64>> =
+            fastest_path_durations(&graph, 0.into(), f64::NEG_INFINITY, f64::INFINITY, |e| {
+                Ok(*e.weight())
+            });
+        let durations = durations.unwrap();
+        let expected: DictMap<NodeIndex, f64> = [(0, 0.), (1, 1.), (2, 3.), (3, 0.)]
+            .into_iter()
+            .map(|(node, duration)| (NodeIndex::new(node), duration))
+            .collect();
+        assert_eq!(durations, expected);
+    }
+
+    #[test]
+    fn test_fastest_path_removed_nodes() {
+        let mut graph = StableDiGraph::<(), (f64, f64)>::new();
+        let nodes: Vec<_> = (0..4).map(|_| graph.add_node(())).collect();
+        graph.add_edge(nodes[0], nodes[2], (1., 2.));
+        graph.add_edge(nodes[2], nodes[3], (4., 5.));
+        graph.remove_node(nodes[1]);
+        let durations: Result<DictMap<NodeIndex, f64>> =
+            fastest_path_durations(&graph, nodes[0], 0., 10., |e| Ok(*e.weight()));
+        let durations = durations.unwrap();
+        assert_eq!(durations.len(), 3);
+        assert_eq!(durations[&nodes[3]], 4.);
+    }
+
+    #[test]
+    fn test_snapshot_window() {
+        let graph = DiGraph::<(), (f64, f64)>::from_edges([
+            (0, 1, (0., 1.)),
+            (1, 2, (2., 3.)),
+            (2, 3, (4., 4.)),
+        ]);
+        let edges: Result<Vec<_>> = snapshot_edges(&graph, 1., 4., |e| Ok(*e.weight()));
+        let edges: Vec<usize> = edges.unwrap().into_iter().map(|e| e.index()).collect();
+        assert_eq!(edges, vec![0, 1, 2]);
+        let edges: Result<Vec<_>> = snapshot_edges(&graph, 1.5, 1.8, |e| Ok(*e.weight()));
+        assert!(edges.unwrap().is_empty());
+    }
+}
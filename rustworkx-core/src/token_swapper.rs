@@ -10,13 +10,15 @@
 // License for the specific language governing permissions and limitations
 // under the License.
 
-use rand::distributions::{Standard, Uniform};
+use crate::rng::{task_seeds, UniformIndex};
 use rand::prelude::*;
 use rand_pcg::Pcg64;
 use std::error::Error;
 use std::fmt;
 use std::hash::Hash;
 
+use crate::par::CondIterator;
+use crate::par::*;
 use hashbrown::HashMap;
 use petgraph::stable_graph::{NodeIndex, StableGraph};
 use petgraph::visit::{
@@ -25,8 +27,6 @@ use petgraph::visit::{
 };
 use petgraph::Directed;
 use petgraph::Direction::{Incoming, Outgoing};
-use rayon::prelude::*;
-use rayon_cond::CondIterator;
 
 use crate::connectivity::find_cycle;
 use crate::dictmap::*;
@@ -159,12 +159,7 @@ where
         }
         // First collect the self.trial number of random numbers
         // into a Vec based on the given seed
-        let outer_rng: Pcg64 = match self.seed {
-            Some(rng_seed) => Pcg64::seed_from_u64(rng_seed),
-            None => Pcg64::from_entropy(),
-        };
-        let trial_seeds_vec: Vec<u64> =
-            outer_rng.sample_iter(&Standard).take(self.trials).collect();
+        let trial_seeds_vec: Vec<u64> = task_seeds(self.seed, self.trials);
 
         CondIterator::new(
             trial_seeds_vec,
@@ -260,7 +255,7 @@ where
         let mut rng_seed: Pcg64 = Pcg64::seed_from_u64(trial_seed);
         while !todo_nodes.is_empty() && steps <= 4 * digraph.node_count().pow(2) {
             // Choose a random todo_node
-            let between = Uniform::new(0, todo_nodes.len());
+            let between = UniformIndex::new(todo_nodes.len());
             let random: usize = between.sample(&mut rng_seed);
             let todo_node = todo_nodes[random];
 
@@ -0,0 +1,568 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+use petgraph::visit::{EdgeCount, GraphProp, IntoNeighbors, IntoNodeIdentifiers, NodeIndexable};
+use petgraph::Undirected;
+
+use crate::dictmap::*;
+
+/// Error returned by the tree functions.
+#[derive(Debug, PartialEq, Eq)]
+pub enum TreeError {
+    /// The graph has no nodes.
+    NullGraph,
+    /// The graph isn't connected or has a cycle.
+    NotATree,
+    /// The requested root isn't a node of the graph.
+    InvalidRoot,
+}
+
+impl Display for TreeError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TreeError::NullGraph => write!(f, "The graph has no nodes"),
+            TreeError::NotATree => write!(
+                f,
+                "The graph isn't a tree, it must be connected and have one edge less than nodes"
+            ),
+            TreeError::InvalidRoot => write!(f, "The root isn't a node of the graph"),
+        }
+    }
+}
+
+impl Error for TreeError {}
+
+/// A tree with its nodes relabeled to `0..n` and the neighbors of every
+/// node sorted by their new label.
+struct Tree<N> {
+    nodes: Vec<N>,
+    compact: Vec<usize>,
+    neighbors: Vec<Vec<usize>>,
+}
+
+impl<N: Copy> Tree<N> {
+    fn new<G>(graph: G) -> Result<Self, TreeError>
+    where
+        G: IntoNeighbors<NodeId = N>
+            + IntoNodeIdentifiers
+            + NodeIndexable
+            + EdgeCount
+            + GraphProp<EdgeType = Undirected>,
+    {
+        let nodes: Vec<N> = graph.node_identifiers().collect();
+        if nodes.is_empty() {
+            return Err(TreeError::NullGraph);
+        }
+        if graph.edge_count() + 1 != nodes.len() {
+            return Err(TreeError::NotATree);
+        }
+        let mut compact: Vec<usize> = vec![usize::MAX; graph.node_bound()];
+        for (position, node) in nodes.iter().enumerate() {
+            compact[graph.to_index(*node)] = position;
+        }
+        let neighbors: Vec<Vec<usize>> = nodes
+            .iter()
+            .map(|node| {
+                let mut neighbors: Vec<usize> = graph
+                    .neighbors(*node)
+                    .map(|other| compact[graph.to_index(other)])
+                    .collect();
+                neighbors.sort_unstable();
+                neighbors
+            })
+            .collect();
+        // With one edge less than nodes the graph is a tree if it's connected
+        let mut visited: Vec<bool> = vec![false; nodes.len()];
+        let mut stack: Vec<usize> = vec![0];
+        visited[0] = true;
+        let mut count = 1;
+        while let Some(node) = stack.pop() {
+            for other in &neighbors[node] {
+                if !visited[*other] {
+                    visited[*other] = true;
+                    count += 1;
+                    stack.push(*other);
+                }
+            }
+        }
+        if count != nodes.len() {
+            return Err(TreeError::NotATree);
+        }
+        Ok(Tree {
+            nodes,
+            compact,
+            neighbors,
+        })
+    }
+
+    /// Return the nodes reachable from `root` without passing through the
+    /// `removed` nodes, in breadth-first order, and the parent of every
+    /// reached node other than `root`.
+    fn component(&self, root: usize, removed: &[bool], parent: &mut [usize]) -> Vec<usize> {
+        let mut order: Vec<usize> = vec![root];
+        parent[root] = usize::MAX;
+        let mut index = 0;
+        while index < order.len() {
+            let node = order[index];
+            for other in &self.neighbors[node] {
+                if !removed[*other] && *other != parent[node] {
+                    parent[*other] = node;
+                    order.push(*other);
+                }
+            }
+            index += 1;
+        }
+        order
+    }
+
+    fn breadth_first(&self, root: usize) -> (Vec<usize>, Vec<usize>) {
+        let mut parent: Vec<usize> = vec![usize::MAX; self.nodes.len()];
+        let order = self.component(root, &vec![false; self.nodes.len()], &mut parent);
+        (order, parent)
+    }
+
+    fn centers(&self) -> Vec<usize> {
+        let size = self.nodes.len();
+        let mut degree: Vec<usize> = self.neighbors.iter().map(|n| n.len()).collect();
+        let mut leaves: Vec<usize> = (0..size).filter(|node| degree[*node] <= 1).collect();
+        let mut remaining = size;
+        while remaining > 2 {
+            remaining -= leaves.len();
+            let mut next: Vec<usize> = Vec::new();
+            for leaf in leaves {
+                for other in &self.neighbors[leaf] {
+                    degree[*other] -= 1;
+                    if degree[*other] == 1 {
+                        next.push(*other);
+                    }
+                }
+            }
+            next.sort_unstable();
+            leaves = next;
+        }
+        leaves
+    }
+
+    fn centroids(&self) -> Vec<usize> {
+        let size = self.nodes.len();
+        let (order, parent) = self.breadth_first(0);
+        let mut subtree: Vec<usize> = vec![1; size];
+        let mut largest: Vec<usize> = vec![0; size];
+        for node in order.iter().rev() {
+            largest[*node] = largest[*node].max(size - subtree[*node]);
+            if parent[*node] != usize::MAX {
+                subtree[parent[*node]] += subtree[*node];
+                largest[parent[*node]] = largest[parent[*node]].max(subtree[*node]);
+            }
+        }
+        (0..size)
+            .filter(|node| 2 * largest[*node] <= size)
+            .collect()
+    }
+}
+
+/// Find the center of a tree.
+///
+/// The center is the set of nodes with the smallest eccentricity, the
+/// largest distance to any other node. A tree has one center node or two
+/// adjacent ones, which are found by repeatedly removing all the leaves of
+/// the tree. This runs in `O(n log n)` time.
+///
+/// Arguments:
+///
+/// * `graph` - The tree to find the center of.
+///
+/// Returns the center nodes in the order of `node_identifiers()`, or an
+/// error if the graph is empty or isn't a tree.
+///
+/// # Example
+/// ```rust
+/// use rustworkx_core::petgraph::graph::{NodeIndex, UnGraph};
+/// use rustworkx_core::tree::tree_center;
+///
+/// let graph = UnGraph::<(), ()>::from_edges([(0, 1), (1, 2), (2, 3)]);
+/// let center = tree_center(&graph).unwrap();
+/// assert_eq!(center, vec![NodeIndex::new(1), NodeIndex::new(2)]);
+/// ```
+pub fn tree_center<G>(graph: G) -> Result<Vec<G::NodeId>, TreeError>
+where
+    G: IntoNeighbors
+        + IntoNodeIdentifiers
+        + NodeIndexable
+        + EdgeCount
+        + GraphProp<EdgeType = Undirected>,
+{
+    let tree = Tree::new(graph)?;
+    Ok(tree
+        .centers()
+        .into_iter()
+        .map(|node| tree.nodes[node])
+        .collect())
+}
+
+/// Find the centroid of a tree.
+///
+/// The centroid is the set of nodes whose removal leaves connected
+/// components with at most half of the nodes of the tree each. A tree has
+/// one centroid node or two adjacent ones. This runs in `O(n log n)` time.
+///
+/// Arguments:
+///
+/// * `graph` - The tree to find the centroid of.
+///
+/// Returns the centroid nodes in the order of `node_identifiers()`, or an
+/// error if the graph is empty or isn't a tree.
+///
+/// # Example
+/// ```rust
+/// use rustworkx_core::petgraph::graph::{NodeIndex, UnGraph};
+/// use rustworkx_core::tree::tree_centroid;
+///
+/// // A star with a long tail, whose center is on the tail
+/// let graph = UnGraph::<(), ()>::from_edges([
+///     (0, 1), (0, 2), (0, 3), (0, 4), (0, 5), (5, 6), (6, 7), (7, 8),
+/// ]);
+/// let centroid = tree_centroid(&graph).unwrap();
+/// assert_eq!(centroid, vec![NodeIndex::new(0)]);
+/// ```
+pub fn tree_centroid<G>(graph: G) -> Result<Vec<G::NodeId>, TreeError>
+where
+    G: IntoNeighbors
+        + IntoNodeIdentifiers
+        + NodeIndexable
+        + EdgeCount
+        + GraphProp<EdgeType = Undirected>,
+{
+    let tree = Tree::new(graph)?;
+    Ok(tree
+        .centroids()
+        .into_iter()
+        .map(|node| tree.nodes[node])
+        .collect())
+}
+
+/// Compute the centroid decomposition of a tree.
+///
+/// The centroid decomposition picks a centroid of the tree as the root and
+/// then recursively decomposes each of the components left after removing
+/// it, making their centroids children of the root. The resulting centroid
+/// tree has a depth of at most `log2(n) + 1`, and the path between any two
+/// nodes of the tree passes through their lowest common ancestor in the
+/// centroid tree, which is the basis of many divide and conquer algorithms
+/// on trees. This runs in `O(n log n)` time.
+///
+/// Arguments:
+///
+/// * `graph` - The tree to decompose.
+///
+/// Returns a map of every node to its parent in the centroid tree, or
+/// `None` for the root, in the order the centroids are picked so the root is
+/// first. Returns an error if the graph is empty or isn't a tree.
+///
+/// # Example
+/// ```rust
+/// use rustworkx_core::petgraph::graph::{NodeIndex, UnGraph};
+/// use rustworkx_core::tree::centroid_decomposition;
+///
+/// let graph = UnGraph::<(), ()>::from_edges([(0, 1), (1, 2), (2, 3), (3, 4), (4, 5), (5, 6)]);
+/// let parents = centroid_decomposition(&graph).unwrap();
+/// assert_eq!(parents[&NodeIndex::new(3)], None);
+/// assert_eq!(parents[&NodeIndex::new(1)], Some(NodeIndex::new(3)));
+/// assert_eq!(parents[&NodeIndex::new(0)], Some(NodeIndex::new(1)));
+/// ```
+pub fn centroid_decomposition<G>(
+    graph: G,
+) -> Result<DictMap<G::NodeId, Option<G::NodeId>>, TreeError>
+where
+    G: IntoNeighbors
+        + IntoNodeIdentifiers
+        + NodeIndexable
+        + EdgeCount
+        + GraphProp<EdgeType = Undirected>,
+    G::NodeId: Eq + std::hash::Hash,
+{
+    let tree = Tree::new(graph)?;
+    let size = tree.nodes.len();
+    let mut removed: Vec<bool> = vec![false; size];
+    let mut parent: Vec<usize> = vec![usize::MAX; size];
+    let mut subtree: Vec<usize> = vec![1; size];
+    let mut decomposition: DictMap<G::NodeId, Option<G::NodeId>> = DictMap::with_capacity(size);
+    let mut stack: Vec<(usize, Option<usize>)> = vec![(0, None)];
+    while let Some((start, centroid_parent)) = stack.pop() {
+        let component = tree.component(start, &removed, &mut parent);
+        for node in component.iter().rev() {
+            subtree[*node] = 1 + tree.neighbors[*node]
+                .iter()
+                .filter(|other| !removed[**other] && **other != parent[*node])
+                .map(|other| subtree[*other])
+                .sum::<usize>();
+        }
+        // Walk towards the larger half until no child subtree is larger than
+        // half of the component
+        let total = component.len();
+        let mut centroid = start;
+        while let Some(next) = tree.neighbors[centroid].iter().find(|other| {
+            !removed[**other] && **other != parent[centroid] && 2 * subtree[**other] > total
+        }) {
+            centroid = *next;
+        }
+        removed[centroid] = true;
+        decomposition.insert(
+            tree.nodes[centroid],
+            centroid_parent.map(|node| tree.nodes[node]),
+        );
+        for other in tree.neighbors[centroid].iter().rev() {
+            if !removed[*other] {
+                stack.push((*other, Some(centroid)));
+            }
+        }
+    }
+    Ok(decomposition)
+}
+
+/// A tree rooted at one of its nodes, as returned by [`root_tree`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RootedTree<N: Eq + std::hash::Hash> {
+    /// The root of the tree.
+    pub root: N,
+    /// The parent of every node, or `None` for the root, in depth-first
+    /// preorder.
+    pub parents: DictMap<N, Option<N>>,
+    /// The nodes in the order a depth-first traversal from the root visits
+    /// them, where a node is listed again after returning from each of its
+    /// children. It has `2n - 1` entries, and the nodes between the first
+    /// occurrences of two nodes include their lowest common ancestor as the
+    /// node of the smallest depth.
+    pub euler_tour: Vec<N>,
+}
+
+/// Root a tree at one of its nodes.
+///
+/// This finds the parent of every node and an Euler tour of the tree rooted
+/// at `root`. If `root` is `None` the tree is rooted at its center, or at
+/// the first one in the order of `node_identifiers()` if it has two center
+/// nodes, which roots isomorphic trees with a single center node
+/// consistently. The children of a node are visited in the order of their
+/// position in `node_identifiers()`. This runs in `O(n log n)` time.
+///
+/// Arguments:
+///
+/// * `graph` - The tree to root.
+/// * `root` - The optional node to root the tree at.
+///
+/// Returns the rooted tree, or an error if the graph is empty, isn't a tree
+/// or `root` isn't a node of the graph.
+///
+/// # Example
+/// ```rust
+/// use rustworkx_core::petgraph::graph::{NodeIndex, UnGraph};
+/// use rustworkx_core::tree::root_tree;
+///
+/// let graph = UnGraph::<(), ()>::from_edges([(0, 1), (1, 2), (1, 3)]);
+/// let rooted = root_tree(&graph, None).unwrap();
+/// assert_eq!(rooted.root, NodeIndex::new(1));
+/// assert_eq!(rooted.parents[&NodeIndex::new(3)], Some(NodeIndex::new(1)));
+/// let tour: Vec<usize> = rooted.euler_tour.iter().map(|n| n.index()).collect();
+/// assert_eq!(tour, vec![1, 0, 1, 2, 1, 3, 1]);
+/// ```
+pub fn root_tree<G>(graph: G, root: Option<G::NodeId>) -> Result<RootedTree<G::NodeId>, TreeError>
+where
+    G: IntoNeighbors
+        + IntoNodeIdentifiers
+        + NodeIndexable
+        + EdgeCount
+        + GraphProp<EdgeType = Undirected>,
+    G::NodeId: Eq + std::hash::Hash,
+{
+    let tree = Tree::new(graph)?;
+    let root = match root {
+        Some(root) => *tree
+            .compact
+            .get(graph.to_index(root))
+            .filter(|position| **position != usize::MAX)
+            .ok_or(TreeError::InvalidRoot)?,
+        None => tree.centers()[0],
+    };
+    let size = tree.nodes.len();
+    let mut parents: DictMap<G::NodeId, Option<G::NodeId>> = DictMap::with_capacity(size);
+    let mut euler_tour: Vec<G::NodeId> = Vec::with_capacity(2 * size - 1);
+    parents.insert(tree.nodes[root], None);
+    euler_tour.push(tree.nodes[root]);
+    // The nodes on the path from the root with the position of their next
+    // neighbor to visit
+    let mut stack: Vec<(usize, usize, usize)> = vec![(root, usize::MAX, 0)];
+    while let Some((node, parent, next)) = stack.last_mut() {
+        let neighbors = &tree.neighbors[*node];
+        match neighbors[*next..]
+            .iter()
+            .position(|other| *other != *parent)
+        {
+            Some(offset) => {
+                let child = neighbors[*next + offset];
+                *next += offset + 1;
+                let node = *node;
+                parents.insert(tree.nodes[child], Some(tree.nodes[node]));
+                euler_tour.push(tree.nodes[child]);
+                stack.push((child, node, 0));
+            }
+            None => {
+                stack.pop();
+                if let Some((node, _, _)) = stack.last() {
+                    euler_tour.push(tree.nodes[*node]);
+                }
+            }
+        }
+    }
+    Ok(RootedTree {
+        root: tree.nodes[root],
+        parents,
+        euler_tour,
+    })
+}
+
+#[cfg(test)]
+mod test_tree {
+    use super::{centroid_decomposition, root_tree, tree_center, tree_centroid, TreeError};
+    use crate::petgraph::graph::{NodeIndex, UnGraph};
+    use crate::petgraph::stable_graph::StableUnGraph;
+
+    fn nodes(indices: &[usize]) -> Vec<NodeIndex> {
+        indices.iter().map(|i| NodeIndex::new(*i)).collect()
+    }
+
+    #[test]
+    fn test_errors() {
+        let graph = UnGraph::<(), ()>::default();
+        assert_eq!(tree_center(&graph), Err(TreeError::NullGraph));
+        let graph = UnGraph::<(), ()>::from_edges([(0, 1), (1, 2), (2, 0)]);
+        assert_eq!(tree_centroid(&graph), Err(TreeError::NotATree));
+        let graph = UnGraph::<(), ()>::from_edges([(0, 1), (2, 3)]);
+        assert_eq!(centroid_decomposition(&graph), Err(TreeError::NotATree));
+        // As many edges as a tree, but with a cycle and an isolated node
+        let mut graph = UnGraph::<(), ()>::from_edges([(0, 1), (1, 2), (2, 0)]);
+        graph.add_node(());
+        assert_eq!(tree_center(&graph), Err(TreeError::NotATree));
+        let graph = UnGraph::<(), ()>::from_edges([(0, 1)]);
+        assert_eq!(
+            root_tree(&graph, Some(NodeIndex::new(5))),
+            Err(TreeError::InvalidRoot)
+        );
+    }
+
+    #[test]
+    fn test_single_node() {
+        let mut graph = UnGraph::<(), ()>::default();
+        graph.add_node(());
+        assert_eq!(tree_center(&graph).unwrap(), nodes(&[0]));
+        assert_eq!(tree_centroid(&graph).unwrap(), nodes(&[0]));
+        let rooted = root_tree(&graph, None).unwrap();
+        assert_eq!(rooted.euler_tour, nodes(&[0]));
+        assert_eq!(rooted.parents[&NodeIndex::new(0)], None);
+    }
+
+    #[test]
+    fn test_path_center_and_centroid() {
+        let graph = UnGraph::<(), ()>::from_edges([(0, 1), (1, 2), (2, 3), (3, 4)]);
+        assert_eq!(tree_center(&graph).unwrap(), nodes(&[2]));
+        assert_eq!(tree_centroid(&graph).unwrap(), nodes(&[2]));
+        let graph = UnGraph::<(), ()>::from_edges([(0, 1), (1, 2), (2, 3), (3, 4), (4, 5)]);
+        assert_eq!(tree_center(&graph).unwrap(), nodes(&[2, 3]));
+        assert_eq!(tree_centroid(&graph).unwrap(), nodes(&[2, 3]));
+    }
+
+    #[test]
+    fn test_center_differs_from_centroid() {
+        // A star with 5 leaves and a tail of length 4 from its center
+        let graph = UnGraph::<(), ()>::from_edges([
+            (0, 1),
+            (0, 2),
+            (0, 3),
+            (0, 4),
+            (0, 5),
+            (5, 6),
+            (6, 7),
+            (7, 8),
+        ]);
+        assert_eq!(tree_center(&graph).unwrap(), nodes(&[5, 6]));
+        assert_eq!(tree_centroid(&graph).unwrap(), nodes(&[0]));
+    }
+
+    #[test]
+    fn test_centroid_decomposition() {
+        let mut graph = UnGraph::<(), ()>::default();
+        for _ in 0..31 {
+            graph.add_node(());
+        }
+        for node in 1..31_usize {
+            graph.add_edge(NodeIndex::new((node - 1) / 2), NodeIndex::new(node), ());
+        }
+        let parents = centroid_decomposition(&graph).unwrap();
+        assert_eq!(parents.len(), 31);
+        assert_eq!(parents.first(), Some((&NodeIndex::new(0), &None)));
+        for node in graph.node_indices() {
+            // Every component is at most half the size of the one before, so
+            // the depth is at most log2(31) + 1
+            let mut depth = 0;
+            let mut current = node;
+            while let Some(parent) = parents[&current] {
+                current = parent;
+                depth += 1;
+            }
+            assert!(depth <= 4);
+        }
+    }
+
+    #[test]
+    fn test_root_tree_euler_tour() {
+        let graph = UnGraph::<(), ()>::from_edges([(0, 1), (0, 2), (2, 3), (2, 4)]);
+        let rooted = root_tree(&graph, Some(NodeIndex::new(3))).unwrap();
+        assert_eq!(rooted.root, NodeIndex::new(3));
+        assert_eq!(rooted.euler_tour, nodes(&[3, 2, 0, 1, 0, 2, 4, 2, 3]));
+        let parents: Vec<(usize, Option<usize>)> = rooted
+            .parents
+            .iter()
+            .map(|(node, parent)| (node.index(), parent.map(|p| p.index())))
+            .collect();
+        assert_eq!(
+            parents,
+            vec![
+                (3, None),
+                (2, Some(3)),
+                (0, Some(2)),
+                (1, Some(0)),
+                (4, Some(2))
+            ]
+        );
+    }
+
+    #[test]
+    fn test_stable_graph_with_removed_nodes() {
+        let mut graph = StableUnGraph::<(), ()>::default();
+        let a = graph.add_node(());
+        let removed = graph.add_node(());
+        let b = graph.add_node(());
+        let c = graph.add_node(());
+        graph.add_edge(a, b, ());
+        graph.add_edge(b, c, ());
+        graph.remove_node(removed);
+        assert_eq!(tree_center(&graph).unwrap(), vec![b]);
+        let rooted = root_tree(&graph, Some(a)).unwrap();
+        assert_eq!(rooted.euler_tour, vec![a, b, c, b, a]);
+        assert_eq!(
+            root_tree(&graph, Some(removed)),
+            Err(TreeError::InvalidRoot)
+        );
+    }
+}
@@ -0,0 +1,461 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+use std::collections::BTreeSet;
+use std::hash::Hash;
+
+use hashbrown::{HashMap, HashSet};
+use petgraph::data::Create;
+use petgraph::visit::{Data, EdgeRef, IntoEdgeReferences, IntoNodeIdentifiers};
+
+/// The heuristic used to pick the next node to eliminate in
+/// [`elimination_ordering`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TreewidthHeuristic {
+    /// Eliminate a node with the fewest remaining neighbors.
+    MinDegree,
+    /// Eliminate a node whose elimination adds the fewest edges between its
+    /// remaining neighbors.
+    MinFillIn,
+}
+
+/// The simple undirected graph underlying the input graph, as sets of
+/// neighbors, which is updated as nodes are eliminated.
+struct EliminationGraph {
+    neighbors: Vec<HashSet<usize>>,
+}
+
+impl EliminationGraph {
+    fn new<G>(graph: G) -> (Vec<G::NodeId>, HashMap<G::NodeId, usize>, Self)
+    where
+        G: IntoNodeIdentifiers + IntoEdgeReferences,
+        G::NodeId: Hash + Eq,
+    {
+        let nodes: Vec<G::NodeId> = graph.node_identifiers().collect();
+        let index: HashMap<G::NodeId, usize> = nodes
+            .iter()
+            .enumerate()
+            .map(|(i, node)| (*node, i))
+            .collect();
+        let mut neighbors: Vec<HashSet<usize>> = vec![HashSet::new(); nodes.len()];
+        for edge in graph.edge_references() {
+            let source = index[&edge.source()];
+            let target = index[&edge.target()];
+            if source != target {
+                neighbors[source].insert(target);
+                neighbors[target].insert(source);
+            }
+        }
+        (nodes, index, EliminationGraph { neighbors })
+    }
+
+    /// The number of edges that eliminating `node` adds.
+    fn fill_in(&self, node: usize) -> usize {
+        let neighbors: Vec<usize> = self.neighbors[node].iter().copied().collect();
+        let mut missing = 0;
+        for (i, u) in neighbors.iter().enumerate() {
+            for v in &neighbors[i + 1..] {
+                if !self.neighbors[*u].contains(v) {
+                    missing += 1;
+                }
+            }
+        }
+        missing
+    }
+
+    /// Remove `node`, make its neighbors a clique and return them sorted.
+    fn eliminate(&mut self, node: usize) -> Vec<usize> {
+        let mut neighbors: Vec<usize> = self.neighbors[node].drain().collect();
+        neighbors.sort_unstable();
+        for (i, u) in neighbors.iter().enumerate() {
+            self.neighbors[*u].remove(&node);
+            for v in &neighbors[i + 1..] {
+                self.neighbors[*u].insert(*v);
+                self.neighbors[*v].insert(*u);
+            }
+        }
+        neighbors
+    }
+}
+
+/// Compute an elimination ordering of the nodes of a graph with a greedy
+/// heuristic.
+///
+/// Eliminating a node removes it from the graph and adds edges between all
+/// of its remaining neighbors. At every step the node picked by `heuristic`
+/// is eliminated, with ties broken by the order of
+/// [`IntoNodeIdentifiers::node_identifiers`]. The largest number of
+/// neighbors a node has when it is eliminated is an upper bound on the
+/// treewidth of the graph, and [`tree_decomposition`] builds the tree
+/// decomposition of that width from the ordering.
+///
+/// Edge directions, self-loops and parallel edges are ignored.
+///
+/// Arguments:
+///
+/// * `graph` - The graph to compute the elimination ordering of.
+/// * `heuristic` - The rule used to pick the next node to eliminate.
+///
+/// # Example
+/// ```rust
+/// use rustworkx_core::petgraph::graph::{NodeIndex, UnGraph};
+/// use rustworkx_core::treewidth::{elimination_ordering, TreewidthHeuristic};
+///
+/// // The center of a star is eliminated after two of its leaves
+/// let graph = UnGraph::<(), ()>::from_edges([(0, 1), (0, 2), (0, 3)]);
+/// let ordering = elimination_ordering(&graph, TreewidthHeuristic::MinDegree);
+/// assert_eq!(ordering, [1, 2, 0, 3].map(NodeIndex::new));
+/// ```
+pub fn elimination_ordering<G>(graph: G, heuristic: TreewidthHeuristic) -> Vec<G::NodeId>
+where
+    G: IntoNodeIdentifiers + IntoEdgeReferences,
+    G::NodeId: Hash + Eq,
+{
+    let (nodes, _, mut elimination) = EliminationGraph::new(graph);
+    let mut ordering: Vec<G::NodeId> = Vec::with_capacity(nodes.len());
+    match heuristic {
+        TreewidthHeuristic::MinDegree => {
+            let mut queue: BTreeSet<(usize, usize)> = elimination
+                .neighbors
+                .iter()
+                .enumerate()
+                .map(|(node, neighbors)| (neighbors.len(), node))
+                .collect();
+            while let Some((_, node)) = queue.pop_first() {
+                for neighbor in &elimination.neighbors[node] {
+                    queue.remove(&(elimination.neighbors[*neighbor].len(), *neighbor));
+                }
+                for neighbor in elimination.eliminate(node) {
+                    queue.insert((elimination.neighbors[neighbor].len(), neighbor));
+                }
+                ordering.push(nodes[node]);
+            }
+        }
+        TreewidthHeuristic::MinFillIn => {
+            let mut remaining: BTreeSet<usize> = (0..nodes.len()).collect();
+            while !remaining.is_empty() {
+                let mut best: Option<(usize, usize)> = None;
+                for node in &remaining {
+                    let fill_in = elimination.fill_in(*node);
+                    if best.map_or(true, |(best_fill_in, _)| fill_in < best_fill_in) {
+                        best = Some((fill_in, *node));
+                        if fill_in == 0 {
+                            break;
+                        }
+                    }
+                }
+                let (_, node) = best.unwrap();
+                remaining.remove(&node);
+                elimination.eliminate(node);
+                ordering.push(nodes[node]);
+            }
+        }
+    }
+    ordering
+}
+
+/// Build the tree decomposition of a graph given by an elimination ordering.
+///
+/// The decomposition has one bag for every node `v` of the graph, made of
+/// `v` and the neighbors it has when it is eliminated, in the order of
+/// [`IntoNodeIdentifiers::node_identifiers`]. The bag of `v` is joined to the
+/// bag of the first of those neighbors in the ordering, and the bags of the
+/// last nodes of every connected component are joined in a path, so the
+/// decomposition is a tree. The nodes of the output graph are added in the
+/// order of `ordering`, so the `i`-th node of the output is the bag of
+/// `ordering[i]`.
+///
+/// Edge directions, self-loops and parallel edges are ignored.
+///
+/// Arguments:
+///
+/// * `graph` - The graph to decompose.
+/// * `ordering` - The elimination ordering, which must contain every node of
+///   `graph` exactly once.
+/// * `bag_weight` - A callable that is passed the nodes of a bag and returns
+///   the weight of the node of the output graph for it.
+/// * `default_edge_weight` - A callable that returns the weight of the edges
+///   of the output graph.
+///
+/// Returns the width of the decomposition, which is the size of its largest
+/// bag minus one (or `0` for an empty graph), and the decomposition, or
+/// `None` if `ordering` isn't an ordering of the nodes of `graph`.
+///
+/// # Example
+/// ```rust
+/// use rustworkx_core::petgraph::graph::{NodeIndex, UnGraph};
+/// use rustworkx_core::treewidth::tree_decomposition;
+///
+/// let cycle = UnGraph::<(), ()>::from_edges([(0, 1), (1, 2), (2, 3), (3, 0)]);
+/// let ordering: Vec<NodeIndex> = (0..4).map(NodeIndex::new).collect();
+/// let (width, decomposition): (usize, UnGraph<Vec<NodeIndex>, ()>) =
+///     tree_decomposition(&cycle, &ordering, |bag| bag, || ()).unwrap();
+/// assert_eq!(width, 2);
+/// assert_eq!(decomposition.node_count(), 4);
+/// assert_eq!(decomposition.edge_count(), 3);
+/// assert_eq!(
+///     decomposition[NodeIndex::new(0)],
+///     vec![NodeIndex::new(0), NodeIndex::new(1), NodeIndex::new(3)]
+/// );
+/// ```
+pub fn tree_decomposition<G, T, F, H>(
+    graph: G,
+    ordering: &[G::NodeId],
+    mut bag_weight: F,
+    mut default_edge_weight: H,
+) -> Option<(usize, T)>
+where
+    G: IntoNodeIdentifiers + IntoEdgeReferences,
+    G::NodeId: Hash + Eq,
+    T: Create + Data,
+    F: FnMut(Vec<G::NodeId>) -> T::NodeWeight,
+    H: FnMut() -> T::EdgeWeight,
+{
+    let (nodes, index, mut elimination) = EliminationGraph::new(graph);
+    if ordering.len() != nodes.len() {
+        return None;
+    }
+    let mut position: Vec<Option<usize>> = vec![None; nodes.len()];
+    for (i, node) in ordering.iter().enumerate() {
+        let node = *index.get(node)?;
+        if position[node].replace(i).is_some() {
+            return None;
+        }
+    }
+
+    let mut width = 0;
+    let mut bags: Vec<Vec<usize>> = Vec::with_capacity(nodes.len());
+    let mut parents: Vec<Option<usize>> = Vec::with_capacity(nodes.len());
+    for node in ordering {
+        let node = index[node];
+        let neighbors = elimination.eliminate(node);
+        width = width.max(neighbors.len());
+        parents.push(
+            neighbors
+                .iter()
+                .map(|neighbor| position[*neighbor].unwrap())
+                .min(),
+        );
+        let mut bag = neighbors;
+        bag.push(node);
+        bag.sort_unstable();
+        bags.push(bag);
+    }
+
+    let mut decomposition = T::with_capacity(bags.len(), bags.len().saturating_sub(1));
+    let bag_nodes: Vec<T::NodeId> = bags
+        .into_iter()
+        .map(|bag| decomposition.add_node(bag_weight(bag.into_iter().map(|n| nodes[n]).collect())))
+        .collect();
+    let mut last_root: Option<usize> = None;
+    for (bag, parent) in parents.into_iter().enumerate() {
+        let parent = match parent {
+            Some(parent) => parent,
+            None => match last_root.replace(bag) {
+                Some(root) => root,
+                None => continue,
+            },
+        };
+        decomposition.add_edge(bag_nodes[bag], bag_nodes[parent], default_edge_weight());
+    }
+    Some((width, decomposition))
+}
+
+/// Compute an upper bound on the treewidth of a graph and a tree
+/// decomposition of that width.
+///
+/// This combines [`elimination_ordering`] and [`tree_decomposition`].
+///
+/// Arguments:
+///
+/// * `graph` - The graph to decompose.
+/// * `heuristic` - The rule used to pick the next node to eliminate.
+/// * `bag_weight` - A callable that is passed the nodes of a bag and returns
+///   the weight of the node of the output graph for it.
+/// * `default_edge_weight` - A callable that returns the weight of the edges
+///   of the output graph.
+///
+/// Returns the width of the decomposition, the elimination ordering and the
+/// decomposition.
+///
+/// # Example
+/// ```rust
+/// use rustworkx_core::petgraph::graph::{NodeIndex, UnGraph};
+/// use rustworkx_core::generators::grid_graph;
+/// use rustworkx_core::treewidth::{treewidth, TreewidthHeuristic};
+///
+/// let grid: UnGraph<(), ()> = grid_graph(Some(3), Some(3), None, || (), || (), false).unwrap();
+/// let (width, ordering, decomposition): (usize, _, UnGraph<Vec<NodeIndex>, ()>) =
+///     treewidth(&grid, TreewidthHeuristic::MinFillIn, |bag| bag, || ());
+/// assert_eq!(width, 3);
+/// assert_eq!(ordering.len(), 9);
+/// assert_eq!(decomposition.node_count(), 9);
+/// ```
+pub fn treewidth<G, T, F, H>(
+    graph: G,
+    heuristic: TreewidthHeuristic,
+    bag_weight: F,
+    default_edge_weight: H,
+) -> (usize, Vec<G::NodeId>, T)
+where
+    G: IntoNodeIdentifiers + IntoEdgeReferences,
+    G::NodeId: Hash + Eq,
+    T: Create + Data,
+    F: FnMut(Vec<G::NodeId>) -> T::NodeWeight,
+    H: FnMut() -> T::EdgeWeight,
+{
+    let ordering = elimination_ordering(graph, heuristic);
+    let (width, decomposition) =
+        tree_decomposition(graph, &ordering, bag_weight, default_edge_weight).unwrap();
+    (width, ordering, decomposition)
+}
+
+#[cfg(test)]
+mod test_treewidth {
+    use super::{elimination_ordering, tree_decomposition, treewidth, TreewidthHeuristic};
+    use crate::generators::{complete_graph, cycle_graph, grid_graph, path_graph, petersen_graph};
+    use crate::petgraph::graph::{NodeIndex, UnGraph};
+    use crate::petgraph::visit::EdgeRef;
+    use hashbrown::HashSet;
+
+    const HEURISTICS: [TreewidthHeuristic; 2] =
+        [TreewidthHeuristic::MinDegree, TreewidthHeuristic::MinFillIn];
+
+    type Decomposition = UnGraph<Vec<NodeIndex>, ()>;
+
+    fn decompose(graph: &UnGraph<(), ()>, heuristic: TreewidthHeuristic) -> usize {
+        let (width, ordering, decomposition): (usize, Vec<NodeIndex>, Decomposition) =
+            treewidth(graph, heuristic, |bag| bag, || ());
+        assert_eq!(ordering.len(), graph.node_count());
+        assert_valid(graph, &decomposition, width);
+        width
+    }
+
+    fn assert_valid(graph: &UnGraph<(), ()>, decomposition: &Decomposition, width: usize) {
+        // The decomposition is a tree
+        assert_eq!(decomposition.node_count(), graph.node_count());
+        if graph.node_count() > 0 {
+            assert_eq!(decomposition.edge_count(), graph.node_count() - 1);
+            assert_eq!(petgraph::algo::connected_components(decomposition), 1);
+        }
+        assert_eq!(
+            decomposition
+                .node_weights()
+                .map(|bag| bag.len() - 1)
+                .max()
+                .unwrap_or(0),
+            width
+        );
+        // Every edge is in a bag
+        for edge in graph.edge_references() {
+            assert!(decomposition
+                .node_weights()
+                .any(|bag| bag.contains(&edge.source()) && bag.contains(&edge.target())));
+        }
+        // The bags that contain a node are connected
+        for node in graph.node_indices() {
+            let bags: HashSet<NodeIndex> = decomposition
+                .node_indices()
+                .filter(|bag| decomposition[*bag].contains(&node))
+                .collect();
+            assert!(!bags.is_empty());
+            let start = *bags.iter().next().unwrap();
+            let mut seen = HashSet::from([start]);
+            let mut stack = vec![start];
+            while let Some(bag) = stack.pop() {
+                for next in decomposition.neighbors(bag) {
+                    if bags.contains(&next) && seen.insert(next) {
+                        stack.push(next);
+                    }
+                }
+            }
+            assert_eq!(seen, bags);
+        }
+    }
+
+    #[test]
+    fn test_empty_graph() {
+        let graph = UnGraph::<(), ()>::default();
+        for heuristic in HEURISTICS {
+            assert_eq!(decompose(&graph, heuristic), 0);
+        }
+    }
+
+    #[test]
+    fn test_path_and_cycle() {
+        let path: UnGraph<(), ()> = path_graph(Some(8), None, || (), || (), false).unwrap();
+        let cycle: UnGraph<(), ()> = cycle_graph(Some(8), None, || (), || (), false).unwrap();
+        for heuristic in HEURISTICS {
+            assert_eq!(decompose(&path, heuristic), 1);
+            assert_eq!(decompose(&cycle, heuristic), 2);
+        }
+    }
+
+    #[test]
+    fn test_complete_graph() {
+        let graph: UnGraph<(), ()> = complete_graph(Some(6), None, || (), || ()).unwrap();
+        for heuristic in HEURISTICS {
+            assert_eq!(decompose(&graph, heuristic), 5);
+        }
+    }
+
+    #[test]
+    fn test_grid_and_petersen() {
+        let grid: UnGraph<(), ()> =
+            grid_graph(Some(4), Some(4), None, || (), || (), false).unwrap();
+        let petersen: UnGraph<(), ()> = petersen_graph(5, 2, || (), || ()).unwrap();
+        for heuristic in HEURISTICS {
+            assert!(decompose(&grid, heuristic) >= 4);
+            assert!(decompose(&petersen, heuristic) >= 4);
+        }
+        assert_eq!(decompose(&grid, TreewidthHeuristic::MinFillIn), 4);
+    }
+
+    #[test]
+    fn test_disconnected_graph() {
+        let mut graph = UnGraph::<(), ()>::from_edges([(0, 1), (1, 2), (2, 0), (3, 4)]);
+        graph.add_node(());
+        for heuristic in HEURISTICS {
+            assert_eq!(decompose(&graph, heuristic), 2);
+        }
+    }
+
+    #[test]
+    fn test_min_degree_eliminates_leaves_first() {
+        let graph = UnGraph::<(), ()>::from_edges([(0, 1), (1, 2), (1, 3), (3, 4)]);
+        let ordering = elimination_ordering(&graph, TreewidthHeuristic::MinDegree);
+        assert_eq!(ordering, [0, 2, 1, 3, 4].map(NodeIndex::new).to_vec());
+    }
+
+    #[test]
+    fn test_bad_ordering() {
+        let graph = UnGraph::<(), ()>::from_edges([(0, 1), (1, 2)]);
+        let bad_orderings = [vec![0, 1], vec![0, 1, 1], vec![0, 1, 3], vec![0, 1, 2, 0]];
+        for ordering in bad_orderings {
+            let ordering: Vec<NodeIndex> = ordering.into_iter().map(NodeIndex::new).collect();
+            let result: Option<(usize, Decomposition)> =
+                tree_decomposition(&graph, &ordering, |bag| bag, || ());
+            assert!(result.is_none());
+        }
+    }
+
+    #[test]
+    fn test_bad_ordering_width() {
+        // Eliminating the center of a star first makes the leaves a clique
+        let graph = UnGraph::<(), ()>::from_edges([(0, 1), (0, 2), (0, 3), (0, 4)]);
+        let ordering: Vec<NodeIndex> = (0..5).map(NodeIndex::new).collect();
+        let (width, decomposition): (usize, Decomposition) =
+            tree_decomposition(&graph, &ordering, |bag| bag, || ()).unwrap();
+        assert_eq!(width, 4);
+        assert_valid(&graph, &decomposition, width);
+    }
+}
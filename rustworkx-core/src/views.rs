@@ -0,0 +1,583 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+//! Lightweight views of a graph that can be passed to any algorithm in
+//! place of the graph itself.
+//!
+//! A view borrows the underlying graph and adapts it on the fly, so running
+//! an algorithm on a part of a graph doesn't require copying that part into
+//! a new graph first. Views keep the node and edge identifiers of the
+//! underlying graph, so the results of an algorithm on a view can be used
+//! with the underlying graph directly.
+
+use petgraph::visit::{
+    Data, EdgeCount, EdgeIndexable, EdgeRef, FilterEdge, FilterNode, GraphBase, GraphProp,
+    IntoEdgeReferences, IntoEdges, IntoEdgesDirected, IntoNeighbors, IntoNeighborsDirected,
+    IntoNodeIdentifiers, IntoNodeReferences, NodeCount, NodeIndexable, NodeRef, Visitable,
+};
+use petgraph::Direction;
+
+/// A view of a graph with the direction of every edge reversed.
+///
+/// This is [`petgraph::visit::Reversed`], it can be combined with the other
+/// views in this module, for example to run an algorithm on the predecessors
+/// of the nodes of a [`FilteredGraph`].
+///
+/// # Example
+/// ```rust
+/// use rustworkx_core::petgraph;
+/// use rustworkx_core::petgraph::graph::NodeIndex;
+/// use rustworkx_core::shortest_path::dijkstra;
+/// use rustworkx_core::views::Reversed;
+/// use rustworkx_core::Result;
+///
+/// let graph = petgraph::graph::DiGraph::<(), ()>::from_edges(&[(0, 1), (1, 2), (3, 2)]);
+/// // The distances from every node to node 2
+/// let res: Result<Vec<Option<usize>>> =
+///     dijkstra(Reversed(&graph), NodeIndex::new(2), None, |_| Ok(1), None);
+/// assert_eq!(res.unwrap(), vec![Some(2), Some(1), Some(0), Some(1)]);
+/// ```
+pub use petgraph::visit::Reversed;
+
+/// A filter that includes every node and every edge.
+///
+/// It is used as the node or edge filter of a [`FilteredGraph`] that only
+/// filters the other one.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct NoFilter;
+
+impl<N> FilterNode<N> for NoFilter {
+    fn include_node(&self, _node: N) -> bool {
+        true
+    }
+}
+
+impl<E> FilterEdge<E> for NoFilter {
+    fn include_edge(&self, _edge: E) -> bool {
+        true
+    }
+}
+
+/// A view of the subgraph of a graph made of the nodes and edges accepted
+/// by a node filter and an edge filter.
+///
+/// An edge is part of the view when the edge filter accepts it and the node
+/// filter accepts both of its endpoints. Any type implementing
+/// [`FilterNode`] or [`FilterEdge`] can be used as a filter, which includes
+/// closures returning a `bool`, [`FixedBitSet`](fixedbitset::FixedBitSet)
+/// and `HashSet` of nodes.
+///
+/// The filters are evaluated lazily every time the view is traversed, so
+/// creating a view is free. Counting the nodes or edges of a view is linear
+/// in the size of the underlying graph. Node indices are those of the
+/// underlying graph, so for [`NodeIndexable`] the view has the same node
+/// bound as the underlying graph.
+///
+/// # Example
+/// ```rust
+/// use rustworkx_core::petgraph;
+/// use rustworkx_core::petgraph::graph::NodeIndex;
+/// use rustworkx_core::petgraph::visit::EdgeRef;
+/// use rustworkx_core::shortest_path::dijkstra;
+/// use rustworkx_core::views::FilteredGraph;
+/// use rustworkx_core::Result;
+///
+/// // A square 0 - 1 - 2 - 3 - 0 with a light edge 1 - 2
+/// let graph = petgraph::graph::UnGraph::<(), u32>::from_edges(&[
+///     (0, 1, 1),
+///     (1, 2, 1),
+///     (2, 3, 5),
+///     (3, 0, 5),
+/// ]);
+/// // Only keep the heavy edges
+/// let view = FilteredGraph::edge_filtered(&graph, |edge: petgraph::graph::EdgeReference<u32>| {
+///     *edge.weight() > 1
+/// });
+/// let res: Result<Vec<Option<u32>>> =
+///     dijkstra(&view, NodeIndex::new(0), None, |e| Ok(*e.weight()), None);
+/// assert_eq!(res.unwrap(), vec![Some(0), None, Some(10), Some(5)]);
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct FilteredGraph<G, FN, FE> {
+    graph: G,
+    node_filter: FN,
+    edge_filter: FE,
+}
+
+impl<G, FN, FE> FilteredGraph<G, FN, FE> {
+    /// Create a view of `graph` with the nodes accepted by `node_filter` and
+    /// the edges accepted by `edge_filter`.
+    pub fn new(graph: G, node_filter: FN, edge_filter: FE) -> Self {
+        FilteredGraph {
+            graph,
+            node_filter,
+            edge_filter,
+        }
+    }
+
+    /// Return the underlying graph of the view.
+    pub fn graph(&self) -> &G {
+        &self.graph
+    }
+}
+
+impl<G, FN> FilteredGraph<G, FN, NoFilter> {
+    /// Create a view of `graph` with the nodes accepted by `node_filter` and
+    /// all the edges between them.
+    pub fn node_filtered(graph: G, node_filter: FN) -> Self {
+        FilteredGraph::new(graph, node_filter, NoFilter)
+    }
+}
+
+impl<G, FE> FilteredGraph<G, NoFilter, FE> {
+    /// Create a view of `graph` with all of its nodes and the edges accepted
+    /// by `edge_filter`.
+    pub fn edge_filtered(graph: G, edge_filter: FE) -> Self {
+        FilteredGraph::new(graph, NoFilter, edge_filter)
+    }
+}
+
+impl<G: GraphBase, FN, FE> GraphBase for FilteredGraph<G, FN, FE> {
+    type NodeId = G::NodeId;
+    type EdgeId = G::EdgeId;
+}
+
+impl<G: Data, FN, FE> Data for FilteredGraph<G, FN, FE> {
+    type NodeWeight = G::NodeWeight;
+    type EdgeWeight = G::EdgeWeight;
+}
+
+impl<G: GraphProp, FN, FE> GraphProp for FilteredGraph<G, FN, FE> {
+    type EdgeType = G::EdgeType;
+}
+
+impl<G: NodeIndexable, FN, FE> NodeIndexable for FilteredGraph<G, FN, FE> {
+    fn node_bound(&self) -> usize {
+        self.graph.node_bound()
+    }
+
+    fn to_index(&self, a: Self::NodeId) -> usize {
+        self.graph.to_index(a)
+    }
+
+    fn from_index(&self, i: usize) -> Self::NodeId {
+        self.graph.from_index(i)
+    }
+}
+
+impl<G: EdgeIndexable, FN, FE> EdgeIndexable for FilteredGraph<G, FN, FE> {
+    fn edge_bound(&self) -> usize {
+        self.graph.edge_bound()
+    }
+
+    fn to_index(&self, a: Self::EdgeId) -> usize {
+        self.graph.to_index(a)
+    }
+
+    fn from_index(&self, i: usize) -> Self::EdgeId {
+        self.graph.from_index(i)
+    }
+}
+
+impl<G: Visitable, FN, FE> Visitable for FilteredGraph<G, FN, FE> {
+    type Map = G::Map;
+
+    fn visit_map(&self) -> Self::Map {
+        self.graph.visit_map()
+    }
+
+    fn reset_map(&self, map: &mut Self::Map) {
+        self.graph.reset_map(map)
+    }
+}
+
+impl<G, FN, FE> NodeCount for FilteredGraph<G, FN, FE>
+where
+    G: IntoNodeIdentifiers,
+    FN: FilterNode<G::NodeId>,
+{
+    fn node_count(&self) -> usize {
+        self.graph
+            .node_identifiers()
+            .filter(|node| self.node_filter.include_node(*node))
+            .count()
+    }
+}
+
+impl<G, FN, FE> EdgeCount for FilteredGraph<G, FN, FE>
+where
+    G: IntoEdgeReferences,
+    FN: FilterNode<G::NodeId>,
+    FE: FilterEdge<G::EdgeRef>,
+{
+    fn edge_count(&self) -> usize {
+        FilteredEdges::new(
+            self.graph.edge_references(),
+            &self.node_filter,
+            &self.edge_filter,
+        )
+        .count()
+    }
+}
+
+impl<'a, G, FN, FE> IntoNodeIdentifiers for &'a FilteredGraph<G, FN, FE>
+where
+    G: IntoNodeIdentifiers,
+    FN: FilterNode<G::NodeId>,
+{
+    type NodeIdentifiers = FilteredNodes<'a, G::NodeIdentifiers, FN>;
+
+    fn node_identifiers(self) -> Self::NodeIdentifiers {
+        FilteredNodes {
+            iter: self.graph.node_identifiers(),
+            node_filter: &self.node_filter,
+        }
+    }
+}
+
+impl<'a, G, FN, FE> IntoNodeReferences for &'a FilteredGraph<G, FN, FE>
+where
+    G: IntoNodeReferences,
+    FN: FilterNode<G::NodeId>,
+{
+    type NodeRef = G::NodeRef;
+    type NodeReferences = FilteredNodeReferences<'a, G::NodeReferences, FN>;
+
+    fn node_references(self) -> Self::NodeReferences {
+        FilteredNodeReferences {
+            iter: self.graph.node_references(),
+            node_filter: &self.node_filter,
+        }
+    }
+}
+
+impl<'a, G, FN, FE> IntoEdgeReferences for &'a FilteredGraph<G, FN, FE>
+where
+    G: IntoEdgeReferences,
+    FN: FilterNode<G::NodeId>,
+    FE: FilterEdge<G::EdgeRef>,
+{
+    type EdgeRef = G::EdgeRef;
+    type EdgeReferences = FilteredEdges<'a, G::EdgeReferences, FN, FE>;
+
+    fn edge_references(self) -> Self::EdgeReferences {
+        FilteredEdges::new(
+            self.graph.edge_references(),
+            &self.node_filter,
+            &self.edge_filter,
+        )
+    }
+}
+
+impl<'a, G, FN, FE> IntoEdges for &'a FilteredGraph<G, FN, FE>
+where
+    G: IntoEdges,
+    FN: FilterNode<G::NodeId>,
+    FE: FilterEdge<G::EdgeRef>,
+{
+    type Edges = FilteredEdges<'a, G::Edges, FN, FE>;
+
+    fn edges(self, a: Self::NodeId) -> Self::Edges {
+        FilteredEdges::new(self.graph.edges(a), &self.node_filter, &self.edge_filter)
+    }
+}
+
+impl<'a, G, FN, FE> IntoEdgesDirected for &'a FilteredGraph<G, FN, FE>
+where
+    G: IntoEdgesDirected,
+    FN: FilterNode<G::NodeId>,
+    FE: FilterEdge<G::EdgeRef>,
+{
+    type EdgesDirected = FilteredEdges<'a, G::EdgesDirected, FN, FE>;
+
+    fn edges_directed(self, a: Self::NodeId, dir: Direction) -> Self::EdgesDirected {
+        FilteredEdges::new(
+            self.graph.edges_directed(a, dir),
+            &self.node_filter,
+            &self.edge_filter,
+        )
+    }
+}
+
+impl<'a, G, FN, FE> IntoNeighbors for &'a FilteredGraph<G, FN, FE>
+where
+    G: IntoEdges,
+    FN: FilterNode<G::NodeId>,
+    FE: FilterEdge<G::EdgeRef>,
+{
+    type Neighbors = FilteredNeighbors<'a, G::Edges, FN, FE>;
+
+    fn neighbors(self, a: Self::NodeId) -> Self::Neighbors {
+        FilteredNeighbors {
+            edges: self.edges(a),
+            node: a,
+        }
+    }
+}
+
+impl<'a, G, FN, FE> IntoNeighborsDirected for &'a FilteredGraph<G, FN, FE>
+where
+    G: IntoEdgesDirected,
+    FN: FilterNode<G::NodeId>,
+    FE: FilterEdge<G::EdgeRef>,
+{
+    type NeighborsDirected = FilteredNeighbors<'a, G::EdgesDirected, FN, FE>;
+
+    fn neighbors_directed(self, a: Self::NodeId, dir: Direction) -> Self::NeighborsDirected {
+        FilteredNeighbors {
+            edges: self.edges_directed(a, dir),
+            node: a,
+        }
+    }
+}
+
+/// An iterator over the node identifiers of a [`FilteredGraph`].
+#[derive(Clone, Debug)]
+pub struct FilteredNodes<'a, I, FN> {
+    iter: I,
+    node_filter: &'a FN,
+}
+
+impl<'a, I, FN> Iterator for FilteredNodes<'a, I, FN>
+where
+    I: Iterator,
+    I::Item: Copy,
+    FN: FilterNode<I::Item>,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let node_filter = self.node_filter;
+        self.iter.find(|node| node_filter.include_node(*node))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.iter.size_hint().1)
+    }
+}
+
+/// An iterator over the node references of a [`FilteredGraph`].
+#[derive(Clone, Debug)]
+pub struct FilteredNodeReferences<'a, I, FN> {
+    iter: I,
+    node_filter: &'a FN,
+}
+
+impl<'a, I, FN> Iterator for FilteredNodeReferences<'a, I, FN>
+where
+    I: Iterator,
+    I::Item: NodeRef,
+    FN: FilterNode<<I::Item as NodeRef>::NodeId>,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let node_filter = self.node_filter;
+        self.iter.find(|node| node_filter.include_node(node.id()))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.iter.size_hint().1)
+    }
+}
+
+/// An iterator over the edge references of a [`FilteredGraph`].
+#[derive(Clone, Debug)]
+pub struct FilteredEdges<'a, I, FN, FE> {
+    iter: I,
+    node_filter: &'a FN,
+    edge_filter: &'a FE,
+}
+
+impl<'a, I, FN, FE> FilteredEdges<'a, I, FN, FE> {
+    fn new(iter: I, node_filter: &'a FN, edge_filter: &'a FE) -> Self {
+        FilteredEdges {
+            iter,
+            node_filter,
+            edge_filter,
+        }
+    }
+}
+
+impl<'a, I, FN, FE> Iterator for FilteredEdges<'a, I, FN, FE>
+where
+    I: Iterator,
+    I::Item: EdgeRef,
+    FN: FilterNode<<I::Item as EdgeRef>::NodeId>,
+    FE: FilterEdge<I::Item>,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let node_filter = self.node_filter;
+        let edge_filter = self.edge_filter;
+        self.iter.find(|edge| {
+            edge_filter.include_edge(*edge)
+                && node_filter.include_node(edge.source())
+                && node_filter.include_node(edge.target())
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.iter.size_hint().1)
+    }
+}
+
+/// An iterator over the neighbors of a node in a [`FilteredGraph`].
+///
+/// A neighbor is yielded once for every edge of the view connecting it to
+/// the node.
+pub struct FilteredNeighbors<'a, I, FN, FE>
+where
+    I: Iterator,
+    I::Item: EdgeRef,
+{
+    edges: FilteredEdges<'a, I, FN, FE>,
+    node: <I::Item as EdgeRef>::NodeId,
+}
+
+impl<'a, I, FN, FE> Iterator for FilteredNeighbors<'a, I, FN, FE>
+where
+    I: Iterator,
+    I::Item: EdgeRef,
+    <I::Item as EdgeRef>::NodeId: PartialEq,
+    FN: FilterNode<<I::Item as EdgeRef>::NodeId>,
+    FE: FilterEdge<I::Item>,
+{
+    type Item = <I::Item as EdgeRef>::NodeId;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.edges.next().map(|edge| {
+            if edge.source() == self.node {
+                edge.target()
+            } else {
+                edge.source()
+            }
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.edges.size_hint()
+    }
+}
+
+#[cfg(test)]
+mod test_filtered_graph {
+    use super::{FilteredGraph, Reversed};
+    use crate::centrality::betweenness_centrality;
+    use crate::petgraph::graph::{DiGraph, EdgeReference, NodeIndex, UnGraph};
+    use crate::petgraph::visit::{
+        EdgeCount, EdgeRef, IntoEdgeReferences, IntoNeighbors, IntoNeighborsDirected,
+        IntoNodeIdentifiers, NodeCount,
+    };
+    use crate::petgraph::Direction;
+    use crate::shortest_path::dijkstra;
+    use crate::Result;
+    use hashbrown::HashSet;
+
+    #[test]
+    fn test_node_filtered_counts() {
+        let graph = UnGraph::<(), ()>::from_edges([(0, 1), (1, 2), (2, 3), (3, 0)]);
+        let view = FilteredGraph::node_filtered(&graph, |node: NodeIndex| node.index() != 2);
+        assert_eq!(view.node_count(), 3);
+        assert_eq!(view.edge_count(), 2);
+        let nodes: Vec<usize> = view.node_identifiers().map(|n| n.index()).collect();
+        assert_eq!(nodes, vec![0, 1, 3]);
+    }
+
+    #[test]
+    fn test_excluded_node_has_no_neighbors() {
+        let graph = UnGraph::<(), ()>::from_edges([(0, 1), (1, 2)]);
+        let view = FilteredGraph::node_filtered(&graph, |node: NodeIndex| node.index() != 1);
+        assert_eq!(view.neighbors(NodeIndex::new(1)).count(), 0);
+        assert_eq!(view.neighbors(NodeIndex::new(0)).count(), 0);
+    }
+
+    #[test]
+    fn test_undirected_neighbors() {
+        let graph = UnGraph::<(), u32>::from_edges([(0, 1, 1), (2, 0, 2), (0, 3, 3)]);
+        let view =
+            FilteredGraph::edge_filtered(&graph, |edge: EdgeReference<u32>| *edge.weight() > 1);
+        let mut neighbors: Vec<usize> = view
+            .neighbors(NodeIndex::new(0))
+            .map(|n| n.index())
+            .collect();
+        neighbors.sort_unstable();
+        assert_eq!(neighbors, vec![2, 3]);
+    }
+
+    #[test]
+    fn test_directed_neighbors() {
+        let graph = DiGraph::<(), ()>::from_edges([(0, 1), (2, 1), (3, 1), (1, 3)]);
+        let excluded: HashSet<NodeIndex> = [NodeIndex::new(3)].into_iter().collect();
+        let view = FilteredGraph::node_filtered(&graph, |node| !excluded.contains(&node));
+        let mut incoming: Vec<usize> = view
+            .neighbors_directed(NodeIndex::new(1), Direction::Incoming)
+            .map(|n| n.index())
+            .collect();
+        incoming.sort_unstable();
+        assert_eq!(incoming, vec![0, 2]);
+        assert_eq!(
+            view.neighbors_directed(NodeIndex::new(1), Direction::Outgoing)
+                .count(),
+            0
+        );
+    }
+
+    #[test]
+    fn test_node_and_edge_filters() {
+        let graph = DiGraph::<(), u32>::from_edges([(0, 1, 1), (1, 2, 2), (2, 3, 3), (0, 3, 4)]);
+        let view = FilteredGraph::new(
+            &graph,
+            |node: NodeIndex| node.index() != 3,
+            |edge: EdgeReference<u32>| *edge.weight() != 1,
+        );
+        let edges: Vec<(usize, usize)> = view
+            .edge_references()
+            .map(|e| (e.source().index(), e.target().index()))
+            .collect();
+        assert_eq!(edges, vec![(1, 2)]);
+    }
+
+    #[test]
+    fn test_dijkstra_on_view() {
+        let graph = UnGraph::<(), u32>::from_edges([(0, 1, 1), (1, 2, 1), (0, 2, 5)]);
+        let view = FilteredGraph::node_filtered(&graph, |node: NodeIndex| node.index() != 1);
+        let res: Result<Vec<Option<u32>>> =
+            dijkstra(&view, NodeIndex::new(0), None, |e| Ok(*e.weight()), None);
+        assert_eq!(res.unwrap(), vec![Some(0), None, Some(5)]);
+    }
+
+    #[test]
+    fn test_betweenness_on_view() {
+        // Removing the center of a star with an extra path leaves a path
+        let graph = UnGraph::<(), ()>::from_edges([(0, 1), (0, 2), (0, 3), (1, 2), (2, 3)]);
+        let view = FilteredGraph::node_filtered(&graph, |node: NodeIndex| node.index() != 0);
+        let output = betweenness_centrality(&view, false, false, 200);
+        assert_eq!(output, vec![None, Some(0.0), Some(1.0), Some(0.0)]);
+    }
+
+    #[test]
+    fn test_reversed_filtered_graph() {
+        let graph = DiGraph::<(), ()>::from_edges([(0, 1), (1, 2), (3, 2), (0, 3)]);
+        let view = FilteredGraph::node_filtered(&graph, |node: NodeIndex| node.index() != 1);
+        let mut predecessors: Vec<usize> = Reversed(&view)
+            .neighbors(NodeIndex::new(2))
+            .map(|n| n.index())
+            .collect();
+        predecessors.sort_unstable();
+        assert_eq!(predecessors, vec![3]);
+        let res: Result<Vec<Option<usize>>> =
+            dijkstra(Reversed(&view), NodeIndex::new(2), None, |_| Ok(1), None);
+        assert_eq!(res.unwrap(), vec![Some(2), None, Some(0), Some(1)]);
+    }
+}
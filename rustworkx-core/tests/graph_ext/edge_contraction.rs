@@ -0,0 +1,195 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+use petgraph::prelude::*;
+use petgraph::visit::{EdgeRef, IntoEdgeReferences};
+use rustworkx_core::err::ContractSimpleError;
+use rustworkx_core::graph_ext::*;
+use std::convert::Infallible;
+
+const MERGE: ContractionPolicy = ContractionPolicy {
+    parallel_edges: ParallelEdges::Merge,
+    self_loops: SelfLoops::Remove,
+};
+
+fn sorted_edges<Ty: petgraph::EdgeType>(
+    graph: &StableGraph<char, usize, Ty>,
+) -> Vec<(char, char, usize)> {
+    let mut edges: Vec<(char, char, usize)> = graph
+        .edge_references()
+        .map(|e| (graph[e.source()], graph[e.target()], *e.weight()))
+        .collect();
+    edges.sort_unstable();
+    edges
+}
+
+fn keep_first(a: &char, _: &char) -> Result<char, Infallible> {
+    Ok(*a)
+}
+
+fn add_weights(a: &usize, b: &usize) -> Result<usize, Infallible> {
+    Ok(a + b)
+}
+
+#[test]
+fn test_directed_keep_parallel_edges() {
+    // a -> b, a -> c, b -> d, c -> d; contracting b -> d leaves two edges
+    // from a to the merged node
+    let mut dag: StableDiGraph<char, usize> = StableDiGraph::default();
+    let a = dag.add_node('a');
+    let b = dag.add_node('b');
+    let c = dag.add_node('c');
+    let d = dag.add_node('d');
+    dag.add_edge(a, b, 0);
+    dag.add_edge(a, c, 1);
+    let e = dag.add_edge(b, d, 2);
+    dag.add_edge(c, d, 3);
+    let m = dag
+        .contract_edge(
+            e,
+            ContractionPolicy::default(),
+            true,
+            keep_first,
+            add_weights,
+        )
+        .unwrap();
+    assert_eq!(m, b);
+    assert!(!dag.contains_node(d));
+    assert_eq!(
+        sorted_edges(&dag),
+        vec![('a', 'b', 0), ('a', 'c', 1), ('c', 'b', 3)]
+    );
+}
+
+#[test]
+fn test_directed_merge_parallel_edges() {
+    let mut dag: StableDiGraph<char, usize> = StableDiGraph::default();
+    let a = dag.add_node('a');
+    let b = dag.add_node('b');
+    let c = dag.add_node('c');
+    dag.add_edge(a, b, 1);
+    dag.add_edge(a, c, 2);
+    dag.add_edge(b, c, 4);
+    dag.merge_nodes(b, c, MERGE, true, keep_first, add_weights)
+        .unwrap();
+    assert_eq!(sorted_edges(&dag), vec![('a', 'b', 3)]);
+}
+
+#[test]
+fn test_directed_keep_self_loops() {
+    let mut dag: StableDiGraph<char, usize> = StableDiGraph::default();
+    let a = dag.add_node('a');
+    let b = dag.add_node('b');
+    let e = dag.add_edge(a, b, 1);
+    dag.add_edge(b, a, 2);
+    dag.add_edge(b, b, 4);
+    let policy = ContractionPolicy {
+        parallel_edges: ParallelEdges::Merge,
+        self_loops: SelfLoops::Keep,
+    };
+    dag.contract_edge(e, policy, false, keep_first, add_weights)
+        .unwrap();
+    assert_eq!(sorted_edges(&dag), vec![('a', 'a', 7)]);
+}
+
+#[test]
+fn test_directed_cycle_check() {
+    // Contracting a -> c when there is also a path a -> b -> c creates a
+    // cycle between the merged node and b
+    let mut dag: StableDiGraph<char, usize> = StableDiGraph::default();
+    let a = dag.add_node('a');
+    let b = dag.add_node('b');
+    let c = dag.add_node('c');
+    dag.add_edge(a, b, 0);
+    dag.add_edge(b, c, 1);
+    let e = dag.add_edge(a, c, 2);
+    let res = dag.contract_edge(
+        e,
+        ContractionPolicy::default(),
+        true,
+        keep_first,
+        add_weights,
+    );
+    assert!(matches!(res, Err(ContractSimpleError::DAGWouldCycle)));
+    assert_eq!(dag.node_count(), 3);
+    assert_eq!(dag.edge_count(), 3);
+    dag.contract_edge(
+        e,
+        ContractionPolicy::default(),
+        false,
+        keep_first,
+        add_weights,
+    )
+    .unwrap();
+    assert_eq!(sorted_edges(&dag), vec![('a', 'b', 0), ('b', 'a', 1)]);
+}
+
+#[test]
+fn test_undirected_repeated_contraction() {
+    // Contract the path a - b - c - d into a single node
+    let mut graph: StableUnGraph<char, usize> = StableUnGraph::default();
+    let a = graph.add_node('a');
+    let b = graph.add_node('b');
+    let c = graph.add_node('c');
+    let d = graph.add_node('d');
+    let e = graph.add_node('e');
+    graph.add_edge(a, b, 1);
+    graph.add_edge(b, c, 2);
+    graph.add_edge(c, d, 3);
+    graph.add_edge(d, e, 4);
+    graph.add_edge(a, e, 5);
+    for node in [b, c, d] {
+        graph
+            .merge_nodes(a, node, MERGE, keep_first, add_weights)
+            .unwrap();
+    }
+    assert_eq!(graph.node_count(), 2);
+    assert_eq!(sorted_edges(&graph), vec![('a', 'e', 9)]);
+}
+
+#[test]
+fn test_undirected_contract_self_loop() {
+    let mut graph: StableUnGraph<char, usize> = StableUnGraph::default();
+    let a = graph.add_node('a');
+    let e = graph.add_edge(a, a, 1);
+    let policy = ContractionPolicy {
+        parallel_edges: ParallelEdges::Keep,
+        self_loops: SelfLoops::Keep,
+    };
+    graph
+        .contract_edge(e, policy, keep_first, add_weights)
+        .unwrap();
+    assert_eq!(graph.edge_count(), 1);
+    graph
+        .contract_edge(e, ContractionPolicy::default(), keep_first, add_weights)
+        .unwrap();
+    assert_eq!(graph.edge_count(), 0);
+    assert_eq!(graph.node_count(), 1);
+}
+
+#[test]
+fn test_callback_error_leaves_graph_unchanged() {
+    let mut graph: StableUnGraph<char, usize> = StableUnGraph::default();
+    let a = graph.add_node('a');
+    let b = graph.add_node('b');
+    let c = graph.add_node('c');
+    let e = graph.add_edge(a, b, 1);
+    graph.add_edge(a, c, 2);
+    graph.add_edge(b, c, 3);
+    let res = graph.contract_edge(e, MERGE, |a, _| Ok(*a), |_, _| Err("error"));
+    assert_eq!(res, Err("error"));
+    assert_eq!(graph.node_count(), 3);
+    assert_eq!(
+        sorted_edges(&graph),
+        vec![('a', 'b', 1), ('a', 'c', 2), ('b', 'c', 3)]
+    );
+}
@@ -0,0 +1,176 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+use hashbrown::HashSet;
+use petgraph::prelude::*;
+use petgraph::visit::{EdgeRef, IntoEdgeReferences};
+use rustworkx_core::generators::gnm_random_graph;
+use rustworkx_core::graph_ext::*;
+
+fn degrees(graph: &StableUnGraph<(), usize>) -> Vec<usize> {
+    graph
+        .node_indices()
+        .map(|n| graph.edges(n).count())
+        .collect()
+}
+
+fn edge_set(graph: &StableUnGraph<(), usize>) -> HashSet<(usize, usize)> {
+    graph
+        .edge_references()
+        .map(|e| {
+            let (a, b) = (e.source().index(), e.target().index());
+            (a.min(b), a.max(b))
+        })
+        .collect()
+}
+
+fn random_graph() -> StableUnGraph<(), usize> {
+    let mut weight = 0;
+    gnm_random_graph(
+        30,
+        80,
+        Some(7),
+        || (),
+        || {
+            weight += 1;
+            weight
+        },
+    )
+    .unwrap()
+}
+
+#[test]
+fn test_preserves_degrees() {
+    let mut graph = random_graph();
+    let before = degrees(&graph);
+    let swaps = graph.double_edge_swap(200, 10_000, Some(42));
+    assert_eq!(swaps, 200);
+    assert_eq!(degrees(&graph), before);
+    assert_eq!(graph.edge_count(), 80);
+}
+
+#[test]
+fn test_stays_simple() {
+    let mut graph = random_graph();
+    graph.double_edge_swap(200, 10_000, Some(42));
+    assert_eq!(edge_set(&graph).len(), graph.edge_count());
+    assert!(graph.edge_references().all(|e| e.source() != e.target()));
+}
+
+#[test]
+fn test_keeps_edge_weights() {
+    let mut graph = random_graph();
+    graph.double_edge_swap(200, 10_000, Some(42));
+    let mut weights: Vec<usize> = graph.edge_weights().copied().collect();
+    weights.sort_unstable();
+    assert_eq!(weights, (1..=80).collect::<Vec<_>>());
+}
+
+#[test]
+fn test_changes_edges() {
+    let mut graph = random_graph();
+    let before = edge_set(&graph);
+    graph.double_edge_swap(200, 10_000, Some(42));
+    assert_ne!(edge_set(&graph), before);
+}
+
+#[test]
+fn test_seed_is_reproducible() {
+    let mut first = random_graph();
+    let mut second = random_graph();
+    first.double_edge_swap(50, 1000, Some(3));
+    second.double_edge_swap(50, 1000, Some(3));
+    assert_eq!(edge_set(&first), edge_set(&second));
+}
+
+#[test]
+fn test_no_valid_swap() {
+    // Every swap in a star graph would create a self-loop or parallel edge
+    let mut graph = StableUnGraph::<(), ()>::from_edges([(0, 1), (0, 2), (0, 3)]);
+    assert_eq!(graph.double_edge_swap(5, 100, Some(1)), 0);
+    let mut graph = StableUnGraph::<(), ()>::from_edges([(0, 1)]);
+    assert_eq!(graph.double_edge_swap(5, 100, Some(1)), 0);
+}
+
+fn directed_degrees(graph: &StableDiGraph<(), usize>) -> Vec<(usize, usize)> {
+    graph
+        .node_indices()
+        .map(|n| {
+            (
+                graph.edges_directed(n, Incoming).count(),
+                graph.edges_directed(n, Outgoing).count(),
+            )
+        })
+        .collect()
+}
+
+fn directed_edge_set(graph: &StableDiGraph<(), usize>) -> HashSet<(usize, usize)> {
+    graph
+        .edge_references()
+        .map(|e| (e.source().index(), e.target().index()))
+        .collect()
+}
+
+fn random_digraph() -> StableDiGraph<(), usize> {
+    let mut weight = 0;
+    gnm_random_graph(
+        30,
+        120,
+        Some(7),
+        || (),
+        || {
+            weight += 1;
+            weight
+        },
+    )
+    .unwrap()
+}
+
+#[test]
+fn test_directed_preserves_degrees() {
+    let mut graph = random_digraph();
+    let before = directed_degrees(&graph);
+    let swaps = graph.double_edge_swap(300, 10_000, Some(42));
+    assert_eq!(swaps, 300);
+    assert_eq!(directed_degrees(&graph), before);
+    assert_eq!(graph.edge_count(), 120);
+}
+
+#[test]
+fn test_directed_stays_simple() {
+    let mut graph = random_digraph();
+    let before = directed_edge_set(&graph);
+    graph.double_edge_swap(300, 10_000, Some(42));
+    let after = directed_edge_set(&graph);
+    assert_eq!(after.len(), graph.edge_count());
+    assert!(graph.edge_references().all(|e| e.source() != e.target()));
+    assert_ne!(after, before);
+}
+
+#[test]
+fn test_directed_seed_is_reproducible() {
+    let mut first = random_digraph();
+    let mut second = random_digraph();
+    first.double_edge_swap(50, 1000, Some(3));
+    second.double_edge_swap(50, 1000, Some(3));
+    assert_eq!(directed_edge_set(&first), directed_edge_set(&second));
+}
+
+#[test]
+fn test_directed_no_valid_swap() {
+    // Swapping the targets of two edges out of the same node is a no-op
+    let mut graph = StableDiGraph::<(), ()>::from_edges([(0, 1), (0, 2), (0, 3)]);
+    assert_eq!(graph.double_edge_swap(5, 100, Some(1)), 0);
+    // The only swap of a 2-cycle would create self-loops
+    let mut graph = StableDiGraph::<(), ()>::from_edges([(0, 1), (1, 0)]);
+    assert_eq!(graph.double_edge_swap(5, 100, Some(1)), 0);
+}
@@ -20,3 +20,5 @@ macro_rules! common_test {
 }
 
 mod contraction;
+mod edge_contraction;
+mod edge_swap;
@@ -218,6 +218,80 @@ def unweighted_average_shortest_path_length(graph, parallel_threshold=300, disco
     raise TypeError("Invalid Input Type %s for graph" % type(graph))
 
 
+@_rustworkx_dispatch
+def eccentricity(graph, weight_fn=None, default_weight=1.0):
+    """Compute the eccentricity of every node of a graph.
+
+    The eccentricity of a node is the largest shortest path length from the
+    node to any other node, following the direction of the edges of a
+    :class:`~rustworkx.PyDiGraph`. The shortest path lengths are computed
+    with Dijkstra's algorithm from every node in parallel. The edge weights
+    are computed before the searches, so ``weight_fn`` is called once for
+    every edge.
+
+    :param graph: The graph to compute the eccentricities of. Can either be a
+        :class:`~rustworkx.PyGraph` or :class:`~rustworkx.PyDiGraph`. Every
+        node must be reachable from every other node.
+    :param weight_fn: An optional callable that will be passed the weight of
+        every edge and is expected to return a non-negative ``float`` weight
+        for it. If not specified ``default_weight`` is used for every edge.
+    :param float default_weight: The weight of every edge if ``weight_fn`` is
+        not specified. Default: ``1.0``.
+
+    :returns: A dictionary mapping every node index to its eccentricity
+    :rtype: dict
+    :raises ValueError: If some node can't reach every other node
+    """
+    raise TypeError("Invalid Input Type %s for graph" % type(graph))
+
+
+@_rustworkx_dispatch
+def center(graph, weight_fn=None, default_weight=1.0):
+    """Return the center of a graph, the nodes with the smallest eccentricity.
+
+    The eccentricities are computed like in :func:`~rustworkx.eccentricity`,
+    with one search per node run in parallel.
+
+    :param graph: The graph to find the center of. Can either be a
+        :class:`~rustworkx.PyGraph` or :class:`~rustworkx.PyDiGraph`. Every
+        node must be reachable from every other node.
+    :param weight_fn: An optional callable that will be passed the weight of
+        every edge and is expected to return a non-negative ``float`` weight
+        for it. If not specified ``default_weight`` is used for every edge.
+    :param float default_weight: The weight of every edge if ``weight_fn`` is
+        not specified. Default: ``1.0``.
+
+    :returns: The indices of the nodes in the center
+    :rtype: NodeIndices
+    :raises ValueError: If some node can't reach every other node
+    """
+    raise TypeError("Invalid Input Type %s for graph" % type(graph))
+
+
+@_rustworkx_dispatch
+def periphery(graph, weight_fn=None, default_weight=1.0):
+    """Return the periphery of a graph, the nodes with the largest
+    eccentricity.
+
+    The eccentricities are computed like in :func:`~rustworkx.eccentricity`,
+    with one search per node run in parallel.
+
+    :param graph: The graph to find the periphery of. Can either be a
+        :class:`~rustworkx.PyGraph` or :class:`~rustworkx.PyDiGraph`. Every
+        node must be reachable from every other node.
+    :param weight_fn: An optional callable that will be passed the weight of
+        every edge and is expected to return a non-negative ``float`` weight
+        for it. If not specified ``default_weight`` is used for every edge.
+    :param float default_weight: The weight of every edge if ``weight_fn`` is
+        not specified. Default: ``1.0``.
+
+    :returns: The indices of the nodes in the periphery
+    :rtype: NodeIndices
+    :raises ValueError: If some node can't reach every other node
+    """
+    raise TypeError("Invalid Input Type %s for graph" % type(graph))
+
+
 @_rustworkx_dispatch
 def adjacency_matrix(graph, weight_fn=None, default_weight=1.0, null_value=0.0):
     """Return the adjacency matrix for a graph object
@@ -309,7 +383,9 @@ def floyd_warshall(
 
             floyd_warshall(graph, weight_fn=float)
 
-        to cast the edge object as a float as the weight. If this is not
+        to cast the edge object as a float as the weight. It can also be a
+        sequence or numpy array of the edge weights indexed by edge index,
+        which avoids a Python call per edge. If this is not
         specified a default value (either ``default_weight`` or 1) will be used
         for all edges.
     :param float default_weight: If ``weight_fn`` is not used this can be
@@ -365,7 +441,9 @@ def floyd_warshall_numpy(
 
             floyd_warshall_numpy(graph, weight_fn: lambda x: float(x))
 
-        to cast the edge object as a float as the weight. If this is not
+        to cast the edge object as a float as the weight. It can also be a
+        sequence or numpy array of the edge weights indexed by edge index,
+        which avoids a Python call per edge. If this is not
         specified a default value (either ``default_weight`` or 1) will be used
         for all edges.
     :param float default_weight: If ``weight_fn`` is not used this can be
@@ -417,6 +495,7 @@ def dijkstra_shortest_paths(
     weight_fn=None,
     default_weight=1.0,
     as_undirected=False,
+    reverse_view=False,
 ):
     """Find the shortest path from a node
 
@@ -429,12 +508,20 @@ def dijkstra_shortest_paths(
     :param int target: An optional target to find a path to
     :param weight_fn: An optional weight function for an edge. It will accept
         a single argument, the edge's weight object and will return a float
-        which will be used to represent the weight/cost of the edge
+        which will be used to represent the weight/cost of the edge.
+        It can also be a sequence or numpy array of the edge weights indexed
+        by edge index, which avoids a Python call per edge.
     :param float default_weight: If ``weight_fn`` isn't specified this optional
         float value will be used for the weight/cost of each edge.
     :param bool as_undirected: If set to true the graph will be treated as
         undirected for finding the shortest path. This only works with a
         :class:`~rustworkx.PyDiGraph` input for ``graph``
+    :param bool reverse_view: If set to true the edges of the graph will be
+        followed in the reverse direction, so the shortest paths found are the
+        paths from every node to ``source``. The paths are listed in the order
+        they are traversed from ``source``. The graph isn't copied to reverse
+        it. This only works with a :class:`~rustworkx.PyDiGraph` input for
+        ``graph``
 
     :return: Dictionary of paths. The keys are destination node indices and
         the dict values are lists of node indices making the path.
@@ -449,6 +536,7 @@ def has_path(
     source,
     target,
     as_undirected=False,
+    reverse_view=False,
 ):
     """Checks if a path exists between a source and target node
 
@@ -459,6 +547,11 @@ def has_path(
     :param bool as_undirected: If set to true the graph will be treated as
         undirected for finding existence of a path. This only works with a
         :class:`~rustworkx.PyDiGraph` input for ``graph``
+    :param bool reverse_view: If set to true the edges of the graph will be
+        followed in the reverse direction, so this checks if there is a path
+        from ``target`` to ``source`` instead. The graph isn't copied to
+        reverse it. This only works with a :class:`~rustworkx.PyDiGraph` input
+        for ``graph``
 
     :return: True if a path exists, False if not
     :rtype: bool
@@ -467,13 +560,16 @@ def has_path(
 
 
 @_rustworkx_dispatch
-def all_pairs_dijkstra_shortest_paths(graph, edge_cost_fn):
+def all_pairs_dijkstra_shortest_paths(
+    graph, edge_cost_fn, num_threads=None, progress_fn=None, progress_interval=None
+):
     """For each node in the graph, finds the shortest paths to all others.
 
     This function will generate the shortest path from all nodes in the graph
     using Dijkstra's algorithm. This function is multithreaded and will run
     launch a thread pool with threads equal to the number of CPUs by default.
-    You can tune the number of threads with the ``RAYON_NUM_THREADS``
+    You can limit the number of threads with ``num_threads`` or
+    :func:`~rustworkx.set_num_threads`, or with the ``RAYON_NUM_THREADS``
     environment variable. For example, setting ``RAYON_NUM_THREADS=4`` would
     limit the thread pool to 4 threads.
 
@@ -482,7 +578,18 @@ def all_pairs_dijkstra_shortest_paths(graph, edge_cost_fn):
     :param edge_cost_fn: A callable object that acts as a weight function for
         an edge. It will accept a single positional argument, the edge's weight
         object and will return a float which will be used to represent the
-        weight/cost of the edge
+        weight/cost of the edge.
+        It can also be a sequence or numpy array of the edge weights indexed
+        by edge index, which avoids a Python call per edge.
+    :param int num_threads: The maximum number of threads to use. If it is not
+        specified the number set with :func:`~rustworkx.set_num_threads` is
+        used.
+    :param progress_fn: An optional callable which is called as
+        ``progress_fn(done, total)`` after every ``progress_interval`` of the
+        ``total`` source nodes are searched from. An exception raised by it
+        stops the computation and is raised from this function.
+    :param int progress_interval: The number of source nodes between the calls
+        to ``progress_fn``. By default it is called 100 times.
 
     :return: A read-only dictionary of paths. The keys are source node
         indices and the values are a dict of target node indices and a list
@@ -525,13 +632,16 @@ def all_pairs_all_simple_paths(graph, min_depth=None, cutoff=None):
 
 
 @_rustworkx_dispatch
-def all_pairs_dijkstra_path_lengths(graph, edge_cost_fn):
+def all_pairs_dijkstra_path_lengths(
+    graph, edge_cost_fn, num_threads=None, progress_fn=None, progress_interval=None
+):
     """For each node in the graph, calculates the lengths of the shortest paths to all others.
 
     This function will generate the shortest path lengths from all nodes in the
     graph using Dijkstra's algorithm. This function is multithreaded and will
     launch a thread pool with threads equal to the number of CPUs by
-    default. You can tune the number of threads with the ``RAYON_NUM_THREADS``
+    default. You can limit the number of threads with ``num_threads`` or
+    :func:`~rustworkx.set_num_threads`, or with the ``RAYON_NUM_THREADS``
     environment variable. For example, setting ``RAYON_NUM_THREADS=4`` would
     limit the thread pool to 4 threads.
 
@@ -540,7 +650,18 @@ def all_pairs_dijkstra_path_lengths(graph, edge_cost_fn):
     :param edge_cost_fn: A callable object that acts as a weight function for
         an edge. It will accept a single positional argument, the edge's weight
         object and will return a float which will be used to represent the
-        weight/cost of the edge
+        weight/cost of the edge.
+        It can also be a sequence or numpy array of the edge weights indexed
+        by edge index, which avoids a Python call per edge.
+    :param int num_threads: The maximum number of threads to use. If it is not
+        specified the number set with :func:`~rustworkx.set_num_threads` is
+        used.
+    :param progress_fn: An optional callable which is called as
+        ``progress_fn(done, total)`` after every ``progress_interval`` of the
+        ``total`` source nodes are searched from. An exception raised by it
+        stops the computation and is raised from this function.
+    :param int progress_interval: The number of source nodes between the calls
+        to ``progress_fn``. By default it is called 100 times.
 
     :return: A read-only dictionary of path lengths. The keys are the source
         node indices and the values are a dict of the target node and the
@@ -558,7 +679,73 @@ def all_pairs_dijkstra_path_lengths(graph, edge_cost_fn):
 
 
 @_rustworkx_dispatch
-def dijkstra_shortest_path_lengths(graph, node, edge_cost_fn, goal=None):
+def all_pairs_dijkstra_path_lengths_csr(
+    graph, edge_cost_fn, cutoff=None, num_threads=None, progress_fn=None, progress_interval=None
+):
+    """For each node in the graph, calculates the lengths of the shortest paths
+    to the nodes within a distance of it, in compressed sparse row (CSR) format.
+
+    Unlike :func:`~rustworkx.all_pairs_dijkstra_path_lengths`, which stores a
+    dictionary entry for every pair of nodes connected by a path, only the
+    pairs of nodes at a distance of at most ``cutoff`` are stored, in numpy
+    arrays. Every search from a source node only visits the nodes within the
+    cutoff, so this scales to graphs where the distances between all pairs of
+    nodes don't fit in memory. The searches run in parallel in chunks of
+    source nodes, with the threads set by ``num_threads``,
+    :func:`~rustworkx.set_num_threads` or the ``RAYON_NUM_THREADS``
+    environment variable.
+
+    :param graph: The input graph to use. Can either be a
+        :class:`~rustworkx.PyGraph` or :class:`~rustworkx.PyDiGraph`
+    :param edge_cost_fn: A callable object that acts as a weight function for
+        an edge. It will accept a single positional argument, the edge's weight
+        object and will return a float which will be used to represent the
+        weight/cost of the edge.
+        It can also be a sequence or numpy array of the edge weights indexed
+        by edge index, which avoids a Python call per edge.
+    :param float cutoff: The largest length of the shortest paths to store.
+        If it's not specified the lengths of the shortest paths between all
+        pairs of nodes connected by a path are stored.
+    :param int num_threads: The maximum number of threads to use. If it is not
+        specified the number set with :func:`~rustworkx.set_num_threads` is
+        used.
+    :param progress_fn: An optional callable which is called as
+        ``progress_fn(done, total)`` after every ``progress_interval`` of the
+        ``total`` source nodes are searched from. An exception raised by it
+        stops the computation and is raised from this function.
+    :param int progress_interval: The number of source nodes between the calls
+        to ``progress_fn``. By default it is called 100 times.
+
+    :return: A tuple of the numpy arrays ``(data, indices, indptr)``. The
+        lengths of the shortest paths from the node with index ``i`` are
+        ``data[indptr[i]:indptr[i + 1]]`` and their target nodes are
+        ``indices[indptr[i]:indptr[i + 1]]``, in increasing order. There's a
+        row for every index up to the largest node index, which is empty for
+        the indices that aren't nodes of the graph, and the path from a node
+        to itself isn't stored. They can be passed to
+        ``scipy.sparse.csr_array`` with a shape of
+        ``(len(indptr) - 1, len(indptr) - 1)``.
+    :rtype: tuple
+    :raises ValueError: when an edge weight with NaN or negative value
+        is provided, or ``cutoff`` is negative.
+
+    .. jupyter-execute::
+
+      import rustworkx as rx
+
+      graph = rx.generators.path_graph(5)
+      data, indices, indptr = rx.all_pairs_dijkstra_path_lengths_csr(
+          graph, lambda _: 1.0, cutoff=2.0
+      )
+      print(data)
+      print(indices)
+      print(indptr)
+    """
+    raise TypeError("Invalid Input Type %s for graph" % type(graph))
+
+
+@_rustworkx_dispatch
+def dijkstra_shortest_path_lengths(graph, node, edge_cost_fn, goal=None, reverse_view=False):
     """Compute the lengths of the shortest paths for a graph object using
     Dijkstra's algorithm.
 
@@ -568,11 +755,18 @@ def dijkstra_shortest_path_lengths(graph, node, edge_cost_fn, goal=None):
         shortest paths from
     :param edge_cost_fn: A python callable that will take in 1 parameter, an
         edge's data object and will return a float that represents the
-        cost/weight of that edge. It must be non-negative
+        cost/weight of that edge. It must be non-negative.
+        It can also be a sequence or numpy array of the edge weights indexed
+        by edge index, which avoids a Python call per edge.
     :param int goal: An optional node index to use as the end of the path.
         When specified the traversal will stop when the goal is reached and
         the output dictionary will only have a single entry with the length
         of the shortest path to the goal node.
+    :param bool reverse_view: If set to true the edges of the graph will be
+        followed in the reverse direction, so the lengths of the shortest paths
+        from every node to ``node`` are found instead. The graph isn't copied
+        to reverse it. This only works with a :class:`~rustworkx.PyDiGraph`
+        input for ``graph``
 
     :returns: A dictionary of the shortest paths from the provided node where
         the key is the node index of the end of the path and the value is the
@@ -582,6 +776,40 @@ def dijkstra_shortest_path_lengths(graph, node, edge_cost_fn, goal=None):
     raise TypeError("Invalid Input Type %s for graph" % type(graph))
 
 
+@_rustworkx_dispatch
+def voronoi_cells(graph, sources, weight_fn=None, default_weight=1.0):
+    """Partition the nodes of a graph by their nearest source node.
+
+    The Voronoi cell of a source is the set of nodes that are closer to it
+    than to any other source, where the distance from a source to a node is
+    the length of the shortest path from the source to the node, computed
+    with Dijkstra's algorithm from all the sources at once. A node that is as
+    close to several sources is put in the cell of the first of them in
+    ``sources``, and nodes that can't be reached from any source aren't in
+    any cell.
+
+    :param graph: The input graph to use. Can either be a
+        :class:`~rustworkx.PyGraph` or :class:`~rustworkx.PyDiGraph`
+    :param list sources: The node indices of the sources. Duplicates are
+        ignored.
+    :param weight_fn: An optional weight function for an edge. It will accept
+        a single argument, the edge's weight object and will return a float
+        which will be used to represent the weight/cost of the edge. It must be
+        non-negative. It can also be a sequence or numpy array of the edge
+        weights indexed by edge index, which avoids a Python call per edge.
+    :param float default_weight: If ``weight_fn`` isn't specified this optional
+        float value will be used for the weight/cost of each edge.
+
+    :returns: A tuple of a dictionary mapping every source to the sorted list
+        of the node indices in its cell, in the order of ``sources``, and a
+        dictionary mapping every node in a cell to its distance from the
+        source of the cell.
+    :rtype: tuple[dict[int, list[int]], PathLengthMapping]
+    :raises IndexError: when a source isn't a node of the graph.
+    """
+    raise TypeError("Invalid Input Type %s for graph" % type(graph))
+
+
 @_rustworkx_dispatch
 def k_shortest_path_lengths(graph, start, k, edge_cost, goal=None):
     """Compute the length of the kth shortest path
@@ -659,6 +887,8 @@ def is_isomorphic(
     edge_matcher=None,
     id_order=True,
     call_limit=None,
+    progress_fn=None,
+    progress_interval=None,
 ):
     """Determine if 2 graphs are isomorphic
 
@@ -672,6 +902,11 @@ def is_isomorphic(
             rustworkx.is_isomorphic(graph_a, graph_b,
                                 lambda x, y: x == y)
 
+    Before running VF2 the isomorphism invariants of the graphs, such as
+    their degree sequences, are compared and ``False`` is returned without
+    searching for a mapping if any of them differ, see
+    :func:`~rustworkx.distinguishing_invariant`.
+
     .. note::
 
         For better performance on large graphs, consider setting
@@ -696,6 +931,12 @@ def is_isomorphic(
     :param int call_limit: An optional bound on the number of states that VF2
         algorithm visits while searching for a solution. If it exceeds this limit,
         the algorithm will stop and return ``False``.
+    :param progress_fn: An optional callable which is called as
+        ``progress_fn(states, None)`` after every ``progress_interval`` states
+        the VF2 algorithm visits. An exception raised by it stops the search
+        and is raised from this function.
+    :param int progress_interval: The number of states between the calls to
+        ``progress_fn``. Defaults to 1000.
 
     :returns: ``True`` if the 2 graphs are isomorphic, ``False`` if they are
         not.
@@ -707,6 +948,99 @@ def is_isomorphic(
     raise TypeError("Invalid Input Type %s for graph" % type(first))
 
 
+@_rustworkx_dispatch
+def distinguishing_invariant(first, second, wl_iterations=3):
+    """Find an isomorphism invariant that differs between 2 graphs
+
+    The invariants are compared from the cheapest to the most expensive and
+    the first one that differs is returned, which proves that the graphs
+    aren't isomorphic. The invariants are, in order:
+
+    * ``"node_count"``: the number of nodes.
+    * ``"edge_count"``: the number of edges.
+    * ``"degree_sequence"``: the sorted (out-degree, in-degree) pairs of the
+      nodes, which are both the degree of the node for undirected graphs.
+    * ``"triangle_counts"``: the sorted number of triangles through every
+      node, ignoring the direction of the edges.
+    * ``"weisfeiler_lehman_colors"``: the colors of the nodes after up to
+      ``wl_iterations`` rounds of Weisfeiler-Lehman color refinement.
+
+    :func:`~rustworkx.is_isomorphic` and its variants check these invariants
+    before running VF2, so this can be used to find out why two graphs
+    aren't isomorphic. The node and edge data are ignored.
+
+    :param first: The first graph to compare. Can either be a
+        :class:`~rustworkx.PyGraph` or :class:`~rustworkx.PyDiGraph`.
+    :param second: The second graph to compare. It should be the same type
+        as the first graph.
+    :param int wl_iterations: The largest number of Weisfeiler-Lehman rounds.
+        Defaults to 3.
+
+    :returns: The name of the first invariant that differs, or ``None`` if
+        the graphs agree on all of them and may be isomorphic.
+    :rtype: str
+
+    .. jupyter-execute::
+
+      import rustworkx as rx
+
+      hexagon = rx.generators.cycle_graph(6)
+      triangles = rx.generators.cycle_graph(3)
+      triangles.compose(rx.generators.cycle_graph(3), {})
+      print(rx.distinguishing_invariant(hexagon, triangles))
+      print(rx.distinguishing_invariant(hexagon, rx.generators.cycle_graph(6)))
+    """
+    raise TypeError("Invalid Input Type %s for graph" % type(first))
+
+
+@_rustworkx_dispatch
+def canonical_hash(graph, node_attr_fn=None, edge_attr_fn=None):
+    """Compute a hash of a graph that doesn't depend on its node indices
+
+    The graph is relabeled by a canonical labeling, which orders the nodes
+    the same way for every graph isomorphic to it, and the result is hashed
+    with the 128-bit FNV-1a hash function. Isomorphic graphs always have the
+    same hash and graphs that aren't isomorphic only have the same hash in
+    the unlikely case of a hash collision. The hash doesn't depend on the
+    order the nodes and edges were added in, the platform or the Python
+    process, so it can be used as a key to cache results computed for the
+    graph, such as on disk. A :class:`~rustworkx.PyGraph` and a
+    :class:`~rustworkx.PyDiGraph` never have the same hash.
+
+    The canonical labeling is computed with Weisfeiler-Lehman color
+    refinement and a search over the ways of breaking the ties it leaves,
+    pruned by the automorphisms of the graph. This is fast for most graphs
+    but can take exponential time for some highly symmetric ones.
+
+    :param graph: The graph to hash. Can either be a
+        :class:`~rustworkx.PyGraph` or :class:`~rustworkx.PyDiGraph`.
+    :param node_attr_fn: An optional callable that will be passed the data of
+        each node and returns its label. The label is converted with
+        ``str()``, so its string representation has to be deterministic. If
+        not specified the node data are ignored.
+    :param edge_attr_fn: An optional callable that will be passed the data of
+        each edge and returns its label. The label is converted with
+        ``str()``, so its string representation has to be deterministic. If
+        not specified the edge data are ignored.
+
+    :returns: The hash as 32 hexadecimal digits.
+    :rtype: str
+
+    .. jupyter-execute::
+
+      import rustworkx as rx
+
+      graph = rx.PyGraph()
+      graph.extend_from_edge_list([(0, 1), (1, 2), (2, 3)])
+      relabeled = rx.PyGraph()
+      relabeled.extend_from_edge_list([(3, 1), (1, 0), (0, 2)])
+      print(rx.canonical_hash(graph))
+      print(rx.canonical_hash(relabeled))
+      print(rx.canonical_hash(rx.generators.star_graph(4)))
+    """
+    raise TypeError("Invalid Input Type %s for graph" % type(graph))
+
+
 def is_isomorphic_node_match(first, second, matcher, id_order=True):
     """Determine if 2 graphs are isomorphic
 
@@ -754,6 +1088,8 @@ def is_subgraph_isomorphic(
     id_order=False,
     induced=True,
     call_limit=None,
+    progress_fn=None,
+    progress_interval=None,
 ):
     """Determine if 2 graphs are subgraph isomorphic
 
@@ -794,6 +1130,12 @@ def is_subgraph_isomorphic(
     :param int call_limit: An optional bound on the number of states that VF2
         algorithm visits while searching for a solution. If it exceeds this limit,
         the algorithm will stop and return ``False``.
+    :param progress_fn: An optional callable which is called as
+        ``progress_fn(states, None)`` after every ``progress_interval`` states
+        the VF2 algorithm visits. An exception raised by it stops the search
+        and is raised from this function.
+    :param int progress_interval: The number of states between the calls to
+        ``progress_fn``. Defaults to 1000.
 
     :returns: ``True`` if there is a subgraph of `first` isomorphic to `second`
         , ``False`` if there is not.
@@ -829,207 +1171,774 @@ def transitivity(graph):
 
 
 @_rustworkx_dispatch
-def core_number(graph):
-    """Return the core number for each node in the graph.
+def graph_summary(graph, clustering_samples=None, seed=None):
+    """Compute summary statistics of a graph
 
-    A k-core is a maximal subgraph that contains nodes of degree k or more.
+    The statistics are the number of nodes and edges, the density, the
+    minimum, maximum and mean degree, the number of (weakly) connected
+    components, the average clustering coefficient and whether the graph is
+    a DAG, a forest or bipartite. See :class:`~.GraphSummary` for how each of
+    them is defined.
 
-    .. note::
+    The degrees and clustering coefficients are computed in parallel.
+    For large graphs the average clustering coefficient can be estimated
+    from a random sample of the nodes with ``clustering_samples``.
 
-        The function implicitly assumes that there are no parallel edges
-        or self loops. It may produce incorrect/unexpected results if the
-        input graph has self loops or parallel edges.
+    This function is multithreaded and will launch a thread pool with threads
+    equal to the number of CPUs by default. You can tune the number of threads
+    with the ``RAYON_NUM_THREADS`` environment variable. For example, setting
+    ``RAYON_NUM_THREADS=4`` would limit the thread pool to 4 threads.
 
-    :param graph: The graph to get core numbers. Can either be a
-        :class:`~rustworkx.PyGraph` or :class:`~rustworkx.PyDiGraph`
+    .. note::
 
-    :returns: A dictionary keyed by node index to the core number
-    :rtype: dict
-        raise TypeError("Invalid Input Type %s for graph" % type(graph))
-    """
-    raise TypeError("Invalid Input Type %s for graph" % type(graph))
+        The clustering coefficient of a node with fewer than 2 neighbors is 0
+        and these nodes are included in the average. Like
+        :func:`~rustworkx.transitivity`, the clustering coefficients assume
+        that there are no parallel edges or self loops.
 
+    :param graph: The graph to summarize. Can either be a
+        :class:`~rustworkx.PyGraph` or :class:`~rustworkx.PyDiGraph`.
+    :param int clustering_samples: An optional number of nodes to sample
+        uniformly at random to estimate the average clustering coefficient.
+        If not specified, or if it's at least the number of nodes, the
+        average is computed over all nodes.
+    :param int seed: An optional seed for the random number generator used
+        to sample the nodes
 
-@_rustworkx_dispatch
-def complement(graph):
-    """Compute the complement of a graph.
+    :returns: The summary statistics of ``graph``
+    :rtype: GraphSummary
 
-    :param graph: The graph to be used, can be either a
-        :class:`~rustworkx.PyGraph` or :class:`~rustworkx.PyDiGraph`.
+    .. jupyter-execute::
 
-    :returns: The complement of the graph.
-    :rtype: :class:`~rustworkx.PyGraph` or :class:`~rustworkx.PyDiGraph`
+        import rustworkx as rx
 
-    .. note::
-        Parallel edges and self-loops are never created,
-        even if the ``multigraph`` is set to ``True``
+        graph = rx.generators.generalized_petersen_graph(5, 2)
+        summary = rx.graph_summary(graph)
+        print(summary.density, summary.is_bipartite)
     """
     raise TypeError("Invalid Input Type %s for graph" % type(graph))
 
 
 @_rustworkx_dispatch
-def random_layout(graph, center=None, seed=None):
-    """Generate a random layout
+def degree_assortativity_coefficient(
+    graph, weight_fn=None, default_weight=1.0, source="out", target="in"
+):
+    """Compute the degree assortativity coefficient of a graph.
 
-    :param PyGraph graph: The graph to generate the layout for
-    :param tuple center: An optional center position. This is a 2 tuple of two
-        ``float`` values for the center position
-    :param int seed: An optional seed to set for the random number generator.
+    The degree assortativity coefficient is the Pearson correlation
+    coefficient of the degrees of the nodes at the two ends of every edge.
+    It's positive if high degree nodes tend to be joined to other high degree
+    nodes and negative if they tend to be joined to low degree nodes.
 
-    :returns: The random layout of the graph.
-    :rtype: Pos2DMapping
+    :param graph: The graph to compute the coefficient for. Can either be a
+        :class:`~rustworkx.PyGraph` or :class:`~rustworkx.PyDiGraph`.
+    :param weight_fn: An optional callable that will be passed the data
+        payload of every edge and is expected to return a ``float`` weight
+        for that edge. If it's specified the weighted degree, the sum of the
+        weights of the edges of a node, is used instead of the degree.
+    :param float default_weight: If ``weight_fn`` is not specified the weight
+        used for every edge. Default: ``1.0``.
+    :param str source: The degree used for the source of every edge of a
+        :class:`~rustworkx.PyDiGraph`, either ``"out"`` or ``"in"``. Default:
+        ``"out"``. It can't be specified for a :class:`~rustworkx.PyGraph`.
+    :param str target: The degree used for the target of every edge of a
+        :class:`~rustworkx.PyDiGraph`, either ``"out"`` or ``"in"``. Default:
+        ``"in"``. It can't be specified for a :class:`~rustworkx.PyGraph`.
+
+    :returns: The degree assortativity coefficient of the graph. It's ``nan``
+        if it isn't defined.
+    :rtype: float
     """
     raise TypeError("Invalid Input Type %s for graph" % type(graph))
 
 
 @_rustworkx_dispatch
-def spring_layout(
-    graph,
-    pos=None,
-    fixed=None,
-    k=None,
-    repulsive_exponent=2,
-    adaptive_cooling=True,
-    num_iter=50,
-    tol=1e-6,
-    weight_fn=None,
-    default_weight=1,
-    scale=1,
-    center=None,
-    seed=None,
-):
-    """
-    Position nodes using Fruchterman-Reingold force-directed algorithm.
+def attribute_assortativity_coefficient(graph, attribute_fn):
+    """Compute the attribute assortativity coefficient of a graph.
 
-    The algorithm simulates a force-directed representation of the network
-    treating edges as springs holding nodes close, while treating nodes
-    as repelling objects, sometimes called an anti-gravity force.
-    Simulation continues until the positions are close to an equilibrium.
+    The attribute assortativity coefficient measures how much more often
+    edges join nodes with the same categorical attribute than they would if
+    the edges were placed at random. It's ``1.0`` if every edge joins nodes
+    with the same attribute, and negative if edges mostly join nodes with
+    different attributes.
 
-    :param graph: Graph to be used. Can either be a
+    :param graph: The graph to compute the coefficient for. Can either be a
         :class:`~rustworkx.PyGraph` or :class:`~rustworkx.PyDiGraph`.
-    :param dict pos:
-        Initial node positions as a dictionary with node ids as keys and values
-        as a coordinate list. If ``None``, then use random initial positions.
-        (``default=None``)
-    :param set fixed: Nodes to keep fixed at initial position.
-        Error raised if fixed specified and ``pos`` is not. (``default=None``)
-    :param float  k:
-        Optimal distance between nodes. If ``None`` the distance is set to
-        :math:`\\frac{1}{\\sqrt{n}}` where :math:`n` is the number of nodes.
-        Increase this value to move nodes farther apart. (``default=None``)
-    :param int repulsive_exponent:
-        Repulsive force exponent. (``default=2``)
-    :param bool adaptive_cooling:
-        Use an adaptive cooling scheme. If set to ``False``,
-        a linear cooling scheme is used. (``default=True``)
-    :param int num_iter:
-        Maximum number of iterations. (``default=50``)
-    :param float tol:
-        Threshold for relative error in node position changes.
-        The iteration stops if the error is below this threshold.
-        (``default = 1e-6``)
-    :param weight_fn: An optional weight function for an edge. It will accept
-        a single argument, the edge's weight object and will return a float
-        which will be used to represent the weight of the edge.
-    :param float (default=1) default_weight: If ``weight_fn`` isn't specified
-        this optional float value will be used for the weight/cost of each edge
-    :param float|None scale: Scale factor for positions.
-        Not used unless fixed is None. If scale is ``None``, no re-scaling is
-        performed. (``default=1.0``)
-    :param list center: Coordinate pair around which to center
-        the layout. Not used unless fixed is ``None``. (``default=None``)
-    :param int seed: An optional seed to use for the random number generator
+    :param attribute_fn: A callable that will be passed the data payload of
+        every node and is expected to return a hashable attribute for that
+        node.
 
-    :returns: A dictionary of positions keyed by node id.
-    :rtype: dict
+    :returns: The attribute assortativity coefficient of the graph. It's
+        ``nan`` if it isn't defined.
+    :rtype: float
     """
     raise TypeError("Invalid Input Type %s for graph" % type(graph))
 
 
-def networkx_converter(graph, keep_attributes: bool = False):
-    """Convert a networkx graph object into a rustworkx graph object.
-
-    .. note::
+@_rustworkx_dispatch
+def degree_histogram(graph):
+    """Return the degree histogram of a graph.
 
-        networkx is **not** a dependency of rustworkx and this function
-        is provided as a convenience method for users of both networkx and
-        rustworkx. This function will not work unless you install networkx
-        independently.
+    The degree of a node is the number of edges incident to it, the sum of
+    its in-degree and its out-degree for a :class:`~rustworkx.PyDiGraph`. A
+    self loop adds 2 to the degree of its node.
 
-    :param networkx.Graph graph: The networkx graph to convert.
-    :param bool keep_attributes: If ``True``, add networkx node attributes to
-        the data payload in the nodes of the output rustworkx graph. When set to
-        ``True``, the node data payloads in the output rustworkx graph object
-        will be dictionaries with the node attributes from the input networkx
-        graph where the ``"__networkx_node__"`` key contains the node from the
-        input networkx graph.
+    :param graph: The graph to compute the degree histogram of. Can either be
+        a :class:`~rustworkx.PyGraph` or :class:`~rustworkx.PyDiGraph`.
 
-    :returns: A rustworkx graph, either a :class:`~rustworkx.PyDiGraph` or a
-        :class:`~rustworkx.PyGraph` based on whether the input graph is directed
-        or not.
-    :rtype: :class:`~rustworkx.PyDiGraph` or :class:`~rustworkx.PyGraph`
+    :returns: A list whose element ``k`` is the number of nodes with degree
+        ``k``, up to the largest degree of the graph. It's empty if the graph
+        has no nodes.
+    :rtype: list
     """
-    if graph.is_directed():
-        new_graph = PyDiGraph(multigraph=graph.is_multigraph())
-    else:
-        new_graph = PyGraph(multigraph=graph.is_multigraph())
-    nodes = list(graph.nodes)
-    node_indices = dict(zip(nodes, new_graph.add_nodes_from(nodes)))
-    new_graph.add_edges_from(
-        [(node_indices[x[0]], node_indices[x[1]], x[2]) for x in graph.edges(data=True)]
-    )
-
-    if keep_attributes:
-        for node, node_index in node_indices.items():
-            attributes = graph.nodes[node]
-            attributes["__networkx_node__"] = node
-            new_graph[node_index] = attributes
-
-    return new_graph
+    raise TypeError("Invalid Input Type %s for graph" % type(graph))
 
 
 @_rustworkx_dispatch
-def bipartite_layout(
-    graph,
-    first_nodes,
-    horizontal=False,
-    scale=1,
-    center=None,
-    aspect_ratio=4 / 3,
-):
-    """Generate a bipartite layout of the graph
-
-    :param graph: The graph to generate the layout for. Can either be a
-        :class:`~rustworkx.PyGraph` or :class:`~rustworkx.PyDiGraph`
-    :param set first_nodes: The set of node indices on the left (or top if
-        horitontal is true)
-    :param bool horizontal: An optional bool specifying the orientation of the
-        layout
-    :param float scale: An optional scaling factor to scale positions
-    :param tuple center: An optional center position. This is a 2 tuple of two
-        ``float`` values for the center position
-    :param float aspect_ratio: An optional number for the ratio of the width to
-        the height of the layout.
-
-    :returns: The bipartite layout of the graph.
-    :rtype: Pos2DMapping
+def joint_degree_matrix(graph, normalized=True, source="out", target="in"):
+    """Return the joint degree matrix of a graph.
+
+    The joint degree matrix counts the edges of the graph by the degrees of
+    the nodes at their two ends. Every edge of a :class:`~rustworkx.PyGraph`
+    is counted in both directions, so its matrix is symmetric. Normalized,
+    it's the joint degree distribution ``P(k1, k2)``, the probability that an
+    edge joins nodes with degrees ``k1`` and ``k2``.
+
+    :param graph: The graph to compute the joint degree matrix of. Can either
+        be a :class:`~rustworkx.PyGraph` or :class:`~rustworkx.PyDiGraph`.
+    :param bool normalized: If ``True`` the entries are divided by their sum,
+        otherwise they're the number of edges. Default: ``True``.
+    :param str source: The degree used for the source of every edge of a
+        :class:`~rustworkx.PyDiGraph`, either ``"out"`` or ``"in"``. Default:
+        ``"out"``. It can't be specified for a :class:`~rustworkx.PyGraph`.
+    :param str target: The degree used for the target of every edge of a
+        :class:`~rustworkx.PyDiGraph`, either ``"out"`` or ``"in"``. Default:
+        ``"in"``. It can't be specified for a :class:`~rustworkx.PyGraph`.
+
+    :returns: A dictionary mapping every pair of degrees ``(k1, k2)`` joined
+        by at least one edge to the entry of the matrix for it, sorted by the
+        degrees.
+    :rtype: dict
     """
     raise TypeError("Invalid Input Type %s for graph" % type(graph))
 
 
 @_rustworkx_dispatch
-def circular_layout(graph, scale=1, center=None):
-    """Generate a circular layout of the graph
+def average_neighbor_degree(graph, weight_fn=None, default_weight=1.0, source="out", target="out"):
+    r"""Compute the average degree of the neighbors of every node of a graph.
 
-    :param graph: The graph to generate the layout for. Can either be a
-        :class:`~rustworkx.PyGraph` or :class:`~rustworkx.PyDiGraph`
-    :param float scale: An optional scaling factor to scale positions
-    :param tuple center: An optional center position. This is a 2 tuple of two
-        ``float`` values for the center position
+    The average neighbor degree of a node :math:`i` is
 
-    :returns: The circular layout of the graph.
-    :rtype: Pos2DMapping
+    .. math::
+
+        k_{nn,i} = \frac{1}{s_i} \sum_{j \in N(i)} w_{ij} k_j
+
+    where :math:`N(i)` are the neighbors of :math:`i`, :math:`w_{ij}` is the
+    weight of the edge between :math:`i` and :math:`j`, :math:`k_j` is the
+    degree of :math:`j` and :math:`s_i` is the sum of the weights of the
+    edges between :math:`i` and :math:`N(i)`.
+
+    :param graph: The graph to compute the average neighbor degrees for. Can
+        either be a :class:`~rustworkx.PyGraph` or
+        :class:`~rustworkx.PyDiGraph`.
+    :param weight_fn: An optional callable that will be passed the data
+        payload of every edge and is expected to return a ``float`` weight
+        for that edge.
+    :param float default_weight: If ``weight_fn`` is not specified the weight
+        used for every edge. Default: ``1.0``.
+    :param str source: The edges used for the neighbors of every node of a
+        :class:`~rustworkx.PyDiGraph`, either ``"out"`` for the successors or
+        ``"in"`` for the predecessors. Default: ``"out"``. It can't be
+        specified for a :class:`~rustworkx.PyGraph`.
+    :param str target: The degree used for the neighbors in a
+        :class:`~rustworkx.PyDiGraph`, either ``"out"`` or ``"in"``. Default:
+        ``"out"``. It can't be specified for a :class:`~rustworkx.PyGraph`.
+
+    :returns: A dictionary mapping every node index to its average neighbor
+        degree, which is ``0.0`` for nodes without neighbors.
+    :rtype: dict
+    """
+    raise TypeError("Invalid Input Type %s for graph" % type(graph))
+
+
+@_rustworkx_dispatch
+def earliest_arrival_times(graph, source, time_fn, start_time=None, end_time=None):
+    """Compute the earliest arrival times from a node of a temporal graph.
+
+    The edges of a temporal graph are only available at given times, which
+    are returned by ``time_fn``. The search leaves ``source`` at
+    ``start_time`` and follows time-respecting paths: every edge has to
+    depart no earlier than the arrival at its source node, and arrive no
+    later than ``end_time``. Timestamped contacts are edges with equal
+    departure and arrival times, and parallel edges can be used for an edge
+    that is available several times.
+
+    .. jupyter-execute::
+
+        import rustworkx as rx
+
+        graph = rx.PyDiGraph()
+        graph.add_nodes_from(range(3))
+        graph.add_edges_from([(0, 1, 3.0), (1, 2, 1.0), (1, 2, 5.0)])
+        # Node 1 is reached at time 3, too late for the contact at time 1
+        print(rx.earliest_arrival_times(graph, 0, lambda t: t))
+
+    :param graph: The input graph to use. Can either be a
+        :class:`~rustworkx.PyGraph` or :class:`~rustworkx.PyDiGraph`.
+    :param int source: The node index to start the search from
+    :param time_fn: A callable that will be passed the data payload of every
+        edge and is expected to return either a ``float`` timestamp when the
+        edge is available, or a tuple ``(departure, arrival)`` of the times
+        at which the edge is entered and its other endpoint is reached.
+    :param float start_time: The time the search leaves ``source``. If not
+        specified all the edges out of ``source`` can be used.
+    :param float end_time: The latest allowed arrival time. If not specified
+        there is no limit.
+
+    :returns: A mapping of the nodes temporally reachable from ``source``
+        (not including ``source``) to their earliest arrival time
+    :rtype: PathLengthMapping
+    :raises ValueError: when an edge time is NaN or an arrival time is
+        earlier than its departure time
+    """
+    raise TypeError("Invalid Input Type %s for graph" % type(graph))
+
+
+@_rustworkx_dispatch
+def earliest_arrival_paths(graph, source, time_fn, start_time=None, end_time=None):
+    """Find the earliest arrival paths from a node of a temporal graph.
+
+    See :func:`~rustworkx.earliest_arrival_times` for how the edge times are
+    used.
+
+    :param graph: The input graph to use. Can either be a
+        :class:`~rustworkx.PyGraph` or :class:`~rustworkx.PyDiGraph`.
+    :param int source: The node index to start the search from
+    :param time_fn: A callable that will be passed the data payload of every
+        edge and is expected to return either a ``float`` timestamp when the
+        edge is available, or a tuple ``(departure, arrival)`` of the times
+        at which the edge is entered and its other endpoint is reached.
+    :param float start_time: The time the search leaves ``source``. If not
+        specified all the edges out of ``source`` can be used.
+    :param float end_time: The latest allowed arrival time. If not specified
+        there is no limit.
+
+    :returns: A mapping of the nodes temporally reachable from ``source``
+        (not including ``source``) to a time-respecting path of node indices
+        reaching them at their earliest arrival time
+    :rtype: PathMapping
+    :raises ValueError: when an edge time is NaN or an arrival time is
+        earlier than its departure time
+    """
+    raise TypeError("Invalid Input Type %s for graph" % type(graph))
+
+
+@_rustworkx_dispatch
+def fastest_path_durations(graph, source, time_fn, start_time=None, end_time=None):
+    """Compute the durations of the fastest time-respecting paths from a node
+    of a temporal graph.
+
+    The duration of a path is the arrival time at its last node minus the
+    departure time of its first edge, so unlike
+    :func:`~rustworkx.earliest_arrival_times` the path may wait at
+    ``source`` for a later departure. One earliest arrival search is run in
+    parallel for every distinct departure time of the edges out of
+    ``source``.
+
+    :param graph: The input graph to use. Can either be a
+        :class:`~rustworkx.PyGraph` or :class:`~rustworkx.PyDiGraph`.
+    :param int source: The node index to start the search from
+    :param time_fn: A callable that will be passed the data payload of every
+        edge and is expected to return either a ``float`` timestamp when the
+        edge is available, or a tuple ``(departure, arrival)`` of the times
+        at which the edge is entered and its other endpoint is reached.
+    :param float start_time: The earliest allowed departure time. If not
+        specified there is no limit.
+    :param float end_time: The latest allowed arrival time. If not specified
+        there is no limit.
+
+    :returns: A mapping of the nodes temporally reachable from ``source``
+        (not including ``source``) to the duration of the fastest path to them
+    :rtype: PathLengthMapping
+    :raises ValueError: when an edge time is NaN or an arrival time is
+        earlier than its departure time
+    """
+    raise TypeError("Invalid Input Type %s for graph" % type(graph))
+
+
+@_rustworkx_dispatch
+def temporal_reachability(graph, source, time_fn, start_time=None, end_time=None):
+    """Return the nodes of a temporal graph that can be reached from a node
+    with a time-respecting path.
+
+    See :func:`~rustworkx.earliest_arrival_times` for how the edge times are
+    used.
+
+    :param graph: The input graph to use. Can either be a
+        :class:`~rustworkx.PyGraph` or :class:`~rustworkx.PyDiGraph`.
+    :param int source: The node index to start the search from
+    :param time_fn: A callable that will be passed the data payload of every
+        edge and is expected to return either a ``float`` timestamp when the
+        edge is available, or a tuple ``(departure, arrival)`` of the times
+        at which the edge is entered and its other endpoint is reached.
+    :param float start_time: The time the search leaves ``source``. If not
+        specified all the edges out of ``source`` can be used.
+    :param float end_time: The latest allowed arrival time. If not specified
+        there is no limit.
+
+    :returns: A set of the indices of the nodes temporally reachable from
+        ``source``, not including ``source``
+    :rtype: set
+    :raises ValueError: when an edge time is NaN or an arrival time is
+        earlier than its departure time
+    """
+    raise TypeError("Invalid Input Type %s for graph" % type(graph))
+
+
+@_rustworkx_dispatch
+def temporal_snapshot(graph, time_fn, start_time, end_time=None):
+    """Return the snapshot of a temporal graph at a given time.
+
+    The snapshot is a copy of the graph with only the edges whose
+    ``(departure, arrival)`` interval intersects the time window
+    ``[start_time, end_time]``. All the nodes are kept, with the same indices
+    as in the input graph.
+
+    .. jupyter-execute::
+
+        import rustworkx as rx
+
+        graph = rx.PyGraph()
+        graph.add_nodes_from(range(3))
+        graph.add_edges_from([(0, 1, (0.0, 2.0)), (1, 2, (1.0, 4.0)), (2, 0, (3.0, 5.0))])
+        snapshot = rx.temporal_snapshot(graph, lambda interval: interval, 2.0)
+        print(snapshot.edge_list())
+
+    :param graph: The input graph to use. Can either be a
+        :class:`~rustworkx.PyGraph` or :class:`~rustworkx.PyDiGraph`.
+    :param time_fn: A callable that will be passed the data payload of every
+        edge and is expected to return either a ``float`` timestamp when the
+        edge is available, or a tuple ``(departure, arrival)`` of the times
+        at which the edge is entered and its other endpoint is reached.
+    :param float start_time: The time of the snapshot, or the start of the
+        time window
+    :param float end_time: The end of the time window. If not specified it is
+        ``start_time``.
+
+    :returns: The snapshot of the graph
+    :rtype: PyGraph or PyDiGraph
+    :raises ValueError: when an edge time is NaN, an arrival time is earlier
+        than its departure time or ``end_time`` is earlier than ``start_time``
+    """
+    raise TypeError("Invalid Input Type %s for graph" % type(graph))
+
+
+@_rustworkx_dispatch
+def modularity(graph, communities, weight_fn=None, default_weight=1.0, resolution=1.0):
+    r"""Compute the modularity of a partition of the nodes of a graph.
+
+    For a :class:`~rustworkx.PyGraph` the modularity of a partition into
+    communities is
+
+    .. math::
+
+        Q = \sum_c \left( \frac{L_c}{m} - \gamma \left( \frac{d_c}{2m}
+        \right)^2 \right)
+
+    where :math:`m` is the total weight of the edges, :math:`\gamma` is the
+    resolution, and for every community :math:`c`, :math:`L_c` is the total
+    weight of the edges inside the community and :math:`d_c` is the sum of the
+    weighted degrees of its nodes. For a :class:`~rustworkx.PyDiGraph` the
+    second term is :math:`\gamma d^{out}_c d^{in}_c / m^2` instead.
+
+    The graph can be a multigraph: the weights of parallel edges add up and
+    a self-loop is an edge inside the community of its node.
+
+    .. jupyter-execute::
+
+        import rustworkx as rx
+
+        graph = rx.PyGraph()
+        graph.add_nodes_from(range(4))
+        graph.add_edges_from_no_data([(0, 1), (0, 1), (1, 2), (2, 3), (3, 3)])
+        print(rx.modularity(graph, [[0, 1], [2, 3]]))
+
+    :param graph: The input graph to use. Can either be a
+        :class:`~rustworkx.PyGraph` or :class:`~rustworkx.PyDiGraph`.
+    :param list communities: A list of the communities of the partition, each
+        of them a list of node indices. Every node of the graph has to be in
+        exactly one community.
+    :param weight_fn: An optional callable that will be passed the data
+        payload of every edge and is expected to return a ``float`` weight for
+        that edge.
+    :param float default_weight: If ``weight_fn`` is not specified the weight
+        used for every edge. Default: ``1.0``.
+    :param float resolution: The resolution :math:`\gamma`, values smaller
+        than ``1`` favor larger communities. Default: ``1.0``.
+
+    :returns: The modularity of the partition, ``nan`` if the total weight of
+        the edges is ``0``
+    :rtype: float
+    :raises ValueError: when ``communities`` is not a partition of the nodes of
+        the graph
+    """
+    raise TypeError("Invalid Input Type %s for graph" % type(graph))
+
+
+@_rustworkx_dispatch
+def sum_parallel_edges(graph, weight_fn=None, default_weight=1.0, combine_fn=None):
+    """Collapse the parallel edges of a multigraph into a weighted simple graph.
+
+    The returned graph has the same nodes, with the same indices and data
+    payloads, and a single edge for every pair of nodes connected by at least
+    one edge. In a :class:`~rustworkx.PyDiGraph` edges in opposite directions
+    are not parallel. The payload of every edge is a ``float`` weight: the
+    weights of the parallel edges combined with ``combine_fn``, their sum by
+    default. Self-loops are kept, with the parallel self-loops of a node
+    combined.
+
+    .. jupyter-execute::
+
+        import rustworkx as rx
+
+        graph = rx.PyGraph()
+        graph.add_nodes_from(range(3))
+        graph.add_edges_from([(0, 1, 2.0), (1, 0, 3.0), (1, 2, 1.0), (2, 2, 4.0)])
+        simple = rx.sum_parallel_edges(graph, weight_fn=float)
+        print(simple.weighted_edge_list())
+
+    :param graph: The input graph to use. Can either be a
+        :class:`~rustworkx.PyGraph` or :class:`~rustworkx.PyDiGraph`.
+    :param weight_fn: An optional callable that will be passed the data
+        payload of every edge and is expected to return a ``float`` weight for
+        that edge.
+    :param float default_weight: If ``weight_fn`` is not specified the weight
+        used for every edge. Default: ``1.0``.
+    :param combine_fn: An optional callable that will be passed two weights and
+        is expected to return their combined ``float`` weight, for example
+        ``max``. If not specified the weights are summed.
+
+    :returns: The weighted simple graph
+    :rtype: PyGraph or PyDiGraph
+    """
+    raise TypeError("Invalid Input Type %s for graph" % type(graph))
+
+
+@_rustworkx_dispatch
+def community_subgraphs(graph, communities, preserve_attrs=False):
+    """Return the induced subgraph of every community of a graph
+
+    This is equivalent to calling ``graph.subgraph()`` for every community,
+    but all the subgraphs are built in a single pass over the edges of the
+    graph. The nodes of every subgraph are added in increasing order of their
+    index in ``graph``, and the data payloads of the nodes and edges are
+    shared with ``graph``, not copied. The communities don't have to be a
+    partition of the nodes: they can overlap and they don't have to cover
+    every node.
+
+    :param graph: The graph to extract the communities from. Can either be a
+        :class:`~rustworkx.PyGraph` or :class:`~rustworkx.PyDiGraph`.
+    :param list communities: A list of the communities, each of them a list
+        of node indices
+    :param bool preserve_attrs: If set to ``True`` the attributes of
+        ``graph`` will be copied by reference to the ``attrs`` attribute of
+        every subgraph. Default: ``False``.
+
+    :returns: A list with a tuple for every community, in the order of
+        ``communities``, of its induced subgraph and a
+        :class:`~rustworkx.NodeMap` mapping the node indices of the subgraph
+        to the node indices of ``graph``
+    :rtype: list
+    :raises InvalidNode: when a community contains a node index that isn't
+        in the graph
+
+    .. jupyter-execute::
+
+        import rustworkx as rx
+
+        graph = rx.generators.barbell_graph(4, 0)
+        communities = [[0, 1, 2, 3], [4, 5, 6, 7]]
+        for subgraph, node_map in rx.community_subgraphs(graph, communities):
+            print(len(subgraph.edges()), dict(node_map))
+    """
+    raise TypeError("Invalid Input Type %s for graph" % type(graph))
+
+
+@_rustworkx_dispatch
+def core_number(graph):
+    """Return the core number for each node in the graph.
+
+    A k-core is a maximal subgraph that contains nodes of degree k or more.
+
+    .. note::
+
+        The function implicitly assumes that there are no parallel edges
+        or self loops. It may produce incorrect/unexpected results if the
+        input graph has self loops or parallel edges.
+
+    :param graph: The graph to get core numbers. Can either be a
+        :class:`~rustworkx.PyGraph` or :class:`~rustworkx.PyDiGraph`
+
+    :returns: A dictionary keyed by node index to the core number
+    :rtype: dict
+        raise TypeError("Invalid Input Type %s for graph" % type(graph))
+    """
+    raise TypeError("Invalid Input Type %s for graph" % type(graph))
+
+
+@_rustworkx_dispatch
+def s_core_number(graph, weight_fn=None, default_weight=1.0):
+    """Return the s-core number for each node in a weighted graph.
+
+    The s-core decomposition [Eidsaa2013]_ generalizes the k-core
+    decomposition, see :func:`~rustworkx.core_number`, to weighted graphs by
+    using the strength of a node, the total weight of its edges, instead of
+    its degree. An s-core is a maximal subgraph in which every node has a
+    strength of at least ``s``, and the s-core number of a node is the
+    largest ``s`` of an s-core that contains it.
+
+    The strength of a node ignores the direction of the edges: the weights of
+    parallel edges and of edges in opposite directions add up, and self loops
+    don't contribute to it. With unit weights the s-core numbers of a graph
+    without parallel edges or edges in opposite directions are its core
+    numbers.
+
+    :param graph: The graph to get the s-core numbers of. Can either be a
+        :class:`~rustworkx.PyGraph` or :class:`~rustworkx.PyDiGraph`
+    :param weight_fn: An optional callable that will be passed the data
+        payload of every edge and is expected to return a non-negative
+        ``float`` weight for that edge.
+    :param float default_weight: If ``weight_fn`` is not specified the weight
+        used for every edge. Default: ``1.0``.
+
+    :returns: A dictionary keyed by node index to the s-core number
+    :rtype: dict
+    :raises ValueError: when an edge has a negative or NaN weight
+
+    .. jupyter-execute::
+
+        import rustworkx as rx
+
+        # A heavy triangle with a light edge to node 3
+        graph = rx.PyGraph()
+        graph.extend_from_weighted_edge_list([(0, 1, 3.), (1, 2, 3.), (2, 0, 3.), (2, 3, 1.)])
+        print(rx.s_core_number(graph, weight_fn=float))
+
+    .. [Eidsaa2013] M. Eidsaa and E. Almaas, "s-core network decomposition: A
+        generalization of k-core analysis to weighted networks", Physical
+        Review E 88, 062819 (2013).
+    """
+    raise TypeError("Invalid Input Type %s for graph" % type(graph))
+
+
+@_rustworkx_dispatch
+def onion_decomposition(graph):
+    """Return the onion decomposition of the graph.
+
+    The onion decomposition refines the k-core decomposition, see
+    :func:`~rustworkx.core_number`: the nodes are removed in layers, where
+    every layer is made up of all the nodes whose degree in the remaining
+    graph is at most the current core number. Isolated nodes make up the
+    first layer. Nodes in higher layers of the same core are more deeply
+    embedded in it, which makes the layers useful to show the core-periphery
+    structure of a graph. For a :class:`~rustworkx.PyDiGraph` the direction
+    of the edges is ignored.
+
+    .. note::
+
+        Self loops and parallel edges are ignored when computing the degree
+        of a node.
+
+    :param graph: The graph to get the onion decomposition of. Can either be
+        a :class:`~rustworkx.PyGraph` or :class:`~rustworkx.PyDiGraph`
+
+    :returns: A tuple of a dictionary keyed by node index to the core number
+        and a dictionary keyed by node index to the onion layer, starting at 1
+    :rtype: tuple
+    """
+    raise TypeError("Invalid Input Type %s for graph" % type(graph))
+
+
+@_rustworkx_dispatch
+def complement(graph):
+    """Compute the complement of a graph.
+
+    :param graph: The graph to be used, can be either a
+        :class:`~rustworkx.PyGraph` or :class:`~rustworkx.PyDiGraph`.
+
+    :returns: The complement of the graph.
+    :rtype: :class:`~rustworkx.PyGraph` or :class:`~rustworkx.PyDiGraph`
+
+    .. note::
+        Parallel edges and self-loops are never created,
+        even if the ``multigraph`` is set to ``True``
+    """
+    raise TypeError("Invalid Input Type %s for graph" % type(graph))
+
+
+@_rustworkx_dispatch
+def random_layout(graph, center=None, seed=None):
+    """Generate a random layout
+
+    :param PyGraph graph: The graph to generate the layout for
+    :param tuple center: An optional center position. This is a 2 tuple of two
+        ``float`` values for the center position
+    :param int seed: An optional seed to set for the random number generator.
+
+    :returns: The random layout of the graph.
+    :rtype: Pos2DMapping
+    """
+    raise TypeError("Invalid Input Type %s for graph" % type(graph))
+
+
+@_rustworkx_dispatch
+def spring_layout(
+    graph,
+    pos=None,
+    fixed=None,
+    k=None,
+    repulsive_exponent=2,
+    adaptive_cooling=True,
+    num_iter=50,
+    tol=1e-6,
+    weight_fn=None,
+    default_weight=1,
+    scale=1,
+    center=None,
+    seed=None,
+):
+    """
+    Position nodes using Fruchterman-Reingold force-directed algorithm.
+
+    The algorithm simulates a force-directed representation of the network
+    treating edges as springs holding nodes close, while treating nodes
+    as repelling objects, sometimes called an anti-gravity force.
+    Simulation continues until the positions are close to an equilibrium.
+
+    :param graph: Graph to be used. Can either be a
+        :class:`~rustworkx.PyGraph` or :class:`~rustworkx.PyDiGraph`.
+    :param dict pos:
+        Initial node positions as a dictionary with node ids as keys and values
+        as a coordinate list. If ``None``, then use random initial positions.
+        (``default=None``)
+    :param set fixed: Nodes to keep fixed at initial position.
+        Error raised if fixed specified and ``pos`` is not. (``default=None``)
+    :param float  k:
+        Optimal distance between nodes. If ``None`` the distance is set to
+        :math:`\\frac{1}{\\sqrt{n}}` where :math:`n` is the number of nodes.
+        Increase this value to move nodes farther apart. (``default=None``)
+    :param int repulsive_exponent:
+        Repulsive force exponent. (``default=2``)
+    :param bool adaptive_cooling:
+        Use an adaptive cooling scheme. If set to ``False``,
+        a linear cooling scheme is used. (``default=True``)
+    :param int num_iter:
+        Maximum number of iterations. (``default=50``)
+    :param float tol:
+        Threshold for relative error in node position changes.
+        The iteration stops if the error is below this threshold.
+        (``default = 1e-6``)
+    :param weight_fn: An optional weight function for an edge. It will accept
+        a single argument, the edge's weight object and will return a float
+        which will be used to represent the weight of the edge.
+    :param float (default=1) default_weight: If ``weight_fn`` isn't specified
+        this optional float value will be used for the weight/cost of each edge
+    :param float|None scale: Scale factor for positions.
+        Not used unless fixed is None. If scale is ``None``, no re-scaling is
+        performed. (``default=1.0``)
+    :param list center: Coordinate pair around which to center
+        the layout. Not used unless fixed is ``None``. (``default=None``)
+    :param int seed: An optional seed to use for the random number generator
+
+    :returns: A dictionary of positions keyed by node id.
+    :rtype: dict
+    """
+    raise TypeError("Invalid Input Type %s for graph" % type(graph))
+
+
+def networkx_converter(graph, keep_attributes: bool = False):
+    """Convert a networkx graph object into a rustworkx graph object.
+
+    .. note::
+
+        networkx is **not** a dependency of rustworkx and this function
+        is provided as a convenience method for users of both networkx and
+        rustworkx. This function will not work unless you install networkx
+        independently.
+
+    :param networkx.Graph graph: The networkx graph to convert.
+    :param bool keep_attributes: If ``True``, add networkx node attributes to
+        the data payload in the nodes of the output rustworkx graph. When set to
+        ``True``, the node data payloads in the output rustworkx graph object
+        will be dictionaries with the node attributes from the input networkx
+        graph where the ``"__networkx_node__"`` key contains the node from the
+        input networkx graph.
+
+    :returns: A rustworkx graph, either a :class:`~rustworkx.PyDiGraph` or a
+        :class:`~rustworkx.PyGraph` based on whether the input graph is directed
+        or not.
+    :rtype: :class:`~rustworkx.PyDiGraph` or :class:`~rustworkx.PyGraph`
+    """
+    if graph.is_directed():
+        new_graph = PyDiGraph(multigraph=graph.is_multigraph())
+    else:
+        new_graph = PyGraph(multigraph=graph.is_multigraph())
+    nodes = list(graph.nodes)
+    node_indices = dict(zip(nodes, new_graph.add_nodes_from(nodes)))
+    new_graph.add_edges_from(
+        [(node_indices[x[0]], node_indices[x[1]], x[2]) for x in graph.edges(data=True)]
+    )
+
+    if keep_attributes:
+        for node, node_index in node_indices.items():
+            attributes = graph.nodes[node]
+            attributes["__networkx_node__"] = node
+            new_graph[node_index] = attributes
+
+    return new_graph
+
+
+@_rustworkx_dispatch
+def bipartite_layout(
+    graph,
+    first_nodes,
+    horizontal=False,
+    scale=1,
+    center=None,
+    aspect_ratio=4 / 3,
+):
+    """Generate a bipartite layout of the graph
+
+    :param graph: The graph to generate the layout for. Can either be a
+        :class:`~rustworkx.PyGraph` or :class:`~rustworkx.PyDiGraph`
+    :param set first_nodes: The set of node indices on the left (or top if
+        horitontal is true)
+    :param bool horizontal: An optional bool specifying the orientation of the
+        layout
+    :param float scale: An optional scaling factor to scale positions
+    :param tuple center: An optional center position. This is a 2 tuple of two
+        ``float`` values for the center position
+    :param float aspect_ratio: An optional number for the ratio of the width to
+        the height of the layout.
+
+    :returns: The bipartite layout of the graph.
+    :rtype: Pos2DMapping
+    """
+    raise TypeError("Invalid Input Type %s for graph" % type(graph))
+
+
+@_rustworkx_dispatch
+def circular_layout(graph, scale=1, center=None):
+    """Generate a circular layout of the graph
+
+    :param graph: The graph to generate the layout for. Can either be a
+        :class:`~rustworkx.PyGraph` or :class:`~rustworkx.PyDiGraph`
+    :param float scale: An optional scaling factor to scale positions
+    :param tuple center: An optional center position. This is a 2 tuple of two
+        ``float`` values for the center position
+
+    :returns: The circular layout of the graph.
+    :rtype: Pos2DMapping
     """
     raise TypeError("Invalid Input Type %s for graph" % type(graph))
 
@@ -1092,7 +2001,16 @@ def num_shortest_paths_unweighted(graph, source):
 
 
 @_rustworkx_dispatch
-def betweenness_centrality(graph, normalized=True, endpoints=False, parallel_threshold=50):
+def betweenness_centrality(
+    graph,
+    normalized=True,
+    endpoints=False,
+    parallel_threshold=50,
+    num_threads=None,
+    progress_fn=None,
+    progress_interval=None,
+    weight_fn=None,
+):
     r"""Returns the betweenness centrality of each node in the graph.
 
     Betweenness centrality of a node :math:`v` is the sum of the
@@ -1115,7 +2033,9 @@ def betweenness_centrality(graph, normalized=True, endpoints=False, parallel_thr
 
     This function is multithreaded and will run in parallel if the number
     of nodes in the graph is above the value of ``parallel_threshold`` (it
-    defaults to 50). If the function will be running in parallel the env var
+    defaults to 50). If the function will be running in parallel the number
+    of threads can be limited with ``num_threads`` or
+    :func:`~rustworkx.set_num_threads`, otherwise the env var
     ``RAYON_NUM_THREADS`` can be used to adjust how many threads will be used.
 
     See Also
@@ -1131,6 +2051,21 @@ def betweenness_centrality(graph, normalized=True, endpoints=False, parallel_thr
         the betweenness centrality in parallel at if the number of nodes in
         the graph is less than this value it will run in a single thread. The
         default value is 50
+    :param int num_threads: The maximum number of threads to use. If it is not
+        specified the number set with :func:`~rustworkx.set_num_threads` is
+        used.
+    :param progress_fn: An optional callable which is called as
+        ``progress_fn(done, total)`` after every ``progress_interval`` of the
+        ``total`` source nodes are searched from. An exception raised by it
+        stops the computation and is raised from this function.
+    :param int progress_interval: The number of source nodes between the calls
+        to ``progress_fn``. By default it is called 100 times.
+    :param weight_fn: An optional weight function for an edge. It will accept
+        a single argument, the edge's weight object and will return a float
+        which will be used as the length of the edge, which must be
+        non-negative. It can also be a sequence or numpy array of the edge
+        weights indexed by edge index, which avoids a Python call per edge. If
+        it isn't set every edge has the length ``1``.
 
     :returns: A dictionary mapping each node index to its betweenness centrality.
     :rtype: dict
@@ -1139,7 +2074,9 @@ def betweenness_centrality(graph, normalized=True, endpoints=False, parallel_thr
 
 
 @_rustworkx_dispatch
-def closeness_centrality(graph, wf_improved=True):
+def closeness_centrality(
+    graph, wf_improved=True, weight_fn=None, default_weight=1.0, direction="incoming"
+):
     r"""Compute the closeness centrality of each node in a graph object.
 
     The closeness centrality of a node :math:`u` is defined as the
@@ -1167,12 +2104,29 @@ def closeness_centrality(graph, wf_improved=True):
         C_{WF}(u) = \frac{n-1}{N-1} \frac{n - 1}{\sum_{v=1}^{n-1} d(v, u)},
 
     where :math:`N` is the number of nodes in the graph. This alternative
-    formula can be used with the ``wf_improved`` argument.
+    formula can be used with the ``wf_improved`` argument. See
+    :func:`~rustworkx.harmonic_centrality` for a measure that needs no
+    correction for disconnected graphs.
+
+    The shortest path distances are the numbers of edges on the paths unless
+    ``weight_fn`` is set, in which case they are computed with Dijkstra's
+    algorithm from the edge weights.
 
     :param graph: The input graph. Can either be a
         :class:`~retworkx.PyGraph` or :class:`~retworkx.PyDiGraph`.
     :param bool wf_improved: This is optional; the default is True. If True,
         scale by the fraction of nodes reachable.
+    :param weight_fn: An optional input callable that will be passed the edge's
+        payload object and is expected to return a ``float`` length for the
+        edge, which must not be negative. This also accepts a sequence or
+        numpy array of edge lengths indexed by edge index.
+    :param float default_weight: The length of every edge if ``weight_fn``
+        is set to ``None``. This is only used when ``weight_fn`` is set or
+        ``default_weight`` isn't ``1.0``.
+    :param str direction: Either ``"incoming"`` to use the distances of the
+        paths to each node or ``"outgoing"`` to use the distances of the paths
+        from each node. The default is ``"incoming"``. This is only used for
+        :class:`~rustworkx.PyDiGraph`.
 
     :returns: A dictionary mapping each node index to its closeness centrality.
     :rtype: dict
@@ -1185,7 +2139,54 @@ def closeness_centrality(graph, wf_improved=True):
 
 
 @_rustworkx_dispatch
-def edge_betweenness_centrality(graph, normalized=True, parallel_threshold=50):
+def harmonic_centrality(graph, weight_fn=None, default_weight=1.0, direction="incoming"):
+    r"""Compute the harmonic centrality of each node in a graph object.
+
+    The harmonic centrality of a node :math:`u` is the sum of the reciprocals
+    of the shortest path distances to :math:`u` from all other nodes:
+
+    .. math::
+
+        H(u) = \sum_{v \neq u} \frac{1}{d(v, u)},
+
+    where nodes that can't reach :math:`u` add :math:`0` to the sum [BV]_, so
+    unlike :func:`~rustworkx.closeness_centrality` it is well defined on
+    disconnected graphs without a correction. Nodes at distance :math:`0` are
+    skipped.
+
+    :param graph: The input graph. Can either be a
+        :class:`~rustworkx.PyGraph` or :class:`~rustworkx.PyDiGraph`.
+    :param weight_fn: An optional input callable that will be passed the edge's
+        payload object and is expected to return a ``float`` length for the
+        edge, which must not be negative. This also accepts a sequence or
+        numpy array of edge lengths indexed by edge index. If this isn't set
+        every edge has the length ``default_weight``.
+    :param float default_weight: The length of every edge if ``weight_fn``
+        is set to ``None``. The default is ``1.0``.
+    :param str direction: Either ``"incoming"`` to use the distances of the
+        paths to each node or ``"outgoing"`` to use the distances of the paths
+        from each node. The default is ``"incoming"``. This is only used for
+        :class:`~rustworkx.PyDiGraph`.
+
+    :returns: A dictionary mapping each node index to its harmonic centrality.
+    :rtype: dict
+
+    .. [BV] Boldi, P., & Vigna, S. (2014). Axioms for Centrality.
+      Internet Mathematics, 10(3-4), 222-262. doi:10.1080/15427951.2013.865686
+    """
+    raise TypeError("Invalid input type %s for graph" % type(graph))
+
+
+@_rustworkx_dispatch
+def edge_betweenness_centrality(
+    graph,
+    normalized=True,
+    parallel_threshold=50,
+    num_threads=None,
+    progress_fn=None,
+    progress_interval=None,
+    weight_fn=None,
+):
     r"""Compute the edge betweenness centrality of all edges in a graph.
 
     Edge betweenness centrality of an edge :math:`e` is the sum of the
@@ -1206,7 +2207,9 @@ def edge_betweenness_centrality(graph, normalized=True, parallel_threshold=50):
 
     This function is multithreaded and will run in parallel if the number
     of nodes in the graph is above the value of ``parallel_threshold`` (it
-    defaults to 50). If the function will be running in parallel the env var
+    defaults to 50). If the function will be running in parallel the number
+    of threads can be limited with ``num_threads`` or
+    :func:`~rustworkx.set_num_threads`, otherwise the env var
     ``RAYON_NUM_THREADS`` can be used to adjust how many threads will be used.
 
     See Also
@@ -1220,6 +2223,21 @@ def edge_betweenness_centrality(graph, normalized=True, parallel_threshold=50):
         the edge betweenness centrality in parallel at if the number of nodes in
         the graph is less than this value it will run in a single thread. The
         default value is 50
+    :param int num_threads: The maximum number of threads to use. If it is not
+        specified the number set with :func:`~rustworkx.set_num_threads` is
+        used.
+    :param progress_fn: An optional callable which is called as
+        ``progress_fn(done, total)`` after every ``progress_interval`` of the
+        ``total`` source nodes are searched from. An exception raised by it
+        stops the computation and is raised from this function.
+    :param int progress_interval: The number of source nodes between the calls
+        to ``progress_fn``. By default it is called 100 times.
+    :param weight_fn: An optional weight function for an edge. It will accept
+        a single argument, the edge's weight object and will return a float
+        which will be used as the length of the edge, which must be
+        non-negative. It can also be a sequence or numpy array of the edge
+        weights indexed by edge index, which avoids a Python call per edge. If
+        it isn't set every edge has the length ``1``.
 
     :returns: a read-only dict-like object whose keys are edges and values are the
         betweenness score for each node.
@@ -1316,6 +2334,372 @@ def katz_centrality(
     raise TypeError("Invalid input type %s for graph" % type(graph))
 
 
+@_rustworkx_dispatch
+def random_walk_with_restart(
+    graph,
+    seeds,
+    restart_prob=0.15,
+    weight_fn=None,
+    default_weight=1.0,
+    max_iter=100,
+    tol=1e-6,
+):
+    """Compute the random walk with restart proximity of every node to a set
+    of seed nodes.
+
+    A random walker starts at one of the ``seeds``, and at every step either
+    moves along one of the edges out of its current node (any edge of the
+    node for a :class:`~rustworkx.PyGraph`), chosen with a probability
+    proportional to the edge weights, or with probability ``restart_prob``
+    jumps back to one of the ``seeds``, chosen uniformly at random. A walker
+    at a node without any edges out of it always jumps back to the seeds. The
+    proximity of a node is the probability of finding the walker there in the
+    stationary distribution of this process, which is the same as the
+    personalized PageRank of the nodes with the seeds as the personalization
+    set. For details refer to:
+
+    Hanghang Tong, Christos Faloutsos and Jia-Yu Pan. "Fast random walk with
+    restart and its applications." Sixth International Conference on Data
+    Mining (2006): 613-622. <https://doi.org/10.1109/ICDM.2006.70>
+
+    This function uses power iteration on the sparse transition matrix and
+    convergence is not guaranteed. The function will stop when ``max_iter``
+    iterations is reached or when the L1 norm of the difference between the
+    proximities of two iterations is smaller than ``tol``. In the case of
+    multigraphs the weights of any parallel edges will be summed.
+
+    :param graph: The graph to run the random walks on. Can either be a
+        :class:`~rustworkx.PyGraph` or :class:`~rustworkx.PyDiGraph`.
+    :param list seeds: The node indices the walker restarts from
+    :param float restart_prob: The probability of jumping back to the seeds
+        at every step, greater than 0 and at most 1. Defaults to 0.15.
+    :param weight_fn: An optional input callable that will be passed the edge's
+        payload object and is expected to return a non-negative ``float``
+        weight for that edge. If this is not specified ``default_weight`` will
+        be used as the weight for every edge in ``graph``
+    :param float default_weight: If ``weight_fn`` is not set the default
+        weight value to use for the weight of all edges
+    :param int max_iter: The maximum number of iterations in the power method.
+        Defaults to 100.
+    :param float tol: The error tolerance used when checking for convergence.
+        Defaults to 1e-6.
+
+    :returns: A read-only dict-like object whose keys are the node indices and
+        values are the proximity of that node to the seeds. The proximities
+        sum to 1.
+    :rtype: CentralityMapping
+
+    :raises ValueError: If ``seeds`` is empty or ``restart_prob`` is invalid
+    :raises FailedToConverge: If the power iteration doesn't converge within
+        ``max_iter`` iterations
+    """
+    raise TypeError("Invalid input type %s for graph" % type(graph))
+
+
+@_rustworkx_dispatch
+def recursive_structural_features(graph, rounds=2):
+    """Compute recursive structural features of the nodes of a graph.
+
+    This is the feature extraction step of ReFeX, which is also the expensive
+    part of role discovery with RolX. Every node starts out with local and
+    egonet features, where the egonet of a node is the node together with its
+    neighbors. For a :class:`~rustworkx.PyGraph` these are the degree of the
+    node, the number of edges within its egonet and the number of edges
+    leaving its egonet. For a :class:`~rustworkx.PyDiGraph` these are the
+    in-degree and out-degree of the node, the number of edges within its
+    egonet, the number of edges leaving its egonet and the number of edges
+    entering its egonet. Then in each of the ``rounds`` recursive rounds, the
+    sum and the mean over the neighbors of a node of every feature added in
+    the previous round are added as new features. For details refer to:
+
+    Keith Henderson, Brian Gallagher, Lei Li, Leman Akoglu, Tina Eliassi-Rad,
+    Hanghang Tong and Christos Faloutsos. "It's who you know: graph mining
+    using recursive structural features." Proceedings of the 17th ACM SIGKDD
+    (2011): 663-671. <https://doi.org/10.1145/2020408.2020512>
+
+    The features of the nodes are computed in parallel. The env var
+    ``RAYON_NUM_THREADS`` can be used to adjust how many threads will be
+    used.
+
+    :param graph: The graph to compute the features of. Can either be a
+        :class:`~rustworkx.PyGraph` or :class:`~rustworkx.PyDiGraph`.
+    :param int rounds: The number of recursive aggregation rounds. Defaults
+        to 2.
+
+    :returns: A matrix with a row of features for every node, in the order of
+        the graph's ``node_indices()``
+    :rtype: numpy.ndarray
+
+    .. jupyter-execute::
+
+      import rustworkx as rx
+
+      graph = rx.generators.star_graph(4)
+      print(rx.recursive_structural_features(graph, rounds=1))
+    """
+    raise TypeError("Invalid input type %s for graph" % type(graph))
+
+
+@_rustworkx_dispatch
+def laplacian_matrix(graph, weight_fn=None, default_weight=1.0, sparse=False):
+    """Return the Laplacian matrix of a graph
+
+    The Laplacian matrix is :math:`L = D - A`, where :math:`A` is the weighted
+    adjacency matrix of the graph and :math:`D` is the diagonal matrix of the
+    weighted degrees of the nodes. For a :class:`~rustworkx.PyDiGraph` the
+    out-degrees are used. The weights of parallel edges are summed and
+    self-loops have no effect on :math:`L`.
+
+    :param graph: The graph to compute the Laplacian of. Can either be a
+        :class:`~rustworkx.PyGraph` or :class:`~rustworkx.PyDiGraph`.
+    :param weight_fn: An optional callable that will be passed the edge's
+        payload object and is expected to return a ``float`` weight for that
+        edge. If this is not specified ``default_weight`` will be used as the
+        weight for every edge in ``graph``
+    :param float default_weight: If ``weight_fn`` is not set the default
+        weight value to use for the weight of all edges
+    :param bool sparse: If ``True`` return the matrix in compressed sparse row
+        format as a tuple of the numpy arrays ``(data, indices, indptr)``,
+        which can be passed to ``scipy.sparse.csr_array``. Every diagonal
+        entry is stored, even if it is zero. Defaults to ``False``.
+
+    :returns: The Laplacian matrix, with a row and a column for each node in
+        the order of the graph's ``node_indices()``
+    :rtype: numpy.ndarray or tuple
+
+    .. jupyter-execute::
+
+      import rustworkx as rx
+
+      graph = rx.generators.path_graph(3)
+      print(rx.laplacian_matrix(graph))
+    """
+    raise TypeError("Invalid input type %s for graph" % type(graph))
+
+
+@_rustworkx_dispatch
+def normalized_laplacian_matrix(graph, weight_fn=None, default_weight=1.0, sparse=False):
+    """Return the normalized Laplacian matrix of a graph
+
+    The normalized Laplacian matrix is :math:`D^{-1/2} (D - A) D^{-1/2}`,
+    where :math:`A` is the weighted adjacency matrix of the graph and
+    :math:`D` is the diagonal matrix of the weighted degrees of the nodes. For
+    a :class:`~rustworkx.PyDiGraph` the out-degrees are used. The rows and
+    columns of nodes with a degree of 0 are all zero. The weights of parallel
+    edges are summed.
+
+    :param graph: The graph to compute the normalized Laplacian of. Can either be a
+        :class:`~rustworkx.PyGraph` or :class:`~rustworkx.PyDiGraph`.
+    :param weight_fn: An optional callable that will be passed the edge's
+        payload object and is expected to return a ``float`` weight for that
+        edge. If this is not specified ``default_weight`` will be used as the
+        weight for every edge in ``graph``
+    :param float default_weight: If ``weight_fn`` is not set the default
+        weight value to use for the weight of all edges
+    :param bool sparse: If ``True`` return the matrix in compressed sparse row
+        format as a tuple of the numpy arrays ``(data, indices, indptr)``,
+        which can be passed to ``scipy.sparse.csr_array``. Every diagonal
+        entry is stored, even if it is zero. Defaults to ``False``.
+
+    :returns: The normalized Laplacian matrix, with a row and a column for
+        each node in the order of the graph's ``node_indices()``
+    :rtype: numpy.ndarray or tuple
+
+    .. jupyter-execute::
+
+      import rustworkx as rx
+
+      graph = rx.generators.path_graph(3)
+      print(rx.normalized_laplacian_matrix(graph))
+    """
+    raise TypeError("Invalid input type %s for graph" % type(graph))
+
+
+@_rustworkx_dispatch
+def simrank_similarity(graph, source=None, target=None, decay=0.8, max_iter=100, tol=1e-4):
+    r"""Compute the SimRank similarity of the nodes of a graph.
+
+    SimRank is based on the idea that two nodes are similar if they are
+    referenced by similar nodes. Every node has a similarity of 1 to itself,
+    and the similarity of two distinct nodes :math:`a` and :math:`b` is
+
+    .. math::
+
+        s(a, b) = \frac{C}{|I(a)||I(b)|} \sum_{i \in I(a)} \sum_{j \in I(b)} s(i, j)
+
+    where :math:`I(a)` are the predecessors of :math:`a` (or the neighbors of
+    :math:`a` for a :class:`~rustworkx.PyGraph`) and :math:`C` is the decay
+    factor. For details refer to:
+
+    Glen Jeh and Jennifer Widom. "SimRank: a measure of structural-context
+    similarity." Proceedings of the 8th ACM SIGKDD (2002): 538-543.
+    <https://doi.org/10.1145/775047.775126>
+
+    The similarities are computed iteratively until no similarity changes by
+    more than ``tol``, with every iteration taking :math:`O(|V||E|)` time.
+    The similarities of all pairs of nodes are computed even if only a
+    single source or pair of nodes is requested, so :math:`O(|V|^2)` memory
+    is used in every case.
+
+    :param graph: The graph to compute the similarities of. Can either be a
+        :class:`~rustworkx.PyGraph` or :class:`~rustworkx.PyDiGraph`.
+    :param int source: An optional node index, if specified only the
+        similarities to this node are returned
+    :param int target: An optional node index, if specified together with
+        ``source`` only the similarity of ``source`` and ``target`` is returned
+    :param float decay: The decay factor :math:`C`, between 0 and 1
+    :param int max_iter: The maximum number of iterations
+    :param float tol: The error tolerance used to check for convergence
+
+    :returns: The similarity of ``source`` and ``target`` if both are
+        specified, a dictionary mapping node indices to their similarity to
+        ``source`` if only ``source`` is specified, and otherwise a dictionary
+        of these dictionaries for every node in the graph
+    :rtype: float | dict
+
+    :raises FailedToConverge: if the similarities don't converge in
+        ``max_iter`` iterations
+    """
+    raise TypeError("Invalid input type %s for graph" % type(graph))
+
+
+@_rustworkx_dispatch
+def weighted_simrank_similarity(
+    graph,
+    source=None,
+    target=None,
+    weight_fn=None,
+    default_weight=1.0,
+    decay=0.8,
+    max_iter=100,
+    tol=1e-4,
+):
+    r"""Compute the weighted SimRank++ similarity of the nodes of a graph.
+
+    SimRank++ extends :func:`~rustworkx.simrank_similarity` to weighted
+    graphs. The similarity of two distinct nodes :math:`a` and :math:`b` is
+
+    .. math::
+
+        s(a, b) = \textit{evidence}(a, b) \cdot C \sum_{i \in I(a)} \sum_{j \in I(b)}
+            W(i, a) W(j, b) s(i, j)
+
+    where :math:`I(a)` are the predecessors of :math:`a` (or the neighbors of
+    :math:`a` for a :class:`~rustworkx.PyGraph`) and :math:`W(i, a)` is the
+    weight of the edge from :math:`i` to :math:`a` normalized by the total
+    weight of the edges into :math:`a` and scaled by :math:`e^{-\sigma^2_i}`,
+    with :math:`\sigma^2_i` the variance of the weights of the edges out of
+    :math:`i`. The evidence factor :math:`1 - 2^{-n}`, where :math:`n` is the
+    size of :math:`I(a) \cap I(b)`, makes pairs with more common predecessors
+    more similar. The weights of parallel edges are summed. For details refer
+    to:
+
+    Ioannis Antonellis, Hector Garcia-Molina and Chi-Chao Chang. "Simrank++:
+    query rewriting through link analysis of the click graph." Proceedings of
+    the VLDB Endowment 1.1 (2008): 408-421.
+    <https://doi.org/10.14778/1453856.1453903>
+
+    As for the unweighted SimRank, the similarities of all pairs of nodes are
+    computed even if only a single source or pair of nodes is requested, so
+    :math:`O(|V|^2)` memory is used in every case.
+
+    :param graph: The graph to compute the similarities of. Can either be a
+        :class:`~rustworkx.PyGraph` or :class:`~rustworkx.PyDiGraph`.
+    :param int source: An optional node index, if specified only the
+        similarities to this node are returned
+    :param int target: An optional node index, if specified together with
+        ``source`` only the similarity of ``source`` and ``target`` is returned
+    :param weight_fn: An optional callable that will be passed the edge's
+        payload object and is expected to return a non-negative ``float``
+        weight for that edge. If this is not specified ``default_weight`` will
+        be used as the weight for every edge in ``graph``
+    :param float default_weight: If ``weight_fn`` is not set the default weight
+        value to use for the weight of all edges
+    :param float decay: The decay factor :math:`C`, between 0 and 1
+    :param int max_iter: The maximum number of iterations
+    :param float tol: The error tolerance used to check for convergence
+
+    :returns: The similarity of ``source`` and ``target`` if both are
+        specified, a dictionary mapping node indices to their similarity to
+        ``source`` if only ``source`` is specified, and otherwise a dictionary
+        of these dictionaries for every node in the graph
+    :rtype: float | dict
+
+    :raises FailedToConverge: if the similarities don't converge in
+        ``max_iter`` iterations
+    """
+    raise TypeError("Invalid input type %s for graph" % type(graph))
+
+
+@_rustworkx_dispatch
+def katz_similarity(
+    graph,
+    source=None,
+    target=None,
+    alpha=0.1,
+    weight_fn=None,
+    default_weight=1.0,
+    max_iter=1000,
+    tol=1e-8,
+):
+    r"""Compute the Katz similarity of the nodes of a graph.
+
+    The Katz similarity of a node :math:`a` to a node :math:`b` counts the
+    walks from :math:`a` to :math:`b`, with a walk of length :math:`k`
+    weighted by :math:`\alpha^k`. For a :class:`~rustworkx.PyDiGraph` the
+    walks follow the directions of the edges. The similarity matrix is
+
+    .. math::
+
+        S = \sum_{k=1}^\infty \alpha^k A^k = (I - \alpha A)^{-1} - I
+
+    where :math:`A` is the weighted adjacency matrix of the graph, with the
+    weights of parallel edges summed. The series only converges if
+    :math:`\alpha` is smaller than the reciprocal of the largest eigenvalue of
+    :math:`A`. For details refer to:
+
+    Leo Katz. "A new status index derived from sociometric analysis."
+    Psychometrika 18.1 (1953): 39-43. <https://doi.org/10.1007/BF02289026>
+
+    If ``source`` is specified only the row of ``source`` is computed, by
+    summing the series until the sum of a term is less than ``tol``, where
+    every term takes :math:`O(|E|)` time. Otherwise the whole matrix is
+    computed by inverting :math:`I - \alpha A` as a dense matrix, which takes
+    :math:`O(|V|^3)` time and is only suitable for small graphs.
+
+    :param graph: The graph to compute the similarities of. Can either be a
+        :class:`~rustworkx.PyGraph` or :class:`~rustworkx.PyDiGraph`.
+    :param int source: An optional node index, if specified only the
+        similarities to this node are returned
+    :param int target: An optional node index, if specified together with
+        ``source`` only the similarity of ``source`` and ``target`` is returned
+    :param float alpha: The attenuation factor :math:`\alpha`, which must be
+        positive
+    :param weight_fn: An optional callable that will be passed the edge's
+        payload object and is expected to return a non-negative ``float``
+        weight for that edge. If this is not specified ``default_weight`` will
+        be used as the weight for every edge in ``graph``
+    :param float default_weight: If ``weight_fn`` is not set the default weight
+        value to use for the weight of all edges
+    :param int max_iter: The maximum number of terms of the series summed
+        when ``source`` is specified
+    :param float tol: The error tolerance used to check for convergence when
+        ``source`` is specified
+
+    :returns: The similarity of ``source`` and ``target`` if both are
+        specified, a dictionary mapping node indices to their similarity to
+        ``source`` if only ``source`` is specified, and otherwise a dictionary
+        of these dictionaries for every node in the graph
+    :rtype: float | dict
+
+    :raises ValueError: if ``alpha`` is too large for the series to converge
+        and ``source`` isn't specified
+    :raises FailedToConverge: if the series doesn't converge in ``max_iter``
+        terms when ``source`` is specified
+    """
+    raise TypeError("Invalid input type %s for graph" % type(graph))
+
+
 @_rustworkx_dispatch
 def vf2_mapping(
     first,
@@ -1326,6 +2710,8 @@ def vf2_mapping(
     subgraph=False,
     induced=True,
     call_limit=None,
+    progress_fn=None,
+    progress_interval=None,
 ):
     """
     Return an iterator over all vf2 mappings between two graphs.
@@ -1366,65 +2752,374 @@ def vf2_mapping(
     :param int call_limit: An optional bound on the number of states that VF2
         algorithm visits while searching for a solution. If it exceeds this limit,
         the algorithm will stop. Default: ``None``.
+    :param progress_fn: An optional callable which is called as
+        ``progress_fn(states, None)`` after every ``progress_interval`` states
+        the VF2 algorithm visits. An exception raised by it stops the search
+        and is raised from this function.
+    :param int progress_interval: The number of states between the calls to
+        ``progress_fn``. Defaults to 1000.
+
+    :returns: An iterator over dicitonaries of node indices from ``first`` to node
+        indices in ``second`` representing the mapping found.
+    :rtype: Iterable[NodeMap]
+    """
+    raise TypeError("Invalid Input Type %s for graph" % type(first))
+
+
+@_rustworkx_dispatch
+def rewire(graph, n_swaps, /, max_tries=None, seed=None):
+    """Randomize the edges of a graph in place while preserving its degrees.
+
+    This is the rewiring of Maslov and Sneppen [MS]_. Every swap picks two
+    edges ``(u, v)`` and ``(x, y)`` at random and replaces them with the
+    edges ``(u, x)`` and ``(v, y)`` for a :class:`~rustworkx.PyGraph`, or
+    ``(u, y)`` and ``(x, v)`` for a :class:`~rustworkx.PyDiGraph` so that the
+    in-degree and out-degree of every node are preserved. A swap is only done
+    if it doesn't add a self-loop or an edge which is already in the graph,
+    so a simple graph stays simple. The new edges take the weights of the
+    edges they replace.
+
+    Repeated enough times it samples a random graph with the degree sequence
+    of ``graph``, which is the null model used to tell if a property of
+    ``graph``, such as its assortativity or its count of a motif, is
+    explained by its degrees alone.
+
+    .. jupyter-execute::
+
+        import rustworkx as rx
+
+        graph = rx.generators.grid_graph(4, 4)
+        degrees = [graph.degree(node) for node in graph.node_indices()]
+        swaps = rx.rewire(graph, 20, seed=42)
+        print(swaps)
+        print(degrees == [graph.degree(node) for node in graph.node_indices()])
+
+    :param graph: The graph to rewire. Can either be a
+        :class:`~rustworkx.PyGraph` or :class:`~rustworkx.PyDiGraph`
+    :param int n_swaps: The number of swaps to do
+    :param int max_tries: The maximum number of swaps to attempt, including
+        the rejected ones. Defaults to ``10 * n_swaps``.
+    :param int seed: An optional seed to use for the random number generator
+
+    :returns: The number of swaps done, which is less than ``n_swaps`` if
+        ``max_tries`` was reached first
+    :rtype: int
+    :raises DAGWouldCycle: If ``graph`` is a :class:`~rustworkx.PyDiGraph`
+        with ``check_cycle`` set, since the swaps can add cycles
+
+    .. [MS] S. Maslov and K. Sneppen, "Specificity and stability in topology
+        of protein networks", Science 296, 910-913 (2002).
+    """
+    raise TypeError("Invalid Input Type %s for graph" % type(graph))
+
+
+@_rustworkx_dispatch
+def motif_counts(graph, /, size=3, probabilities=None, seed=None):
+    """Count the motifs of a graph.
+
+    The motifs are the connected induced subgraphs with ``size`` nodes
+    (weakly connected for a :class:`~rustworkx.PyDiGraph`), which are
+    enumerated with the ESU algorithm of Wernicke [ESU]_. The graph is treated
+    as a simple graph: self-loops are ignored and parallel edges are counted
+    once.
+
+    With ``probabilities`` the motifs are sampled with the RAND-ESU algorithm
+    instead, which is much faster on large graphs. The subgraphs are
+    enumerated as a tree, and a subtree at depth ``d`` is only visited with
+    probability ``probabilities[d]``. Every sampled motif is counted as
+    ``1 / p`` motifs, where ``p`` is the product of the probabilities, so the
+    counts are unbiased estimates.
+
+    .. jupyter-execute::
+
+        import rustworkx as rx
+
+        # A triangle with a pendant node
+        graph = rx.PyGraph()
+        graph.extend_from_edge_list([(0, 1), (1, 2), (2, 0), (2, 3)])
+        print(rx.motif_counts(graph, 3))
+
+    :param graph: The graph to count the motifs of. Can either be a
+        :class:`~rustworkx.PyGraph` or :class:`~rustworkx.PyDiGraph`
+    :param int size: The number of nodes of the motifs, ``3`` or ``4``.
+        Default: ``3``.
+    :param list probabilities: The optional sampling probability at every
+        depth, ``size`` values in ``(0, 1]``
+    :param int seed: An optional seed to use for the random number generator
+        of the sampling
+
+    :returns: A dictionary mapping the isomorphism class of every motif found
+        to its count. A class is the tuple of the edges of its canonical form
+        on the nodes ``0`` to ``size - 1``.
+    :rtype: dict
+    :raises ValueError: If ``size`` or ``probabilities`` is invalid
+
+    .. [ESU] S. Wernicke, "Efficient detection of network motifs", IEEE/ACM
+        Transactions on Computational Biology and Bioinformatics 3(4),
+        347-359 (2006).
+    """
+    raise TypeError("Invalid Input Type %s for graph" % type(graph))
+
+
+@_rustworkx_dispatch
+def motif_significance_profile(
+    graph,
+    /,
+    size=3,
+    num_randomizations=100,
+    swaps_per_edge=10,
+    probabilities=None,
+    seed=None,
+):
+    """Compute the motif significance profile of a graph.
+
+    The motifs are counted as with :func:`~rustworkx.motif_counts` in the
+    graph and in ``num_randomizations`` random graphs with the same degrees,
+    made with :func:`~rustworkx.rewire`. Every class of motif found in any of
+    the graphs gets the z-score of its count in the graph against the counts
+    in the random graphs, and the significance profile of Milo et al. [SP]_ is
+    the vector of z-scores normalized to length ``1``. A positive
+    significance means the motif is more common than expected from the
+    degrees alone. The z-score of a class whose count is the same in all the
+    random graphs is ``nan``, and it's left out of the normalization.
+
+    :param graph: The graph to compute the significance profile of. Can
+        either be a :class:`~rustworkx.PyGraph` or
+        :class:`~rustworkx.PyDiGraph`
+    :param int size: The number of nodes of the motifs, ``3`` or ``4``.
+        Default: ``3``.
+    :param int num_randomizations: The number of random graphs. It must be
+        positive. Default: ``100``.
+    :param int swaps_per_edge: The number of edge swaps done to randomize the
+        graph, per edge of the graph. Default: ``10``.
+    :param list probabilities: The optional sampling probabilities of
+        RAND-ESU, as in :func:`~rustworkx.motif_counts`
+    :param int seed: An optional seed to use for the random number generator
+
+    :returns: A dictionary mapping every class of motif to a dictionary with
+        its ``count`` in the graph, the ``random_mean`` and ``random_std`` of
+        its counts in the random graphs, its ``z_score`` and its
+        ``significance``, the entry of the significance profile.
+    :rtype: dict
+    :raises ValueError: If ``size`` or ``probabilities`` is invalid or
+        ``num_randomizations`` is ``0``
+
+    .. [SP] R. Milo et al., "Superfamilies of evolved and designed networks",
+        Science 303, 1538-1542 (2004).
+    """
+    raise TypeError("Invalid Input Type %s for graph" % type(graph))
+
+
+@_rustworkx_dispatch
+def union(
+    first,
+    second,
+    merge_nodes=False,
+    merge_edges=False,
+    key_fn=None,
+):
+    """Return a new graph by forming a union from two input graph objects
+
+    The algorithm in this function operates in three phases:
+
+    1. Add all the nodes from  ``second`` into ``first``. operates in
+    :math:`\\mathcal{O}(n_2)`, with :math:`n_2` being number of nodes in
+    ``second``.
+    2. Merge nodes from ``second`` over ``first`` given that:
+
+       - The ``merge_nodes`` is ``True``. operates in :math:`\\mathcal{O}(n_1 n_2)`,
+         with :math:`n_1` being the number of nodes in ``first`` and :math:`n_2`
+         the number of nodes in ``second``
+       - The respective node in ``second`` and ``first`` share the same
+         weight/data payload.
+
+    3. Adds all the edges from ``second`` to ``first``. If the ``merge_edges``
+       parameter is ``True`` and the respective edge in ``second`` and
+       ``first`` share the same weight/data payload they will be merged together.
+
+    :param first: The first graph object
+    :param second: The second graph object
+    :param bool merge_nodes: If set to ``True`` nodes will be merged between
+        ``second`` and ``first`` if the weights are equal. Default: ``False``.
+    :param bool merge_edges: If set to ``True`` edges will be merged between
+        ``second`` and ``first`` if the weights are equal. Default: ``False``.
+    :param key_fn: An optional callable that will be passed the data payload
+        of each node and is expected to return a hashable key. If specified
+        and ``merge_nodes`` is ``True``, a node of ``second`` is merged with
+        the lowest index node of ``first`` that has an equal key instead of
+        an equal weight.
+
+    :returns: A new graph object that is the union of ``second`` and
+        ``first``. It's worth noting the weight/data payload objects are
+        passed by reference from ``first`` and ``second`` to this new object.
+    :rtype: :class:`~rustworkx.PyGraph` or :class:`~rustworkx.PyDiGraph`
+    """
+    raise TypeError("Invalid Input Type %s for graph" % type(first))
+
+
+@_rustworkx_dispatch
+def union_with_mapping(
+    first,
+    second,
+    merge_nodes=False,
+    merge_edges=False,
+    key_fn=None,
+):
+    """Return a new graph by forming a union from two input graph objects,
+    together with a report of where their nodes and edges ended up
+
+    This is the same as :func:`~rustworkx.union`, but it also returns a
+    :class:`~rustworkx.UnionMapping` that maps the node and edge indices of
+    ``first`` and ``second`` to the indices in the output graph and lists the
+    nodes and edges of ``second`` that were merged into ``first``. The output
+    graph is a copy of ``first`` with the remaining nodes and edges of
+    ``second`` added, so the indices of ``first`` are unchanged.
+
+    :param first: The first graph object
+    :param second: The second graph object
+    :param bool merge_nodes: If set to ``True`` nodes will be merged between
+        ``second`` and ``first`` if the weights are equal. Default: ``False``.
+    :param bool merge_edges: If set to ``True`` edges will be merged between
+        ``second`` and ``first`` if the weights are equal. Default: ``False``.
+    :param key_fn: An optional callable that will be passed the data payload
+        of each node and is expected to return a hashable key. If specified
+        and ``merge_nodes`` is ``True``, a node of ``second`` is merged with
+        the lowest index node of ``first`` that has an equal key instead of
+        an equal weight.
+
+    :returns: A tuple of the new graph object that is the union of ``second``
+        and ``first`` and the :class:`~rustworkx.UnionMapping` for it
+    :rtype: tuple
+    """
+    raise TypeError("Invalid Input Type %s for graph" % type(first))
+
+
+@_rustworkx_dispatch
+def intersection(first, second, align_by="index", node_merge_fn=None, edge_merge_fn=None):
+    """Return a new graph by forming the intersection of two graph objects
+
+    The nodes of ``second`` are first aligned with the nodes of ``first``,
+    either by node index or by equal data payloads. The edges of ``second``
+    are then aligned with the edges of ``first`` between the aligned nodes,
+    and with ``align_by="weight"`` only if their data payloads are equal too.
+    Every node and edge is aligned with at most one node or edge of the other
+    graph, so parallel edges are aligned one to one. The intersection has a
+    node for every pair of aligned nodes and an edge for every pair of
+    aligned edges, in the order of ``first``.
+
+    :param first: The first graph object
+    :param second: The second graph object
+    :param str align_by: How the nodes of the graphs are aligned, either
+        ``"index"`` for nodes with the same index or ``"weight"`` for nodes
+        with equal data payloads. Default: ``"index"``.
+    :param node_merge_fn: An optional callable that will be passed the data
+        payloads of a pair of aligned nodes and is expected to return the data
+        payload of their node in the output. By default the payload of
+        ``first`` is used.
+    :param edge_merge_fn: An optional callable that will be passed the data
+        payloads of a pair of aligned edges and is expected to return the data
+        payload of their edge in the output. By default the payload of
+        ``first`` is used.
+
+    :returns: A new graph object that is the intersection of ``first`` and
+        ``second``
+    :rtype: :class:`~rustworkx.PyGraph` or :class:`~rustworkx.PyDiGraph`
+    :raises ValueError: If ``align_by`` is not ``"index"`` or ``"weight"``
+    """
+    raise TypeError("Invalid Input Type %s for graph" % type(first))
+
+
+@_rustworkx_dispatch
+def difference(first, second, align_by="index"):
+    """Return a new graph by removing the edges of a graph from another graph
+
+    The nodes and edges of ``second`` are aligned with the nodes and edges of
+    ``first`` in the same way as in :func:`~rustworkx.intersection`. The
+    difference has all the nodes of ``first`` and the edges of ``first`` that
+    aren't aligned with an edge of ``second``, with the data payloads of
+    ``first``.
+
+    :param first: The first graph object
+    :param second: The second graph object
+    :param str align_by: How the nodes of the graphs are aligned, either
+        ``"index"`` for nodes with the same index or ``"weight"`` for nodes
+        with equal data payloads. Default: ``"index"``.
 
-    :returns: An iterator over dicitonaries of node indices from ``first`` to node
-        indices in ``second`` representing the mapping found.
-    :rtype: Iterable[NodeMap]
+    :returns: A new graph object that is the difference of ``first`` and
+        ``second``
+    :rtype: :class:`~rustworkx.PyGraph` or :class:`~rustworkx.PyDiGraph`
+    :raises ValueError: If ``align_by`` is not ``"index"`` or ``"weight"``
     """
     raise TypeError("Invalid Input Type %s for graph" % type(first))
 
 
 @_rustworkx_dispatch
-def union(
-    first,
-    second,
-    merge_nodes=False,
-    merge_edges=False,
-):
-    """Return a new graph by forming a union from two input graph objects
+def symmetric_difference(first, second, align_by="index", node_merge_fn=None):
+    """Return a new graph by forming the symmetric difference of two graph
+    objects
 
-    The algorithm in this function operates in three phases:
+    The nodes and edges of ``second`` are aligned with the nodes and edges of
+    ``first`` in the same way as in :func:`~rustworkx.intersection`. The
+    symmetric difference has all the nodes of ``first`` followed by the nodes
+    of ``second`` that aren't aligned with a node of ``first``, and the edges
+    of both graphs that aren't aligned with an edge of the other graph.
 
-    1. Add all the nodes from  ``second`` into ``first``. operates in
-    :math:`\\mathcal{O}(n_2)`, with :math:`n_2` being number of nodes in
-    ``second``.
-    2. Merge nodes from ``second`` over ``first`` given that:
+    :param first: The first graph object
+    :param second: The second graph object
+    :param str align_by: How the nodes of the graphs are aligned, either
+        ``"index"`` for nodes with the same index or ``"weight"`` for nodes
+        with equal data payloads. Default: ``"index"``.
+    :param node_merge_fn: An optional callable that will be passed the data
+        payloads of a pair of aligned nodes and is expected to return the data
+        payload of their node in the output. By default the payload of
+        ``first`` is used.
+
+    :returns: A new graph object that is the symmetric difference of
+        ``first`` and ``second``
+    :rtype: :class:`~rustworkx.PyGraph` or :class:`~rustworkx.PyDiGraph`
+    :raises ValueError: If ``align_by`` is not ``"index"`` or ``"weight"``
+    """
+    raise TypeError("Invalid Input Type %s for graph" % type(first))
 
-       - The ``merge_nodes`` is ``True``. operates in :math:`\\mathcal{O}(n_1 n_2)`,
-         with :math:`n_1` being the number of nodes in ``first`` and :math:`n_2`
-         the number of nodes in ``second``
-       - The respective node in ``second`` and ``first`` share the same
-         weight/data payload.
 
-    3. Adds all the edges from ``second`` to ``first``. If the ``merge_edges``
-       parameter is ``True`` and the respective edge in ``second`` and
-       ``first`` share the same weight/data payload they will be merged together.
+@_rustworkx_dispatch
+def full_join(first, second, join_fn=None):
+    """Return a new graph by forming the full join of two graph objects
+
+    The full join is the disjoint union of the two graphs with an extra edge
+    between every node of ``first`` and every node of ``second``, in both
+    directions for directed graphs. The nodes of ``first`` come before the
+    nodes of ``second``.
 
     :param first: The first graph object
     :param second: The second graph object
-    :param bool merge_nodes: If set to ``True`` nodes will be merged between
-        ``second`` and ``first`` if the weights are equal. Default: ``False``.
-    :param bool merge_edges: If set to ``True`` edges will be merged between
-        ``second`` and ``first`` if the weights are equal. Default: ``False``.
+    :param join_fn: An optional callable that will be passed the data
+        payloads of a node of ``first`` and a node of ``second`` and is
+        expected to return the data payload of an edge joining them. By
+        default the payload is ``None``.
 
-    :returns: A new graph object that is the union of ``second`` and
-        ``first``. It's worth noting the weight/data payload objects are
-        passed by reference from ``first`` and ``second`` to this new object.
+    :returns: A new graph object that is the full join of ``first`` and
+        ``second``
     :rtype: :class:`~rustworkx.PyGraph` or :class:`~rustworkx.PyDiGraph`
     """
     raise TypeError("Invalid Input Type %s for graph" % type(first))
 
 
 @_rustworkx_dispatch
-def tensor_product(
-    first,
-    second,
-):
+def tensor_product(first, second, node_fn=None, edge_fn=None):
     """Return a new graph by forming the tensor product
     from two input graph objects
 
     :param first: The first graph object
     :param second: The second graph object
+    :param node_fn: An optional callable that will be passed the data
+        payloads of a node of ``first`` and a node of ``second`` and is
+        expected to return the data payload of the corresponding node of the
+        product. By default the payload is a tuple of the two payloads.
+    :param edge_fn: An optional callable that will be passed the data
+        payloads of the edges of ``first`` and ``second`` an edge of the
+        product is formed from and is expected to return the data payload of
+        that edge. By default the payload is a tuple of the two payloads.
 
     :returns: A new graph object that is the tensor product of ``second`` and
         ``first``. It's worth noting the weight/data payload objects are
@@ -1446,17 +3141,24 @@ def tensor_product(
 
 
 @_rustworkx_dispatch
-def cartesian_product(
-    first,
-    second,
-):
+def cartesian_product(first, second, node_fn=None, edge_fn=None):
     """Return a new graph by forming the cartesian product
     from two input graph objects
 
     :param first: The first graph object
     :param second: The second graph object
-
-    :returns: A new graph object that is the union of ``second`` and
+    :param node_fn: An optional callable that will be passed the data
+        payloads of a node of ``first`` and a node of ``second`` and is
+        expected to return the data payload of the corresponding node of the
+        product. By default the payload is a tuple of the two payloads.
+    :param edge_fn: An optional callable that will be passed the data
+        payloads of the edges of ``first`` and ``second`` an edge of the
+        product is formed from and is expected to return the data payload of
+        that edge. As every edge of the product comes from an edge of one of
+        the graphs, one of the arguments is always ``None``. By default the
+        payload of the edge of ``first`` or ``second`` is used.
+
+    :returns: A new graph object that is the cartesian product of ``second`` and
         ``first``. It's worth noting the weight/data payload objects are
         passed by reference from ``first`` and ``second`` to this new object.
         A read-only dictionary of the product of nodes is also returned. The keys
@@ -1475,6 +3177,133 @@ def cartesian_product(
     raise TypeError("Invalid Input Type %s for graph" % type(first))
 
 
+@_rustworkx_dispatch
+def strong_product(first, second, node_fn=None, edge_fn=None):
+    """Return a new graph by forming the strong product
+    from two input graph objects
+
+    The edges of the strong product are the edges of the cartesian product
+    and the edges of the tensor product of the two graphs.
+
+    :param first: The first graph object
+    :param second: The second graph object
+    :param node_fn: An optional callable that will be passed the data
+        payloads of a node of ``first`` and a node of ``second`` and is
+        expected to return the data payload of the corresponding node of the
+        product. By default the payload is a tuple of the two payloads.
+    :param edge_fn: An optional callable that will be passed the data
+        payloads of the edges of ``first`` and ``second`` an edge of the
+        product is formed from and is expected to return the data payload of
+        that edge. The edges of the product come from an edge of one of the
+        graphs or from an edge of each, and the argument for a graph without
+        an edge is ``None``. By default the payload is the payload of the
+        single edge, or a tuple of the two payloads.
+
+    :returns: A new graph object that is the strong product of ``first`` and
+        ``second``. A read-only dictionary of the product of nodes is also
+        returned. The keys are a tuple where the first element is a node of
+        the first graph and the second element is a node of the second graph,
+        and the values are the map of those elements to node indices in the
+        product graph.
+
+    :rtype: Tuple[:class:`~rustworkx.PyGraph` or :class:`~rustworkx.PyDiGraph`,
+        :class:`~rustworkx.ProductNodeMap`]
+    """
+    raise TypeError("Invalid Input Type %s for graph" % type(first))
+
+
+@_rustworkx_dispatch
+def lexicographic_product(first, second, node_fn=None, edge_fn=None):
+    """Return a new graph by forming the lexicographic product
+    from two input graph objects
+
+    The lexicographic product replaces every node of ``first`` with a copy of
+    ``second``, and every edge of ``first`` connects every node of the copy at
+    its source to every node of the copy at its target.
+
+    :param first: The first graph object
+    :param second: The second graph object
+    :param node_fn: An optional callable that will be passed the data
+        payloads of a node of ``first`` and a node of ``second`` and is
+        expected to return the data payload of the corresponding node of the
+        product. By default the payload is a tuple of the two payloads.
+    :param edge_fn: An optional callable that will be passed the data
+        payloads of the edges of ``first`` and ``second`` an edge of the
+        product is formed from and is expected to return the data payload of
+        that edge. As every edge of the product comes from an edge of one of
+        the graphs, one of the arguments is always ``None``. By default the
+        payload of the edge of ``first`` or ``second`` is used.
+
+    :returns: A new graph object that is the lexicographic product of
+        ``first`` and ``second``. A read-only dictionary of the product of
+        nodes is also returned. The keys are a tuple where the first element
+        is a node of the first graph and the second element is a node of the
+        second graph, and the values are the map of those elements to node
+        indices in the product graph.
+
+    :rtype: Tuple[:class:`~rustworkx.PyGraph` or :class:`~rustworkx.PyDiGraph`,
+        :class:`~rustworkx.ProductNodeMap`]
+    """
+    raise TypeError("Invalid Input Type %s for graph" % type(first))
+
+
+@_rustworkx_dispatch
+def line_graph(graph):
+    """Constructs the line graph of a graph.
+
+    The line graph `L(G)` of a graph `G` contains a vertex for every edge in
+    `G`. For an undirected graph `L(G)` contains an edge between two vertices
+    if the corresponding edges in `G` have a vertex in common. For a directed
+    graph `L(G)` contains an edge from the vertex of an edge `(u, v)` to the
+    vertex of an edge `(v, w)`, so only edges that follow each other
+    head-to-tail are adjacent.
+
+    :param graph: The input graph object
+
+    :returns: A new graph object that is the line graph of ``graph``, and the
+        dictionary where the keys are indices of edges in ``graph`` and the
+        values are the corresponding indices of nodes in the line graph.
+    :rtype: Tuple[:class:`~rustworkx.PyGraph` or :class:`~rustworkx.PyDiGraph`, dict]
+    """
+    raise TypeError("Invalid Input Type %s for graph" % type(graph))
+
+
+@_rustworkx_dispatch
+def quotient_graph(graph, partition, node_fn=None, edge_fn=None, self_loops=False):
+    """Return the quotient graph of a graph for a partition of its nodes.
+
+    Every block of the partition is collapsed into a single node of the
+    quotient graph, and all the edges between the nodes of two blocks are
+    merged into a single edge between the nodes of those blocks. For a
+    directed graph the edges in the two directions are merged separately.
+
+    :param graph: The input graph
+    :param partition: Either a sequence of blocks, where every block is a
+        sequence of node indices, or a callable that will be passed the data
+        payload of every node and is expected to return a hashable key, in
+        which case nodes with equal keys are in the same block. A node that
+        isn't in any block of a sequence is in a block on its own.
+    :param node_fn: An optional callable that will be passed a list of the
+        data payloads of the nodes of a block and is expected to return the
+        data payload of the node of the quotient graph for that block. By
+        default the list of payloads is used.
+    :param edge_fn: An optional callable that will be passed a list of the
+        data payloads of the edges between two blocks and is expected to
+        return the data payload of the edge between them. By default the list
+        of payloads is used.
+    :param bool self_loops: If ``True`` the edges within a block are merged
+        into a self-loop on the node of that block, otherwise they are
+        dropped. Defaults to ``False``.
+
+    :returns: A tuple of the quotient graph and a dictionary mapping the node
+        indices of ``graph`` to the node indices of the quotient graph.
+    :rtype: Tuple[:class:`~rustworkx.PyGraph` or :class:`~rustworkx.PyDiGraph`, dict]
+    :raises ValueError: If a node index in ``partition`` isn't in the graph
+        or is in more than one block.
+    """
+    raise TypeError("Invalid Input Type %s for graph" % type(graph))
+
+
 @_rustworkx_dispatch
 def bfs_search(graph, source, visitor):
     """Breadth-first traversal of a directed/undirected graph.
@@ -1696,7 +3525,9 @@ def bellman_ford_shortest_paths(
     :param int target: An optional target to find a path to
     :param weight_fn: An optional weight function for an edge. It will accept
         a single argument, the edge's weight object and will return a float
-        which will be used to represent the weight/cost of the edge
+        which will be used to represent the weight/cost of the edge. It can
+        also be a sequence or numpy array of the edge weights indexed by edge
+        index, which avoids a Python call per edge.
     :param float default_weight: If ``weight_fn`` isn't specified this optional
         float value will be used for the weight/cost of each edge.
     :param bool as_undirected: If set to true the graph will be treated as
@@ -1724,7 +3555,9 @@ def bellman_ford_shortest_path_lengths(graph, node, edge_cost_fn, goal=None):
         shortest paths from
     :param edge_cost_fn: A python callable that will take in 1 parameter, an
         edge's data object and will return a float that represents the
-        cost/weight of that edge. It can be negative.
+        cost/weight of that edge. It can be negative. It can also be a
+        sequence or numpy array of the edge weights indexed by edge index,
+        which avoids a Python call per edge.
     :param int goal: An optional node index to use as the end of the path.
         When specified the output dictionary will only have a single entry with
         the length of the shortest path to the goal node.
@@ -1741,13 +3574,16 @@ def bellman_ford_shortest_path_lengths(graph, node, edge_cost_fn, goal=None):
 
 
 @_rustworkx_dispatch
-def all_pairs_bellman_ford_path_lengths(graph, edge_cost_fn):
+def all_pairs_bellman_ford_path_lengths(
+    graph, edge_cost_fn, num_threads=None, progress_fn=None, progress_interval=None
+):
     """For each node in the graph, calculates the lengths of the shortest paths to all others.
 
     This function will generate the shortest path lengths from all nodes in the
     graph using the Bellman-Ford algorithm. This function is multithreaded and will
     launch a thread pool with threads equal to the number of CPUs by
-    default. You can tune the number of threads with the ``RAYON_NUM_THREADS``
+    default. You can limit the number of threads with ``num_threads`` or
+    :func:`~rustworkx.set_num_threads`, or with the ``RAYON_NUM_THREADS``
     environment variable. For example, setting ``RAYON_NUM_THREADS=4`` would
     limit the thread pool to 4 threads.
 
@@ -1756,7 +3592,18 @@ def all_pairs_bellman_ford_path_lengths(graph, edge_cost_fn):
     :param edge_cost_fn: A callable object that acts as a weight function for
         an edge. It will accept a single positional argument, the edge's weight
         object and will return a float which will be used to represent the
-        weight/cost of the edge
+        weight/cost of the edge. It can also be a sequence or numpy array of
+        the edge weights indexed by edge index, which avoids a Python call per
+        edge.
+    :param int num_threads: The maximum number of threads to use. If it is not
+        specified the number set with :func:`~rustworkx.set_num_threads` is
+        used.
+    :param progress_fn: An optional callable which is called as
+        ``progress_fn(done, total)`` after every ``progress_interval`` of the
+        ``total`` source nodes are searched from. An exception raised by it
+        stops the computation and is raised from this function.
+    :param int progress_interval: The number of source nodes between the calls
+        to ``progress_fn``. By default it is called 100 times.
 
     :return: A read-only dictionary of path lengths. The keys are the source
         node indices and the values are a dict of the target node and the
@@ -1777,13 +3624,16 @@ def all_pairs_bellman_ford_path_lengths(graph, edge_cost_fn):
 
 
 @_rustworkx_dispatch
-def all_pairs_bellman_ford_shortest_paths(graph, edge_cost_fn):
+def all_pairs_bellman_ford_shortest_paths(
+    graph, edge_cost_fn, num_threads=None, progress_fn=None, progress_interval=None
+):
     """For each node in the graph, finds the shortest paths to all others.
 
     This function will generate the shortest path from all nodes in the graph
     using the Bellman-Ford algorithm. This function is multithreaded and will run
     launch a thread pool with threads equal to the number of CPUs by default.
-    You can tune the number of threads with the ``RAYON_NUM_THREADS``
+    You can limit the number of threads with ``num_threads`` or
+    :func:`~rustworkx.set_num_threads`, or with the ``RAYON_NUM_THREADS``
     environment variable. For example, setting ``RAYON_NUM_THREADS=4`` would
     limit the thread pool to 4 threads.
 
@@ -1792,7 +3642,18 @@ def all_pairs_bellman_ford_shortest_paths(graph, edge_cost_fn):
     :param edge_cost_fn: A callable object that acts as a weight function for
         an edge. It will accept a single positional argument, the edge's weight
         object and will return a float which will be used to represent the
-        weight/cost of the edge
+        weight/cost of the edge. It can also be a sequence or numpy array of
+        the edge weights indexed by edge index, which avoids a Python call per
+        edge.
+    :param int num_threads: The maximum number of threads to use. If it is not
+        specified the number set with :func:`~rustworkx.set_num_threads` is
+        used.
+    :param progress_fn: An optional callable which is called as
+        ``progress_fn(done, total)`` after every ``progress_interval`` of the
+        ``total`` source nodes are searched from. An exception raised by it
+        stops the computation and is raised from this function.
+    :param int progress_interval: The number of source nodes between the calls
+        to ``progress_fn``. By default it is called 100 times.
 
     :return: A read-only dictionary of paths. The keys are source node
         indices and the values are a dict of target node indices and a list
@@ -1911,6 +3772,110 @@ def is_bipartite(graph):
     """
 
 
+@_rustworkx_dispatch
+def bipartite_sets(graph):
+    """Split the nodes of a bipartite graph into its two sides
+
+    A graph is bipartite if its nodes can be split into two sets such that
+    every edge joins a node of one set to a node of the other. The direction
+    of edges is ignored. If the graph isn't connected the split isn't unique,
+    and the node with the lowest index of every connected component is put
+    in the first set.
+
+    If the graph isn't bipartite the :class:`~.GraphNotBipartite` exception
+    that is raised has an ``odd_cycle`` attribute with the node indices of an
+    odd cycle of the graph, which proves it. The cycle closes with an edge
+    from its last node back to its first, and is a single node for a
+    self-loop.
+
+    :param graph: The graph to split. Can either be a
+        :class:`~rustworkx.PyGraph` or :class:`~rustworkx.PyDiGraph`.
+
+    :returns: A tuple of the two sets of node indices
+    :rtype: tuple[set[int], set[int]]
+    :raises GraphNotBipartite: If the graph isn't bipartite
+
+    .. jupyter-execute::
+
+      import rustworkx as rx
+
+      graph = rx.generators.cycle_graph(5)
+      try:
+          rx.bipartite_sets(graph)
+      except rx.GraphNotBipartite as err:
+          print(err.odd_cycle)
+    """
+
+
+@_rustworkx_dispatch
+def edge_disjoint_paths(graph, source, target, /, k=None):
+    """Find a largest set of edge-disjoint paths between two nodes
+
+    The paths don't share any edge, but they can share nodes. The number of
+    paths found is the size of a minimum edge cut from ``source`` to
+    ``target`` (unless it's limited by ``k``), and among the sets of
+    edge-disjoint paths of that size the returned one has the smallest total
+    number of edges. The paths of a :class:`~rustworkx.PyDiGraph` follow the
+    direction of edges.
+
+    :param graph: The graph to find the paths in. Can either be a
+        :class:`~rustworkx.PyGraph` or :class:`~rustworkx.PyDiGraph`.
+    :param int source: The node index the paths start at
+    :param int target: The node index the paths end at
+    :param int k: The largest number of paths to find. If it's not specified
+        as many paths as possible are found.
+
+    :returns: A list of paths, each a list of node indices from ``source``
+        to ``target``
+    :rtype: list[list[int]]
+    :raises InvalidNode: If ``source`` or ``target`` isn't in the graph
+    :raises ValueError: If ``source`` and ``target`` are the same node
+
+    .. jupyter-execute::
+
+      import rustworkx as rx
+
+      graph = rx.generators.grid_graph(3, 3)
+      print(rx.edge_disjoint_paths(graph, 0, 8))
+    """
+    raise TypeError("Invalid Input Type %s for graph" % type(graph))
+
+
+@_rustworkx_dispatch
+def node_disjoint_paths(graph, source, target, /, k=None):
+    """Find a largest set of node-disjoint paths between two nodes
+
+    The paths don't share any node other than ``source`` and ``target``. The
+    number of paths found is the size of a minimum node cut from ``source``
+    to ``target``, plus one if they're adjacent (unless it's limited by
+    ``k``), and among the sets of node-disjoint paths of that size the
+    returned one has the smallest total number of edges. The paths of a
+    :class:`~rustworkx.PyDiGraph` follow the direction of edges. If there are
+    parallel edges from ``source`` to ``target`` only one path uses them.
+
+    :param graph: The graph to find the paths in. Can either be a
+        :class:`~rustworkx.PyGraph` or :class:`~rustworkx.PyDiGraph`.
+    :param int source: The node index the paths start at
+    :param int target: The node index the paths end at
+    :param int k: The largest number of paths to find. If it's not specified
+        as many paths as possible are found.
+
+    :returns: A list of paths, each a list of node indices from ``source``
+        to ``target``
+    :rtype: list[list[int]]
+    :raises InvalidNode: If ``source`` or ``target`` isn't in the graph
+    :raises ValueError: If ``source`` and ``target`` are the same node
+
+    .. jupyter-execute::
+
+      import rustworkx as rx
+
+      graph = rx.generators.grid_graph(3, 3)
+      print(rx.node_disjoint_paths(graph, 0, 8))
+    """
+    raise TypeError("Invalid Input Type %s for graph" % type(graph))
+
+
 @_rustworkx_dispatch
 def floyd_warshall_successor_and_distance(
     graph,
@@ -1943,7 +3908,9 @@ def floyd_warshall_successor_and_distance(
 
             floyd_warshall_successor_and_distance(graph, weight_fn=float)
 
-        to cast the edge object as a float as the weight.
+        to cast the edge object as a float as the weight. It can also be a
+        sequence or numpy array of the edge weights indexed by edge index,
+        which avoids a Python call per edge.
     :param as_undirected: If set to true each directed edge will be treated as
         bidirectional/undirected.
     :param int parallel_threshold: The number of nodes to execute
@@ -1994,7 +3961,9 @@ def all_shortest_paths(
     :param int target: A target to find paths to
     :param weight_fn: An optional weight function for an edge. It will accept
         a single argument, the edge's weight object and will return a float which
-        will be used to represent the weight/cost of the edge
+        will be used to represent the weight/cost of the edge.
+        It can also be a sequence or numpy array of the edge weights indexed
+        by edge index, which avoids a Python call per edge.
     :param float default_weight: If ``weight_fn`` isn't specified this optional
         float value will be used for the weight/cost of each edge.
 
@@ -2006,3 +3975,368 @@ def all_shortest_paths(
 
     """
     raise TypeError("Invalid Input Type %s for graph" % type(graph))
+
+
+@_rustworkx_dispatch
+def node_boundary(graph, nodes, targets=None):
+    """Compute the node boundary of a set of nodes.
+
+    The node boundary of a set of nodes ``S`` is the set of nodes outside of
+    ``S`` that are adjacent to a node of ``S``. If ``targets`` is set, only
+    the nodes of ``targets`` that are adjacent to a node of ``S`` and not in
+    ``S`` are in the boundary. For a :class:`~rustworkx.PyDiGraph` only the
+    edges from the nodes of ``S`` are followed.
+
+    :param graph: The input graph. Can either be a
+        :class:`~rustworkx.PyGraph` or :class:`~rustworkx.PyDiGraph`.
+    :param list nodes: The node indices of ``S``.
+    :param list targets: An optional list of node indices to restrict the
+        boundary to.
+
+    :returns: The node indices of the boundary, in the order they are found
+        from the nodes of ``S`` in the order of ``nodes``.
+    :rtype: NodeIndices
+    :raises IndexError: when a node index isn't a node of the graph.
+    """
+    raise TypeError("Invalid Input Type %s for graph" % type(graph))
+
+
+@_rustworkx_dispatch
+def edge_boundary(graph, nodes, targets=None):
+    """Compute the edge boundary of a set of nodes.
+
+    The edge boundary of a set of nodes ``S`` is the set of edges with one
+    endpoint in ``S`` and the other one outside of ``S``. If ``targets`` is
+    set, only the edges whose other endpoint is in ``targets`` (and not in
+    ``S``) are in the boundary. For a :class:`~rustworkx.PyDiGraph` only the
+    edges from the nodes of ``S`` are in the boundary.
+
+    :param graph: The input graph. Can either be a
+        :class:`~rustworkx.PyGraph` or :class:`~rustworkx.PyDiGraph`.
+    :param list nodes: The node indices of ``S``.
+    :param list targets: An optional list of node indices to restrict the
+        boundary to.
+
+    :returns: The edges of the boundary, with their endpoint in ``S`` first,
+        grouped by that endpoint in the order of ``nodes``.
+    :rtype: EdgeList
+    :raises IndexError: when a node index isn't a node of the graph.
+    """
+    raise TypeError("Invalid Input Type %s for graph" % type(graph))
+
+
+@_rustworkx_dispatch
+def cut_size(graph, nodes, targets=None, weight_fn=None, default_weight=1.0):
+    """Compute the size of the cut between two sets of nodes.
+
+    The size of the cut between the sets of nodes ``S`` and ``T`` is the
+    total weight of the edges with one endpoint in ``S`` and the other one in
+    ``T``, which is everything outside of ``S`` if ``targets`` isn't set. For
+    a :class:`~rustworkx.PyDiGraph` the edges in both directions are counted.
+
+    :param graph: The input graph. Can either be a
+        :class:`~rustworkx.PyGraph` or :class:`~rustworkx.PyDiGraph`.
+    :param list nodes: The node indices of ``S``.
+    :param list targets: The optional node indices of ``T``.
+    :param weight_fn: An optional weight function for an edge. It will accept
+        a single argument, the edge's weight object and will return a float
+        which will be used to represent the weight of the edge. It must be
+        non-negative. It can also be a sequence or numpy array of the edge
+        weights indexed by edge index, which avoids a Python call per edge.
+    :param float default_weight: If ``weight_fn`` isn't specified this optional
+        float value will be used for the weight of each edge.
+
+    :returns: The size of the cut.
+    :rtype: float
+    :raises IndexError: when a node index isn't a node of the graph.
+    :raises ValueError: when an edge weight with NaN or negative value
+        is provided.
+    """
+    raise TypeError("Invalid Input Type %s for graph" % type(graph))
+
+
+@_rustworkx_dispatch
+def volume(graph, nodes, weight_fn=None, default_weight=1.0):
+    """Compute the volume of a set of nodes.
+
+    The volume of a set of nodes is the sum of their weighted degrees, where
+    a self-loop counts twice. For a :class:`~rustworkx.PyDiGraph` only the
+    out-degrees are used. With :func:`~rustworkx.cut_size` this gives the
+    conductance of a set of nodes ``S`` of a :class:`~rustworkx.PyGraph`::
+
+        complement = [n for n in graph.node_indices() if n not in S]
+        conductance = rx.cut_size(graph, S) / min(
+            rx.volume(graph, S), rx.volume(graph, complement)
+        )
+
+    :param graph: The input graph. Can either be a
+        :class:`~rustworkx.PyGraph` or :class:`~rustworkx.PyDiGraph`.
+    :param list nodes: The node indices of the set. Duplicates are ignored.
+    :param weight_fn: An optional weight function for an edge. It will accept
+        a single argument, the edge's weight object and will return a float
+        which will be used to represent the weight of the edge. It must be
+        non-negative. It can also be a sequence or numpy array of the edge
+        weights indexed by edge index, which avoids a Python call per edge.
+    :param float default_weight: If ``weight_fn`` isn't specified this optional
+        float value will be used for the weight of each edge.
+
+    :returns: The volume of the set of nodes.
+    :rtype: float
+    :raises IndexError: when a node index isn't a node of the graph.
+    :raises ValueError: when an edge weight with NaN or negative value
+        is provided.
+    """
+    raise TypeError("Invalid Input Type %s for graph" % type(graph))
+
+
+@_rustworkx_dispatch
+def feedback_vertex_set(graph, weight_fn=None):
+    """Find a small feedback vertex set of a graph.
+
+    A feedback vertex set is a set of nodes whose removal makes the graph
+    acyclic. Finding one of minimum total weight is NP-hard. For a
+    :class:`~rustworkx.PyGraph` this uses the local ratio algorithm of Bafna,
+    Berman and Fujito, which finds a set whose weight is at most twice the
+    minimum. For a :class:`~rustworkx.PyDiGraph` it uses a greedy heuristic
+    without an approximation guarantee. In both cases the set is minimal:
+    removing any node from it leaves a cycle.
+
+    To remove edges instead of nodes from a :class:`~rustworkx.PyDiGraph` see
+    :func:`~rustworkx.feedback_arc_set`.
+
+    :param graph: The input graph. Can either be a
+        :class:`~rustworkx.PyGraph` or :class:`~rustworkx.PyDiGraph`.
+    :param weight_fn: An optional callable that will be passed the node's
+        payload object and is expected to return a non-negative ``float``
+        weight for that node. If this is not specified every node has a
+        weight of 1.
+
+    :returns: The indices of the nodes in the feedback vertex set
+    :rtype: NodeIndices
+    :raises ValueError: when a node weight with NaN or negative value
+        is provided.
+
+    .. jupyter-execute::
+
+      import rustworkx as rx
+
+      graph = rx.generators.generalized_petersen_graph(5, 2)
+      print(rx.feedback_vertex_set(graph))
+    """
+    raise TypeError("Invalid Input Type %s for graph" % type(graph))
+
+
+@_rustworkx_dispatch
+def percolation(graph, strategy="random", remove_edges=False, seed=None, threshold=0.1):
+    """Simulate the removal of the nodes or edges of a graph and track the
+    size of its largest connected component.
+
+    The nodes (or edges) are removed one at a time, in an order given by a
+    strategy or by the caller, and the size of the largest connected
+    component, the giant component, is reported after every removal. This
+    measures how robust the connectivity of a network is to random failures
+    or targeted attacks. The whole curve is computed in near linear time by
+    adding the nodes or edges back in reverse order with a union-find data
+    structure. For a :class:`~rustworkx.PyDiGraph` the weakly connected
+    components are used.
+
+    The strategies are:
+
+    * ``"random"``: a uniformly random order.
+    * ``"degree"``: by decreasing degree. An edge is ranked by the product
+      of the degrees of its endpoints.
+    * ``"betweenness"``: by decreasing betweenness centrality, or edge
+      betweenness centrality when removing edges.
+
+    The degree and betweenness are computed once for the original graph.
+
+    :param graph: The input graph. Can either be a
+        :class:`~rustworkx.PyGraph` or :class:`~rustworkx.PyDiGraph`.
+    :param strategy: The name of the strategy that orders the nodes or
+        edges, or a list of node (or edge) indices in the order they are
+        removed, which doesn't have to contain every node (or edge) of the
+        graph. Defaults to ``"random"``.
+    :param bool remove_edges: If set to ``True`` the edges of the graph are
+        removed instead of its nodes. Defaults to ``False``.
+    :param int seed: An optional seed for the random number generator used
+        by the ``"random"`` strategy.
+    :param float threshold: The fraction of the nodes of the graph the giant
+        component has at most for the graph to count as broken up, which
+        defines the critical fraction. Defaults to ``0.1``.
+
+    :returns: A tuple of the order the nodes (or edges) were removed in, the
+        size of the giant component after removing the first ``k`` of them
+        for every ``k`` from ``0`` up to the length of the order, and the
+        critical fraction: the smallest fraction of the nodes (or edges) of
+        the graph whose removal leaves a giant component of at most
+        ``threshold`` times the number of nodes. The critical fraction is
+        ``None`` if the order never gets there.
+    :rtype: tuple[list[int], list[int], float | None]
+    :raises ValueError: If ``strategy`` isn't a valid strategy name or
+        ``threshold`` isn't between 0 and 1
+    :raises InvalidNode: If ``strategy`` contains a node that is not in the
+        graph
+    :raises IndexError: If ``strategy`` contains an edge that is not in the
+        graph
+
+    .. jupyter-execute::
+
+      import rustworkx as rx
+
+      graph = rx.generators.barbell_graph(4, 2)
+      order, sizes, critical = rx.percolation(graph, strategy="betweenness")
+      print(sizes)
+      print(critical)
+    """
+    raise TypeError("Invalid Input Type %s for graph" % type(graph))
+
+
+@_rustworkx_dispatch
+def sample_nodes(graph, num_nodes, seed=None):
+    """Sample nodes of a graph uniformly at random
+
+    Every set of ``num_nodes`` nodes is equally likely to be sampled, and the
+    induced subgraph of the sampled nodes is returned.
+
+    :param graph: The graph to sample. Can either be a
+        :class:`~rustworkx.PyGraph` or :class:`~rustworkx.PyDiGraph`.
+    :param int num_nodes: The number of nodes to sample
+    :param int seed: An optional seed for the random number generator
+
+    :returns: A tuple of the induced subgraph of the sampled nodes and a
+        mapping from the node indices of the subgraph to the node indices of
+        ``graph``. The sampled nodes are in the same order in the subgraph as
+        in ``graph``.
+    :rtype: tuple[PyGraph | PyDiGraph, NodeMap]
+    :raises ValueError: If the graph has fewer than ``num_nodes`` nodes
+
+    .. jupyter-execute::
+
+      import rustworkx as rx
+
+      graph = rx.generators.grid_graph(10, 10)
+      sample, node_map = rx.sample_nodes(graph, 20, seed=42)
+      print(sample.num_nodes(), sample.num_edges())
+      print(node_map)
+    """
+    raise TypeError("Invalid Input Type %s for graph" % type(graph))
+
+
+@_rustworkx_dispatch
+def sample_edges(graph, num_edges, seed=None):
+    """Sample edges of a graph uniformly at random
+
+    Every set of ``num_edges`` edges is equally likely to be sampled, and the
+    subgraph induced by the endpoints of the sampled edges is returned. It
+    contains every edge between those endpoints, not only the sampled edges,
+    which is known as induced edge sampling. Nodes of high degree are more
+    likely to be in the sample than with :func:`~rustworkx.sample_nodes`.
+
+    :param graph: The graph to sample. Can either be a
+        :class:`~rustworkx.PyGraph` or :class:`~rustworkx.PyDiGraph`.
+    :param int num_edges: The number of edges to sample
+    :param int seed: An optional seed for the random number generator
+
+    :returns: A tuple of the induced subgraph of the sampled nodes and a
+        mapping from the node indices of the subgraph to the node indices of
+        ``graph``. The sampled nodes are in the same order in the subgraph as
+        in ``graph``.
+    :rtype: tuple[PyGraph | PyDiGraph, NodeMap]
+    :raises ValueError: If the graph has fewer than ``num_edges`` edges
+
+    .. jupyter-execute::
+
+      import rustworkx as rx
+
+      graph = rx.generators.grid_graph(10, 10)
+      sample, node_map = rx.sample_edges(graph, 20, seed=42)
+      print(sample.num_nodes(), sample.num_edges())
+      print(node_map)
+    """
+    raise TypeError("Invalid Input Type %s for graph" % type(graph))
+
+
+@_rustworkx_dispatch
+def random_walk_sample(graph, num_nodes, restart_probability=0.15, seed=None):
+    """Sample nodes of a graph with a random walk with restarts
+
+    The walk starts at a random node, and at every step either goes back to
+    its starting node with probability ``restart_probability`` or moves to a
+    random neighbor of its current node, following the direction of edges in
+    a :class:`~rustworkx.PyDiGraph`. A walk that has no neighbor to move to
+    goes back to its starting node, and a walk that hasn't visited a new node
+    in 100 steps starts again from a random node it hasn't visited. The
+    induced subgraph of the first ``num_nodes`` nodes visited is returned.
+    Unlike uniform sampling the sample is mostly connected, so it preserves
+    the local structure of the graph.
+
+    :param graph: The graph to sample. Can either be a
+        :class:`~rustworkx.PyGraph` or :class:`~rustworkx.PyDiGraph`.
+    :param int num_nodes: The number of nodes to sample
+    :param float restart_probability: The probability of going back to the
+        starting node at every step. Defaults to ``0.15``.
+    :param int seed: An optional seed for the random number generator
+
+    :returns: A tuple of the induced subgraph of the sampled nodes and a
+        mapping from the node indices of the subgraph to the node indices of
+        ``graph``. The sampled nodes are in the same order in the subgraph as
+        in ``graph``.
+    :rtype: tuple[PyGraph | PyDiGraph, NodeMap]
+    :raises ValueError: If the graph has fewer than ``num_nodes`` nodes or
+        ``restart_probability`` isn't in ``[0, 1]``
+
+    .. jupyter-execute::
+
+      import rustworkx as rx
+
+      graph = rx.generators.grid_graph(10, 10)
+      sample, node_map = rx.random_walk_sample(graph, 20, seed=42)
+      print(sample.num_nodes(), sample.num_edges())
+      print(node_map)
+    """
+    raise TypeError("Invalid Input Type %s for graph" % type(graph))
+
+
+@_rustworkx_dispatch
+def forest_fire_sample(graph, num_nodes, burn_probability=0.7, seed=None):
+    """Sample nodes of a graph with a forest fire
+
+    The fire starts at a random node. Every burning node sets fire to a random
+    number of its neighbors that aren't burned yet, following the direction
+    of edges in a :class:`~rustworkx.PyDiGraph`, drawn from a geometric
+    distribution with mean ``p / (1 - p)`` for the burn probability ``p``.
+    When the fire dies out it starts again from a random node that isn't
+    burned. The induced subgraph of the first ``num_nodes`` nodes burned is
+    returned. Forest fire samples preserve many properties of the graph, such
+    as its degree distribution and clustering [1]_.
+
+    :param graph: The graph to sample. Can either be a
+        :class:`~rustworkx.PyGraph` or :class:`~rustworkx.PyDiGraph`.
+    :param int num_nodes: The number of nodes to sample
+    :param float burn_probability: The forward burning probability, which
+        must be less than ``1``. Defaults to ``0.7``.
+    :param int seed: An optional seed for the random number generator
+
+    :returns: A tuple of the induced subgraph of the sampled nodes and a
+        mapping from the node indices of the subgraph to the node indices of
+        ``graph``. The sampled nodes are in the same order in the subgraph as
+        in ``graph``.
+    :rtype: tuple[PyGraph | PyDiGraph, NodeMap]
+    :raises ValueError: If the graph has fewer than ``num_nodes`` nodes or
+        ``burn_probability`` isn't in ``[0, 1)``
+
+    .. jupyter-execute::
+
+      import rustworkx as rx
+
+      graph = rx.generators.grid_graph(10, 10)
+      sample, node_map = rx.forest_fire_sample(graph, 20, seed=42)
+      print(sample.num_nodes(), sample.num_edges())
+      print(node_map)
+
+    .. [1] Leskovec, Jure, and Christos Faloutsos.
+       "Sampling from large graphs."
+       Proceedings of the 12th ACM SIGKDD International Conference on
+       Knowledge Discovery and Data Mining (2006): 631-636.
+    """
+    raise TypeError("Invalid Input Type %s for graph" % type(graph))
@@ -11,7 +11,7 @@
 
 import numpy as np
 
-from typing import Generic, TypeVar, Any, Callable, Iterator, overload, Sequence
+from typing import Generic, TypeVar, Any, Callable, Hashable, Iterator, overload, Sequence
 
 # Re-Exports of rust native functions in rustworkx.rustworkx
 # To workaround limitations in mypy around re-exporting objects from the inner
@@ -39,21 +39,76 @@ from .rustworkx import ColoringStrategy as ColoringStrategy
 from .rustworkx import digraph_maximum_bisimulation as digraph_maximum_bisimulation
 from .rustworkx import digraph_cartesian_product as digraph_cartesian_product
 from .rustworkx import graph_cartesian_product as graph_cartesian_product
+from .rustworkx import digraph_lexicographic_product as digraph_lexicographic_product
+from .rustworkx import graph_lexicographic_product as graph_lexicographic_product
+from .rustworkx import digraph_strong_product as digraph_strong_product
+from .rustworkx import graph_strong_product as graph_strong_product
 from .rustworkx import digraph_eigenvector_centrality as digraph_eigenvector_centrality
 from .rustworkx import graph_eigenvector_centrality as graph_eigenvector_centrality
 from .rustworkx import digraph_betweenness_centrality as digraph_betweenness_centrality
 from .rustworkx import graph_betweenness_centrality as graph_betweenness_centrality
 from .rustworkx import digraph_edge_betweenness_centrality as digraph_edge_betweenness_centrality
 from .rustworkx import graph_edge_betweenness_centrality as graph_edge_betweenness_centrality
+from .rustworkx import set_num_threads as set_num_threads
+from .rustworkx import get_num_threads as get_num_threads
 from .rustworkx import digraph_closeness_centrality as digraph_closeness_centrality
 from .rustworkx import graph_closeness_centrality as graph_closeness_centrality
+from .rustworkx import digraph_harmonic_centrality as digraph_harmonic_centrality
+from .rustworkx import graph_harmonic_centrality as graph_harmonic_centrality
 from .rustworkx import digraph_katz_centrality as digraph_katz_centrality
 from .rustworkx import graph_katz_centrality as graph_katz_centrality
+from .rustworkx import digraph_simrank_similarity as digraph_simrank_similarity
+from .rustworkx import graph_simrank_similarity as graph_simrank_similarity
+from .rustworkx import digraph_weighted_simrank_similarity as digraph_weighted_simrank_similarity
+from .rustworkx import graph_weighted_simrank_similarity as graph_weighted_simrank_similarity
+from .rustworkx import digraph_katz_similarity as digraph_katz_similarity
+from .rustworkx import graph_katz_similarity as graph_katz_similarity
+from .rustworkx import digraph_random_walk_with_restart as digraph_random_walk_with_restart
+from .rustworkx import graph_random_walk_with_restart as graph_random_walk_with_restart
+from .rustworkx import weisfeiler_lehman_subtree_features as weisfeiler_lehman_subtree_features
+from .rustworkx import weisfeiler_lehman_subtree_kernel as weisfeiler_lehman_subtree_kernel
+from .rustworkx import (
+    digraph_recursive_structural_features as digraph_recursive_structural_features,
+)
+from .rustworkx import graph_recursive_structural_features as graph_recursive_structural_features
+from .rustworkx import digraph_laplacian_matrix as digraph_laplacian_matrix
+from .rustworkx import graph_laplacian_matrix as graph_laplacian_matrix
+from .rustworkx import (
+    digraph_normalized_laplacian_matrix as digraph_normalized_laplacian_matrix,
+)
+from .rustworkx import graph_normalized_laplacian_matrix as graph_normalized_laplacian_matrix
+from .rustworkx import directed_laplacian_matrix as directed_laplacian_matrix
+from .rustworkx import algebraic_connectivity as algebraic_connectivity
+from .rustworkx import fiedler_vector as fiedler_vector
+from .rustworkx import spectral_partition as spectral_partition
+from .rustworkx import spectral_sparsify as spectral_sparsify
+from .rustworkx import jaccard_coefficient as jaccard_coefficient
+from .rustworkx import adamic_adar_index as adamic_adar_index
+from .rustworkx import resource_allocation_index as resource_allocation_index
+from .rustworkx import preferential_attachment as preferential_attachment
+from .rustworkx import common_neighbor_centrality as common_neighbor_centrality
+from .rustworkx import cn_soundarajan_hopcroft as cn_soundarajan_hopcroft
+from .rustworkx import ra_index_soundarajan_hopcroft as ra_index_soundarajan_hopcroft
+from .rustworkx import within_inter_cluster as within_inter_cluster
 from .rustworkx import graph_greedy_color as graph_greedy_color
 from .rustworkx import graph_greedy_edge_color as graph_greedy_edge_color
 from .rustworkx import graph_is_bipartite as graph_is_bipartite
 from .rustworkx import connected_subgraphs as connected_subgraphs
+from .rustworkx import digraph_node_boundary as digraph_node_boundary
+from .rustworkx import graph_node_boundary as graph_node_boundary
+from .rustworkx import digraph_edge_boundary as digraph_edge_boundary
+from .rustworkx import graph_edge_boundary as graph_edge_boundary
+from .rustworkx import digraph_cut_size as digraph_cut_size
+from .rustworkx import graph_cut_size as graph_cut_size
+from .rustworkx import digraph_volume as digraph_volume
+from .rustworkx import graph_volume as graph_volume
 from .rustworkx import digraph_is_bipartite as digraph_is_bipartite
+from .rustworkx import graph_bipartite_sets as graph_bipartite_sets
+from .rustworkx import digraph_bipartite_sets as digraph_bipartite_sets
+from .rustworkx import graph_edge_disjoint_paths as graph_edge_disjoint_paths
+from .rustworkx import digraph_edge_disjoint_paths as digraph_edge_disjoint_paths
+from .rustworkx import graph_node_disjoint_paths as graph_node_disjoint_paths
+from .rustworkx import digraph_node_disjoint_paths as digraph_node_disjoint_paths
 from .rustworkx import graph_two_color as graph_two_color
 from .rustworkx import digraph_two_color as digraph_two_color
 from .rustworkx import graph_misra_gries_edge_color as graph_misra_gries_edge_color
@@ -75,6 +130,19 @@ from .rustworkx import bridges as bridges
 from .rustworkx import biconnected_components as biconnected_components
 from .rustworkx import chain_decomposition as chain_decomposition
 from .rustworkx import digraph_find_cycle as digraph_find_cycle
+from .rustworkx import feedback_arc_set as feedback_arc_set
+from .rustworkx import graph_feedback_vertex_set as graph_feedback_vertex_set
+from .rustworkx import digraph_feedback_vertex_set as digraph_feedback_vertex_set
+from .rustworkx import graph_percolation as graph_percolation
+from .rustworkx import digraph_percolation as digraph_percolation
+from .rustworkx import graph_sample_nodes as graph_sample_nodes
+from .rustworkx import digraph_sample_nodes as digraph_sample_nodes
+from .rustworkx import graph_sample_edges as graph_sample_edges
+from .rustworkx import digraph_sample_edges as digraph_sample_edges
+from .rustworkx import graph_random_walk_sample as graph_random_walk_sample
+from .rustworkx import digraph_random_walk_sample as digraph_random_walk_sample
+from .rustworkx import graph_forest_fire_sample as graph_forest_fire_sample
+from .rustworkx import digraph_forest_fire_sample as digraph_forest_fire_sample
 from .rustworkx import digraph_complement as digraph_complement
 from .rustworkx import graph_complement as graph_complement
 from .rustworkx import digraph_all_simple_paths as digraph_all_simple_paths
@@ -85,6 +153,12 @@ from .rustworkx import digraph_longest_simple_path as digraph_longest_simple_pat
 from .rustworkx import graph_longest_simple_path as graph_longest_simple_path
 from .rustworkx import digraph_core_number as digraph_core_number
 from .rustworkx import graph_core_number as graph_core_number
+from .rustworkx import digraph_s_core_number as digraph_s_core_number
+from .rustworkx import graph_s_core_number as graph_s_core_number
+from .rustworkx import digraph_onion_decomposition as digraph_onion_decomposition
+from .rustworkx import graph_onion_decomposition as graph_onion_decomposition
+from .rustworkx import truss_number as truss_number
+from .rustworkx import k_truss as k_truss
 from .rustworkx import stoer_wagner_min_cut as stoer_wagner_min_cut
 from .rustworkx import simple_cycles as simple_cycles
 from .rustworkx import digraph_isolates as digraph_isolates
@@ -95,19 +169,31 @@ from .rustworkx import dag_longest_path as dag_longest_path
 from .rustworkx import dag_longest_path_length as dag_longest_path_length
 from .rustworkx import dag_weighted_longest_path as dag_weighted_longest_path
 from .rustworkx import dag_weighted_longest_path_length as dag_weighted_longest_path_length
+from .rustworkx import dag_critical_path as dag_critical_path
 from .rustworkx import is_directed_acyclic_graph as is_directed_acyclic_graph
 from .rustworkx import topological_sort as topological_sort
+from .rustworkx import all_topological_sorts as all_topological_sorts
+from .rustworkx import count_topological_sorts as count_topological_sorts
 from .rustworkx import topological_generations as topological_generations
 from .rustworkx import lexicographical_topological_sort as lexicographical_topological_sort
 from .rustworkx import transitive_reduction as transitive_reduction
+from .rustworkx import transitive_closure_dag as transitive_closure_dag
+from .rustworkx import dag_minimum_chain_decomposition as dag_minimum_chain_decomposition
+from .rustworkx import dag_maximum_antichain as dag_maximum_antichain
 from .rustworkx import layers as layers
 from .rustworkx import TopologicalSorter as TopologicalSorter
+from .rustworkx import ReachabilityIndex as ReachabilityIndex
 from .rustworkx import digraph_is_isomorphic as digraph_is_isomorphic
 from .rustworkx import graph_is_isomorphic as graph_is_isomorphic
+from .rustworkx import digraph_distinguishing_invariant as digraph_distinguishing_invariant
+from .rustworkx import graph_distinguishing_invariant as graph_distinguishing_invariant
+from .rustworkx import digraph_canonical_hash as digraph_canonical_hash
+from .rustworkx import graph_canonical_hash as graph_canonical_hash
 from .rustworkx import digraph_is_subgraph_isomorphic as digraph_is_subgraph_isomorphic
 from .rustworkx import graph_is_subgraph_isomorphic as graph_is_subgraph_isomorphic
 from .rustworkx import digraph_vf2_mapping as digraph_vf2_mapping
 from .rustworkx import graph_vf2_mapping as graph_vf2_mapping
+from .rustworkx import digraph_rewrite as digraph_rewrite
 from .rustworkx import digraph_bipartite_layout as digraph_bipartite_layout
 from .rustworkx import graph_bipartite_layout as graph_bipartite_layout
 from .rustworkx import digraph_circular_layout as digraph_circular_layout
@@ -120,13 +206,23 @@ from .rustworkx import digraph_spiral_layout as digraph_spiral_layout
 from .rustworkx import graph_spiral_layout as graph_spiral_layout
 from .rustworkx import digraph_spring_layout as digraph_spring_layout
 from .rustworkx import graph_spring_layout as graph_spring_layout
+from .rustworkx import digraph_line_graph as digraph_line_graph
 from .rustworkx import graph_line_graph as graph_line_graph
+from .rustworkx import digraph_quotient_graph as digraph_quotient_graph
+from .rustworkx import graph_quotient_graph as graph_quotient_graph
+from .rustworkx import bipartite_projection as bipartite_projection
 from .rustworkx import hits as hits
 from .rustworkx import pagerank as pagerank
 from .rustworkx import max_weight_matching as max_weight_matching
 from .rustworkx import is_matching as is_matching
 from .rustworkx import is_maximal_matching as is_maximal_matching
 from .rustworkx import is_planar as is_planar
+from .rustworkx import is_minor_of as is_minor_of
+from .rustworkx import treewidth_min_degree as treewidth_min_degree
+from .rustworkx import treewidth_min_fill_in as treewidth_min_fill_in
+from .rustworkx import tree_decomposition as tree_decomposition
+from .rustworkx import modular_decomposition as modular_decomposition
+from .rustworkx import is_cograph as is_cograph
 from .rustworkx import directed_gnm_random_graph as directed_gnm_random_graph
 from .rustworkx import undirected_gnm_random_graph as undirected_gnm_random_graph
 from .rustworkx import directed_gnp_random_graph as directed_gnp_random_graph
@@ -144,6 +240,8 @@ from .rustworkx import digraph_node_link_json as digraph_node_link_json
 from .rustworkx import graph_node_link_json as graph_node_link_json
 from .rustworkx import from_node_link_json_file as from_node_link_json_file
 from .rustworkx import parse_node_link_json as parse_node_link_json
+from .rustworkx import digraph_to_svg as digraph_to_svg
+from .rustworkx import graph_to_svg as graph_to_svg
 from .rustworkx import digraph_bellman_ford_shortest_paths as digraph_bellman_ford_shortest_paths
 from .rustworkx import graph_bellman_ford_shortest_paths as graph_bellman_ford_shortest_paths
 from .rustworkx import (
@@ -158,6 +256,8 @@ from .rustworkx import (
     digraph_dijkstra_shortest_path_lengths as digraph_dijkstra_shortest_path_lengths,
 )
 from .rustworkx import graph_dijkstra_shortest_path_lengths as graph_dijkstra_shortest_path_lengths
+from .rustworkx import digraph_voronoi_cells as digraph_voronoi_cells
+from .rustworkx import graph_voronoi_cells as graph_voronoi_cells
 from .rustworkx import (
     digraph_all_pairs_bellman_ford_path_lengths as digraph_all_pairs_bellman_ford_path_lengths,
 )
@@ -176,6 +276,12 @@ from .rustworkx import (
 from .rustworkx import (
     graph_all_pairs_dijkstra_path_lengths as graph_all_pairs_dijkstra_path_lengths,
 )
+from .rustworkx import (
+    digraph_all_pairs_dijkstra_path_lengths_csr as digraph_all_pairs_dijkstra_path_lengths_csr,
+)
+from .rustworkx import (
+    graph_all_pairs_dijkstra_path_lengths_csr as graph_all_pairs_dijkstra_path_lengths_csr,
+)
 from .rustworkx import (
     digraph_all_pairs_dijkstra_shortest_paths as digraph_all_pairs_dijkstra_shortest_paths,
 )
@@ -219,6 +325,65 @@ from .rustworkx import graph_tensor_product as graph_tensor_product
 from .rustworkx import graph_token_swapper as graph_token_swapper
 from .rustworkx import digraph_transitivity as digraph_transitivity
 from .rustworkx import graph_transitivity as graph_transitivity
+from .rustworkx import digraph_graph_summary as digraph_graph_summary
+from .rustworkx import graph_graph_summary as graph_graph_summary
+from .rustworkx import GraphSummary as GraphSummary
+from .rustworkx import (
+    digraph_degree_assortativity_coefficient as digraph_degree_assortativity_coefficient,
+)
+from .rustworkx import (
+    graph_degree_assortativity_coefficient as graph_degree_assortativity_coefficient,
+)
+from .rustworkx import (
+    digraph_attribute_assortativity_coefficient as digraph_attribute_assortativity_coefficient,
+)
+from .rustworkx import (
+    graph_attribute_assortativity_coefficient as graph_attribute_assortativity_coefficient,
+)
+from .rustworkx import digraph_degree_histogram as digraph_degree_histogram
+from .rustworkx import graph_degree_histogram as graph_degree_histogram
+from .rustworkx import digraph_joint_degree_matrix as digraph_joint_degree_matrix
+from .rustworkx import graph_joint_degree_matrix as graph_joint_degree_matrix
+from .rustworkx import digraph_average_neighbor_degree as digraph_average_neighbor_degree
+from .rustworkx import graph_average_neighbor_degree as graph_average_neighbor_degree
+from .rustworkx import digraph_earliest_arrival_times as digraph_earliest_arrival_times
+from .rustworkx import graph_earliest_arrival_times as graph_earliest_arrival_times
+from .rustworkx import digraph_earliest_arrival_paths as digraph_earliest_arrival_paths
+from .rustworkx import graph_earliest_arrival_paths as graph_earliest_arrival_paths
+from .rustworkx import digraph_fastest_path_durations as digraph_fastest_path_durations
+from .rustworkx import graph_fastest_path_durations as graph_fastest_path_durations
+from .rustworkx import digraph_temporal_reachability as digraph_temporal_reachability
+from .rustworkx import graph_temporal_reachability as graph_temporal_reachability
+from .rustworkx import digraph_temporal_snapshot as digraph_temporal_snapshot
+from .rustworkx import graph_temporal_snapshot as graph_temporal_snapshot
+from .rustworkx import digraph_modularity as digraph_modularity
+from .rustworkx import graph_modularity as graph_modularity
+from .rustworkx import digraph_sum_parallel_edges as digraph_sum_parallel_edges
+from .rustworkx import graph_sum_parallel_edges as graph_sum_parallel_edges
+from .rustworkx import digraph_community_subgraphs as digraph_community_subgraphs
+from .rustworkx import graph_community_subgraphs as graph_community_subgraphs
+from .rustworkx import rich_club_coefficient as rich_club_coefficient
+from .rustworkx import digraph_rewire as digraph_rewire
+from .rustworkx import graph_rewire as graph_rewire
+from .rustworkx import digraph_motif_counts as digraph_motif_counts
+from .rustworkx import graph_motif_counts as graph_motif_counts
+from .rustworkx import digraph_motif_significance_profile as digraph_motif_significance_profile
+from .rustworkx import graph_motif_significance_profile as graph_motif_significance_profile
+from .rustworkx import triad_census as triad_census
+from .rustworkx import sigma as sigma
+from .rustworkx import omega as omega
+from .rustworkx import diameter as diameter
+from .rustworkx import radius as radius
+from .rustworkx import global_efficiency as global_efficiency
+from .rustworkx import local_efficiency as local_efficiency
+from .rustworkx import digraph_eccentricity as digraph_eccentricity
+from .rustworkx import graph_eccentricity as graph_eccentricity
+from .rustworkx import digraph_center as digraph_center
+from .rustworkx import graph_center as graph_center
+from .rustworkx import digraph_periphery as digraph_periphery
+from .rustworkx import graph_periphery as graph_periphery
+from .rustworkx import square_clustering as square_clustering
+from .rustworkx import weighted_clustering as weighted_clustering
 from .rustworkx import digraph_bfs_search as digraph_bfs_search
 from .rustworkx import graph_bfs_search as graph_bfs_search
 from .rustworkx import digraph_dfs_search as digraph_dfs_search
@@ -233,11 +398,32 @@ from .rustworkx import bfs_successors as bfs_successors
 from .rustworkx import descendants as descendants
 from .rustworkx import minimum_spanning_edges as minimum_spanning_edges
 from .rustworkx import minimum_spanning_tree as minimum_spanning_tree
+from .rustworkx import minimum_spanning_arborescence as minimum_spanning_arborescence
+from .rustworkx import random_spanning_tree as random_spanning_tree
+from .rustworkx import k_smallest_spanning_trees as k_smallest_spanning_trees
+from .rustworkx import greedy_spanner as greedy_spanner
+from .rustworkx import degree_constrained_spanning_tree as degree_constrained_spanning_tree
+from .rustworkx import tree_center as tree_center
+from .rustworkx import tree_centroid as tree_centroid
+from .rustworkx import centroid_decomposition as centroid_decomposition
+from .rustworkx import root_tree as root_tree
 from .rustworkx import steiner_tree as steiner_tree
 from .rustworkx import metric_closure as metric_closure
 from .rustworkx import digraph_union as digraph_union
 from .rustworkx import graph_union as graph_union
+from .rustworkx import digraph_union_with_mapping as digraph_union_with_mapping
+from .rustworkx import graph_union_with_mapping as graph_union_with_mapping
+from .rustworkx import digraph_intersection as digraph_intersection
+from .rustworkx import graph_intersection as graph_intersection
+from .rustworkx import digraph_difference as digraph_difference
+from .rustworkx import graph_difference as graph_difference
+from .rustworkx import digraph_symmetric_difference as digraph_symmetric_difference
+from .rustworkx import graph_symmetric_difference as graph_symmetric_difference
+from .rustworkx import digraph_full_join as digraph_full_join
+from .rustworkx import graph_full_join as graph_full_join
 from .rustworkx import NodeIndices as NodeIndices
+from .rustworkx import EdgeMap as EdgeMap
+from .rustworkx import UnionMapping as UnionMapping
 from .rustworkx import PathLengthMapping as PathLengthMapping
 from .rustworkx import PathMapping as PathMapping
 from .rustworkx import AllPairsPathLengthMapping as AllPairsPathLengthMapping
@@ -262,6 +448,7 @@ from .rustworkx import MultiplePathMapping as MultiplePathMapping
 from .rustworkx import AllPairsMultiplePathMapping as AllPairsMultiplePathMapping
 from .rustworkx import PyGraph as PyGraph
 from .rustworkx import PyDiGraph as PyDiGraph
+from .rustworkx import PyHypergraph as PyHypergraph
 
 _S = TypeVar("_S")
 _T = TypeVar("_T")
@@ -282,6 +469,21 @@ def unweighted_average_shortest_path_length(
     parallel_threshold: int = ...,
     disconnected: bool = ...,
 ) -> float: ...
+def eccentricity(
+    graph: PyGraph[_S, _T] | PyDiGraph[_S, _T],
+    weight_fn: Callable[[_T], float] | None = ...,
+    default_weight: float = ...,
+) -> dict[int, float]: ...
+def center(
+    graph: PyGraph[_S, _T] | PyDiGraph[_S, _T],
+    weight_fn: Callable[[_T], float] | None = ...,
+    default_weight: float = ...,
+) -> NodeIndices: ...
+def periphery(
+    graph: PyGraph[_S, _T] | PyDiGraph[_S, _T],
+    weight_fn: Callable[[_T], float] | None = ...,
+    default_weight: float = ...,
+) -> NodeIndices: ...
 def adjacency_matrix(
     graph: PyGraph[_S, _T] | PyDiGraph[_S, _T],
     weight_fn: Callable[[_T], float] | None = ...,
@@ -297,19 +499,19 @@ def all_simple_paths(
 ) -> list[list[int]]: ...
 def floyd_warshall(
     graph: PyGraph[_S, _T] | PyDiGraph[_S, _T],
-    weight_fn: Callable[[_T], float] | None = ...,
+    weight_fn: Callable[[_T], float] | Sequence[float] | np.ndarray | None = ...,
     default_weight: float = ...,
     parallel_threshold: int = ...,
 ) -> AllPairsPathLengthMapping: ...
 def floyd_warshall_numpy(
     graph: PyGraph[_S, _T] | PyDiGraph[_S, _T],
-    weight_fn: Callable[[_T], float] | None = ...,
+    weight_fn: Callable[[_T], float] | Sequence[float] | np.ndarray | None = ...,
     default_weight: float = ...,
     parallel_threshold: int = ...,
 ) -> np.ndarray: ...
 def floyd_warshall_successor_and_distance(
     graph: PyGraph[_S, _T] | PyDiGraph[_S, _T],
-    weight_fn: Callable[[_T], float] | None = ...,
+    weight_fn: Callable[[_T], float] | Sequence[float] | np.ndarray | None = ...,
     default_weight: float | None = ...,
     parallel_threshold: int | None = ...,
 ) -> tuple[np.ndarray, np.ndarray]: ...
@@ -324,16 +526,24 @@ def dijkstra_shortest_paths(
     graph: PyGraph[_S, _T] | PyDiGraph[_S, _T],
     source: int,
     target: int | None = ...,
-    weight_fn: Callable[[_T], float] | None = ...,
+    weight_fn: Callable[[_T], float] | Sequence[float] | np.ndarray | None = ...,
     default_weight: float = ...,
     as_undirected: bool = ...,
+    reverse_view: bool = ...,
 ) -> PathMapping: ...
 def has_path(
-    graph: PyGraph[_S, _T] | PyDiGraph[_S, _T], source: int, target: int, as_undirected: bool = ...
+    graph: PyGraph[_S, _T] | PyDiGraph[_S, _T],
+    source: int,
+    target: int,
+    as_undirected: bool = ...,
+    reverse_view: bool = ...,
 ) -> bool: ...
 def all_pairs_dijkstra_shortest_paths(
     graph: PyGraph[_S, _T] | PyDiGraph[_S, _T],
-    edge_cost_fn: Callable[[_T], float] | None,
+    edge_cost_fn: Callable[[_T], float] | Sequence[float] | np.ndarray | None,
+    num_threads: int | None = ...,
+    progress_fn: Callable[[int, int], object] | None = ...,
+    progress_interval: int | None = ...,
 ) -> AllPairsPathMapping: ...
 def all_pairs_all_simple_paths(
     graph: PyGraph | PyDiGraph,
@@ -342,14 +552,32 @@ def all_pairs_all_simple_paths(
 ) -> AllPairsMultiplePathMapping: ...
 def all_pairs_dijkstra_path_lengths(
     graph: PyGraph[_S, _T] | PyDiGraph[_S, _T],
-    edge_cost_fn: Callable[[_T], float] | None,
+    edge_cost_fn: Callable[[_T], float] | Sequence[float] | np.ndarray | None,
+    num_threads: int | None = ...,
+    progress_fn: Callable[[int, int], object] | None = ...,
+    progress_interval: int | None = ...,
 ) -> AllPairsPathLengthMapping: ...
+def all_pairs_dijkstra_path_lengths_csr(
+    graph: PyGraph[_S, _T] | PyDiGraph[_S, _T],
+    edge_cost_fn: Callable[[_T], float] | Sequence[float] | np.ndarray | None,
+    cutoff: float | None = ...,
+    num_threads: int | None = ...,
+    progress_fn: Callable[[int, int], object] | None = ...,
+    progress_interval: int | None = ...,
+) -> tuple[np.ndarray, np.ndarray, np.ndarray]: ...
 def dijkstra_shortest_path_lengths(
     graph: PyGraph[_S, _T] | PyDiGraph[_S, _T],
     node: int,
-    edge_cost_fn: Callable[[_T], float] | None,
+    edge_cost_fn: Callable[[_T], float] | Sequence[float] | np.ndarray | None,
     goal: int | None = ...,
+    reverse_view: bool = ...,
 ) -> PathLengthMapping: ...
+def voronoi_cells(
+    graph: PyGraph[_S, _T] | PyDiGraph[_S, _T],
+    sources: Sequence[int],
+    weight_fn: Callable[[_T], float] | Sequence[float] | np.ndarray | None = ...,
+    default_weight: float = ...,
+) -> tuple[dict[int, list[int]], PathLengthMapping]: ...
 def k_shortest_path_lengths(
     graph: PyGraph[_S, _T] | PyDiGraph[_S, _T],
     start: int,
@@ -361,7 +589,7 @@ def all_shortest_paths(
     graph: PyGraph[_S, _T] | PyDiGraph[_S, _T],
     source: int,
     target: int,
-    weight_fn: Callable[[_T], float] | None = ...,
+    weight_fn: Callable[[_T], float] | Sequence[float] | np.ndarray | None = ...,
     default_weight: float = ...,
     as_undirected: bool = ...,
 ) -> list[list[int]]: ...
@@ -374,6 +602,8 @@ def is_isomorphic(
     edge_matcher: Callable[[_T, _T], bool] | None = ...,
     id_order: bool = ...,
     call_limit: int | None = ...,
+    progress_fn: Callable[[int, None], object] | None = ...,
+    progress_interval: int | None = ...,
 ) -> bool: ...
 @overload
 def is_isomorphic(
@@ -383,7 +613,19 @@ def is_isomorphic(
     edge_matcher: Callable[[_T, _T], bool] | None = ...,
     id_order: bool = ...,
     call_limit: int | None = ...,
+    progress_fn: Callable[[int, None], object] | None = ...,
+    progress_interval: int | None = ...,
 ) -> bool: ...
+def distinguishing_invariant(
+    first: PyGraph | PyDiGraph,
+    second: PyGraph | PyDiGraph,
+    wl_iterations: int = ...,
+) -> str | None: ...
+def canonical_hash(
+    graph: PyGraph[_S, _T] | PyDiGraph[_S, _T],
+    node_attr_fn: Callable[[_S], Any] | None = ...,
+    edge_attr_fn: Callable[[_T], Any] | None = ...,
+) -> str: ...
 @overload
 def is_isomorphic_node_match(
     first: PyGraph[_S, _T],
@@ -407,6 +649,8 @@ def is_subgraph_isomorphic(
     id_order: bool = ...,
     induced: bool = ...,
     call_limit: int | None = ...,
+    progress_fn: Callable[[int, None], object] | None = ...,
+    progress_interval: int | None = ...,
 ) -> bool: ...
 @overload
 def is_subgraph_isomorphic(
@@ -417,9 +661,150 @@ def is_subgraph_isomorphic(
     id_order: bool = ...,
     induced: bool = ...,
     call_limit: int | None = ...,
+    progress_fn: Callable[[int, None], object] | None = ...,
+    progress_interval: int | None = ...,
 ) -> bool: ...
 def transitivity(graph: PyGraph[_S, _T] | PyDiGraph[_S, _T]) -> float: ...
+def graph_summary(
+    graph: PyGraph[_S, _T] | PyDiGraph[_S, _T],
+    clustering_samples: int | None = ...,
+    seed: int | None = ...,
+) -> GraphSummary: ...
+@overload
+def degree_assortativity_coefficient(
+    graph: PyGraph[_S, _T],
+    weight_fn: Callable[[_T], float] | None = ...,
+    default_weight: float = ...,
+) -> float: ...
+@overload
+def degree_assortativity_coefficient(
+    graph: PyDiGraph[_S, _T],
+    weight_fn: Callable[[_T], float] | None = ...,
+    default_weight: float = ...,
+    source: str = ...,
+    target: str = ...,
+) -> float: ...
+def attribute_assortativity_coefficient(
+    graph: PyGraph[_S, _T] | PyDiGraph[_S, _T], attribute_fn: Callable[[_S], Hashable]
+) -> float: ...
+def degree_histogram(graph: PyGraph[_S, _T] | PyDiGraph[_S, _T]) -> list[int]: ...
+@overload
+def joint_degree_matrix(
+    graph: PyGraph[_S, _T],
+    normalized: bool = ...,
+) -> dict[tuple[int, int], float]: ...
+@overload
+def joint_degree_matrix(
+    graph: PyDiGraph[_S, _T],
+    normalized: bool = ...,
+    source: str = ...,
+    target: str = ...,
+) -> dict[tuple[int, int], float]: ...
+@overload
+def average_neighbor_degree(
+    graph: PyGraph[_S, _T],
+    weight_fn: Callable[[_T], float] | None = ...,
+    default_weight: float = ...,
+) -> dict[int, float]: ...
+@overload
+def average_neighbor_degree(
+    graph: PyDiGraph[_S, _T],
+    weight_fn: Callable[[_T], float] | None = ...,
+    default_weight: float = ...,
+    source: str = ...,
+    target: str = ...,
+) -> dict[int, float]: ...
+def earliest_arrival_times(
+    graph: PyGraph[_S, _T] | PyDiGraph[_S, _T],
+    source: int,
+    time_fn: Callable[[_T], float | tuple[float, float]],
+    start_time: float | None = ...,
+    end_time: float | None = ...,
+) -> PathLengthMapping: ...
+def earliest_arrival_paths(
+    graph: PyGraph[_S, _T] | PyDiGraph[_S, _T],
+    source: int,
+    time_fn: Callable[[_T], float | tuple[float, float]],
+    start_time: float | None = ...,
+    end_time: float | None = ...,
+) -> PathMapping: ...
+def fastest_path_durations(
+    graph: PyGraph[_S, _T] | PyDiGraph[_S, _T],
+    source: int,
+    time_fn: Callable[[_T], float | tuple[float, float]],
+    start_time: float | None = ...,
+    end_time: float | None = ...,
+) -> PathLengthMapping: ...
+def temporal_reachability(
+    graph: PyGraph[_S, _T] | PyDiGraph[_S, _T],
+    source: int,
+    time_fn: Callable[[_T], float | tuple[float, float]],
+    start_time: float | None = ...,
+    end_time: float | None = ...,
+) -> set[int]: ...
+@overload
+def temporal_snapshot(
+    graph: PyGraph[_S, _T],
+    time_fn: Callable[[_T], float | tuple[float, float]],
+    start_time: float,
+    end_time: float | None = ...,
+) -> PyGraph[_S, _T]: ...
+@overload
+def temporal_snapshot(
+    graph: PyDiGraph[_S, _T],
+    time_fn: Callable[[_T], float | tuple[float, float]],
+    start_time: float,
+    end_time: float | None = ...,
+) -> PyDiGraph[_S, _T]: ...
+def modularity(
+    graph: PyGraph[_S, _T] | PyDiGraph[_S, _T],
+    communities: Sequence[Sequence[int]],
+    weight_fn: Callable[[_T], float] | None = ...,
+    default_weight: float = ...,
+    resolution: float = ...,
+) -> float: ...
+@overload
+def sum_parallel_edges(
+    graph: PyGraph[_S, _T],
+    weight_fn: Callable[[_T], float] | None = ...,
+    default_weight: float = ...,
+    combine_fn: Callable[[float, float], float] | None = ...,
+) -> PyGraph[_S, float]: ...
+@overload
+def sum_parallel_edges(
+    graph: PyDiGraph[_S, _T],
+    weight_fn: Callable[[_T], float] | None = ...,
+    default_weight: float = ...,
+    combine_fn: Callable[[float, float], float] | None = ...,
+) -> PyDiGraph[_S, float]: ...
+@overload
+def community_subgraphs(
+    graph: PyGraph[_S, _T],
+    communities: Sequence[Sequence[int]],
+    preserve_attrs: bool = ...,
+) -> list[tuple[PyGraph[_S, _T], NodeMap]]: ...
+@overload
+def community_subgraphs(
+    graph: PyDiGraph[_S, _T],
+    communities: Sequence[Sequence[int]],
+    preserve_attrs: bool = ...,
+) -> list[tuple[PyDiGraph[_S, _T], NodeMap]]: ...
 def core_number(graph: PyGraph[_S, _T] | PyDiGraph[_S, _T]) -> int: ...
+@overload
+def s_core_number(
+    graph: PyGraph[_S, _T],
+    weight_fn: Callable[[_T], float] | None = ...,
+    default_weight: float = ...,
+) -> dict[int, float]: ...
+@overload
+def s_core_number(
+    graph: PyDiGraph[_S, _T],
+    weight_fn: Callable[[_T], float] | None = ...,
+    default_weight: float = ...,
+) -> dict[int, float]: ...
+def onion_decomposition(
+    graph: PyGraph[_S, _T] | PyDiGraph[_S, _T]
+) -> tuple[dict[int, int], dict[int, int]]: ...
 def complement(
     graph: PyGraph[_S, _T] | PyDiGraph[_S, _T]
 ) -> PyGraph[_S, _T | None] | PyDiGraph[_S, _T | None]: ...
@@ -479,14 +864,32 @@ def betweenness_centrality(
     normalized: bool = ...,
     endpoints: bool = ...,
     parallel_threshold: int = ...,
+    num_threads: int | None = ...,
+    progress_fn: Callable[[int, int], object] | None = ...,
+    progress_interval: int | None = ...,
+    weight_fn: Callable[[_T], float] | Sequence[float] | np.ndarray | None = ...,
 ) -> CentralityMapping: ...
 def closeness_centrality(
-    graph: PyGraph[_S, _T] | PyDiGraph[_S, _T], wf_improved: bool = ...
+    graph: PyGraph[_S, _T] | PyDiGraph[_S, _T],
+    wf_improved: bool = ...,
+    weight_fn: Callable[[_T], float] | Sequence[float] | np.ndarray | None = ...,
+    default_weight: float = ...,
+    direction: str = ...,
+) -> CentralityMapping: ...
+def harmonic_centrality(
+    graph: PyGraph[_S, _T] | PyDiGraph[_S, _T],
+    weight_fn: Callable[[_T], float] | Sequence[float] | np.ndarray | None = ...,
+    default_weight: float = ...,
+    direction: str = ...,
 ) -> CentralityMapping: ...
 def edge_betweenness_centrality(
     graph: PyGraph[_S, _T] | PyDiGraph[_S, _T],
     normalized: bool = ...,
     parallel_threshold: int = ...,
+    num_threads: int | None = ...,
+    progress_fn: Callable[[int, int], object] | None = ...,
+    progress_interval: int | None = ...,
+    weight_fn: Callable[[_T], float] | Sequence[float] | np.ndarray | None = ...,
 ) -> CentralityMapping: ...
 def eigenvector_centrality(
     graph: PyGraph[_S, _T] | PyDiGraph[_S, _T],
@@ -504,6 +907,58 @@ def katz_centrality(
     max_iter: int = ...,
     tol: float = ...,
 ) -> CentralityMapping: ...
+def simrank_similarity(
+    graph: PyGraph | PyDiGraph,
+    source: int | None = ...,
+    target: int | None = ...,
+    decay: float = ...,
+    max_iter: int = ...,
+    tol: float = ...,
+) -> float | dict[int, float] | dict[int, dict[int, float]]: ...
+def weighted_simrank_similarity(
+    graph: PyGraph[_S, _T] | PyDiGraph[_S, _T],
+    source: int | None = ...,
+    target: int | None = ...,
+    weight_fn: Callable[[_T], float] | None = ...,
+    default_weight: float = ...,
+    decay: float = ...,
+    max_iter: int = ...,
+    tol: float = ...,
+) -> float | dict[int, float] | dict[int, dict[int, float]]: ...
+def katz_similarity(
+    graph: PyGraph[_S, _T] | PyDiGraph[_S, _T],
+    source: int | None = ...,
+    target: int | None = ...,
+    alpha: float = ...,
+    weight_fn: Callable[[_T], float] | None = ...,
+    default_weight: float = ...,
+    max_iter: int = ...,
+    tol: float = ...,
+) -> float | dict[int, float] | dict[int, dict[int, float]]: ...
+def random_walk_with_restart(
+    graph: PyGraph[_S, _T] | PyDiGraph[_S, _T],
+    seeds: Sequence[int],
+    restart_prob: float = ...,
+    weight_fn: Callable[[_T], float] | None = ...,
+    default_weight: float = ...,
+    max_iter: int = ...,
+    tol: float = ...,
+) -> CentralityMapping: ...
+def recursive_structural_features(
+    graph: PyGraph | PyDiGraph, rounds: int = ...
+) -> np.ndarray: ...
+def laplacian_matrix(
+    graph: PyGraph[_S, _T] | PyDiGraph[_S, _T],
+    weight_fn: Callable[[_T], float] | None = ...,
+    default_weight: float = ...,
+    sparse: bool = ...,
+) -> np.ndarray | tuple[np.ndarray, np.ndarray, np.ndarray]: ...
+def normalized_laplacian_matrix(
+    graph: PyGraph[_S, _T] | PyDiGraph[_S, _T],
+    weight_fn: Callable[[_T], float] | None = ...,
+    default_weight: float = ...,
+    sparse: bool = ...,
+) -> np.ndarray | tuple[np.ndarray, np.ndarray, np.ndarray]: ...
 @overload
 def vf2_mapping(
     first: PyGraph[_S, _T],
@@ -514,6 +969,8 @@ def vf2_mapping(
     subgraph: bool = ...,
     induced: bool = ...,
     call_limit: int | None = ...,
+    progress_fn: Callable[[int, None], object] | None = ...,
+    progress_interval: int | None = ...,
 ) -> Iterator[NodeMap]: ...
 @overload
 def vf2_mapping(
@@ -525,13 +982,39 @@ def vf2_mapping(
     subgraph: bool = ...,
     induced: bool = ...,
     call_limit: int | None = ...,
+    progress_fn: Callable[[int, None], object] | None = ...,
+    progress_interval: int | None = ...,
 ) -> Iterator[NodeMap]: ...
+def rewire(
+    graph: PyGraph | PyDiGraph,
+    n_swaps: int,
+    /,
+    max_tries: int | None = ...,
+    seed: int | None = ...,
+) -> int: ...
+def motif_counts(
+    graph: PyGraph | PyDiGraph,
+    /,
+    size: int = ...,
+    probabilities: Sequence[float] | None = ...,
+    seed: int | None = ...,
+) -> dict[tuple[tuple[int, int], ...], float]: ...
+def motif_significance_profile(
+    graph: PyGraph | PyDiGraph,
+    /,
+    size: int = ...,
+    num_randomizations: int = ...,
+    swaps_per_edge: int = ...,
+    probabilities: Sequence[float] | None = ...,
+    seed: int | None = ...,
+) -> dict[tuple[tuple[int, int], ...], dict[str, float]]: ...
 @overload
 def union(
     first: PyGraph[_S, _T],
     second: PyGraph[_S, _T],
     merge_nodes: bool = ...,
     merge_edges: bool = ...,
+    key_fn: Callable[[_S], Hashable] | None = ...,
 ) -> PyGraph[_S, _T]: ...
 @overload
 def union(
@@ -539,27 +1022,154 @@ def union(
     second: PyDiGraph[_S, _T],
     merge_nodes: bool = ...,
     merge_edges: bool = ...,
+    key_fn: Callable[[_S], Hashable] | None = ...,
 ) -> PyDiGraph[_S, _T]: ...
 @overload
+def union_with_mapping(
+    first: PyGraph[_S, _T],
+    second: PyGraph[_S, _T],
+    merge_nodes: bool = ...,
+    merge_edges: bool = ...,
+    key_fn: Callable[[_S], Hashable] | None = ...,
+) -> tuple[PyGraph[_S, _T], UnionMapping]: ...
+@overload
+def union_with_mapping(
+    first: PyDiGraph[_S, _T],
+    second: PyDiGraph[_S, _T],
+    merge_nodes: bool = ...,
+    merge_edges: bool = ...,
+    key_fn: Callable[[_S], Hashable] | None = ...,
+) -> tuple[PyDiGraph[_S, _T], UnionMapping]: ...
+@overload
+def intersection(
+    first: PyGraph[_S, _T],
+    second: PyGraph[_S, _T],
+    align_by: str = ...,
+    node_merge_fn: Callable[[_S, _S], _S] | None = ...,
+    edge_merge_fn: Callable[[_T, _T], _T] | None = ...,
+) -> PyGraph[_S, _T]: ...
+@overload
+def intersection(
+    first: PyDiGraph[_S, _T],
+    second: PyDiGraph[_S, _T],
+    align_by: str = ...,
+    node_merge_fn: Callable[[_S, _S], _S] | None = ...,
+    edge_merge_fn: Callable[[_T, _T], _T] | None = ...,
+) -> PyDiGraph[_S, _T]: ...
+@overload
+def difference(
+    first: PyGraph[_S, _T],
+    second: PyGraph[_S, _T],
+    align_by: str = ...,
+) -> PyGraph[_S, _T]: ...
+@overload
+def difference(
+    first: PyDiGraph[_S, _T],
+    second: PyDiGraph[_S, _T],
+    align_by: str = ...,
+) -> PyDiGraph[_S, _T]: ...
+@overload
+def symmetric_difference(
+    first: PyGraph[_S, _T],
+    second: PyGraph[_S, _T],
+    align_by: str = ...,
+    node_merge_fn: Callable[[_S, _S], _S] | None = ...,
+) -> PyGraph[_S, _T]: ...
+@overload
+def symmetric_difference(
+    first: PyDiGraph[_S, _T],
+    second: PyDiGraph[_S, _T],
+    align_by: str = ...,
+    node_merge_fn: Callable[[_S, _S], _S] | None = ...,
+) -> PyDiGraph[_S, _T]: ...
+@overload
+def full_join(
+    first: PyGraph[_S, _T],
+    second: PyGraph[_S, _T],
+    join_fn: Callable[[_S, _S], Any] | None = ...,
+) -> PyGraph[_S, Any]: ...
+@overload
+def full_join(
+    first: PyDiGraph[_S, _T],
+    second: PyDiGraph[_S, _T],
+    join_fn: Callable[[_S, _S], Any] | None = ...,
+) -> PyDiGraph[_S, Any]: ...
+@overload
 def tensor_product(
     first: PyGraph,
     second: PyGraph,
+    node_fn: Callable[[Any, Any], Any] | None = ...,
+    edge_fn: Callable[[Any, Any], Any] | None = ...,
 ) -> tuple[PyGraph, ProductNodeMap]: ...
 @overload
 def tensor_product(
     first: PyDiGraph,
     second: PyDiGraph,
+    node_fn: Callable[[Any, Any], Any] | None = ...,
+    edge_fn: Callable[[Any, Any], Any] | None = ...,
 ) -> tuple[PyDiGraph, ProductNodeMap]: ...
 @overload
 def cartesian_product(
     first: PyGraph,
     second: PyGraph,
+    node_fn: Callable[[Any, Any], Any] | None = ...,
+    edge_fn: Callable[[Any, Any], Any] | None = ...,
 ) -> tuple[PyGraph, ProductNodeMap]: ...
 @overload
 def cartesian_product(
     first: PyDiGraph,
     second: PyDiGraph,
+    node_fn: Callable[[Any, Any], Any] | None = ...,
+    edge_fn: Callable[[Any, Any], Any] | None = ...,
+) -> tuple[PyDiGraph, ProductNodeMap]: ...
+@overload
+def strong_product(
+    first: PyGraph,
+    second: PyGraph,
+    node_fn: Callable[[Any, Any], Any] | None = ...,
+    edge_fn: Callable[[Any, Any], Any] | None = ...,
+) -> tuple[PyGraph, ProductNodeMap]: ...
+@overload
+def strong_product(
+    first: PyDiGraph,
+    second: PyDiGraph,
+    node_fn: Callable[[Any, Any], Any] | None = ...,
+    edge_fn: Callable[[Any, Any], Any] | None = ...,
+) -> tuple[PyDiGraph, ProductNodeMap]: ...
+@overload
+def lexicographic_product(
+    first: PyGraph,
+    second: PyGraph,
+    node_fn: Callable[[Any, Any], Any] | None = ...,
+    edge_fn: Callable[[Any, Any], Any] | None = ...,
+) -> tuple[PyGraph, ProductNodeMap]: ...
+@overload
+def lexicographic_product(
+    first: PyDiGraph,
+    second: PyDiGraph,
+    node_fn: Callable[[Any, Any], Any] | None = ...,
+    edge_fn: Callable[[Any, Any], Any] | None = ...,
 ) -> tuple[PyDiGraph, ProductNodeMap]: ...
+@overload
+def line_graph(graph: PyGraph) -> tuple[PyGraph, dict[int, int]]: ...
+@overload
+def line_graph(graph: PyDiGraph) -> tuple[PyDiGraph, dict[int, int]]: ...
+@overload
+def quotient_graph(
+    graph: PyGraph[_S, _T],
+    partition: Sequence[Sequence[int]] | Callable[[_S], Hashable],
+    node_fn: Callable[[list[_S]], Any] | None = ...,
+    edge_fn: Callable[[list[_T]], Any] | None = ...,
+    self_loops: bool = ...,
+) -> tuple[PyGraph, dict[int, int]]: ...
+@overload
+def quotient_graph(
+    graph: PyDiGraph[_S, _T],
+    partition: Sequence[Sequence[int]] | Callable[[_S], Hashable],
+    node_fn: Callable[[list[_S]], Any] | None = ...,
+    edge_fn: Callable[[list[_T]], Any] | None = ...,
+    self_loops: bool = ...,
+) -> tuple[PyDiGraph, dict[int, int]]: ...
 def bfs_search(
     graph: PyGraph | PyDiGraph,
     source: Sequence[int] | None,
@@ -580,23 +1190,29 @@ def bellman_ford_shortest_paths(
     graph: PyGraph[_S, _T] | PyDiGraph[_S, _T],
     source,
     target: int | None = ...,
-    weight_fn: Callable[[_T], float] | None = ...,
+    weight_fn: Callable[[_T], float] | Sequence[float] | np.ndarray | None = ...,
     default_weight: float = ...,
     as_undirected: bool = ...,
 ) -> PathMapping: ...
 def bellman_ford_shortest_path_lengths(
     graph: PyGraph[_S, _T] | PyDiGraph[_S, _T],
     node: int,
-    edge_cost_fn: Callable[[_T], float] | None,
+    edge_cost_fn: Callable[[_T], float] | Sequence[float] | np.ndarray | None,
     goal: int | None = ...,
 ) -> PathLengthMapping: ...
 def all_pairs_bellman_ford_path_lengths(
     graph: PyGraph[_S, _T] | PyDiGraph[_S, _T],
-    edge_cost_fn: Callable[[_T], float] | None,
+    edge_cost_fn: Callable[[_T], float] | Sequence[float] | np.ndarray | None,
+    num_threads: int | None = ...,
+    progress_fn: Callable[[int, int], object] | None = ...,
+    progress_interval: int | None = ...,
 ) -> AllPairsPathLengthMapping: ...
 def all_pairs_bellman_ford_shortest_paths(
     graph: PyGraph[_S, _T] | PyDiGraph[_S, _T],
-    edge_cost_fn: Callable[[_T], float] | None,
+    edge_cost_fn: Callable[[_T], float] | Sequence[float] | np.ndarray | None,
+    num_threads: int | None = ...,
+    progress_fn: Callable[[int, int], object] | None = ...,
+    progress_interval: int | None = ...,
 ) -> AllPairsPathMapping: ...
 def node_link_json(
     graph: PyGraph[_S, _T] | PyDiGraph[_S, _T],
@@ -609,3 +1225,104 @@ def longest_simple_path(graph: PyGraph[_S, _T] | PyDiGraph[_S, _T]) -> NodeIndic
 def isolates(graph: PyGraph[_S, _T] | PyDiGraph[_S, _T]) -> NodeIndices: ...
 def two_color(graph: PyGraph[_S, _T] | PyDiGraph[_S, _T]) -> dict[int, int]: ...
 def is_bipartite(graph: PyGraph[_S, _T] | PyDiGraph[_S, _T]) -> bool: ...
+def bipartite_sets(graph: PyGraph[_S, _T] | PyDiGraph[_S, _T]) -> tuple[set[int], set[int]]: ...
+def edge_disjoint_paths(
+    graph: PyGraph[_S, _T] | PyDiGraph[_S, _T],
+    source: int,
+    target: int,
+    /,
+    k: int | None = ...,
+) -> list[list[int]]: ...
+def node_disjoint_paths(
+    graph: PyGraph[_S, _T] | PyDiGraph[_S, _T],
+    source: int,
+    target: int,
+    /,
+    k: int | None = ...,
+) -> list[list[int]]: ...
+def node_boundary(
+    graph: PyGraph[_S, _T] | PyDiGraph[_S, _T],
+    nodes: Sequence[int],
+    targets: Sequence[int] | None = ...,
+) -> NodeIndices: ...
+def edge_boundary(
+    graph: PyGraph[_S, _T] | PyDiGraph[_S, _T],
+    nodes: Sequence[int],
+    targets: Sequence[int] | None = ...,
+) -> EdgeList: ...
+def cut_size(
+    graph: PyGraph[_S, _T] | PyDiGraph[_S, _T],
+    nodes: Sequence[int],
+    targets: Sequence[int] | None = ...,
+    weight_fn: Callable[[_T], float] | Sequence[float] | np.ndarray | None = ...,
+    default_weight: float = ...,
+) -> float: ...
+def volume(
+    graph: PyGraph[_S, _T] | PyDiGraph[_S, _T],
+    nodes: Sequence[int],
+    weight_fn: Callable[[_T], float] | Sequence[float] | np.ndarray | None = ...,
+    default_weight: float = ...,
+) -> float: ...
+def feedback_vertex_set(
+    graph: PyGraph[_S, _T] | PyDiGraph[_S, _T],
+    weight_fn: Callable[[_S], float] | None = ...,
+) -> NodeIndices: ...
+def percolation(
+    graph: PyGraph[_S, _T] | PyDiGraph[_S, _T],
+    strategy: str | Sequence[int] = ...,
+    remove_edges: bool = ...,
+    seed: int | None = ...,
+    threshold: float = ...,
+) -> tuple[list[int], list[int], float | None]: ...
+@overload
+def sample_nodes(
+    graph: PyGraph[_S, _T],
+    num_nodes: int,
+    seed: int | None = ...,
+) -> tuple[PyGraph[_S, _T], NodeMap]: ...
+@overload
+def sample_nodes(
+    graph: PyDiGraph[_S, _T],
+    num_nodes: int,
+    seed: int | None = ...,
+) -> tuple[PyDiGraph[_S, _T], NodeMap]: ...
+@overload
+def sample_edges(
+    graph: PyGraph[_S, _T],
+    num_edges: int,
+    seed: int | None = ...,
+) -> tuple[PyGraph[_S, _T], NodeMap]: ...
+@overload
+def sample_edges(
+    graph: PyDiGraph[_S, _T],
+    num_edges: int,
+    seed: int | None = ...,
+) -> tuple[PyDiGraph[_S, _T], NodeMap]: ...
+@overload
+def random_walk_sample(
+    graph: PyGraph[_S, _T],
+    num_nodes: int,
+    restart_probability: float = ...,
+    seed: int | None = ...,
+) -> tuple[PyGraph[_S, _T], NodeMap]: ...
+@overload
+def random_walk_sample(
+    graph: PyDiGraph[_S, _T],
+    num_nodes: int,
+    restart_probability: float = ...,
+    seed: int | None = ...,
+) -> tuple[PyDiGraph[_S, _T], NodeMap]: ...
+@overload
+def forest_fire_sample(
+    graph: PyGraph[_S, _T],
+    num_nodes: int,
+    burn_probability: float = ...,
+    seed: int | None = ...,
+) -> tuple[PyGraph[_S, _T], NodeMap]: ...
+@overload
+def forest_fire_sample(
+    graph: PyDiGraph[_S, _T],
+    num_nodes: int,
+    burn_probability: float = ...,
+    seed: int | None = ...,
+) -> tuple[PyDiGraph[_S, _T], NodeMap]: ...
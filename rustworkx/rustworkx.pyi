@@ -25,6 +25,7 @@ from typing import (
     Mapping,
     overload,
     Hashable,
+    Literal,
 )
 from abc import ABC
 from rustworkx import generators  # noqa
@@ -47,7 +48,11 @@ class NullGraph(Exception): ...
 class NegativeCycle(Exception): ...
 class JSONSerializationError(Exception): ...
 class JSONDeserializationError(Exception): ...
-class FailedToConverge(Exception): ...
+class FailedToConverge(Exception):
+    partial_result: Any | None
+    iterations: int | None
+    residual: float | None
+
 class InvalidMapping(Exception): ...
 class GraphNotBipartite(Exception): ...
 
@@ -63,11 +68,15 @@ def digraph_cartesian_product(
     first: PyDiGraph,
     second: PyDiGraph,
     /,
+    node_fn: Callable[[Any, Any], Any] | None = ...,
+    edge_fn: Callable[[Any, Any], Any] | None = ...,
 ) -> tuple[PyDiGraph, ProductNodeMap]: ...
 def graph_cartesian_product(
     first: PyGraph,
     second: PyGraph,
     /,
+    node_fn: Callable[[Any, Any], Any] | None = ...,
+    edge_fn: Callable[[Any, Any], Any] | None = ...,
 ) -> tuple[PyGraph, ProductNodeMap]: ...
 
 # Centrality
@@ -88,12 +97,18 @@ def graph_eigenvector_centrality(
     max_iter: int = ...,
     tol: float = ...,
 ) -> CentralityMapping: ...
+def set_num_threads(num_threads: int | None = ...) -> None: ...
+def get_num_threads() -> int: ...
 def digraph_betweenness_centrality(
     graph: PyDiGraph[_S, _T],
     /,
     normalized: bool = ...,
     endpoints: bool = ...,
     parallel_threshold: int = ...,
+    num_threads: int | None = ...,
+    progress_fn: Callable[[int, int], object] | None = ...,
+    progress_interval: int | None = ...,
+    weight_fn: Callable[[_T], float] | Sequence[float] | np.ndarray | None = ...,
 ) -> CentralityMapping: ...
 def graph_betweenness_centrality(
     graph: PyGraph[_S, _T],
@@ -101,26 +116,54 @@ def graph_betweenness_centrality(
     normalized: bool = ...,
     endpoints: bool = ...,
     parallel_threshold: int = ...,
+    num_threads: int | None = ...,
+    progress_fn: Callable[[int, int], object] | None = ...,
+    progress_interval: int | None = ...,
+    weight_fn: Callable[[_T], float] | Sequence[float] | np.ndarray | None = ...,
 ) -> CentralityMapping: ...
 def digraph_edge_betweenness_centrality(
     graph: PyDiGraph[_S, _T],
     /,
     normalized: bool = ...,
     parallel_threshold: int = ...,
+    num_threads: int | None = ...,
+    progress_fn: Callable[[int, int], object] | None = ...,
+    progress_interval: int | None = ...,
+    weight_fn: Callable[[_T], float] | Sequence[float] | np.ndarray | None = ...,
 ) -> EdgeCentralityMapping: ...
 def graph_edge_betweenness_centrality(
     graph: PyGraph[_S, _T],
     /,
     normalized: bool = ...,
     parallel_threshold: int = ...,
+    num_threads: int | None = ...,
+    progress_fn: Callable[[int, int], object] | None = ...,
+    progress_interval: int | None = ...,
+    weight_fn: Callable[[_T], float] | Sequence[float] | np.ndarray | None = ...,
 ) -> EdgeCentralityMapping: ...
 def digraph_closeness_centrality(
     graph: PyDiGraph[_S, _T],
     wf_improved: bool = ...,
+    weight_fn: Callable[[_T], float] | Sequence[float] | np.ndarray | None = ...,
+    default_weight: float = ...,
+    direction: str = ...,
 ) -> CentralityMapping: ...
 def graph_closeness_centrality(
     graph: PyGraph[_S, _T],
     wf_improved: bool = ...,
+    weight_fn: Callable[[_T], float] | Sequence[float] | np.ndarray | None = ...,
+    default_weight: float = ...,
+) -> CentralityMapping: ...
+def digraph_harmonic_centrality(
+    graph: PyDiGraph[_S, _T],
+    weight_fn: Callable[[_T], float] | Sequence[float] | np.ndarray | None = ...,
+    default_weight: float = ...,
+    direction: str = ...,
+) -> CentralityMapping: ...
+def graph_harmonic_centrality(
+    graph: PyGraph[_S, _T],
+    weight_fn: Callable[[_T], float] | Sequence[float] | np.ndarray | None = ...,
+    default_weight: float = ...,
 ) -> CentralityMapping: ...
 def digraph_katz_centrality(
     graph: PyDiGraph[_S, _T],
@@ -143,6 +186,225 @@ def graph_katz_centrality(
     tol: float | None = ...,
 ) -> CentralityMapping: ...
 
+# Similarity
+
+def digraph_simrank_similarity(
+    graph: PyDiGraph,
+    /,
+    source: int | None = ...,
+    target: int | None = ...,
+    decay: float = ...,
+    max_iter: int = ...,
+    tol: float = ...,
+) -> float | dict[int, float] | dict[int, dict[int, float]]: ...
+def graph_simrank_similarity(
+    graph: PyGraph,
+    /,
+    source: int | None = ...,
+    target: int | None = ...,
+    decay: float = ...,
+    max_iter: int = ...,
+    tol: float = ...,
+) -> float | dict[int, float] | dict[int, dict[int, float]]: ...
+def digraph_weighted_simrank_similarity(
+    graph: PyDiGraph[_S, _T],
+    /,
+    source: int | None = ...,
+    target: int | None = ...,
+    weight_fn: Callable[[_T], float] | None = ...,
+    default_weight: float = ...,
+    decay: float = ...,
+    max_iter: int = ...,
+    tol: float = ...,
+) -> float | dict[int, float] | dict[int, dict[int, float]]: ...
+def graph_weighted_simrank_similarity(
+    graph: PyGraph[_S, _T],
+    /,
+    source: int | None = ...,
+    target: int | None = ...,
+    weight_fn: Callable[[_T], float] | None = ...,
+    default_weight: float = ...,
+    decay: float = ...,
+    max_iter: int = ...,
+    tol: float = ...,
+) -> float | dict[int, float] | dict[int, dict[int, float]]: ...
+def digraph_katz_similarity(
+    graph: PyDiGraph[_S, _T],
+    /,
+    source: int | None = ...,
+    target: int | None = ...,
+    alpha: float = ...,
+    weight_fn: Callable[[_T], float] | None = ...,
+    default_weight: float = ...,
+    max_iter: int = ...,
+    tol: float = ...,
+) -> float | dict[int, float] | dict[int, dict[int, float]]: ...
+def graph_katz_similarity(
+    graph: PyGraph[_S, _T],
+    /,
+    source: int | None = ...,
+    target: int | None = ...,
+    alpha: float = ...,
+    weight_fn: Callable[[_T], float] | None = ...,
+    default_weight: float = ...,
+    max_iter: int = ...,
+    tol: float = ...,
+) -> float | dict[int, float] | dict[int, dict[int, float]]: ...
+def weisfeiler_lehman_subtree_features(
+    graphs: Sequence[PyGraph[_S, _T]] | Sequence[PyDiGraph[_S, _T]],
+    /,
+    node_label: Callable[[_S], Hashable] | None = ...,
+    iterations: int = ...,
+) -> list[dict[int, int]]: ...
+def weisfeiler_lehman_subtree_kernel(
+    graphs: Sequence[PyGraph[_S, _T]] | Sequence[PyDiGraph[_S, _T]],
+    /,
+    node_label: Callable[[_S], Hashable] | None = ...,
+    iterations: int = ...,
+    normalize: bool = ...,
+) -> np.ndarray: ...
+
+def digraph_recursive_structural_features(
+    graph: PyDiGraph, /, rounds: int = ...
+) -> np.ndarray: ...
+def graph_recursive_structural_features(graph: PyGraph, /, rounds: int = ...) -> np.ndarray: ...
+
+# Spectral
+
+def digraph_laplacian_matrix(
+    graph: PyDiGraph[_S, _T],
+    /,
+    weight_fn: Callable[[_T], float] | None = ...,
+    default_weight: float = ...,
+    sparse: bool = ...,
+) -> np.ndarray | tuple[np.ndarray, np.ndarray, np.ndarray]: ...
+def graph_laplacian_matrix(
+    graph: PyGraph[_S, _T],
+    /,
+    weight_fn: Callable[[_T], float] | None = ...,
+    default_weight: float = ...,
+    sparse: bool = ...,
+) -> np.ndarray | tuple[np.ndarray, np.ndarray, np.ndarray]: ...
+def digraph_normalized_laplacian_matrix(
+    graph: PyDiGraph[_S, _T],
+    /,
+    weight_fn: Callable[[_T], float] | None = ...,
+    default_weight: float = ...,
+    sparse: bool = ...,
+) -> np.ndarray | tuple[np.ndarray, np.ndarray, np.ndarray]: ...
+def graph_normalized_laplacian_matrix(
+    graph: PyGraph[_S, _T],
+    /,
+    weight_fn: Callable[[_T], float] | None = ...,
+    default_weight: float = ...,
+    sparse: bool = ...,
+) -> np.ndarray | tuple[np.ndarray, np.ndarray, np.ndarray]: ...
+def directed_laplacian_matrix(
+    graph: PyDiGraph[_S, _T],
+    /,
+    weight_fn: Callable[[_T], float] | None = ...,
+    default_weight: float = ...,
+    walk_type: str | None = ...,
+    alpha: float = ...,
+    max_iter: int = ...,
+    tol: float = ...,
+) -> np.ndarray: ...
+def algebraic_connectivity(
+    graph: PyGraph[_S, _T],
+    /,
+    weight_fn: Callable[[_T], float] | None = ...,
+    default_weight: float = ...,
+    normalized: bool = ...,
+    max_iter: int = ...,
+    tol: float = ...,
+    seed: int | None = ...,
+) -> float: ...
+def fiedler_vector(
+    graph: PyGraph[_S, _T],
+    /,
+    weight_fn: Callable[[_T], float] | None = ...,
+    default_weight: float = ...,
+    normalized: bool = ...,
+    max_iter: int = ...,
+    tol: float = ...,
+    seed: int | None = ...,
+) -> np.ndarray: ...
+def spectral_partition(
+    graph: PyGraph[_S, _T],
+    num_parts: int,
+    /,
+    weight_fn: Callable[[_T], float] | None = ...,
+    default_weight: float = ...,
+    refine: bool = ...,
+    max_iter: int = ...,
+    tol: float = ...,
+    seed: int | None = ...,
+) -> tuple[list[list[int]], float]: ...
+def spectral_sparsify(
+    graph: PyGraph[_S, _T],
+    target_edges: int,
+    /,
+    weight_fn: Callable[[_T], float] | None = ...,
+    default_weight: float = ...,
+    seed: int | None = ...,
+) -> PyGraph[_S, float]: ...
+
+# Link Prediction
+
+def jaccard_coefficient(
+    graph: PyGraph,
+    /,
+    ebunch: Sequence[tuple[int, int]] | None = ...,
+    parallel_threshold: int = ...,
+) -> list[tuple[int, int, float]]: ...
+def adamic_adar_index(
+    graph: PyGraph,
+    /,
+    ebunch: Sequence[tuple[int, int]] | None = ...,
+    parallel_threshold: int = ...,
+) -> list[tuple[int, int, float]]: ...
+def resource_allocation_index(
+    graph: PyGraph,
+    /,
+    ebunch: Sequence[tuple[int, int]] | None = ...,
+    parallel_threshold: int = ...,
+) -> list[tuple[int, int, float]]: ...
+def preferential_attachment(
+    graph: PyGraph,
+    /,
+    ebunch: Sequence[tuple[int, int]] | None = ...,
+    parallel_threshold: int = ...,
+) -> list[tuple[int, int, float]]: ...
+def common_neighbor_centrality(
+    graph: PyGraph,
+    /,
+    ebunch: Sequence[tuple[int, int]] | None = ...,
+    alpha: float = ...,
+    parallel_threshold: int = ...,
+) -> list[tuple[int, int, float]]: ...
+def cn_soundarajan_hopcroft(
+    graph: PyGraph,
+    community: Mapping[int, int],
+    /,
+    ebunch: Sequence[tuple[int, int]] | None = ...,
+    parallel_threshold: int = ...,
+) -> list[tuple[int, int, float]]: ...
+def ra_index_soundarajan_hopcroft(
+    graph: PyGraph,
+    community: Mapping[int, int],
+    /,
+    ebunch: Sequence[tuple[int, int]] | None = ...,
+    parallel_threshold: int = ...,
+) -> list[tuple[int, int, float]]: ...
+def within_inter_cluster(
+    graph: PyGraph,
+    community: Mapping[int, int],
+    /,
+    ebunch: Sequence[tuple[int, int]] | None = ...,
+    delta: float = ...,
+    parallel_threshold: int = ...,
+) -> list[tuple[int, int, float]]: ...
+
 # Coloring
 
 def graph_greedy_color(
@@ -159,6 +421,20 @@ def graph_greedy_edge_color(
 ) -> dict[int, int]: ...
 def graph_is_bipartite(graph: PyGraph) -> bool: ...
 def digraph_is_bipartite(graph: PyDiGraph) -> bool: ...
+def graph_bipartite_sets(graph: PyGraph, /) -> tuple[set[int], set[int]]: ...
+def digraph_bipartite_sets(graph: PyDiGraph, /) -> tuple[set[int], set[int]]: ...
+def graph_edge_disjoint_paths(
+    graph: PyGraph, source: int, target: int, /, k: int | None = ...
+) -> list[list[int]]: ...
+def digraph_edge_disjoint_paths(
+    graph: PyDiGraph, source: int, target: int, /, k: int | None = ...
+) -> list[list[int]]: ...
+def graph_node_disjoint_paths(
+    graph: PyGraph, source: int, target: int, /, k: int | None = ...
+) -> list[list[int]]: ...
+def digraph_node_disjoint_paths(
+    graph: PyDiGraph, source: int, target: int, /, k: int | None = ...
+) -> list[list[int]]: ...
 def graph_two_color(graph: PyGraph) -> dict[int, int]: ...
 def digraph_two_color(graph: PyDiGraph) -> dict[int, int]: ...
 def graph_misra_gries_edge_color(graph: PyGraph, /) -> dict[int, int]: ...
@@ -201,6 +477,92 @@ def digraph_find_cycle(
     /,
     source: int | None = ...,
 ) -> EdgeList: ...
+def feedback_arc_set(
+    graph: PyDiGraph[_S, _T],
+    /,
+    weight_fn: Callable[[_T], float] | Sequence[float] | np.ndarray | None = ...,
+    default_weight: float = ...,
+    method: str = ...,
+    max_passes: int = ...,
+) -> tuple[EdgeIndices, NodeIndices]: ...
+def graph_feedback_vertex_set(
+    graph: PyGraph[_S, _T],
+    /,
+    weight_fn: Callable[[_S], float] | None = ...,
+) -> NodeIndices: ...
+def digraph_feedback_vertex_set(
+    graph: PyDiGraph[_S, _T],
+    /,
+    weight_fn: Callable[[_S], float] | None = ...,
+) -> NodeIndices: ...
+def graph_percolation(
+    graph: PyGraph[_S, _T],
+    /,
+    strategy: str | Sequence[int] = ...,
+    remove_edges: bool = ...,
+    seed: int | None = ...,
+    threshold: float = ...,
+) -> tuple[list[int], list[int], float | None]: ...
+def digraph_percolation(
+    graph: PyDiGraph[_S, _T],
+    /,
+    strategy: str | Sequence[int] = ...,
+    remove_edges: bool = ...,
+    seed: int | None = ...,
+    threshold: float = ...,
+) -> tuple[list[int], list[int], float | None]: ...
+def graph_sample_nodes(
+    graph: PyGraph[_S, _T],
+    num_nodes: int,
+    /,
+    seed: int | None = ...,
+) -> tuple[PyGraph[_S, _T], NodeMap]: ...
+def digraph_sample_nodes(
+    graph: PyDiGraph[_S, _T],
+    num_nodes: int,
+    /,
+    seed: int | None = ...,
+) -> tuple[PyDiGraph[_S, _T], NodeMap]: ...
+def graph_sample_edges(
+    graph: PyGraph[_S, _T],
+    num_edges: int,
+    /,
+    seed: int | None = ...,
+) -> tuple[PyGraph[_S, _T], NodeMap]: ...
+def digraph_sample_edges(
+    graph: PyDiGraph[_S, _T],
+    num_edges: int,
+    /,
+    seed: int | None = ...,
+) -> tuple[PyDiGraph[_S, _T], NodeMap]: ...
+def graph_random_walk_sample(
+    graph: PyGraph[_S, _T],
+    num_nodes: int,
+    /,
+    restart_probability: float = ...,
+    seed: int | None = ...,
+) -> tuple[PyGraph[_S, _T], NodeMap]: ...
+def digraph_random_walk_sample(
+    graph: PyDiGraph[_S, _T],
+    num_nodes: int,
+    /,
+    restart_probability: float = ...,
+    seed: int | None = ...,
+) -> tuple[PyDiGraph[_S, _T], NodeMap]: ...
+def graph_forest_fire_sample(
+    graph: PyGraph[_S, _T],
+    num_nodes: int,
+    /,
+    burn_probability: float = ...,
+    seed: int | None = ...,
+) -> tuple[PyGraph[_S, _T], NodeMap]: ...
+def digraph_forest_fire_sample(
+    graph: PyDiGraph[_S, _T],
+    num_nodes: int,
+    /,
+    burn_probability: float = ...,
+    seed: int | None = ...,
+) -> tuple[PyDiGraph[_S, _T], NodeMap]: ...
 def digraph_complement(graph: PyDiGraph[_S, _T], /) -> PyDiGraph[_S, _T | None]: ...
 def graph_complement(
     graph: PyGraph[_S, _T],
@@ -244,6 +606,28 @@ def graph_core_number(
     graph: PyGraph,
     /,
 ) -> int: ...
+def digraph_s_core_number(
+    graph: PyDiGraph[_S, _T],
+    /,
+    weight_fn: Callable[[_T], float] | None = ...,
+    default_weight: float = ...,
+) -> dict[int, float]: ...
+def graph_s_core_number(
+    graph: PyGraph[_S, _T],
+    /,
+    weight_fn: Callable[[_T], float] | None = ...,
+    default_weight: float = ...,
+) -> dict[int, float]: ...
+def digraph_onion_decomposition(
+    graph: PyDiGraph,
+    /,
+) -> tuple[dict[int, int], dict[int, int]]: ...
+def graph_onion_decomposition(
+    graph: PyGraph,
+    /,
+) -> tuple[dict[int, int], dict[int, int]]: ...
+def truss_number(graph: PyGraph, /) -> dict[int, int]: ...
+def k_truss(graph: PyGraph[_S, _T], k: int, /) -> PyGraph[_S, _T]: ...
 def stoer_wagner_min_cut(
     graph: PyGraph[_S, _T],
     /,
@@ -253,6 +637,48 @@ def simple_cycles(graph: PyDiGraph, /) -> Iterator[NodeIndices]: ...
 def graph_isolates(graph: PyGraph) -> NodeIndices: ...
 def digraph_isolates(graph: PyDiGraph) -> NodeIndices: ...
 def connected_subgraphs(graph: PyGraph, k: int, /) -> list[list[int]]: ...
+def digraph_node_boundary(
+    graph: PyDiGraph, nodes: Sequence[int], /, targets: Sequence[int] | None = ...
+) -> NodeIndices: ...
+def graph_node_boundary(
+    graph: PyGraph, nodes: Sequence[int], /, targets: Sequence[int] | None = ...
+) -> NodeIndices: ...
+def digraph_edge_boundary(
+    graph: PyDiGraph, nodes: Sequence[int], /, targets: Sequence[int] | None = ...
+) -> EdgeList: ...
+def graph_edge_boundary(
+    graph: PyGraph, nodes: Sequence[int], /, targets: Sequence[int] | None = ...
+) -> EdgeList: ...
+def digraph_cut_size(
+    graph: PyDiGraph[_S, _T],
+    nodes: Sequence[int],
+    /,
+    targets: Sequence[int] | None = ...,
+    weight_fn: Callable[[_T], float] | Sequence[float] | np.ndarray | None = ...,
+    default_weight: float = ...,
+) -> float: ...
+def graph_cut_size(
+    graph: PyGraph[_S, _T],
+    nodes: Sequence[int],
+    /,
+    targets: Sequence[int] | None = ...,
+    weight_fn: Callable[[_T], float] | Sequence[float] | np.ndarray | None = ...,
+    default_weight: float = ...,
+) -> float: ...
+def digraph_volume(
+    graph: PyDiGraph[_S, _T],
+    nodes: Sequence[int],
+    /,
+    weight_fn: Callable[[_T], float] | Sequence[float] | np.ndarray | None = ...,
+    default_weight: float = ...,
+) -> float: ...
+def graph_volume(
+    graph: PyGraph[_S, _T],
+    nodes: Sequence[int],
+    /,
+    weight_fn: Callable[[_T], float] | Sequence[float] | np.ndarray | None = ...,
+    default_weight: float = ...,
+) -> float: ...
 
 # DAG Algorithms
 
@@ -281,8 +707,16 @@ def dag_weighted_longest_path_length(
     weight_fn: Callable[[int, int, _T], float],
     /,
 ) -> float: ...
+def dag_critical_path(
+    graph: PyDiGraph[_S, _T],
+    /,
+    node_weight_fn: Callable[[_S], float] | None = ...,
+    edge_weight_fn: Callable[[int, int, _T], float] | None = ...,
+) -> tuple[NodeIndices, float, dict[int, dict[str, float]]]: ...
 def is_directed_acyclic_graph(graph: PyDiGraph, /) -> bool: ...
 def topological_sort(graph: PyDiGraph, /) -> NodeIndices: ...
+def all_topological_sorts(graph: PyDiGraph, /) -> Iterator[NodeIndices]: ...
+def count_topological_sorts(graph: PyDiGraph, /) -> int: ...
 def topological_generations(dag: PyDiGraph, /) -> list[NodeIndices]: ...
 def lexicographical_topological_sort(
     dag: PyDiGraph[_S, _T],
@@ -293,6 +727,9 @@ def lexicographical_topological_sort(
     initial: Iterable[int] | None = ...,
 ) -> list[_S]: ...
 def transitive_reduction(graph: PyDiGraph, /) -> tuple[PyDiGraph, dict[int, int]]: ...
+def transitive_closure_dag(graph: PyDiGraph[_S, _T], /) -> PyDiGraph[_S, _T | None]: ...
+def dag_minimum_chain_decomposition(graph: PyDiGraph, /) -> list[NodeIndices]: ...
+def dag_maximum_antichain(graph: PyDiGraph, /) -> NodeIndices: ...
 def layers(
     dag: PyDiGraph[_S, _T],
     first_layer: list[int],
@@ -315,6 +752,14 @@ class TopologicalSorter:
     def get_ready(self) -> list[int]: ...
     def done(self, nodes: int | Sequence[int]) -> None: ...
 
+@final
+class ReachabilityIndex:
+    def __init__(self, graph: PyDiGraph, /) -> None: ...
+    def can_reach(self, source: int, target: int, /) -> bool: ...
+    def descendants(self, node: int, /) -> NodeIndices: ...
+    @property
+    def num_chains(self) -> int: ...
+
 # isomorpism
 
 def digraph_is_isomorphic(
@@ -325,6 +770,8 @@ def digraph_is_isomorphic(
     edge_matcher: Callable[[_T, _T], bool] | None = ...,
     id_order: bool = ...,
     call_limit: int | None = ...,
+    progress_fn: Callable[[int, None], object] | None = ...,
+    progress_interval: int | None = ...,
 ) -> bool: ...
 def graph_is_isomorphic(
     first: PyGraph[_S, _T],
@@ -334,7 +781,27 @@ def graph_is_isomorphic(
     edge_matcher: Callable[[_T, _T], bool] | None = ...,
     id_order: bool = ...,
     call_limit: int | None = ...,
+    progress_fn: Callable[[int, None], object] | None = ...,
+    progress_interval: int | None = ...,
 ) -> bool: ...
+def digraph_distinguishing_invariant(
+    first: PyDiGraph, second: PyDiGraph, /, wl_iterations: int = ...
+) -> str | None: ...
+def graph_distinguishing_invariant(
+    first: PyGraph, second: PyGraph, /, wl_iterations: int = ...
+) -> str | None: ...
+def digraph_canonical_hash(
+    graph: PyDiGraph[_S, _T],
+    /,
+    node_attr_fn: Callable[[_S], Any] | None = ...,
+    edge_attr_fn: Callable[[_T], Any] | None = ...,
+) -> str: ...
+def graph_canonical_hash(
+    graph: PyGraph[_S, _T],
+    /,
+    node_attr_fn: Callable[[_S], Any] | None = ...,
+    edge_attr_fn: Callable[[_T], Any] | None = ...,
+) -> str: ...
 def digraph_is_subgraph_isomorphic(
     first: PyDiGraph[_S, _T],
     second: PyDiGraph[_S, _T],
@@ -344,6 +811,8 @@ def digraph_is_subgraph_isomorphic(
     id_order: bool = ...,
     induced: bool = ...,
     call_limit: int | None = ...,
+    progress_fn: Callable[[int, None], object] | None = ...,
+    progress_interval: int | None = ...,
 ) -> bool: ...
 def graph_is_subgraph_isomorphic(
     first: PyGraph[_S, _T],
@@ -354,6 +823,8 @@ def graph_is_subgraph_isomorphic(
     id_order: bool = ...,
     induced: bool = ...,
     call_limit: int | None = ...,
+    progress_fn: Callable[[int, None], object] | None = ...,
+    progress_interval: int | None = ...,
 ) -> bool: ...
 def digraph_vf2_mapping(
     first: PyDiGraph[_S, _T],
@@ -365,6 +836,8 @@ def digraph_vf2_mapping(
     subgraph: bool = ...,
     induced: bool = ...,
     call_limit: int | None = ...,
+    progress_fn: Callable[[int, None], object] | None = ...,
+    progress_interval: int | None = ...,
 ) -> Iterator[NodeMap]: ...
 def graph_vf2_mapping(
     first: PyGraph[_S, _T],
@@ -376,7 +849,21 @@ def graph_vf2_mapping(
     subgraph: bool = ...,
     induced: bool = ...,
     call_limit: int | None = ...,
+    progress_fn: Callable[[int, None], object] | None = ...,
+    progress_interval: int | None = ...,
 ) -> Iterator[NodeMap]: ...
+def digraph_rewrite(
+    graph: PyDiGraph[_S, _T],
+    pattern: PyDiGraph[_S, _T],
+    replacement: PyDiGraph[_S, _T],
+    /,
+    interface: dict[int, int] | None = ...,
+    node_matcher: Callable[[_S, _S], bool] | None = ...,
+    edge_matcher: Callable[[_T, _T], bool] | None = ...,
+    node_fn: Callable[[_S, dict[int, _S]], _S] | None = ...,
+    edge_fn: Callable[[_T, dict[int, _S]], _T] | None = ...,
+    induced: bool = ...,
+) -> list[tuple[NodeMap, NodeMap]]: ...
 
 # Layout
 
@@ -489,40 +976,124 @@ def graph_spring_layout(
 
 # Line graph
 
+def digraph_line_graph(graph: PyDiGraph, /) -> tuple[PyDiGraph, dict[int, int]]: ...
 def graph_line_graph(graph: PyGraph, /) -> tuple[PyGraph, dict[int, int]]: ...
+def digraph_quotient_graph(
+    graph: PyDiGraph[_S, _T],
+    partition: Sequence[Sequence[int]] | Callable[[_S], Hashable],
+    /,
+    node_fn: Callable[[list[_S]], Any] | None = ...,
+    edge_fn: Callable[[list[_T]], Any] | None = ...,
+    self_loops: bool = ...,
+) -> tuple[PyDiGraph, dict[int, int]]: ...
+def graph_quotient_graph(
+    graph: PyGraph[_S, _T],
+    partition: Sequence[Sequence[int]] | Callable[[_S], Hashable],
+    /,
+    node_fn: Callable[[list[_S]], Any] | None = ...,
+    edge_fn: Callable[[list[_T]], Any] | None = ...,
+    self_loops: bool = ...,
+) -> tuple[PyGraph, dict[int, int]]: ...
+def bipartite_projection(
+    graph: PyGraph[_S, _T],
+    /,
+    nodes: Sequence[int] | None = ...,
+    weight: str = ...,
+) -> tuple[PyGraph[_S, float], dict[int, int]]: ...
 
 # Link Analysis
 
+@overload
 def hits(
     graph: PyDiGraph[_S, _T],
     /,
     weight_fn: Callable[[_T], float] | None = ...,
-    nstart: dict[int, float] | None = ...,
+    nstart: dict[int, float] | Sequence[float] | None = ...,
     tol: float | None = ...,
     max_iter: int | None = ...,
     normalized: bool | None = ...,
+    norm: Literal["l1", "l2", "max"] = ...,
+    return_diagnostics: Literal[False] = ...,
 ) -> tuple[CentralityMapping, CentralityMapping]: ...
+@overload
+def hits(
+    graph: PyDiGraph[_S, _T],
+    /,
+    weight_fn: Callable[[_T], float] | None = ...,
+    nstart: dict[int, float] | Sequence[float] | None = ...,
+    tol: float | None = ...,
+    max_iter: int | None = ...,
+    normalized: bool | None = ...,
+    norm: Literal["l1", "l2", "max"] = ...,
+    *,
+    return_diagnostics: Literal[True],
+) -> tuple[CentralityMapping, CentralityMapping, dict[str, Any]]: ...
+@overload
 def pagerank(
     graph: PyDiGraph[_S, _T],
     /,
     alpha: float | None = ...,
     weight_fn: Callable[[_T], float] | None = ...,
-    nstart: dict[int, float] | None = ...,
-    personalization: dict[int, float] | None = ...,
+    nstart: dict[int, float] | Sequence[float] | None = ...,
+    personalization: dict[int, float] | Sequence[float] | None = ...,
     tol: float | None = ...,
     max_iter: int | None = ...,
-    dangling: dict[int, float] | None = ...,
+    dangling: dict[int, float]
+    | Sequence[float]
+    | Literal["personalization", "uniform", "self"]
+    | None = ...,
+    method: Literal["power", "linear"] = ...,
+    return_diagnostics: Literal[False] = ...,
 ) -> CentralityMapping: ...
-
-# Matching
-
-def max_weight_matching(
-    graph: PyGraph[_S, _T],
+@overload
+def pagerank(
+    graph: PyDiGraph[_S, _T],
+    /,
+    alpha: float | None = ...,
+    weight_fn: Callable[[_T], float] | None = ...,
+    nstart: dict[int, float] | Sequence[float] | None = ...,
+    personalization: dict[int, float] | Sequence[float] | None = ...,
+    tol: float | None = ...,
+    max_iter: int | None = ...,
+    dangling: dict[int, float]
+    | Sequence[float]
+    | Literal["personalization", "uniform", "self"]
+    | None = ...,
+    method: Literal["power", "linear"] = ...,
+    *,
+    return_diagnostics: Literal[True],
+) -> tuple[CentralityMapping, dict[str, Any]]: ...
+def digraph_random_walk_with_restart(
+    graph: PyDiGraph[_S, _T],
+    seeds: Sequence[int],
+    /,
+    restart_prob: float = ...,
+    weight_fn: Callable[[_T], float] | None = ...,
+    default_weight: float = ...,
+    max_iter: int = ...,
+    tol: float = ...,
+) -> CentralityMapping: ...
+def graph_random_walk_with_restart(
+    graph: PyGraph[_S, _T],
+    seeds: Sequence[int],
+    /,
+    restart_prob: float = ...,
+    weight_fn: Callable[[_T], float] | None = ...,
+    default_weight: float = ...,
+    max_iter: int = ...,
+    tol: float = ...,
+) -> CentralityMapping: ...
+
+# Matching
+
+def max_weight_matching(
+    graph: PyGraph[_S, _T],
     /,
     max_cardinality: bool = ...,
     weight_fn: Callable[[_T], float] | None = ...,
     default_weight: int = ...,
     verify_optimum: bool = ...,
+    method: str = ...,
 ) -> set[tuple[int, int]]: ...
 def is_matching(
     graph: PyGraph,
@@ -543,6 +1114,17 @@ def digraph_maximum_bisimulation(graph: PyDiGraph) -> RelationalCoarsestPartitio
 
 def is_planar(graph: PyGraph, /) -> bool: ...
 
+# Minors
+
+def is_minor_of(pattern: PyGraph, graph: PyGraph, /) -> bool: ...
+def treewidth_min_degree(graph: PyGraph, /) -> tuple[int, NodeIndices, PyGraph]: ...
+def treewidth_min_fill_in(graph: PyGraph, /) -> tuple[int, NodeIndices, PyGraph]: ...
+def tree_decomposition(
+    graph: PyGraph, elimination_ordering: Sequence[int], /
+) -> tuple[int, PyGraph]: ...
+def modular_decomposition(graph: PyGraph, /) -> PyDiGraph[tuple[str, list[int]], None]: ...
+def is_cograph(graph: PyGraph, /) -> bool: ...
+
 # Random Graph
 
 def directed_gnm_random_graph(
@@ -657,6 +1239,28 @@ def from_node_link_json_file(
     node_attrs: Callable[[dict[str, str]], _S] | None = ...,
     edge_attrs: Callable[[dict[str, str]], _T] | None = ...,
 ) -> PyDiGraph[_S, _T] | PyGraph[_S, _T]: ...
+def digraph_to_svg(
+    graph: PyDiGraph[_S, _T],
+    pos: Mapping[int, tuple[float, float]],
+    /,
+    node_attr_fn: Callable[[_S], dict[str, str]] | None = ...,
+    edge_attr_fn: Callable[[_T], dict[str, str]] | None = ...,
+    width: float = ...,
+    height: float = ...,
+    with_labels: bool = ...,
+    filename: str | None = ...,
+) -> str | None: ...
+def graph_to_svg(
+    graph: PyGraph[_S, _T],
+    pos: Mapping[int, tuple[float, float]],
+    /,
+    node_attr_fn: Callable[[_S], dict[str, str]] | None = ...,
+    edge_attr_fn: Callable[[_T], dict[str, str]] | None = ...,
+    width: float = ...,
+    height: float = ...,
+    with_labels: bool = ...,
+    filename: str | None = ...,
+) -> str | None: ...
 
 # Shortest Path
 
@@ -665,7 +1269,7 @@ def digraph_bellman_ford_shortest_paths(
     source: int,
     /,
     target: int | None = ...,
-    weight_fn: Callable[[_T], float] | None = ...,
+    weight_fn: Callable[[_T], float] | Sequence[float] | np.ndarray | None = ...,
     default_weight: float = ...,
     as_undirected: bool = ...,
 ) -> PathMapping: ...
@@ -674,20 +1278,20 @@ def graph_bellman_ford_shortest_paths(
     source: int,
     /,
     target: int | None = ...,
-    weight_fn: Callable[[_T], float] | None = ...,
+    weight_fn: Callable[[_T], float] | Sequence[float] | np.ndarray | None = ...,
     default_weight: float = ...,
 ) -> PathMapping: ...
 def digraph_bellman_ford_shortest_path_lengths(
     graph: PyDiGraph[_S, _T],
     node: int,
-    edge_cost_fn: Callable[[_T], float] | None,
+    edge_cost_fn: Callable[[_T], float] | Sequence[float] | np.ndarray | None,
     /,
     goal: int | None = ...,
 ) -> PathLengthMapping: ...
 def graph_bellman_ford_shortest_path_lengths(
     graph: PyGraph[_S, _T],
     node: int,
-    edge_cost_fn: Callable[[_T], float] | None,
+    edge_cost_fn: Callable[[_T], float] | Sequence[float] | np.ndarray | None,
     /,
     goal: int | None = ...,
 ) -> PathLengthMapping: ...
@@ -696,71 +1300,129 @@ def digraph_dijkstra_shortest_paths(
     source: int,
     /,
     target: int | None,
-    weight_fn: Callable[[_T], float] | None = ...,
+    weight_fn: Callable[[_T], float] | Sequence[float] | np.ndarray | None = ...,
     default_weight: float = ...,
     as_undirected: bool = ...,
+    reverse_view: bool = ...,
 ) -> PathMapping: ...
 def graph_dijkstra_shortest_paths(
     graph: PyDiGraph[_S, _T],
     source: int,
     /,
     target: int | None,
-    weight_fn: Callable[[_T], float] | None = ...,
+    weight_fn: Callable[[_T], float] | Sequence[float] | np.ndarray | None = ...,
     default_weight: float = ...,
 ) -> PathMapping: ...
 def digraph_dijkstra_shortest_path_lengths(
     graph: PyDiGraph[_S, _T],
     node: int,
-    edge_cost_fn: Callable[[_T], float] | None,
+    edge_cost_fn: Callable[[_T], float] | Sequence[float] | np.ndarray | None,
     /,
     goal: int | None = ...,
+    reverse_view: bool = ...,
 ) -> PathLengthMapping: ...
 def graph_dijkstra_shortest_path_lengths(
     graph: PyGraph[_S, _T],
     node: int,
-    edge_cost_fn: Callable[[_T], float] | None,
+    edge_cost_fn: Callable[[_T], float] | Sequence[float] | np.ndarray | None,
     /,
     goal: int | None = ...,
 ) -> PathLengthMapping: ...
+def digraph_voronoi_cells(
+    graph: PyDiGraph[_S, _T],
+    sources: Sequence[int],
+    /,
+    weight_fn: Callable[[_T], float] | Sequence[float] | np.ndarray | None = ...,
+    default_weight: float = ...,
+) -> tuple[dict[int, list[int]], PathLengthMapping]: ...
+def graph_voronoi_cells(
+    graph: PyGraph[_S, _T],
+    sources: Sequence[int],
+    /,
+    weight_fn: Callable[[_T], float] | Sequence[float] | np.ndarray | None = ...,
+    default_weight: float = ...,
+) -> tuple[dict[int, list[int]], PathLengthMapping]: ...
 def digraph_all_pairs_bellman_ford_path_lengths(
     graph: PyDiGraph[_S, _T],
-    edge_cost: Callable[[_T], float],
+    edge_cost: Callable[[_T], float] | Sequence[float] | np.ndarray,
     /,
+    num_threads: int | None = ...,
+    progress_fn: Callable[[int, int], object] | None = ...,
+    progress_interval: int | None = ...,
 ) -> AllPairsPathLengthMapping: ...
 def graph_all_pairs_bellman_ford_path_lengths(
     graph: PyGraph[_S, _T],
-    edge_cost: Callable[[_T], float],
+    edge_cost: Callable[[_T], float] | Sequence[float] | np.ndarray,
     /,
+    num_threads: int | None = ...,
+    progress_fn: Callable[[int, int], object] | None = ...,
+    progress_interval: int | None = ...,
 ) -> AllPairsPathLengthMapping: ...
 def digraph_all_pairs_bellman_ford_shortest_paths(
     graph: PyDiGraph[_S, _T],
-    edge_cost: Callable[[_T], float],
+    edge_cost: Callable[[_T], float] | Sequence[float] | np.ndarray,
     /,
+    num_threads: int | None = ...,
+    progress_fn: Callable[[int, int], object] | None = ...,
+    progress_interval: int | None = ...,
 ) -> AllPairsPathMapping: ...
 def graph_all_pairs_bellman_ford_shortest_paths(
     graph: PyDiGraph[_S, _T],
-    edge_cost: Callable[[_T], float],
+    edge_cost: Callable[[_T], float] | Sequence[float] | np.ndarray,
     /,
+    num_threads: int | None = ...,
+    progress_fn: Callable[[int, int], object] | None = ...,
+    progress_interval: int | None = ...,
 ) -> AllPairsPathMapping: ...
 def digraph_all_pairs_dijkstra_path_lengths(
     graph: PyDiGraph[_S, _T],
-    edge_cost: Callable[[_T], float],
+    edge_cost: Callable[[_T], float] | Sequence[float] | np.ndarray,
     /,
+    num_threads: int | None = ...,
+    progress_fn: Callable[[int, int], object] | None = ...,
+    progress_interval: int | None = ...,
 ) -> AllPairsPathLengthMapping: ...
 def graph_all_pairs_dijkstra_path_lengths(
     graph: PyGraph[_S, _T],
-    edge_cost: Callable[[_T], float],
+    edge_cost: Callable[[_T], float] | Sequence[float] | np.ndarray,
     /,
+    num_threads: int | None = ...,
+    progress_fn: Callable[[int, int], object] | None = ...,
+    progress_interval: int | None = ...,
 ) -> AllPairsPathLengthMapping: ...
+def digraph_all_pairs_dijkstra_path_lengths_csr(
+    graph: PyDiGraph[_S, _T],
+    edge_cost: Callable[[_T], float] | Sequence[float] | np.ndarray,
+    /,
+    cutoff: float | None = ...,
+    num_threads: int | None = ...,
+    progress_fn: Callable[[int, int], object] | None = ...,
+    progress_interval: int | None = ...,
+) -> tuple[np.ndarray, np.ndarray, np.ndarray]: ...
+def graph_all_pairs_dijkstra_path_lengths_csr(
+    graph: PyGraph[_S, _T],
+    edge_cost: Callable[[_T], float] | Sequence[float] | np.ndarray,
+    /,
+    cutoff: float | None = ...,
+    num_threads: int | None = ...,
+    progress_fn: Callable[[int, int], object] | None = ...,
+    progress_interval: int | None = ...,
+) -> tuple[np.ndarray, np.ndarray, np.ndarray]: ...
 def digraph_all_pairs_dijkstra_shortest_paths(
     graph: PyDiGraph[_S, _T],
-    edge_cost: Callable[[_T], float],
+    edge_cost: Callable[[_T], float] | Sequence[float] | np.ndarray,
     /,
+    num_threads: int | None = ...,
+    progress_fn: Callable[[int, int], object] | None = ...,
+    progress_interval: int | None = ...,
 ) -> AllPairsPathMapping: ...
 def graph_all_pairs_dijkstra_shortest_paths(
     graph: PyDiGraph[_S, _T],
-    edge_cost: Callable[[_T], float],
+    edge_cost: Callable[[_T], float] | Sequence[float] | np.ndarray,
     /,
+    num_threads: int | None = ...,
+    progress_fn: Callable[[int, int], object] | None = ...,
+    progress_interval: int | None = ...,
 ) -> AllPairsPathMapping: ...
 def digraph_astar_shortest_path(
     graph: PyDiGraph[_S, _T],
@@ -800,6 +1462,7 @@ def digraph_has_path(
     target: int,
     /,
     as_undirected: bool | None = ...,
+    reverse_view: bool = ...,
 ) -> bool: ...
 def graph_has_path(
     graph: PyGraph,
@@ -826,123 +1489,502 @@ def digraph_unweighted_average_shortest_path_length(
 def graph_unweighted_average_shortest_path_length(
     graph: PyGraph,
     /,
-    parallel_threshold: int | None = ...,
-    disconnected: bool | None = ...,
-) -> float: ...
-def digraph_distance_matrix(
-    graph: PyDiGraph,
+    parallel_threshold: int | None = ...,
+    disconnected: bool | None = ...,
+) -> float: ...
+def digraph_distance_matrix(
+    graph: PyDiGraph,
+    /,
+    parallel_threshold: int | None = ...,
+    as_undirected: bool | None = ...,
+    null_value: float | None = ...,
+) -> np.ndarray: ...
+def graph_distance_matrix(
+    graph: PyGraph,
+    /,
+    parallel_threshold: int | None = ...,
+    null_value: float | None = ...,
+) -> np.ndarray: ...
+def digraph_floyd_warshall(
+    graph: PyDiGraph[_S, _T],
+    /,
+    weight_fn: Callable[[_T], float] | Sequence[float] | np.ndarray | None = ...,
+    as_undirected: bool | None = ...,
+    default_weight: float | None = ...,
+    parallel_threshold: int | None = ...,
+) -> AllPairsPathLengthMapping: ...
+def graph_floyd_warshall(
+    graph: PyGraph[_S, _T],
+    /,
+    weight_fn: Callable[[_T], float] | Sequence[float] | np.ndarray | None = ...,
+    default_weight: float | None = ...,
+    parallel_threshold: int | None = ...,
+) -> AllPairsPathLengthMapping: ...
+def digraph_floyd_warshall_numpy(
+    graph: PyDiGraph[_S, _T],
+    /,
+    weight_fn: Callable[[_T], float] | Sequence[float] | np.ndarray | None = ...,
+    as_undirected: bool | None = ...,
+    default_weight: float | None = ...,
+    parallel_threshold: int | None = ...,
+) -> np.ndarray: ...
+def graph_floyd_warshall_numpy(
+    graph: PyGraph[_S, _T],
+    /,
+    weight_fn: Callable[[_T], float] | Sequence[float] | np.ndarray | None = ...,
+    default_weight: float | None = ...,
+    parallel_threshold: int | None = ...,
+) -> np.ndarray: ...
+def digraph_floyd_warshall_successor_and_distance(
+    graph: PyDiGraph[_S, _T],
+    /,
+    weight_fn: Callable[[_T], float] | Sequence[float] | np.ndarray | None = ...,
+    as_undirected: bool | None = ...,
+    default_weight: float | None = ...,
+    parallel_threshold: int | None = ...,
+) -> tuple[np.ndarray, np.ndarray]: ...
+def graph_floyd_warshall_successor_and_distance(
+    graph: PyGraph[_S, _T],
+    /,
+    weight_fn: Callable[[_T], float] | Sequence[float] | np.ndarray | None = ...,
+    default_weight: float | None = ...,
+    parallel_threshold: int | None = ...,
+) -> tuple[np.ndarray, np.ndarray]: ...
+def find_negative_cycle(
+    graph: PyDiGraph[_S, _T],
+    edge_cost_fn: Callable[[_T], float] | Sequence[float] | np.ndarray,
+    /,
+) -> NodeIndices: ...
+def negative_edge_cycle(
+    graph: PyDiGraph[_S, _T],
+    edge_cost_fn: Callable[[_T], float] | Sequence[float] | np.ndarray,
+    /,
+) -> bool: ...
+def digraph_all_shortest_paths(
+    graph: PyDiGraph[_S, _T],
+    source: int,
+    target: int,
+    /,
+    weight_fn: Callable[[_T], float] | Sequence[float] | np.ndarray | None = ...,
+    default_weight: float = ...,
+    as_undirected: bool = ...,
+) -> list[list[int]]: ...
+def graph_all_shortest_paths(
+    graph: PyGraph[_S, _T],
+    source: int,
+    target: int,
+    /,
+    weight_fn: Callable[[_T], float] | Sequence[float] | np.ndarray | None = ...,
+    default_weight: float = ...,
+) -> list[list[int]]: ...
+
+# Tensor Product
+
+def digraph_tensor_product(
+    first: PyDiGraph,
+    second: PyDiGraph,
+    /,
+    node_fn: Callable[[Any, Any], Any] | None = ...,
+    edge_fn: Callable[[Any, Any], Any] | None = ...,
+) -> tuple[PyDiGraph, ProductNodeMap]: ...
+def graph_tensor_product(
+    first: PyGraph,
+    second: PyGraph,
+    /,
+    node_fn: Callable[[Any, Any], Any] | None = ...,
+    edge_fn: Callable[[Any, Any], Any] | None = ...,
+) -> tuple[PyGraph, ProductNodeMap]: ...
+
+# Strong Product
+
+def digraph_strong_product(
+    first: PyDiGraph,
+    second: PyDiGraph,
+    /,
+    node_fn: Callable[[Any, Any], Any] | None = ...,
+    edge_fn: Callable[[Any, Any], Any] | None = ...,
+) -> tuple[PyDiGraph, ProductNodeMap]: ...
+def graph_strong_product(
+    first: PyGraph,
+    second: PyGraph,
+    /,
+    node_fn: Callable[[Any, Any], Any] | None = ...,
+    edge_fn: Callable[[Any, Any], Any] | None = ...,
+) -> tuple[PyGraph, ProductNodeMap]: ...
+
+# Lexicographic Product
+
+def digraph_lexicographic_product(
+    first: PyDiGraph,
+    second: PyDiGraph,
+    /,
+    node_fn: Callable[[Any, Any], Any] | None = ...,
+    edge_fn: Callable[[Any, Any], Any] | None = ...,
+) -> tuple[PyDiGraph, ProductNodeMap]: ...
+def graph_lexicographic_product(
+    first: PyGraph,
+    second: PyGraph,
+    /,
+    node_fn: Callable[[Any, Any], Any] | None = ...,
+    edge_fn: Callable[[Any, Any], Any] | None = ...,
+) -> tuple[PyGraph, ProductNodeMap]: ...
+
+# Token Swapper
+
+def graph_token_swapper(
+    graph: PyGraph,
+    mapping: dict[int, int],
+    /,
+    trials: int | None = ...,
+    seed: int | None = ...,
+    parallel_threshold: int | None = ...,
+) -> EdgeList: ...
+
+# Transitivity
+
+def digraph_transitivity(graph: PyDiGraph, /) -> float: ...
+def graph_transitivity(graph: PyGraph, /) -> float: ...
+
+# Summary
+
+@final
+class GraphSummary:
+    @property
+    def num_nodes(self) -> int: ...
+    @property
+    def num_edges(self) -> int: ...
+    @property
+    def density(self) -> float: ...
+    @property
+    def min_degree(self) -> int: ...
+    @property
+    def max_degree(self) -> int: ...
+    @property
+    def mean_degree(self) -> float: ...
+    @property
+    def num_components(self) -> int: ...
+    @property
+    def average_clustering(self) -> float: ...
+    @property
+    def is_dag(self) -> bool: ...
+    @property
+    def is_forest(self) -> bool: ...
+    @property
+    def is_bipartite(self) -> bool: ...
+
+def digraph_graph_summary(
+    graph: PyDiGraph,
+    /,
+    clustering_samples: int | None = ...,
+    seed: int | None = ...,
+) -> GraphSummary: ...
+def graph_graph_summary(
+    graph: PyGraph,
+    /,
+    clustering_samples: int | None = ...,
+    seed: int | None = ...,
+) -> GraphSummary: ...
+
+# Assortativity
+
+def digraph_degree_assortativity_coefficient(
+    graph: PyDiGraph[_S, _T],
+    /,
+    weight_fn: Callable[[_T], float] | None = ...,
+    default_weight: float = ...,
+    source: str = ...,
+    target: str = ...,
+) -> float: ...
+def graph_degree_assortativity_coefficient(
+    graph: PyGraph[_S, _T],
+    /,
+    weight_fn: Callable[[_T], float] | None = ...,
+    default_weight: float = ...,
+) -> float: ...
+def digraph_attribute_assortativity_coefficient(
+    graph: PyDiGraph[_S, _T], attribute_fn: Callable[[_S], Hashable], /
+) -> float: ...
+def graph_attribute_assortativity_coefficient(
+    graph: PyGraph[_S, _T], attribute_fn: Callable[[_S], Hashable], /
+) -> float: ...
+def digraph_degree_histogram(graph: PyDiGraph, /) -> list[int]: ...
+def graph_degree_histogram(graph: PyGraph, /) -> list[int]: ...
+def digraph_joint_degree_matrix(
+    graph: PyDiGraph,
+    /,
+    normalized: bool = ...,
+    source: str = ...,
+    target: str = ...,
+) -> dict[tuple[int, int], float]: ...
+def graph_joint_degree_matrix(
+    graph: PyGraph, /, normalized: bool = ...
+) -> dict[tuple[int, int], float]: ...
+def digraph_average_neighbor_degree(
+    graph: PyDiGraph[_S, _T],
+    /,
+    weight_fn: Callable[[_T], float] | None = ...,
+    default_weight: float = ...,
+    source: str = ...,
+    target: str = ...,
+) -> dict[int, float]: ...
+def graph_average_neighbor_degree(
+    graph: PyGraph[_S, _T],
+    /,
+    weight_fn: Callable[[_T], float] | None = ...,
+    default_weight: float = ...,
+) -> dict[int, float]: ...
+def rich_club_coefficient(
+    graph: PyGraph,
+    /,
+    normalized: bool = ...,
+    num_randomizations: int = ...,
+    swaps_per_edge: int = ...,
+    seed: int | None = ...,
+) -> dict[int, float]: ...
+def graph_rewire(
+    graph: PyGraph,
+    n_swaps: int,
+    /,
+    max_tries: int | None = ...,
+    seed: int | None = ...,
+) -> int: ...
+def digraph_rewire(
+    graph: PyDiGraph,
+    n_swaps: int,
+    /,
+    max_tries: int | None = ...,
+    seed: int | None = ...,
+) -> int: ...
+def graph_motif_counts(
+    graph: PyGraph,
+    /,
+    size: int = ...,
+    probabilities: Sequence[float] | None = ...,
+    seed: int | None = ...,
+) -> dict[tuple[tuple[int, int], ...], float]: ...
+def digraph_motif_counts(
+    graph: PyDiGraph,
+    /,
+    size: int = ...,
+    probabilities: Sequence[float] | None = ...,
+    seed: int | None = ...,
+) -> dict[tuple[tuple[int, int], ...], float]: ...
+def graph_motif_significance_profile(
+    graph: PyGraph,
+    /,
+    size: int = ...,
+    num_randomizations: int = ...,
+    swaps_per_edge: int = ...,
+    probabilities: Sequence[float] | None = ...,
+    seed: int | None = ...,
+) -> dict[tuple[tuple[int, int], ...], dict[str, float]]: ...
+def digraph_motif_significance_profile(
+    graph: PyDiGraph,
+    /,
+    size: int = ...,
+    num_randomizations: int = ...,
+    swaps_per_edge: int = ...,
+    probabilities: Sequence[float] | None = ...,
+    seed: int | None = ...,
+) -> dict[tuple[tuple[int, int], ...], dict[str, float]]: ...
+def triad_census(graph: PyDiGraph, /) -> dict[str, int]: ...
+
+# Temporal graphs
+
+def digraph_earliest_arrival_times(
+    graph: PyDiGraph[_S, _T],
+    source: int,
+    time_fn: Callable[[_T], float | tuple[float, float]],
+    /,
+    start_time: float | None = ...,
+    end_time: float | None = ...,
+) -> PathLengthMapping: ...
+def graph_earliest_arrival_times(
+    graph: PyGraph[_S, _T],
+    source: int,
+    time_fn: Callable[[_T], float | tuple[float, float]],
+    /,
+    start_time: float | None = ...,
+    end_time: float | None = ...,
+) -> PathLengthMapping: ...
+def digraph_earliest_arrival_paths(
+    graph: PyDiGraph[_S, _T],
+    source: int,
+    time_fn: Callable[[_T], float | tuple[float, float]],
+    /,
+    start_time: float | None = ...,
+    end_time: float | None = ...,
+) -> PathMapping: ...
+def graph_earliest_arrival_paths(
+    graph: PyGraph[_S, _T],
+    source: int,
+    time_fn: Callable[[_T], float | tuple[float, float]],
+    /,
+    start_time: float | None = ...,
+    end_time: float | None = ...,
+) -> PathMapping: ...
+def digraph_fastest_path_durations(
+    graph: PyDiGraph[_S, _T],
+    source: int,
+    time_fn: Callable[[_T], float | tuple[float, float]],
+    /,
+    start_time: float | None = ...,
+    end_time: float | None = ...,
+) -> PathLengthMapping: ...
+def graph_fastest_path_durations(
+    graph: PyGraph[_S, _T],
+    source: int,
+    time_fn: Callable[[_T], float | tuple[float, float]],
+    /,
+    start_time: float | None = ...,
+    end_time: float | None = ...,
+) -> PathLengthMapping: ...
+def digraph_temporal_reachability(
+    graph: PyDiGraph[_S, _T],
+    source: int,
+    time_fn: Callable[[_T], float | tuple[float, float]],
+    /,
+    start_time: float | None = ...,
+    end_time: float | None = ...,
+) -> set[int]: ...
+def graph_temporal_reachability(
+    graph: PyGraph[_S, _T],
+    source: int,
+    time_fn: Callable[[_T], float | tuple[float, float]],
+    /,
+    start_time: float | None = ...,
+    end_time: float | None = ...,
+) -> set[int]: ...
+def digraph_temporal_snapshot(
+    graph: PyDiGraph[_S, _T],
+    time_fn: Callable[[_T], float | tuple[float, float]],
+    start_time: float,
     /,
-    parallel_threshold: int | None = ...,
-    as_undirected: bool | None = ...,
-    null_value: float | None = ...,
-) -> np.ndarray: ...
-def graph_distance_matrix(
-    graph: PyGraph,
+    end_time: float | None = ...,
+) -> PyDiGraph[_S, _T]: ...
+def graph_temporal_snapshot(
+    graph: PyGraph[_S, _T],
+    time_fn: Callable[[_T], float | tuple[float, float]],
+    start_time: float,
     /,
-    parallel_threshold: int | None = ...,
-    null_value: float | None = ...,
-) -> np.ndarray: ...
-def digraph_floyd_warshall(
+    end_time: float | None = ...,
+) -> PyGraph[_S, _T]: ...
+
+# Community
+
+def digraph_modularity(
     graph: PyDiGraph[_S, _T],
+    communities: Sequence[Sequence[int]],
     /,
     weight_fn: Callable[[_T], float] | None = ...,
-    as_undirected: bool | None = ...,
-    default_weight: float | None = ...,
-    parallel_threshold: int | None = ...,
-) -> AllPairsPathLengthMapping: ...
-def graph_floyd_warshall(
+    default_weight: float = ...,
+    resolution: float = ...,
+) -> float: ...
+def graph_modularity(
     graph: PyGraph[_S, _T],
+    communities: Sequence[Sequence[int]],
     /,
     weight_fn: Callable[[_T], float] | None = ...,
-    default_weight: float | None = ...,
-    parallel_threshold: int | None = ...,
-) -> AllPairsPathLengthMapping: ...
-def digraph_floyd_warshall_numpy(
+    default_weight: float = ...,
+    resolution: float = ...,
+) -> float: ...
+def digraph_sum_parallel_edges(
     graph: PyDiGraph[_S, _T],
     /,
     weight_fn: Callable[[_T], float] | None = ...,
-    as_undirected: bool | None = ...,
-    default_weight: float | None = ...,
-    parallel_threshold: int | None = ...,
-) -> np.ndarray: ...
-def graph_floyd_warshall_numpy(
+    default_weight: float = ...,
+    combine_fn: Callable[[float, float], float] | None = ...,
+) -> PyDiGraph[_S, float]: ...
+def graph_sum_parallel_edges(
     graph: PyGraph[_S, _T],
     /,
     weight_fn: Callable[[_T], float] | None = ...,
-    default_weight: float | None = ...,
-    parallel_threshold: int | None = ...,
-) -> np.ndarray: ...
-def digraph_floyd_warshall_successor_and_distance(
+    default_weight: float = ...,
+    combine_fn: Callable[[float, float], float] | None = ...,
+) -> PyGraph[_S, float]: ...
+def digraph_community_subgraphs(
     graph: PyDiGraph[_S, _T],
+    communities: Sequence[Sequence[int]],
     /,
-    weight_fn: Callable[[_T], float] | None = ...,
-    as_undirected: bool | None = ...,
-    default_weight: float | None = ...,
-    parallel_threshold: int | None = ...,
-) -> tuple[np.ndarray, np.ndarray]: ...
-def graph_floyd_warshall_successor_and_distance(
+    preserve_attrs: bool = ...,
+) -> list[tuple[PyDiGraph[_S, _T], NodeMap]]: ...
+def graph_community_subgraphs(
     graph: PyGraph[_S, _T],
+    communities: Sequence[Sequence[int]],
     /,
-    weight_fn: Callable[[_T], float] | None = ...,
-    default_weight: float | None = ...,
-    parallel_threshold: int | None = ...,
-) -> tuple[np.ndarray, np.ndarray]: ...
-def find_negative_cycle(
+    preserve_attrs: bool = ...,
+) -> list[tuple[PyGraph[_S, _T], NodeMap]]: ...
+
+# Small world
+
+def sigma(
+    graph: PyGraph,
+    /,
+    num_iterations: int = ...,
+    num_randomizations: int = ...,
+    seed: int | None = ...,
+) -> float: ...
+def omega(
+    graph: PyGraph,
+    /,
+    num_iterations: int = ...,
+    num_randomizations: int = ...,
+    seed: int | None = ...,
+) -> float: ...
+
+# Distance
+
+def diameter(graph: PyGraph, /) -> int: ...
+def radius(graph: PyGraph, /) -> int: ...
+def global_efficiency(graph: PyGraph, /) -> float: ...
+def local_efficiency(graph: PyGraph, /) -> float: ...
+def digraph_eccentricity(
     graph: PyDiGraph[_S, _T],
-    edge_cost_fn: Callable[[_T], float],
     /,
-) -> NodeIndices: ...
-def negative_edge_cycle(
+    weight_fn: Callable[[_T], float] | None = ...,
+    default_weight: float = ...,
+) -> dict[int, float]: ...
+def digraph_center(
     graph: PyDiGraph[_S, _T],
-    edge_cost_fn: Callable[[_T], float],
     /,
-) -> bool: ...
-def digraph_all_shortest_paths(
+    weight_fn: Callable[[_T], float] | None = ...,
+    default_weight: float = ...,
+) -> NodeIndices: ...
+def digraph_periphery(
     graph: PyDiGraph[_S, _T],
-    source: int,
-    target: int,
     /,
     weight_fn: Callable[[_T], float] | None = ...,
     default_weight: float = ...,
-    as_undirected: bool = ...,
-) -> list[list[int]]: ...
-def graph_all_shortest_paths(
+) -> NodeIndices: ...
+def graph_eccentricity(
     graph: PyGraph[_S, _T],
-    source: int,
-    target: int,
     /,
     weight_fn: Callable[[_T], float] | None = ...,
     default_weight: float = ...,
-) -> list[list[int]]: ...
-
-# Tensor Product
-
-def digraph_tensor_product(
-    first: PyDiGraph,
-    second: PyDiGraph,
+) -> dict[int, float]: ...
+def graph_center(
+    graph: PyGraph[_S, _T],
     /,
-) -> tuple[PyDiGraph, ProductNodeMap]: ...
-def graph_tensor_product(
-    first: PyGraph,
-    second: PyGraph,
+    weight_fn: Callable[[_T], float] | None = ...,
+    default_weight: float = ...,
+) -> NodeIndices: ...
+def graph_periphery(
+    graph: PyGraph[_S, _T],
     /,
-) -> tuple[PyGraph, ProductNodeMap]: ...
+    weight_fn: Callable[[_T], float] | None = ...,
+    default_weight: float = ...,
+) -> NodeIndices: ...
 
-# Token Swapper
+# Clustering
 
-def graph_token_swapper(
-    graph: PyGraph,
-    mapping: dict[int, int],
+def square_clustering(graph: PyGraph, /) -> dict[int, float]: ...
+def weighted_clustering(
+    graph: PyGraph[_S, _T],
     /,
-    trials: int | None = ...,
-    seed: int | None = ...,
-    parallel_threshold: int | None = ...,
-) -> EdgeList: ...
-
-# Transitivity
-
-def digraph_transitivity(graph: PyDiGraph, /) -> float: ...
-def graph_transitivity(graph: PyGraph, /) -> float: ...
+    weight_fn: Callable[[_T], float] | None = ...,
+    default_weight: float = ...,
+    method: str = ...,
+) -> dict[int, float]: ...
 
 # Traversal
 
@@ -995,12 +2037,56 @@ def minimum_spanning_edges(
     graph: PyGraph[_S, _T],
     weight_fn: Callable[[_T], float] | None = ...,
     default_weight: float = ...,
+    parallel_threshold: int = ...,
 ) -> WeightedEdgeList: ...
 def minimum_spanning_tree(
     graph: PyGraph[_S, _T],
     weight_fn: Callable[[_T], float] | None = ...,
     default_weight: float = ...,
+    parallel_threshold: int = ...,
+) -> PyGraph[_S, _T]: ...
+def minimum_spanning_arborescence(
+    graph: PyDiGraph[_S, _T],
+    /,
+    root: int | None = ...,
+    weight_fn: Callable[[_T], float] | None = ...,
+    default_weight: float = ...,
+) -> PyDiGraph[_S, _T]: ...
+def random_spanning_tree(
+    graph: PyGraph[_S, _T],
+    /,
+    seed: int | None = ...,
+    weight_fn: Callable[[_T], float] | None = ...,
+    default_weight: float = ...,
+) -> WeightedEdgeList: ...
+def k_smallest_spanning_trees(
+    graph: PyGraph[_S, _T],
+    k: int,
+    /,
+    weight_fn: Callable[[_T], float] | None = ...,
+    default_weight: float = ...,
+) -> list[tuple[float, WeightedEdgeList]]: ...
+def greedy_spanner(
+    graph: PyGraph[_S, _T],
+    stretch: float,
+    /,
+    weight_fn: Callable[[_T], float] | None = ...,
+    default_weight: float = ...,
+) -> WeightedEdgeList: ...
+def degree_constrained_spanning_tree(
+    graph: PyGraph[_S, _T],
+    max_degree: int | dict[int, int],
+    /,
+    weight_fn: Callable[[_T], float] | None = ...,
+    default_weight: float = ...,
+    max_passes: int = ...,
 ) -> PyGraph[_S, _T]: ...
+def tree_center(graph: PyGraph[_S, _T], /) -> NodeIndices: ...
+def tree_centroid(graph: PyGraph[_S, _T], /) -> NodeIndices: ...
+def centroid_decomposition(graph: PyGraph[_S, _T], /) -> dict[int, int | None]: ...
+def root_tree(
+    graph: PyGraph[_S, _T], /, root: int | None = ...
+) -> tuple[dict[int, int | None], NodeIndices]: ...
 def steiner_tree(
     graph: PyGraph[_S, _T],
     terminal_nodes: list[int],
@@ -1015,20 +2101,110 @@ def metric_closure(
 
 # Union
 
+@final
+class UnionMapping:
+    @property
+    def first_nodes(self) -> NodeMap: ...
+    @property
+    def second_nodes(self) -> NodeMap: ...
+    @property
+    def first_edges(self) -> EdgeMap: ...
+    @property
+    def second_edges(self) -> EdgeMap: ...
+    @property
+    def merged_nodes(self) -> NodeIndices: ...
+    @property
+    def merged_edges(self) -> EdgeIndices: ...
+
 def digraph_union(
     first: PyDiGraph[_S, _T],
     second: PyDiGraph[_S, _T],
     /,
     merge_nodes: bool = ...,
     merge_edges: bool = ...,
+    key_fn: Callable[[_S], Hashable] | None = ...,
 ) -> PyDiGraph[_S, _T]: ...
+def digraph_union_with_mapping(
+    first: PyDiGraph[_S, _T],
+    second: PyDiGraph[_S, _T],
+    /,
+    merge_nodes: bool = ...,
+    merge_edges: bool = ...,
+    key_fn: Callable[[_S], Hashable] | None = ...,
+) -> tuple[PyDiGraph[_S, _T], UnionMapping]: ...
 def graph_union(
     first: PyGraph[_S, _T],
     second: PyGraph[_S, _T],
     /,
     merge_nodes: bool = ...,
     merge_edges: bool = ...,
+    key_fn: Callable[[_S], Hashable] | None = ...,
+) -> PyGraph[_S, _T]: ...
+def graph_union_with_mapping(
+    first: PyGraph[_S, _T],
+    second: PyGraph[_S, _T],
+    /,
+    merge_nodes: bool = ...,
+    merge_edges: bool = ...,
+    key_fn: Callable[[_S], Hashable] | None = ...,
+) -> tuple[PyGraph[_S, _T], UnionMapping]: ...
+
+# Set operations
+
+def digraph_intersection(
+    first: PyDiGraph[_S, _T],
+    second: PyDiGraph[_S, _T],
+    /,
+    align_by: str = ...,
+    node_merge_fn: Callable[[_S, _S], _S] | None = ...,
+    edge_merge_fn: Callable[[_T, _T], _T] | None = ...,
+) -> PyDiGraph[_S, _T]: ...
+def graph_intersection(
+    first: PyGraph[_S, _T],
+    second: PyGraph[_S, _T],
+    /,
+    align_by: str = ...,
+    node_merge_fn: Callable[[_S, _S], _S] | None = ...,
+    edge_merge_fn: Callable[[_T, _T], _T] | None = ...,
+) -> PyGraph[_S, _T]: ...
+def digraph_difference(
+    first: PyDiGraph[_S, _T],
+    second: PyDiGraph[_S, _T],
+    /,
+    align_by: str = ...,
+) -> PyDiGraph[_S, _T]: ...
+def graph_difference(
+    first: PyGraph[_S, _T],
+    second: PyGraph[_S, _T],
+    /,
+    align_by: str = ...,
+) -> PyGraph[_S, _T]: ...
+def digraph_symmetric_difference(
+    first: PyDiGraph[_S, _T],
+    second: PyDiGraph[_S, _T],
+    /,
+    align_by: str = ...,
+    node_merge_fn: Callable[[_S, _S], _S] | None = ...,
+) -> PyDiGraph[_S, _T]: ...
+def graph_symmetric_difference(
+    first: PyGraph[_S, _T],
+    second: PyGraph[_S, _T],
+    /,
+    align_by: str = ...,
+    node_merge_fn: Callable[[_S, _S], _S] | None = ...,
 ) -> PyGraph[_S, _T]: ...
+def digraph_full_join(
+    first: PyDiGraph[_S, _T],
+    second: PyDiGraph[_S, _T],
+    /,
+    join_fn: Callable[[_S, _S], Any] | None = ...,
+) -> PyDiGraph[_S, Any]: ...
+def graph_full_join(
+    first: PyGraph[_S, _T],
+    second: PyGraph[_S, _T],
+    /,
+    join_fn: Callable[[_S, _S], Any] | None = ...,
+) -> PyGraph[_S, Any]: ...
 
 # Iterators
 
@@ -1107,6 +2283,9 @@ class EdgeList(_RustworkxCustomVecIter[tuple[int, int]]): ...
 @final
 class NodeMap(_RustworkxCustomHashMapIter[int, int]): ...
 
+@final
+class EdgeMap(_RustworkxCustomHashMapIter[int, int]): ...
+
 @final
 class NodesCountMapping(_RustworkxCustomHashMapIter[int, int]): ...
 
@@ -1177,6 +2356,23 @@ class PyGraph(Generic[_S, _T]):
         /,
         weight_combo_fn: Callable[[_T, _T], _T] | None = ...,
     ) -> int: ...
+    def contract_edge(
+        self,
+        node_a: int,
+        node_b: int,
+        /,
+        node_fn: Callable[[_S, _S], _S] | None = ...,
+        weight_combo_fn: Callable[[_T, _T], _T] | None = ...,
+        self_loops: bool = ...,
+    ) -> int: ...
+    def contract_edges(
+        self,
+        edge_list: Sequence[tuple[int, int]],
+        /,
+        node_fn: Callable[[_S, _S], _S] | None = ...,
+        weight_combo_fn: Callable[[_T, _T], _T] | None = ...,
+        self_loops: bool = ...,
+    ) -> NodeMap: ...
     def copy(self) -> PyGraph[_S, _T]: ...
     def degree(self, node: int, /) -> int: ...
     def edge_index_map(self) -> EdgeIndexMap[_T]: ...
@@ -1201,6 +2397,16 @@ class PyGraph(Generic[_S, _T]):
         /,
     ) -> int | None: ...
     @staticmethod
+    def from_edge_iterator(
+        edges: Iterable[tuple[int, int] | tuple[int, int, _T]],
+        /,
+        num_nodes: int | None = ...,
+        num_edges: int | None = ...,
+        multigraph: bool = ...,
+        combine_fn: Callable[[_T, _T], _T] | None = ...,
+        chunk_size: int = ...,
+    ) -> PyGraph[None, _T]: ...
+    @staticmethod
     def from_adjacency_matrix(
         matrix: np.ndarray, /, null_value: float = ...
     ) -> PyGraph[int, float]: ...
@@ -1219,6 +2425,14 @@ class PyGraph(Generic[_S, _T]):
     def in_edges(self, node: int, /) -> WeightedEdgeList[_T]: ...
     def incident_edge_index_map(self, node: int, /) -> EdgeIndexMap: ...
     def incident_edges(self, node: int, /) -> EdgeIndices: ...
+    def merge_nodes_by_key(
+        self,
+        key_fn: Callable[[_S], Hashable],
+        /,
+        node_fn: Callable[[_S, _S], _S] | None = ...,
+        weight_combo_fn: Callable[[_T, _T], _T] | None = ...,
+        self_loops: bool = ...,
+    ) -> NodeMap: ...
     def neighbors(self, node: int, /) -> NodeIndices: ...
     def node_indexes(self) -> NodeIndices: ...
     def node_indices(self) -> NodeIndices: ...
@@ -1240,6 +2454,13 @@ class PyGraph(Generic[_S, _T]):
     def remove_node(self, node: int, /) -> None: ...
     def remove_nodes_from(self, index_list: Sequence[int], /) -> None: ...
     def subgraph(self, nodes: Sequence[int], /, preserve_attrs: bool = ...) -> PyGraph[_S, _T]: ...
+    def subgraph_view(
+        self,
+        /,
+        nodes: Sequence[int] | None = ...,
+        edges: Sequence[int] | None = ...,
+        preserve_attrs: bool = ...,
+    ) -> PyGraph[_S, _T]: ...
     def substitute_node_with_subgraph(
         self,
         node: int,
@@ -1249,6 +2470,7 @@ class PyGraph(Generic[_S, _T]):
         node_filter: Callable[[_S], bool] | None = ...,
         edge_weight_map: Callable[[_T], _T] | None = ...,
     ) -> NodeMap: ...
+    def to_csr(self) -> tuple[np.ndarray, np.ndarray, np.ndarray]: ...
     def to_dot(
         self,
         /,
@@ -1282,6 +2504,39 @@ class PyGraph(Generic[_S, _T]):
     def __setitem__(self, idx: int, value: _S, /) -> None: ...
     def __setstate__(self, state, /) -> None: ...
 
+# Hypergraph
+
+class PyHypergraph(Generic[_S, _T]):
+    def __init__(self) -> None: ...
+    def add_hyperedge(self, nodes: Sequence[int], /, obj: _T | None = ...) -> int: ...
+    def add_node(self, obj: _S, /) -> int: ...
+    def add_nodes_from(self, obj_list: Sequence[_S], /) -> NodeIndices: ...
+    def copy(self) -> Self: ...
+    def degree(self, node: int, /) -> int: ...
+    @staticmethod
+    def from_incidence_graph(
+        graph: PyGraph[_S, Any], hyperedges: set[int], /
+    ) -> PyHypergraph[_S, _S]: ...
+    def get_hyperedge_data(self, hyperedge: int, /) -> _T: ...
+    def get_node_data(self, node: int, /) -> _S: ...
+    def hyperedge_indices(self) -> EdgeIndices: ...
+    def hyperedge_nodes(self, hyperedge: int, /) -> NodeIndices: ...
+    def hyperedges(self) -> list[_T]: ...
+    def incident_hyperedges(self, node: int, /) -> EdgeIndices: ...
+    def neighbors(self, node: int, /) -> NodeIndices: ...
+    def node_indices(self) -> NodeIndices: ...
+    def nodes(self) -> list[_S]: ...
+    def num_hyperedges(self) -> int: ...
+    def num_nodes(self) -> int: ...
+    def remove_hyperedge(self, hyperedge: int, /) -> None: ...
+    def remove_node(self, node: int, /) -> None: ...
+    def to_clique_expansion(self) -> tuple[PyGraph[_S, list[int]], NodeMap]: ...
+    def to_incidence_graph(self) -> tuple[PyGraph[_S | _T, None], NodeMap, NodeMap]: ...
+    def __delitem__(self, idx: int, /) -> None: ...
+    def __getitem__(self, idx: int, /) -> _S: ...
+    def __len__(self) -> int: ...
+    def __setitem__(self, idx: int, value: _S, /) -> None: ...
+
 # Digraph
 
 class PyDiGraph(Generic[_S, _T]):
@@ -1331,6 +2586,25 @@ class PyDiGraph(Generic[_S, _T]):
         check_cycle: bool | None = ...,
         weight_combo_fn: Callable[[_T, _T], _T] | None = ...,
     ) -> int: ...
+    def contract_edge(
+        self,
+        source: int,
+        target: int,
+        /,
+        node_fn: Callable[[_S, _S], _S] | None = ...,
+        weight_combo_fn: Callable[[_T, _T], _T] | None = ...,
+        self_loops: bool = ...,
+        check_cycle: bool | None = ...,
+    ) -> int: ...
+    def contract_edges(
+        self,
+        edge_list: Sequence[tuple[int, int]],
+        /,
+        node_fn: Callable[[_S, _S], _S] | None = ...,
+        weight_combo_fn: Callable[[_T, _T], _T] | None = ...,
+        self_loops: bool = ...,
+        check_cycle: bool | None = ...,
+    ) -> NodeMap: ...
     def copy(self) -> PyDiGraph[_S, _T]: ...
     def edge_index_map(self) -> EdgeIndexMap[_T]: ...
     def edge_indices(self) -> EdgeIndices: ...
@@ -1364,6 +2638,16 @@ class PyDiGraph(Generic[_S, _T]):
         self, node: int, filter_fn: Callable[[_T], bool], /
     ) -> list[_S]: ...
     @staticmethod
+    def from_edge_iterator(
+        edges: Iterable[tuple[int, int] | tuple[int, int, _T]],
+        /,
+        num_nodes: int | None = ...,
+        num_edges: int | None = ...,
+        multigraph: bool = ...,
+        combine_fn: Callable[[_T, _T], _T] | None = ...,
+        chunk_size: int = ...,
+    ) -> PyDiGraph[None, _T]: ...
+    @staticmethod
     def from_adjacency_matrix(
         matrix: np.ndarray, /, null_value: float = ...
     ) -> PyDiGraph[int, float]: ...
@@ -1390,6 +2674,15 @@ class PyDiGraph(Generic[_S, _T]):
     def is_symmetric(self) -> bool: ...
     def make_symmetric(self, edge_payload_fn: Callable[[_T], _T] | None = ...) -> None: ...
     def merge_nodes(self, u: int, v: int, /) -> None: ...
+    def merge_nodes_by_key(
+        self,
+        key_fn: Callable[[_S], Hashable],
+        /,
+        node_fn: Callable[[_S, _S], _S] | None = ...,
+        weight_combo_fn: Callable[[_T, _T], _T] | None = ...,
+        self_loops: bool = ...,
+        check_cycle: bool | None = ...,
+    ) -> NodeMap: ...
     def neighbors(self, node: int, /) -> NodeIndices: ...
     def node_indexes(self) -> NodeIndices: ...
     def node_indices(self) -> NodeIndices: ...
@@ -1432,6 +2725,13 @@ class PyDiGraph(Generic[_S, _T]):
     def subgraph(
         self, nodes: Sequence[int], /, preserve_attrs: bool = ...
     ) -> PyDiGraph[_S, _T]: ...
+    def subgraph_view(
+        self,
+        /,
+        nodes: Sequence[int] | None = ...,
+        edges: Sequence[int] | None = ...,
+        preserve_attrs: bool = ...,
+    ) -> PyDiGraph[_S, _T]: ...
     def substitute_node_with_subgraph(
         self,
         node: int,
@@ -1443,6 +2743,7 @@ class PyDiGraph(Generic[_S, _T]):
     ) -> NodeMap: ...
     def successor_indices(self, node: int, /) -> NodeIndices: ...
     def successors(self, node: int, /) -> list[_S]: ...
+    def to_csr(self) -> tuple[np.ndarray, np.ndarray, np.ndarray]: ...
     def to_dot(
         self,
         /,
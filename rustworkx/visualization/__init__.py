@@ -11,7 +11,9 @@
 __all__ = [
     "mpl_draw",
     "graphviz_draw",
+    "svg_draw",
 ]
 
 from .matplotlib import mpl_draw
 from .graphviz import graphviz_draw
+from .svg import svg_draw
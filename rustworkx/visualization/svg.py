@@ -0,0 +1,105 @@
+# This code is licensed under the Apache License, Version 2.0. You may
+# obtain a copy of this license in the LICENSE.txt file in the root directory
+# of this source tree or at http://www.apache.org/licenses/LICENSE-2.0.
+#
+# Any modifications or derivative works of this code must retain this
+# copyright notice, and modified files need to carry a notice indicating
+# that they have been altered from the originals.
+
+from __future__ import annotations
+
+from typing import TypeVar, Callable, Mapping
+
+import rustworkx
+from rustworkx import PyDiGraph, PyGraph
+
+_S = TypeVar("_S")
+_T = TypeVar("_T")
+
+
+__all__ = ["svg_draw"]
+
+
+def svg_draw(
+    graph: "PyDiGraph[_S, _T] | PyGraph[_S, _T]",  # noqa
+    pos: Mapping[int, tuple[float, float]] | None = None,
+    node_attr_fn: Callable[[_S], dict[str, str]] | None = None,
+    edge_attr_fn: Callable[[_T], dict[str, str]] | None = None,
+    width: float = 600.0,
+    height: float = 600.0,
+    with_labels: bool = False,
+    filename: str | None = None,
+) -> str | None:
+    """Draw a :class:`~rustworkx.PyGraph` or :class:`~rustworkx.PyDiGraph` object
+    as an SVG image
+
+    Unlike :func:`~rustworkx.visualization.mpl_draw` and
+    :func:`~rustworkx.visualization.graphviz_draw` this doesn't need any
+    optional dependency, the SVG image is written directly by rustworkx. Every
+    node is drawn as a circle and every edge as a straight line, with an
+    arrowhead for a :class:`~rustworkx.PyDiGraph`.
+
+    :param graph: The rustworkx graph object to draw, can be a
+        :class:`~rustworkx.PyGraph` or a :class:`~rustworkx.PyDiGraph`
+    :param pos: An optional mapping of node indices to ``(x, y)`` positions
+        for every node in the graph, such as the output of one of the
+        :ref:`layout-functions`. If it's not specified
+        :func:`~rustworkx.spring_layout` is used.
+    :param node_attr_fn: An optional callable object that will be passed the
+        weight/data payload for every node in the graph and expected to return
+        a dictionary of SVG attributes for the node's ``<circle>`` element.
+        The key and value of this dictionary **must** be a string. The special
+        key ``"r"`` sets the radius of the node and ``"label"`` sets a text
+        label drawn on the node.
+    :param edge_attr_fn: An optional callable that will be passed the
+        weight/data payload for each edge in the graph and expected to return a
+        dictionary of SVG attributes for the edge's ``<line>`` element. The
+        key and value of this dictionary **must** be a string. The special key
+        ``"label"`` sets a text label drawn at the middle of the edge.
+    :param float width: The width of the image in pixels
+    :param float height: The height of the image in pixels
+    :param bool with_labels: Set to ``True`` to label every node without a
+        ``"label"`` attribute with its index
+    :param str filename: An optional path to write the SVG image to. If
+        specified the return type from this function will be ``None`` as the
+        image is saved to disk.
+
+    :returns: A string with the SVG image if ``filename`` is not specified
+    :rtype: str
+
+    .. jupyter-execute::
+
+        import rustworkx as rx
+        from rustworkx.visualization import svg_draw
+        from IPython.display import SVG
+
+        def node_attr(node):
+            if node % 2:
+                return {"fill": "orange", "label": str(node)}
+            return {"fill": "lightblue", "label": str(node)}
+
+        graph = rx.generators.generalized_petersen_graph(5, 2)
+        for node in graph.node_indices():
+            graph[node] = node
+        pos = rx.shell_layout(graph, nlist=[list(range(5)), list(range(5, 10))])
+        SVG(svg_draw(graph, pos, node_attr_fn=node_attr, width=300, height=300))
+
+    """
+    if pos is None:
+        pos = rustworkx.spring_layout(graph)
+    if isinstance(graph, PyDiGraph):
+        draw = rustworkx.digraph_to_svg
+    elif isinstance(graph, PyGraph):
+        draw = rustworkx.graph_to_svg
+    else:
+        raise TypeError("Invalid Input Type %s for graph" % type(graph))
+    return draw(
+        graph,
+        pos,
+        node_attr_fn=node_attr_fn,
+        edge_attr_fn=edge_attr_fn,
+        width=width,
+        height=height,
+        with_labels=with_labels,
+        filename=filename,
+    )
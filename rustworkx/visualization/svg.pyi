@@ -0,0 +1,24 @@
+# This code is licensed under the Apache License, Version 2.0. You may
+# obtain a copy of this license in the LICENSE.txt file in the root directory
+# of this source tree or at http://www.apache.org/licenses/LICENSE-2.0.
+#
+# Any modifications or derivative works of this code must retain this
+# copyright notice, and modified files need to carry a notice indicating
+# that they have been altered from the originals.
+
+import typing
+from rustworkx.rustworkx import PyGraph, PyDiGraph
+
+_S = typing.TypeVar("_S")
+_T = typing.TypeVar("_T")
+
+def svg_draw(
+    graph: PyDiGraph[_S, _T] | PyGraph[_S, _T],
+    pos: typing.Mapping[int, tuple[float, float]] | None = ...,
+    node_attr_fn: typing.Callable[[_S], dict[str, str]] | None = ...,
+    edge_attr_fn: typing.Callable[[_T], dict[str, str]] | None = ...,
+    width: float = ...,
+    height: float = ...,
+    with_labels: bool = ...,
+    filename: str | None = ...,
+) -> str | None: ...
@@ -0,0 +1,500 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+use crate::{digraph, graph, weight_callable, StablePyGraph};
+
+use petgraph::Direction::{self, Incoming, Outgoing};
+use petgraph::EdgeType;
+use rustworkx_core::assortativity::{
+    attribute_assortativity, average_neighbor_degree, degree_assortativity, degree_histogram,
+    joint_degree_matrix,
+};
+use rustworkx_core::dictmap::*;
+
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pyo3::types::{PyDict, PyTuple};
+use pyo3::Python;
+
+fn parse_degree(degree: &str) -> PyResult<Direction> {
+    match degree {
+        "out" => Ok(Outgoing),
+        "in" => Ok(Incoming),
+        _ => Err(PyValueError::new_err(format!(
+            "Invalid degree type '{}', it must be 'in' or 'out'",
+            degree
+        ))),
+    }
+}
+
+fn py_attribute_assortativity<Ty: EdgeType>(
+    py: Python,
+    graph: &StablePyGraph<Ty>,
+    attribute_fn: &Bound<PyAny>,
+) -> PyResult<f64> {
+    // Python attributes are only required to be hashable, so map every
+    // distinct attribute to an integer id first
+    let ids = PyDict::new_bound(py);
+    attribute_assortativity(graph, |node| {
+        let attribute = attribute_fn.call1((&graph[node],))?;
+        match ids.get_item(&attribute)? {
+            Some(id) => id.extract::<usize>(),
+            None => {
+                let id = ids.len();
+                ids.set_item(attribute, id)?;
+                Ok(id)
+            }
+        }
+    })
+}
+
+fn py_joint_degree_matrix<Ty: EdgeType>(
+    py: Python,
+    graph: &StablePyGraph<Ty>,
+    normalized: bool,
+    source: Direction,
+    target: Direction,
+) -> PyResult<PyObject> {
+    let matrix = joint_degree_matrix(graph, source, target);
+    let total: usize = matrix.values().sum();
+    let out_dict = PyDict::new_bound(py);
+    for ((source_degree, target_degree), count) in matrix {
+        let key = PyTuple::new_bound(py, [source_degree, target_degree]);
+        if normalized {
+            out_dict.set_item(key, count as f64 / total as f64)?;
+        } else {
+            out_dict.set_item(key, count)?;
+        }
+    }
+    Ok(out_dict.into())
+}
+
+/// Compute the degree assortativity coefficient of a :class:`~.PyGraph`.
+///
+/// The degree assortativity coefficient is the Pearson correlation
+/// coefficient of the degrees of the nodes at the two ends of every edge.
+/// It's positive if high degree nodes tend to be joined to other high degree
+/// nodes and negative if they tend to be joined to low degree nodes.
+///
+/// :param PyGraph graph: The graph to compute the coefficient for
+/// :param weight_fn: An optional callable that will be passed the data
+///     payload of every edge and is expected to return a ``float`` weight
+///     for that edge. If it's specified the weighted degree, the sum of the
+///     weights of the edges of a node, is used instead of the degree.
+/// :param float default_weight: If ``weight_fn`` is not specified the weight
+///     used for every edge. Default: ``1.0``.
+///
+/// :returns: The degree assortativity coefficient of the graph. It's ``nan``
+///     if the graph has no edges or if every edge joins nodes with the same
+///     degrees.
+/// :rtype: float
+///
+/// .. jupyter-execute::
+///
+///   import rustworkx as rx
+///
+///   graph = rx.generators.star_graph(5)
+///   print(rx.graph_degree_assortativity_coefficient(graph))
+#[pyfunction]
+#[pyo3(
+    signature=(graph, /, weight_fn=None, default_weight=1.0),
+    text_signature = "(graph, /, weight_fn=None, default_weight=1.0)"
+)]
+pub fn graph_degree_assortativity_coefficient(
+    py: Python,
+    graph: &graph::PyGraph,
+    weight_fn: Option<PyObject>,
+    default_weight: f64,
+) -> PyResult<f64> {
+    degree_assortativity(&graph.graph, Outgoing, Incoming, |edge| {
+        weight_callable(py, &weight_fn, edge.weight(), default_weight)
+    })
+}
+
+/// Compute the degree assortativity coefficient of a :class:`~.PyDiGraph`.
+///
+/// The degree assortativity coefficient is the Pearson correlation
+/// coefficient of the degrees of the source and the target of every edge.
+/// The degree of the source and of the target can each be either the
+/// in-degree or the out-degree. By default the out-degree of the source is
+/// correlated with the in-degree of the target.
+///
+/// :param PyDiGraph graph: The graph to compute the coefficient for
+/// :param weight_fn: An optional callable that will be passed the data
+///     payload of every edge and is expected to return a ``float`` weight
+///     for that edge. If it's specified the weighted degree, the sum of the
+///     weights of the edges of a node, is used instead of the degree.
+/// :param float default_weight: If ``weight_fn`` is not specified the weight
+///     used for every edge. Default: ``1.0``.
+/// :param str source: The degree used for the source of every edge, either
+///     ``"out"`` or ``"in"``. Default: ``"out"``.
+/// :param str target: The degree used for the target of every edge, either
+///     ``"out"`` or ``"in"``. Default: ``"in"``.
+///
+/// :returns: The degree assortativity coefficient of the graph. It's ``nan``
+///     if the graph has no edges or if all the degrees of the sources or of
+///     the targets are equal.
+/// :rtype: float
+/// :raises ValueError: If ``source`` or ``target`` is not ``"out"`` or
+///     ``"in"``
+///
+/// .. jupyter-execute::
+///
+///   import rustworkx as rx
+///
+///   graph = rx.PyDiGraph()
+///   graph.extend_from_edge_list([(0, 1), (0, 2), (1, 2), (2, 3), (3, 0)])
+///   print(rx.digraph_degree_assortativity_coefficient(graph))
+///   print(rx.digraph_degree_assortativity_coefficient(graph, source="in", target="out"))
+#[pyfunction]
+#[pyo3(
+    signature=(graph, /, weight_fn=None, default_weight=1.0, source="out", target="in"),
+    text_signature = "(graph, /, weight_fn=None, default_weight=1.0, source=\"out\", target=\"in\")"
+)]
+pub fn digraph_degree_assortativity_coefficient(
+    py: Python,
+    graph: &digraph::PyDiGraph,
+    weight_fn: Option<PyObject>,
+    default_weight: f64,
+    source: &str,
+    target: &str,
+) -> PyResult<f64> {
+    let source = parse_degree(source)?;
+    let target = parse_degree(target)?;
+    degree_assortativity(&graph.graph, source, target, |edge| {
+        weight_callable(py, &weight_fn, edge.weight(), default_weight)
+    })
+}
+
+/// Compute the attribute assortativity coefficient of a :class:`~.PyGraph`.
+///
+/// The attribute assortativity coefficient measures how much more often
+/// edges join nodes with the same categorical attribute than they would if
+/// the edges were placed at random. It's ``1.0`` if every edge joins nodes
+/// with the same attribute, and negative if edges mostly join nodes with
+/// different attributes.
+///
+/// :param PyGraph graph: The graph to compute the coefficient for
+/// :param attribute_fn: A callable that will be passed the data payload of
+///     every node and is expected to return a hashable attribute for that
+///     node.
+///
+/// :returns: The attribute assortativity coefficient of the graph. It's
+///     ``nan`` if the graph has no edges or if every edge joins nodes with
+///     the same attribute.
+/// :rtype: float
+///
+/// .. jupyter-execute::
+///
+///   import rustworkx as rx
+///
+///   graph = rx.generators.barbell_graph(3, 0)
+///   for node in graph.node_indices():
+///       graph[node] = "left" if node < 3 else "right"
+///   print(rx.graph_attribute_assortativity_coefficient(graph, lambda side: side))
+#[pyfunction]
+#[pyo3(text_signature = "(graph, attribute_fn, /)")]
+pub fn graph_attribute_assortativity_coefficient(
+    py: Python,
+    graph: &graph::PyGraph,
+    attribute_fn: &Bound<PyAny>,
+) -> PyResult<f64> {
+    py_attribute_assortativity(py, &graph.graph, attribute_fn)
+}
+
+/// Compute the attribute assortativity coefficient of a :class:`~.PyDiGraph`.
+///
+/// The attribute assortativity coefficient measures how much more often
+/// edges join nodes with the same categorical attribute than they would if
+/// the edges were placed at random. It's ``1.0`` if every edge joins nodes
+/// with the same attribute, and negative if edges mostly join nodes with
+/// different attributes.
+///
+/// :param PyDiGraph graph: The graph to compute the coefficient for
+/// :param attribute_fn: A callable that will be passed the data payload of
+///     every node and is expected to return a hashable attribute for that
+///     node.
+///
+/// :returns: The attribute assortativity coefficient of the graph. It's
+///     ``nan`` if the graph has no edges or if every edge joins nodes with
+///     the same attribute.
+/// :rtype: float
+///
+/// .. jupyter-execute::
+///
+///   import rustworkx as rx
+///
+///   graph = rx.generators.directed_cycle_graph(4)
+///   for node in graph.node_indices():
+///       graph[node] = node % 2
+///   print(rx.digraph_attribute_assortativity_coefficient(graph, lambda parity: parity))
+#[pyfunction]
+#[pyo3(text_signature = "(graph, attribute_fn, /)")]
+pub fn digraph_attribute_assortativity_coefficient(
+    py: Python,
+    graph: &digraph::PyDiGraph,
+    attribute_fn: &Bound<PyAny>,
+) -> PyResult<f64> {
+    py_attribute_assortativity(py, &graph.graph, attribute_fn)
+}
+
+/// Return the degree histogram of a :class:`~.PyGraph`.
+///
+/// The degree of a node is the number of edges incident to it, where a self
+/// loop adds 2 to the degree of its node.
+///
+/// :param PyGraph graph: The graph to compute the degree histogram of
+///
+/// :returns: A list whose element ``k`` is the number of nodes with degree
+///     ``k``, up to the largest degree of the graph. It's empty if the graph
+///     has no nodes.
+/// :rtype: list
+///
+/// .. jupyter-execute::
+///
+///   import rustworkx as rx
+///
+///   graph = rx.generators.star_graph(5)
+///   print(rx.graph_degree_histogram(graph))
+#[pyfunction]
+#[pyo3(text_signature = "(graph, /)")]
+pub fn graph_degree_histogram(graph: &graph::PyGraph) -> Vec<usize> {
+    degree_histogram(&graph.graph)
+}
+
+/// Return the degree histogram of a :class:`~.PyDiGraph`.
+///
+/// The degree of a node is the number of edges incident to it, the sum of
+/// its in-degree and its out-degree.
+///
+/// :param PyDiGraph graph: The graph to compute the degree histogram of
+///
+/// :returns: A list whose element ``k`` is the number of nodes with degree
+///     ``k``, up to the largest degree of the graph. It's empty if the graph
+///     has no nodes.
+/// :rtype: list
+///
+/// .. jupyter-execute::
+///
+///   import rustworkx as rx
+///
+///   graph = rx.generators.directed_star_graph(5)
+///   print(rx.digraph_degree_histogram(graph))
+#[pyfunction]
+#[pyo3(text_signature = "(graph, /)")]
+pub fn digraph_degree_histogram(graph: &digraph::PyDiGraph) -> Vec<usize> {
+    degree_histogram(&graph.graph)
+}
+
+/// Return the joint degree matrix of a :class:`~.PyGraph`.
+///
+/// The joint degree matrix counts the edges of the graph by the degrees of
+/// the nodes at their two ends. Every edge is counted in both directions, so
+/// the matrix is symmetric. Normalized, it's the joint degree distribution
+/// ``P(k1, k2)``, the probability that an edge joins nodes with degrees
+/// ``k1`` and ``k2``.
+///
+/// :param PyGraph graph: The graph to compute the joint degree matrix of
+/// :param bool normalized: If ``True`` the entries are divided by their sum,
+///     otherwise they're the number of edges. Default: ``True``.
+///
+/// :returns: A dictionary mapping every pair of degrees ``(k1, k2)`` joined
+///     by at least one edge to the entry of the matrix for it, sorted by the
+///     degrees.
+/// :rtype: dict
+///
+/// .. jupyter-execute::
+///
+///   import rustworkx as rx
+///
+///   graph = rx.generators.path_graph(4)
+///   print(rx.graph_joint_degree_matrix(graph))
+#[pyfunction]
+#[pyo3(
+    signature=(graph, /, normalized=true),
+    text_signature = "(graph, /, normalized=True)"
+)]
+pub fn graph_joint_degree_matrix(
+    py: Python,
+    graph: &graph::PyGraph,
+    normalized: bool,
+) -> PyResult<PyObject> {
+    py_joint_degree_matrix(py, &graph.graph, normalized, Outgoing, Incoming)
+}
+
+/// Return the joint degree matrix of a :class:`~.PyDiGraph`.
+///
+/// The joint degree matrix counts the edges of the graph by the degrees of
+/// their source and their target. The degree of the source and of the
+/// target can each be either the in-degree or the out-degree. By default
+/// the out-degree of the source is paired with the in-degree of the target.
+/// Normalized, it's the joint degree distribution ``P(k1, k2)``, the
+/// probability that an edge joins nodes with degrees ``k1`` and ``k2``.
+///
+/// :param PyDiGraph graph: The graph to compute the joint degree matrix of
+/// :param bool normalized: If ``True`` the entries are divided by their sum,
+///     otherwise they're the number of edges. Default: ``True``.
+/// :param str source: The degree used for the source of every edge, either
+///     ``"out"`` or ``"in"``. Default: ``"out"``.
+/// :param str target: The degree used for the target of every edge, either
+///     ``"out"`` or ``"in"``. Default: ``"in"``.
+///
+/// :returns: A dictionary mapping every pair of degrees ``(k1, k2)`` joined
+///     by at least one edge to the entry of the matrix for it, sorted by the
+///     degrees.
+/// :rtype: dict
+/// :raises ValueError: If ``source`` or ``target`` is not ``"out"`` or
+///     ``"in"``
+///
+/// .. jupyter-execute::
+///
+///   import rustworkx as rx
+///
+///   graph = rx.PyDiGraph()
+///   graph.extend_from_edge_list([(0, 1), (0, 2), (1, 2), (2, 3), (3, 0)])
+///   print(rx.digraph_joint_degree_matrix(graph, normalized=False))
+#[pyfunction]
+#[pyo3(
+    signature=(graph, /, normalized=true, source="out", target="in"),
+    text_signature = "(graph, /, normalized=True, source=\"out\", target=\"in\")"
+)]
+pub fn digraph_joint_degree_matrix(
+    py: Python,
+    graph: &digraph::PyDiGraph,
+    normalized: bool,
+    source: &str,
+    target: &str,
+) -> PyResult<PyObject> {
+    let source = parse_degree(source)?;
+    let target = parse_degree(target)?;
+    py_joint_degree_matrix(py, &graph.graph, normalized, source, target)
+}
+
+/// Compute the average degree of the neighbors of every node of a
+/// :class:`~.PyGraph`.
+///
+/// The average neighbor degree of a node :math:`i` is
+///
+/// .. math::
+///
+///     k_{nn,i} = \frac{1}{s_i} \sum_{j \in N(i)} w_{ij} k_j
+///
+/// where :math:`N(i)` are the neighbors of :math:`i`, :math:`w_{ij}` is the
+/// weight of the edge between :math:`i` and :math:`j`, :math:`k_j` is the
+/// degree of :math:`j` and :math:`s_i` is the sum of the weights of the
+/// edges of :math:`i`. Without a ``weight_fn`` it's the mean degree of the
+/// neighbors of the node.
+///
+/// :param PyGraph graph: The graph to compute the average neighbor degrees
+///     for
+/// :param weight_fn: An optional callable that will be passed the data
+///     payload of every edge and is expected to return a ``float`` weight
+///     for that edge.
+/// :param float default_weight: If ``weight_fn`` is not specified the weight
+///     used for every edge. Default: ``1.0``.
+///
+/// :returns: A dictionary mapping every node index to its average neighbor
+///     degree, which is ``0.0`` for nodes without neighbors.
+/// :rtype: dict
+///
+/// .. jupyter-execute::
+///
+///   import rustworkx as rx
+///
+///   graph = rx.generators.star_graph(5)
+///   print(rx.graph_average_neighbor_degree(graph))
+#[pyfunction]
+#[pyo3(
+    signature=(graph, /, weight_fn=None, default_weight=1.0),
+    text_signature = "(graph, /, weight_fn=None, default_weight=1.0)"
+)]
+pub fn graph_average_neighbor_degree(
+    py: Python,
+    graph: &graph::PyGraph,
+    weight_fn: Option<PyObject>,
+    default_weight: f64,
+) -> PyResult<DictMap<usize, f64>> {
+    let degrees = average_neighbor_degree(&graph.graph, Outgoing, Outgoing, |edge| {
+        weight_callable(py, &weight_fn, edge.weight(), default_weight)
+    })?;
+    Ok(degrees
+        .into_iter()
+        .map(|(node, degree)| (node.index(), degree))
+        .collect())
+}
+
+/// Compute the average degree of the neighbors of every node of a
+/// :class:`~.PyDiGraph`.
+///
+/// The average neighbor degree of a node :math:`i` is
+///
+/// .. math::
+///
+///     k_{nn,i} = \frac{1}{s_i} \sum_{j \in N(i)} w_{ij} k_j
+///
+/// where :math:`N(i)` are the successors of :math:`i` if ``source`` is
+/// ``"out"`` and its predecessors if it's ``"in"``, :math:`w_{ij}` is the
+/// weight of the edge between :math:`i` and :math:`j`, :math:`k_j` is the
+/// out-degree of :math:`j` if ``target`` is ``"out"`` and its in-degree if
+/// it's ``"in"``, and :math:`s_i` is the sum of the weights of the edges
+/// between :math:`i` and :math:`N(i)`.
+///
+/// :param PyDiGraph graph: The graph to compute the average neighbor degrees
+///     for
+/// :param weight_fn: An optional callable that will be passed the data
+///     payload of every edge and is expected to return a ``float`` weight
+///     for that edge.
+/// :param float default_weight: If ``weight_fn`` is not specified the weight
+///     used for every edge. Default: ``1.0``.
+/// :param str source: The edges used for the neighbors of every node, either
+///     ``"out"`` for the successors or ``"in"`` for the predecessors.
+///     Default: ``"out"``.
+/// :param str target: The degree used for the neighbors, either ``"out"`` or
+///     ``"in"``. Default: ``"out"``.
+///
+/// :returns: A dictionary mapping every node index to its average neighbor
+///     degree, which is ``0.0`` for nodes without neighbors.
+/// :rtype: dict
+/// :raises ValueError: If ``source`` or ``target`` is not ``"out"`` or
+///     ``"in"``
+///
+/// .. jupyter-execute::
+///
+///   import rustworkx as rx
+///
+///   graph = rx.PyDiGraph()
+///   graph.extend_from_edge_list([(0, 1), (0, 2), (1, 2), (2, 3)])
+///   print(rx.digraph_average_neighbor_degree(graph))
+///   print(rx.digraph_average_neighbor_degree(graph, source="in", target="in"))
+#[pyfunction]
+#[pyo3(
+    signature=(graph, /, weight_fn=None, default_weight=1.0, source="out", target="out"),
+    text_signature = "(graph, /, weight_fn=None, default_weight=1.0, source=\"out\", target=\"out\")"
+)]
+pub fn digraph_average_neighbor_degree(
+    py: Python,
+    graph: &digraph::PyDiGraph,
+    weight_fn: Option<PyObject>,
+    default_weight: f64,
+    source: &str,
+    target: &str,
+) -> PyResult<DictMap<usize, f64>> {
+    let source = parse_degree(source)?;
+    let target = parse_degree(target)?;
+    let degrees = average_neighbor_degree(&graph.graph, source, target, |edge| {
+        weight_callable(py, &weight_fn, edge.weight(), default_weight)
+    })?;
+    Ok(degrees
+        .into_iter()
+        .map(|(node, degree)| (node.index(), degree))
+        .collect())
+}
@@ -0,0 +1,155 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+use crate::{graph, StablePyGraph};
+
+use hashbrown::{HashMap, HashSet};
+
+use petgraph::graph::NodeIndex;
+use petgraph::visit::{EdgeRef, IntoEdgeReferences};
+use petgraph::Undirected;
+use rustworkx_core::bipartite_projection::{
+    bipartite_projection as core_projection, ProjectionWeight,
+};
+use rustworkx_core::coloring::two_color;
+use rustworkx_core::dictmap::*;
+
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pyo3::Python;
+
+fn parse_weight(weight: &str) -> PyResult<ProjectionWeight> {
+    match weight {
+        "count" => Ok(ProjectionWeight::Count),
+        "newman" => Ok(ProjectionWeight::Newman),
+        "jaccard" => Ok(ProjectionWeight::Jaccard),
+        _ => Err(PyValueError::new_err(format!(
+            "Invalid weight value '{}', it must be 'count', 'newman' or 'jaccard'",
+            weight
+        ))),
+    }
+}
+
+/// Return the projection of a bipartite :class:`~.PyGraph` onto one of its
+/// parts.
+///
+/// The projection has a node for every node of the part and an edge between
+/// two of them if they have at least one neighbor in common in ``graph``. The
+/// data payload of every node is the data payload of the node of ``graph``
+/// and the data payload of every edge is a float weight computed with one of
+/// the following schemes:
+///
+/// * ``"count"``: The number of neighbors the two nodes have in common.
+/// * ``"newman"``: Newman's collaboration weight, the sum of ``1 / (d - 1)``
+///   over the common neighbors of the two nodes, where ``d`` is the degree
+///   of the common neighbor.
+/// * ``"jaccard"``: The number of common neighbors divided by the number of
+///   neighbors of either of the two nodes.
+///
+/// Parallel edges are counted once.
+///
+/// :param PyGraph graph: The bipartite input graph
+/// :param nodes: An optional sequence of the node indices of the part to
+///     project onto. If it's not specified the bipartition is computed with
+///     :func:`~rustworkx.two_color` and the graph is projected onto the part
+///     with the lowest node index of every connected component.
+/// :param str weight: The scheme used to weight the edges of the projection,
+///     either ``"count"``, ``"newman"`` or ``"jaccard"``. Default:
+///     ``"count"``.
+///
+/// :returns: A tuple of the projection and a dictionary mapping the node
+///     indices of the part of ``graph`` to the node indices of the
+///     projection.
+/// :rtype: Tuple[PyGraph, dict]
+/// :raises ValueError: If ``weight`` is not a valid scheme, if ``graph`` is
+///     not bipartite, or if ``nodes`` contains an index that isn't in the
+///     graph or isn't one part of a bipartition of it.
+///
+/// .. jupyter-execute::
+///
+///   import rustworkx as rx
+///
+///   graph = rx.PyGraph()
+///   people = graph.add_nodes_from(["Alice", "Bob", "Carol"])
+///   groups = graph.add_nodes_from(["chess", "rowing"])
+///   graph.add_edges_from_no_data([(0, 3), (1, 3), (1, 4), (2, 4), (0, 4)])
+///   projection, node_map = rx.bipartite_projection(graph, people, weight="newman")
+///   print(projection.nodes())
+///   print(projection.weighted_edge_list())
+#[pyfunction]
+#[pyo3(
+    signature=(graph, /, nodes=None, weight="count"),
+    text_signature = "(graph, /, nodes=None, weight=\"count\")"
+)]
+pub fn bipartite_projection(
+    py: Python,
+    graph: &graph::PyGraph,
+    nodes: Option<Vec<usize>>,
+    weight: &str,
+) -> PyResult<(graph::PyGraph, DictMap<usize, usize>)> {
+    let weight = parse_weight(weight)?;
+    let part: HashSet<NodeIndex> = match nodes {
+        Some(nodes) => {
+            let mut part = HashSet::with_capacity(nodes.len());
+            for index in nodes {
+                let node = NodeIndex::new(index);
+                if !graph.graph.contains_node(node) {
+                    return Err(PyValueError::new_err(format!(
+                        "Node index {} is not in the graph",
+                        index
+                    )));
+                }
+                part.insert(node);
+            }
+            for edge in graph.graph.edge_references() {
+                if part.contains(&edge.source()) == part.contains(&edge.target()) {
+                    return Err(PyValueError::new_err(format!(
+                        "The edge ({}, {}) doesn't join the two parts of the bipartition",
+                        edge.source().index(),
+                        edge.target().index()
+                    )));
+                }
+            }
+            part
+        }
+        None => match two_color(&graph.graph) {
+            Some(colors) => colors
+                .into_iter()
+                .filter(|(_, color)| *color == 1)
+                .map(|(node, _)| node)
+                .collect(),
+            None => return Err(PyValueError::new_err("The graph is not bipartite")),
+        },
+    };
+    let (out_graph, node_map): (StablePyGraph<Undirected>, HashMap<NodeIndex, NodeIndex>) =
+        core_projection(
+            &graph.graph,
+            &part,
+            weight,
+            |node| Ok::<PyObject, PyErr>(graph.graph[node].clone_ref(py)),
+            |edge_weight| Ok(edge_weight.into_py(py)),
+        )?;
+    let node_map: DictMap<usize, usize> = graph
+        .graph
+        .node_indices()
+        .filter_map(|node| node_map.get(&node).map(|out| (node.index(), out.index())))
+        .collect();
+    Ok((
+        graph::PyGraph {
+            graph: out_graph,
+            node_removed: false,
+            multigraph: false,
+            attrs: py.None(),
+        },
+        node_map,
+    ))
+}
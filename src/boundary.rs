@@ -0,0 +1,426 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+use crate::digraph::PyDiGraph;
+use crate::graph::PyGraph;
+use crate::iterators::{EdgeList, NodeIndices};
+use crate::{CostFn, StablePyGraph};
+
+use petgraph::graph::NodeIndex;
+use petgraph::visit::EdgeRef;
+use petgraph::EdgeType;
+use rustworkx_core::boundary;
+
+use pyo3::exceptions::PyIndexError;
+use pyo3::prelude::*;
+use pyo3::Python;
+
+fn node_indices<Ty: EdgeType>(
+    graph: &StablePyGraph<Ty>,
+    nodes: Vec<usize>,
+) -> PyResult<Vec<NodeIndex>> {
+    nodes
+        .into_iter()
+        .map(|node| {
+            let index = NodeIndex::new(node);
+            if graph.contains_node(index) {
+                Ok(index)
+            } else {
+                Err(PyIndexError::new_err(format!(
+                    "Node source index \"{node}\" out of graph bound"
+                )))
+            }
+        })
+        .collect()
+}
+
+fn node_boundary_inner<Ty: EdgeType>(
+    graph: &StablePyGraph<Ty>,
+    nodes: Vec<usize>,
+    targets: Option<Vec<usize>>,
+) -> PyResult<NodeIndices> {
+    let nodes = node_indices(graph, nodes)?;
+    let targets = targets.map(|t| node_indices(graph, t)).transpose()?;
+    Ok(NodeIndices {
+        nodes: boundary::node_boundary(graph, nodes, targets)
+            .into_iter()
+            .map(|node| node.index())
+            .collect(),
+    })
+}
+
+fn edge_boundary_inner<Ty: EdgeType>(
+    graph: &StablePyGraph<Ty>,
+    nodes: Vec<usize>,
+    targets: Option<Vec<usize>>,
+) -> PyResult<EdgeList> {
+    let nodes = node_indices(graph, nodes)?;
+    let targets = targets.map(|t| node_indices(graph, t)).transpose()?;
+    Ok(EdgeList {
+        edges: boundary::edge_boundary(graph, nodes, targets)
+            .into_iter()
+            .map(|edge| (edge.source().index(), edge.target().index()))
+            .collect(),
+    })
+}
+
+fn cut_size_inner<Ty: EdgeType>(
+    py: Python,
+    graph: &StablePyGraph<Ty>,
+    nodes: Vec<usize>,
+    targets: Option<Vec<usize>>,
+    weight_fn: Option<PyObject>,
+    default_weight: f64,
+) -> PyResult<f64> {
+    let nodes = node_indices(graph, nodes)?;
+    let targets = targets.map(|t| node_indices(graph, t)).transpose()?;
+    let cost_fn = CostFn::try_from((weight_fn, default_weight))?;
+    boundary::cut_size(graph, nodes, targets, |edge| {
+        cost_fn.call_edge(py, edge.id(), edge.weight())
+    })
+}
+
+fn volume_inner<Ty: EdgeType>(
+    py: Python,
+    graph: &StablePyGraph<Ty>,
+    nodes: Vec<usize>,
+    weight_fn: Option<PyObject>,
+    default_weight: f64,
+) -> PyResult<f64> {
+    let nodes = node_indices(graph, nodes)?;
+    let cost_fn = CostFn::try_from((weight_fn, default_weight))?;
+    boundary::volume(graph, nodes, |edge| {
+        cost_fn.call_edge(py, edge.id(), edge.weight())
+    })
+}
+
+/// Compute the node boundary of a set of nodes in a :class:`~.PyGraph`.
+///
+/// The node boundary of a set of nodes ``S`` is the set of nodes outside of
+/// ``S`` that are adjacent to a node of ``S``. If ``targets`` is set, only
+/// the nodes of ``targets`` that are adjacent to a node of ``S`` and not in
+/// ``S`` are in the boundary.
+///
+/// :param PyGraph graph: The input graph.
+/// :param list nodes: The node indices of ``S``.
+/// :param list targets: An optional list of node indices to restrict the
+///     boundary to.
+///
+/// :returns: The node indices of the boundary, in the order they are found
+///     from the nodes of ``S`` in the order of ``nodes``.
+/// :rtype: NodeIndices
+/// :raises IndexError: when a node index isn't a node of the graph.
+///
+/// .. jupyter-execute::
+///
+///   import rustworkx as rx
+///
+///   graph = rx.generators.path_graph(5)
+///   print(rx.graph_node_boundary(graph, [1, 2]))
+#[pyfunction]
+#[pyo3(
+    signature=(graph, nodes, targets=None),
+    text_signature = "(graph, nodes, /, targets=None)"
+)]
+pub fn graph_node_boundary(
+    graph: &PyGraph,
+    nodes: Vec<usize>,
+    targets: Option<Vec<usize>>,
+) -> PyResult<NodeIndices> {
+    node_boundary_inner(&graph.graph, nodes, targets)
+}
+
+/// Compute the node boundary of a set of nodes in a :class:`~.PyDiGraph`.
+///
+/// The node boundary of a set of nodes ``S`` is the set of nodes outside of
+/// ``S`` that are the target of an edge from a node of ``S``. If ``targets``
+/// is set, only the nodes of ``targets`` that are the target of an edge from
+/// a node of ``S`` and not in ``S`` are in the boundary.
+///
+/// :param PyDiGraph graph: The input graph.
+/// :param list nodes: The node indices of ``S``.
+/// :param list targets: An optional list of node indices to restrict the
+///     boundary to.
+///
+/// :returns: The node indices of the boundary, in the order they are found
+///     from the nodes of ``S`` in the order of ``nodes``.
+/// :rtype: NodeIndices
+/// :raises IndexError: when a node index isn't a node of the graph.
+///
+/// .. jupyter-execute::
+///
+///   import rustworkx as rx
+///
+///   graph = rx.generators.directed_path_graph(5)
+///   print(rx.digraph_node_boundary(graph, [1, 2]))
+#[pyfunction]
+#[pyo3(
+    signature=(graph, nodes, targets=None),
+    text_signature = "(graph, nodes, /, targets=None)"
+)]
+pub fn digraph_node_boundary(
+    graph: &PyDiGraph,
+    nodes: Vec<usize>,
+    targets: Option<Vec<usize>>,
+) -> PyResult<NodeIndices> {
+    node_boundary_inner(&graph.graph, nodes, targets)
+}
+
+/// Compute the edge boundary of a set of nodes in a :class:`~.PyGraph`.
+///
+/// The edge boundary of a set of nodes ``S`` is the set of edges with one
+/// endpoint in ``S`` and the other one outside of ``S``. If ``targets`` is
+/// set, only the edges whose other endpoint is in ``targets`` (and not in
+/// ``S``) are in the boundary. Parallel edges are all in the boundary.
+///
+/// :param PyGraph graph: The input graph.
+/// :param list nodes: The node indices of ``S``.
+/// :param list targets: An optional list of node indices to restrict the
+///     boundary to.
+///
+/// :returns: The edges of the boundary, with their endpoint in ``S`` first,
+///     grouped by that endpoint in the order of ``nodes``.
+/// :rtype: EdgeList
+/// :raises IndexError: when a node index isn't a node of the graph.
+///
+/// .. jupyter-execute::
+///
+///   import rustworkx as rx
+///
+///   graph = rx.generators.cycle_graph(5)
+///   print(rx.graph_edge_boundary(graph, [0, 1]))
+#[pyfunction]
+#[pyo3(
+    signature=(graph, nodes, targets=None),
+    text_signature = "(graph, nodes, /, targets=None)"
+)]
+pub fn graph_edge_boundary(
+    graph: &PyGraph,
+    nodes: Vec<usize>,
+    targets: Option<Vec<usize>>,
+) -> PyResult<EdgeList> {
+    edge_boundary_inner(&graph.graph, nodes, targets)
+}
+
+/// Compute the edge boundary of a set of nodes in a :class:`~.PyDiGraph`.
+///
+/// The edge boundary of a set of nodes ``S`` is the set of edges from a node
+/// of ``S`` to a node outside of ``S``. If ``targets`` is set, only the edges
+/// to a node of ``targets`` (and not of ``S``) are in the boundary. Parallel
+/// edges are all in the boundary.
+///
+/// :param PyDiGraph graph: The input graph.
+/// :param list nodes: The node indices of ``S``.
+/// :param list targets: An optional list of node indices to restrict the
+///     boundary to.
+///
+/// :returns: The edges of the boundary, grouped by their source in the order
+///     of ``nodes``.
+/// :rtype: EdgeList
+/// :raises IndexError: when a node index isn't a node of the graph.
+///
+/// .. jupyter-execute::
+///
+///   import rustworkx as rx
+///
+///   graph = rx.generators.directed_cycle_graph(5)
+///   print(rx.digraph_edge_boundary(graph, [0, 1]))
+#[pyfunction]
+#[pyo3(
+    signature=(graph, nodes, targets=None),
+    text_signature = "(graph, nodes, /, targets=None)"
+)]
+pub fn digraph_edge_boundary(
+    graph: &PyDiGraph,
+    nodes: Vec<usize>,
+    targets: Option<Vec<usize>>,
+) -> PyResult<EdgeList> {
+    edge_boundary_inner(&graph.graph, nodes, targets)
+}
+
+/// Compute the size of the cut between two sets of nodes in a
+/// :class:`~.PyGraph`.
+///
+/// The size of the cut between the sets of nodes ``S`` and ``T`` is the
+/// total weight of the edges with one endpoint in ``S`` and the other one in
+/// ``T``, which is everything outside of ``S`` if ``targets`` isn't set.
+///
+/// :param PyGraph graph: The input graph.
+/// :param list nodes: The node indices of ``S``.
+/// :param list targets: The optional node indices of ``T``.
+/// :param weight_fn: An optional weight function for an edge. It will accept
+///     a single argument, the edge's weight object and will return a float which
+///     will be used to represent the weight of the edge. It must be
+///     non-negative. It can also be a sequence or numpy array of the edge
+///     weights indexed by edge index, which avoids a Python call per edge.
+/// :param float default_weight: If ``weight_fn`` isn't specified this optional
+///     float value will be used for the weight of each edge.
+///
+/// :returns: The size of the cut.
+/// :rtype: float
+/// :raises IndexError: when a node index isn't a node of the graph.
+/// :raises ValueError: when an edge weight with NaN or negative value
+///     is provided.
+///
+/// .. jupyter-execute::
+///
+///   import rustworkx as rx
+///
+///   graph = rx.generators.cycle_graph(5)
+///   print(rx.graph_cut_size(graph, [0, 1]))
+#[pyfunction]
+#[pyo3(
+    signature=(graph, nodes, targets=None, weight_fn=None, default_weight=1.0),
+    text_signature = "(graph, nodes, /, targets=None, weight_fn=None, default_weight=1.0)"
+)]
+pub fn graph_cut_size(
+    py: Python,
+    graph: &PyGraph,
+    nodes: Vec<usize>,
+    targets: Option<Vec<usize>>,
+    weight_fn: Option<PyObject>,
+    default_weight: f64,
+) -> PyResult<f64> {
+    cut_size_inner(py, &graph.graph, nodes, targets, weight_fn, default_weight)
+}
+
+/// Compute the size of the cut between two sets of nodes in a
+/// :class:`~.PyDiGraph`.
+///
+/// The size of the cut between the sets of nodes ``S`` and ``T`` is the
+/// total weight of the edges from a node of ``S`` to a node of ``T`` and from
+/// a node of ``T`` to a node of ``S``, where ``T`` is everything outside of
+/// ``S`` if ``targets`` isn't set.
+///
+/// :param PyDiGraph graph: The input graph.
+/// :param list nodes: The node indices of ``S``.
+/// :param list targets: The optional node indices of ``T``.
+/// :param weight_fn: An optional weight function for an edge. It will accept
+///     a single argument, the edge's weight object and will return a float which
+///     will be used to represent the weight of the edge. It must be
+///     non-negative. It can also be a sequence or numpy array of the edge
+///     weights indexed by edge index, which avoids a Python call per edge.
+/// :param float default_weight: If ``weight_fn`` isn't specified this optional
+///     float value will be used for the weight of each edge.
+///
+/// :returns: The size of the cut.
+/// :rtype: float
+/// :raises IndexError: when a node index isn't a node of the graph.
+/// :raises ValueError: when an edge weight with NaN or negative value
+///     is provided.
+///
+/// .. jupyter-execute::
+///
+///   import rustworkx as rx
+///
+///   graph = rx.generators.directed_cycle_graph(5)
+///   print(rx.digraph_cut_size(graph, [0, 1]))
+#[pyfunction]
+#[pyo3(
+    signature=(graph, nodes, targets=None, weight_fn=None, default_weight=1.0),
+    text_signature = "(graph, nodes, /, targets=None, weight_fn=None, default_weight=1.0)"
+)]
+pub fn digraph_cut_size(
+    py: Python,
+    graph: &PyDiGraph,
+    nodes: Vec<usize>,
+    targets: Option<Vec<usize>>,
+    weight_fn: Option<PyObject>,
+    default_weight: f64,
+) -> PyResult<f64> {
+    cut_size_inner(py, &graph.graph, nodes, targets, weight_fn, default_weight)
+}
+
+/// Compute the volume of a set of nodes in a :class:`~.PyGraph`.
+///
+/// The volume of a set of nodes is the sum of their weighted degrees, where
+/// a self-loop counts twice. Together with
+/// :func:`~rustworkx.graph_cut_size` this gives the conductance of a set of
+/// nodes ``S``, which is ``cut_size(S) / min(volume(S), volume(T))``.
+///
+/// :param PyGraph graph: The input graph.
+/// :param list nodes: The node indices of the set. Duplicates are ignored.
+/// :param weight_fn: An optional weight function for an edge. It will accept
+///     a single argument, the edge's weight object and will return a float which
+///     will be used to represent the weight of the edge. It must be
+///     non-negative. It can also be a sequence or numpy array of the edge
+///     weights indexed by edge index, which avoids a Python call per edge.
+/// :param float default_weight: If ``weight_fn`` isn't specified this optional
+///     float value will be used for the weight of each edge.
+///
+/// :returns: The volume of the set of nodes.
+/// :rtype: float
+/// :raises IndexError: when a node index isn't a node of the graph.
+/// :raises ValueError: when an edge weight with NaN or negative value
+///     is provided.
+///
+/// .. jupyter-execute::
+///
+///   import rustworkx as rx
+///
+///   graph = rx.generators.cycle_graph(5)
+///   print(rx.graph_volume(graph, [0, 1]))
+#[pyfunction]
+#[pyo3(
+    signature=(graph, nodes, weight_fn=None, default_weight=1.0),
+    text_signature = "(graph, nodes, /, weight_fn=None, default_weight=1.0)"
+)]
+pub fn graph_volume(
+    py: Python,
+    graph: &PyGraph,
+    nodes: Vec<usize>,
+    weight_fn: Option<PyObject>,
+    default_weight: f64,
+) -> PyResult<f64> {
+    volume_inner(py, &graph.graph, nodes, weight_fn, default_weight)
+}
+
+/// Compute the volume of a set of nodes in a :class:`~.PyDiGraph`.
+///
+/// The volume of a set of nodes is the sum of their weighted out-degrees.
+///
+/// :param PyDiGraph graph: The input graph.
+/// :param list nodes: The node indices of the set. Duplicates are ignored.
+/// :param weight_fn: An optional weight function for an edge. It will accept
+///     a single argument, the edge's weight object and will return a float which
+///     will be used to represent the weight of the edge. It must be
+///     non-negative. It can also be a sequence or numpy array of the edge
+///     weights indexed by edge index, which avoids a Python call per edge.
+/// :param float default_weight: If ``weight_fn`` isn't specified this optional
+///     float value will be used for the weight of each edge.
+///
+/// :returns: The volume of the set of nodes.
+/// :rtype: float
+/// :raises IndexError: when a node index isn't a node of the graph.
+/// :raises ValueError: when an edge weight with NaN or negative value
+///     is provided.
+///
+/// .. jupyter-execute::
+///
+///   import rustworkx as rx
+///
+///   graph = rx.generators.directed_cycle_graph(5)
+///   print(rx.digraph_volume(graph, [0, 1]))
+#[pyfunction]
+#[pyo3(
+    signature=(graph, nodes, weight_fn=None, default_weight=1.0),
+    text_signature = "(graph, nodes, /, weight_fn=None, default_weight=1.0)"
+)]
+pub fn digraph_volume(
+    py: Python,
+    graph: &PyDiGraph,
+    nodes: Vec<usize>,
+    weight_fn: Option<PyObject>,
+    default_weight: f64,
+) -> PyResult<f64> {
+    volume_inner(py, &graph.graph, nodes, weight_fn, default_weight)
+}
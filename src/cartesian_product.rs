@@ -10,69 +10,32 @@
 // License for the specific language governing permissions and limitations
 // under the License.
 
+use crate::graph_product::{combine_edge_weights, combine_node_weights, product_node_map};
 use crate::iterators::ProductNodeMap;
-use crate::{digraph, graph, StablePyGraph};
+use crate::{digraph, graph};
 
-use hashbrown::HashMap;
+use petgraph::algo;
 
-use petgraph::visit::{EdgeRef, IntoEdgeReferences, IntoNodeReferences};
-use petgraph::{algo, EdgeType};
+use rustworkx_core::graph_product::cartesian_product as core_cartesian_product;
 
 use pyo3::prelude::*;
 use pyo3::Python;
 
-fn cartesian_product<Ty: EdgeType>(
-    py: Python,
-    first: &StablePyGraph<Ty>,
-    second: &StablePyGraph<Ty>,
-) -> (StablePyGraph<Ty>, ProductNodeMap) {
-    let mut final_graph = StablePyGraph::<Ty>::with_capacity(
-        first.node_count() * second.node_count(),
-        first.node_count() * second.edge_count() + first.edge_count() * second.node_count(),
-    );
-
-    let mut hash_nodes = HashMap::with_capacity(first.node_count() * second.node_count());
-
-    for (x, weight_x) in first.node_references() {
-        for (y, weight_y) in second.node_references() {
-            let n0 = final_graph.add_node((weight_x, weight_y).into_py(py));
-            hash_nodes.insert((x, y), n0);
-        }
-    }
-
-    for edge_first in first.edge_references() {
-        for node_second in second.node_indices() {
-            let source = hash_nodes[&(edge_first.source(), node_second)];
-            let target = hash_nodes[&(edge_first.target(), node_second)];
-
-            final_graph.add_edge(source, target, edge_first.weight().clone_ref(py));
-        }
-    }
-
-    for node_first in first.node_indices() {
-        for edge_second in second.edge_references() {
-            let source = hash_nodes[&(node_first, edge_second.source())];
-            let target = hash_nodes[&(node_first, edge_second.target())];
-
-            final_graph.add_edge(source, target, edge_second.weight().clone_ref(py));
-        }
-    }
-
-    let out_node_map = ProductNodeMap {
-        node_map: hash_nodes
-            .into_iter()
-            .map(|((x, y), n)| ((x.index(), y.index()), n.index()))
-            .collect(),
-    };
-
-    (final_graph, out_node_map)
-}
-
 /// Return a new PyGraph by forming the cartesian product from two input
 /// PyGraph objects
 ///
 /// :param PyGraph first: The first undirected graph object
 /// :param PyGraph second: The second undirected graph object
+/// :param node_fn: An optional callable that will be passed the data
+///     payloads of a node of ``first`` and a node of ``second`` and is
+///     expected to return the data payload of the corresponding node of the
+///     product. By default the payload is a tuple of the two payloads.
+/// :param edge_fn: An optional callable that will be passed the data
+///     payloads of the edges of ``first`` and ``second`` an edge of the
+///     product is formed from and is expected to return the data payload of
+///     that edge. As every edge of the product comes from an
+///     edge of one of the graphs, one of the arguments is always ``None``. By
+///     default the payload of the edge of ``first`` or ``second`` is used.
 ///
 /// :returns: A new PyGraph object that is the cartesian product of ``first``
 ///     and ``second``. It's worth noting the weight/data payload objects are
@@ -99,23 +62,33 @@ fn cartesian_product<Ty: EdgeType>(
 ///   graph_product, _ = rustworkx.graph_cartesian_product(graph_1, graph_2)
 ///   mpl_draw(graph_product)
 #[pyfunction()]
-#[pyo3(text_signature = "(first, second, /)")]
+#[pyo3(
+    signature=(first, second, /, node_fn=None, edge_fn=None),
+    text_signature = "(first, second, /, node_fn=None, edge_fn=None)"
+)]
 pub fn graph_cartesian_product(
     py: Python,
     first: &graph::PyGraph,
     second: &graph::PyGraph,
-) -> (graph::PyGraph, ProductNodeMap) {
-    let (out_graph, out_node_map) = cartesian_product(py, &first.graph, &second.graph);
-
-    (
+    node_fn: Option<PyObject>,
+    edge_fn: Option<PyObject>,
+) -> PyResult<(graph::PyGraph, ProductNodeMap)> {
+    let (out_graph, node_map) = core_cartesian_product(
+        &first.graph,
+        &second.graph,
+        |a, b| combine_node_weights(py, &node_fn, a, b),
+        |edge| combine_edge_weights(py, &edge_fn, edge),
+    )?;
+
+    Ok((
         graph::PyGraph {
             graph: out_graph,
             multigraph: true,
             node_removed: false,
             attrs: py.None(),
         },
-        out_node_map,
-    )
+        product_node_map(node_map),
+    ))
 }
 
 /// Return a new PyDiGraph by forming the cartesian product from two input
@@ -123,6 +96,16 @@ pub fn graph_cartesian_product(
 ///
 /// :param PyDiGraph first: The first undirected graph object
 /// :param PyDiGraph second: The second undirected graph object
+/// :param node_fn: An optional callable that will be passed the data
+///     payloads of a node of ``first`` and a node of ``second`` and is
+///     expected to return the data payload of the corresponding node of the
+///     product. By default the payload is a tuple of the two payloads.
+/// :param edge_fn: An optional callable that will be passed the data
+///     payloads of the edges of ``first`` and ``second`` an edge of the
+///     product is formed from and is expected to return the data payload of
+///     that edge. As every edge of the product comes from an
+///     edge of one of the graphs, one of the arguments is always ``None``. By
+///     default the payload of the edge of ``first`` or ``second`` is used.
 ///
 /// :returns: A new PyDiGraph object that is the cartesian product of ``first``
 ///     and ``second``. It's worth noting the weight/data payload objects are
@@ -149,15 +132,25 @@ pub fn graph_cartesian_product(
 ///   graph_product, _ = rustworkx.digraph_cartesian_product(graph_1, graph_2)
 ///   mpl_draw(graph_product)
 #[pyfunction()]
-#[pyo3(text_signature = "(first, second, /)")]
+#[pyo3(
+    signature=(first, second, /, node_fn=None, edge_fn=None),
+    text_signature = "(first, second, /, node_fn=None, edge_fn=None)"
+)]
 pub fn digraph_cartesian_product(
     py: Python,
     first: &digraph::PyDiGraph,
     second: &digraph::PyDiGraph,
-) -> (digraph::PyDiGraph, ProductNodeMap) {
-    let (out_graph, out_node_map) = cartesian_product(py, &first.graph, &second.graph);
-
-    (
+    node_fn: Option<PyObject>,
+    edge_fn: Option<PyObject>,
+) -> PyResult<(digraph::PyDiGraph, ProductNodeMap)> {
+    let (out_graph, node_map) = core_cartesian_product(
+        &first.graph,
+        &second.graph,
+        |a, b| combine_node_weights(py, &node_fn, a, b),
+        |edge| combine_edge_weights(py, &edge_fn, edge),
+    )?;
+
+    Ok((
         digraph::PyDiGraph {
             graph: out_graph,
             cycle_state: algo::DfsSpace::default(),
@@ -166,6 +159,6 @@ pub fn digraph_cartesian_product(
             multigraph: true,
             attrs: py.None(),
         },
-        out_node_map,
-    )
+        product_node_map(node_map),
+    ))
 }
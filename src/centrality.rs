@@ -14,20 +14,157 @@
 
 use std::convert::TryFrom;
 
+use crate::csr_structure;
 use crate::digraph;
 use crate::graph;
 use crate::iterators::{CentralityMapping, EdgeCentralityMapping};
+use crate::parallel::run_with_num_threads;
+use crate::progress::ProgressFn;
 use crate::CostFn;
 use crate::FailedToConverge;
+use crate::StablePyGraph;
 
 use hashbrown::HashMap;
-use petgraph::graph::NodeIndex;
 use petgraph::visit::EdgeIndexable;
 use petgraph::visit::EdgeRef;
 use petgraph::visit::IntoNodeIdentifiers;
+use petgraph::{Direction, EdgeType};
 use pyo3::exceptions::PyValueError;
 use pyo3::prelude::*;
 use rustworkx_core::centrality;
+use rustworkx_core::csr::CsrGraph;
+
+/// Build the CSR form of a graph with the weight of every edge given by
+/// ``weight_fn``, so the Python callable is called once per edge up front.
+fn weighted_csr_structure<Ty: EdgeType>(
+    py: Python,
+    graph: &StablePyGraph<Ty>,
+    weight_fn: PyObject,
+) -> PyResult<CsrGraph<f64, Ty>> {
+    let cost_fn = CostFn::try_from(weight_fn)?;
+    CsrGraph::from_graph(graph, |e| cost_fn.call_edge(py, e.id(), e.weight()))
+}
+
+fn betweenness_inner<Ty: EdgeType + Sync>(
+    py: Python,
+    graph: &StablePyGraph<Ty>,
+    normalized: bool,
+    endpoints: bool,
+    parallel_threshold: usize,
+    num_threads: Option<usize>,
+    progress: ProgressFn,
+    weight_fn: Option<PyObject>,
+) -> PyResult<Vec<Option<f64>>> {
+    let interval = progress.interval(Some(graph.node_count()));
+    match weight_fn {
+        Some(weight_fn) => {
+            let csr = weighted_csr_structure(py, graph, weight_fn)?;
+            if progress.is_enabled() {
+                progress.run_released(py, num_threads, |report| {
+                    centrality::weighted_betweenness_centrality_with_progress(
+                        &csr,
+                        endpoints,
+                        normalized,
+                        parallel_threshold,
+                        |e| Ok(*e.weight()),
+                        interval,
+                        report,
+                    )
+                })
+            } else {
+                run_with_num_threads(num_threads, || {
+                    centrality::weighted_betweenness_centrality(
+                        &csr,
+                        endpoints,
+                        normalized,
+                        parallel_threshold,
+                        |e| Ok(*e.weight()),
+                    )
+                })?
+            }
+        }
+        None => {
+            let csr = csr_structure(graph);
+            if progress.is_enabled() {
+                progress.run_released(py, num_threads, |report| {
+                    centrality::betweenness_centrality_with_progress(
+                        &csr,
+                        endpoints,
+                        normalized,
+                        parallel_threshold,
+                        interval,
+                        report,
+                    )
+                })
+            } else {
+                run_with_num_threads(num_threads, || {
+                    centrality::betweenness_centrality(
+                        &csr,
+                        endpoints,
+                        normalized,
+                        parallel_threshold,
+                    )
+                })
+            }
+        }
+    }
+}
+
+fn edge_betweenness_inner<Ty: EdgeType + Sync>(
+    py: Python,
+    graph: &StablePyGraph<Ty>,
+    normalized: bool,
+    parallel_threshold: usize,
+    num_threads: Option<usize>,
+    progress: ProgressFn,
+    weight_fn: Option<PyObject>,
+) -> PyResult<Vec<Option<f64>>> {
+    let interval = progress.interval(Some(graph.node_count()));
+    match weight_fn {
+        Some(weight_fn) => {
+            let csr = weighted_csr_structure(py, graph, weight_fn)?;
+            if progress.is_enabled() {
+                progress.run_released(py, num_threads, |report| {
+                    centrality::weighted_edge_betweenness_centrality_with_progress(
+                        &csr,
+                        normalized,
+                        parallel_threshold,
+                        |e| Ok(*e.weight()),
+                        interval,
+                        report,
+                    )
+                })
+            } else {
+                run_with_num_threads(num_threads, || {
+                    centrality::weighted_edge_betweenness_centrality(
+                        &csr,
+                        normalized,
+                        parallel_threshold,
+                        |e| Ok(*e.weight()),
+                    )
+                })?
+            }
+        }
+        None => {
+            let csr = csr_structure(graph);
+            if progress.is_enabled() {
+                progress.run_released(py, num_threads, |report| {
+                    centrality::edge_betweenness_centrality_with_progress(
+                        &csr,
+                        normalized,
+                        parallel_threshold,
+                        interval,
+                        report,
+                    )
+                })
+            } else {
+                run_with_num_threads(num_threads, || {
+                    centrality::edge_betweenness_centrality(&csr, normalized, parallel_threshold)
+                })
+            }
+        }
+    }
+}
 
 /// Compute the betweenness centrality of all nodes in a PyGraph.
 ///
@@ -51,7 +188,9 @@ use rustworkx_core::centrality;
 ///
 /// This function is multithreaded and will run in parallel if the number
 /// of nodes in the graph is above the value of ``parallel_threshold`` (it
-/// defaults to 50). If the function will be running in parallel the env var
+/// defaults to 50). If the function will be running in parallel the number
+/// of threads can be limited with ``num_threads`` or
+/// :func:`~rustworkx.set_num_threads`, otherwise the env var
 /// ``RAYON_NUM_THREADS`` can be used to adjust how many threads will be used.
 ///
 /// See Also
@@ -67,6 +206,21 @@ use rustworkx_core::centrality;
 ///     the betweenness centrality in parallel at if the number of nodes in
 ///     the graph is less than this value it will run in a single thread. The
 ///     default value is 50
+/// :param int num_threads: The maximum number of threads to use. If it is not
+///     specified the number set with :func:`~rustworkx.set_num_threads` is
+///     used.
+/// :param progress_fn: An optional callable which is called as
+///     ``progress_fn(done, total)`` after every ``progress_interval`` of the
+///     ``total`` source nodes are searched from. An exception raised by it
+///     stops the computation and is raised from this function.
+/// :param int progress_interval: The number of source nodes between the calls
+///     to ``progress_fn``. By default it is called 100 times.
+/// :param weight_fn: An optional weight function for an edge. It will accept
+///     a single argument, the edge's weight object and will return a float
+///     which will be used as the length of the edge, which must be
+///     non-negative. It can also be a sequence or numpy array of the edge
+///     weights indexed by edge index, which avoids a Python call per edge. If
+///     it isn't set every edge has the length ``1``.
 ///
 /// :returns: a read-only dict-like object whose keys are the node indices and values are the
 ///      betweenness score for each node.
@@ -76,25 +230,45 @@ use rustworkx_core::centrality;
         graph,
         normalized=true,
         endpoints=false,
-        parallel_threshold=50
+        parallel_threshold=50,
+        num_threads=None,
+        progress_fn=None,
+        progress_interval=None,
+        weight_fn=None
     )
 )]
-#[pyo3(text_signature = "(graph, /, normalized=True, endpoints=False, parallel_threshold=50)")]
+#[pyo3(
+    text_signature = "(graph, /, normalized=True, endpoints=False, parallel_threshold=50, num_threads=None, progress_fn=None, progress_interval=None, weight_fn=None)"
+)]
 pub fn graph_betweenness_centrality(
+    py: Python,
     graph: &graph::PyGraph,
     normalized: bool,
     endpoints: bool,
     parallel_threshold: usize,
-) -> CentralityMapping {
-    let betweenness =
-        centrality::betweenness_centrality(&graph.graph, endpoints, normalized, parallel_threshold);
-    CentralityMapping {
+    num_threads: Option<usize>,
+    progress_fn: Option<PyObject>,
+    progress_interval: Option<usize>,
+    weight_fn: Option<PyObject>,
+) -> PyResult<CentralityMapping> {
+    let progress = ProgressFn::new(progress_fn, progress_interval)?;
+    let betweenness = betweenness_inner(
+        py,
+        &graph.graph,
+        normalized,
+        endpoints,
+        parallel_threshold,
+        num_threads,
+        progress,
+        weight_fn,
+    )?;
+    Ok(CentralityMapping {
         centralities: betweenness
             .into_iter()
             .enumerate()
             .filter_map(|(i, v)| v.map(|x| (i, x)))
             .collect(),
-    }
+    })
 }
 
 /// Compute the betweenness centrality of all nodes in a PyDiGraph.
@@ -119,7 +293,9 @@ pub fn graph_betweenness_centrality(
 ///
 /// This function is multithreaded and will run in parallel if the number
 /// of nodes in the graph is above the value of ``parallel_threshold`` (it
-/// defaults to 50). If the function will be running in parallel the env var
+/// defaults to 50). If the function will be running in parallel the number
+/// of threads can be limited with ``num_threads`` or
+/// :func:`~rustworkx.set_num_threads`, otherwise the env var
 /// ``RAYON_NUM_THREADS`` can be used to adjust how many threads will be used.
 ///
 /// See Also
@@ -135,6 +311,21 @@ pub fn graph_betweenness_centrality(
 ///     the betweenness centrality in parallel at if the number of nodes in
 ///     the graph is less than this value it will run in a single thread. The
 ///     default value is 50
+/// :param int num_threads: The maximum number of threads to use. If it is not
+///     specified the number set with :func:`~rustworkx.set_num_threads` is
+///     used.
+/// :param progress_fn: An optional callable which is called as
+///     ``progress_fn(done, total)`` after every ``progress_interval`` of the
+///     ``total`` source nodes are searched from. An exception raised by it
+///     stops the computation and is raised from this function.
+/// :param int progress_interval: The number of source nodes between the calls
+///     to ``progress_fn``. By default it is called 100 times.
+/// :param weight_fn: An optional weight function for an edge. It will accept
+///     a single argument, the edge's weight object and will return a float
+///     which will be used as the length of the edge, which must be
+///     non-negative. It can also be a sequence or numpy array of the edge
+///     weights indexed by edge index, which avoids a Python call per edge. If
+///     it isn't set every edge has the length ``1``.
 ///
 /// :returns: a read-only dict-like object whose keys are the node indices and values are the
 ///      betweenness score for each node.
@@ -144,25 +335,45 @@ pub fn graph_betweenness_centrality(
         graph,
         normalized=true,
         endpoints=false,
-        parallel_threshold=50
+        parallel_threshold=50,
+        num_threads=None,
+        progress_fn=None,
+        progress_interval=None,
+        weight_fn=None
     )
 )]
-#[pyo3(text_signature = "(graph, /, normalized=True, endpoints=False, parallel_threshold=50)")]
+#[pyo3(
+    text_signature = "(graph, /, normalized=True, endpoints=False, parallel_threshold=50, num_threads=None, progress_fn=None, progress_interval=None, weight_fn=None)"
+)]
 pub fn digraph_betweenness_centrality(
+    py: Python,
     graph: &digraph::PyDiGraph,
     normalized: bool,
     endpoints: bool,
     parallel_threshold: usize,
-) -> CentralityMapping {
-    let betweenness =
-        centrality::betweenness_centrality(&graph.graph, endpoints, normalized, parallel_threshold);
-    CentralityMapping {
+    num_threads: Option<usize>,
+    progress_fn: Option<PyObject>,
+    progress_interval: Option<usize>,
+    weight_fn: Option<PyObject>,
+) -> PyResult<CentralityMapping> {
+    let progress = ProgressFn::new(progress_fn, progress_interval)?;
+    let betweenness = betweenness_inner(
+        py,
+        &graph.graph,
+        normalized,
+        endpoints,
+        parallel_threshold,
+        num_threads,
+        progress,
+        weight_fn,
+    )?;
+    Ok(CentralityMapping {
         centralities: betweenness
             .into_iter()
             .enumerate()
             .filter_map(|(i, v)| v.map(|x| (i, x)))
             .collect(),
-    }
+    })
 }
 
 /// Compute the closeness centrality of each node in a :class:`~.PyGraph` object.
@@ -194,23 +405,50 @@ pub fn digraph_betweenness_centrality(
 /// where :math:`N` is the number of nodes in the graph. This alternative
 /// formula can be used with the ``wf_improved`` argument.
 ///
+/// The shortest path distances are the numbers of edges on the paths unless
+/// ``weight_fn`` is set, in which case they are computed with Dijkstra's
+/// algorithm from the edge weights.
+///
 /// :param PyGraph graph: The input graph. Can either be a
 ///     :class:`~retworkx.PyGraph` or :class:`~retworkx.PyDiGraph`.
 /// :param bool wf_improved: This is optional; the default is True. If True,
 ///     scale by the fraction of nodes reachable.
+/// :param weight_fn: An optional input callable that will be passed the edge's
+///     payload object and is expected to return a ``float`` length for the
+///     edge, which must not be negative. This also accepts a sequence or
+///     numpy array of edge lengths indexed by edge index.
+/// :param float default_weight: The length of every edge if ``weight_fn``
+///     is set to ``None``. This is only used when ``weight_fn`` is set or
+///     ``default_weight`` isn't ``1.0``.
 ///
 /// :returns: A dictionary mapping each node index to its closeness centrality.
 /// :rtype: CentralityMapping
-#[pyfunction(signature = (graph, wf_improved=true))]
-pub fn graph_closeness_centrality(graph: &graph::PyGraph, wf_improved: bool) -> CentralityMapping {
-    let closeness = centrality::closeness_centrality(&graph.graph, wf_improved);
-    CentralityMapping {
+#[pyfunction(signature = (graph, wf_improved=true, weight_fn=None, default_weight=1.0))]
+pub fn graph_closeness_centrality(
+    py: Python,
+    graph: &graph::PyGraph,
+    wf_improved: bool,
+    weight_fn: Option<PyObject>,
+    default_weight: f64,
+) -> PyResult<CentralityMapping> {
+    let closeness = if weight_fn.is_none() && default_weight == 1.0 {
+        centrality::closeness_centrality(&graph.graph, wf_improved)
+    } else {
+        let edge_weights = edge_lengths(py, &graph.graph, weight_fn, default_weight)?;
+        centrality::weighted_closeness_centrality(
+            &graph.graph,
+            wf_improved,
+            Direction::Incoming,
+            |e| -> PyResult<f64> { Ok(edge_weights[e.id().index()]) },
+        )?
+    };
+    Ok(CentralityMapping {
         centralities: closeness
             .into_iter()
             .enumerate()
             .filter_map(|(i, v)| v.map(|x| (i, x)))
             .collect(),
-    }
+    })
 }
 
 /// Compute the closeness centrality of each node in a :class:`~.PyDiGraph` object.
@@ -242,26 +480,204 @@ pub fn graph_closeness_centrality(graph: &graph::PyGraph, wf_improved: bool) ->
 /// where :math:`N` is the number of nodes in the graph. This alternative
 /// formula can be used with the ``wf_improved`` argument.
 ///
+/// The shortest path distances are the numbers of edges on the paths unless
+/// ``weight_fn`` is set, in which case they are computed with Dijkstra's
+/// algorithm from the edge weights. By default the distances from the other
+/// nodes to :math:`u` are used, and with ``direction="outgoing"`` the
+/// distances from :math:`u` to the other nodes are used instead.
+///
 /// :param PyDiGraph graph: The input graph. Can either be a
 ///     :class:`~retworkx.PyGraph` or :class:`~retworkx.PyDiGraph`.
 /// :param bool wf_improved: This is optional; the default is True. If True,
 ///     scale by the fraction of nodes reachable.
+/// :param weight_fn: An optional input callable that will be passed the edge's
+///     payload object and is expected to return a ``float`` length for the
+///     edge, which must not be negative. This also accepts a sequence or
+///     numpy array of edge lengths indexed by edge index.
+/// :param float default_weight: The length of every edge if ``weight_fn``
+///     is set to ``None``. This is only used when ``weight_fn`` is set or
+///     ``default_weight`` isn't ``1.0``.
+/// :param str direction: Either ``"incoming"`` to use the distances of the
+///     paths to each node or ``"outgoing"`` to use the distances of the paths
+///     from each node. The default is ``"incoming"``.
 ///
 /// :returns: A dictionary mapping each node index to its closeness centrality.
 /// :rtype: CentralityMapping
-#[pyfunction(signature = (graph, wf_improved=true))]
+/// :raises ValueError: If ``direction`` isn't ``"incoming"`` or ``"outgoing"``.
+#[pyfunction(
+    signature = (graph, wf_improved=true, weight_fn=None, default_weight=1.0, direction="incoming")
+)]
 pub fn digraph_closeness_centrality(
+    py: Python,
     graph: &digraph::PyDiGraph,
     wf_improved: bool,
-) -> CentralityMapping {
-    let closeness = centrality::closeness_centrality(&graph.graph, wf_improved);
-    CentralityMapping {
+    weight_fn: Option<PyObject>,
+    default_weight: f64,
+    direction: &str,
+) -> PyResult<CentralityMapping> {
+    let direction = parse_direction(direction)?;
+    let closeness =
+        if weight_fn.is_none() && default_weight == 1.0 && direction == Direction::Incoming {
+            centrality::closeness_centrality(&graph.graph, wf_improved)
+        } else {
+            let edge_weights = edge_lengths(py, &graph.graph, weight_fn, default_weight)?;
+            centrality::weighted_closeness_centrality(
+                &graph.graph,
+                wf_improved,
+                direction,
+                |e| -> PyResult<f64> { Ok(edge_weights[e.id().index()]) },
+            )?
+        };
+    Ok(CentralityMapping {
         centralities: closeness
             .into_iter()
             .enumerate()
             .filter_map(|(i, v)| v.map(|x| (i, x)))
             .collect(),
+    })
+}
+
+/// Compute the harmonic centrality of each node in a :class:`~.PyGraph` object.
+///
+/// The harmonic centrality of a node :math:`u` is the sum of the reciprocals
+/// of the shortest path distances to :math:`u` from all other nodes:
+///
+/// .. math::
+///
+///     H(u) = \sum_{v \neq u} \frac{1}{d(v, u)},
+///
+/// where nodes that can't reach :math:`u` add :math:`0` to the sum [BV]_, so
+/// unlike :func:`~rustworkx.graph_closeness_centrality` it is well defined
+/// on disconnected graphs without a correction. Nodes at distance :math:`0`
+/// are skipped.
+///
+/// :param PyGraph graph: The input graph.
+/// :param weight_fn: An optional input callable that will be passed the edge's
+///     payload object and is expected to return a ``float`` length for the
+///     edge, which must not be negative. This also accepts a sequence or
+///     numpy array of edge lengths indexed by edge index. If this isn't set
+///     every edge has the length ``default_weight``.
+/// :param float default_weight: The length of every edge if ``weight_fn``
+///     is set to ``None``. The default is ``1.0``.
+///
+/// :returns: A dictionary mapping each node index to its harmonic centrality.
+/// :rtype: CentralityMapping
+///
+/// .. jupyter-execute::
+///
+///   import rustworkx as rx
+///
+///   graph = rx.PyGraph()
+///   graph.extend_from_edge_list([(0, 1), (1, 2), (3, 4)])
+///   print(rx.graph_harmonic_centrality(graph))
+#[pyfunction(signature = (graph, weight_fn=None, default_weight=1.0))]
+pub fn graph_harmonic_centrality(
+    py: Python,
+    graph: &graph::PyGraph,
+    weight_fn: Option<PyObject>,
+    default_weight: f64,
+) -> PyResult<CentralityMapping> {
+    let edge_weights = edge_lengths(py, &graph.graph, weight_fn, default_weight)?;
+    let harmonic =
+        centrality::harmonic_centrality(&graph.graph, Direction::Incoming, |e| -> PyResult<f64> {
+            Ok(edge_weights[e.id().index()])
+        })?;
+    Ok(CentralityMapping {
+        centralities: harmonic
+            .into_iter()
+            .enumerate()
+            .filter_map(|(i, v)| v.map(|x| (i, x)))
+            .collect(),
+    })
+}
+
+/// Compute the harmonic centrality of each node in a :class:`~.PyDiGraph` object.
+///
+/// The harmonic centrality of a node :math:`u` is the sum of the reciprocals
+/// of the shortest path distances to :math:`u` from all other nodes:
+///
+/// .. math::
+///
+///     H(u) = \sum_{v \neq u} \frac{1}{d(v, u)},
+///
+/// where nodes that can't reach :math:`u` add :math:`0` to the sum [BV]_, so
+/// unlike :func:`~rustworkx.digraph_closeness_centrality` it is well defined
+/// on graphs that aren't strongly connected without a correction. Nodes at
+/// distance :math:`0` are skipped. With ``direction="outgoing"`` the
+/// distances from :math:`u` to the other nodes are used instead.
+///
+/// :param PyDiGraph graph: The input graph.
+/// :param weight_fn: An optional input callable that will be passed the edge's
+///     payload object and is expected to return a ``float`` length for the
+///     edge, which must not be negative. This also accepts a sequence or
+///     numpy array of edge lengths indexed by edge index. If this isn't set
+///     every edge has the length ``default_weight``.
+/// :param float default_weight: The length of every edge if ``weight_fn``
+///     is set to ``None``. The default is ``1.0``.
+/// :param str direction: Either ``"incoming"`` to use the distances of the
+///     paths to each node or ``"outgoing"`` to use the distances of the paths
+///     from each node. The default is ``"incoming"``.
+///
+/// :returns: A dictionary mapping each node index to its harmonic centrality.
+/// :rtype: CentralityMapping
+/// :raises ValueError: If ``direction`` isn't ``"incoming"`` or ``"outgoing"``.
+///
+/// .. jupyter-execute::
+///
+///   import rustworkx as rx
+///
+///   graph = rx.generators.directed_path_graph(4)
+///   print(rx.digraph_harmonic_centrality(graph))
+///   print(rx.digraph_harmonic_centrality(graph, direction="outgoing"))
+#[pyfunction(signature = (graph, weight_fn=None, default_weight=1.0, direction="incoming"))]
+pub fn digraph_harmonic_centrality(
+    py: Python,
+    graph: &digraph::PyDiGraph,
+    weight_fn: Option<PyObject>,
+    default_weight: f64,
+    direction: &str,
+) -> PyResult<CentralityMapping> {
+    let direction = parse_direction(direction)?;
+    let edge_weights = edge_lengths(py, &graph.graph, weight_fn, default_weight)?;
+    let harmonic =
+        centrality::harmonic_centrality(&graph.graph, direction, |e| -> PyResult<f64> {
+            Ok(edge_weights[e.id().index()])
+        })?;
+    Ok(CentralityMapping {
+        centralities: harmonic
+            .into_iter()
+            .enumerate()
+            .filter_map(|(i, v)| v.map(|x| (i, x)))
+            .collect(),
+    })
+}
+
+/// Parse the ``direction`` argument of the closeness and harmonic centrality
+/// functions of :class:`~.PyDiGraph`.
+fn parse_direction(direction: &str) -> PyResult<Direction> {
+    match direction {
+        "incoming" => Ok(Direction::Incoming),
+        "outgoing" => Ok(Direction::Outgoing),
+        _ => Err(PyValueError::new_err(
+            "direction must be either \"incoming\" or \"outgoing\"",
+        )),
+    }
+}
+
+/// The length of every edge of ``graph``, indexed by edge index.
+fn edge_lengths<Ty: EdgeType>(
+    py: Python,
+    graph: &StablePyGraph<Ty>,
+    weight_fn: Option<PyObject>,
+    default_weight: f64,
+) -> PyResult<Vec<f64>> {
+    let cost_fn = CostFn::try_from((weight_fn, default_weight))?;
+    let mut edge_weights = vec![default_weight; graph.edge_bound()];
+    for edge in graph.edge_indices() {
+        edge_weights[edge.index()] =
+            cost_fn.call_edge(py, edge, graph.edge_weight(edge).unwrap())?;
     }
+    Ok(edge_weights)
 }
 
 /// Compute the edge betweenness centrality of all edges in a :class:`~PyGraph`.
@@ -284,7 +700,9 @@ pub fn digraph_closeness_centrality(
 ///
 /// This function is multithreaded and will run in parallel if the number
 /// of nodes in the graph is above the value of ``parallel_threshold`` (it
-/// defaults to 50). If the function will be running in parallel the env var
+/// defaults to 50). If the function will be running in parallel the number
+/// of threads can be limited with ``num_threads`` or
+/// :func:`~rustworkx.set_num_threads`, otherwise the env var
 /// ``RAYON_NUM_THREADS`` can be used to adjust how many threads will be used.
 ///
 /// See Also
@@ -298,6 +716,21 @@ pub fn digraph_closeness_centrality(
 ///     the betweenness centrality in parallel at if the number of nodes in
 ///     the graph is less than this value it will run in a single thread. The
 ///     default value is 50
+/// :param int num_threads: The maximum number of threads to use. If it is not
+///     specified the number set with :func:`~rustworkx.set_num_threads` is
+///     used.
+/// :param progress_fn: An optional callable which is called as
+///     ``progress_fn(done, total)`` after every ``progress_interval`` of the
+///     ``total`` source nodes are searched from. An exception raised by it
+///     stops the computation and is raised from this function.
+/// :param int progress_interval: The number of source nodes between the calls
+///     to ``progress_fn``. By default it is called 100 times.
+/// :param weight_fn: An optional weight function for an edge. It will accept
+///     a single argument, the edge's weight object and will return a float
+///     which will be used as the length of the edge, which must be
+///     non-negative. It can also be a sequence or numpy array of the edge
+///     weights indexed by edge index, which avoids a Python call per edge. If
+///     it isn't set every edge has the length ``1``.
 ///
 /// :returns: a read-only dict-like object whose keys are the edge indices and values are the
 ///      betweenness score for each edge.
@@ -306,17 +739,36 @@ pub fn digraph_closeness_centrality(
     signature = (
         graph,
         normalized=true,
-        parallel_threshold=50
+        parallel_threshold=50,
+        num_threads=None,
+        progress_fn=None,
+        progress_interval=None,
+        weight_fn=None
     )
 )]
-#[pyo3(text_signature = "(graph, /, normalized=True, parallel_threshold=50)")]
+#[pyo3(
+    text_signature = "(graph, /, normalized=True, parallel_threshold=50, num_threads=None, progress_fn=None, progress_interval=None, weight_fn=None)"
+)]
 pub fn graph_edge_betweenness_centrality(
+    py: Python,
     graph: &graph::PyGraph,
     normalized: bool,
     parallel_threshold: usize,
+    num_threads: Option<usize>,
+    progress_fn: Option<PyObject>,
+    progress_interval: Option<usize>,
+    weight_fn: Option<PyObject>,
 ) -> PyResult<EdgeCentralityMapping> {
-    let betweenness =
-        centrality::edge_betweenness_centrality(&graph.graph, normalized, parallel_threshold);
+    let progress = ProgressFn::new(progress_fn, progress_interval)?;
+    let betweenness = edge_betweenness_inner(
+        py,
+        &graph.graph,
+        normalized,
+        parallel_threshold,
+        num_threads,
+        progress,
+        weight_fn,
+    )?;
     Ok(EdgeCentralityMapping {
         centralities: betweenness
             .into_iter()
@@ -346,7 +798,9 @@ pub fn graph_edge_betweenness_centrality(
 ///
 /// This function is multithreaded and will run in parallel if the number
 /// of nodes in the graph is above the value of ``parallel_threshold`` (it
-/// defaults to 50). If the function will be running in parallel the env var
+/// defaults to 50). If the function will be running in parallel the number
+/// of threads can be limited with ``num_threads`` or
+/// :func:`~rustworkx.set_num_threads`, otherwise the env var
 /// ``RAYON_NUM_THREADS`` can be used to adjust how many threads will be used.
 ///
 /// See Also
@@ -360,6 +814,21 @@ pub fn graph_edge_betweenness_centrality(
 ///     the betweenness centrality in parallel at if the number of nodes in
 ///     the graph is less than this value it will run in a single thread. The
 ///     default value is 50
+/// :param int num_threads: The maximum number of threads to use. If it is not
+///     specified the number set with :func:`~rustworkx.set_num_threads` is
+///     used.
+/// :param progress_fn: An optional callable which is called as
+///     ``progress_fn(done, total)`` after every ``progress_interval`` of the
+///     ``total`` source nodes are searched from. An exception raised by it
+///     stops the computation and is raised from this function.
+/// :param int progress_interval: The number of source nodes between the calls
+///     to ``progress_fn``. By default it is called 100 times.
+/// :param weight_fn: An optional weight function for an edge. It will accept
+///     a single argument, the edge's weight object and will return a float
+///     which will be used as the length of the edge, which must be
+///     non-negative. It can also be a sequence or numpy array of the edge
+///     weights indexed by edge index, which avoids a Python call per edge. If
+///     it isn't set every edge has the length ``1``.
 ///
 /// :returns: a read-only dict-like object whose keys are edges and values are the
 ///      betweenness score for each node.
@@ -368,17 +837,36 @@ pub fn graph_edge_betweenness_centrality(
     signature = (
         graph,
         normalized=true,
-        parallel_threshold=50
+        parallel_threshold=50,
+        num_threads=None,
+        progress_fn=None,
+        progress_interval=None,
+        weight_fn=None
     )
 )]
-#[pyo3(text_signature = "(graph, /, normalized=True, parallel_threshold=50)")]
+#[pyo3(
+    text_signature = "(graph, /, normalized=True, parallel_threshold=50, num_threads=None, progress_fn=None, progress_interval=None, weight_fn=None)"
+)]
 pub fn digraph_edge_betweenness_centrality(
+    py: Python,
     graph: &digraph::PyDiGraph,
     normalized: bool,
     parallel_threshold: usize,
+    num_threads: Option<usize>,
+    progress_fn: Option<PyObject>,
+    progress_interval: Option<usize>,
+    weight_fn: Option<PyObject>,
 ) -> PyResult<EdgeCentralityMapping> {
-    let betweenness =
-        centrality::edge_betweenness_centrality(&graph.graph, normalized, parallel_threshold);
+    let progress = ProgressFn::new(progress_fn, progress_interval)?;
+    let betweenness = edge_betweenness_inner(
+        py,
+        &graph.graph,
+        normalized,
+        parallel_threshold,
+        num_threads,
+        progress,
+        weight_fn,
+    )?;
     Ok(EdgeCentralityMapping {
         centralities: betweenness
             .into_iter()
@@ -388,6 +876,41 @@ pub fn digraph_edge_betweenness_centrality(
     })
 }
 
+/// Convert the outcome of a power iteration to a ``CentralityMapping``, or
+/// raise ``FailedToConverge`` with the last iteration as its
+/// ``partial_result`` if it didn't converge
+fn power_iteration_result<Ty: EdgeType>(
+    py: Python,
+    graph: &StablePyGraph<Ty>,
+    result: Option<centrality::PowerIteration>,
+    max_iter: usize,
+) -> PyResult<CentralityMapping> {
+    let err = || {
+        FailedToConverge::new_err(format!(
+            "Function failed to converge on a solution in {} iterations",
+            max_iter
+        ))
+    };
+    let Some(result) = result else {
+        return Err(err());
+    };
+    let centralities = CentralityMapping {
+        centralities: graph
+            .node_indices()
+            .map(|node| (node.index(), result.values[node.index()]))
+            .collect(),
+    };
+    if !result.converged {
+        let err = err();
+        let value = err.value_bound(py);
+        value.setattr("partial_result", centralities.into_py(py))?;
+        value.setattr("iterations", result.iterations)?;
+        value.setattr("residual", result.residual)?;
+        return Err(err);
+    }
+    Ok(centralities)
+}
+
 /// Compute the eigenvector centrality of a :class:`~PyGraph`.
 ///
 /// For details on the eigenvector centrality refer to:
@@ -422,6 +945,12 @@ pub fn digraph_edge_betweenness_centrality(
 /// :returns: a read-only dict-like object whose keys are the node indices and values are the
 ///      centrality score for that node.
 /// :rtype: CentralityMapping
+///
+/// :raises FailedToConverge: If the power iteration doesn't converge within
+///     ``max_iter`` iterations. The exception has a ``partial_result``
+///     attribute with the normalized centralities of the last iteration as a
+///     :class:`~.CentralityMapping`, and ``iterations`` and ``residual``
+///     attributes.
 #[pyfunction(
     signature = (
         graph,
@@ -445,34 +974,16 @@ pub fn graph_eigenvector_centrality(
         let cost_fn = CostFn::try_from((weight_fn, default_weight))?;
         for edge in graph.graph.edge_indices() {
             edge_weights[edge.index()] =
-                cost_fn.call(py, graph.graph.edge_weight(edge).unwrap())?;
+                cost_fn.call_edge(py, edge, graph.graph.edge_weight(edge).unwrap())?;
         }
     }
-    let ev_centrality = centrality::eigenvector_centrality(
+    let ev_centrality = centrality::eigenvector_centrality_iteration(
         &graph.graph,
         |e| -> PyResult<f64> { Ok(edge_weights[e.id().index()]) },
         Some(max_iter),
         Some(tol),
     )?;
-    match ev_centrality {
-        Some(centrality) => Ok(CentralityMapping {
-            centralities: centrality
-                .iter()
-                .enumerate()
-                .filter_map(|(k, v)| {
-                    if graph.graph.contains_node(NodeIndex::new(k)) {
-                        Some((k, *v))
-                    } else {
-                        None
-                    }
-                })
-                .collect(),
-        }),
-        None => Err(FailedToConverge::new_err(format!(
-            "Function failed to converge on a solution in {} iterations",
-            max_iter
-        ))),
-    }
+    power_iteration_result(py, &graph.graph, ev_centrality, max_iter)
 }
 
 /// Compute the eigenvector centrality of a :class:`~PyDiGraph`.
@@ -509,6 +1020,12 @@ pub fn graph_eigenvector_centrality(
 /// :returns: a read-only dict-like object whose keys are the node indices and values are the
 ///      centrality score for that node.
 /// :rtype: CentralityMapping
+///
+/// :raises FailedToConverge: If the power iteration doesn't converge within
+///     ``max_iter`` iterations. The exception has a ``partial_result``
+///     attribute with the normalized centralities of the last iteration as a
+///     :class:`~.CentralityMapping`, and ``iterations`` and ``residual``
+///     attributes.
 #[pyfunction(
     signature = (
         graph,
@@ -532,35 +1049,17 @@ pub fn digraph_eigenvector_centrality(
         let cost_fn = CostFn::try_from((weight_fn, default_weight))?;
         for edge in graph.graph.edge_indices() {
             edge_weights[edge.index()] =
-                cost_fn.call(py, graph.graph.edge_weight(edge).unwrap())?;
+                cost_fn.call_edge(py, edge, graph.graph.edge_weight(edge).unwrap())?;
         }
     }
-    let ev_centrality = centrality::eigenvector_centrality(
+    let ev_centrality = centrality::eigenvector_centrality_iteration(
         &graph.graph,
         |e| -> PyResult<f64> { Ok(edge_weights[e.id().index()]) },
         Some(max_iter),
         Some(tol),
     )?;
 
-    match ev_centrality {
-        Some(centrality) => Ok(CentralityMapping {
-            centralities: centrality
-                .iter()
-                .enumerate()
-                .filter_map(|(k, v)| {
-                    if graph.graph.contains_node(NodeIndex::new(k)) {
-                        Some((k, *v))
-                    } else {
-                        None
-                    }
-                })
-                .collect(),
-        }),
-        None => Err(FailedToConverge::new_err(format!(
-            "Function failed to converge on a solution in {} iterations",
-            max_iter
-        ))),
-    }
+    power_iteration_result(py, &graph.graph, ev_centrality, max_iter)
 }
 
 /// Compute the Katz centrality of a :class:`~PyGraph`.
@@ -601,6 +1100,12 @@ pub fn digraph_eigenvector_centrality(
 /// :returns: a read-only dict-like object whose keys are the node indices and values are the
 ///      centrality score for that node.
 /// :rtype: CentralityMapping
+///
+/// :raises FailedToConverge: If the power iteration doesn't converge within
+///     ``max_iter`` iterations. The exception has a ``partial_result``
+///     attribute with the normalized centralities of the last iteration as a
+///     :class:`~.CentralityMapping`, and ``iterations`` and ``residual``
+///     attributes.
 #[pyfunction(
     signature = (
         graph,
@@ -630,7 +1135,7 @@ pub fn graph_katz_centrality(
         let cost_fn = CostFn::try_from((weight_fn, default_weight))?;
         for edge in graph.graph.edge_indices() {
             edge_weights[edge.index()] =
-                cost_fn.call(py, graph.graph.edge_weight(edge).unwrap())?;
+                cost_fn.call_edge(py, edge, graph.graph.edge_weight(edge).unwrap())?;
         }
     }
 
@@ -663,7 +1168,7 @@ pub fn graph_katz_centrality(
         }
     }
 
-    let ev_centrality = centrality::katz_centrality(
+    let ev_centrality = centrality::katz_centrality_iteration(
         &graph.graph,
         |e| -> PyResult<f64> { Ok(edge_weights[e.id().index()]) },
         Some(alpha),
@@ -672,25 +1177,7 @@ pub fn graph_katz_centrality(
         Some(max_iter),
         Some(tol),
     )?;
-    match ev_centrality {
-        Some(centrality) => Ok(CentralityMapping {
-            centralities: centrality
-                .iter()
-                .enumerate()
-                .filter_map(|(k, v)| {
-                    if graph.graph.contains_node(NodeIndex::new(k)) {
-                        Some((k, *v))
-                    } else {
-                        None
-                    }
-                })
-                .collect(),
-        }),
-        None => Err(FailedToConverge::new_err(format!(
-            "Function failed to converge on a solution in {} iterations",
-            max_iter
-        ))),
-    }
+    power_iteration_result(py, &graph.graph, ev_centrality, max_iter)
 }
 
 /// Compute the Katz centrality of a :class:`~PyDiGraph`.
@@ -731,6 +1218,12 @@ pub fn graph_katz_centrality(
 /// :returns: a read-only dict-like object whose keys are the node indices and values are the
 ///      centrality score for that node.
 /// :rtype: CentralityMapping
+///
+/// :raises FailedToConverge: If the power iteration doesn't converge within
+///     ``max_iter`` iterations. The exception has a ``partial_result``
+///     attribute with the normalized centralities of the last iteration as a
+///     :class:`~.CentralityMapping`, and ``iterations`` and ``residual``
+///     attributes.
 #[pyfunction(
     signature = (
         graph,
@@ -760,7 +1253,7 @@ pub fn digraph_katz_centrality(
         let cost_fn = CostFn::try_from((weight_fn, default_weight))?;
         for edge in graph.graph.edge_indices() {
             edge_weights[edge.index()] =
-                cost_fn.call(py, graph.graph.edge_weight(edge).unwrap())?;
+                cost_fn.call_edge(py, edge, graph.graph.edge_weight(edge).unwrap())?;
         }
     }
 
@@ -793,7 +1286,7 @@ pub fn digraph_katz_centrality(
         }
     }
 
-    let ev_centrality = centrality::katz_centrality(
+    let ev_centrality = centrality::katz_centrality_iteration(
         &graph.graph,
         |e| -> PyResult<f64> { Ok(edge_weights[e.id().index()]) },
         Some(alpha),
@@ -803,23 +1296,5 @@ pub fn digraph_katz_centrality(
         Some(tol),
     )?;
 
-    match ev_centrality {
-        Some(centrality) => Ok(CentralityMapping {
-            centralities: centrality
-                .iter()
-                .enumerate()
-                .filter_map(|(k, v)| {
-                    if graph.graph.contains_node(NodeIndex::new(k)) {
-                        Some((k, *v))
-                    } else {
-                        None
-                    }
-                })
-                .collect(),
-        }),
-        None => Err(FailedToConverge::new_err(format!(
-            "Function failed to converge on a solution in {} iterations",
-            max_iter
-        ))),
-    }
+    power_iteration_result(py, &graph.graph, ev_centrality, max_iter)
 }
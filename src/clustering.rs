@@ -0,0 +1,156 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+use crate::{graph, weight_callable};
+
+use rustworkx_core::clustering::{self, WeightedClustering};
+use rustworkx_core::dictmap::*;
+
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pyo3::Python;
+
+fn to_dict(clustering: Vec<Option<f64>>) -> DictMap<usize, f64> {
+    clustering
+        .into_iter()
+        .enumerate()
+        .filter_map(|(index, value)| value.map(|value| (index, value)))
+        .collect()
+}
+
+/// Compute the square clustering coefficient of every node of a
+/// :class:`~.PyGraph`.
+///
+/// The square clustering coefficient of a node is the fraction of the
+/// possible squares, cycles of length four, through the node that exist in
+/// the graph [Lind]_. It's the analogue of the triangle-based clustering
+/// coefficient for graphs with few triangles, such as bipartite graphs. For
+/// every pair of neighbors :math:`u` and :math:`w` of a node :math:`v`,
+/// :math:`q_v(u, w)` is the number of common neighbors of :math:`u` and
+/// :math:`w` other than :math:`v`, and the coefficient is
+///
+/// .. math::
+///
+///     C_4(v) = \frac{\sum_{u < w} q_v(u, w)}{\sum_{u < w} [a_v(u, w) + q_v(u, w)]}
+///
+/// where :math:`a_v(u, w)` is the number of neighbors of :math:`u` and
+/// :math:`w` that aren't :math:`v`, the other node of the pair or a common
+/// neighbor of the pair. The coefficient is ``0.0`` for nodes without any
+/// possible squares.
+///
+/// The graph is treated as a simple graph: self-loops are ignored and
+/// parallel edges are counted once.
+///
+/// :param PyGraph graph: The graph to compute the coefficients for
+///
+/// :returns: A dictionary mapping the index of every node to its square
+///     clustering coefficient.
+/// :rtype: dict
+///
+/// .. jupyter-execute::
+///
+///   import rustworkx as rx
+///
+///   graph = rx.generators.grid_graph(3, 3)
+///   print(rx.square_clustering(graph))
+///
+/// .. [Lind] Cycles and clustering in bipartite networks by P. G. Lind,
+///    M. C. Gonzalez and H. J. Herrmann, Phys. Rev. E 72, 056127 (2005)
+#[pyfunction]
+#[pyo3(text_signature = "(graph, /)")]
+pub fn square_clustering(graph: &graph::PyGraph) -> DictMap<usize, f64> {
+    to_dict(clustering::square_clustering(&graph.graph))
+}
+
+/// Compute the weighted clustering coefficient of every node of a
+/// :class:`~.PyGraph`.
+///
+/// The weighted clustering coefficient generalizes the clustering
+/// coefficient, the fraction of the pairs of neighbors of a node that are
+/// adjacent, to weighted graphs. For a node :math:`u` with degree :math:`k`
+/// and strength :math:`s`, the sum of the weights of its edges, the
+/// ``"onnela"`` definition [Onnela]_ is
+///
+/// .. math::
+///
+///     C(u) = \frac{2}{k (k - 1)} \sum_{v < w} (\hat{w}_{uv} \hat{w}_{uw} \hat{w}_{vw})^{1/3}
+///
+/// where the weights :math:`\hat{w}` are normalized by the largest weight of
+/// the graph, and the ``"barrat"`` definition [Barrat]_ is
+///
+/// .. math::
+///
+///     C(u) = \frac{1}{s (k - 1)} \sum_{v < w} (w_{uv} + w_{uw})
+///
+/// where both sums are over the pairs of adjacent neighbors of :math:`u`.
+/// With equal weights both are the unweighted clustering coefficient. The
+/// coefficient is ``0.0`` for nodes with a degree less than two.
+///
+/// The graph is treated as a simple graph: self-loops are ignored and the
+/// weights of parallel edges are summed.
+///
+/// :param PyGraph graph: The graph to compute the coefficients for
+/// :param weight_fn: An optional callable that will be passed the data
+///     payload of every edge and is expected to return a non-negative
+///     ``float`` weight for that edge.
+/// :param float default_weight: If ``weight_fn`` is not specified the weight
+///     used for every edge. Default: ``1.0``.
+/// :param str method: The definition of the weighted clustering coefficient,
+///     either ``"onnela"`` or ``"barrat"``. Default: ``"onnela"``.
+///
+/// :returns: A dictionary mapping the index of every node to its weighted
+///     clustering coefficient.
+/// :rtype: dict
+/// :raises ValueError: If ``method`` is not ``"onnela"`` or ``"barrat"``
+///
+/// .. jupyter-execute::
+///
+///   import rustworkx as rx
+///
+///   graph = rx.PyGraph()
+///   graph.extend_from_weighted_edge_list([(0, 1, 1.0), (1, 2, 2.0), (2, 0, 4.0), (0, 3, 1.0)])
+///   print(rx.weighted_clustering(graph, weight_fn=float))
+///   print(rx.weighted_clustering(graph, weight_fn=float, method="barrat"))
+///
+/// .. [Onnela] Intensity and coherence of motifs in weighted complex networks
+///    by J. P. Onnela, J. Saramäki, J. Kertész and K. Kaski,
+///    Phys. Rev. E 71, 065103 (2005)
+/// .. [Barrat] The architecture of complex weighted networks by A. Barrat,
+///    M. Barthélemy, R. Pastor-Satorras and A. Vespignani,
+///    PNAS 101 (11) 3747-3752 (2004)
+#[pyfunction]
+#[pyo3(
+    signature=(graph, /, weight_fn=None, default_weight=1.0, method="onnela"),
+    text_signature = "(graph, /, weight_fn=None, default_weight=1.0, method=\"onnela\")"
+)]
+pub fn weighted_clustering(
+    py: Python,
+    graph: &graph::PyGraph,
+    weight_fn: Option<PyObject>,
+    default_weight: f64,
+    method: &str,
+) -> PyResult<DictMap<usize, f64>> {
+    let method = match method {
+        "onnela" => WeightedClustering::Onnela,
+        "barrat" => WeightedClustering::Barrat,
+        _ => {
+            return Err(PyValueError::new_err(format!(
+                "Invalid method '{}', it must be 'onnela' or 'barrat'",
+                method
+            )))
+        }
+    };
+    let res = clustering::weighted_clustering(&graph.graph, method, |edge| {
+        weight_callable(py, &weight_fn, edge.weight(), default_weight)
+    })?;
+    Ok(to_dict(res))
+}
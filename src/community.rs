@@ -0,0 +1,454 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+use crate::iterators::NodeMap;
+use crate::{digraph, graph, weight_callable, InvalidNode, StablePyGraph};
+
+use petgraph::prelude::*;
+use petgraph::visit::{IntoEdgeReferences, NodeIndexable};
+use petgraph::{algo, EdgeType};
+use rustworkx_core::community;
+use rustworkx_core::dictmap::*;
+
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pyo3::Python;
+
+fn py_modularity<Ty: EdgeType>(
+    py: Python,
+    graph: &StablePyGraph<Ty>,
+    communities: Vec<Vec<usize>>,
+    weight_fn: Option<PyObject>,
+    default_weight: f64,
+    resolution: f64,
+) -> PyResult<f64> {
+    let communities: Vec<Vec<NodeIndex>> = communities
+        .into_iter()
+        .map(|nodes| nodes.into_iter().map(NodeIndex::new).collect())
+        .collect();
+    community::modularity(
+        graph,
+        &communities,
+        |edge| weight_callable(py, &weight_fn, edge.weight(), default_weight),
+        resolution,
+    )?
+    .ok_or_else(|| {
+        PyValueError::new_err("communities must be a partition of the nodes of the graph")
+    })
+}
+
+fn py_sum_parallel_edges<Ty: EdgeType>(
+    py: Python,
+    graph: &StablePyGraph<Ty>,
+    weight_fn: Option<PyObject>,
+    default_weight: f64,
+    combine_fn: Option<PyObject>,
+) -> PyResult<StablePyGraph<Ty>> {
+    // Combining can fail in Python, so the first error is kept and the
+    // remaining weights are ignored
+    let mut combine_error: Option<PyErr> = None;
+    let weights = community::sum_parallel_edges(
+        graph,
+        |edge| weight_callable(py, &weight_fn, edge.weight(), default_weight),
+        |a: f64, b: f64| match &combine_fn {
+            None => a + b,
+            Some(combine_fn) => {
+                if combine_error.is_some() {
+                    return a;
+                }
+                match combine_fn
+                    .bind(py)
+                    .call1((a, b))
+                    .and_then(|res| res.extract::<f64>())
+                {
+                    Ok(weight) => weight,
+                    Err(err) => {
+                        combine_error = Some(err);
+                        a
+                    }
+                }
+            }
+        },
+    )?;
+    if let Some(err) = combine_error {
+        return Err(err);
+    }
+    let mut out_graph = graph.clone();
+    out_graph.clear_edges();
+    for ((source, target), weight) in weights {
+        out_graph.add_edge(source, target, weight.into_py(py));
+    }
+    Ok(out_graph)
+}
+
+fn py_community_subgraphs<Ty: EdgeType>(
+    py: Python,
+    graph: &StablePyGraph<Ty>,
+    communities: Vec<Vec<usize>>,
+) -> PyResult<Vec<(StablePyGraph<Ty>, NodeMap)>> {
+    // The communities every node is in and the index of the node in the
+    // subgraph of each of them
+    let mut membership: Vec<Vec<(usize, NodeIndex)>> = vec![Vec::new(); graph.node_bound()];
+    let mut subgraphs: Vec<(StablePyGraph<Ty>, NodeMap)> = Vec::with_capacity(communities.len());
+    for (community, mut nodes) in communities.into_iter().enumerate() {
+        nodes.sort_unstable();
+        nodes.dedup();
+        let mut out_graph = StablePyGraph::<Ty>::with_capacity(nodes.len(), 0);
+        let mut node_map: DictMap<usize, usize> = DictMap::with_capacity(nodes.len());
+        for node in nodes {
+            if !graph.contains_node(NodeIndex::new(node)) {
+                return Err(InvalidNode::new_err(format!(
+                    "Node index {} is not in the graph",
+                    node
+                )));
+            }
+            let new_node = out_graph.add_node(graph[NodeIndex::new(node)].clone_ref(py));
+            membership[node].push((community, new_node));
+            node_map.insert(new_node.index(), node);
+        }
+        subgraphs.push((out_graph, NodeMap { node_map }));
+    }
+    for edge in graph.edge_references() {
+        for &(community, source) in &membership[edge.source().index()] {
+            let target = membership[edge.target().index()]
+                .iter()
+                .find(|(other, _)| *other == community);
+            if let Some(&(_, target)) = target {
+                subgraphs[community]
+                    .0
+                    .add_edge(source, target, edge.weight().clone_ref(py));
+            }
+        }
+    }
+    Ok(subgraphs)
+}
+
+/// Compute the modularity of a partition of the nodes of a
+/// :class:`~rustworkx.PyGraph` object.
+///
+/// The modularity [Newman2004]_ of a partition into communities is
+///
+/// .. math::
+///
+///     Q = \sum_c \left( \frac{L_c}{m} - \gamma \left( \frac{d_c}{2m}
+///     \right)^2 \right)
+///
+/// where :math:`m` is the total weight of the edges, :math:`\gamma` is the
+/// resolution, and for every community :math:`c`, :math:`L_c` is the total
+/// weight of the edges inside the community and :math:`d_c` is the sum of
+/// the weighted degrees of its nodes.
+///
+/// The graph can be a multigraph: the weights of parallel edges add up, and
+/// a self-loop of weight :math:`w` is an edge inside the community of its
+/// node that adds :math:`2w` to the weighted degree of the node.
+///
+/// :param PyGraph graph: The graph to compute the modularity for
+/// :param list communities: A list of the communities of the partition, each
+///     of them a list of node indices. Every node of the graph has to be in
+///     exactly one community.
+/// :param weight_fn: An optional callable that will be passed the data
+///     payload of every edge and is expected to return a ``float`` weight
+///     for that edge.
+/// :param float default_weight: If ``weight_fn`` is not specified the weight
+///     used for every edge. Default: ``1.0``.
+/// :param float resolution: The resolution :math:`\gamma`, values smaller
+///     than ``1`` favor larger communities. Default: ``1.0``.
+///
+/// :returns: The modularity of the partition, ``nan`` if the total weight
+///     of the edges is ``0``
+/// :rtype: float
+/// :raises ValueError: when ``communities`` is not a partition of the nodes
+///     of the graph
+///
+/// .. [Newman2004] M. E. J. Newman, "Analysis of weighted networks",
+///     Physical Review E 70, 056131 (2004).
+#[pyfunction]
+#[pyo3(
+    signature=(graph, communities, weight_fn=None, default_weight=1.0, resolution=1.0),
+    text_signature = "(graph, communities, /, weight_fn=None, default_weight=1.0, resolution=1.0)"
+)]
+pub fn graph_modularity(
+    py: Python,
+    graph: &graph::PyGraph,
+    communities: Vec<Vec<usize>>,
+    weight_fn: Option<PyObject>,
+    default_weight: f64,
+    resolution: f64,
+) -> PyResult<f64> {
+    py_modularity(
+        py,
+        &graph.graph,
+        communities,
+        weight_fn,
+        default_weight,
+        resolution,
+    )
+}
+
+/// Compute the modularity of a partition of the nodes of a
+/// :class:`~rustworkx.PyDiGraph` object.
+///
+/// The modularity of a partition of a directed graph into communities is
+///
+/// .. math::
+///
+///     Q = \sum_c \left( \frac{L_c}{m} - \gamma \frac{d^{out}_c
+///     d^{in}_c}{m^2} \right)
+///
+/// where :math:`m` is the total weight of the edges, :math:`\gamma` is the
+/// resolution, and for every community :math:`c`, :math:`L_c` is the total
+/// weight of the edges inside the community and :math:`d^{out}_c` and
+/// :math:`d^{in}_c` are the sums of the weighted out-degrees and in-degrees
+/// of its nodes.
+///
+/// The graph can be a multigraph: the weights of parallel edges add up, and
+/// a self-loop is an edge inside the community of its node.
+///
+/// :param PyDiGraph graph: The graph to compute the modularity for
+/// :param list communities: A list of the communities of the partition, each
+///     of them a list of node indices. Every node of the graph has to be in
+///     exactly one community.
+/// :param weight_fn: An optional callable that will be passed the data
+///     payload of every edge and is expected to return a ``float`` weight
+///     for that edge.
+/// :param float default_weight: If ``weight_fn`` is not specified the weight
+///     used for every edge. Default: ``1.0``.
+/// :param float resolution: The resolution :math:`\gamma`, values smaller
+///     than ``1`` favor larger communities. Default: ``1.0``.
+///
+/// :returns: The modularity of the partition, ``nan`` if the total weight
+///     of the edges is ``0``
+/// :rtype: float
+/// :raises ValueError: when ``communities`` is not a partition of the nodes
+///     of the graph
+#[pyfunction]
+#[pyo3(
+    signature=(graph, communities, weight_fn=None, default_weight=1.0, resolution=1.0),
+    text_signature = "(graph, communities, /, weight_fn=None, default_weight=1.0, resolution=1.0)"
+)]
+pub fn digraph_modularity(
+    py: Python,
+    graph: &digraph::PyDiGraph,
+    communities: Vec<Vec<usize>>,
+    weight_fn: Option<PyObject>,
+    default_weight: f64,
+    resolution: f64,
+) -> PyResult<f64> {
+    py_modularity(
+        py,
+        &graph.graph,
+        communities,
+        weight_fn,
+        default_weight,
+        resolution,
+    )
+}
+
+/// Collapse the parallel edges of a :class:`~rustworkx.PyGraph` multigraph
+/// into a weighted simple graph.
+///
+/// The returned graph has the same nodes, with the same indices and data
+/// payloads, and a single edge between every pair of nodes connected by at
+/// least one edge. The payload of every edge is a ``float`` weight: the
+/// weights of the parallel edges combined with ``combine_fn``, their sum by
+/// default. Self-loops are kept, with the parallel self-loops of a node
+/// combined.
+///
+/// :param PyGraph graph: The multigraph to collapse
+/// :param weight_fn: An optional callable that will be passed the data
+///     payload of every edge and is expected to return a ``float`` weight
+///     for that edge.
+/// :param float default_weight: If ``weight_fn`` is not specified the weight
+///     used for every edge. Default: ``1.0``.
+/// :param combine_fn: An optional callable that will be passed two weights
+///     and is expected to return their combined ``float`` weight, for
+///     example ``max``. If not specified the weights are summed.
+///
+/// :returns: The weighted simple graph
+/// :rtype: PyGraph
+#[pyfunction]
+#[pyo3(
+    signature=(graph, weight_fn=None, default_weight=1.0, combine_fn=None),
+    text_signature = "(graph, /, weight_fn=None, default_weight=1.0, combine_fn=None)"
+)]
+pub fn graph_sum_parallel_edges(
+    py: Python,
+    graph: &graph::PyGraph,
+    weight_fn: Option<PyObject>,
+    default_weight: f64,
+    combine_fn: Option<PyObject>,
+) -> PyResult<graph::PyGraph> {
+    Ok(graph::PyGraph {
+        graph: py_sum_parallel_edges(py, &graph.graph, weight_fn, default_weight, combine_fn)?,
+        node_removed: graph.node_removed,
+        multigraph: graph.multigraph,
+        attrs: graph.attrs.clone_ref(py),
+    })
+}
+
+/// Collapse the parallel edges of a :class:`~rustworkx.PyDiGraph`
+/// multigraph into a weighted simple graph.
+///
+/// The returned graph has the same nodes, with the same indices and data
+/// payloads, and a single edge from every node to each of its successors.
+/// Edges in opposite directions are not parallel. The payload of every edge
+/// is a ``float`` weight: the weights of the parallel edges combined with
+/// ``combine_fn``, their sum by default. Self-loops are kept, with the
+/// parallel self-loops of a node combined.
+///
+/// :param PyDiGraph graph: The multigraph to collapse
+/// :param weight_fn: An optional callable that will be passed the data
+///     payload of every edge and is expected to return a ``float`` weight
+///     for that edge.
+/// :param float default_weight: If ``weight_fn`` is not specified the weight
+///     used for every edge. Default: ``1.0``.
+/// :param combine_fn: An optional callable that will be passed two weights
+///     and is expected to return their combined ``float`` weight, for
+///     example ``max``. If not specified the weights are summed.
+///
+/// :returns: The weighted simple graph
+/// :rtype: PyDiGraph
+#[pyfunction]
+#[pyo3(
+    signature=(graph, weight_fn=None, default_weight=1.0, combine_fn=None),
+    text_signature = "(graph, /, weight_fn=None, default_weight=1.0, combine_fn=None)"
+)]
+pub fn digraph_sum_parallel_edges(
+    py: Python,
+    graph: &digraph::PyDiGraph,
+    weight_fn: Option<PyObject>,
+    default_weight: f64,
+    combine_fn: Option<PyObject>,
+) -> PyResult<digraph::PyDiGraph> {
+    let mut out_graph = graph.clone();
+    out_graph.graph =
+        py_sum_parallel_edges(py, &graph.graph, weight_fn, default_weight, combine_fn)?;
+    Ok(out_graph)
+}
+
+/// Return the induced subgraph of every community of a
+/// :class:`~rustworkx.PyGraph` object.
+///
+/// This is equivalent to calling :meth:`~rustworkx.PyGraph.subgraph` for
+/// every community, but all the subgraphs are built in a single pass over
+/// the edges of the graph. The nodes of every subgraph are added in
+/// increasing order of their index in ``graph``, and the data payloads of
+/// the nodes and edges are shared with ``graph``, not copied. The
+/// communities don't have to be a partition of the nodes: they can overlap
+/// and they don't have to cover every node.
+///
+/// :param PyGraph graph: The graph to extract the communities from
+/// :param list communities: A list of the communities, each of them a list
+///     of node indices
+/// :param bool preserve_attrs: If set to ``True`` the attributes of
+///     ``graph`` will be copied by reference to the ``attrs`` attribute of
+///     every subgraph. Default: ``False``.
+///
+/// :returns: A list with a tuple for every community, in the order of
+///     ``communities``, of its induced subgraph and a
+///     :class:`~rustworkx.NodeMap` mapping the node indices of the subgraph
+///     to the node indices of ``graph``
+/// :rtype: list
+/// :raises InvalidNode: when a community contains a node index that isn't
+///     in the graph
+#[pyfunction]
+#[pyo3(
+    signature=(graph, communities, preserve_attrs=false),
+    text_signature = "(graph, communities, /, preserve_attrs=False)"
+)]
+pub fn graph_community_subgraphs(
+    py: Python,
+    graph: &graph::PyGraph,
+    communities: Vec<Vec<usize>>,
+    preserve_attrs: bool,
+) -> PyResult<Vec<(graph::PyGraph, NodeMap)>> {
+    let subgraphs = py_community_subgraphs(py, &graph.graph, communities)?;
+    Ok(subgraphs
+        .into_iter()
+        .map(|(out_graph, node_map)| {
+            let attrs = if preserve_attrs {
+                graph.attrs.clone_ref(py)
+            } else {
+                py.None()
+            };
+            (
+                graph::PyGraph {
+                    graph: out_graph,
+                    node_removed: false,
+                    multigraph: graph.multigraph,
+                    attrs,
+                },
+                node_map,
+            )
+        })
+        .collect())
+}
+
+/// Return the induced subgraph of every community of a
+/// :class:`~rustworkx.PyDiGraph` object.
+///
+/// This is equivalent to calling :meth:`~rustworkx.PyDiGraph.subgraph` for
+/// every community, but all the subgraphs are built in a single pass over
+/// the edges of the graph. The nodes of every subgraph are added in
+/// increasing order of their index in ``graph``, and the data payloads of
+/// the nodes and edges are shared with ``graph``, not copied. The
+/// communities don't have to be a partition of the nodes: they can overlap
+/// and they don't have to cover every node.
+///
+/// :param PyDiGraph graph: The graph to extract the communities from
+/// :param list communities: A list of the communities, each of them a list
+///     of node indices
+/// :param bool preserve_attrs: If set to ``True`` the attributes of
+///     ``graph`` will be copied by reference to the ``attrs`` attribute of
+///     every subgraph. Default: ``False``.
+///
+/// :returns: A list with a tuple for every community, in the order of
+///     ``communities``, of its induced subgraph and a
+///     :class:`~rustworkx.NodeMap` mapping the node indices of the subgraph
+///     to the node indices of ``graph``
+/// :rtype: list
+/// :raises InvalidNode: when a community contains a node index that isn't
+///     in the graph
+#[pyfunction]
+#[pyo3(
+    signature=(graph, communities, preserve_attrs=false),
+    text_signature = "(graph, communities, /, preserve_attrs=False)"
+)]
+pub fn digraph_community_subgraphs(
+    py: Python,
+    graph: &digraph::PyDiGraph,
+    communities: Vec<Vec<usize>>,
+    preserve_attrs: bool,
+) -> PyResult<Vec<(digraph::PyDiGraph, NodeMap)>> {
+    let subgraphs = py_community_subgraphs(py, &graph.graph, communities)?;
+    Ok(subgraphs
+        .into_iter()
+        .map(|(out_graph, node_map)| {
+            let attrs = if preserve_attrs {
+                graph.attrs.clone_ref(py)
+            } else {
+                py.None()
+            };
+            (
+                digraph::PyDiGraph {
+                    graph: out_graph,
+                    cycle_state: algo::DfsSpace::default(),
+                    check_cycle: graph.check_cycle,
+                    node_removed: false,
+                    multigraph: graph.multigraph,
+                    attrs,
+                },
+                node_map,
+            )
+        })
+        .collect())
+}
@@ -17,14 +17,15 @@ mod johnson_simple_cycles;
 mod subgraphs;
 
 use super::{
-    digraph, get_edge_iter_with_weights, graph, score, weight_callable, InvalidNode, NullGraph,
+    digraph, get_edge_iter_with_weights, graph, is_valid_weight, score, weight_callable,
+    InvalidNode, NullGraph,
 };
 
 use hashbrown::{HashMap, HashSet};
 use petgraph::algo;
 use petgraph::algo::condensation;
 use petgraph::graph::DiGraph;
-use petgraph::stable_graph::NodeIndex;
+use petgraph::stable_graph::{EdgeIndex, NodeIndex};
 use petgraph::unionfind::UnionFind;
 use petgraph::visit::{EdgeRef, IntoEdgeReferences, NodeCount, NodeIndexable, Visitable};
 use pyo3::exceptions::PyValueError;
@@ -39,9 +40,10 @@ use numpy::IntoPyArray;
 use crate::iterators::{
     AllPairsMultiplePathMapping, BiconnectedComponents, Chains, EdgeList, NodeIndices,
 };
-use crate::{EdgeType, StablePyGraph};
+use crate::{csr_structure, EdgeType, GraphNotBipartite, StablePyGraph, CSR_NODE_THRESHOLD};
 
 use crate::graph::PyGraph;
+use rustworkx_core::bipartite::bipartite_sets;
 use rustworkx_core::coloring::two_color;
 use rustworkx_core::connectivity;
 use rustworkx_core::dag_algo::longest_path;
@@ -135,6 +137,19 @@ pub fn digraph_find_cycle(graph: &digraph::PyDiGraph, source: Option<usize>) ->
     }
 }
 
+/// The (weakly) connected components of a graph as sets of node indices,
+/// found on the compressed sparse row form of the graph if it is large
+fn component_sets<Ty: EdgeType>(graph: &StablePyGraph<Ty>) -> Vec<HashSet<usize>> {
+    if graph.node_count() < CSR_NODE_THRESHOLD {
+        connectivity::connected_components(graph)
+            .into_iter()
+            .map(|component| component.into_iter().map(|x| x.index()).collect())
+            .collect()
+    } else {
+        connectivity::connected_components(&csr_structure(graph))
+    }
+}
+
 /// Find the number of connected components in an undirected graph.
 ///
 /// :param PyGraph graph: The graph to find the number of connected
@@ -145,7 +160,11 @@ pub fn digraph_find_cycle(graph: &digraph::PyDiGraph, source: Option<usize>) ->
 #[pyfunction]
 #[pyo3(text_signature = "(graph, /)")]
 pub fn number_connected_components(graph: &graph::PyGraph) -> usize {
-    connectivity::number_connected_components(&graph.graph)
+    if graph.graph.node_count() < CSR_NODE_THRESHOLD {
+        connectivity::number_connected_components(&graph.graph)
+    } else {
+        connectivity::number_connected_components(&csr_structure(&graph.graph))
+    }
 }
 
 /// Find the connected components in an undirected graph
@@ -158,10 +177,7 @@ pub fn number_connected_components(graph: &graph::PyGraph) -> usize {
 #[pyfunction]
 #[pyo3(text_signature = "(graph, /)")]
 pub fn connected_components(graph: &graph::PyGraph) -> Vec<HashSet<usize>> {
-    connectivity::connected_components(&graph.graph)
-        .into_iter()
-        .map(|res_map| res_map.into_iter().map(|x| x.index()).collect())
-        .collect()
+    component_sets(&graph.graph)
 }
 
 /// Returns the set of nodes in the component of graph containing `node`.
@@ -245,10 +261,7 @@ pub fn number_weakly_connected_components(graph: &digraph::PyDiGraph) -> usize {
 #[pyfunction]
 #[pyo3(text_signature = "(graph, /)")]
 pub fn weakly_connected_components(graph: &digraph::PyDiGraph) -> Vec<HashSet<usize>> {
-    connectivity::connected_components(&graph.graph)
-        .into_iter()
-        .map(|res_map| res_map.into_iter().map(|x| x.index()).collect())
-        .collect()
+    component_sets(&graph.graph)
 }
 
 /// Check if the graph is weakly connected
@@ -918,6 +931,258 @@ pub fn digraph_core_number(py: Python, graph: &digraph::PyDiGraph) -> PyResult<P
     Ok(out_dict.into())
 }
 
+/// Return the s-core number for each node in the graph.
+///
+/// The s-core decomposition [Eidsaa2013]_ generalizes the k-core
+/// decomposition, see :func:`~rustworkx.graph_core_number`, to weighted
+/// graphs by using the strength of a node, the total weight of its edges,
+/// instead of its degree. An s-core is a maximal subgraph in which every
+/// node has a strength of at least ``s``, and the s-core number of a node
+/// is the largest ``s`` of an s-core that contains it.
+///
+/// The weights of parallel edges add up in the strength of a node, and self
+/// loops don't contribute to it. With unit weights the s-core numbers of a
+/// graph without parallel edges are its core numbers.
+///
+/// :param PyGraph graph: The graph to get the s-core numbers of
+/// :param weight_fn: An optional callable that will be passed the data
+///     payload of every edge and is expected to return a non-negative
+///     ``float`` weight for that edge.
+/// :param float default_weight: If ``weight_fn`` is not specified the weight
+///     used for every edge. Default: ``1.0``.
+///
+/// :returns: A dictionary keyed by node index to the s-core number
+/// :rtype: dict
+/// :raises ValueError: when an edge has a negative or NaN weight
+///
+/// .. [Eidsaa2013] M. Eidsaa and E. Almaas, "s-core network decomposition: A
+///     generalization of k-core analysis to weighted networks", Physical
+///     Review E 88, 062819 (2013).
+#[pyfunction]
+#[pyo3(
+    signature=(graph, weight_fn=None, default_weight=1.0),
+    text_signature = "(graph, /, weight_fn=None, default_weight=1.0)"
+)]
+pub fn graph_s_core_number(
+    py: Python,
+    graph: &graph::PyGraph,
+    weight_fn: Option<PyObject>,
+    default_weight: f64,
+) -> PyResult<PyObject> {
+    let cores = connectivity::s_core_number(&graph.graph, |edge| {
+        is_valid_weight(weight_callable(
+            py,
+            &weight_fn,
+            edge.weight(),
+            default_weight,
+        )?)
+    })?;
+    let out_dict = PyDict::new_bound(py);
+    for (k, v) in cores {
+        out_dict.set_item(k.index(), v)?;
+    }
+    Ok(out_dict.into())
+}
+
+/// Return the s-core number for each node in the directed graph.
+///
+/// The s-core decomposition [Eidsaa2013]_ generalizes the k-core
+/// decomposition, see :func:`~rustworkx.digraph_core_number`, to weighted
+/// graphs by using the strength of a node, the total weight of its edges,
+/// instead of its degree. An s-core is a maximal subgraph in which every
+/// node has a strength of at least ``s``, and the s-core number of a node
+/// is the largest ``s`` of an s-core that contains it.
+///
+/// The strength of a node ignores the direction of the edges: the weights of
+/// parallel edges and of edges in opposite directions add up, and self loops
+/// don't contribute to it. With unit weights the s-core numbers of a graph
+/// without parallel edges or edges in opposite directions are its core
+/// numbers.
+///
+/// :param PyDiGraph graph: The graph to get the s-core numbers of
+/// :param weight_fn: An optional callable that will be passed the data
+///     payload of every edge and is expected to return a non-negative
+///     ``float`` weight for that edge.
+/// :param float default_weight: If ``weight_fn`` is not specified the weight
+///     used for every edge. Default: ``1.0``.
+///
+/// :returns: A dictionary keyed by node index to the s-core number
+/// :rtype: dict
+/// :raises ValueError: when an edge has a negative or NaN weight
+///
+/// .. [Eidsaa2013] M. Eidsaa and E. Almaas, "s-core network decomposition: A
+///     generalization of k-core analysis to weighted networks", Physical
+///     Review E 88, 062819 (2013).
+#[pyfunction]
+#[pyo3(
+    signature=(graph, weight_fn=None, default_weight=1.0),
+    text_signature = "(graph, /, weight_fn=None, default_weight=1.0)"
+)]
+pub fn digraph_s_core_number(
+    py: Python,
+    graph: &digraph::PyDiGraph,
+    weight_fn: Option<PyObject>,
+    default_weight: f64,
+) -> PyResult<PyObject> {
+    let cores = connectivity::s_core_number(&graph.graph, |edge| {
+        is_valid_weight(weight_callable(
+            py,
+            &weight_fn,
+            edge.weight(),
+            default_weight,
+        )?)
+    })?;
+    let out_dict = PyDict::new_bound(py);
+    for (k, v) in cores {
+        out_dict.set_item(k.index(), v)?;
+    }
+    Ok(out_dict.into())
+}
+
+/// Return the onion decomposition of the graph.
+///
+/// The onion decomposition refines the k-core decomposition, see
+/// :func:`~rustworkx.graph_core_number`: the nodes are removed in layers,
+/// where every layer is made up of all the nodes whose degree in the
+/// remaining graph is at most the current core number. Isolated nodes make
+/// up the first layer. Nodes in higher layers of the same core are more
+/// deeply embedded in it, which makes the layers useful to show the
+/// core-periphery structure of a graph.
+///
+/// .. note::
+///
+///     Self loops and parallel edges are ignored when computing the degree
+///     of a node.
+///
+/// :param PyGraph graph: The graph to get the onion decomposition of
+///
+/// :returns: A tuple of a dictionary keyed by node index to the core number
+///     and a dictionary keyed by node index to the onion layer, starting at 1
+/// :rtype: tuple
+#[pyfunction]
+#[pyo3(text_signature = "(graph, /)")]
+pub fn graph_onion_decomposition(
+    py: Python,
+    graph: &graph::PyGraph,
+) -> PyResult<(PyObject, PyObject)> {
+    let (cores, layers) = connectivity::onion_decomposition(&graph.graph);
+    let cores_dict = PyDict::new_bound(py);
+    for (k, v) in cores {
+        cores_dict.set_item(k.index(), v)?;
+    }
+    let layers_dict = PyDict::new_bound(py);
+    for (k, v) in layers {
+        layers_dict.set_item(k.index(), v)?;
+    }
+    Ok((cores_dict.into(), layers_dict.into()))
+}
+
+/// Return the onion decomposition of the directed graph.
+///
+/// The onion decomposition refines the k-core decomposition, see
+/// :func:`~rustworkx.digraph_core_number`: the nodes are removed in layers,
+/// where every layer is made up of all the nodes whose degree in the
+/// remaining graph is at most the current core number. Isolated nodes make
+/// up the first layer. Nodes in higher layers of the same core are more
+/// deeply embedded in it, which makes the layers useful to show the
+/// core-periphery structure of a graph.
+/// The direction of the edges is ignored, so the degree of a node is its
+/// number of distinct neighbors.
+///
+/// .. note::
+///
+///     Self loops and parallel edges are ignored when computing the degree
+///     of a node.
+///
+/// :param PyDiGraph graph: The graph to get the onion decomposition of
+///
+/// :returns: A tuple of a dictionary keyed by node index to the core number
+///     and a dictionary keyed by node index to the onion layer, starting at 1
+/// :rtype: tuple
+#[pyfunction]
+#[pyo3(text_signature = "(graph, /)")]
+pub fn digraph_onion_decomposition(
+    py: Python,
+    graph: &digraph::PyDiGraph,
+) -> PyResult<(PyObject, PyObject)> {
+    let (cores, layers) = connectivity::onion_decomposition(&graph.graph);
+    let cores_dict = PyDict::new_bound(py);
+    for (k, v) in cores {
+        cores_dict.set_item(k.index(), v)?;
+    }
+    let layers_dict = PyDict::new_bound(py);
+    for (k, v) in layers {
+        layers_dict.set_item(k.index(), v)?;
+    }
+    Ok((cores_dict.into(), layers_dict.into()))
+}
+
+/// Return the truss number for each edge in the graph.
+///
+/// A k-truss is a maximal subgraph in which every edge is part of at least
+/// ``k - 2`` triangles of the subgraph. The truss number of an edge is the
+/// largest ``k`` for which the edge is in the k-truss, so edges that aren't
+/// part of any triangle have a truss number of 2. The truss numbers are
+/// computed by peeling the edges in parallel batches.
+///
+/// .. note::
+///
+///     Self loops are ignored and get a truss number of 2, and parallel edges
+///     are treated as a single edge that they all share the truss number of.
+///
+/// :param PyGraph graph: The graph to get the truss numbers of
+///
+/// :returns: A dictionary keyed by edge index to the truss number
+/// :rtype: dict
+#[pyfunction]
+#[pyo3(text_signature = "(graph, /)")]
+pub fn truss_number(py: Python, graph: &graph::PyGraph) -> PyResult<PyObject> {
+    let truss = connectivity::truss_number(&graph.graph);
+    let out_dict = PyDict::new_bound(py);
+    for (k, v) in truss {
+        out_dict.set_item(k.index(), v)?;
+    }
+    Ok(out_dict.into())
+}
+
+/// Return the k-truss of the graph.
+///
+/// A k-truss is a maximal subgraph in which every edge is part of at least
+/// ``k - 2`` triangles of the subgraph. The returned graph contains the edges
+/// with a truss number of at least ``k``, see :func:`~rustworkx.truss_number`,
+/// and the nodes they are incident to. Node and edge indices are preserved.
+///
+/// :param PyGraph graph: The graph to get the k-truss of
+/// :param int k: The order of the truss
+///
+/// :returns: The k-truss of the graph
+/// :rtype: PyGraph
+#[pyfunction]
+#[pyo3(text_signature = "(graph, k, /)")]
+pub fn k_truss(graph: &graph::PyGraph, k: usize) -> graph::PyGraph {
+    let edges: HashSet<EdgeIndex> = connectivity::k_truss(&graph.graph, k).into_iter().collect();
+    let nodes: HashSet<NodeIndex> = edges
+        .iter()
+        .flat_map(|edge| {
+            let (source, target) = graph.graph.edge_endpoints(*edge).unwrap();
+            [source, target]
+        })
+        .collect();
+    let mut out_graph = graph.clone();
+    for node in graph.graph.node_indices() {
+        if !nodes.contains(&node) {
+            out_graph.graph.remove_node(node);
+            out_graph.node_removed = true;
+        }
+    }
+    for edge in graph.graph.edge_indices() {
+        if !edges.contains(&edge) {
+            out_graph.graph.remove_edge(edge);
+        }
+    }
+    out_graph
+}
+
 /// Compute a weighted minimum cut using the Stoer-Wagner algorithm.
 ///
 /// Determine the minimum cut of a graph using the Stoer-Wagner algorithm [stoer_simple_1997]_.
@@ -1151,3 +1416,286 @@ pub fn graph_is_bipartite(graph: graph::PyGraph) -> bool {
 pub fn digraph_is_bipartite(graph: digraph::PyDiGraph) -> bool {
     two_color(&graph.graph).is_some()
 }
+
+fn bipartite_sets_inner<Ty: EdgeType>(
+    py: Python,
+    graph: &StablePyGraph<Ty>,
+) -> PyResult<(HashSet<usize>, HashSet<usize>)> {
+    match bipartite_sets(graph) {
+        Ok((first, second)) => Ok((
+            first.into_iter().map(|node| node.index()).collect(),
+            second.into_iter().map(|node| node.index()).collect(),
+        )),
+        Err(err) => {
+            let odd_cycle = NodeIndices {
+                nodes: err.odd_cycle.iter().map(|node| node.index()).collect(),
+            };
+            let exc = GraphNotBipartite::new_err(format!(
+                "Graph is not bipartite, it has the odd cycle {:?}",
+                odd_cycle.nodes
+            ));
+            exc.value_bound(py)
+                .setattr("odd_cycle", odd_cycle.into_py(py))?;
+            Err(exc)
+        }
+    }
+}
+
+/// Split the nodes of a bipartite :class:`~.PyGraph` into its two sides
+///
+/// A graph is bipartite if its nodes can be split into two sets such that
+/// every edge joins a node of one set to a node of the other. If the graph
+/// isn't connected the split isn't unique, and the node with the lowest
+/// index of every connected component is put in the first set.
+///
+/// If the graph isn't bipartite the :class:`~.GraphNotBipartite` exception
+/// that is raised has an ``odd_cycle`` attribute with the node indices of
+/// an odd cycle of the graph, which proves it. The cycle closes with an edge
+/// from its last node back to its first, and is a single node for a
+/// self-loop.
+///
+/// :param PyGraph graph: The graph to split
+///
+/// :returns: A tuple of the two sets of node indices
+/// :rtype: tuple[set[int], set[int]]
+/// :raises GraphNotBipartite: If the graph isn't bipartite
+///
+/// .. jupyter-execute::
+///
+///   import rustworkx as rx
+///
+///   graph = rx.generators.cycle_graph(6)
+///   print(rx.graph_bipartite_sets(graph))
+///   graph.add_edge(0, 2, None)
+///   try:
+///       rx.graph_bipartite_sets(graph)
+///   except rx.GraphNotBipartite as err:
+///       print(err.odd_cycle)
+#[pyfunction]
+#[pyo3(text_signature = "(graph, /)")]
+pub fn graph_bipartite_sets(
+    py: Python,
+    graph: &graph::PyGraph,
+) -> PyResult<(HashSet<usize>, HashSet<usize>)> {
+    bipartite_sets_inner(py, &graph.graph)
+}
+
+/// Split the nodes of a bipartite :class:`~.PyDiGraph` into its two sides
+///
+/// A graph is bipartite if its nodes can be split into two sets such that
+/// every edge joins a node of one set to a node of the other. The direction
+/// of edges is ignored. If the graph isn't weakly connected the split isn't
+/// unique, and the node with the lowest index of every weakly connected
+/// component is put in the first set.
+///
+/// If the graph isn't bipartite the :class:`~.GraphNotBipartite` exception
+/// that is raised has an ``odd_cycle`` attribute with the node indices of
+/// an odd cycle of the graph, which proves it. The cycle closes with an edge
+/// from its last node back to its first, ignoring the direction of edges,
+/// and is a single node for a self-loop.
+///
+/// :param PyDiGraph graph: The graph to split
+///
+/// :returns: A tuple of the two sets of node indices
+/// :rtype: tuple[set[int], set[int]]
+/// :raises GraphNotBipartite: If the graph isn't bipartite
+///
+/// .. jupyter-execute::
+///
+///   import rustworkx as rx
+///
+///   graph = rx.generators.directed_path_graph(5)
+///   print(rx.digraph_bipartite_sets(graph))
+#[pyfunction]
+#[pyo3(text_signature = "(graph, /)")]
+pub fn digraph_bipartite_sets(
+    py: Python,
+    graph: &digraph::PyDiGraph,
+) -> PyResult<(HashSet<usize>, HashSet<usize>)> {
+    bipartite_sets_inner(py, &graph.graph)
+}
+
+fn disjoint_paths_inner<Ty: EdgeType>(
+    graph: &StablePyGraph<Ty>,
+    source: usize,
+    target: usize,
+    k: Option<usize>,
+    node_disjoint: bool,
+) -> PyResult<Vec<Vec<usize>>> {
+    for node in [source, target] {
+        if !graph.contains_node(NodeIndex::new(node)) {
+            return Err(InvalidNode::new_err(format!(
+                "Node index {} is not in the graph",
+                node
+            )));
+        }
+    }
+    if source == target {
+        return Err(PyValueError::new_err(
+            "source and target must be different nodes",
+        ));
+    }
+    let (source, target) = (NodeIndex::new(source), NodeIndex::new(target));
+    let paths = if node_disjoint {
+        connectivity::node_disjoint_paths(graph, source, target, k)
+    } else {
+        connectivity::edge_disjoint_paths(graph, source, target, k)
+    };
+    Ok(paths
+        .into_iter()
+        .map(|path| path.into_iter().map(|node| node.index()).collect())
+        .collect())
+}
+
+/// Find a largest set of edge-disjoint paths between two nodes of a
+/// :class:`~.PyGraph`
+///
+/// The paths don't share any edge, but they can share nodes. The number of
+/// paths found is the size of a minimum edge cut between the two nodes
+/// (unless it's limited by ``k``), and among the sets of edge-disjoint paths
+/// of that size the returned one has the smallest total number of edges.
+///
+/// :param PyGraph graph: The graph to find the paths in
+/// :param int source: The node index the paths start at
+/// :param int target: The node index the paths end at
+/// :param int k: The largest number of paths to find. If it's not specified
+///     as many paths as possible are found.
+///
+/// :returns: A list of paths, each a list of node indices from ``source``
+///     to ``target``
+/// :rtype: list[list[int]]
+/// :raises InvalidNode: If ``source`` or ``target`` isn't in the graph
+/// :raises ValueError: If ``source`` and ``target`` are the same node
+///
+/// .. jupyter-execute::
+///
+///   import rustworkx as rx
+///
+///   graph = rx.generators.grid_graph(3, 3)
+///   print(rx.graph_edge_disjoint_paths(graph, 0, 8))
+#[pyfunction]
+#[pyo3(text_signature = "(graph, source, target, /, k=None)")]
+pub fn graph_edge_disjoint_paths(
+    graph: &graph::PyGraph,
+    source: usize,
+    target: usize,
+    k: Option<usize>,
+) -> PyResult<Vec<Vec<usize>>> {
+    disjoint_paths_inner(&graph.graph, source, target, k, false)
+}
+
+/// Find a largest set of edge-disjoint paths between two nodes of a
+/// :class:`~.PyDiGraph`
+///
+/// The paths follow the direction of edges and don't share any edge, but
+/// they can share nodes. The number of paths found is the size of a minimum
+/// edge cut from ``source`` to ``target`` (unless it's limited by ``k``), and
+/// among the sets of edge-disjoint paths of that size the returned one has
+/// the smallest total number of edges.
+///
+/// :param PyDiGraph graph: The graph to find the paths in
+/// :param int source: The node index the paths start at
+/// :param int target: The node index the paths end at
+/// :param int k: The largest number of paths to find. If it's not specified
+///     as many paths as possible are found.
+///
+/// :returns: A list of paths, each a list of node indices from ``source``
+///     to ``target``
+/// :rtype: list[list[int]]
+/// :raises InvalidNode: If ``source`` or ``target`` isn't in the graph
+/// :raises ValueError: If ``source`` and ``target`` are the same node
+///
+/// .. jupyter-execute::
+///
+///   import rustworkx as rx
+///
+///   graph = rx.generators.directed_grid_graph(3, 3)
+///   print(rx.digraph_edge_disjoint_paths(graph, 0, 8))
+#[pyfunction]
+#[pyo3(text_signature = "(graph, source, target, /, k=None)")]
+pub fn digraph_edge_disjoint_paths(
+    graph: &digraph::PyDiGraph,
+    source: usize,
+    target: usize,
+    k: Option<usize>,
+) -> PyResult<Vec<Vec<usize>>> {
+    disjoint_paths_inner(&graph.graph, source, target, k, false)
+}
+
+/// Find a largest set of node-disjoint paths between two nodes of a
+/// :class:`~.PyGraph`
+///
+/// The paths don't share any node other than ``source`` and ``target``. The
+/// number of paths found is the size of a minimum node cut between the two
+/// nodes, plus one if they're adjacent (unless it's limited by ``k``), and
+/// among the sets of node-disjoint paths of that size the returned one has
+/// the smallest total number of edges. If there are parallel edges between
+/// ``source`` and ``target`` only one path uses them.
+///
+/// :param PyGraph graph: The graph to find the paths in
+/// :param int source: The node index the paths start at
+/// :param int target: The node index the paths end at
+/// :param int k: The largest number of paths to find. If it's not specified
+///     as many paths as possible are found.
+///
+/// :returns: A list of paths, each a list of node indices from ``source``
+///     to ``target``
+/// :rtype: list[list[int]]
+/// :raises InvalidNode: If ``source`` or ``target`` isn't in the graph
+/// :raises ValueError: If ``source`` and ``target`` are the same node
+///
+/// .. jupyter-execute::
+///
+///   import rustworkx as rx
+///
+///   graph = rx.generators.grid_graph(3, 3)
+///   print(rx.graph_node_disjoint_paths(graph, 0, 8))
+#[pyfunction]
+#[pyo3(text_signature = "(graph, source, target, /, k=None)")]
+pub fn graph_node_disjoint_paths(
+    graph: &graph::PyGraph,
+    source: usize,
+    target: usize,
+    k: Option<usize>,
+) -> PyResult<Vec<Vec<usize>>> {
+    disjoint_paths_inner(&graph.graph, source, target, k, true)
+}
+
+/// Find a largest set of node-disjoint paths between two nodes of a
+/// :class:`~.PyDiGraph`
+///
+/// The paths follow the direction of edges and don't share any node other
+/// than ``source`` and ``target``. The number of paths found is the size of
+/// a minimum node cut from ``source`` to ``target``, plus one if there's an
+/// edge from ``source`` to ``target`` (unless it's limited by ``k``), and
+/// among the sets of node-disjoint paths of that size the returned one has
+/// the smallest total number of edges.
+///
+/// :param PyDiGraph graph: The graph to find the paths in
+/// :param int source: The node index the paths start at
+/// :param int target: The node index the paths end at
+/// :param int k: The largest number of paths to find. If it's not specified
+///     as many paths as possible are found.
+///
+/// :returns: A list of paths, each a list of node indices from ``source``
+///     to ``target``
+/// :rtype: list[list[int]]
+/// :raises InvalidNode: If ``source`` or ``target`` isn't in the graph
+/// :raises ValueError: If ``source`` and ``target`` are the same node
+///
+/// .. jupyter-execute::
+///
+///   import rustworkx as rx
+///
+///   graph = rx.generators.directed_grid_graph(3, 3)
+///   print(rx.digraph_node_disjoint_paths(graph, 0, 8))
+#[pyfunction]
+#[pyo3(text_signature = "(graph, source, target, /, k=None)")]
+pub fn digraph_node_disjoint_paths(
+    graph: &digraph::PyDiGraph,
+    source: usize,
+    target: usize,
+    k: Option<usize>,
+) -> PyResult<Vec<Vec<usize>>> {
+    disjoint_paths_inner(&graph.graph, source, target, k, true)
+}
@@ -19,13 +19,23 @@ use rustworkx_core::dictmap::InitWithHasher;
 use super::iterators::NodeIndices;
 use crate::{digraph, DAGHasCycle, InvalidNode, RxPyResult, StablePyGraph};
 
+use rustworkx_core::dag_algo::all_topological_sorts as core_all_topological_sorts;
 use rustworkx_core::dag_algo::collect_bicolor_runs as core_collect_bicolor_runs;
 use rustworkx_core::dag_algo::collect_runs as core_collect_runs;
+use rustworkx_core::dag_algo::count_topological_sorts as core_count_topological_sorts;
+use rustworkx_core::dag_algo::critical_path as core_critical_path;
 use rustworkx_core::dag_algo::lexicographical_topological_sort as core_lexico_topo_sort;
 use rustworkx_core::dag_algo::longest_path as core_longest_path;
+use rustworkx_core::dag_algo::maximum_antichain as core_maximum_antichain;
+use rustworkx_core::dag_algo::minimum_chain_decomposition as core_minimum_chain_decomposition;
+use rustworkx_core::dag_algo::topological_generations as core_topological_generations;
+use rustworkx_core::dag_algo::transitive_closure_dag as core_transitive_closure_dag;
+use rustworkx_core::dag_algo::AllTopologicalSorts as CoreAllTopologicalSorts;
+use rustworkx_core::dag_algo::ReachabilityIndex as CoreReachabilityIndex;
+use rustworkx_core::dag_algo::TopologicalSortCountError;
 use rustworkx_core::traversal::dfs_edges;
 
-use pyo3::exceptions::PyValueError;
+use pyo3::exceptions::{PyOverflowError, PyValueError};
 use pyo3::prelude::*;
 use pyo3::types::PyList;
 use pyo3::Python;
@@ -37,6 +47,8 @@ use petgraph::visit::NodeIndexable;
 
 use num_traits::{Num, Zero};
 
+type TaskSchedules = DictMap<usize, DictMap<&'static str, f64>>;
+
 /// Calculate the longest path in a directed acyclic graph (DAG).
 ///
 /// This function interfaces with the Python `PyDiGraph` object to compute the longest path
@@ -254,6 +266,115 @@ pub fn dag_weighted_longest_path_length(
     Ok(path_weight)
 }
 
+/// Run the critical path method (CPM) on a DAG
+///
+/// Every node of the DAG is treated as a task with a duration given by
+/// ``node_weight_fn``, and every edge :math:`(u, v)` as a dependency requiring
+/// that task :math:`v` starts at least the lag given by ``edge_weight_fn``
+/// after task :math:`u` finishes. The tasks without predecessors start at
+/// time ``0``. For every task this computes the earliest start and finish time,
+/// the latest start and finish time that doesn't delay the completion of the
+/// whole project, and the slack, which is how much the task can be delayed
+/// without delaying the project. The tasks with zero slack are critical.
+///
+/// With a ``node_weight_fn`` returning ``0.0`` for every node this computes the
+/// same path as :func:`~rustworkx.dag_weighted_longest_path`, together with
+/// the slack of every node.
+///
+/// :param PyDiGraph graph: The graph to run the analysis on. The input object
+///     must be a DAG without a cycle.
+/// :param node_weight_fn: An optional python callable that will be passed the
+///     weight of each node and is expected to return the ``float`` duration of
+///     that node. If not specified every node has a duration of ``1.0``.
+/// :param edge_weight_fn: An optional python callable that will be passed the
+///     3 positional arguments, the source node, the target node, and the edge
+///     weight for each edge and is expected to return the ``float`` lag of that
+///     edge. If not specified every edge has a lag of ``0.0``.
+///
+/// :returns: A tuple of a critical path as a list of node indices, the
+///     duration of the whole project, and a dictionary mapping every node
+///     index to a dictionary with the keys ``"earliest_start"``,
+///     ``"earliest_finish"``, ``"latest_start"``, ``"latest_finish"``, and
+///     ``"slack"``.
+/// :rtype: tuple[NodeIndices, float, dict[int, dict[str, float]]]
+///
+/// :raises DAGHasCycle: If the input PyDiGraph has a cycle
+/// :raises ValueError: If a node or edge weight is NaN
+///
+/// .. jupyter-execute::
+///
+///   import rustworkx as rx
+///
+///   graph = rx.PyDiGraph()
+///   a, b, c, d = graph.add_nodes_from([3.0, 2.0, 4.0, 1.0])
+///   graph.add_edges_from_no_data([(a, b), (a, c), (b, d), (c, d)])
+///   path, duration, schedule = rx.dag_critical_path(graph, node_weight_fn=float)
+///   print(path, duration)
+///   print(schedule[b])
+#[pyfunction]
+#[pyo3(
+    signature=(graph, node_weight_fn=None, edge_weight_fn=None),
+    text_signature = "(graph, /, node_weight_fn=None, edge_weight_fn=None)"
+)]
+pub fn dag_critical_path(
+    py: Python,
+    graph: &digraph::PyDiGraph,
+    node_weight_fn: Option<PyObject>,
+    edge_weight_fn: Option<PyObject>,
+) -> PyResult<(NodeIndices, f64, TaskSchedules)> {
+    let check_weight = |weight: f64| -> PyResult<f64> {
+        if weight.is_nan() {
+            return Err(PyValueError::new_err("NaN is not a valid weight"));
+        }
+        Ok(weight)
+    };
+    let node_weight_callable = |node: NodeIndex| -> PyResult<f64> {
+        match &node_weight_fn {
+            Some(node_weight_fn) => {
+                let res = node_weight_fn.call1(py, (&graph.graph[node],))?;
+                check_weight(res.extract(py)?)
+            }
+            None => Ok(1.0),
+        }
+    };
+    let edge_weight_callable = |edge: EdgeReference<PyObject>| -> PyResult<f64> {
+        match &edge_weight_fn {
+            Some(edge_weight_fn) => {
+                let res = edge_weight_fn.call1(
+                    py,
+                    (edge.source().index(), edge.target().index(), edge.weight()),
+                )?;
+                check_weight(res.extract(py)?)
+            }
+            None => Ok(0.0),
+        }
+    };
+    let cpm = match core_critical_path(&graph.graph, node_weight_callable, edge_weight_callable)? {
+        Some(cpm) => cpm,
+        None => return Err(DAGHasCycle::new_err("The graph contains a cycle")),
+    };
+    let schedule = cpm
+        .schedule
+        .into_iter()
+        .map(|(node, task)| {
+            let mut out: DictMap<&'static str, f64> = DictMap::with_capacity(5);
+            out.insert("earliest_start", task.earliest_start);
+            out.insert("earliest_finish", task.earliest_finish);
+            out.insert("latest_start", task.latest_start);
+            out.insert("latest_finish", task.latest_finish);
+            out.insert("slack", task.slack);
+            (node.index(), out)
+        })
+        .collect();
+    Ok((
+        NodeIndices {
+            nodes: cpm.path.into_iter().map(|node| node.index()).collect(),
+        },
+        cpm.duration,
+        schedule,
+    ))
+}
+
 /// Check that the PyDiGraph or PyDAG doesn't have a cycle
 ///
 /// :param PyDiGraph graph: The graph to check for cycles
@@ -428,43 +549,14 @@ pub fn lexicographical_topological_sort(
 #[pyfunction]
 #[pyo3(text_signature = "(dag, /)")]
 pub fn topological_generations(dag: &digraph::PyDiGraph) -> PyResult<Vec<NodeIndices>> {
-    let mut in_degree_map: HashMap<NodeIndex, usize> = HashMap::new();
-    let mut zero_in_degree: Vec<NodeIndex> = Vec::new();
-    for node in dag.graph.node_indices() {
-        let in_degree = dag.in_degree(node.index());
-        if in_degree == 0 {
-            zero_in_degree.push(node);
-        } else {
-            in_degree_map.insert(node, in_degree);
-        }
-    }
-
-    let mut generations: Vec<NodeIndices> = Vec::new();
-    let dir = petgraph::Direction::Outgoing;
-    while !zero_in_degree.is_empty() {
-        let this_generation = zero_in_degree.clone();
-        zero_in_degree.clear();
-        for node in this_generation.iter() {
-            let neighbors = dag.graph.neighbors_directed(*node, dir);
-            for child in neighbors {
-                let child_degree = in_degree_map.get_mut(&child).unwrap();
-                *child_degree -= 1;
-                if *child_degree == 0 {
-                    zero_in_degree.push(child);
-                    in_degree_map.remove(&child);
-                }
-            }
-        }
-        generations.push(NodeIndices {
-            nodes: this_generation.iter().map(|node| node.index()).collect(),
-        });
-    }
-
-    // Check for cycle
-    if !in_degree_map.is_empty() {
-        return Err(DAGHasCycle::new_err("Topological sort encountered a cycle"));
+    match core_topological_generations(&dag.graph) {
+        Some(generations) => Ok(generations
+            .map(|generation| NodeIndices {
+                nodes: generation.into_iter().map(|node| node.index()).collect(),
+            })
+            .collect()),
+        None => Err(DAGHasCycle::new_err("Topological sort encountered a cycle")),
     }
-    Ok(generations)
 }
 
 /// Return the topological sort of node indices from the provided graph
@@ -487,6 +579,99 @@ pub fn topological_sort(graph: &digraph::PyDiGraph) -> PyResult<NodeIndices> {
     })
 }
 
+/// An iterator over all the topological sorts of a DAG, created by
+/// :func:`~rustworkx.all_topological_sorts`
+#[pyclass(module = "rustworkx")]
+pub struct AllTopologicalSortsIter {
+    sorts: CoreAllTopologicalSorts<NodeIndex>,
+}
+
+#[pymethods]
+impl AllTopologicalSortsIter {
+    fn __iter__(slf: PyRef<Self>) -> Py<AllTopologicalSortsIter> {
+        slf.into()
+    }
+
+    fn __next__(mut slf: PyRefMut<Self>) -> Option<NodeIndices> {
+        slf.sorts.next().map(|sort| NodeIndices {
+            nodes: sort.into_iter().map(|node| node.index()).collect(),
+        })
+    }
+}
+
+/// Return an iterator over all the topological sorts of a DAG
+///
+/// The sorts are generated lazily with a backtracking algorithm, each sort
+/// takes :math:`O(|V|^2 + |E|)` time to generate. The number of topological
+/// sorts can grow factorially with the number of nodes, use
+/// :func:`~rustworkx.count_topological_sorts` to count them without
+/// enumerating them.
+///
+/// The iterator is created from a copy of the graph, changes made to
+/// ``graph`` after calling this function are not reflected in the sorts.
+///
+/// :param PyDiGraph graph: The DAG to get the topological sorts of
+///
+/// :returns: An iterator of the topological sorts of ``graph``, each sort is
+///     a list of node indices
+/// :rtype: Iterator[NodeIndices]
+///
+/// :raises DAGHasCycle: if ``graph`` is not a DAG
+///
+/// .. jupyter-execute::
+///
+///   import rustworkx as rx
+///
+///   graph = rx.PyDiGraph()
+///   graph.extend_from_edge_list([(0, 1), (0, 2), (1, 3), (2, 3)])
+///   for sort in rx.all_topological_sorts(graph):
+///       print(sort)
+#[pyfunction]
+#[pyo3(text_signature = "(graph, /)")]
+pub fn all_topological_sorts(graph: &digraph::PyDiGraph) -> PyResult<AllTopologicalSortsIter> {
+    match core_all_topological_sorts(&graph.graph) {
+        Some(sorts) => Ok(AllTopologicalSortsIter { sorts }),
+        None => Err(DAGHasCycle::new_err("Topological sort encountered a cycle")),
+    }
+}
+
+/// Count the topological sorts of a DAG
+///
+/// The sorts are counted with dynamic programming over the sets of nodes
+/// that can start a topological sort, without enumerating the sorts. The
+/// number of such sets can still be exponential in the width of the DAG (the
+/// size of its largest antichain), so this is only practical for small or
+/// narrow graphs.
+///
+/// :param PyDiGraph graph: The DAG to count the topological sorts of
+///
+/// :returns: The number of topological sorts of ``graph``
+/// :rtype: int
+///
+/// :raises DAGHasCycle: if ``graph`` is not a DAG
+/// :raises OverflowError: if the number of sorts is :math:`2^{128}` or more
+///
+/// .. jupyter-execute::
+///
+///   import rustworkx as rx
+///
+///   graph = rx.PyDiGraph()
+///   graph.extend_from_edge_list([(0, 1), (0, 2), (1, 3), (2, 3)])
+///   print(rx.count_topological_sorts(graph))
+#[pyfunction]
+#[pyo3(text_signature = "(graph, /)")]
+pub fn count_topological_sorts(graph: &digraph::PyDiGraph) -> PyResult<u128> {
+    match core_count_topological_sorts(&graph.graph) {
+        Ok(count) => Ok(count),
+        Err(TopologicalSortCountError::Cycle) => {
+            Err(DAGHasCycle::new_err("Topological sort encountered a cycle"))
+        }
+        Err(TopologicalSortCountError::Overflow) => Err(PyOverflowError::new_err(
+            "The number of topological sorts is too large to count",
+        )),
+    }
+}
+
 /// Collect runs that match a filter function
 ///
 /// A run is a path of nodes where there is only a single successor and all
@@ -682,3 +867,220 @@ pub fn transitive_reduction(
             .collect::<DictMap<usize, usize>>(),
     ));
 }
+
+/// Return the transitive closure of a directed acyclic graph
+///
+/// The transitive closure of :math:`G = (V,E)` is a graph :math:`G^+ = (V,E^+)`
+/// such that for all distinct :math:`v` and :math:`w` in :math:`V` there is an
+/// edge :math:`(v, w)` in :math:`E^+` if and only if there is a path from
+/// :math:`v` to :math:`w` in :math:`G`.
+///
+/// The closure is computed using a :class:`~rustworkx.ReachabilityIndex`. If
+/// only reachability queries between pairs of nodes are needed it is more
+/// efficient to use a :class:`~rustworkx.ReachabilityIndex` directly, as that
+/// avoids materializing all the edges of the closure.
+///
+/// :param PyDiGraph graph: A directed acyclic graph
+///
+/// :returns: A copy of ``graph`` which also contains an edge for every pair
+///     of nodes connected by a path that isn't already an edge. The added
+///     edges have a weight of ``None``.
+/// :rtype: PyDiGraph
+///
+/// :raises DAGHasCycle: if ``graph`` is not a DAG
+///
+/// .. jupyter-execute::
+///
+///   import rustworkx as rx
+///
+///   graph = rx.generators.directed_path_graph(4)
+///   closure = rx.transitive_closure_dag(graph)
+///   print(closure.edge_list())
+#[pyfunction]
+#[pyo3(text_signature = "(graph, /)")]
+pub fn transitive_closure_dag(
+    py: Python,
+    graph: &digraph::PyDiGraph,
+) -> PyResult<digraph::PyDiGraph> {
+    let edges = match core_transitive_closure_dag(&graph.graph) {
+        Some(edges) => edges,
+        None => return Err(DAGHasCycle::new_err("The graph contains a cycle")),
+    };
+    let mut closure = graph.clone();
+    for (source, target) in edges {
+        if closure.graph.find_edge(source, target).is_none() {
+            closure.graph.add_edge(source, target, py.None());
+        }
+    }
+    Ok(closure)
+}
+
+/// Find a minimum chain decomposition of a directed acyclic graph
+///
+/// A DAG defines a partial order on its nodes where :math:`u < v` if there is
+/// a path from :math:`u` to :math:`v`. A chain is a set of nodes which are
+/// pairwise comparable and a chain decomposition partitions the nodes into
+/// chains. By Dilworth's theorem the minimum number of chains needed is equal
+/// to the size of a maximum antichain, see :func:`~rustworkx.dag_maximum_antichain`.
+///
+/// The decomposition is found by reducing the problem to a maximum bipartite
+/// matching on the transitive closure of the graph, which is solved with the
+/// Hopcroft-Karp algorithm.
+///
+/// :param PyDiGraph graph: A directed acyclic graph
+///
+/// :returns: A list of chains, each chain is the node indices of the chain in
+///     topological order. Consecutive nodes of a chain are connected by a path
+///     in ``graph``, but not necessarily by an edge.
+/// :rtype: list[NodeIndices]
+///
+/// :raises DAGHasCycle: if ``graph`` is not a DAG
+///
+/// .. jupyter-execute::
+///
+///   import rustworkx as rx
+///
+///   graph = rx.PyDiGraph()
+///   graph.extend_from_edge_list([(0, 1), (0, 2), (1, 3), (2, 3), (3, 4)])
+///   print(rx.dag_minimum_chain_decomposition(graph))
+#[pyfunction]
+#[pyo3(text_signature = "(graph, /)")]
+pub fn dag_minimum_chain_decomposition(graph: &digraph::PyDiGraph) -> PyResult<Vec<NodeIndices>> {
+    match core_minimum_chain_decomposition(&graph.graph) {
+        Some(chains) => Ok(chains
+            .into_iter()
+            .map(|chain| NodeIndices {
+                nodes: chain.into_iter().map(|node| node.index()).collect(),
+            })
+            .collect()),
+        None => Err(DAGHasCycle::new_err("The graph contains a cycle")),
+    }
+}
+
+/// Find a maximum antichain of a directed acyclic graph
+///
+/// A DAG defines a partial order on its nodes where :math:`u < v` if there is
+/// a path from :math:`u` to :math:`v`. An antichain is a set of nodes where
+/// no node is reachable from any other node in the set. The size of a maximum
+/// antichain is the width of the partial order, which is the maximum number
+/// of tasks in a dependency graph that can ever run concurrently. By
+/// Dilworth's theorem it is equal to the minimum number of chains needed to
+/// cover the nodes, see :func:`~rustworkx.dag_minimum_chain_decomposition`.
+///
+/// :param PyDiGraph graph: A directed acyclic graph
+///
+/// :returns: The node indices of a maximum antichain
+/// :rtype: NodeIndices
+///
+/// :raises DAGHasCycle: if ``graph`` is not a DAG
+///
+/// .. jupyter-execute::
+///
+///   import rustworkx as rx
+///
+///   graph = rx.PyDiGraph()
+///   graph.extend_from_edge_list([(0, 1), (0, 2), (0, 3), (1, 4), (2, 4)])
+///   print(rx.dag_maximum_antichain(graph))
+#[pyfunction]
+#[pyo3(text_signature = "(graph, /)")]
+pub fn dag_maximum_antichain(graph: &digraph::PyDiGraph) -> PyResult<NodeIndices> {
+    match core_maximum_antichain(&graph.graph) {
+        Some(antichain) => Ok(NodeIndices {
+            nodes: antichain.into_iter().map(|node| node.index()).collect(),
+        }),
+        None => Err(DAGHasCycle::new_err("The graph contains a cycle")),
+    }
+}
+
+/// An index for fast reachability queries on a directed acyclic graph.
+///
+/// The index decomposes the DAG into disjoint chains and stores, for every
+/// node, the earliest position it can reach on every chain. A reachability
+/// query then only needs to compare a single stored position, which takes
+/// constant time, without materializing the transitive closure of the graph.
+/// Building the index takes :math:`O(|E| k)` time and :math:`O(|V| k)` memory,
+/// where :math:`k` is the number of chains.
+///
+/// The index is a snapshot of the graph at the time it is created, it
+/// will not reflect any later modifications of the graph.
+///
+/// .. jupyter-execute::
+///
+///   import rustworkx as rx
+///
+///   graph = rx.PyDiGraph()
+///   graph.extend_from_edge_list([(0, 1), (1, 2), (0, 3), (3, 2), (4, 2)])
+///   index = rx.ReachabilityIndex(graph)
+///   print(index.can_reach(0, 2), index.can_reach(1, 3))
+///
+/// :param PyDiGraph graph: The directed acyclic graph to index
+///
+/// :raises DAGHasCycle: if ``graph`` is not a DAG
+#[pyclass(module = "rustworkx")]
+pub struct ReachabilityIndex {
+    index: CoreReachabilityIndex,
+}
+
+impl ReachabilityIndex {
+    fn check_node(&self, node: usize) -> PyResult<()> {
+        if self.index.contains_node(node) {
+            Ok(())
+        } else {
+            Err(InvalidNode::new_err(format!(
+                "Node index {} is not in the indexed graph",
+                node
+            )))
+        }
+    }
+}
+
+#[pymethods]
+impl ReachabilityIndex {
+    #[new]
+    #[pyo3(text_signature = "(graph, /)")]
+    fn new(graph: &digraph::PyDiGraph) -> PyResult<Self> {
+        match CoreReachabilityIndex::new(&graph.graph) {
+            Some(index) => Ok(ReachabilityIndex { index }),
+            None => Err(DAGHasCycle::new_err("The graph contains a cycle")),
+        }
+    }
+
+    /// Check whether there is a path from ``source`` to ``target``
+    ///
+    /// Every node can reach itself.
+    ///
+    /// :param int source: The index of the node the path starts at
+    /// :param int target: The index of the node the path ends at
+    ///
+    /// :returns: ``True`` if ``target`` is reachable from ``source``
+    /// :rtype: bool
+    /// :raises InvalidNode: if either node index is not in the graph
+    #[pyo3(text_signature = "(self, source, target, /)")]
+    fn can_reach(&self, source: usize, target: usize) -> PyResult<bool> {
+        self.check_node(source)?;
+        self.check_node(target)?;
+        Ok(self.index.can_reach(source, target))
+    }
+
+    /// Return the indices of all the nodes reachable from ``node``
+    ///
+    /// :param int node: The index of the node to find the descendants of
+    ///
+    /// :returns: The indices of the nodes reachable from ``node``, not
+    ///     including ``node`` itself
+    /// :rtype: NodeIndices
+    /// :raises InvalidNode: if ``node`` is not in the graph
+    #[pyo3(text_signature = "(self, node, /)")]
+    fn descendants(&self, node: usize) -> PyResult<NodeIndices> {
+        self.check_node(node)?;
+        Ok(NodeIndices {
+            nodes: self.index.descendants(node).collect(),
+        })
+    }
+
+    /// The number of chains the graph was decomposed into
+    #[getter]
+    fn num_chains(&self) -> usize {
+        self.index.num_chains()
+    }
+}
@@ -38,7 +38,7 @@ use pyo3::Python;
 use ndarray::prelude::*;
 use num_traits::Zero;
 use numpy::Complex64;
-use numpy::PyReadonlyArray2;
+use numpy::{IntoPyArray, PyReadonlyArray2};
 
 use petgraph::algo;
 use petgraph::graph::{EdgeIndex, NodeIndex};
@@ -55,8 +55,8 @@ use super::iterators::{
     EdgeIndexMap, EdgeIndices, EdgeList, NodeIndices, NodeMap, WeightedEdgeList,
 };
 use super::{
-    find_node_by_weight, weight_callable, DAGHasCycle, DAGWouldCycle, IsNan, NoEdgeBetweenNodes,
-    NoSuitableNeighbors, NodesRemoved, StablePyGraph,
+    csr_structure, find_node_by_weight, graph_from_edge_iterator, weight_callable, DAGHasCycle,
+    DAGWouldCycle, IsNan, NoEdgeBetweenNodes, NoSuitableNeighbors, NodesRemoved, StablePyGraph,
 };
 
 use super::dag_algo::is_directed_acyclic_graph;
@@ -2045,6 +2045,42 @@ impl PyDiGraph {
         Err(NoSuitableNeighbors::new_err("No suitable neighbor"))
     }
 
+    /// Return the compressed sparse row (CSR) form of the adjacency of the
+    /// graph.
+    ///
+    /// Row ``i`` holds the successors of the node with index ``i``, with an
+    /// entry for every outgoing edge of the node. There's an empty row for
+    /// every index up to the largest node index that isn't a node of the
+    /// graph. The entries of a row are in the order of the indices of their
+    /// edges.
+    ///
+    /// :returns: A tuple of the numpy arrays ``(data, indices, indptr)``,
+    ///     where ``data`` holds the index of the edge of every entry,
+    ///     ``indices`` the neighbor of every entry, and the entries of row
+    ///     ``i`` are at the positions ``indptr[i]`` to ``indptr[i + 1]``.
+    ///     They can be passed to ``scipy.sparse.csr_array`` with a shape of
+    ///     ``(len(indptr) - 1, len(indptr) - 1)``.
+    /// :rtype: tuple
+    ///
+    /// .. jupyter-execute::
+    ///
+    ///   import rustworkx as rx
+    ///
+    ///   graph = rx.generators.directed_path_graph(4)
+    ///   data, indices, indptr = graph.to_csr()
+    ///   print(indptr)
+    ///   print(indices)
+    #[pyo3(text_signature = "(self)")]
+    pub fn to_csr(&self, py: Python) -> PyObject {
+        let csr = csr_structure(&self.graph);
+        (
+            csr.edge_indices().into_pyarray_bound(py),
+            csr.indices().to_vec().into_pyarray_bound(py),
+            csr.indptr().to_vec().into_pyarray_bound(py),
+        )
+            .into_py(py)
+    }
+
     /// Generate a dot file from the graph
     ///
     /// :param node_attr: A callable that will take in a node data object
@@ -2309,6 +2345,83 @@ impl PyDiGraph {
         Ok(())
     }
 
+    /// Create a new :class:`~rustworkx.PyDiGraph` object from an iterable of edges
+    ///
+    /// This method builds a graph from an iterable of edges, such as a
+    /// generator reading an edge list file, without building the whole edge
+    /// list in memory first. The edges are consumed in chunks of
+    /// ``chunk_size`` edges, the nodes are added as the edges refer to them
+    /// with a weight of ``None``, and the graph is preallocated for
+    /// ``num_nodes`` nodes and ``num_edges`` edges if they are specified.
+    /// This is faster and uses less memory than building the graph with
+    /// :meth:`~rustworkx.PyDiGraph.add_edges_from` for large graphs.
+    ///
+    /// If ``multigraph`` is ``False`` parallel edges are merged as they are
+    /// read: the weight of the merged edge is the result of ``combine_fn``,
+    /// or the weight of the last of the parallel edges if it isn't
+    /// specified. In a directed graph ``(a, b)`` and ``(b, a)`` are not parallel edges.
+    ///
+    /// :param edges: An iterable of ``(source, target)`` or
+    ///     ``(source, target, weight)`` tuples, where source and target are
+    ///     integer node indices. The weight of an edge without one is
+    ///     ``None``.
+    /// :param int num_nodes: The number of nodes of the graph, nodes without
+    ///     any edges are added so the graph has ``num_nodes`` nodes. It is
+    ///     also used to preallocate the graph. If it is specified, an edge
+    ///     with an endpoint index of ``num_nodes`` or more raises a
+    ///     ``ValueError``.
+    /// :param int num_edges: The expected number of edges, which is used to
+    ///     preallocate the graph.
+    /// :param bool multigraph: Whether the graph keeps parallel edges, if it
+    ///     is ``False`` they are merged. Default: ``True``.
+    /// :param combine_fn: An optional callable that will be passed the weight
+    ///     of an edge and the weight of an edge parallel to it, and is
+    ///     expected to return the weight of the merged edge. It is only used
+    ///     if ``multigraph`` is ``False``.
+    /// :param int chunk_size: The number of edges consumed from ``edges`` at
+    ///     a time. Default: ``65536``.
+    ///
+    /// :returns: A new graph object with the edges
+    /// :rtype: PyDiGraph
+    ///
+    /// For example:
+    ///
+    /// .. jupyter-execute::
+    ///
+    ///   import rustworkx as rx
+    ///
+    ///   edges = ((i % 10, (i * 7) % 10, 1) for i in range(100))
+    ///   graph = rx.PyDiGraph.from_edge_iterator(
+    ///       edges, num_nodes=10, multigraph=False, combine_fn=lambda a, b: a + b
+    ///   )
+    ///   print(graph.weighted_edge_list())
+    #[staticmethod]
+    #[pyo3(
+        signature=(edges, num_nodes=None, num_edges=None, multigraph=true, combine_fn=None, chunk_size=65536),
+        text_signature = "(edges, /, num_nodes=None, num_edges=None, multigraph=True, combine_fn=None, chunk_size=65536)"
+    )]
+    pub fn from_edge_iterator(
+        py: Python,
+        edges: &Bound<PyAny>,
+        num_nodes: Option<usize>,
+        num_edges: Option<usize>,
+        multigraph: bool,
+        combine_fn: Option<PyObject>,
+        chunk_size: usize,
+    ) -> PyResult<PyDiGraph> {
+        let graph = graph_from_edge_iterator(
+            py, edges, num_nodes, num_edges, multigraph, combine_fn, chunk_size,
+        )?;
+        Ok(PyDiGraph {
+            graph,
+            cycle_state: algo::DfsSpace::default(),
+            check_cycle: false,
+            node_removed: false,
+            multigraph,
+            attrs: py.None(),
+        })
+    }
+
     /// Create a new :class:`~rustworkx.PyDiGraph` object from an adjacency matrix
     /// with matrix elements of type ``float``
     ///
@@ -2691,6 +2804,305 @@ impl PyDiGraph {
         Ok(res.index())
     }
 
+    /// Contract an edge in place, merging its endpoints into a single node.
+    ///
+    /// Unlike :meth:`~PyDiGraph.contract_nodes`, this doesn't create a new
+    /// node. The target of the edge is merged into its source: its edges are
+    /// moved to the source and it is removed from the graph, so the indices
+    /// of the other nodes stay valid and the method can be called repeatedly.
+    ///
+    /// :param int source: The index of the source of the edge, which is kept.
+    /// :param int target: The index of the target of the edge, which is
+    ///     merged into ``source``.
+    /// :param node_fn: An optional python callable that will be passed the
+    ///     data payloads of ``source`` and ``target`` and is expected to
+    ///     return the data payload of the merged node. By default the payload
+    ///     of ``source`` is kept.
+    /// :param weight_combo_fn: An optional python callable that, when
+    ///     specified, is used to merge the parallel edges introduced by the
+    ///     contraction. It will be passed the data payloads of the edge
+    ///     already at ``source`` and of the edge moved from ``target``. If
+    ///     this instance of :class:`~rustworkx.PyDiGraph` is a multigraph,
+    ///     leave this unspecified to preserve parallel edges. If unspecified
+    ///     when not a multigraph, the payload of the edge already at
+    ///     ``source`` is kept.
+    /// :param bool self_loops: If ``True`` the edges between ``source`` and
+    ///     ``target`` (in either direction) become self-loops on the merged
+    ///     node, otherwise they are removed. Defaults to ``False``.
+    /// :param bool check_cycle: If set to ``True``, validates that the
+    ///     contraction will not introduce cycles before modifying the graph.
+    ///     If not provided, inherits the value of ``check_cycle`` from this
+    ///     instance of :class:`~rustworkx.PyDiGraph`.
+    ///
+    /// :returns: The index of the merged node, which is ``source``.
+    /// :rtype: int
+    /// :raises NoEdgeBetweenNodes: If there is no edge from ``source`` to
+    ///     ``target``.
+    /// :raises DAGWouldCycle: The cycle check is enabled and the
+    ///     contraction would introduce a cycle.
+    #[pyo3(
+        signature=(source, target, /, node_fn=None, weight_combo_fn=None, self_loops=false, check_cycle=None),
+        text_signature = "(self, source, target, /, node_fn=None, weight_combo_fn=None, self_loops=False, check_cycle=None)"
+    )]
+    #[allow(clippy::too_many_arguments)]
+    pub fn contract_edge(
+        &mut self,
+        py: Python,
+        source: usize,
+        target: usize,
+        node_fn: Option<PyObject>,
+        weight_combo_fn: Option<PyObject>,
+        self_loops: bool,
+        check_cycle: Option<bool>,
+    ) -> RxPyResult<usize> {
+        let edges = [(source, target)];
+        self.contract_edges(
+            py,
+            edges.to_vec(),
+            node_fn,
+            weight_combo_fn,
+            self_loops,
+            check_cycle,
+        )?;
+        Ok(source)
+    }
+
+    /// Contract a sequence of edges in place.
+    ///
+    /// The edges are contracted one at a time, in order, like with
+    /// :meth:`~PyDiGraph.contract_edge`. An edge whose endpoints were already
+    /// merged by the contraction of earlier edges is skipped, unless it is a
+    /// self-loop, which is removed if ``self_loops`` is ``False``.
+    ///
+    /// :param list edge_list: A list of ``(source, target)`` tuples of the
+    ///     endpoints of the edges to contract. For every edge the node that
+    ///     ``target`` has been merged into is merged into the node that
+    ///     ``source`` has been merged into.
+    /// :param node_fn: An optional python callable that will be passed the
+    ///     data payloads of the two nodes being merged and is expected to
+    ///     return the data payload of the merged node. By default the payload
+    ///     of the first node is kept.
+    /// :param weight_combo_fn: An optional python callable that, when
+    ///     specified, is used to merge the parallel edges introduced by the
+    ///     contractions, like with :meth:`~PyDiGraph.contract_edge`.
+    /// :param bool self_loops: If ``True`` the edges between merged nodes
+    ///     become self-loops, otherwise they are removed. Defaults to
+    ///     ``False``.
+    /// :param bool check_cycle: If set to ``True``, validates that every
+    ///     contraction will not introduce cycles before applying it. If not
+    ///     provided, inherits the value of ``check_cycle`` from this instance
+    ///     of :class:`~rustworkx.PyDiGraph`.
+    ///
+    /// :returns: A mapping from the index of every node removed by the
+    ///     contractions to the index of the node it was merged into.
+    /// :rtype: NodeMap
+    /// :raises NoEdgeBetweenNodes: If there is no edge between the endpoints
+    ///     of an edge in ``edge_list``. The graph isn't modified in that case.
+    /// :raises DAGWouldCycle: The cycle check is enabled and a contraction
+    ///     would introduce a cycle. The contractions of the edges before it in
+    ///     ``edge_list`` have been applied in that case.
+    #[pyo3(
+        signature=(edge_list, /, node_fn=None, weight_combo_fn=None, self_loops=false, check_cycle=None),
+        text_signature = "(self, edge_list, /, node_fn=None, weight_combo_fn=None, self_loops=False, check_cycle=None)"
+    )]
+    pub fn contract_edges(
+        &mut self,
+        py: Python,
+        edge_list: Vec<(usize, usize)>,
+        node_fn: Option<PyObject>,
+        weight_combo_fn: Option<PyObject>,
+        self_loops: bool,
+        check_cycle: Option<bool>,
+    ) -> RxPyResult<NodeMap> {
+        let edge_list: Vec<(NodeIndex, NodeIndex)> = edge_list
+            .into_iter()
+            .map(|(a, b)| (NodeIndex::new(a), NodeIndex::new(b)))
+            .collect();
+        if edge_list
+            .iter()
+            .any(|(a, b)| self.graph.find_edge(*a, *b).is_none())
+        {
+            return Err(NoEdgeBetweenNodes::new_err("No edge found between nodes").into());
+        }
+        let check_cycle = check_cycle.unwrap_or(self.check_cycle);
+        let policy = ContractionPolicy {
+            parallel_edges: if weight_combo_fn.is_some() || !self.multigraph {
+                ParallelEdges::Merge
+            } else {
+                ParallelEdges::Keep
+            },
+            self_loops: if self_loops {
+                SelfLoops::Keep
+            } else {
+                SelfLoops::Remove
+            },
+        };
+        let node_weight_fn = |w1: &PyObject, w2: &PyObject| match &node_fn {
+            Some(node_fn) => node_fn.call1(py, (w1, w2)),
+            None => Ok(w1.clone_ref(py)),
+        };
+        let edge_merge_fn = |w1: &PyObject, w2: &PyObject| match &weight_combo_fn {
+            Some(weight_combo_fn) => weight_combo_fn.call1(py, (w1, w2)),
+            None => Ok(w1.clone_ref(py)),
+        };
+        let mut merged_into: HashMap<NodeIndex, NodeIndex> = HashMap::new();
+        let representative = |merged_into: &HashMap<NodeIndex, NodeIndex>, mut node| {
+            while let Some(next) = merged_into.get(&node) {
+                node = *next;
+            }
+            node
+        };
+        for (a, b) in edge_list {
+            let keep = representative(&merged_into, a);
+            let remove = representative(&merged_into, b);
+            if keep != remove {
+                self.graph.merge_nodes(
+                    keep,
+                    remove,
+                    policy,
+                    check_cycle,
+                    node_weight_fn,
+                    edge_merge_fn,
+                )?;
+                merged_into.insert(remove, keep);
+                self.node_removed = true;
+            } else if a == b {
+                if let Some(edge) = self.graph.find_edge(keep, keep) {
+                    self.graph.contract_edge(
+                        edge,
+                        policy,
+                        check_cycle,
+                        node_weight_fn,
+                        edge_merge_fn,
+                    )?;
+                }
+            }
+        }
+        let node_map = merged_into
+            .keys()
+            .map(|node| (node.index(), representative(&merged_into, *node).index()))
+            .collect();
+        Ok(NodeMap { node_map })
+    }
+
+    /// Merge all the nodes that share a key into a single node in place.
+    ///
+    /// ``key_fn`` is called on the data payload of every node, and the nodes
+    /// with equal keys are merged into the node of that key with the lowest
+    /// index, like with :meth:`~PyDiGraph.contract_edges`. The other nodes are
+    /// removed, so the indices of the kept nodes stay valid. Merging nodes
+    /// can create parallel edges, for example when two merged nodes have an
+    /// edge to the same node, and those are merged into a single edge unless
+    /// this graph is a multigraph and ``weight_combo_fn`` isn't specified.
+    ///
+    /// :param key_fn: A python callable that will be passed the data payload
+    ///     of every node and is expected to return a hashable key. Nodes with
+    ///     equal keys are merged.
+    /// :param node_fn: An optional python callable that will be passed the
+    ///     data payloads of two nodes being merged and is expected to return
+    ///     the data payload of the merged node. The nodes of a key are merged
+    ///     in order of their indices, so it's called with the combined payload
+    ///     so far and the payload of the next node. By default the payload of
+    ///     the node with the lowest index is kept.
+    /// :param weight_combo_fn: An optional python callable that, when
+    ///     specified, is used to merge the parallel edges from the same node to the same node
+    ///     introduced by the merges. It will be passed the data payloads of
+    ///     the edge already at the kept node and of the edge moved to it. If
+    ///     this instance of :class:`~rustworkx.PyDiGraph` is a multigraph, leave
+    ///     this unspecified to preserve parallel edges. If unspecified when not
+    ///     a multigraph, the payload of the edge already at the kept node is
+    ///     kept.
+    /// :param bool self_loops: If ``True`` the edges between merged nodes
+    ///     become self-loops on the kept node, otherwise they are removed.
+    ///     Defaults to ``False``.
+    /// :param bool check_cycle: If set to ``True``, validates that every
+    ///     merge will not introduce cycles before applying it. If not
+    ///     provided, inherits the value of ``check_cycle`` from this instance
+    ///     of :class:`~rustworkx.PyDiGraph`.
+    ///
+    /// :returns: A mapping from the index of every removed node to the index
+    ///     of the node it was merged into.
+    /// :rtype: NodeMap
+    /// :raises TypeError: If a key returned by ``key_fn`` isn't hashable. The
+    ///     graph isn't modified if ``key_fn`` raises an exception.
+    /// :raises DAGWouldCycle: The cycle check is enabled and a merge would
+    ///     introduce a cycle. The merges before it have been applied in that
+    ///     case.
+    ///
+    /// .. jupyter-execute::
+    ///
+    ///   import rustworkx as rx
+    ///
+    ///   graph = rx.PyDiGraph()
+    ///   graph.add_nodes_from(["alice", "Alice", "bob", "ALICE", "Bob"])
+    ///   graph.add_edges_from([(0, 2, 1), (1, 2, 2), (3, 4, 3)])
+    ///   node_map = graph.merge_nodes_by_key(
+    ///       str.lower, weight_combo_fn=lambda a, b: a + b
+    ///   )
+    ///   print(node_map)
+    ///   print(graph.nodes())
+    ///   print(graph.weighted_edge_list())
+    #[pyo3(
+        signature=(key_fn, /, node_fn=None, weight_combo_fn=None, self_loops=false, check_cycle=None),
+        text_signature = "(self, key_fn, /, node_fn=None, weight_combo_fn=None, self_loops=False, check_cycle=None)"
+    )]
+    pub fn merge_nodes_by_key(
+        &mut self,
+        py: Python,
+        key_fn: PyObject,
+        node_fn: Option<PyObject>,
+        weight_combo_fn: Option<PyObject>,
+        self_loops: bool,
+        check_cycle: Option<bool>,
+    ) -> RxPyResult<NodeMap> {
+        // Compute every key before modifying the graph so an error from
+        // ``key_fn`` leaves it untouched.
+        let keys = PyDict::new_bound(py);
+        let mut merges: Vec<(NodeIndex, NodeIndex)> = Vec::new();
+        for node in self.graph.node_indices() {
+            let key = key_fn.call1(py, (&self.graph[node],))?;
+            match keys.get_item(&key)? {
+                Some(keep) => merges.push((NodeIndex::new(keep.extract::<usize>()?), node)),
+                None => keys.set_item(key, node.index())?,
+            }
+        }
+        let check_cycle = check_cycle.unwrap_or(self.check_cycle);
+        let policy = ContractionPolicy {
+            parallel_edges: if weight_combo_fn.is_some() || !self.multigraph {
+                ParallelEdges::Merge
+            } else {
+                ParallelEdges::Keep
+            },
+            self_loops: if self_loops {
+                SelfLoops::Keep
+            } else {
+                SelfLoops::Remove
+            },
+        };
+        let node_weight_fn = |w1: &PyObject, w2: &PyObject| match &node_fn {
+            Some(node_fn) => node_fn.call1(py, (w1, w2)),
+            None => Ok(w1.clone_ref(py)),
+        };
+        let edge_merge_fn = |w1: &PyObject, w2: &PyObject| match &weight_combo_fn {
+            Some(weight_combo_fn) => weight_combo_fn.call1(py, (w1, w2)),
+            None => Ok(w1.clone_ref(py)),
+        };
+        let mut node_map = DictMap::with_capacity(merges.len());
+        for (keep, node) in merges {
+            self.graph.merge_nodes(
+                keep,
+                node,
+                policy,
+                check_cycle,
+                node_weight_fn,
+                edge_merge_fn,
+            )?;
+            node_map.insert(node.index(), keep.index());
+            self.node_removed = true;
+        }
+        Ok(NodeMap { node_map })
+    }
+
     /// Return a new PyDiGraph object for a subgraph of this graph
     ///
     /// :param list nodes: A list of node indices to generate the subgraph
@@ -2955,6 +3367,83 @@ impl PyDiGraph {
         })
     }
 
+    /// Return a new PyDiGraph object for a subgraph of this graph that keeps
+    /// the node and edge indices of this graph
+    ///
+    /// Unlike :meth:`~PyDiGraph.subgraph` the nodes and edges of the subgraph
+    /// aren't renumbered, so the indices returned by
+    /// :meth:`~PyDiGraph.filter_nodes` and :meth:`~PyDiGraph.filter_edges` and
+    /// the results of algorithms run on the subgraph can be used with this
+    /// graph directly. For example::
+    ///
+    ///     import rustworkx as rx
+    ///
+    ///     graph = rx.PyDiGraph()
+    ///     graph.add_nodes_from(["a", "b", "c", "d"])
+    ///     graph.add_edges_from([(0, 1, 1.0), (1, 2, 5.0), (2, 3, 1.0)])
+    ///     light = graph.filter_edges(lambda weight: weight < 2.0)
+    ///     view = graph.subgraph_view(edges=light)
+    ///     assert list(view.edge_indices()) == [0, 2]
+    ///     assert view.get_edge_data(2, 3) == 1.0
+    ///
+    /// Only the structure of the subgraph is stored in the new object, the
+    /// node and edge data payloads are shared with this graph by reference.
+    /// Replacing a payload in either graph doesn't affect the other, but if
+    /// you update (not replace) an object used as a payload it will also be
+    /// updated in the other graph.
+    ///
+    /// :param list nodes: An optional list of node indices to keep. If it's
+    ///     not specified all nodes are kept. Node indices that aren't present
+    ///     in the graph are silently ignored.
+    /// :param list edges: An optional list of edge indices to keep. If it's
+    ///     not specified all edges between the kept nodes are kept. Edges
+    ///     with an endpoint that isn't kept and edge indices that aren't
+    ///     present in the graph are silently ignored.
+    /// :param preserve_attrs: If set to the True the attributes of the PyDiGraph
+    ///     will be copied by reference to be the attributes of the output
+    ///     subgraph. By default this is set to False and the :attr:`~.PyDiGraph.attrs`
+    ///     attribute will be ``None`` in the subgraph.
+    ///
+    /// :returns: The subgraph with the same node and edge indices
+    /// :rtype: PyDiGraph
+    #[pyo3(
+        signature=(nodes=None, edges=None, preserve_attrs=false),
+        text_signature = "(self, /, nodes=None, edges=None, preserve_attrs=False)"
+    )]
+    pub fn subgraph_view(
+        &self,
+        py: Python,
+        nodes: Option<Vec<usize>>,
+        edges: Option<Vec<usize>>,
+        preserve_attrs: bool,
+    ) -> PyDiGraph {
+        let node_set: Option<HashSet<usize>> = nodes.map(|nodes| nodes.into_iter().collect());
+        let edge_set: Option<HashSet<usize>> = edges.map(|edges| edges.into_iter().collect());
+        let out_graph = self.graph.filter_map(
+            |node, weight| match &node_set {
+                Some(node_set) if !node_set.contains(&node.index()) => None,
+                _ => Some(weight.clone_ref(py)),
+            },
+            |edge, weight| match &edge_set {
+                Some(edge_set) if !edge_set.contains(&edge.index()) => None,
+                _ => Some(weight.clone_ref(py)),
+            },
+        );
+        let attrs = if preserve_attrs {
+            self.attrs.clone_ref(py)
+        } else {
+            py.None()
+        };
+        PyDiGraph {
+            node_removed: out_graph.node_count() < out_graph.node_bound(),
+            graph: out_graph,
+            cycle_state: algo::DfsSpace::default(),
+            check_cycle: self.check_cycle,
+            multigraph: self.multigraph,
+            attrs,
+        }
+    }
+
     /// Return a shallow copy of the graph
     ///
     /// All node and edge weight/data payloads in the copy will have a
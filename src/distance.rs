@@ -0,0 +1,410 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+use crate::iterators::NodeIndices;
+use crate::{digraph, graph, CostFn, EdgeType, StablePyGraph};
+
+use petgraph::visit::{EdgeIndexable, EdgeRef, IntoEdgeReferences};
+use rustworkx_core::dictmap::*;
+use rustworkx_core::distance;
+
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+
+fn disconnected_error() -> PyErr {
+    PyValueError::new_err("The graph must be connected and non-empty")
+}
+
+fn unreachable_error() -> PyErr {
+    PyValueError::new_err("Every node of the graph must be reachable from every other node")
+}
+
+/// Compute the weight of every edge up front, so the searches from every
+/// node can run in parallel without calling back into Python.
+fn edge_weights<Ty: EdgeType>(
+    py: Python,
+    graph: &StablePyGraph<Ty>,
+    weight_fn: Option<PyObject>,
+    default_weight: f64,
+) -> PyResult<Vec<f64>> {
+    let cost_fn = CostFn::try_from((weight_fn, default_weight))?;
+    let mut weights = vec![default_weight; graph.edge_bound()];
+    for edge in graph.edge_references() {
+        weights[edge.id().index()] = cost_fn.call_edge(py, edge.id(), edge.weight())?;
+    }
+    Ok(weights)
+}
+
+fn eccentricity<Ty: EdgeType>(
+    py: Python,
+    graph: &StablePyGraph<Ty>,
+    weight_fn: Option<PyObject>,
+    default_weight: f64,
+) -> PyResult<DictMap<usize, f64>> {
+    let weights = edge_weights(py, graph, weight_fn, default_weight)?;
+    let eccentricity =
+        distance::eccentricity(graph, |e| -> PyResult<f64> { Ok(weights[e.id().index()]) })?
+            .ok_or_else(unreachable_error)?;
+    Ok(eccentricity
+        .into_iter()
+        .map(|(node, eccentricity)| (node.index(), eccentricity))
+        .collect())
+}
+
+fn center<Ty: EdgeType>(
+    py: Python,
+    graph: &StablePyGraph<Ty>,
+    weight_fn: Option<PyObject>,
+    default_weight: f64,
+) -> PyResult<NodeIndices> {
+    let weights = edge_weights(py, graph, weight_fn, default_weight)?;
+    let center = distance::center(graph, |e| -> PyResult<f64> { Ok(weights[e.id().index()]) })?
+        .ok_or_else(unreachable_error)?;
+    Ok(NodeIndices {
+        nodes: center.into_iter().map(|node| node.index()).collect(),
+    })
+}
+
+fn periphery<Ty: EdgeType>(
+    py: Python,
+    graph: &StablePyGraph<Ty>,
+    weight_fn: Option<PyObject>,
+    default_weight: f64,
+) -> PyResult<NodeIndices> {
+    let weights = edge_weights(py, graph, weight_fn, default_weight)?;
+    let periphery =
+        distance::periphery(graph, |e| -> PyResult<f64> { Ok(weights[e.id().index()]) })?
+            .ok_or_else(unreachable_error)?;
+    Ok(NodeIndices {
+        nodes: periphery.into_iter().map(|node| node.index()).collect(),
+    })
+}
+
+/// Compute the diameter of a :class:`~.PyGraph`.
+///
+/// The diameter is the largest distance between any two nodes of the graph,
+/// where every edge has a length of 1. It's computed exactly with the iFUB
+/// (iterative Fringe Upper Bound) algorithm [Crescenzi2013]_, which starts
+/// with a breadth-first search from a central node and then only searches
+/// from the nodes farthest from it until the lower and upper bounds on the
+/// diameter meet. On large sparse graphs it usually needs only a handful of
+/// searches instead of one from every node, and the searches from nodes at
+/// the same distance from the central node are run in parallel.
+///
+/// :param PyGraph graph: The graph to compute the diameter of. It must be
+///     connected and non-empty.
+///
+/// :returns: The diameter of the graph
+/// :rtype: int
+/// :raises ValueError: If the graph is empty or isn't connected
+///
+/// .. [Crescenzi2013] Crescenzi, Pierluigi, et al. "On computing the diameter
+///     of real-world undirected graphs." Theoretical Computer Science 514
+///     (2013): 84-95.
+///
+/// .. jupyter-execute::
+///
+///   import rustworkx as rx
+///
+///   graph = rx.generators.grid_graph(4, 6)
+///   print(rx.diameter(graph))
+#[pyfunction]
+#[pyo3(text_signature = "(graph, /)")]
+pub fn diameter(graph: &graph::PyGraph) -> PyResult<usize> {
+    distance::diameter(&graph.graph).ok_or_else(disconnected_error)
+}
+
+/// Compute the radius of a :class:`~.PyGraph`.
+///
+/// The radius is the smallest eccentricity of the nodes of the graph, where
+/// the eccentricity of a node is its largest distance to any other node and
+/// every edge has a length of 1. It's computed exactly with the bounding
+/// algorithm of Takes and Kosters [Takes2013]_, which keeps bounds on the
+/// eccentricity of every node and tightens them with a breadth-first search
+/// from a few well chosen nodes instead of one from every node.
+///
+/// :param PyGraph graph: The graph to compute the radius of. It must be
+///     connected and non-empty.
+///
+/// :returns: The radius of the graph
+/// :rtype: int
+/// :raises ValueError: If the graph is empty or isn't connected
+///
+/// .. [Takes2013] Takes, Frank W., and Walter A. Kosters. "Computing the
+///     eccentricity distribution of large graphs." Algorithms 6.1 (2013):
+///     100-118.
+///
+/// .. jupyter-execute::
+///
+///   import rustworkx as rx
+///
+///   graph = rx.generators.grid_graph(4, 6)
+///   print(rx.radius(graph))
+#[pyfunction]
+#[pyo3(text_signature = "(graph, /)")]
+pub fn radius(graph: &graph::PyGraph) -> PyResult<usize> {
+    distance::radius(&graph.graph).ok_or_else(disconnected_error)
+}
+
+/// Compute the global efficiency of a :class:`~.PyGraph`.
+///
+/// The efficiency of a pair of nodes is the inverse of the length of the
+/// shortest path between them, and 0 if there's no path between them. The
+/// global efficiency is the average efficiency of all the pairs of distinct
+/// nodes of the graph [Latora2001]_, where every edge has a length of 1.
+/// Unlike the average shortest path length it's also defined for
+/// disconnected graphs. The breadth-first searches from every node are run
+/// in parallel.
+///
+/// :param PyGraph graph: The graph to compute the global efficiency of.
+///
+/// :returns: The global efficiency of the graph, 0 if it has fewer than
+///     two nodes
+/// :rtype: float
+///
+/// .. [Latora2001] Latora, Vito, and Massimo Marchiori. "Efficient behavior
+///     of small-world networks." Physical Review Letters 87.19 (2001):
+///     198701.
+///
+/// .. jupyter-execute::
+///
+///   import rustworkx as rx
+///
+///   graph = rx.generators.cycle_graph(5)
+///   print(rx.global_efficiency(graph))
+#[pyfunction]
+#[pyo3(text_signature = "(graph, /)")]
+pub fn global_efficiency(graph: &graph::PyGraph) -> f64 {
+    distance::global_efficiency(&graph.graph)
+}
+
+/// Compute the local efficiency of a :class:`~.PyGraph`.
+///
+/// The local efficiency of a node is the global efficiency, as computed by
+/// :func:`~rustworkx.global_efficiency`, of the subgraph induced by its
+/// neighbors, which measures how well its neighbors stay connected if the
+/// node is removed [Latora2001]_. The local efficiency of the graph is the
+/// average local efficiency of its nodes. The local efficiencies of the
+/// nodes are computed in parallel. Self loops and parallel edges are
+/// ignored.
+///
+/// :param PyGraph graph: The graph to compute the local efficiency of.
+///
+/// :returns: The local efficiency of the graph, 0 if it has no nodes
+/// :rtype: float
+///
+/// .. jupyter-execute::
+///
+///   import rustworkx as rx
+///
+///   graph = rx.generators.complete_graph(5)
+///   graph.remove_edge(0, 1)
+///   print(rx.local_efficiency(graph))
+#[pyfunction]
+#[pyo3(text_signature = "(graph, /)")]
+pub fn local_efficiency(graph: &graph::PyGraph) -> f64 {
+    distance::local_efficiency(&graph.graph)
+}
+
+/// Compute the eccentricity of every node of a :class:`~.PyGraph`.
+///
+/// The eccentricity of a node is the largest shortest path length from the
+/// node to any other node. The shortest path lengths are computed
+/// with Dijkstra's algorithm from every node in parallel. The edge weights
+/// are computed before the searches, so ``weight_fn`` is called once for
+/// every edge.
+///
+/// :param PyGraph graph: The graph to compute the eccentricities of. Every
+///     node must be reachable from every other node.
+/// :param weight_fn: An optional callable that will be passed the weight of
+///     every edge and is expected to return a non-negative ``float`` weight
+///     for it. If not specified ``default_weight`` is used for every edge.
+/// :param float default_weight: The weight of every edge if ``weight_fn`` is
+///     not specified. Default: ``1.0``.
+///
+/// :returns: A dictionary mapping every node index to its eccentricity
+/// :rtype: dict
+/// :raises ValueError: If some node can't reach every other node
+#[pyfunction]
+#[pyo3(
+    signature=(graph, /, weight_fn=None, default_weight=1.0),
+    text_signature = "(graph, /, weight_fn=None, default_weight=1.0)"
+)]
+pub fn graph_eccentricity(
+    py: Python,
+    graph: &graph::PyGraph,
+    weight_fn: Option<PyObject>,
+    default_weight: f64,
+) -> PyResult<DictMap<usize, f64>> {
+    eccentricity(py, &graph.graph, weight_fn, default_weight)
+}
+
+/// Return the center of a :class:`~.PyGraph`, the nodes with the smallest
+/// eccentricity.
+///
+/// The eccentricities are computed like in
+/// :func:`~rustworkx.graph_eccentricity`, with one search per node run
+/// in parallel.
+///
+/// :param PyGraph graph: The graph to find the center of. Every node must be
+///     reachable from every other node.
+/// :param weight_fn: An optional callable that will be passed the weight of
+///     every edge and is expected to return a non-negative ``float`` weight
+///     for it. If not specified ``default_weight`` is used for every edge.
+/// :param float default_weight: The weight of every edge if ``weight_fn`` is
+///     not specified. Default: ``1.0``.
+///
+/// :returns: The indices of the nodes in the center
+/// :rtype: NodeIndices
+/// :raises ValueError: If some node can't reach every other node
+#[pyfunction]
+#[pyo3(
+    signature=(graph, /, weight_fn=None, default_weight=1.0),
+    text_signature = "(graph, /, weight_fn=None, default_weight=1.0)"
+)]
+pub fn graph_center(
+    py: Python,
+    graph: &graph::PyGraph,
+    weight_fn: Option<PyObject>,
+    default_weight: f64,
+) -> PyResult<NodeIndices> {
+    center(py, &graph.graph, weight_fn, default_weight)
+}
+
+/// Return the periphery of a :class:`~.PyGraph`, the nodes with the largest
+/// eccentricity.
+///
+/// The eccentricities are computed like in
+/// :func:`~rustworkx.graph_eccentricity`, with one search per node run
+/// in parallel.
+///
+/// :param PyGraph graph: The graph to find the periphery of. Every node must be
+///     reachable from every other node.
+/// :param weight_fn: An optional callable that will be passed the weight of
+///     every edge and is expected to return a non-negative ``float`` weight
+///     for it. If not specified ``default_weight`` is used for every edge.
+/// :param float default_weight: The weight of every edge if ``weight_fn`` is
+///     not specified. Default: ``1.0``.
+///
+/// :returns: The indices of the nodes in the periphery
+/// :rtype: NodeIndices
+/// :raises ValueError: If some node can't reach every other node
+#[pyfunction]
+#[pyo3(
+    signature=(graph, /, weight_fn=None, default_weight=1.0),
+    text_signature = "(graph, /, weight_fn=None, default_weight=1.0)"
+)]
+pub fn graph_periphery(
+    py: Python,
+    graph: &graph::PyGraph,
+    weight_fn: Option<PyObject>,
+    default_weight: f64,
+) -> PyResult<NodeIndices> {
+    periphery(py, &graph.graph, weight_fn, default_weight)
+}
+
+/// Compute the eccentricity of every node of a :class:`~.PyDiGraph`.
+///
+/// The eccentricity of a node is the largest shortest path length from the
+/// node to any other node, following the direction of the edges. The shortest path lengths are computed
+/// with Dijkstra's algorithm from every node in parallel. The edge weights
+/// are computed before the searches, so ``weight_fn`` is called once for
+/// every edge.
+///
+/// :param PyDiGraph graph: The graph to compute the eccentricities of. Every
+///     node must be reachable from every other node.
+/// :param weight_fn: An optional callable that will be passed the weight of
+///     every edge and is expected to return a non-negative ``float`` weight
+///     for it. If not specified ``default_weight`` is used for every edge.
+/// :param float default_weight: The weight of every edge if ``weight_fn`` is
+///     not specified. Default: ``1.0``.
+///
+/// :returns: A dictionary mapping every node index to its eccentricity
+/// :rtype: dict
+/// :raises ValueError: If some node can't reach every other node
+#[pyfunction]
+#[pyo3(
+    signature=(graph, /, weight_fn=None, default_weight=1.0),
+    text_signature = "(graph, /, weight_fn=None, default_weight=1.0)"
+)]
+pub fn digraph_eccentricity(
+    py: Python,
+    graph: &digraph::PyDiGraph,
+    weight_fn: Option<PyObject>,
+    default_weight: f64,
+) -> PyResult<DictMap<usize, f64>> {
+    eccentricity(py, &graph.graph, weight_fn, default_weight)
+}
+
+/// Return the center of a :class:`~.PyDiGraph`, the nodes with the smallest
+/// eccentricity.
+///
+/// The eccentricities are computed like in
+/// :func:`~rustworkx.digraph_eccentricity`, with one search per node run
+/// in parallel.
+///
+/// :param PyDiGraph graph: The graph to find the center of. Every node must be
+///     reachable from every other node.
+/// :param weight_fn: An optional callable that will be passed the weight of
+///     every edge and is expected to return a non-negative ``float`` weight
+///     for it. If not specified ``default_weight`` is used for every edge.
+/// :param float default_weight: The weight of every edge if ``weight_fn`` is
+///     not specified. Default: ``1.0``.
+///
+/// :returns: The indices of the nodes in the center
+/// :rtype: NodeIndices
+/// :raises ValueError: If some node can't reach every other node
+#[pyfunction]
+#[pyo3(
+    signature=(graph, /, weight_fn=None, default_weight=1.0),
+    text_signature = "(graph, /, weight_fn=None, default_weight=1.0)"
+)]
+pub fn digraph_center(
+    py: Python,
+    graph: &digraph::PyDiGraph,
+    weight_fn: Option<PyObject>,
+    default_weight: f64,
+) -> PyResult<NodeIndices> {
+    center(py, &graph.graph, weight_fn, default_weight)
+}
+
+/// Return the periphery of a :class:`~.PyDiGraph`, the nodes with the largest
+/// eccentricity.
+///
+/// The eccentricities are computed like in
+/// :func:`~rustworkx.digraph_eccentricity`, with one search per node run
+/// in parallel.
+///
+/// :param PyDiGraph graph: The graph to find the periphery of. Every node must be
+///     reachable from every other node.
+/// :param weight_fn: An optional callable that will be passed the weight of
+///     every edge and is expected to return a non-negative ``float`` weight
+///     for it. If not specified ``default_weight`` is used for every edge.
+/// :param float default_weight: The weight of every edge if ``weight_fn`` is
+///     not specified. Default: ``1.0``.
+///
+/// :returns: The indices of the nodes in the periphery
+/// :rtype: NodeIndices
+/// :raises ValueError: If some node can't reach every other node
+#[pyfunction]
+#[pyo3(
+    signature=(graph, /, weight_fn=None, default_weight=1.0),
+    text_signature = "(graph, /, weight_fn=None, default_weight=1.0)"
+)]
+pub fn digraph_periphery(
+    py: Python,
+    graph: &digraph::PyDiGraph,
+    weight_fn: Option<PyObject>,
+    default_weight: f64,
+) -> PyResult<NodeIndices> {
+    periphery(py, &graph.graph, weight_fn, default_weight)
+}
@@ -0,0 +1,209 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+use std::convert::TryFrom;
+
+use crate::digraph::PyDiGraph;
+use crate::graph::PyGraph;
+use crate::iterators::{EdgeIndices, NodeIndices};
+use crate::{CostFn, StablePyGraph};
+
+use petgraph::visit::EdgeRef;
+use petgraph::EdgeType;
+use rustworkx_core::feedback;
+
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pyo3::Python;
+
+/// Find a small feedback arc set of a :class:`~.PyDiGraph`
+///
+/// A feedback arc set is a set of edges whose removal makes the graph
+/// acyclic. Finding one of minimum total weight is NP-hard, so this function
+/// uses a heuristic: it finds an ordering of the nodes and returns the edges
+/// that don't point forward in it. This is useful before topologically
+/// sorting data that is almost, but not quite, a DAG.
+///
+/// With ``method="greedy"`` the ordering is found by the greedy heuristic of
+/// Eades, Lin and Smyth [1]_, which repeatedly moves sinks to the end and
+/// sources to the start of the ordering, and otherwise moves the node with
+/// the largest difference of outgoing and incoming edge weight to the start.
+/// With ``method="local_search"`` that ordering is then improved by moving
+/// single nodes to the position that minimizes the weight of the backward
+/// edges, until no move helps or ``max_passes`` passes over the nodes were
+/// made. The local search result is never worse than the greedy one.
+///
+/// Self-loops are always in the feedback arc set, and parallel edges are
+/// either all in it or none of them.
+///
+/// :param PyDiGraph graph: The graph to find a feedback arc set of
+/// :param weight_fn: An optional callable that will be passed the edge's
+///     payload object and is expected to return a non-negative ``float``
+///     weight for that edge. A sequence of weights indexed by edge index is
+///     also accepted. If this is not specified ``default_weight`` will be
+///     used as the weight for every edge in ``graph``
+/// :param float default_weight: If ``weight_fn`` is not set the default
+///     weight value to use for the weight of all edges
+/// :param str method: The heuristic to use, either ``"greedy"`` or
+///     ``"local_search"``. Defaults to ``"local_search"``.
+/// :param int max_passes: The maximum number of passes of the local search.
+///     Ignored for ``method="greedy"``. Defaults to 100.
+///
+/// :returns: A tuple of the indices of the feedback arcs and the ordering
+///     of the nodes. Removing the feedback arcs from ``graph`` leaves a DAG
+///     and the ordering is a topological ordering of it.
+/// :rtype: tuple[EdgeIndices, NodeIndices]
+/// :raises ValueError: If ``method`` is not a valid method name
+///
+/// .. jupyter-execute::
+///
+///   import rustworkx as rx
+///
+///   graph = rx.generators.directed_cycle_graph(4)
+///   graph.add_edge(1, 3, None)
+///   arcs, ordering = rx.feedback_arc_set(graph)
+///   print(list(arcs), list(ordering))
+///
+/// .. [1] Eades, Peter, Xuemin Lin, and William F. Smyth.
+///     "A fast and effective heuristic for the feedback arc set problem."
+///     Information Processing Letters 47.6 (1993): 319-323.
+///     https://doi.org/10.1016/0020-0190(93)90079-O
+#[pyfunction]
+#[pyo3(
+    signature = (graph, /, weight_fn=None, default_weight=1.0, method="local_search", max_passes=100),
+    text_signature = "(graph, /, weight_fn=None, default_weight=1.0, method=\"local_search\", max_passes=100)"
+)]
+pub fn feedback_arc_set(
+    py: Python,
+    graph: &PyDiGraph,
+    weight_fn: Option<PyObject>,
+    default_weight: f64,
+    method: &str,
+    max_passes: usize,
+) -> PyResult<(EdgeIndices, NodeIndices)> {
+    let cost_fn = CostFn::try_from((weight_fn, default_weight))?;
+    let (arcs, ordering) = match method {
+        "greedy" => feedback::greedy_feedback_arc_set(&graph.graph, |edge| {
+            cost_fn.call_edge(py, edge.id(), edge.weight())
+        })?,
+        "local_search" => feedback::local_search_feedback_arc_set(
+            &graph.graph,
+            |edge| cost_fn.call_edge(py, edge.id(), edge.weight()),
+            max_passes,
+        )?,
+        _ => {
+            return Err(PyValueError::new_err(format!(
+                "Invalid method '{}', it must be 'greedy' or 'local_search'",
+                method
+            )))
+        }
+    };
+    Ok((
+        EdgeIndices {
+            edges: arcs.into_iter().map(|edge| edge.index()).collect(),
+        },
+        NodeIndices {
+            nodes: ordering.into_iter().map(|node| node.index()).collect(),
+        },
+    ))
+}
+
+fn feedback_vertex_set_inner<Ty: EdgeType>(
+    py: Python,
+    graph: &StablePyGraph<Ty>,
+    weight_fn: Option<PyObject>,
+) -> PyResult<NodeIndices> {
+    let cost_fn = CostFn::try_from_node_fn(weight_fn, 1.0)?;
+    let nodes = feedback::feedback_vertex_set(graph, |node| cost_fn.call(py, &graph[node]))?;
+    Ok(NodeIndices {
+        nodes: nodes.into_iter().map(|node| node.index()).collect(),
+    })
+}
+
+/// Find a small feedback vertex set of a :class:`~.PyGraph`
+///
+/// A feedback vertex set is a set of nodes whose removal makes the graph
+/// acyclic. Finding one of minimum total weight is NP-hard, so this function
+/// uses the local ratio algorithm of Bafna, Berman and Fujito [1]_, which
+/// finds a feedback vertex set whose weight is at most twice the minimum.
+/// The set is also minimal: removing any node from it leaves a cycle.
+///
+/// Nodes with self-loops are always in the set, and parallel edges count as
+/// a cycle.
+///
+/// :param PyGraph graph: The graph to find a feedback vertex set of
+/// :param weight_fn: An optional callable that will be passed the node's
+///     payload object and is expected to return a non-negative ``float``
+///     weight for that node. If this is not specified every node has a
+///     weight of 1
+///
+/// :returns: The indices of the nodes in the feedback vertex set
+/// :rtype: NodeIndices
+///
+/// .. jupyter-execute::
+///
+///   import rustworkx as rx
+///
+///   graph = rx.generators.generalized_petersen_graph(5, 2)
+///   print(rx.graph_feedback_vertex_set(graph))
+///
+/// .. [1] Bafna, Vineet, Piotr Berman, and Toshihiro Fujito.
+///     "A 2-approximation algorithm for the undirected feedback vertex set
+///     problem." SIAM Journal on Discrete Mathematics 12.3 (1999): 289-297.
+///     https://doi.org/10.1137/S0895480196305124
+#[pyfunction]
+#[pyo3(signature = (graph, /, weight_fn=None), text_signature = "(graph, /, weight_fn=None)")]
+pub fn graph_feedback_vertex_set(
+    py: Python,
+    graph: &PyGraph,
+    weight_fn: Option<PyObject>,
+) -> PyResult<NodeIndices> {
+    feedback_vertex_set_inner(py, &graph.graph, weight_fn)
+}
+
+/// Find a small feedback vertex set of a :class:`~.PyDiGraph`
+///
+/// A feedback vertex set is a set of nodes whose removal makes the graph
+/// acyclic. Finding one of minimum total weight is NP-hard, so this function
+/// uses a heuristic without an approximation guarantee: after removing the
+/// nodes that can't be on a cycle, it repeatedly adds the node with the
+/// largest product of its in and out degree divided by its weight to the
+/// set until the graph is acyclic. Then the nodes that aren't needed are
+/// dropped, so the set is minimal: removing any node from it leaves a cycle.
+///
+/// Nodes with self-loops are always in the set.
+///
+/// :param PyDiGraph graph: The graph to find a feedback vertex set of
+/// :param weight_fn: An optional callable that will be passed the node's
+///     payload object and is expected to return a non-negative ``float``
+///     weight for that node. If this is not specified every node has a
+///     weight of 1
+///
+/// :returns: The indices of the nodes in the feedback vertex set
+/// :rtype: NodeIndices
+///
+/// .. jupyter-execute::
+///
+///   import rustworkx as rx
+///
+///   graph = rx.generators.directed_cycle_graph(5)
+///   graph.add_edge(3, 1, None)
+///   print(rx.digraph_feedback_vertex_set(graph))
+#[pyfunction]
+#[pyo3(signature = (graph, /, weight_fn=None), text_signature = "(graph, /, weight_fn=None)")]
+pub fn digraph_feedback_vertex_set(
+    py: Python,
+    graph: &PyDiGraph,
+    weight_fn: Option<PyObject>,
+) -> PyResult<NodeIndices> {
+    feedback_vertex_set_inner(py, &graph.graph, weight_fn)
+}
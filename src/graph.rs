@@ -33,14 +33,15 @@ use pyo3::Python;
 use ndarray::prelude::*;
 use num_traits::Zero;
 use numpy::Complex64;
-use numpy::PyReadonlyArray2;
+use numpy::{IntoPyArray, PyReadonlyArray2};
 
 use crate::iterators::NodeMap;
 
 use super::dot_utils::build_dot;
 use super::iterators::{EdgeIndexMap, EdgeIndices, EdgeList, NodeIndices, WeightedEdgeList};
 use super::{
-    find_node_by_weight, weight_callable, IsNan, NoEdgeBetweenNodes, NodesRemoved, StablePyGraph,
+    csr_structure, find_node_by_weight, graph_from_edge_iterator, weight_callable, IsNan,
+    NoEdgeBetweenNodes, NodesRemoved, StablePyGraph,
 };
 
 use crate::RxPyResult;
@@ -1193,6 +1194,42 @@ impl PyGraph {
         }
     }
 
+    /// Return the compressed sparse row (CSR) form of the adjacency of the
+    /// graph.
+    ///
+    /// Row ``i`` holds the neighbors of the node with index ``i``, with an
+    /// entry for every edge incident to it, where a self loop only has one
+    /// entry. There's an empty row for every index up to the largest node
+    /// index that isn't a node of the graph. The entries of a row are in the
+    /// order of the indices of their edges.
+    ///
+    /// :returns: A tuple of the numpy arrays ``(data, indices, indptr)``,
+    ///     where ``data`` holds the index of the edge of every entry,
+    ///     ``indices`` the neighbor of every entry, and the entries of row
+    ///     ``i`` are at the positions ``indptr[i]`` to ``indptr[i + 1]``.
+    ///     They can be passed to ``scipy.sparse.csr_array`` with a shape of
+    ///     ``(len(indptr) - 1, len(indptr) - 1)``.
+    /// :rtype: tuple
+    ///
+    /// .. jupyter-execute::
+    ///
+    ///   import rustworkx as rx
+    ///
+    ///   graph = rx.generators.path_graph(4)
+    ///   data, indices, indptr = graph.to_csr()
+    ///   print(indptr)
+    ///   print(indices)
+    #[pyo3(text_signature = "(self)")]
+    pub fn to_csr(&self, py: Python) -> PyObject {
+        let csr = csr_structure(&self.graph);
+        (
+            csr.edge_indices().into_pyarray_bound(py),
+            csr.indices().to_vec().into_pyarray_bound(py),
+            csr.indptr().to_vec().into_pyarray_bound(py),
+        )
+            .into_py(py)
+    }
+
     /// Generate a dot file from the graph
     ///
     /// :param node_attr: A callable that will take in a node data object
@@ -1454,6 +1491,81 @@ impl PyGraph {
         Ok(())
     }
 
+    /// Create a new :class:`~rustworkx.PyGraph` object from an iterable of edges
+    ///
+    /// This method builds a graph from an iterable of edges, such as a
+    /// generator reading an edge list file, without building the whole edge
+    /// list in memory first. The edges are consumed in chunks of
+    /// ``chunk_size`` edges, the nodes are added as the edges refer to them
+    /// with a weight of ``None``, and the graph is preallocated for
+    /// ``num_nodes`` nodes and ``num_edges`` edges if they are specified.
+    /// This is faster and uses less memory than building the graph with
+    /// :meth:`~rustworkx.PyGraph.add_edges_from` for large graphs.
+    ///
+    /// If ``multigraph`` is ``False`` parallel edges are merged as they are
+    /// read: the weight of the merged edge is the result of ``combine_fn``,
+    /// or the weight of the last of the parallel edges if it isn't
+    /// specified. In a undirected graph ``(a, b)`` and ``(b, a)`` are parallel edges.
+    ///
+    /// :param edges: An iterable of ``(source, target)`` or
+    ///     ``(source, target, weight)`` tuples, where source and target are
+    ///     integer node indices. The weight of an edge without one is
+    ///     ``None``.
+    /// :param int num_nodes: The number of nodes of the graph, nodes without
+    ///     any edges are added so the graph has ``num_nodes`` nodes. It is
+    ///     also used to preallocate the graph. If it is specified, an edge
+    ///     with an endpoint index of ``num_nodes`` or more raises a
+    ///     ``ValueError``.
+    /// :param int num_edges: The expected number of edges, which is used to
+    ///     preallocate the graph.
+    /// :param bool multigraph: Whether the graph keeps parallel edges, if it
+    ///     is ``False`` they are merged. Default: ``True``.
+    /// :param combine_fn: An optional callable that will be passed the weight
+    ///     of an edge and the weight of an edge parallel to it, and is
+    ///     expected to return the weight of the merged edge. It is only used
+    ///     if ``multigraph`` is ``False``.
+    /// :param int chunk_size: The number of edges consumed from ``edges`` at
+    ///     a time. Default: ``65536``.
+    ///
+    /// :returns: A new graph object with the edges
+    /// :rtype: PyGraph
+    ///
+    /// For example:
+    ///
+    /// .. jupyter-execute::
+    ///
+    ///   import rustworkx as rx
+    ///
+    ///   edges = ((i % 10, (i * 7) % 10, 1) for i in range(100))
+    ///   graph = rx.PyGraph.from_edge_iterator(
+    ///       edges, num_nodes=10, multigraph=False, combine_fn=lambda a, b: a + b
+    ///   )
+    ///   print(graph.weighted_edge_list())
+    #[staticmethod]
+    #[pyo3(
+        signature=(edges, num_nodes=None, num_edges=None, multigraph=true, combine_fn=None, chunk_size=65536),
+        text_signature = "(edges, /, num_nodes=None, num_edges=None, multigraph=True, combine_fn=None, chunk_size=65536)"
+    )]
+    pub fn from_edge_iterator(
+        py: Python,
+        edges: &Bound<PyAny>,
+        num_nodes: Option<usize>,
+        num_edges: Option<usize>,
+        multigraph: bool,
+        combine_fn: Option<PyObject>,
+        chunk_size: usize,
+    ) -> PyResult<PyGraph> {
+        let graph = graph_from_edge_iterator(
+            py, edges, num_nodes, num_edges, multigraph, combine_fn, chunk_size,
+        )?;
+        Ok(PyGraph {
+            graph,
+            node_removed: false,
+            multigraph,
+            attrs: py.None(),
+        })
+    }
+
     /// Create a new :class:`~rustworkx.PyGraph` object from an adjacency matrix
     /// with matrix elements of type ``float``
     ///
@@ -1842,6 +1954,253 @@ impl PyGraph {
         Ok(res.index())
     }
 
+    /// Contract an edge in place, merging its endpoints into a single node.
+    ///
+    /// Unlike :meth:`~PyGraph.contract_nodes`, this doesn't create a new node.
+    /// The node ``node_b`` is merged into ``node_a``: its edges are moved to
+    /// ``node_a`` and it is removed from the graph, so the indices of the
+    /// other nodes stay valid and the method can be called repeatedly.
+    ///
+    /// :param int node_a: The index of the node that is kept.
+    /// :param int node_b: The index of the node merged into ``node_a``.
+    /// :param node_fn: An optional python callable that will be passed the
+    ///     data payloads of ``node_a`` and ``node_b`` and is expected to return
+    ///     the data payload of the merged node. By default the payload of
+    ///     ``node_a`` is kept.
+    /// :param weight_combo_fn: An optional python callable that, when
+    ///     specified, is used to merge the parallel edges introduced by the
+    ///     contraction. It will be passed the data payloads of the edge
+    ///     already at ``node_a`` and of the edge moved from ``node_b``. If this
+    ///     instance of :class:`~rustworkx.PyGraph` is a multigraph, leave this
+    ///     unspecified to preserve parallel edges. If unspecified when not a
+    ///     multigraph, the payload of the edge already at ``node_a`` is kept.
+    /// :param bool self_loops: If ``True`` the edges between ``node_a`` and
+    ///     ``node_b`` become self-loops on the merged node, otherwise they are
+    ///     removed. Defaults to ``False``.
+    ///
+    /// :returns: The index of the merged node, which is ``node_a``.
+    /// :rtype: int
+    /// :raises NoEdgeBetweenNodes: If there is no edge between ``node_a``
+    ///     and ``node_b``.
+    #[pyo3(
+        signature=(node_a, node_b, /, node_fn=None, weight_combo_fn=None, self_loops=false),
+        text_signature = "(self, node_a, node_b, /, node_fn=None, weight_combo_fn=None, self_loops=False)"
+    )]
+    pub fn contract_edge(
+        &mut self,
+        py: Python,
+        node_a: usize,
+        node_b: usize,
+        node_fn: Option<PyObject>,
+        weight_combo_fn: Option<PyObject>,
+        self_loops: bool,
+    ) -> PyResult<usize> {
+        let edges = [(node_a, node_b)];
+        self.contract_edges(py, edges.to_vec(), node_fn, weight_combo_fn, self_loops)?;
+        Ok(node_a)
+    }
+
+    /// Contract a sequence of edges in place.
+    ///
+    /// The edges are contracted one at a time, in order, like with
+    /// :meth:`~PyGraph.contract_edge`. An edge whose endpoints were already
+    /// merged by the contraction of earlier edges is skipped, unless it is a
+    /// self-loop, which is removed if ``self_loops`` is ``False``.
+    ///
+    /// :param list edge_list: A list of ``(node_a, node_b)`` tuples of the
+    ///     endpoints of the edges to contract. For every edge the node that
+    ///     ``node_b`` has been merged into is merged into the node that
+    ///     ``node_a`` has been merged into.
+    /// :param node_fn: An optional python callable that will be passed the
+    ///     data payloads of the two nodes being merged and is expected to
+    ///     return the data payload of the merged node. By default the payload
+    ///     of the first node is kept.
+    /// :param weight_combo_fn: An optional python callable that, when
+    ///     specified, is used to merge the parallel edges introduced by the
+    ///     contractions, like with :meth:`~PyGraph.contract_edge`.
+    /// :param bool self_loops: If ``True`` the edges between merged nodes
+    ///     become self-loops, otherwise they are removed. Defaults to
+    ///     ``False``.
+    ///
+    /// :returns: A mapping from the index of every node removed by the
+    ///     contractions to the index of the node it was merged into.
+    /// :rtype: NodeMap
+    /// :raises NoEdgeBetweenNodes: If there is no edge between the endpoints
+    ///     of an edge in ``edge_list``. The graph isn't modified in that case.
+    #[pyo3(
+        signature=(edge_list, /, node_fn=None, weight_combo_fn=None, self_loops=false),
+        text_signature = "(self, edge_list, /, node_fn=None, weight_combo_fn=None, self_loops=False)"
+    )]
+    pub fn contract_edges(
+        &mut self,
+        py: Python,
+        edge_list: Vec<(usize, usize)>,
+        node_fn: Option<PyObject>,
+        weight_combo_fn: Option<PyObject>,
+        self_loops: bool,
+    ) -> PyResult<NodeMap> {
+        let edge_list: Vec<(NodeIndex, NodeIndex)> = edge_list
+            .into_iter()
+            .map(|(a, b)| (NodeIndex::new(a), NodeIndex::new(b)))
+            .collect();
+        if edge_list
+            .iter()
+            .any(|(a, b)| self.graph.find_edge(*a, *b).is_none())
+        {
+            return Err(NoEdgeBetweenNodes::new_err("No edge found between nodes"));
+        }
+        let policy = ContractionPolicy {
+            parallel_edges: if weight_combo_fn.is_some() || !self.multigraph {
+                ParallelEdges::Merge
+            } else {
+                ParallelEdges::Keep
+            },
+            self_loops: if self_loops {
+                SelfLoops::Keep
+            } else {
+                SelfLoops::Remove
+            },
+        };
+        let node_weight_fn = |w1: &PyObject, w2: &PyObject| match &node_fn {
+            Some(node_fn) => node_fn.call1(py, (w1, w2)),
+            None => Ok(w1.clone_ref(py)),
+        };
+        let edge_merge_fn = |w1: &PyObject, w2: &PyObject| match &weight_combo_fn {
+            Some(weight_combo_fn) => weight_combo_fn.call1(py, (w1, w2)),
+            None => Ok(w1.clone_ref(py)),
+        };
+        let mut merged_into: HashMap<NodeIndex, NodeIndex> = HashMap::new();
+        let representative = |merged_into: &HashMap<NodeIndex, NodeIndex>, mut node| {
+            while let Some(next) = merged_into.get(&node) {
+                node = *next;
+            }
+            node
+        };
+        for (a, b) in edge_list {
+            let keep = representative(&merged_into, a);
+            let remove = representative(&merged_into, b);
+            if keep != remove {
+                self.graph
+                    .merge_nodes(keep, remove, policy, node_weight_fn, edge_merge_fn)?;
+                merged_into.insert(remove, keep);
+                self.node_removed = true;
+            } else if a == b {
+                if let Some(edge) = self.graph.find_edge(keep, keep) {
+                    self.graph
+                        .contract_edge(edge, policy, node_weight_fn, edge_merge_fn)?;
+                }
+            }
+        }
+        let node_map = merged_into
+            .keys()
+            .map(|node| (node.index(), representative(&merged_into, *node).index()))
+            .collect();
+        Ok(NodeMap { node_map })
+    }
+
+    /// Merge all the nodes that share a key into a single node in place.
+    ///
+    /// ``key_fn`` is called on the data payload of every node, and the nodes
+    /// with equal keys are merged into the node of that key with the lowest
+    /// index, like with :meth:`~PyGraph.contract_edges`. The other nodes are
+    /// removed, so the indices of the kept nodes stay valid. Merging nodes
+    /// can create parallel edges, for example when two merged nodes have an
+    /// edge to the same node, and those are merged into a single edge unless
+    /// this graph is a multigraph and ``weight_combo_fn`` isn't specified.
+    ///
+    /// :param key_fn: A python callable that will be passed the data payload
+    ///     of every node and is expected to return a hashable key. Nodes with
+    ///     equal keys are merged.
+    /// :param node_fn: An optional python callable that will be passed the
+    ///     data payloads of two nodes being merged and is expected to return
+    ///     the data payload of the merged node. The nodes of a key are merged
+    ///     in order of their indices, so it's called with the combined payload
+    ///     so far and the payload of the next node. By default the payload of
+    ///     the node with the lowest index is kept.
+    /// :param weight_combo_fn: An optional python callable that, when
+    ///     specified, is used to merge the parallel edges between the same two nodes
+    ///     introduced by the merges. It will be passed the data payloads of
+    ///     the edge already at the kept node and of the edge moved to it. If
+    ///     this instance of :class:`~rustworkx.PyGraph` is a multigraph, leave
+    ///     this unspecified to preserve parallel edges. If unspecified when not
+    ///     a multigraph, the payload of the edge already at the kept node is
+    ///     kept.
+    /// :param bool self_loops: If ``True`` the edges between merged nodes
+    ///     become self-loops on the kept node, otherwise they are removed.
+    ///     Defaults to ``False``.
+    ///
+    /// :returns: A mapping from the index of every removed node to the index
+    ///     of the node it was merged into.
+    /// :rtype: NodeMap
+    /// :raises TypeError: If a key returned by ``key_fn`` isn't hashable. The
+    ///     graph isn't modified if ``key_fn`` raises an exception.
+    ///
+    /// .. jupyter-execute::
+    ///
+    ///   import rustworkx as rx
+    ///
+    ///   graph = rx.PyGraph()
+    ///   graph.add_nodes_from(["alice", "Alice", "bob", "ALICE", "Bob"])
+    ///   graph.add_edges_from([(0, 2, 1), (1, 2, 2), (3, 4, 3)])
+    ///   node_map = graph.merge_nodes_by_key(
+    ///       str.lower, weight_combo_fn=lambda a, b: a + b
+    ///   )
+    ///   print(node_map)
+    ///   print(graph.nodes())
+    ///   print(graph.weighted_edge_list())
+    #[pyo3(
+        signature=(key_fn, /, node_fn=None, weight_combo_fn=None, self_loops=false),
+        text_signature = "(self, key_fn, /, node_fn=None, weight_combo_fn=None, self_loops=False)"
+    )]
+    pub fn merge_nodes_by_key(
+        &mut self,
+        py: Python,
+        key_fn: PyObject,
+        node_fn: Option<PyObject>,
+        weight_combo_fn: Option<PyObject>,
+        self_loops: bool,
+    ) -> PyResult<NodeMap> {
+        // Compute every key before modifying the graph so an error from
+        // ``key_fn`` leaves it untouched.
+        let keys = PyDict::new_bound(py);
+        let mut merges: Vec<(NodeIndex, NodeIndex)> = Vec::new();
+        for node in self.graph.node_indices() {
+            let key = key_fn.call1(py, (&self.graph[node],))?;
+            match keys.get_item(&key)? {
+                Some(keep) => merges.push((NodeIndex::new(keep.extract::<usize>()?), node)),
+                None => keys.set_item(key, node.index())?,
+            }
+        }
+        let policy = ContractionPolicy {
+            parallel_edges: if weight_combo_fn.is_some() || !self.multigraph {
+                ParallelEdges::Merge
+            } else {
+                ParallelEdges::Keep
+            },
+            self_loops: if self_loops {
+                SelfLoops::Keep
+            } else {
+                SelfLoops::Remove
+            },
+        };
+        let node_weight_fn = |w1: &PyObject, w2: &PyObject| match &node_fn {
+            Some(node_fn) => node_fn.call1(py, (w1, w2)),
+            None => Ok(w1.clone_ref(py)),
+        };
+        let edge_merge_fn = |w1: &PyObject, w2: &PyObject| match &weight_combo_fn {
+            Some(weight_combo_fn) => weight_combo_fn.call1(py, (w1, w2)),
+            None => Ok(w1.clone_ref(py)),
+        };
+        let mut node_map = DictMap::with_capacity(merges.len());
+        for (keep, node) in merges {
+            self.graph
+                .merge_nodes(keep, node, policy, node_weight_fn, edge_merge_fn)?;
+            node_map.insert(node.index(), keep.index());
+            self.node_removed = true;
+        }
+        Ok(NodeMap { node_map })
+    }
+
     /// Return a new PyGraph object for a subgraph of this graph
     ///
     /// :param list nodes: A list of node indices to generate the subgraph
@@ -1944,6 +2303,81 @@ impl PyGraph {
         out_graph
     }
 
+    /// Return a new PyGraph object for a subgraph of this graph that keeps
+    /// the node and edge indices of this graph
+    ///
+    /// Unlike :meth:`~PyGraph.subgraph` the nodes and edges of the subgraph
+    /// aren't renumbered, so the indices returned by
+    /// :meth:`~PyGraph.filter_nodes` and :meth:`~PyGraph.filter_edges` and
+    /// the results of algorithms run on the subgraph can be used with this
+    /// graph directly. For example::
+    ///
+    ///     import rustworkx as rx
+    ///
+    ///     graph = rx.PyGraph()
+    ///     graph.add_nodes_from(["a", "b", "c", "d"])
+    ///     graph.add_edges_from([(0, 1, 1.0), (1, 2, 5.0), (2, 3, 1.0)])
+    ///     light = graph.filter_edges(lambda weight: weight < 2.0)
+    ///     view = graph.subgraph_view(edges=light)
+    ///     assert list(view.edge_indices()) == [0, 2]
+    ///     assert view.get_edge_data(2, 3) == 1.0
+    ///
+    /// Only the structure of the subgraph is stored in the new object, the
+    /// node and edge data payloads are shared with this graph by reference.
+    /// Replacing a payload in either graph doesn't affect the other, but if
+    /// you update (not replace) an object used as a payload it will also be
+    /// updated in the other graph.
+    ///
+    /// :param list nodes: An optional list of node indices to keep. If it's
+    ///     not specified all nodes are kept. Node indices that aren't present
+    ///     in the graph are silently ignored.
+    /// :param list edges: An optional list of edge indices to keep. If it's
+    ///     not specified all edges between the kept nodes are kept. Edges
+    ///     with an endpoint that isn't kept and edge indices that aren't
+    ///     present in the graph are silently ignored.
+    /// :param preserve_attrs: If set to the True the attributes of the PyGraph
+    ///     will be copied by reference to be the attributes of the output
+    ///     subgraph. By default this is set to False and the :attr:`~.PyGraph.attrs`
+    ///     attribute will be ``None`` in the subgraph.
+    ///
+    /// :returns: The subgraph with the same node and edge indices
+    /// :rtype: PyGraph
+    #[pyo3(
+        signature=(nodes=None, edges=None, preserve_attrs=false),
+        text_signature = "(self, /, nodes=None, edges=None, preserve_attrs=False)"
+    )]
+    pub fn subgraph_view(
+        &self,
+        py: Python,
+        nodes: Option<Vec<usize>>,
+        edges: Option<Vec<usize>>,
+        preserve_attrs: bool,
+    ) -> PyGraph {
+        let node_set: Option<HashSet<usize>> = nodes.map(|nodes| nodes.into_iter().collect());
+        let edge_set: Option<HashSet<usize>> = edges.map(|edges| edges.into_iter().collect());
+        let out_graph = self.graph.filter_map(
+            |node, weight| match &node_set {
+                Some(node_set) if !node_set.contains(&node.index()) => None,
+                _ => Some(weight.clone_ref(py)),
+            },
+            |edge, weight| match &edge_set {
+                Some(edge_set) if !edge_set.contains(&edge.index()) => None,
+                _ => Some(weight.clone_ref(py)),
+            },
+        );
+        let attrs = if preserve_attrs {
+            self.attrs.clone_ref(py)
+        } else {
+            py.None()
+        };
+        PyGraph {
+            node_removed: out_graph.node_count() < out_graph.node_bound(),
+            graph: out_graph,
+            multigraph: self.multigraph,
+            attrs,
+        }
+    }
+
     /// Return a shallow copy of the graph
     ///
     /// All node and edge weight/data payloads in the copy will have a
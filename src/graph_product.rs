@@ -0,0 +1,367 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+use crate::iterators::ProductNodeMap;
+use crate::{digraph, graph};
+
+use hashbrown::HashMap;
+
+use petgraph::algo;
+use petgraph::graph::NodeIndex;
+
+use rustworkx_core::graph_product::{
+    lexicographic_product as core_lexicographic_product, strong_product as core_strong_product,
+    ProductEdge,
+};
+
+use pyo3::prelude::*;
+use pyo3::Python;
+
+/// Combine the payloads of a node of each factor graph into the payload of
+/// a node of a product graph.
+pub fn combine_node_weights(
+    py: Python,
+    node_fn: &Option<PyObject>,
+    first: &PyObject,
+    second: &PyObject,
+) -> PyResult<PyObject> {
+    match node_fn {
+        Some(node_fn) => node_fn.call1(py, (first, second)),
+        None => Ok((first, second).into_py(py)),
+    }
+}
+
+/// Combine the payloads of the factor edges of an edge of a product graph
+/// into the payload of that edge.
+pub fn combine_edge_weights(
+    py: Python,
+    edge_fn: &Option<PyObject>,
+    edge: ProductEdge<PyObject, PyObject>,
+) -> PyResult<PyObject> {
+    match edge_fn {
+        Some(edge_fn) => match edge {
+            ProductEdge::First(first) => edge_fn.call1(py, (first, py.None())),
+            ProductEdge::Second(second) => edge_fn.call1(py, (py.None(), second)),
+            ProductEdge::Both(first, second) => edge_fn.call1(py, (first, second)),
+        },
+        None => Ok(match edge {
+            ProductEdge::First(first) => first.clone_ref(py),
+            ProductEdge::Second(second) => second.clone_ref(py),
+            ProductEdge::Both(first, second) => (first, second).into_py(py),
+        }),
+    }
+}
+
+pub fn product_node_map(node_map: HashMap<(NodeIndex, NodeIndex), NodeIndex>) -> ProductNodeMap {
+    ProductNodeMap {
+        node_map: node_map
+            .into_iter()
+            .map(|((x, y), n)| ((x.index(), y.index()), n.index()))
+            .collect(),
+    }
+}
+
+/// Return a new PyGraph by forming the strong product from two input
+/// PyGraph objects
+///
+/// The edges of the strong product are the edges of the cartesian product
+/// and the edges of the tensor product of the two graphs.
+///
+/// :param PyGraph first: The first undirected graph object
+/// :param PyGraph second: The second undirected graph object
+/// :param node_fn: An optional callable that will be passed the data
+///     payloads of a node of ``first`` and a node of ``second`` and is
+///     expected to return the data payload of the corresponding node of the
+///     product. By default the payload is a tuple of the two payloads.
+/// :param edge_fn: An optional callable that will be passed the data
+///     payloads of the edges of ``first`` and ``second`` an edge of the
+///     product is formed from and is expected to return the data payload of
+///     that edge. The edges of the product come from an edge of
+///     one of the graphs or from an edge of each, and the argument for a
+///     graph without an edge is ``None``. By default the payload is the
+///     payload of the single edge, or a tuple of the two payloads.
+///
+/// :returns: A new PyGraph object that is the strong product of ``first``
+///     and ``second``.
+///     A read-only dictionary of the product of nodes is also returned. The keys
+///     are a tuple where the first element is a node of the first graph and the
+///     second element is a node of the second graph, and the values are the map
+///     of those elements to node indices in the product graph. For example::
+///     
+///         {
+///             (0, 0): 0,
+///             (0, 1): 1,
+///         }
+///
+/// :rtype: Tuple[:class:`~rustworkx.PyGraph`, :class:`~rustworkx.ProductNodeMap`]
+///
+/// .. jupyter-execute::
+///
+///   import rustworkx.generators
+///   from rustworkx.visualization import mpl_draw
+///
+///   graph_1 = rustworkx.generators.path_graph(2)
+///   graph_2 = rustworkx.generators.path_graph(3)
+///   graph_product, _ = rustworkx.graph_strong_product(graph_1, graph_2)
+///   mpl_draw(graph_product)
+#[pyfunction()]
+#[pyo3(
+    signature=(first, second, /, node_fn=None, edge_fn=None),
+    text_signature = "(first, second, /, node_fn=None, edge_fn=None)"
+)]
+pub fn graph_strong_product(
+    py: Python,
+    first: &graph::PyGraph,
+    second: &graph::PyGraph,
+    node_fn: Option<PyObject>,
+    edge_fn: Option<PyObject>,
+) -> PyResult<(graph::PyGraph, ProductNodeMap)> {
+    let (out_graph, node_map) = core_strong_product(
+        &first.graph,
+        &second.graph,
+        |a, b| combine_node_weights(py, &node_fn, a, b),
+        |edge| combine_edge_weights(py, &edge_fn, edge),
+    )?;
+
+    Ok((
+        graph::PyGraph {
+            graph: out_graph,
+            multigraph: true,
+            node_removed: false,
+            attrs: py.None(),
+        },
+        product_node_map(node_map),
+    ))
+}
+
+/// Return a new PyDiGraph by forming the strong product from two input
+/// PyDiGraph objects
+///
+/// The edges of the strong product are the edges of the cartesian product
+/// and the edges of the tensor product of the two graphs.
+///
+/// :param PyDiGraph first: The first undirected graph object
+/// :param PyDiGraph second: The second undirected graph object
+/// :param node_fn: An optional callable that will be passed the data
+///     payloads of a node of ``first`` and a node of ``second`` and is
+///     expected to return the data payload of the corresponding node of the
+///     product. By default the payload is a tuple of the two payloads.
+/// :param edge_fn: An optional callable that will be passed the data
+///     payloads of the edges of ``first`` and ``second`` an edge of the
+///     product is formed from and is expected to return the data payload of
+///     that edge. The edges of the product come from an edge of
+///     one of the graphs or from an edge of each, and the argument for a
+///     graph without an edge is ``None``. By default the payload is the
+///     payload of the single edge, or a tuple of the two payloads.
+///
+/// :returns: A new PyDiGraph object that is the strong product of ``first``
+///     and ``second``.
+///     A read-only dictionary of the product of nodes is also returned. The keys
+///     are a tuple where the first element is a node of the first graph and the
+///     second element is a node of the second graph, and the values are the map
+///     of those elements to node indices in the product graph. For example::
+///     
+///         {
+///             (0, 0): 0,
+///             (0, 1): 1,
+///         }
+///
+/// :rtype: Tuple[:class:`~rustworkx.PyDiGraph`, :class:`~rustworkx.ProductNodeMap`]
+///
+/// .. jupyter-execute::
+///
+///   import rustworkx.generators
+///   from rustworkx.visualization import mpl_draw
+///
+///   graph_1 = rustworkx.generators.directed_path_graph(2)
+///   graph_2 = rustworkx.generators.directed_path_graph(3)
+///   graph_product, _ = rustworkx.digraph_strong_product(graph_1, graph_2)
+///   mpl_draw(graph_product)
+#[pyfunction()]
+#[pyo3(
+    signature=(first, second, /, node_fn=None, edge_fn=None),
+    text_signature = "(first, second, /, node_fn=None, edge_fn=None)"
+)]
+pub fn digraph_strong_product(
+    py: Python,
+    first: &digraph::PyDiGraph,
+    second: &digraph::PyDiGraph,
+    node_fn: Option<PyObject>,
+    edge_fn: Option<PyObject>,
+) -> PyResult<(digraph::PyDiGraph, ProductNodeMap)> {
+    let (out_graph, node_map) = core_strong_product(
+        &first.graph,
+        &second.graph,
+        |a, b| combine_node_weights(py, &node_fn, a, b),
+        |edge| combine_edge_weights(py, &edge_fn, edge),
+    )?;
+
+    Ok((
+        digraph::PyDiGraph {
+            graph: out_graph,
+            cycle_state: algo::DfsSpace::default(),
+            check_cycle: false,
+            node_removed: false,
+            multigraph: true,
+            attrs: py.None(),
+        },
+        product_node_map(node_map),
+    ))
+}
+
+/// Return a new PyGraph by forming the lexicographic product from two input
+/// PyGraph objects
+///
+/// The lexicographic product replaces every node of ``first`` with a copy of
+/// ``second``, and every edge of ``first`` connects every node of the copy
+/// at its source to every node of the copy at its target.
+///
+/// :param PyGraph first: The first undirected graph object
+/// :param PyGraph second: The second undirected graph object
+/// :param node_fn: An optional callable that will be passed the data
+///     payloads of a node of ``first`` and a node of ``second`` and is
+///     expected to return the data payload of the corresponding node of the
+///     product. By default the payload is a tuple of the two payloads.
+/// :param edge_fn: An optional callable that will be passed the data
+///     payloads of the edges of ``first`` and ``second`` an edge of the
+///     product is formed from and is expected to return the data payload of
+///     that edge. As every edge of the product comes from an
+///     edge of one of the graphs, one of the arguments is always ``None``. By
+///     default the payload of the edge of ``first`` or ``second`` is used.
+///
+/// :returns: A new PyGraph object that is the lexicographic product of ``first``
+///     and ``second``.
+///     A read-only dictionary of the product of nodes is also returned. The keys
+///     are a tuple where the first element is a node of the first graph and the
+///     second element is a node of the second graph, and the values are the map
+///     of those elements to node indices in the product graph. For example::
+///     
+///         {
+///             (0, 0): 0,
+///             (0, 1): 1,
+///         }
+///
+/// :rtype: Tuple[:class:`~rustworkx.PyGraph`, :class:`~rustworkx.ProductNodeMap`]
+///
+/// .. jupyter-execute::
+///
+///   import rustworkx.generators
+///   from rustworkx.visualization import mpl_draw
+///
+///   graph_1 = rustworkx.generators.path_graph(2)
+///   graph_2 = rustworkx.generators.path_graph(3)
+///   graph_product, _ = rustworkx.graph_lexicographic_product(graph_1, graph_2)
+///   mpl_draw(graph_product)
+#[pyfunction()]
+#[pyo3(
+    signature=(first, second, /, node_fn=None, edge_fn=None),
+    text_signature = "(first, second, /, node_fn=None, edge_fn=None)"
+)]
+pub fn graph_lexicographic_product(
+    py: Python,
+    first: &graph::PyGraph,
+    second: &graph::PyGraph,
+    node_fn: Option<PyObject>,
+    edge_fn: Option<PyObject>,
+) -> PyResult<(graph::PyGraph, ProductNodeMap)> {
+    let (out_graph, node_map) = core_lexicographic_product(
+        &first.graph,
+        &second.graph,
+        |a, b| combine_node_weights(py, &node_fn, a, b),
+        |edge| combine_edge_weights(py, &edge_fn, edge),
+    )?;
+
+    Ok((
+        graph::PyGraph {
+            graph: out_graph,
+            multigraph: true,
+            node_removed: false,
+            attrs: py.None(),
+        },
+        product_node_map(node_map),
+    ))
+}
+
+/// Return a new PyDiGraph by forming the lexicographic product from two input
+/// PyDiGraph objects
+///
+/// The lexicographic product replaces every node of ``first`` with a copy of
+/// ``second``, and every edge of ``first`` connects every node of the copy
+/// at its source to every node of the copy at its target.
+///
+/// :param PyDiGraph first: The first undirected graph object
+/// :param PyDiGraph second: The second undirected graph object
+/// :param node_fn: An optional callable that will be passed the data
+///     payloads of a node of ``first`` and a node of ``second`` and is
+///     expected to return the data payload of the corresponding node of the
+///     product. By default the payload is a tuple of the two payloads.
+/// :param edge_fn: An optional callable that will be passed the data
+///     payloads of the edges of ``first`` and ``second`` an edge of the
+///     product is formed from and is expected to return the data payload of
+///     that edge. As every edge of the product comes from an
+///     edge of one of the graphs, one of the arguments is always ``None``. By
+///     default the payload of the edge of ``first`` or ``second`` is used.
+///
+/// :returns: A new PyDiGraph object that is the lexicographic product of ``first``
+///     and ``second``.
+///     A read-only dictionary of the product of nodes is also returned. The keys
+///     are a tuple where the first element is a node of the first graph and the
+///     second element is a node of the second graph, and the values are the map
+///     of those elements to node indices in the product graph. For example::
+///     
+///         {
+///             (0, 0): 0,
+///             (0, 1): 1,
+///         }
+///
+/// :rtype: Tuple[:class:`~rustworkx.PyDiGraph`, :class:`~rustworkx.ProductNodeMap`]
+///
+/// .. jupyter-execute::
+///
+///   import rustworkx.generators
+///   from rustworkx.visualization import mpl_draw
+///
+///   graph_1 = rustworkx.generators.directed_path_graph(2)
+///   graph_2 = rustworkx.generators.directed_path_graph(3)
+///   graph_product, _ = rustworkx.digraph_lexicographic_product(graph_1, graph_2)
+///   mpl_draw(graph_product)
+#[pyfunction()]
+#[pyo3(
+    signature=(first, second, /, node_fn=None, edge_fn=None),
+    text_signature = "(first, second, /, node_fn=None, edge_fn=None)"
+)]
+pub fn digraph_lexicographic_product(
+    py: Python,
+    first: &digraph::PyDiGraph,
+    second: &digraph::PyDiGraph,
+    node_fn: Option<PyObject>,
+    edge_fn: Option<PyObject>,
+) -> PyResult<(digraph::PyDiGraph, ProductNodeMap)> {
+    let (out_graph, node_map) = core_lexicographic_product(
+        &first.graph,
+        &second.graph,
+        |a, b| combine_node_weights(py, &node_fn, a, b),
+        |edge| combine_edge_weights(py, &edge_fn, edge),
+    )?;
+
+    Ok((
+        digraph::PyDiGraph {
+            graph: out_graph,
+            cycle_state: algo::DfsSpace::default(),
+            check_cycle: false,
+            node_removed: false,
+            multigraph: true,
+            attrs: py.None(),
+        },
+        product_node_map(node_map),
+    ))
+}
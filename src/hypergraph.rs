@@ -0,0 +1,438 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+use hashbrown::HashSet;
+
+use pyo3::exceptions::PyIndexError;
+use pyo3::gc::PyVisit;
+use pyo3::prelude::*;
+use pyo3::PyTraverseError;
+
+use petgraph::prelude::*;
+use rustworkx_core::hypergraph::Hypergraph;
+
+use crate::graph;
+use crate::iterators::{EdgeIndices, NodeIndices, NodeMap};
+use crate::StablePyGraph;
+
+/// A class for creating hypergraphs
+///
+/// A hypergraph generalizes a graph by letting every edge, called a
+/// hyperedge, connect any number of nodes. The nodes and hyperedges of a
+/// :class:`~rustworkx.PyHypergraph` can have any Python object as their
+/// payload, and are identified by integer indices. Unlike
+/// :class:`~rustworkx.PyGraph`, the indices of removed nodes and hyperedges
+/// are never reused. A hyperedge is a set of nodes, so adding a node to a
+/// hyperedge more than once has no effect.
+///
+/// The algorithms in rustworkx can be applied to a hypergraph after
+/// converting it to its bipartite incidence graph with
+/// :meth:`~rustworkx.PyHypergraph.to_incidence_graph` or to its clique
+/// expansion with :meth:`~rustworkx.PyHypergraph.to_clique_expansion`.
+///
+/// .. jupyter-execute::
+///
+///     import rustworkx as rx
+///
+///     hypergraph = rx.PyHypergraph()
+///     a, b, c, d = hypergraph.add_nodes_from(["a", "b", "c", "d"])
+///     hypergraph.add_hyperedge([a, b, c], "team 1")
+///     hypergraph.add_hyperedge([c, d], "team 2")
+///     graph, node_map, hyperedge_map = hypergraph.to_incidence_graph()
+///     print(graph.nodes())
+///     print(rx.is_bipartite(graph))
+#[pyclass(mapping, module = "rustworkx", subclass)]
+#[derive(Clone)]
+pub struct PyHypergraph {
+    pub hypergraph: Hypergraph<PyObject, PyObject>,
+}
+
+#[pymethods]
+impl PyHypergraph {
+    #[new]
+    fn new() -> Self {
+        PyHypergraph {
+            hypergraph: Hypergraph::new(),
+        }
+    }
+
+    /// Return the number of nodes in the hypergraph
+    #[pyo3(text_signature = "(self)")]
+    pub fn num_nodes(&self) -> usize {
+        self.hypergraph.node_count()
+    }
+
+    /// Return the number of hyperedges in the hypergraph
+    #[pyo3(text_signature = "(self)")]
+    pub fn num_hyperedges(&self) -> usize {
+        self.hypergraph.hyperedge_count()
+    }
+
+    /// Add a new node to the hypergraph.
+    ///
+    /// :param obj: The Python object to attach to the node
+    ///
+    /// :returns: The index of the newly created node
+    /// :rtype: int
+    #[pyo3(text_signature = "(self, obj, /)")]
+    pub fn add_node(&mut self, obj: PyObject) -> usize {
+        self.hypergraph.add_node(obj)
+    }
+
+    /// Add new nodes to the hypergraph.
+    ///
+    /// :param list obj_list: A list of Python objects to attach to the nodes
+    ///
+    /// :returns: The indices of the newly created nodes
+    /// :rtype: NodeIndices
+    #[pyo3(text_signature = "(self, obj_list, /)")]
+    pub fn add_nodes_from(&mut self, obj_list: Vec<PyObject>) -> NodeIndices {
+        NodeIndices {
+            nodes: obj_list
+                .into_iter()
+                .map(|obj| self.hypergraph.add_node(obj))
+                .collect(),
+        }
+    }
+
+    /// Add a new hyperedge to the hypergraph.
+    ///
+    /// :param list nodes: The indices of the nodes of the hyperedge
+    /// :param obj: The Python object to attach to the hyperedge. If not
+    ///     specified it is ``None``.
+    ///
+    /// :returns: The index of the newly created hyperedge
+    /// :rtype: int
+    /// :raises IndexError: when one of the nodes is not in the hypergraph
+    #[pyo3(signature=(nodes, obj=None), text_signature = "(self, nodes, /, obj=None)")]
+    pub fn add_hyperedge(
+        &mut self,
+        py: Python,
+        nodes: Vec<usize>,
+        obj: Option<PyObject>,
+    ) -> PyResult<usize> {
+        if let Some(node) = nodes
+            .iter()
+            .find(|node| !self.hypergraph.contains_node(**node))
+        {
+            return Err(PyIndexError::new_err(format!(
+                "Node index {node} is not in the hypergraph"
+            )));
+        }
+        Ok(self
+            .hypergraph
+            .add_hyperedge(nodes, obj.unwrap_or_else(|| py.None())))
+    }
+
+    /// Remove a node from the hypergraph.
+    ///
+    /// The node is removed from all of its hyperedges, which are kept. If
+    /// the node is not in the hypergraph this is a no-op.
+    ///
+    /// :param int node: The index of the node to remove
+    #[pyo3(text_signature = "(self, node, /)")]
+    pub fn remove_node(&mut self, node: usize) {
+        self.hypergraph.remove_node(node);
+    }
+
+    /// Remove a hyperedge from the hypergraph.
+    ///
+    /// If the hyperedge is not in the hypergraph this is a no-op.
+    ///
+    /// :param int hyperedge: The index of the hyperedge to remove
+    #[pyo3(text_signature = "(self, hyperedge, /)")]
+    pub fn remove_hyperedge(&mut self, hyperedge: usize) {
+        self.hypergraph.remove_hyperedge(hyperedge);
+    }
+
+    /// Return a list of all node data.
+    ///
+    /// :returns: A list of all the node data objects in the hypergraph
+    /// :rtype: list
+    #[pyo3(text_signature = "(self)")]
+    pub fn nodes(&self) -> Vec<&PyObject> {
+        self.hypergraph
+            .node_indices()
+            .map(|node| self.hypergraph.node_weight(node).unwrap())
+            .collect()
+    }
+
+    /// Return a list of all hyperedge data.
+    ///
+    /// :returns: A list of all the hyperedge data objects in the hypergraph
+    /// :rtype: list
+    #[pyo3(text_signature = "(self)")]
+    pub fn hyperedges(&self) -> Vec<&PyObject> {
+        self.hypergraph
+            .hyperedge_indices()
+            .map(|hyperedge| self.hypergraph.hyperedge_weight(hyperedge).unwrap())
+            .collect()
+    }
+
+    /// Return a list of all node indices.
+    ///
+    /// :returns: A list of all the node indices in the hypergraph
+    /// :rtype: NodeIndices
+    #[pyo3(text_signature = "(self)")]
+    pub fn node_indices(&self) -> NodeIndices {
+        NodeIndices {
+            nodes: self.hypergraph.node_indices().collect(),
+        }
+    }
+
+    /// Return a list of all hyperedge indices.
+    ///
+    /// :returns: A list of all the hyperedge indices in the hypergraph
+    /// :rtype: EdgeIndices
+    #[pyo3(text_signature = "(self)")]
+    pub fn hyperedge_indices(&self) -> EdgeIndices {
+        EdgeIndices {
+            edges: self.hypergraph.hyperedge_indices().collect(),
+        }
+    }
+
+    /// Return the node data for a given node index
+    ///
+    /// :param int node: The index for the node
+    ///
+    /// :returns: The data object set for that node
+    /// :raises IndexError: when an invalid node index is provided
+    #[pyo3(text_signature = "(self, node, /)")]
+    pub fn get_node_data(&self, node: usize) -> PyResult<&PyObject> {
+        self.hypergraph
+            .node_weight(node)
+            .ok_or_else(|| PyIndexError::new_err("No node found for index"))
+    }
+
+    /// Return the hyperedge data for a given hyperedge index
+    ///
+    /// :param int hyperedge: The index for the hyperedge
+    ///
+    /// :returns: The data object set for that hyperedge
+    /// :raises IndexError: when an invalid hyperedge index is provided
+    #[pyo3(text_signature = "(self, hyperedge, /)")]
+    pub fn get_hyperedge_data(&self, hyperedge: usize) -> PyResult<&PyObject> {
+        self.hypergraph
+            .hyperedge_weight(hyperedge)
+            .ok_or_else(|| PyIndexError::new_err("No hyperedge found for index"))
+    }
+
+    /// Return the nodes of a hyperedge.
+    ///
+    /// :param int hyperedge: The index for the hyperedge
+    ///
+    /// :returns: The indices of the nodes of the hyperedge, in the order they
+    ///     were added
+    /// :rtype: NodeIndices
+    /// :raises IndexError: when an invalid hyperedge index is provided
+    #[pyo3(text_signature = "(self, hyperedge, /)")]
+    pub fn hyperedge_nodes(&self, hyperedge: usize) -> PyResult<NodeIndices> {
+        match self.hypergraph.hyperedge_nodes(hyperedge) {
+            Some(nodes) => Ok(NodeIndices {
+                nodes: nodes.to_vec(),
+            }),
+            None => Err(PyIndexError::new_err("No hyperedge found for index")),
+        }
+    }
+
+    /// Return the hyperedges containing a node.
+    ///
+    /// :param int node: The index for the node
+    ///
+    /// :returns: The indices of the hyperedges containing the node
+    /// :rtype: EdgeIndices
+    /// :raises IndexError: when an invalid node index is provided
+    #[pyo3(text_signature = "(self, node, /)")]
+    pub fn incident_hyperedges(&self, node: usize) -> PyResult<EdgeIndices> {
+        match self.hypergraph.incident_hyperedges(node) {
+            Some(hyperedges) => Ok(EdgeIndices {
+                edges: hyperedges.to_vec(),
+            }),
+            None => Err(PyIndexError::new_err("No node found for index")),
+        }
+    }
+
+    /// Return the number of hyperedges containing a node.
+    ///
+    /// :param int node: The index for the node
+    ///
+    /// :returns: The degree of the node
+    /// :rtype: int
+    /// :raises IndexError: when an invalid node index is provided
+    #[pyo3(text_signature = "(self, node, /)")]
+    pub fn degree(&self, node: usize) -> PyResult<usize> {
+        match self.hypergraph.incident_hyperedges(node) {
+            Some(hyperedges) => Ok(hyperedges.len()),
+            None => Err(PyIndexError::new_err("No node found for index")),
+        }
+    }
+
+    /// Return the nodes sharing at least one hyperedge with a node.
+    ///
+    /// :param int node: The index for the node
+    ///
+    /// :returns: The indices of the neighbors of the node, not including the
+    ///     node itself
+    /// :rtype: NodeIndices
+    /// :raises IndexError: when an invalid node index is provided
+    #[pyo3(text_signature = "(self, node, /)")]
+    pub fn neighbors(&self, node: usize) -> PyResult<NodeIndices> {
+        match self.hypergraph.neighbors(node) {
+            Some(nodes) => Ok(NodeIndices { nodes }),
+            None => Err(PyIndexError::new_err("No node found for index")),
+        }
+    }
+
+    /// Return the bipartite incidence graph of the hypergraph.
+    ///
+    /// The incidence graph has a node for every node and every hyperedge of
+    /// the hypergraph, with the same data payloads, and an edge with a
+    /// ``None`` payload between every node and each of its hyperedges.
+    ///
+    /// :returns: A tuple of the incidence graph, a mapping of the node
+    ///     indices of the hypergraph to the node indices of the graph and a
+    ///     mapping of the hyperedge indices to the node indices of the graph
+    /// :rtype: tuple[PyGraph, NodeMap, NodeMap]
+    #[pyo3(text_signature = "(self)")]
+    pub fn to_incidence_graph(&self, py: Python) -> (graph::PyGraph, NodeMap, NodeMap) {
+        let (graph, node_map, hyperedge_map): (StablePyGraph<Undirected>, _, _) =
+            self.hypergraph.incidence_graph(
+                |node| node.clone_ref(py),
+                |hyperedge| hyperedge.clone_ref(py),
+                || py.None(),
+            );
+        (
+            new_graph(py, graph),
+            node_map_to_py(node_map),
+            node_map_to_py(hyperedge_map),
+        )
+    }
+
+    /// Create a hypergraph from a bipartite incidence graph.
+    ///
+    /// Every node of ``graph`` in ``hyperedges`` becomes a hyperedge
+    /// containing its neighbors and every other node becomes a node of the
+    /// hypergraph, with the same data payloads. Nodes and hyperedges are
+    /// numbered in the order of the node indices of ``graph``. Edges between
+    /// two nodes or between two hyperedges are ignored.
+    ///
+    /// :param PyGraph graph: The incidence graph
+    /// :param hyperedges: The indices of the nodes of ``graph`` that are
+    ///     hyperedges
+    ///
+    /// :returns: The hypergraph
+    /// :rtype: PyHypergraph
+    #[staticmethod]
+    #[pyo3(text_signature = "(graph, hyperedges, /)")]
+    pub fn from_incidence_graph(
+        py: Python,
+        graph: &graph::PyGraph,
+        hyperedges: HashSet<usize>,
+    ) -> PyHypergraph {
+        PyHypergraph {
+            hypergraph: Hypergraph::from_incidence_graph(
+                &graph.graph,
+                |node| hyperedges.contains(&node.index()),
+                |weight| weight.clone_ref(py),
+                |weight| weight.clone_ref(py),
+            ),
+        }
+    }
+
+    /// Return the clique expansion of the hypergraph.
+    ///
+    /// The clique expansion is the graph on the nodes of the hypergraph,
+    /// with the same data payloads, that has a single edge between every
+    /// pair of distinct nodes sharing at least one hyperedge. The payload of
+    /// every edge is the list of the indices of the hyperedges shared by its
+    /// endpoints. Unlike the incidence graph, the hypergraph can't be
+    /// recovered from its clique expansion.
+    ///
+    /// :returns: A tuple of the clique expansion and a mapping of the node
+    ///     indices of the hypergraph to the node indices of the graph
+    /// :rtype: tuple[PyGraph, NodeMap]
+    #[pyo3(text_signature = "(self)")]
+    pub fn to_clique_expansion(&self, py: Python) -> (graph::PyGraph, NodeMap) {
+        let (graph, node_map): (StablePyGraph<Undirected>, _) = self
+            .hypergraph
+            .clique_expansion(|node| node.clone_ref(py), |shared| shared.to_object(py));
+        (new_graph(py, graph), node_map_to_py(node_map))
+    }
+
+    /// Return a shallow copy of the hypergraph
+    ///
+    /// All node and hyperedge data payloads in the copy will have a shared
+    /// reference to the original hypergraph.
+    #[pyo3(text_signature = "(self)")]
+    pub fn copy(&self) -> PyHypergraph {
+        self.clone()
+    }
+
+    /// Return the number of nodes in the hypergraph
+    fn __len__(&self) -> usize {
+        self.hypergraph.node_count()
+    }
+
+    fn __getitem__(&self, idx: usize) -> PyResult<&PyObject> {
+        self.get_node_data(idx)
+    }
+
+    fn __setitem__(&mut self, idx: usize, value: PyObject) -> PyResult<()> {
+        match self.hypergraph.node_weight_mut(idx) {
+            Some(data) => {
+                *data = value;
+                Ok(())
+            }
+            None => Err(PyIndexError::new_err("No node found for index")),
+        }
+    }
+
+    fn __delitem__(&mut self, idx: usize) -> PyResult<()> {
+        match self.hypergraph.remove_node(idx) {
+            Some(_) => Ok(()),
+            None => Err(PyIndexError::new_err("No node found for index")),
+        }
+    }
+
+    // Functions to enable Python Garbage Collection, see PyGraph for details
+    fn __traverse__(&self, visit: PyVisit) -> Result<(), PyTraverseError> {
+        for node in self.hypergraph.node_indices() {
+            visit.call(self.hypergraph.node_weight(node).unwrap())?;
+        }
+        for hyperedge in self.hypergraph.hyperedge_indices() {
+            visit.call(self.hypergraph.hyperedge_weight(hyperedge).unwrap())?;
+        }
+        Ok(())
+    }
+
+    fn __clear__(&mut self) {
+        self.hypergraph = Hypergraph::new();
+    }
+}
+
+fn new_graph(py: Python, graph: StablePyGraph<Undirected>) -> graph::PyGraph {
+    graph::PyGraph {
+        graph,
+        node_removed: false,
+        multigraph: true,
+        attrs: py.None(),
+    }
+}
+
+fn node_map_to_py(map: rustworkx_core::dictmap::DictMap<usize, NodeIndex>) -> NodeMap {
+    NodeMap {
+        node_map: map
+            .into_iter()
+            .map(|(index, node)| (index, node.index()))
+            .collect(),
+    }
+}
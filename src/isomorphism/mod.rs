@@ -12,12 +12,21 @@
 
 #![allow(clippy::too_many_arguments)]
 
+mod rewrite;
 mod vf2;
 
-use crate::{digraph, graph};
+use crate::iterators::NodeMap;
+use crate::progress::ProgressFn;
+use crate::{digraph, graph, StablePyGraph};
 
 use std::cmp::Ordering;
 
+use hashbrown::HashMap;
+
+use petgraph::EdgeType;
+use rustworkx_core::canonical::canonical_hash;
+use rustworkx_core::invariants::{distinguishing_invariant, Invariant};
+
 use pyo3::prelude::*;
 use pyo3::Python;
 
@@ -53,15 +62,21 @@ use pyo3::Python;
 /// :param int call_limit: An optional bound on the number of states that VF2 algorithm
 ///     visits while searching for a solution. If it exceeds this limit, the algorithm
 ///     will stop and return ``False``.
+/// :param progress_fn: An optional callable which is called as
+///     ``progress_fn(states, None)`` after every ``progress_interval`` states
+///     the VF2 algorithm visits. An exception raised by it stops the search
+///     and is raised from this function.
+/// :param int progress_interval: The number of states between the calls to
+///     ``progress_fn``. Defaults to 1000.
 ///
 /// :returns: ``True`` if the 2 graphs are isomorphic ``False`` if they are
 ///     not.
 /// :rtype: bool
 #[pyfunction]
 #[pyo3(
-    signature=(first, second, node_matcher=None, edge_matcher=None, id_order=true, call_limit=None),
+    signature=(first, second, node_matcher=None, edge_matcher=None, id_order=true, call_limit=None, progress_fn=None, progress_interval=None),
     text_signature = "(first, second, /, node_matcher=None, edge_matcher=None,
-                    id_order=True, call_limit=None)"
+                    id_order=True, call_limit=None, progress_fn=None, progress_interval=None)"
 )]
 pub fn digraph_is_isomorphic(
     py: Python,
@@ -71,6 +86,8 @@ pub fn digraph_is_isomorphic(
     edge_matcher: Option<PyObject>,
     id_order: bool,
     call_limit: Option<usize>,
+    progress_fn: Option<PyObject>,
+    progress_interval: Option<usize>,
 ) -> PyResult<bool> {
     vf2::is_isomorphic(
         py,
@@ -82,6 +99,7 @@ pub fn digraph_is_isomorphic(
         Ordering::Equal,
         true,
         call_limit,
+        ProgressFn::new(progress_fn, progress_interval)?,
     )
 }
 
@@ -117,15 +135,21 @@ pub fn digraph_is_isomorphic(
 /// :param int call_limit: An optional bound on the number of states that VF2 algorithm
 ///     visits while searching for a solution. If it exceeds this limit, the algorithm
 ///     will stop and return ``False``.
+/// :param progress_fn: An optional callable which is called as
+///     ``progress_fn(states, None)`` after every ``progress_interval`` states
+///     the VF2 algorithm visits. An exception raised by it stops the search
+///     and is raised from this function.
+/// :param int progress_interval: The number of states between the calls to
+///     ``progress_fn``. Defaults to 1000.
 ///
 /// :returns: ``True`` if the 2 graphs are isomorphic ``False`` if they are
 ///     not.
 /// :rtype: bool
 #[pyfunction]
 #[pyo3(
-    signature=(first, second, node_matcher=None, edge_matcher=None, id_order=true, call_limit=None),
+    signature=(first, second, node_matcher=None, edge_matcher=None, id_order=true, call_limit=None, progress_fn=None, progress_interval=None),
     text_signature = "(first, second, /, node_matcher=None, edge_matcher=None,
-                    id_order=True, call_limit=None)"
+                    id_order=True, call_limit=None, progress_fn=None, progress_interval=None)"
 )]
 pub fn graph_is_isomorphic(
     py: Python,
@@ -135,6 +159,8 @@ pub fn graph_is_isomorphic(
     edge_matcher: Option<PyObject>,
     id_order: bool,
     call_limit: Option<usize>,
+    progress_fn: Option<PyObject>,
+    progress_interval: Option<usize>,
 ) -> PyResult<bool> {
     vf2::is_isomorphic(
         py,
@@ -146,6 +172,7 @@ pub fn graph_is_isomorphic(
         Ordering::Equal,
         true,
         call_limit,
+        ProgressFn::new(progress_fn, progress_interval)?,
     )
 }
 
@@ -188,15 +215,21 @@ pub fn graph_is_isomorphic(
 /// :param int call_limit: An optional bound on the number of states that VF2 algorithm
 ///     visits while searching for a solution. If it exceeds this limit, the algorithm
 ///     will stop and return ``False``.
+/// :param progress_fn: An optional callable which is called as
+///     ``progress_fn(states, None)`` after every ``progress_interval`` states
+///     the VF2 algorithm visits. An exception raised by it stops the search
+///     and is raised from this function.
+/// :param int progress_interval: The number of states between the calls to
+///     ``progress_fn``. Defaults to 1000.
 ///
 /// :returns: ``True`` if there is a subgraph of `first` isomorphic to `second`,
 ///     ``False`` if there is not.
 /// :rtype: bool
 #[pyfunction]
 #[pyo3(
-    signature=(first, second, node_matcher=None, edge_matcher=None, id_order=false, induced=true, call_limit=None),
+    signature=(first, second, node_matcher=None, edge_matcher=None, id_order=false, induced=true, call_limit=None, progress_fn=None, progress_interval=None),
     text_signature = "(first, second, /, node_matcher=None, edge_matcher=None,
-                    id_order=False, induced=True, call_limit=None)"
+                    id_order=False, induced=True, call_limit=None, progress_fn=None, progress_interval=None)"
 )]
 pub fn digraph_is_subgraph_isomorphic(
     py: Python,
@@ -207,6 +240,8 @@ pub fn digraph_is_subgraph_isomorphic(
     id_order: bool,
     induced: bool,
     call_limit: Option<usize>,
+    progress_fn: Option<PyObject>,
+    progress_interval: Option<usize>,
 ) -> PyResult<bool> {
     vf2::is_isomorphic(
         py,
@@ -218,6 +253,7 @@ pub fn digraph_is_subgraph_isomorphic(
         Ordering::Greater,
         induced,
         call_limit,
+        ProgressFn::new(progress_fn, progress_interval)?,
     )
 }
 
@@ -260,15 +296,21 @@ pub fn digraph_is_subgraph_isomorphic(
 /// :param int call_limit: An optional bound on the number of states that VF2 algorithm
 ///     visits while searching for a solution. If it exceeds this limit, the algorithm
 ///     will stop and return ``False``.
+/// :param progress_fn: An optional callable which is called as
+///     ``progress_fn(states, None)`` after every ``progress_interval`` states
+///     the VF2 algorithm visits. An exception raised by it stops the search
+///     and is raised from this function.
+/// :param int progress_interval: The number of states between the calls to
+///     ``progress_fn``. Defaults to 1000.
 ///
 /// :returns: ``True`` if there is a subgraph of `first` isomorphic to `second`,
 ///     ``False`` if there is not.
 /// :rtype: bool
 #[pyfunction]
 #[pyo3(
-    signature=(first, second, node_matcher=None, edge_matcher=None, id_order=false, induced=true, call_limit=None),
+    signature=(first, second, node_matcher=None, edge_matcher=None, id_order=false, induced=true, call_limit=None, progress_fn=None, progress_interval=None),
     text_signature = "(first, second, /, node_matcher=None, edge_matcher=None,
-                    id_order=False, induced=True, call_limit=None)"
+                    id_order=False, induced=True, call_limit=None, progress_fn=None, progress_interval=None)"
 )]
 pub fn graph_is_subgraph_isomorphic(
     py: Python,
@@ -279,6 +321,8 @@ pub fn graph_is_subgraph_isomorphic(
     id_order: bool,
     induced: bool,
     call_limit: Option<usize>,
+    progress_fn: Option<PyObject>,
+    progress_interval: Option<usize>,
 ) -> PyResult<bool> {
     vf2::is_isomorphic(
         py,
@@ -290,6 +334,7 @@ pub fn graph_is_subgraph_isomorphic(
         Ordering::Greater,
         induced,
         call_limit,
+        ProgressFn::new(progress_fn, progress_interval)?,
     )
 }
 
@@ -332,15 +377,21 @@ pub fn graph_is_subgraph_isomorphic(
 /// :param int call_limit: An optional bound on the number of states that VF2 algorithm
 ///     visits while searching for a solution. If it exceeds this limit, the algorithm
 ///     will stop.
+/// :param progress_fn: An optional callable which is called as
+///     ``progress_fn(states, None)`` after every ``progress_interval`` states
+///     the VF2 algorithm visits. An exception raised by it stops the search
+///     and is raised from this function.
+/// :param int progress_interval: The number of states between the calls to
+///     ``progress_fn``. Defaults to 1000.
 ///
 /// :returns: An iterator over dicitonaries of node indices from ``first`` to node
 ///     indices in ``second`` representing the mapping found.
 /// :rtype: Iterable[NodeMap]
 #[pyfunction]
 #[pyo3(
-    signature=(first, second, node_matcher=None, edge_matcher=None, id_order=true, subgraph=false, induced=true, call_limit=None),
+    signature=(first, second, node_matcher=None, edge_matcher=None, id_order=true, subgraph=false, induced=true, call_limit=None, progress_fn=None, progress_interval=None),
     text_signature = "(first, second, /, node_matcher=None, edge_matcher=None,
-                    id_order=True, subgraph=False, induced=True, call_limit=None)"
+                    id_order=True, subgraph=False, induced=True, call_limit=None, progress_fn=None, progress_interval=None)"
 )]
 pub fn digraph_vf2_mapping(
     py: Python,
@@ -352,14 +403,16 @@ pub fn digraph_vf2_mapping(
     subgraph: bool,
     induced: bool,
     call_limit: Option<usize>,
-) -> vf2::DiGraphVf2Mapping {
+    progress_fn: Option<PyObject>,
+    progress_interval: Option<usize>,
+) -> PyResult<vf2::DiGraphVf2Mapping> {
     let ordering = if subgraph {
         Ordering::Greater
     } else {
         Ordering::Equal
     };
 
-    vf2::DiGraphVf2Mapping::new(
+    Ok(vf2::DiGraphVf2Mapping::new(
         py,
         &first.graph,
         &second.graph,
@@ -369,7 +422,8 @@ pub fn digraph_vf2_mapping(
         ordering,
         induced,
         call_limit,
-    )
+        ProgressFn::new(progress_fn, progress_interval)?,
+    ))
 }
 
 /// Return an iterator over all vf2 mappings between two :class:`~rustworkx.PyGraph` objects
@@ -410,15 +464,21 @@ pub fn digraph_vf2_mapping(
 /// :param int call_limit: An optional bound on the number of states that VF2 algorithm
 ///     visits while searching for a solution. If it exceeds this limit, the algorithm
 ///     will stop. Default: ``None``.
+/// :param progress_fn: An optional callable which is called as
+///     ``progress_fn(states, None)`` after every ``progress_interval`` states
+///     the VF2 algorithm visits. An exception raised by it stops the search
+///     and is raised from this function.
+/// :param int progress_interval: The number of states between the calls to
+///     ``progress_fn``. Defaults to 1000.
 ///
 /// :returns: An iterator over dicitonaries of node indices from ``first`` to node
 ///     indices in ``second`` representing the mapping found.
 /// :rtype: Iterable[NodeMap]
 #[pyfunction]
 #[pyo3(
-    signature=(first, second, node_matcher=None, edge_matcher=None, id_order=true, subgraph=false, induced=true, call_limit=None),
+    signature=(first, second, node_matcher=None, edge_matcher=None, id_order=true, subgraph=false, induced=true, call_limit=None, progress_fn=None, progress_interval=None),
     text_signature = "(first, second, /, node_matcher=None, edge_matcher=None,
-                    id_order=True, subgraph=False, induced=True, call_limit=None)"
+                    id_order=True, subgraph=False, induced=True, call_limit=None, progress_fn=None, progress_interval=None)"
 )]
 pub fn graph_vf2_mapping(
     py: Python,
@@ -430,14 +490,16 @@ pub fn graph_vf2_mapping(
     subgraph: bool,
     induced: bool,
     call_limit: Option<usize>,
-) -> vf2::GraphVf2Mapping {
+    progress_fn: Option<PyObject>,
+    progress_interval: Option<usize>,
+) -> PyResult<vf2::GraphVf2Mapping> {
     let ordering = if subgraph {
         Ordering::Greater
     } else {
         Ordering::Equal
     };
 
-    vf2::GraphVf2Mapping::new(
+    Ok(vf2::GraphVf2Mapping::new(
         py,
         &first.graph,
         &second.graph,
@@ -447,5 +509,306 @@ pub fn graph_vf2_mapping(
         ordering,
         induced,
         call_limit,
+        ProgressFn::new(progress_fn, progress_interval)?,
+    ))
+}
+
+fn invariant_name(invariant: Invariant) -> &'static str {
+    match invariant {
+        Invariant::NodeCount => "node_count",
+        Invariant::EdgeCount => "edge_count",
+        Invariant::DegreeSequence => "degree_sequence",
+        Invariant::TriangleCounts => "triangle_counts",
+        Invariant::WeisfeilerLehmanColors => "weisfeiler_lehman_colors",
+    }
+}
+
+/// Find an isomorphism invariant that differs between 2 directed graphs
+///
+/// The invariants are compared from the cheapest to the most expensive and
+/// the first one that differs is returned, which proves that the graphs
+/// aren't isomorphic. The invariants are, in order:
+///
+/// * ``"node_count"``: the number of nodes.
+/// * ``"edge_count"``: the number of edges.
+/// * ``"degree_sequence"``: the sorted (out-degree, in-degree) pairs of the
+///   nodes.
+/// * ``"triangle_counts"``: the sorted number of triangles through every
+///   node, ignoring the direction of the edges.
+/// * ``"weisfeiler_lehman_colors"``: the colors of the nodes after up to
+///   ``wl_iterations`` rounds of Weisfeiler-Lehman color refinement.
+///
+/// :func:`~rustworkx.is_isomorphic` and its variants check these invariants
+/// before running VF2. The node and edge data are ignored.
+///
+/// :param PyDiGraph first: The first graph to compare.
+/// :param PyDiGraph second: The second graph to compare.
+/// :param int wl_iterations: The largest number of Weisfeiler-Lehman rounds.
+///     Defaults to 3.
+///
+/// :returns: The name of the first invariant that differs, or ``None`` if
+///     the graphs agree on all of them and may be isomorphic.
+/// :rtype: str
+#[pyfunction]
+#[pyo3(
+    signature=(first, second, wl_iterations=3),
+    text_signature = "(first, second, /, wl_iterations=3)"
+)]
+pub fn digraph_distinguishing_invariant(
+    first: &digraph::PyDiGraph,
+    second: &digraph::PyDiGraph,
+    wl_iterations: usize,
+) -> Option<&'static str> {
+    distinguishing_invariant(&first.graph, &second.graph, wl_iterations).map(invariant_name)
+}
+
+/// Find an isomorphism invariant that differs between 2 undirected graphs
+///
+/// The invariants are compared from the cheapest to the most expensive and
+/// the first one that differs is returned, which proves that the graphs
+/// aren't isomorphic. The invariants are, in order:
+///
+/// * ``"node_count"``: the number of nodes.
+/// * ``"edge_count"``: the number of edges.
+/// * ``"degree_sequence"``: the sorted degrees of the nodes, given as
+///   (degree, degree) pairs.
+/// * ``"triangle_counts"``: the sorted number of triangles through every
+///   node.
+/// * ``"weisfeiler_lehman_colors"``: the colors of the nodes after up to
+///   ``wl_iterations`` rounds of Weisfeiler-Lehman color refinement.
+///
+/// :func:`~rustworkx.is_isomorphic` and its variants check these invariants
+/// before running VF2. The node and edge data are ignored.
+///
+/// :param PyGraph first: The first graph to compare.
+/// :param PyGraph second: The second graph to compare.
+/// :param int wl_iterations: The largest number of Weisfeiler-Lehman rounds.
+///     Defaults to 3.
+///
+/// :returns: The name of the first invariant that differs, or ``None`` if
+///     the graphs agree on all of them and may be isomorphic.
+/// :rtype: str
+#[pyfunction]
+#[pyo3(
+    signature=(first, second, wl_iterations=3),
+    text_signature = "(first, second, /, wl_iterations=3)"
+)]
+pub fn graph_distinguishing_invariant(
+    first: &graph::PyGraph,
+    second: &graph::PyGraph,
+    wl_iterations: usize,
+) -> Option<&'static str> {
+    distinguishing_invariant(&first.graph, &second.graph, wl_iterations).map(invariant_name)
+}
+
+fn canonical_hash_inner<Ty: EdgeType>(
+    py: Python,
+    graph: &StablePyGraph<Ty>,
+    node_attr_fn: Option<PyObject>,
+    edge_attr_fn: Option<PyObject>,
+) -> PyResult<String> {
+    // The labels go through str() and not hash(), which is randomized for
+    // strings between Python processes
+    let label = |attr_fn: &Option<PyObject>, weight: &PyObject| -> PyResult<String> {
+        match attr_fn {
+            Some(attr_fn) => Ok(format!("{}", attr_fn.bind(py).call1((weight,))?.str()?)),
+            None => Ok(String::new()),
+        }
+    };
+    let hash = canonical_hash(
+        graph,
+        |node| label(&node_attr_fn, &graph[node]),
+        |edge| label(&edge_attr_fn, edge.weight()),
+    )?;
+    Ok(format!("{:032x}", hash))
+}
+
+/// Compute a hash of a directed graph that doesn't depend on its node indices
+///
+/// The graph is relabeled by a canonical labeling, which orders the nodes
+/// the same way for every graph isomorphic to it, and the result is hashed
+/// with the 128-bit FNV-1a hash function. Isomorphic graphs always have the
+/// same hash and graphs that aren't isomorphic only have the same hash in
+/// the unlikely case of a hash collision. The hash doesn't depend on the
+/// order the nodes and edges were added in, the platform or the Python
+/// process, so it can be used as a key to cache results computed for the
+/// graph, such as on disk.
+///
+/// The canonical labeling is computed with Weisfeiler-Lehman color
+/// refinement and a search over the ways of breaking the ties it leaves,
+/// pruned by the automorphisms of the graph. This is fast for most graphs
+/// but can take exponential time for some highly symmetric ones.
+///
+/// :param PyDiGraph graph: The graph to hash.
+/// :param node_attr_fn: An optional callable that will be passed the data of
+///     each node and returns its label. The label is converted with
+///     ``str()``, so its string representation has to be deterministic. If
+///     not specified the node data are ignored.
+/// :param edge_attr_fn: An optional callable that will be passed the data of
+///     each edge and returns its label. The label is converted with
+///     ``str()``, so its string representation has to be deterministic. If
+///     not specified the edge data are ignored.
+///
+/// :returns: The hash as 32 hexadecimal digits.
+/// :rtype: str
+#[pyfunction]
+#[pyo3(
+    signature=(graph, /, node_attr_fn=None, edge_attr_fn=None),
+    text_signature = "(graph, /, node_attr_fn=None, edge_attr_fn=None)"
+)]
+pub fn digraph_canonical_hash(
+    py: Python,
+    graph: &digraph::PyDiGraph,
+    node_attr_fn: Option<PyObject>,
+    edge_attr_fn: Option<PyObject>,
+) -> PyResult<String> {
+    canonical_hash_inner(py, &graph.graph, node_attr_fn, edge_attr_fn)
+}
+
+/// Compute a hash of an undirected graph that doesn't depend on its node
+/// indices
+///
+/// The graph is relabeled by a canonical labeling, which orders the nodes
+/// the same way for every graph isomorphic to it, and the result is hashed
+/// with the 128-bit FNV-1a hash function. Isomorphic graphs always have the
+/// same hash and graphs that aren't isomorphic only have the same hash in
+/// the unlikely case of a hash collision. The hash doesn't depend on the
+/// order the nodes and edges were added in, the direction edges were added
+/// with, the platform or the Python process, so it can be used as a key to
+/// cache results computed for the graph, such as on disk.
+///
+/// The canonical labeling is computed with Weisfeiler-Lehman color
+/// refinement and a search over the ways of breaking the ties it leaves,
+/// pruned by the automorphisms of the graph. This is fast for most graphs
+/// but can take exponential time for some highly symmetric ones.
+///
+/// :param PyGraph graph: The graph to hash.
+/// :param node_attr_fn: An optional callable that will be passed the data of
+///     each node and returns its label. The label is converted with
+///     ``str()``, so its string representation has to be deterministic. If
+///     not specified the node data are ignored.
+/// :param edge_attr_fn: An optional callable that will be passed the data of
+///     each edge and returns its label. The label is converted with
+///     ``str()``, so its string representation has to be deterministic. If
+///     not specified the edge data are ignored.
+///
+/// :returns: The hash as 32 hexadecimal digits.
+/// :rtype: str
+#[pyfunction]
+#[pyo3(
+    signature=(graph, /, node_attr_fn=None, edge_attr_fn=None),
+    text_signature = "(graph, /, node_attr_fn=None, edge_attr_fn=None)"
+)]
+pub fn graph_canonical_hash(
+    py: Python,
+    graph: &graph::PyGraph,
+    node_attr_fn: Option<PyObject>,
+    edge_attr_fn: Option<PyObject>,
+) -> PyResult<String> {
+    canonical_hash_inner(py, &graph.graph, node_attr_fn, edge_attr_fn)
+}
+
+/// Replace every occurrence of a pattern in a directed graph with a
+/// replacement graph
+///
+/// The occurrences of ``pattern`` are found with the same VF2 subgraph
+/// isomorphism search used by :func:`~rustworkx.digraph_vf2_mapping`, all on
+/// the graph as it was before the call. They are applied greedily in the order
+/// they are found, skipping any that share a node with an occurrence that was
+/// already accepted, so all the rewrites in one call are non-overlapping.
+///
+/// For each accepted occurrence a copy of ``replacement`` is added to the
+/// graph and the matched nodes are removed, along with every edge incident to
+/// them. The ``interface`` maps pattern nodes to replacement nodes: the edges
+/// between a matched node in the interface and the rest of the graph are
+/// reconnected to the corresponding new node, keeping their direction and
+/// data. Edges to the rest of the graph from matched nodes not in the
+/// interface are dropped.
+///
+/// For example, to contract every edge ``"a" -> "b"`` into a single node
+/// ``"ab"`` while keeping the edges of both ends::
+///
+///     pattern = rustworkx.PyDiGraph()
+///     pattern.add_nodes_from(["a", "b"])
+///     pattern.add_edge(0, 1, None)
+///     replacement = rustworkx.PyDiGraph()
+///     replacement.add_node("ab")
+///     rustworkx.digraph_rewrite(
+///         graph, pattern, replacement, interface={0: 0, 1: 0},
+///         node_matcher=lambda x, y: x == y,
+///     )
+///
+/// All the callbacks run before the graph is modified, so if one of them
+/// raises an exception the graph is left unchanged.
+///
+/// :param PyDiGraph graph: The graph to rewrite in place.
+/// :param PyDiGraph pattern: The graph to search for. It must have at least
+///     one node.
+/// :param PyDiGraph replacement: The graph each occurrence of ``pattern`` is
+///     replaced with. It can be empty, which deletes every occurrence.
+/// :param dict interface: An optional dictionary mapping node indices in
+///     ``pattern`` to node indices in ``replacement``. If not specified no
+///     edges to the rest of the graph are kept.
+/// :param node_matcher: An optional callable that will be passed the data of
+///     a node in ``graph`` and of a node in ``pattern`` and returns ``True``
+///     if they match. If not specified any nodes match.
+/// :param edge_matcher: An optional callable that will be passed the data of
+///     an edge in ``graph`` and of an edge in ``pattern`` and returns
+///     ``True`` if they match. If not specified any edges match.
+/// :param node_fn: An optional callable that will be passed the data of a
+///     node in ``replacement`` and a dictionary mapping the node indices of
+///     ``pattern`` to the data of the matched nodes, and returns the data for
+///     the new node. If not specified the data of the replacement node is
+///     used.
+/// :param edge_fn: An optional callable with the same arguments as
+///     ``node_fn`` that returns the data for a new edge from the data of an
+///     edge in ``replacement``. If not specified the data of the replacement
+///     edge is used.
+/// :param bool induced: If set to ``True`` (the default) an occurrence can't
+///     have edges between the matched nodes that aren't in ``pattern``. If
+///     set to ``False`` such edges are allowed and are removed with the
+///     matched nodes.
+///
+/// :returns: A list with an entry for each rewrite applied, in the order they
+///     were applied. Each entry is a tuple of a mapping of the node indices
+///     of ``pattern`` to the indices of the nodes they matched (which are no
+///     longer in the graph) and a mapping of the node indices of
+///     ``replacement`` to the indices of the nodes added for them.
+/// :rtype: list[tuple[NodeMap, NodeMap]]
+///
+/// :raises InvalidNode: If a key of ``interface`` is not in ``pattern`` or a
+///     value is not in ``replacement``.
+/// :raises DAGWouldCycle: If ``check_cycle`` is enabled on ``graph`` and the
+///     rewrite would add a cycle. The graph is left unchanged.
+#[pyfunction]
+#[pyo3(
+    signature=(graph, pattern, replacement, /, interface=None, node_matcher=None,
+               edge_matcher=None, node_fn=None, edge_fn=None, induced=true),
+    text_signature = "(graph, pattern, replacement, /, interface=None, node_matcher=None,
+                     edge_matcher=None, node_fn=None, edge_fn=None, induced=True)"
+)]
+pub fn digraph_rewrite(
+    py: Python,
+    graph: &mut digraph::PyDiGraph,
+    pattern: &digraph::PyDiGraph,
+    replacement: &digraph::PyDiGraph,
+    interface: Option<HashMap<usize, usize>>,
+    node_matcher: Option<PyObject>,
+    edge_matcher: Option<PyObject>,
+    node_fn: Option<PyObject>,
+    edge_fn: Option<PyObject>,
+    induced: bool,
+) -> PyResult<Vec<(NodeMap, NodeMap)>> {
+    rewrite::rewrite(
+        py,
+        graph,
+        pattern,
+        replacement,
+        interface.unwrap_or_default(),
+        node_matcher,
+        edge_matcher,
+        node_fn,
+        edge_fn,
+        induced,
     )
 }
@@ -0,0 +1,236 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+use std::cmp::Ordering;
+
+use hashbrown::{HashMap, HashSet};
+use petgraph::algo;
+use petgraph::stable_graph::NodeIndex;
+use petgraph::visit::{EdgeRef, IntoEdgeReferences};
+use petgraph::Direction::{Incoming, Outgoing};
+
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+use pyo3::Python;
+
+use rustworkx_core::dictmap::*;
+
+use super::vf2::DiGraphVf2Mapping;
+use crate::iterators::NodeMap;
+use crate::progress::ProgressFn;
+use crate::{digraph, DAGWouldCycle, InvalidNode};
+
+/// The payloads of the nodes and edges of the replacement added for a
+/// match, in the order of the replacement's node and edge indices.
+struct ReplacementPayloads {
+    nodes: Vec<PyObject>,
+    edges: Vec<PyObject>,
+}
+
+fn check_interface(
+    pattern: &digraph::PyDiGraph,
+    replacement: &digraph::PyDiGraph,
+    interface: &HashMap<usize, usize>,
+) -> PyResult<()> {
+    for (&pattern_node, &replacement_node) in interface {
+        if !pattern.graph.contains_node(NodeIndex::new(pattern_node)) {
+            return Err(InvalidNode::new_err(format!(
+                "Node index {} is not in the pattern",
+                pattern_node
+            )));
+        }
+        if !replacement
+            .graph
+            .contains_node(NodeIndex::new(replacement_node))
+        {
+            return Err(InvalidNode::new_err(format!(
+                "Node index {} is not in the replacement",
+                replacement_node
+            )));
+        }
+    }
+    Ok(())
+}
+
+/// Find the matches of ``pattern`` in ``graph`` that don't share a node with
+/// an earlier match, as maps of pattern nodes to graph nodes.
+fn find_matches(
+    py: Python,
+    graph: &digraph::PyDiGraph,
+    pattern: &digraph::PyDiGraph,
+    node_matcher: Option<PyObject>,
+    edge_matcher: Option<PyObject>,
+    induced: bool,
+) -> PyResult<Vec<DictMap<usize, usize>>> {
+    let mut vf2 = DiGraphVf2Mapping::new(
+        py,
+        &graph.graph,
+        &pattern.graph,
+        node_matcher,
+        edge_matcher,
+        true,
+        Ordering::Greater,
+        induced,
+        None,
+        ProgressFn::new(None, None)?,
+    );
+    let mut used: HashSet<usize> = HashSet::new();
+    let mut matches: Vec<DictMap<usize, usize>> = Vec::new();
+    while let Some(mapping) = vf2.next_mapping(py)? {
+        if mapping.node_map.keys().any(|node| used.contains(node)) {
+            continue;
+        }
+        used.extend(mapping.node_map.keys().copied());
+        let mut matched: Vec<(usize, usize)> = mapping
+            .node_map
+            .into_iter()
+            .map(|(node, pattern_node)| (pattern_node, node))
+            .collect();
+        matched.sort_unstable();
+        matches.push(matched.into_iter().collect());
+    }
+    Ok(matches)
+}
+
+fn replacement_payloads(
+    py: Python,
+    graph: &digraph::PyDiGraph,
+    replacement: &digraph::PyDiGraph,
+    matched: &DictMap<usize, usize>,
+    node_fn: &Option<PyObject>,
+    edge_fn: &Option<PyObject>,
+) -> PyResult<ReplacementPayloads> {
+    let matched_weights = PyDict::new_bound(py);
+    for (&pattern_node, &node) in matched {
+        matched_weights.set_item(pattern_node, &graph.graph[NodeIndex::new(node)])?;
+    }
+    let payload = |func: &Option<PyObject>, weight: &PyObject| -> PyResult<PyObject> {
+        match func {
+            Some(func) => func.call1(py, (weight, &matched_weights)),
+            None => Ok(weight.clone_ref(py)),
+        }
+    };
+    let nodes = replacement
+        .graph
+        .node_weights()
+        .map(|weight| payload(node_fn, weight))
+        .collect::<PyResult<Vec<PyObject>>>()?;
+    let edges = replacement
+        .graph
+        .edge_weights()
+        .map(|weight| payload(edge_fn, weight))
+        .collect::<PyResult<Vec<PyObject>>>()?;
+    Ok(ReplacementPayloads { nodes, edges })
+}
+
+/// Replace the nodes of ``matched`` with a copy of ``replacement`` and
+/// reconnect the edges to the rest of the graph through ``interface``.
+fn apply_match(
+    py: Python,
+    graph: &mut digraph::PyDiGraph,
+    replacement: &digraph::PyDiGraph,
+    interface: &HashMap<usize, usize>,
+    matched: &DictMap<usize, usize>,
+    payloads: ReplacementPayloads,
+) -> NodeMap {
+    let mut node_map: DictMap<usize, usize> =
+        DictMap::with_capacity(replacement.graph.node_count());
+    for (node, weight) in replacement.graph.node_indices().zip(payloads.nodes) {
+        node_map.insert(node.index(), graph.graph.add_node(weight).index());
+    }
+    for (edge, weight) in replacement.graph.edge_references().zip(payloads.edges) {
+        graph.graph.add_edge(
+            NodeIndex::new(node_map[&edge.source().index()]),
+            NodeIndex::new(node_map[&edge.target().index()]),
+            weight,
+        );
+    }
+
+    let matched_nodes: HashSet<NodeIndex> = matched.values().map(|n| NodeIndex::new(*n)).collect();
+    let mut boundary_edges: Vec<(NodeIndex, NodeIndex, PyObject)> = Vec::new();
+    for (pattern_node, &node) in matched {
+        let Some(replacement_node) = interface.get(pattern_node) else {
+            continue;
+        };
+        let node = NodeIndex::new(node);
+        let new_node = NodeIndex::new(node_map[replacement_node]);
+        for edge in graph.graph.edges_directed(node, Outgoing) {
+            if !matched_nodes.contains(&edge.target()) {
+                boundary_edges.push((new_node, edge.target(), edge.weight().clone_ref(py)));
+            }
+        }
+        for edge in graph.graph.edges_directed(node, Incoming) {
+            if !matched_nodes.contains(&edge.source()) {
+                boundary_edges.push((edge.source(), new_node, edge.weight().clone_ref(py)));
+            }
+        }
+    }
+    for (source, target, weight) in boundary_edges {
+        graph.graph.add_edge(source, target, weight);
+    }
+    for node in matched_nodes {
+        graph.graph.remove_node(node);
+    }
+    NodeMap { node_map }
+}
+
+pub fn rewrite(
+    py: Python,
+    graph: &mut digraph::PyDiGraph,
+    pattern: &digraph::PyDiGraph,
+    replacement: &digraph::PyDiGraph,
+    interface: HashMap<usize, usize>,
+    node_matcher: Option<PyObject>,
+    edge_matcher: Option<PyObject>,
+    node_fn: Option<PyObject>,
+    edge_fn: Option<PyObject>,
+    induced: bool,
+) -> PyResult<Vec<(NodeMap, NodeMap)>> {
+    if pattern.graph.node_count() == 0 {
+        return Err(PyValueError::new_err(
+            "The pattern must have at least one node",
+        ));
+    }
+    check_interface(pattern, replacement, &interface)?;
+    let matches = find_matches(py, graph, pattern, node_matcher, edge_matcher, induced)?;
+    // The callbacks are run before the graph is modified, so an exception
+    // raised by one of them leaves the graph unchanged
+    let payloads = matches
+        .iter()
+        .map(|matched| replacement_payloads(py, graph, replacement, matched, &node_fn, &edge_fn))
+        .collect::<PyResult<Vec<ReplacementPayloads>>>()?;
+    if matches.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let original = if graph.check_cycle {
+        Some(graph.graph.clone())
+    } else {
+        None
+    };
+    let mut applied: Vec<(NodeMap, NodeMap)> = Vec::with_capacity(matches.len());
+    for (matched, payloads) in matches.into_iter().zip(payloads) {
+        let node_map = apply_match(py, graph, replacement, &interface, &matched, payloads);
+        applied.push((NodeMap { node_map: matched }, node_map));
+    }
+    graph.node_removed = true;
+    if let Some(original) = original {
+        if algo::is_cyclic_directed(&graph.graph) {
+            graph.graph = original;
+            return Err(DAGWouldCycle::new_err(
+                "Applying the rewrite would add a cycle to the graph",
+            ));
+        }
+    }
+    Ok(applied)
+}
@@ -21,6 +21,7 @@ use std::marker;
 
 use hashbrown::HashMap;
 use rustworkx_core::dictmap::*;
+use rustworkx_core::invariants::distinguishing_invariant;
 
 use pyo3::gc::PyVisit;
 use pyo3::prelude::*;
@@ -34,8 +35,13 @@ use petgraph::{Directed, Incoming, Outgoing, Undirected};
 use rayon::slice::ParallelSliceMut;
 
 use crate::iterators::NodeMap;
+use crate::progress::ProgressFn;
 use crate::StablePyGraph;
 
+/// The number of Weisfeiler-Lehman rounds used to rule out an isomorphism
+/// before running VF2.
+const PRESCREEN_WL_ITERATIONS: usize = 3;
+
 /// Returns `true` if we can map every element of `xs` to a unique
 /// element of `ys` while using `matcher` func to compare two elements.
 fn is_subset<T: Copy, F>(xs: &[T], ys: &[T], matcher: F) -> PyResult<bool>
@@ -422,6 +428,9 @@ impl SemanticMatcher<PyObject> for Option<PyObject> {
 /// graph isomorphism (graph structure and matching node and edge weights).
 ///
 /// The graphs should not be multigraphs.
+///
+/// When checking for an isomorphism, the isomorphism invariants of the
+/// graphs are compared first and VF2 is only run if they all agree.
 pub fn is_isomorphic<Ty: EdgeType>(
     py: Python,
     g0: &StablePyGraph<Ty>,
@@ -432,15 +441,21 @@ pub fn is_isomorphic<Ty: EdgeType>(
     ordering: Ordering,
     induced: bool,
     call_limit: Option<usize>,
+    progress: ProgressFn,
 ) -> PyResult<bool> {
     if (g0.node_count().cmp(&g1.node_count()).then(ordering) != ordering)
         || (g0.edge_count().cmp(&g1.edge_count()).then(ordering) != ordering)
     {
         return Ok(false);
     }
+    if ordering == Ordering::Equal
+        && distinguishing_invariant(g0, g1, PRESCREEN_WL_ITERATIONS).is_some()
+    {
+        return Ok(false);
+    }
 
     let mut vf2 = Vf2Algorithm::new(
-        py, g0, g1, node_match, edge_match, id_order, ordering, induced, call_limit,
+        py, g0, g1, node_match, edge_match, id_order, ordering, induced, call_limit, progress,
     );
     if vf2.next(py)?.is_some() {
         return Ok(true);
@@ -477,6 +492,7 @@ where
     node_map_g1: HashMap<usize, usize>,
     stack: Vec<Frame<NodeIndex>>,
     call_limit: Option<usize>,
+    progress: ProgressFn,
     _counter: usize,
 }
 
@@ -496,6 +512,7 @@ where
         ordering: Ordering,
         induced: bool,
         call_limit: Option<usize>,
+        progress: ProgressFn,
     ) -> Self {
         let (g0, node_map_g0) = if id_order {
             DefaultIdSorter.reorder(py, g0)
@@ -520,6 +537,7 @@ where
             node_map_g1,
             stack: vec![Frame::Outer],
             call_limit,
+            progress,
             _counter: 0,
         }
     }
@@ -946,6 +964,9 @@ where
                                     return Ok(None);
                                 }
                             }
+                            if self._counter % self.progress.interval(None) == 0 {
+                                self.progress.report(py, self._counter, None)?;
+                            }
                             let f0 = Frame::Unwind {
                                 nodes,
                                 open_list: ol,
@@ -992,12 +1013,18 @@ macro_rules! vf2_mapping_impl {
                 ordering: Ordering,
                 induced: bool,
                 call_limit: Option<usize>,
+                progress: ProgressFn,
             ) -> Self {
                 let vf2 = Vf2Algorithm::new(
                     py, g0, g1, node_match, edge_match, id_order, ordering, induced, call_limit,
+                    progress,
                 );
                 $name { vf2 }
             }
+
+            pub fn next_mapping(&mut self, py: Python) -> PyResult<Option<NodeMap>> {
+                self.vf2.next(py)
+            }
         }
 
         #[pymethods]
@@ -1007,10 +1034,7 @@ macro_rules! vf2_mapping_impl {
             }
 
             fn __next__(mut slf: PyRefMut<Self>) -> PyResult<Option<NodeMap>> {
-                Python::with_gil(|py| match slf.vf2.next(py)? {
-                    Some(mapping) => Ok(Some(mapping)),
-                    None => Ok(None),
-                })
+                Python::with_gil(|py| slf.next_mapping(py))
             }
 
             fn __traverse__(&self, visit: PyVisit) -> Result<(), PyTraverseError> {
@@ -1028,6 +1052,7 @@ macro_rules! vf2_mapping_impl {
                 if let Some(ref obj) = self.vf2.edge_match {
                     visit.call(obj)?;
                 }
+                self.vf2.progress.traverse(&visit)?;
                 Ok(())
             }
 
@@ -1036,6 +1061,7 @@ macro_rules! vf2_mapping_impl {
                 self.vf2.st[1].graph = StablePyGraph::<$Ty>::default();
                 self.vf2.node_match = None;
                 self.vf2.edge_match = None;
+                self.vf2.progress.clear();
             }
         }
     };
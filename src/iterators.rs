@@ -1950,6 +1950,31 @@ custom_hash_map_iter_impl!(
 );
 impl PyGCProtocol for NodeMap {}
 
+custom_hash_map_iter_impl!(
+    EdgeMap,
+    EdgeMapKeys,
+    EdgeMapValues,
+    EdgeMapItems,
+    edge_map,
+    edge_map_keys,
+    edge_map_values,
+    edge_map_items,
+    usize,
+    usize,
+    "A class representing a mapping of edge indices to edge indices
+
+     This class is equivalent to having a dict of the form::
+
+         {1: 0, 3: 1}
+
+    Unlike a dict though this class is unordered and multiple EdgeMap
+    objects with the same contents might yield a different order when
+    iterated over. If a consistent order is required you should sort
+    the object.
+    "
+);
+impl PyGCProtocol for EdgeMap {}
+
 custom_hash_map_iter_impl!(
     ProductNodeMap,
     ProductNodeMapKeys,
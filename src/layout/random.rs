@@ -14,6 +14,7 @@ use petgraph::EdgeType;
 
 use rand::prelude::*;
 use rand_pcg::Pcg64;
+use rustworkx_core::rng::rng_from_seed;
 
 use crate::iterators::Pos2DMapping;
 use crate::StablePyGraph;
@@ -23,10 +24,7 @@ pub fn random_layout<Ty: EdgeType>(
     center: Option<[f64; 2]>,
     seed: Option<u64>,
 ) -> Pos2DMapping {
-    let mut rng: Pcg64 = match seed {
-        Some(seed) => Pcg64::seed_from_u64(seed),
-        None => Pcg64::from_entropy(),
-    };
+    let mut rng: Pcg64 = rng_from_seed(seed);
 
     Pos2DMapping {
         pos_map: graph
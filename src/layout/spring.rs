@@ -26,8 +26,8 @@ use petgraph::visit::{IntoEdgeReferences, NodeIndexable};
 use petgraph::EdgeType;
 
 use rand::distributions::{Distribution, Uniform};
-use rand::prelude::*;
 use rand_pcg::Pcg64;
+use rustworkx_core::rng::rng_from_seed;
 
 use crate::StablePyGraph;
 
@@ -320,10 +320,7 @@ where
         return Err(PyValueError::new_err("`fixed` specified but `pos` not."));
     }
 
-    let mut rng: Pcg64 = match seed {
-        Some(seed) => Pcg64::seed_from_u64(seed),
-        None => Pcg64::from_entropy(),
-    };
+    let mut rng: Pcg64 = rng_from_seed(seed);
 
     let dist = Uniform::new(0.0, 1.0);
 
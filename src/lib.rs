@@ -10,70 +10,129 @@
 // License for the specific language governing permissions and limitations
 // under the License.
 
+mod assortativity;
+mod bipartite;
 mod bisimulation;
+mod boundary;
 mod cartesian_product;
 mod centrality;
+mod clustering;
 mod coloring;
+mod community;
 mod connectivity;
 mod dag_algo;
 mod digraph;
+mod distance;
 mod dot_utils;
+mod feedback;
 mod generators;
 mod graph;
+mod graph_product;
 mod graphml;
+mod hypergraph;
 mod isomorphism;
 mod iterators;
 mod json;
 mod layout;
 mod line_graph;
 mod link_analysis;
+mod link_prediction;
 mod matching;
+mod minor;
+mod modular_decomposition;
+mod motifs;
+mod parallel;
+mod percolation;
 mod planar;
+mod progress;
+mod quotient_graph;
 mod random_graph;
+mod rewire;
+mod rich_club;
+mod sampling;
 mod score;
+mod set_operations;
 mod shortest_path;
+mod similarity;
+mod small_world;
+mod spectral;
 mod steiner_tree;
+mod structural_roles;
+mod summary;
+mod svg;
+mod temporal;
 mod tensor_product;
 mod token_swapper;
 mod toposort;
 mod transitivity;
 mod traversal;
 mod tree;
+mod treewidth;
 mod union;
 
+use assortativity::*;
+use bipartite::*;
 use bisimulation::*;
+use boundary::*;
 use cartesian_product::*;
 use centrality::*;
+use clustering::*;
 use coloring::*;
+use community::*;
 use connectivity::*;
 use dag_algo::*;
+use distance::*;
+use feedback::*;
+use graph_product::*;
 use graphml::*;
 use isomorphism::*;
 use json::*;
 use layout::*;
 use line_graph::*;
 use link_analysis::*;
+use link_prediction::*;
 
 use matching::*;
+use minor::*;
+use modular_decomposition::*;
+use motifs::*;
+use parallel::*;
+use percolation::*;
 use planar::*;
+use quotient_graph::*;
 use random_graph::*;
+use rewire::*;
+use rich_club::*;
+use sampling::*;
+use set_operations::*;
 use shortest_path::*;
+use similarity::*;
+use small_world::*;
+use spectral::*;
 use steiner_tree::*;
+use structural_roles::*;
+use summary::*;
+use svg::*;
+use temporal::*;
 use tensor_product::*;
 use token_swapper::*;
 use transitivity::*;
 use traversal::*;
 use tree::*;
+use treewidth::*;
 use union::*;
 
 use hashbrown::HashMap;
 use numpy::Complex64;
+use numpy::PyReadonlyArray1;
 
 use pyo3::create_exception;
 use pyo3::exceptions::PyException;
 use pyo3::exceptions::PyValueError;
+use pyo3::exceptions::{PyIndexError, PyTypeError};
 use pyo3::import_exception;
 use pyo3::prelude::*;
+use pyo3::types::{PyList, PyTuple};
 use pyo3::wrap_pyfunction;
 use pyo3::wrap_pymodule;
 use pyo3::Python;
@@ -86,11 +145,13 @@ use petgraph::visit::{
 };
 use petgraph::EdgeType;
 
+use rustworkx_core::csr::CsrGraph;
 use rustworkx_core::dag_algo::TopologicalSortError;
-use std::convert::TryFrom;
+use rustworkx_core::graph_builder::EdgeStreamBuilder;
+use std::convert::{Infallible, TryFrom};
 
 use rustworkx_core::dictmap::*;
-use rustworkx_core::err::{ContractError, ContractSimpleError};
+use rustworkx_core::err::{ContractError, ContractSimpleError, EdgeStreamError};
 
 /// An ergonomic error type used to map Rustworkx core errors to
 /// [PyErr] automatically, via [From::from].
@@ -210,6 +271,22 @@ where
     }
 }
 
+/// The number of nodes from which algorithms that traverse a whole graph
+/// once, like single source Dijkstra without a goal or connected components,
+/// run on its compressed sparse row form. Below it building the copy costs more than the
+/// faster traversal saves.
+pub const CSR_NODE_THRESHOLD: usize = 10_000;
+
+/// Build the compressed sparse row form of the structure of a graph, for
+/// algorithms that traverse it many times, or once if it is large (see
+/// [`CSR_NODE_THRESHOLD`]). It keeps the node and edge indices of the graph.
+pub fn csr_structure<Ty: EdgeType>(graph: &StablePyGraph<Ty>) -> CsrGraph<(), Ty> {
+    match CsrGraph::from_graph(graph, |_| Ok::<(), Infallible>(())) {
+        Ok(csr) => csr,
+        Err(never) => match never {},
+    }
+}
+
 pub fn get_edge_iter_with_weights<G>(graph: G) -> impl Iterator<Item = (usize, usize, PyObject)>
 where
     G: GraphBase
@@ -280,6 +357,32 @@ where
     T: FromPyObject<'p> + Copy,
 {
     let mut edge_weights: Vec<Option<T>> = Vec::with_capacity(graph.edge_bound());
+    // Like with CostFn anything but a callable is expected to be a numpy
+    // array or sequence of weights indexed by edge index
+    if let Some(weights) = weight_fn.as_ref().map(|obj| obj.bind(py)) {
+        if !weights.is_callable() {
+            let weights: Vec<T> = weights.extract().map_err(|_| {
+                PyTypeError::new_err(
+                    "Expected a callable or a sequence of edge weights indexed by edge index",
+                )
+            })?;
+            for index in 0..=graph.edge_bound() {
+                if graph.edge_weight(EdgeIndex::new(index)).is_none() {
+                    edge_weights.push(None);
+                    continue;
+                }
+                match weights.get(index) {
+                    Some(weight) => edge_weights.push(Some(*weight)),
+                    None => {
+                        return Err(PyIndexError::new_err(format!(
+                            "No weight for edge index {index}"
+                        )))
+                    }
+                }
+            }
+            return Ok(edge_weights);
+        }
+    }
     for index in 0..=graph.edge_bound() {
         let raw_weight = graph.edge_weight(EdgeIndex::new(index));
         match raw_weight {
@@ -296,6 +399,86 @@ where
     Ok(edge_weights)
 }
 
+/// Build a graph from a Python iterable of ``(source, target)`` or
+/// ``(source, target, weight)`` tuples, pulling ``chunk_size`` edges from it
+/// at a time so the whole edge list is never in memory.
+pub fn graph_from_edge_iterator<Ty: EdgeType>(
+    py: Python,
+    edges: &Bound<PyAny>,
+    num_nodes: Option<usize>,
+    num_edges: Option<usize>,
+    multigraph: bool,
+    combine_fn: Option<PyObject>,
+    chunk_size: usize,
+) -> PyResult<StablePyGraph<Ty>> {
+    if chunk_size == 0 {
+        return Err(PyValueError::new_err("chunk_size must be positive"));
+    }
+    // The indices come from Python, so they are checked before nodes are
+    // added for them: against num_nodes if it's given, and otherwise against
+    // the largest node index of the graph
+    let node_limit = num_nodes.unwrap_or(NodeIndex::<u32>::end().index());
+    let mut builder: EdgeStreamBuilder<StablePyGraph<Ty>, _> =
+        EdgeStreamBuilder::new(num_nodes.unwrap_or(0), num_edges.unwrap_or(0), || py.None())
+            .node_limit(node_limit);
+    if !multigraph {
+        builder = builder.deduplicate();
+    }
+    let mut iter = edges.iter()?;
+    loop {
+        let mut chunk: Vec<(usize, usize, PyObject)> = Vec::with_capacity(chunk_size);
+        for item in iter.by_ref().take(chunk_size) {
+            let item = item?;
+            let edge = match item.len()? {
+                2 => (
+                    item.get_item(0)?.extract()?,
+                    item.get_item(1)?.extract()?,
+                    py.None(),
+                ),
+                3 => (
+                    item.get_item(0)?.extract()?,
+                    item.get_item(1)?.extract()?,
+                    item.get_item(2)?.unbind(),
+                ),
+                _ => {
+                    return Err(PyValueError::new_err(
+                        "Edges must be (source, target) or (source, target, weight) tuples",
+                    ))
+                }
+            };
+            chunk.push(edge);
+        }
+        if chunk.is_empty() {
+            break;
+        }
+        let res = builder.add_edges_with(chunk, |weight, new_weight| {
+            *weight = match &combine_fn {
+                Some(combine_fn) => combine_fn.call1(py, (weight.clone_ref(py), new_weight))?,
+                None => new_weight,
+            };
+            Ok::<(), PyErr>(())
+        });
+        match res {
+            Ok(()) => (),
+            Err(EdgeStreamError::NodeLimit(index)) => {
+                return Err(PyValueError::new_err(match num_nodes {
+                    Some(num_nodes) => format!(
+                        "Node index {} is out of range for a graph with {} nodes",
+                        index, num_nodes
+                    ),
+                    None => format!("Node index {} is too large", index),
+                }))
+            }
+            Err(EdgeStreamError::CombineError(err)) => return Err(err),
+        }
+    }
+    let mut graph = builder.finish();
+    while graph.node_count() < num_nodes.unwrap_or(0) {
+        graph.add_node(py.None());
+    }
+    Ok(graph)
+}
+
 #[inline]
 fn is_valid_weight(val: f64) -> PyResult<f64> {
     if val.is_sign_negative() {
@@ -312,11 +495,39 @@ fn is_valid_weight(val: f64) -> PyResult<f64> {
 pub enum CostFn {
     Default(f64),
     PyFunction(PyObject),
+    EdgeWeights(Vec<f64>),
 }
 
-impl From<PyObject> for CostFn {
-    fn from(obj: PyObject) -> Self {
-        CostFn::PyFunction(obj)
+impl TryFrom<PyObject> for CostFn {
+    type Error = PyErr;
+
+    // A callable is called with the edge payloads, anything else is expected to
+    // be a numpy array or sequence of weights indexed by edge index
+    fn try_from(obj: PyObject) -> Result<Self, Self::Error> {
+        Python::with_gil(|py| {
+            let bound = obj.bind(py);
+            if bound.is_callable() {
+                return Ok(CostFn::PyFunction(obj));
+            }
+            let weights: Vec<f64> = if bound.is_instance_of::<PyList>()
+                || bound.is_instance_of::<PyTuple>()
+            {
+                bound.extract()?
+            } else {
+                match bound.extract::<PyReadonlyArray1<f64>>() {
+                    Ok(array) => array.as_array().to_vec(),
+                    Err(_) => bound.extract().map_err(|_| {
+                        PyTypeError::new_err(
+                            "Expected a callable or a sequence of edge weights indexed by edge index",
+                        )
+                    })?,
+                }
+            };
+            for weight in &weights {
+                is_valid_weight(*weight)?;
+            }
+            Ok(CostFn::EdgeWeights(weights))
+        })
     }
 }
 
@@ -335,13 +546,32 @@ impl TryFrom<(Option<PyObject>, f64)> for CostFn {
     fn try_from(func_or_default: (Option<PyObject>, f64)) -> Result<Self, Self::Error> {
         let (obj, val) = func_or_default;
         match obj {
-            Some(obj) => Ok(CostFn::PyFunction(obj)),
+            Some(obj) => CostFn::try_from(obj),
             None => CostFn::try_from(val),
         }
     }
 }
 
 impl CostFn {
+    // Node costs are only computed by a callable, a sequence of weights is
+    // indexed by edge index and is rejected here instead of when it is first
+    // called with a node
+    fn try_from_node_fn(obj: Option<PyObject>, default: f64) -> PyResult<Self> {
+        match obj {
+            Some(obj) => Python::with_gil(|py| {
+                if obj.bind(py).is_callable() {
+                    Ok(CostFn::PyFunction(obj))
+                } else {
+                    Err(PyTypeError::new_err(
+                        "Expected a callable for the node weights, a sequence of \
+                        weights can only be used for edge costs",
+                    ))
+                }
+            }),
+            None => CostFn::try_from(default),
+        }
+    }
+
     fn call(&self, py: Python, arg: &PyObject) -> PyResult<f64> {
         match self {
             CostFn::Default(val) => Ok(*val),
@@ -350,6 +580,18 @@ impl CostFn {
                 let val: f64 = raw.extract(py)?;
                 is_valid_weight(val)
             }
+            CostFn::EdgeWeights(_) => Err(PyTypeError::new_err(
+                "A sequence of edge weights can only be used for edge costs",
+            )),
+        }
+    }
+
+    fn call_edge(&self, py: Python, edge: EdgeIndex, arg: &PyObject) -> PyResult<f64> {
+        match self {
+            CostFn::EdgeWeights(weights) => weights.get(edge.index()).copied().ok_or_else(|| {
+                PyIndexError::new_err(format!("No weight for edge index {}", edge.index()))
+            }),
+            _ => self.call(py, arg),
         }
     }
 }
@@ -427,7 +669,13 @@ fn rustworkx(py: Python<'_>, m: &Bound<PyModule>) -> PyResult<()> {
         "JSONSerializationError",
         py.get_type_bound::<JSONSerializationError>(),
     )?;
-    m.add("FailedToConverge", py.get_type_bound::<FailedToConverge>())?;
+    let failed_to_converge = py.get_type_bound::<FailedToConverge>();
+    // The iterative algorithms set these on the exceptions they raise when they
+    // have a partial result, the class attributes are the defaults otherwise
+    failed_to_converge.setattr("partial_result", py.None())?;
+    failed_to_converge.setattr("iterations", py.None())?;
+    failed_to_converge.setattr("residual", py.None())?;
+    m.add("FailedToConverge", failed_to_converge)?;
     m.add(
         "GraphNotBipartite",
         py.get_type_bound::<GraphNotBipartite>(),
@@ -446,7 +694,11 @@ fn rustworkx(py: Python<'_>, m: &Bound<PyModule>) -> PyResult<()> {
     m.add_wrapped(wrap_pyfunction!(dag_longest_path_length))?;
     m.add_wrapped(wrap_pyfunction!(dag_weighted_longest_path))?;
     m.add_wrapped(wrap_pyfunction!(dag_weighted_longest_path_length))?;
+    m.add_wrapped(wrap_pyfunction!(dag_critical_path))?;
     m.add_wrapped(wrap_pyfunction!(transitive_reduction))?;
+    m.add_wrapped(wrap_pyfunction!(transitive_closure_dag))?;
+    m.add_wrapped(wrap_pyfunction!(dag_minimum_chain_decomposition))?;
+    m.add_wrapped(wrap_pyfunction!(dag_maximum_antichain))?;
     m.add_wrapped(wrap_pyfunction!(number_connected_components))?;
     m.add_wrapped(wrap_pyfunction!(connected_components))?;
     m.add_wrapped(wrap_pyfunction!(is_connected))?;
@@ -458,16 +710,33 @@ fn rustworkx(py: Python<'_>, m: &Bound<PyModule>) -> PyResult<()> {
     m.add_wrapped(wrap_pyfunction!(is_directed_acyclic_graph))?;
     m.add_wrapped(wrap_pyfunction!(digraph_is_isomorphic))?;
     m.add_wrapped(wrap_pyfunction!(graph_is_isomorphic))?;
+    m.add_wrapped(wrap_pyfunction!(digraph_distinguishing_invariant))?;
+    m.add_wrapped(wrap_pyfunction!(graph_distinguishing_invariant))?;
+    m.add_wrapped(wrap_pyfunction!(digraph_canonical_hash))?;
+    m.add_wrapped(wrap_pyfunction!(graph_canonical_hash))?;
+    m.add_wrapped(wrap_pyfunction!(digraph_rewrite))?;
     m.add_wrapped(wrap_pyfunction!(digraph_is_subgraph_isomorphic))?;
     m.add_wrapped(wrap_pyfunction!(graph_is_subgraph_isomorphic))?;
     m.add_wrapped(wrap_pyfunction!(digraph_vf2_mapping))?;
     m.add_wrapped(wrap_pyfunction!(graph_vf2_mapping))?;
     m.add_wrapped(wrap_pyfunction!(digraph_union))?;
+    m.add_wrapped(wrap_pyfunction!(digraph_union_with_mapping))?;
+    m.add_wrapped(wrap_pyfunction!(graph_intersection))?;
+    m.add_wrapped(wrap_pyfunction!(digraph_intersection))?;
+    m.add_wrapped(wrap_pyfunction!(graph_difference))?;
+    m.add_wrapped(wrap_pyfunction!(digraph_difference))?;
+    m.add_wrapped(wrap_pyfunction!(graph_symmetric_difference))?;
+    m.add_wrapped(wrap_pyfunction!(digraph_symmetric_difference))?;
+    m.add_wrapped(wrap_pyfunction!(graph_full_join))?;
+    m.add_wrapped(wrap_pyfunction!(digraph_full_join))?;
     m.add_wrapped(wrap_pyfunction!(graph_union))?;
+    m.add_wrapped(wrap_pyfunction!(graph_union_with_mapping))?;
     m.add_wrapped(wrap_pyfunction!(digraph_maximum_bisimulation))?;
     m.add_wrapped(wrap_pyfunction!(digraph_cartesian_product))?;
     m.add_wrapped(wrap_pyfunction!(graph_cartesian_product))?;
     m.add_wrapped(wrap_pyfunction!(topological_sort))?;
+    m.add_wrapped(wrap_pyfunction!(all_topological_sorts))?;
+    m.add_wrapped(wrap_pyfunction!(count_topological_sorts))?;
     m.add_wrapped(wrap_pyfunction!(topological_generations))?;
     m.add_wrapped(wrap_pyfunction!(descendants))?;
     m.add_wrapped(wrap_pyfunction!(ancestors))?;
@@ -488,6 +757,15 @@ fn rustworkx(py: Python<'_>, m: &Bound<PyModule>) -> PyResult<()> {
     m.add_wrapped(wrap_pyfunction!(graph_distance_matrix))?;
     m.add_wrapped(wrap_pyfunction!(digraph_distance_matrix))?;
     m.add_wrapped(wrap_pyfunction!(digraph_adjacency_matrix))?;
+    m.add_wrapped(wrap_pyfunction!(graph_laplacian_matrix))?;
+    m.add_wrapped(wrap_pyfunction!(digraph_laplacian_matrix))?;
+    m.add_wrapped(wrap_pyfunction!(graph_normalized_laplacian_matrix))?;
+    m.add_wrapped(wrap_pyfunction!(digraph_normalized_laplacian_matrix))?;
+    m.add_wrapped(wrap_pyfunction!(directed_laplacian_matrix))?;
+    m.add_wrapped(wrap_pyfunction!(algebraic_connectivity))?;
+    m.add_wrapped(wrap_pyfunction!(fiedler_vector))?;
+    m.add_wrapped(wrap_pyfunction!(spectral_partition))?;
+    m.add_wrapped(wrap_pyfunction!(spectral_sparsify))?;
     m.add_wrapped(wrap_pyfunction!(graph_adjacency_matrix))?;
     m.add_wrapped(wrap_pyfunction!(graph_all_pairs_all_simple_paths))?;
     m.add_wrapped(wrap_pyfunction!(digraph_all_pairs_all_simple_paths))?;
@@ -503,6 +781,8 @@ fn rustworkx(py: Python<'_>, m: &Bound<PyModule>) -> PyResult<()> {
     m.add_wrapped(wrap_pyfunction!(digraph_has_path))?;
     m.add_wrapped(wrap_pyfunction!(graph_dijkstra_shortest_path_lengths))?;
     m.add_wrapped(wrap_pyfunction!(digraph_dijkstra_shortest_path_lengths))?;
+    m.add_wrapped(wrap_pyfunction!(graph_voronoi_cells))?;
+    m.add_wrapped(wrap_pyfunction!(digraph_voronoi_cells))?;
     m.add_wrapped(wrap_pyfunction!(graph_bellman_ford_shortest_paths))?;
     m.add_wrapped(wrap_pyfunction!(digraph_bellman_ford_shortest_paths))?;
     m.add_wrapped(wrap_pyfunction!(graph_bellman_ford_shortest_path_lengths))?;
@@ -513,6 +793,10 @@ fn rustworkx(py: Python<'_>, m: &Bound<PyModule>) -> PyResult<()> {
     m.add_wrapped(wrap_pyfunction!(digraph_all_pairs_dijkstra_shortest_paths))?;
     m.add_wrapped(wrap_pyfunction!(graph_all_pairs_dijkstra_path_lengths))?;
     m.add_wrapped(wrap_pyfunction!(graph_all_pairs_dijkstra_shortest_paths))?;
+    m.add_wrapped(wrap_pyfunction!(
+        digraph_all_pairs_dijkstra_path_lengths_csr
+    ))?;
+    m.add_wrapped(wrap_pyfunction!(graph_all_pairs_dijkstra_path_lengths_csr))?;
     m.add_wrapped(wrap_pyfunction!(
         digraph_all_pairs_bellman_ford_path_lengths
     ))?;
@@ -523,16 +807,38 @@ fn rustworkx(py: Python<'_>, m: &Bound<PyModule>) -> PyResult<()> {
     m.add_wrapped(wrap_pyfunction!(
         graph_all_pairs_bellman_ford_shortest_paths
     ))?;
+    m.add_wrapped(wrap_pyfunction!(set_num_threads))?;
+    m.add_wrapped(wrap_pyfunction!(get_num_threads))?;
     m.add_wrapped(wrap_pyfunction!(graph_betweenness_centrality))?;
     m.add_wrapped(wrap_pyfunction!(digraph_betweenness_centrality))?;
     m.add_wrapped(wrap_pyfunction!(graph_closeness_centrality))?;
     m.add_wrapped(wrap_pyfunction!(digraph_closeness_centrality))?;
+    m.add_wrapped(wrap_pyfunction!(graph_harmonic_centrality))?;
+    m.add_wrapped(wrap_pyfunction!(digraph_harmonic_centrality))?;
     m.add_wrapped(wrap_pyfunction!(graph_edge_betweenness_centrality))?;
     m.add_wrapped(wrap_pyfunction!(digraph_edge_betweenness_centrality))?;
     m.add_wrapped(wrap_pyfunction!(graph_eigenvector_centrality))?;
     m.add_wrapped(wrap_pyfunction!(digraph_eigenvector_centrality))?;
     m.add_wrapped(wrap_pyfunction!(graph_katz_centrality))?;
     m.add_wrapped(wrap_pyfunction!(digraph_katz_centrality))?;
+    m.add_wrapped(wrap_pyfunction!(graph_simrank_similarity))?;
+    m.add_wrapped(wrap_pyfunction!(digraph_simrank_similarity))?;
+    m.add_wrapped(wrap_pyfunction!(graph_weighted_simrank_similarity))?;
+    m.add_wrapped(wrap_pyfunction!(digraph_weighted_simrank_similarity))?;
+    m.add_wrapped(wrap_pyfunction!(graph_katz_similarity))?;
+    m.add_wrapped(wrap_pyfunction!(digraph_katz_similarity))?;
+    m.add_wrapped(wrap_pyfunction!(weisfeiler_lehman_subtree_features))?;
+    m.add_wrapped(wrap_pyfunction!(weisfeiler_lehman_subtree_kernel))?;
+    m.add_wrapped(wrap_pyfunction!(graph_recursive_structural_features))?;
+    m.add_wrapped(wrap_pyfunction!(digraph_recursive_structural_features))?;
+    m.add_wrapped(wrap_pyfunction!(jaccard_coefficient))?;
+    m.add_wrapped(wrap_pyfunction!(adamic_adar_index))?;
+    m.add_wrapped(wrap_pyfunction!(resource_allocation_index))?;
+    m.add_wrapped(wrap_pyfunction!(preferential_attachment))?;
+    m.add_wrapped(wrap_pyfunction!(common_neighbor_centrality))?;
+    m.add_wrapped(wrap_pyfunction!(cn_soundarajan_hopcroft))?;
+    m.add_wrapped(wrap_pyfunction!(ra_index_soundarajan_hopcroft))?;
+    m.add_wrapped(wrap_pyfunction!(within_inter_cluster))?;
     m.add_wrapped(wrap_pyfunction!(graph_astar_shortest_path))?;
     m.add_wrapped(wrap_pyfunction!(digraph_astar_shortest_path))?;
     m.add_wrapped(wrap_pyfunction!(graph_greedy_color))?;
@@ -541,11 +847,83 @@ fn rustworkx(py: Python<'_>, m: &Bound<PyModule>) -> PyResult<()> {
     m.add_wrapped(wrap_pyfunction!(graph_bipartite_edge_color))?;
     m.add_wrapped(wrap_pyfunction!(graph_two_color))?;
     m.add_wrapped(wrap_pyfunction!(digraph_two_color))?;
+    m.add_wrapped(wrap_pyfunction!(bipartite_projection))?;
+    m.add_wrapped(wrap_pyfunction!(graph_degree_assortativity_coefficient))?;
+    m.add_wrapped(wrap_pyfunction!(digraph_degree_assortativity_coefficient))?;
+    m.add_wrapped(wrap_pyfunction!(graph_attribute_assortativity_coefficient))?;
+    m.add_wrapped(wrap_pyfunction!(
+        digraph_attribute_assortativity_coefficient
+    ))?;
+    m.add_wrapped(wrap_pyfunction!(graph_degree_histogram))?;
+    m.add_wrapped(wrap_pyfunction!(digraph_degree_histogram))?;
+    m.add_wrapped(wrap_pyfunction!(graph_joint_degree_matrix))?;
+    m.add_wrapped(wrap_pyfunction!(digraph_joint_degree_matrix))?;
+    m.add_wrapped(wrap_pyfunction!(graph_average_neighbor_degree))?;
+    m.add_wrapped(wrap_pyfunction!(digraph_average_neighbor_degree))?;
+    m.add_wrapped(wrap_pyfunction!(rich_club_coefficient))?;
+    m.add_wrapped(wrap_pyfunction!(graph_rewire))?;
+    m.add_wrapped(wrap_pyfunction!(digraph_rewire))?;
+    m.add_wrapped(wrap_pyfunction!(graph_motif_counts))?;
+    m.add_wrapped(wrap_pyfunction!(digraph_motif_counts))?;
+    m.add_wrapped(wrap_pyfunction!(graph_motif_significance_profile))?;
+    m.add_wrapped(wrap_pyfunction!(digraph_motif_significance_profile))?;
+    m.add_wrapped(wrap_pyfunction!(triad_census))?;
+    m.add_wrapped(wrap_pyfunction!(sigma))?;
+    m.add_wrapped(wrap_pyfunction!(omega))?;
+    m.add_wrapped(wrap_pyfunction!(diameter))?;
+    m.add_wrapped(wrap_pyfunction!(radius))?;
+    m.add_wrapped(wrap_pyfunction!(global_efficiency))?;
+    m.add_wrapped(wrap_pyfunction!(graph_earliest_arrival_times))?;
+    m.add_wrapped(wrap_pyfunction!(digraph_earliest_arrival_times))?;
+    m.add_wrapped(wrap_pyfunction!(graph_earliest_arrival_paths))?;
+    m.add_wrapped(wrap_pyfunction!(digraph_earliest_arrival_paths))?;
+    m.add_wrapped(wrap_pyfunction!(graph_fastest_path_durations))?;
+    m.add_wrapped(wrap_pyfunction!(digraph_fastest_path_durations))?;
+    m.add_wrapped(wrap_pyfunction!(graph_temporal_reachability))?;
+    m.add_wrapped(wrap_pyfunction!(digraph_temporal_reachability))?;
+    m.add_wrapped(wrap_pyfunction!(graph_temporal_snapshot))?;
+    m.add_wrapped(wrap_pyfunction!(digraph_temporal_snapshot))?;
+    m.add_wrapped(wrap_pyfunction!(graph_modularity))?;
+    m.add_wrapped(wrap_pyfunction!(digraph_modularity))?;
+    m.add_wrapped(wrap_pyfunction!(graph_sum_parallel_edges))?;
+    m.add_wrapped(wrap_pyfunction!(digraph_sum_parallel_edges))?;
+    m.add_wrapped(wrap_pyfunction!(graph_community_subgraphs))?;
+    m.add_wrapped(wrap_pyfunction!(digraph_community_subgraphs))?;
+    m.add_wrapped(wrap_pyfunction!(local_efficiency))?;
+    m.add_wrapped(wrap_pyfunction!(graph_eccentricity))?;
+    m.add_wrapped(wrap_pyfunction!(digraph_eccentricity))?;
+    m.add_wrapped(wrap_pyfunction!(graph_center))?;
+    m.add_wrapped(wrap_pyfunction!(digraph_center))?;
+    m.add_wrapped(wrap_pyfunction!(graph_periphery))?;
+    m.add_wrapped(wrap_pyfunction!(digraph_periphery))?;
+    m.add_wrapped(wrap_pyfunction!(square_clustering))?;
+    m.add_wrapped(wrap_pyfunction!(weighted_clustering))?;
     m.add_wrapped(wrap_pyfunction!(graph_is_bipartite))?;
     m.add_wrapped(wrap_pyfunction!(digraph_is_bipartite))?;
+    m.add_wrapped(wrap_pyfunction!(graph_bipartite_sets))?;
+    m.add_wrapped(wrap_pyfunction!(digraph_bipartite_sets))?;
+    m.add_wrapped(wrap_pyfunction!(graph_edge_disjoint_paths))?;
+    m.add_wrapped(wrap_pyfunction!(digraph_edge_disjoint_paths))?;
+    m.add_wrapped(wrap_pyfunction!(graph_node_disjoint_paths))?;
+    m.add_wrapped(wrap_pyfunction!(digraph_node_disjoint_paths))?;
+    m.add_wrapped(wrap_pyfunction!(graph_sample_nodes))?;
+    m.add_wrapped(wrap_pyfunction!(digraph_sample_nodes))?;
+    m.add_wrapped(wrap_pyfunction!(graph_sample_edges))?;
+    m.add_wrapped(wrap_pyfunction!(digraph_sample_edges))?;
+    m.add_wrapped(wrap_pyfunction!(graph_random_walk_sample))?;
+    m.add_wrapped(wrap_pyfunction!(digraph_random_walk_sample))?;
+    m.add_wrapped(wrap_pyfunction!(graph_forest_fire_sample))?;
+    m.add_wrapped(wrap_pyfunction!(digraph_forest_fire_sample))?;
     m.add_wrapped(wrap_pyfunction!(graph_line_graph))?;
+    m.add_wrapped(wrap_pyfunction!(digraph_line_graph))?;
+    m.add_wrapped(wrap_pyfunction!(graph_quotient_graph))?;
+    m.add_wrapped(wrap_pyfunction!(digraph_quotient_graph))?;
     m.add_wrapped(wrap_pyfunction!(graph_tensor_product))?;
     m.add_wrapped(wrap_pyfunction!(digraph_tensor_product))?;
+    m.add_wrapped(wrap_pyfunction!(graph_strong_product))?;
+    m.add_wrapped(wrap_pyfunction!(digraph_strong_product))?;
+    m.add_wrapped(wrap_pyfunction!(graph_lexicographic_product))?;
+    m.add_wrapped(wrap_pyfunction!(digraph_lexicographic_product))?;
     m.add_wrapped(wrap_pyfunction!(directed_gnp_random_graph))?;
     m.add_wrapped(wrap_pyfunction!(undirected_gnp_random_graph))?;
     m.add_wrapped(wrap_pyfunction!(directed_gnm_random_graph))?;
@@ -564,6 +942,11 @@ fn rustworkx(py: Python<'_>, m: &Bound<PyModule>) -> PyResult<()> {
     m.add_wrapped(wrap_pyfunction!(digraph_dfs_edges))?;
     m.add_wrapped(wrap_pyfunction!(graph_dfs_edges))?;
     m.add_wrapped(wrap_pyfunction!(digraph_find_cycle))?;
+    m.add_wrapped(wrap_pyfunction!(feedback_arc_set))?;
+    m.add_wrapped(wrap_pyfunction!(graph_feedback_vertex_set))?;
+    m.add_wrapped(wrap_pyfunction!(digraph_feedback_vertex_set))?;
+    m.add_wrapped(wrap_pyfunction!(graph_percolation))?;
+    m.add_wrapped(wrap_pyfunction!(digraph_percolation))?;
     m.add_wrapped(wrap_pyfunction!(digraph_k_shortest_path_lengths))?;
     m.add_wrapped(wrap_pyfunction!(graph_k_shortest_path_lengths))?;
     m.add_wrapped(wrap_pyfunction!(is_matching))?;
@@ -571,11 +954,28 @@ fn rustworkx(py: Python<'_>, m: &Bound<PyModule>) -> PyResult<()> {
     m.add_wrapped(wrap_pyfunction!(max_weight_matching))?;
     m.add_wrapped(wrap_pyfunction!(minimum_spanning_edges))?;
     m.add_wrapped(wrap_pyfunction!(minimum_spanning_tree))?;
+    m.add_wrapped(wrap_pyfunction!(minimum_spanning_arborescence))?;
+    m.add_wrapped(wrap_pyfunction!(random_spanning_tree))?;
+    m.add_wrapped(wrap_pyfunction!(k_smallest_spanning_trees))?;
+    m.add_wrapped(wrap_pyfunction!(greedy_spanner))?;
+    m.add_wrapped(wrap_pyfunction!(degree_constrained_spanning_tree))?;
+    m.add_wrapped(wrap_pyfunction!(tree_center))?;
+    m.add_wrapped(wrap_pyfunction!(tree_centroid))?;
+    m.add_wrapped(wrap_pyfunction!(centroid_decomposition))?;
+    m.add_wrapped(wrap_pyfunction!(root_tree))?;
     m.add_wrapped(wrap_pyfunction!(graph_transitivity))?;
     m.add_wrapped(wrap_pyfunction!(digraph_transitivity))?;
+    m.add_wrapped(wrap_pyfunction!(graph_graph_summary))?;
+    m.add_wrapped(wrap_pyfunction!(digraph_graph_summary))?;
     m.add_wrapped(wrap_pyfunction!(graph_token_swapper))?;
     m.add_wrapped(wrap_pyfunction!(graph_core_number))?;
     m.add_wrapped(wrap_pyfunction!(digraph_core_number))?;
+    m.add_wrapped(wrap_pyfunction!(graph_s_core_number))?;
+    m.add_wrapped(wrap_pyfunction!(digraph_s_core_number))?;
+    m.add_wrapped(wrap_pyfunction!(graph_onion_decomposition))?;
+    m.add_wrapped(wrap_pyfunction!(digraph_onion_decomposition))?;
+    m.add_wrapped(wrap_pyfunction!(truss_number))?;
+    m.add_wrapped(wrap_pyfunction!(k_truss))?;
     m.add_wrapped(wrap_pyfunction!(graph_complement))?;
     m.add_wrapped(wrap_pyfunction!(digraph_complement))?;
     m.add_wrapped(wrap_pyfunction!(graph_random_layout))?;
@@ -611,16 +1011,40 @@ fn rustworkx(py: Python<'_>, m: &Bound<PyModule>) -> PyResult<()> {
     m.add_wrapped(wrap_pyfunction!(digraph_isolates))?;
     m.add_wrapped(wrap_pyfunction!(connected_subgraphs))?;
     m.add_wrapped(wrap_pyfunction!(is_planar))?;
+    m.add_wrapped(wrap_pyfunction!(is_minor_of))?;
+    m.add_wrapped(wrap_pyfunction!(treewidth_min_degree))?;
+    m.add_wrapped(wrap_pyfunction!(treewidth_min_fill_in))?;
+    m.add_wrapped(wrap_pyfunction!(tree_decomposition))?;
+    m.add_wrapped(wrap_pyfunction!(
+        modular_decomposition::modular_decomposition
+    ))?;
+    m.add_wrapped(wrap_pyfunction!(is_cograph))?;
+    m.add_wrapped(wrap_pyfunction!(graph_node_boundary))?;
+    m.add_wrapped(wrap_pyfunction!(digraph_node_boundary))?;
+    m.add_wrapped(wrap_pyfunction!(graph_edge_boundary))?;
+    m.add_wrapped(wrap_pyfunction!(digraph_edge_boundary))?;
+    m.add_wrapped(wrap_pyfunction!(graph_cut_size))?;
+    m.add_wrapped(wrap_pyfunction!(digraph_cut_size))?;
+    m.add_wrapped(wrap_pyfunction!(graph_volume))?;
+    m.add_wrapped(wrap_pyfunction!(digraph_volume))?;
     m.add_wrapped(wrap_pyfunction!(read_graphml))?;
     m.add_wrapped(wrap_pyfunction!(digraph_node_link_json))?;
     m.add_wrapped(wrap_pyfunction!(graph_node_link_json))?;
     m.add_wrapped(wrap_pyfunction!(from_node_link_json_file))?;
     m.add_wrapped(wrap_pyfunction!(parse_node_link_json))?;
+    m.add_wrapped(wrap_pyfunction!(graph_to_svg))?;
+    m.add_wrapped(wrap_pyfunction!(digraph_to_svg))?;
     m.add_wrapped(wrap_pyfunction!(pagerank))?;
     m.add_wrapped(wrap_pyfunction!(hits))?;
+    m.add_wrapped(wrap_pyfunction!(graph_random_walk_with_restart))?;
+    m.add_wrapped(wrap_pyfunction!(digraph_random_walk_with_restart))?;
     m.add_class::<digraph::PyDiGraph>()?;
     m.add_class::<graph::PyGraph>()?;
+    m.add_class::<hypergraph::PyHypergraph>()?;
     m.add_class::<toposort::TopologicalSorter>()?;
+    m.add_class::<dag_algo::ReachabilityIndex>()?;
+    m.add_class::<union::UnionMapping>()?;
+    m.add_class::<summary::GraphSummary>()?;
     m.add_class::<iterators::RelationalCoarsestPartition>()?;
     m.add_class::<iterators::IndexPartitionBlock>()?;
     m.add_class::<iterators::BFSSuccessors>()?;
@@ -642,6 +1066,7 @@ fn rustworkx(py: Python<'_>, m: &Bound<PyModule>) -> PyResult<()> {
     m.add_class::<iterators::AllPairsPathMapping>()?;
     m.add_class::<iterators::NodesCountMapping>()?;
     m.add_class::<iterators::NodeMap>()?;
+    m.add_class::<iterators::EdgeMap>()?;
     m.add_class::<iterators::ProductNodeMap>()?;
     m.add_class::<iterators::BiconnectedComponents>()?;
     m.add_class::<ColoringStrategy>()?;
@@ -10,15 +10,16 @@
 // License for the specific language governing permissions and limitations
 // under the License.
 
-use crate::{graph, StablePyGraph};
+use crate::{digraph, graph, StablePyGraph};
 
 use hashbrown::HashMap;
 
+use petgraph::algo;
 use petgraph::graph::{EdgeIndex, NodeIndex};
 use petgraph::visit::{EdgeRef, IntoEdgeReferences};
-use petgraph::Undirected;
+use petgraph::{Directed, Undirected};
 use rustworkx_core::dictmap::*;
-use rustworkx_core::line_graph::line_graph;
+use rustworkx_core::line_graph::{directed_line_graph, line_graph};
 
 use pyo3::prelude::*;
 use pyo3::Python;
@@ -85,3 +86,67 @@ pub fn graph_line_graph(
 
     (output_graph_py, output_edge_to_node_map_py)
 }
+
+/// Constructs the line graph of a :class:`~.PyDiGraph` object.
+///
+/// The line graph `L(G)` of a directed graph `G` contains a vertex for every edge in `G`,
+/// and `L(G)` contains an edge from the vertex of an edge `(u, v)` to the vertex of an
+/// edge `(v, w)`, so two edges are only adjacent if the head of the first edge is the
+/// tail of the second. A self-loop in `G` gives a self-loop in `L(G)`.
+///
+/// :param PyDiGraph: The input PyDiGraph object
+///
+/// :returns: A new PyDiGraph object that is the line graph of ``graph``, and the dictionary
+///     where the keys are indices of edges in``graph`` and the values are the corresponding
+///     indices of nodes in the linear graph.
+/// :rtype: Tuple[:class:`~rustworkx.PyDiGraph`, dict]
+///
+/// .. jupyter-execute::
+///
+///   import rustworkx as rx
+///
+///   graph = rx.PyDiGraph()
+///   node_a = graph.add_node("a")
+///   node_b = graph.add_node("b")
+///   node_c = graph.add_node("c")
+///   edge_ab = graph.add_edge(node_a, node_b, 1)
+///   edge_bc = graph.add_edge(node_b, node_c, 1)
+///   edge_ca = graph.add_edge(node_c, node_a, 1)
+///
+///   out_graph, out_edge_map = rx.digraph_line_graph(graph)
+///   assert out_graph.node_indices() == [0, 1, 2]
+///   assert sorted(out_graph.edge_list()) == [(0, 1), (1, 2), (2, 0)]
+///   assert out_edge_map == {edge_ab: 0, edge_bc: 1, edge_ca: 2}
+///
+#[pyfunction]
+#[pyo3(text_signature = "(graph, /)")]
+pub fn digraph_line_graph(
+    py: Python,
+    graph: &digraph::PyDiGraph,
+) -> (digraph::PyDiGraph, DictMap<usize, usize>) {
+    let default_fn = || py.None();
+
+    let (output_graph, output_edge_to_node_map): (
+        StablePyGraph<Directed>,
+        HashMap<EdgeIndex, NodeIndex>,
+    ) = directed_line_graph(&graph.graph, default_fn, default_fn);
+
+    let output_graph_py = digraph::PyDiGraph {
+        graph: output_graph,
+        cycle_state: algo::DfsSpace::default(),
+        check_cycle: false,
+        node_removed: false,
+        multigraph: false,
+        attrs: py.None(),
+    };
+
+    let mut output_edge_to_node_map_py: DictMap<usize, usize> = DictMap::new();
+
+    for edge in graph.graph.edge_references() {
+        let edge_id = edge.id();
+        let node_id = output_edge_to_node_map.get(&edge_id).unwrap();
+        output_edge_to_node_map_py.insert(edge_id.index(), node_id.index());
+    }
+
+    (output_graph_py, output_edge_to_node_map_py)
+}
@@ -13,12 +13,17 @@
 // PageRank has many possible personalizations, so we accept them all
 #![allow(clippy::too_many_arguments)]
 
+use std::convert::TryFrom;
+
+use pyo3::exceptions::{PyTypeError, PyValueError};
 use pyo3::prelude::*;
+use pyo3::types::PyDict;
 use pyo3::Python;
 
 use crate::digraph::PyDiGraph;
+use crate::graph::PyGraph;
 use crate::iterators::CentralityMapping;
-use crate::{weight_callable, FailedToConverge};
+use crate::{weight_callable, CostFn, FailedToConverge, InvalidNode, StablePyGraph};
 
 use hashbrown::HashMap;
 use ndarray::prelude::*;
@@ -26,9 +31,218 @@ use ndarray_stats::{DeviationExt, QuantileExt};
 use petgraph::prelude::*;
 use petgraph::visit::IntoEdgeReferences;
 use petgraph::visit::NodeIndexable;
+use petgraph::EdgeType;
 use rustworkx_core::dictmap::*;
+use rustworkx_core::link_analysis;
 use sprs::{CsMat, TriMat};
 
+/// Read a vector of non-negative values indexed by node index from either a
+/// dict or a sequence, normalized to sum to 1.
+fn node_distribution(
+    py: Python,
+    values: &PyObject,
+    name: &str,
+    node_indices: &[usize],
+    mat_size: usize,
+) -> PyResult<Array1<f64>> {
+    let mut distribution = Array1::<f64>::zeros(mat_size);
+    if let Ok(values) = values.extract::<HashMap<usize, f64>>(py) {
+        for i in node_indices {
+            distribution[*i] = *values.get(i).unwrap_or(&0.0);
+        }
+    } else {
+        let values: Vec<f64> = values.extract(py).map_err(|_| {
+            PyTypeError::new_err(format!(
+                "{} must be a dict or a sequence of floats indexed by node index",
+                name
+            ))
+        })?;
+        if values.len() != mat_size {
+            return Err(PyValueError::new_err(format!(
+                "{} has {} entries but the graph has a node index bound of {}",
+                name,
+                values.len(),
+                mat_size
+            )));
+        }
+        for i in node_indices {
+            distribution[*i] = values[*i];
+        }
+    }
+    if distribution
+        .iter()
+        .any(|value| value.is_nan() || *value < 0.0)
+    {
+        return Err(PyValueError::new_err(format!(
+            "{} can't have negative or NaN values",
+            name
+        )));
+    }
+    let sum = distribution.sum();
+    if sum <= 0.0 || !sum.is_finite() {
+        return Err(PyValueError::new_err(format!(
+            "{} must have a positive and finite sum over the nodes of the graph",
+            name
+        )));
+    }
+    distribution /= sum;
+    Ok(distribution)
+}
+
+/// Where the rank of the dangling nodes is redistributed to.
+enum DanglingPolicy {
+    /// To every node with the given probabilities.
+    Distribution(Array1<f64>),
+    /// Back to the dangling node itself.
+    SelfLoop,
+}
+
+/// The PageRank transition operator, `alpha * (A + dangling) x`.
+struct PageRankOperator {
+    alpha: f64,
+    transition: CsMat<f64>,
+    is_dangling: Vec<bool>,
+    dangling: DanglingPolicy,
+}
+
+impl PageRankOperator {
+    fn apply(&self, x: &Array1<f64>) -> Array1<f64> {
+        let mut result = &self.transition * x;
+        match &self.dangling {
+            DanglingPolicy::Distribution(weights) => {
+                let dangling_sum: f64 = self
+                    .is_dangling
+                    .iter()
+                    .zip(x.iter())
+                    .map(|(cond, value)| if *cond { *value } else { 0.0 })
+                    .sum();
+                result.scaled_add(dangling_sum, weights);
+            }
+            DanglingPolicy::SelfLoop => {
+                for (i, cond) in self.is_dangling.iter().enumerate() {
+                    if *cond {
+                        result[i] += x[i];
+                    }
+                }
+            }
+        }
+        result * self.alpha
+    }
+}
+
+/// The outcome of solving for the PageRank, converged or not.
+struct PageRankSolution {
+    ranks: Array1<f64>,
+    iterations: usize,
+    residual: f64,
+    converged: bool,
+}
+
+fn pagerank_power(
+    operator: &PageRankOperator,
+    damping: &Array1<f64>,
+    start: Array1<f64>,
+    threshold: f64,
+    max_iter: usize,
+) -> PageRankSolution {
+    let mut popularity = start;
+    let mut residual = f64::INFINITY;
+    for iteration in 1..=max_iter {
+        let new_popularity = operator.apply(&popularity) + damping;
+        residual = new_popularity.l1_dist(&popularity).unwrap();
+        popularity = new_popularity;
+        if residual < threshold {
+            return PageRankSolution {
+                ranks: popularity,
+                iterations: iteration,
+                residual,
+                converged: true,
+            };
+        }
+    }
+    PageRankSolution {
+        ranks: popularity,
+        iterations: max_iter,
+        residual,
+        converged: false,
+    }
+}
+
+/// Solve `(I - operator) x = rhs` with the stabilized biconjugate gradient
+/// method, starting from `start`.
+fn pagerank_linear(
+    operator: &PageRankOperator,
+    rhs: &Array1<f64>,
+    start: Array1<f64>,
+    threshold: f64,
+    max_iter: usize,
+) -> PageRankSolution {
+    let system = |x: &Array1<f64>| x - &operator.apply(x);
+    let l1_norm = |x: &Array1<f64>| x.iter().map(|value| value.abs()).sum::<f64>();
+    let mut x = start;
+    let mut r = rhs - &system(&x);
+    let mut residual = l1_norm(&r);
+    if residual < threshold {
+        return PageRankSolution {
+            ranks: x,
+            iterations: 0,
+            residual,
+            converged: true,
+        };
+    }
+    let r_hat = r.clone();
+    let mut rho = 1.0;
+    let mut step = 1.0;
+    let mut omega = 1.0;
+    let mut v = Array1::<f64>::zeros(x.len());
+    let mut p = Array1::<f64>::zeros(x.len());
+    for iteration in 1..=max_iter {
+        let rho_next = r_hat.dot(&r);
+        if rho_next == 0.0 {
+            break;
+        }
+        let beta = (rho_next / rho) * (step / omega);
+        p = &r + &((&p - &(omega * &v)) * beta);
+        v = system(&p);
+        step = rho_next / r_hat.dot(&v);
+        let s = &r - &(step * &v);
+        residual = l1_norm(&s);
+        if residual < threshold {
+            x.scaled_add(step, &p);
+            return PageRankSolution {
+                ranks: x,
+                iterations: iteration,
+                residual,
+                converged: true,
+            };
+        }
+        let t = system(&s);
+        omega = t.dot(&s) / t.dot(&t);
+        x.scaled_add(step, &p);
+        x.scaled_add(omega, &s);
+        r = &s - &(omega * &t);
+        residual = l1_norm(&r);
+        if residual < threshold {
+            return PageRankSolution {
+                ranks: x,
+                iterations: iteration,
+                residual,
+                converged: true,
+            };
+        }
+        if !residual.is_finite() || omega == 0.0 {
+            break;
+        }
+        rho = rho_next;
+    }
+    PageRankSolution {
+        ranks: x,
+        iterations: max_iter,
+        residual,
+        converged: false,
+    }
+}
+
 /// Computes the PageRank of the nodes in a :class:`~PyDiGraph`.
 ///
 /// For details on the PageRank, refer to:
@@ -37,14 +251,23 @@ use sprs::{CsMat, TriMat};
 /// Stanford Digital Library Technologies Project, (1998).
 /// <http://dbpubs.stanford.edu:8090/pub/showDoc.Fulltext?lang=en&doc=1999-66&format=pdf>
 ///
-/// This function uses a power iteration method to compute the PageRank
-/// and convergence is not guaranteed. The function will stop when `max_iter`
-/// iterations is reached or when the computed vector between two iterations
-/// is smaller than the error tolerance multiplied by the number of nodes.
-/// The implementation of this algorithm tries to match NetworkX's
+/// By default this function uses a power iteration method to compute the
+/// PageRank and convergence is not guaranteed. The function will stop when
+/// `max_iter` iterations is reached or when the computed vector between two
+/// iterations is smaller than the error tolerance multiplied by the number of
+/// nodes. The implementation of this algorithm tries to match NetworkX's
 /// `pagerank() <https://networkx.org/documentation/stable/reference/algorithms/generated/networkx.algorithms.link_analysis.pagerank_alg.pagerank.html>`__
 /// implementation.
 ///
+/// With ``method="linear"`` the PageRank is instead found by solving the
+/// sparse linear system :math:`(I - \alpha M) x = (1 - \alpha) p`, where
+/// :math:`M` is the transition matrix including the dangling nodes and
+/// :math:`p` the personalization vector, with the stabilized biconjugate
+/// gradient method. It usually needs far fewer iterations than the power
+/// method when ``alpha`` is close to 1, and stops once the sum of the
+/// absolute residuals is smaller than the error tolerance multiplied by the
+/// number of nodes.
+///
 /// In the case of multigraphs the weights of any parallel edges will be
 /// summed when computing the PageRank.
 ///
@@ -54,24 +277,50 @@ use sprs::{CsMat, TriMat};
 ///     payload object and is expected to return a `float` weight for that edge.
 ///     If this is not specified ``default_weight`` will be used as the weight
 ///     for every edge in ``graph``
-/// :param dict nstart: Optional starting value of PageRank iteration for each node.
-/// :param dict personalization: An optional dictionary representing the personalization
-///     vector for a subset of nodes. At least one personalization entry must be non-zero.
-///     If not specified, a nodes personalization value will be zero. By default,
-///     a uniform distribution is used.
+/// :param nstart: Optional starting value of PageRank iteration for each node,
+///     either as a dict or as a sequence indexed by node index.
+/// :param personalization: An optional personalization vector, either as a
+///     dictionary for a subset of nodes or as a sequence with an entry for every
+///     node index up to :meth:`~PyDiGraph.node_bound`. At least one
+///     personalization entry must be non-zero and none can be negative.
+///     If a dictionary is given, a node without an entry has a personalization
+///     value of zero. By default, a uniform distribution is used.
 /// :param float tol: The error tolerance used when checking for convergence in the
 ///     power method. If this is not specified default value of 1e-6 is used.
 /// :param int max_iter: The maximum number of iterations in the power method. If
 ///     not specified a default value of 100 is used.
-/// :param dict dangling: An optional dictionary for the outedges to be assigned to any "dangling" nodes,
-///     i.e., nodes without any outedges.  The dict key is the node the outedge points to and the dict
-///     value is the weight of that outedge. By default, dangling nodes are given outedges according to
-///     the personalization vector (uniform if not specified). This must be selected to result in an irreducible
-///     transition matrix. It may be common to have the dangling dict to be the same as the personalization dict.
+/// :param dangling: How the rank of "dangling" nodes, i.e., nodes without any
+///     outedges, is redistributed. This can be a dict or a sequence indexed by
+///     node index of the weights of the outedges to be assigned to every dangling
+///     node, or one of the following policies:
+///
+///     * ``"personalization"``: the dangling nodes get outedges according to
+///       the personalization vector (uniform if not specified). This is the
+///       default.
+///     * ``"uniform"``: the dangling nodes get an outedge of equal weight to
+///       every node.
+///     * ``"self"``: the dangling nodes keep their rank, as if they had a
+///       self-loop.
+///
+///     The weights must be selected to result in an irreducible transition
+///     matrix. It may be common to have the dangling weights be the same as
+///     the personalization vector.
+/// :param str method: The method used to compute the PageRank, either
+///     ``"power"`` for power iteration (the default) or ``"linear"`` to solve
+///     the linear system. ``alpha`` must be less than 1 for ``"linear"``.
+/// :param bool return_diagnostics: If set to ``True`` a dictionary with the
+///     ``"method"`` used, the number of ``"iterations"`` and the final
+///     ``"residual"`` is returned along with the PageRank.
 ///
 /// :returns: a read-only dict-like object whose keys are the node indices and values are the
-///      PageRank score for that node.
+///      PageRank score for that node. If ``return_diagnostics`` is set, a tuple of that
+///      object and the diagnostics dictionary.
 /// :rtype: CentralityMapping
+///
+/// :raises FailedToConverge: If the PageRank didn't converge within ``max_iter``
+///     iterations. The exception has a ``partial_result`` attribute with the
+///     PageRank of the last iteration as a :class:`~.CentralityMapping`, and
+///     ``iterations`` and ``residual`` attributes.
 #[pyfunction(
     signature = (
         graph,
@@ -82,32 +331,64 @@ use sprs::{CsMat, TriMat};
         tol=1e-6,
         max_iter=100,
         dangling=None,
+        method="power",
+        return_diagnostics=false,
     )
 )]
 #[pyo3(
-    text_signature = "(graph, /, alpha=0.85, weight_fn=None, nstart=None, personalization=None, tol=1.0e-6, max_iter=100, dangling=None)"
+    text_signature = "(graph, /, alpha=0.85, weight_fn=None, nstart=None, personalization=None, tol=1.0e-6, max_iter=100, dangling=None, method=\"power\", return_diagnostics=False)"
 )]
 pub fn pagerank(
     py: Python,
     graph: &PyDiGraph,
     alpha: f64,
     weight_fn: Option<PyObject>,
-    nstart: Option<HashMap<usize, f64>>,
-    personalization: Option<HashMap<usize, f64>>,
+    nstart: Option<PyObject>,
+    personalization: Option<PyObject>,
     tol: f64,
     max_iter: usize,
-    dangling: Option<HashMap<usize, f64>>,
-) -> PyResult<CentralityMapping> {
+    dangling: Option<PyObject>,
+    method: &str,
+    return_diagnostics: bool,
+) -> PyResult<PyObject> {
+    let linear = match method {
+        "power" => false,
+        "linear" => true,
+        _ => {
+            return Err(PyValueError::new_err(format!(
+                "Unknown method {:?}, it must be \"power\" or \"linear\"",
+                method
+            )))
+        }
+    };
+    if linear && alpha >= 1.0 {
+        return Err(PyValueError::new_err(
+            "alpha must be less than 1 for the linear method",
+        ));
+    }
     // we use the node bound to make the code work if nodes were removed
     let n = graph.graph.node_count();
     let mat_size = graph.graph.node_bound();
     let node_indices: Vec<usize> = graph.graph.node_indices().map(|x| x.index()).collect();
+    let with_diagnostics =
+        |py: Python, ranks: CentralityMapping, iterations: usize, residual: f64| {
+            if return_diagnostics {
+                let diagnostics = PyDict::new_bound(py);
+                diagnostics.set_item("method", method)?;
+                diagnostics.set_item("iterations", iterations)?;
+                diagnostics.set_item("residual", residual)?;
+                Ok((ranks, diagnostics).into_py(py))
+            } else {
+                Ok(ranks.into_py(py))
+            }
+        };
 
     // Handle empty case
     if n == 0 {
-        return Ok(CentralityMapping {
+        let ranks = CentralityMapping {
             centralities: DictMap::new(),
-        });
+        };
+        return with_diagnostics(py, ranks, 0, 0.0);
     }
 
     // Grab the graph weights from Python to Rust
@@ -134,40 +415,28 @@ pub fn pagerank(
     let a: CsMat<_> = a.to_csr();
 
     // Vector with probabilities for the Markov Chain process
-    let mut popularity = Array1::<f64>::zeros(mat_size);
+    let mut uniform = Array1::<f64>::zeros(mat_size);
     let default_pop = (n as f64).recip();
+    for i in &node_indices {
+        uniform[*i] = default_pop;
+    }
 
     // Handle custom start
-    if let Some(nstart) = nstart {
-        for i in &node_indices {
-            popularity[*i] = *nstart.get(i).unwrap_or(&0.0);
-        }
-        let pop_sum = popularity.sum();
-        popularity /= pop_sum;
-    } else {
-        for i in &node_indices {
-            popularity[*i] = default_pop;
-        }
-    }
+    let popularity = match nstart {
+        Some(nstart) => node_distribution(py, &nstart, "nstart", &node_indices, mat_size)?,
+        None => uniform.clone(),
+    };
 
     // Handle personalization
     let personalized_array: Array1<f64> = match personalization {
-        Some(personalization) => {
-            let mut personalized_array = Array1::<f64>::zeros(mat_size);
-            for i in &node_indices {
-                personalized_array[*i] = *personalization.get(i).unwrap_or(&0.0);
-            }
-            let p_sum = personalized_array.sum();
-            personalized_array /= p_sum;
-            personalized_array
-        }
-        None => {
-            let mut personalized_array = Array1::<f64>::zeros(mat_size);
-            for i in &node_indices {
-                personalized_array[*i] = default_pop;
-            }
-            personalized_array
-        }
+        Some(personalization) => node_distribution(
+            py,
+            &personalization,
+            "personalization",
+            &node_indices,
+            mat_size,
+        )?,
+        None => uniform.clone(),
     };
     let damping = (1.0 - alpha) * &personalized_array;
 
@@ -175,55 +444,63 @@ pub fn pagerank(
     let is_dangling = (0..mat_size)
         .map(|i| out_weights[i] == 0.0)
         .collect::<Vec<_>>();
-    let dangling_weights: Array1<f64> = match dangling {
-        Some(dangling) => {
-            let mut dangling_weights = Array1::<f64>::zeros(mat_size);
-            for i in &node_indices {
-                dangling_weights[*i] = *dangling.get(i).unwrap_or(&0.0);
+    let dangling = match dangling {
+        None => DanglingPolicy::Distribution(personalized_array),
+        Some(dangling) => match dangling.extract::<String>(py).ok().as_deref() {
+            Some("personalization") => DanglingPolicy::Distribution(personalized_array),
+            Some("uniform") => DanglingPolicy::Distribution(uniform),
+            Some("self") => DanglingPolicy::SelfLoop,
+            Some(policy) => {
+                return Err(PyValueError::new_err(format!(
+                    "Unknown dangling policy {:?}, it must be \"personalization\", \"uniform\" or \"self\"",
+                    policy
+                )))
             }
-            let d_sum = dangling_weights.sum();
-            dangling_weights /= d_sum;
-            dangling_weights
-        }
-        None => personalized_array,
+            None => DanglingPolicy::Distribution(node_distribution(
+                py,
+                &dangling,
+                "dangling",
+                &node_indices,
+                mat_size,
+            )?),
+        },
+    };
+    let operator = PageRankOperator {
+        alpha,
+        transition: a,
+        is_dangling,
+        dangling,
     };
 
-    // Power Method iteration for the Google Matrix
-    let mut has_converged = false;
-    for _ in 0..max_iter {
-        let dangling_sum: f64 = is_dangling
-            .iter()
-            .zip(popularity.iter())
-            .map(|(cond, pop)| if *cond { *pop } else { 0.0 })
-            .sum();
-        let new_popularity =
-            alpha * ((&a * &popularity) + (dangling_sum * &dangling_weights)) + &damping;
-        let norm: f64 = new_popularity.l1_dist(&popularity).unwrap();
-        if norm < (n as f64) * tol {
-            has_converged = true;
-            break;
-        } else {
-            popularity = new_popularity;
-        }
-    }
+    let threshold = (n as f64) * tol;
+    let solution = if linear {
+        pagerank_linear(&operator, &damping, popularity, threshold, max_iter)
+    } else {
+        pagerank_power(&operator, &damping, popularity, threshold, max_iter)
+    };
+
+    let out_map: DictMap<usize, f64> = node_indices
+        .iter()
+        .map(|x| (*x, solution.ranks[*x]))
+        .collect();
+    let ranks = CentralityMapping {
+        centralities: out_map,
+    };
 
     // Convert to custom return type
-    if !has_converged {
-        return Err(FailedToConverge::new_err(format!(
+    if !solution.converged {
+        let err = FailedToConverge::new_err(format!(
             "Function failed to converge on a solution in {} iterations",
             max_iter
-        )));
+        ));
+        let value = err.value_bound(py);
+        value.setattr("partial_result", ranks.into_py(py))?;
+        value.setattr("iterations", solution.iterations)?;
+        value.setattr("residual", solution.residual)?;
+        return Err(err);
     }
 
-    let out_map: DictMap<usize, f64> = graph
-        .graph
-        .node_indices()
-        .map(|x| (x.index(), popularity[x.index()]))
-        .collect();
-
-    Ok(CentralityMapping {
-        centralities: out_map,
-    })
+    with_diagnostics(py, ranks, solution.iterations, solution.residual)
 }
 
 /// Computes the hubs and authorities in a :class:`~PyDiGraph`.
@@ -247,16 +524,31 @@ pub fn pagerank(
 ///     payload object and is expected to return a `float` weight for that edge.
 ///     If this is not specified 1.0 will be used as the weight
 ///     for every edge in ``graph``
-/// :param dict nstart: Optional starting value for the power iteration for each node.
+/// :param nstart: Optional prior authority scores to start the power iteration
+///     from, either as a dict or as a sequence indexed by node index. Nodes
+///     without a value in a dict start at zero.
 /// :param float tol: The error tolerance used when checking for convergence in the
 ///     power method. If this is not specified default value of 1e-8 is used.
 /// :param int max_iter: The maximum number of iterations in the power method. If
 ///     not specified a default value of 100 is used.
 /// :param boolean normalized: If the scores should be normalized (defaults to True).
+/// :param str norm: The norm the hub and authority scores are each scaled to 1 in
+///     when ``normalized`` is set: ``"l1"`` for the sum of the scores (the
+///     default), ``"l2"`` for the euclidean norm or ``"max"`` for the largest
+///     score.
+/// :param bool return_diagnostics: If set to ``True`` a dictionary with the
+///     number of ``"iterations"`` and the final ``"residual"`` is returned along
+///     with the scores.
 ///
 /// :returns: a tuple of read-only dict-like object whose keys are the node indices. The first value in the tuple
-///      contain the hubs scores. The second value contains the authority scores.
+///      contain the hubs scores. The second value contains the authority scores. If
+///      ``return_diagnostics`` is set, the diagnostics dictionary is the third value.
 /// :rtype: tuple[CentralityMapping, CentralityMapping]
+///
+/// :raises FailedToConverge: If the scores didn't converge within ``max_iter``
+///     iterations. The exception has a ``partial_result`` attribute with the
+///     tuple of the hub and authority scores of the last iteration, and
+///     ``iterations`` and ``residual`` attributes.
 #[pyfunction(
     signature = (
         graph,
@@ -265,35 +557,72 @@ pub fn pagerank(
         tol=1e-6,
         max_iter=100,
         normalized=true,
+        norm="l1",
+        return_diagnostics=false,
     )
 )]
 #[pyo3(
-    text_signature = "(graph, /, weight_fn=None, nstart=None, tol=1.0e-8, max_iter=100, normalized=True)"
+    text_signature = "(graph, /, weight_fn=None, nstart=None, tol=1.0e-8, max_iter=100, normalized=True, norm=\"l1\", return_diagnostics=False)"
 )]
 pub fn hits(
     py: Python,
     graph: &PyDiGraph,
     weight_fn: Option<PyObject>,
-    nstart: Option<HashMap<usize, f64>>,
+    nstart: Option<PyObject>,
     tol: f64,
     max_iter: usize,
     normalized: bool,
-) -> PyResult<(CentralityMapping, CentralityMapping)> {
+    norm: &str,
+    return_diagnostics: bool,
+) -> PyResult<PyObject> {
+    let scale = |scores: &Array1<f64>| -> f64 {
+        match norm {
+            "l2" => scores.dot(scores).sqrt(),
+            "max" => *scores.max_skipnan(),
+            _ => scores.sum(),
+        }
+    };
+    if !["l1", "l2", "max"].contains(&norm) {
+        return Err(PyValueError::new_err(format!(
+            "Unknown norm {:?}, it must be \"l1\", \"l2\" or \"max\"",
+            norm
+        )));
+    }
     // we use the node bound to make the code work if nodes were removed
     let n = graph.graph.node_count();
     let mat_size = graph.graph.node_bound();
     let node_indices: Vec<usize> = graph.graph.node_indices().map(|x| x.index()).collect();
-
-    // Handle empty case
-    if n == 0 {
-        return Ok((
+    let to_mappings = |hubs: &Array1<f64>, authority: &Array1<f64>| {
+        let hubs_map: DictMap<usize, f64> = node_indices.iter().map(|x| (*x, hubs[*x])).collect();
+        let auth_map: DictMap<usize, f64> =
+            node_indices.iter().map(|x| (*x, authority[*x])).collect();
+        (
             CentralityMapping {
-                centralities: DictMap::new(),
+                centralities: hubs_map,
             },
             CentralityMapping {
-                centralities: DictMap::new(),
+                centralities: auth_map,
             },
-        ));
+        )
+    };
+    let with_diagnostics = |py: Python,
+                            scores: (CentralityMapping, CentralityMapping),
+                            iterations: usize,
+                            residual: f64| {
+        if return_diagnostics {
+            let diagnostics = PyDict::new_bound(py);
+            diagnostics.set_item("iterations", iterations)?;
+            diagnostics.set_item("residual", residual)?;
+            Ok((scores.0, scores.1, diagnostics).into_py(py))
+        } else {
+            Ok(scores.into_py(py))
+        }
+    };
+
+    // Handle empty case
+    if n == 0 {
+        let empty = Array1::<f64>::zeros(0);
+        return with_diagnostics(py, to_mappings(&empty, &empty), 0, 0.0);
     }
 
     // Grab the graph weights from Python to Rust
@@ -322,24 +651,22 @@ pub fn hits(
     let a_t: CsMat<_> = a_t.to_csr();
 
     // Initial guess of eigenvector of A^T @ A
-    let mut authority = Array1::<f64>::zeros(mat_size);
-    let default_auth = (n as f64).recip();
-
-    // Handle custom start
-    if let Some(nstart) = nstart {
-        for i in &node_indices {
-            authority[*i] = *nstart.get(i).unwrap_or(&0.0);
-        }
-        let a_sum = authority.sum();
-        authority /= a_sum;
-    } else {
-        for i in &node_indices {
-            authority[*i] = default_auth;
+    let mut authority = match nstart {
+        Some(nstart) => node_distribution(py, &nstart, "nstart", &node_indices, mat_size)?,
+        None => {
+            let mut authority = Array1::<f64>::zeros(mat_size);
+            let default_auth = (n as f64).recip();
+            for i in &node_indices {
+                authority[*i] = default_auth;
+            }
+            authority
         }
-    }
+    };
 
     // Power Method iteration for A^T @ A
     let mut has_converged = false;
+    let mut iterations = 0;
+    let mut residual = f64::INFINITY;
     for _ in 0..max_iter {
         // Instead of evaluating A^T @ A, which might not be sparse
         // we prefer to calculate A^T (A @ x); A @ x is a vector hence
@@ -347,39 +674,252 @@ pub fn hits(
         let temp_hub = &a * &authority;
         let mut new_authority = &a_t * &temp_hub;
         new_authority /= *new_authority.max_skipnan();
-        let norm: f64 = new_authority.l1_dist(&authority).unwrap();
-        if norm < tol {
+        residual = new_authority.l1_dist(&authority).unwrap();
+        iterations += 1;
+        authority = new_authority;
+        if residual < tol {
             has_converged = true;
             break;
-        } else {
-            authority = new_authority;
         }
     }
 
+    let mut hubs = &a * &authority;
+
+    if normalized {
+        hubs /= scale(&hubs);
+        authority /= scale(&authority);
+    }
+
     // Convert to custom return type
     if !has_converged {
-        return Err(FailedToConverge::new_err(format!(
+        let err = FailedToConverge::new_err(format!(
             "Function failed to converge on a solution in {} iterations",
             max_iter
-        )));
+        ));
+        let partial_result: PyObject = to_mappings(&hubs, &authority).into_py(py);
+        let value = err.value_bound(py);
+        value.setattr("partial_result", partial_result)?;
+        value.setattr("iterations", iterations)?;
+        value.setattr("residual", residual)?;
+        return Err(err);
     }
 
-    let mut hubs = &a * &authority;
+    with_diagnostics(py, to_mappings(&hubs, &authority), iterations, residual)
+}
 
-    if normalized {
-        hubs /= hubs.sum();
-        authority /= authority.sum();
+fn random_walk_with_restart<Ty: EdgeType>(
+    py: Python,
+    graph: &StablePyGraph<Ty>,
+    seeds: Vec<usize>,
+    restart_prob: f64,
+    weight_fn: Option<PyObject>,
+    default_weight: f64,
+    max_iter: usize,
+    tol: f64,
+) -> PyResult<CentralityMapping> {
+    if seeds.is_empty() {
+        return Err(PyValueError::new_err("At least one seed node is required"));
+    }
+    if restart_prob.is_nan() || restart_prob <= 0. || restart_prob > 1. {
+        return Err(PyValueError::new_err(
+            "restart_prob must be greater than 0 and at most 1",
+        ));
+    }
+    let seeds = seeds
+        .into_iter()
+        .map(|seed| {
+            let seed = NodeIndex::new(seed);
+            if graph.contains_node(seed) {
+                Ok(seed)
+            } else {
+                Err(InvalidNode::new_err(format!(
+                    "Node index {} is not in the graph",
+                    seed.index()
+                )))
+            }
+        })
+        .collect::<PyResult<Vec<NodeIndex>>>()?;
+    let cost_fn = CostFn::try_from((weight_fn, default_weight))?;
+    let proximity = link_analysis::random_walk_with_restart(
+        graph,
+        &seeds,
+        |edge| cost_fn.call_edge(py, edge.id(), edge.weight()),
+        restart_prob,
+        max_iter,
+        tol,
+    )?;
+    match proximity {
+        Some(proximity) => Ok(CentralityMapping {
+            centralities: graph
+                .node_indices()
+                .map(|node| (node.index(), proximity[node.index()]))
+                .collect(),
+        }),
+        None => Err(FailedToConverge::new_err(format!(
+            "Function failed to converge on a solution in {} iterations",
+            max_iter
+        ))),
     }
+}
 
-    let hubs_map: DictMap<usize, f64> = node_indices.iter().map(|x| (*x, hubs[*x])).collect();
-    let auth_map: DictMap<usize, f64> = node_indices.iter().map(|x| (*x, authority[*x])).collect();
+/// Compute the random walk with restart proximity of every node in a
+/// :class:`~.PyGraph` to a set of seed nodes
+///
+/// A random walker starts at one of the ``seeds``, and at every step either
+/// moves along one of the edges of its current node, chosen with a probability
+/// proportional to the edge weights, or with probability ``restart_prob``
+/// jumps back to one of the ``seeds``, chosen uniformly at random. A walker
+/// at a node without any edges always jumps back to the seeds. The
+/// proximity of a node is the probability of finding the walker there in the
+/// stationary distribution of this process, which is the same as the
+/// personalized PageRank of the nodes with the seeds as the personalization
+/// set. For details refer to:
+///
+/// Hanghang Tong, Christos Faloutsos and Jia-Yu Pan. "Fast random walk with
+/// restart and its applications." Sixth International Conference on Data
+/// Mining (2006): 613-622. <https://doi.org/10.1109/ICDM.2006.70>
+///
+/// This function uses power iteration on the sparse transition matrix and
+/// convergence is not guaranteed. The function will stop when ``max_iter``
+/// iterations is reached or when the L1 norm of the difference between the
+/// proximities of two iterations is smaller than ``tol``. In the case of
+/// multigraphs the weights of any parallel edges will be summed.
+///
+/// :param PyGraph graph: The graph to run the random walks on
+/// :param list seeds: The node indices the walker restarts from
+/// :param float restart_prob: The probability of jumping back to the seeds
+///     at every step, greater than 0 and at most 1. Defaults to 0.15.
+/// :param weight_fn: An optional input callable that will be passed the edge's
+///     payload object and is expected to return a non-negative ``float``
+///     weight for that edge. If this is not specified ``default_weight`` will
+///     be used as the weight for every edge in ``graph``
+/// :param float default_weight: If ``weight_fn`` is not set the default
+///     weight value to use for the weight of all edges
+/// :param int max_iter: The maximum number of iterations in the power method.
+///     Defaults to 100.
+/// :param float tol: The error tolerance used when checking for convergence.
+///     Defaults to 1e-6.
+///
+/// :returns: A read-only dict-like object whose keys are the node indices and
+///     values are the proximity of that node to the seeds. The proximities
+///     sum to 1.
+/// :rtype: CentralityMapping
+///
+/// :raises ValueError: If ``seeds`` is empty or ``restart_prob`` is invalid
+/// :raises FailedToConverge: If the power iteration doesn't converge within
+///     ``max_iter`` iterations
+///
+/// .. jupyter-execute::
+///
+///   import rustworkx as rx
+///
+///   graph = rx.generators.path_graph(5)
+///   proximity = rx.graph_random_walk_with_restart(graph, [0])
+///   print(proximity)
+#[pyfunction]
+#[pyo3(
+    signature = (graph, seeds, /, restart_prob=0.15, weight_fn=None, default_weight=1.0, max_iter=100, tol=1e-6),
+    text_signature = "(graph, seeds, /, restart_prob=0.15, weight_fn=None, default_weight=1.0, max_iter=100, tol=1e-6)"
+)]
+pub fn graph_random_walk_with_restart(
+    py: Python,
+    graph: &PyGraph,
+    seeds: Vec<usize>,
+    restart_prob: f64,
+    weight_fn: Option<PyObject>,
+    default_weight: f64,
+    max_iter: usize,
+    tol: f64,
+) -> PyResult<CentralityMapping> {
+    random_walk_with_restart(
+        py,
+        &graph.graph,
+        seeds,
+        restart_prob,
+        weight_fn,
+        default_weight,
+        max_iter,
+        tol,
+    )
+}
 
-    Ok((
-        CentralityMapping {
-            centralities: hubs_map,
-        },
-        CentralityMapping {
-            centralities: auth_map,
-        },
-    ))
+/// Compute the random walk with restart proximity of every node in a
+/// :class:`~.PyDiGraph` to a set of seed nodes
+///
+/// A random walker starts at one of the ``seeds``, and at every step either
+/// moves along one of the edges out of its current node, chosen with a probability
+/// proportional to the edge weights, or with probability ``restart_prob``
+/// jumps back to one of the ``seeds``, chosen uniformly at random. A walker
+/// at a node without any edges out of it always jumps back to the seeds. The
+/// proximity of a node is the probability of finding the walker there in the
+/// stationary distribution of this process, which is the same as the
+/// personalized PageRank of the nodes with the seeds as the personalization
+/// set. For details refer to:
+///
+/// Hanghang Tong, Christos Faloutsos and Jia-Yu Pan. "Fast random walk with
+/// restart and its applications." Sixth International Conference on Data
+/// Mining (2006): 613-622. <https://doi.org/10.1109/ICDM.2006.70>
+///
+/// This function uses power iteration on the sparse transition matrix and
+/// convergence is not guaranteed. The function will stop when ``max_iter``
+/// iterations is reached or when the L1 norm of the difference between the
+/// proximities of two iterations is smaller than ``tol``. In the case of
+/// multigraphs the weights of any parallel edges will be summed.
+///
+/// :param PyDiGraph graph: The graph to run the random walks on
+/// :param list seeds: The node indices the walker restarts from
+/// :param float restart_prob: The probability of jumping back to the seeds
+///     at every step, greater than 0 and at most 1. Defaults to 0.15.
+/// :param weight_fn: An optional input callable that will be passed the edge's
+///     payload object and is expected to return a non-negative ``float``
+///     weight for that edge. If this is not specified ``default_weight`` will
+///     be used as the weight for every edge in ``graph``
+/// :param float default_weight: If ``weight_fn`` is not set the default
+///     weight value to use for the weight of all edges
+/// :param int max_iter: The maximum number of iterations in the power method.
+///     Defaults to 100.
+/// :param float tol: The error tolerance used when checking for convergence.
+///     Defaults to 1e-6.
+///
+/// :returns: A read-only dict-like object whose keys are the node indices and
+///     values are the proximity of that node to the seeds. The proximities
+///     sum to 1.
+/// :rtype: CentralityMapping
+///
+/// :raises ValueError: If ``seeds`` is empty or ``restart_prob`` is invalid
+/// :raises FailedToConverge: If the power iteration doesn't converge within
+///     ``max_iter`` iterations
+///
+/// .. jupyter-execute::
+///
+///   import rustworkx as rx
+///
+///   graph = rx.generators.directed_cycle_graph(5)
+///   proximity = rx.digraph_random_walk_with_restart(graph, [0])
+///   print(proximity)
+#[pyfunction]
+#[pyo3(
+    signature = (graph, seeds, /, restart_prob=0.15, weight_fn=None, default_weight=1.0, max_iter=100, tol=1e-6),
+    text_signature = "(graph, seeds, /, restart_prob=0.15, weight_fn=None, default_weight=1.0, max_iter=100, tol=1e-6)"
+)]
+pub fn digraph_random_walk_with_restart(
+    py: Python,
+    graph: &PyDiGraph,
+    seeds: Vec<usize>,
+    restart_prob: f64,
+    weight_fn: Option<PyObject>,
+    default_weight: f64,
+    max_iter: usize,
+    tol: f64,
+) -> PyResult<CentralityMapping> {
+    random_walk_with_restart(
+        py,
+        &graph.graph,
+        seeds,
+        restart_prob,
+        weight_fn,
+        default_weight,
+        max_iter,
+        tol,
+    )
 }
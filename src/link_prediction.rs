@@ -0,0 +1,543 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+use crate::{graph, InvalidNode, StablePyGraph};
+
+use hashbrown::HashMap;
+use petgraph::prelude::*;
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use rustworkx_core::link_prediction;
+
+type ScoredPairs = Vec<(usize, usize, f64)>;
+
+/// Validate the pairs of nodes to score, or if they aren't specified return
+/// every pair of distinct nodes which aren't adjacent.
+fn ebunch_or_non_edges(
+    graph: &StablePyGraph<Undirected>,
+    ebunch: Option<Vec<(usize, usize)>>,
+) -> PyResult<Vec<(NodeIndex, NodeIndex)>> {
+    match ebunch {
+        Some(ebunch) => ebunch
+            .into_iter()
+            .map(|(u, v)| {
+                for node in [u, v] {
+                    if !graph.contains_node(NodeIndex::new(node)) {
+                        return Err(InvalidNode::new_err(format!(
+                            "Node index {} is not in the graph",
+                            node
+                        )));
+                    }
+                }
+                Ok((NodeIndex::new(u), NodeIndex::new(v)))
+            })
+            .collect(),
+        None => {
+            let nodes: Vec<NodeIndex> = graph.node_indices().collect();
+            let mut pairs: Vec<(NodeIndex, NodeIndex)> = Vec::new();
+            for (i, u) in nodes.iter().enumerate() {
+                for v in &nodes[i + 1..] {
+                    if graph.find_edge(*u, *v).is_none() {
+                        pairs.push((*u, *v));
+                    }
+                }
+            }
+            Ok(pairs)
+        }
+    }
+}
+
+fn scored_pairs(ebunch: &[(NodeIndex, NodeIndex)], scores: Vec<f64>) -> ScoredPairs {
+    ebunch
+        .iter()
+        .zip(scores)
+        .map(|((u, v), score)| (u.index(), v.index(), score))
+        .collect()
+}
+
+fn community_of(community: &HashMap<usize, usize>, node: NodeIndex) -> PyResult<usize> {
+    community.get(&node.index()).copied().ok_or_else(|| {
+        PyValueError::new_err(format!("Node index {} has no community", node.index()))
+    })
+}
+
+/// Compute the Jaccard coefficient of pairs of nodes in a :class:`~.PyGraph`
+///
+/// The Jaccard coefficient of the nodes :math:`u` and :math:`v` is
+///
+/// .. math::
+///
+///     \frac{|N(u) \cap N(v)|}{|N(u) \cup N(v)|}
+///
+/// where :math:`N(u)` is the set of neighbors of :math:`u`. It is 0 if
+/// neither node has any neighbors. Parallel edges are ignored.
+///
+/// This function is multithreaded and will score the pairs in parallel if
+/// there are at least ``parallel_threshold`` of them. If the function will
+/// be running in parallel the env var ``RAYON_NUM_THREADS`` can be used to
+/// adjust how many threads will be used.
+///
+/// :param PyGraph graph: The graph the nodes are in
+/// :param list ebunch: An optional list of pairs of node indices to score. If
+///     not specified every pair of distinct nodes that aren't adjacent is
+///     scored.
+/// :param int parallel_threshold: The number of pairs to score in parallel at,
+///     if ``ebunch`` has fewer pairs than this they are scored in a single
+///     thread. Defaults to 1000.
+///
+/// :returns: A list of ``(u, v, score)`` tuples for every pair of nodes in
+///     ``ebunch``, in the same order
+/// :rtype: list[tuple[int, int, float]]
+///
+/// .. jupyter-execute::
+///
+///   import rustworkx as rx
+///
+///   graph = rx.generators.cycle_graph(5)
+///   print(rx.jaccard_coefficient(graph, [(0, 2), (0, 1)]))
+#[pyfunction]
+#[pyo3(
+    signature = (graph, ebunch=None, parallel_threshold=1000),
+    text_signature = "(graph, /, ebunch=None, parallel_threshold=1000)"
+)]
+pub fn jaccard_coefficient(
+    graph: &graph::PyGraph,
+    ebunch: Option<Vec<(usize, usize)>>,
+    parallel_threshold: usize,
+) -> PyResult<ScoredPairs> {
+    let ebunch = ebunch_or_non_edges(&graph.graph, ebunch)?;
+    let scores = link_prediction::jaccard_coefficient(&graph.graph, &ebunch, parallel_threshold);
+    Ok(scored_pairs(&ebunch, scores))
+}
+
+/// Compute the Adamic-Adar index of pairs of nodes in a :class:`~.PyGraph`
+///
+/// The Adamic-Adar index of the nodes :math:`u` and :math:`v` is
+///
+/// .. math::
+///
+///     \sum_{w \in N(u) \cap N(v)} \frac{1}{\log |N(w)|}
+///
+/// where :math:`N(u)` is the set of neighbors of :math:`u`. Parallel edges
+/// are ignored.
+///
+/// This function is multithreaded and will score the pairs in parallel if
+/// there are at least ``parallel_threshold`` of them. If the function will
+/// be running in parallel the env var ``RAYON_NUM_THREADS`` can be used to
+/// adjust how many threads will be used.
+///
+/// :param PyGraph graph: The graph the nodes are in
+/// :param list ebunch: An optional list of pairs of node indices to score. If
+///     not specified every pair of distinct nodes that aren't adjacent is
+///     scored.
+/// :param int parallel_threshold: The number of pairs to score in parallel at,
+///     if ``ebunch`` has fewer pairs than this they are scored in a single
+///     thread. Defaults to 1000.
+///
+/// :returns: A list of ``(u, v, score)`` tuples for every pair of nodes in
+///     ``ebunch``, in the same order
+/// :rtype: list[tuple[int, int, float]]
+///
+/// .. jupyter-execute::
+///
+///   import rustworkx as rx
+///
+///   graph = rx.generators.cycle_graph(5)
+///   print(rx.adamic_adar_index(graph, [(0, 2), (0, 1)]))
+#[pyfunction]
+#[pyo3(
+    signature = (graph, ebunch=None, parallel_threshold=1000),
+    text_signature = "(graph, /, ebunch=None, parallel_threshold=1000)"
+)]
+pub fn adamic_adar_index(
+    graph: &graph::PyGraph,
+    ebunch: Option<Vec<(usize, usize)>>,
+    parallel_threshold: usize,
+) -> PyResult<ScoredPairs> {
+    let ebunch = ebunch_or_non_edges(&graph.graph, ebunch)?;
+    let scores = link_prediction::adamic_adar_index(&graph.graph, &ebunch, parallel_threshold);
+    Ok(scored_pairs(&ebunch, scores))
+}
+
+/// Compute the resource allocation index of pairs of nodes in a
+/// :class:`~.PyGraph`
+///
+/// The resource allocation index of the nodes :math:`u` and :math:`v` is
+///
+/// .. math::
+///
+///     \sum_{w \in N(u) \cap N(v)} \frac{1}{|N(w)|}
+///
+/// where :math:`N(u)` is the set of neighbors of :math:`u`. Parallel edges
+/// are ignored.
+///
+/// This function is multithreaded and will score the pairs in parallel if
+/// there are at least ``parallel_threshold`` of them. If the function will
+/// be running in parallel the env var ``RAYON_NUM_THREADS`` can be used to
+/// adjust how many threads will be used.
+///
+/// :param PyGraph graph: The graph the nodes are in
+/// :param list ebunch: An optional list of pairs of node indices to score. If
+///     not specified every pair of distinct nodes that aren't adjacent is
+///     scored.
+/// :param int parallel_threshold: The number of pairs to score in parallel at,
+///     if ``ebunch`` has fewer pairs than this they are scored in a single
+///     thread. Defaults to 1000.
+///
+/// :returns: A list of ``(u, v, score)`` tuples for every pair of nodes in
+///     ``ebunch``, in the same order
+/// :rtype: list[tuple[int, int, float]]
+///
+/// .. jupyter-execute::
+///
+///   import rustworkx as rx
+///
+///   graph = rx.generators.cycle_graph(5)
+///   print(rx.resource_allocation_index(graph, [(0, 2), (0, 1)]))
+#[pyfunction]
+#[pyo3(
+    signature = (graph, ebunch=None, parallel_threshold=1000),
+    text_signature = "(graph, /, ebunch=None, parallel_threshold=1000)"
+)]
+pub fn resource_allocation_index(
+    graph: &graph::PyGraph,
+    ebunch: Option<Vec<(usize, usize)>>,
+    parallel_threshold: usize,
+) -> PyResult<ScoredPairs> {
+    let ebunch = ebunch_or_non_edges(&graph.graph, ebunch)?;
+    let scores =
+        link_prediction::resource_allocation_index(&graph.graph, &ebunch, parallel_threshold);
+    Ok(scored_pairs(&ebunch, scores))
+}
+
+/// Compute the preferential attachment score of pairs of nodes in a
+/// :class:`~.PyGraph`
+///
+/// The preferential attachment score of the nodes :math:`u` and :math:`v`
+/// is :math:`|N(u)| |N(v)|`, where :math:`N(u)` is the set of neighbors of
+/// :math:`u`. Parallel edges are ignored.
+///
+/// This function is multithreaded and will score the pairs in parallel if
+/// there are at least ``parallel_threshold`` of them. If the function will
+/// be running in parallel the env var ``RAYON_NUM_THREADS`` can be used to
+/// adjust how many threads will be used.
+///
+/// :param PyGraph graph: The graph the nodes are in
+/// :param list ebunch: An optional list of pairs of node indices to score. If
+///     not specified every pair of distinct nodes that aren't adjacent is
+///     scored.
+/// :param int parallel_threshold: The number of pairs to score in parallel at,
+///     if ``ebunch`` has fewer pairs than this they are scored in a single
+///     thread. Defaults to 1000.
+///
+/// :returns: A list of ``(u, v, score)`` tuples for every pair of nodes in
+///     ``ebunch``, in the same order
+/// :rtype: list[tuple[int, int, float]]
+///
+/// .. jupyter-execute::
+///
+///   import rustworkx as rx
+///
+///   graph = rx.generators.star_graph(5)
+///   print(rx.preferential_attachment(graph, [(0, 1), (1, 2)]))
+#[pyfunction]
+#[pyo3(
+    signature = (graph, ebunch=None, parallel_threshold=1000),
+    text_signature = "(graph, /, ebunch=None, parallel_threshold=1000)"
+)]
+pub fn preferential_attachment(
+    graph: &graph::PyGraph,
+    ebunch: Option<Vec<(usize, usize)>>,
+    parallel_threshold: usize,
+) -> PyResult<ScoredPairs> {
+    let ebunch = ebunch_or_non_edges(&graph.graph, ebunch)?;
+    let scores =
+        link_prediction::preferential_attachment(&graph.graph, &ebunch, parallel_threshold);
+    Ok(scored_pairs(&ebunch, scores))
+}
+
+/// Compute the common neighbor and centrality based parameterized algorithm
+/// (CCPA) score of pairs of nodes in a :class:`~.PyGraph`
+///
+/// The CCPA score of the nodes :math:`u` and :math:`v` is
+///
+/// .. math::
+///
+///     \alpha |N(u) \cap N(v)| + (1 - \alpha) \frac{|V|}{d(u, v)}
+///
+/// where :math:`N(u)` is the set of neighbors of :math:`u` and
+/// :math:`d(u, v)` is the length of the shortest path between :math:`u` and
+/// :math:`v`. The second term is 0 if there is no path between :math:`u`
+/// and :math:`v`. For details refer to:
+///
+/// Iftikhar Ahmad, Muhammad Usman Akhtar, Salma Noor and Ambreen Shahnaz.
+/// "Missing Link Prediction using Common Neighbor and Centrality based
+/// Parameterized Algorithm." Scientific Reports 10.1 (2020): 364.
+/// <https://doi.org/10.1038/s41598-019-57304-y>
+///
+/// This function is multithreaded and will score the pairs in parallel if
+/// there are at least ``parallel_threshold`` of them. If the function will
+/// be running in parallel the env var ``RAYON_NUM_THREADS`` can be used to
+/// adjust how many threads will be used.
+///
+/// :param PyGraph graph: The graph the nodes are in
+/// :param list ebunch: An optional list of pairs of node indices to score. If
+///     not specified every pair of distinct nodes that aren't adjacent is
+///     scored.
+/// :param float alpha: The weight of the number of common neighbors, between
+///     0 and 1. Defaults to 0.8.
+/// :param int parallel_threshold: The number of pairs to score in parallel at,
+///     if ``ebunch`` has fewer pairs than this they are scored in a single
+///     thread. Defaults to 1000.
+///
+/// :returns: A list of ``(u, v, score)`` tuples for every pair of nodes in
+///     ``ebunch``, in the same order
+/// :rtype: list[tuple[int, int, float]]
+///
+/// :raises ValueError: If ``ebunch`` contains a pair of a node with itself
+///
+/// .. jupyter-execute::
+///
+///   import rustworkx as rx
+///
+///   graph = rx.generators.path_graph(5)
+///   print(rx.common_neighbor_centrality(graph, [(0, 2), (0, 4)]))
+#[pyfunction]
+#[pyo3(
+    signature = (graph, ebunch=None, alpha=0.8, parallel_threshold=1000),
+    text_signature = "(graph, /, ebunch=None, alpha=0.8, parallel_threshold=1000)"
+)]
+pub fn common_neighbor_centrality(
+    graph: &graph::PyGraph,
+    ebunch: Option<Vec<(usize, usize)>>,
+    alpha: f64,
+    parallel_threshold: usize,
+) -> PyResult<ScoredPairs> {
+    if !(0. ..=1.).contains(&alpha) {
+        return Err(PyValueError::new_err("alpha must be between 0 and 1"));
+    }
+    let ebunch = ebunch_or_non_edges(&graph.graph, ebunch)?;
+    if ebunch.iter().any(|(u, v)| u == v) {
+        return Err(PyValueError::new_err(
+            "The score of a node with itself is not defined",
+        ));
+    }
+    let scores = link_prediction::common_neighbor_centrality(
+        &graph.graph,
+        &ebunch,
+        alpha,
+        parallel_threshold,
+    );
+    Ok(scored_pairs(&ebunch, scores))
+}
+
+/// Compute the community aware common neighbor score of pairs of nodes in a
+/// :class:`~.PyGraph`
+///
+/// This is the number of common neighbors of the nodes :math:`u` and
+/// :math:`v`, where common neighbors in the same community as :math:`u` and
+/// :math:`v` count twice. If :math:`u` and :math:`v` are in different
+/// communities this is just the number of common neighbors. For details
+/// refer to:
+///
+/// Sucheta Soundarajan and John Hopcroft. "Using community information to
+/// improve the precision of link prediction methods." Proceedings of the 21st
+/// International Conference on World Wide Web (2012): 607-608.
+/// <https://doi.org/10.1145/2187980.2188150>
+///
+/// This function is multithreaded and will score the pairs in parallel if
+/// there are at least ``parallel_threshold`` of them. If the function will
+/// be running in parallel the env var ``RAYON_NUM_THREADS`` can be used to
+/// adjust how many threads will be used.
+///
+/// :param PyGraph graph: The graph the nodes are in
+/// :param dict community: A dictionary mapping every node index in the graph
+///     to the integer label of its community
+/// :param list ebunch: An optional list of pairs of node indices to score. If
+///     not specified every pair of distinct nodes that aren't adjacent is
+///     scored.
+/// :param int parallel_threshold: The number of pairs to score in parallel at,
+///     if ``ebunch`` has fewer pairs than this they are scored in a single
+///     thread. Defaults to 1000.
+///
+/// :returns: A list of ``(u, v, score)`` tuples for every pair of nodes in
+///     ``ebunch``, in the same order
+/// :rtype: list[tuple[int, int, float]]
+///
+/// :raises ValueError: If ``community`` is missing a node in the graph
+///
+/// .. jupyter-execute::
+///
+///   import rustworkx as rx
+///
+///   graph = rx.generators.cycle_graph(4)
+///   community = {0: 0, 1: 0, 2: 0, 3: 1}
+///   print(rx.cn_soundarajan_hopcroft(graph, community, [(0, 2)]))
+#[pyfunction]
+#[pyo3(
+    signature = (graph, community, ebunch=None, parallel_threshold=1000),
+    text_signature = "(graph, community, /, ebunch=None, parallel_threshold=1000)"
+)]
+pub fn cn_soundarajan_hopcroft(
+    graph: &graph::PyGraph,
+    community: HashMap<usize, usize>,
+    ebunch: Option<Vec<(usize, usize)>>,
+    parallel_threshold: usize,
+) -> PyResult<ScoredPairs> {
+    let ebunch = ebunch_or_non_edges(&graph.graph, ebunch)?;
+    let scores = link_prediction::cn_soundarajan_hopcroft(
+        &graph.graph,
+        &ebunch,
+        |node| community_of(&community, node),
+        parallel_threshold,
+    )?;
+    Ok(scored_pairs(&ebunch, scores))
+}
+
+/// Compute the community aware resource allocation index of pairs of nodes
+/// in a :class:`~.PyGraph`
+///
+/// This is the resource allocation index of the nodes :math:`u` and
+/// :math:`v` (see :func:`~rustworkx.resource_allocation_index`) where only
+/// the common neighbors in the same community as :math:`u` and :math:`v` are
+/// counted. It is 0 if :math:`u` and :math:`v` are in different communities.
+/// For details refer to:
+///
+/// Sucheta Soundarajan and John Hopcroft. "Using community information to
+/// improve the precision of link prediction methods." Proceedings of the 21st
+/// International Conference on World Wide Web (2012): 607-608.
+/// <https://doi.org/10.1145/2187980.2188150>
+///
+/// This function is multithreaded and will score the pairs in parallel if
+/// there are at least ``parallel_threshold`` of them. If the function will
+/// be running in parallel the env var ``RAYON_NUM_THREADS`` can be used to
+/// adjust how many threads will be used.
+///
+/// :param PyGraph graph: The graph the nodes are in
+/// :param dict community: A dictionary mapping every node index in the graph
+///     to the integer label of its community
+/// :param list ebunch: An optional list of pairs of node indices to score. If
+///     not specified every pair of distinct nodes that aren't adjacent is
+///     scored.
+/// :param int parallel_threshold: The number of pairs to score in parallel at,
+///     if ``ebunch`` has fewer pairs than this they are scored in a single
+///     thread. Defaults to 1000.
+///
+/// :returns: A list of ``(u, v, score)`` tuples for every pair of nodes in
+///     ``ebunch``, in the same order
+/// :rtype: list[tuple[int, int, float]]
+///
+/// :raises ValueError: If ``community`` is missing a node in the graph
+///
+/// .. jupyter-execute::
+///
+///   import rustworkx as rx
+///
+///   graph = rx.generators.cycle_graph(4)
+///   community = {0: 0, 1: 0, 2: 0, 3: 1}
+///   print(rx.ra_index_soundarajan_hopcroft(graph, community, [(0, 2)]))
+#[pyfunction]
+#[pyo3(
+    signature = (graph, community, ebunch=None, parallel_threshold=1000),
+    text_signature = "(graph, community, /, ebunch=None, parallel_threshold=1000)"
+)]
+pub fn ra_index_soundarajan_hopcroft(
+    graph: &graph::PyGraph,
+    community: HashMap<usize, usize>,
+    ebunch: Option<Vec<(usize, usize)>>,
+    parallel_threshold: usize,
+) -> PyResult<ScoredPairs> {
+    let ebunch = ebunch_or_non_edges(&graph.graph, ebunch)?;
+    let scores = link_prediction::ra_index_soundarajan_hopcroft(
+        &graph.graph,
+        &ebunch,
+        |node| community_of(&community, node),
+        parallel_threshold,
+    )?;
+    Ok(scored_pairs(&ebunch, scores))
+}
+
+/// Compute the ratio of within- and inter-cluster common neighbors of pairs
+/// of nodes in a :class:`~.PyGraph`
+///
+/// If the nodes :math:`u` and :math:`v` are in the same community, this is
+///
+/// .. math::
+///
+///     \frac{|W|}{|I| + \delta}
+///
+/// where :math:`W` are the common neighbors of :math:`u` and :math:`v` in the
+/// same community as them and :math:`I` are their other common neighbors. It
+/// is 0 if :math:`u` and :math:`v` are in different communities. For details
+/// refer to:
+///
+/// Jorge Carlos Valverde-Rebaza and Alneu de Andrade Lopes. "Link prediction
+/// in complex networks based on cluster information." Advances in Artificial
+/// Intelligence - SBIA 2012: 92-101.
+/// <https://doi.org/10.1007/978-3-642-34459-6_10>
+///
+/// This function is multithreaded and will score the pairs in parallel if
+/// there are at least ``parallel_threshold`` of them. If the function will
+/// be running in parallel the env var ``RAYON_NUM_THREADS`` can be used to
+/// adjust how many threads will be used.
+///
+/// :param PyGraph graph: The graph the nodes are in
+/// :param dict community: A dictionary mapping every node index in the graph
+///     to the integer label of its community
+/// :param list ebunch: An optional list of pairs of node indices to score. If
+///     not specified every pair of distinct nodes that aren't adjacent is
+///     scored.
+/// :param float delta: A positive value added to the number of inter-cluster
+///     common neighbors to avoid dividing by zero. Defaults to 0.001.
+/// :param int parallel_threshold: The number of pairs to score in parallel at,
+///     if ``ebunch`` has fewer pairs than this they are scored in a single
+///     thread. Defaults to 1000.
+///
+/// :returns: A list of ``(u, v, score)`` tuples for every pair of nodes in
+///     ``ebunch``, in the same order
+/// :rtype: list[tuple[int, int, float]]
+///
+/// :raises ValueError: If ``community`` is missing a node in the graph or
+///     ``delta`` isn't positive
+///
+/// .. jupyter-execute::
+///
+///   import rustworkx as rx
+///
+///   graph = rx.generators.cycle_graph(4)
+///   community = {0: 0, 1: 0, 2: 0, 3: 1}
+///   print(rx.within_inter_cluster(graph, community, [(0, 2)]))
+#[pyfunction]
+#[pyo3(
+    signature = (graph, community, ebunch=None, delta=0.001, parallel_threshold=1000),
+    text_signature = "(graph, community, /, ebunch=None, delta=0.001, parallel_threshold=1000)"
+)]
+pub fn within_inter_cluster(
+    graph: &graph::PyGraph,
+    community: HashMap<usize, usize>,
+    ebunch: Option<Vec<(usize, usize)>>,
+    delta: f64,
+    parallel_threshold: usize,
+) -> PyResult<ScoredPairs> {
+    if delta.is_nan() || delta <= 0. {
+        return Err(PyValueError::new_err("delta must be positive"));
+    }
+    let ebunch = ebunch_or_non_edges(&graph.graph, ebunch)?;
+    let scores = link_prediction::within_inter_cluster(
+        &graph.graph,
+        &ebunch,
+        |node| community_of(&community, node),
+        delta,
+        parallel_threshold,
+    )?;
+    Ok(scored_pairs(&ebunch, scores))
+}
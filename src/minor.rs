@@ -0,0 +1,47 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+use crate::graph::PyGraph;
+use rustworkx_core::minor;
+
+use pyo3::prelude::*;
+
+/// Check if an undirected graph is a minor of another undirected graph.
+///
+/// A graph ``pattern`` is a minor of ``graph`` if ``pattern`` can be
+/// obtained from ``graph`` by deleting nodes, deleting edges and contracting
+/// edges. Self-loops and parallel edges of both graphs are ignored, and the
+/// data payloads aren't used.
+///
+/// The check is a backtracking search over the sets of nodes of ``graph``
+/// that could be contracted into every node of ``pattern``, which takes
+/// exponential time in the worst case. It is meant for small pattern graphs,
+/// for example to look for a :math:`K_5` or :math:`K_{3,3}` minor.
+///
+/// :param PyGraph pattern: The graph to find as a minor.
+/// :param PyGraph graph: The graph to search in.
+///
+/// :returns: Whether ``pattern`` is a minor of ``graph``.
+/// :rtype: bool
+///
+/// .. jupyter-execute::
+///
+///   import rustworkx as rx
+///
+///   k5 = rx.generators.complete_graph(5)
+///   petersen = rx.generators.generalized_petersen_graph(5, 2)
+///   print(rx.is_minor_of(k5, petersen))
+#[pyfunction]
+#[pyo3(text_signature = "(pattern, graph, /)")]
+pub fn is_minor_of(pattern: &PyGraph, graph: &PyGraph) -> bool {
+    minor::is_minor_of(&pattern.graph, &graph.graph)
+}
@@ -0,0 +1,128 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+use crate::digraph::PyDiGraph;
+use crate::graph::PyGraph;
+use crate::StablePyGraph;
+
+use petgraph::algo;
+use petgraph::graph::NodeIndex;
+use petgraph::Directed;
+use rustworkx_core::modular_decomposition::{
+    is_cograph as core_is_cograph, modular_decomposition as core_modular_decomposition,
+    ModularDecomposition,
+};
+
+use pyo3::prelude::*;
+use pyo3::Python;
+
+/// Compute the modular decomposition of a :class:`~.PyGraph`.
+///
+/// A module of a graph is a set of nodes that all have the same neighbors
+/// outside of the set. The modular decomposition is the tree of the strong
+/// modules of the graph, the modules that don't overlap any other module.
+/// Every inner node of the tree is one of:
+///
+/// * ``"parallel"``: the subgraph of the module is disconnected and its
+///   children are the connected components.
+/// * ``"series"``: the complement of the subgraph of the module is
+///   disconnected, so every node of a child is adjacent to every node of the
+///   other children.
+/// * ``"prime"``: neither of the above, so the graph of the adjacencies
+///   between the children is prime.
+///
+/// and every leaf is a single node of the graph. A graph is a cograph exactly
+/// when its decomposition has no prime node, see :func:`~rustworkx.is_cograph`.
+///
+/// The decomposition is computed top down, which takes :math:`O(n^4)` time in
+/// the worst case, although graphs with few prime modules are decomposed much
+/// faster. Self-loops and parallel edges are ignored.
+///
+/// :param PyGraph graph: The graph to decompose.
+///
+/// :returns: The decomposition tree, with edges from every module to its
+///     children and the root at index ``0`` (or no nodes if ``graph`` is
+///     empty). The data payload of every node of the tree is a tuple of its
+///     kind, which is ``"parallel"``, ``"series"``, ``"prime"`` or ``"leaf"``,
+///     and the sorted list of the node indices of ``graph`` in the module.
+/// :rtype: PyDiGraph
+///
+/// .. jupyter-execute::
+///
+///   import rustworkx as rx
+///
+///   graph = rx.generators.star_graph(4)
+///   tree = rx.modular_decomposition(graph)
+///   for node in tree.node_indices():
+///       print(node, tree[node], list(tree.successor_indices(node)))
+#[pyfunction]
+#[pyo3(text_signature = "(graph, /)")]
+pub fn modular_decomposition(py: Python, graph: &PyGraph) -> PyDiGraph {
+    let mut tree: StablePyGraph<Directed> = StablePyGraph::default();
+    if let Some(root) = core_modular_decomposition(&graph.graph) {
+        let mut stack: Vec<(Option<NodeIndex>, &ModularDecomposition<NodeIndex>)> =
+            vec![(None, &root)];
+        while let Some((parent, module)) = stack.pop() {
+            let kind = match module {
+                ModularDecomposition::Node(_) => "leaf",
+                ModularDecomposition::Parallel(_) => "parallel",
+                ModularDecomposition::Series(_) => "series",
+                ModularDecomposition::Prime(_) => "prime",
+            };
+            let mut nodes: Vec<usize> = module.nodes().iter().map(|node| node.index()).collect();
+            nodes.sort_unstable();
+            let index = tree.add_node((kind, nodes).to_object(py));
+            if let Some(parent) = parent {
+                tree.add_edge(parent, index, py.None());
+            }
+            stack.extend(
+                module
+                    .children()
+                    .iter()
+                    .rev()
+                    .map(|child| (Some(index), child)),
+            );
+        }
+    }
+    PyDiGraph {
+        graph: tree,
+        cycle_state: algo::DfsSpace::default(),
+        check_cycle: false,
+        node_removed: false,
+        multigraph: false,
+        attrs: py.None(),
+    }
+}
+
+/// Check if a :class:`~.PyGraph` is a cograph.
+///
+/// A cograph is a graph that can be built from single nodes by disjoint
+/// unions and complements, which is the same as having no induced path on
+/// four nodes or having a :func:`~rustworkx.modular_decomposition` without
+/// prime nodes. Self-loops and parallel edges are ignored.
+///
+/// :param PyGraph graph: The graph to check.
+///
+/// :returns: Whether ``graph`` is a cograph.
+/// :rtype: bool
+///
+/// .. jupyter-execute::
+///
+///   import rustworkx as rx
+///
+///   print(rx.is_cograph(rx.generators.star_graph(4)))
+///   print(rx.is_cograph(rx.generators.path_graph(4)))
+#[pyfunction]
+#[pyo3(text_signature = "(graph, /)")]
+pub fn is_cograph(graph: &PyGraph) -> bool {
+    core_is_cograph(&graph.graph)
+}
@@ -0,0 +1,317 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+use crate::{digraph, graph, StablePyGraph};
+
+use petgraph::EdgeType;
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pyo3::types::{PyDict, PyTuple};
+
+use rustworkx_core::dictmap::*;
+use rustworkx_core::motifs;
+
+fn class_to_py(py: Python, class: motifs::MotifClass) -> Bound<PyTuple> {
+    PyTuple::new_bound(py, class)
+}
+
+fn motif_counts<Ty: EdgeType>(
+    py: Python,
+    graph: &StablePyGraph<Ty>,
+    size: usize,
+    probabilities: Option<Vec<f64>>,
+    seed: Option<u64>,
+) -> PyResult<PyObject> {
+    let counts = motifs::motif_counts(graph, size, probabilities.as_deref(), seed)
+        .map_err(|err| PyValueError::new_err(err.to_string()))?;
+    let out = PyDict::new_bound(py);
+    for (class, count) in counts {
+        out.set_item(class_to_py(py, class), count)?;
+    }
+    Ok(out.into())
+}
+
+fn motif_significance_profile<Ty: EdgeType>(
+    py: Python,
+    graph: &StablePyGraph<Ty>,
+    size: usize,
+    num_randomizations: usize,
+    swaps_per_edge: usize,
+    probabilities: Option<Vec<f64>>,
+    seed: Option<u64>,
+) -> PyResult<PyObject> {
+    let profile = motifs::motif_significance_profile(
+        graph,
+        size,
+        num_randomizations,
+        swaps_per_edge,
+        probabilities.as_deref(),
+        seed,
+    )
+    .map_err(|err| PyValueError::new_err(err.to_string()))?;
+    let out = PyDict::new_bound(py);
+    for (class, stats) in profile {
+        let stats_dict = PyDict::new_bound(py);
+        stats_dict.set_item("count", stats.count)?;
+        stats_dict.set_item("random_mean", stats.random_mean)?;
+        stats_dict.set_item("random_std", stats.random_std)?;
+        stats_dict.set_item("z_score", stats.z_score)?;
+        stats_dict.set_item("significance", stats.significance)?;
+        out.set_item(class_to_py(py, class), stats_dict)?;
+    }
+    Ok(out.into())
+}
+
+/// Count the motifs of a :class:`~.PyGraph`.
+///
+/// The motifs are the connected induced subgraphs with ``size`` nodes,
+/// which are enumerated with the ESU algorithm of Wernicke [1]_. The graph is
+/// treated as a simple graph: self-loops are ignored and parallel edges are
+/// counted once.
+///
+/// With ``probabilities`` the motifs are sampled with the RAND-ESU algorithm
+/// instead, which is much faster on large graphs. The subgraphs are
+/// enumerated as a tree, and a subtree at depth ``d`` is only visited with
+/// probability ``probabilities[d]``. Every sampled motif is counted as
+/// ``1 / p`` motifs, where ``p`` is the product of the probabilities, so the
+/// counts are unbiased estimates.
+///
+/// :param PyGraph graph: The graph to count the motifs of
+/// :param int size: The number of nodes of the motifs, ``3`` or ``4``.
+///     Default: ``3``.
+/// :param list probabilities: The optional sampling probability at every
+///     depth, ``size`` values in ``(0, 1]``
+/// :param int seed: An optional seed to use for the random number generator
+///     of the sampling
+///
+/// :returns: A dictionary mapping the isomorphism class of every motif found
+///     to its count. A class is the tuple of the edges of its canonical form
+///     on the nodes ``0`` to ``size - 1``, with ``a < b`` for every edge
+///     ``(a, b)``.
+/// :rtype: dict
+/// :raises ValueError: If ``size`` or ``probabilities`` is invalid
+///
+/// .. [1] S. Wernicke, "Efficient detection of network motifs", IEEE/ACM
+///    Transactions on Computational Biology and Bioinformatics 3(4),
+///    347-359 (2006).
+#[pyfunction]
+#[pyo3(
+    signature=(graph, /, size=3, probabilities=None, seed=None),
+    text_signature = "(graph, /, size=3, probabilities=None, seed=None)"
+)]
+pub fn graph_motif_counts(
+    py: Python,
+    graph: &graph::PyGraph,
+    size: usize,
+    probabilities: Option<Vec<f64>>,
+    seed: Option<u64>,
+) -> PyResult<PyObject> {
+    motif_counts(py, &graph.graph, size, probabilities, seed)
+}
+
+/// Count the motifs of a :class:`~.PyDiGraph`.
+///
+/// The motifs are the weakly connected induced subgraphs with ``size``
+/// nodes, which are enumerated with the ESU algorithm of Wernicke [1]_. The
+/// graph is treated as a simple graph: self-loops are ignored and parallel
+/// edges are counted once.
+///
+/// With ``probabilities`` the motifs are sampled with the RAND-ESU algorithm
+/// instead, which is much faster on large graphs. The subgraphs are
+/// enumerated as a tree, and a subtree at depth ``d`` is only visited with
+/// probability ``probabilities[d]``. Every sampled motif is counted as
+/// ``1 / p`` motifs, where ``p`` is the product of the probabilities, so the
+/// counts are unbiased estimates.
+///
+/// :param PyDiGraph graph: The graph to count the motifs of
+/// :param int size: The number of nodes of the motifs, ``3`` or ``4``.
+///     Default: ``3``.
+/// :param list probabilities: The optional sampling probability at every
+///     depth, ``size`` values in ``(0, 1]``
+/// :param int seed: An optional seed to use for the random number generator
+///     of the sampling
+///
+/// :returns: A dictionary mapping the isomorphism class of every motif found
+///     to its count. A class is the tuple of the edges of its canonical form
+///     on the nodes ``0`` to ``size - 1``.
+/// :rtype: dict
+/// :raises ValueError: If ``size`` or ``probabilities`` is invalid
+///
+/// .. [1] S. Wernicke, "Efficient detection of network motifs", IEEE/ACM
+///    Transactions on Computational Biology and Bioinformatics 3(4),
+///    347-359 (2006).
+#[pyfunction]
+#[pyo3(
+    signature=(graph, /, size=3, probabilities=None, seed=None),
+    text_signature = "(graph, /, size=3, probabilities=None, seed=None)"
+)]
+pub fn digraph_motif_counts(
+    py: Python,
+    graph: &digraph::PyDiGraph,
+    size: usize,
+    probabilities: Option<Vec<f64>>,
+    seed: Option<u64>,
+) -> PyResult<PyObject> {
+    motif_counts(py, &graph.graph, size, probabilities, seed)
+}
+
+/// Compute the motif significance profile of a :class:`~.PyGraph`.
+///
+/// The motifs are counted as with :func:`~rustworkx.graph_motif_counts` in
+/// the graph and in ``num_randomizations`` random graphs with the same
+/// degrees, made with :func:`~rustworkx.graph_rewire`. Every class of motif
+/// found in any of the graphs gets the z-score of its count in the graph
+/// against the counts in the random graphs, and the significance profile of
+/// Milo et al. [1]_ is the vector of z-scores normalized to length ``1``. A
+/// positive significance means the motif is more common than expected from
+/// the degrees alone. The z-score of a class whose count is the same in all
+/// the random graphs is ``nan``, and it's left out of the normalization.
+///
+/// :param PyGraph graph: The graph to compute the significance profile of
+/// :param int size: The number of nodes of the motifs, ``3`` or ``4``.
+///     Default: ``3``.
+/// :param int num_randomizations: The number of random graphs. It must be
+///     positive. Default: ``100``.
+/// :param int swaps_per_edge: The number of edge swaps done to randomize the
+///     graph, per edge of the graph. Default: ``10``.
+/// :param list probabilities: The optional sampling probabilities of
+///     RAND-ESU, as in :func:`~rustworkx.graph_motif_counts`
+/// :param int seed: An optional seed to use for the random number generator
+///
+/// :returns: A dictionary mapping every class of motif to a dictionary with
+///     its ``count`` in the graph, the ``random_mean`` and ``random_std`` of
+///     its counts in the random graphs, its ``z_score`` and its
+///     ``significance``, the entry of the significance profile.
+/// :rtype: dict
+/// :raises ValueError: If ``size`` or ``probabilities`` is invalid or
+///     ``num_randomizations`` is ``0``
+///
+/// .. [1] R. Milo et al., "Superfamilies of evolved and designed networks",
+///    Science 303, 1538-1542 (2004).
+#[pyfunction]
+#[pyo3(
+    signature=(graph, /, size=3, num_randomizations=100, swaps_per_edge=10, probabilities=None, seed=None),
+    text_signature = "(graph, /, size=3, num_randomizations=100, swaps_per_edge=10, probabilities=None, seed=None)"
+)]
+pub fn graph_motif_significance_profile(
+    py: Python,
+    graph: &graph::PyGraph,
+    size: usize,
+    num_randomizations: usize,
+    swaps_per_edge: usize,
+    probabilities: Option<Vec<f64>>,
+    seed: Option<u64>,
+) -> PyResult<PyObject> {
+    motif_significance_profile(
+        py,
+        &graph.graph,
+        size,
+        num_randomizations,
+        swaps_per_edge,
+        probabilities,
+        seed,
+    )
+}
+
+/// Compute the motif significance profile of a :class:`~.PyDiGraph`.
+///
+/// The motifs are counted as with :func:`~rustworkx.digraph_motif_counts` in
+/// the graph and in ``num_randomizations`` random graphs with the same in and
+/// out degrees, made with :func:`~rustworkx.digraph_rewire`. Every class of
+/// motif found in any of the graphs gets the z-score of its count in the
+/// graph against the counts in the random graphs, and the significance
+/// profile of Milo et al. [1]_ is the vector of z-scores normalized to length
+/// ``1``. A positive significance means the motif is more common than
+/// expected from the degrees alone. The z-score of a class whose count is the
+/// same in all the random graphs is ``nan``, and it's left out of the
+/// normalization.
+///
+/// :param PyDiGraph graph: The graph to compute the significance profile of
+/// :param int size: The number of nodes of the motifs, ``3`` or ``4``.
+///     Default: ``3``.
+/// :param int num_randomizations: The number of random graphs. It must be
+///     positive. Default: ``100``.
+/// :param int swaps_per_edge: The number of edge swaps done to randomize the
+///     graph, per edge of the graph. Default: ``10``.
+/// :param list probabilities: The optional sampling probabilities of
+///     RAND-ESU, as in :func:`~rustworkx.digraph_motif_counts`
+/// :param int seed: An optional seed to use for the random number generator
+///
+/// :returns: A dictionary mapping every class of motif to a dictionary with
+///     its ``count`` in the graph, the ``random_mean`` and ``random_std`` of
+///     its counts in the random graphs, its ``z_score`` and its
+///     ``significance``, the entry of the significance profile.
+/// :rtype: dict
+/// :raises ValueError: If ``size`` or ``probabilities`` is invalid or
+///     ``num_randomizations`` is ``0``
+///
+/// .. [1] R. Milo et al., "Superfamilies of evolved and designed networks",
+///    Science 303, 1538-1542 (2004).
+#[pyfunction]
+#[pyo3(
+    signature=(graph, /, size=3, num_randomizations=100, swaps_per_edge=10, probabilities=None, seed=None),
+    text_signature = "(graph, /, size=3, num_randomizations=100, swaps_per_edge=10, probabilities=None, seed=None)"
+)]
+pub fn digraph_motif_significance_profile(
+    py: Python,
+    graph: &digraph::PyDiGraph,
+    size: usize,
+    num_randomizations: usize,
+    swaps_per_edge: usize,
+    probabilities: Option<Vec<f64>>,
+    seed: Option<u64>,
+) -> PyResult<PyObject> {
+    motif_significance_profile(
+        py,
+        &graph.graph,
+        size,
+        num_randomizations,
+        swaps_per_edge,
+        probabilities,
+        seed,
+    )
+}
+
+/// Compute the triad census of a :class:`~.PyDiGraph`.
+///
+/// The triad census counts the triples of nodes of the graph by the
+/// isomorphism class of the subgraph they induce, including the triples which
+/// aren't connected. The 16 classes are named in the MAN notation of Holland
+/// and Leinhardt: ``"003"``, ``"012"``, ``"102"``, ``"021D"``, ``"021U"``,
+/// ``"021C"``, ``"111D"``, ``"111U"``, ``"030T"``, ``"030C"``, ``"201"``,
+/// ``"120D"``, ``"120U"``, ``"120C"``, ``"210"`` and ``"300"``. The counts are
+/// computed with the algorithm of Batagelj and Mrvar [1]_, which only visits
+/// the connected triples. Self-loops are ignored and parallel edges are
+/// counted once.
+///
+/// :param PyDiGraph graph: The graph to compute the triad census of
+///
+/// :returns: A dictionary mapping the name of every class of triad to its
+///     count, with all 16 classes in the order above
+/// :rtype: dict
+///
+/// .. jupyter-execute::
+///
+///   import rustworkx as rx
+///
+///   graph = rx.PyDiGraph()
+///   graph.extend_from_edge_list([(0, 1), (1, 2), (2, 0), (2, 3)])
+///   print(rx.triad_census(graph))
+///
+/// .. [1] V. Batagelj and A. Mrvar, "A subquadratic triad census algorithm
+///    for large sparse networks with small maximum degree", Social Networks
+///    23, 237-243 (2001).
+#[pyfunction]
+#[pyo3(text_signature = "(graph, /)")]
+pub fn triad_census(graph: &digraph::PyDiGraph) -> DictMap<&'static str, usize> {
+    motifs::triad_census(&graph.graph)
+}
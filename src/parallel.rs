@@ -0,0 +1,86 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use pyo3::exceptions::{PyRuntimeError, PyValueError};
+use pyo3::prelude::*;
+
+use rustworkx_core::parallel;
+
+// The default for the ``num_threads`` argument of the parallel functions,
+// ``0`` if it isn't set
+static NUM_THREADS: AtomicUsize = AtomicUsize::new(0);
+
+/// Run ``op`` with at most ``num_threads`` threads, or the number of threads
+/// set with :func:`set_num_threads` if ``num_threads`` is ``None``.
+pub fn run_with_num_threads<OP, R>(num_threads: Option<usize>, op: OP) -> PyResult<R>
+where
+    OP: FnOnce() -> R + Send,
+    R: Send,
+{
+    let num_threads = match num_threads {
+        Some(0) => return Err(PyValueError::new_err("num_threads must be positive")),
+        Some(num_threads) => Some(num_threads),
+        None => match NUM_THREADS.load(Ordering::Relaxed) {
+            0 => None,
+            num_threads => Some(num_threads),
+        },
+    };
+    parallel::with_num_threads(num_threads, op)
+        .map_err(|err| PyRuntimeError::new_err(format!("Failed to start threads: {}", err)))
+}
+
+/// Set the default number of threads used by the parallel functions.
+///
+/// The functions which run in parallel, such as
+/// :func:`~rustworkx.betweenness_centrality` and
+/// :func:`~rustworkx.all_pairs_dijkstra_path_lengths`, use a thread per core
+/// by default, or the number of threads in the ``RAYON_NUM_THREADS``
+/// environment variable if it is set. This function caps the number of
+/// threads used by every function with a ``num_threads`` argument when that
+/// argument isn't specified, which is useful when rustworkx runs in an
+/// application that shares the cores with other work.
+///
+/// :param int num_threads: The maximum number of threads to use. If it is
+///     ``None`` the default number of threads is restored.
+///
+/// :raises ValueError: If ``num_threads`` is ``0``
+#[pyfunction]
+#[pyo3(signature=(num_threads=None), text_signature = "(num_threads=None)")]
+pub fn set_num_threads(num_threads: Option<usize>) -> PyResult<()> {
+    match num_threads {
+        Some(0) => Err(PyValueError::new_err("num_threads must be positive")),
+        Some(num_threads) => {
+            NUM_THREADS.store(num_threads, Ordering::Relaxed);
+            Ok(())
+        }
+        None => {
+            NUM_THREADS.store(0, Ordering::Relaxed);
+            Ok(())
+        }
+    }
+}
+
+/// Get the default number of threads used by the parallel functions.
+///
+/// :returns: The number of threads set with :func:`~rustworkx.set_num_threads`,
+///     or the size of the default thread pool if it isn't set
+/// :rtype: int
+#[pyfunction]
+#[pyo3(text_signature = "()")]
+pub fn get_num_threads() -> usize {
+    match NUM_THREADS.load(Ordering::Relaxed) {
+        0 => rayon::current_num_threads(),
+        num_threads => num_threads,
+    }
+}
@@ -0,0 +1,261 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+use crate::digraph::PyDiGraph;
+use crate::graph::PyGraph;
+use crate::{InvalidNode, StablePyGraph};
+
+use petgraph::prelude::*;
+use petgraph::EdgeType;
+use rustworkx_core::percolation::{self, RemovalStrategy};
+
+use pyo3::exceptions::{PyIndexError, PyValueError};
+use pyo3::prelude::*;
+
+#[derive(FromPyObject)]
+pub enum RemovalOrder {
+    Strategy(String),
+    Indices(Vec<usize>),
+}
+
+fn removal_strategy(name: &str, seed: Option<u64>) -> PyResult<RemovalStrategy> {
+    match name {
+        "random" => Ok(RemovalStrategy::Random(seed)),
+        "degree" => Ok(RemovalStrategy::Degree),
+        "betweenness" => Ok(RemovalStrategy::Betweenness),
+        _ => Err(PyValueError::new_err(format!(
+            "Invalid strategy '{}', it must be 'random', 'degree', 'betweenness' or a list of indices",
+            name
+        ))),
+    }
+}
+
+fn percolation_inner<Ty: EdgeType + Sync>(
+    graph: &StablePyGraph<Ty>,
+    strategy: RemovalOrder,
+    remove_edges: bool,
+    seed: Option<u64>,
+    threshold: f64,
+) -> PyResult<(Vec<usize>, Vec<usize>, Option<f64>)> {
+    if !(0.0..=1.0).contains(&threshold) {
+        return Err(PyValueError::new_err("threshold must be between 0 and 1"));
+    }
+    let (order, sizes, total) = if remove_edges {
+        let order: Vec<EdgeIndex> = match strategy {
+            RemovalOrder::Strategy(name) => {
+                percolation::edge_removal_order(graph, removal_strategy(&name, seed)?)
+            }
+            RemovalOrder::Indices(indices) => indices
+                .into_iter()
+                .map(|index| {
+                    let edge = EdgeIndex::new(index);
+                    match graph.edge_weight(edge) {
+                        Some(_) => Ok(edge),
+                        None => Err(PyIndexError::new_err(format!(
+                            "Provided edge index {} is not present in the graph",
+                            index
+                        ))),
+                    }
+                })
+                .collect::<PyResult<_>>()?,
+        };
+        let sizes = percolation::edge_percolation(graph, &order);
+        let order: Vec<usize> = order.into_iter().map(|edge| edge.index()).collect();
+        (order, sizes, graph.edge_count())
+    } else {
+        let order: Vec<NodeIndex> = match strategy {
+            RemovalOrder::Strategy(name) => {
+                percolation::node_removal_order(graph, removal_strategy(&name, seed)?)
+            }
+            RemovalOrder::Indices(indices) => indices
+                .into_iter()
+                .map(|index| {
+                    let node = NodeIndex::new(index);
+                    match graph.contains_node(node) {
+                        true => Ok(node),
+                        false => Err(InvalidNode::new_err(format!(
+                            "Node index {} is not in the graph",
+                            index
+                        ))),
+                    }
+                })
+                .collect::<PyResult<_>>()?,
+        };
+        let sizes = percolation::node_percolation(graph, &order);
+        let order: Vec<usize> = order.into_iter().map(|node| node.index()).collect();
+        (order, sizes, graph.node_count())
+    };
+    // The critical fraction is reached once the largest component has at
+    // most `threshold` of the nodes of the graph
+    let limit = threshold * graph.node_count() as f64;
+    let critical_fraction = sizes
+        .iter()
+        .position(|size| *size as f64 <= limit)
+        .filter(|_| total > 0)
+        .map(|position| position as f64 / total as f64);
+    Ok((order, sizes, critical_fraction))
+}
+
+/// Simulate the removal of the nodes or edges of a :class:`~.PyGraph` and
+/// track the size of its largest connected component
+///
+/// The nodes (or edges) are removed one at a time, in an order given by a
+/// strategy or by the caller, and the size of the largest connected
+/// component, the giant component, is reported after every removal. This
+/// measures how robust the connectivity of a network is to random failures
+/// or targeted attacks. Instead of recomputing the connected components
+/// after every removal, the nodes or edges are added back in reverse order
+/// with a union-find data structure [1]_, so the whole curve is computed in
+/// near linear time.
+///
+/// The strategies are:
+///
+/// * ``"random"``: a uniformly random order.
+/// * ``"degree"``: by decreasing degree. An edge is ranked by the product
+///   of the degrees of its endpoints.
+/// * ``"betweenness"``: by decreasing betweenness centrality, or edge
+///   betweenness centrality when removing edges.
+///
+/// The degree and betweenness are computed once for the original graph.
+///
+/// :param PyGraph graph: The graph to remove the nodes or edges of
+/// :param strategy: The name of the strategy that orders the nodes or edges,
+///     or a list of node (or edge) indices in the order they are removed,
+///     which doesn't have to contain every node (or edge) of the graph.
+///     Defaults to ``"random"``.
+/// :param bool remove_edges: If set to ``True`` the edges of the graph are
+///     removed instead of its nodes. Defaults to ``False``.
+/// :param int seed: An optional seed for the random number generator used
+///     by the ``"random"`` strategy.
+/// :param float threshold: The fraction of the nodes of the graph the giant
+///     component has at most for the graph to count as broken up, which
+///     defines the critical fraction. Defaults to ``0.1``.
+///
+/// :returns: A tuple of the order the nodes (or edges) were removed in, the
+///     size of the giant component after removing the first ``k`` of them
+///     for every ``k`` from ``0`` up to the length of the order, and the
+///     critical fraction: the smallest fraction of the nodes (or edges) of
+///     the graph whose removal leaves a giant component of at most
+///     ``threshold`` times the number of nodes. The critical fraction is
+///     ``None`` if the order never gets there.
+/// :rtype: tuple[list[int], list[int], float | None]
+/// :raises ValueError: If ``strategy`` isn't a valid strategy name or
+///     ``threshold`` isn't between 0 and 1
+/// :raises InvalidNode: If ``strategy`` contains a node that is not in the graph
+/// :raises IndexError: If ``strategy`` contains an edge that is not in the graph
+///
+/// .. jupyter-execute::
+///
+///   import rustworkx as rx
+///
+///   graph = rx.generators.barbell_graph(4, 2)
+///   order, sizes, critical = rx.graph_percolation(graph, strategy="betweenness")
+///   print(order)
+///   print(sizes)
+///   print(critical)
+///
+/// .. [1] Newman, M. E. J., and R. M. Ziff. "Efficient Monte Carlo
+///     algorithm and high-precision results for percolation." Physical
+///     Review Letters 85.19 (2000): 4104.
+///     https://doi.org/10.1103/PhysRevLett.85.4104
+#[pyfunction]
+#[pyo3(
+    signature = (graph, /, strategy=RemovalOrder::Strategy("random".to_string()), remove_edges=false, seed=None, threshold=0.1),
+    text_signature = "(graph, /, strategy=\"random\", remove_edges=False, seed=None, threshold=0.1)"
+)]
+pub fn graph_percolation(
+    graph: &PyGraph,
+    strategy: RemovalOrder,
+    remove_edges: bool,
+    seed: Option<u64>,
+    threshold: f64,
+) -> PyResult<(Vec<usize>, Vec<usize>, Option<f64>)> {
+    percolation_inner(&graph.graph, strategy, remove_edges, seed, threshold)
+}
+
+/// Simulate the removal of the nodes or edges of a :class:`~.PyDiGraph` and
+/// track the size of its largest weakly connected component
+///
+/// The nodes (or edges) are removed one at a time, in an order given by a
+/// strategy or by the caller, and the size of the largest weakly connected
+/// component, the giant component, is reported after every removal. This
+/// measures how robust the connectivity of a network is to random failures
+/// or targeted attacks. Instead of recomputing the weakly connected
+/// components after every removal, the nodes or edges are added back in
+/// reverse order with a union-find data structure [1]_, so the whole curve
+/// is computed in near linear time.
+///
+/// The strategies are:
+///
+/// * ``"random"``: a uniformly random order.
+/// * ``"degree"``: by decreasing total degree, the sum of the in and out
+///   degree. An edge is ranked by the product of the degrees of its
+///   endpoints.
+/// * ``"betweenness"``: by decreasing betweenness centrality along directed
+///   paths, or edge betweenness centrality when removing edges.
+///
+/// The degree and betweenness are computed once for the original graph.
+///
+/// :param PyDiGraph graph: The graph to remove the nodes or edges of
+/// :param strategy: The name of the strategy that orders the nodes or edges,
+///     or a list of node (or edge) indices in the order they are removed,
+///     which doesn't have to contain every node (or edge) of the graph.
+///     Defaults to ``"random"``.
+/// :param bool remove_edges: If set to ``True`` the edges of the graph are
+///     removed instead of its nodes. Defaults to ``False``.
+/// :param int seed: An optional seed for the random number generator used
+///     by the ``"random"`` strategy.
+/// :param float threshold: The fraction of the nodes of the graph the giant
+///     component has at most for the graph to count as broken up, which
+///     defines the critical fraction. Defaults to ``0.1``.
+///
+/// :returns: A tuple of the order the nodes (or edges) were removed in, the
+///     size of the giant component after removing the first ``k`` of them
+///     for every ``k`` from ``0`` up to the length of the order, and the
+///     critical fraction: the smallest fraction of the nodes (or edges) of
+///     the graph whose removal leaves a giant component of at most
+///     ``threshold`` times the number of nodes. The critical fraction is
+///     ``None`` if the order never gets there.
+/// :rtype: tuple[list[int], list[int], float | None]
+/// :raises ValueError: If ``strategy`` isn't a valid strategy name or
+///     ``threshold`` isn't between 0 and 1
+/// :raises InvalidNode: If ``strategy`` contains a node that is not in the graph
+/// :raises IndexError: If ``strategy`` contains an edge that is not in the graph
+///
+/// .. jupyter-execute::
+///
+///   import rustworkx as rx
+///
+///   graph = rx.generators.directed_star_graph(6)
+///   order, sizes, critical = rx.digraph_percolation(graph, strategy="degree", threshold=0.2)
+///   print(order)
+///   print(sizes)
+///   print(critical)
+///
+/// .. [1] Newman, M. E. J., and R. M. Ziff. "Efficient Monte Carlo
+///     algorithm and high-precision results for percolation." Physical
+///     Review Letters 85.19 (2000): 4104.
+///     https://doi.org/10.1103/PhysRevLett.85.4104
+#[pyfunction]
+#[pyo3(
+    signature = (graph, /, strategy=RemovalOrder::Strategy("random".to_string()), remove_edges=false, seed=None, threshold=0.1),
+    text_signature = "(graph, /, strategy=\"random\", remove_edges=False, seed=None, threshold=0.1)"
+)]
+pub fn digraph_percolation(
+    graph: &PyDiGraph,
+    strategy: RemovalOrder,
+    remove_edges: bool,
+    seed: Option<u64>,
+    threshold: f64,
+) -> PyResult<(Vec<usize>, Vec<usize>, Option<f64>)> {
+    percolation_inner(&graph.graph, strategy, remove_edges, seed, threshold)
+}
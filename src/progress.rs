@@ -0,0 +1,132 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pyo3::{PyTraverseError, PyVisit};
+
+use rayon::prelude::*;
+
+use crate::parallel::run_with_num_threads;
+
+// The interval used when the total amount of work isn't known up front
+const DEFAULT_INTERVAL: usize = 1000;
+// The number of calls to the callback when the total amount of work is known
+const DEFAULT_REPORTS: usize = 100;
+
+/// The ``progress_fn`` and ``progress_interval`` arguments of the long running
+/// functions.
+///
+/// The callback is called as ``progress_fn(done, total)`` every ``interval``
+/// units of work, with ``total`` set to ``None`` if it isn't known. An
+/// exception raised by the callback stops the function and is raised from
+/// it, which is how a computation is cancelled. Pending signals, such as a
+/// ``KeyboardInterrupt``, are checked at the same points.
+pub struct ProgressFn {
+    callback: Option<PyObject>,
+    interval: Option<usize>,
+}
+
+impl ProgressFn {
+    pub fn new(callback: Option<PyObject>, interval: Option<usize>) -> PyResult<Self> {
+        if interval == Some(0) {
+            return Err(PyValueError::new_err("progress_interval must be positive"));
+        }
+        Ok(ProgressFn { callback, interval })
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.callback.is_some()
+    }
+
+    /// The number of units of work between the calls to the callback, which
+    /// defaults to a hundredth of ``total``.
+    pub fn interval(&self, total: Option<usize>) -> usize {
+        match (self.interval, total) {
+            (Some(interval), _) => interval,
+            (None, Some(total)) => ((total + DEFAULT_REPORTS - 1) / DEFAULT_REPORTS).max(1),
+            (None, None) => DEFAULT_INTERVAL,
+        }
+    }
+
+    pub fn report(&self, py: Python, done: usize, total: Option<usize>) -> PyResult<()> {
+        if let Some(callback) = &self.callback {
+            py.check_signals()?;
+            callback.call1(py, (done, total))?;
+        }
+        Ok(())
+    }
+
+    pub fn traverse(&self, visit: &PyVisit) -> Result<(), PyTraverseError> {
+        if let Some(callback) = &self.callback {
+            visit.call(callback)?;
+        }
+        Ok(())
+    }
+
+    pub fn clear(&mut self) {
+        self.callback = None;
+    }
+
+    /// Map ``f`` over ``items`` in parallel with at most ``num_threads``
+    /// threads, reporting every ``interval`` items done.
+    pub fn par_map<T, R, F>(
+        &self,
+        py: Python,
+        items: Vec<T>,
+        num_threads: Option<usize>,
+        f: F,
+    ) -> PyResult<Vec<R>>
+    where
+        T: Send,
+        R: Send,
+        F: Fn(T) -> R + Sync + Send,
+    {
+        if !self.is_enabled() {
+            return run_with_num_threads(num_threads, || items.into_par_iter().map(f).collect());
+        }
+        let total = items.len();
+        let interval = self.interval(Some(total));
+        let mut out = Vec::with_capacity(total);
+        let mut items = items.into_iter();
+        loop {
+            let chunk: Vec<T> = items.by_ref().take(interval).collect();
+            if chunk.is_empty() {
+                break;
+            }
+            out.extend(run_with_num_threads(num_threads, || {
+                chunk.into_par_iter().map(&f).collect::<Vec<R>>()
+            })?);
+            self.report(py, out.len(), Some(total))?;
+        }
+        Ok(out)
+    }
+
+    /// Run ``op`` with the GIL released and at most ``num_threads`` threads.
+    ///
+    /// ``op`` is passed a callback for the ``progress`` argument of the
+    /// functions of ``rustworkx-core``, which takes the GIL to report the
+    /// number of units of work done out of the total.
+    pub fn run_released<OP, R>(&self, py: Python, num_threads: Option<usize>, op: OP) -> PyResult<R>
+    where
+        OP: FnOnce(&mut dyn FnMut(usize, usize) -> PyResult<()>) -> PyResult<R> + Send,
+        R: Send,
+    {
+        py.allow_threads(|| {
+            run_with_num_threads(num_threads, || {
+                let mut report =
+                    |done, total| Python::with_gil(|py| self.report(py, done, Some(total)));
+                op(&mut report)
+            })
+        })?
+    }
+}
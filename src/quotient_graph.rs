@@ -0,0 +1,253 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+use crate::{digraph, graph, StablePyGraph};
+
+use hashbrown::HashMap;
+
+use petgraph::graph::NodeIndex;
+use petgraph::stable_graph::EdgeReference;
+use petgraph::{algo, EdgeType};
+use rustworkx_core::dictmap::*;
+use rustworkx_core::quotient_graph::quotient_graph;
+
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+use pyo3::Python;
+
+/// Assign a block id to every node of ``graph`` from either a callable
+/// returning a block key for a node payload or a sequence of blocks of node
+/// indices.
+fn node_blocks<Ty: EdgeType>(
+    py: Python,
+    graph: &StablePyGraph<Ty>,
+    partition: &Bound<PyAny>,
+) -> PyResult<HashMap<NodeIndex, usize>> {
+    let mut blocks: HashMap<NodeIndex, usize> = HashMap::with_capacity(graph.node_count());
+    if partition.is_callable() {
+        let keys = PyDict::new_bound(py);
+        for node in graph.node_indices() {
+            let key = partition.call1((&graph[node],))?;
+            let block = match keys.get_item(&key)? {
+                Some(block) => block.extract::<usize>()?,
+                None => {
+                    let block = keys.len();
+                    keys.set_item(key, block)?;
+                    block
+                }
+            };
+            blocks.insert(node, block);
+        }
+        return Ok(blocks);
+    }
+    let mut num_blocks = 0;
+    for block in partition.iter()? {
+        for node in block?.iter()? {
+            let index: usize = node?.extract()?;
+            let node = NodeIndex::new(index);
+            if !graph.contains_node(node) {
+                return Err(PyValueError::new_err(format!(
+                    "Node index {} in the partition is not in the graph",
+                    index
+                )));
+            }
+            if blocks.insert(node, num_blocks).is_some() {
+                return Err(PyValueError::new_err(format!(
+                    "Node index {} is in more than one block of the partition",
+                    index
+                )));
+            }
+        }
+        num_blocks += 1;
+    }
+    // Nodes that aren't in any block of the partition are left on their own
+    for node in graph.node_indices() {
+        if !blocks.contains_key(&node) {
+            blocks.insert(node, num_blocks);
+            num_blocks += 1;
+        }
+    }
+    Ok(blocks)
+}
+
+fn py_quotient_graph<Ty: EdgeType>(
+    py: Python,
+    graph: &StablePyGraph<Ty>,
+    partition: &Bound<PyAny>,
+    node_fn: Option<PyObject>,
+    edge_fn: Option<PyObject>,
+    self_loops: bool,
+) -> PyResult<(StablePyGraph<Ty>, DictMap<usize, usize>)> {
+    let blocks = node_blocks(py, graph, partition)?;
+    let (out_graph, node_map): (StablePyGraph<Ty>, HashMap<NodeIndex, NodeIndex>) = quotient_graph(
+        graph,
+        |node| Ok::<usize, PyErr>(blocks[&node]),
+        |nodes| {
+            let weights: Vec<&PyObject> = nodes.iter().map(|node| &graph[*node]).collect();
+            match &node_fn {
+                Some(node_fn) => node_fn.call1(py, (weights,)),
+                None => Ok(weights.into_py(py)),
+            }
+        },
+        |edges: &[EdgeReference<PyObject>]| {
+            let weights: Vec<&PyObject> = edges.iter().map(|edge| edge.weight()).collect();
+            match &edge_fn {
+                Some(edge_fn) => edge_fn.call1(py, (weights,)),
+                None => Ok(weights.into_py(py)),
+            }
+        },
+        self_loops,
+    )?;
+    let node_map: DictMap<usize, usize> = graph
+        .node_indices()
+        .map(|node| (node.index(), node_map[&node].index()))
+        .collect();
+    Ok((out_graph, node_map))
+}
+
+/// Return the quotient graph of a :class:`~.PyGraph` for a partition of its
+/// nodes.
+///
+/// Every block of the partition is collapsed into a single node of the
+/// quotient graph, and all the edges between the nodes of two blocks are
+/// merged into a single edge between the nodes of those blocks.
+///
+/// :param PyGraph graph: The input graph
+/// :param partition: Either a sequence of blocks, where every block is a
+///     sequence of node indices, or a callable that will be passed the data
+///     payload of every node and is expected to return a hashable key, in
+///     which case nodes with equal keys are in the same block. A node that
+///     isn't in any block of a sequence is in a block on its own.
+/// :param node_fn: An optional callable that will be passed a list of the
+///     data payloads of the nodes of a block and is expected to return the
+///     data payload of the node of the quotient graph for that block. By
+///     default the list of payloads is used.
+/// :param edge_fn: An optional callable that will be passed a list of the
+///     data payloads of the edges between two blocks and is expected to
+///     return the data payload of the edge between them. By default the list
+///     of payloads is used.
+/// :param bool self_loops: If ``True`` the edges within a block are merged
+///     into a self-loop on the node of that block, otherwise they are
+///     dropped. Defaults to ``False``.
+///
+/// :returns: A tuple of the quotient graph and a dictionary mapping the node
+///     indices of ``graph`` to the node indices of the quotient graph.
+/// :rtype: Tuple[PyGraph, dict]
+/// :raises ValueError: If a node index in ``partition`` isn't in the graph
+///     or is in more than one block.
+///
+/// .. jupyter-execute::
+///
+///   import rustworkx as rx
+///
+///   graph = rx.generators.path_graph(6, weights=list(range(6)))
+///   quotient, node_map = rx.graph_quotient_graph(
+///       graph, lambda weight: weight // 2, node_fn=sum
+///   )
+///   print(quotient.nodes())
+///   print(quotient.edge_list())
+///   print(node_map)
+#[pyfunction]
+#[pyo3(
+    signature=(graph, partition, /, node_fn=None, edge_fn=None, self_loops=false),
+    text_signature = "(graph, partition, /, node_fn=None, edge_fn=None, self_loops=False)"
+)]
+pub fn graph_quotient_graph(
+    py: Python,
+    graph: &graph::PyGraph,
+    partition: &Bound<PyAny>,
+    node_fn: Option<PyObject>,
+    edge_fn: Option<PyObject>,
+    self_loops: bool,
+) -> PyResult<(graph::PyGraph, DictMap<usize, usize>)> {
+    let (out_graph, node_map) =
+        py_quotient_graph(py, &graph.graph, partition, node_fn, edge_fn, self_loops)?;
+    Ok((
+        graph::PyGraph {
+            graph: out_graph,
+            node_removed: false,
+            multigraph: graph.multigraph,
+            attrs: py.None(),
+        },
+        node_map,
+    ))
+}
+
+/// Return the quotient graph of a :class:`~.PyDiGraph` for a partition of
+/// its nodes.
+///
+/// Every block of the partition is collapsed into a single node of the
+/// quotient graph. All the edges from the nodes of one block to the nodes of
+/// another block are merged into a single edge between the nodes of those
+/// blocks, so there are separate edges for the two directions.
+///
+/// :param PyDiGraph graph: The input graph
+/// :param partition: Either a sequence of blocks, where every block is a
+///     sequence of node indices, or a callable that will be passed the data
+///     payload of every node and is expected to return a hashable key, in
+///     which case nodes with equal keys are in the same block. A node that
+///     isn't in any block of a sequence is in a block on its own.
+/// :param node_fn: An optional callable that will be passed a list of the
+///     data payloads of the nodes of a block and is expected to return the
+///     data payload of the node of the quotient graph for that block. By
+///     default the list of payloads is used.
+/// :param edge_fn: An optional callable that will be passed a list of the
+///     data payloads of the edges from one block to another and is expected
+///     to return the data payload of the edge between them. By default the
+///     list of payloads is used.
+/// :param bool self_loops: If ``True`` the edges within a block are merged
+///     into a self-loop on the node of that block, otherwise they are
+///     dropped. Defaults to ``False``.
+///
+/// :returns: A tuple of the quotient graph and a dictionary mapping the node
+///     indices of ``graph`` to the node indices of the quotient graph.
+/// :rtype: Tuple[PyDiGraph, dict]
+/// :raises ValueError: If a node index in ``partition`` isn't in the graph
+///     or is in more than one block.
+///
+/// .. jupyter-execute::
+///
+///   import rustworkx as rx
+///
+///   graph = rx.generators.directed_cycle_graph(6)
+///   quotient, node_map = rx.digraph_quotient_graph(
+///       graph, [[0, 1, 2], [3, 4, 5]], edge_fn=len
+///   )
+///   print(quotient.weighted_edge_list())
+#[pyfunction]
+#[pyo3(
+    signature=(graph, partition, /, node_fn=None, edge_fn=None, self_loops=false),
+    text_signature = "(graph, partition, /, node_fn=None, edge_fn=None, self_loops=False)"
+)]
+pub fn digraph_quotient_graph(
+    py: Python,
+    graph: &digraph::PyDiGraph,
+    partition: &Bound<PyAny>,
+    node_fn: Option<PyObject>,
+    edge_fn: Option<PyObject>,
+    self_loops: bool,
+) -> PyResult<(digraph::PyDiGraph, DictMap<usize, usize>)> {
+    let (out_graph, node_map) =
+        py_quotient_graph(py, &graph.graph, partition, node_fn, edge_fn, self_loops)?;
+    Ok((
+        digraph::PyDiGraph {
+            graph: out_graph,
+            cycle_state: algo::DfsSpace::default(),
+            check_cycle: false,
+            node_removed: false,
+            multigraph: graph.multigraph,
+            attrs: py.None(),
+        },
+        node_map,
+    ))
+}
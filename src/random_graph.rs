@@ -26,8 +26,8 @@ use petgraph::prelude::*;
 use numpy::PyReadonlyArray2;
 
 use rand::distributions::{Distribution, Uniform};
-use rand::prelude::*;
 use rand_pcg::Pcg64;
+use rustworkx_core::rng::rng_from_seed;
 
 use rustworkx_core::generators as core_generators;
 
@@ -450,10 +450,7 @@ pub fn random_geometric_graph(
     let mut inner_graph = StablePyGraph::<Undirected>::default();
 
     let radius_p = pnorm(radius, p);
-    let mut rng: Pcg64 = match seed {
-        Some(seed) => Pcg64::seed_from_u64(seed),
-        None => Pcg64::from_entropy(),
-    };
+    let mut rng: Pcg64 = rng_from_seed(seed);
 
     let dist = Uniform::new(0.0, 1.0);
     let pos = pos.unwrap_or_else(|| {
@@ -0,0 +1,100 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+use crate::{digraph, graph, DAGWouldCycle};
+
+use rustworkx_core::graph_ext::{DoubleEdgeSwapDirected, DoubleEdgeSwapUndirected};
+
+use pyo3::prelude::*;
+
+/// Randomize the edges of a :class:`~.PyGraph` in place while preserving
+/// the degree of every node.
+///
+/// This is the rewiring of Maslov and Sneppen [1]_. Every swap picks two
+/// edges ``(u, v)`` and ``(x, y)`` at random and replaces them with the edges
+/// ``(u, x)`` and ``(v, y)``. A swap is only done if it doesn't add a
+/// self-loop or an edge which is already in the graph, so a simple graph
+/// stays simple. The new edges take the weights of the edges they replace.
+/// Repeated enough times it samples a random graph with the degree sequence
+/// of ``graph``, which is the null model used to tell if a property of
+/// ``graph``, such as its assortativity or its count of a motif, is explained
+/// by its degrees alone.
+///
+/// :param PyGraph graph: The graph to rewire
+/// :param int n_swaps: The number of swaps to do
+/// :param int max_tries: The maximum number of swaps to attempt, including the
+///     rejected ones. Defaults to ``10 * n_swaps``.
+/// :param int seed: An optional seed to use for the random number generator
+///
+/// :returns: The number of swaps done, which is less than ``n_swaps`` if
+///     ``max_tries`` was reached first
+/// :rtype: int
+///
+/// .. [1] S. Maslov and K. Sneppen, "Specificity and stability in topology of
+///    protein networks", Science 296, 910-913 (2002).
+#[pyfunction]
+#[pyo3(
+    signature=(graph, n_swaps, /, max_tries=None, seed=None),
+    text_signature = "(graph, n_swaps, /, max_tries=None, seed=None)"
+)]
+pub fn graph_rewire(
+    graph: &mut graph::PyGraph,
+    n_swaps: usize,
+    max_tries: Option<usize>,
+    seed: Option<u64>,
+) -> usize {
+    let max_tries = max_tries.unwrap_or(n_swaps.saturating_mul(10));
+    graph.graph.double_edge_swap(n_swaps, max_tries, seed)
+}
+
+/// Randomize the edges of a :class:`~.PyDiGraph` in place while preserving
+/// the in-degree and out-degree of every node.
+///
+/// This is the rewiring of Maslov and Sneppen [1]_. Every swap picks two
+/// edges ``(u, v)`` and ``(x, y)`` at random and replaces them with the edges
+/// ``(u, y)`` and ``(x, v)``. A swap is only done if it doesn't add a
+/// self-loop or an edge which is already in the graph, so a simple graph
+/// stays simple. The new edges take the weights of the edges they replace.
+///
+/// :param PyDiGraph graph: The graph to rewire
+/// :param int n_swaps: The number of swaps to do
+/// :param int max_tries: The maximum number of swaps to attempt, including the
+///     rejected ones. Defaults to ``10 * n_swaps``.
+/// :param int seed: An optional seed to use for the random number generator
+///
+/// :returns: The number of swaps done, which is less than ``n_swaps`` if
+///     ``max_tries`` was reached first
+/// :rtype: int
+/// :raises DAGWouldCycle: If ``check_cycle`` is set on ``graph``, since the
+///     swaps can add cycles
+///
+/// .. [1] S. Maslov and K. Sneppen, "Specificity and stability in topology of
+///    protein networks", Science 296, 910-913 (2002).
+#[pyfunction]
+#[pyo3(
+    signature=(graph, n_swaps, /, max_tries=None, seed=None),
+    text_signature = "(graph, n_swaps, /, max_tries=None, seed=None)"
+)]
+pub fn digraph_rewire(
+    graph: &mut digraph::PyDiGraph,
+    n_swaps: usize,
+    max_tries: Option<usize>,
+    seed: Option<u64>,
+) -> PyResult<usize> {
+    if graph.check_cycle {
+        return Err(DAGWouldCycle::new_err(
+            "Rewiring a PyDiGraph with check_cycle set could add a cycle",
+        ));
+    }
+    let max_tries = max_tries.unwrap_or(n_swaps.saturating_mul(10));
+    Ok(graph.graph.double_edge_swap(n_swaps, max_tries, seed))
+}
@@ -0,0 +1,99 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+use crate::graph;
+
+use rustworkx_core::dictmap::*;
+use rustworkx_core::rich_club;
+
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+
+/// Compute the rich-club coefficient of a :class:`~.PyGraph` for every
+/// degree.
+///
+/// The rich-club coefficient for a degree ``k`` is the density of the
+/// subgraph of the nodes with a degree greater than ``k``,
+///
+/// .. math::
+///
+///     \phi(k) = \frac{2 E_k}{N_k (N_k - 1)}
+///
+/// where :math:`N_k` is the number of nodes with a degree greater than ``k``
+/// and :math:`E_k` is the number of edges between them. It's only defined if
+/// there are at least two such nodes.
+///
+/// The normalized rich-club coefficient divides it by the average rich-club
+/// coefficient of random graphs with the same degrees as ``graph``. The
+/// random graphs are generated by repeatedly swapping the endpoints of pairs
+/// of edges while keeping the graph simple, and a normalized value greater
+/// than ``1.0`` means the high degree nodes of ``graph`` are more densely
+/// connected than expected from their degrees alone.
+///
+/// The graph is treated as a simple graph: self-loops are ignored and
+/// parallel edges aren't expected.
+///
+/// :param PyGraph graph: The graph to compute the coefficients for
+/// :param bool normalized: If ``True`` the normalized rich-club coefficient
+///     is returned. Default: ``False``.
+/// :param int num_randomizations: The number of random graphs to average over
+///     for the normalized coefficient. It must be positive if ``normalized``
+///     is ``True``. Default: ``10``.
+/// :param int swaps_per_edge: The number of edge swaps done to generate a
+///     random graph, per edge of ``graph``. Default: ``10``.
+/// :param int seed: An optional seed to use for the random number generator
+///     of the normalized coefficient.
+///
+/// :returns: A dictionary mapping every degree ``k`` the coefficient is
+///     defined for to the coefficient.
+/// :rtype: dict
+///
+/// :raises ValueError: If ``normalized`` is ``True`` and ``num_randomizations``
+///     is ``0``
+///
+/// .. jupyter-execute::
+///
+///   import rustworkx as rx
+///
+///   graph = rx.barabasi_albert_graph(100, 3, seed=42)
+///   rich_club = rx.rich_club_coefficient(graph)
+///   normalized = rx.rich_club_coefficient(graph, normalized=True, seed=42)
+///   print({k: round(rich_club[k], 3) for k in range(3, 8)})
+///   print({k: round(normalized[k], 3) for k in range(3, 8)})
+#[pyfunction]
+#[pyo3(
+    signature=(graph, /, normalized=false, num_randomizations=10, swaps_per_edge=10, seed=None),
+    text_signature = "(graph, /, normalized=False, num_randomizations=10, swaps_per_edge=10, seed=None)"
+)]
+pub fn rich_club_coefficient(
+    graph: &graph::PyGraph,
+    normalized: bool,
+    num_randomizations: usize,
+    swaps_per_edge: usize,
+    seed: Option<u64>,
+) -> PyResult<DictMap<usize, f64>> {
+    if normalized {
+        if num_randomizations == 0 {
+            return Err(PyValueError::new_err(
+                "num_randomizations must be positive for the normalized coefficient",
+            ));
+        }
+        Ok(rich_club::normalized_rich_club_coefficient(
+            &graph.graph,
+            num_randomizations,
+            swaps_per_edge,
+            seed,
+        ))
+    } else {
+        Ok(rich_club::rich_club_coefficient(&graph.graph))
+    }
+}
@@ -0,0 +1,477 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+use crate::iterators::NodeMap;
+use crate::{digraph, graph, StablePyGraph};
+
+use petgraph::visit::EdgeRef;
+use petgraph::EdgeType;
+use rustworkx_core::dictmap::*;
+use rustworkx_core::sampling;
+
+use hashbrown::HashSet;
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pyo3::Python;
+
+/// The sampling methods, with their probability argument where they take one
+enum Sampler {
+    Nodes,
+    Edges,
+    RandomWalk(f64),
+    ForestFire(f64),
+}
+
+/// Sample nodes of ``graph`` and return their indices in increasing order
+fn sampled_nodes<Ty: EdgeType>(
+    graph: &StablePyGraph<Ty>,
+    size: usize,
+    sampler: Sampler,
+    seed: Option<u64>,
+) -> PyResult<Vec<usize>> {
+    let nodes = match sampler {
+        Sampler::Nodes => sampling::sample_nodes(graph, size, seed),
+        Sampler::Edges => sampling::sample_edges(graph, size, seed).map(|edges| {
+            let mut nodes = HashSet::with_capacity(2 * edges.len());
+            for edge in edges {
+                nodes.insert(edge.source());
+                nodes.insert(edge.target());
+            }
+            nodes.into_iter().collect()
+        }),
+        Sampler::RandomWalk(probability) => {
+            sampling::random_walk_sample(graph, size, probability, seed)
+        }
+        Sampler::ForestFire(probability) => {
+            sampling::forest_fire_sample(graph, size, probability, seed)
+        }
+    }
+    .map_err(|err| PyValueError::new_err(err.to_string()))?;
+    let mut nodes: Vec<usize> = nodes.into_iter().map(|node| node.index()).collect();
+    nodes.sort_unstable();
+    Ok(nodes)
+}
+
+/// Map the node indices of the induced subgraph of ``nodes``, which are
+/// added to it in increasing order, to the indices of ``nodes``
+fn subgraph_node_map(nodes: &[usize]) -> NodeMap {
+    let node_map: DictMap<usize, usize> = nodes.iter().copied().enumerate().collect();
+    NodeMap { node_map }
+}
+
+fn graph_sample(
+    py: Python,
+    graph: &graph::PyGraph,
+    size: usize,
+    sampler: Sampler,
+    seed: Option<u64>,
+) -> PyResult<(graph::PyGraph, NodeMap)> {
+    let nodes = sampled_nodes(&graph.graph, size, sampler, seed)?;
+    let node_map = subgraph_node_map(&nodes);
+    Ok((graph.subgraph(py, nodes, false), node_map))
+}
+
+fn digraph_sample(
+    py: Python,
+    graph: &digraph::PyDiGraph,
+    size: usize,
+    sampler: Sampler,
+    seed: Option<u64>,
+) -> PyResult<(digraph::PyDiGraph, NodeMap)> {
+    let nodes = sampled_nodes(&graph.graph, size, sampler, seed)?;
+    let node_map = subgraph_node_map(&nodes);
+    Ok((graph.subgraph(py, nodes, false), node_map))
+}
+
+/// Sample nodes of a :class:`~.PyGraph` uniformly at random
+///
+/// Every set of ``num_nodes`` nodes is equally likely to be sampled, and
+/// the induced subgraph of the sampled nodes is returned.
+///
+/// :param PyGraph graph: The graph to sample
+/// :param int num_nodes: The number of nodes to sample
+/// :param int seed: An optional seed for the random number generator
+///
+/// :returns: A tuple of the induced subgraph of the sampled nodes and a
+///     mapping from the node indices of the subgraph to the node indices of
+///     ``graph``. The sampled nodes are in the same order in the subgraph as
+///     in ``graph``.
+/// :rtype: tuple[PyGraph, NodeMap]
+/// :raises ValueError: If the graph has fewer than ``num_nodes`` nodes
+///
+/// .. jupyter-execute::
+///
+///   import rustworkx as rx
+///
+///   graph = rx.generators.grid_graph(10, 10)
+///   sample, node_map = rx.graph_sample_nodes(graph, 20, seed=42)
+///   print(sample.num_nodes(), sample.num_edges())
+///   print(node_map)
+#[pyfunction]
+#[pyo3(
+    signature=(graph, num_nodes, /, seed=None),
+    text_signature = "(graph, num_nodes, /, seed=None)"
+)]
+pub fn graph_sample_nodes(
+    py: Python,
+    graph: &graph::PyGraph,
+    num_nodes: usize,
+    seed: Option<u64>,
+) -> PyResult<(graph::PyGraph, NodeMap)> {
+    graph_sample(py, graph, num_nodes, Sampler::Nodes, seed)
+}
+
+/// Sample nodes of a :class:`~.PyDiGraph` uniformly at random
+///
+/// Every set of ``num_nodes`` nodes is equally likely to be sampled, and
+/// the induced subgraph of the sampled nodes is returned.
+///
+/// :param PyDiGraph graph: The graph to sample
+/// :param int num_nodes: The number of nodes to sample
+/// :param int seed: An optional seed for the random number generator
+///
+/// :returns: A tuple of the induced subgraph of the sampled nodes and a
+///     mapping from the node indices of the subgraph to the node indices of
+///     ``graph``. The sampled nodes are in the same order in the subgraph as
+///     in ``graph``.
+/// :rtype: tuple[PyDiGraph, NodeMap]
+/// :raises ValueError: If the graph has fewer than ``num_nodes`` nodes
+///
+/// .. jupyter-execute::
+///
+///   import rustworkx as rx
+///
+///   graph = rx.generators.directed_grid_graph(10, 10)
+///   sample, node_map = rx.digraph_sample_nodes(graph, 20, seed=42)
+///   print(sample.num_nodes(), sample.num_edges())
+///   print(node_map)
+#[pyfunction]
+#[pyo3(
+    signature=(graph, num_nodes, /, seed=None),
+    text_signature = "(graph, num_nodes, /, seed=None)"
+)]
+pub fn digraph_sample_nodes(
+    py: Python,
+    graph: &digraph::PyDiGraph,
+    num_nodes: usize,
+    seed: Option<u64>,
+) -> PyResult<(digraph::PyDiGraph, NodeMap)> {
+    digraph_sample(py, graph, num_nodes, Sampler::Nodes, seed)
+}
+
+/// Sample edges of a :class:`~.PyGraph` uniformly at random
+///
+/// Every set of ``num_edges`` edges is equally likely to be sampled, and
+/// the subgraph induced by the endpoints of the sampled edges is returned.
+/// It contains every edge between those endpoints, not only the sampled
+/// edges, which is known as induced edge sampling. Nodes of high degree are
+/// more likely to be in the sample than with :func:`~rustworkx.sample_nodes`.
+///
+/// :param PyGraph graph: The graph to sample
+/// :param int num_edges: The number of edges to sample
+/// :param int seed: An optional seed for the random number generator
+///
+/// :returns: A tuple of the induced subgraph of the sampled nodes and a
+///     mapping from the node indices of the subgraph to the node indices of
+///     ``graph``. The sampled nodes are in the same order in the subgraph as
+///     in ``graph``.
+/// :rtype: tuple[PyGraph, NodeMap]
+/// :raises ValueError: If the graph has fewer than ``num_edges`` edges
+///
+/// .. jupyter-execute::
+///
+///   import rustworkx as rx
+///
+///   graph = rx.generators.grid_graph(10, 10)
+///   sample, node_map = rx.graph_sample_edges(graph, 20, seed=42)
+///   print(sample.num_nodes(), sample.num_edges())
+///   print(node_map)
+#[pyfunction]
+#[pyo3(
+    signature=(graph, num_edges, /, seed=None),
+    text_signature = "(graph, num_edges, /, seed=None)"
+)]
+pub fn graph_sample_edges(
+    py: Python,
+    graph: &graph::PyGraph,
+    num_edges: usize,
+    seed: Option<u64>,
+) -> PyResult<(graph::PyGraph, NodeMap)> {
+    graph_sample(py, graph, num_edges, Sampler::Edges, seed)
+}
+
+/// Sample edges of a :class:`~.PyDiGraph` uniformly at random
+///
+/// Every set of ``num_edges`` edges is equally likely to be sampled, and
+/// the subgraph induced by the endpoints of the sampled edges is returned.
+/// It contains every edge between those endpoints, not only the sampled
+/// edges, which is known as induced edge sampling. Nodes of high degree are
+/// more likely to be in the sample than with :func:`~rustworkx.sample_nodes`.
+///
+/// :param PyDiGraph graph: The graph to sample
+/// :param int num_edges: The number of edges to sample
+/// :param int seed: An optional seed for the random number generator
+///
+/// :returns: A tuple of the induced subgraph of the sampled nodes and a
+///     mapping from the node indices of the subgraph to the node indices of
+///     ``graph``. The sampled nodes are in the same order in the subgraph as
+///     in ``graph``.
+/// :rtype: tuple[PyDiGraph, NodeMap]
+/// :raises ValueError: If the graph has fewer than ``num_edges`` edges
+///
+/// .. jupyter-execute::
+///
+///   import rustworkx as rx
+///
+///   graph = rx.generators.directed_grid_graph(10, 10)
+///   sample, node_map = rx.digraph_sample_edges(graph, 20, seed=42)
+///   print(sample.num_nodes(), sample.num_edges())
+///   print(node_map)
+#[pyfunction]
+#[pyo3(
+    signature=(graph, num_edges, /, seed=None),
+    text_signature = "(graph, num_edges, /, seed=None)"
+)]
+pub fn digraph_sample_edges(
+    py: Python,
+    graph: &digraph::PyDiGraph,
+    num_edges: usize,
+    seed: Option<u64>,
+) -> PyResult<(digraph::PyDiGraph, NodeMap)> {
+    digraph_sample(py, graph, num_edges, Sampler::Edges, seed)
+}
+
+/// Sample nodes of a :class:`~.PyGraph` with a random walk with restarts
+///
+/// The walk starts at a random node, and at every step either goes back to
+/// its starting node with probability ``restart_probability`` or moves to a
+/// random neighbor of its current node. A walk that has no neighbor to
+/// move to goes back to its starting node, and a walk that hasn't visited a
+/// new node in 100 steps starts again from a random node it hasn't visited.
+/// The induced subgraph of the first ``num_nodes`` nodes visited is
+/// returned. Unlike uniform sampling the sample is mostly connected, so it
+/// preserves the local structure of the graph.
+///
+/// :param PyGraph graph: The graph to sample
+/// :param int num_nodes: The number of nodes to sample
+/// :param float restart_probability: The probability of going back to the
+///     starting node at every step. Defaults to ``0.15``.
+/// :param int seed: An optional seed for the random number generator
+///
+/// :returns: A tuple of the induced subgraph of the sampled nodes and a
+///     mapping from the node indices of the subgraph to the node indices of
+///     ``graph``. The sampled nodes are in the same order in the subgraph as
+///     in ``graph``.
+/// :rtype: tuple[PyGraph, NodeMap]
+/// :raises ValueError: If the graph has fewer than ``num_nodes`` nodes or
+///     ``restart_probability`` isn't in ``[0, 1]``
+///
+/// .. jupyter-execute::
+///
+///   import rustworkx as rx
+///
+///   graph = rx.generators.grid_graph(10, 10)
+///   sample, node_map = rx.graph_random_walk_sample(graph, 20, seed=42)
+///   print(sample.num_nodes(), sample.num_edges())
+///   print(node_map)
+#[pyfunction]
+#[pyo3(
+    signature=(graph, num_nodes, /, restart_probability=0.15, seed=None),
+    text_signature = "(graph, num_nodes, /, restart_probability=0.15, seed=None)"
+)]
+pub fn graph_random_walk_sample(
+    py: Python,
+    graph: &graph::PyGraph,
+    num_nodes: usize,
+    restart_probability: f64,
+    seed: Option<u64>,
+) -> PyResult<(graph::PyGraph, NodeMap)> {
+    graph_sample(
+        py,
+        graph,
+        num_nodes,
+        Sampler::RandomWalk(restart_probability),
+        seed,
+    )
+}
+
+/// Sample nodes of a :class:`~.PyDiGraph` with a random walk with restarts
+///
+/// The walk starts at a random node, and at every step either goes back to
+/// its starting node with probability ``restart_probability`` or moves to a
+/// random neighbor of its current node, following the direction of edges.
+/// A walk that has no neighbor to move to goes back to its starting node,
+/// and a walk that hasn't visited a new node in 100 steps starts again from
+/// a random node it hasn't visited.
+/// The induced subgraph of the first ``num_nodes`` nodes visited is
+/// returned. Unlike uniform sampling the sample is mostly connected, so it
+/// preserves the local structure of the graph.
+///
+/// :param PyDiGraph graph: The graph to sample
+/// :param int num_nodes: The number of nodes to sample
+/// :param float restart_probability: The probability of going back to the
+///     starting node at every step. Defaults to ``0.15``.
+/// :param int seed: An optional seed for the random number generator
+///
+/// :returns: A tuple of the induced subgraph of the sampled nodes and a
+///     mapping from the node indices of the subgraph to the node indices of
+///     ``graph``. The sampled nodes are in the same order in the subgraph as
+///     in ``graph``.
+/// :rtype: tuple[PyDiGraph, NodeMap]
+/// :raises ValueError: If the graph has fewer than ``num_nodes`` nodes or
+///     ``restart_probability`` isn't in ``[0, 1]``
+///
+/// .. jupyter-execute::
+///
+///   import rustworkx as rx
+///
+///   graph = rx.generators.directed_grid_graph(10, 10)
+///   sample, node_map = rx.digraph_random_walk_sample(graph, 20, seed=42)
+///   print(sample.num_nodes(), sample.num_edges())
+///   print(node_map)
+#[pyfunction]
+#[pyo3(
+    signature=(graph, num_nodes, /, restart_probability=0.15, seed=None),
+    text_signature = "(graph, num_nodes, /, restart_probability=0.15, seed=None)"
+)]
+pub fn digraph_random_walk_sample(
+    py: Python,
+    graph: &digraph::PyDiGraph,
+    num_nodes: usize,
+    restart_probability: f64,
+    seed: Option<u64>,
+) -> PyResult<(digraph::PyDiGraph, NodeMap)> {
+    digraph_sample(
+        py,
+        graph,
+        num_nodes,
+        Sampler::RandomWalk(restart_probability),
+        seed,
+    )
+}
+
+/// Sample nodes of a :class:`~.PyGraph` with a forest fire
+///
+/// The fire starts at a random node. Every burning node sets fire to a
+/// random number of its neighbors that aren't burned yet, drawn from a
+/// geometric distribution with mean ``p / (1 - p)`` for the burn probability
+/// ``p``. When the fire dies out it starts again from a random node that
+/// isn't burned. The induced subgraph of the first ``num_nodes`` nodes
+/// burned is returned. Forest fire samples preserve many properties of the
+/// graph, such as its degree distribution and clustering [1]_.
+///
+/// :param PyGraph graph: The graph to sample
+/// :param int num_nodes: The number of nodes to sample
+/// :param float burn_probability: The forward burning probability, which
+///     must be less than ``1``. Defaults to ``0.7``.
+/// :param int seed: An optional seed for the random number generator
+///
+/// :returns: A tuple of the induced subgraph of the sampled nodes and a
+///     mapping from the node indices of the subgraph to the node indices of
+///     ``graph``. The sampled nodes are in the same order in the subgraph as
+///     in ``graph``.
+/// :rtype: tuple[PyGraph, NodeMap]
+/// :raises ValueError: If the graph has fewer than ``num_nodes`` nodes or
+///     ``burn_probability`` isn't in ``[0, 1)``
+///
+/// .. jupyter-execute::
+///
+///   import rustworkx as rx
+///
+///   graph = rx.generators.grid_graph(10, 10)
+///   sample, node_map = rx.graph_forest_fire_sample(graph, 20, seed=42)
+///   print(sample.num_nodes(), sample.num_edges())
+///   print(node_map)
+///
+/// .. [1] Leskovec, Jure, and Christos Faloutsos.
+///    "Sampling from large graphs."
+///    Proceedings of the 12th ACM SIGKDD International Conference on
+///    Knowledge Discovery and Data Mining (2006): 631-636.
+#[pyfunction]
+#[pyo3(
+    signature=(graph, num_nodes, /, burn_probability=0.7, seed=None),
+    text_signature = "(graph, num_nodes, /, burn_probability=0.7, seed=None)"
+)]
+pub fn graph_forest_fire_sample(
+    py: Python,
+    graph: &graph::PyGraph,
+    num_nodes: usize,
+    burn_probability: f64,
+    seed: Option<u64>,
+) -> PyResult<(graph::PyGraph, NodeMap)> {
+    graph_sample(
+        py,
+        graph,
+        num_nodes,
+        Sampler::ForestFire(burn_probability),
+        seed,
+    )
+}
+
+/// Sample nodes of a :class:`~.PyDiGraph` with a forest fire
+///
+/// The fire starts at a random node. Every burning node sets fire to a
+/// random number of its neighbors that aren't burned yet, following the
+/// direction of edges, drawn from a geometric distribution with mean
+/// ``p / (1 - p)`` for the burn probability ``p``. When the fire dies out
+/// it starts again from a random node that isn't burned. The induced
+/// subgraph of the first ``num_nodes`` nodes burned is returned. Forest fire
+/// samples preserve many properties of the graph, such as its degree
+/// distribution and clustering [1]_.
+///
+/// :param PyDiGraph graph: The graph to sample
+/// :param int num_nodes: The number of nodes to sample
+/// :param float burn_probability: The forward burning probability, which
+///     must be less than ``1``. Defaults to ``0.7``.
+/// :param int seed: An optional seed for the random number generator
+///
+/// :returns: A tuple of the induced subgraph of the sampled nodes and a
+///     mapping from the node indices of the subgraph to the node indices of
+///     ``graph``. The sampled nodes are in the same order in the subgraph as
+///     in ``graph``.
+/// :rtype: tuple[PyDiGraph, NodeMap]
+/// :raises ValueError: If the graph has fewer than ``num_nodes`` nodes or
+///     ``burn_probability`` isn't in ``[0, 1)``
+///
+/// .. jupyter-execute::
+///
+///   import rustworkx as rx
+///
+///   graph = rx.generators.directed_grid_graph(10, 10)
+///   sample, node_map = rx.digraph_forest_fire_sample(graph, 20, seed=42)
+///   print(sample.num_nodes(), sample.num_edges())
+///   print(node_map)
+///
+/// .. [1] Leskovec, Jure, and Christos Faloutsos.
+///    "Sampling from large graphs."
+///    Proceedings of the 12th ACM SIGKDD International Conference on
+///    Knowledge Discovery and Data Mining (2006): 631-636.
+#[pyfunction]
+#[pyo3(
+    signature=(graph, num_nodes, /, burn_probability=0.7, seed=None),
+    text_signature = "(graph, num_nodes, /, burn_probability=0.7, seed=None)"
+)]
+pub fn digraph_forest_fire_sample(
+    py: Python,
+    graph: &digraph::PyDiGraph,
+    num_nodes: usize,
+    burn_probability: f64,
+    seed: Option<u64>,
+) -> PyResult<(digraph::PyDiGraph, NodeMap)> {
+    digraph_sample(
+        py,
+        graph,
+        num_nodes,
+        Sampler::ForestFire(burn_probability),
+        seed,
+    )
+}
@@ -0,0 +1,693 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+use crate::{digraph, graph, StablePyGraph};
+
+use hashbrown::HashSet;
+
+use petgraph::stable_graph::{EdgeIndex, NodeIndex};
+use petgraph::visit::{EdgeRef, IntoEdgeReferences, NodeIndexable};
+use petgraph::{algo, EdgeType};
+
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pyo3::Python;
+
+#[derive(Clone, Copy, PartialEq)]
+enum AlignBy {
+    Index,
+    Weight,
+}
+
+impl AlignBy {
+    fn parse(align_by: &str) -> PyResult<Self> {
+        match align_by {
+            "index" => Ok(AlignBy::Index),
+            "weight" => Ok(AlignBy::Weight),
+            _ => Err(PyValueError::new_err(format!(
+                "Invalid align_by value '{}', it must be 'index' or 'weight'",
+                align_by
+            ))),
+        }
+    }
+}
+
+/// The nodes and edges of two graphs that are aligned with each other.
+struct Alignment {
+    /// The node of ``first`` every node of ``second`` is aligned with,
+    /// indexed by node index of ``second``
+    nodes: Vec<Option<NodeIndex>>,
+    /// The pairs of aligned edges of ``first`` and ``second``, sorted by the
+    /// edge of ``first``
+    edges: Vec<(EdgeIndex, EdgeIndex)>,
+}
+
+impl Alignment {
+    /// The node of ``second`` every node of ``first`` is aligned with,
+    /// indexed by node index of ``first``
+    fn first_nodes(&self, node_bound: usize) -> Vec<Option<NodeIndex>> {
+        let mut aligned_with: Vec<Option<NodeIndex>> = vec![None; node_bound];
+        for (node, aligned) in self.nodes.iter().enumerate() {
+            if let Some(aligned) = aligned {
+                aligned_with[aligned.index()] = Some(NodeIndex::new(node));
+            }
+        }
+        aligned_with
+    }
+
+    fn first_edges(&self) -> HashSet<EdgeIndex> {
+        self.edges.iter().map(|(edge, _)| *edge).collect()
+    }
+
+    fn second_edges(&self) -> HashSet<EdgeIndex> {
+        self.edges.iter().map(|(_, edge)| *edge).collect()
+    }
+}
+
+fn weights_equal(py: Python, a: &PyObject, b: &PyObject) -> PyResult<bool> {
+    a.bind(py)
+        .rich_compare(b, pyo3::basic::CompareOp::Eq)?
+        .is_truthy()
+}
+
+/// Align the nodes and edges of ``second`` with the nodes and edges of
+/// ``first``. Every node and edge is aligned with at most one other node or
+/// edge.
+fn align<Ty: EdgeType>(
+    py: Python,
+    first: &StablePyGraph<Ty>,
+    second: &StablePyGraph<Ty>,
+    align_by: AlignBy,
+) -> PyResult<Alignment> {
+    let mut nodes: Vec<Option<NodeIndex>> = vec![None; second.node_bound()];
+    match align_by {
+        AlignBy::Index => {
+            for node in second.node_indices() {
+                if first.contains_node(node) {
+                    nodes[node.index()] = Some(node);
+                }
+            }
+        }
+        AlignBy::Weight => {
+            let mut used: HashSet<NodeIndex> = HashSet::new();
+            for node in second.node_indices() {
+                for candidate in first.node_indices() {
+                    if !used.contains(&candidate)
+                        && weights_equal(py, &second[node], &first[candidate])?
+                    {
+                        used.insert(candidate);
+                        nodes[node.index()] = Some(candidate);
+                        break;
+                    }
+                }
+            }
+        }
+    }
+
+    let mut used: HashSet<EdgeIndex> = HashSet::new();
+    let mut edges: Vec<(EdgeIndex, EdgeIndex)> = Vec::new();
+    for edge in second.edge_references() {
+        let (Some(source), Some(target)) =
+            (nodes[edge.source().index()], nodes[edge.target().index()])
+        else {
+            continue;
+        };
+        // Parallel edges are aligned in the order of their edge indices
+        let mut candidates: Vec<EdgeIndex> = first
+            .edges_connecting(source, target)
+            .map(|candidate| candidate.id())
+            .filter(|candidate| !used.contains(candidate))
+            .collect();
+        candidates.sort_unstable();
+        for candidate in candidates {
+            if align_by == AlignBy::Weight && !weights_equal(py, edge.weight(), &first[candidate])?
+            {
+                continue;
+            }
+            used.insert(candidate);
+            edges.push((candidate, edge.id()));
+            break;
+        }
+    }
+    edges.sort_unstable();
+    Ok(Alignment { nodes, edges })
+}
+
+fn merge_weights(
+    py: Python,
+    merge_fn: &Option<PyObject>,
+    first: &PyObject,
+    second: &PyObject,
+) -> PyResult<PyObject> {
+    match merge_fn {
+        Some(merge_fn) => merge_fn.call1(py, (first, second)),
+        None => Ok(first.clone_ref(py)),
+    }
+}
+
+fn intersection<Ty: EdgeType>(
+    py: Python,
+    first: &StablePyGraph<Ty>,
+    second: &StablePyGraph<Ty>,
+    align_by: AlignBy,
+    node_merge_fn: Option<PyObject>,
+    edge_merge_fn: Option<PyObject>,
+) -> PyResult<StablePyGraph<Ty>> {
+    let alignment = align(py, first, second, align_by)?;
+    let aligned_with = alignment.first_nodes(first.node_bound());
+
+    let mut out_graph = StablePyGraph::<Ty>::default();
+    let mut node_map: Vec<Option<NodeIndex>> = vec![None; first.node_bound()];
+    for node in first.node_indices() {
+        if let Some(other) = aligned_with[node.index()] {
+            let weight = merge_weights(py, &node_merge_fn, &first[node], &second[other])?;
+            node_map[node.index()] = Some(out_graph.add_node(weight));
+        }
+    }
+    for (edge, other) in alignment.edges {
+        let (source, target) = first.edge_endpoints(edge).unwrap();
+        let weight = merge_weights(py, &edge_merge_fn, &first[edge], &second[other])?;
+        out_graph.add_edge(
+            node_map[source.index()].unwrap(),
+            node_map[target.index()].unwrap(),
+            weight,
+        );
+    }
+    Ok(out_graph)
+}
+
+fn difference<Ty: EdgeType>(
+    py: Python,
+    first: &StablePyGraph<Ty>,
+    second: &StablePyGraph<Ty>,
+    align_by: AlignBy,
+) -> PyResult<StablePyGraph<Ty>> {
+    let alignment = align(py, first, second, align_by)?;
+    let aligned_edges = alignment.first_edges();
+
+    let mut out_graph = StablePyGraph::<Ty>::default();
+    let mut node_map: Vec<Option<NodeIndex>> = vec![None; first.node_bound()];
+    for node in first.node_indices() {
+        node_map[node.index()] = Some(out_graph.add_node(first[node].clone_ref(py)));
+    }
+    for edge in first.edge_references() {
+        if aligned_edges.contains(&edge.id()) {
+            continue;
+        }
+        out_graph.add_edge(
+            node_map[edge.source().index()].unwrap(),
+            node_map[edge.target().index()].unwrap(),
+            edge.weight().clone_ref(py),
+        );
+    }
+    Ok(out_graph)
+}
+
+fn symmetric_difference<Ty: EdgeType>(
+    py: Python,
+    first: &StablePyGraph<Ty>,
+    second: &StablePyGraph<Ty>,
+    align_by: AlignBy,
+    node_merge_fn: Option<PyObject>,
+) -> PyResult<StablePyGraph<Ty>> {
+    let alignment = align(py, first, second, align_by)?;
+    let aligned_first_edges = alignment.first_edges();
+    let aligned_second_edges = alignment.second_edges();
+    let aligned_with = alignment.first_nodes(first.node_bound());
+
+    let mut out_graph = StablePyGraph::<Ty>::default();
+    let mut first_map: Vec<Option<NodeIndex>> = vec![None; first.node_bound()];
+    for node in first.node_indices() {
+        let weight = match aligned_with[node.index()] {
+            Some(other) => merge_weights(py, &node_merge_fn, &first[node], &second[other])?,
+            None => first[node].clone_ref(py),
+        };
+        first_map[node.index()] = Some(out_graph.add_node(weight));
+    }
+    let mut second_map: Vec<Option<NodeIndex>> = vec![None; second.node_bound()];
+    for node in second.node_indices() {
+        second_map[node.index()] = match alignment.nodes[node.index()] {
+            Some(aligned) => first_map[aligned.index()],
+            None => Some(out_graph.add_node(second[node].clone_ref(py))),
+        };
+    }
+    for edge in first.edge_references() {
+        if !aligned_first_edges.contains(&edge.id()) {
+            out_graph.add_edge(
+                first_map[edge.source().index()].unwrap(),
+                first_map[edge.target().index()].unwrap(),
+                edge.weight().clone_ref(py),
+            );
+        }
+    }
+    for edge in second.edge_references() {
+        if !aligned_second_edges.contains(&edge.id()) {
+            out_graph.add_edge(
+                second_map[edge.source().index()].unwrap(),
+                second_map[edge.target().index()].unwrap(),
+                edge.weight().clone_ref(py),
+            );
+        }
+    }
+    Ok(out_graph)
+}
+
+fn full_join<Ty: EdgeType>(
+    py: Python,
+    first: &StablePyGraph<Ty>,
+    second: &StablePyGraph<Ty>,
+    join_fn: Option<PyObject>,
+) -> PyResult<StablePyGraph<Ty>> {
+    let mut out_graph = StablePyGraph::<Ty>::with_capacity(
+        first.node_count() + second.node_count(),
+        first.edge_count() + second.edge_count() + first.node_count() * second.node_count(),
+    );
+    let mut first_map: Vec<Option<NodeIndex>> = vec![None; first.node_bound()];
+    for node in first.node_indices() {
+        first_map[node.index()] = Some(out_graph.add_node(first[node].clone_ref(py)));
+    }
+    let mut second_map: Vec<Option<NodeIndex>> = vec![None; second.node_bound()];
+    for node in second.node_indices() {
+        second_map[node.index()] = Some(out_graph.add_node(second[node].clone_ref(py)));
+    }
+    for (graph, map) in [(first, &first_map), (second, &second_map)] {
+        for edge in graph.edge_references() {
+            out_graph.add_edge(
+                map[edge.source().index()].unwrap(),
+                map[edge.target().index()].unwrap(),
+                edge.weight().clone_ref(py),
+            );
+        }
+    }
+    let join_weight = |a: NodeIndex, b: NodeIndex| -> PyResult<PyObject> {
+        match &join_fn {
+            Some(join_fn) => join_fn.call1(py, (&first[a], &second[b])),
+            None => Ok(py.None()),
+        }
+    };
+    for a in first.node_indices() {
+        for b in second.node_indices() {
+            let source = first_map[a.index()].unwrap();
+            let target = second_map[b.index()].unwrap();
+            out_graph.add_edge(source, target, join_weight(a, b)?);
+            if Ty::is_directed() {
+                out_graph.add_edge(target, source, join_weight(a, b)?);
+            }
+        }
+    }
+    Ok(out_graph)
+}
+
+/// Return a new PyGraph by forming the intersection of two PyGraph objects
+///
+/// The nodes of ``second`` are first aligned with the nodes of ``first``,
+/// either by node index or by equal data payloads. The edges of ``second``
+/// are then aligned with the edges of ``first`` between the aligned nodes,
+/// and with ``align_by="weight"`` only if their data payloads are equal too.
+/// Every node and edge is aligned with at most one node or edge of the other
+/// graph, so parallel edges are aligned one to one. The intersection has a
+/// node for every pair of aligned nodes and an edge for every pair of
+/// aligned edges, in the order of ``first``.
+///
+/// :param PyGraph first: The first undirected graph object
+/// :param PyGraph second: The second undirected graph object
+/// :param str align_by: How the nodes of the graphs are aligned, either
+///     ``"index"`` for nodes with the same index or ``"weight"`` for nodes
+///     with equal data payloads. Default: ``"index"``.
+/// :param node_merge_fn: An optional callable that will be passed the data
+///     payloads of a pair of aligned nodes and is expected to return the data
+///     payload of their node in the output. By default the payload of
+///     ``first`` is used.
+/// :param edge_merge_fn: An optional callable that will be passed the data
+///     payloads of a pair of aligned edges and is expected to return the data
+///     payload of their edge in the output. By default the payload of
+///     ``first`` is used.
+///
+/// :returns: A new PyGraph object that is the intersection of ``first`` and
+///     ``second``
+/// :rtype: PyGraph
+/// :raises ValueError: If ``align_by`` is not ``"index"`` or ``"weight"``
+#[pyfunction]
+#[pyo3(
+    signature=(first, second, /, align_by="index", node_merge_fn=None, edge_merge_fn=None),
+    text_signature = "(first, second, /, align_by=\"index\", node_merge_fn=None, edge_merge_fn=None)"
+)]
+pub fn graph_intersection(
+    py: Python,
+    first: &graph::PyGraph,
+    second: &graph::PyGraph,
+    align_by: &str,
+    node_merge_fn: Option<PyObject>,
+    edge_merge_fn: Option<PyObject>,
+) -> PyResult<graph::PyGraph> {
+    let out_graph = intersection(
+        py,
+        &first.graph,
+        &second.graph,
+        AlignBy::parse(align_by)?,
+        node_merge_fn,
+        edge_merge_fn,
+    )?;
+    Ok(graph::PyGraph {
+        graph: out_graph,
+        node_removed: false,
+        multigraph: first.multigraph,
+        attrs: py.None(),
+    })
+}
+
+/// Return a new PyDiGraph by forming the intersection of two PyDiGraph
+/// objects
+///
+/// The nodes of ``second`` are first aligned with the nodes of ``first``,
+/// either by node index or by equal data payloads. The edges of ``second``
+/// are then aligned with the edges of ``first`` between the aligned nodes in
+/// the same direction, and with ``align_by="weight"`` only if their data
+/// payloads are equal too. Every node and edge is aligned with at most one
+/// node or edge of the other graph, so parallel edges are aligned one to
+/// one. The intersection has a node for every pair of aligned nodes and an
+/// edge for every pair of aligned edges, in the order of ``first``.
+///
+/// :param PyDiGraph first: The first directed graph object
+/// :param PyDiGraph second: The second directed graph object
+/// :param str align_by: How the nodes of the graphs are aligned, either
+///     ``"index"`` for nodes with the same index or ``"weight"`` for nodes
+///     with equal data payloads. Default: ``"index"``.
+/// :param node_merge_fn: An optional callable that will be passed the data
+///     payloads of a pair of aligned nodes and is expected to return the data
+///     payload of their node in the output. By default the payload of
+///     ``first`` is used.
+/// :param edge_merge_fn: An optional callable that will be passed the data
+///     payloads of a pair of aligned edges and is expected to return the data
+///     payload of their edge in the output. By default the payload of
+///     ``first`` is used.
+///
+/// :returns: A new PyDiGraph object that is the intersection of ``first`` and
+///     ``second``
+/// :rtype: PyDiGraph
+/// :raises ValueError: If ``align_by`` is not ``"index"`` or ``"weight"``
+#[pyfunction]
+#[pyo3(
+    signature=(first, second, /, align_by="index", node_merge_fn=None, edge_merge_fn=None),
+    text_signature = "(first, second, /, align_by=\"index\", node_merge_fn=None, edge_merge_fn=None)"
+)]
+pub fn digraph_intersection(
+    py: Python,
+    first: &digraph::PyDiGraph,
+    second: &digraph::PyDiGraph,
+    align_by: &str,
+    node_merge_fn: Option<PyObject>,
+    edge_merge_fn: Option<PyObject>,
+) -> PyResult<digraph::PyDiGraph> {
+    let out_graph = intersection(
+        py,
+        &first.graph,
+        &second.graph,
+        AlignBy::parse(align_by)?,
+        node_merge_fn,
+        edge_merge_fn,
+    )?;
+    Ok(digraph::PyDiGraph {
+        graph: out_graph,
+        cycle_state: algo::DfsSpace::default(),
+        check_cycle: false,
+        node_removed: false,
+        multigraph: first.multigraph,
+        attrs: py.None(),
+    })
+}
+
+/// Return a new PyGraph by removing the edges of a PyGraph from another
+/// PyGraph
+///
+/// The nodes and edges of ``second`` are aligned with the nodes and edges of
+/// ``first`` in the same way as in :func:`~rustworkx.graph_intersection`.
+/// The difference has all the nodes of ``first`` and the edges of ``first``
+/// that aren't aligned with an edge of ``second``, with the data payloads of
+/// ``first``.
+///
+/// :param PyGraph first: The first undirected graph object
+/// :param PyGraph second: The second undirected graph object
+/// :param str align_by: How the nodes of the graphs are aligned, either
+///     ``"index"`` for nodes with the same index or ``"weight"`` for nodes
+///     with equal data payloads. Default: ``"index"``.
+///
+/// :returns: A new PyGraph object that is the difference of ``first`` and
+///     ``second``
+/// :rtype: PyGraph
+/// :raises ValueError: If ``align_by`` is not ``"index"`` or ``"weight"``
+#[pyfunction]
+#[pyo3(
+    signature=(first, second, /, align_by="index"),
+    text_signature = "(first, second, /, align_by=\"index\")"
+)]
+pub fn graph_difference(
+    py: Python,
+    first: &graph::PyGraph,
+    second: &graph::PyGraph,
+    align_by: &str,
+) -> PyResult<graph::PyGraph> {
+    let out_graph = difference(py, &first.graph, &second.graph, AlignBy::parse(align_by)?)?;
+    Ok(graph::PyGraph {
+        graph: out_graph,
+        node_removed: false,
+        multigraph: first.multigraph,
+        attrs: py.None(),
+    })
+}
+
+/// Return a new PyDiGraph by removing the edges of a PyDiGraph from another
+/// PyDiGraph
+///
+/// The nodes and edges of ``second`` are aligned with the nodes and edges of
+/// ``first`` in the same way as in :func:`~rustworkx.digraph_intersection`.
+/// The difference has all the nodes of ``first`` and the edges of ``first``
+/// that aren't aligned with an edge of ``second``, with the data payloads of
+/// ``first``.
+///
+/// :param PyDiGraph first: The first directed graph object
+/// :param PyDiGraph second: The second directed graph object
+/// :param str align_by: How the nodes of the graphs are aligned, either
+///     ``"index"`` for nodes with the same index or ``"weight"`` for nodes
+///     with equal data payloads. Default: ``"index"``.
+///
+/// :returns: A new PyDiGraph object that is the difference of ``first`` and
+///     ``second``
+/// :rtype: PyDiGraph
+/// :raises ValueError: If ``align_by`` is not ``"index"`` or ``"weight"``
+#[pyfunction]
+#[pyo3(
+    signature=(first, second, /, align_by="index"),
+    text_signature = "(first, second, /, align_by=\"index\")"
+)]
+pub fn digraph_difference(
+    py: Python,
+    first: &digraph::PyDiGraph,
+    second: &digraph::PyDiGraph,
+    align_by: &str,
+) -> PyResult<digraph::PyDiGraph> {
+    let out_graph = difference(py, &first.graph, &second.graph, AlignBy::parse(align_by)?)?;
+    Ok(digraph::PyDiGraph {
+        graph: out_graph,
+        cycle_state: algo::DfsSpace::default(),
+        check_cycle: false,
+        node_removed: false,
+        multigraph: first.multigraph,
+        attrs: py.None(),
+    })
+}
+
+/// Return a new PyGraph by forming the symmetric difference of two PyGraph
+/// objects
+///
+/// The nodes and edges of ``second`` are aligned with the nodes and edges of
+/// ``first`` in the same way as in :func:`~rustworkx.graph_intersection`.
+/// The symmetric difference has all the nodes of ``first`` followed by the
+/// nodes of ``second`` that aren't aligned with a node of ``first``, and the
+/// edges of both graphs that aren't aligned with an edge of the other graph.
+///
+/// :param PyGraph first: The first undirected graph object
+/// :param PyGraph second: The second undirected graph object
+/// :param str align_by: How the nodes of the graphs are aligned, either
+///     ``"index"`` for nodes with the same index or ``"weight"`` for nodes
+///     with equal data payloads. Default: ``"index"``.
+/// :param node_merge_fn: An optional callable that will be passed the data
+///     payloads of a pair of aligned nodes and is expected to return the data
+///     payload of their node in the output. By default the payload of
+///     ``first`` is used.
+///
+/// :returns: A new PyGraph object that is the symmetric difference of
+///     ``first`` and ``second``
+/// :rtype: PyGraph
+/// :raises ValueError: If ``align_by`` is not ``"index"`` or ``"weight"``
+#[pyfunction]
+#[pyo3(
+    signature=(first, second, /, align_by="index", node_merge_fn=None),
+    text_signature = "(first, second, /, align_by=\"index\", node_merge_fn=None)"
+)]
+pub fn graph_symmetric_difference(
+    py: Python,
+    first: &graph::PyGraph,
+    second: &graph::PyGraph,
+    align_by: &str,
+    node_merge_fn: Option<PyObject>,
+) -> PyResult<graph::PyGraph> {
+    let out_graph = symmetric_difference(
+        py,
+        &first.graph,
+        &second.graph,
+        AlignBy::parse(align_by)?,
+        node_merge_fn,
+    )?;
+    Ok(graph::PyGraph {
+        graph: out_graph,
+        node_removed: false,
+        multigraph: first.multigraph,
+        attrs: py.None(),
+    })
+}
+
+/// Return a new PyDiGraph by forming the symmetric difference of two
+/// PyDiGraph objects
+///
+/// The nodes and edges of ``second`` are aligned with the nodes and edges of
+/// ``first`` in the same way as in :func:`~rustworkx.digraph_intersection`.
+/// The symmetric difference has all the nodes of ``first`` followed by the
+/// nodes of ``second`` that aren't aligned with a node of ``first``, and the
+/// edges of both graphs that aren't aligned with an edge of the other graph.
+///
+/// :param PyDiGraph first: The first directed graph object
+/// :param PyDiGraph second: The second directed graph object
+/// :param str align_by: How the nodes of the graphs are aligned, either
+///     ``"index"`` for nodes with the same index or ``"weight"`` for nodes
+///     with equal data payloads. Default: ``"index"``.
+/// :param node_merge_fn: An optional callable that will be passed the data
+///     payloads of a pair of aligned nodes and is expected to return the data
+///     payload of their node in the output. By default the payload of
+///     ``first`` is used.
+///
+/// :returns: A new PyDiGraph object that is the symmetric difference of
+///     ``first`` and ``second``
+/// :rtype: PyDiGraph
+/// :raises ValueError: If ``align_by`` is not ``"index"`` or ``"weight"``
+#[pyfunction]
+#[pyo3(
+    signature=(first, second, /, align_by="index", node_merge_fn=None),
+    text_signature = "(first, second, /, align_by=\"index\", node_merge_fn=None)"
+)]
+pub fn digraph_symmetric_difference(
+    py: Python,
+    first: &digraph::PyDiGraph,
+    second: &digraph::PyDiGraph,
+    align_by: &str,
+    node_merge_fn: Option<PyObject>,
+) -> PyResult<digraph::PyDiGraph> {
+    let out_graph = symmetric_difference(
+        py,
+        &first.graph,
+        &second.graph,
+        AlignBy::parse(align_by)?,
+        node_merge_fn,
+    )?;
+    Ok(digraph::PyDiGraph {
+        graph: out_graph,
+        cycle_state: algo::DfsSpace::default(),
+        check_cycle: false,
+        node_removed: false,
+        multigraph: first.multigraph,
+        attrs: py.None(),
+    })
+}
+
+/// Return a new PyGraph by forming the full join of two PyGraph objects
+///
+/// The full join is the disjoint union of the two graphs with an extra edge
+/// between every node of ``first`` and every node of ``second``. The nodes
+/// of ``first`` come before the nodes of ``second``.
+///
+/// :param PyGraph first: The first undirected graph object
+/// :param PyGraph second: The second undirected graph object
+/// :param join_fn: An optional callable that will be passed the data
+///     payloads of a node of ``first`` and a node of ``second`` and is
+///     expected to return the data payload of the edge joining them. By
+///     default the payload is ``None``.
+///
+/// :returns: A new PyGraph object that is the full join of ``first`` and
+///     ``second``
+/// :rtype: PyGraph
+///
+/// .. jupyter-execute::
+///
+///   import rustworkx
+///   from rustworkx.visualization import mpl_draw
+///
+///   graph = rustworkx.graph_full_join(
+///       rustworkx.generators.path_graph(3), rustworkx.generators.path_graph(2)
+///   )
+///   mpl_draw(graph)
+#[pyfunction]
+#[pyo3(
+    signature=(first, second, /, join_fn=None),
+    text_signature = "(first, second, /, join_fn=None)"
+)]
+pub fn graph_full_join(
+    py: Python,
+    first: &graph::PyGraph,
+    second: &graph::PyGraph,
+    join_fn: Option<PyObject>,
+) -> PyResult<graph::PyGraph> {
+    let out_graph = full_join(py, &first.graph, &second.graph, join_fn)?;
+    Ok(graph::PyGraph {
+        graph: out_graph,
+        node_removed: false,
+        multigraph: first.multigraph,
+        attrs: py.None(),
+    })
+}
+
+/// Return a new PyDiGraph by forming the full join of two PyDiGraph objects
+///
+/// The full join is the disjoint union of the two graphs with an extra edge
+/// in each direction between every node of ``first`` and every node of
+/// ``second``. The nodes of ``first`` come before the nodes of ``second``.
+///
+/// :param PyDiGraph first: The first directed graph object
+/// :param PyDiGraph second: The second directed graph object
+/// :param join_fn: An optional callable that will be passed the data
+///     payloads of a node of ``first`` and a node of ``second`` and is
+///     expected to return the data payload of each of the edges joining them.
+///     By default the payload is ``None``.
+///
+/// :returns: A new PyDiGraph object that is the full join of ``first`` and
+///     ``second``
+/// :rtype: PyDiGraph
+#[pyfunction]
+#[pyo3(
+    signature=(first, second, /, join_fn=None),
+    text_signature = "(first, second, /, join_fn=None)"
+)]
+pub fn digraph_full_join(
+    py: Python,
+    first: &digraph::PyDiGraph,
+    second: &digraph::PyDiGraph,
+    join_fn: Option<PyObject>,
+) -> PyResult<digraph::PyDiGraph> {
+    let out_graph = full_join(py, &first.graph, &second.graph, join_fn)?;
+    Ok(digraph::PyDiGraph {
+        graph: out_graph,
+        cycle_state: algo::DfsSpace::default(),
+        check_cycle: false,
+        node_removed: false,
+        multigraph: first.multigraph,
+        attrs: py.None(),
+    })
+}
@@ -23,17 +23,18 @@ use petgraph::graph::NodeIndex;
 use petgraph::prelude::*;
 use petgraph::EdgeType;
 
-use rayon::prelude::*;
-
 use crate::iterators::{
     AllPairsPathLengthMapping, AllPairsPathMapping, PathLengthMapping, PathMapping,
 };
+use crate::progress::ProgressFn;
 use crate::{edge_weights_from_callable, NegativeCycle, StablePyGraph};
 
 pub fn all_pairs_bellman_ford_path_lengths<Ty: EdgeType + Sync>(
     py: Python,
     graph: &StablePyGraph<Ty>,
     edge_cost_fn: PyObject,
+    num_threads: Option<usize>,
+    progress: &ProgressFn,
 ) -> PyResult<AllPairsPathLengthMapping> {
     if graph.node_count() == 0 {
         return Ok(AllPairsPathLengthMapping {
@@ -66,9 +67,8 @@ pub fn all_pairs_bellman_ford_path_lengths<Ty: EdgeType + Sync>(
     let negative_cycle = RwLock::new(false);
 
     let node_indices: Vec<NodeIndex> = graph.node_indices().collect();
-    let out_map: DictMap<usize, PathLengthMapping> = node_indices
-        .into_par_iter()
-        .map(|x| {
+    let out_map: DictMap<usize, PathLengthMapping> = progress
+        .par_map(py, node_indices, num_threads, |x| {
             if *negative_cycle.read().unwrap() {
                 return (
                     x.index(),
@@ -107,7 +107,8 @@ pub fn all_pairs_bellman_ford_path_lengths<Ty: EdgeType + Sync>(
                     .collect(),
             };
             (x.index(), out_map)
-        })
+        })?
+        .into_iter()
         .collect();
 
     if *negative_cycle.read().unwrap() {
@@ -125,6 +126,8 @@ pub fn all_pairs_bellman_ford_shortest_paths<Ty: EdgeType + Sync>(
     py: Python,
     graph: &StablePyGraph<Ty>,
     edge_cost_fn: PyObject,
+    num_threads: Option<usize>,
+    progress: &ProgressFn,
 ) -> PyResult<AllPairsPathMapping> {
     if graph.node_count() == 0 {
         return Ok(AllPairsPathMapping {
@@ -159,9 +162,8 @@ pub fn all_pairs_bellman_ford_shortest_paths<Ty: EdgeType + Sync>(
     let negative_cycle = RwLock::new(false);
 
     let out_map = AllPairsPathMapping {
-        paths: node_indices
-            .into_par_iter()
-            .map(|x| {
+        paths: progress
+            .par_map(py, node_indices, num_threads, |x| {
                 if *negative_cycle.read().unwrap() {
                     return (
                         x.index(),
@@ -206,7 +208,8 @@ pub fn all_pairs_bellman_ford_shortest_paths<Ty: EdgeType + Sync>(
                         .collect(),
                 };
                 (index, out_paths)
-            })
+            })?
+            .into_iter()
             .collect(),
     };
 
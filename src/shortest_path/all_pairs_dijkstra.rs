@@ -12,12 +12,16 @@
 
 use hashbrown::HashMap;
 
+use rustworkx_core::csr::CsrGraph;
 use rustworkx_core::dictmap::*;
-use rustworkx_core::shortest_path::dijkstra;
+use rustworkx_core::shortest_path::{
+    all_pairs_dijkstra_within, all_pairs_dijkstra_within_with_progress, dijkstra,
+};
 
 use std::sync::RwLock;
 
-use pyo3::exceptions::PyIndexError;
+use numpy::IntoPyArray;
+use pyo3::exceptions::{PyIndexError, PyValueError};
 use pyo3::prelude::*;
 use pyo3::Python;
 
@@ -26,17 +30,19 @@ use petgraph::prelude::*;
 use petgraph::visit::EdgeIndexable;
 use petgraph::EdgeType;
 
-use rayon::prelude::*;
-
 use crate::iterators::{
     AllPairsPathLengthMapping, AllPairsPathMapping, PathLengthMapping, PathMapping,
 };
+use crate::parallel::run_with_num_threads;
+use crate::progress::ProgressFn;
 use crate::{CostFn, StablePyGraph};
 
 pub fn all_pairs_dijkstra_path_lengths<Ty: EdgeType + Sync>(
     py: Python,
     graph: &StablePyGraph<Ty>,
     edge_cost_fn: PyObject,
+    num_threads: Option<usize>,
+    progress: &ProgressFn,
 ) -> PyResult<AllPairsPathLengthMapping> {
     if graph.node_count() == 0 {
         return Ok(AllPairsPathLengthMapping {
@@ -57,34 +63,24 @@ pub fn all_pairs_dijkstra_path_lengths<Ty: EdgeType + Sync>(
                 .collect(),
         });
     }
-    let edge_cost_callable = CostFn::from(edge_cost_fn);
-    let mut edge_weights: Vec<Option<f64>> = Vec::with_capacity(graph.edge_bound());
-    for index in 0..=graph.edge_bound() {
-        let raw_weight = graph.edge_weight(EdgeIndex::new(index));
-        match raw_weight {
-            Some(weight) => edge_weights.push(Some(edge_cost_callable.call(py, weight)?)),
-            None => edge_weights.push(None),
-        };
-    }
-    let edge_cost = |e: EdgeIndex| -> PyResult<f64> {
-        match edge_weights[e.index()] {
-            Some(weight) => Ok(weight),
-            None => Err(PyIndexError::new_err("No edge found for index")),
-        }
-    };
-    let node_indices: Vec<NodeIndex> = graph.node_indices().collect();
-    let out_map: DictMap<usize, PathLengthMapping> = node_indices
-        .into_par_iter()
-        .map(|x| {
+    let edge_cost_callable = CostFn::try_from(edge_cost_fn)?;
+    // Every node is searched from, so the compact form of the graph with the
+    // edge costs computed up front is worth building first
+    let csr: CsrGraph<f64, Ty> = CsrGraph::from_graph(graph, |e| {
+        edge_cost_callable.call_edge(py, e.id(), e.weight())
+    })?;
+    let node_indices: Vec<usize> = graph.node_indices().map(|node| node.index()).collect();
+    let out_map: DictMap<usize, PathLengthMapping> = progress
+        .par_map(py, node_indices, num_threads, |x| {
             let path_lenghts: PyResult<Vec<Option<f64>>> =
-                dijkstra(graph, x, None, |e| edge_cost(e.id()), None);
+                dijkstra(&csr, x, None, |e| Ok(*e.weight()), None);
             let out_map = PathLengthMapping {
                 path_lengths: path_lenghts
                     .unwrap()
                     .into_iter()
                     .enumerate()
                     .filter_map(|(index, opt_cost)| {
-                        if index != x.index() {
+                        if index != x {
                             opt_cost.map(|cost| (index, cost))
                         } else {
                             None
@@ -92,8 +88,9 @@ pub fn all_pairs_dijkstra_path_lengths<Ty: EdgeType + Sync>(
                     })
                     .collect(),
             };
-            (x.index(), out_map)
-        })
+            (x, out_map)
+        })?
+        .into_iter()
         .collect();
     Ok(AllPairsPathLengthMapping {
         path_lengths: out_map,
@@ -105,6 +102,8 @@ pub fn all_pairs_dijkstra_shortest_paths<Ty: EdgeType + Sync>(
     graph: &StablePyGraph<Ty>,
     edge_cost_fn: PyObject,
     distances: Option<&mut HashMap<usize, DictMap<NodeIndex, f64>>>,
+    num_threads: Option<usize>,
+    progress: &ProgressFn,
 ) -> PyResult<AllPairsPathMapping> {
     if graph.node_count() == 0 {
         return Ok(AllPairsPathMapping {
@@ -125,12 +124,16 @@ pub fn all_pairs_dijkstra_shortest_paths<Ty: EdgeType + Sync>(
                 .collect(),
         });
     }
-    let edge_cost_callable = CostFn::from(edge_cost_fn);
+    let edge_cost_callable = CostFn::try_from(edge_cost_fn)?;
     let mut edge_weights: Vec<Option<f64>> = Vec::with_capacity(graph.edge_bound());
     for index in 0..=graph.edge_bound() {
         let raw_weight = graph.edge_weight(EdgeIndex::new(index));
         match raw_weight {
-            Some(weight) => edge_weights.push(Some(edge_cost_callable.call(py, weight)?)),
+            Some(weight) => edge_weights.push(Some(edge_cost_callable.call_edge(
+                py,
+                EdgeIndex::new(index),
+                weight,
+            )?)),
             None => edge_weights.push(None),
         };
     }
@@ -148,9 +151,8 @@ pub fn all_pairs_dijkstra_shortest_paths<Ty: EdgeType + Sync>(
         RwLock::new(HashMap::new())
     };
     let out_map = AllPairsPathMapping {
-        paths: node_indices
-            .into_par_iter()
-            .map(|x| {
+        paths: progress
+            .par_map(py, node_indices, num_threads, |x| {
                 let mut paths: DictMap<NodeIndex, Vec<NodeIndex>> =
                     DictMap::with_capacity(graph.node_count());
                 let distance =
@@ -176,7 +178,8 @@ pub fn all_pairs_dijkstra_shortest_paths<Ty: EdgeType + Sync>(
                         .collect(),
                 };
                 (index, out_paths)
-            })
+            })?
+            .into_iter()
             .collect(),
     };
     if let Some(x) = distances {
@@ -184,3 +187,51 @@ pub fn all_pairs_dijkstra_shortest_paths<Ty: EdgeType + Sync>(
     };
     Ok(out_map)
 }
+
+/// The number of nodes at which the searches from the source nodes of
+/// :func:`all_pairs_dijkstra_path_lengths_csr` run in parallel.
+const PARALLEL_THRESHOLD: usize = 50;
+
+pub fn all_pairs_dijkstra_path_lengths_csr<Ty: EdgeType + Sync>(
+    py: Python,
+    graph: &StablePyGraph<Ty>,
+    edge_cost_fn: PyObject,
+    cutoff: Option<f64>,
+    num_threads: Option<usize>,
+    progress: &ProgressFn,
+) -> PyResult<PyObject> {
+    if cutoff.is_some_and(|cutoff| cutoff.is_nan() || cutoff < 0.0) {
+        return Err(PyValueError::new_err(
+            "cutoff must be a non-negative number",
+        ));
+    }
+    let edge_cost_callable = CostFn::try_from(edge_cost_fn)?;
+    let csr: CsrGraph<f64, Ty> = CsrGraph::from_graph(graph, |e| {
+        edge_cost_callable.call_edge(py, e.id(), e.weight())
+    })?;
+    let distances = if progress.is_enabled() {
+        let interval = progress.interval(Some(graph.node_count()));
+        progress.run_released(py, num_threads, |report| {
+            all_pairs_dijkstra_within_with_progress(
+                &csr,
+                cutoff,
+                PARALLEL_THRESHOLD,
+                |e| Ok(*e.weight()),
+                interval,
+                report,
+            )
+        })?
+    } else {
+        run_with_num_threads(num_threads, || {
+            all_pairs_dijkstra_within(&csr, cutoff, PARALLEL_THRESHOLD, |e| {
+                Ok::<f64, PyErr>(*e.weight())
+            })
+        })??
+    };
+    Ok((
+        distances.data.into_pyarray_bound(py),
+        distances.indices.into_pyarray_bound(py),
+        distances.indptr.into_pyarray_bound(py),
+    )
+        .into_py(py))
+}
@@ -13,7 +13,7 @@
 use hashbrown::HashMap;
 use rustworkx_core::dictmap::*;
 
-use crate::{get_edge_iter_with_weights, weight_callable};
+use crate::{edge_weights_from_callable, get_edge_iter_with_weights};
 
 use pyo3::prelude::*;
 use pyo3::Python;
@@ -82,12 +82,11 @@ pub fn floyd_warshall<Ty: EdgeType>(
     }
 
     // Build adjacency matrix
+    let edge_weights = edge_weights_from_callable(py, graph, &weight_fn, default_weight)?;
     for edge in graph.edge_references() {
         let i = NodeIndexable::to_index(&graph, edge.source());
         let j = NodeIndexable::to_index(&graph, edge.target());
-        let weight = edge.weight().clone();
-
-        let edge_weight = weight_callable(py, &weight_fn, &weight, default_weight)?;
+        let edge_weight = edge_weights[edge.id().index()].unwrap();
         if let Some(row_i) = mat.get_mut(i) {
             insert_or_minimize!(row_i, j, edge_weight);
         }
@@ -168,9 +167,11 @@ pub fn floyd_warshall_numpy<Ty: EdgeType>(
         None
     };
 
-    // Build adjacency matrix
-    for (i, j, weight) in get_edge_iter_with_weights(graph) {
-        let edge_weight = weight_callable(py, &weight_fn, &weight, default_weight)?;
+    // Build adjacency matrix, get_edge_iter_with_weights() yields the edges in
+    // the order of edge_indices()
+    let edge_weights = edge_weights_from_callable(py, graph, &weight_fn, default_weight)?;
+    for ((i, j, _), edge) in get_edge_iter_with_weights(graph).zip(graph.edge_indices()) {
+        let edge_weight = edge_weights[edge.index()].unwrap();
         mat[[i, j]] = mat[[i, j]].min(edge_weight);
         if as_undirected {
             mat[[j, i]] = mat[[j, i]].min(edge_weight);
@@ -19,7 +19,10 @@ mod num_shortest_path;
 
 use std::convert::TryFrom;
 
-use crate::{digraph, edge_weights_from_callable, graph, CostFn, NegativeCycle, NoPathFound};
+use crate::{
+    csr_structure, digraph, edge_weights_from_callable, graph, CostFn, NegativeCycle, NoPathFound,
+    StablePyGraph, CSR_NODE_THRESHOLD,
+};
 
 use pyo3::prelude::*;
 use pyo3::Python;
@@ -27,7 +30,8 @@ use pyo3::Python;
 use petgraph::graph::NodeIndex;
 use petgraph::prelude::*;
 use petgraph::stable_graph::EdgeIndex;
-use petgraph::visit::NodeCount;
+use petgraph::visit::{NodeCount, Reversed};
+use petgraph::EdgeType;
 use pyo3::exceptions::PyIndexError;
 use pyo3::exceptions::PyValueError;
 
@@ -36,12 +40,81 @@ use numpy::IntoPyArray;
 use rustworkx_core::dictmap::*;
 use rustworkx_core::shortest_path::{
     all_shortest_paths, astar, bellman_ford, dijkstra, k_shortest_path, negative_cycle_finder,
+    voronoi_cells,
 };
 
 use crate::iterators::{
     AllPairsPathLengthMapping, AllPairsPathMapping, NodeIndices, NodesCountMapping,
     PathLengthMapping, PathMapping,
 };
+use crate::progress::ProgressFn;
+
+/// Run Dijkstra's algorithm from ``start`` on ``graph``, or on its reverse if
+/// ``reverse`` is set. Without a ``goal``, graphs with at least
+/// ``CSR_NODE_THRESHOLD`` nodes are searched on their compressed sparse row
+/// form. A search for a ``goal`` stays on the graph, since it usually stops
+/// long before visiting every node and building the copy would cost more.
+fn single_source_dijkstra<Ty: EdgeType>(
+    py: Python,
+    graph: &StablePyGraph<Ty>,
+    reverse: bool,
+    start: NodeIndex,
+    goal: Option<NodeIndex>,
+    cost_fn: &CostFn,
+    paths: Option<&mut DictMap<NodeIndex, Vec<NodeIndex>>>,
+) -> PyResult<Vec<Option<f64>>> {
+    if goal.is_some() || graph.node_count() < CSR_NODE_THRESHOLD {
+        return if reverse {
+            dijkstra(
+                Reversed(graph),
+                start,
+                goal,
+                |e| cost_fn.call_edge(py, e.id(), e.weight()),
+                paths,
+            )
+        } else {
+            dijkstra(
+                graph,
+                start,
+                goal,
+                |e| cost_fn.call_edge(py, e.id(), e.weight()),
+                paths,
+            )
+        };
+    }
+    let csr = csr_structure(graph);
+    let edge_cost = |edge: usize| {
+        let edge = EdgeIndex::new(edge);
+        cost_fn.call_edge(py, edge, &graph[edge])
+    };
+    let mut csr_paths: Option<DictMap<usize, Vec<usize>>> = paths.as_ref().map(|_| DictMap::new());
+    let res = if reverse {
+        dijkstra(
+            Reversed(&csr),
+            start.index(),
+            None,
+            |e| edge_cost(e.id()),
+            csr_paths.as_mut(),
+        )
+    } else {
+        dijkstra(
+            &csr,
+            start.index(),
+            None,
+            |e| edge_cost(e.id()),
+            csr_paths.as_mut(),
+        )
+    };
+    if let (Some(paths), Some(csr_paths)) = (paths, csr_paths) {
+        paths.extend(csr_paths.into_iter().map(|(node, path)| {
+            (
+                NodeIndex::new(node),
+                path.into_iter().map(NodeIndex::new).collect(),
+            )
+        }));
+    }
+    res
+}
 
 /// Find the shortest path from a node
 ///
@@ -53,7 +126,9 @@ use crate::iterators::{
 /// :param int target: An optional target to find a path to
 /// :param weight_fn: An optional weight function for an edge. It will accept
 ///     a single argument, the edge's weight object and will return a float which
-///     will be used to represent the weight/cost of the edge
+///     will be used to represent the weight/cost of the edge.
+///     It can also be a sequence or numpy array of the edge weights
+///     indexed by edge index, which avoids a Python call per edge.
 /// :param float default_weight: If ``weight_fn`` isn't specified this optional
 ///     float value will be used for the weight/cost of each edge.
 /// :param bool as_undirected: If set to true the graph will be treated as
@@ -89,13 +164,15 @@ pub fn graph_dijkstra_shortest_paths(
 
     let cost_fn = CostFn::try_from((weight_fn, default_weight))?;
 
-    (dijkstra(
+    single_source_dijkstra(
+        py,
         &graph.graph,
+        false,
         start,
         goal_index,
-        |e| cost_fn.call(py, e.weight()),
+        &cost_fn,
         Some(&mut paths),
-    ) as PyResult<Vec<Option<f64>>>)?;
+    )?;
 
     Ok(PathMapping {
         paths: paths
@@ -125,7 +202,9 @@ pub fn graph_dijkstra_shortest_paths(
 /// :param int target: A target to find paths to
 /// :param weight_fn: An optional weight function for an edge. It will accept
 ///     a single argument, the edge's weight object and will return a float which
-///     will be used to represent the weight/cost of the edge
+///     will be used to represent the weight/cost of the edge.
+///     It can also be a sequence or numpy array of the edge weights
+///     indexed by edge index, which avoids a Python call per edge.
 /// :param float default_weight: If ``weight_fn`` isn't specified this optional
 ///     float value will be used for the weight/cost of each edge.
 ///
@@ -152,8 +231,9 @@ pub fn graph_all_shortest_paths(
 
     let cost_fn = CostFn::try_from((weight_fn, default_weight))?;
 
-    let paths = (all_shortest_paths(&graph.graph, start, goal, |e| cost_fn.call(py, e.weight()))
-        as PyResult<Vec<Vec<NodeIndex>>>)?;
+    let paths = (all_shortest_paths(&graph.graph, start, goal, |e| {
+        cost_fn.call_edge(py, e.id(), e.weight())
+    }) as PyResult<Vec<Vec<NodeIndex>>>)?;
 
     Ok(paths
         .iter()
@@ -197,11 +277,18 @@ pub fn graph_has_path(
 /// :param int target: An optional target path to find the path
 /// :param weight_fn: An optional weight function for an edge. It will accept
 ///     a single argument, the edge's weight object and will return a float which
-///     will be used to represent the weight/cost of the edge
+///     will be used to represent the weight/cost of the edge.
+///     It can also be a sequence or numpy array of the edge weights
+///     indexed by edge index, which avoids a Python call per edge.
 /// :param float default_weight: If ``weight_fn`` isn't specified this optional
 ///     float value will be used for the weight/cost of each edge.
 /// :param bool as_undirected: If set to true the graph will be treated as
 ///     undirected for finding the shortest path.
+/// :param bool reverse_view: If set to true the edges of the graph will be
+///     followed in the reverse direction, so the shortest paths found are the
+///     paths from every node to ``source``. The paths are listed in the order
+///     they are traversed from ``source``. The graph isn't copied to reverse
+///     it. This has no effect if ``as_undirected`` is set to true.
 ///
 /// :return: Dictionary of paths. The keys are destination node indices and
 ///     the dict values are lists of node indices making the path.
@@ -210,9 +297,10 @@ pub fn graph_has_path(
 ///     is provided.
 #[pyfunction]
 #[pyo3(
-    signature=(graph, source, target=None, weight_fn=None, default_weight=1.0, as_undirected=false),
-    text_signature = "(graph, source, /, target=None weight_fn=None, default_weight=1.0, as_undirected=False)"
+    signature=(graph, source, target=None, weight_fn=None, default_weight=1.0, as_undirected=false, reverse_view=false),
+    text_signature = "(graph, source, /, target=None weight_fn=None, default_weight=1.0, as_undirected=False, reverse_view=False)"
 )]
+#[allow(clippy::too_many_arguments)]
 pub fn digraph_dijkstra_shortest_paths(
     py: Python,
     graph: &digraph::PyDiGraph,
@@ -221,6 +309,7 @@ pub fn digraph_dijkstra_shortest_paths(
     weight_fn: Option<PyObject>,
     default_weight: f64,
     as_undirected: bool,
+    reverse_view: bool,
 ) -> PyResult<PathMapping> {
     let start = NodeIndex::new(source);
     if !graph.graph.contains_node(start) {
@@ -234,24 +323,28 @@ pub fn digraph_dijkstra_shortest_paths(
     let cost_fn = CostFn::try_from((weight_fn, default_weight))?;
 
     if as_undirected {
-        (dijkstra(
+        single_source_dijkstra(
+            py,
             // TODO: Use petgraph undirected adapter after
             // https://github.com/petgraph/petgraph/pull/318 is available in
             // a petgraph release.
             &graph.to_undirected(py, true, None)?.graph,
+            false,
             start,
             goal_index,
-            |e| cost_fn.call(py, e.weight()),
+            &cost_fn,
             Some(&mut paths),
-        ) as PyResult<Vec<Option<f64>>>)?;
+        )?;
     } else {
-        (dijkstra(
+        single_source_dijkstra(
+            py,
             &graph.graph,
+            reverse_view,
             start,
             goal_index,
-            |e| cost_fn.call(py, e.weight()),
+            &cost_fn,
             Some(&mut paths),
-        ) as PyResult<Vec<Option<f64>>>)?;
+        )?;
     }
     Ok(PathMapping {
         paths: paths
@@ -278,7 +371,9 @@ pub fn digraph_dijkstra_shortest_paths(
 /// :param int target: A target to find paths to
 /// :param weight_fn: An optional weight function for an edge. It will accept
 ///     a single argument, the edge's weight object and will return a float which
-///     will be used to represent the weight/cost of the edge
+///     will be used to represent the weight/cost of the edge.
+///     It can also be a sequence or numpy array of the edge weights
+///     indexed by edge index, which avoids a Python call per edge.
 /// :param float default_weight: If ``weight_fn`` isn't specified this optional
 ///     float value will be used for the weight/cost of each edge.
 ///
@@ -311,11 +406,12 @@ pub fn digraph_all_shortest_paths(
             &graph.to_undirected(py, true, None)?.graph,
             start,
             goal,
-            |e| cost_fn.call(py, e.weight()),
+            |e| cost_fn.call_edge(py, e.id(), e.weight()),
         ) as PyResult<Vec<Vec<NodeIndex>>>)?
     } else {
-        (all_shortest_paths(&graph.graph, start, goal, |e| cost_fn.call(py, e.weight()))
-            as PyResult<Vec<Vec<NodeIndex>>>)?
+        (all_shortest_paths(&graph.graph, start, goal, |e| {
+            cost_fn.call_edge(py, e.id(), e.weight())
+        }) as PyResult<Vec<Vec<NodeIndex>>>)?
     };
 
     Ok(paths
@@ -331,6 +427,10 @@ pub fn digraph_all_shortest_paths(
 /// :param int target: The index of the target node
 /// :param bool as_undirected: If set to true the graph will be treated as
 ///     undirected for finding a path
+/// :param bool reverse_view: If set to true the edges of the graph will be
+///     followed in the reverse direction, so this checks if there is a path
+///     from ``target`` to ``source`` instead. The graph isn't copied to
+///     reverse it.
 ///
 /// :return: True if a path exists, False if not.
 /// :rtype: bool
@@ -338,8 +438,8 @@ pub fn digraph_all_shortest_paths(
 ///     is provided.
 #[pyfunction]
 #[pyo3(
-    signature=(graph, source, target, as_undirected=false),
-    text_signature = "(graph, source, target, /, as_undirected=false)"
+    signature=(graph, source, target, as_undirected=false, reverse_view=false),
+    text_signature = "(graph, source, target, /, as_undirected=false, reverse_view=False)"
 )]
 pub fn digraph_has_path(
     py: Python,
@@ -347,9 +447,18 @@ pub fn digraph_has_path(
     source: usize,
     target: usize,
     as_undirected: bool,
+    reverse_view: bool,
 ) -> PyResult<bool> {
-    let path_mapping =
-        digraph_dijkstra_shortest_paths(py, graph, source, Some(target), None, 1.0, as_undirected)?;
+    let path_mapping = digraph_dijkstra_shortest_paths(
+        py,
+        graph,
+        source,
+        Some(target),
+        None,
+        1.0,
+        as_undirected,
+        reverse_view,
+    )?;
 
     Ok(!path_mapping.paths.is_empty())
 }
@@ -362,7 +471,9 @@ pub fn digraph_has_path(
 ///     shortest paths from
 /// :param edge_cost_fn: A python callable that will take in 1 parameter, an
 ///     edge's data object and will return a float that represents the
-///     cost/weight of that edge. It must be non-negative
+///     cost/weight of that edge. It must be non-negative.
+///     It can also be a sequence or numpy array of the edge weights
+///     indexed by edge index, which avoids a Python call per edge.
 /// :param int goal: An optional node index to use as the end of the path.
 ///     When specified the traversal will stop when the goal is reached and
 ///     the output dictionary will only have a single entry with the length
@@ -384,7 +495,7 @@ pub fn graph_dijkstra_shortest_path_lengths(
     goal: Option<usize>,
 ) -> PyResult<PathLengthMapping> {
     let start = NodeIndex::new(node);
-    let edge_cost_callable = CostFn::from(edge_cost_fn);
+    let edge_cost_callable = CostFn::try_from(edge_cost_fn)?;
     let goal_index: Option<NodeIndex> = goal.map(NodeIndex::new);
 
     if !graph.graph.contains_node(start) {
@@ -393,11 +504,13 @@ pub fn graph_dijkstra_shortest_path_lengths(
         )));
     }
 
-    let res: Vec<Option<f64>> = dijkstra(
+    let res = single_source_dijkstra(
+        py,
         &graph.graph,
+        false,
         start,
         goal_index,
-        |e| edge_cost_callable.call(py, e.weight()),
+        &edge_cost_callable,
         None,
     )?;
 
@@ -437,11 +550,17 @@ pub fn graph_dijkstra_shortest_path_lengths(
 ///     shortest paths from
 /// :param edge_cost_fn: A python callable that will take in 1 parameter, an
 ///     edge's data object and will return a float that represents the
-///     cost/weight of that edge. It must be non-negative
+///     cost/weight of that edge. It must be non-negative.
+///     It can also be a sequence or numpy array of the edge weights
+///     indexed by edge index, which avoids a Python call per edge.
 /// :param int goal: An optional node index to use as the end of the path.
 ///     When specified the traversal will stop when the goal is reached and
 ///     the output dictionary will only have a single entry with the length
 ///     of the shortest path to the goal node.
+/// :param bool reverse_view: If set to true the edges of the graph will be
+///     followed in the reverse direction, so the lengths of the shortest paths
+///     from every node to ``node`` are found instead. The graph isn't copied
+///     to reverse it.
 ///
 /// :returns: A dictionary of the shortest paths from the provided node where
 ///     the key is the node index of the end of the path and the value is the
@@ -450,15 +569,19 @@ pub fn graph_dijkstra_shortest_path_lengths(
 /// :raises ValueError: when an edge weight with NaN or negative value
 ///     is provided.
 #[pyfunction]
-#[pyo3(text_signature = "(graph, node, edge_cost_fn, /, goal=None)")]
+#[pyo3(
+    signature=(graph, node, edge_cost_fn, goal=None, reverse_view=false),
+    text_signature = "(graph, node, edge_cost_fn, /, goal=None, reverse_view=False)"
+)]
 pub fn digraph_dijkstra_shortest_path_lengths(
     py: Python,
     graph: &digraph::PyDiGraph,
     node: usize,
     edge_cost_fn: PyObject,
     goal: Option<usize>,
+    reverse_view: bool,
 ) -> PyResult<PathLengthMapping> {
-    let edge_cost_callable = CostFn::from(edge_cost_fn);
+    let edge_cost_callable = CostFn::try_from(edge_cost_fn)?;
 
     let start = NodeIndex::new(node);
     let goal_index: Option<NodeIndex> = goal.map(NodeIndex::new);
@@ -469,11 +592,13 @@ pub fn digraph_dijkstra_shortest_path_lengths(
         )));
     }
 
-    let res: Vec<Option<f64>> = dijkstra(
+    let res = single_source_dijkstra(
+        py,
         &graph.graph,
+        reverse_view,
         start,
         goal_index,
-        |e| edge_cost_callable.call(py, e.weight()),
+        &edge_cost_callable,
         None,
     )?;
 
@@ -505,13 +630,149 @@ pub fn digraph_dijkstra_shortest_path_lengths(
     })
 }
 
+fn voronoi_cells_inner<Ty: EdgeType>(
+    py: Python,
+    graph: &StablePyGraph<Ty>,
+    sources: Vec<usize>,
+    weight_fn: Option<PyObject>,
+    default_weight: f64,
+) -> PyResult<(DictMap<usize, Vec<usize>>, PathLengthMapping)> {
+    let sources: Vec<NodeIndex> = sources.into_iter().map(NodeIndex::new).collect();
+    for source in &sources {
+        if !graph.contains_node(*source) {
+            return Err(PyIndexError::new_err(format!(
+                "Node source index \"{}\" out of graph bound",
+                source.index()
+            )));
+        }
+    }
+    let cost_fn = CostFn::try_from((weight_fn, default_weight))?;
+    let (cells, distances) = voronoi_cells(graph, &sources, |e| {
+        cost_fn.call_edge(py, e.id(), e.weight())
+    })?;
+    Ok((
+        cells
+            .into_iter()
+            .map(|(source, cell)| {
+                (
+                    source.index(),
+                    cell.into_iter().map(|node| node.index()).collect(),
+                )
+            })
+            .collect(),
+        PathLengthMapping {
+            path_lengths: distances
+                .into_iter()
+                .map(|(node, distance)| (node.index(), distance))
+                .collect(),
+        },
+    ))
+}
+
+/// Partition the nodes of a :class:`~.PyGraph` by their nearest source node.
+///
+/// The Voronoi cell of a source is the set of nodes that are closer to it
+/// than to any other source, where the distance between two nodes is the
+/// length of the shortest path between them, computed with Dijkstra's
+/// algorithm from all the sources at once. A node that is as close to several
+/// sources is put in the cell of the first of them in ``sources``, and nodes
+/// that aren't connected to any source aren't in any cell.
+///
+/// :param PyGraph graph: The input graph to use
+/// :param list sources: The node indices of the sources. Duplicates are
+///     ignored.
+/// :param weight_fn: An optional weight function for an edge. It will accept
+///     a single argument, the edge's weight object and will return a float which
+///     will be used to represent the weight/cost of the edge. It must be
+///     non-negative. It can also be a sequence or numpy array of the edge
+///     weights indexed by edge index, which avoids a Python call per edge.
+/// :param float default_weight: If ``weight_fn`` isn't specified this optional
+///     float value will be used for the weight/cost of each edge.
+///
+/// :returns: A tuple of a dictionary mapping every source to the sorted list
+///     of the node indices in its cell, in the order of ``sources``, and a
+///     dictionary mapping every node in a cell to its distance to the source
+///     of the cell.
+/// :rtype: tuple[dict[int, list[int]], PathLengthMapping]
+/// :raises IndexError: when a source isn't a node of the graph.
+/// :raises ValueError: when an edge weight with NaN or negative value
+///     is provided.
+///
+/// .. jupyter-execute::
+///
+///   import rustworkx as rx
+///
+///   graph = rx.generators.grid_graph(3, 3)
+///   cells, distances = rx.graph_voronoi_cells(graph, [0, 8])
+///   print(cells)
+///   print(distances)
+#[pyfunction]
+#[pyo3(
+    signature=(graph, sources, weight_fn=None, default_weight=1.0),
+    text_signature = "(graph, sources, /, weight_fn=None, default_weight=1.0)"
+)]
+pub fn graph_voronoi_cells(
+    py: Python,
+    graph: &graph::PyGraph,
+    sources: Vec<usize>,
+    weight_fn: Option<PyObject>,
+    default_weight: f64,
+) -> PyResult<(DictMap<usize, Vec<usize>>, PathLengthMapping)> {
+    voronoi_cells_inner(py, &graph.graph, sources, weight_fn, default_weight)
+}
+
+/// Partition the nodes of a :class:`~.PyDiGraph` by their nearest source node.
+///
+/// The Voronoi cell of a source is the set of nodes that are closer to it
+/// than to any other source, where the distance from a source to a node is
+/// the length of the shortest path from the source to the node, computed
+/// with Dijkstra's algorithm from all the sources at once. A node that is as
+/// close to several sources is put in the cell of the first of them in
+/// ``sources``, and nodes that can't be reached from any source aren't in
+/// any cell.
+///
+/// :param PyDiGraph graph: The input graph to use
+/// :param list sources: The node indices of the sources. Duplicates are
+///     ignored.
+/// :param weight_fn: An optional weight function for an edge. It will accept
+///     a single argument, the edge's weight object and will return a float which
+///     will be used to represent the weight/cost of the edge. It must be
+///     non-negative. It can also be a sequence or numpy array of the edge
+///     weights indexed by edge index, which avoids a Python call per edge.
+/// :param float default_weight: If ``weight_fn`` isn't specified this optional
+///     float value will be used for the weight/cost of each edge.
+///
+/// :returns: A tuple of a dictionary mapping every source to the sorted list
+///     of the node indices in its cell, in the order of ``sources``, and a
+///     dictionary mapping every node in a cell to its distance from the
+///     source of the cell.
+/// :rtype: tuple[dict[int, list[int]], PathLengthMapping]
+/// :raises IndexError: when a source isn't a node of the graph.
+/// :raises ValueError: when an edge weight with NaN or negative value
+///     is provided.
+#[pyfunction]
+#[pyo3(
+    signature=(graph, sources, weight_fn=None, default_weight=1.0),
+    text_signature = "(graph, sources, /, weight_fn=None, default_weight=1.0)"
+)]
+pub fn digraph_voronoi_cells(
+    py: Python,
+    graph: &digraph::PyDiGraph,
+    sources: Vec<usize>,
+    weight_fn: Option<PyObject>,
+    default_weight: f64,
+) -> PyResult<(DictMap<usize, Vec<usize>>, PathLengthMapping)> {
+    voronoi_cells_inner(py, &graph.graph, sources, weight_fn, default_weight)
+}
+
 /// For each node in the graph, calculates the lengths of the shortest paths
 /// to all others in a :class:`~rustworkx.PyDiGraph` object
 ///
 /// This function will calculate the shortest path lengths from all nodes in the
 /// graph using Dijkstra's algorithm. This function is multithreaded and will
 /// launch a thread pool with threads equal to the number of CPUs by
-/// default. You can tune the number of threads with the ``RAYON_NUM_THREADS``
+/// default. You can limit the number of threads with ``num_threads`` or
+/// :func:`~rustworkx.set_num_threads`, or with the ``RAYON_NUM_THREADS``
 /// environment variable. For example, setting ``RAYON_NUM_THREADS=4`` would
 /// limit the thread pool to 4 threads.
 ///
@@ -519,7 +780,19 @@ pub fn digraph_dijkstra_shortest_path_lengths(
 /// :param edge_cost_fn: A callable object that acts as a weight function for
 ///     an edge. It will accept a single positional argument, the edge's weight
 ///     object and will return a float which will be used to represent the
-///     weight/cost of the edge
+///     weight/cost of the edge.
+///     It can also be a sequence or numpy array of the edge weights
+///     indexed by edge index, which avoids a Python call per edge.
+///
+/// :param int num_threads: The maximum number of threads to use. If it is not
+///     specified the number set with :func:`~rustworkx.set_num_threads` is
+///     used.
+/// :param progress_fn: An optional callable which is called as
+///     ``progress_fn(done, total)`` after every ``progress_interval`` of the
+///     ``total`` source nodes are searched from. An exception raised by it
+///     stops the computation and is raised from this function.
+/// :param int progress_interval: The number of source nodes between the calls
+///     to ``progress_fn``. By default it is called 100 times.
 ///
 /// :return: A read-only dictionary of path lengths. The keys are source
 ///     node indices and the values are dicts of the target node and the length
@@ -535,13 +808,26 @@ pub fn digraph_dijkstra_shortest_path_lengths(
 /// :raises ValueError: when an edge weight with NaN or negative value
 ///     is provided.
 #[pyfunction]
-#[pyo3(text_signature = "(graph, edge_cost_fn, /)")]
+#[pyo3(
+    signature=(graph, edge_cost_fn, num_threads=None, progress_fn=None, progress_interval=None),
+    text_signature = "(graph, edge_cost_fn, /, num_threads=None, progress_fn=None, progress_interval=None)"
+)]
 pub fn digraph_all_pairs_dijkstra_path_lengths(
     py: Python,
     graph: &digraph::PyDiGraph,
     edge_cost_fn: PyObject,
+    num_threads: Option<usize>,
+    progress_fn: Option<PyObject>,
+    progress_interval: Option<usize>,
 ) -> PyResult<AllPairsPathLengthMapping> {
-    all_pairs_dijkstra::all_pairs_dijkstra_path_lengths(py, &graph.graph, edge_cost_fn)
+    let progress = ProgressFn::new(progress_fn, progress_interval)?;
+    all_pairs_dijkstra::all_pairs_dijkstra_path_lengths(
+        py,
+        &graph.graph,
+        edge_cost_fn,
+        num_threads,
+        &progress,
+    )
 }
 
 /// For each node in the graph, finds the shortest paths to all others in a
@@ -550,7 +836,8 @@ pub fn digraph_all_pairs_dijkstra_path_lengths(
 /// This function will generate the shortest paths from all nodes in the graph
 /// Dijkstra's algorithm. This function is multithreaded and will run
 /// launch a thread pool with threads equal to the number of CPUs by default.
-/// You can tune the number of threads with the ``RAYON_NUM_THREADS``
+/// You can limit the number of threads with ``num_threads`` or
+/// :func:`~rustworkx.set_num_threads`, or with the ``RAYON_NUM_THREADS``
 /// environment variable. For example, setting ``RAYON_NUM_THREADS=4`` would
 /// limit the thread pool to 4 threads.
 ///
@@ -558,7 +845,19 @@ pub fn digraph_all_pairs_dijkstra_path_lengths(
 /// :param edge_cost_fn: A callable object that acts as a weight function for
 ///     an edge. It will accept a single positional argument, the edge's weight
 ///     object and will return a float which will be used to represent the
-///     weight/cost of the edge
+///     weight/cost of the edge.
+///     It can also be a sequence or numpy array of the edge weights
+///     indexed by edge index, which avoids a Python call per edge.
+///
+/// :param int num_threads: The maximum number of threads to use. If it is not
+///     specified the number set with :func:`~rustworkx.set_num_threads` is
+///     used.
+/// :param progress_fn: An optional callable which is called as
+///     ``progress_fn(done, total)`` after every ``progress_interval`` of the
+///     ``total`` source nodes are searched from. An exception raised by it
+///     stops the computation and is raised from this function.
+/// :param int progress_interval: The number of source nodes between the calls
+///     to ``progress_fn``. By default it is called 100 times.
 ///
 /// :return: A read-only dictionary of paths. The keys are source node indices
 ///     and the values are dicts of the target node and the list of the
@@ -574,13 +873,27 @@ pub fn digraph_all_pairs_dijkstra_path_lengths(
 /// :raises ValueError: when an edge weight with NaN or negative value
 ///     is provided.
 #[pyfunction]
-#[pyo3(text_signature = "(graph, edge_cost_fn, /)")]
+#[pyo3(
+    signature=(graph, edge_cost_fn, num_threads=None, progress_fn=None, progress_interval=None),
+    text_signature = "(graph, edge_cost_fn, /, num_threads=None, progress_fn=None, progress_interval=None)"
+)]
 pub fn digraph_all_pairs_dijkstra_shortest_paths(
     py: Python,
     graph: &digraph::PyDiGraph,
     edge_cost_fn: PyObject,
+    num_threads: Option<usize>,
+    progress_fn: Option<PyObject>,
+    progress_interval: Option<usize>,
 ) -> PyResult<AllPairsPathMapping> {
-    all_pairs_dijkstra::all_pairs_dijkstra_shortest_paths(py, &graph.graph, edge_cost_fn, None)
+    let progress = ProgressFn::new(progress_fn, progress_interval)?;
+    all_pairs_dijkstra::all_pairs_dijkstra_shortest_paths(
+        py,
+        &graph.graph,
+        edge_cost_fn,
+        None,
+        num_threads,
+        &progress,
+    )
 }
 
 /// For each node in the graph, calculates the lengths of the shortest paths
@@ -593,7 +906,19 @@ pub fn digraph_all_pairs_dijkstra_shortest_paths(
 /// :param edge_cost_fn: A callable object that acts as a weight function for
 ///     an edge. It will accept a single positional argument, the edge's weight
 ///     object and will return a float which will be used to represent the
-///     weight/cost of the edge
+///     weight/cost of the edge.
+///     It can also be a sequence or numpy array of the edge weights
+///     indexed by edge index, which avoids a Python call per edge.
+///
+/// :param int num_threads: The maximum number of threads to use. If it is not
+///     specified the number set with :func:`~rustworkx.set_num_threads` is
+///     used.
+/// :param progress_fn: An optional callable which is called as
+///     ``progress_fn(done, total)`` after every ``progress_interval`` of the
+///     ``total`` source nodes are searched from. An exception raised by it
+///     stops the computation and is raised from this function.
+/// :param int progress_interval: The number of source nodes between the calls
+///     to ``progress_fn``. By default it is called 100 times.
 ///
 /// :return: A read-only dictionary of path lengths. The keys are source
 ///     node indices and the values are dicts of the target node and the length
@@ -609,13 +934,26 @@ pub fn digraph_all_pairs_dijkstra_shortest_paths(
 /// :raises ValueError: when an edge weight with NaN or negative value
 ///     is provided.
 #[pyfunction]
-#[pyo3(text_signature = "(graph, edge_cost_fn, /)")]
+#[pyo3(
+    signature=(graph, edge_cost_fn, num_threads=None, progress_fn=None, progress_interval=None),
+    text_signature = "(graph, edge_cost_fn, /, num_threads=None, progress_fn=None, progress_interval=None)"
+)]
 pub fn graph_all_pairs_dijkstra_path_lengths(
     py: Python,
     graph: &graph::PyGraph,
     edge_cost_fn: PyObject,
+    num_threads: Option<usize>,
+    progress_fn: Option<PyObject>,
+    progress_interval: Option<usize>,
 ) -> PyResult<AllPairsPathLengthMapping> {
-    all_pairs_dijkstra::all_pairs_dijkstra_path_lengths(py, &graph.graph, edge_cost_fn)
+    let progress = ProgressFn::new(progress_fn, progress_interval)?;
+    all_pairs_dijkstra::all_pairs_dijkstra_path_lengths(
+        py,
+        &graph.graph,
+        edge_cost_fn,
+        num_threads,
+        &progress,
+    )
 }
 
 /// For each node in the graph, finds the shortest paths to all others in a
@@ -628,7 +966,19 @@ pub fn graph_all_pairs_dijkstra_path_lengths(
 /// :param edge_cost_fn: A callable object that acts as a weight function for
 ///     an edge. It will accept a single positional argument, the edge's weight
 ///     object and will return a float which will be used to represent the
-///     weight/cost of the edge
+///     weight/cost of the edge.
+///     It can also be a sequence or numpy array of the edge weights
+///     indexed by edge index, which avoids a Python call per edge.
+///
+/// :param int num_threads: The maximum number of threads to use. If it is not
+///     specified the number set with :func:`~rustworkx.set_num_threads` is
+///     used.
+/// :param progress_fn: An optional callable which is called as
+///     ``progress_fn(done, total)`` after every ``progress_interval`` of the
+///     ``total`` source nodes are searched from. An exception raised by it
+///     stops the computation and is raised from this function.
+/// :param int progress_interval: The number of source nodes between the calls
+///     to ``progress_fn``. By default it is called 100 times.
 ///
 /// :return: A read-only dictionary of paths. The keys are destination node
 ///     indices and the values are dicts of the target node and the list of the
@@ -644,13 +994,169 @@ pub fn graph_all_pairs_dijkstra_path_lengths(
 /// :raises ValueError: when an edge weight with NaN or negative value
 ///     is provided.
 #[pyfunction]
-#[pyo3(text_signature = "(graph, edge_cost_fn, /)")]
+#[pyo3(
+    signature=(graph, edge_cost_fn, num_threads=None, progress_fn=None, progress_interval=None),
+    text_signature = "(graph, edge_cost_fn, /, num_threads=None, progress_fn=None, progress_interval=None)"
+)]
 pub fn graph_all_pairs_dijkstra_shortest_paths(
     py: Python,
     graph: &graph::PyGraph,
     edge_cost_fn: PyObject,
+    num_threads: Option<usize>,
+    progress_fn: Option<PyObject>,
+    progress_interval: Option<usize>,
 ) -> PyResult<AllPairsPathMapping> {
-    all_pairs_dijkstra::all_pairs_dijkstra_shortest_paths(py, &graph.graph, edge_cost_fn, None)
+    let progress = ProgressFn::new(progress_fn, progress_interval)?;
+    all_pairs_dijkstra::all_pairs_dijkstra_shortest_paths(
+        py,
+        &graph.graph,
+        edge_cost_fn,
+        None,
+        num_threads,
+        &progress,
+    )
+}
+
+/// For each node in the graph, calculates the lengths of the shortest paths
+/// to the nodes within a distance of it in a :class:`~rustworkx.PyDiGraph`
+/// object, in compressed sparse row (CSR) format
+///
+/// Unlike :func:`~rustworkx.digraph_all_pairs_dijkstra_path_lengths`, which stores
+/// a dictionary entry for every pair of nodes connected by a path, only the
+/// pairs of nodes at a distance of at most ``cutoff`` are stored, in numpy
+/// arrays. Every search from a source node only visits the nodes within the
+/// cutoff, so this scales to graphs where the distances between all pairs of
+/// nodes don't fit in memory. The searches run in parallel in chunks of
+/// source nodes, with the threads set by ``num_threads``,
+/// :func:`~rustworkx.set_num_threads` or the ``RAYON_NUM_THREADS``
+/// environment variable.
+///
+/// :param PyDiGraph graph: The input graph to use
+/// :param edge_cost_fn: A callable object that acts as a weight function for
+///     an edge. It will accept a single positional argument, the edge's weight
+///     object and will return a float which will be used to represent the
+///     weight/cost of the edge.
+///     It can also be a sequence or numpy array of the edge weights
+///     indexed by edge index, which avoids a Python call per edge.
+/// :param float cutoff: The largest length of the shortest paths to store.
+///     If it's not specified the lengths of the shortest paths between all
+///     pairs of nodes connected by a path are stored.
+/// :param int num_threads: The maximum number of threads to use. If it is not
+///     specified the number set with :func:`~rustworkx.set_num_threads` is
+///     used.
+/// :param progress_fn: An optional callable which is called as
+///     ``progress_fn(done, total)`` after every ``progress_interval`` of the
+///     ``total`` source nodes are searched from. An exception raised by it
+///     stops the computation and is raised from this function.
+/// :param int progress_interval: The number of source nodes between the calls
+///     to ``progress_fn``. By default it is called 100 times.
+///
+/// :return: A tuple of the numpy arrays ``(data, indices, indptr)``. The
+///     lengths of the shortest paths from the node with index ``i`` are
+///     ``data[indptr[i]:indptr[i + 1]]`` and their target nodes are
+///     ``indices[indptr[i]:indptr[i + 1]]``, in increasing order. There's a
+///     row for every index up to the largest node index, which is empty for
+///     the indices that aren't nodes of the graph, and the path from a node
+///     to itself isn't stored. They can be passed to
+///     ``scipy.sparse.csr_array`` with a shape of
+///     ``(len(indptr) - 1, len(indptr) - 1)``.
+/// :rtype: tuple
+/// :raises ValueError: when an edge weight with NaN or negative value
+///     is provided, or ``cutoff`` is negative.
+#[pyfunction]
+#[pyo3(
+    signature=(graph, edge_cost_fn, cutoff=None, num_threads=None, progress_fn=None, progress_interval=None),
+    text_signature = "(graph, edge_cost_fn, /, cutoff=None, num_threads=None, progress_fn=None, progress_interval=None)"
+)]
+pub fn digraph_all_pairs_dijkstra_path_lengths_csr(
+    py: Python,
+    graph: &digraph::PyDiGraph,
+    edge_cost_fn: PyObject,
+    cutoff: Option<f64>,
+    num_threads: Option<usize>,
+    progress_fn: Option<PyObject>,
+    progress_interval: Option<usize>,
+) -> PyResult<PyObject> {
+    let progress = ProgressFn::new(progress_fn, progress_interval)?;
+    all_pairs_dijkstra::all_pairs_dijkstra_path_lengths_csr(
+        py,
+        &graph.graph,
+        edge_cost_fn,
+        cutoff,
+        num_threads,
+        &progress,
+    )
+}
+
+/// For each node in the graph, calculates the lengths of the shortest paths
+/// to the nodes within a distance of it in a :class:`~rustworkx.PyGraph`
+/// object, in compressed sparse row (CSR) format
+///
+/// Unlike :func:`~rustworkx.graph_all_pairs_dijkstra_path_lengths`, which stores
+/// a dictionary entry for every pair of nodes connected by a path, only the
+/// pairs of nodes at a distance of at most ``cutoff`` are stored, in numpy
+/// arrays. Every search from a source node only visits the nodes within the
+/// cutoff, so this scales to graphs where the distances between all pairs of
+/// nodes don't fit in memory. The searches run in parallel in chunks of
+/// source nodes, with the threads set by ``num_threads``,
+/// :func:`~rustworkx.set_num_threads` or the ``RAYON_NUM_THREADS``
+/// environment variable.
+///
+/// :param PyGraph graph: The input graph to use
+/// :param edge_cost_fn: A callable object that acts as a weight function for
+///     an edge. It will accept a single positional argument, the edge's weight
+///     object and will return a float which will be used to represent the
+///     weight/cost of the edge.
+///     It can also be a sequence or numpy array of the edge weights
+///     indexed by edge index, which avoids a Python call per edge.
+/// :param float cutoff: The largest length of the shortest paths to store.
+///     If it's not specified the lengths of the shortest paths between all
+///     pairs of nodes connected by a path are stored.
+/// :param int num_threads: The maximum number of threads to use. If it is not
+///     specified the number set with :func:`~rustworkx.set_num_threads` is
+///     used.
+/// :param progress_fn: An optional callable which is called as
+///     ``progress_fn(done, total)`` after every ``progress_interval`` of the
+///     ``total`` source nodes are searched from. An exception raised by it
+///     stops the computation and is raised from this function.
+/// :param int progress_interval: The number of source nodes between the calls
+///     to ``progress_fn``. By default it is called 100 times.
+///
+/// :return: A tuple of the numpy arrays ``(data, indices, indptr)``. The
+///     lengths of the shortest paths from the node with index ``i`` are
+///     ``data[indptr[i]:indptr[i + 1]]`` and their target nodes are
+///     ``indices[indptr[i]:indptr[i + 1]]``, in increasing order. There's a
+///     row for every index up to the largest node index, which is empty for
+///     the indices that aren't nodes of the graph, and the path from a node
+///     to itself isn't stored. They can be passed to
+///     ``scipy.sparse.csr_array`` with a shape of
+///     ``(len(indptr) - 1, len(indptr) - 1)``.
+/// :rtype: tuple
+/// :raises ValueError: when an edge weight with NaN or negative value
+///     is provided, or ``cutoff`` is negative.
+#[pyfunction]
+#[pyo3(
+    signature=(graph, edge_cost_fn, cutoff=None, num_threads=None, progress_fn=None, progress_interval=None),
+    text_signature = "(graph, edge_cost_fn, /, cutoff=None, num_threads=None, progress_fn=None, progress_interval=None)"
+)]
+pub fn graph_all_pairs_dijkstra_path_lengths_csr(
+    py: Python,
+    graph: &graph::PyGraph,
+    edge_cost_fn: PyObject,
+    cutoff: Option<f64>,
+    num_threads: Option<usize>,
+    progress_fn: Option<PyObject>,
+    progress_interval: Option<usize>,
+) -> PyResult<PyObject> {
+    let progress = ProgressFn::new(progress_fn, progress_interval)?;
+    all_pairs_dijkstra::all_pairs_dijkstra_path_lengths_csr(
+        py,
+        &graph.graph,
+        edge_cost_fn,
+        cutoff,
+        num_threads,
+        &progress,
+    )
 }
 
 /// Compute the A* shortest path for a PyDiGraph
@@ -691,8 +1197,8 @@ pub fn digraph_astar_shortest_path(
         Ok(output)
     };
 
-    let edge_cost_callable = CostFn::from(edge_cost_fn);
-    let estimate_cost_callable = CostFn::from(estimate_cost_fn);
+    let edge_cost_callable = CostFn::try_from(edge_cost_fn)?;
+    let estimate_cost_callable = CostFn::try_from_node_fn(Some(estimate_cost_fn), 0.0)?;
     let start = NodeIndex::new(node);
 
     if !graph.graph.contains_node(start) {
@@ -705,7 +1211,7 @@ pub fn digraph_astar_shortest_path(
         &graph.graph,
         start,
         |f| goal_fn_callable(graph.graph.node_weight(f).unwrap()),
-        |e| edge_cost_callable.call(py, e.weight()),
+        |e| edge_cost_callable.call_edge(py, e.id(), e.weight()),
         |estimate| estimate_cost_callable.call(py, &graph.graph[estimate]),
     )?;
     let path = match astar_res {
@@ -755,8 +1261,8 @@ pub fn graph_astar_shortest_path(
         Ok(output)
     };
 
-    let edge_cost_callable = CostFn::from(edge_cost_fn);
-    let estimate_cost_callable = CostFn::from(estimate_cost_fn);
+    let edge_cost_callable = CostFn::try_from(edge_cost_fn)?;
+    let estimate_cost_callable = CostFn::try_from_node_fn(Some(estimate_cost_fn), 0.0)?;
     let start = NodeIndex::new(node);
 
     if !graph.graph.contains_node(start) {
@@ -769,7 +1275,7 @@ pub fn graph_astar_shortest_path(
         &graph.graph,
         start,
         |f| goal_fn_callable(graph.graph.node_weight(f).unwrap()),
-        |e| edge_cost_callable.call(py, e.weight()),
+        |e| edge_cost_callable.call_edge(py, e.id(), e.weight()),
         |estimate| estimate_cost_callable.call(py, &graph.graph[estimate]),
     )?;
     let path = match astar_res {
@@ -812,11 +1318,11 @@ pub fn digraph_k_shortest_path_lengths(
     goal: Option<usize>,
 ) -> PyResult<PathLengthMapping> {
     let out_goal = goal.map(NodeIndex::new);
-    let edge_cost_callable = CostFn::from(edge_cost);
+    let edge_cost_callable = CostFn::try_from(edge_cost)?;
 
     let out_map: Vec<Option<f64>> =
         k_shortest_path(&graph.graph, NodeIndex::new(start), out_goal, k, |e| {
-            edge_cost_callable.call(py, e.weight())
+            edge_cost_callable.call_edge(py, e.id(), e.weight())
         })?;
 
     if let Some(goal_usize) = goal {
@@ -872,11 +1378,11 @@ pub fn graph_k_shortest_path_lengths(
     goal: Option<usize>,
 ) -> PyResult<PathLengthMapping> {
     let out_goal = goal.map(NodeIndex::new);
-    let edge_cost_callable = CostFn::from(edge_cost);
+    let edge_cost_callable = CostFn::try_from(edge_cost)?;
 
     let out_map: Vec<Option<f64>> =
         k_shortest_path(&graph.graph, NodeIndex::new(start), out_goal, k, |e| {
-            edge_cost_callable.call(py, e.weight())
+            edge_cost_callable.call_edge(py, e.id(), e.weight())
         })?;
 
     if let Some(goal_usize) = goal {
@@ -925,7 +1431,9 @@ pub fn graph_k_shortest_path_lengths(
 ///
 ///         digraph_floyd_warshall(graph, weight_fn=float)
 ///
-///     to cast the edge object as a float as the weight.
+///     to cast the edge object as a float as the weight. It can also be a
+///     sequence or numpy array of the edge weights indexed by edge index,
+///     which avoids a Python call per edge.
 /// :param as_undirected: If set to true each directed edge will be treated as
 ///     bidirectional/undirected.
 /// :param int parallel_threshold: The number of nodes to execute
@@ -990,7 +1498,9 @@ pub fn digraph_floyd_warshall(
 ///
 ///         graph_floyd_warshall(graph, weight_fn=float)
 ///
-///     to cast the edge object as a float as the weight.
+///     to cast the edge object as a float as the weight. It can also be a
+///     sequence or numpy array of the edge weights indexed by edge index,
+///     which avoids a Python call per edge.
 /// :param int parallel_threshold: The number of nodes to execute
 ///     the algorithm in parallel at. It defaults to 300, but this can
 ///     be tuned
@@ -1053,7 +1563,9 @@ pub fn graph_floyd_warshall(
 ///
 ///         graph_floyd_warshall_numpy(graph, weight_fn: lambda x: float(x))
 ///
-///     to cast the edge object as a float as the weight.
+///     to cast the edge object as a float as the weight. It can also be a
+///     sequence or numpy array of the edge weights indexed by edge index,
+///     which avoids a Python call per edge.
 /// :param int parallel_threshold: The number of nodes to execute
 ///     the algorithm in parallel at. It defaults to 300, but this can
 ///     be tuned
@@ -1110,7 +1622,9 @@ pub fn graph_floyd_warshall_numpy(
 ///
 ///         graph_floyd_warshall_numpy(graph, weight_fn: lambda x: float(x))
 ///
-///     to cast the edge object as a float as the weight.
+///     to cast the edge object as a float as the weight. It can also be a
+///     sequence or numpy array of the edge weights indexed by edge index,
+///     which avoids a Python call per edge.
 /// :param int parallel_threshold: The number of nodes to execute
 ///     the algorithm in parallel at. It defaults to 300, but this can
 ///     be tuned
@@ -1186,7 +1700,9 @@ pub fn graph_floyd_warshall_successor_and_distance(
 ///
 ///         graph_floyd_warshall_numpy(graph, weight_fn: lambda x: float(x))
 ///
-///     to cast the edge object as a float as the weight.
+///     to cast the edge object as a float as the weight. It can also be a
+///     sequence or numpy array of the edge weights indexed by edge index,
+///     which avoids a Python call per edge.
 /// :param as_undirected: If set to true each directed edge will be treated as
 ///     bidirectional/undirected.
 /// :param int parallel_threshold: The number of nodes to execute
@@ -1246,7 +1762,9 @@ pub fn digraph_floyd_warshall_numpy(
 ///
 ///         graph_floyd_warshall_numpy(graph, weight_fn: lambda x: float(x))
 ///
-///     to cast the edge object as a float as the weight.
+///     to cast the edge object as a float as the weight. It can also be a
+///     sequence or numpy array of the edge weights indexed by edge index,
+///     which avoids a Python call per edge.
 /// :param as_undirected: If set to true each directed edge will be treated as
 ///     bidirectional/undirected.
 /// :param int parallel_threshold: The number of nodes to execute
@@ -1565,7 +2083,9 @@ pub fn graph_unweighted_average_shortest_path_length(
 ///     shortest paths from
 /// :param edge_cost_fn: A python callable that will take in 1 parameter, an
 ///     edge's data object and will return a float that represents the
-///     cost/weight of that edge. It can be negative.
+///     cost/weight of that edge. It can be negative. It can also be a sequence
+///     or numpy array of the edge weights indexed by edge index, which avoids
+///     a Python call per edge.
 /// :param int goal: An optional node index to use as the end of the path.
 ///     When specified the output dictionary will only have a single entry with
 ///     the length of the shortest path to the goal node.
@@ -1650,7 +2170,9 @@ pub fn digraph_bellman_ford_shortest_path_lengths(
 ///     shortest paths from
 /// :param edge_cost_fn: A python callable that will take in 1 parameter, an
 ///     edge's data object and will return a float that represents the
-///     cost/weight of that edge. It can be negative.
+///     cost/weight of that edge. It can be negative. It can also be a sequence
+///     or numpy array of the edge weights indexed by edge index, which avoids
+///     a Python call per edge.
 /// :param int goal: An optional node index to use as the end of the path.
 ///     When specified the output dictionary will only have a single entry with
 ///     the length of the shortest path to the goal node.
@@ -1737,7 +2259,9 @@ pub fn graph_bellman_ford_shortest_path_lengths(
 /// :param int target: An optional target to find a path to
 /// :param weight_fn: An optional weight function for an edge. It will accept
 ///     a single argument, the edge's weight object and will return a float which
-///     will be used to represent the weight/cost of the edge
+///     will be used to represent the weight/cost of the edge. It can also be
+///     a sequence or numpy array of the edge weights indexed by edge index,
+///     which avoids a Python call per edge.
 /// :param float default_weight: If ``weight_fn`` isn't specified this optional
 ///     float value will be used for the weight/cost of each edge.
 /// :param bool as_undirected: If set to true the graph will be treated as
@@ -1818,7 +2342,9 @@ pub fn graph_bellman_ford_shortest_paths(
 /// :param int target: An optional target to find a path to
 /// :param weight_fn: An optional weight function for an edge. It will accept
 ///     a single argument, the edge's weight object and will return a float which
-///     will be used to represent the weight/cost of the edge
+///     will be used to represent the weight/cost of the edge. It can also be
+///     a sequence or numpy array of the edge weights indexed by edge index,
+///     which avoids a Python call per edge.
 /// :param float default_weight: If ``weight_fn`` isn't specified this optional
 ///     float value will be used for the weight/cost of each edge.
 /// :param bool as_undirected: If set to true the graph will be treated as
@@ -1909,7 +2435,8 @@ pub fn digraph_bellman_ford_shortest_paths(
 /// :param PyDiGraph graph: The input graph to use
 /// :param edge_cost_fn: A python callable that will take in 1 parameter, an edge's
 ///     data object and will return a float that represents the cost of that
-///     edge.
+///     edge. It can also be a sequence or numpy array of the edge weights
+///     indexed by edge index, which avoids a Python call per edge.
 ///
 /// :return: True if there is a negative cycle or False otherwise
 /// :rtype: bool
@@ -1942,7 +2469,8 @@ pub fn negative_edge_cycle(
 /// :param PyDiGraph graph: The input graph to use
 /// :param edge_cost_fn: A python callable that will take in 1 parameter, an edge's
 ///     data object and will return a float that represents the cost of that
-///     edge.
+///     edge. It can also be a sequence or numpy array of the edge weights
+///     indexed by edge index, which avoids a Python call per edge.
 ///
 /// :return: A list of the nodes in an arbitrary negative cycle, if it exists
 /// :rtype: NodeIndices
@@ -1983,7 +2511,8 @@ pub fn find_negative_cycle(
 /// This function will calculate the shortest path lengths from all nodes in the
 /// graph using the Bellman-Ford algorithm. This function is multithreaded and will
 /// launch a thread pool with threads equal to the number of CPUs by
-/// default. You can tune the number of threads with the ``RAYON_NUM_THREADS``
+/// default. You can limit the number of threads with ``num_threads`` or
+/// :func:`~rustworkx.set_num_threads`, or with the ``RAYON_NUM_THREADS``
 /// environment variable. For example, setting ``RAYON_NUM_THREADS=4`` would
 /// limit the thread pool to 4 threads.
 ///
@@ -1991,7 +2520,19 @@ pub fn find_negative_cycle(
 /// :param edge_cost_fn: A callable object that acts as a weight function for
 ///     an edge. It will accept a single positional argument, the edge's weight
 ///     object and will return a float which will be used to represent the
-///     weight/cost of the edge
+///     weight/cost of the edge. It can also be a sequence or numpy array of
+///     the edge weights indexed by edge index, which avoids a Python call per
+///     edge.
+///
+/// :param int num_threads: The maximum number of threads to use. If it is not
+///     specified the number set with :func:`~rustworkx.set_num_threads` is
+///     used.
+/// :param progress_fn: An optional callable which is called as
+///     ``progress_fn(done, total)`` after every ``progress_interval`` of the
+///     ``total`` source nodes are searched from. An exception raised by it
+///     stops the computation and is raised from this function.
+/// :param int progress_interval: The number of source nodes between the calls
+///     to ``progress_fn``. By default it is called 100 times.
 ///
 /// :return: A read-only dictionary of path lengths. The keys are source
 ///     node indices and the values are dicts of the target node and the length
@@ -2008,13 +2549,26 @@ pub fn find_negative_cycle(
 /// :raises: :class:`~rustworkx.NegativeCycle`: when there is a negative cycle and the shortest
 ///     path is not defined.
 #[pyfunction]
-#[pyo3(text_signature = "(graph, edge_cost_fn, /)")]
+#[pyo3(
+    signature=(graph, edge_cost_fn, num_threads=None, progress_fn=None, progress_interval=None),
+    text_signature = "(graph, edge_cost_fn, /, num_threads=None, progress_fn=None, progress_interval=None)"
+)]
 pub fn digraph_all_pairs_bellman_ford_path_lengths(
     py: Python,
     graph: &digraph::PyDiGraph,
     edge_cost_fn: PyObject,
+    num_threads: Option<usize>,
+    progress_fn: Option<PyObject>,
+    progress_interval: Option<usize>,
 ) -> PyResult<AllPairsPathLengthMapping> {
-    all_pairs_bellman_ford::all_pairs_bellman_ford_path_lengths(py, &graph.graph, edge_cost_fn)
+    let progress = ProgressFn::new(progress_fn, progress_interval)?;
+    all_pairs_bellman_ford::all_pairs_bellman_ford_path_lengths(
+        py,
+        &graph.graph,
+        edge_cost_fn,
+        num_threads,
+        &progress,
+    )
 }
 
 /// For each node in the graph, finds the shortest paths to all others in a
@@ -2023,7 +2577,8 @@ pub fn digraph_all_pairs_bellman_ford_path_lengths(
 /// This function will generate the shortest paths from all nodes in the graph
 /// the Bellman-Ford  algorithm. This function is multithreaded and will run
 /// launch a thread pool with threads equal to the number of CPUs by default.
-/// You can tune the number of threads with the ``RAYON_NUM_THREADS``
+/// You can limit the number of threads with ``num_threads`` or
+/// :func:`~rustworkx.set_num_threads`, or with the ``RAYON_NUM_THREADS``
 /// environment variable. For example, setting ``RAYON_NUM_THREADS=4`` would
 /// limit the thread pool to 4 threads.
 ///
@@ -2031,7 +2586,19 @@ pub fn digraph_all_pairs_bellman_ford_path_lengths(
 /// :param edge_cost_fn: A callable object that acts as a weight function for
 ///     an edge. It will accept a single positional argument, the edge's weight
 ///     object and will return a float which will be used to represent the
-///     weight/cost of the edge
+///     weight/cost of the edge. It can also be a sequence or numpy array of
+///     the edge weights indexed by edge index, which avoids a Python call per
+///     edge.
+///
+/// :param int num_threads: The maximum number of threads to use. If it is not
+///     specified the number set with :func:`~rustworkx.set_num_threads` is
+///     used.
+/// :param progress_fn: An optional callable which is called as
+///     ``progress_fn(done, total)`` after every ``progress_interval`` of the
+///     ``total`` source nodes are searched from. An exception raised by it
+///     stops the computation and is raised from this function.
+/// :param int progress_interval: The number of source nodes between the calls
+///     to ``progress_fn``. By default it is called 100 times.
 ///
 /// :return: A read-only dictionary of paths. The keys are source node indices
 ///     and the values are dicts of the target node and the list of the
@@ -2048,13 +2615,26 @@ pub fn digraph_all_pairs_bellman_ford_path_lengths(
 /// :raises: :class:`~rustworkx.NegativeCycle`: when there is a negative cycle and the shortest
 ///     path is not defined.
 #[pyfunction]
-#[pyo3(text_signature = "(graph, edge_cost_fn, /)")]
+#[pyo3(
+    signature=(graph, edge_cost_fn, num_threads=None, progress_fn=None, progress_interval=None),
+    text_signature = "(graph, edge_cost_fn, /, num_threads=None, progress_fn=None, progress_interval=None)"
+)]
 pub fn digraph_all_pairs_bellman_ford_shortest_paths(
     py: Python,
     graph: &digraph::PyDiGraph,
     edge_cost_fn: PyObject,
+    num_threads: Option<usize>,
+    progress_fn: Option<PyObject>,
+    progress_interval: Option<usize>,
 ) -> PyResult<AllPairsPathMapping> {
-    all_pairs_bellman_ford::all_pairs_bellman_ford_shortest_paths(py, &graph.graph, edge_cost_fn)
+    let progress = ProgressFn::new(progress_fn, progress_interval)?;
+    all_pairs_bellman_ford::all_pairs_bellman_ford_shortest_paths(
+        py,
+        &graph.graph,
+        edge_cost_fn,
+        num_threads,
+        &progress,
+    )
 }
 
 /// For each node in the graph, calculates the lengths of the shortest paths
@@ -2067,7 +2647,19 @@ pub fn digraph_all_pairs_bellman_ford_shortest_paths(
 /// :param edge_cost_fn: A callable object that acts as a weight function for
 ///     an edge. It will accept a single positional argument, the edge's weight
 ///     object and will return a float which will be used to represent the
-///     weight/cost of the edge
+///     weight/cost of the edge. It can also be a sequence or numpy array of
+///     the edge weights indexed by edge index, which avoids a Python call per
+///     edge.
+///
+/// :param int num_threads: The maximum number of threads to use. If it is not
+///     specified the number set with :func:`~rustworkx.set_num_threads` is
+///     used.
+/// :param progress_fn: An optional callable which is called as
+///     ``progress_fn(done, total)`` after every ``progress_interval`` of the
+///     ``total`` source nodes are searched from. An exception raised by it
+///     stops the computation and is raised from this function.
+/// :param int progress_interval: The number of source nodes between the calls
+///     to ``progress_fn``. By default it is called 100 times.
 ///
 /// :return: A read-only dictionary of path lengths. The keys are source
 ///     node indices and the values are dicts of the target node and the length
@@ -2084,13 +2676,26 @@ pub fn digraph_all_pairs_bellman_ford_shortest_paths(
 /// :raises: :class:`~rustworkx.NegativeCycle`: when there is a negative cycle and the shortest
 ///     path is not defined.
 #[pyfunction]
-#[pyo3(text_signature = "(graph, edge_cost_fn, /)")]
+#[pyo3(
+    signature=(graph, edge_cost_fn, num_threads=None, progress_fn=None, progress_interval=None),
+    text_signature = "(graph, edge_cost_fn, /, num_threads=None, progress_fn=None, progress_interval=None)"
+)]
 pub fn graph_all_pairs_bellman_ford_path_lengths(
     py: Python,
     graph: &graph::PyGraph,
     edge_cost_fn: PyObject,
+    num_threads: Option<usize>,
+    progress_fn: Option<PyObject>,
+    progress_interval: Option<usize>,
 ) -> PyResult<AllPairsPathLengthMapping> {
-    all_pairs_bellman_ford::all_pairs_bellman_ford_path_lengths(py, &graph.graph, edge_cost_fn)
+    let progress = ProgressFn::new(progress_fn, progress_interval)?;
+    all_pairs_bellman_ford::all_pairs_bellman_ford_path_lengths(
+        py,
+        &graph.graph,
+        edge_cost_fn,
+        num_threads,
+        &progress,
+    )
 }
 
 /// For each node in the graph, finds the shortest paths to all others in a
@@ -2103,7 +2708,19 @@ pub fn graph_all_pairs_bellman_ford_path_lengths(
 /// :param edge_cost_fn: A callable object that acts as a weight function for
 ///     an edge. It will accept a single positional argument, the edge's weight
 ///     object and will return a float which will be used to represent the
-///     weight/cost of the edge
+///     weight/cost of the edge. It can also be a sequence or numpy array of
+///     the edge weights indexed by edge index, which avoids a Python call per
+///     edge.
+///
+/// :param int num_threads: The maximum number of threads to use. If it is not
+///     specified the number set with :func:`~rustworkx.set_num_threads` is
+///     used.
+/// :param progress_fn: An optional callable which is called as
+///     ``progress_fn(done, total)`` after every ``progress_interval`` of the
+///     ``total`` source nodes are searched from. An exception raised by it
+///     stops the computation and is raised from this function.
+/// :param int progress_interval: The number of source nodes between the calls
+///     to ``progress_fn``. By default it is called 100 times.
 ///
 /// :return: A read-only dictionary of paths. The keys are destination node
 ///     indices and the values are dicts of the target node and the list of the
@@ -2120,11 +2737,24 @@ pub fn graph_all_pairs_bellman_ford_path_lengths(
 /// :raises: :class:`~rustworkx.NegativeCycle`: when there is a negative cycle and the shortest
 ///     path is not defined.
 #[pyfunction]
-#[pyo3(text_signature = "(graph, edge_cost_fn, /)")]
+#[pyo3(
+    signature=(graph, edge_cost_fn, num_threads=None, progress_fn=None, progress_interval=None),
+    text_signature = "(graph, edge_cost_fn, /, num_threads=None, progress_fn=None, progress_interval=None)"
+)]
 pub fn graph_all_pairs_bellman_ford_shortest_paths(
     py: Python,
     graph: &graph::PyGraph,
     edge_cost_fn: PyObject,
+    num_threads: Option<usize>,
+    progress_fn: Option<PyObject>,
+    progress_interval: Option<usize>,
 ) -> PyResult<AllPairsPathMapping> {
-    all_pairs_bellman_ford::all_pairs_bellman_ford_shortest_paths(py, &graph.graph, edge_cost_fn)
+    let progress = ProgressFn::new(progress_fn, progress_interval)?;
+    all_pairs_bellman_ford::all_pairs_bellman_ford_shortest_paths(
+        py,
+        &graph.graph,
+        edge_cost_fn,
+        num_threads,
+        &progress,
+    )
 }
@@ -0,0 +1,826 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+#![allow(clippy::too_many_arguments)]
+
+use std::convert::TryFrom;
+
+use crate::{digraph, graph, CostFn, FailedToConverge, InvalidNode, StablePyGraph};
+
+use hashbrown::HashMap;
+use ndarray::prelude::*;
+use numpy::IntoPyArray;
+use petgraph::prelude::*;
+use petgraph::EdgeType;
+use pyo3::exceptions::{PyTypeError, PyValueError};
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+use pyo3::IntoPy;
+use pyo3::PyClass;
+use rustworkx_core::dictmap::*;
+use rustworkx_core::similarity;
+
+fn check_arguments<Ty: EdgeType>(
+    graph: &StablePyGraph<Ty>,
+    source: Option<usize>,
+    target: Option<usize>,
+    decay: f64,
+) -> PyResult<()> {
+    if !(0. ..=1.).contains(&decay) {
+        return Err(PyValueError::new_err("decay must be between 0 and 1"));
+    }
+    check_nodes(graph, source, target)
+}
+
+fn check_nodes<Ty: EdgeType>(
+    graph: &StablePyGraph<Ty>,
+    source: Option<usize>,
+    target: Option<usize>,
+) -> PyResult<()> {
+    for node in [source, target].into_iter().flatten() {
+        if !graph.contains_node(NodeIndex::new(node)) {
+            return Err(InvalidNode::new_err(format!(
+                "Node index {} is not in the graph",
+                node
+            )));
+        }
+    }
+    if source.is_none() && target.is_some() {
+        return Err(PyValueError::new_err(
+            "source must be specified if target is specified",
+        ));
+    }
+    Ok(())
+}
+
+/// Select the part of the similarity matrix requested by `source` and
+/// `target` and convert it to a Python object.
+fn similarity_output<Ty: EdgeType>(
+    py: Python,
+    graph: &StablePyGraph<Ty>,
+    scores: Option<Vec<Vec<f64>>>,
+    source: Option<usize>,
+    target: Option<usize>,
+    max_iter: usize,
+) -> PyResult<PyObject> {
+    let scores = match scores {
+        Some(scores) => scores,
+        None => {
+            return Err(FailedToConverge::new_err(format!(
+                "Function failed to converge on a solution in {} iterations",
+                max_iter
+            )))
+        }
+    };
+    let row = |source: usize| -> DictMap<usize, f64> {
+        graph
+            .node_indices()
+            .map(|node| (node.index(), scores[source][node.index()]))
+            .collect()
+    };
+    Ok(match (source, target) {
+        (Some(source), Some(target)) => scores[source][target].into_py(py),
+        (Some(source), None) => row(source).into_py(py),
+        _ => graph
+            .node_indices()
+            .map(|node| (node.index(), row(node.index())))
+            .collect::<DictMap<usize, DictMap<usize, f64>>>()
+            .into_py(py),
+    })
+}
+
+fn simrank_similarity<Ty: EdgeType>(
+    py: Python,
+    graph: &StablePyGraph<Ty>,
+    source: Option<usize>,
+    target: Option<usize>,
+    decay: f64,
+    max_iter: usize,
+    tol: f64,
+) -> PyResult<PyObject> {
+    check_arguments(graph, source, target, decay)?;
+    let scores = similarity::simrank_similarity(graph, decay, max_iter, tol);
+    similarity_output(py, graph, scores, source, target, max_iter)
+}
+
+fn weighted_simrank_similarity<Ty: EdgeType>(
+    py: Python,
+    graph: &StablePyGraph<Ty>,
+    source: Option<usize>,
+    target: Option<usize>,
+    weight_fn: Option<PyObject>,
+    default_weight: f64,
+    decay: f64,
+    max_iter: usize,
+    tol: f64,
+) -> PyResult<PyObject> {
+    check_arguments(graph, source, target, decay)?;
+    let cost_fn = CostFn::try_from((weight_fn, default_weight))?;
+    let scores = similarity::weighted_simrank_similarity(
+        graph,
+        |edge| cost_fn.call_edge(py, edge.id(), edge.weight()),
+        decay,
+        max_iter,
+        tol,
+    )?;
+    similarity_output(py, graph, scores, source, target, max_iter)
+}
+
+/// Compute the SimRank similarity of the nodes of a :class:`~.PyGraph`
+///
+/// SimRank is based on the idea that two nodes are similar if their
+/// neighbors are similar. Every node has a similarity of 1 to itself, and
+/// the similarity of two distinct nodes :math:`a` and :math:`b` is
+///
+/// .. math::
+///
+///     s(a, b) = \frac{C}{|N(a)||N(b)|} \sum_{i \in N(a)} \sum_{j \in N(b)} s(i, j)
+///
+/// where :math:`N(a)` are the neighbors of :math:`a` and :math:`C` is the
+/// decay factor. For details refer to:
+///
+/// Glen Jeh and Jennifer Widom. "SimRank: a measure of structural-context
+/// similarity." Proceedings of the 8th ACM SIGKDD (2002): 538-543.
+/// <https://doi.org/10.1145/775047.775126>
+///
+/// The similarities are computed iteratively until no similarity changes by
+/// more than ``tol``, with every iteration taking :math:`O(|V||E|)` time.
+/// The similarities of all pairs of nodes are computed even if only a
+/// single source or pair of nodes is requested, so :math:`O(|V|^2)` memory
+/// is used in every case.
+///
+/// :param PyGraph graph: The graph to compute the similarities of
+/// :param int source: An optional node index, if specified only the
+///     similarities to this node are returned
+/// :param int target: An optional node index, if specified together with
+///     ``source`` only the similarity of ``source`` and ``target`` is returned
+/// :param float decay: The decay factor :math:`C`, between 0 and 1
+/// :param int max_iter: The maximum number of iterations
+/// :param float tol: The error tolerance used to check for convergence
+///
+/// :returns: The similarity of ``source`` and ``target`` if both are
+///     specified, a dictionary mapping node indices to their similarity to
+///     ``source`` if only ``source`` is specified, and otherwise a dictionary
+///     of these dictionaries for every node in the graph
+/// :rtype: float | dict
+///
+/// :raises FailedToConverge: if the similarities don't converge in
+///     ``max_iter`` iterations
+#[pyfunction(
+    signature = (graph, source=None, target=None, decay=0.8, max_iter=100, tol=1e-4)
+)]
+#[pyo3(text_signature = "(graph, /, source=None, target=None, decay=0.8, max_iter=100, tol=1e-4)")]
+pub fn graph_simrank_similarity(
+    py: Python,
+    graph: &graph::PyGraph,
+    source: Option<usize>,
+    target: Option<usize>,
+    decay: f64,
+    max_iter: usize,
+    tol: f64,
+) -> PyResult<PyObject> {
+    simrank_similarity(py, &graph.graph, source, target, decay, max_iter, tol)
+}
+
+/// Compute the SimRank similarity of the nodes of a :class:`~.PyDiGraph`
+///
+/// SimRank is based on the idea that two nodes are similar if they are
+/// referenced by similar nodes. Every node has a similarity of 1 to itself,
+/// and the similarity of two distinct nodes :math:`a` and :math:`b` is
+///
+/// .. math::
+///
+///     s(a, b) = \frac{C}{|I(a)||I(b)|} \sum_{i \in I(a)} \sum_{j \in I(b)} s(i, j)
+///
+/// where :math:`I(a)` are the predecessors of :math:`a` and :math:`C` is the
+/// decay factor. For details refer to:
+///
+/// Glen Jeh and Jennifer Widom. "SimRank: a measure of structural-context
+/// similarity." Proceedings of the 8th ACM SIGKDD (2002): 538-543.
+/// <https://doi.org/10.1145/775047.775126>
+///
+/// The similarities are computed iteratively until no similarity changes by
+/// more than ``tol``, with every iteration taking :math:`O(|V||E|)` time.
+/// The similarities of all pairs of nodes are computed even if only a
+/// single source or pair of nodes is requested, so :math:`O(|V|^2)` memory
+/// is used in every case.
+///
+/// :param PyDiGraph graph: The graph to compute the similarities of
+/// :param int source: An optional node index, if specified only the
+///     similarities to this node are returned
+/// :param int target: An optional node index, if specified together with
+///     ``source`` only the similarity of ``source`` and ``target`` is returned
+/// :param float decay: The decay factor :math:`C`, between 0 and 1
+/// :param int max_iter: The maximum number of iterations
+/// :param float tol: The error tolerance used to check for convergence
+///
+/// :returns: The similarity of ``source`` and ``target`` if both are
+///     specified, a dictionary mapping node indices to their similarity to
+///     ``source`` if only ``source`` is specified, and otherwise a dictionary
+///     of these dictionaries for every node in the graph
+/// :rtype: float | dict
+///
+/// :raises FailedToConverge: if the similarities don't converge in
+///     ``max_iter`` iterations
+#[pyfunction(
+    signature = (graph, source=None, target=None, decay=0.8, max_iter=100, tol=1e-4)
+)]
+#[pyo3(text_signature = "(graph, /, source=None, target=None, decay=0.8, max_iter=100, tol=1e-4)")]
+pub fn digraph_simrank_similarity(
+    py: Python,
+    graph: &digraph::PyDiGraph,
+    source: Option<usize>,
+    target: Option<usize>,
+    decay: f64,
+    max_iter: usize,
+    tol: f64,
+) -> PyResult<PyObject> {
+    simrank_similarity(py, &graph.graph, source, target, decay, max_iter, tol)
+}
+
+/// Compute the weighted SimRank++ similarity of the nodes of a :class:`~.PyGraph`
+///
+/// SimRank++ extends SimRank to weighted graphs. The similarity of two
+/// distinct nodes :math:`a` and :math:`b` is
+///
+/// .. math::
+///
+///     s(a, b) = \textit{evidence}(a, b) \cdot C \sum_{i \in N(a)} \sum_{j \in N(b)}
+///         W(i, a) W(j, b) s(i, j)
+///
+/// where :math:`W(i, a)` is the weight of the edge between :math:`i` and
+/// :math:`a` normalized by the total weight of the edges of :math:`a` and
+/// scaled by :math:`e^{-\sigma^2_i}`, with :math:`\sigma^2_i` the variance of
+/// the weights of the edges of :math:`i`. The evidence factor
+/// :math:`1 - 2^{-n}`, where :math:`n` is the number of neighbors :math:`a`
+/// and :math:`b` have in common, makes pairs with more common neighbors more
+/// similar. The weights of parallel edges are summed. For details refer to:
+///
+/// Ioannis Antonellis, Hector Garcia-Molina and Chi-Chao Chang. "Simrank++:
+/// query rewriting through link analysis of the click graph." Proceedings of
+/// the VLDB Endowment 1.1 (2008): 408-421.
+/// <https://doi.org/10.14778/1453856.1453903>
+///
+/// As for the unweighted SimRank, the similarities of all pairs of nodes are
+/// computed even if only a single source or pair of nodes is requested, so
+/// :math:`O(|V|^2)` memory is used in every case.
+///
+/// :param PyGraph graph: The graph to compute the similarities of
+/// :param int source: An optional node index, if specified only the
+///     similarities to this node are returned
+/// :param int target: An optional node index, if specified together with
+///     ``source`` only the similarity of ``source`` and ``target`` is returned
+/// :param weight_fn: An optional callable that will be passed the edge's
+///     payload object and is expected to return a non-negative ``float``
+///     weight for that edge. If this is not specified ``default_weight`` will
+///     be used as the weight for every edge in ``graph``
+/// :param float default_weight: If ``weight_fn`` is not set the default weight
+///     value to use for the weight of all edges
+/// :param float decay: The decay factor :math:`C`, between 0 and 1
+/// :param int max_iter: The maximum number of iterations
+/// :param float tol: The error tolerance used to check for convergence
+///
+/// :returns: The similarity of ``source`` and ``target`` if both are
+///     specified, a dictionary mapping node indices to their similarity to
+///     ``source`` if only ``source`` is specified, and otherwise a dictionary
+///     of these dictionaries for every node in the graph
+/// :rtype: float | dict
+///
+/// :raises FailedToConverge: if the similarities don't converge in
+///     ``max_iter`` iterations
+#[pyfunction(
+    signature = (
+        graph,
+        source=None,
+        target=None,
+        weight_fn=None,
+        default_weight=1.0,
+        decay=0.8,
+        max_iter=100,
+        tol=1e-4
+    )
+)]
+#[pyo3(
+    text_signature = "(graph, /, source=None, target=None, weight_fn=None, default_weight=1.0, decay=0.8, max_iter=100, tol=1e-4)"
+)]
+pub fn graph_weighted_simrank_similarity(
+    py: Python,
+    graph: &graph::PyGraph,
+    source: Option<usize>,
+    target: Option<usize>,
+    weight_fn: Option<PyObject>,
+    default_weight: f64,
+    decay: f64,
+    max_iter: usize,
+    tol: f64,
+) -> PyResult<PyObject> {
+    weighted_simrank_similarity(
+        py,
+        &graph.graph,
+        source,
+        target,
+        weight_fn,
+        default_weight,
+        decay,
+        max_iter,
+        tol,
+    )
+}
+
+/// Compute the weighted SimRank++ similarity of the nodes of a :class:`~.PyDiGraph`
+///
+/// SimRank++ extends SimRank to weighted graphs. The similarity of two
+/// distinct nodes :math:`a` and :math:`b` is
+///
+/// .. math::
+///
+///     s(a, b) = \textit{evidence}(a, b) \cdot C \sum_{i \in I(a)} \sum_{j \in I(b)}
+///         W(i, a) W(j, b) s(i, j)
+///
+/// where :math:`W(i, a)` is the weight of the edge from :math:`i` to
+/// :math:`a` normalized by the total weight of the edges into :math:`a` and
+/// scaled by :math:`e^{-\sigma^2_i}`, with :math:`\sigma^2_i` the variance of
+/// the weights of the edges out of :math:`i`. The evidence factor
+/// :math:`1 - 2^{-n}`, where :math:`n` is the number of predecessors
+/// :math:`a` and :math:`b` have in common, makes pairs with more common
+/// predecessors more similar. The weights of parallel edges are summed. For
+/// details refer to:
+///
+/// Ioannis Antonellis, Hector Garcia-Molina and Chi-Chao Chang. "Simrank++:
+/// query rewriting through link analysis of the click graph." Proceedings of
+/// the VLDB Endowment 1.1 (2008): 408-421.
+/// <https://doi.org/10.14778/1453856.1453903>
+///
+/// As for the unweighted SimRank, the similarities of all pairs of nodes are
+/// computed even if only a single source or pair of nodes is requested, so
+/// :math:`O(|V|^2)` memory is used in every case.
+///
+/// :param PyDiGraph graph: The graph to compute the similarities of
+/// :param int source: An optional node index, if specified only the
+///     similarities to this node are returned
+/// :param int target: An optional node index, if specified together with
+///     ``source`` only the similarity of ``source`` and ``target`` is returned
+/// :param weight_fn: An optional callable that will be passed the edge's
+///     payload object and is expected to return a non-negative ``float``
+///     weight for that edge. If this is not specified ``default_weight`` will
+///     be used as the weight for every edge in ``graph``
+/// :param float default_weight: If ``weight_fn`` is not set the default weight
+///     value to use for the weight of all edges
+/// :param float decay: The decay factor :math:`C`, between 0 and 1
+/// :param int max_iter: The maximum number of iterations
+/// :param float tol: The error tolerance used to check for convergence
+///
+/// :returns: The similarity of ``source`` and ``target`` if both are
+///     specified, a dictionary mapping node indices to their similarity to
+///     ``source`` if only ``source`` is specified, and otherwise a dictionary
+///     of these dictionaries for every node in the graph
+/// :rtype: float | dict
+///
+/// :raises FailedToConverge: if the similarities don't converge in
+///     ``max_iter`` iterations
+#[pyfunction(
+    signature = (
+        graph,
+        source=None,
+        target=None,
+        weight_fn=None,
+        default_weight=1.0,
+        decay=0.8,
+        max_iter=100,
+        tol=1e-4
+    )
+)]
+#[pyo3(
+    text_signature = "(graph, /, source=None, target=None, weight_fn=None, default_weight=1.0, decay=0.8, max_iter=100, tol=1e-4)"
+)]
+pub fn digraph_weighted_simrank_similarity(
+    py: Python,
+    graph: &digraph::PyDiGraph,
+    source: Option<usize>,
+    target: Option<usize>,
+    weight_fn: Option<PyObject>,
+    default_weight: f64,
+    decay: f64,
+    max_iter: usize,
+    tol: f64,
+) -> PyResult<PyObject> {
+    weighted_simrank_similarity(
+        py,
+        &graph.graph,
+        source,
+        target,
+        weight_fn,
+        default_weight,
+        decay,
+        max_iter,
+        tol,
+    )
+}
+
+fn katz_similarity<Ty: EdgeType>(
+    py: Python,
+    graph: &StablePyGraph<Ty>,
+    source: Option<usize>,
+    target: Option<usize>,
+    alpha: f64,
+    weight_fn: Option<PyObject>,
+    default_weight: f64,
+    max_iter: usize,
+    tol: f64,
+) -> PyResult<PyObject> {
+    if alpha <= 0. {
+        return Err(PyValueError::new_err("alpha must be positive"));
+    }
+    check_nodes(graph, source, target)?;
+    let cost_fn = CostFn::try_from((weight_fn, default_weight))?;
+    match source {
+        Some(source) => {
+            let scores = similarity::katz_similarity_from(
+                graph,
+                NodeIndex::new(source),
+                |edge| cost_fn.call_edge(py, edge.id(), edge.weight()),
+                alpha,
+                max_iter,
+                tol,
+            )?;
+            let scores = match scores {
+                Some(scores) => scores,
+                None => {
+                    return Err(FailedToConverge::new_err(format!(
+                        "Function failed to converge on a solution in {} iterations",
+                        max_iter
+                    )))
+                }
+            };
+            Ok(match target {
+                Some(target) => scores[target].into_py(py),
+                None => graph
+                    .node_indices()
+                    .map(|node| (node.index(), scores[node.index()]))
+                    .collect::<DictMap<usize, f64>>()
+                    .into_py(py),
+            })
+        }
+        None => {
+            let scores = similarity::katz_similarity(
+                graph,
+                |edge| cost_fn.call_edge(py, edge.id(), edge.weight()),
+                alpha,
+            )?;
+            if scores.is_none() {
+                return Err(PyValueError::new_err(
+                    "alpha is too large for the Katz similarity to converge",
+                ));
+            }
+            similarity_output(py, graph, scores, None, None, max_iter)
+        }
+    }
+}
+
+/// Compute the Katz similarity of the nodes of a :class:`~.PyGraph`
+///
+/// The Katz similarity of two nodes counts the walks between them, with a
+/// walk of length :math:`k` weighted by :math:`\alpha^k`.
+/// The similarity matrix is
+///
+/// .. math::
+///
+///     S = \sum_{k=1}^\infty \alpha^k A^k = (I - \alpha A)^{-1} - I
+///
+/// where :math:`A` is the weighted adjacency matrix of the graph, with the
+/// weights of parallel edges summed. The series only converges if
+/// :math:`\alpha` is smaller than the reciprocal of the largest eigenvalue of
+/// :math:`A`. For details refer to:
+///
+/// Leo Katz. "A new status index derived from sociometric analysis."
+/// Psychometrika 18.1 (1953): 39-43. <https://doi.org/10.1007/BF02289026>
+///
+/// If ``source`` is specified only the row of ``source`` is computed, by
+/// summing the series until the sum of a term is less than ``tol``, where
+/// every term takes :math:`O(|E|)` time. Otherwise the whole matrix is
+/// computed by inverting :math:`I - \alpha A` as a dense matrix, which takes
+/// :math:`O(|V|^3)` time and is only suitable for small graphs.
+///
+/// :param PyGraph graph: The graph to compute the similarities of
+/// :param int source: An optional node index, if specified only the
+///     similarities to this node are returned
+/// :param int target: An optional node index, if specified together with
+///     ``source`` only the similarity of ``source`` and ``target`` is returned
+/// :param float alpha: The attenuation factor :math:`\alpha`, which must be
+///     positive
+/// :param weight_fn: An optional callable that will be passed the edge's
+///     payload object and is expected to return a non-negative ``float``
+///     weight for that edge. If this is not specified ``default_weight`` will
+///     be used as the weight for every edge in ``graph``
+/// :param float default_weight: If ``weight_fn`` is not set the default weight
+///     value to use for the weight of all edges
+/// :param int max_iter: The maximum number of terms of the series summed
+///     when ``source`` is specified
+/// :param float tol: The error tolerance used to check for convergence when
+///     ``source`` is specified
+///
+/// :returns: The similarity of ``source`` and ``target`` if both are
+///     specified, a dictionary mapping node indices to their similarity to
+///     ``source`` if only ``source`` is specified, and otherwise a dictionary
+///     of these dictionaries for every node in the graph
+/// :rtype: float | dict
+///
+/// :raises ValueError: if ``alpha`` is too large for the series to converge
+///     and ``source`` isn't specified
+/// :raises FailedToConverge: if the series doesn't converge in ``max_iter``
+///     terms when ``source`` is specified
+#[pyfunction(
+    signature = (
+        graph,
+        source=None,
+        target=None,
+        alpha=0.1,
+        weight_fn=None,
+        default_weight=1.0,
+        max_iter=1000,
+        tol=1e-8
+    )
+)]
+#[pyo3(
+    text_signature = "(graph, /, source=None, target=None, alpha=0.1, weight_fn=None, default_weight=1.0, max_iter=1000, tol=1e-8)"
+)]
+pub fn graph_katz_similarity(
+    py: Python,
+    graph: &graph::PyGraph,
+    source: Option<usize>,
+    target: Option<usize>,
+    alpha: f64,
+    weight_fn: Option<PyObject>,
+    default_weight: f64,
+    max_iter: usize,
+    tol: f64,
+) -> PyResult<PyObject> {
+    katz_similarity(
+        py,
+        &graph.graph,
+        source,
+        target,
+        alpha,
+        weight_fn,
+        default_weight,
+        max_iter,
+        tol,
+    )
+}
+
+/// Compute the Katz similarity of the nodes of a :class:`~.PyDiGraph`
+///
+/// The Katz similarity of a node :math:`a` to a node :math:`b` counts the
+/// walks from :math:`a` to :math:`b` along the directions of the edges, with
+/// a walk of length :math:`k` weighted by :math:`\alpha^k`.
+/// The similarity matrix is
+///
+/// .. math::
+///
+///     S = \sum_{k=1}^\infty \alpha^k A^k = (I - \alpha A)^{-1} - I
+///
+/// where :math:`A` is the weighted adjacency matrix of the graph, with the
+/// weights of parallel edges summed. The series only converges if
+/// :math:`\alpha` is smaller than the reciprocal of the largest eigenvalue of
+/// :math:`A`. For details refer to:
+///
+/// Leo Katz. "A new status index derived from sociometric analysis."
+/// Psychometrika 18.1 (1953): 39-43. <https://doi.org/10.1007/BF02289026>
+///
+/// If ``source`` is specified only the row of ``source`` is computed, by
+/// summing the series until the sum of a term is less than ``tol``, where
+/// every term takes :math:`O(|E|)` time. Otherwise the whole matrix is
+/// computed by inverting :math:`I - \alpha A` as a dense matrix, which takes
+/// :math:`O(|V|^3)` time and is only suitable for small graphs.
+///
+/// :param PyDiGraph graph: The graph to compute the similarities of
+/// :param int source: An optional node index, if specified only the
+///     similarities to this node are returned
+/// :param int target: An optional node index, if specified together with
+///     ``source`` only the similarity of ``source`` and ``target`` is returned
+/// :param float alpha: The attenuation factor :math:`\alpha`, which must be
+///     positive
+/// :param weight_fn: An optional callable that will be passed the edge's
+///     payload object and is expected to return a non-negative ``float``
+///     weight for that edge. If this is not specified ``default_weight`` will
+///     be used as the weight for every edge in ``graph``
+/// :param float default_weight: If ``weight_fn`` is not set the default weight
+///     value to use for the weight of all edges
+/// :param int max_iter: The maximum number of terms of the series summed
+///     when ``source`` is specified
+/// :param float tol: The error tolerance used to check for convergence when
+///     ``source`` is specified
+///
+/// :returns: The similarity of ``source`` and ``target`` if both are
+///     specified, a dictionary mapping node indices to their similarity to
+///     ``source`` if only ``source`` is specified, and otherwise a dictionary
+///     of these dictionaries for every node in the graph
+/// :rtype: float | dict
+///
+/// :raises ValueError: if ``alpha`` is too large for the series to converge
+///     and ``source`` isn't specified
+/// :raises FailedToConverge: if the series doesn't converge in ``max_iter``
+///     terms when ``source`` is specified
+#[pyfunction(
+    signature = (
+        graph,
+        source=None,
+        target=None,
+        alpha=0.1,
+        weight_fn=None,
+        default_weight=1.0,
+        max_iter=1000,
+        tol=1e-8
+    )
+)]
+#[pyo3(
+    text_signature = "(graph, /, source=None, target=None, alpha=0.1, weight_fn=None, default_weight=1.0, max_iter=1000, tol=1e-8)"
+)]
+pub fn digraph_katz_similarity(
+    py: Python,
+    graph: &digraph::PyDiGraph,
+    source: Option<usize>,
+    target: Option<usize>,
+    alpha: f64,
+    weight_fn: Option<PyObject>,
+    default_weight: f64,
+    max_iter: usize,
+    tol: f64,
+) -> PyResult<PyObject> {
+    katz_similarity(
+        py,
+        &graph.graph,
+        source,
+        target,
+        alpha,
+        weight_fn,
+        default_weight,
+        max_iter,
+        tol,
+    )
+}
+
+/// Return a node label callback that compresses the hashable Python labels
+/// returned by ``node_label`` to integers, or labels every node 0 if it isn't
+/// set.
+fn node_label_fn<'a>(
+    py: Python<'a>,
+    node_label: &'a Option<PyObject>,
+) -> impl FnMut((NodeIndex, &PyObject)) -> PyResult<usize> + 'a {
+    let labels = PyDict::new_bound(py);
+    move |node| {
+        let label = match node_label {
+            Some(node_label) => node_label.call1(py, (node.1,))?,
+            None => return Ok(0),
+        };
+        match labels.get_item(&label)? {
+            Some(index) => index.extract(),
+            None => {
+                let index = labels.len();
+                labels.set_item(label, index)?;
+                Ok(index)
+            }
+        }
+    }
+}
+
+/// Extract ``graphs`` if they are all instances of the same graph class.
+fn extract_graphs<'py, T: PyClass>(graphs: &'py [Bound<'py, PyAny>]) -> Option<Vec<PyRef<'py, T>>> {
+    graphs.iter().map(|graph| graph.extract().ok()).collect()
+}
+
+fn mixed_graphs_error() -> PyErr {
+    PyTypeError::new_err("graphs must either all be PyGraph objects or all be PyDiGraph objects")
+}
+
+/// Compute the Weisfeiler-Lehman subtree features of a list of graphs
+///
+/// Every node starts out labeled with the output of ``node_label``. In each
+/// of the ``iterations`` rounds the label of every node is replaced by a new
+/// label for the combination of its current label and the multiset of the
+/// current labels of its neighbors. The new labels are shared by all the
+/// graphs in ``graphs``, so the same subtree pattern has the same feature in
+/// every graph. For a :class:`~.PyDiGraph` only the successors of a node are
+/// used as its neighbors. For details refer to:
+///
+/// Nino Shervashidze, Pascal Schweitzer, Erik Jan van Leeuwen, Kurt
+/// Mehlhorn and Karsten M. Borgwardt. "Weisfeiler-Lehman graph kernels."
+/// Journal of Machine Learning Research 12.77 (2011): 2539-2561.
+/// <https://www.jmlr.org/papers/v12/shervashidze11a.html>
+///
+/// :param list graphs: The graphs to compute the features of, which must
+///     either all be :class:`~.PyGraph` objects or all be
+///     :class:`~.PyDiGraph` objects
+/// :param node_label: An optional callable that will be passed the data
+///     payload of each node and is expected to return a hashable label for
+///     that node. If not specified all nodes start with the same label.
+/// :param int iterations: The number of relabeling rounds. Defaults to 3.
+///
+/// :returns: A list with a dictionary for each graph in ``graphs`` that maps
+///     feature indices to the number of times that feature appears in that
+///     graph
+/// :rtype: list[dict[int, int]]
+///
+/// :raises TypeError: If the graphs aren't all the same type of graph
+///
+/// .. jupyter-execute::
+///
+///   import rustworkx as rx
+///
+///   path = rx.generators.path_graph(3)
+///   triangle = rx.generators.cycle_graph(3)
+///   print(rx.weisfeiler_lehman_subtree_features([path, triangle], iterations=1))
+#[pyfunction]
+#[pyo3(
+    signature = (graphs, /, node_label=None, iterations=3),
+    text_signature = "(graphs, /, node_label=None, iterations=3)"
+)]
+pub fn weisfeiler_lehman_subtree_features(
+    py: Python,
+    graphs: Vec<Bound<PyAny>>,
+    node_label: Option<PyObject>,
+    iterations: usize,
+) -> PyResult<Vec<HashMap<usize, usize>>> {
+    let label_fn = node_label_fn(py, &node_label);
+    if let Some(graphs) = extract_graphs::<graph::PyGraph>(&graphs) {
+        let graphs: Vec<_> = graphs.iter().map(|graph| &graph.graph).collect();
+        return similarity::weisfeiler_lehman_subtree_features(&graphs, label_fn, iterations);
+    }
+    if let Some(graphs) = extract_graphs::<digraph::PyDiGraph>(&graphs) {
+        let graphs: Vec<_> = graphs.iter().map(|graph| &graph.graph).collect();
+        return similarity::weisfeiler_lehman_subtree_features(&graphs, label_fn, iterations);
+    }
+    Err(mixed_graphs_error())
+}
+
+/// Compute the Weisfeiler-Lehman subtree kernel between every pair of graphs
+/// in a list
+///
+/// The kernel value of two graphs is the dot product of their feature vectors
+/// as computed by :func:`~rustworkx.weisfeiler_lehman_subtree_features`. If
+/// ``normalize`` is ``True`` the kernel values are divided by the square
+/// root of the product of the kernel values of each graph with itself, so
+/// every graph has a kernel value of 1 with itself.
+///
+/// The rows of the kernel matrix are computed in parallel. The env var
+/// ``RAYON_NUM_THREADS`` can be used to adjust how many threads will be
+/// used.
+///
+/// :param list graphs: The graphs to compute the kernel between, which must
+///     either all be :class:`~.PyGraph` objects or all be
+///     :class:`~.PyDiGraph` objects
+/// :param node_label: An optional callable that will be passed the data
+///     payload of each node and is expected to return a hashable label for
+///     that node. If not specified all nodes start with the same label.
+/// :param int iterations: The number of relabeling rounds. Defaults to 3.
+/// :param bool normalize: Whether to normalize the kernel values. Defaults
+///     to ``False``.
+///
+/// :returns: The symmetric kernel matrix with a row and a column for each
+///     graph in ``graphs``
+/// :rtype: numpy.ndarray
+///
+/// :raises TypeError: If the graphs aren't all the same type of graph
+///
+/// .. jupyter-execute::
+///
+///   import rustworkx as rx
+///
+///   path = rx.generators.path_graph(3)
+///   triangle = rx.generators.cycle_graph(3)
+///   print(rx.weisfeiler_lehman_subtree_kernel([path, triangle], iterations=1))
+#[pyfunction]
+#[pyo3(
+    signature = (graphs, /, node_label=None, iterations=3, normalize=false),
+    text_signature = "(graphs, /, node_label=None, iterations=3, normalize=False)"
+)]
+pub fn weisfeiler_lehman_subtree_kernel(
+    py: Python,
+    graphs: Vec<Bound<PyAny>>,
+    node_label: Option<PyObject>,
+    iterations: usize,
+    normalize: bool,
+) -> PyResult<PyObject> {
+    let label_fn = node_label_fn(py, &node_label);
+    let kernel = if let Some(graphs) = extract_graphs::<graph::PyGraph>(&graphs) {
+        let graphs: Vec<_> = graphs.iter().map(|graph| &graph.graph).collect();
+        similarity::weisfeiler_lehman_subtree_kernel(&graphs, label_fn, iterations, normalize)?
+    } else if let Some(graphs) = extract_graphs::<digraph::PyDiGraph>(&graphs) {
+        let graphs: Vec<_> = graphs.iter().map(|graph| &graph.graph).collect();
+        similarity::weisfeiler_lehman_subtree_kernel(&graphs, label_fn, iterations, normalize)?
+    } else {
+        return Err(mixed_graphs_error());
+    };
+    let size = kernel.len();
+    let kernel = Array2::from_shape_vec((size, size), kernel.concat()).unwrap();
+    Ok(kernel.into_pyarray_bound(py).into())
+}
@@ -0,0 +1,142 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+use crate::graph;
+
+use rustworkx_core::small_world;
+
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+
+fn small_world_error() -> PyErr {
+    PyValueError::new_err("The graph must be connected and have at least four nodes")
+}
+
+fn check_randomizations(num_randomizations: usize) -> PyResult<()> {
+    if num_randomizations == 0 {
+        return Err(PyValueError::new_err("num_randomizations must be positive"));
+    }
+    Ok(())
+}
+
+/// Compute the small-world coefficient sigma of a :class:`~.PyGraph`.
+///
+/// The small-world coefficient sigma is
+///
+/// .. math::
+///
+///     \sigma = \frac{C / C_r}{L / L_r}
+///
+/// where :math:`C` and :math:`L` are the average clustering coefficient and
+/// the average shortest path length of the graph, and :math:`C_r` and
+/// :math:`L_r` are their averages over random graphs with the same degrees.
+/// A graph is commonly considered small-world if sigma is greater than
+/// ``1.0``.
+///
+/// The random graphs are generated in parallel by repeatedly swapping the
+/// endpoints of pairs of edges (Maslov-Sneppen rewiring), keeping them
+/// simple and connected. The graph is treated as a simple graph: self-loops
+/// are ignored and parallel edges are counted once.
+///
+/// :param PyGraph graph: The graph to compute sigma for. It must be connected
+///     and have at least four nodes.
+/// :param int num_iterations: The number of edge swaps to attempt per edge
+///     when generating each random graph. Default: ``100``.
+/// :param int num_randomizations: The number of random graphs to average
+///     over. It must be positive. Default: ``10``.
+/// :param int seed: An optional seed to use for the random number generator
+///
+/// :returns: The small-world coefficient sigma
+/// :rtype: float
+/// :raises ValueError: If the graph isn't connected, has fewer than four
+///     nodes or ``num_randomizations`` is ``0``
+///
+/// .. jupyter-execute::
+///
+///   import rustworkx as rx
+///
+///   graph = rx.generators.complete_graph(6)
+///   print(rx.sigma(graph, seed=42))
+#[pyfunction]
+#[pyo3(
+    signature=(graph, /, num_iterations=100, num_randomizations=10, seed=None),
+    text_signature = "(graph, /, num_iterations=100, num_randomizations=10, seed=None)"
+)]
+pub fn sigma(
+    graph: &graph::PyGraph,
+    num_iterations: usize,
+    num_randomizations: usize,
+    seed: Option<u64>,
+) -> PyResult<f64> {
+    check_randomizations(num_randomizations)?;
+    small_world::sigma(&graph.graph, num_iterations, num_randomizations, seed)
+        .ok_or_else(small_world_error)
+}
+
+/// Compute the small-world coefficient omega of a :class:`~.PyGraph`.
+///
+/// The small-world coefficient omega is
+///
+/// .. math::
+///
+///     \omega = \frac{L_r}{L} - \frac{C}{C_l}
+///
+/// where :math:`C` and :math:`L` are the average clustering coefficient and
+/// the average shortest path length of the graph, :math:`L_r` is the
+/// average shortest path length of random graphs with the same degrees and
+/// :math:`C_l` is the largest clustering coefficient of the graph and of
+/// lattice graphs with the same degrees. Omega ranges from ``-1.0`` to
+/// ``1.0``: values close to ``0.0`` mean the graph is small-world, negative
+/// values mean it's more like a lattice and positive values mean it's more
+/// like a random graph.
+///
+/// The reference graphs are generated in parallel by repeatedly swapping
+/// the endpoints of pairs of edges (Maslov-Sneppen rewiring), keeping them
+/// simple and connected. For the lattice graphs, only the swaps that don't
+/// move the edges away from a ring lattice over the nodes in index order
+/// are done. The graph is treated as a simple graph: self-loops are
+/// ignored and parallel edges are counted once.
+///
+/// :param PyGraph graph: The graph to compute omega for. It must be connected
+///     and have at least four nodes.
+/// :param int num_iterations: The number of edge swaps to attempt per edge
+///     when generating each reference graph. Default: ``5``.
+/// :param int num_randomizations: The number of random and lattice graphs to
+///     generate. It must be positive. Default: ``10``.
+/// :param int seed: An optional seed to use for the random number generator
+///
+/// :returns: The small-world coefficient omega
+/// :rtype: float
+/// :raises ValueError: If the graph isn't connected, has fewer than four
+///     nodes or ``num_randomizations`` is ``0``
+///
+/// .. jupyter-execute::
+///
+///   import rustworkx as rx
+///
+///   graph = rx.generators.cycle_graph(20)
+///   print(rx.omega(graph, seed=42))
+#[pyfunction]
+#[pyo3(
+    signature=(graph, /, num_iterations=5, num_randomizations=10, seed=None),
+    text_signature = "(graph, /, num_iterations=5, num_randomizations=10, seed=None)"
+)]
+pub fn omega(
+    graph: &graph::PyGraph,
+    num_iterations: usize,
+    num_randomizations: usize,
+    seed: Option<u64>,
+) -> PyResult<f64> {
+    check_randomizations(num_randomizations)?;
+    small_world::omega(&graph.graph, num_iterations, num_randomizations, seed)
+        .ok_or_else(small_world_error)
+}
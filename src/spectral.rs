@@ -0,0 +1,704 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+use std::convert::TryFrom;
+
+use crate::{digraph, graph, weight_callable, CostFn, FailedToConverge};
+
+use hashbrown::HashMap;
+use numpy::IntoPyArray;
+use petgraph::algo::kosaraju_scc;
+use petgraph::graph::EdgeIndex;
+use petgraph::visit::EdgeRef;
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pyo3::IntoPy;
+use rustworkx_core::connectivity::number_connected_components;
+use rustworkx_core::spectral::{self, CsrMatrix, WalkType};
+
+/// Return a dense matrix as a numpy array, or a sparse matrix as a tuple of
+/// the ``(data, indices, indptr)`` numpy arrays.
+fn matrix_output(py: Python, matrix: CsrMatrix, sparse: bool) -> PyObject {
+    if sparse {
+        (
+            matrix.data.into_pyarray_bound(py),
+            matrix.indices.into_pyarray_bound(py),
+            matrix.indptr.into_pyarray_bound(py),
+        )
+            .into_py(py)
+    } else {
+        matrix.to_dense().into_pyarray_bound(py).into()
+    }
+}
+
+/// Return the Laplacian matrix of a :class:`~.PyGraph`
+///
+/// The Laplacian matrix is :math:`L = D - A`, where :math:`A` is the weighted
+/// adjacency matrix of the graph and :math:`D` is the diagonal matrix of the
+/// weighted degrees of the nodes. The weights of parallel edges are summed
+/// and self-loops have no effect on :math:`L`.
+///
+/// :param PyGraph graph: The graph to compute the Laplacian of
+/// :param weight_fn: An optional callable that will be passed the edge's
+///     payload object and is expected to return a ``float`` weight for that
+///     edge. If this is not specified ``default_weight`` will be used as the
+///     weight for every edge in ``graph``
+/// :param float default_weight: If ``weight_fn`` is not set the default
+///     weight value to use for the weight of all edges
+/// :param bool sparse: If ``True`` return the matrix in compressed sparse row
+///     format as a tuple of the numpy arrays ``(data, indices, indptr)``,
+///     which can be passed to ``scipy.sparse.csr_array``. Every diagonal
+///     entry is stored, even if it is zero. Defaults to ``False``.
+///
+/// :returns: The Laplacian matrix, with a row and a column for each node in
+///     the order of :meth:`~.PyGraph.node_indices`
+/// :rtype: numpy.ndarray or tuple
+///
+/// .. jupyter-execute::
+///
+///   import rustworkx as rx
+///
+///   graph = rx.generators.path_graph(3)
+///   print(rx.graph_laplacian_matrix(graph))
+#[pyfunction]
+#[pyo3(
+    signature = (graph, /, weight_fn=None, default_weight=1.0, sparse=false),
+    text_signature = "(graph, /, weight_fn=None, default_weight=1.0, sparse=False)"
+)]
+pub fn graph_laplacian_matrix(
+    py: Python,
+    graph: &graph::PyGraph,
+    weight_fn: Option<PyObject>,
+    default_weight: f64,
+    sparse: bool,
+) -> PyResult<PyObject> {
+    let matrix = spectral::laplacian_matrix(&graph.graph, |edge| {
+        weight_callable(py, &weight_fn, edge.weight(), default_weight)
+    })?;
+    Ok(matrix_output(py, matrix, sparse))
+}
+
+/// Return the Laplacian matrix of a :class:`~.PyDiGraph`
+///
+/// The Laplacian matrix is :math:`L = D - A`, where :math:`A` is the weighted
+/// adjacency matrix of the graph and :math:`D` is the diagonal matrix of the
+/// weighted out-degrees of the nodes. The weights of parallel edges are
+/// summed and self-loops have no effect on :math:`L`.
+///
+/// :param PyDiGraph graph: The graph to compute the Laplacian of
+/// :param weight_fn: An optional callable that will be passed the edge's
+///     payload object and is expected to return a ``float`` weight for that
+///     edge. If this is not specified ``default_weight`` will be used as the
+///     weight for every edge in ``graph``
+/// :param float default_weight: If ``weight_fn`` is not set the default
+///     weight value to use for the weight of all edges
+/// :param bool sparse: If ``True`` return the matrix in compressed sparse row
+///     format as a tuple of the numpy arrays ``(data, indices, indptr)``,
+///     which can be passed to ``scipy.sparse.csr_array``. Every diagonal
+///     entry is stored, even if it is zero. Defaults to ``False``.
+///
+/// :returns: The Laplacian matrix, with a row and a column for each node in
+///     the order of :meth:`~.PyDiGraph.node_indices`
+/// :rtype: numpy.ndarray or tuple
+///
+/// .. jupyter-execute::
+///
+///   import rustworkx as rx
+///
+///   graph = rx.generators.directed_path_graph(3)
+///   print(rx.digraph_laplacian_matrix(graph))
+#[pyfunction]
+#[pyo3(
+    signature = (graph, /, weight_fn=None, default_weight=1.0, sparse=false),
+    text_signature = "(graph, /, weight_fn=None, default_weight=1.0, sparse=False)"
+)]
+pub fn digraph_laplacian_matrix(
+    py: Python,
+    graph: &digraph::PyDiGraph,
+    weight_fn: Option<PyObject>,
+    default_weight: f64,
+    sparse: bool,
+) -> PyResult<PyObject> {
+    let matrix = spectral::laplacian_matrix(&graph.graph, |edge| {
+        weight_callable(py, &weight_fn, edge.weight(), default_weight)
+    })?;
+    Ok(matrix_output(py, matrix, sparse))
+}
+
+/// Return the normalized Laplacian matrix of a :class:`~.PyGraph`
+///
+/// The normalized Laplacian matrix is :math:`D^{-1/2} (D - A) D^{-1/2}`,
+/// where :math:`A` is the weighted adjacency matrix of the graph and
+/// :math:`D` is the diagonal matrix of the weighted degrees of the nodes. The
+/// rows and columns of nodes with a degree of 0 are all zero. The weights of
+/// parallel edges are summed.
+///
+/// :param PyGraph graph: The graph to compute the normalized Laplacian of
+/// :param weight_fn: An optional callable that will be passed the edge's
+///     payload object and is expected to return a ``float`` weight for that
+///     edge. If this is not specified ``default_weight`` will be used as the
+///     weight for every edge in ``graph``
+/// :param float default_weight: If ``weight_fn`` is not set the default
+///     weight value to use for the weight of all edges
+/// :param bool sparse: If ``True`` return the matrix in compressed sparse row
+///     format as a tuple of the numpy arrays ``(data, indices, indptr)``,
+///     which can be passed to ``scipy.sparse.csr_array``. Every diagonal
+///     entry is stored, even if it is zero. Defaults to ``False``.
+///
+/// :returns: The normalized Laplacian matrix, with a row and a column for
+///     each node in the order of :meth:`~.PyGraph.node_indices`
+/// :rtype: numpy.ndarray or tuple
+///
+/// .. jupyter-execute::
+///
+///   import rustworkx as rx
+///
+///   graph = rx.generators.path_graph(3)
+///   print(rx.graph_normalized_laplacian_matrix(graph))
+#[pyfunction]
+#[pyo3(
+    signature = (graph, /, weight_fn=None, default_weight=1.0, sparse=false),
+    text_signature = "(graph, /, weight_fn=None, default_weight=1.0, sparse=False)"
+)]
+pub fn graph_normalized_laplacian_matrix(
+    py: Python,
+    graph: &graph::PyGraph,
+    weight_fn: Option<PyObject>,
+    default_weight: f64,
+    sparse: bool,
+) -> PyResult<PyObject> {
+    let matrix = spectral::normalized_laplacian_matrix(&graph.graph, |edge| {
+        weight_callable(py, &weight_fn, edge.weight(), default_weight)
+    })?;
+    Ok(matrix_output(py, matrix, sparse))
+}
+
+/// Return the normalized Laplacian matrix of a :class:`~.PyDiGraph`
+///
+/// The normalized Laplacian matrix is :math:`D^{-1/2} (D - A) D^{-1/2}`,
+/// where :math:`A` is the weighted adjacency matrix of the graph and
+/// :math:`D` is the diagonal matrix of the weighted out-degrees of the nodes.
+/// The rows and columns of nodes with an out-degree of 0 are all zero. The
+/// weights of parallel edges are summed. See
+/// :func:`~rustworkx.directed_laplacian_matrix` for a normalized Laplacian
+/// that is symmetric for directed graphs.
+///
+/// :param PyDiGraph graph: The graph to compute the normalized Laplacian of
+/// :param weight_fn: An optional callable that will be passed the edge's
+///     payload object and is expected to return a ``float`` weight for that
+///     edge. If this is not specified ``default_weight`` will be used as the
+///     weight for every edge in ``graph``
+/// :param float default_weight: If ``weight_fn`` is not set the default
+///     weight value to use for the weight of all edges
+/// :param bool sparse: If ``True`` return the matrix in compressed sparse row
+///     format as a tuple of the numpy arrays ``(data, indices, indptr)``,
+///     which can be passed to ``scipy.sparse.csr_array``. Every diagonal
+///     entry is stored, even if it is zero. Defaults to ``False``.
+///
+/// :returns: The normalized Laplacian matrix, with a row and a column for
+///     each node in the order of :meth:`~.PyDiGraph.node_indices`
+/// :rtype: numpy.ndarray or tuple
+///
+/// .. jupyter-execute::
+///
+///   import rustworkx as rx
+///
+///   graph = rx.generators.directed_cycle_graph(3)
+///   print(rx.digraph_normalized_laplacian_matrix(graph))
+#[pyfunction]
+#[pyo3(
+    signature = (graph, /, weight_fn=None, default_weight=1.0, sparse=false),
+    text_signature = "(graph, /, weight_fn=None, default_weight=1.0, sparse=False)"
+)]
+pub fn digraph_normalized_laplacian_matrix(
+    py: Python,
+    graph: &digraph::PyDiGraph,
+    weight_fn: Option<PyObject>,
+    default_weight: f64,
+    sparse: bool,
+) -> PyResult<PyObject> {
+    let matrix = spectral::normalized_laplacian_matrix(&graph.graph, |edge| {
+        weight_callable(py, &weight_fn, edge.weight(), default_weight)
+    })?;
+    Ok(matrix_output(py, matrix, sparse))
+}
+
+/// Return the directed Laplacian matrix of a :class:`~.PyDiGraph`
+///
+/// The directed Laplacian matrix is
+///
+/// .. math::
+///
+///     L = I - \frac{\Phi^{1/2} P \Phi^{-1/2} + \Phi^{-1/2} P^T \Phi^{1/2}}{2}
+///
+/// where :math:`P` is the transition matrix of a random walk on the graph
+/// and :math:`\Phi` is the diagonal matrix of the stationary distribution of
+/// the walk. The transition probabilities are proportional to the edge
+/// weights, with the weights of parallel edges summed. For details refer to:
+///
+/// Fan Chung. "Laplacians and the Cheeger inequality for directed graphs."
+/// Annals of Combinatorics 9.1 (2005): 1-19.
+/// <https://doi.org/10.1007/s00026-005-0237-z>
+///
+/// The stationary distribution is computed with power iteration, which stops
+/// when the L1 norm of the difference of the distributions of two consecutive
+/// iterations is less than ``tol``.
+///
+/// :param PyDiGraph graph: The graph to compute the directed Laplacian of
+/// :param weight_fn: An optional callable that will be passed the edge's
+///     payload object and is expected to return a non-negative ``float``
+///     weight for that edge. If this is not specified ``default_weight`` will
+///     be used as the weight for every edge in ``graph``
+/// :param float default_weight: If ``weight_fn`` is not set the default
+///     weight value to use for the weight of all edges
+/// :param str walk_type: The random walk to use, either ``"random"``,
+///     ``"lazy"`` (which stays at the current node with probability 1/2) or
+///     ``"pagerank"`` (which jumps to a random node with probability
+///     ``1 - alpha``). The ``"random"`` and ``"lazy"`` walks require the graph
+///     to be strongly connected. If not specified this is ``"random"`` for
+///     strongly connected aperiodic graphs, ``"lazy"`` for other strongly
+///     connected graphs and ``"pagerank"`` otherwise.
+/// :param float alpha: The probability of following an edge in the
+///     ``"pagerank"`` walk. Defaults to 0.95.
+/// :param int max_iter: The maximum number of iterations in the power method.
+///     Defaults to 1000.
+/// :param float tol: The error tolerance used when checking for convergence.
+///     Defaults to 1e-10.
+///
+/// :returns: The directed Laplacian matrix, with a row and a column for each
+///     node in the order of :meth:`~.PyDiGraph.node_indices`
+/// :rtype: numpy.ndarray
+///
+/// :raises ValueError: If ``walk_type`` or ``alpha`` is invalid, or the walk
+///     requires a strongly connected graph and ``graph`` isn't
+/// :raises FailedToConverge: If the power iteration doesn't converge within
+///     ``max_iter`` iterations
+///
+/// .. jupyter-execute::
+///
+///   import rustworkx as rx
+///
+///   graph = rx.generators.directed_cycle_graph(3)
+///   print(rx.directed_laplacian_matrix(graph))
+#[allow(clippy::too_many_arguments)]
+#[pyfunction]
+#[pyo3(
+    signature = (graph, /, weight_fn=None, default_weight=1.0, walk_type=None, alpha=0.95, max_iter=1000, tol=1e-10),
+    text_signature = "(graph, /, weight_fn=None, default_weight=1.0, walk_type=None, alpha=0.95, max_iter=1000, tol=1e-10)"
+)]
+pub fn directed_laplacian_matrix(
+    py: Python,
+    graph: &digraph::PyDiGraph,
+    weight_fn: Option<PyObject>,
+    default_weight: f64,
+    walk_type: Option<&str>,
+    alpha: f64,
+    max_iter: usize,
+    tol: f64,
+) -> PyResult<PyObject> {
+    if !(0. ..=1.).contains(&alpha) {
+        return Err(PyValueError::new_err("alpha must be between 0 and 1"));
+    }
+    let walk_type = match walk_type {
+        None => None,
+        Some("random") => Some(WalkType::Random),
+        Some("lazy") => Some(WalkType::Lazy),
+        Some("pagerank") => Some(WalkType::PageRank(alpha)),
+        Some(_) => {
+            return Err(PyValueError::new_err(
+                "walk_type must be one of \"random\", \"lazy\" or \"pagerank\"",
+            ))
+        }
+    };
+    let walk_type = match walk_type {
+        // The default only differs from the core default in using alpha
+        None if kosaraju_scc(&graph.graph).len() > 1 => Some(WalkType::PageRank(alpha)),
+        Some(WalkType::Random | WalkType::Lazy) if kosaraju_scc(&graph.graph).len() > 1 => {
+            return Err(PyValueError::new_err(
+                "The random and lazy walks require a strongly connected graph",
+            ))
+        }
+        walk_type => walk_type,
+    };
+    let cost_fn = CostFn::try_from((weight_fn, default_weight))?;
+    let matrix = spectral::directed_laplacian_matrix(
+        &graph.graph,
+        |edge| cost_fn.call_edge(py, edge.id(), edge.weight()),
+        walk_type,
+        max_iter,
+        tol,
+    )?;
+    match matrix {
+        Some(matrix) => Ok(matrix.into_pyarray_bound(py).into()),
+        None => Err(FailedToConverge::new_err(format!(
+            "Function failed to converge on a solution in {} iterations",
+            max_iter
+        ))),
+    }
+}
+
+fn check_fiedler_graph(graph: &graph::PyGraph) -> PyResult<()> {
+    if graph.graph.node_count() < 2 {
+        return Err(PyValueError::new_err(
+            "The graph must have at least two nodes",
+        ));
+    }
+    Ok(())
+}
+
+/// Compute the algebraic connectivity of a :class:`~.PyGraph`
+///
+/// The algebraic connectivity is the second smallest eigenvalue of the
+/// Laplacian matrix of the graph. It is greater than 0 if and only if the
+/// graph is connected, and larger values indicate a graph that is harder to
+/// cut into pieces. For details refer to:
+///
+/// Miroslav Fiedler. "Algebraic connectivity of graphs." Czechoslovak
+/// Mathematical Journal 23.2 (1973): 298-305.
+///
+/// The eigenvalue is computed with the thick restart Lanczos method, which
+/// only needs products of the sparse Laplacian with vectors. The weights of
+/// parallel edges are summed.
+///
+/// :param PyGraph graph: The graph to use
+/// :param weight_fn: An optional callable that will be passed the edge's
+///     payload object and is expected to return a non-negative ``float``
+///     weight for that edge. If this is not specified ``default_weight`` will
+///     be used as the weight for every edge in ``graph``
+/// :param float default_weight: If ``weight_fn`` is not set the default
+///     weight value to use for the weight of all edges
+/// :param bool normalized: Whether to use the normalized Laplacian matrix
+///     instead of the Laplacian matrix. Defaults to ``False``.
+/// :param int max_iter: The maximum number of restarts of the Lanczos method.
+///     Defaults to 100.
+/// :param float tol: The error tolerance used when checking for convergence,
+///     relative to the largest absolute row sum of the Laplacian. Defaults to
+///     1e-8.
+/// :param int seed: An optional seed for the random start vector of the
+///     Lanczos method
+///
+/// :returns: The algebraic connectivity, which is 0 for a disconnected graph
+/// :rtype: float
+///
+/// :raises ValueError: If ``graph`` has fewer than two nodes
+/// :raises FailedToConverge: If the Lanczos method doesn't converge within
+///     ``max_iter`` restarts
+///
+/// .. jupyter-execute::
+///
+///   import rustworkx as rx
+///
+///   graph = rx.generators.cycle_graph(4)
+///   print(rx.algebraic_connectivity(graph, seed=42))
+#[pyfunction]
+#[pyo3(
+    signature = (graph, /, weight_fn=None, default_weight=1.0, normalized=false, max_iter=100, tol=1e-8, seed=None),
+    text_signature = "(graph, /, weight_fn=None, default_weight=1.0, normalized=False, max_iter=100, tol=1e-8, seed=None)"
+)]
+#[allow(clippy::too_many_arguments)]
+pub fn algebraic_connectivity(
+    py: Python,
+    graph: &graph::PyGraph,
+    weight_fn: Option<PyObject>,
+    default_weight: f64,
+    normalized: bool,
+    max_iter: usize,
+    tol: f64,
+    seed: Option<u64>,
+) -> PyResult<f64> {
+    check_fiedler_graph(graph)?;
+    let cost_fn = CostFn::try_from((weight_fn, default_weight))?;
+    let value = spectral::algebraic_connectivity(
+        &graph.graph,
+        |edge| cost_fn.call_edge(py, edge.id(), edge.weight()),
+        normalized,
+        max_iter,
+        tol,
+        seed,
+    )?;
+    value.ok_or_else(|| {
+        FailedToConverge::new_err(format!(
+            "Function failed to converge on a solution in {} iterations",
+            max_iter
+        ))
+    })
+}
+
+/// Compute the Fiedler vector of a :class:`~.PyGraph`
+///
+/// The Fiedler vector is a unit eigenvector for the second smallest
+/// eigenvalue of the Laplacian matrix of the graph, which is the algebraic
+/// connectivity (see :func:`~rustworkx.algebraic_connectivity`). Splitting
+/// the nodes by the sign of their entry in the Fiedler vector gives a
+/// spectral bisection of the graph. The Fiedler vector is only unique up to
+/// sign, and not at all if the eigenvalue has multiplicity greater than one.
+///
+/// The eigenvector is computed with the thick restart Lanczos method, which
+/// only needs products of the sparse Laplacian with vectors. The weights of
+/// parallel edges are summed.
+///
+/// :param PyGraph graph: The graph to use
+/// :param weight_fn: An optional callable that will be passed the edge's
+///     payload object and is expected to return a non-negative ``float``
+///     weight for that edge. If this is not specified ``default_weight`` will
+///     be used as the weight for every edge in ``graph``
+/// :param float default_weight: If ``weight_fn`` is not set the default
+///     weight value to use for the weight of all edges
+/// :param bool normalized: Whether to use the normalized Laplacian matrix
+///     instead of the Laplacian matrix. Defaults to ``False``.
+/// :param int max_iter: The maximum number of restarts of the Lanczos method.
+///     Defaults to 100.
+/// :param float tol: The error tolerance used when checking for convergence,
+///     relative to the largest absolute row sum of the Laplacian. Defaults to
+///     1e-8.
+/// :param int seed: An optional seed for the random start vector of the
+///     Lanczos method
+///
+/// :returns: The Fiedler vector, with an entry for each node in the order of
+///     :meth:`~.PyGraph.node_indices`
+/// :rtype: numpy.ndarray
+///
+/// :raises ValueError: If ``graph`` has fewer than two nodes or is
+///     disconnected
+/// :raises FailedToConverge: If the Lanczos method doesn't converge within
+///     ``max_iter`` restarts
+///
+/// .. jupyter-execute::
+///
+///   import rustworkx as rx
+///
+///   graph = rx.generators.barbell_graph(3, 0)
+///   print(rx.fiedler_vector(graph, seed=42))
+#[pyfunction]
+#[pyo3(
+    signature = (graph, /, weight_fn=None, default_weight=1.0, normalized=false, max_iter=100, tol=1e-8, seed=None),
+    text_signature = "(graph, /, weight_fn=None, default_weight=1.0, normalized=False, max_iter=100, tol=1e-8, seed=None)"
+)]
+#[allow(clippy::too_many_arguments)]
+pub fn fiedler_vector(
+    py: Python,
+    graph: &graph::PyGraph,
+    weight_fn: Option<PyObject>,
+    default_weight: f64,
+    normalized: bool,
+    max_iter: usize,
+    tol: f64,
+    seed: Option<u64>,
+) -> PyResult<PyObject> {
+    check_fiedler_graph(graph)?;
+    if number_connected_components(&graph.graph) > 1 {
+        return Err(PyValueError::new_err(
+            "The Fiedler vector of a disconnected graph is not defined",
+        ));
+    }
+    let cost_fn = CostFn::try_from((weight_fn, default_weight))?;
+    let vector = spectral::fiedler_vector(
+        &graph.graph,
+        |edge| cost_fn.call_edge(py, edge.id(), edge.weight()),
+        normalized,
+        max_iter,
+        tol,
+        seed,
+    )?;
+    match vector {
+        Some(vector) => Ok(vector.into_pyarray_bound(py).into()),
+        None => Err(FailedToConverge::new_err(format!(
+            "Function failed to converge on a solution in {} iterations",
+            max_iter
+        ))),
+    }
+}
+
+/// Partition the nodes of a :class:`~.PyGraph` into balanced parts by
+/// recursive spectral bisection
+///
+/// The nodes are split in two by their entries in the Fiedler vector (see
+/// :func:`~rustworkx.fiedler_vector`), with the split point chosen so that the
+/// sizes of the two halves are proportional to the number of parts each of
+/// them is split into next, and then each half is split again recursively
+/// until there are ``num_parts`` parts. If ``refine`` is ``True`` every
+/// bisection is improved with the Kernighan-Lin heuristic, which swaps pairs
+/// of nodes between the two halves while that reduces the weight of the
+/// edges between them. For details refer to:
+///
+/// Alex Pothen, Horst D. Simon and Kang-Pu Liou. "Partitioning sparse
+/// matrices with eigenvectors of graphs." SIAM Journal on Matrix Analysis and
+/// Applications 11.3 (1990): 430-452.
+///
+/// This is a lighter-weight alternative to multilevel partitioners like METIS
+/// for load balancing, although those usually find smaller edge cuts on
+/// large graphs. The weights of parallel edges are summed.
+///
+/// :param PyGraph graph: The graph to partition
+/// :param int num_parts: The number of parts. If it's larger than the number
+///     of nodes some parts are empty.
+/// :param weight_fn: An optional callable that will be passed the edge's
+///     payload object and is expected to return a non-negative ``float``
+///     weight for that edge. If this is not specified ``default_weight`` will
+///     be used as the weight for every edge in ``graph``
+/// :param float default_weight: If ``weight_fn`` is not set the default
+///     weight value to use for the weight of all edges
+/// :param bool refine: Whether to refine the bisections with the
+///     Kernighan-Lin heuristic. Defaults to ``True``.
+/// :param int max_iter: The maximum number of restarts of the Lanczos method
+///     used to compute every Fiedler vector. Defaults to 100.
+/// :param float tol: The error tolerance used when checking for convergence
+///     of the Lanczos method. Defaults to 1e-8.
+/// :param int seed: An optional seed for the random start vectors of the
+///     Lanczos method
+///
+/// :returns: A tuple of the list of parts, each of which is a sorted list of
+///     node indices, and the total weight of the edges between different
+///     parts
+/// :rtype: tuple
+///
+/// :raises ValueError: If ``num_parts`` is 0
+/// :raises FailedToConverge: If the Lanczos method doesn't converge within
+///     ``max_iter`` restarts for a Fiedler vector
+///
+/// .. jupyter-execute::
+///
+///   import rustworkx as rx
+///
+///   graph = rx.generators.grid_graph(4, 4)
+///   parts, edge_cut = rx.spectral_partition(graph, 4, seed=42)
+///   print(parts)
+///   print(edge_cut)
+#[pyfunction]
+#[pyo3(
+    signature = (graph, num_parts, /, weight_fn=None, default_weight=1.0, refine=true, max_iter=100, tol=1e-8, seed=None),
+    text_signature = "(graph, num_parts, /, weight_fn=None, default_weight=1.0, refine=True, max_iter=100, tol=1e-8, seed=None)"
+)]
+#[allow(clippy::too_many_arguments)]
+pub fn spectral_partition(
+    py: Python,
+    graph: &graph::PyGraph,
+    num_parts: usize,
+    weight_fn: Option<PyObject>,
+    default_weight: f64,
+    refine: bool,
+    max_iter: usize,
+    tol: f64,
+    seed: Option<u64>,
+) -> PyResult<(Vec<Vec<usize>>, f64)> {
+    if num_parts == 0 {
+        return Err(PyValueError::new_err("num_parts must be at least 1"));
+    }
+    let cost_fn = CostFn::try_from((weight_fn, default_weight))?;
+    let partition = spectral::spectral_partition(
+        &graph.graph,
+        num_parts,
+        |edge| cost_fn.call_edge(py, edge.id(), edge.weight()),
+        refine,
+        max_iter,
+        tol,
+        seed,
+    )?;
+    match partition {
+        Some((parts, edge_cut)) => {
+            let parts = parts
+                .into_iter()
+                .map(|part| {
+                    let mut part: Vec<usize> = part.into_iter().map(|node| node.index()).collect();
+                    part.sort_unstable();
+                    part
+                })
+                .collect();
+            Ok((parts, edge_cut))
+        }
+        None => Err(FailedToConverge::new_err(format!(
+            "Function failed to converge on a solution in {} iterations",
+            max_iter
+        ))),
+    }
+}
+
+/// Sparsify a :class:`~.PyGraph` by sampling its edges by their effective
+/// resistance
+///
+/// This is the spectral sparsification of Spielman and Srivastava: every edge
+/// of weight :math:`w` is kept with a probability :math:`p` proportional to
+/// :math:`w R`, where :math:`R` is the effective resistance between the
+/// endpoints of the edge when the graph is seen as an electrical network
+/// with conductances given by the weights, and a kept edge gets the weight
+/// :math:`w / p`. The probabilities are capped at 1 and scaled so that the
+/// expected number of kept edges is ``target_edges``. The Laplacian of the
+/// sparsified graph then equals the Laplacian of the graph in expectation,
+/// so its cuts and spectrum approximate those of the graph, and the
+/// approximation gets better the larger ``target_edges`` is. For details
+/// refer to:
+///
+/// Daniel A. Spielman and Nikhil Srivastava. "Graph sparsification by
+/// effective resistances." SIAM Journal on Computing 40.6 (2011): 1913-1926.
+///
+/// The effective resistances are computed by solving linear systems in the
+/// Laplacian with the conjugate gradient method. For graphs with more than
+/// :math:`24 \ln n` edges they are approximated with that many solves using
+/// random projections, otherwise they are exact. Self-loops and edges with a
+/// weight of 0 are never kept.
+///
+/// :param PyGraph graph: The graph to sparsify
+/// :param int target_edges: The expected number of edges to keep. If it's at
+///     least the number of edges that can be kept, all of them are kept with
+///     their weights.
+/// :param weight_fn: An optional callable that will be passed the edge's
+///     payload object and is expected to return a non-negative ``float``
+///     weight for that edge. If this is not specified ``default_weight`` will
+///     be used as the weight for every edge in ``graph``
+/// :param float default_weight: If ``weight_fn`` is not set the default
+///     weight value to use for the weight of all edges
+/// :param int seed: An optional seed for the random number generator
+///
+/// :returns: A new graph with the same nodes, node indices and node data as
+///     ``graph`` and the kept edges, whose data payloads are their new
+///     ``float`` weights
+/// :rtype: PyGraph
+///
+/// .. jupyter-execute::
+///
+///   import rustworkx as rx
+///
+///   graph = rx.generators.complete_graph(30)
+///   sparse = rx.spectral_sparsify(graph, 150, seed=42)
+///   print(graph.num_edges(), sparse.num_edges())
+///   print(sum(sparse.edges()))
+#[pyfunction]
+#[pyo3(
+    signature = (graph, target_edges, /, weight_fn=None, default_weight=1.0, seed=None),
+    text_signature = "(graph, target_edges, /, weight_fn=None, default_weight=1.0, seed=None)"
+)]
+pub fn spectral_sparsify(
+    py: Python,
+    graph: &graph::PyGraph,
+    target_edges: usize,
+    weight_fn: Option<PyObject>,
+    default_weight: f64,
+    seed: Option<u64>,
+) -> PyResult<graph::PyGraph> {
+    let cost_fn = CostFn::try_from((weight_fn, default_weight))?;
+    let kept: HashMap<EdgeIndex, f64> = spectral::spectral_sparsify(
+        &graph.graph,
+        target_edges,
+        |edge| cost_fn.call_edge(py, edge.id(), edge.weight()),
+        seed,
+    )?
+    .into_iter()
+    .collect();
+    let mut sparse = graph.clone();
+    sparse
+        .graph
+        .retain_edges(|_, edge| kept.contains_key(&edge));
+    for (edge, weight) in kept {
+        sparse.graph[edge] = weight.into_py(py);
+    }
+    Ok(sparse)
+}
@@ -0,0 +1,128 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+use crate::{digraph, graph, StablePyGraph};
+
+use ndarray::prelude::*;
+use numpy::IntoPyArray;
+use petgraph::EdgeType;
+use pyo3::prelude::*;
+use rustworkx_core::structural_roles;
+
+fn recursive_structural_features<Ty: EdgeType + Sync>(
+    py: Python,
+    graph: &StablePyGraph<Ty>,
+    rounds: usize,
+) -> PyObject {
+    let features = structural_roles::recursive_structural_features(graph, rounds);
+    let base_count = if graph.is_directed() { 5 } else { 3 };
+    let feature_count = base_count * ((1 << (rounds + 1)) - 1);
+    let mut matrix = Array2::<f64>::zeros((graph.node_count(), feature_count));
+    for (mut row, node) in matrix.rows_mut().into_iter().zip(graph.node_indices()) {
+        for (value, feature) in row.iter_mut().zip(&features[node.index()]) {
+            *value = *feature;
+        }
+    }
+    matrix.into_pyarray_bound(py).into()
+}
+
+/// Compute recursive structural features of the nodes of a :class:`~.PyGraph`
+///
+/// This is the feature extraction step of ReFeX, which is also the expensive
+/// part of role discovery with RolX. Every node starts out with three
+/// features: its degree, the number of edges within its egonet (the node
+/// together with its neighbors) and the number of edges leaving its egonet.
+/// Then in each of the ``rounds`` recursive rounds, the sum and the mean over
+/// the neighbors of a node of every feature added in the previous round are
+/// added as new features, so there are :math:`3 (2^{rounds + 1} - 1)`
+/// features in total. For details refer to:
+///
+/// Keith Henderson, Brian Gallagher, Lei Li, Leman Akoglu, Tina Eliassi-Rad,
+/// Hanghang Tong and Christos Faloutsos. "It's who you know: graph mining
+/// using recursive structural features." Proceedings of the 17th ACM SIGKDD
+/// (2011): 663-671. <https://doi.org/10.1145/2020408.2020512>
+///
+/// The features of the nodes are computed in parallel. The env var
+/// ``RAYON_NUM_THREADS`` can be used to adjust how many threads will be used.
+///
+/// :param PyGraph graph: The graph to compute the features of
+/// :param int rounds: The number of recursive aggregation rounds. Defaults
+///     to 2.
+///
+/// :returns: A matrix with a row of features for every node, in the order of
+///     :meth:`~.PyGraph.node_indices`. The features are ordered by round, and
+///     within a round the sum and then the mean of each feature of the
+///     previous round are listed in the order of the previous round.
+/// :rtype: numpy.ndarray
+///
+/// .. jupyter-execute::
+///
+///   import rustworkx as rx
+///
+///   graph = rx.generators.star_graph(4)
+///   print(rx.graph_recursive_structural_features(graph, rounds=1))
+#[pyfunction]
+#[pyo3(signature = (graph, /, rounds=2), text_signature = "(graph, /, rounds=2)")]
+pub fn graph_recursive_structural_features(
+    py: Python,
+    graph: &graph::PyGraph,
+    rounds: usize,
+) -> PyObject {
+    recursive_structural_features(py, &graph.graph, rounds)
+}
+
+/// Compute recursive structural features of the nodes of a
+/// :class:`~.PyDiGraph`
+///
+/// This is the feature extraction step of ReFeX, which is also the expensive
+/// part of role discovery with RolX. Every node starts out with five
+/// features: its in-degree, its out-degree, the number of edges within its
+/// egonet (the node together with its predecessors and successors), the
+/// number of edges leaving its egonet and the number of edges entering its
+/// egonet. Then in each of the ``rounds`` recursive rounds, the sum and the
+/// mean over the neighbors of a node of every feature added in the previous
+/// round are added as new features, so there are :math:`5 (2^{rounds + 1} - 1)`
+/// features in total. For details refer to:
+///
+/// Keith Henderson, Brian Gallagher, Lei Li, Leman Akoglu, Tina Eliassi-Rad,
+/// Hanghang Tong and Christos Faloutsos. "It's who you know: graph mining
+/// using recursive structural features." Proceedings of the 17th ACM SIGKDD
+/// (2011): 663-671. <https://doi.org/10.1145/2020408.2020512>
+///
+/// The features of the nodes are computed in parallel. The env var
+/// ``RAYON_NUM_THREADS`` can be used to adjust how many threads will be used.
+///
+/// :param PyDiGraph graph: The graph to compute the features of
+/// :param int rounds: The number of recursive aggregation rounds. Defaults
+///     to 2.
+///
+/// :returns: A matrix with a row of features for every node, in the order of
+///     :meth:`~.PyDiGraph.node_indices`. The features are ordered by round,
+///     and within a round the sum and then the mean of each feature of the
+///     previous round are listed in the order of the previous round.
+/// :rtype: numpy.ndarray
+///
+/// .. jupyter-execute::
+///
+///   import rustworkx as rx
+///
+///   graph = rx.generators.directed_star_graph(4)
+///   print(rx.digraph_recursive_structural_features(graph, rounds=1))
+#[pyfunction]
+#[pyo3(signature = (graph, /, rounds=2), text_signature = "(graph, /, rounds=2)")]
+pub fn digraph_recursive_structural_features(
+    py: Python,
+    graph: &digraph::PyDiGraph,
+    rounds: usize,
+) -> PyObject {
+    recursive_structural_features(py, &graph.graph, rounds)
+}
@@ -0,0 +1,285 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+use crate::transitivity::{_digraph_triangles, _graph_triangles};
+use crate::{digraph, graph, StablePyGraph};
+
+use petgraph::algo;
+use petgraph::stable_graph::NodeIndex;
+use petgraph::visit::EdgeRef;
+use petgraph::EdgeType;
+use rayon::prelude::*;
+
+use pyo3::prelude::*;
+
+use rustworkx_core::coloring::two_color;
+use rustworkx_core::connectivity::number_connected_components;
+use rustworkx_core::sampling::sample_nodes;
+
+/// Summary statistics of a graph, returned by
+/// :func:`~rustworkx.graph_summary`.
+///
+/// The degree of a node counts both its incoming and outgoing edges in a
+/// directed graph, and a self loop adds 2 to the degree of its node. For a
+/// directed graph ``num_components`` is the number of weakly connected
+/// components, and ``is_forest`` and ``is_bipartite`` are computed for the
+/// underlying undirected multigraph, where parallel edges and self loops
+/// are cycles. ``is_dag`` is always ``False`` for an undirected graph.
+#[pyclass(module = "rustworkx")]
+pub struct GraphSummary {
+    #[pyo3(get)]
+    num_nodes: usize,
+    #[pyo3(get)]
+    num_edges: usize,
+    #[pyo3(get)]
+    density: f64,
+    #[pyo3(get)]
+    min_degree: usize,
+    #[pyo3(get)]
+    max_degree: usize,
+    #[pyo3(get)]
+    mean_degree: f64,
+    #[pyo3(get)]
+    num_components: usize,
+    #[pyo3(get)]
+    average_clustering: f64,
+    #[pyo3(get)]
+    is_dag: bool,
+    #[pyo3(get)]
+    is_forest: bool,
+    #[pyo3(get)]
+    is_bipartite: bool,
+}
+
+#[pymethods]
+impl GraphSummary {
+    fn __repr__(&self) -> String {
+        format!(
+            "GraphSummary(num_nodes={}, num_edges={}, density={:?}, min_degree={}, \
+             max_degree={}, mean_degree={:?}, num_components={}, average_clustering={:?}, \
+             is_dag={}, is_forest={}, is_bipartite={})",
+            self.num_nodes,
+            self.num_edges,
+            self.density,
+            self.min_degree,
+            self.max_degree,
+            self.mean_degree,
+            self.num_components,
+            self.average_clustering,
+            if self.is_dag { "True" } else { "False" },
+            if self.is_forest { "True" } else { "False" },
+            if self.is_bipartite { "True" } else { "False" },
+        )
+    }
+}
+
+fn degree<Ty: EdgeType>(graph: &StablePyGraph<Ty>, node: NodeIndex) -> usize {
+    if Ty::is_directed() {
+        graph
+            .edges_directed(node, petgraph::Direction::Outgoing)
+            .count()
+            + graph
+                .edges_directed(node, petgraph::Direction::Incoming)
+                .count()
+    } else {
+        graph
+            .edges(node)
+            .map(|edge| if edge.source() == edge.target() { 2 } else { 1 })
+            .sum()
+    }
+}
+
+fn summary<Ty: EdgeType + Sync>(
+    graph: &StablePyGraph<Ty>,
+    triangles: impl Fn(usize) -> (usize, usize) + Sync,
+    clustering_samples: Option<usize>,
+    seed: Option<u64>,
+) -> GraphSummary {
+    let num_nodes = graph.node_count();
+    let num_edges = graph.edge_count();
+    let nodes: Vec<NodeIndex> = graph.node_indices().collect();
+
+    let clustering_nodes = match clustering_samples {
+        Some(samples) if samples < num_nodes => sample_nodes(graph, samples, seed).unwrap(),
+        _ => nodes.clone(),
+    };
+    let ((min_degree, max_degree), clustering) = rayon::join(
+        || {
+            nodes
+                .par_iter()
+                .map(|node| {
+                    let degree = degree(graph, *node);
+                    (degree, degree)
+                })
+                .reduce(
+                    || (usize::MAX, 0),
+                    |(min_a, max_a), (min_b, max_b)| (min_a.min(min_b), max_a.max(max_b)),
+                )
+        },
+        || {
+            clustering_nodes
+                .par_iter()
+                .map(|node| match triangles(node.index()) {
+                    (_, 0) => 0.0,
+                    (triangles, triples) => triangles as f64 / triples as f64,
+                })
+                .sum::<f64>()
+        },
+    );
+
+    let num_components = number_connected_components(graph);
+    let possible_edges = num_nodes * num_nodes.saturating_sub(1);
+    GraphSummary {
+        num_nodes,
+        num_edges,
+        density: match possible_edges {
+            0 => 0.0,
+            _ if Ty::is_directed() => num_edges as f64 / possible_edges as f64,
+            _ => 2.0 * num_edges as f64 / possible_edges as f64,
+        },
+        min_degree: if num_nodes == 0 { 0 } else { min_degree },
+        max_degree,
+        mean_degree: match num_nodes {
+            0 => 0.0,
+            _ => 2.0 * num_edges as f64 / num_nodes as f64,
+        },
+        num_components,
+        average_clustering: match clustering_nodes.len() {
+            0 => 0.0,
+            len => clustering / len as f64,
+        },
+        is_dag: Ty::is_directed() && algo::toposort(graph, None).is_ok(),
+        is_forest: num_edges + num_components == num_nodes,
+        is_bipartite: two_color(graph).is_some(),
+    }
+}
+
+/// Compute summary statistics of a :class:`~.PyGraph` object
+///
+/// The statistics are the number of nodes and edges, the density, the
+/// minimum, maximum and mean degree, the number of connected components,
+/// the average clustering coefficient and whether the graph is a forest or
+/// bipartite. See :class:`~.GraphSummary` for how each of them is defined.
+///
+/// The degrees and clustering coefficients are computed in parallel.
+/// For large graphs the average clustering coefficient can be estimated
+/// from a random sample of the nodes with ``clustering_samples``.
+///
+/// This function is multithreaded and will launch a thread pool with threads
+/// equal to the number of CPUs by default. You can tune the number of threads
+/// with the ``RAYON_NUM_THREADS`` environment variable. For example, setting
+/// ``RAYON_NUM_THREADS=4`` would limit the thread pool to 4 threads.
+///
+/// .. note::
+///
+///     The clustering coefficient of a node with fewer than 2 neighbors is 0
+///     and these nodes are included in the average. Like
+///     :func:`~rustworkx.graph_transitivity`, the clustering coefficients
+///     assume that there are no parallel edges or self loops.
+///
+/// :param PyGraph graph: The graph to summarize
+/// :param int clustering_samples: An optional number of nodes to sample
+///     uniformly at random to estimate the average clustering coefficient.
+///     If not specified, or if it's at least the number of nodes, the
+///     average is computed over all nodes.
+/// :param int seed: An optional seed for the random number generator used
+///     to sample the nodes
+///
+/// :returns: The summary statistics of ``graph``
+/// :rtype: GraphSummary
+///
+/// .. jupyter-execute::
+///
+///   import rustworkx as rx
+///
+///   graph = rx.generators.generalized_petersen_graph(5, 2)
+///   print(rx.graph_graph_summary(graph))
+#[pyfunction]
+#[pyo3(
+    signature=(graph, /, clustering_samples=None, seed=None),
+    text_signature = "(graph, /, clustering_samples=None, seed=None)"
+)]
+pub fn graph_graph_summary(
+    graph: &graph::PyGraph,
+    clustering_samples: Option<usize>,
+    seed: Option<u64>,
+) -> GraphSummary {
+    summary(
+        &graph.graph,
+        |node| _graph_triangles(graph, node),
+        clustering_samples,
+        seed,
+    )
+}
+
+/// Compute summary statistics of a :class:`~.PyDiGraph` object
+///
+/// The statistics are the number of nodes and edges, the density, the
+/// minimum, maximum and mean degree, the number of weakly connected
+/// components, the average clustering coefficient and whether the graph is
+/// a DAG, a forest or bipartite. See :class:`~.GraphSummary` for how each of
+/// them is defined.
+///
+/// The degrees and clustering coefficients are computed in parallel.
+/// For large graphs the average clustering coefficient can be estimated
+/// from a random sample of the nodes with ``clustering_samples``.
+///
+/// This function is multithreaded and will launch a thread pool with threads
+/// equal to the number of CPUs by default. You can tune the number of threads
+/// with the ``RAYON_NUM_THREADS`` environment variable. For example, setting
+/// ``RAYON_NUM_THREADS=4`` would limit the thread pool to 4 threads.
+///
+/// .. note::
+///
+///     The clustering coefficient of a node is the directed clustering
+///     coefficient of [Fag]_ and it's 0 for a node with fewer than 2
+///     neighbors. These nodes are included in the average. Like
+///     :func:`~rustworkx.digraph_transitivity`, the clustering coefficients
+///     assume that there are no parallel edges or self loops.
+///
+/// :param PyDiGraph graph: The graph to summarize
+/// :param int clustering_samples: An optional number of nodes to sample
+///     uniformly at random to estimate the average clustering coefficient.
+///     If not specified, or if it's at least the number of nodes, the
+///     average is computed over all nodes.
+/// :param int seed: An optional seed for the random number generator used
+///     to sample the nodes
+///
+/// :returns: The summary statistics of ``graph``
+/// :rtype: GraphSummary
+///
+/// .. jupyter-execute::
+///
+///   import rustworkx as rx
+///
+///   graph = rx.generators.directed_path_graph(5)
+///   print(rx.digraph_graph_summary(graph))
+///
+/// .. [Fag] Clustering in complex directed networks by G. Fagiolo,
+///    Physical Review E, 76(2), 026107 (2007)
+#[pyfunction]
+#[pyo3(
+    signature=(graph, /, clustering_samples=None, seed=None),
+    text_signature = "(graph, /, clustering_samples=None, seed=None)"
+)]
+pub fn digraph_graph_summary(
+    graph: &digraph::PyDiGraph,
+    clustering_samples: Option<usize>,
+    seed: Option<u64>,
+) -> GraphSummary {
+    summary(
+        &graph.graph,
+        |node| _digraph_triangles(graph, node),
+        clustering_samples,
+        seed,
+    )
+}
@@ -0,0 +1,494 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+use std::collections::BTreeMap;
+use std::fs::File;
+use std::io::prelude::*;
+use std::str;
+
+use hashbrown::HashMap;
+
+use petgraph::visit::{EdgeRef, IntoEdgeReferences};
+use petgraph::EdgeType;
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pyo3::types::PyString;
+
+use crate::{digraph, graph, StablePyGraph};
+
+const DEFAULT_RADIUS: f64 = 10.;
+const DEFAULT_NODE_FILL: &str = "#1f78b4";
+const DEFAULT_EDGE_STROKE: &str = "black";
+const FONT_SIZE: f64 = 12.;
+const MARGIN: f64 = 10.;
+const CIRCLE_GEOMETRY: [&str; 3] = ["cx", "cy", "r"];
+const LINE_GEOMETRY: [&str; 4] = ["x1", "y1", "x2", "y2"];
+
+type AttrMap = BTreeMap<String, String>;
+
+/// Escape a string for use as XML text or as an attribute value
+fn escape(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            '\'' => out.push_str("&apos;"),
+            _ => out.push(c),
+        }
+    }
+    out
+}
+
+/// Call an optional attribute callback on a node or edge weight
+fn call_attr_fn(py: Python, attr_fn: Option<&PyObject>, weight: &PyObject) -> PyResult<AttrMap> {
+    match attr_fn {
+        Some(attr_fn) => attr_fn.call1(py, (weight,))?.extract(py),
+        None => Ok(AttrMap::new()),
+    }
+}
+
+/// Write the attributes of an element, using the defaults for any missing key
+///
+/// The geometry of the element is written by the caller, so its attributes
+/// can't be set from the attribute callbacks.
+fn write_attrs<T: Write>(
+    file: &mut T,
+    attrs: &AttrMap,
+    defaults: &[(&str, &str)],
+    reserved: &[&str],
+) -> PyResult<()> {
+    for (key, value) in defaults {
+        if !attrs.contains_key(*key) {
+            write!(file, " {}=\"{}\"", key, value)?;
+        }
+    }
+    for (key, value) in attrs {
+        let valid_name = !key.is_empty()
+            && key
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | ':'));
+        if !valid_name || reserved.contains(&key.as_str()) {
+            return Err(PyValueError::new_err(format!(
+                "Invalid SVG attribute name {:?}",
+                key
+            )));
+        }
+        write!(file, " {}=\"{}\"", key, escape(value))?;
+    }
+    Ok(())
+}
+
+fn write_label<T: Write>(file: &mut T, x: f64, y: f64, label: &str) -> PyResult<()> {
+    writeln!(
+        file,
+        "    <text x=\"{:.2}\" y=\"{:.2}\" text-anchor=\"middle\" dominant-baseline=\"central\">{}</text>",
+        x,
+        y,
+        escape(label)
+    )?;
+    Ok(())
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn build_svg<Ty: EdgeType, T: Write>(
+    py: Python,
+    graph: &StablePyGraph<Ty>,
+    pos: &Bound<PyAny>,
+    file: &mut T,
+    node_attr_fn: Option<PyObject>,
+    edge_attr_fn: Option<PyObject>,
+    width: f64,
+    height: f64,
+    with_labels: bool,
+) -> PyResult<()> {
+    if !(width > 0. && width.is_finite() && height > 0. && height.is_finite()) {
+        return Err(PyValueError::new_err(
+            "width and height must be positive numbers",
+        ));
+    }
+    // Read the node positions and styles first, they're needed to scale the
+    // drawing and to end the edges at the boundary of the nodes.
+    let mut nodes: Vec<(usize, [f64; 2], f64, AttrMap)> = Vec::with_capacity(graph.node_count());
+    for node in graph.node_indices() {
+        let [x, y]: [f64; 2] = pos.get_item(node.index())?.extract()?;
+        if !(x.is_finite() && y.is_finite()) {
+            return Err(PyValueError::new_err(format!(
+                "Position of node {} is not finite",
+                node.index()
+            )));
+        }
+        let mut attrs = call_attr_fn(py, node_attr_fn.as_ref(), &graph[node])?;
+        let radius = match attrs.remove("r") {
+            Some(radius) => radius
+                .parse::<f64>()
+                .map_err(|_| PyValueError::new_err(format!("Invalid node radius {:?}", radius)))?,
+            None => DEFAULT_RADIUS,
+        };
+        nodes.push((node.index(), [x, y], radius, attrs));
+    }
+    let max_radius = nodes
+        .iter()
+        .map(|(_, _, radius, _)| *radius)
+        .fold(0., f64::max);
+    let (mut min_x, mut max_x, mut min_y, mut max_y) = (0., 0., 0., 0.);
+    if let Some((_, [x, y], _, _)) = nodes.first() {
+        (min_x, max_x, min_y, max_y) = (*x, *x, *y, *y);
+    }
+    for (_, [x, y], _, _) in &nodes {
+        min_x = f64::min(min_x, *x);
+        max_x = f64::max(max_x, *x);
+        min_y = f64::min(min_y, *y);
+        max_y = f64::max(max_y, *y);
+    }
+    // Scale both axes by the same factor so the layout isn't distorted, and
+    // flip the y axis since it points down in SVG.
+    let margin = max_radius + MARGIN;
+    let inner_width = f64::max(width - 2. * margin, 0.);
+    let inner_height = f64::max(height - 2. * margin, 0.);
+    let scale = match (max_x - min_x > 0., max_y - min_y > 0.) {
+        (true, true) => f64::min(
+            inner_width / (max_x - min_x),
+            inner_height / (max_y - min_y),
+        ),
+        (true, false) => inner_width / (max_x - min_x),
+        (false, true) => inner_height / (max_y - min_y),
+        (false, false) => 0.,
+    };
+    let center = [(min_x + max_x) / 2., (min_y + max_y) / 2.];
+    let mut points: HashMap<usize, ([f64; 2], f64)> = HashMap::with_capacity(nodes.len());
+    for (index, [x, y], radius, _) in &nodes {
+        let point = [
+            width / 2. + (x - center[0]) * scale,
+            height / 2. - (y - center[1]) * scale,
+        ];
+        points.insert(*index, (point, *radius));
+    }
+
+    writeln!(
+        file,
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{}\" height=\"{}\" viewBox=\"0 0 {} {}\">",
+        width, height, width, height
+    )?;
+    if graph.is_directed() {
+        writeln!(file, "  <defs>")?;
+        writeln!(
+            file,
+            "    <marker id=\"arrowhead\" viewBox=\"0 0 10 10\" refX=\"10\" refY=\"5\" \
+             markerWidth=\"8\" markerHeight=\"8\" markerUnits=\"userSpaceOnUse\" orient=\"auto\">"
+        )?;
+        writeln!(file, "      <path d=\"M 0 0 L 10 5 L 0 10 z\"/>")?;
+        writeln!(file, "    </marker>")?;
+        writeln!(file, "  </defs>")?;
+    }
+
+    let mut edge_labels: Vec<(f64, f64, String)> = Vec::new();
+    writeln!(file, "  <g class=\"edges\">")?;
+    for edge in graph.edge_references() {
+        let mut attrs = call_attr_fn(py, edge_attr_fn.as_ref(), edge.weight())?;
+        let label = attrs.remove("label");
+        let (source, source_radius) = points[&edge.source().index()];
+        let (target, target_radius) = points[&edge.target().index()];
+        let (label_x, label_y);
+        if edge.source() == edge.target() {
+            // Draw a self-loop as a circle overlapping the top of the node.
+            let loop_radius = f64::max(source_radius, 1.) * 0.75;
+            let cy = source[1] - source_radius - loop_radius / 2.;
+            write!(
+                file,
+                "    <circle cx=\"{:.2}\" cy=\"{:.2}\" r=\"{:.2}\"",
+                source[0], cy, loop_radius
+            )?;
+            write_attrs(
+                file,
+                &attrs,
+                &[("stroke", DEFAULT_EDGE_STROKE), ("fill", "none")],
+                &CIRCLE_GEOMETRY,
+            )?;
+            writeln!(file, "/>")?;
+            (label_x, label_y) = (source[0], cy - loop_radius - FONT_SIZE / 2.);
+        } else {
+            let (dx, dy) = (target[0] - source[0], target[1] - source[1]);
+            let length = dx.hypot(dy);
+            // Start and end the edge at the boundary of the nodes so that
+            // arrowheads aren't hidden behind the target node.
+            let (start, end) = if length > source_radius + target_radius {
+                (
+                    [
+                        source[0] + dx * source_radius / length,
+                        source[1] + dy * source_radius / length,
+                    ],
+                    [
+                        target[0] - dx * target_radius / length,
+                        target[1] - dy * target_radius / length,
+                    ],
+                )
+            } else {
+                (source, target)
+            };
+            write!(
+                file,
+                "    <line x1=\"{:.2}\" y1=\"{:.2}\" x2=\"{:.2}\" y2=\"{:.2}\"",
+                start[0], start[1], end[0], end[1]
+            )?;
+            if graph.is_directed() {
+                write_attrs(
+                    file,
+                    &attrs,
+                    &[
+                        ("stroke", DEFAULT_EDGE_STROKE),
+                        ("marker-end", "url(#arrowhead)"),
+                    ],
+                    &LINE_GEOMETRY,
+                )?;
+            } else {
+                write_attrs(
+                    file,
+                    &attrs,
+                    &[("stroke", DEFAULT_EDGE_STROKE)],
+                    &LINE_GEOMETRY,
+                )?;
+            }
+            writeln!(file, "/>")?;
+            (label_x, label_y) = ((source[0] + target[0]) / 2., (source[1] + target[1]) / 2.);
+        }
+        if let Some(label) = label {
+            edge_labels.push((label_x, label_y, label));
+        }
+    }
+    writeln!(file, "  </g>")?;
+
+    let mut node_labels: Vec<(f64, f64, String)> = Vec::new();
+    writeln!(file, "  <g class=\"nodes\">")?;
+    for (index, _, radius, mut attrs) in nodes {
+        let label = attrs.remove("label");
+        let (point, _) = points[&index];
+        write!(
+            file,
+            "    <circle cx=\"{:.2}\" cy=\"{:.2}\" r=\"{}\"",
+            point[0], point[1], radius
+        )?;
+        write_attrs(
+            file,
+            &attrs,
+            &[("fill", DEFAULT_NODE_FILL)],
+            &CIRCLE_GEOMETRY,
+        )?;
+        writeln!(file, "/>")?;
+        match label {
+            Some(label) => node_labels.push((point[0], point[1], label)),
+            None if with_labels => node_labels.push((point[0], point[1], index.to_string())),
+            None => (),
+        }
+    }
+    writeln!(file, "  </g>")?;
+
+    if !node_labels.is_empty() || !edge_labels.is_empty() {
+        writeln!(
+            file,
+            "  <g class=\"labels\" font-family=\"sans-serif\" font-size=\"{}\">",
+            FONT_SIZE
+        )?;
+        for (x, y, label) in edge_labels.iter().chain(node_labels.iter()) {
+            write_label(file, *x, *y, label)?;
+        }
+        writeln!(file, "  </g>")?;
+    }
+    writeln!(file, "</svg>")?;
+    Ok(())
+}
+
+#[allow(clippy::too_many_arguments)]
+fn to_svg_inner<Ty: EdgeType>(
+    py: Python,
+    graph: &StablePyGraph<Ty>,
+    pos: &Bound<PyAny>,
+    node_attr_fn: Option<PyObject>,
+    edge_attr_fn: Option<PyObject>,
+    width: f64,
+    height: f64,
+    with_labels: bool,
+    filename: Option<String>,
+) -> PyResult<Option<PyObject>> {
+    let mut file = Vec::<u8>::new();
+    build_svg(
+        py,
+        graph,
+        pos,
+        &mut file,
+        node_attr_fn,
+        edge_attr_fn,
+        width,
+        height,
+        with_labels,
+    )?;
+    match filename {
+        // The file is only created once the drawing succeeded so a failing
+        // callback doesn't leave a truncated file behind.
+        Some(filename) => {
+            File::create(filename)?.write_all(&file)?;
+            Ok(None)
+        }
+        None => Ok(Some(
+            PyString::new_bound(py, str::from_utf8(&file)?).to_object(py),
+        )),
+    }
+}
+
+/// Draw a :class:`~.PyGraph` as an SVG image
+///
+/// The image is written directly without any plotting library, so it can be
+/// used where matplotlib isn't available. Every node is drawn as a circle at
+/// its position from ``pos`` and every edge as a straight line. The positions
+/// are scaled uniformly to fit the image, with the y axis pointing up.
+///
+/// :param PyGraph graph: The graph to draw
+/// :param pos: A mapping of node indices to ``(x, y)`` positions for every
+///     node in the graph, such as the output of :func:`~rustworkx.spring_layout`
+///     or any other layout function
+/// :param node_attr_fn: An optional callable that will be passed the
+///     weight/data payload of every node and returns a dictionary of SVG
+///     attributes for the node's ``<circle>`` element, for example
+///     ``{"fill": "red", "stroke": "black"}``. The key and value of this
+///     dictionary must be strings. The special key ``"r"`` sets the radius of
+///     the node (which defaults to ``10``) and ``"label"`` sets a text label
+///     drawn on the node.
+/// :param edge_attr_fn: An optional callable that will be passed the
+///     weight/data payload of every edge and returns a dictionary of SVG
+///     attributes for the edge's ``<line>`` element, for example
+///     ``{"stroke": "gray", "stroke-width": "2"}``. The key and value of this
+///     dictionary must be strings. The special key ``"label"`` sets a text
+///     label drawn at the middle of the edge.
+/// :param float width: The width of the image in pixels
+/// :param float height: The height of the image in pixels
+/// :param bool with_labels: If set to ``True`` every node without a
+///     ``"label"`` attribute is labeled with its index
+/// :param str filename: An optional path to write the SVG image to. If
+///     specified there is no return from the function
+///
+/// :returns: A string with the SVG image if ``filename`` is not specified
+/// :rtype: str
+/// :raises ValueError: If an attribute name isn't valid, a radius isn't a
+///     number, or the size of the image isn't positive
+///
+/// .. jupyter-execute::
+///
+///   import rustworkx as rx
+///
+///   graph = rx.generators.cycle_graph(4)
+///   pos = rx.circular_layout(graph)
+///   print(rx.graph_to_svg(graph, pos, width=200, height=200))
+#[pyfunction]
+#[pyo3(
+    signature = (graph, pos, /, node_attr_fn=None, edge_attr_fn=None, width=600., height=600., with_labels=false, filename=None),
+    text_signature = "(graph, pos, /, node_attr_fn=None, edge_attr_fn=None, width=600.0, height=600.0, with_labels=False, filename=None)"
+)]
+#[allow(clippy::too_many_arguments)]
+pub fn graph_to_svg(
+    py: Python,
+    graph: &graph::PyGraph,
+    pos: &Bound<PyAny>,
+    node_attr_fn: Option<PyObject>,
+    edge_attr_fn: Option<PyObject>,
+    width: f64,
+    height: f64,
+    with_labels: bool,
+    filename: Option<String>,
+) -> PyResult<Option<PyObject>> {
+    to_svg_inner(
+        py,
+        &graph.graph,
+        pos,
+        node_attr_fn,
+        edge_attr_fn,
+        width,
+        height,
+        with_labels,
+        filename,
+    )
+}
+
+/// Draw a :class:`~.PyDiGraph` as an SVG image
+///
+/// The image is written directly without any plotting library, so it can be
+/// used where matplotlib isn't available. Every node is drawn as a circle at
+/// its position from ``pos`` and every edge as a straight arrow ending at
+/// the boundary of its target node. The positions are scaled uniformly to
+/// fit the image, with the y axis pointing up.
+///
+/// :param PyDiGraph graph: The graph to draw
+/// :param pos: A mapping of node indices to ``(x, y)`` positions for every
+///     node in the graph, such as the output of :func:`~rustworkx.spring_layout`
+///     or any other layout function
+/// :param node_attr_fn: An optional callable that will be passed the
+///     weight/data payload of every node and returns a dictionary of SVG
+///     attributes for the node's ``<circle>`` element, for example
+///     ``{"fill": "red", "stroke": "black"}``. The key and value of this
+///     dictionary must be strings. The special key ``"r"`` sets the radius of
+///     the node (which defaults to ``10``) and ``"label"`` sets a text label
+///     drawn on the node.
+/// :param edge_attr_fn: An optional callable that will be passed the
+///     weight/data payload of every edge and returns a dictionary of SVG
+///     attributes for the edge's ``<line>`` element, for example
+///     ``{"stroke": "gray", "stroke-width": "2"}``. The key and value of this
+///     dictionary must be strings. The special key ``"label"`` sets a text
+///     label drawn at the middle of the edge.
+/// :param float width: The width of the image in pixels
+/// :param float height: The height of the image in pixels
+/// :param bool with_labels: If set to ``True`` every node without a
+///     ``"label"`` attribute is labeled with its index
+/// :param str filename: An optional path to write the SVG image to. If
+///     specified there is no return from the function
+///
+/// :returns: A string with the SVG image if ``filename`` is not specified
+/// :rtype: str
+/// :raises ValueError: If an attribute name isn't valid, a radius isn't a
+///     number, or the size of the image isn't positive
+///
+/// .. jupyter-execute::
+///
+///   import rustworkx as rx
+///
+///   graph = rx.generators.directed_path_graph(3)
+///   pos = rx.shell_layout(graph)
+///   print(rx.digraph_to_svg(graph, pos, width=200, height=200))
+#[pyfunction]
+#[pyo3(
+    signature = (graph, pos, /, node_attr_fn=None, edge_attr_fn=None, width=600., height=600., with_labels=false, filename=None),
+    text_signature = "(graph, pos, /, node_attr_fn=None, edge_attr_fn=None, width=600.0, height=600.0, with_labels=False, filename=None)"
+)]
+#[allow(clippy::too_many_arguments)]
+pub fn digraph_to_svg(
+    py: Python,
+    graph: &digraph::PyDiGraph,
+    pos: &Bound<PyAny>,
+    node_attr_fn: Option<PyObject>,
+    edge_attr_fn: Option<PyObject>,
+    width: f64,
+    height: f64,
+    with_labels: bool,
+    filename: Option<String>,
+) -> PyResult<Option<PyObject>> {
+    to_svg_inner(
+        py,
+        &graph.graph,
+        pos,
+        node_attr_fn,
+        edge_attr_fn,
+        width,
+        height,
+        with_labels,
+        filename,
+    )
+}
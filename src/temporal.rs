@@ -0,0 +1,607 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+use hashbrown::HashSet;
+
+use crate::iterators::{PathLengthMapping, PathMapping};
+use crate::{digraph, graph, EdgeType, StablePyGraph};
+
+use petgraph::prelude::*;
+use petgraph::visit::{EdgeIndexable, IntoEdgeReferences};
+use pyo3::exceptions::{PyIndexError, PyValueError};
+use pyo3::prelude::*;
+use rustworkx_core::dictmap::*;
+use rustworkx_core::temporal;
+
+/// Call ``time_fn`` on every edge of the graph. The callable can return
+/// either a single timestamp or a ``(departure, arrival)`` tuple.
+fn edge_times<Ty: EdgeType>(
+    py: Python,
+    graph: &StablePyGraph<Ty>,
+    time_fn: &PyObject,
+) -> PyResult<Vec<(f64, f64)>> {
+    let mut times = vec![(0., 0.); graph.edge_bound()];
+    for edge in graph.edge_references() {
+        let res = time_fn.bind(py).call1((edge.weight(),))?;
+        let (departure, arrival) = match res.extract::<(f64, f64)>() {
+            Ok(interval) => interval,
+            Err(_) => {
+                let time = res.extract::<f64>()?;
+                (time, time)
+            }
+        };
+        if departure.is_nan() || arrival.is_nan() {
+            return Err(PyValueError::new_err("NaN found as an edge time"));
+        }
+        if arrival < departure {
+            return Err(PyValueError::new_err(format!(
+                "Edge arrival time {arrival} is earlier than its departure time {departure}"
+            )));
+        }
+        times[edge.id().index()] = (departure, arrival);
+    }
+    Ok(times)
+}
+
+fn check_source<Ty: EdgeType>(graph: &StablePyGraph<Ty>, source: usize) -> PyResult<NodeIndex> {
+    let index = NodeIndex::new(source);
+    if !graph.contains_node(index) {
+        return Err(PyIndexError::new_err(format!(
+            "Node source index \"{source}\" out of graph bound"
+        )));
+    }
+    Ok(index)
+}
+
+fn earliest_arrival<Ty: EdgeType>(
+    py: Python,
+    graph: &StablePyGraph<Ty>,
+    source: usize,
+    time_fn: PyObject,
+    start_time: Option<f64>,
+    end_time: Option<f64>,
+    paths: Option<&mut DictMap<NodeIndex, Vec<NodeIndex>>>,
+) -> PyResult<DictMap<usize, f64>> {
+    let index = check_source(graph, source)?;
+    let times = edge_times(py, graph, &time_fn)?;
+    let arrival = temporal::earliest_arrival(
+        graph,
+        index,
+        start_time.unwrap_or(f64::NEG_INFINITY),
+        end_time.unwrap_or(f64::INFINITY),
+        |e| -> PyResult<(f64, f64)> { Ok(times[e.id().index()]) },
+        paths,
+    )?;
+    Ok(arrival
+        .into_iter()
+        .filter(|(node, _)| *node != index)
+        .map(|(node, time)| (node.index(), time))
+        .collect())
+}
+
+fn earliest_arrival_paths<Ty: EdgeType>(
+    py: Python,
+    graph: &StablePyGraph<Ty>,
+    source: usize,
+    time_fn: PyObject,
+    start_time: Option<f64>,
+    end_time: Option<f64>,
+) -> PyResult<PathMapping> {
+    let mut paths = DictMap::new();
+    earliest_arrival(
+        py,
+        graph,
+        source,
+        time_fn,
+        start_time,
+        end_time,
+        Some(&mut paths),
+    )?;
+    Ok(PathMapping {
+        paths: paths
+            .into_iter()
+            .filter(|(node, _)| node.index() != source)
+            .map(|(node, path)| (node.index(), path.into_iter().map(|x| x.index()).collect()))
+            .collect(),
+    })
+}
+
+fn fastest_path_durations<Ty: EdgeType>(
+    py: Python,
+    graph: &StablePyGraph<Ty>,
+    source: usize,
+    time_fn: PyObject,
+    start_time: Option<f64>,
+    end_time: Option<f64>,
+) -> PyResult<PathLengthMapping> {
+    let index = check_source(graph, source)?;
+    let times = edge_times(py, graph, &time_fn)?;
+    let durations = temporal::fastest_path_durations(
+        graph,
+        index,
+        start_time.unwrap_or(f64::NEG_INFINITY),
+        end_time.unwrap_or(f64::INFINITY),
+        |e| -> PyResult<(f64, f64)> { Ok(times[e.id().index()]) },
+    )?;
+    Ok(PathLengthMapping {
+        path_lengths: durations
+            .into_iter()
+            .filter(|(node, _)| *node != index)
+            .map(|(node, duration)| (node.index(), duration))
+            .collect(),
+    })
+}
+
+fn snapshot_edges<Ty: EdgeType>(
+    py: Python,
+    graph: &StablePyGraph<Ty>,
+    time_fn: PyObject,
+    start_time: f64,
+    end_time: Option<f64>,
+) -> PyResult<HashSet<EdgeIndex>> {
+    let end_time = end_time.unwrap_or(start_time);
+    if end_time < start_time {
+        return Err(PyValueError::new_err(
+            "end_time must not be earlier than start_time",
+        ));
+    }
+    let times = edge_times(py, graph, &time_fn)?;
+    let edges =
+        temporal::snapshot_edges(graph, start_time, end_time, |e| -> PyResult<(f64, f64)> {
+            Ok(times[e.id().index()])
+        })?;
+    Ok(edges.into_iter().collect())
+}
+
+/// Compute the earliest arrival times from a node of a temporal
+/// :class:`~rustworkx.PyGraph` object.
+///
+/// The edges of a temporal graph are only available at given times, which
+/// are returned by ``time_fn``. The search leaves ``source`` at
+/// ``start_time`` and follows time-respecting paths: every edge has to
+/// depart no earlier than the arrival at its source node, and arrive no
+/// later than ``end_time``.
+///
+/// :param PyGraph graph: The input graph to use
+/// :param int source: The node index to start the search from
+/// :param time_fn: A callable that will take in an edge's data object and
+///     return either a single float timestamp when the edge is available,
+///     or a tuple ``(departure, arrival)`` of the times at which the edge
+///     is entered and its other endpoint is reached.
+/// :param float start_time: The time the search leaves ``source``. If not
+///     specified all the edges out of ``source`` can be used.
+/// :param float end_time: The latest allowed arrival time. If not
+///     specified there is no limit.
+///
+/// :returns: A mapping of the nodes temporally reachable from ``source``
+///     (not including ``source``) to their earliest arrival time
+/// :rtype: PathLengthMapping
+/// :raises ValueError: when an edge time is NaN or an arrival time is
+///     earlier than its departure time
+#[pyfunction]
+#[pyo3(
+    signature=(graph, source, time_fn, start_time=None, end_time=None),
+    text_signature = "(graph, source, time_fn, /, start_time=None, end_time=None)"
+)]
+pub fn graph_earliest_arrival_times(
+    py: Python,
+    graph: &graph::PyGraph,
+    source: usize,
+    time_fn: PyObject,
+    start_time: Option<f64>,
+    end_time: Option<f64>,
+) -> PyResult<PathLengthMapping> {
+    Ok(PathLengthMapping {
+        path_lengths: earliest_arrival(
+            py,
+            &graph.graph,
+            source,
+            time_fn,
+            start_time,
+            end_time,
+            None,
+        )?,
+    })
+}
+
+/// Compute the earliest arrival times from a node of a temporal
+/// :class:`~rustworkx.PyDiGraph` object.
+///
+/// The edges of a temporal graph are only available at given times, which
+/// are returned by ``time_fn``. The search leaves ``source`` at
+/// ``start_time`` and follows time-respecting paths: every edge has to
+/// depart no earlier than the arrival at its source node, and arrive no
+/// later than ``end_time``.
+///
+/// :param PyDiGraph graph: The input graph to use
+/// :param int source: The node index to start the search from
+/// :param time_fn: A callable that will take in an edge's data object and
+///     return either a single float timestamp when the edge is available,
+///     or a tuple ``(departure, arrival)`` of the times at which the edge
+///     is entered and its target is reached.
+/// :param float start_time: The time the search leaves ``source``. If not
+///     specified all the edges out of ``source`` can be used.
+/// :param float end_time: The latest allowed arrival time. If not
+///     specified there is no limit.
+///
+/// :returns: A mapping of the nodes temporally reachable from ``source``
+///     (not including ``source``) to their earliest arrival time
+/// :rtype: PathLengthMapping
+/// :raises ValueError: when an edge time is NaN or an arrival time is
+///     earlier than its departure time
+#[pyfunction]
+#[pyo3(
+    signature=(graph, source, time_fn, start_time=None, end_time=None),
+    text_signature = "(graph, source, time_fn, /, start_time=None, end_time=None)"
+)]
+pub fn digraph_earliest_arrival_times(
+    py: Python,
+    graph: &digraph::PyDiGraph,
+    source: usize,
+    time_fn: PyObject,
+    start_time: Option<f64>,
+    end_time: Option<f64>,
+) -> PyResult<PathLengthMapping> {
+    Ok(PathLengthMapping {
+        path_lengths: earliest_arrival(
+            py,
+            &graph.graph,
+            source,
+            time_fn,
+            start_time,
+            end_time,
+            None,
+        )?,
+    })
+}
+
+/// Find the earliest arrival paths from a node of a temporal
+/// :class:`~rustworkx.PyGraph` object.
+///
+/// See :func:`~rustworkx.graph_earliest_arrival_times` for how the edge
+/// times are used.
+///
+/// :param PyGraph graph: The input graph to use
+/// :param int source: The node index to start the search from
+/// :param time_fn: A callable that will take in an edge's data object and
+///     return either a single float timestamp or a ``(departure, arrival)``
+///     tuple.
+/// :param float start_time: The time the search leaves ``source``. If not
+///     specified all the edges out of ``source`` can be used.
+/// :param float end_time: The latest allowed arrival time. If not
+///     specified there is no limit.
+///
+/// :returns: A mapping of the nodes temporally reachable from ``source``
+///     (not including ``source``) to a time-respecting path of node indices
+///     reaching them at their earliest arrival time
+/// :rtype: PathMapping
+/// :raises ValueError: when an edge time is NaN or an arrival time is
+///     earlier than its departure time
+#[pyfunction]
+#[pyo3(
+    signature=(graph, source, time_fn, start_time=None, end_time=None),
+    text_signature = "(graph, source, time_fn, /, start_time=None, end_time=None)"
+)]
+pub fn graph_earliest_arrival_paths(
+    py: Python,
+    graph: &graph::PyGraph,
+    source: usize,
+    time_fn: PyObject,
+    start_time: Option<f64>,
+    end_time: Option<f64>,
+) -> PyResult<PathMapping> {
+    earliest_arrival_paths(py, &graph.graph, source, time_fn, start_time, end_time)
+}
+
+/// Find the earliest arrival paths from a node of a temporal
+/// :class:`~rustworkx.PyDiGraph` object.
+///
+/// See :func:`~rustworkx.digraph_earliest_arrival_times` for how the edge
+/// times are used.
+///
+/// :param PyDiGraph graph: The input graph to use
+/// :param int source: The node index to start the search from
+/// :param time_fn: A callable that will take in an edge's data object and
+///     return either a single float timestamp or a ``(departure, arrival)``
+///     tuple.
+/// :param float start_time: The time the search leaves ``source``. If not
+///     specified all the edges out of ``source`` can be used.
+/// :param float end_time: The latest allowed arrival time. If not
+///     specified there is no limit.
+///
+/// :returns: A mapping of the nodes temporally reachable from ``source``
+///     (not including ``source``) to a time-respecting path of node indices
+///     reaching them at their earliest arrival time
+/// :rtype: PathMapping
+/// :raises ValueError: when an edge time is NaN or an arrival time is
+///     earlier than its departure time
+#[pyfunction]
+#[pyo3(
+    signature=(graph, source, time_fn, start_time=None, end_time=None),
+    text_signature = "(graph, source, time_fn, /, start_time=None, end_time=None)"
+)]
+pub fn digraph_earliest_arrival_paths(
+    py: Python,
+    graph: &digraph::PyDiGraph,
+    source: usize,
+    time_fn: PyObject,
+    start_time: Option<f64>,
+    end_time: Option<f64>,
+) -> PyResult<PathMapping> {
+    earliest_arrival_paths(py, &graph.graph, source, time_fn, start_time, end_time)
+}
+
+/// Compute the durations of the fastest time-respecting paths from a node
+/// of a temporal :class:`~rustworkx.PyGraph` object.
+///
+/// The duration of a path is the arrival time at its last node minus the
+/// departure time of its first edge, so unlike
+/// :func:`~rustworkx.graph_earliest_arrival_times` the path may wait at
+/// ``source`` for a later departure. One earliest arrival search is run in
+/// parallel for every distinct departure time of the edges out of
+/// ``source``.
+///
+/// :param PyGraph graph: The input graph to use
+/// :param int source: The node index to start the search from
+/// :param time_fn: A callable that will take in an edge's data object and
+///     return either a single float timestamp or a ``(departure, arrival)``
+///     tuple.
+/// :param float start_time: The earliest allowed departure time. If not
+///     specified there is no limit.
+/// :param float end_time: The latest allowed arrival time. If not
+///     specified there is no limit.
+///
+/// :returns: A mapping of the nodes temporally reachable from ``source``
+///     (not including ``source``) to the duration of the fastest path to
+///     them
+/// :rtype: PathLengthMapping
+/// :raises ValueError: when an edge time is NaN or an arrival time is
+///     earlier than its departure time
+#[pyfunction]
+#[pyo3(
+    signature=(graph, source, time_fn, start_time=None, end_time=None),
+    text_signature = "(graph, source, time_fn, /, start_time=None, end_time=None)"
+)]
+pub fn graph_fastest_path_durations(
+    py: Python,
+    graph: &graph::PyGraph,
+    source: usize,
+    time_fn: PyObject,
+    start_time: Option<f64>,
+    end_time: Option<f64>,
+) -> PyResult<PathLengthMapping> {
+    fastest_path_durations(py, &graph.graph, source, time_fn, start_time, end_time)
+}
+
+/// Compute the durations of the fastest time-respecting paths from a node
+/// of a temporal :class:`~rustworkx.PyDiGraph` object.
+///
+/// The duration of a path is the arrival time at its last node minus the
+/// departure time of its first edge, so unlike
+/// :func:`~rustworkx.digraph_earliest_arrival_times` the path may wait at
+/// ``source`` for a later departure. One earliest arrival search is run in
+/// parallel for every distinct departure time of the edges out of
+/// ``source``.
+///
+/// :param PyDiGraph graph: The input graph to use
+/// :param int source: The node index to start the search from
+/// :param time_fn: A callable that will take in an edge's data object and
+///     return either a single float timestamp or a ``(departure, arrival)``
+///     tuple.
+/// :param float start_time: The earliest allowed departure time. If not
+///     specified there is no limit.
+/// :param float end_time: The latest allowed arrival time. If not
+///     specified there is no limit.
+///
+/// :returns: A mapping of the nodes temporally reachable from ``source``
+///     (not including ``source``) to the duration of the fastest path to
+///     them
+/// :rtype: PathLengthMapping
+/// :raises ValueError: when an edge time is NaN or an arrival time is
+///     earlier than its departure time
+#[pyfunction]
+#[pyo3(
+    signature=(graph, source, time_fn, start_time=None, end_time=None),
+    text_signature = "(graph, source, time_fn, /, start_time=None, end_time=None)"
+)]
+pub fn digraph_fastest_path_durations(
+    py: Python,
+    graph: &digraph::PyDiGraph,
+    source: usize,
+    time_fn: PyObject,
+    start_time: Option<f64>,
+    end_time: Option<f64>,
+) -> PyResult<PathLengthMapping> {
+    fastest_path_durations(py, &graph.graph, source, time_fn, start_time, end_time)
+}
+
+/// Return the nodes of a temporal :class:`~rustworkx.PyGraph` object that
+/// can be reached from a node with a time-respecting path.
+///
+/// See :func:`~rustworkx.graph_earliest_arrival_times` for how the edge
+/// times are used.
+///
+/// :param PyGraph graph: The input graph to use
+/// :param int source: The node index to start the search from
+/// :param time_fn: A callable that will take in an edge's data object and
+///     return either a single float timestamp or a ``(departure, arrival)``
+///     tuple.
+/// :param float start_time: The time the search leaves ``source``. If not
+///     specified all the edges out of ``source`` can be used.
+/// :param float end_time: The latest allowed arrival time. If not
+///     specified there is no limit.
+///
+/// :returns: A set of the indices of the nodes temporally reachable from
+///     ``source``, not including ``source``
+/// :rtype: set
+/// :raises ValueError: when an edge time is NaN or an arrival time is
+///     earlier than its departure time
+#[pyfunction]
+#[pyo3(
+    signature=(graph, source, time_fn, start_time=None, end_time=None),
+    text_signature = "(graph, source, time_fn, /, start_time=None, end_time=None)"
+)]
+pub fn graph_temporal_reachability(
+    py: Python,
+    graph: &graph::PyGraph,
+    source: usize,
+    time_fn: PyObject,
+    start_time: Option<f64>,
+    end_time: Option<f64>,
+) -> PyResult<HashSet<usize>> {
+    Ok(earliest_arrival(
+        py,
+        &graph.graph,
+        source,
+        time_fn,
+        start_time,
+        end_time,
+        None,
+    )?
+    .into_keys()
+    .collect())
+}
+
+/// Return the nodes of a temporal :class:`~rustworkx.PyDiGraph` object
+/// that can be reached from a node with a time-respecting path.
+///
+/// See :func:`~rustworkx.digraph_earliest_arrival_times` for how the edge
+/// times are used.
+///
+/// :param PyDiGraph graph: The input graph to use
+/// :param int source: The node index to start the search from
+/// :param time_fn: A callable that will take in an edge's data object and
+///     return either a single float timestamp or a ``(departure, arrival)``
+///     tuple.
+/// :param float start_time: The time the search leaves ``source``. If not
+///     specified all the edges out of ``source`` can be used.
+/// :param float end_time: The latest allowed arrival time. If not
+///     specified there is no limit.
+///
+/// :returns: A set of the indices of the nodes temporally reachable from
+///     ``source``, not including ``source``
+/// :rtype: set
+/// :raises ValueError: when an edge time is NaN or an arrival time is
+///     earlier than its departure time
+#[pyfunction]
+#[pyo3(
+    signature=(graph, source, time_fn, start_time=None, end_time=None),
+    text_signature = "(graph, source, time_fn, /, start_time=None, end_time=None)"
+)]
+pub fn digraph_temporal_reachability(
+    py: Python,
+    graph: &digraph::PyDiGraph,
+    source: usize,
+    time_fn: PyObject,
+    start_time: Option<f64>,
+    end_time: Option<f64>,
+) -> PyResult<HashSet<usize>> {
+    Ok(earliest_arrival(
+        py,
+        &graph.graph,
+        source,
+        time_fn,
+        start_time,
+        end_time,
+        None,
+    )?
+    .into_keys()
+    .collect())
+}
+
+/// Return the snapshot of a temporal :class:`~rustworkx.PyGraph` object at
+/// a given time.
+///
+/// The snapshot is a copy of the graph with only the edges whose
+/// ``(departure, arrival)`` interval intersects the time window
+/// ``[start_time, end_time]``. All the nodes are kept, with the same
+/// indices as in the input graph.
+///
+/// :param PyGraph graph: The input graph to use
+/// :param time_fn: A callable that will take in an edge's data object and
+///     return either a single float timestamp or a ``(departure, arrival)``
+///     tuple.
+/// :param float start_time: The time of the snapshot, or the start of the
+///     time window
+/// :param float end_time: The end of the time window. If not specified it
+///     is ``start_time``.
+///
+/// :returns: The snapshot of the graph
+/// :rtype: PyGraph
+/// :raises ValueError: when an edge time is NaN, an arrival time is
+///     earlier than its departure time or ``end_time`` is earlier than
+///     ``start_time``
+#[pyfunction]
+#[pyo3(
+    signature=(graph, time_fn, start_time, end_time=None),
+    text_signature = "(graph, time_fn, start_time, /, end_time=None)"
+)]
+pub fn graph_temporal_snapshot(
+    py: Python,
+    graph: &graph::PyGraph,
+    time_fn: PyObject,
+    start_time: f64,
+    end_time: Option<f64>,
+) -> PyResult<graph::PyGraph> {
+    let edges = snapshot_edges(py, &graph.graph, time_fn, start_time, end_time)?;
+    let mut out_graph = graph.clone();
+    for edge in graph.graph.edge_indices() {
+        if !edges.contains(&edge) {
+            out_graph.graph.remove_edge(edge);
+        }
+    }
+    Ok(out_graph)
+}
+
+/// Return the snapshot of a temporal :class:`~rustworkx.PyDiGraph` object
+/// at a given time.
+///
+/// The snapshot is a copy of the graph with only the edges whose
+/// ``(departure, arrival)`` interval intersects the time window
+/// ``[start_time, end_time]``. All the nodes are kept, with the same
+/// indices as in the input graph.
+///
+/// :param PyDiGraph graph: The input graph to use
+/// :param time_fn: A callable that will take in an edge's data object and
+///     return either a single float timestamp or a ``(departure, arrival)``
+///     tuple.
+/// :param float start_time: The time of the snapshot, or the start of the
+///     time window
+/// :param float end_time: The end of the time window. If not specified it
+///     is ``start_time``.
+///
+/// :returns: The snapshot of the graph
+/// :rtype: PyDiGraph
+/// :raises ValueError: when an edge time is NaN, an arrival time is
+///     earlier than its departure time or ``end_time`` is earlier than
+///     ``start_time``
+#[pyfunction]
+#[pyo3(
+    signature=(graph, time_fn, start_time, end_time=None),
+    text_signature = "(graph, time_fn, start_time, /, end_time=None)"
+)]
+pub fn digraph_temporal_snapshot(
+    py: Python,
+    graph: &digraph::PyDiGraph,
+    time_fn: PyObject,
+    start_time: f64,
+    end_time: Option<f64>,
+) -> PyResult<digraph::PyDiGraph> {
+    let edges = snapshot_edges(py, &graph.graph, time_fn, start_time, end_time)?;
+    let mut out_graph = graph.clone();
+    for edge in graph.graph.edge_indices() {
+        if !edges.contains(&edge) {
+            out_graph.graph.remove_edge(edge);
+        }
+    }
+    Ok(out_graph)
+}
@@ -10,108 +10,31 @@
 // License for the specific language governing permissions and limitations
 // under the License.
 
+use crate::graph_product::{combine_edge_weights, combine_node_weights, product_node_map};
 use crate::iterators::ProductNodeMap;
-use crate::{digraph, graph, StablePyGraph};
+use crate::{digraph, graph};
 
-use hashbrown::HashMap;
+use petgraph::algo;
 
-use petgraph::visit::{EdgeRef, IntoEdgeReferences};
-use petgraph::{algo, EdgeType};
+use rustworkx_core::graph_product::tensor_product as core_tensor_product;
 
 use pyo3::prelude::*;
 use pyo3::Python;
 
-fn tensor_product<Ty: EdgeType>(
-    py: Python,
-    first: &StablePyGraph<Ty>,
-    second: &StablePyGraph<Ty>,
-    undirected: bool,
-) -> (StablePyGraph<Ty>, ProductNodeMap) {
-    let num_nodes = first.node_count() * second.node_count();
-    let mut num_edges = first.edge_count() * second.edge_count();
-
-    if undirected {
-        num_edges *= 2;
-    }
-
-    let mut final_graph = StablePyGraph::<Ty>::with_capacity(num_nodes, num_edges);
-    let mut hash_nodes = HashMap::with_capacity(num_nodes);
-
-    for x in first.node_indices() {
-        for y in second.node_indices() {
-            let weight_x = &first[x];
-            let weight_y = &second[y];
-            let n0 = final_graph.add_node((weight_x, weight_y).into_py(py));
-            hash_nodes.insert((x, y), n0);
-        }
-    }
-
-    for edge_first in first.edge_references() {
-        for edge_second in second.edge_references() {
-            let source = hash_nodes
-                .get(&(edge_first.source(), edge_second.source()))
-                .unwrap();
-
-            let target = hash_nodes
-                .get(&(edge_first.target(), edge_second.target()))
-                .unwrap();
-
-            final_graph.add_edge(
-                *source,
-                *target,
-                (
-                    edge_first.weight().clone_ref(py),
-                    edge_second.weight().clone_ref(py),
-                )
-                    .into_py(py),
-            );
-        }
-    }
-    if undirected {
-        for edge_first in first.edge_references() {
-            for edge_second in second.edge_references() {
-                if edge_first.source() == edge_first.target()
-                    || edge_second.source() == edge_second.target()
-                {
-                    continue;
-                }
-
-                let source = hash_nodes
-                    .get(&(edge_first.source(), edge_second.target()))
-                    .unwrap();
-
-                let target = hash_nodes
-                    .get(&(edge_first.target(), edge_second.source()))
-                    .unwrap();
-
-                final_graph.add_edge(
-                    *source,
-                    *target,
-                    (
-                        edge_first.weight().clone_ref(py),
-                        edge_second.weight().clone_ref(py),
-                    )
-                        .into_py(py),
-                );
-            }
-        }
-    }
-
-    let out_node_map = ProductNodeMap {
-        node_map: hash_nodes
-            .into_iter()
-            .map(|((x, y), n)| ((x.index(), y.index()), n.index()))
-            .collect(),
-    };
-
-    (final_graph, out_node_map)
-}
-
 /// Return a new PyGraph by forming the tensor product from two input
 /// PyGraph objects
 ///
 /// :param PyGraph first: The first undirected graph object
 /// :param PyGraph second: The second undirected graph object
+/// :param node_fn: An optional callable that will be passed the data
+///     payloads of a node of ``first`` and a node of ``second`` and is
+///     expected to return the data payload of the corresponding node of the
+///     product. By default the payload is a tuple of the two payloads.
+/// :param edge_fn: An optional callable that will be passed the data
+///     payloads of the edges of ``first`` and ``second`` an edge of the
+///     product is formed from and is expected to return the data payload of
+///     that edge. By default the payload is a tuple of the two
+///     payloads.
 ///
 /// :returns: A new PyGraph object that is the tensor product of ``first``
 ///     and ``second``.
@@ -137,23 +60,33 @@ fn tensor_product<Ty: EdgeType>(
 ///   graph_product, _ = rustworkx.graph_tensor_product(graph_1, graph_2)
 ///   mpl_draw(graph_product)
 #[pyfunction()]
-#[pyo3(text_signature = "(first, second, /)")]
+#[pyo3(
+    signature=(first, second, /, node_fn=None, edge_fn=None),
+    text_signature = "(first, second, /, node_fn=None, edge_fn=None)"
+)]
 pub fn graph_tensor_product(
     py: Python,
     first: &graph::PyGraph,
     second: &graph::PyGraph,
-) -> (graph::PyGraph, ProductNodeMap) {
-    let (out_graph, out_node_map) = tensor_product(py, &first.graph, &second.graph, true);
-
-    (
+    node_fn: Option<PyObject>,
+    edge_fn: Option<PyObject>,
+) -> PyResult<(graph::PyGraph, ProductNodeMap)> {
+    let (out_graph, node_map) = core_tensor_product(
+        &first.graph,
+        &second.graph,
+        |a, b| combine_node_weights(py, &node_fn, a, b),
+        |edge| combine_edge_weights(py, &edge_fn, edge),
+    )?;
+
+    Ok((
         graph::PyGraph {
             graph: out_graph,
             multigraph: true,
             node_removed: false,
             attrs: py.None(),
         },
-        out_node_map,
-    )
+        product_node_map(node_map),
+    ))
 }
 
 /// Return a new PyDiGraph by forming the tensor product from two input
@@ -161,6 +94,15 @@ pub fn graph_tensor_product(
 ///
 /// :param PyDiGraph first: The first undirected graph object
 /// :param PyDiGraph second: The second undirected graph object
+/// :param node_fn: An optional callable that will be passed the data
+///     payloads of a node of ``first`` and a node of ``second`` and is
+///     expected to return the data payload of the corresponding node of the
+///     product. By default the payload is a tuple of the two payloads.
+/// :param edge_fn: An optional callable that will be passed the data
+///     payloads of the edges of ``first`` and ``second`` an edge of the
+///     product is formed from and is expected to return the data payload of
+///     that edge. By default the payload is a tuple of the two
+///     payloads.
 ///
 /// :returns: A new PyDiGraph object that is the tensor product of ``first``
 ///     and ``second``.
@@ -186,15 +128,25 @@ pub fn graph_tensor_product(
 ///   graph_product, _ = rustworkx.digraph_tensor_product(graph_1, graph_2)
 ///   mpl_draw(graph_product)
 #[pyfunction()]
-#[pyo3(text_signature = "(first, second, /)")]
+#[pyo3(
+    signature=(first, second, /, node_fn=None, edge_fn=None),
+    text_signature = "(first, second, /, node_fn=None, edge_fn=None)"
+)]
 pub fn digraph_tensor_product(
     py: Python,
     first: &digraph::PyDiGraph,
     second: &digraph::PyDiGraph,
-) -> (digraph::PyDiGraph, ProductNodeMap) {
-    let (out_graph, out_node_map) = tensor_product(py, &first.graph, &second.graph, false);
-
-    (
+    node_fn: Option<PyObject>,
+    edge_fn: Option<PyObject>,
+) -> PyResult<(digraph::PyDiGraph, ProductNodeMap)> {
+    let (out_graph, node_map) = core_tensor_product(
+        &first.graph,
+        &second.graph,
+        |a, b| combine_node_weights(py, &node_fn, a, b),
+        |edge| combine_edge_weights(py, &edge_fn, edge),
+    )?;
+
+    Ok((
         digraph::PyDiGraph {
             graph: out_graph,
             cycle_state: algo::DfsSpace::default(),
@@ -203,6 +155,6 @@ pub fn digraph_tensor_product(
             multigraph: true,
             attrs: py.None(),
         },
-        out_node_map,
-    )
+        product_node_map(node_map),
+    ))
 }
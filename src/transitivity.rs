@@ -18,7 +18,7 @@ use pyo3::prelude::*;
 use petgraph::graph::NodeIndex;
 use rayon::prelude::*;
 
-fn _graph_triangles(graph: &graph::PyGraph, node: usize) -> (usize, usize) {
+pub(crate) fn _graph_triangles(graph: &graph::PyGraph, node: usize) -> (usize, usize) {
     let mut triangles: usize = 0;
 
     let index = NodeIndex::new(node);
@@ -86,7 +86,7 @@ pub fn graph_transitivity(graph: &graph::PyGraph) -> f64 {
     }
 }
 
-fn _digraph_triangles(graph: &digraph::PyDiGraph, node: usize) -> (usize, usize) {
+pub(crate) fn _digraph_triangles(graph: &digraph::PyDiGraph, node: usize) -> (usize, usize) {
     let mut triangles: usize = 0;
 
     let index = NodeIndex::new(node);
@@ -35,6 +35,7 @@ use pyo3::prelude::*;
 use pyo3::Python;
 
 use petgraph::graph::NodeIndex;
+use petgraph::visit::EdgeRef;
 
 use crate::iterators::EdgeList;
 
@@ -686,7 +687,7 @@ pub fn digraph_dijkstra_search(
     dijkstra_search(
         &graph.graph,
         starts,
-        |e| edge_cost_fn.call(py, e.weight()),
+        |e| edge_cost_fn.call_edge(py, e.id(), e.weight()),
         |event| dijkstra_handler(py, &visitor, event),
     )??;
 
@@ -767,7 +768,7 @@ pub fn graph_dijkstra_search(
     dijkstra_search(
         &graph.graph,
         starts,
-        |e| edge_cost_fn.call(py, e.weight()),
+        |e| edge_cost_fn.call_edge(py, e.id(), e.weight()),
         |event| dijkstra_handler(py, &visitor, event),
     )??;
 
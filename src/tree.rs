@@ -12,7 +12,9 @@
 
 use std::cmp::Ordering;
 
-use super::{graph, weight_callable};
+use hashbrown::HashMap;
+
+use super::{digraph, graph, is_valid_weight, weight_callable, InvalidNode, NullGraph};
 
 use pyo3::exceptions::PyValueError;
 use pyo3::prelude::*;
@@ -25,7 +27,17 @@ use petgraph::visit::{IntoEdgeReferences, NodeIndexable};
 
 use rayon::prelude::*;
 
-use crate::iterators::WeightedEdgeList;
+use rustworkx_core::dictmap::*;
+
+use crate::iterators::{NodeIndices, WeightedEdgeList};
+
+use rustworkx_core::spanning_tree::degree_constrained_spanning_tree as core_degree_constrained_spanning_tree;
+use rustworkx_core::spanning_tree::greedy_spanner as core_greedy_spanner;
+use rustworkx_core::spanning_tree::k_smallest_spanning_trees as core_k_smallest_spanning_trees;
+use rustworkx_core::spanning_tree::minimum_spanning_arborescence as core_arborescence;
+use rustworkx_core::spanning_tree::minimum_spanning_edges as core_minimum_spanning_edges;
+use rustworkx_core::spanning_tree::random_spanning_tree as core_random_spanning_tree;
+use rustworkx_core::tree::{self as core_tree, TreeError};
 
 /// Find the edges in the minimum spanning tree or forest of a graph
 /// using Kruskal's algorithm.
@@ -45,18 +57,54 @@ use crate::iterators::WeightedEdgeList;
 ///     to cast the edge object as a float as the weight.
 /// :param float default_weight: If ``weight_fn`` isn't specified this optional
 ///     float value will be used for the weight/cost of each edge.
+/// :param int parallel_threshold: The number of edges in the graph at which
+///     a multithreaded implementation of Borůvka's algorithm is used instead
+///     of Kruskal's algorithm. If the function will be running in parallel the
+///     env var ``RAYON_NUM_THREADS`` can be used to adjust how many threads
+///     will be used. The default value is 100000
 ///
 /// :returns: The :math:`N - |c|` edges of the Minimum Spanning Tree (or Forest, if :math:`|c| > 1`)
 ///     where :math:`N` is the number of nodes and :math:`|c|` is the number of connected components of the graph
 /// :rtype: WeightedEdgeList
 #[pyfunction]
-#[pyo3(signature=(graph, weight_fn=None, default_weight=1.0), text_signature = "(graph, weight_fn=None, default_weight=1.0)")]
+#[pyo3(
+    signature=(graph, weight_fn=None, default_weight=1.0, parallel_threshold=100000),
+    text_signature = "(graph, weight_fn=None, default_weight=1.0, parallel_threshold=100000)"
+)]
 pub fn minimum_spanning_edges(
     py: Python,
     graph: &graph::PyGraph,
     weight_fn: Option<PyObject>,
     default_weight: f64,
+    parallel_threshold: usize,
 ) -> PyResult<WeightedEdgeList> {
+    if graph.graph.edge_count() >= parallel_threshold {
+        let edges = core_minimum_spanning_edges(
+            &graph.graph,
+            |edge| -> PyResult<f64> {
+                let weight = weight_callable(py, &weight_fn, edge.weight(), default_weight)?;
+                if weight.is_nan() {
+                    return Err(PyValueError::new_err("NaN found as an edge weight"));
+                }
+                Ok(weight)
+            },
+            parallel_threshold,
+        )?;
+        return Ok(WeightedEdgeList {
+            edges: edges
+                .into_iter()
+                .map(|edge| {
+                    let (source, target) = graph.graph.edge_endpoints(edge).unwrap();
+                    (
+                        source.index(),
+                        target.index(),
+                        graph.graph.edge_weight(edge).unwrap().clone_ref(py),
+                    )
+                })
+                .collect(),
+        });
+    }
+
     let mut subgraphs = UnionFind::<usize>::new(graph.graph.node_bound());
 
     let mut edge_list: Vec<(f64, EdgeReference<PyObject>)> =
@@ -89,6 +137,164 @@ pub fn minimum_spanning_edges(
     Ok(WeightedEdgeList { edges: answer })
 }
 
+/// Sample a random spanning tree or forest of a graph
+/// using Wilson's algorithm.
+///
+/// The spanning tree is built from loop-erased random walks where each step
+/// follows an incident edge with probability proportional to its weight. This
+/// samples each spanning tree with probability proportional to the product of
+/// its edge weights, so with equal weights (the default) every spanning tree
+/// of the graph is equally likely. If the graph is disconnected a random
+/// spanning forest is returned, with a spanning tree sampled independently
+/// for each connected component. Edges with a weight of ``0`` are never
+/// included in the output.
+///
+/// :param PyGraph graph: Undirected graph
+/// :param int seed: An optional seed to use for the random number generator
+/// :param weight_fn: A callable object (function, lambda, etc) which
+///     will be passed the edge object and expected to return a non-negative
+///     ``float``. For example::
+///
+///         random_spanning_tree(graph, weight_fn=float)
+///
+///     to cast the edge object as a float as the weight.
+/// :param float default_weight: If ``weight_fn`` isn't specified this optional
+///     float value will be used for the weight of each edge.
+///
+/// :returns: The :math:`N - |c|` edges of the sampled spanning tree (or forest,
+///     if :math:`|c| > 1`) where :math:`N` is the number of nodes and
+///     :math:`|c|` is the number of connected components of the graph
+/// :rtype: WeightedEdgeList
+/// :raises ValueError: when an edge weight with NaN or negative value
+///     is provided.
+///
+/// .. jupyter-execute::
+///
+///   import rustworkx as rx
+///
+///   graph = rx.generators.grid_graph(3, 3)
+///   tree = rx.random_spanning_tree(graph, seed=42)
+///   print(tree)
+#[pyfunction]
+#[pyo3(
+    signature=(graph, seed=None, weight_fn=None, default_weight=1.0),
+    text_signature = "(graph, /, seed=None, weight_fn=None, default_weight=1.0)"
+)]
+pub fn random_spanning_tree(
+    py: Python,
+    graph: &graph::PyGraph,
+    seed: Option<u64>,
+    weight_fn: Option<PyObject>,
+    default_weight: f64,
+) -> PyResult<WeightedEdgeList> {
+    let edges = core_random_spanning_tree(
+        &graph.graph,
+        |edge| -> PyResult<f64> {
+            is_valid_weight(weight_callable(
+                py,
+                &weight_fn,
+                edge.weight(),
+                default_weight,
+            )?)
+        },
+        seed,
+    )?;
+    Ok(WeightedEdgeList {
+        edges: edges
+            .into_iter()
+            .map(|edge| {
+                let (source, target) = graph.graph.edge_endpoints(edge).unwrap();
+                (
+                    source.index(),
+                    target.index(),
+                    graph.graph.edge_weight(edge).unwrap().clone_ref(py),
+                )
+            })
+            .collect(),
+    })
+}
+
+/// Find the ``k`` spanning trees of a graph with the smallest total weight.
+///
+/// The spanning trees are enumerated in order of nondecreasing total weight
+/// by repeatedly partitioning the set of spanning trees on which edges are
+/// forced into and excluded from each tree, as described in [Sorensen2005]_.
+/// This is useful for sensitivity analysis of a network design, where the
+/// trees that are close to optimal are of interest alongside the minimum
+/// spanning tree. If the graph is disconnected the spanning forests with the
+/// smallest total weight are found instead. Parallel edges are treated as
+/// distinct edges.
+///
+/// :param PyGraph graph: Undirected graph
+/// :param int k: The maximum number of spanning trees to return
+/// :param weight_fn: A callable object (function, lambda, etc) which
+///     will be passed the edge object and expected to return a ``float``.
+///     For example::
+///
+///         k_smallest_spanning_trees(graph, 3, weight_fn=float)
+///
+///     to cast the edge object as a float as the weight.
+/// :param float default_weight: If ``weight_fn`` isn't specified this optional
+///     float value will be used for the weight of each edge.
+///
+/// :returns: A list of up to ``k`` tuples of the total weight and the edges
+///     of a spanning tree, in nondecreasing order of total weight. Fewer than
+///     ``k`` trees are returned if the graph has fewer than ``k`` spanning
+///     trees.
+/// :rtype: list[tuple[float, WeightedEdgeList]]
+/// :raises ValueError: when an edge weight with NaN value is provided.
+///
+/// .. jupyter-execute::
+///
+///   import rustworkx as rx
+///
+///   graph = rx.PyGraph()
+///   graph.extend_from_weighted_edge_list([(0, 1, 1.0), (1, 2, 2.0), (2, 0, 3.0)])
+///   for weight, edges in rx.k_smallest_spanning_trees(graph, 3, weight_fn=float):
+///       print(weight, edges)
+///
+/// .. [Sorensen2005] Sörensen, Kenneth and Janssens, Gerrit K.
+///     "An algorithm to generate all spanning trees of a graph in order of
+///     increasing cost." Pesquisa Operacional 25 (2005): 219-229.
+///     <https://doi.org/10.1590/S0101-74382005000200004>
+#[pyfunction]
+#[pyo3(
+    signature=(graph, k, weight_fn=None, default_weight=1.0),
+    text_signature = "(graph, k, /, weight_fn=None, default_weight=1.0)"
+)]
+pub fn k_smallest_spanning_trees(
+    py: Python,
+    graph: &graph::PyGraph,
+    k: usize,
+    weight_fn: Option<PyObject>,
+    default_weight: f64,
+) -> PyResult<Vec<(f64, WeightedEdgeList)>> {
+    let trees = core_k_smallest_spanning_trees(&graph.graph, k, |edge| -> PyResult<f64> {
+        let weight = weight_callable(py, &weight_fn, edge.weight(), default_weight)?;
+        if weight.is_nan() {
+            return Err(PyValueError::new_err("NaN found as an edge weight"));
+        }
+        Ok(weight)
+    })?;
+    Ok(trees
+        .into_iter()
+        .map(|(weight, edges)| {
+            let edges = edges
+                .into_iter()
+                .map(|edge| {
+                    let (source, target) = graph.graph.edge_endpoints(edge).unwrap();
+                    (
+                        source.index(),
+                        target.index(),
+                        graph.graph.edge_weight(edge).unwrap().clone_ref(py),
+                    )
+                })
+                .collect();
+            (weight, WeightedEdgeList { edges })
+        })
+        .collect())
+}
+
 /// Find the minimum spanning tree or forest of a graph
 /// using Kruskal's algorithm.
 ///
@@ -108,6 +314,12 @@ pub fn minimum_spanning_edges(
 /// :param float default_weight: If ``weight_fn`` isn't specified this optional
 ///     float value will be used for the weight/cost of each edge.
 ///
+/// :param int parallel_threshold: The number of edges in the graph at which
+///     a multithreaded implementation of Borůvka's algorithm is used instead
+///     of Kruskal's algorithm. If the function will be running in parallel the
+///     env var ``RAYON_NUM_THREADS`` can be used to adjust how many threads
+///     will be used. The default value is 100000
+///
 /// :returns: A Minimum Spanning Tree (or Forest, if the graph is not connected).
 ///
 /// :rtype: PyGraph
@@ -116,17 +328,21 @@ pub fn minimum_spanning_edges(
 ///
 ///     The new graph will keep the same node indices, but edge indices might differ.
 #[pyfunction]
-#[pyo3(signature=(graph, weight_fn=None, default_weight=1.0), text_signature = "(graph, weight_fn=None, default_weight=1.0)")]
+#[pyo3(
+    signature=(graph, weight_fn=None, default_weight=1.0, parallel_threshold=100000),
+    text_signature = "(graph, weight_fn=None, default_weight=1.0, parallel_threshold=100000)"
+)]
 pub fn minimum_spanning_tree(
     py: Python,
     graph: &graph::PyGraph,
     weight_fn: Option<PyObject>,
     default_weight: f64,
+    parallel_threshold: usize,
 ) -> PyResult<graph::PyGraph> {
     let mut spanning_tree = (*graph).clone();
     spanning_tree.graph.clear_edges();
 
-    for edge in minimum_spanning_edges(py, graph, weight_fn, default_weight)?
+    for edge in minimum_spanning_edges(py, graph, weight_fn, default_weight, parallel_threshold)?
         .edges
         .iter()
     {
@@ -135,3 +351,440 @@ pub fn minimum_spanning_tree(
 
     Ok(spanning_tree)
 }
+
+/// Find the minimum spanning arborescence of a directed graph
+/// using the Chu-Liu/Edmonds algorithm.
+///
+/// A spanning arborescence is a directed spanning tree rooted at a single
+/// node in which every other node has exactly one incoming edge, and every
+/// node is reachable from the root. The minimum spanning arborescence is
+/// the spanning arborescence with the minimum total edge weight.
+///
+/// :param PyDiGraph graph: The directed graph to find the arborescence of
+/// :param int root: The node index to use as the root of the arborescence.
+///     If not specified the root that yields the arborescence with the
+///     minimum total weight among all possible roots will be used.
+/// :param weight_fn: A callable object (function, lambda, etc) which
+///     will be passed the edge object and expected to return a ``float``. This
+///     tells rustworkx/rust how to extract a numerical weight as a ``float``
+///     for edge object. For example::
+///
+///         minimum_spanning_arborescence(graph, weight_fn=float)
+///
+///     to cast the edge object as a float as the weight.
+/// :param float default_weight: If ``weight_fn`` isn't specified this optional
+///     float value will be used for the weight/cost of each edge.
+///
+/// :returns: A minimum spanning arborescence of the graph
+/// :rtype: PyDiGraph
+/// :raises InvalidNode: If ``root`` is not a valid node index
+/// :raises ValueError: If no spanning arborescence exists (with the given
+///     ``root`` if one is specified) or if an edge weight is ``NaN``
+///
+/// .. note::
+///
+///     The new graph will keep the same node indices, but edge indices might differ.
+///
+/// .. jupyter-execute::
+///
+///   import rustworkx as rx
+///
+///   graph = rx.PyDiGraph()
+///   graph.add_nodes_from(list(range(4)))
+///   graph.add_edges_from([
+///       (0, 1, 5.0), (0, 2, 1.0), (2, 1, 1.0), (1, 3, 2.0), (2, 3, 4.0), (3, 2, 1.0)
+///   ])
+///   arborescence = rx.minimum_spanning_arborescence(graph, root=0, weight_fn=float)
+///   print(sorted(arborescence.weighted_edge_list()))
+#[pyfunction]
+#[pyo3(
+    signature=(graph, root=None, weight_fn=None, default_weight=1.0),
+    text_signature = "(graph, /, root=None, weight_fn=None, default_weight=1.0)"
+)]
+pub fn minimum_spanning_arborescence(
+    py: Python,
+    graph: &digraph::PyDiGraph,
+    root: Option<usize>,
+    weight_fn: Option<PyObject>,
+    default_weight: f64,
+) -> PyResult<digraph::PyDiGraph> {
+    let root = match root {
+        Some(root) => {
+            let index = NodeIndex::new(root);
+            if !graph.graph.contains_node(index) {
+                return Err(InvalidNode::new_err(
+                    "The input index for 'root' is not a valid node index",
+                ));
+            }
+            Some(index)
+        }
+        None => None,
+    };
+    let edges = core_arborescence(&graph.graph, root, |edge| -> PyResult<f64> {
+        let weight = weight_callable(py, &weight_fn, edge.weight(), default_weight)?;
+        if weight.is_nan() {
+            return Err(PyValueError::new_err("NaN found as an edge weight"));
+        }
+        Ok(weight)
+    })?;
+    match edges {
+        Some(edges) => {
+            let mut arborescence = (*graph).clone();
+            arborescence.graph.clear_edges();
+            for edge in edges {
+                let (source, target) = graph.graph.edge_endpoints(edge).unwrap();
+                arborescence.graph.add_edge(
+                    source,
+                    target,
+                    graph.graph.edge_weight(edge).unwrap().clone_ref(py),
+                );
+            }
+            Ok(arborescence)
+        }
+        None => Err(PyValueError::new_err(
+            "No spanning arborescence exists for the input graph",
+        )),
+    }
+}
+
+/// Find a spanner of a graph with a given stretch factor using the greedy
+/// algorithm.
+///
+/// A :math:`t`-spanner of a graph is a subgraph containing all of its nodes in
+/// which the shortest path distance between any two nodes is at most
+/// :math:`t` times their shortest path distance in the original graph. The
+/// greedy algorithm from [Althofer1993]_ considers the edges in order of
+/// nondecreasing weight, and keeps an edge :math:`(u, v)` with weight
+/// :math:`w` only if the edges kept so far don't contain a path from :math:`u`
+/// to :math:`v` with a total weight of at most :math:`t \cdot w`. Spanners are
+/// useful for sparsifying a graph before running expensive distance based
+/// analyses, as all the distances are preserved up to the stretch factor.
+///
+/// :param PyGraph graph: Undirected graph
+/// :param float stretch: The stretch factor :math:`t`, this must be at least
+///     ``1``. Larger values result in sparser spanners.
+/// :param weight_fn: A callable object (function, lambda, etc) which
+///     will be passed the edge object and expected to return a non-negative
+///     ``float``. For example::
+///
+///         greedy_spanner(graph, 2.0, weight_fn=float)
+///
+///     to cast the edge object as a float as the weight.
+/// :param float default_weight: If ``weight_fn`` isn't specified this optional
+///     float value will be used for the weight of each edge.
+///
+/// :returns: The edges kept in the spanner
+/// :rtype: WeightedEdgeList
+/// :raises ValueError: when ``stretch`` is less than ``1`` or when an edge
+///     weight with NaN or negative value is provided.
+///
+/// .. jupyter-execute::
+///
+///   import rustworkx as rx
+///
+///   graph = rx.generators.complete_graph(6)
+///   spanner = rx.greedy_spanner(graph, 2.0)
+///   print(spanner)
+///
+/// .. [Althofer1993] Althöfer, Ingo, et al. "On sparse spanners of weighted
+///     graphs." Discrete & Computational Geometry 9.1 (1993): 81-100.
+///     <https://doi.org/10.1007/BF02189308>
+#[pyfunction]
+#[pyo3(
+    signature=(graph, stretch, weight_fn=None, default_weight=1.0),
+    text_signature = "(graph, stretch, /, weight_fn=None, default_weight=1.0)"
+)]
+pub fn greedy_spanner(
+    py: Python,
+    graph: &graph::PyGraph,
+    stretch: f64,
+    weight_fn: Option<PyObject>,
+    default_weight: f64,
+) -> PyResult<WeightedEdgeList> {
+    if stretch.is_nan() || stretch < 1. {
+        return Err(PyValueError::new_err(
+            "The stretch factor must be greater than or equal to 1",
+        ));
+    }
+    let edges = core_greedy_spanner(&graph.graph, stretch, |edge| -> PyResult<f64> {
+        is_valid_weight(weight_callable(
+            py,
+            &weight_fn,
+            edge.weight(),
+            default_weight,
+        )?)
+    })?;
+    Ok(WeightedEdgeList {
+        edges: edges
+            .into_iter()
+            .map(|edge| {
+                let (source, target) = graph.graph.edge_endpoints(edge).unwrap();
+                (
+                    source.index(),
+                    target.index(),
+                    graph.graph.edge_weight(edge).unwrap().clone_ref(py),
+                )
+            })
+            .collect(),
+    })
+}
+
+#[derive(FromPyObject)]
+pub enum DegreeBound {
+    Uniform(usize),
+    PerNode(HashMap<usize, usize>),
+}
+
+/// Find a spanning tree of a graph with a small total weight whose nodes
+/// don't exceed a maximum degree
+///
+/// Finding a minimum spanning tree with degree bounds is NP-hard, so this
+/// function uses a heuristic. It first runs Kruskal's algorithm, skipping the
+/// edges at nodes that already reached their bound, then joins any trees left
+/// over by exchanging edges at nodes without room. Finally it repeatedly
+/// replaces a tree edge with a lighter edge that closes a cycle through it,
+/// as long as the degree bounds are respected, until no exchange reduces the
+/// total weight or ``max_passes`` passes over the edges were made.
+///
+/// If the graph isn't connected a spanning forest with one tree per connected
+/// component is found instead. Self-loops are ignored.
+///
+/// :param PyGraph graph: The graph to find a spanning tree of
+/// :param max_degree: The largest degree of the nodes in the spanning tree.
+///     Either an ``int`` used for every node, or a ``dict`` mapping node
+///     indices to their largest degree in which case the nodes that are not
+///     in the ``dict`` have no bound.
+/// :param weight_fn: A callable object (function, lambda, etc) which
+///     will be passed the edge object and expected to return a ``float``. This
+///     tells rustworkx/rust how to extract a numerical weight as a ``float``
+///     for edge object. For example::
+///
+///         degree_constrained_spanning_tree(graph, 3, weight_fn=float)
+///
+///     to cast the edge object as a float as the weight.
+/// :param float default_weight: If ``weight_fn`` isn't specified this optional
+///     float value will be used for the weight/cost of each edge.
+/// :param int max_passes: The maximum number of passes of edge exchanges.
+///     Defaults to 100.
+///
+/// :returns: A spanning tree (or forest, if the graph is not connected) of
+///     the graph that respects the degree bounds
+/// :rtype: PyGraph
+/// :raises ValueError: If no spanning tree respecting the degree bounds was
+///     found, or when an edge weight with NaN or negative value is provided.
+///
+/// .. note::
+///
+///     The new graph will keep the same node indices, but edge indices might differ.
+///
+/// .. jupyter-execute::
+///
+///   import rustworkx as rx
+///
+///   graph = rx.generators.star_graph(5)
+///   graph.add_edges_from_no_data([(1, 2), (2, 3), (3, 4)])
+///   tree = rx.degree_constrained_spanning_tree(graph, 2)
+///   print(sorted(tree.edge_list()))
+#[pyfunction]
+#[pyo3(
+    signature=(graph, max_degree, weight_fn=None, default_weight=1.0, max_passes=100),
+    text_signature = "(graph, max_degree, /, weight_fn=None, default_weight=1.0, max_passes=100)"
+)]
+pub fn degree_constrained_spanning_tree(
+    py: Python,
+    graph: &graph::PyGraph,
+    max_degree: DegreeBound,
+    weight_fn: Option<PyObject>,
+    default_weight: f64,
+    max_passes: usize,
+) -> PyResult<graph::PyGraph> {
+    let edges = core_degree_constrained_spanning_tree(
+        &graph.graph,
+        |node| match &max_degree {
+            DegreeBound::Uniform(bound) => *bound,
+            DegreeBound::PerNode(bounds) => {
+                bounds.get(&node.index()).copied().unwrap_or(usize::MAX)
+            }
+        },
+        |edge| -> PyResult<f64> {
+            is_valid_weight(weight_callable(
+                py,
+                &weight_fn,
+                edge.weight(),
+                default_weight,
+            )?)
+        },
+        max_passes,
+    )?
+    .ok_or_else(|| {
+        PyValueError::new_err("No spanning tree respecting the degree bounds was found")
+    })?;
+    let mut spanning_tree = (*graph).clone();
+    spanning_tree.graph.clear_edges();
+    for edge in edges {
+        let (source, target) = graph.graph.edge_endpoints(edge).unwrap();
+        spanning_tree.add_edge(
+            source.index(),
+            target.index(),
+            graph.graph.edge_weight(edge).unwrap().clone_ref(py),
+        )?;
+    }
+    Ok(spanning_tree)
+}
+
+fn tree_error(err: TreeError, root: Option<usize>) -> PyErr {
+    match err {
+        TreeError::NullGraph => NullGraph::new_err(err.to_string()),
+        TreeError::NotATree => PyValueError::new_err(err.to_string()),
+        TreeError::InvalidRoot => {
+            InvalidNode::new_err(format!("Node index {} is not in the graph", root.unwrap()))
+        }
+    }
+}
+
+/// Find the center of a tree
+///
+/// The center is the set of nodes with the smallest eccentricity, the
+/// largest distance to any other node. A tree has one center node or two
+/// adjacent ones. They're found by repeatedly removing all the leaves of
+/// the tree.
+///
+/// :param PyGraph graph: The tree to find the center of
+///
+/// :returns: The indices of the one or two center nodes, in ascending order
+/// :rtype: NodeIndices
+/// :raises NullGraph: If the graph has no nodes
+/// :raises ValueError: If the graph isn't a tree
+///
+/// .. jupyter-execute::
+///
+///   import rustworkx as rx
+///
+///   graph = rx.generators.path_graph(6)
+///   print(rx.tree_center(graph))
+#[pyfunction]
+#[pyo3(text_signature = "(graph, /)")]
+pub fn tree_center(graph: &graph::PyGraph) -> PyResult<NodeIndices> {
+    let center = core_tree::tree_center(&graph.graph).map_err(|err| tree_error(err, None))?;
+    Ok(NodeIndices {
+        nodes: center.into_iter().map(|node| node.index()).collect(),
+    })
+}
+
+/// Find the centroid of a tree
+///
+/// The centroid is the set of nodes whose removal leaves connected
+/// components with at most half of the nodes of the tree each. A tree has
+/// one centroid node or two adjacent ones.
+///
+/// :param PyGraph graph: The tree to find the centroid of
+///
+/// :returns: The indices of the one or two centroid nodes, in ascending order
+/// :rtype: NodeIndices
+/// :raises NullGraph: If the graph has no nodes
+/// :raises ValueError: If the graph isn't a tree
+///
+/// .. jupyter-execute::
+///
+///   import rustworkx as rx
+///
+///   graph = rx.generators.star_graph(5)
+///   graph.extend_from_edge_list([(4, 5), (5, 6), (6, 7)])
+///   print(rx.tree_center(graph), rx.tree_centroid(graph))
+#[pyfunction]
+#[pyo3(text_signature = "(graph, /)")]
+pub fn tree_centroid(graph: &graph::PyGraph) -> PyResult<NodeIndices> {
+    let centroid = core_tree::tree_centroid(&graph.graph).map_err(|err| tree_error(err, None))?;
+    Ok(NodeIndices {
+        nodes: centroid.into_iter().map(|node| node.index()).collect(),
+    })
+}
+
+/// Compute the centroid decomposition of a tree
+///
+/// The centroid decomposition picks a centroid of the tree as the root and
+/// then recursively decomposes each of the components left after removing
+/// it, making their centroids children of the root. The resulting centroid
+/// tree has a depth of at most :math:`\log_2(n) + 1`, and the path between
+/// any two nodes of the tree passes through their lowest common ancestor in
+/// the centroid tree, which is the basis of many divide and conquer
+/// algorithms on trees.
+///
+/// :param PyGraph graph: The tree to decompose
+///
+/// :returns: A dictionary mapping every node index to the index of its
+///     parent in the centroid tree, or ``None`` for its root. The nodes are
+///     in the order the centroids are picked, so the root is first.
+/// :rtype: dict
+/// :raises NullGraph: If the graph has no nodes
+/// :raises ValueError: If the graph isn't a tree
+///
+/// .. jupyter-execute::
+///
+///   import rustworkx as rx
+///
+///   graph = rx.generators.path_graph(7)
+///   print(rx.centroid_decomposition(graph))
+#[pyfunction]
+#[pyo3(text_signature = "(graph, /)")]
+pub fn centroid_decomposition(graph: &graph::PyGraph) -> PyResult<DictMap<usize, Option<usize>>> {
+    let decomposition =
+        core_tree::centroid_decomposition(&graph.graph).map_err(|err| tree_error(err, None))?;
+    Ok(decomposition
+        .into_iter()
+        .map(|(node, parent)| (node.index(), parent.map(|parent| parent.index())))
+        .collect())
+}
+
+/// Root a tree at one of its nodes
+///
+/// This finds the parent of every node and an Euler tour of the tree rooted
+/// at ``root``. The Euler tour lists the nodes in the order a depth-first
+/// traversal from the root visits them, where a node is listed again after
+/// returning from each of its children, so it has :math:`2n - 1` entries.
+/// Between the first occurrences of two nodes in the Euler tour, the node
+/// with the smallest depth is their lowest common ancestor. The children of
+/// a node are visited in ascending order of their indices.
+///
+/// :param PyGraph graph: The tree to root
+/// :param int root: The optional index of the node to root the tree at. If
+///     it's not specified the tree is rooted at its center, or at the center
+///     node with the smaller index if there are two (see
+///     :func:`~rustworkx.tree_center`).
+///
+/// :returns: A tuple of a dictionary mapping every node index to the index
+///     of its parent, or ``None`` for the root, in depth-first preorder, and
+///     the Euler tour
+/// :rtype: tuple[dict, NodeIndices]
+/// :raises NullGraph: If the graph has no nodes
+/// :raises ValueError: If the graph isn't a tree
+/// :raises InvalidNode: If ``root`` isn't a node of the graph
+///
+/// .. jupyter-execute::
+///
+///   import rustworkx as rx
+///
+///   graph = rx.generators.star_graph(4)
+///   parents, euler_tour = rx.root_tree(graph, root=1)
+///   print(parents)
+///   print(euler_tour)
+#[pyfunction]
+#[pyo3(signature = (graph, /, root=None), text_signature = "(graph, /, root=None)")]
+pub fn root_tree(
+    graph: &graph::PyGraph,
+    root: Option<usize>,
+) -> PyResult<(DictMap<usize, Option<usize>>, NodeIndices)> {
+    let rooted = core_tree::root_tree(&graph.graph, root.map(NodeIndex::new))
+        .map_err(|err| tree_error(err, root))?;
+    let parents = rooted
+        .parents
+        .into_iter()
+        .map(|(node, parent)| (node.index(), parent.map(|parent| parent.index())))
+        .collect();
+    Ok((
+        parents,
+        NodeIndices {
+            nodes: rooted.euler_tour.iter().map(|node| node.index()).collect(),
+        },
+    ))
+}
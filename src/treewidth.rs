@@ -0,0 +1,179 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+use crate::graph::PyGraph;
+use crate::iterators::NodeIndices;
+use crate::StablePyGraph;
+
+use petgraph::graph::NodeIndex;
+use petgraph::Undirected;
+use rustworkx_core::treewidth::{self, TreewidthHeuristic};
+
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pyo3::Python;
+
+fn decomposition_graph(py: Python, decomposition: StablePyGraph<Undirected>) -> PyGraph {
+    PyGraph {
+        graph: decomposition,
+        node_removed: false,
+        multigraph: false,
+        attrs: py.None(),
+    }
+}
+
+fn bag_weight(py: Python, bag: Vec<NodeIndex>) -> PyObject {
+    bag.into_iter()
+        .map(|node| node.index())
+        .collect::<Vec<usize>>()
+        .to_object(py)
+}
+
+fn heuristic_treewidth(
+    py: Python,
+    graph: &PyGraph,
+    heuristic: TreewidthHeuristic,
+) -> (usize, NodeIndices, PyGraph) {
+    let (width, ordering, decomposition) = treewidth::treewidth(
+        &graph.graph,
+        heuristic,
+        |bag| bag_weight(py, bag),
+        || py.None(),
+    );
+    (
+        width,
+        NodeIndices {
+            nodes: ordering.into_iter().map(|node| node.index()).collect(),
+        },
+        decomposition_graph(py, decomposition),
+    )
+}
+
+/// Compute an upper bound on the treewidth of a graph with the minimum degree
+/// heuristic.
+///
+/// The nodes of the graph are eliminated one at a time, always picking a node
+/// with the fewest remaining neighbors, and eliminating a node adds edges
+/// between all of its remaining neighbors. The tree decomposition built from
+/// this elimination ordering is the same as the one returned by
+/// :func:`~rustworkx.tree_decomposition`. Self-loops and parallel edges are
+/// ignored.
+///
+/// :param PyGraph graph: The graph to decompose.
+///
+/// :returns: A tuple of the width of the tree decomposition, the elimination
+///     ordering and the tree decomposition. The node at index ``i`` of the tree
+///     decomposition is the bag of the ``i``-th node of the elimination ordering,
+///     and its data payload is the sorted list of the node indices in the bag.
+/// :rtype: tuple[int, NodeIndices, PyGraph]
+///
+/// .. jupyter-execute::
+///
+///   import rustworkx as rx
+///
+///   graph = rx.generators.grid_graph(3, 3)
+///   width, ordering, decomposition = rx.treewidth_min_degree(graph)
+///   print(width)
+///   print(decomposition.nodes())
+#[pyfunction]
+#[pyo3(text_signature = "(graph, /)")]
+pub fn treewidth_min_degree(py: Python, graph: &PyGraph) -> (usize, NodeIndices, PyGraph) {
+    heuristic_treewidth(py, graph, TreewidthHeuristic::MinDegree)
+}
+
+/// Compute an upper bound on the treewidth of a graph with the minimum
+/// fill-in heuristic.
+///
+/// The nodes of the graph are eliminated one at a time, always picking a node
+/// whose elimination adds the fewest edges between its remaining neighbors.
+/// This is slower than :func:`~rustworkx.treewidth_min_degree` but often
+/// finds a smaller width. The tree decomposition built from this elimination
+/// ordering is the same as the one returned by
+/// :func:`~rustworkx.tree_decomposition`. Self-loops and parallel edges are
+/// ignored.
+///
+/// :param PyGraph graph: The graph to decompose.
+///
+/// :returns: A tuple of the width of the tree decomposition, the elimination
+///     ordering and the tree decomposition. The node at index ``i`` of the tree
+///     decomposition is the bag of the ``i``-th node of the elimination ordering,
+///     and its data payload is the sorted list of the node indices in the bag.
+/// :rtype: tuple[int, NodeIndices, PyGraph]
+///
+/// .. jupyter-execute::
+///
+///   import rustworkx as rx
+///
+///   graph = rx.generators.grid_graph(3, 3)
+///   width, ordering, decomposition = rx.treewidth_min_fill_in(graph)
+///   print(width)
+///   print(decomposition.nodes())
+#[pyfunction]
+#[pyo3(text_signature = "(graph, /)")]
+pub fn treewidth_min_fill_in(py: Python, graph: &PyGraph) -> (usize, NodeIndices, PyGraph) {
+    heuristic_treewidth(py, graph, TreewidthHeuristic::MinFillIn)
+}
+
+/// Build the tree decomposition of a graph given by an elimination ordering.
+///
+/// The tree decomposition has one bag for every node ``v`` of the graph,
+/// made of ``v`` and the neighbors it has when it is eliminated, where
+/// eliminating a node adds edges between all of its remaining neighbors. The
+/// bag of ``v`` is joined to the bag of the first of those neighbors in the
+/// ordering, and the bags of different connected components are joined so
+/// the decomposition is a tree. Self-loops and parallel edges are ignored.
+///
+/// :param PyGraph graph: The graph to decompose.
+/// :param list elimination_ordering: The order to eliminate the nodes in,
+///     which must contain every node index of ``graph`` exactly once.
+///
+/// :returns: A tuple of the width of the tree decomposition, which is the
+///     size of its largest bag minus one, and the tree decomposition. The node
+///     at index ``i`` of the tree decomposition is the bag of
+///     ``elimination_ordering[i]``, and its data payload is the sorted list of
+///     the node indices in the bag.
+/// :rtype: tuple[int, PyGraph]
+///
+/// :raises ValueError: If ``elimination_ordering`` isn't an ordering of the
+///     nodes of ``graph``.
+///
+/// .. jupyter-execute::
+///
+///   import rustworkx as rx
+///
+///   graph = rx.generators.cycle_graph(5)
+///   width, decomposition = rx.tree_decomposition(graph, [0, 1, 2, 3, 4])
+///   print(width)
+///   print(decomposition.nodes())
+#[pyfunction]
+#[pyo3(text_signature = "(graph, elimination_ordering, /)")]
+pub fn tree_decomposition(
+    py: Python,
+    graph: &PyGraph,
+    elimination_ordering: Vec<usize>,
+) -> PyResult<(usize, PyGraph)> {
+    let ordering: Vec<NodeIndex> = elimination_ordering
+        .into_iter()
+        .map(NodeIndex::new)
+        .collect();
+    match treewidth::tree_decomposition(
+        &graph.graph,
+        &ordering,
+        |bag| bag_weight(py, bag),
+        || py.None(),
+    ) {
+        Some((width, decomposition)) => Ok((width, decomposition_graph(py, decomposition))),
+        None => Err(PyValueError::new_err(
+            "elimination_ordering must contain every node of the graph exactly once",
+        )),
+    }
+}
@@ -10,6 +10,7 @@
 // License for the specific language governing permissions and limitations
 // under the License.
 
+use crate::iterators::{EdgeIndices, EdgeMap, NodeIndices, NodeMap};
 use crate::{digraph, find_node_by_weight, graph, StablePyGraph};
 
 use petgraph::stable_graph::NodeIndex;
@@ -17,8 +18,11 @@ use petgraph::visit::{EdgeRef, IntoEdgeReferences, NodeIndexable};
 use petgraph::{algo, EdgeType};
 
 use pyo3::prelude::*;
+use pyo3::types::PyDict;
 use pyo3::Python;
 
+use rustworkx_core::dictmap::*;
+
 #[derive(Copy, Clone)]
 enum Entry<T> {
     Merged(T),
@@ -34,21 +38,108 @@ fn extract<T>(x: Entry<T>) -> T {
     }
 }
 
+/// A report of how the nodes and edges of the input graphs of
+/// :func:`~rustworkx.union_with_mapping` map to the output graph.
+///
+/// ``first_nodes`` and ``second_nodes`` map the node indices of ``first`` and
+/// ``second`` to node indices in the output graph, ``first_edges`` and
+/// ``second_edges`` do the same for edge indices. A node or edge of
+/// ``second`` that was merged is mapped to the node or edge of ``first`` it
+/// was merged with, and its index is listed in ``merged_nodes`` or
+/// ``merged_edges``.
+#[pyclass(module = "rustworkx")]
+pub struct UnionMapping {
+    #[pyo3(get)]
+    first_nodes: NodeMap,
+    #[pyo3(get)]
+    second_nodes: NodeMap,
+    #[pyo3(get)]
+    first_edges: EdgeMap,
+    #[pyo3(get)]
+    second_edges: EdgeMap,
+    #[pyo3(get)]
+    merged_nodes: NodeIndices,
+    #[pyo3(get)]
+    merged_edges: EdgeIndices,
+}
+
+#[pymethods]
+impl UnionMapping {
+    fn __repr__(&self) -> String {
+        format!(
+            "UnionMapping(merged_nodes={:?}, merged_edges={:?})",
+            self.merged_nodes.nodes, self.merged_edges.edges
+        )
+    }
+}
+
+/// Find the node of ``first`` that a node of ``second`` is merged with, either
+/// the first node with an equal weight or, if ``key_fn`` is set, the lowest
+/// index node with an equal key.
+struct NodeMatcher<'a, Ty: EdgeType> {
+    first: &'a StablePyGraph<Ty>,
+    keys: Option<(PyObject, Bound<'a, PyDict>)>,
+}
+
+impl<'a, Ty: EdgeType> NodeMatcher<'a, Ty> {
+    fn new(
+        py: Python<'a>,
+        first: &'a StablePyGraph<Ty>,
+        key_fn: Option<PyObject>,
+    ) -> PyResult<Self> {
+        let keys = match key_fn {
+            Some(key_fn) => {
+                let keys = PyDict::new_bound(py);
+                for node in first.node_indices() {
+                    let key = key_fn.call1(py, (&first[node],))?;
+                    if !keys.contains(&key)? {
+                        keys.set_item(key, node.index())?;
+                    }
+                }
+                Some((key_fn, keys))
+            }
+            None => None,
+        };
+        Ok(NodeMatcher { first, keys })
+    }
+
+    fn find(&self, py: Python, weight: &PyObject) -> PyResult<Option<NodeIndex>> {
+        match &self.keys {
+            Some((key_fn, keys)) => {
+                let key = key_fn.call1(py, (weight,))?;
+                match keys.get_item(key)? {
+                    Some(index) => Ok(Some(NodeIndex::new(index.extract()?))),
+                    None => Ok(None),
+                }
+            }
+            None => find_node_by_weight(py, self.first, weight),
+        }
+    }
+}
+
 fn union<Ty: EdgeType>(
     py: Python,
     first: &StablePyGraph<Ty>,
     second: &StablePyGraph<Ty>,
     merge_nodes: bool,
     merge_edges: bool,
-) -> PyResult<StablePyGraph<Ty>> {
+    key_fn: Option<PyObject>,
+) -> PyResult<(StablePyGraph<Ty>, UnionMapping)> {
     let mut out_graph = first.clone();
+    let matcher = if merge_nodes {
+        Some(NodeMatcher::new(py, first, key_fn)?)
+    } else {
+        None
+    };
 
+    let mut merged_nodes: Vec<usize> = Vec::new();
     let mut node_map: Vec<Entry<NodeIndex>> = vec![Entry::None; second.node_bound()];
     for node in second.node_indices() {
         let weight = &second[node];
-        if merge_nodes {
-            if let Some(index) = find_node_by_weight(py, first, weight)? {
+        if let Some(matcher) = &matcher {
+            if let Some(index) = matcher.find(py, weight)? {
                 node_map[node.index()] = Entry::Merged(index);
+                merged_nodes.push(node.index());
                 continue;
             }
         }
@@ -63,12 +154,14 @@ fn union<Ty: EdgeType>(
             .is_truthy()
     };
 
+    let mut merged_edges: Vec<usize> = Vec::new();
+    let mut second_edges: DictMap<usize, usize> = DictMap::with_capacity(second.edge_count());
     for edge in second.edge_references() {
         let source = edge.source().index();
         let target = edge.target().index();
         let new_weight = edge.weight();
 
-        let mut found = false;
+        let mut found = None;
         if merge_edges {
             // if both endpoints were merged,
             // check if need to skip the edge as well.
@@ -77,21 +170,57 @@ fn union<Ty: EdgeType>(
             {
                 for edge in first.edges(new_source) {
                     if edge.target() == new_target && weights_equal(new_weight, edge.weight())? {
-                        found = true;
+                        found = Some(edge.id());
                         break;
                     }
                 }
             }
         }
 
-        if !found {
-            let new_source = extract(node_map[source]);
-            let new_target = extract(node_map[target]);
-            out_graph.add_edge(new_source, new_target, new_weight.clone_ref(py));
-        }
+        let index = match found {
+            Some(index) => {
+                merged_edges.push(edge.id().index());
+                index
+            }
+            None => {
+                let new_source = extract(node_map[source]);
+                let new_target = extract(node_map[target]);
+                out_graph.add_edge(new_source, new_target, new_weight.clone_ref(py))
+            }
+        };
+        second_edges.insert(edge.id().index(), index.index());
     }
 
-    Ok(out_graph)
+    let mapping = UnionMapping {
+        first_nodes: NodeMap {
+            node_map: first
+                .node_indices()
+                .map(|node| (node.index(), node.index()))
+                .collect(),
+        },
+        second_nodes: NodeMap {
+            node_map: second
+                .node_indices()
+                .map(|node| (node.index(), extract(node_map[node.index()]).index()))
+                .collect(),
+        },
+        first_edges: EdgeMap {
+            edge_map: first
+                .edge_indices()
+                .map(|edge| (edge.index(), edge.index()))
+                .collect(),
+        },
+        second_edges: EdgeMap {
+            edge_map: second_edges,
+        },
+        merged_nodes: NodeIndices {
+            nodes: merged_nodes,
+        },
+        merged_edges: EdgeIndices {
+            edges: merged_edges,
+        },
+    };
+    Ok((out_graph, mapping))
 }
 
 /// Return a new PyGraph by forming a union from two input PyGraph objects
@@ -119,21 +248,34 @@ fn union<Ty: EdgeType>(
 ///     ``second`` and ``first`` if the weights are equal. Default: ``False``.
 /// :param bool merge_edges: If set to ``True`` edges will be merged between
 ///     ``second`` and ``first`` if the weights are equal. Default: ``False``.
+/// :param key_fn: An optional callable that will be passed the data payload
+///     of each node and is expected to return a hashable key. If specified
+///     and ``merge_nodes`` is ``True``, a node of ``second`` is merged with
+///     the lowest index node of ``first`` that has an equal key instead of
+///     an equal weight.
 ///
 /// :returns: A new PyGraph object that is the union of ``second`` and
 ///     ``first``. It's worth noting the weight/data payload objects are
 ///     passed by reference from ``first`` and ``second`` to this new object.
 /// :rtype: PyGraph
 #[pyfunction]
-#[pyo3(signature=(first, second, merge_nodes=false, merge_edges=false), text_signature = "(first, second, /, merge_nodes=False, merge_edges=False)")]
+#[pyo3(signature=(first, second, merge_nodes=false, merge_edges=false, key_fn=None), text_signature = "(first, second, /, merge_nodes=False, merge_edges=False, key_fn=None)")]
 pub fn graph_union(
     py: Python,
     first: &graph::PyGraph,
     second: &graph::PyGraph,
     merge_nodes: bool,
     merge_edges: bool,
+    key_fn: Option<PyObject>,
 ) -> PyResult<graph::PyGraph> {
-    let out_graph = union(py, &first.graph, &second.graph, merge_nodes, merge_edges)?;
+    let (out_graph, _) = union(
+        py,
+        &first.graph,
+        &second.graph,
+        merge_nodes,
+        merge_edges,
+        key_fn,
+    )?;
 
     Ok(graph::PyGraph {
         graph: out_graph,
@@ -143,6 +285,80 @@ pub fn graph_union(
     })
 }
 
+/// Return a new PyGraph by forming a union from two input PyGraph objects,
+/// together with a report of where their nodes and edges ended up
+///
+/// This is the same as :func:`~rustworkx.graph_union`, but it also returns a
+/// :class:`~rustworkx.UnionMapping` that maps the node and edge indices of
+/// ``first`` and ``second`` to the indices in the output graph and lists the
+/// nodes and edges of ``second`` that were merged into ``first``. The output
+/// graph is a copy of ``first`` with the remaining nodes and edges of
+/// ``second`` added, so the indices of ``first`` are unchanged.
+///
+/// :param PyGraph first: The first undirected graph object
+/// :param PyGraph second: The second undirected graph object
+/// :param bool merge_nodes: If set to ``True`` nodes will be merged between
+///     ``second`` and ``first`` if the weights are equal. Default: ``False``.
+/// :param bool merge_edges: If set to ``True`` edges will be merged between
+///     ``second`` and ``first`` if the weights are equal. Default: ``False``.
+/// :param key_fn: An optional callable that will be passed the data payload
+///     of each node and is expected to return a hashable key. If specified
+///     and ``merge_nodes`` is ``True``, a node of ``second`` is merged with
+///     the lowest index node of ``first`` that has an equal key instead of
+///     an equal weight.
+///
+/// :returns: A tuple of the new PyGraph object that is the union of ``second``
+///     and ``first`` and the :class:`~rustworkx.UnionMapping` for it
+/// :rtype: tuple
+///
+/// .. jupyter-execute::
+///
+///   import rustworkx as rx
+///
+///   first = rx.PyGraph()
+///   first.add_nodes_from([("a", 1), ("b", 2)])
+///   first.add_edge(0, 1, None)
+///   second = rx.PyGraph()
+///   second.add_nodes_from([("b", 3), ("c", 4)])
+///   second.add_edge(0, 1, None)
+///   union, mapping = rx.graph_union_with_mapping(
+///       first, second, merge_nodes=True, key_fn=lambda node: node[0]
+///   )
+///   print(union.nodes())
+///   print(mapping.second_nodes)
+#[pyfunction]
+#[pyo3(
+    signature=(first, second, merge_nodes=false, merge_edges=false, key_fn=None),
+    text_signature = "(first, second, /, merge_nodes=False, merge_edges=False, key_fn=None)"
+)]
+pub fn graph_union_with_mapping(
+    py: Python,
+    first: &graph::PyGraph,
+    second: &graph::PyGraph,
+    merge_nodes: bool,
+    merge_edges: bool,
+    key_fn: Option<PyObject>,
+) -> PyResult<(graph::PyGraph, UnionMapping)> {
+    let (out_graph, mapping) = union(
+        py,
+        &first.graph,
+        &second.graph,
+        merge_nodes,
+        merge_edges,
+        key_fn,
+    )?;
+
+    Ok((
+        graph::PyGraph {
+            graph: out_graph,
+            node_removed: first.node_removed,
+            multigraph: true,
+            attrs: py.None(),
+        },
+        mapping,
+    ))
+}
+
 /// Return a new PyDiGraph by forming a union from two input PyDiGraph objects
 ///
 /// The algorithm in this function operates in three phases:
@@ -168,21 +384,34 @@ pub fn graph_union(
 ///     ``second`` and ``first`` if the weights are equal. Default: ``False``.
 /// :param bool merge_edges: If set to ``True`` edges will be merged between
 ///     ``second`` and ``first`` if the weights are equal. Default: ``False``.
+/// :param key_fn: An optional callable that will be passed the data payload
+///     of each node and is expected to return a hashable key. If specified
+///     and ``merge_nodes`` is ``True``, a node of ``second`` is merged with
+///     the lowest index node of ``first`` that has an equal key instead of
+///     an equal weight.
 ///
 /// :returns: A new PyDiGraph object that is the union of ``second`` and
 ///     ``first``. It's worth noting the weight/data payload objects are
 ///     passed by reference from ``first`` and ``second`` to this new object.
 /// :rtype: PyDiGraph
 #[pyfunction]
-#[pyo3(signature=(first, second, merge_nodes=false, merge_edges=false), text_signature = "(first, second, /, merge_nodes=False, merge_edges=False)")]
+#[pyo3(signature=(first, second, merge_nodes=false, merge_edges=false, key_fn=None), text_signature = "(first, second, /, merge_nodes=False, merge_edges=False, key_fn=None)")]
 pub fn digraph_union(
     py: Python,
     first: &digraph::PyDiGraph,
     second: &digraph::PyDiGraph,
     merge_nodes: bool,
     merge_edges: bool,
+    key_fn: Option<PyObject>,
 ) -> PyResult<digraph::PyDiGraph> {
-    let out_graph = union(py, &first.graph, &second.graph, merge_nodes, merge_edges)?;
+    let (out_graph, _) = union(
+        py,
+        &first.graph,
+        &second.graph,
+        merge_nodes,
+        merge_edges,
+        key_fn,
+    )?;
 
     Ok(digraph::PyDiGraph {
         graph: out_graph,
@@ -193,3 +422,79 @@ pub fn digraph_union(
         attrs: py.None(),
     })
 }
+
+/// Return a new PyDiGraph by forming a union from two input PyDiGraph objects,
+/// together with a report of where their nodes and edges ended up
+///
+/// This is the same as :func:`~rustworkx.digraph_union`, but it also returns a
+/// :class:`~rustworkx.UnionMapping` that maps the node and edge indices of
+/// ``first`` and ``second`` to the indices in the output graph and lists the
+/// nodes and edges of ``second`` that were merged into ``first``. The output
+/// graph is a copy of ``first`` with the remaining nodes and edges of
+/// ``second`` added, so the indices of ``first`` are unchanged.
+///
+/// :param PyDiGraph first: The first directed graph object
+/// :param PyDiGraph second: The second directed graph object
+/// :param bool merge_nodes: If set to ``True`` nodes will be merged between
+///     ``second`` and ``first`` if the weights are equal. Default: ``False``.
+/// :param bool merge_edges: If set to ``True`` edges will be merged between
+///     ``second`` and ``first`` if the weights are equal. Default: ``False``.
+/// :param key_fn: An optional callable that will be passed the data payload
+///     of each node and is expected to return a hashable key. If specified
+///     and ``merge_nodes`` is ``True``, a node of ``second`` is merged with
+///     the lowest index node of ``first`` that has an equal key instead of
+///     an equal weight.
+///
+/// :returns: A tuple of the new PyDiGraph object that is the union of ``second``
+///     and ``first`` and the :class:`~rustworkx.UnionMapping` for it
+/// :rtype: tuple
+///
+/// .. jupyter-execute::
+///
+///   import rustworkx as rx
+///
+///   first = rx.PyDiGraph()
+///   first.add_nodes_from([("a", 1), ("b", 2)])
+///   first.add_edge(0, 1, None)
+///   second = rx.PyDiGraph()
+///   second.add_nodes_from([("b", 3), ("c", 4)])
+///   second.add_edge(0, 1, None)
+///   union, mapping = rx.digraph_union_with_mapping(
+///       first, second, merge_nodes=True, key_fn=lambda node: node[0]
+///   )
+///   print(union.nodes())
+///   print(mapping.second_nodes)
+#[pyfunction]
+#[pyo3(
+    signature=(first, second, merge_nodes=false, merge_edges=false, key_fn=None),
+    text_signature = "(first, second, /, merge_nodes=False, merge_edges=False, key_fn=None)"
+)]
+pub fn digraph_union_with_mapping(
+    py: Python,
+    first: &digraph::PyDiGraph,
+    second: &digraph::PyDiGraph,
+    merge_nodes: bool,
+    merge_edges: bool,
+    key_fn: Option<PyObject>,
+) -> PyResult<(digraph::PyDiGraph, UnionMapping)> {
+    let (out_graph, mapping) = union(
+        py,
+        &first.graph,
+        &second.graph,
+        merge_nodes,
+        merge_edges,
+        key_fn,
+    )?;
+
+    Ok((
+        digraph::PyDiGraph {
+            graph: out_graph,
+            cycle_state: algo::DfsSpace::default(),
+            check_cycle: false,
+            node_removed: first.node_removed,
+            multigraph: true,
+            attrs: py.None(),
+        },
+        mapping,
+    ))
+}
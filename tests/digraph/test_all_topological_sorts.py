@@ -0,0 +1,69 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+
+import math
+import unittest
+
+import rustworkx
+
+
+class TestAllTopologicalSorts(unittest.TestCase):
+    def test_empty_graph(self):
+        graph = rustworkx.PyDiGraph()
+        self.assertEqual(list(rustworkx.all_topological_sorts(graph)), [[]])
+        self.assertEqual(rustworkx.count_topological_sorts(graph), 1)
+
+    def test_diamond(self):
+        graph = rustworkx.PyDiGraph()
+        graph.extend_from_edge_list([(0, 1), (0, 2), (1, 3), (2, 3)])
+        sorts = sorted(list(sort) for sort in rustworkx.all_topological_sorts(graph))
+        self.assertEqual(sorts, [[0, 1, 2, 3], [0, 2, 1, 3]])
+        self.assertEqual(rustworkx.count_topological_sorts(graph), 2)
+
+    def test_sorts_are_valid_and_distinct(self):
+        graph = rustworkx.PyDiGraph()
+        graph.extend_from_edge_list([(0, 2), (1, 2), (2, 3), (1, 4), (4, 5), (0, 5)])
+        sorts = [tuple(sort) for sort in rustworkx.all_topological_sorts(graph)]
+        self.assertEqual(len(sorts), len(set(sorts)))
+        for sort in sorts:
+            position = {node: index for index, node in enumerate(sort)}
+            for source, target in graph.edge_list():
+                self.assertLess(position[source], position[target])
+        self.assertEqual(len(sorts), rustworkx.count_topological_sorts(graph))
+
+    def test_iterator_is_lazy(self):
+        graph = rustworkx.PyDiGraph()
+        graph.add_nodes_from(range(12))
+        sorts = rustworkx.all_topological_sorts(graph)
+        self.assertEqual(list(next(sorts)), list(range(12)))
+        self.assertEqual(rustworkx.count_topological_sorts(graph), math.factorial(12))
+
+    def test_count_large_width_two(self):
+        graph = rustworkx.PyDiGraph()
+        graph.extend_from_edge_list([(i, i + 1) for i in range(29)])
+        graph.extend_from_edge_list([(i, i + 1) for i in range(30, 59)])
+        self.assertEqual(rustworkx.count_topological_sorts(graph), math.comb(60, 30))
+
+    def test_count_overflow(self):
+        graph = rustworkx.PyDiGraph()
+        graph.extend_from_edge_list([(i, i + 1) for i in range(69)])
+        graph.extend_from_edge_list([(i, i + 1) for i in range(70, 139)])
+        with self.assertRaises(OverflowError):
+            rustworkx.count_topological_sorts(graph)
+
+    def test_cycle(self):
+        graph = rustworkx.generators.directed_cycle_graph(3)
+        with self.assertRaises(rustworkx.DAGHasCycle):
+            rustworkx.all_topological_sorts(graph)
+        with self.assertRaises(rustworkx.DAGHasCycle):
+            rustworkx.count_topological_sorts(graph)
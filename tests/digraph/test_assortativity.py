@@ -0,0 +1,96 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+
+import math
+import unittest
+
+import rustworkx
+
+
+class TestAssortativity(unittest.TestCase):
+    def setUp(self):
+        self.graph = rustworkx.PyDiGraph()
+        self.graph.extend_from_edge_list([(0, 1), (0, 2), (1, 2), (2, 3), (3, 0)])
+
+    def test_degree_out_in(self):
+        res = rustworkx.degree_assortativity_coefficient(self.graph)
+        self.assertAlmostEqual(res, 1 / 6)
+
+    def test_degree_in_out(self):
+        res = rustworkx.digraph_degree_assortativity_coefficient(
+            self.graph, source="in", target="out"
+        )
+        self.assertAlmostEqual(res, -0.25)
+
+    def test_degree_out_out(self):
+        # Out-degrees 2, 1, 1, 1 so the pairs are (2, 1), (2, 1), (1, 1),
+        # (1, 1) and (1, 2)
+        res = rustworkx.degree_assortativity_coefficient(
+            self.graph, source="out", target="out"
+        )
+        self.assertAlmostEqual(res, -1 / math.sqrt(6))
+
+    def test_degree_weighted(self):
+        for edge in self.graph.edge_indices():
+            self.graph.update_edge_by_index(edge, 2.0)
+        res = rustworkx.degree_assortativity_coefficient(self.graph, weight_fn=float)
+        self.assertAlmostEqual(res, 1 / 6)
+
+    def test_invalid_degree(self):
+        with self.assertRaises(ValueError):
+            rustworkx.digraph_degree_assortativity_coefficient(self.graph, source="both")
+
+    def test_attribute(self):
+        graph = rustworkx.PyDiGraph()
+        graph.add_nodes_from(["red", "blue", "blue"])
+        graph.add_edges_from_no_data([(0, 1), (1, 2), (2, 1)])
+        res = rustworkx.attribute_assortativity_coefficient(graph, lambda color: color)
+        self.assertAlmostEqual(res, 0.0)
+
+    def test_attribute_cycle(self):
+        graph = rustworkx.generators.directed_cycle_graph(4)
+        for node in graph.node_indices():
+            graph[node] = node % 2
+        res = rustworkx.digraph_attribute_assortativity_coefficient(graph, lambda x: x)
+        self.assertAlmostEqual(res, -1.0)
+
+    def test_degree_histogram(self):
+        graph = rustworkx.PyDiGraph()
+        graph.extend_from_edge_list([(0, 1), (0, 2), (1, 2), (2, 3), (3, 0), (3, 1)])
+        self.assertEqual(rustworkx.digraph_degree_histogram(graph), [0, 0, 0, 4])
+        self.assertEqual(rustworkx.degree_histogram(graph), [0, 0, 0, 4])
+
+    def test_joint_degree_matrix(self):
+        graph = rustworkx.PyDiGraph()
+        graph.extend_from_edge_list([(0, 1), (0, 2), (1, 2), (2, 3), (3, 0), (3, 1)])
+        res = rustworkx.digraph_joint_degree_matrix(graph, normalized=False)
+        self.assertEqual(res, {(1, 1): 1, (1, 2): 1, (2, 1): 1, (2, 2): 3})
+        res = rustworkx.joint_degree_matrix(graph, source="in", target="out")
+        self.assertEqual(res, {(1, 1): 3 / 6, (1, 2): 1 / 6, (2, 1): 1 / 6, (2, 2): 1 / 6})
+
+    def test_average_neighbor_degree(self):
+        graph = rustworkx.PyDiGraph()
+        graph.extend_from_edge_list([(0, 1), (0, 2), (1, 2), (2, 3), (3, 0), (3, 1)])
+        res = rustworkx.digraph_average_neighbor_degree(graph)
+        self.assertEqual(res, {0: 1.0, 1: 1.0, 2: 2.0, 3: 1.5})
+        res = rustworkx.average_neighbor_degree(graph, source="in", target="in")
+        self.assertEqual(res, {0: 1.0, 1: 1.0, 2: 1.5, 3: 2.0})
+        res = rustworkx.average_neighbor_degree(graph, source="in", target="out")
+        self.assertEqual(res, {0: 2.0, 1: 2.0, 2: 1.5, 3: 1.0})
+
+    def test_invalid_degree_type(self):
+        graph = rustworkx.PyDiGraph()
+        with self.assertRaises(ValueError):
+            rustworkx.digraph_joint_degree_matrix(graph, target="both")
+        with self.assertRaises(ValueError):
+            rustworkx.digraph_average_neighbor_degree(graph, source="both")
@@ -126,3 +126,17 @@ class TestAstarDigraph(unittest.TestCase):
                 edge_cost_fn=lambda x: float(x),
                 estimate_cost_fn=lambda _: 0,
             )
+
+    def test_astar_with_estimate_sequence(self):
+        g = rustworkx.PyDAG()
+        a = g.add_node("A")
+        b = g.add_node("B")
+        g.add_edge(a, b, 7)
+        with self.assertRaisesRegex(TypeError, "node weights"):
+            rustworkx.digraph_astar_shortest_path(
+                g,
+                a,
+                goal_fn=lambda goal: goal == "B",
+                edge_cost_fn=lambda x: float(x),
+                estimate_cost_fn=[0.0, 0.0],
+            )
@@ -454,3 +454,24 @@ class TestBellmanFordDiGraph(unittest.TestCase):
             rustworkx.digraph_bellman_ford_shortest_path_lengths(
                 self.graph, len(self.graph.node_indices()) + 1, edge_cost_fn=lambda x: float(x)
             )
+
+    def test_bellman_ford_edge_weight_sequence(self):
+        weights = [float(weight) for weight in self.graph.edges()]
+        self.assertEqual(
+            rustworkx.bellman_ford_shortest_path_lengths(self.graph, self.a, float),
+            rustworkx.bellman_ford_shortest_path_lengths(self.graph, self.a, weights),
+        )
+        self.assertEqual(
+            rustworkx.bellman_ford_shortest_paths(self.graph, self.a, weight_fn=float),
+            rustworkx.bellman_ford_shortest_paths(self.graph, self.a, weight_fn=weights),
+        )
+        self.assertEqual(
+            rustworkx.all_pairs_bellman_ford_path_lengths(self.graph, float),
+            rustworkx.all_pairs_bellman_ford_path_lengths(self.graph, weights),
+        )
+        negative = [-weight for weight in weights]
+        self.assertTrue(rustworkx.negative_edge_cycle(self.graph, negative))
+
+    def test_bellman_ford_edge_weight_sequence_too_short(self):
+        with self.assertRaises(IndexError):
+            rustworkx.bellman_ford_shortest_path_lengths(self.graph, self.a, [1.0])
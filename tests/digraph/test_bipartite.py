@@ -74,3 +74,23 @@ class TestBipartite(unittest.TestCase):
                     self.assertFalse(res)
                 else:
                     self.assertTrue(res)
+
+    def test_bipartite_sets(self):
+        graph = rustworkx.generators.directed_path_graph(5)
+        self.assertEqual(rustworkx.bipartite_sets(graph), ({0, 2, 4}, {1, 3}))
+
+    def test_bipartite_sets_ignores_direction(self):
+        graph = rustworkx.PyDiGraph()
+        graph.add_nodes_from(range(4))
+        graph.add_edges_from_no_data([(1, 0), (1, 2), (3, 2)])
+        self.assertEqual(rustworkx.digraph_bipartite_sets(graph), ({0, 2}, {1, 3}))
+
+    def test_bipartite_sets_odd_cycle(self):
+        graph = rustworkx.generators.directed_cycle_graph(7)
+        with self.assertRaises(rustworkx.GraphNotBipartite) as cm:
+            rustworkx.digraph_bipartite_sets(graph)
+        cycle = list(cm.exception.odd_cycle)
+        self.assertEqual(sorted(cycle), list(range(7)))
+        for i, node in enumerate(cycle):
+            next_node = cycle[(i + 1) % len(cycle)]
+            self.assertTrue(graph.has_edge(node, next_node) or graph.has_edge(next_node, node))
@@ -0,0 +1,85 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+import random
+import unittest
+
+import rustworkx
+
+
+def relabeled(graph, seed):
+    rng = random.Random(seed)
+    nodes = list(graph.node_indices())
+    rng.shuffle(nodes)
+    out = rustworkx.PyDiGraph()
+    mapping = {node: out.add_node(graph[node]) for node in nodes}
+    edges = list(graph.weighted_edge_list())
+    rng.shuffle(edges)
+    for source, target, weight in edges:
+        out.add_edge(mapping[source], mapping[target], weight)
+    return out
+
+
+class TestCanonicalHash(unittest.TestCase):
+    def test_invariant_under_relabeling(self):
+        for graph in [
+            rustworkx.generators.directed_cycle_graph(7),
+            rustworkx.generators.directed_grid_graph(3, 4),
+            rustworkx.generators.directed_star_graph(6, inward=True),
+            rustworkx.directed_gnp_random_graph(30, 0.1, seed=7),
+        ]:
+            expected = rustworkx.canonical_hash(graph)
+            for seed in range(5):
+                with self.subTest(seed=seed):
+                    self.assertEqual(rustworkx.canonical_hash(relabeled(graph, seed)), expected)
+
+    def test_direction(self):
+        outward = rustworkx.generators.directed_star_graph(5)
+        inward = rustworkx.generators.directed_star_graph(5, inward=True)
+        self.assertNotEqual(rustworkx.canonical_hash(outward), rustworkx.canonical_hash(inward))
+        reversed_inward = inward.copy()
+        reversed_inward.reverse()
+        self.assertEqual(
+            rustworkx.canonical_hash(outward), rustworkx.canonical_hash(reversed_inward)
+        )
+        self.assertNotEqual(
+            rustworkx.canonical_hash(outward),
+            rustworkx.canonical_hash(outward.to_undirected()),
+        )
+
+    def test_agrees_with_is_isomorphic(self):
+        graphs = [rustworkx.directed_gnm_random_graph(6, 8, seed=seed) for seed in range(30)]
+        for i, first in enumerate(graphs):
+            for second in graphs[:i]:
+                self.assertEqual(
+                    rustworkx.digraph_canonical_hash(first)
+                    == rustworkx.digraph_canonical_hash(second),
+                    rustworkx.is_isomorphic(first, second),
+                )
+
+    def test_attributes(self):
+        graph = rustworkx.PyDiGraph()
+        graph.add_nodes_from(["a", "b", "a"])
+        graph.add_edges_from([(0, 1, "x"), (2, 1, "y")])
+        swapped = rustworkx.PyDiGraph()
+        swapped.add_nodes_from(["a", "b", "a"])
+        swapped.add_edges_from([(0, 1, "y"), (2, 1, "x")])
+        other = rustworkx.PyDiGraph()
+        other.add_nodes_from(["a", "b", "a"])
+        other.add_edges_from([(0, 1, "x"), (2, 1, "x")])
+
+        def labeled_hash(graph):
+            return rustworkx.canonical_hash(graph, node_attr_fn=str, edge_attr_fn=str)
+
+        self.assertEqual(labeled_hash(graph), labeled_hash(swapped))
+        self.assertNotEqual(labeled_hash(graph), labeled_hash(other))
+        self.assertEqual(rustworkx.canonical_hash(graph), rustworkx.canonical_hash(other))
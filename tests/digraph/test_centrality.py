@@ -141,6 +141,45 @@ class TestCentralityDiGraphDeletedNode(unittest.TestCase):
         expected = {0: 0.0, 1: 1.0, 2: 2.0 / 3.0, 4: 0.5}
         self.assertEqual(expected, closeness)
 
+    def test_closeness_centrality_direction(self):
+        graph = rustworkx.generators.directed_path_graph(3)
+        incoming = rustworkx.digraph_closeness_centrality(graph, wf_improved=False)
+        self.assertEqual({0: 0.0, 1: 1.0, 2: 2.0 / 3.0}, incoming)
+        outgoing = rustworkx.digraph_closeness_centrality(
+            graph, wf_improved=False, direction="outgoing"
+        )
+        self.assertEqual({0: 2.0 / 3.0, 1: 1.0, 2: 0.0}, outgoing)
+        outgoing = rustworkx.closeness_centrality(graph, direction="outgoing")
+        self.assertEqual({0: 2.0 / 3.0, 1: 0.5, 2: 0.0}, outgoing)
+
+    def test_closeness_centrality_weighted(self):
+        graph = rustworkx.PyDiGraph()
+        graph.add_nodes_from(range(3))
+        graph.add_edges_from([(0, 1, 1.0), (1, 2, 3.0), (0, 2, 2.0)])
+        closeness = rustworkx.digraph_closeness_centrality(
+            graph, wf_improved=False, weight_fn=float
+        )
+        self.assertEqual({0: 0.0, 1: 1.0, 2: 2.0 / 5.0}, closeness)
+        closeness = rustworkx.digraph_closeness_centrality(
+            graph, wf_improved=False, weight_fn=float, direction="outgoing"
+        )
+        self.assertEqual({0: 2.0 / 3.0, 1: 1.0 / 3.0, 2: 0.0}, closeness)
+
+    def test_closeness_centrality_invalid_direction(self):
+        with self.assertRaises(ValueError):
+            rustworkx.digraph_closeness_centrality(self.graph, direction="both")
+        with self.assertRaises(ValueError):
+            rustworkx.digraph_harmonic_centrality(self.graph, direction="both")
+
+    def test_harmonic_centrality(self):
+        graph = rustworkx.generators.directed_path_graph(4)
+        harmonic = rustworkx.digraph_harmonic_centrality(graph)
+        expected = {0: 0.0, 1: 1.0, 2: 1.5, 3: 1.0 + 1.0 / 2.0 + 1.0 / 3.0}
+        self.assertEqual(expected, harmonic)
+        harmonic = rustworkx.harmonic_centrality(graph, direction="outgoing")
+        expected = {0: 1.0 + 1.0 / 2.0 + 1.0 / 3.0, 1: 1.5, 2: 1.0, 3: 0.0}
+        self.assertEqual(expected, harmonic)
+
 
 class TestEigenvectorCentrality(unittest.TestCase):
     def test_complete_graph(self):
@@ -162,6 +201,24 @@ class TestEigenvectorCentrality(unittest.TestCase):
         with self.assertRaises(rustworkx.FailedToConverge):
             rustworkx.eigenvector_centrality(graph, max_iter=0)
 
+    def test_no_convergence_partial_result(self):
+        graph = rustworkx.generators.directed_path_graph(4)
+        graph.remove_node(0)
+        with self.assertRaises(rustworkx.FailedToConverge) as cm:
+            rustworkx.eigenvector_centrality(graph, max_iter=2, tol=1e-12)
+        partial = cm.exception.partial_result
+        self.assertIsInstance(partial, rustworkx.CentralityMapping)
+        self.assertEqual(list(partial.keys()), [1, 2, 3])
+        self.assertEqual(cm.exception.iterations, 2)
+        self.assertGreater(cm.exception.residual, 0.0)
+
+    def test_no_convergence_without_partial_result(self):
+        with self.assertRaises(rustworkx.FailedToConverge) as cm:
+            rustworkx.eigenvector_centrality(rustworkx.PyDiGraph())
+        self.assertIsNone(cm.exception.partial_result)
+        self.assertIsNone(cm.exception.iterations)
+        self.assertIsNone(cm.exception.residual)
+
 
 class TestKatzCentrality(unittest.TestCase):
     def test_complete_graph(self):
@@ -176,6 +233,16 @@ class TestKatzCentrality(unittest.TestCase):
         with self.assertRaises(rustworkx.FailedToConverge):
             rustworkx.katz_centrality(graph, max_iter=0)
 
+    def test_no_convergence_partial_result(self):
+        graph = rustworkx.generators.directed_path_graph(4)
+        with self.assertRaises(rustworkx.FailedToConverge) as cm:
+            rustworkx.katz_centrality(graph, max_iter=2)
+        partial = cm.exception.partial_result
+        self.assertEqual(list(partial.keys()), [0, 1, 2, 3])
+        self.assertAlmostEqual(math.sqrt(sum(v**2 for v in partial.values())), 1.0)
+        self.assertEqual(cm.exception.iterations, 2)
+        self.assertGreater(cm.exception.residual, 0.0)
+
     def test_beta_scalar(self):
         rx_graph = rustworkx.generators.directed_grid_graph(5, 2)
         beta = 0.3
@@ -241,3 +308,32 @@ class TestEdgeBetweennessCentrality(unittest.TestCase):
         expected = {0: 4.0, 1: 6.0, 2: 6.0, 3: 4.0}
         for k, v in centrality.items():
             self.assertAlmostEqual(v, expected[k])
+
+
+class TestWeightedBetweennessCentrality(unittest.TestCase):
+    def setUp(self):
+        self.nx_graph = nx.gnm_random_graph(30, 120, seed=42, directed=True)
+        self.graph = rustworkx.PyDiGraph()
+        self.graph.add_nodes_from(self.nx_graph.nodes())
+        for source, target, data in self.nx_graph.edges(data=True):
+            data["index"] = self.graph.add_edge(source, target, self.graph.num_edges() % 4 + 1)
+            data["weight"] = self.graph.get_edge_data_by_index(data["index"])
+
+    def test_betweenness_centrality(self):
+        for endpoints in (False, True):
+            centrality = rustworkx.digraph_betweenness_centrality(
+                self.graph, endpoints=endpoints, weight_fn=float, parallel_threshold=1
+            )
+            expected = nx.betweenness_centrality(
+                self.nx_graph, endpoints=endpoints, weight="weight"
+            )
+            for node, value in expected.items():
+                self.assertAlmostEqual(value, centrality[node])
+
+    def test_edge_betweenness_centrality(self):
+        weights = [float(weight) for weight in self.graph.edges()]
+        centrality = rustworkx.digraph_edge_betweenness_centrality(self.graph, weight_fn=weights)
+        expected = nx.edge_betweenness_centrality(self.nx_graph, weight="weight")
+        for (source, target), value in expected.items():
+            index = self.nx_graph.edges[source, target]["index"]
+            self.assertAlmostEqual(value, centrality[index])
@@ -0,0 +1,106 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+import unittest
+
+import rustworkx
+
+
+class TestModularity(unittest.TestCase):
+    def setUp(self):
+        self.graph = rustworkx.PyDiGraph()
+        self.graph.add_nodes_from(range(5))
+        self.graph.add_edges_from(
+            [
+                (0, 1, 1.0),
+                (1, 0, 2.0),
+                (1, 2, 1.0),
+                (2, 3, 3.0),
+                (3, 4, 1.0),
+                (4, 3, 1.0),
+                (4, 4, 2.0),
+                (0, 1, 1.0),
+            ]
+        )
+
+    def test_weighted(self):
+        res = rustworkx.digraph_modularity(self.graph, [[0, 1, 2], [3, 4]], weight_fn=float)
+        self.assertAlmostEqual(res, 0.2777777777777778)
+
+    def test_resolution(self):
+        res = rustworkx.modularity(
+            self.graph, [[0, 1, 2], [3, 4]], weight_fn=float, resolution=2.0
+        )
+        self.assertAlmostEqual(res, -0.19444444444444442)
+
+    def test_not_a_partition(self):
+        with self.assertRaises(ValueError):
+            rustworkx.digraph_modularity(self.graph, [[0, 1, 2], [3]])
+
+
+class TestSumParallelEdges(unittest.TestCase):
+    def test_directions_not_merged(self):
+        graph = rustworkx.PyDiGraph()
+        graph.add_nodes_from(range(3))
+        graph.add_edges_from(
+            [(0, 1, 2.0), (1, 0, 3.0), (0, 1, 1.0), (1, 2, 1.0), (2, 2, 4.0), (2, 2, 1.0)]
+        )
+        res = rustworkx.digraph_sum_parallel_edges(graph, weight_fn=float)
+        self.assertEqual(
+            res.weighted_edge_list(), [(0, 1, 3.0), (1, 0, 3.0), (1, 2, 1.0), (2, 2, 5.0)]
+        )
+
+    def test_combine_fn(self):
+        graph = rustworkx.PyDiGraph()
+        graph.add_nodes_from(range(2))
+        graph.add_edges_from([(0, 1, 2.0), (0, 1, 5.0), (0, 1, 3.0)])
+        res = rustworkx.sum_parallel_edges(graph, weight_fn=float, combine_fn=min)
+        self.assertEqual(res.weighted_edge_list(), [(0, 1, 2.0)])
+
+
+class TestCommunitySubgraphs(unittest.TestCase):
+    def setUp(self):
+        self.graph = rustworkx.PyDiGraph()
+        self.graph.add_nodes_from(range(6))
+        self.graph.add_edges_from(
+            [(0, 1, "a"), (1, 0, "b"), (1, 2, "c"), (2, 3, "d"), (3, 4, "e"), (5, 3, "f")]
+        )
+
+    def test_matches_subgraph(self):
+        communities = [[2, 1, 0], [5, 4, 3], [0, 3]]
+        res = rustworkx.digraph_community_subgraphs(self.graph, communities)
+        for community, (subgraph, node_map) in zip(communities, res):
+            expected = self.graph.subgraph(community)
+            self.assertIsInstance(subgraph, rustworkx.PyDiGraph)
+            self.assertEqual(subgraph.nodes(), expected.nodes())
+            self.assertEqual(subgraph.weighted_edge_list(), expected.weighted_edge_list())
+            self.assertEqual(dict(node_map), dict(enumerate(sorted(community))))
+
+    def test_edge_directions_kept(self):
+        subgraph, _ = rustworkx.digraph_community_subgraphs(self.graph, [[3, 4, 5]])[0]
+        self.assertEqual(subgraph.weighted_edge_list(), [(0, 1, "e"), (2, 0, "f")])
+
+    def test_check_cycle_kept(self):
+        dag = rustworkx.PyDAG(check_cycle=True)
+        dag.extend_from_edge_list([(0, 1), (1, 2)])
+        subgraph, _ = rustworkx.digraph_community_subgraphs(dag, [[0, 1, 2]])[0]
+        self.assertTrue(subgraph.check_cycle)
+        with self.assertRaises(rustworkx.DAGWouldCycle):
+            subgraph.add_edge(2, 0, None)
+
+    def test_invalid_node(self):
+        with self.assertRaises(rustworkx.InvalidNode):
+            rustworkx.digraph_community_subgraphs(self.graph, [[6]])
+
+    def test_universal(self):
+        res = rustworkx.community_subgraphs(self.graph, [[0, 1], [2, 3]], preserve_attrs=True)
+        self.assertEqual([len(subgraph.edges()) for subgraph, _ in res], [2, 1])
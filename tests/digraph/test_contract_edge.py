@@ -0,0 +1,68 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+import unittest
+
+import rustworkx
+
+
+class TestContractEdge(unittest.TestCase):
+    def test_contract_edge(self):
+        # a -> b -> c -> d
+        dag = rustworkx.PyDAG()
+        a, b, c, d = dag.add_nodes_from(["a", "b", "c", "d"])
+        dag.add_edges_from([(a, b, 1), (b, c, 2), (c, d, 3)])
+        res = dag.contract_edge(b, c, node_fn=lambda x, y: x + y)
+        self.assertEqual(b, res)
+        self.assertEqual(["a", "bc", "d"], dag.nodes())
+        self.assertEqual([(a, b, 1), (b, d, 3)], sorted(dag.weighted_edge_list()))
+
+    def test_contract_edge_direction(self):
+        graph = rustworkx.PyDiGraph()
+        graph.add_nodes_from(["a", "b"])
+        graph.add_edge(0, 1, None)
+        with self.assertRaises(rustworkx.NoEdgeBetweenNodes):
+            graph.contract_edge(1, 0)
+        self.assertEqual(0, graph.contract_edge(0, 1))
+        self.assertEqual([0], graph.node_indices())
+        self.assertEqual(0, graph.num_edges())
+
+    def test_contract_edge_merges_both_directions(self):
+        graph = rustworkx.PyDiGraph()
+        graph.add_nodes_from(["a", "b", "c"])
+        graph.add_edges_from([(0, 1, 1), (0, 2, 2), (1, 2, 4), (2, 1, 8), (1, 0, 16)])
+        graph.contract_edge(0, 1, weight_combo_fn=lambda x, y: x + y, self_loops=True)
+        self.assertEqual([(0, 0, 17), (0, 2, 6), (2, 0, 8)], sorted(graph.weighted_edge_list()))
+
+    def test_contract_edge_would_cycle(self):
+        # Contracting a -> c when there is also a path a -> b -> c
+        dag = rustworkx.PyDAG(check_cycle=True)
+        a, b, c = dag.add_nodes_from(["a", "b", "c"])
+        dag.add_edges_from([(a, b, 1), (b, c, 2), (a, c, 3)])
+        with self.assertRaises(rustworkx.DAGWouldCycle):
+            dag.contract_edge(a, c)
+        self.assertEqual(3, len(dag))
+        dag.contract_edge(a, c, check_cycle=False)
+        self.assertEqual([(a, b, 1), (b, a, 2)], sorted(dag.weighted_edge_list()))
+
+    def test_contract_edges(self):
+        graph = rustworkx.generators.directed_path_graph(5, weights=list("abcde"))
+        node_map = graph.contract_edges([(3, 4), (0, 1), (1, 2)], node_fn=lambda x, y: x + y)
+        self.assertEqual({4: 3, 1: 0, 2: 0}, dict(node_map))
+        self.assertEqual(["abc", "de"], graph.nodes())
+        self.assertEqual([(0, 3)], graph.edge_list())
+
+    def test_contract_edges_validates_before_contracting(self):
+        graph = rustworkx.generators.directed_path_graph(3)
+        with self.assertRaises(rustworkx.NoEdgeBetweenNodes):
+            graph.contract_edges([(0, 1), (2, 1)])
+        self.assertEqual(3, len(graph))
@@ -0,0 +1,95 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+import unittest
+
+import rustworkx
+
+
+class TestCriticalPath(unittest.TestCase):
+    def setUp(self):
+        self.graph = rustworkx.PyDiGraph()
+        self.a, self.b, self.c, self.d, self.e = self.graph.add_nodes_from([2, 3, 4, 1, 5])
+        self.graph.add_edges_from(
+            [
+                (self.a, self.c, 0),
+                (self.b, self.c, 0),
+                (self.b, self.d, 0),
+                (self.c, self.e, 0),
+                (self.d, self.e, 1),
+            ]
+        )
+
+    def test_schedule(self):
+        path, duration, schedule = rustworkx.dag_critical_path(
+            self.graph,
+            node_weight_fn=float,
+            edge_weight_fn=lambda _, __, weight: float(weight),
+        )
+        self.assertEqual(path, [self.b, self.c, self.e])
+        self.assertEqual(duration, 12.0)
+        self.assertEqual(
+            schedule[self.a],
+            {
+                "earliest_start": 0.0,
+                "earliest_finish": 2.0,
+                "latest_start": 1.0,
+                "latest_finish": 3.0,
+                "slack": 1.0,
+            },
+        )
+        self.assertEqual(schedule[self.d]["earliest_start"], 3.0)
+        self.assertEqual(schedule[self.d]["slack"], 2.0)
+        for node in path:
+            self.assertEqual(schedule[node]["slack"], 0.0)
+        self.assertEqual(set(schedule), set(self.graph.node_indices()))
+
+    def test_default_weights(self):
+        graph = rustworkx.generators.directed_path_graph(4)
+        graph.add_edge(0, 3, None)
+        path, duration, schedule = rustworkx.dag_critical_path(graph)
+        self.assertEqual(path, [0, 1, 2, 3])
+        self.assertEqual(duration, 4.0)
+        self.assertEqual(schedule[3]["earliest_start"], 3.0)
+
+    def test_matches_weighted_longest_path(self):
+        graph = rustworkx.PyDiGraph()
+        graph.add_nodes_from(range(6))
+        graph.add_edges_from(
+            [(0, 1, 2.0), (0, 2, 5.0), (1, 3, 5.0), (2, 3, 1.0), (3, 4, 3.0), (5, 4, 1.0)]
+        )
+        weight_fn = lambda _, __, weight: weight  # noqa: E731
+        path, duration, schedule = rustworkx.dag_critical_path(
+            graph, node_weight_fn=lambda _: 0.0, edge_weight_fn=weight_fn
+        )
+        self.assertEqual(path, rustworkx.dag_weighted_longest_path(graph, weight_fn))
+        self.assertEqual(duration, rustworkx.dag_weighted_longest_path_length(graph, weight_fn))
+        self.assertEqual(schedule[5]["slack"], 9.0)
+
+    def test_empty_graph(self):
+        path, duration, schedule = rustworkx.dag_critical_path(rustworkx.PyDiGraph())
+        self.assertEqual(path, [])
+        self.assertEqual(duration, 0.0)
+        self.assertEqual(schedule, {})
+
+    def test_cycle(self):
+        graph = rustworkx.generators.directed_cycle_graph(3)
+        with self.assertRaises(rustworkx.DAGHasCycle):
+            rustworkx.dag_critical_path(graph)
+
+    def test_nan_weight(self):
+        with self.assertRaises(ValueError):
+            rustworkx.dag_critical_path(self.graph, node_weight_fn=lambda _: float("nan"))
+        with self.assertRaises(ValueError):
+            rustworkx.dag_critical_path(
+                self.graph, edge_weight_fn=lambda _, __, ___: float("nan")
+            )
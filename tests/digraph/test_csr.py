@@ -0,0 +1,71 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+import unittest
+
+import rustworkx
+
+
+class TestToCsr(unittest.TestCase):
+    def test_outgoing_edges(self):
+        graph = rustworkx.PyDiGraph()
+        graph.add_nodes_from([None] * 4)
+        graph.add_edges_from_no_data([(0, 1), (2, 1), (1, 3), (3, 1), (0, 3)])
+        data, indices, indptr = graph.to_csr()
+        self.assertEqual(indptr.tolist(), [0, 2, 3, 4, 5])
+        self.assertEqual(indices.tolist(), [1, 3, 3, 1, 1])
+        self.assertEqual(data.tolist(), [0, 4, 2, 1, 3])
+
+    def test_removed_edges(self):
+        graph = rustworkx.generators.directed_path_graph(4)
+        graph.remove_edge_from_index(1)
+        data, indices, indptr = graph.to_csr()
+        self.assertEqual(indptr.tolist(), [0, 1, 1, 2, 2])
+        self.assertEqual(indices.tolist(), [1, 3])
+        self.assertEqual(data.tolist(), [0, 2])
+
+    def test_all_pairs_with_removed_nodes(self):
+        graph = rustworkx.PyDiGraph()
+        graph.extend_from_weighted_edge_list([(0, 1, 1), (1, 2, 1), (2, 3, 2), (3, 4, 3)])
+        graph.remove_node(1)
+        graph.add_edge(0, 4, 10)
+        res = rustworkx.digraph_all_pairs_dijkstra_path_lengths(graph, float)
+        self.assertEqual(
+            {node: dict(lengths) for node, lengths in res.items()},
+            {0: {4: 10.0}, 2: {3: 2.0, 4: 5.0}, 3: {4: 3.0}, 4: {}},
+        )
+
+    def test_large_graph_dijkstra(self):
+        # Large enough to run on the compressed sparse row form
+        graph = rustworkx.generators.directed_path_graph(10_005)
+        graph.remove_node(5000)
+        res = rustworkx.digraph_dijkstra_shortest_path_lengths(graph, 0, lambda _: 2.0)
+        self.assertEqual(dict(res), {node: 2.0 * node for node in range(1, 5000)})
+        res = rustworkx.digraph_dijkstra_shortest_path_lengths(
+            graph, 4999, lambda _: 1.0, reverse_view=True
+        )
+        self.assertEqual(dict(res), {node: 4999.0 - node for node in range(4999)})
+        # Searches for a target stay on the graph
+        res = rustworkx.digraph_dijkstra_shortest_paths(graph, 4, target=0, reverse_view=True)
+        self.assertEqual(dict(res), {0: [4, 3, 2, 1, 0]})
+        res = rustworkx.digraph_dijkstra_shortest_paths(graph, 4, target=0, as_undirected=True)
+        self.assertEqual(dict(res), {0: [4, 3, 2, 1, 0]})
+        res = rustworkx.digraph_dijkstra_shortest_paths(graph, 4, target=0)
+        self.assertEqual(dict(res), {})
+
+    def test_large_graph_weakly_connected_components(self):
+        graph = rustworkx.generators.directed_path_graph(10_005)
+        graph.remove_node(5000)
+        self.assertEqual(
+            sorted(map(sorted, rustworkx.weakly_connected_components(graph))),
+            [list(range(5000)), list(range(5001, 10_005))],
+        )
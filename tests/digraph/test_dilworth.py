@@ -0,0 +1,68 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+
+import itertools
+import unittest
+
+import rustworkx
+
+
+class TestDilworth(unittest.TestCase):
+    def assert_width(self, graph, width):
+        chains = rustworkx.dag_minimum_chain_decomposition(graph)
+        self.assertEqual(len(chains), width)
+        self.assertEqual(sorted(node for chain in chains for node in chain), graph.node_indices())
+        for chain in chains:
+            for source, target in zip(chain, chain[1:]):
+                self.assertTrue(rustworkx.has_path(graph, source, target))
+        antichain = rustworkx.dag_maximum_antichain(graph)
+        self.assertEqual(len(antichain), width)
+        for source, target in itertools.permutations(antichain, 2):
+            self.assertFalse(rustworkx.has_path(graph, source, target))
+
+    def test_empty_graph(self):
+        graph = rustworkx.PyDiGraph()
+        self.assertEqual(rustworkx.dag_minimum_chain_decomposition(graph), [])
+        self.assertEqual(rustworkx.dag_maximum_antichain(graph), [])
+
+    def test_path_graph(self):
+        graph = rustworkx.generators.directed_path_graph(5)
+        self.assertEqual(rustworkx.dag_minimum_chain_decomposition(graph), [[0, 1, 2, 3, 4]])
+        self.assertEqual(len(rustworkx.dag_maximum_antichain(graph)), 1)
+
+    def test_star_graph(self):
+        graph = rustworkx.generators.directed_star_graph(6)
+        self.assertEqual(sorted(rustworkx.dag_maximum_antichain(graph)), [1, 2, 3, 4, 5])
+        self.assert_width(graph, 5)
+
+    def test_diamond(self):
+        graph = rustworkx.PyDiGraph()
+        graph.extend_from_edge_list([(0, 1), (0, 2), (0, 3), (1, 4), (2, 4)])
+        self.assert_width(graph, 3)
+
+    def test_chains_through_paths(self):
+        graph = rustworkx.PyDiGraph()
+        graph.extend_from_edge_list([(0, 1), (1, 2), (1, 3), (2, 4), (3, 5), (0, 6), (6, 4)])
+        self.assert_width(graph, 3)
+
+    def test_removed_nodes(self):
+        graph = rustworkx.generators.directed_path_graph(5)
+        graph.remove_node(2)
+        self.assert_width(graph, 2)
+
+    def test_cycle(self):
+        graph = rustworkx.generators.directed_cycle_graph(3)
+        with self.assertRaises(rustworkx.DAGHasCycle):
+            rustworkx.dag_minimum_chain_decomposition(graph)
+        with self.assertRaises(rustworkx.DAGHasCycle):
+            rustworkx.dag_maximum_antichain(graph)
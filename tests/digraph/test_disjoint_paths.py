@@ -0,0 +1,79 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+import unittest
+
+import rustworkx
+
+
+class TestDiGraphDisjointPaths(unittest.TestCase):
+    def assertEdgeDisjoint(self, graph, paths, source, target):
+        used = set()
+        for path in paths:
+            self.assertEqual(path[0], source)
+            self.assertEqual(path[-1], target)
+            for u, v in zip(path, path[1:]):
+                self.assertTrue(graph.has_edge(u, v))
+                self.assertNotIn((u, v), used)
+                used.add((u, v))
+
+    def test_edge_disjoint_follows_direction(self):
+        graph = rustworkx.PyDiGraph()
+        graph.extend_from_edge_list([(0, 1), (1, 3), (0, 2), (2, 3), (3, 0)])
+        paths = rustworkx.digraph_edge_disjoint_paths(graph, 0, 3)
+        self.assertEqual(sorted(paths), [[0, 1, 3], [0, 2, 3]])
+        self.assertEqual(rustworkx.digraph_edge_disjoint_paths(graph, 3, 0), [[3, 0]])
+
+    def test_edge_disjoint_shared_node(self):
+        graph = rustworkx.PyDiGraph()
+        graph.extend_from_edge_list(
+            [(0, 1), (0, 2), (1, 3), (2, 3), (3, 4), (3, 5), (4, 6), (5, 6)]
+        )
+        paths = rustworkx.edge_disjoint_paths(graph, 0, 6)
+        self.assertEqual(len(paths), 2)
+        self.assertEdgeDisjoint(graph, paths, 0, 6)
+        self.assertEqual(len(rustworkx.node_disjoint_paths(graph, 0, 6)), 1)
+
+    def test_node_disjoint_grid(self):
+        graph = rustworkx.generators.directed_grid_graph(3, 3)
+        paths = rustworkx.digraph_node_disjoint_paths(graph, 0, 8)
+        self.assertEqual(len(paths), 2)
+        self.assertEdgeDisjoint(graph, paths, 0, 8)
+        inner = [node for path in paths for node in path[1:-1]]
+        self.assertEqual(len(inner), len(set(inner)))
+
+    def test_direct_edge(self):
+        graph = rustworkx.PyDiGraph()
+        graph.extend_from_edge_list([(0, 2), (0, 2), (0, 1), (1, 2)])
+        self.assertEqual(len(rustworkx.digraph_edge_disjoint_paths(graph, 0, 2)), 3)
+        paths = rustworkx.digraph_node_disjoint_paths(graph, 0, 2)
+        self.assertEqual(sorted(paths), [[0, 1, 2], [0, 2]])
+
+    def test_limit(self):
+        graph = rustworkx.generators.directed_complete_graph(5)
+        self.assertEqual(len(rustworkx.digraph_edge_disjoint_paths(graph, 0, 4)), 4)
+        self.assertEqual(len(rustworkx.digraph_edge_disjoint_paths(graph, 0, 4, k=2)), 2)
+        self.assertEqual(rustworkx.digraph_node_disjoint_paths(graph, 0, 4, k=1), [[0, 4]])
+
+    def test_invalid_node(self):
+        graph = rustworkx.generators.directed_path_graph(3)
+        with self.assertRaises(rustworkx.InvalidNode):
+            rustworkx.digraph_edge_disjoint_paths(graph, 0, 5)
+        with self.assertRaises(rustworkx.InvalidNode):
+            rustworkx.digraph_node_disjoint_paths(graph, 5, 0)
+
+    def test_same_node(self):
+        graph = rustworkx.generators.directed_path_graph(3)
+        with self.assertRaises(ValueError):
+            rustworkx.digraph_edge_disjoint_paths(graph, 1, 1)
+        with self.assertRaises(ValueError):
+            rustworkx.digraph_node_disjoint_paths(graph, 1, 1)
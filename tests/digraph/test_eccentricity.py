@@ -0,0 +1,41 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+import unittest
+
+import rustworkx
+
+
+class TestEccentricity(unittest.TestCase):
+    def test_directed_cycle(self):
+        graph = rustworkx.generators.directed_cycle_graph(5)
+        self.assertEqual(rustworkx.eccentricity(graph), {i: 4.0 for i in range(5)})
+        self.assertEqual(rustworkx.center(graph), [0, 1, 2, 3, 4])
+
+    def test_follows_edge_direction(self):
+        graph = rustworkx.PyDiGraph()
+        graph.extend_from_weighted_edge_list([(0, 1, 1.0), (1, 2, 1.0), (2, 0, 1.0), (1, 0, 3.0)])
+        self.assertEqual(rustworkx.eccentricity(graph), {0: 2.0, 1: 1.0, 2: 2.0})
+        self.assertEqual(
+            rustworkx.digraph_eccentricity(graph, weight_fn=float), {0: 2.0, 1: 2.0, 2: 2.0}
+        )
+        self.assertEqual(rustworkx.center(graph), [1])
+        self.assertEqual(rustworkx.digraph_periphery(graph), [0, 2])
+
+    def test_not_strongly_connected(self):
+        graph = rustworkx.generators.directed_path_graph(4)
+        with self.assertRaises(ValueError):
+            rustworkx.eccentricity(graph)
+        with self.assertRaises(ValueError):
+            rustworkx.center(graph)
+        with self.assertRaises(ValueError):
+            rustworkx.periphery(graph)
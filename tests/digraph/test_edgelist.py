@@ -211,3 +211,44 @@ def cleanup_file(path):
         os.remove(path)
     except Exception:
         pass
+
+
+class TestEdgeIterator(unittest.TestCase):
+    def test_from_generator(self):
+        edges = ((i, i + 1) for i in range(5))
+        graph = rustworkx.PyDiGraph.from_edge_iterator(edges, chunk_size=2)
+        self.assertEqual(graph.edge_list(), [(i, i + 1) for i in range(5)])
+        self.assertEqual(graph.nodes(), [None] * 6)
+        self.assertTrue(graph.multigraph)
+
+    def test_num_nodes(self):
+        graph = rustworkx.PyDiGraph.from_edge_iterator([(2, 1, "a")], num_nodes=4)
+        self.assertEqual(len(graph), 4)
+        self.assertEqual(graph.weighted_edge_list(), [(2, 1, "a")])
+
+    def test_index_out_of_range(self):
+        with self.assertRaises(ValueError):
+            rustworkx.PyDiGraph.from_edge_iterator([(0, 1), (1, 4)], num_nodes=4)
+
+    def test_index_too_large(self):
+        with self.assertRaises(ValueError):
+            rustworkx.PyDiGraph.from_edge_iterator([(0, 10**12)])
+
+    def test_multigraph(self):
+        edges = [(0, 1, 1), (1, 0, 2), (0, 1, 3)]
+        graph = rustworkx.PyDiGraph.from_edge_iterator(edges)
+        self.assertEqual(graph.num_edges(), 3)
+        graph = rustworkx.PyDiGraph.from_edge_iterator(edges, multigraph=False, chunk_size=1)
+        self.assertFalse(graph.multigraph)
+        self.assertEqual(graph.weighted_edge_list(), [(0, 1, 3), (1, 0, 2)])
+
+    def test_combine_fn(self):
+        edges = [(0, 1, 1), (1, 0, 2), (0, 1, 4)]
+        graph = rustworkx.PyDiGraph.from_edge_iterator(edges, multigraph=False, combine_fn=max)
+        self.assertEqual(graph.weighted_edge_list(), [(0, 1, 4), (1, 0, 2)])
+
+    def test_invalid_edges(self):
+        with self.assertRaises(ValueError):
+            rustworkx.PyDiGraph.from_edge_iterator([(0,)])
+        with self.assertRaises(ValueError):
+            rustworkx.PyDiGraph.from_edge_iterator([(0, 1)], chunk_size=0)
@@ -0,0 +1,102 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+import unittest
+
+import rustworkx
+
+
+class TestFeedbackArcSet(unittest.TestCase):
+    def assertValidResult(self, graph, result):
+        arcs, ordering = result
+        self.assertEqual(sorted(ordering), sorted(graph.node_indices()))
+        position = {node: index for index, node in enumerate(ordering)}
+        for edge, (source, target, _) in graph.edge_index_map().items():
+            if edge in arcs:
+                self.assertGreaterEqual(position[source], position[target])
+            else:
+                self.assertLess(position[source], position[target])
+        acyclic = graph.copy()
+        for edge in arcs:
+            acyclic.remove_edge_from_index(edge)
+        self.assertTrue(rustworkx.is_directed_acyclic_graph(acyclic))
+
+    def test_empty(self):
+        graph = rustworkx.PyDiGraph()
+        arcs, ordering = rustworkx.feedback_arc_set(graph)
+        self.assertEqual([], list(arcs))
+        self.assertEqual([], list(ordering))
+
+    def test_dag(self):
+        graph = rustworkx.generators.directed_path_graph(5)
+        arcs, ordering = rustworkx.feedback_arc_set(graph, method="greedy")
+        self.assertEqual([], list(arcs))
+        self.assertEqual([0, 1, 2, 3, 4], list(ordering))
+
+    def test_cycle(self):
+        graph = rustworkx.generators.directed_cycle_graph(5)
+        for method in ["greedy", "local_search"]:
+            with self.subTest(method=method):
+                result = rustworkx.feedback_arc_set(graph, method=method)
+                self.assertEqual(1, len(result[0]))
+                self.assertValidResult(graph, result)
+
+    def test_self_loop(self):
+        graph = rustworkx.PyDiGraph()
+        graph.add_nodes_from(range(2))
+        graph.add_edge(0, 1, None)
+        loop = graph.add_edge(1, 1, None)
+        arcs, _ = rustworkx.feedback_arc_set(graph)
+        self.assertEqual([loop], list(arcs))
+
+    def test_weight_fn(self):
+        graph = rustworkx.PyDiGraph()
+        graph.add_nodes_from(range(4))
+        graph.add_edges_from([(0, 1, 5.0), (1, 2, 5.0), (2, 3, 5.0), (3, 0, 1.0)])
+        arcs, _ = rustworkx.feedback_arc_set(graph, weight_fn=float)
+        self.assertEqual([3], list(arcs))
+        arcs, _ = rustworkx.feedback_arc_set(graph, weight_fn=[1.0, 1.0, 0.5, 5.0])
+        self.assertEqual([2], list(arcs))
+
+    def test_local_search_not_worse(self):
+        graph = rustworkx.PyDiGraph()
+        graph.add_nodes_from(range(15))
+        for i in range(15):
+            for j in [1, 2, 5]:
+                graph.add_edge(i, (i + j) % 15, float((i * 3 + j) % 4 + 1))
+
+        def weight(arcs):
+            return sum(graph.get_edge_data_by_index(edge) for edge in arcs)
+
+        greedy = rustworkx.feedback_arc_set(graph, weight_fn=float, method="greedy")
+        local = rustworkx.feedback_arc_set(graph, weight_fn=float)
+        self.assertValidResult(graph, greedy)
+        self.assertValidResult(graph, local)
+        self.assertLessEqual(weight(local[0]), weight(greedy[0]))
+
+    def test_removed_nodes(self):
+        graph = rustworkx.generators.directed_cycle_graph(6)
+        graph.remove_node(2)
+        graph.add_edge(1, 3, None)
+        result = rustworkx.feedback_arc_set(graph)
+        self.assertEqual(1, len(result[0]))
+        self.assertValidResult(graph, result)
+
+    def test_invalid_method(self):
+        graph = rustworkx.generators.directed_cycle_graph(3)
+        with self.assertRaises(ValueError):
+            rustworkx.feedback_arc_set(graph, method="exact")
+
+    def test_negative_weight(self):
+        graph = rustworkx.generators.directed_cycle_graph(3)
+        with self.assertRaises(ValueError):
+            rustworkx.feedback_arc_set(graph, default_weight=-1.0)
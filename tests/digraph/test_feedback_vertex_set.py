@@ -0,0 +1,72 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+import unittest
+
+import rustworkx
+
+
+def is_dag_without(graph, removed):
+    subgraph = graph.copy()
+    subgraph.remove_nodes_from(list(removed))
+    return rustworkx.is_directed_acyclic_graph(subgraph)
+
+
+class TestFeedbackVertexSet(unittest.TestCase):
+    def test_empty(self):
+        graph = rustworkx.PyDiGraph()
+        self.assertEqual([], list(rustworkx.feedback_vertex_set(graph)))
+
+    def test_dag(self):
+        graph = rustworkx.generators.directed_grid_graph(3, 3)
+        self.assertEqual([], list(rustworkx.digraph_feedback_vertex_set(graph)))
+
+    def test_cycle(self):
+        graph = rustworkx.generators.directed_cycle_graph(6)
+        result = rustworkx.feedback_vertex_set(graph)
+        self.assertEqual(1, len(result))
+
+    def test_shared_node(self):
+        graph = rustworkx.PyDiGraph()
+        graph.add_nodes_from(range(7))
+        graph.add_edges_from_no_data([(0, 1), (1, 2), (2, 0), (2, 3), (3, 4), (4, 2), (2, 5), (5, 6), (6, 2)])
+        self.assertEqual([2], list(rustworkx.feedback_vertex_set(graph)))
+
+    def test_self_loop(self):
+        graph = rustworkx.PyDiGraph()
+        graph.add_nodes_from(range(3))
+        graph.add_edges_from_no_data([(0, 1), (1, 1), (1, 2)])
+        self.assertEqual([1], list(rustworkx.feedback_vertex_set(graph)))
+
+    def test_weight_fn(self):
+        graph = rustworkx.generators.directed_cycle_graph(4)
+        for node in graph.node_indices():
+            graph[node] = 5.0
+        graph[2] = 1.0
+        self.assertEqual([2], list(rustworkx.feedback_vertex_set(graph, weight_fn=float)))
+
+    def test_minimal(self):
+        graph = rustworkx.directed_gnm_random_graph(20, 60, seed=7)
+        result = list(rustworkx.feedback_vertex_set(graph))
+        self.assertTrue(is_dag_without(graph, result))
+        for node in result:
+            self.assertFalse(is_dag_without(graph, [n for n in result if n != node]))
+
+    def test_invalid_weight(self):
+        graph = rustworkx.generators.directed_cycle_graph(3)
+        with self.assertRaises(ValueError):
+            rustworkx.feedback_vertex_set(graph, weight_fn=lambda _: float("nan"))
+
+    def test_weight_sequence_not_accepted(self):
+        graph = rustworkx.generators.directed_cycle_graph(3)
+        with self.assertRaisesRegex(TypeError, "node weights"):
+            rustworkx.feedback_vertex_set(graph, weight_fn=[1.0, 2.0, 3.0])
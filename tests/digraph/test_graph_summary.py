@@ -0,0 +1,86 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+import unittest
+
+import rustworkx
+
+
+class TestGraphSummary(unittest.TestCase):
+    def test_empty_graph(self):
+        summary = rustworkx.digraph_graph_summary(rustworkx.PyDiGraph())
+        self.assertEqual(summary.num_nodes, 0)
+        self.assertEqual(summary.density, 0.0)
+        self.assertEqual(summary.num_components, 0)
+        self.assertTrue(summary.is_dag)
+        self.assertTrue(summary.is_forest)
+        self.assertTrue(summary.is_bipartite)
+
+    def test_path_graph(self):
+        summary = rustworkx.digraph_graph_summary(rustworkx.generators.directed_path_graph(4))
+        self.assertEqual(summary.num_nodes, 4)
+        self.assertEqual(summary.num_edges, 3)
+        self.assertAlmostEqual(summary.density, 3 / 12)
+        self.assertEqual(summary.min_degree, 1)
+        self.assertEqual(summary.max_degree, 2)
+        self.assertEqual(summary.mean_degree, 1.5)
+        self.assertEqual(summary.num_components, 1)
+        self.assertEqual(summary.average_clustering, 0.0)
+        self.assertTrue(summary.is_dag)
+        self.assertTrue(summary.is_forest)
+        self.assertTrue(summary.is_bipartite)
+
+    def test_complete_graph(self):
+        summary = rustworkx.digraph_graph_summary(rustworkx.generators.directed_complete_graph(4))
+        self.assertEqual(summary.num_edges, 12)
+        self.assertEqual(summary.density, 1.0)
+        self.assertEqual(summary.min_degree, 6)
+        self.assertEqual(summary.max_degree, 6)
+        self.assertAlmostEqual(summary.average_clustering, 1.0)
+        self.assertFalse(summary.is_dag)
+        self.assertFalse(summary.is_forest)
+        self.assertFalse(summary.is_bipartite)
+
+    def test_weak_components(self):
+        graph = rustworkx.PyDiGraph()
+        graph.extend_from_edge_list([(0, 1), (2, 1), (3, 4)])
+        summary = rustworkx.digraph_graph_summary(graph)
+        self.assertEqual(summary.num_components, 2)
+        self.assertTrue(summary.is_dag)
+        self.assertTrue(summary.is_forest)
+
+    def test_dag_not_forest(self):
+        graph = rustworkx.PyDiGraph()
+        graph.extend_from_edge_list([(0, 1), (0, 2), (1, 3), (2, 3)])
+        summary = rustworkx.digraph_graph_summary(graph)
+        self.assertTrue(summary.is_dag)
+        self.assertFalse(summary.is_forest)
+        self.assertTrue(summary.is_bipartite)
+
+    def test_directed_cycle(self):
+        summary = rustworkx.digraph_graph_summary(rustworkx.generators.directed_cycle_graph(3))
+        self.assertFalse(summary.is_dag)
+        self.assertFalse(summary.is_bipartite)
+        self.assertEqual(summary.min_degree, 2)
+
+    def test_clustering_samples_seeded(self):
+        graph = rustworkx.generators.directed_complete_graph(6)
+        graph.add_nodes_from(range(6))
+        first = rustworkx.digraph_graph_summary(graph, clustering_samples=4, seed=7)
+        second = rustworkx.digraph_graph_summary(graph, clustering_samples=4, seed=7)
+        self.assertEqual(first.average_clustering, second.average_clustering)
+        self.assertAlmostEqual(rustworkx.digraph_graph_summary(graph).average_clustering, 0.5)
+
+    def test_universal(self):
+        summary = rustworkx.graph_summary(rustworkx.generators.directed_path_graph(3))
+        self.assertIsInstance(summary, rustworkx.GraphSummary)
+        self.assertTrue(summary.is_dag)
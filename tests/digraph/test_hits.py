@@ -173,3 +173,55 @@ class TestHits(unittest.TestCase):
         for v in graph.node_indices():
             self.assertAlmostEqual(h[v], 1 / 10.0, delta=1.0e-4)
             self.assertAlmostEqual(a[v], 1 / 10.0, delta=1.0e-4)
+
+    def test_nstart_sequence(self):
+        graph = rustworkx.generators.directed_complete_graph(10)
+        by_dict = rustworkx.hits(graph, nstart={5: 1, 6: 1})
+        by_list = rustworkx.hits(graph, nstart=[0, 0, 0, 0, 0, 1, 1, 0, 0, 0])
+        for expected, scores in zip(by_dict, by_list):
+            self.assertEqual(dict(expected), dict(scores))
+        with self.assertRaises(ValueError):
+            rustworkx.hits(graph, nstart=[1.0])
+        with self.assertRaises(ValueError):
+            rustworkx.hits(graph, nstart={0: -1.0, 1: 2.0})
+
+    def test_norms(self):
+        graph = rustworkx.PyDiGraph()
+        graph.extend_from_edge_list([(0, 2), (0, 4), (1, 0), (2, 4), (4, 3), (4, 2), (5, 4)])
+        l1_h, l1_a = rustworkx.hits(graph)
+        for norm, scale in [
+            ("l1", lambda scores: sum(scores)),
+            ("l2", lambda scores: sum(x * x for x in scores) ** 0.5),
+            ("max", max),
+        ]:
+            with self.subTest(norm=norm):
+                h, a = rustworkx.hits(graph, norm=norm)
+                self.assertAlmostEqual(scale(h.values()), 1.0)
+                self.assertAlmostEqual(scale(a.values()), 1.0)
+                for v in graph.node_indices():
+                    self.assertAlmostEqual(h[v] / sum(h.values()), l1_h[v])
+                    self.assertAlmostEqual(a[v] / sum(a.values()), l1_a[v])
+        with self.assertRaises(ValueError):
+            rustworkx.hits(graph, norm="l3")
+
+    def test_diagnostics(self):
+        graph = rustworkx.generators.directed_complete_graph(4)
+        h, a, diagnostics = rustworkx.hits(graph, tol=1.0e-10, return_diagnostics=True)
+        self.assertEqual(dict(h), dict(a))
+        self.assertGreater(diagnostics["iterations"], 0)
+        self.assertLess(diagnostics["residual"], 1.0e-10)
+        h, a, diagnostics = rustworkx.hits(rustworkx.PyDiGraph(), return_diagnostics=True)
+        self.assertEqual({}, dict(h))
+        self.assertEqual(0, diagnostics["iterations"])
+
+    def test_no_convergence_partial_result(self):
+        graph = rustworkx.PyDiGraph()
+        graph.extend_from_edge_list([(0, 2), (0, 4), (1, 0), (2, 4), (4, 3), (4, 2), (5, 4)])
+        with self.assertRaises(rustworkx.FailedToConverge) as cm:
+            rustworkx.hits(graph, max_iter=2, tol=1.0e-12)
+        self.assertEqual(cm.exception.iterations, 2)
+        self.assertGreater(cm.exception.residual, 1.0e-12)
+        h, a = cm.exception.partial_result
+        self.assertEqual(list(h.keys()), list(range(6)))
+        self.assertAlmostEqual(sum(h.values()), 1.0)
+        self.assertAlmostEqual(sum(a.values()), 1.0)
@@ -348,3 +348,42 @@ class TestIsomorphic(unittest.TestCase):
             with self.subTest(id_order=id_order):
                 mapping = rustworkx.digraph_vf2_mapping(g_a, g_b, id_order=id_order, subgraph=False)
                 self.assertEqual({}, next(mapping))
+
+
+class TestDistinguishingInvariant(unittest.TestCase):
+    def test_isomorphic_graphs(self):
+        graph = rustworkx.generators.directed_cycle_graph(5)
+        graph.add_edge(0, 2, None)
+        relabeled = rustworkx.PyDiGraph()
+        relabeled.add_nodes_from(range(5))
+        for a, b in graph.edge_list():
+            relabeled.add_edge((a + 3) % 5, (b + 3) % 5, None)
+        self.assertIsNone(rustworkx.distinguishing_invariant(graph, relabeled))
+        self.assertTrue(rustworkx.is_isomorphic(graph, relabeled))
+
+    def test_direction_matters(self):
+        forward = rustworkx.generators.directed_path_graph(3)
+        outward = rustworkx.PyDiGraph()
+        outward.add_nodes_from(range(3))
+        outward.add_edges_from_no_data([(1, 0), (1, 2)])
+        self.assertEqual(
+            "degree_sequence", rustworkx.digraph_distinguishing_invariant(forward, outward)
+        )
+        self.assertFalse(rustworkx.is_isomorphic(forward, outward))
+
+    def test_weisfeiler_lehman(self):
+        # A directed 6-cycle and two directed 3-cycles only differ in their
+        # triangles, while moving a pendant node along a path only
+        # shows up in the color refinement.
+        cycle = rustworkx.generators.directed_cycle_graph(6)
+        triangles = rustworkx.generators.directed_cycle_graph(3)
+        triangles.compose(rustworkx.generators.directed_cycle_graph(3), {})
+        self.assertEqual("triangle_counts", rustworkx.distinguishing_invariant(cycle, triangles))
+        first = rustworkx.generators.directed_path_graph(6)
+        first.add_edge(1, first.add_node(None), None)
+        second = rustworkx.generators.directed_path_graph(6)
+        second.add_edge(2, second.add_node(None), None)
+        self.assertEqual(
+            "weisfeiler_lehman_colors", rustworkx.distinguishing_invariant(first, second)
+        )
+        self.assertFalse(rustworkx.is_isomorphic(first, second))
@@ -0,0 +1,37 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+
+import unittest
+
+import rustworkx
+
+
+class TestKatzSimilarity(unittest.TestCase):
+    def test_follows_edge_directions(self):
+        graph = rustworkx.generators.directed_path_graph(3)
+        scores = rustworkx.katz_similarity(graph, alpha=0.5)
+        self.assertEqual(scores[0], {0: 0.0, 1: 0.5, 2: 0.25})
+        self.assertEqual(scores[2], {0: 0.0, 1: 0.0, 2: 0.0})
+
+    def test_cycle(self):
+        graph = rustworkx.generators.directed_cycle_graph(3)
+        # The walks from 0 to 1 have lengths 1, 4, 7, ...
+        score = rustworkx.digraph_katz_similarity(graph, 0, 1, alpha=0.5, tol=1e-14)
+        self.assertAlmostEqual(score, 0.5 / (1 - 0.125))
+        scores = rustworkx.digraph_katz_similarity(graph, alpha=0.5)
+        self.assertAlmostEqual(scores[1][0], 0.25 / (1 - 0.125))
+
+    def test_single_source(self):
+        graph = rustworkx.generators.directed_path_graph(4)
+        scores = rustworkx.katz_similarity(graph, 1, alpha=0.5)
+        self.assertEqual(scores, {0: 0.0, 1: 0.0, 2: 0.5, 3: 0.25})
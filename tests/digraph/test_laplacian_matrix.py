@@ -0,0 +1,93 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+
+import unittest
+
+import numpy as np
+
+import rustworkx
+
+
+class TestLaplacianMatrix(unittest.TestCase):
+    def test_path(self):
+        graph = rustworkx.generators.directed_path_graph(3)
+        res = rustworkx.laplacian_matrix(graph)
+        expected = np.array([[1, -1, 0], [0, 1, -1], [0, 0, 0]], dtype=np.float64)
+        np.testing.assert_array_equal(res, expected)
+
+    def test_sparse(self):
+        graph = rustworkx.generators.directed_path_graph(3)
+        data, indices, indptr = rustworkx.digraph_laplacian_matrix(graph, sparse=True)
+        np.testing.assert_array_equal(data, [1, -1, 1, -1, 0])
+        np.testing.assert_array_equal(indices, [0, 1, 1, 2, 2])
+        np.testing.assert_array_equal(indptr, [0, 2, 4, 5])
+
+    def test_normalized(self):
+        graph = rustworkx.PyDiGraph()
+        graph.add_nodes_from(range(2))
+        graph.add_edges_from([(0, 1, 4.0), (1, 0, 1.0)])
+        res = rustworkx.normalized_laplacian_matrix(graph, weight_fn=float)
+        np.testing.assert_allclose(res, [[1, -2], [-0.5, 1]])
+
+
+class TestDirectedLaplacianMatrix(unittest.TestCase):
+    def test_cycle_lazy(self):
+        # A directed cycle is periodic, so the lazy walk is used and the
+        # stationary distribution is uniform
+        graph = rustworkx.generators.directed_cycle_graph(3)
+        res = rustworkx.directed_laplacian_matrix(graph)
+        expected = np.array([[0.5, -0.25, -0.25], [-0.25, 0.5, -0.25], [-0.25, -0.25, 0.5]])
+        np.testing.assert_allclose(res, expected, atol=1e-8)
+        explicit = rustworkx.directed_laplacian_matrix(graph, walk_type="lazy")
+        np.testing.assert_allclose(res, explicit)
+
+    def test_random_walk_cycle(self):
+        graph = rustworkx.generators.directed_cycle_graph(4)
+        graph.add_edge(0, 2, None)
+        res = rustworkx.directed_laplacian_matrix(graph, walk_type="random")
+        np.testing.assert_allclose(res, res.T, atol=1e-8)
+        np.testing.assert_allclose(np.diag(res), np.ones(4), atol=1e-8)
+
+    def test_pagerank_not_strongly_connected(self):
+        graph = rustworkx.generators.directed_path_graph(3)
+        res = rustworkx.directed_laplacian_matrix(graph, alpha=0.9)
+        np.testing.assert_allclose(res, res.T, atol=1e-8)
+        # The walker jumps uniformly from node 2, which has no successors
+        expected_diag = [1 - 0.1 / 3, 1 - 0.1 / 3, 1 - 1 / 3]
+        np.testing.assert_allclose(np.diag(res), expected_diag, atol=1e-8)
+
+    def test_not_strongly_connected(self):
+        graph = rustworkx.generators.directed_path_graph(3)
+        for walk_type in ["random", "lazy"]:
+            with self.assertRaises(ValueError):
+                rustworkx.directed_laplacian_matrix(graph, walk_type=walk_type)
+
+    def test_invalid_walk_type(self):
+        graph = rustworkx.generators.directed_cycle_graph(3)
+        with self.assertRaises(ValueError):
+            rustworkx.directed_laplacian_matrix(graph, walk_type="teleport")
+
+    def test_invalid_alpha(self):
+        graph = rustworkx.generators.directed_cycle_graph(3)
+        with self.assertRaises(ValueError):
+            rustworkx.directed_laplacian_matrix(graph, walk_type="pagerank", alpha=1.5)
+
+    def test_negative_weight(self):
+        graph = rustworkx.generators.directed_cycle_graph(3)
+        with self.assertRaises(ValueError):
+            rustworkx.directed_laplacian_matrix(graph, weight_fn=lambda _: -1.0)
+
+    def test_failed_to_converge(self):
+        graph = rustworkx.generators.directed_path_graph(20)
+        with self.assertRaises(rustworkx.FailedToConverge):
+            rustworkx.directed_laplacian_matrix(graph, max_iter=1)
@@ -0,0 +1,65 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+
+import unittest
+import rustworkx
+
+
+class TestLexicographicProduct(unittest.TestCase):
+    def test_null_lexicographic_null(self):
+        graph_product, _ = rustworkx.digraph_lexicographic_product(
+            rustworkx.PyDiGraph(), rustworkx.PyDiGraph()
+        )
+        self.assertEqual(graph_product.num_nodes(), 0)
+        self.assertEqual(graph_product.num_edges(), 0)
+
+    def test_path_3_lexicographic_path_2(self):
+        graph_1 = rustworkx.generators.directed_path_graph(3)
+        graph_2 = rustworkx.generators.directed_path_graph(2)
+
+        graph_product, _ = rustworkx.digraph_lexicographic_product(graph_1, graph_2)
+        self.assertEqual(graph_product.num_nodes(), 6)
+        # 2 edges of graph_1 with 4 pairs of nodes each and 3 copies of graph_2
+        self.assertEqual(graph_product.num_edges(), 11)
+
+    def test_not_commutative(self):
+        graph_1 = rustworkx.generators.directed_path_graph(3)
+        graph_2 = rustworkx.PyDiGraph()
+        graph_2.add_nodes_from([0, 1])
+
+        graph_product, _ = rustworkx.digraph_lexicographic_product(graph_1, graph_2)
+        self.assertEqual(graph_product.num_edges(), 8)
+        graph_product, _ = rustworkx.digraph_lexicographic_product(graph_2, graph_1)
+        self.assertEqual(graph_product.num_edges(), 4)
+
+    def test_node_map(self):
+        graph_1 = rustworkx.generators.directed_path_graph(2)
+        graph_2 = rustworkx.generators.directed_path_graph(2)
+
+        graph_product, node_map = rustworkx.digraph_lexicographic_product(graph_1, graph_2)
+        self.assertTrue(graph_product.has_edge(node_map[(0, 1)], node_map[(1, 0)]))
+        self.assertTrue(graph_product.has_edge(node_map[(1, 0)], node_map[(1, 1)]))
+        self.assertFalse(graph_product.has_edge(node_map[(1, 1)], node_map[(0, 0)]))
+
+    def test_edge_weights_lexicographic(self):
+        graph_1 = rustworkx.PyDiGraph()
+        graph_1.add_nodes_from([0, 1])
+        graph_1.add_edge(0, 1, "w_1")
+        graph_2 = rustworkx.PyDiGraph()
+        graph_2.add_nodes_from([0, 1])
+        graph_2.add_edge(0, 1, "w_2")
+
+        graph_product, _ = rustworkx.digraph_lexicographic_product(
+            graph_1, graph_2, edge_fn=lambda a, b: b if a is None else a.upper()
+        )
+        self.assertEqual(["W_1"] * 4 + ["w_2"] * 2, graph_product.edges())
@@ -0,0 +1,69 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+import unittest
+
+import rustworkx
+
+
+class TestMergeNodesByKey(unittest.TestCase):
+    def test_merge_keeps_direction(self):
+        graph = rustworkx.PyDiGraph(multigraph=False)
+        graph.add_nodes_from(["a", "b", "A", "c"])
+        graph.add_edges_from([(0, 1, 1), (1, 2, 2), (2, 3, 3), (3, 0, 4)])
+        node_map = graph.merge_nodes_by_key(str.lower)
+        self.assertEqual(dict(node_map), {2: 0})
+        self.assertEqual(graph.nodes(), ["a", "b", "c"])
+        self.assertEqual(
+            sorted(graph.weighted_edge_list()), [(0, 1, 1), (0, 3, 3), (1, 0, 2), (3, 0, 4)]
+        )
+
+    def test_combine_parallel_edges(self):
+        graph = rustworkx.PyDiGraph()
+        graph.add_nodes_from(["s", "x", "x", "x", "t"])
+        graph.add_edges_from(
+            [(0, 1, 1), (0, 2, 2), (0, 3, 3), (1, 4, 10), (2, 4, 20), (3, 4, 30)]
+        )
+        node_map = graph.merge_nodes_by_key(lambda node: node, weight_combo_fn=max)
+        self.assertEqual(dict(node_map), {2: 1, 3: 1})
+        self.assertEqual(sorted(graph.weighted_edge_list()), [(0, 1, 3), (1, 4, 30)])
+
+    def test_multigraph_keeps_parallel_edges(self):
+        graph = rustworkx.PyDiGraph()
+        graph.add_nodes_from(["s", "x", "x"])
+        graph.add_edges_from([(0, 1, "a"), (0, 2, "b")])
+        graph.merge_nodes_by_key(lambda node: node)
+        self.assertEqual(sorted(graph.weighted_edge_list()), [(0, 1, "a"), (0, 1, "b")])
+
+    def test_node_fn(self):
+        graph = rustworkx.PyDiGraph()
+        graph.add_nodes_from([{"id": 1, "n": 1}, {"id": 2, "n": 5}, {"id": 1, "n": 2}])
+
+        def node_fn(a, b):
+            return {"id": a["id"], "n": a["n"] + b["n"]}
+
+        graph.merge_nodes_by_key(lambda node: node["id"], node_fn=node_fn)
+        self.assertEqual(graph.nodes(), [{"id": 1, "n": 3}, {"id": 2, "n": 5}])
+
+    def test_check_cycle(self):
+        graph = rustworkx.PyDiGraph(check_cycle=True)
+        graph.add_nodes_from(["a", "b", "a"])
+        graph.add_edges_from_no_data([(0, 1), (1, 2)])
+        with self.assertRaises(rustworkx.DAGWouldCycle):
+            graph.merge_nodes_by_key(lambda node: node)
+        graph = rustworkx.PyDiGraph()
+        graph.add_nodes_from(["a", "b", "a"])
+        graph.add_edges_from_no_data([(0, 1), (1, 2)])
+        with self.assertRaises(rustworkx.DAGWouldCycle):
+            graph.merge_nodes_by_key(lambda node: node, check_cycle=True)
+        graph.merge_nodes_by_key(lambda node: node)
+        self.assertEqual(sorted(graph.edge_list()), [(0, 1), (1, 0)])
@@ -0,0 +1,96 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+import unittest
+
+import rustworkx
+
+
+class TestMinimumSpanningArborescence(unittest.TestCase):
+    def setUp(self):
+        self.graph = rustworkx.PyDiGraph()
+        self.graph.add_nodes_from(list(range(4)))
+        self.graph.add_edges_from(
+            [
+                (0, 1, 5.0),
+                (0, 2, 1.0),
+                (2, 1, 1.0),
+                (1, 3, 2.0),
+                (2, 3, 4.0),
+                (3, 2, 1.0),
+            ]
+        )
+
+    def test_arborescence_with_root(self):
+        arborescence = rustworkx.minimum_spanning_arborescence(
+            self.graph, root=0, weight_fn=float
+        )
+        self.assertEqual(arborescence.node_indices(), self.graph.node_indices())
+        self.assertEqual(
+            sorted(arborescence.weighted_edge_list()),
+            [(0, 2, 1.0), (1, 3, 2.0), (2, 1, 1.0)],
+        )
+
+    def test_arborescence_best_root(self):
+        self.graph.add_edge(1, 0, 0.5)
+        arborescence = rustworkx.minimum_spanning_arborescence(self.graph, weight_fn=float)
+        self.assertEqual(
+            sorted(arborescence.weighted_edge_list()),
+            [(1, 0, 0.5), (2, 1, 1.0), (3, 2, 1.0)],
+        )
+
+    def test_default_weight(self):
+        arborescence = rustworkx.minimum_spanning_arborescence(self.graph, root=0)
+        self.assertEqual(len(arborescence.edge_list()), 3)
+        self.assertEqual(arborescence.in_degree(0), 0)
+
+    def test_cycle_contraction(self):
+        graph = rustworkx.PyDiGraph()
+        graph.add_nodes_from(list(range(4)))
+        graph.add_edges_from(
+            [
+                (0, 1, 10.0),
+                (0, 2, 12.0),
+                (0, 3, 11.0),
+                (1, 2, 1.0),
+                (2, 3, 1.0),
+                (3, 1, 1.0),
+            ]
+        )
+        arborescence = rustworkx.minimum_spanning_arborescence(graph, root=0, weight_fn=float)
+        self.assertEqual(
+            sorted(arborescence.weighted_edge_list()),
+            [(0, 1, 10.0), (1, 2, 1.0), (2, 3, 1.0)],
+        )
+
+    def test_no_arborescence(self):
+        graph = rustworkx.PyDiGraph()
+        graph.add_nodes_from(list(range(3)))
+        graph.add_edges_from([(0, 1, 1.0), (2, 1, 1.0)])
+        with self.assertRaises(ValueError):
+            rustworkx.minimum_spanning_arborescence(graph, weight_fn=float)
+        with self.assertRaises(ValueError):
+            rustworkx.minimum_spanning_arborescence(graph, root=0, weight_fn=float)
+
+    def test_invalid_root(self):
+        with self.assertRaises(rustworkx.InvalidNode):
+            rustworkx.minimum_spanning_arborescence(self.graph, root=10)
+
+    def test_nan_weight(self):
+        self.graph.add_edge(0, 3, float("nan"))
+        with self.assertRaises(ValueError):
+            rustworkx.minimum_spanning_arborescence(self.graph, root=0, weight_fn=float)
+
+    def test_empty_graph(self):
+        graph = rustworkx.PyDiGraph()
+        arborescence = rustworkx.minimum_spanning_arborescence(graph)
+        self.assertEqual(arborescence.num_nodes(), 0)
@@ -0,0 +1,82 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+import unittest
+
+import rustworkx
+
+
+class TestOnionDecomposition(unittest.TestCase):
+    def setUp(self):
+        # This is the example graph in Figure 1 from Batagelj and
+        # Zaversnik's paper titled An O(m) Algorithm for Cores
+        # Decomposition of Networks, 2003,
+        # http://arXiv.org/abs/cs/0310049.
+        self.graph = rustworkx.PyDiGraph()
+        self.graph.add_nodes_from(list(range(21)))
+        self.graph.add_edges_from_no_data(
+            [
+                (0, 2),
+                (0, 3),
+                (0, 5),
+                (1, 4),
+                (1, 6),
+                (1, 7),
+                (2, 3),
+                (3, 5),
+                (2, 5),
+                (5, 6),
+                (4, 6),
+                (4, 7),
+                (6, 7),
+                (5, 8),
+                (6, 8),
+                (6, 9),
+                (8, 9),
+                (0, 10),
+                (1, 10),
+                (1, 11),
+                (10, 11),
+                (12, 13),
+                (13, 15),
+                (14, 15),
+                (12, 14),
+                (8, 19),
+                (11, 16),
+                (11, 17),
+                (12, 18),
+            ]
+        )
+
+    def test_paper_example(self):
+        cores, layers = rustworkx.onion_decomposition(self.graph)
+        self.assertEqual(cores, rustworkx.core_number(self.graph))
+        expected = [5, 5, 5, 5, 5, 6, 6, 5, 4, 3, 4, 3, 3, 3, 3, 3, 2, 2, 2, 2, 1]
+        self.assertEqual(layers, dict(enumerate(expected)))
+
+    def test_empty(self):
+        self.assertEqual(rustworkx.onion_decomposition(rustworkx.PyDiGraph()), ({}, {}))
+
+    def test_removed_nodes(self):
+        self.graph.remove_nodes_from(list(range(12)))
+        cores, layers = rustworkx.onion_decomposition(self.graph)
+        self.assertEqual(list(cores), list(range(12, 21)))
+        self.assertEqual(cores, {12: 2, 13: 2, 14: 2, 15: 2, 16: 0, 17: 0, 18: 1, 19: 0, 20: 0})
+        self.assertEqual(layers, {12: 3, 13: 3, 14: 3, 15: 3, 16: 1, 17: 1, 18: 2, 19: 1, 20: 1})
+
+    def test_self_loops_and_parallel_edges(self):
+        graph = rustworkx.PyDiGraph()
+        graph.add_nodes_from(list(range(3)))
+        graph.add_edges_from_no_data([(0, 1), (1, 0), (1, 2), (1, 2), (2, 2)])
+        cores, layers = rustworkx.digraph_onion_decomposition(graph)
+        self.assertEqual(cores, {0: 1, 1: 1, 2: 1})
+        self.assertEqual(layers, {0: 1, 1: 2, 2: 1})
@@ -321,3 +321,125 @@ class TestPageRank(unittest.TestCase):
 
         for v in multi_graph.node_indices():
             self.assertAlmostEqual(ranks_multi[v], ranks_weight[v], delta=1.0e-4)
+
+    def dangling_graph(self):
+        rx_graph = rustworkx.PyDiGraph()
+        rx_graph.extend_from_edge_list(
+            [(0, 1), (0, 2), (2, 0), (2, 1), (2, 4), (3, 4), (3, 5), (4, 3), (4, 5), (5, 4)]
+        )
+        nx_graph = nx.DiGraph()
+        nx_graph.add_edges_from(rx_graph.edge_list())
+        return rx_graph, nx_graph
+
+    def test_linear_method(self):
+        rx_graph, nx_graph = self.dangling_graph()
+        personalization = {0: 1.0, 3: 2.0}
+        for kwargs in [{}, {"personalization": personalization}, {"dangling": {1: 1.0, 4: 3.0}}]:
+            with self.subTest(**kwargs):
+                rx_ranks = rustworkx.pagerank(
+                    rx_graph, alpha=0.9, tol=1.0e-10, method="linear", **kwargs
+                )
+                nx_ranks = pagerank_python(nx_graph, alpha=0.9, tol=1.0e-10, **kwargs)
+                for v in rx_graph.node_indices():
+                    self.assertAlmostEqual(rx_ranks[v], nx_ranks[v], delta=1.0e-8)
+
+    def test_sequence_personalization_and_dangling(self):
+        rx_graph, nx_graph = self.dangling_graph()
+        # The entries for the removed node 6 are ignored
+        rx_graph.add_nodes_from([None, None])
+        rx_graph.remove_node(6)
+        nx_graph.add_node(7)
+        personalization = [1.0, 0.0, 0.0, 2.0, 0.0, 0.0, 5.0, 0.0]
+        dangling = [0.0, 1.0, 0.0, 0.0, 3.0, 0.0, 5.0, 0.0]
+        rx_ranks = rustworkx.pagerank(
+            rx_graph, personalization=personalization, dangling=dangling, tol=1.0e-10
+        )
+        nx_ranks = pagerank_python(
+            nx_graph,
+            personalization={0: 1.0, 3: 2.0},
+            dangling={1: 1.0, 4: 3.0},
+            tol=1.0e-10,
+        )
+        self.assertEqual(list(rx_ranks.keys()), [0, 1, 2, 3, 4, 5, 7])
+        for v in rx_graph.node_indices():
+            self.assertAlmostEqual(rx_ranks[v], nx_ranks[v], delta=1.0e-8)
+
+    def test_dangling_policies(self):
+        rx_graph, nx_graph = self.dangling_graph()
+        personalization = {0: 1.0, 3: 2.0}
+        uniform = rustworkx.pagerank(
+            rx_graph, personalization=personalization, dangling="uniform", tol=1.0e-10
+        )
+        nx_uniform = pagerank_python(
+            nx_graph,
+            personalization=personalization,
+            dangling={v: 1.0 for v in range(6)},
+            tol=1.0e-10,
+        )
+        for v in rx_graph.node_indices():
+            self.assertAlmostEqual(uniform[v], nx_uniform[v], delta=1.0e-8)
+        default = rustworkx.pagerank(rx_graph, personalization=personalization)
+        explicit = rustworkx.pagerank(
+            rx_graph, personalization=personalization, dangling="personalization"
+        )
+        self.assertEqual(dict(default), dict(explicit))
+        # A dangling node keeping its rank is the same as giving it a self-loop
+        self_policy = rustworkx.pagerank(rx_graph, dangling="self", tol=1.0e-10)
+        rx_graph.add_edge(1, 1, None)
+        self_loop = rustworkx.pagerank(rx_graph, tol=1.0e-10)
+        for method in ["power", "linear"]:
+            with self.subTest(method=method):
+                ranks = rustworkx.pagerank(
+                    rx_graph, dangling="self", tol=1.0e-10, method=method
+                )
+                for v in rx_graph.node_indices():
+                    self.assertAlmostEqual(self_policy[v], self_loop[v], delta=1.0e-8)
+                    self.assertAlmostEqual(ranks[v], self_loop[v], delta=1.0e-8)
+
+    def test_diagnostics(self):
+        graph = rustworkx.generators.directed_cycle_graph(5)
+        graph.add_edge(0, 2, None)
+        for method in ["power", "linear"]:
+            with self.subTest(method=method):
+                ranks, diagnostics = rustworkx.pagerank(
+                    graph, method=method, return_diagnostics=True
+                )
+                self.assertAlmostEqual(sum(ranks.values()), 1.0)
+                self.assertEqual(diagnostics["method"], method)
+                self.assertGreater(diagnostics["iterations"], 0)
+                self.assertLess(diagnostics["residual"], 5 * 1.0e-6)
+        ranks, diagnostics = rustworkx.pagerank(rustworkx.PyDiGraph(), return_diagnostics=True)
+        self.assertEqual({}, dict(ranks))
+        self.assertEqual(0, diagnostics["iterations"])
+
+    def test_no_convergence_partial_result(self):
+        graph = rustworkx.generators.directed_cycle_graph(5)
+        graph.add_edge(0, 2, None)
+        for method in ["power", "linear"]:
+            with self.subTest(method=method):
+                with self.assertRaises(rustworkx.FailedToConverge) as cm:
+                    rustworkx.pagerank(graph, max_iter=1, tol=1.0e-12, method=method)
+                self.assertEqual(cm.exception.iterations, 1)
+                self.assertGreater(cm.exception.residual, 5 * 1.0e-12)
+                self.assertEqual(list(cm.exception.partial_result.keys()), list(range(5)))
+                ranks = rustworkx.pagerank(graph, tol=1.0e-12, max_iter=1000, method=method)
+                self.assertNotEqual(dict(ranks), dict(cm.exception.partial_result))
+
+    def test_invalid_arguments(self):
+        graph = rustworkx.generators.directed_cycle_graph(3)
+        with self.assertRaises(ValueError):
+            rustworkx.pagerank(graph, method="eigen")
+        with self.assertRaises(ValueError):
+            rustworkx.pagerank(graph, method="linear", alpha=1.0)
+        with self.assertRaises(ValueError):
+            rustworkx.pagerank(graph, dangling="nowhere")
+        with self.assertRaises(ValueError):
+            rustworkx.pagerank(graph, personalization={0: 0.0, 1: 0.0})
+        with self.assertRaises(ValueError):
+            rustworkx.pagerank(graph, personalization={0: 1.0, 1: -1.0})
+        with self.assertRaises(ValueError):
+            rustworkx.pagerank(graph, personalization=[1.0, 1.0])
+        with self.assertRaises(ValueError):
+            rustworkx.pagerank(graph, nstart={5: 1.0})
+        with self.assertRaises(TypeError):
+            rustworkx.pagerank(graph, personalization=1.0)
@@ -0,0 +1,48 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+import unittest
+
+import rustworkx
+
+
+def largest_component(graph):
+    return max((len(c) for c in rustworkx.weakly_connected_components(graph)), default=0)
+
+
+class TestPercolation(unittest.TestCase):
+    def test_weak_components(self):
+        graph = rustworkx.directed_gnp_random_graph(30, 0.05, seed=3)
+        for remove_edges in [False, True]:
+            order, sizes, _ = rustworkx.digraph_percolation(
+                graph, "betweenness", remove_edges=remove_edges
+            )
+            removed = graph.copy()
+            self.assertEqual(sizes[0], largest_component(removed))
+            for position, index in enumerate(order):
+                if remove_edges:
+                    removed.remove_edge_from_index(index)
+                else:
+                    removed.remove_node(index)
+                self.assertEqual(sizes[position + 1], largest_component(removed))
+
+    def test_degree_counts_both_directions(self):
+        graph = rustworkx.PyDiGraph()
+        graph.add_nodes_from(range(5))
+        graph.add_edges_from_no_data([(0, 1), (2, 1), (3, 1), (3, 4)])
+        order, sizes, _ = rustworkx.digraph_percolation(graph, "degree")
+        self.assertEqual(order[:2], [1, 3])
+        self.assertEqual(sizes[:3], [5, 2, 1])
+
+    def test_universal(self):
+        graph = rustworkx.generators.directed_path_graph(4)
+        self.assertEqual(rustworkx.percolation(graph, [1], threshold=0.5), ([1], [4, 2], 0.25))
@@ -0,0 +1,49 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+
+import unittest
+
+import rustworkx
+
+
+class TestRandomWalkWithRestart(unittest.TestCase):
+    def test_directed_path(self):
+        graph = rustworkx.PyDiGraph()
+        graph.extend_from_edge_list([(0, 1), (1, 2), (3, 0)])
+        res = rustworkx.random_walk_with_restart(
+            graph, [0], restart_prob=0.5, tol=1e-12, max_iter=1000
+        )
+        self.assertAlmostEqual(sum(res.values()), 1.0)
+        self.assertAlmostEqual(res[1], 0.5 * res[0])
+        self.assertAlmostEqual(res[2], 0.5 * res[1])
+        self.assertEqual(res[3], 0.0)
+
+    def test_cycle_is_symmetric_under_rotation(self):
+        graph = rustworkx.generators.directed_cycle_graph(5)
+        first = rustworkx.digraph_random_walk_with_restart(graph, [0])
+        second = rustworkx.digraph_random_walk_with_restart(graph, [2])
+        for node in range(5):
+            self.assertAlmostEqual(first[node], second[(node + 2) % 5])
+
+    def test_multiple_seeds(self):
+        graph = rustworkx.PyDiGraph()
+        graph.add_nodes_from(range(3))
+        res = rustworkx.random_walk_with_restart(graph, [0, 0, 1])
+        self.assertAlmostEqual(res[0], 2 / 3)
+        self.assertAlmostEqual(res[1], 1 / 3)
+        self.assertEqual(res[2], 0.0)
+
+    def test_invalid_seed(self):
+        graph = rustworkx.PyDiGraph()
+        with self.assertRaises(rustworkx.InvalidNode):
+            rustworkx.random_walk_with_restart(graph, [0])
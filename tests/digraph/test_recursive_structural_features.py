@@ -0,0 +1,34 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+
+import unittest
+
+import numpy as np
+
+import rustworkx
+
+
+class TestRecursiveStructuralFeatures(unittest.TestCase):
+    def test_directed(self):
+        graph = rustworkx.PyDiGraph()
+        graph.extend_from_edge_list([(0, 1), (1, 2), (2, 0), (3, 0)])
+        res = rustworkx.recursive_structural_features(graph, rounds=1)
+        self.assertEqual(res.shape, (4, 15))
+        np.testing.assert_array_equal(res[0][:5], [2, 1, 4, 0, 0])
+        np.testing.assert_array_equal(res[3][:5], [0, 1, 1, 1, 1])
+        np.testing.assert_array_equal(res[3][5:7], [2, 2])
+
+    def test_default_rounds(self):
+        graph = rustworkx.generators.directed_path_graph(3)
+        res = rustworkx.digraph_recursive_structural_features(graph)
+        self.assertEqual(res.shape, (3, 35))
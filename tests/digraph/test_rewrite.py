@@ -0,0 +1,186 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+
+import unittest
+
+import rustworkx
+
+
+def _edge_pattern(source, target):
+    pattern = rustworkx.PyDiGraph()
+    pattern.add_nodes_from([source, target])
+    pattern.add_edge(0, 1, None)
+    return pattern
+
+
+class TestRewrite(unittest.TestCase):
+    def test_contract_edge(self):
+        graph = rustworkx.PyDiGraph()
+        graph.add_nodes_from(["x", "a", "b", "y"])
+        graph.add_edges_from([(0, 1, "xa"), (1, 2, "ab"), (2, 3, "by")])
+        replacement = rustworkx.PyDiGraph()
+        replacement.add_node("ab")
+        res = rustworkx.digraph_rewrite(
+            graph,
+            _edge_pattern("a", "b"),
+            replacement,
+            interface={0: 0, 1: 0},
+            node_matcher=lambda x, y: x == y,
+        )
+        self.assertEqual(len(res), 1)
+        matched, added = res[0]
+        self.assertEqual(dict(matched), {0: 1, 1: 2})
+        new_node = added[0]
+        self.assertEqual(graph[new_node], "ab")
+        self.assertEqual(
+            sorted(graph.weighted_edge_list()), sorted([(0, new_node, "xa"), (new_node, 3, "by")])
+        )
+        self.assertEqual(graph.num_nodes(), 3)
+
+    def test_non_overlapping_matches(self):
+        graph = rustworkx.generators.directed_path_graph(5)
+        replacement = rustworkx.PyDiGraph()
+        replacement.add_node("merged")
+        res = rustworkx.digraph_rewrite(
+            graph, _edge_pattern(None, None), replacement, interface={0: 0, 1: 0}
+        )
+        self.assertEqual(len(res), 2)
+        used = set()
+        for matched, _ in res:
+            nodes = set(matched.values())
+            self.assertTrue(used.isdisjoint(nodes))
+            used |= nodes
+        self.assertEqual(graph.num_nodes(), 3)
+        self.assertEqual(graph.num_edges(), 2)
+
+    def test_no_match(self):
+        graph = rustworkx.generators.directed_path_graph(3)
+        pattern = rustworkx.generators.directed_cycle_graph(3)
+        res = rustworkx.digraph_rewrite(graph, pattern, rustworkx.PyDiGraph())
+        self.assertEqual(res, [])
+        self.assertEqual(graph.num_nodes(), 3)
+        self.assertEqual(graph.num_edges(), 2)
+
+    def test_empty_replacement_deletes(self):
+        graph = rustworkx.PyDiGraph()
+        graph.add_nodes_from(["x", "a", "b", "y"])
+        graph.add_edges_from_no_data([(0, 1), (1, 2), (2, 3)])
+        rustworkx.digraph_rewrite(
+            graph,
+            _edge_pattern("a", "b"),
+            rustworkx.PyDiGraph(),
+            node_matcher=lambda x, y: x == y,
+        )
+        self.assertEqual(graph.nodes(), ["x", "y"])
+        self.assertEqual(graph.num_edges(), 0)
+
+    def test_node_and_edge_fn(self):
+        graph = rustworkx.PyDiGraph()
+        graph.add_nodes_from([1, 2])
+        graph.add_edge(0, 1, None)
+        replacement = rustworkx.PyDiGraph()
+        replacement.add_nodes_from(["sum", "product"])
+        replacement.add_edge(0, 1, "edge")
+
+        def node_fn(payload, matched):
+            if payload == "sum":
+                return matched[0] + matched[1]
+            return matched[0] * matched[1]
+
+        res = rustworkx.digraph_rewrite(
+            graph,
+            _edge_pattern(None, None),
+            replacement,
+            node_fn=node_fn,
+            edge_fn=lambda payload, matched: (payload, sorted(matched.values())),
+        )
+        added = res[0][1]
+        self.assertEqual(graph[added[0]], 3)
+        self.assertEqual(graph[added[1]], 2)
+        self.assertEqual(graph.get_edge_data(added[0], added[1]), ("edge", [1, 2]))
+
+    def test_edge_matcher(self):
+        graph = rustworkx.PyDiGraph()
+        graph.add_nodes_from(range(3))
+        graph.add_edges_from([(0, 1, "keep"), (1, 2, "replace")])
+        pattern = rustworkx.PyDiGraph()
+        pattern.add_nodes_from(range(2))
+        pattern.add_edge(0, 1, "replace")
+        res = rustworkx.digraph_rewrite(
+            graph, pattern, rustworkx.PyDiGraph(), edge_matcher=lambda x, y: x == y
+        )
+        self.assertEqual(dict(res[0][0]), {0: 1, 1: 2})
+        self.assertEqual(graph.node_indices(), [0])
+
+    def test_induced(self):
+        graph = rustworkx.PyDiGraph()
+        graph.add_nodes_from(["a", "b"])
+        graph.add_edges_from_no_data([(0, 1), (1, 0)])
+        pattern = _edge_pattern("a", "b")
+        matcher = lambda x, y: x == y  # noqa: E731
+        res = rustworkx.digraph_rewrite(graph, pattern, rustworkx.PyDiGraph(), node_matcher=matcher)
+        self.assertEqual(res, [])
+        res = rustworkx.digraph_rewrite(
+            graph, pattern, rustworkx.PyDiGraph(), node_matcher=matcher, induced=False
+        )
+        self.assertEqual(len(res), 1)
+        self.assertEqual(graph.num_nodes(), 0)
+
+    def test_callback_error_leaves_graph_unchanged(self):
+        graph = rustworkx.generators.directed_path_graph(4)
+        replacement = rustworkx.PyDiGraph()
+        replacement.add_node(None)
+
+        def node_fn(_payload, _matched):
+            raise ValueError("fail")
+
+        with self.assertRaises(ValueError):
+            rustworkx.digraph_rewrite(
+                graph, _edge_pattern(None, None), replacement, node_fn=node_fn
+            )
+        self.assertEqual(graph.num_nodes(), 4)
+        self.assertEqual(graph.edge_list(), [(0, 1), (1, 2), (2, 3)])
+
+    def test_check_cycle(self):
+        graph = rustworkx.PyDiGraph(check_cycle=True)
+        graph.add_nodes_from(["a", "b", "y"])
+        graph.add_edges_from_no_data([(0, 1), (0, 2), (2, 1)])
+        replacement = rustworkx.PyDiGraph()
+        replacement.add_nodes_from(["a", "b"])
+        replacement.add_edge(1, 0, None)
+        with self.assertRaises(rustworkx.DAGWouldCycle):
+            rustworkx.digraph_rewrite(
+                graph,
+                _edge_pattern("a", "b"),
+                replacement,
+                interface={0: 0, 1: 1},
+                node_matcher=lambda x, y: x == y,
+            )
+        self.assertEqual(graph.nodes(), ["a", "b", "y"])
+        self.assertEqual(graph.edge_list(), [(0, 1), (0, 2), (2, 1)])
+
+    def test_empty_pattern(self):
+        graph = rustworkx.generators.directed_path_graph(2)
+        with self.assertRaises(ValueError):
+            rustworkx.digraph_rewrite(graph, rustworkx.PyDiGraph(), rustworkx.PyDiGraph())
+
+    def test_invalid_interface(self):
+        graph = rustworkx.generators.directed_path_graph(2)
+        with self.assertRaises(rustworkx.InvalidNode):
+            rustworkx.digraph_rewrite(
+                graph, _edge_pattern(None, None), rustworkx.PyDiGraph(), interface={0: 0}
+            )
+        with self.assertRaises(rustworkx.InvalidNode):
+            rustworkx.digraph_rewrite(
+                graph, _edge_pattern(None, None), rustworkx.PyDiGraph(), interface={5: 0}
+            )
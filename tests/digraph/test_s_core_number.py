@@ -0,0 +1,97 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+import unittest
+
+import rustworkx
+
+
+class TestSCoreNumber(unittest.TestCase):
+    def test_empty(self):
+        self.assertEqual(rustworkx.digraph_s_core_number(rustworkx.PyDiGraph()), {})
+
+    def test_isolated_nodes(self):
+        graph = rustworkx.PyDiGraph()
+        graph.add_nodes_from(range(3))
+        graph.add_edge(0, 1, 2.5)
+        self.assertEqual(
+            rustworkx.digraph_s_core_number(graph, weight_fn=float), {0: 2.5, 1: 2.5, 2: 0.0}
+        )
+
+    def test_unit_weights_match_core_number(self):
+        graph = rustworkx.PyDiGraph()
+        graph.add_nodes_from(range(8))
+        graph.add_edges_from_no_data(
+            [(0, 1), (0, 2), (0, 3), (1, 2), (1, 3), (2, 3), (3, 4), (4, 5), (4, 6), (5, 6), (6, 7)]
+        )
+        cores = rustworkx.digraph_core_number(graph)
+        s_cores = rustworkx.digraph_s_core_number(graph)
+        self.assertEqual(s_cores, {node: float(core) for node, core in cores.items()})
+
+    def test_default_weight(self):
+        graph = rustworkx.generators.directed_cycle_graph(4)
+        self.assertEqual(
+            rustworkx.digraph_s_core_number(graph, default_weight=0.5),
+            {node: 1.0 for node in range(4)},
+        )
+
+    def test_weights(self):
+        # A heavy triangle with a light edge to node 3
+        graph = rustworkx.PyDiGraph()
+        graph.extend_from_weighted_edge_list([(0, 1, 3.0), (1, 2, 3.0), (2, 0, 3.0), (2, 3, 1.0)])
+        res = rustworkx.digraph_s_core_number(graph, weight_fn=float)
+        self.assertEqual(res, {0: 6.0, 1: 6.0, 2: 6.0, 3: 1.0})
+
+    def test_core_is_monotone(self):
+        graph = rustworkx.PyDiGraph()
+        graph.extend_from_weighted_edge_list([(0, 1, 3.0), (1, 2, 1.0), (0, 3, 1.0)])
+        res = rustworkx.digraph_s_core_number(graph, weight_fn=float)
+        self.assertEqual(res, {0: 3.0, 1: 3.0, 2: 1.0, 3: 1.0})
+
+    def test_parallel_edges_and_self_loops(self):
+        graph = rustworkx.PyDiGraph()
+        graph.extend_from_weighted_edge_list([(0, 1, 2.0), (0, 1, 3.0), (1, 1, 10.0)])
+        res = rustworkx.digraph_s_core_number(graph, weight_fn=float)
+        self.assertEqual(res, {0: 5.0, 1: 5.0})
+
+    def test_removed_nodes(self):
+        graph = rustworkx.generators.directed_path_graph(4)
+        graph.remove_node(0)
+        self.assertEqual(rustworkx.digraph_s_core_number(graph), {1: 1.0, 2: 1.0, 3: 1.0})
+
+    def test_negative_weight(self):
+        graph = rustworkx.PyDiGraph()
+        graph.extend_from_weighted_edge_list([(0, 1, 1.0), (1, 2, -1.0)])
+        with self.assertRaises(ValueError):
+            rustworkx.digraph_s_core_number(graph, weight_fn=float)
+
+    def test_nan_weight(self):
+        graph = rustworkx.PyDiGraph()
+        graph.extend_from_weighted_edge_list([(0, 1, float("nan"))])
+        with self.assertRaises(ValueError):
+            rustworkx.digraph_s_core_number(graph, weight_fn=float)
+
+    def test_weight_fn_error(self):
+        graph = rustworkx.PyDiGraph()
+        graph.extend_from_weighted_edge_list([(0, 1, "a")])
+        with self.assertRaises(ValueError):
+            rustworkx.digraph_s_core_number(graph, weight_fn=float)
+
+    def test_universal(self):
+        graph = rustworkx.generators.directed_star_graph(4)
+        self.assertEqual(rustworkx.s_core_number(graph), {0: 1.0, 1: 1.0, 2: 1.0, 3: 1.0})
+
+    def test_directions_add_up(self):
+        graph = rustworkx.PyDiGraph()
+        graph.extend_from_weighted_edge_list([(0, 1, 1.0), (1, 0, 2.0), (1, 2, 0.5)])
+        res = rustworkx.digraph_s_core_number(graph, weight_fn=float)
+        self.assertEqual(res, {0: 3.0, 1: 3.0, 2: 0.5})
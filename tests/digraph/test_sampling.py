@@ -0,0 +1,71 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+import unittest
+
+import rustworkx
+
+
+class TestSampling(unittest.TestCase):
+    def setUp(self):
+        self.graph = rustworkx.generators.directed_grid_graph(8, 8)
+        for node in self.graph.node_indices():
+            self.graph[node] = node
+
+    def assertInducedSubgraph(self, sample, node_map):
+        self.assertEqual(sorted(node_map.keys()), list(sample.node_indices()))
+        for node, original in node_map.items():
+            self.assertEqual(sample[node], self.graph[original])
+        expected = {
+            (node_map[a], node_map[b])
+            for a in sample.node_indices()
+            for b in sample.node_indices()
+            if self.graph.has_edge(node_map[a], node_map[b])
+        }
+        edges = {(node_map[a], node_map[b]) for a, b in sample.edge_list()}
+        self.assertEqual(edges, expected)
+
+    def test_samplers(self):
+        for sampler in (
+            rustworkx.digraph_sample_nodes,
+            rustworkx.digraph_random_walk_sample,
+            rustworkx.digraph_forest_fire_sample,
+            rustworkx.sample_nodes,
+        ):
+            sample, node_map = sampler(self.graph, 12, seed=7)
+            self.assertIsInstance(sample, rustworkx.PyDiGraph)
+            self.assertEqual(sample.num_nodes(), 12)
+            self.assertInducedSubgraph(sample, node_map)
+
+    def test_sample_edges(self):
+        sample, node_map = rustworkx.digraph_sample_edges(self.graph, 8, seed=8)
+        self.assertIsInstance(sample, rustworkx.PyDiGraph)
+        self.assertGreaterEqual(sample.num_edges(), 8)
+        self.assertInducedSubgraph(sample, node_map)
+
+    def test_random_walk_follows_edges(self):
+        # Without restarts a walk on a directed cycle visits consecutive nodes
+        graph = rustworkx.generators.directed_cycle_graph(10)
+        for seed in range(5):
+            _, node_map = rustworkx.random_walk_sample(
+                graph, 4, restart_probability=0.0, seed=seed
+            )
+            nodes = set(node_map.values())
+            self.assertTrue(
+                any(nodes == {(start + i) % 10 for i in range(4)} for start in range(10))
+            )
+
+    def test_invalid_arguments(self):
+        with self.assertRaises(ValueError):
+            rustworkx.digraph_forest_fire_sample(self.graph, 100)
+        with self.assertRaises(ValueError):
+            rustworkx.digraph_random_walk_sample(self.graph, 10, restart_probability=2.0)
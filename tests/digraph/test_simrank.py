@@ -0,0 +1,93 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+
+import unittest
+
+import rustworkx
+
+
+class TestSimRank(unittest.TestCase):
+    def setUp(self):
+        self.graph = rustworkx.PyDiGraph()
+        self.graph.extend_from_edge_list([(0, 2), (0, 3), (1, 2), (1, 3), (1, 4)])
+
+    def test_all_pairs(self):
+        scores = rustworkx.simrank_similarity(self.graph, decay=0.8, tol=1e-10)
+        self.assertEqual(set(scores), set(self.graph.node_indices()))
+        for node in self.graph.node_indices():
+            self.assertEqual(scores[node][node], 1.0)
+        # 2 and 3 have the same predecessors 0 and 1, which aren't similar
+        self.assertAlmostEqual(scores[2][3], 0.8 * 2 / 4)
+        self.assertAlmostEqual(scores[2][4], 0.8 / 2)
+        self.assertEqual(scores[0][1], 0.0)
+
+    def test_single_source(self):
+        scores = rustworkx.simrank_similarity(self.graph, 2, tol=1e-10)
+        self.assertEqual(list(scores), [0, 1, 2, 3, 4])
+        self.assertAlmostEqual(scores[3], 0.4)
+
+    def test_pair(self):
+        score = rustworkx.simrank_similarity(self.graph, 3, 4, decay=0.5, tol=1e-10)
+        self.assertAlmostEqual(score, 0.25)
+
+    def test_undirected(self):
+        graph = rustworkx.generators.star_graph(4)
+        scores = rustworkx.simrank_similarity(graph, tol=1e-10)
+        self.assertAlmostEqual(scores[1][2], 0.8)
+        self.assertAlmostEqual(scores[1][2], scores[2][1])
+
+    def test_removed_node(self):
+        self.graph.remove_node(1)
+        scores = rustworkx.simrank_similarity(self.graph)
+        self.assertNotIn(1, scores)
+        self.assertAlmostEqual(scores[2][3], 0.8)
+
+    def test_invalid_arguments(self):
+        with self.assertRaises(rustworkx.InvalidNode):
+            rustworkx.simrank_similarity(self.graph, 10)
+        with self.assertRaises(ValueError):
+            rustworkx.simrank_similarity(self.graph, decay=1.5)
+        with self.assertRaises(ValueError):
+            rustworkx.simrank_similarity(self.graph, target=1)
+
+    def test_failed_to_converge(self):
+        graph = rustworkx.generators.cycle_graph(3)
+        with self.assertRaises(rustworkx.FailedToConverge):
+            rustworkx.simrank_similarity(graph, max_iter=2, tol=1e-12)
+
+
+class TestWeightedSimRank(unittest.TestCase):
+    def test_evidence(self):
+        graph = rustworkx.PyDiGraph()
+        graph.extend_from_weighted_edge_list([(0, 2, 1.0), (0, 3, 1.0), (1, 2, 1.0), (1, 3, 1.0)])
+        score = rustworkx.weighted_simrank_similarity(graph, 2, 3, weight_fn=float, tol=1e-10)
+        self.assertAlmostEqual(score, 0.75 * 0.8 * 0.5)
+
+    def test_default_weight(self):
+        graph = rustworkx.generators.directed_star_graph(3)
+        scores = rustworkx.weighted_simrank_similarity(graph, 1, default_weight=2.0)
+        self.assertAlmostEqual(scores[2], 0.5 * 0.8)
+
+    def test_undirected(self):
+        graph = rustworkx.PyGraph()
+        graph.extend_from_weighted_edge_list([(0, 1, 2.0), (0, 2, 2.0), (2, 3, 1.0)])
+        scores = rustworkx.weighted_simrank_similarity(graph, weight_fn=float, tol=1e-10)
+        for a in graph.node_indices():
+            for b in graph.node_indices():
+                self.assertAlmostEqual(scores[a][b], scores[b][a])
+
+    def test_negative_weight(self):
+        graph = rustworkx.PyDiGraph()
+        graph.extend_from_weighted_edge_list([(0, 1, -1.0)])
+        with self.assertRaises(ValueError):
+            rustworkx.weighted_simrank_similarity(graph, weight_fn=float)
@@ -0,0 +1,81 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+
+import unittest
+import rustworkx
+
+
+class TestStrongProduct(unittest.TestCase):
+    def test_null_strong_null(self):
+        graph_product, node_map = rustworkx.digraph_strong_product(
+            rustworkx.PyDiGraph(), rustworkx.PyDiGraph()
+        )
+        self.assertEqual(graph_product.num_nodes(), 0)
+        self.assertEqual(graph_product.num_edges(), 0)
+        self.assertEqual(len(node_map), 0)
+
+    def test_path_2_strong_path_3(self):
+        graph_1 = rustworkx.generators.directed_path_graph(2)
+        graph_2 = rustworkx.generators.directed_path_graph(3)
+
+        graph_product, _ = rustworkx.digraph_strong_product(graph_1, graph_2)
+        self.assertEqual(graph_product.num_nodes(), 6)
+        self.assertEqual(graph_product.num_edges(), 9)
+
+    def test_node_weights_strong(self):
+        graph_1 = rustworkx.PyDiGraph()
+        graph_1.add_node("a_1")
+        graph_2 = rustworkx.PyDiGraph()
+        graph_2.add_node(0)
+
+        graph_product, _ = rustworkx.digraph_strong_product(graph_1, graph_2)
+        self.assertEqual([("a_1", 0)], graph_product.nodes())
+
+    def test_edge_weights_strong(self):
+        graph_1 = rustworkx.PyDiGraph()
+        graph_1.add_nodes_from([0, 1])
+        graph_1.add_edge(0, 1, "w_1")
+        graph_2 = rustworkx.PyDiGraph()
+        graph_2.add_nodes_from([0, 1])
+        graph_2.add_edge(0, 1, "w_2")
+
+        graph_product, _ = rustworkx.digraph_strong_product(graph_1, graph_2)
+        expected = ["w_1", "w_1", "w_2", "w_2"] + [("w_1", "w_2")] * 1
+        self.assertEqual(expected, graph_product.edges())
+
+    def test_combiner_callbacks_strong(self):
+        graph_1 = rustworkx.PyDiGraph()
+        graph_1.add_nodes_from([0, 1])
+        graph_1.add_edge(0, 1, "w_1")
+        graph_2 = graph_1.copy()
+
+        graph_product, node_map = rustworkx.digraph_strong_product(
+            graph_1,
+            graph_2,
+            node_fn=lambda a, b: "node",
+            edge_fn=lambda a, b: (a is not None, b is not None),
+        )
+        self.assertEqual(graph_product.nodes(), ["node"] * 4)
+        self.assertEqual(graph_product.edges().count((True, True)), 1)
+        self.assertEqual(graph_product.edges().count((True, False)), 2)
+        self.assertEqual(graph_product.edges().count((False, True)), 2)
+        self.assertTrue(graph_product.has_edge(node_map[(0, 0)], node_map[(1, 1)]))
+
+    def test_callback_error(self):
+        graph_1 = rustworkx.generators.directed_path_graph(2)
+
+        def fail(*_args):
+            raise ValueError("combiner failed")
+
+        with self.assertRaises(ValueError):
+            rustworkx.digraph_strong_product(graph_1, graph_1, edge_fn=fail)
@@ -0,0 +1,82 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+import unittest
+
+import rustworkx
+
+
+class TestTemporal(unittest.TestCase):
+    def setUp(self):
+        self.graph = rustworkx.PyDiGraph()
+        self.graph.add_nodes_from(range(4))
+        self.graph.add_edges_from(
+            [(0, 1, (1.0, 2.0)), (1, 2, (2.0, 3.0)), (2, 3, (2.5, 3.0)), (3, 0, (4.0, 5.0))]
+        )
+
+    def test_earliest_arrival_times(self):
+        res = rustworkx.earliest_arrival_times(self.graph, 0, lambda t: t)
+        self.assertEqual({1: 2.0, 2: 3.0}, dict(res))
+
+    def test_direction(self):
+        res = rustworkx.digraph_earliest_arrival_times(self.graph, 2, lambda t: t)
+        self.assertEqual({3: 3.0, 0: 5.0}, dict(res))
+
+    def test_zero_duration_chain(self):
+        graph = rustworkx.PyDiGraph()
+        graph.add_nodes_from(range(4))
+        graph.add_edges_from([(2, 3, 1.0), (1, 2, 1.0), (0, 1, 1.0)])
+        res = rustworkx.earliest_arrival_paths(graph, 0, lambda t: t)
+        self.assertEqual({1: [0, 1], 2: [0, 1, 2], 3: [0, 1, 2, 3]}, dict(res))
+
+    def test_earliest_arrival_paths(self):
+        res = rustworkx.digraph_earliest_arrival_paths(self.graph, 3, lambda t: t)
+        self.assertEqual({0: [3, 0]}, dict(res))
+        res = rustworkx.digraph_earliest_arrival_paths(self.graph, 3, lambda t: t, end_time=4.5)
+        self.assertEqual({}, dict(res))
+
+    def test_fastest_path_durations(self):
+        graph = rustworkx.PyDiGraph()
+        graph.add_nodes_from(range(3))
+        graph.add_edges_from([(0, 1, (0.0, 1.0)), (0, 1, (5.0, 6.0)), (1, 2, (6.0, 8.0))])
+        res = rustworkx.fastest_path_durations(graph, 0, lambda t: t)
+        self.assertEqual({1: 1.0, 2: 3.0}, dict(res))
+        res = rustworkx.digraph_fastest_path_durations(graph, 0, lambda t: t, end_time=7.0)
+        self.assertEqual({1: 1.0}, dict(res))
+
+    def test_temporal_reachability(self):
+        res = rustworkx.temporal_reachability(self.graph, 1, lambda t: t)
+        self.assertEqual({2}, res)
+        res = rustworkx.digraph_temporal_reachability(self.graph, 3, lambda t: t)
+        self.assertEqual({0}, res)
+
+    def test_temporal_snapshot(self):
+        res = rustworkx.temporal_snapshot(self.graph, lambda t: t, 2.0)
+        self.assertIsInstance(res, rustworkx.PyDiGraph)
+        self.assertEqual([(0, 1), (1, 2)], res.edge_list())
+        res = rustworkx.digraph_temporal_snapshot(self.graph, lambda t: t, 3.5, 10.0)
+        self.assertEqual([(3, 0)], res.edge_list())
+        self.assertEqual(4, len(res))
+
+    def test_snapshot_removed_nodes(self):
+        self.graph.remove_node(1)
+        res = rustworkx.temporal_snapshot(self.graph, lambda t: t, 3.0)
+        self.assertEqual([0, 2, 3], res.node_indices())
+        self.assertEqual([(2, 3)], res.edge_list())
+
+    def test_invalid_interval(self):
+        with self.assertRaises(ValueError):
+            rustworkx.temporal_snapshot(self.graph, lambda t: (t[1], t[0]), 2.0)
+
+    def test_invalid_source(self):
+        with self.assertRaises(IndexError):
+            rustworkx.temporal_reachability(self.graph, 10, lambda t: t)
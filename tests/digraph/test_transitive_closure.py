@@ -0,0 +1,109 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+import unittest
+
+import rustworkx
+
+
+class TestTransitiveClosure(unittest.TestCase):
+    def setUp(self):
+        self.graph = rustworkx.PyDiGraph()
+        self.graph.add_nodes_from(range(8))
+        self.graph.add_edges_from(
+            [
+                (0, 1, "a"),
+                (0, 2, "b"),
+                (1, 3, "c"),
+                (2, 3, "d"),
+                (3, 4, "e"),
+                (5, 4, "f"),
+                (5, 6, "g"),
+                (6, 7, "h"),
+                (2, 7, "i"),
+            ]
+        )
+
+    def expected_pairs(self):
+        return {
+            (node, target)
+            for node in self.graph.node_indices()
+            for target in rustworkx.descendants(self.graph, node)
+        }
+
+    def test_closure(self):
+        closure = rustworkx.transitive_closure_dag(self.graph)
+        self.assertEqual(closure.nodes(), self.graph.nodes())
+        self.assertEqual(set(closure.edge_list()), self.expected_pairs())
+        self.assertEqual(len(closure.edge_list()), len(self.expected_pairs()))
+        self.assertEqual(closure.get_edge_data(0, 1), "a")
+        self.assertIsNone(closure.get_edge_data(0, 4))
+        # The input graph isn't modified
+        self.assertEqual(self.graph.num_edges(), 9)
+
+    def test_closure_of_path(self):
+        graph = rustworkx.generators.directed_path_graph(4)
+        closure = rustworkx.transitive_closure_dag(graph)
+        self.assertEqual(
+            sorted(closure.edge_list()), [(0, 1), (0, 2), (0, 3), (1, 2), (1, 3), (2, 3)]
+        )
+
+    def test_closure_with_removed_node(self):
+        self.graph.remove_node(3)
+        closure = rustworkx.transitive_closure_dag(self.graph)
+        self.assertEqual(set(closure.edge_list()), self.expected_pairs())
+
+    def test_closure_cycle(self):
+        graph = rustworkx.generators.directed_cycle_graph(3)
+        with self.assertRaises(rustworkx.DAGHasCycle):
+            rustworkx.transitive_closure_dag(graph)
+
+
+class TestReachabilityIndex(unittest.TestCase):
+    def setUp(self):
+        self.graph = rustworkx.PyDiGraph()
+        self.graph.extend_from_edge_list(
+            [(0, 1), (0, 2), (1, 3), (2, 3), (3, 4), (5, 4), (5, 6), (6, 7), (2, 7)]
+        )
+
+    def test_can_reach(self):
+        index = rustworkx.ReachabilityIndex(self.graph)
+        for source in self.graph.node_indices():
+            descendants = rustworkx.descendants(self.graph, source)
+            for target in self.graph.node_indices():
+                self.assertEqual(
+                    index.can_reach(source, target),
+                    source == target or target in descendants,
+                )
+
+    def test_descendants(self):
+        index = rustworkx.ReachabilityIndex(self.graph)
+        for node in self.graph.node_indices():
+            self.assertEqual(
+                set(index.descendants(node)), rustworkx.descendants(self.graph, node)
+            )
+
+    def test_num_chains(self):
+        index = rustworkx.ReachabilityIndex(rustworkx.generators.directed_path_graph(10))
+        self.assertEqual(index.num_chains, 1)
+
+    def test_invalid_node(self):
+        index = rustworkx.ReachabilityIndex(self.graph)
+        with self.assertRaises(rustworkx.InvalidNode):
+            index.can_reach(0, 42)
+        with self.assertRaises(rustworkx.InvalidNode):
+            index.descendants(42)
+
+    def test_cycle(self):
+        graph = rustworkx.generators.directed_cycle_graph(3)
+        with self.assertRaises(rustworkx.DAGHasCycle):
+            rustworkx.ReachabilityIndex(graph)
@@ -0,0 +1,119 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+
+import math
+import unittest
+
+import rustworkx
+
+
+class TestAssortativity(unittest.TestCase):
+    def test_degree_star(self):
+        graph = rustworkx.generators.star_graph(5)
+        self.assertAlmostEqual(rustworkx.degree_assortativity_coefficient(graph), -1.0)
+
+    def test_degree_path(self):
+        graph = rustworkx.generators.path_graph(4)
+        self.assertAlmostEqual(rustworkx.graph_degree_assortativity_coefficient(graph), -0.5)
+
+    def test_degree_regular(self):
+        graph = rustworkx.generators.cycle_graph(5)
+        self.assertTrue(math.isnan(rustworkx.degree_assortativity_coefficient(graph)))
+
+    def test_degree_weighted(self):
+        graph = rustworkx.PyGraph()
+        graph.extend_from_weighted_edge_list([(0, 1, 1.0), (1, 2, 3.0)])
+        self.assertAlmostEqual(rustworkx.degree_assortativity_coefficient(graph), -1.0)
+        res = rustworkx.degree_assortativity_coefficient(graph, weight_fn=float)
+        self.assertAlmostEqual(res, -2 / 3)
+
+    def test_degree_default_weight(self):
+        graph = rustworkx.generators.path_graph(4)
+        res = rustworkx.degree_assortativity_coefficient(graph, default_weight=2.0)
+        self.assertAlmostEqual(res, -0.5)
+
+    def test_attribute_barbell(self):
+        graph = rustworkx.generators.barbell_graph(3, 0)
+        for node in graph.node_indices():
+            graph[node] = node < 3
+        res = rustworkx.attribute_assortativity_coefficient(graph, lambda x: x)
+        self.assertAlmostEqual(res, 5 / 7)
+
+    def test_attribute_alternating(self):
+        graph = rustworkx.generators.path_graph(4, weights=["a", "b", "a", "b"])
+        res = rustworkx.graph_attribute_assortativity_coefficient(graph, str)
+        self.assertAlmostEqual(res, -1.0)
+
+    def test_attribute_unhashable(self):
+        graph = rustworkx.generators.path_graph(2)
+        with self.assertRaises(TypeError):
+            rustworkx.attribute_assortativity_coefficient(graph, lambda _: [])
+
+    def test_attribute_callback_error(self):
+        graph = rustworkx.generators.path_graph(2)
+
+        def attribute_fn(_):
+            raise ValueError("error")
+
+        with self.assertRaises(ValueError):
+            rustworkx.attribute_assortativity_coefficient(graph, attribute_fn)
+
+    def test_no_edges(self):
+        graph = rustworkx.PyGraph()
+        graph.add_nodes_from([0, 1])
+        self.assertTrue(math.isnan(rustworkx.degree_assortativity_coefficient(graph)))
+        res = rustworkx.attribute_assortativity_coefficient(graph, lambda x: x)
+        self.assertTrue(math.isnan(res))
+
+    def test_degree_histogram(self):
+        graph = rustworkx.generators.barbell_graph(4, 2)
+        self.assertEqual(rustworkx.graph_degree_histogram(graph), [0, 0, 2, 6, 2])
+        self.assertEqual(rustworkx.degree_histogram(rustworkx.PyGraph()), [])
+
+    def test_degree_histogram_self_loop(self):
+        graph = rustworkx.PyGraph()
+        graph.add_nodes_from([None, None, None])
+        graph.add_edges_from_no_data([(0, 1), (1, 1)])
+        self.assertEqual(rustworkx.degree_histogram(graph), [1, 1, 0, 1])
+
+    def test_joint_degree_matrix(self):
+        graph = rustworkx.generators.barbell_graph(4, 2)
+        res = rustworkx.graph_joint_degree_matrix(graph, normalized=False)
+        expected = {(2, 2): 2, (2, 4): 2, (3, 3): 12, (3, 4): 6, (4, 2): 2, (4, 3): 6}
+        self.assertEqual(res, expected)
+        self.assertEqual(list(res), sorted(expected))
+        res = rustworkx.joint_degree_matrix(graph)
+        for key, count in expected.items():
+            self.assertAlmostEqual(res[key], count / 30)
+
+    def test_joint_degree_matrix_no_edges(self):
+        graph = rustworkx.PyGraph()
+        graph.add_nodes_from([0, 1])
+        self.assertEqual(rustworkx.joint_degree_matrix(graph), {})
+
+    def test_average_neighbor_degree(self):
+        graph = rustworkx.generators.barbell_graph(4, 2)
+        res = rustworkx.graph_average_neighbor_degree(graph)
+        expected = [10 / 3, 10 / 3, 10 / 3, 2.75, 3.0, 3.0, 2.75, 10 / 3, 10 / 3, 10 / 3]
+        self.assertEqual(list(res), list(range(10)))
+        for node, value in enumerate(expected):
+            self.assertAlmostEqual(res[node], value)
+
+    def test_average_neighbor_degree_weighted(self):
+        graph = rustworkx.PyGraph()
+        graph.add_nodes_from([None] * 5)
+        graph.add_edges_from([(0, 1, 1.0), (0, 2, 3.0), (2, 3, 1.0)])
+        res = rustworkx.average_neighbor_degree(graph, weight_fn=float)
+        self.assertAlmostEqual(res[0], 7 / 4)
+        self.assertAlmostEqual(res[2], 7 / 4)
+        self.assertEqual(res[4], 0.0)
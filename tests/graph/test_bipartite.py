@@ -87,3 +87,47 @@ class TestBipartite(unittest.TestCase):
                             2 * n + 1, 2 * k + 1
                         )
                         self.assertFalse(rustworkx.is_bipartite(graph))
+
+    def assertOddCycle(self, graph, cycle):
+        self.assertEqual(len(cycle) % 2, 1)
+        self.assertEqual(len(set(cycle)), len(cycle))
+        for i, node in enumerate(cycle):
+            self.assertTrue(graph.has_edge(node, cycle[(i + 1) % len(cycle)]))
+
+    def test_bipartite_sets(self):
+        graph = rustworkx.generators.cycle_graph(6)
+        self.assertEqual(rustworkx.bipartite_sets(graph), ({0, 2, 4}, {1, 3, 5}))
+
+    def test_bipartite_sets_with_isolates(self):
+        graph = rustworkx.generators.star_graph(5)
+        graph.add_nodes_from(range(3))
+        first, second = rustworkx.graph_bipartite_sets(graph)
+        self.assertEqual(first, {0, 5, 6, 7})
+        self.assertEqual(second, {1, 2, 3, 4})
+
+    def test_bipartite_sets_every_edge_crosses(self):
+        graph = rustworkx.generators.heavy_square_graph(5)
+        first, second = rustworkx.graph_bipartite_sets(graph)
+        self.assertEqual(first | second, set(graph.node_indices()))
+        for u, v in graph.edge_list():
+            self.assertNotEqual(u in first, v in first)
+
+    def test_bipartite_sets_odd_cycle(self):
+        for graph in [
+            rustworkx.generators.cycle_graph(9),
+            rustworkx.generators.complete_graph(5),
+            rustworkx.generators.generalized_petersen_graph(5, 2),
+        ]:
+            with self.assertRaises(rustworkx.GraphNotBipartite) as cm:
+                rustworkx.bipartite_sets(graph)
+            self.assertOddCycle(graph, list(cm.exception.odd_cycle))
+
+    def test_bipartite_sets_self_loop(self):
+        graph = rustworkx.generators.path_graph(3)
+        graph.add_edge(2, 2, None)
+        with self.assertRaises(rustworkx.GraphNotBipartite) as cm:
+            rustworkx.graph_bipartite_sets(graph)
+        self.assertEqual(list(cm.exception.odd_cycle), [2])
+
+    def test_bipartite_sets_empty(self):
+        self.assertEqual(rustworkx.bipartite_sets(rustworkx.PyGraph()), (set(), set()))
@@ -0,0 +1,79 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+
+import unittest
+
+import rustworkx
+
+
+class TestBipartiteProjection(unittest.TestCase):
+    def setUp(self):
+        # People 0, 1 and 2 and the groups 3, 4 and 5
+        self.graph = rustworkx.PyGraph()
+        self.graph.add_nodes_from(["a", "b", "c", "x", "y", "z"])
+        self.graph.add_edges_from_no_data([(0, 3), (1, 3), (1, 4), (2, 4), (0, 4), (2, 5)])
+
+    def test_count(self):
+        projection, node_map = rustworkx.bipartite_projection(self.graph, [0, 1, 2])
+        self.assertEqual(projection.nodes(), ["a", "b", "c"])
+        self.assertEqual(
+            projection.weighted_edge_list(), [(0, 1, 2.0), (0, 2, 1.0), (1, 2, 1.0)]
+        )
+        self.assertEqual(node_map, {0: 0, 1: 1, 2: 2})
+
+    def test_newman(self):
+        projection, _ = rustworkx.bipartite_projection(self.graph, [0, 1, 2], weight="newman")
+        self.assertEqual(
+            projection.weighted_edge_list(), [(0, 1, 1.5), (0, 2, 0.5), (1, 2, 0.5)]
+        )
+
+    def test_jaccard(self):
+        projection, _ = rustworkx.bipartite_projection(self.graph, [0, 1, 2], weight="jaccard")
+        self.assertEqual(
+            projection.weighted_edge_list(), [(0, 1, 1.0), (0, 2, 1 / 3), (1, 2, 1 / 3)]
+        )
+
+    def test_other_part(self):
+        projection, node_map = rustworkx.bipartite_projection(self.graph, [3, 4, 5])
+        self.assertEqual(projection.nodes(), ["x", "y", "z"])
+        self.assertEqual(projection.weighted_edge_list(), [(0, 1, 2.0), (1, 2, 1.0)])
+        self.assertEqual(node_map, {3: 0, 4: 1, 5: 2})
+
+    def test_computed_bipartition(self):
+        projection, node_map = rustworkx.bipartite_projection(self.graph)
+        self.assertEqual(projection.nodes(), ["a", "b", "c"])
+        self.assertEqual(node_map, {0: 0, 1: 1, 2: 2})
+
+    def test_computed_bipartition_per_component(self):
+        graph = rustworkx.generators.path_graph(3)
+        graph.add_nodes_from([None] * 3)
+        graph.add_edges_from_no_data([(4, 3), (4, 5)])
+        _, node_map = rustworkx.bipartite_projection(graph)
+        self.assertEqual(list(node_map), [0, 2, 3, 5])
+
+    def test_not_bipartite(self):
+        graph = rustworkx.generators.cycle_graph(3)
+        with self.assertRaises(ValueError):
+            rustworkx.bipartite_projection(graph)
+
+    def test_invalid_bipartition(self):
+        with self.assertRaises(ValueError):
+            rustworkx.bipartite_projection(self.graph, [0, 1, 3])
+
+    def test_invalid_node(self):
+        with self.assertRaises(ValueError):
+            rustworkx.bipartite_projection(self.graph, [0, 1, 2, 10])
+
+    def test_invalid_weight(self):
+        with self.assertRaises(ValueError):
+            rustworkx.bipartite_projection(self.graph, [0, 1, 2], weight="sum")
@@ -0,0 +1,131 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+import os
+import random
+import subprocess
+import sys
+import unittest
+
+import rustworkx
+
+
+def relabeled(graph, seed):
+    rng = random.Random(seed)
+    nodes = list(graph.node_indices())
+    rng.shuffle(nodes)
+    out = rustworkx.PyGraph()
+    mapping = {node: out.add_node(graph[node]) for node in nodes}
+    edges = list(graph.weighted_edge_list())
+    rng.shuffle(edges)
+    for source, target, weight in edges:
+        if rng.random() < 0.5:
+            source, target = target, source
+        out.add_edge(mapping[source], mapping[target], weight)
+    return out
+
+
+class TestCanonicalHash(unittest.TestCase):
+    def test_invariant_under_relabeling(self):
+        for graph in [
+            rustworkx.generators.generalized_petersen_graph(5, 2),
+            rustworkx.generators.grid_graph(4, 5),
+            rustworkx.generators.complete_graph(8),
+            rustworkx.generators.star_graph(10),
+            rustworkx.undirected_gnp_random_graph(40, 0.1, seed=42),
+        ]:
+            expected = rustworkx.canonical_hash(graph)
+            self.assertEqual(len(expected), 32)
+            for seed in range(5):
+                with self.subTest(seed=seed):
+                    self.assertEqual(rustworkx.canonical_hash(relabeled(graph, seed)), expected)
+
+    def test_removed_nodes(self):
+        graph = rustworkx.generators.path_graph(5)
+        graph.remove_node(0)
+        self.assertEqual(
+            rustworkx.graph_canonical_hash(graph),
+            rustworkx.graph_canonical_hash(rustworkx.generators.path_graph(4)),
+        )
+
+    def test_agrees_with_is_isomorphic(self):
+        graphs = [rustworkx.undirected_gnm_random_graph(7, 8, seed=seed) for seed in range(30)]
+        for i, first in enumerate(graphs):
+            for second in graphs[:i]:
+                self.assertEqual(
+                    rustworkx.canonical_hash(first) == rustworkx.canonical_hash(second),
+                    rustworkx.is_isomorphic(first, second),
+                )
+
+    def test_regular_graphs(self):
+        hexagon = rustworkx.generators.cycle_graph(6)
+        triangles = rustworkx.generators.cycle_graph(3)
+        triangles.compose(rustworkx.generators.cycle_graph(3), {})
+        self.assertNotEqual(rustworkx.canonical_hash(hexagon), rustworkx.canonical_hash(triangles))
+
+    def test_attributes(self):
+        graph = rustworkx.PyGraph()
+        graph.add_nodes_from([{"color": "red"}, {"color": "blue"}, {"color": "red"}])
+        graph.add_edges_from([(0, 1, 1.5), (1, 2, 2.5)])
+        other = rustworkx.PyGraph()
+        other.add_nodes_from([{"color": "blue"}, {"color": "red"}, {"color": "red"}])
+        other.add_edges_from([(1, 0, 2.5), (0, 2, 1.5)])
+        other_colors = rustworkx.PyGraph()
+        other_colors.add_nodes_from([{"color": "red"}, {"color": "red"}, {"color": "blue"}])
+        other_colors.add_edges_from([(0, 1, 1.5), (1, 2, 2.5)])
+
+        def node_attr_fn(data):
+            return data["color"]
+
+        def edge_hash(graph):
+            return rustworkx.canonical_hash(graph, node_attr_fn, str)
+
+        self.assertEqual(edge_hash(graph), edge_hash(other))
+        self.assertNotEqual(edge_hash(graph), edge_hash(other_colors))
+        # Without the labels only the structure is hashed
+        self.assertEqual(rustworkx.canonical_hash(graph), rustworkx.canonical_hash(other_colors))
+        self.assertNotEqual(rustworkx.canonical_hash(graph), edge_hash(graph))
+
+    def test_callback_error(self):
+        graph = rustworkx.generators.path_graph(3)
+
+        def node_attr_fn(_):
+            raise KeyError("label")
+
+        with self.assertRaises(KeyError):
+            rustworkx.canonical_hash(graph, node_attr_fn)
+
+    def test_empty(self):
+        self.assertEqual(
+            rustworkx.canonical_hash(rustworkx.PyGraph()),
+            rustworkx.canonical_hash(rustworkx.PyGraph()),
+        )
+        self.assertNotEqual(
+            rustworkx.canonical_hash(rustworkx.PyGraph()),
+            rustworkx.canonical_hash(rustworkx.PyDiGraph()),
+        )
+
+    def test_stable_across_processes(self):
+        code = (
+            "import rustworkx;"
+            "graph = rustworkx.generators.path_graph(4, ['a', 'b', 'c', 'd']);"
+            "print(rustworkx.canonical_hash(graph, str, str))"
+        )
+        hashes = set()
+        for hash_seed in ["1", "2"]:
+            env = dict(os.environ, PYTHONHASHSEED=hash_seed)
+            output = subprocess.run(
+                [sys.executable, "-c", code], env=env, capture_output=True, text=True, check=True
+            )
+            hashes.add(output.stdout.strip())
+        graph = rustworkx.generators.path_graph(4, ["a", "b", "c", "d"])
+        self.assertEqual(hashes, {rustworkx.canonical_hash(graph, str, str)})
@@ -58,3 +58,23 @@ class TestCartesianProduct(unittest.TestCase):
 
         graph_product, _ = rustworkx.graph_cartesian_product(graph_1, graph_2)
         self.assertEqual(["w_1", "w_1", "w_2", "w_2"], graph_product.edges())
+
+    def test_combiner_callbacks_cartesian(self):
+        graph_1 = rustworkx.PyGraph()
+        graph_1.add_nodes_from(["a", "b"])
+        graph_1.add_edge(0, 1, 1)
+        graph_2 = rustworkx.PyGraph()
+        graph_2.add_nodes_from(["x", "y"])
+        graph_2.add_edge(0, 1, 2)
+
+        graph_product, node_map = rustworkx.graph_cartesian_product(
+            graph_1,
+            graph_2,
+            node_fn=lambda a, b: a + b,
+            edge_fn=lambda a, b: ("first", a) if b is None else ("second", b),
+        )
+        self.assertEqual(graph_product[node_map[(1, 0)]], "bx")
+        self.assertEqual(
+            [("first", 1), ("first", 1), ("second", 2), ("second", 2)],
+            graph_product.edges(),
+        )
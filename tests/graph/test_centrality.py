@@ -112,6 +112,40 @@ class TestCentralityGraphDeletedNode(unittest.TestCase):
         expected = {0: 0.5, 1: 0.75, 2: 0.75, 4: 0.5}
         self.assertEqual(expected, closeness)
 
+    def test_closeness_centrality_weighted(self):
+        graph = rustworkx.PyGraph()
+        graph.add_nodes_from(range(4))
+        graph.add_edges_from([(0, 1, 1.0), (1, 2, 3.0)])
+        closeness = rustworkx.graph_closeness_centrality(graph, wf_improved=False, weight_fn=float)
+        self.assertEqual({0: 2.0 / 5.0, 1: 2.0 / 4.0, 2: 2.0 / 7.0, 3: 0.0}, closeness)
+        closeness = rustworkx.closeness_centrality(graph, weight_fn=[1.0, 3.0])
+        expected = {0: 4.0 / 15.0, 1: 4.0 / 12.0, 2: 4.0 / 21.0, 3: 0.0}
+        self.assertEqual(expected, closeness)
+
+    def test_closeness_centrality_default_weight(self):
+        unweighted = rustworkx.graph_closeness_centrality(self.graph)
+        weighted = rustworkx.graph_closeness_centrality(self.graph, default_weight=2.0)
+        self.assertEqual({k: v / 2.0 for k, v in unweighted.items()}, weighted)
+
+    def test_closeness_centrality_negative_weight(self):
+        with self.assertRaises(ValueError):
+            rustworkx.graph_closeness_centrality(self.graph, weight_fn=lambda _: -1.0)
+
+    def test_harmonic_centrality(self):
+        graph = rustworkx.PyGraph()
+        graph.extend_from_edge_list([(0, 1), (1, 2), (3, 4)])
+        harmonic = rustworkx.graph_harmonic_centrality(graph)
+        self.assertEqual({0: 1.5, 1: 2.0, 2: 1.5, 3: 1.0, 4: 1.0}, harmonic)
+        self.assertEqual(harmonic, rustworkx.harmonic_centrality(graph))
+
+    def test_harmonic_centrality_weighted(self):
+        graph = rustworkx.PyGraph()
+        graph.add_nodes_from(range(3))
+        graph.add_edges_from([(0, 1, 1.0), (1, 2, 3.0)])
+        harmonic = rustworkx.graph_harmonic_centrality(graph, weight_fn=float)
+        expected = {0: 1.0 + 1.0 / 4.0, 1: 1.0 + 1.0 / 3.0, 2: 1.0 / 4.0 + 1.0 / 3.0}
+        self.assertEqual(expected, harmonic)
+
 
 class TestEigenvectorCentrality(unittest.TestCase):
     def test_complete_graph(self):
@@ -133,6 +167,24 @@ class TestEigenvectorCentrality(unittest.TestCase):
         with self.assertRaises(rustworkx.FailedToConverge):
             rustworkx.eigenvector_centrality(graph, max_iter=0)
 
+    def test_no_convergence_partial_result(self):
+        graph = rustworkx.generators.path_graph(4)
+        graph.remove_node(0)
+        with self.assertRaises(rustworkx.FailedToConverge) as cm:
+            rustworkx.eigenvector_centrality(graph, max_iter=2, tol=1e-12)
+        partial = cm.exception.partial_result
+        self.assertIsInstance(partial, rustworkx.CentralityMapping)
+        self.assertEqual(list(partial.keys()), [1, 2, 3])
+        self.assertEqual(cm.exception.iterations, 2)
+        self.assertGreater(cm.exception.residual, 0.0)
+
+    def test_no_convergence_without_partial_result(self):
+        with self.assertRaises(rustworkx.FailedToConverge) as cm:
+            rustworkx.eigenvector_centrality(rustworkx.PyGraph())
+        self.assertIsNone(cm.exception.partial_result)
+        self.assertIsNone(cm.exception.iterations)
+        self.assertIsNone(cm.exception.residual)
+
 
 class TestKatzCentrality(unittest.TestCase):
     def test_complete_graph(self):
@@ -147,6 +199,16 @@ class TestKatzCentrality(unittest.TestCase):
         with self.assertRaises(rustworkx.FailedToConverge):
             rustworkx.katz_centrality(graph, max_iter=0)
 
+    def test_no_convergence_partial_result(self):
+        graph = rustworkx.generators.path_graph(4)
+        with self.assertRaises(rustworkx.FailedToConverge) as cm:
+            rustworkx.katz_centrality(graph, max_iter=2)
+        partial = cm.exception.partial_result
+        self.assertEqual(list(partial.keys()), [0, 1, 2, 3])
+        self.assertAlmostEqual(math.sqrt(sum(v**2 for v in partial.values())), 1.0)
+        self.assertEqual(cm.exception.iterations, 2)
+        self.assertGreater(cm.exception.residual, 0.0)
+
     def test_beta_scalar(self):
         graph = rustworkx.generators.generalized_petersen_graph(5, 2)
         expected_value = 0.31622776601683794
@@ -230,3 +292,69 @@ class TestEdgeBetweennessCentrality(unittest.TestCase):
         expected = {0: 9, 1: 9, 2: 12, 3: 15, 4: 11, 5: 14, 6: 10, 7: 13, 8: 9, 9: 9}
         for k, v in centrality.items():
             self.assertAlmostEqual(v, expected[k])
+
+
+class TestWeightedBetweennessCentrality(unittest.TestCase):
+    def setUp(self):
+        self.nx_graph = nx.gnm_random_graph(30, 80, seed=42, directed=False)
+        self.graph = rustworkx.PyGraph()
+        self.graph.add_nodes_from(self.nx_graph.nodes())
+        for source, target, data in self.nx_graph.edges(data=True):
+            data["index"] = self.graph.add_edge(source, target, self.graph.num_edges() % 4 + 1)
+            data["weight"] = self.graph.get_edge_data_by_index(data["index"])
+
+    def test_betweenness_centrality(self):
+        for endpoints in (False, True):
+            centrality = rustworkx.betweenness_centrality(
+                self.graph, endpoints=endpoints, weight_fn=float
+            )
+            expected = nx.betweenness_centrality(
+                self.nx_graph, endpoints=endpoints, weight="weight"
+            )
+            for node, value in expected.items():
+                self.assertAlmostEqual(value, centrality[node])
+
+    def test_edge_betweenness_centrality(self):
+        weights = [float(weight) for weight in self.graph.edges()]
+        for weight_fn in (float, weights):
+            centrality = rustworkx.edge_betweenness_centrality(
+                self.graph, normalized=False, weight_fn=weight_fn, parallel_threshold=1
+            )
+            expected = nx.edge_betweenness_centrality(
+                self.nx_graph, normalized=False, weight="weight"
+            )
+            for (source, target), value in expected.items():
+                index = self.nx_graph.edges[source, target]["index"]
+                self.assertAlmostEqual(value, centrality[index])
+
+    def test_weights_change_paths(self):
+        graph = rustworkx.generators.path_graph(3)
+        graph.add_edge(0, 2, None)
+        centrality = rustworkx.graph_betweenness_centrality(
+            graph, normalized=False, weight_fn=[1.0, 1.0, 3.0]
+        )
+        self.assertEqual({0: 0.0, 1: 1.0, 2: 0.0}, centrality)
+        centrality = rustworkx.graph_edge_betweenness_centrality(
+            graph, normalized=False, weight_fn=[1.0, 1.0, 3.0]
+        )
+        self.assertEqual({0: 2.0, 1: 2.0, 2: 0.0}, centrality)
+
+    def test_progress_fn(self):
+        calls = []
+        centrality = rustworkx.betweenness_centrality(
+            self.graph,
+            weight_fn=float,
+            progress_fn=lambda done, total: calls.append((done, total)),
+            progress_interval=10,
+        )
+        self.assertEqual([(10, 30), (20, 30), (30, 30)], calls)
+        self.assertEqual(
+            rustworkx.betweenness_centrality(self.graph, weight_fn=float), centrality
+        )
+
+    def test_invalid_weights(self):
+        graph = rustworkx.generators.path_graph(3)
+        with self.assertRaises(ValueError):
+            rustworkx.betweenness_centrality(graph, weight_fn=lambda _: -1.0)
+        with self.assertRaises(IndexError):
+            rustworkx.edge_betweenness_centrality(graph, weight_fn=[1.0])
@@ -0,0 +1,81 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+
+import unittest
+
+import rustworkx
+
+
+class TestSquareClustering(unittest.TestCase):
+    def test_square_with_pendant_path(self):
+        graph = rustworkx.PyGraph()
+        graph.extend_from_edge_list([(0, 1), (0, 2), (1, 3), (2, 3), (0, 4), (4, 5)])
+        self.assertEqual(
+            rustworkx.square_clustering(graph),
+            {0: 0.2, 1: 0.5, 2: 0.5, 3: 1.0, 4: 0.0, 5: 0.0},
+        )
+
+    def test_complete_graph(self):
+        graph = rustworkx.generators.complete_graph(4)
+        self.assertEqual(rustworkx.square_clustering(graph), {i: 1.0 for i in range(4)})
+
+    def test_grid(self):
+        graph = rustworkx.generators.grid_graph(3, 3)
+        res = rustworkx.square_clustering(graph)
+        # Every corner is in one of its three possible squares
+        for corner in [0, 2, 6, 8]:
+            self.assertAlmostEqual(res[corner], 1 / 3)
+
+    def test_cycle(self):
+        graph = rustworkx.generators.cycle_graph(5)
+        self.assertEqual(rustworkx.square_clustering(graph), {i: 0.0 for i in range(5)})
+
+    def test_removed_node(self):
+        graph = rustworkx.generators.cycle_graph(4)
+        graph.remove_node(1)
+        self.assertEqual(rustworkx.square_clustering(graph), {0: 0.0, 2: 0.0, 3: 0.0})
+
+
+class TestWeightedClustering(unittest.TestCase):
+    def setUp(self):
+        self.graph = rustworkx.PyGraph()
+        self.graph.extend_from_weighted_edge_list(
+            [(0, 1, 1.0), (1, 2, 2.0), (2, 0, 4.0), (0, 3, 1.0)]
+        )
+
+    def assertClusteringAlmostEqual(self, res, expected):
+        self.assertEqual(res.keys(), expected.keys())
+        for node, value in expected.items():
+            self.assertAlmostEqual(res[node], value)
+
+    def test_onnela(self):
+        res = rustworkx.weighted_clustering(self.graph, weight_fn=float)
+        self.assertClusteringAlmostEqual(res, {0: 1 / 6, 1: 0.5, 2: 0.5, 3: 0.0})
+
+    def test_barrat(self):
+        res = rustworkx.weighted_clustering(self.graph, weight_fn=float, method="barrat")
+        self.assertClusteringAlmostEqual(res, {0: 5 / 12, 1: 1.0, 2: 1.0, 3: 0.0})
+
+    def test_unweighted(self):
+        expected = {0: 1 / 3, 1: 1.0, 2: 1.0, 3: 0.0}
+        for method in ["onnela", "barrat"]:
+            res = rustworkx.weighted_clustering(self.graph, default_weight=3.0, method=method)
+            self.assertClusteringAlmostEqual(res, expected)
+
+    def test_invalid_method(self):
+        with self.assertRaises(ValueError):
+            rustworkx.weighted_clustering(self.graph, method="zhang")
+
+    def test_weight_fn_error(self):
+        with self.assertRaises(TypeError):
+            rustworkx.weighted_clustering(self.graph, weight_fn=lambda _: "a")
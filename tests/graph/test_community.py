@@ -0,0 +1,176 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+import math
+import unittest
+
+import rustworkx
+
+
+class TestModularity(unittest.TestCase):
+    def setUp(self):
+        self.graph = rustworkx.PyGraph()
+        self.graph.add_nodes_from(range(6))
+        self.graph.add_edges_from(
+            [
+                (0, 1, 2.0),
+                (1, 2, 1.0),
+                (0, 2, 3.0),
+                (2, 3, 0.5),
+                (3, 4, 1.5),
+                (4, 5, 2.0),
+                (3, 5, 1.0),
+                (5, 5, 2.0),
+                (0, 1, 1.0),
+            ]
+        )
+
+    def test_multigraph_unweighted(self):
+        graph = rustworkx.PyGraph()
+        graph.add_nodes_from(range(4))
+        graph.add_edges_from_no_data([(0, 1), (0, 1), (1, 2), (2, 3), (3, 3)])
+        res = rustworkx.graph_modularity(graph, [[0, 1], [2, 3]])
+        self.assertAlmostEqual(res, 0.3)
+
+    def test_weighted(self):
+        res = rustworkx.graph_modularity(self.graph, [[0, 1, 2], [3, 4, 5]], weight_fn=float)
+        self.assertAlmostEqual(res, 0.4636479591836735)
+
+    def test_resolution(self):
+        res = rustworkx.modularity(
+            self.graph, [[0, 1, 2], [3, 4, 5]], weight_fn=float, resolution=0.5
+        )
+        self.assertAlmostEqual(res, 0.7139668367346939)
+
+    def test_single_community(self):
+        res = rustworkx.graph_modularity(self.graph, [list(range(6))], weight_fn=float)
+        self.assertAlmostEqual(res, 0.0)
+
+    def test_not_a_partition(self):
+        with self.assertRaises(ValueError):
+            rustworkx.graph_modularity(self.graph, [[0, 1, 2], [3, 4]])
+        with self.assertRaises(ValueError):
+            rustworkx.graph_modularity(self.graph, [[0, 1, 2], [2, 3, 4, 5]])
+        with self.assertRaises(ValueError):
+            rustworkx.graph_modularity(self.graph, [[0, 1, 2], [3, 4, 5, 6]])
+
+    def test_no_edges(self):
+        graph = rustworkx.PyGraph()
+        graph.add_nodes_from(range(3))
+        self.assertTrue(math.isnan(rustworkx.graph_modularity(graph, [[0], [1, 2]])))
+
+
+class TestSumParallelEdges(unittest.TestCase):
+    def setUp(self):
+        self.graph = rustworkx.PyGraph()
+        self.graph.add_nodes_from(["a", "b", "c", "d"])
+        self.graph.add_edges_from(
+            [(0, 1, 2.0), (1, 0, 3.0), (1, 2, 1.0), (2, 2, 4.0), (2, 2, 1.0), (0, 1, 0.5)]
+        )
+
+    def test_sum(self):
+        res = rustworkx.graph_sum_parallel_edges(self.graph, weight_fn=float)
+        self.assertEqual(res.nodes(), ["a", "b", "c", "d"])
+        self.assertEqual(res.weighted_edge_list(), [(0, 1, 5.5), (1, 2, 1.0), (2, 2, 5.0)])
+
+    def test_default_weight(self):
+        res = rustworkx.sum_parallel_edges(self.graph, default_weight=2.0)
+        self.assertEqual(res.weighted_edge_list(), [(0, 1, 6.0), (1, 2, 2.0), (2, 2, 4.0)])
+
+    def test_combine_fn(self):
+        res = rustworkx.graph_sum_parallel_edges(self.graph, weight_fn=float, combine_fn=max)
+        self.assertEqual(res.weighted_edge_list(), [(0, 1, 3.0), (1, 2, 1.0), (2, 2, 4.0)])
+
+    def test_combine_fn_error(self):
+        def combine_fn(_a, _b):
+            raise KeyError
+
+        with self.assertRaises(KeyError):
+            rustworkx.graph_sum_parallel_edges(self.graph, weight_fn=float, combine_fn=combine_fn)
+
+    def test_removed_nodes(self):
+        self.graph.remove_node(0)
+        res = rustworkx.graph_sum_parallel_edges(self.graph, weight_fn=float)
+        self.assertEqual(res.node_indices(), [1, 2, 3])
+        self.assertEqual(res.weighted_edge_list(), [(1, 2, 1.0), (2, 2, 5.0)])
+
+    def test_modularity_unchanged(self):
+        communities = [[0, 1], [2, 3]]
+        simple = rustworkx.graph_sum_parallel_edges(self.graph, weight_fn=float)
+        self.assertAlmostEqual(
+            rustworkx.graph_modularity(self.graph, communities, weight_fn=float),
+            rustworkx.graph_modularity(simple, communities, weight_fn=float),
+        )
+
+
+class TestCommunitySubgraphs(unittest.TestCase):
+    def setUp(self):
+        self.graph = rustworkx.generators.barbell_graph(4, 0)
+        self.graph.attrs = {"name": "barbell"}
+
+    def test_matches_subgraph(self):
+        communities = [[4, 7, 5, 6], [2, 0, 1, 3], [3, 4]]
+        res = rustworkx.graph_community_subgraphs(self.graph, communities)
+        self.assertEqual(len(res), 3)
+        for community, (subgraph, node_map) in zip(communities, res):
+            expected = self.graph.subgraph(community)
+            self.assertEqual(subgraph.nodes(), expected.nodes())
+            self.assertEqual(subgraph.weighted_edge_list(), expected.weighted_edge_list())
+            self.assertEqual(dict(node_map), dict(enumerate(sorted(community))))
+            self.assertIsNone(subgraph.attrs)
+
+    def test_overlapping_and_partial(self):
+        res = rustworkx.graph_community_subgraphs(self.graph, [[2, 3, 4], [3, 4], []])
+        self.assertEqual(res[0][0].edge_list(), [(0, 1), (1, 2)])
+        self.assertEqual(res[1][0].edge_list(), [(0, 1)])
+        self.assertEqual(len(res[2][0]), 0)
+        self.assertEqual(dict(res[2][1]), {})
+
+    def test_duplicate_nodes(self):
+        subgraph, node_map = rustworkx.graph_community_subgraphs(self.graph, [[1, 0, 1]])[0]
+        self.assertEqual(subgraph.edge_list(), [(0, 1)])
+        self.assertEqual(dict(node_map), {0: 0, 1: 1})
+
+    def test_shared_payloads(self):
+        graph = rustworkx.PyGraph()
+        graph.add_nodes_from([{"a": 1}, {"b": 2}])
+        graph.add_edge(0, 1, {"w": 3})
+        subgraph, _ = rustworkx.graph_community_subgraphs(graph, [[0, 1]])[0]
+        self.assertIs(subgraph[0], graph[0])
+        self.assertIs(subgraph.get_edge_data(0, 1), graph.get_edge_data(0, 1))
+
+    def test_parallel_edges_and_self_loops(self):
+        graph = rustworkx.PyGraph()
+        graph.add_nodes_from(range(3))
+        graph.add_edges_from([(0, 1, "a"), (0, 1, "b"), (1, 1, "c"), (1, 2, "d")])
+        subgraph, _ = rustworkx.graph_community_subgraphs(graph, [[0, 1]])[0]
+        self.assertEqual(subgraph.weighted_edge_list(), [(0, 1, "a"), (0, 1, "b"), (1, 1, "c")])
+
+    def test_node_holes(self):
+        self.graph.remove_node(0)
+        subgraph, node_map = rustworkx.graph_community_subgraphs(self.graph, [[3, 1, 4]])[0]
+        self.assertEqual(subgraph.edge_list(), [(0, 1), (1, 2)])
+        self.assertEqual(dict(node_map), {0: 1, 1: 3, 2: 4})
+
+    def test_preserve_attrs(self):
+        res = rustworkx.graph_community_subgraphs(self.graph, [[0], [1]], preserve_attrs=True)
+        for subgraph, _ in res:
+            self.assertIs(subgraph.attrs, self.graph.attrs)
+
+    def test_invalid_node(self):
+        with self.assertRaises(rustworkx.InvalidNode):
+            rustworkx.graph_community_subgraphs(self.graph, [[0, 1], [42]])
+
+    def test_universal(self):
+        res = rustworkx.community_subgraphs(self.graph, [[0, 1, 2, 3]])
+        self.assertIsInstance(res[0][0], rustworkx.PyGraph)
+        self.assertEqual(len(res[0][0].edges()), 6)
@@ -0,0 +1,103 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+import unittest
+
+import rustworkx
+
+
+class TestContractEdge(unittest.TestCase):
+    def setUp(self):
+        # A square a - b - c - d - a with a diagonal a - c
+        self.graph = rustworkx.PyGraph()
+        self.a, self.b, self.c, self.d = self.graph.add_nodes_from(["a", "b", "c", "d"])
+        self.graph.add_edges_from(
+            [
+                (self.a, self.b, 1),
+                (self.b, self.c, 2),
+                (self.c, self.d, 3),
+                (self.d, self.a, 4),
+                (self.a, self.c, 5),
+            ]
+        )
+
+    def weighted_edges(self, graph):
+        edges = []
+        for source, target, weight in graph.weighted_edge_list():
+            source, target = sorted([graph[source], graph[target]])
+            edges.append((source, target, weight))
+        return sorted(edges)
+
+    def test_contract_edge_keeps_parallel_edges(self):
+        res = self.graph.contract_edge(self.a, self.b)
+        self.assertEqual(self.a, res)
+        self.assertEqual([self.a, self.c, self.d], self.graph.node_indices())
+        self.assertEqual(
+            [("a", "c", 2), ("a", "c", 5), ("a", "d", 4), ("c", "d", 3)],
+            self.weighted_edges(self.graph),
+        )
+
+    def test_contract_edge_merges_edges(self):
+        self.graph.contract_edge(
+            self.a, self.b, node_fn=lambda x, y: x + y, weight_combo_fn=lambda x, y: x + y
+        )
+        self.assertEqual("ab", self.graph[self.a])
+        self.assertEqual(
+            [("ab", "c", 7), ("ab", "d", 4), ("c", "d", 3)], self.weighted_edges(self.graph)
+        )
+
+    def test_contract_edge_not_multigraph(self):
+        graph = rustworkx.PyGraph(multigraph=False)
+        graph.extend_from_weighted_edge_list([(0, 1, "a"), (1, 2, "b"), (0, 2, "c")])
+        graph.contract_edge(0, 1)
+        self.assertEqual([(0, 2, "c")], graph.weighted_edge_list())
+
+    def test_contract_edge_self_loops(self):
+        self.graph.contract_edge(self.a, self.b, self_loops=True)
+        self.assertIn((self.a, self.a, 1), self.graph.weighted_edge_list())
+        self.graph.contract_edge(self.a, self.a)
+        self.assertFalse(self.graph.has_edge(self.a, self.a))
+
+    def test_contract_edge_no_edge(self):
+        with self.assertRaises(rustworkx.NoEdgeBetweenNodes):
+            self.graph.contract_edge(self.b, self.d)
+        self.assertEqual(4, len(self.graph))
+
+    def test_contract_edges(self):
+        node_map = self.graph.contract_edges(
+            [(self.a, self.b), (self.c, self.d), (self.b, self.c)],
+            node_fn=lambda x, y: x + y,
+            weight_combo_fn=lambda x, y: x + y,
+            self_loops=True,
+        )
+        self.assertEqual({self.b: self.a, self.c: self.a, self.d: self.a}, dict(node_map))
+        self.assertEqual(["abcd"], self.graph.nodes())
+        self.assertEqual([(self.a, self.a, 15)], self.graph.weighted_edge_list())
+
+    def test_contract_edges_skips_merged_edges(self):
+        node_map = self.graph.contract_edges([(self.a, self.b), (self.b, self.c), (self.a, self.c)])
+        self.assertEqual({self.b: self.a, self.c: self.a}, dict(node_map))
+        self.assertEqual([("a", "d", 3), ("a", "d", 4)], self.weighted_edges(self.graph))
+
+    def test_contract_edges_validates_before_contracting(self):
+        with self.assertRaises(rustworkx.NoEdgeBetweenNodes):
+            self.graph.contract_edges([(self.a, self.b), (self.b, self.d)])
+        self.assertEqual(4, len(self.graph))
+        self.assertEqual(5, self.graph.num_edges())
+
+    def test_contract_edges_error_in_callback(self):
+        def node_fn(x, y):
+            raise ValueError("bad payload")
+
+        with self.assertRaises(ValueError):
+            self.graph.contract_edge(self.a, self.b, node_fn=node_fn)
+        self.assertEqual(4, len(self.graph))
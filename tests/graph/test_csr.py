@@ -0,0 +1,85 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+import unittest
+
+import rustworkx
+
+
+class TestToCsr(unittest.TestCase):
+    def test_path_graph(self):
+        graph = rustworkx.generators.path_graph(4)
+        data, indices, indptr = graph.to_csr()
+        self.assertEqual(indptr.tolist(), [0, 1, 3, 5, 6])
+        self.assertEqual(indices.tolist(), [1, 0, 2, 1, 3, 2])
+        self.assertEqual(data.tolist(), [0, 0, 1, 1, 2, 2])
+
+    def test_empty(self):
+        data, indices, indptr = rustworkx.PyGraph().to_csr()
+        self.assertEqual(indptr.tolist(), [0])
+        self.assertEqual(indices.tolist(), [])
+        self.assertEqual(data.tolist(), [])
+
+    def test_self_loop_and_parallel_edges(self):
+        graph = rustworkx.PyGraph()
+        graph.add_nodes_from([None, None])
+        graph.add_edges_from_no_data([(0, 1), (1, 1), (0, 1)])
+        data, indices, indptr = graph.to_csr()
+        self.assertEqual(indptr.tolist(), [0, 2, 5])
+        self.assertEqual(indices.tolist(), [1, 1, 0, 1, 0])
+        self.assertEqual(data.tolist(), [0, 2, 0, 1, 2])
+
+    def test_removed_nodes(self):
+        graph = rustworkx.generators.path_graph(4)
+        graph.remove_node(1)
+        data, indices, indptr = graph.to_csr()
+        self.assertEqual(indptr.tolist(), [0, 0, 0, 1, 2])
+        self.assertEqual(indices.tolist(), [3, 2])
+        self.assertEqual(data.tolist(), [2, 2])
+
+    def test_rows_match_adjacency(self):
+        graph = rustworkx.undirected_gnm_random_graph(50, 200, seed=42)
+        data, indices, indptr = graph.to_csr()
+        for node in graph.node_indices():
+            row = slice(indptr[node], indptr[node + 1])
+            self.assertEqual(sorted(data[row].tolist()), sorted(graph.incident_edges(node)))
+            for edge, neighbor in zip(data[row].tolist(), indices[row].tolist()):
+                self.assertEqual(set(graph.get_edge_endpoints_by_index(edge)), {node, neighbor})
+
+    def test_betweenness_with_removed_nodes(self):
+        graph = rustworkx.generators.path_graph(5)
+        graph.remove_node(0)
+        res = rustworkx.graph_betweenness_centrality(graph, normalized=False)
+        self.assertEqual(dict(res), {1: 0.0, 2: 2.0, 3: 2.0, 4: 0.0})
+        res = rustworkx.graph_edge_betweenness_centrality(graph, normalized=False)
+        self.assertEqual(dict(res), {1: 3.0, 2: 4.0, 3: 3.0})
+
+    def test_large_graph_dijkstra(self):
+        # Large enough to run on the compressed sparse row form
+        graph = rustworkx.generators.path_graph(10_005)
+        graph.remove_node(5000)
+        res = rustworkx.graph_dijkstra_shortest_path_lengths(graph, 0, lambda _: 2.0)
+        self.assertEqual(dict(res), {node: 2.0 * node for node in range(1, 5000)})
+        # Searches for a target stay on the graph
+        res = rustworkx.graph_dijkstra_shortest_paths(graph, 10_004, target=9_990)
+        self.assertEqual(dict(res), {9_990: list(range(10_004, 9_989, -1))})
+        res = rustworkx.graph_dijkstra_shortest_paths(graph, 0, target=5001)
+        self.assertEqual(dict(res), {})
+
+    def test_large_graph_connected_components(self):
+        graph = rustworkx.generators.path_graph(10_005)
+        graph.remove_node(5000)
+        self.assertEqual(rustworkx.number_connected_components(graph), 2)
+        self.assertEqual(
+            sorted(map(sorted, rustworkx.connected_components(graph))),
+            [list(range(5000)), list(range(5001, 10_005))],
+        )
@@ -0,0 +1,78 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+import unittest
+
+import rustworkx
+
+
+class TestDegreeConstrainedSpanningTree(unittest.TestCase):
+    def assertSpanningTree(self, graph, tree, max_degree):
+        self.assertEqual(graph.node_indices(), tree.node_indices())
+        self.assertEqual(len(tree.edges()), len(graph) - 1)
+        self.assertTrue(rustworkx.is_connected(tree))
+        for node in tree.node_indices():
+            self.assertLessEqual(tree.degree(node), max_degree)
+
+    def test_unconstrained_is_minimum_spanning_tree(self):
+        graph = rustworkx.PyGraph()
+        graph.add_nodes_from(list(range(5)))
+        graph.add_edges_from(
+            [(0, 1, 4.0), (0, 2, 1.0), (1, 2, 2.0), (1, 3, 5.0), (2, 3, 8.0), (3, 4, 3.0)]
+        )
+        tree = rustworkx.degree_constrained_spanning_tree(graph, 4, weight_fn=float)
+        expected = rustworkx.minimum_spanning_tree(graph, weight_fn=float)
+        self.assertEqual(sorted(tree.weighted_edge_list()), sorted(expected.weighted_edge_list()))
+
+    def test_star_with_bound(self):
+        graph = rustworkx.generators.star_graph(5, multigraph=False)
+        for edge in graph.edge_indices():
+            graph.update_edge_by_index(edge, 1.0)
+        graph.add_edges_from([(1, 2, 3.0), (2, 3, 3.0), (3, 4, 3.0), (4, 1, 3.0)])
+        tree = rustworkx.degree_constrained_spanning_tree(graph, 2, weight_fn=float)
+        self.assertSpanningTree(graph, tree, 2)
+        self.assertEqual(sum(tree.edges()), 8.0)
+
+    def test_complete_graph_path(self):
+        graph = rustworkx.generators.complete_graph(8)
+        tree = rustworkx.degree_constrained_spanning_tree(graph, 2)
+        self.assertSpanningTree(graph, tree, 2)
+
+    def test_per_node_bounds(self):
+        graph = rustworkx.generators.star_graph(5)
+        graph.add_edges_from_no_data([(1, 2), (2, 3), (3, 4)])
+        tree = rustworkx.degree_constrained_spanning_tree(graph, {0: 1})
+        self.assertSpanningTree(graph, tree, 4)
+        self.assertEqual(tree.degree(0), 1)
+
+    def test_infeasible(self):
+        graph = rustworkx.generators.star_graph(5)
+        with self.assertRaises(ValueError):
+            rustworkx.degree_constrained_spanning_tree(graph, 2)
+
+    def test_disconnected_forest(self):
+        graph = rustworkx.generators.path_graph(3)
+        graph.add_nodes_from([None, None])
+        graph.add_edge(3, 4, None)
+        graph.add_edge(3, 3, None)
+        tree = rustworkx.degree_constrained_spanning_tree(graph, 2)
+        self.assertEqual(len(tree), 5)
+        self.assertEqual(sorted(tree.edge_list()), [(0, 1), (1, 2), (3, 4)])
+
+    def test_negative_weight(self):
+        graph = rustworkx.generators.path_graph(3)
+        with self.assertRaises(ValueError):
+            rustworkx.degree_constrained_spanning_tree(graph, 2, weight_fn=lambda _: -1.0)
+
+    def test_empty_graph(self):
+        tree = rustworkx.degree_constrained_spanning_tree(rustworkx.PyGraph(), 1)
+        self.assertEqual(len(tree), 0)
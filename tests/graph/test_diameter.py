@@ -0,0 +1,68 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+import unittest
+
+import rustworkx
+
+
+class TestDiameter(unittest.TestCase):
+    def test_grid_graph(self):
+        graph = rustworkx.generators.grid_graph(4, 6)
+        self.assertEqual(rustworkx.diameter(graph), 8)
+        self.assertEqual(rustworkx.radius(graph), 5)
+
+    def test_path_graph(self):
+        graph = rustworkx.generators.path_graph(10)
+        self.assertEqual(rustworkx.diameter(graph), 9)
+        self.assertEqual(rustworkx.radius(graph), 5)
+
+    def test_cycle_graph(self):
+        graph = rustworkx.generators.cycle_graph(11)
+        self.assertEqual(rustworkx.diameter(graph), 5)
+        self.assertEqual(rustworkx.radius(graph), 5)
+
+    def test_single_node(self):
+        graph = rustworkx.PyGraph()
+        graph.add_node(None)
+        self.assertEqual(rustworkx.diameter(graph), 0)
+        self.assertEqual(rustworkx.radius(graph), 0)
+
+    def test_matches_distance_matrix(self):
+        for seed in range(5):
+            graph = rustworkx.barabasi_albert_graph(60, 2, seed=seed)
+            eccentricities = [
+                max(rustworkx.dijkstra_shortest_path_lengths(graph, node, lambda _: 1).values())
+                for node in graph.node_indices()
+            ]
+            self.assertEqual(rustworkx.diameter(graph), max(eccentricities))
+            self.assertEqual(rustworkx.radius(graph), min(eccentricities))
+
+    def test_removed_nodes(self):
+        graph = rustworkx.generators.path_graph(6)
+        graph.remove_node(5)
+        self.assertEqual(rustworkx.diameter(graph), 4)
+        self.assertEqual(rustworkx.radius(graph), 2)
+
+    def test_empty(self):
+        with self.assertRaises(ValueError):
+            rustworkx.diameter(rustworkx.PyGraph())
+        with self.assertRaises(ValueError):
+            rustworkx.radius(rustworkx.PyGraph())
+
+    def test_disconnected(self):
+        graph = rustworkx.PyGraph()
+        graph.extend_from_edge_list([(0, 1), (2, 3)])
+        with self.assertRaises(ValueError):
+            rustworkx.diameter(graph)
+        with self.assertRaises(ValueError):
+            rustworkx.radius(graph)
@@ -12,6 +12,8 @@
 
 import unittest
 
+import numpy
+
 import rustworkx
 
 
@@ -261,3 +263,97 @@ class TestDijkstraGraph(unittest.TestCase):
                     rustworkx.graph_all_pairs_dijkstra_path_lengths(
                         graph, edge_cost_fn=lambda _: invalid_weight
                     )
+
+    def test_dijkstra_weight_array(self):
+        weights = numpy.array(self.graph.edges(), dtype=numpy.float64)
+        path = rustworkx.graph_dijkstra_shortest_path_lengths(self.graph, self.a, weights, self.e)
+        self.assertEqual({4: 20.0}, path)
+        expected = rustworkx.graph_dijkstra_shortest_paths(self.graph, self.a, weight_fn=float)
+        paths = rustworkx.dijkstra_shortest_paths(self.graph, self.a, weight_fn=weights)
+        self.assertEqual(expected, paths)
+
+    def test_dijkstra_weight_list(self):
+        weights = list(self.graph.edges())
+        expected = rustworkx.graph_all_pairs_dijkstra_path_lengths(self.graph, float)
+        lengths = rustworkx.graph_all_pairs_dijkstra_path_lengths(self.graph, weights)
+        self.assertEqual(expected, lengths)
+
+    def test_dijkstra_weight_array_removed_edge(self):
+        self.graph.remove_edge_from_index(0)
+        weights = numpy.zeros(self.graph.edge_indices()[-1] + 1)
+        weights[self.graph.edge_indices()] = self.graph.edges()
+        expected = rustworkx.all_pairs_dijkstra_shortest_paths(self.graph, float)
+        paths = rustworkx.all_pairs_dijkstra_shortest_paths(self.graph, weights)
+        self.assertEqual(expected, paths)
+
+    def test_dijkstra_weight_array_invalid(self):
+        with self.assertRaises(IndexError):
+            rustworkx.graph_dijkstra_shortest_path_lengths(self.graph, self.a, [1.0, 2.0])
+        with self.assertRaises(ValueError):
+            rustworkx.graph_dijkstra_shortest_path_lengths(
+                self.graph, self.a, numpy.full(9, -1.0)
+            )
+        with self.assertRaises(TypeError):
+            rustworkx.graph_dijkstra_shortest_path_lengths(self.graph, self.a, "weights")
+
+    def assert_csr_matches_all_pairs(self, graph, cutoff):
+        data, indices, indptr = rustworkx.graph_all_pairs_dijkstra_path_lengths_csr(
+            graph, float, cutoff=cutoff
+        )
+        self.assertEqual(len(indptr), max(graph.node_indices()) + 2)
+        lengths = rustworkx.graph_all_pairs_dijkstra_path_lengths(graph, float)
+        for source in range(len(indptr) - 1):
+            row = {
+                int(target): float(length)
+                for target, length in zip(
+                    indices[indptr[source] : indptr[source + 1]],
+                    data[indptr[source] : indptr[source + 1]],
+                )
+            }
+            expected = {}
+            if source in lengths:
+                expected = {
+                    target: length
+                    for target, length in lengths[source].items()
+                    if cutoff is None or length <= cutoff
+                }
+            self.assertEqual(expected, row)
+            self.assertEqual(sorted(row), list(indices[indptr[source] : indptr[source + 1]]))
+
+    def test_all_pairs_dijkstra_path_lengths_csr(self):
+        for cutoff in [None, 0.0, 9.0, 16.0]:
+            with self.subTest(cutoff=cutoff):
+                self.assert_csr_matches_all_pairs(self.graph, cutoff)
+
+    def test_all_pairs_dijkstra_path_lengths_csr_removed_node(self):
+        self.graph.remove_node(self.c)
+        self.assert_csr_matches_all_pairs(self.graph, 15.0)
+
+    def test_all_pairs_dijkstra_path_lengths_csr_progress(self):
+        reports = []
+        expected = rustworkx.all_pairs_dijkstra_path_lengths_csr(self.graph, float, 10.0)
+        output = rustworkx.all_pairs_dijkstra_path_lengths_csr(
+            self.graph,
+            float,
+            10.0,
+            num_threads=2,
+            progress_fn=lambda done, total: reports.append((done, total)),
+            progress_interval=4,
+        )
+        for array, expected_array in zip(output, expected):
+            numpy.testing.assert_array_equal(array, expected_array)
+        self.assertEqual([(4, 6), (6, 6)], reports)
+
+    def test_all_pairs_dijkstra_path_lengths_csr_invalid(self):
+        with self.assertRaises(ValueError):
+            rustworkx.graph_all_pairs_dijkstra_path_lengths_csr(self.graph, float, -1.0)
+        with self.assertRaises(ValueError):
+            rustworkx.graph_all_pairs_dijkstra_path_lengths_csr(self.graph, lambda _: -1.0)
+
+    def test_all_pairs_dijkstra_path_lengths_csr_empty(self):
+        data, indices, indptr = rustworkx.graph_all_pairs_dijkstra_path_lengths_csr(
+            rustworkx.PyGraph(), float
+        )
+        self.assertEqual([], list(data))
+        self.assertEqual([], list(indices))
+        self.assertEqual([0], list(indptr))
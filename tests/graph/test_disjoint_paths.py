@@ -0,0 +1,95 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+import unittest
+
+import rustworkx
+
+
+class TestGraphDisjointPaths(unittest.TestCase):
+    def assertEdgeDisjoint(self, graph, paths, source, target):
+        used = set()
+        for path in paths:
+            self.assertEqual(path[0], source)
+            self.assertEqual(path[-1], target)
+            for u, v in zip(path, path[1:]):
+                self.assertTrue(graph.has_edge(u, v))
+                edge = frozenset((u, v))
+                self.assertNotIn(edge, used)
+                used.add(edge)
+
+    def assertNodeDisjoint(self, graph, paths, source, target):
+        self.assertEdgeDisjoint(graph, paths, source, target)
+        inner = [node for path in paths for node in path[1:-1]]
+        self.assertEqual(len(inner), len(set(inner)))
+        self.assertNotIn(source, inner)
+        self.assertNotIn(target, inner)
+
+    def test_edge_disjoint_grid(self):
+        graph = rustworkx.generators.grid_graph(3, 3)
+        paths = rustworkx.graph_edge_disjoint_paths(graph, 0, 8)
+        self.assertEqual(len(paths), 2)
+        self.assertEqual(sum(len(path) - 1 for path in paths), 8)
+        self.assertEdgeDisjoint(graph, paths, 0, 8)
+
+    def test_edge_disjoint_shared_node(self):
+        graph = rustworkx.PyGraph()
+        graph.extend_from_edge_list([(0, 1), (1, 2), (2, 0), (2, 3), (3, 4), (4, 2)])
+        paths = rustworkx.edge_disjoint_paths(graph, 0, 4)
+        self.assertEqual(len(paths), 2)
+        self.assertEdgeDisjoint(graph, paths, 0, 4)
+        self.assertEqual(rustworkx.node_disjoint_paths(graph, 0, 4), [[0, 2, 4]])
+
+    def test_node_disjoint_complete(self):
+        graph = rustworkx.generators.complete_graph(6)
+        paths = rustworkx.graph_node_disjoint_paths(graph, 0, 5)
+        self.assertEqual(len(paths), 5)
+        self.assertIn([0, 5], paths)
+        self.assertNodeDisjoint(graph, paths, 0, 5)
+
+    def test_limit(self):
+        graph = rustworkx.generators.complete_graph(6)
+        self.assertEqual(len(rustworkx.graph_edge_disjoint_paths(graph, 0, 5, k=3)), 3)
+        self.assertEqual(len(rustworkx.graph_node_disjoint_paths(graph, 0, 5, k=1)), 1)
+        self.assertEqual(rustworkx.graph_node_disjoint_paths(graph, 0, 5, k=0), [])
+
+    def test_disconnected(self):
+        graph = rustworkx.PyGraph()
+        graph.add_nodes_from(range(4))
+        graph.add_edge(0, 1, None)
+        graph.add_edge(2, 3, None)
+        self.assertEqual(rustworkx.graph_edge_disjoint_paths(graph, 0, 3), [])
+        self.assertEqual(rustworkx.graph_node_disjoint_paths(graph, 0, 3), [])
+
+    def test_count_matches_connectivity(self):
+        graph = rustworkx.generators.generalized_petersen_graph(5, 2)
+        for target in range(1, 10):
+            edge_paths = rustworkx.graph_edge_disjoint_paths(graph, 0, target)
+            node_paths = rustworkx.graph_node_disjoint_paths(graph, 0, target)
+            self.assertEqual(len(edge_paths), 3)
+            self.assertEqual(len(node_paths), 3)
+            self.assertEdgeDisjoint(graph, edge_paths, 0, target)
+            self.assertNodeDisjoint(graph, node_paths, 0, target)
+
+    def test_invalid_node(self):
+        graph = rustworkx.generators.path_graph(3)
+        with self.assertRaises(rustworkx.InvalidNode):
+            rustworkx.graph_edge_disjoint_paths(graph, 0, 5)
+        with self.assertRaises(rustworkx.InvalidNode):
+            rustworkx.graph_node_disjoint_paths(graph, 5, 0)
+
+    def test_same_node(self):
+        graph = rustworkx.generators.path_graph(3)
+        with self.assertRaises(ValueError):
+            rustworkx.graph_edge_disjoint_paths(graph, 1, 1)
+        with self.assertRaises(ValueError):
+            rustworkx.graph_node_disjoint_paths(graph, 1, 1)
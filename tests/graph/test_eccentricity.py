@@ -0,0 +1,77 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+import unittest
+
+import rustworkx
+
+
+class TestEccentricity(unittest.TestCase):
+    def setUp(self):
+        self.graph = rustworkx.PyGraph()
+        self.graph.extend_from_weighted_edge_list(
+            [(0, 1, 1.0), (1, 2, 1.0), (2, 0, 5.0), (2, 3, 2.0)]
+        )
+
+    def test_unweighted(self):
+        self.assertEqual(rustworkx.eccentricity(self.graph), {0: 2.0, 1: 2.0, 2: 1.0, 3: 2.0})
+        self.assertEqual(rustworkx.center(self.graph), [2])
+        self.assertEqual(rustworkx.periphery(self.graph), [0, 1, 3])
+
+    def test_weighted(self):
+        self.assertEqual(
+            rustworkx.graph_eccentricity(self.graph, weight_fn=float),
+            {0: 4.0, 1: 3.0, 2: 2.0, 3: 4.0},
+        )
+        self.assertEqual(rustworkx.graph_center(self.graph, weight_fn=float), [2])
+        self.assertEqual(rustworkx.graph_periphery(self.graph, weight_fn=float), [0, 3])
+
+    def test_default_weight(self):
+        res = rustworkx.eccentricity(self.graph, default_weight=2.5)
+        self.assertEqual(res, {0: 5.0, 1: 5.0, 2: 2.5, 3: 5.0})
+
+    def test_path_graph(self):
+        graph = rustworkx.generators.path_graph(5)
+        self.assertEqual(rustworkx.center(graph), [2])
+        self.assertEqual(rustworkx.periphery(graph), [0, 4])
+
+    def test_removed_node(self):
+        graph = rustworkx.generators.path_graph(5)
+        graph.remove_node(0)
+        self.assertEqual(rustworkx.eccentricity(graph), {1: 3.0, 2: 2.0, 3: 2.0, 4: 3.0})
+        self.assertEqual(rustworkx.center(graph), [2, 3])
+
+    def test_empty(self):
+        graph = rustworkx.PyGraph()
+        self.assertEqual(rustworkx.eccentricity(graph), {})
+        self.assertEqual(rustworkx.center(graph), [])
+
+    def test_disconnected(self):
+        graph = rustworkx.PyGraph()
+        graph.extend_from_edge_list([(0, 1), (2, 3)])
+        with self.assertRaises(ValueError):
+            rustworkx.eccentricity(graph)
+        with self.assertRaises(ValueError):
+            rustworkx.center(graph)
+        with self.assertRaises(ValueError):
+            rustworkx.periphery(graph)
+
+    def test_negative_weight(self):
+        with self.assertRaises(ValueError):
+            rustworkx.eccentricity(self.graph, weight_fn=lambda _: -1.0)
+
+    def test_weight_fn_error(self):
+        def weight_fn(_):
+            raise IndexError
+
+        with self.assertRaises(IndexError):
+            rustworkx.eccentricity(self.graph, weight_fn=weight_fn)
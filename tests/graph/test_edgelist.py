@@ -207,3 +207,72 @@ def cleanup_file(path):
         os.remove(path)
     except Exception:
         pass
+
+
+class TestEdgeIterator(unittest.TestCase):
+    def test_from_generator(self):
+        edges = ((i, i + 1) for i in range(5))
+        graph = rustworkx.PyGraph.from_edge_iterator(edges, chunk_size=2)
+        self.assertEqual(graph.edge_list(), [(i, i + 1) for i in range(5)])
+        self.assertEqual(graph.nodes(), [None] * 6)
+        self.assertTrue(graph.multigraph)
+
+    def test_weighted_and_unweighted(self):
+        graph = rustworkx.PyGraph.from_edge_iterator([(0, 1, "a"), [1, 2]])
+        self.assertEqual(graph.weighted_edge_list(), [(0, 1, "a"), (1, 2, None)])
+
+    def test_from_file(self):
+        with tempfile.NamedTemporaryFile("wt") as fd:
+            fd.write("0 1\n1 2\n2 0\n")
+            fd.flush()
+            with open(fd.name) as edge_file:
+                edges = (tuple(map(int, line.split())) for line in edge_file)
+                graph = rustworkx.PyGraph.from_edge_iterator(edges, num_edges=3)
+        self.assertEqual(graph.edge_list(), [(0, 1), (1, 2), (2, 0)])
+
+    def test_num_nodes(self):
+        graph = rustworkx.PyGraph.from_edge_iterator([(0, 1)], num_nodes=4)
+        self.assertEqual(len(graph), 4)
+        self.assertEqual(graph.edge_list(), [(0, 1)])
+
+    def test_index_out_of_range(self):
+        with self.assertRaises(ValueError):
+            rustworkx.PyGraph.from_edge_iterator([(0, 1), (1, 4)], num_nodes=4)
+
+    def test_index_too_large(self):
+        with self.assertRaises(ValueError):
+            rustworkx.PyGraph.from_edge_iterator([(0, 10**12)])
+
+    def test_multigraph(self):
+        edges = [(0, 1, 1), (1, 0, 2), (1, 2, 3)]
+        graph = rustworkx.PyGraph.from_edge_iterator(edges)
+        self.assertEqual(graph.num_edges(), 3)
+        graph = rustworkx.PyGraph.from_edge_iterator(edges, multigraph=False, chunk_size=1)
+        self.assertFalse(graph.multigraph)
+        self.assertEqual(graph.weighted_edge_list(), [(0, 1, 2), (1, 2, 3)])
+
+    def test_combine_fn(self):
+        edges = [(0, 1, 1), (1, 0, 2), (1, 2, 3), (0, 1, 4)]
+        graph = rustworkx.PyGraph.from_edge_iterator(
+            edges, multigraph=False, combine_fn=lambda a, b: a + b
+        )
+        self.assertEqual(graph.weighted_edge_list(), [(0, 1, 7), (1, 2, 3)])
+
+    def test_combine_fn_error(self):
+        def combine_fn(a, b):
+            raise KeyError
+
+        with self.assertRaises(KeyError):
+            rustworkx.PyGraph.from_edge_iterator(
+                [(0, 1), (0, 1)], multigraph=False, combine_fn=combine_fn
+            )
+
+    def test_invalid_edges(self):
+        with self.assertRaises(ValueError):
+            rustworkx.PyGraph.from_edge_iterator([(0, 1, 2, 3)])
+        with self.assertRaises(TypeError):
+            rustworkx.PyGraph.from_edge_iterator([(0, "a")])
+        with self.assertRaises(TypeError):
+            rustworkx.PyGraph.from_edge_iterator(5)
+        with self.assertRaises(ValueError):
+            rustworkx.PyGraph.from_edge_iterator([(0, 1)], chunk_size=0)
@@ -0,0 +1,63 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+import unittest
+
+import rustworkx
+
+
+class TestEfficiency(unittest.TestCase):
+    def test_complete_graph(self):
+        graph = rustworkx.generators.complete_graph(6)
+        self.assertEqual(rustworkx.global_efficiency(graph), 1.0)
+        self.assertEqual(rustworkx.local_efficiency(graph), 1.0)
+
+    def test_petersen_graph(self):
+        graph = rustworkx.generators.generalized_petersen_graph(5, 2)
+        self.assertAlmostEqual(rustworkx.global_efficiency(graph), 2 / 3)
+        self.assertEqual(rustworkx.local_efficiency(graph), 0.0)
+
+    def test_complete_graph_minus_edge(self):
+        graph = rustworkx.generators.complete_graph(5)
+        graph.remove_edge(0, 1)
+        self.assertAlmostEqual(rustworkx.local_efficiency(graph), 0.95)
+
+    def test_disconnected(self):
+        graph = rustworkx.PyGraph()
+        graph.extend_from_edge_list([(0, 1), (1, 2), (2, 0), (2, 3), (3, 4), (5, 6)])
+        graph.add_node(None)
+        self.assertAlmostEqual(rustworkx.global_efficiency(graph), 0.2916666666666667)
+        self.assertAlmostEqual(rustworkx.local_efficiency(graph), 0.2916666666666667)
+
+    def test_matches_distance_matrix(self):
+        for seed in range(5):
+            graph = rustworkx.barabasi_albert_graph(40, 2, seed=seed)
+            num_nodes = len(graph)
+            lengths = rustworkx.all_pairs_dijkstra_path_lengths(graph, lambda _: 1)
+            expected = sum(
+                1 / length for targets in lengths.values() for length in targets.values()
+            ) / (num_nodes * (num_nodes - 1))
+            self.assertAlmostEqual(rustworkx.global_efficiency(graph), expected)
+
+    def test_self_loops_and_parallel_edges(self):
+        graph = rustworkx.PyGraph()
+        graph.extend_from_edge_list([(0, 1), (0, 1), (1, 2), (2, 0), (0, 0)])
+        self.assertEqual(rustworkx.global_efficiency(graph), 1.0)
+        self.assertEqual(rustworkx.local_efficiency(graph), 1.0)
+
+    def test_small_graphs(self):
+        graph = rustworkx.PyGraph()
+        self.assertEqual(rustworkx.global_efficiency(graph), 0.0)
+        self.assertEqual(rustworkx.local_efficiency(graph), 0.0)
+        graph.add_node(None)
+        self.assertEqual(rustworkx.global_efficiency(graph), 0.0)
+        self.assertEqual(rustworkx.local_efficiency(graph), 0.0)
@@ -0,0 +1,92 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+import itertools
+import random
+import unittest
+
+import rustworkx
+
+
+def is_forest(graph, removed):
+    subgraph = graph.copy()
+    subgraph.remove_nodes_from(list(removed))
+    # A forest has one edge less than nodes per connected component, which
+    # also rules out self-loops and parallel edges
+    return subgraph.num_edges() == subgraph.num_nodes() - rustworkx.number_connected_components(
+        subgraph
+    )
+
+class TestFeedbackVertexSet(unittest.TestCase):
+    def test_empty(self):
+        graph = rustworkx.PyGraph()
+        self.assertEqual([], list(rustworkx.feedback_vertex_set(graph)))
+
+    def test_tree(self):
+        graph = rustworkx.generators.binomial_tree_graph(4)
+        self.assertEqual([], list(rustworkx.graph_feedback_vertex_set(graph)))
+
+    def test_petersen(self):
+        # The minimum feedback vertex set of the Petersen graph has 3 nodes
+        graph = rustworkx.generators.generalized_petersen_graph(5, 2)
+        result = rustworkx.feedback_vertex_set(graph)
+        self.assertTrue(is_forest(graph, result))
+        self.assertLessEqual(len(result), 6)
+
+    def test_self_loop_and_parallel_edges(self):
+        graph = rustworkx.PyGraph()
+        graph.add_nodes_from(range(4))
+        graph.add_edges_from_no_data([(0, 0), (1, 2), (1, 2), (2, 3)])
+        result = rustworkx.feedback_vertex_set(graph)
+        self.assertEqual(2, len(result))
+        self.assertIn(0, result)
+        self.assertTrue(is_forest(graph, result))
+
+    def test_weight_fn(self):
+        graph = rustworkx.generators.cycle_graph(5)
+        for node in graph.node_indices():
+            graph[node] = 10.0
+        graph[3] = 1.0
+        self.assertEqual([3], list(rustworkx.feedback_vertex_set(graph, weight_fn=float)))
+
+    def test_within_factor_two(self):
+        rng = random.Random(42)
+        for _ in range(20):
+            graph = rustworkx.undirected_gnm_random_graph(9, 14, seed=rng.randrange(1000))
+            for node in graph.node_indices():
+                graph[node] = float(rng.randint(1, 5))
+            result = rustworkx.feedback_vertex_set(graph, weight_fn=float)
+            self.assertTrue(is_forest(graph, result))
+            optimum = min(
+                sum(graph[n] for n in subset)
+                for size in range(graph.num_nodes() + 1)
+                for subset in itertools.combinations(graph.node_indices(), size)
+                if is_forest(graph, subset)
+            )
+            self.assertLessEqual(sum(graph[n] for n in result), 2 * optimum)
+
+    def test_minimal(self):
+        graph = rustworkx.generators.grid_graph(4, 4)
+        result = list(rustworkx.feedback_vertex_set(graph))
+        self.assertTrue(is_forest(graph, result))
+        for node in result:
+            self.assertFalse(is_forest(graph, [n for n in result if n != node]))
+
+    def test_negative_weight(self):
+        graph = rustworkx.generators.cycle_graph(3)
+        with self.assertRaises(ValueError):
+            rustworkx.feedback_vertex_set(graph, weight_fn=lambda _: -1.0)
+
+    def test_weight_sequence_not_accepted(self):
+        graph = rustworkx.generators.cycle_graph(3)
+        with self.assertRaisesRegex(TypeError, "node weights"):
+            rustworkx.feedback_vertex_set(graph, weight_fn=[1.0, 2.0, 3.0])
@@ -0,0 +1,93 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+
+import math
+import unittest
+
+import rustworkx
+
+
+class TestAlgebraicConnectivity(unittest.TestCase):
+    def test_path(self):
+        graph = rustworkx.generators.path_graph(10)
+        res = rustworkx.algebraic_connectivity(graph, seed=42)
+        self.assertAlmostEqual(res, 2 * (1 - math.cos(math.pi / 10)))
+
+    def test_complete_graph(self):
+        graph = rustworkx.generators.complete_graph(5)
+        self.assertAlmostEqual(rustworkx.algebraic_connectivity(graph, seed=42), 5)
+        res = rustworkx.algebraic_connectivity(graph, normalized=True, seed=42)
+        self.assertAlmostEqual(res, 1.25)
+
+    def test_weights(self):
+        graph = rustworkx.PyGraph()
+        graph.add_nodes_from(range(2))
+        graph.add_edges_from([(0, 1, 1.0), (0, 1, 2.0)])
+        res = rustworkx.algebraic_connectivity(graph, weight_fn=float, seed=42)
+        self.assertAlmostEqual(res, 6)
+
+    def test_removed_node(self):
+        graph = rustworkx.generators.path_graph(4)
+        graph.remove_node(0)
+        res = rustworkx.algebraic_connectivity(graph, seed=42)
+        self.assertAlmostEqual(res, 1)
+
+    def test_disconnected(self):
+        graph = rustworkx.PyGraph()
+        graph.add_nodes_from(range(3))
+        graph.add_edge(0, 1, None)
+        self.assertEqual(rustworkx.algebraic_connectivity(graph), 0)
+
+    def test_too_few_nodes(self):
+        graph = rustworkx.PyGraph()
+        graph.add_node(None)
+        with self.assertRaises(ValueError):
+            rustworkx.algebraic_connectivity(graph)
+
+    def test_negative_weight(self):
+        graph = rustworkx.generators.path_graph(3)
+        with self.assertRaises(ValueError):
+            rustworkx.algebraic_connectivity(graph, weight_fn=lambda _: -1.0)
+
+    def test_failed_to_converge(self):
+        graph = rustworkx.generators.path_graph(200)
+        with self.assertRaises(rustworkx.FailedToConverge):
+            rustworkx.algebraic_connectivity(graph, max_iter=1, tol=1e-14, seed=42)
+
+
+class TestFiedlerVector(unittest.TestCase):
+    def test_barbell(self):
+        graph = rustworkx.generators.barbell_graph(4, 0)
+        res = rustworkx.fiedler_vector(graph, seed=42)
+        self.assertEqual(len(res), 8)
+        self.assertAlmostEqual(sum(x * x for x in res), 1)
+        self.assertAlmostEqual(sum(res), 0)
+        signs = {x > 0 for x in res[:4]}
+        self.assertEqual(len(signs), 1)
+        self.assertEqual({x > 0 for x in res[4:]}, {not signs.pop()})
+
+    def test_path_is_monotone(self):
+        graph = rustworkx.generators.path_graph(6)
+        res = list(rustworkx.fiedler_vector(graph, seed=42))
+        self.assertIn(res, [sorted(res), sorted(res, reverse=True)])
+
+    def test_disconnected(self):
+        graph = rustworkx.PyGraph()
+        graph.add_nodes_from(range(3))
+        graph.add_edge(0, 1, None)
+        with self.assertRaises(ValueError):
+            rustworkx.fiedler_vector(graph)
+
+    def test_too_few_nodes(self):
+        with self.assertRaises(ValueError):
+            rustworkx.fiedler_vector(rustworkx.PyGraph())
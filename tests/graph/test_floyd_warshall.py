@@ -83,6 +83,30 @@ class TestFloydWarshall(unittest.TestCase):
 
         self.assertEqual(result, expected)
 
+    def test_edge_weight_sequence_with_removals(self):
+        graph = rustworkx.generators.cycle_graph(6)
+        for index in graph.edge_indices():
+            graph.update_edge_by_index(index, index + 1)
+        graph.remove_edge_from_index(2)
+        graph.remove_node(4)
+        weights = [float(index + 1) for index in range(6)]
+        weights[2] = -100.0
+        result = rustworkx.graph_floyd_warshall(
+            graph, weights, parallel_threshold=self.parallel_threshold
+        )
+        expected = rustworkx.graph_floyd_warshall(
+            graph, float, parallel_threshold=self.parallel_threshold
+        )
+        self.assertEqual(result, expected)
+        numpy.testing.assert_array_equal(
+            rustworkx.graph_floyd_warshall_numpy(
+                graph, weights, parallel_threshold=self.parallel_threshold
+            ),
+            rustworkx.graph_floyd_warshall_numpy(
+                graph, float, parallel_threshold=self.parallel_threshold
+            ),
+        )
+
     def test_floyd_warshall_empty_graph(self):
         graph = rustworkx.PyGraph()
         self.assertEqual({}, rustworkx.graph_floyd_warshall(graph, float))
@@ -0,0 +1,130 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+import unittest
+
+import rustworkx
+
+
+class TestGraphSummary(unittest.TestCase):
+    def test_empty_graph(self):
+        summary = rustworkx.graph_graph_summary(rustworkx.PyGraph())
+        self.assertEqual(summary.num_nodes, 0)
+        self.assertEqual(summary.num_edges, 0)
+        self.assertEqual(summary.density, 0.0)
+        self.assertEqual(summary.min_degree, 0)
+        self.assertEqual(summary.max_degree, 0)
+        self.assertEqual(summary.mean_degree, 0.0)
+        self.assertEqual(summary.num_components, 0)
+        self.assertEqual(summary.average_clustering, 0.0)
+        self.assertFalse(summary.is_dag)
+        self.assertTrue(summary.is_forest)
+        self.assertTrue(summary.is_bipartite)
+
+    def test_complete_graph(self):
+        summary = rustworkx.graph_graph_summary(rustworkx.generators.complete_graph(5))
+        self.assertEqual(summary.num_nodes, 5)
+        self.assertEqual(summary.num_edges, 10)
+        self.assertEqual(summary.density, 1.0)
+        self.assertEqual(summary.min_degree, 4)
+        self.assertEqual(summary.max_degree, 4)
+        self.assertEqual(summary.mean_degree, 4.0)
+        self.assertEqual(summary.num_components, 1)
+        self.assertAlmostEqual(summary.average_clustering, 1.0)
+        self.assertFalse(summary.is_forest)
+        self.assertFalse(summary.is_bipartite)
+
+    def test_star_and_path(self):
+        graph = rustworkx.generators.star_graph(4)
+        graph.compose(rustworkx.generators.path_graph(3), {})
+        summary = rustworkx.graph_graph_summary(graph)
+        self.assertEqual(summary.num_nodes, 7)
+        self.assertEqual(summary.num_edges, 5)
+        self.assertAlmostEqual(summary.density, 5 / 21)
+        self.assertEqual(summary.min_degree, 1)
+        self.assertEqual(summary.max_degree, 3)
+        self.assertAlmostEqual(summary.mean_degree, 10 / 7)
+        self.assertEqual(summary.num_components, 2)
+        self.assertEqual(summary.average_clustering, 0.0)
+        self.assertFalse(summary.is_dag)
+        self.assertTrue(summary.is_forest)
+        self.assertTrue(summary.is_bipartite)
+
+    def test_average_clustering(self):
+        graph = rustworkx.PyGraph()
+        graph.extend_from_edge_list([(0, 1), (1, 2), (2, 0), (2, 3)])
+        summary = rustworkx.graph_graph_summary(graph)
+        self.assertAlmostEqual(summary.average_clustering, (1 + 1 + 1 / 3 + 0) / 4)
+        self.assertFalse(summary.is_bipartite)
+
+    def test_self_loop(self):
+        graph = rustworkx.PyGraph()
+        graph.extend_from_edge_list([(0, 1), (1, 1)])
+        summary = rustworkx.graph_graph_summary(graph)
+        self.assertEqual(summary.max_degree, 3)
+        self.assertEqual(summary.mean_degree, 2.0)
+        self.assertFalse(summary.is_forest)
+        self.assertFalse(summary.is_bipartite)
+
+    def test_parallel_edges_not_forest(self):
+        graph = rustworkx.PyGraph()
+        graph.extend_from_edge_list([(0, 1), (0, 1)])
+        self.assertFalse(rustworkx.graph_graph_summary(graph).is_forest)
+
+    def test_node_holes(self):
+        graph = rustworkx.generators.path_graph(5)
+        graph.remove_node(2)
+        summary = rustworkx.graph_graph_summary(graph)
+        self.assertEqual(summary.num_nodes, 4)
+        self.assertEqual(summary.num_edges, 2)
+        self.assertEqual(summary.num_components, 2)
+        self.assertTrue(summary.is_forest)
+
+    def test_clustering_samples(self):
+        graph = rustworkx.generators.complete_graph(10)
+        graph.add_nodes_from(range(10))
+        exact = rustworkx.graph_graph_summary(graph).average_clustering
+        self.assertAlmostEqual(exact, 0.5)
+        estimate = rustworkx.graph_graph_summary(graph, clustering_samples=5, seed=42)
+        # Every sampled node has a clustering coefficient of either 0 or 1
+        sampled_clustering = estimate.average_clustering * 5
+        self.assertAlmostEqual(sampled_clustering, round(sampled_clustering))
+        self.assertEqual(
+            estimate.average_clustering,
+            rustworkx.graph_graph_summary(graph, clustering_samples=5, seed=42).average_clustering,
+        )
+        self.assertAlmostEqual(
+            rustworkx.graph_graph_summary(graph, clustering_samples=100).average_clustering,
+            exact,
+        )
+        self.assertEqual(
+            rustworkx.graph_graph_summary(graph, clustering_samples=0).average_clustering, 0.0
+        )
+
+    def test_repr(self):
+        summary = rustworkx.graph_graph_summary(rustworkx.generators.path_graph(2))
+        self.assertEqual(
+            repr(summary),
+            "GraphSummary(num_nodes=2, num_edges=1, density=1.0, min_degree=1, max_degree=1, "
+            "mean_degree=1.0, num_components=1, average_clustering=0.0, is_dag=False, "
+            "is_forest=True, is_bipartite=True)",
+        )
+
+    def test_universal(self):
+        summary = rustworkx.graph_summary(rustworkx.generators.cycle_graph(4))
+        self.assertIsInstance(summary, rustworkx.GraphSummary)
+        self.assertEqual(summary.num_edges, 4)
+        self.assertTrue(summary.is_bipartite)
+
+    def test_invalid_type(self):
+        with self.assertRaises(TypeError):
+            rustworkx.graph_summary(None)
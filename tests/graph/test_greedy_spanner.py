@@ -0,0 +1,69 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+import unittest
+
+import rustworkx
+
+
+class TestGreedySpanner(unittest.TestCase):
+    def assertStretch(self, graph, edges, stretch):
+        spanner = rustworkx.PyGraph()
+        spanner.add_nodes_from(graph.nodes())
+        spanner.add_edges_from(list(edges))
+        original = rustworkx.all_pairs_dijkstra_path_lengths(graph, float)
+        sparse = rustworkx.all_pairs_dijkstra_path_lengths(spanner, float)
+        for source, lengths in original.items():
+            for target, length in lengths.items():
+                self.assertLessEqual(sparse[source][target], stretch * length + 1e-9)
+
+    def test_complete_graph_unit_weights(self):
+        graph = rustworkx.generators.complete_graph(8)
+        # With a stretch below 2 no edge of a complete graph can be dropped
+        self.assertEqual(len(rustworkx.greedy_spanner(graph, 1.5)), 28)
+        # With a stretch of 2 a star is enough
+        self.assertEqual(len(rustworkx.greedy_spanner(graph, 2.0)), 7)
+
+    def test_stretch_is_respected(self):
+        graph = rustworkx.generators.grid_graph(6, 6)
+        for index, edge in enumerate(graph.edge_indices()):
+            graph.update_edge_by_index(edge, float(1 + (index * 31) % 7))
+        for node in range(0, 30, 3):
+            graph.add_edge(node, node + 6 if node + 6 < 36 else 0, 2.5)
+        for stretch in [1.0, 1.5, 3.0]:
+            edges = rustworkx.greedy_spanner(graph, stretch, weight_fn=float)
+            self.assertStretch(graph, edges, stretch)
+            self.assertLessEqual(len(edges), graph.num_edges())
+
+    def test_large_stretch_is_minimum_spanning_tree(self):
+        graph = rustworkx.PyGraph()
+        graph.extend_from_weighted_edge_list(
+            [(0, 1, 1.0), (1, 2, 2.0), (2, 0, 3.0), (2, 3, 1.0), (3, 0, 5.0)]
+        )
+        edges = rustworkx.greedy_spanner(graph, 100.0, weight_fn=float)
+        self.assertEqual(sorted(edges), [(0, 1, 1.0), (1, 2, 2.0), (2, 3, 1.0)])
+
+    def test_empty_graph(self):
+        self.assertEqual(len(rustworkx.greedy_spanner(rustworkx.PyGraph(), 2.0)), 0)
+
+    def test_invalid_stretch(self):
+        graph = rustworkx.generators.path_graph(3)
+        with self.assertRaises(ValueError):
+            rustworkx.greedy_spanner(graph, 0.5)
+        with self.assertRaises(ValueError):
+            rustworkx.greedy_spanner(graph, float("nan"))
+
+    def test_negative_weight(self):
+        graph = rustworkx.PyGraph()
+        graph.extend_from_weighted_edge_list([(0, 1, 1.0), (1, 2, -1.0)])
+        with self.assertRaises(ValueError):
+            rustworkx.greedy_spanner(graph, 2.0, weight_fn=float)
@@ -311,3 +311,66 @@ class TestIsomorphic(unittest.TestCase):
             with self.subTest(id_order=id_order):
                 mapping = rustworkx.graph_vf2_mapping(g_a, g_b, id_order=id_order, subgraph=False)
                 self.assertEqual({}, next(mapping))
+
+
+class TestDistinguishingInvariant(unittest.TestCase):
+    def test_isomorphic_graphs(self):
+        graph = rustworkx.generators.generalized_petersen_graph(5, 2)
+        relabeled = rustworkx.PyGraph()
+        relabeled.add_nodes_from(range(graph.num_nodes()))
+        for a, b in reversed(graph.edge_list()):
+            relabeled.add_edge(9 - b, 9 - a, None)
+        self.assertIsNone(rustworkx.distinguishing_invariant(graph, relabeled))
+        self.assertTrue(rustworkx.is_isomorphic(graph, relabeled))
+
+    def test_invariants(self):
+        path = rustworkx.generators.path_graph(4)
+        star = rustworkx.generators.star_graph(4)
+        cycle = rustworkx.generators.cycle_graph(6)
+        triangles = rustworkx.generators.cycle_graph(3)
+        triangles.compose(rustworkx.generators.cycle_graph(3), {})
+        first = rustworkx.generators.path_graph(6)
+        first.add_edge(1, first.add_node(None), None)
+        second = rustworkx.generators.path_graph(6)
+        second.add_edge(2, second.add_node(None), None)
+        cases = [
+            (path, rustworkx.generators.path_graph(5), "node_count"),
+            (path, rustworkx.generators.cycle_graph(4), "edge_count"),
+            (path, star, "degree_sequence"),
+            (cycle, triangles, "triangle_counts"),
+            (first, second, "weisfeiler_lehman_colors"),
+        ]
+        for a, b, expected in cases:
+            with self.subTest(expected=expected):
+                self.assertEqual(expected, rustworkx.graph_distinguishing_invariant(a, b))
+                self.assertEqual(expected, rustworkx.distinguishing_invariant(b, a))
+                self.assertFalse(rustworkx.is_isomorphic(a, b))
+        self.assertIsNone(rustworkx.distinguishing_invariant(first, second, wl_iterations=0))
+
+    def test_vf2_skipped_on_mismatch(self):
+        cycle = rustworkx.generators.cycle_graph(6)
+        triangles = rustworkx.generators.cycle_graph(3)
+        triangles.compose(rustworkx.generators.cycle_graph(3), {})
+        states = []
+        self.assertFalse(
+            rustworkx.is_isomorphic(
+                cycle,
+                triangles,
+                progress_fn=lambda count, _: states.append(count),
+                progress_interval=1,
+            )
+        )
+        self.assertEqual(states, [])
+        self.assertTrue(
+            rustworkx.is_isomorphic(
+                cycle,
+                rustworkx.generators.cycle_graph(6),
+                progress_fn=lambda count, _: states.append(count),
+                progress_interval=1,
+            )
+        )
+        self.assertNotEqual(states, [])
+
+    def test_invalid_type(self):
+        with self.assertRaises(TypeError):
+            rustworkx.distinguishing_invariant(None, None)
@@ -0,0 +1,81 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+import itertools
+import sys
+import unittest
+
+import rustworkx
+
+
+class TestKSmallestSpanningTrees(unittest.TestCase):
+    def setUp(self):
+        self.graph = rustworkx.PyGraph()
+        self.graph.add_nodes_from(range(4))
+        self.graph.extend_from_weighted_edge_list(
+            [(0, 1, 1.0), (0, 2, 2.0), (0, 3, 3.0), (1, 2, 4.0), (1, 3, 5.0), (2, 3, 6.0)]
+        )
+
+    def test_first_tree_is_minimum_spanning_tree(self):
+        res = rustworkx.k_smallest_spanning_trees(self.graph, 1, weight_fn=float)
+        self.assertEqual(len(res), 1)
+        weight, edges = res[0]
+        self.assertEqual(weight, 6.0)
+        expected = rustworkx.minimum_spanning_edges(self.graph, weight_fn=float)
+        self.assertEqual(sorted(edges), sorted(expected))
+
+    def test_all_spanning_trees(self):
+        res = rustworkx.k_smallest_spanning_trees(self.graph, 100, weight_fn=float)
+        # K4 has 16 spanning trees
+        self.assertEqual(len(res), 16)
+        weights = [weight for weight, _ in res]
+        self.assertEqual(weights, sorted(weights))
+        brute_force = []
+        for combo in itertools.combinations(self.graph.weighted_edge_list(), 3):
+            tree = rustworkx.PyGraph()
+            tree.add_nodes_from(range(4))
+            tree.extend_from_weighted_edge_list(list(combo))
+            if rustworkx.is_connected(tree):
+                brute_force.append(sum(edge[2] for edge in combo))
+        self.assertEqual(weights, sorted(brute_force))
+        for weight, edges in res:
+            self.assertEqual(weight, sum(edge[2] for edge in edges))
+        self.assertEqual(len({tuple(sorted(edges)) for _, edges in res}), 16)
+
+    def test_huge_k(self):
+        res = rustworkx.k_smallest_spanning_trees(self.graph, sys.maxsize, weight_fn=float)
+        self.assertEqual(len(res), 16)
+
+    def test_default_weight(self):
+        graph = rustworkx.generators.cycle_graph(4)
+        res = rustworkx.k_smallest_spanning_trees(graph, 10, default_weight=2.0)
+        self.assertEqual(len(res), 4)
+        self.assertTrue(all(weight == 6.0 for weight, _ in res))
+
+    def test_disconnected_graph(self):
+        graph = rustworkx.PyGraph()
+        graph.add_nodes_from(range(5))
+        graph.extend_from_weighted_edge_list(
+            [(0, 1, 1.0), (1, 2, 2.0), (2, 0, 3.0), (3, 4, 1.0)]
+        )
+        res = rustworkx.k_smallest_spanning_trees(graph, 10, weight_fn=float)
+        self.assertEqual([weight for weight, _ in res], [4.0, 5.0, 6.0])
+        self.assertTrue(all(len(edges) == 3 for _, edges in res))
+
+    def test_k_zero(self):
+        self.assertEqual(rustworkx.k_smallest_spanning_trees(self.graph, 0), [])
+
+    def test_nan_weight(self):
+        graph = rustworkx.PyGraph()
+        graph.extend_from_weighted_edge_list([(0, 1, 1.0), (1, 2, float("nan"))])
+        with self.assertRaises(ValueError):
+            rustworkx.k_smallest_spanning_trees(graph, 2, weight_fn=float)
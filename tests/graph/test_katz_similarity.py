@@ -0,0 +1,79 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+
+import unittest
+
+import rustworkx
+
+
+class TestKatzSimilarity(unittest.TestCase):
+    def test_all_pairs(self):
+        graph = rustworkx.PyGraph()
+        graph.extend_from_edge_list([(0, 1)])
+        scores = rustworkx.katz_similarity(graph, alpha=0.5)
+        self.assertEqual(set(scores), {0, 1})
+        # The walks between the nodes have odd lengths and the walks from a
+        # node to itself have even lengths
+        self.assertAlmostEqual(scores[0][1], 2 / 3)
+        self.assertAlmostEqual(scores[1][0], 2 / 3)
+        self.assertAlmostEqual(scores[0][0], 1 / 3)
+
+    def test_single_source_matches_all_pairs(self):
+        graph = rustworkx.generators.grid_graph(3, 3)
+        scores = rustworkx.graph_katz_similarity(graph, alpha=0.2)
+        for source in graph.node_indices():
+            row = rustworkx.graph_katz_similarity(graph, source, alpha=0.2, tol=1e-14)
+            self.assertEqual(list(row), list(graph.node_indices()))
+            for target, score in row.items():
+                self.assertAlmostEqual(score, scores[source][target])
+
+    def test_pair(self):
+        graph = rustworkx.generators.path_graph(3)
+        score = rustworkx.katz_similarity(graph, 0, 2, alpha=0.1, tol=1e-14)
+        expected = rustworkx.katz_similarity(graph, alpha=0.1)[0][2]
+        self.assertAlmostEqual(score, expected)
+
+    def test_weights(self):
+        graph = rustworkx.PyGraph()
+        graph.extend_from_weighted_edge_list([(0, 1, 2.0)])
+        score = rustworkx.katz_similarity(graph, 0, 1, alpha=0.25, weight_fn=float, tol=1e-14)
+        # 0.5 + 0.5^3 + 0.5^5 + ...
+        self.assertAlmostEqual(score, 2 / 3)
+
+    def test_removed_node(self):
+        graph = rustworkx.generators.path_graph(3)
+        graph.remove_node(1)
+        scores = rustworkx.katz_similarity(graph, alpha=0.5)
+        self.assertEqual(scores, {0: {0: 0.0, 2: 0.0}, 2: {0: 0.0, 2: 0.0}})
+
+    def test_divergent(self):
+        graph = rustworkx.generators.complete_graph(4)
+        with self.assertRaises(ValueError):
+            rustworkx.katz_similarity(graph, alpha=0.5)
+        with self.assertRaises(rustworkx.FailedToConverge):
+            rustworkx.katz_similarity(graph, 0, alpha=0.5, max_iter=100)
+
+    def test_invalid_alpha(self):
+        graph = rustworkx.generators.path_graph(3)
+        with self.assertRaises(ValueError):
+            rustworkx.katz_similarity(graph, alpha=0.0)
+
+    def test_invalid_node(self):
+        graph = rustworkx.generators.path_graph(3)
+        with self.assertRaises(rustworkx.InvalidNode):
+            rustworkx.katz_similarity(graph, 5)
+
+    def test_negative_weight(self):
+        graph = rustworkx.generators.path_graph(3)
+        with self.assertRaises(ValueError):
+            rustworkx.katz_similarity(graph, weight_fn=lambda _: -1.0)
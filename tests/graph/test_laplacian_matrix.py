@@ -0,0 +1,64 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+
+import unittest
+
+import numpy as np
+
+import rustworkx
+
+
+class TestLaplacianMatrix(unittest.TestCase):
+    def test_path(self):
+        graph = rustworkx.generators.path_graph(3)
+        res = rustworkx.laplacian_matrix(graph)
+        expected = np.array([[1, -1, 0], [-1, 2, -1], [0, -1, 1]], dtype=np.float64)
+        np.testing.assert_array_equal(res, expected)
+
+    def test_weights_and_parallel_edges(self):
+        graph = rustworkx.PyGraph()
+        graph.add_nodes_from(range(2))
+        graph.add_edges_from([(0, 1, 2.0), (0, 1, 0.5), (1, 1, 7.0)])
+        res = rustworkx.graph_laplacian_matrix(graph, weight_fn=float)
+        np.testing.assert_array_equal(res, [[2.5, -2.5], [-2.5, 2.5]])
+
+    def test_sparse(self):
+        graph = rustworkx.generators.path_graph(3)
+        data, indices, indptr = rustworkx.laplacian_matrix(graph, sparse=True)
+        np.testing.assert_array_equal(data, [1, -1, -1, 2, -1, -1, 1])
+        np.testing.assert_array_equal(indices, [0, 1, 0, 1, 2, 1, 2])
+        np.testing.assert_array_equal(indptr, [0, 2, 5, 7])
+
+    def test_removed_node(self):
+        graph = rustworkx.generators.path_graph(3)
+        graph.remove_node(1)
+        res = rustworkx.laplacian_matrix(graph)
+        np.testing.assert_array_equal(res, np.zeros((2, 2)))
+
+    def test_normalized(self):
+        graph = rustworkx.generators.path_graph(3)
+        res = rustworkx.normalized_laplacian_matrix(graph)
+        off = -1 / np.sqrt(2)
+        expected = np.array([[1, off, 0], [off, 1, off], [0, off, 1]])
+        np.testing.assert_allclose(res, expected)
+
+    def test_normalized_isolated_node(self):
+        graph = rustworkx.PyGraph()
+        graph.add_nodes_from(range(3))
+        graph.add_edge(0, 1, None)
+        res = rustworkx.normalized_laplacian_matrix(graph)
+        np.testing.assert_allclose(res, [[1, -1, 0], [-1, 1, 0], [0, 0, 0]])
+
+    def test_empty_graph(self):
+        res = rustworkx.laplacian_matrix(rustworkx.PyGraph())
+        self.assertEqual(res.shape, (0, 0))
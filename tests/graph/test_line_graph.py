@@ -56,3 +56,10 @@ class TestLineGraph(unittest.TestCase):
         self.assertEqual(out_graph.node_indices(), expected_nodes)
         self.assertEqual(out_graph.edge_list(), expected_edges)
         self.assertEqual(out_edge_map, expected_edge_map)
+
+    def test_line_graph_dispatch(self):
+        graph = rustworkx.generators.path_graph(3)
+        out_graph, out_edge_map = rustworkx.line_graph(graph)
+        self.assertIsInstance(out_graph, rustworkx.PyGraph)
+        self.assertEqual(out_graph.edge_list(), [(1, 0)])
+        self.assertEqual(out_edge_map, {0: 0, 1: 1})
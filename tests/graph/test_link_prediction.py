@@ -0,0 +1,112 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+
+import math
+import unittest
+
+import rustworkx
+
+
+class TestLinkPrediction(unittest.TestCase):
+    def setUp(self):
+        # 0 - 1 - 2 - 3 with a chord 0 - 2 and a pendant 4 on 3
+        self.graph = rustworkx.PyGraph()
+        self.graph.add_nodes_from(range(5))
+        self.graph.add_edges_from_no_data([(0, 1), (1, 2), (2, 3), (0, 2), (3, 4)])
+        self.community = {0: 0, 1: 0, 2: 0, 3: 1, 4: 1}
+
+    def assertScores(self, result, expected):
+        self.assertEqual([(u, v) for u, v, _ in result], [(u, v) for u, v, _ in expected])
+        for (_, _, score), (_, _, expected_score) in zip(result, expected):
+            self.assertAlmostEqual(score, expected_score)
+
+    def test_default_ebunch_is_non_edges(self):
+        res = rustworkx.preferential_attachment(self.graph)
+        self.assertEqual(
+            [(u, v) for u, v, _ in res],
+            [(0, 3), (0, 4), (1, 3), (1, 4), (2, 4)],
+        )
+
+    def test_jaccard_coefficient(self):
+        res = rustworkx.jaccard_coefficient(self.graph, [(0, 3), (1, 4), (1, 2)])
+        self.assertScores(res, [(0, 3, 1 / 3), (1, 4, 0.0), (1, 2, 1 / 4)])
+
+    def test_jaccard_coefficient_isolated(self):
+        self.graph.add_node(5)
+        res = rustworkx.jaccard_coefficient(self.graph, [(5, 5)])
+        self.assertScores(res, [(5, 5, 0.0)])
+
+    def test_adamic_adar_index(self):
+        res = rustworkx.adamic_adar_index(self.graph, [(0, 3), (1, 3)])
+        self.assertScores(res, [(0, 3, 1 / math.log(3)), (1, 3, 1 / math.log(3))])
+
+    def test_resource_allocation_index(self):
+        res = rustworkx.resource_allocation_index(self.graph, [(0, 3), (2, 4)])
+        self.assertScores(res, [(0, 3, 1 / 3), (2, 4, 1 / 2)])
+
+    def test_preferential_attachment(self):
+        res = rustworkx.preferential_attachment(self.graph, [(0, 2), (1, 4)])
+        self.assertScores(res, [(0, 2, 6.0), (1, 4, 2.0)])
+
+    def test_common_neighbor_centrality(self):
+        res = rustworkx.common_neighbor_centrality(self.graph, [(0, 3), (0, 4)], alpha=0.5)
+        self.assertScores(res, [(0, 3, 0.5 + 0.5 * 5 / 2), (0, 4, 0.5 * 5 / 3)])
+
+    def test_common_neighbor_centrality_disconnected(self):
+        self.graph.add_node(5)
+        res = rustworkx.common_neighbor_centrality(self.graph, [(0, 5)])
+        self.assertScores(res, [(0, 5, 0.0)])
+
+    def test_common_neighbor_centrality_invalid(self):
+        with self.assertRaises(ValueError):
+            rustworkx.common_neighbor_centrality(self.graph, [(0, 0)])
+        with self.assertRaises(ValueError):
+            rustworkx.common_neighbor_centrality(self.graph, alpha=1.5)
+
+    def test_cn_soundarajan_hopcroft(self):
+        res = rustworkx.cn_soundarajan_hopcroft(self.graph, self.community, [(0, 1), (1, 3)])
+        # 0 and 1 share neighbor 2 in their community, 1 and 3 are in
+        # different communities
+        self.assertScores(res, [(0, 1, 2.0), (1, 3, 1.0)])
+
+    def test_ra_index_soundarajan_hopcroft(self):
+        res = rustworkx.ra_index_soundarajan_hopcroft(
+            self.graph, self.community, [(0, 1), (1, 3), (2, 4)]
+        )
+        self.assertScores(res, [(0, 1, 1 / 3), (1, 3, 0.0), (2, 4, 0.0)])
+
+    def test_within_inter_cluster(self):
+        community = {0: 0, 1: 0, 2: 1, 3: 0, 4: 1}
+        res = rustworkx.within_inter_cluster(self.graph, community, [(0, 3), (1, 3)], delta=0.5)
+        # The only common neighbor of 0 and 3 is 2, which is in another community
+        self.assertScores(res, [(0, 3, 0.0), (1, 3, 0.0)])
+        res = rustworkx.within_inter_cluster(self.graph, self.community, [(0, 1)], delta=0.5)
+        self.assertScores(res, [(0, 1, 2.0)])
+
+    def test_within_inter_cluster_invalid_delta(self):
+        with self.assertRaises(ValueError):
+            rustworkx.within_inter_cluster(self.graph, self.community, delta=0.0)
+
+    def test_missing_community(self):
+        with self.assertRaises(ValueError):
+            rustworkx.cn_soundarajan_hopcroft(self.graph, {0: 0}, [(0, 1)])
+
+    def test_invalid_node(self):
+        with self.assertRaises(rustworkx.InvalidNode):
+            rustworkx.jaccard_coefficient(self.graph, [(0, 10)])
+
+    def test_parallel_matches_serial(self):
+        graph = rustworkx.generators.grid_graph(8, 8)
+        serial = rustworkx.adamic_adar_index(graph)
+        parallel = rustworkx.adamic_adar_index(graph, parallel_threshold=1)
+        self.assertEqual(serial, parallel)
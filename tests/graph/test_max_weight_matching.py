@@ -553,3 +553,44 @@ class TestMaxWeightMatching(unittest.TestCase):
             rx_graph, max_cardinality=True, verify_optimum=True
         )
         self.compare_rx_nx_sets(rx_graph, rx_matches, nx_matches, 42, nx_graph)
+
+    def test_min_cost_flow_against_blossom(self):
+        for i in range(20):
+            with self.subTest(i=i):
+                rx_graph = rustworkx.undirected_random_bipartite_graph(8, 9, 0.4, seed=42 + i)
+                random.seed(i)
+                for edge in rx_graph.edge_list():
+                    rx_graph.update_edge(*edge, random.randint(-10, 100))
+                for max_cardinality in [False, True]:
+                    blossom = rustworkx.max_weight_matching(
+                        rx_graph, max_cardinality=max_cardinality, weight_fn=lambda x: x
+                    )
+                    flow = rustworkx.max_weight_matching(
+                        rx_graph,
+                        max_cardinality=max_cardinality,
+                        weight_fn=lambda x: x,
+                        method="min_cost_flow",
+                    )
+                    self.assertTrue(rustworkx.is_matching(rx_graph, flow))
+                    self.assertEqual(
+                        sum(rx_graph.get_edge_data(*edge) for edge in flow),
+                        sum(rx_graph.get_edge_data(*edge) for edge in blossom),
+                    )
+                    if max_cardinality:
+                        self.assertEqual(len(flow), len(blossom))
+
+    def test_min_cost_flow_default_weight(self):
+        graph = rustworkx.generators.path_graph(5)
+        matching = rustworkx.max_weight_matching(graph, method="min_cost_flow")
+        self.assertEqual(len(matching), 2)
+        self.assertTrue(rustworkx.is_maximal_matching(graph, matching))
+
+    def test_min_cost_flow_not_bipartite(self):
+        graph = rustworkx.generators.cycle_graph(5)
+        with self.assertRaises(rustworkx.GraphNotBipartite):
+            rustworkx.max_weight_matching(graph, method="min_cost_flow")
+
+    def test_invalid_method(self):
+        graph = rustworkx.generators.path_graph(3)
+        with self.assertRaises(ValueError):
+            rustworkx.max_weight_matching(graph, method="hungarian")
@@ -0,0 +1,96 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+import unittest
+
+import rustworkx
+
+
+class TestMergeNodesByKey(unittest.TestCase):
+    def test_merge(self):
+        graph = rustworkx.PyGraph(multigraph=False)
+        graph.add_nodes_from(["a", "A", "b", "c", "B"])
+        graph.add_edges_from([(0, 2, 1), (1, 2, 2), (1, 3, 3), (3, 4, 4)])
+        node_map = graph.merge_nodes_by_key(str.lower)
+        self.assertEqual(dict(node_map), {1: 0, 4: 2})
+        self.assertEqual(graph.node_indices(), [0, 2, 3])
+        self.assertEqual(graph.nodes(), ["a", "b", "c"])
+        self.assertEqual(
+            sorted(graph.weighted_edge_list()), [(0, 2, 1), (0, 3, 3), (2, 3, 4)]
+        )
+
+    def test_combine_payloads(self):
+        graph = rustworkx.PyGraph()
+        graph.add_nodes_from([("x", 1), ("y", 2), ("x", 3), ("x", 4)])
+        graph.add_edges_from([(0, 1, 1.0), (2, 1, 2.0), (3, 1, 3.0)])
+        node_map = graph.merge_nodes_by_key(
+            lambda node: node[0],
+            node_fn=lambda a, b: (a[0], a[1] + b[1]),
+            weight_combo_fn=lambda a, b: a + b,
+        )
+        self.assertEqual(dict(node_map), {2: 0, 3: 0})
+        self.assertEqual(graph.nodes(), [("x", 8), ("y", 2)])
+        self.assertEqual(graph.weighted_edge_list(), [(0, 1, 6.0)])
+
+    def test_multigraph_keeps_parallel_edges(self):
+        graph = rustworkx.PyGraph()
+        graph.add_nodes_from([0, 1, 2])
+        graph.add_edges_from([(0, 2, "a"), (1, 2, "b")])
+        graph.merge_nodes_by_key(lambda node: node < 2)
+        self.assertEqual(sorted(graph.edges()), ["a", "b"])
+        self.assertEqual(graph.num_nodes(), 2)
+
+    def test_self_loops(self):
+        graph = rustworkx.generators.path_graph(4)
+        for node in graph.node_indices():
+            graph[node] = node // 2
+        copy = graph.copy()
+        graph.merge_nodes_by_key(lambda node: node)
+        self.assertEqual(graph.edge_list(), [(0, 2)])
+        copy.merge_nodes_by_key(lambda node: node, self_loops=True)
+        self.assertEqual(sorted(copy.edge_list()), [(0, 0), (0, 2), (2, 2)])
+
+    def test_no_merges(self):
+        graph = rustworkx.generators.cycle_graph(4)
+        for node in graph.node_indices():
+            graph[node] = node
+        node_map = graph.merge_nodes_by_key(lambda node: node)
+        self.assertEqual(dict(node_map), {})
+        self.assertEqual(graph.num_nodes(), 4)
+        self.assertEqual(graph.num_edges(), 4)
+
+    def test_removed_nodes(self):
+        graph = rustworkx.PyGraph()
+        graph.add_nodes_from(["a", "b", "a", "a"])
+        graph.add_edges_from_no_data([(0, 1), (1, 2), (1, 3)])
+        graph.remove_node(0)
+        node_map = graph.merge_nodes_by_key(lambda node: node)
+        self.assertEqual(dict(node_map), {3: 2})
+        self.assertEqual(graph.edge_list(), [(1, 2), (1, 2)])
+
+    def test_key_fn_error_leaves_graph(self):
+        graph = rustworkx.PyGraph()
+        graph.add_nodes_from([1, 1, 0])
+        graph.add_edges_from_no_data([(0, 1), (1, 2)])
+
+        def key_fn(node):
+            return 1 // node
+
+        with self.assertRaises(ZeroDivisionError):
+            graph.merge_nodes_by_key(key_fn)
+        self.assertEqual(graph.num_nodes(), 3)
+        self.assertEqual(graph.num_edges(), 2)
+
+    def test_unhashable_key(self):
+        graph = rustworkx.generators.path_graph(2)
+        with self.assertRaises(TypeError):
+            graph.merge_nodes_by_key(lambda node: [])
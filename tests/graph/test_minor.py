@@ -0,0 +1,53 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+import unittest
+
+import rustworkx
+
+
+class TestIsMinorOf(unittest.TestCase):
+    def test_cycle_minors(self):
+        cycle = rustworkx.generators.cycle_graph(6)
+        self.assertTrue(rustworkx.is_minor_of(rustworkx.generators.cycle_graph(3), cycle))
+        self.assertTrue(rustworkx.is_minor_of(cycle, cycle))
+        self.assertFalse(rustworkx.is_minor_of(rustworkx.generators.cycle_graph(7), cycle))
+
+    def test_tree_has_no_cycle_minor(self):
+        tree = rustworkx.generators.binomial_tree_graph(4)
+        self.assertFalse(rustworkx.is_minor_of(rustworkx.generators.cycle_graph(3), tree))
+        self.assertTrue(rustworkx.is_minor_of(rustworkx.generators.star_graph(5), tree))
+
+    def test_petersen_graph_has_k5_minor(self):
+        petersen = rustworkx.generators.generalized_petersen_graph(5, 2)
+        k5 = rustworkx.generators.complete_graph(5)
+        self.assertTrue(rustworkx.is_minor_of(k5, petersen))
+
+    def test_planar_graph_has_no_k5_minor(self):
+        grid = rustworkx.generators.grid_graph(3, 3)
+        self.assertTrue(rustworkx.is_minor_of(rustworkx.generators.complete_graph(4), grid))
+        self.assertFalse(rustworkx.is_minor_of(rustworkx.generators.complete_graph(5), grid))
+
+    def test_self_loops_and_parallel_edges_ignored(self):
+        graph = rustworkx.PyGraph()
+        graph.extend_from_edge_list([(0, 1), (0, 1), (1, 1)])
+        pattern = rustworkx.PyGraph()
+        pattern.extend_from_edge_list([(0, 1)])
+        self.assertTrue(rustworkx.is_minor_of(graph, pattern))
+        self.assertTrue(rustworkx.is_minor_of(pattern, graph))
+
+    def test_empty_pattern(self):
+        self.assertTrue(rustworkx.is_minor_of(rustworkx.PyGraph(), rustworkx.PyGraph()))
+
+    def test_invalid_type(self):
+        with self.assertRaises(TypeError):
+            rustworkx.is_minor_of(rustworkx.PyDiGraph(), rustworkx.PyGraph())
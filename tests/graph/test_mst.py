@@ -120,3 +120,39 @@ class TestMinimumSpanningTree(unittest.TestCase):
 
         with self.assertRaises(ValueError):
             rustworkx.minimum_spanning_tree(invalid_graph, lambda x: x)
+
+    def test_parallel_edges(self):
+        mst_edges = rustworkx.minimum_spanning_edges(
+            self.graph, weight_fn=lambda x: x, parallel_threshold=1
+        )
+        self.assertEqualEdgeList(self.expected_edges, mst_edges)
+
+    def test_parallel_forest(self):
+        s = self.graph.add_node("S")
+        t = self.graph.add_node("T")
+        u = self.graph.add_node("U")
+        self.graph.add_node("V")
+        self.graph.add_edges_from([(s, t, 10), (t, u, 9), (s, u, 8)])
+        forest_expected_edges = self.expected_edges + [(s, u, 8), (t, u, 9)]
+
+        msf_graph = rustworkx.minimum_spanning_tree(
+            self.graph, weight_fn=lambda x: x, parallel_threshold=1
+        )
+        self.assertEqual(self.graph.nodes(), msf_graph.nodes())
+        self.assertEqualEdgeList(forest_expected_edges, msf_graph.weighted_edge_list())
+
+    def test_parallel_matches_kruskal(self):
+        graph = rustworkx.generators.grid_graph(20, 20)
+        for index, edge in enumerate(graph.edge_indices()):
+            graph.update_edge_by_index(edge, float((index * 7919) % 13))
+        kruskal = rustworkx.minimum_spanning_edges(graph, weight_fn=float)
+        boruvka = rustworkx.minimum_spanning_edges(graph, weight_fn=float, parallel_threshold=1)
+        self.assertEqual(len(kruskal), len(boruvka))
+        self.assertEqual(sum(edge[2] for edge in kruskal), sum(edge[2] for edge in boruvka))
+
+    def test_parallel_nan_weight(self):
+        invalid_graph = rustworkx.PyGraph()
+        invalid_graph.extend_from_weighted_edge_list([(0, 1, 0.5), (0, 2, float("nan"))])
+
+        with self.assertRaises(ValueError):
+            rustworkx.minimum_spanning_tree(invalid_graph, lambda x: x, parallel_threshold=1)
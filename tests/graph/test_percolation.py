@@ -0,0 +1,97 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+import unittest
+
+import rustworkx
+
+
+def largest_component(graph):
+    return max((len(c) for c in rustworkx.connected_components(graph)), default=0)
+
+
+class TestPercolation(unittest.TestCase):
+    def setUp(self):
+        self.graph = rustworkx.undirected_gnp_random_graph(40, 0.1, seed=42)
+
+    def test_node_removal_matches_connected_components(self):
+        for strategy in ["random", "degree", "betweenness"]:
+            order, sizes, _ = rustworkx.graph_percolation(self.graph, strategy=strategy, seed=1)
+            self.assertEqual(sorted(order), list(self.graph.node_indices()))
+            graph = self.graph.copy()
+            self.assertEqual(sizes[0], largest_component(graph))
+            for position, node in enumerate(order):
+                graph.remove_node(node)
+                self.assertEqual(sizes[position + 1], largest_component(graph))
+
+    def test_edge_removal_matches_connected_components(self):
+        for strategy in ["random", "degree", "betweenness"]:
+            order, sizes, _ = rustworkx.graph_percolation(
+                self.graph, strategy=strategy, remove_edges=True, seed=1
+            )
+            self.assertEqual(sorted(order), list(self.graph.edge_indices()))
+            graph = self.graph.copy()
+            for position, edge in enumerate(order):
+                graph.remove_edge_from_index(edge)
+                self.assertEqual(sizes[position + 1], largest_component(graph))
+
+    def test_user_order(self):
+        graph = rustworkx.generators.path_graph(5)
+        order, sizes, critical = rustworkx.graph_percolation(graph, [2, 0, 3], threshold=0.2)
+        self.assertEqual(order, [2, 0, 3])
+        self.assertEqual(sizes, [5, 2, 2, 1])
+        self.assertEqual(critical, 0.6)
+
+    def test_critical_fraction_not_reached(self):
+        graph = rustworkx.generators.path_graph(5)
+        _, sizes, critical = rustworkx.graph_percolation(graph, [0])
+        self.assertEqual(sizes, [5, 4])
+        self.assertIsNone(critical)
+
+    def test_star_degree_attack(self):
+        graph = rustworkx.generators.star_graph(10)
+        order, sizes, critical = rustworkx.graph_percolation(graph, "degree")
+        self.assertEqual(order[0], 0)
+        self.assertEqual(sizes[:2], [10, 1])
+        self.assertEqual(critical, 0.1)
+
+    def test_bridge_betweenness_attack(self):
+        graph = rustworkx.generators.barbell_graph(4, 0)
+        order, sizes, _ = rustworkx.graph_percolation(graph, "betweenness", remove_edges=True)
+        self.assertEqual(set(graph.get_edge_endpoints_by_index(order[0])), {3, 4})
+        self.assertEqual(sizes[:2], [8, 4])
+
+    def test_seed(self):
+        first = rustworkx.graph_percolation(self.graph, seed=7)
+        second = rustworkx.graph_percolation(self.graph, seed=7)
+        self.assertEqual(first, second)
+
+    def test_universal(self):
+        graph = rustworkx.generators.path_graph(5)
+        self.assertEqual(rustworkx.percolation(graph, [2])[1], [5, 2])
+
+    def test_invalid_strategy(self):
+        with self.assertRaises(ValueError):
+            rustworkx.graph_percolation(self.graph, "closeness")
+
+    def test_invalid_threshold(self):
+        with self.assertRaises(ValueError):
+            rustworkx.graph_percolation(self.graph, threshold=1.5)
+
+    def test_invalid_indices(self):
+        with self.assertRaises(rustworkx.InvalidNode):
+            rustworkx.graph_percolation(self.graph, [100])
+        with self.assertRaises(IndexError):
+            rustworkx.graph_percolation(self.graph, [1000], remove_edges=True)
+
+    def test_empty_graph(self):
+        self.assertEqual(rustworkx.graph_percolation(rustworkx.PyGraph()), ([], [0], None))
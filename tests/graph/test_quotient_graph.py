@@ -0,0 +1,84 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+
+import unittest
+
+import rustworkx
+
+
+class TestQuotientGraph(unittest.TestCase):
+    def test_partition_sequence(self):
+        graph = rustworkx.generators.path_graph(4, weights=["a", "b", "c", "d"])
+        quotient, node_map = rustworkx.graph_quotient_graph(graph, [[0, 1], [2, 3]])
+        self.assertEqual(quotient.nodes(), [["a", "b"], ["c", "d"]])
+        self.assertEqual(quotient.weighted_edge_list(), [(0, 1, [None])])
+        self.assertEqual(node_map, {0: 0, 1: 0, 2: 1, 3: 1})
+
+    def test_partition_callable(self):
+        graph = rustworkx.generators.path_graph(6, weights=list(range(6)))
+        quotient, node_map = rustworkx.graph_quotient_graph(
+            graph, lambda weight: weight // 3, node_fn=sum
+        )
+        self.assertEqual(quotient.nodes(), [3, 12])
+        self.assertEqual(quotient.edge_list(), [(0, 1)])
+        self.assertEqual(node_map, {0: 0, 1: 0, 2: 0, 3: 1, 4: 1, 5: 1})
+
+    def test_edge_fn_aggregates_parallel_edges(self):
+        graph = rustworkx.PyGraph()
+        graph.add_nodes_from(range(4))
+        graph.add_edges_from([(0, 1, 1.5), (2, 3, 2.0), (2, 1, 0.5), (0, 2, 10.0)])
+        quotient, _ = rustworkx.graph_quotient_graph(
+            graph, lambda node: node % 2, edge_fn=sum
+        )
+        self.assertEqual(list(quotient.weighted_edge_list()), [(0, 1, 4.0)])
+
+    def test_self_loops(self):
+        graph = rustworkx.generators.path_graph(4)
+        quotient, _ = rustworkx.graph_quotient_graph(
+            graph, [[0, 1], [2, 3]], edge_fn=len, self_loops=True
+        )
+        self.assertEqual(quotient.weighted_edge_list(), [(0, 0, 1), (0, 1, 1), (1, 1, 1)])
+
+    def test_uncovered_nodes_are_singletons(self):
+        graph = rustworkx.generators.path_graph(4)
+        quotient, node_map = rustworkx.graph_quotient_graph(graph, [[1, 2]])
+        self.assertEqual(quotient.num_nodes(), 3)
+        self.assertEqual(node_map, {0: 0, 1: 1, 2: 1, 3: 2})
+
+    def test_removed_nodes(self):
+        graph = rustworkx.generators.path_graph(5)
+        graph.remove_node(2)
+        quotient, node_map = rustworkx.graph_quotient_graph(graph, [[0, 1], [3, 4]])
+        self.assertEqual(quotient.num_nodes(), 2)
+        self.assertEqual(quotient.num_edges(), 0)
+        self.assertEqual(node_map, {0: 0, 1: 0, 3: 1, 4: 1})
+
+    def test_invalid_node(self):
+        graph = rustworkx.generators.path_graph(3)
+        with self.assertRaises(ValueError):
+            rustworkx.graph_quotient_graph(graph, [[0, 7]])
+
+    def test_overlapping_blocks(self):
+        graph = rustworkx.generators.path_graph(3)
+        with self.assertRaises(ValueError):
+            rustworkx.graph_quotient_graph(graph, [[0, 1], [1, 2]])
+
+    def test_unhashable_key(self):
+        graph = rustworkx.generators.path_graph(3)
+        with self.assertRaises(TypeError):
+            rustworkx.graph_quotient_graph(graph, lambda _: [])
+
+    def test_quotient_graph_dispatch(self):
+        graph = rustworkx.generators.path_graph(4)
+        quotient, _ = rustworkx.quotient_graph(graph, [[0, 1], [2, 3]])
+        self.assertIsInstance(quotient, rustworkx.PyGraph)
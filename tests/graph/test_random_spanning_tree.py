@@ -0,0 +1,75 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+import unittest
+
+import rustworkx
+
+
+class TestRandomSpanningTree(unittest.TestCase):
+    def assertSpanningForest(self, graph, edges):
+        forest = rustworkx.PyGraph()
+        forest.add_nodes_from(graph.node_indices())
+        forest.add_edges_from_no_data([(u, v) for u, v, _ in edges])
+        self.assertEqual(
+            rustworkx.number_connected_components(forest),
+            rustworkx.number_connected_components(graph),
+        )
+        self.assertEqual(
+            len(edges), graph.num_nodes() - rustworkx.number_connected_components(graph)
+        )
+
+    def test_grid_graph(self):
+        graph = rustworkx.generators.grid_graph(4, 4)
+        edges = rustworkx.random_spanning_tree(graph, seed=42)
+        self.assertSpanningForest(graph, edges)
+
+    def test_seed_is_reproducible(self):
+        graph = rustworkx.generators.complete_graph(8)
+        first = rustworkx.random_spanning_tree(graph, seed=1234)
+        second = rustworkx.random_spanning_tree(graph, seed=1234)
+        self.assertEqual(list(first), list(second))
+
+    def test_disconnected_graph(self):
+        graph = rustworkx.PyGraph()
+        graph.add_nodes_from(list(range(6)))
+        graph.add_edges_from([(0, 1, 1), (1, 2, 1), (2, 0, 1), (3, 4, 1)])
+        edges = rustworkx.random_spanning_tree(graph, seed=3)
+        self.assertSpanningForest(graph, edges)
+
+    def test_payloads_are_returned(self):
+        graph = rustworkx.PyGraph()
+        graph.add_nodes_from(list(range(3)))
+        graph.add_edges_from([(0, 1, "a"), (1, 2, "b")])
+        edges = rustworkx.random_spanning_tree(graph, seed=3)
+        self.assertEqual(sorted(edges), [(0, 1, "a"), (1, 2, "b")])
+
+    def test_weighted_edges(self):
+        graph = rustworkx.PyGraph()
+        graph.add_nodes_from(list(range(3)))
+        graph.add_edges_from([(0, 1, 0.0), (1, 2, 1.0), (0, 2, 1.0)])
+        for seed in range(10):
+            edges = rustworkx.random_spanning_tree(graph, seed=seed, weight_fn=float)
+            self.assertEqual(sorted(edges), [(0, 2, 1.0), (1, 2, 1.0)])
+
+    def test_negative_weight(self):
+        graph = rustworkx.generators.path_graph(3)
+        with self.assertRaises(ValueError):
+            rustworkx.random_spanning_tree(graph, weight_fn=lambda _: -1.0)
+
+    def test_nan_weight(self):
+        graph = rustworkx.generators.path_graph(3)
+        with self.assertRaises(ValueError):
+            rustworkx.random_spanning_tree(graph, default_weight=float("nan"))
+
+    def test_empty_graph(self):
+        self.assertEqual(len(rustworkx.random_spanning_tree(rustworkx.PyGraph())), 0)
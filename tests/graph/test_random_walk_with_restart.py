@@ -0,0 +1,76 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+
+import unittest
+
+import rustworkx
+
+
+class TestRandomWalkWithRestart(unittest.TestCase):
+    def test_two_nodes(self):
+        graph = rustworkx.PyGraph()
+        graph.add_nodes_from([0, 1])
+        graph.add_edge(0, 1, None)
+        res = rustworkx.random_walk_with_restart(
+            graph, [0], restart_prob=0.3, tol=1e-12, max_iter=1000
+        )
+        self.assertAlmostEqual(res[0], 1 / 1.7)
+        self.assertAlmostEqual(res[1], 0.7 / 1.7)
+
+    def test_sums_to_one(self):
+        graph = rustworkx.generators.grid_graph(4, 4)
+        res = rustworkx.random_walk_with_restart(graph, [0, 15])
+        self.assertAlmostEqual(sum(res.values()), 1.0, places=5)
+        self.assertAlmostEqual(res[0], res[15], places=5)
+        self.assertEqual(set(res.keys()), set(graph.node_indices()))
+
+    def test_weights(self):
+        graph = rustworkx.PyGraph()
+        graph.add_nodes_from([0, 1, 2])
+        graph.add_edges_from([(0, 1, 3.0), (0, 2, 1.0)])
+        res = rustworkx.random_walk_with_restart(
+            graph, [0], weight_fn=float, tol=1e-12, max_iter=1000
+        )
+        self.assertAlmostEqual(res[1], 3 * res[2])
+
+    def test_removed_node(self):
+        graph = rustworkx.generators.path_graph(3)
+        graph.remove_node(1)
+        res = rustworkx.random_walk_with_restart(graph, [0])
+        self.assertEqual(dict(res), {0: 1.0, 2: 0.0})
+
+    def test_no_seeds(self):
+        graph = rustworkx.generators.path_graph(3)
+        with self.assertRaises(ValueError):
+            rustworkx.random_walk_with_restart(graph, [])
+
+    def test_invalid_restart_prob(self):
+        graph = rustworkx.generators.path_graph(3)
+        for restart_prob in [0.0, 1.5, float("nan")]:
+            with self.assertRaises(ValueError):
+                rustworkx.random_walk_with_restart(graph, [0], restart_prob=restart_prob)
+
+    def test_invalid_seed(self):
+        graph = rustworkx.generators.path_graph(3)
+        with self.assertRaises(rustworkx.InvalidNode):
+            rustworkx.random_walk_with_restart(graph, [4])
+
+    def test_negative_weight(self):
+        graph = rustworkx.generators.path_graph(3)
+        with self.assertRaises(ValueError):
+            rustworkx.random_walk_with_restart(graph, [0], weight_fn=lambda _: -1.0)
+
+    def test_failed_to_converge(self):
+        graph = rustworkx.generators.path_graph(10)
+        with self.assertRaises(rustworkx.FailedToConverge):
+            rustworkx.random_walk_with_restart(graph, [0], restart_prob=0.01, max_iter=2)
@@ -0,0 +1,47 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+
+import unittest
+
+import numpy as np
+
+import rustworkx
+
+
+class TestRecursiveStructuralFeatures(unittest.TestCase):
+    def test_base_features(self):
+        graph = rustworkx.PyGraph()
+        graph.add_nodes_from(range(4))
+        graph.add_edges_from_no_data([(0, 1), (1, 2), (2, 0), (2, 3)])
+        res = rustworkx.recursive_structural_features(graph, rounds=0)
+        expected = np.array([[2, 3, 1], [2, 3, 1], [3, 4, 0], [1, 1, 2]], dtype=np.float64)
+        np.testing.assert_array_equal(res, expected)
+
+    def test_star(self):
+        graph = rustworkx.generators.star_graph(4)
+        res = rustworkx.recursive_structural_features(graph, rounds=1)
+        self.assertEqual(res.shape, (4, 9))
+        np.testing.assert_array_equal(res[0], [3, 3, 0, 3, 1, 3, 1, 6, 2])
+        np.testing.assert_array_equal(res[1], [1, 1, 2, 3, 3, 3, 3, 0, 0])
+
+    def test_rows_follow_node_indices(self):
+        graph = rustworkx.generators.path_graph(4)
+        graph.remove_node(1)
+        res = rustworkx.graph_recursive_structural_features(graph)
+        self.assertEqual(res.shape, (3, 21))
+        np.testing.assert_array_equal(res[0], np.zeros(21))
+        np.testing.assert_array_equal(res[1], res[2])
+
+    def test_empty_graph(self):
+        res = rustworkx.recursive_structural_features(rustworkx.PyGraph())
+        self.assertEqual(res.shape, (0, 21))
@@ -0,0 +1,74 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+
+import unittest
+
+import rustworkx
+
+
+class TestRichClub(unittest.TestCase):
+    def test_complete_graph(self):
+        graph = rustworkx.generators.complete_graph(5)
+        self.assertEqual(
+            rustworkx.rich_club_coefficient(graph), {0: 1.0, 1: 1.0, 2: 1.0, 3: 1.0}
+        )
+
+    def test_triangle_with_pendants(self):
+        graph = rustworkx.PyGraph()
+        graph.extend_from_edge_list([(0, 1), (1, 2), (2, 0), (0, 3), (1, 4), (2, 5)])
+        self.assertEqual(rustworkx.rich_club_coefficient(graph), {0: 0.4, 1: 1.0, 2: 1.0})
+
+    def test_star_graph(self):
+        graph = rustworkx.generators.star_graph(5)
+        self.assertEqual(rustworkx.rich_club_coefficient(graph), {0: 0.4})
+
+    def test_empty_graph(self):
+        self.assertEqual(rustworkx.rich_club_coefficient(rustworkx.PyGraph()), {})
+
+    def test_normalized(self):
+        graph = rustworkx.barabasi_albert_graph(60, 2, seed=5)
+        rich_club = rustworkx.rich_club_coefficient(graph)
+        normalized = rustworkx.rich_club_coefficient(graph, normalized=True, seed=42)
+        self.assertEqual(normalized.keys(), rich_club.keys())
+        # Every node has a degree of at least 2 so the coefficients for
+        # k = 0 and k = 1 only depend on the number of nodes and edges
+        self.assertAlmostEqual(normalized[0], 1.0)
+        self.assertAlmostEqual(normalized[1], 1.0)
+
+    def test_normalized_seed(self):
+        graph = rustworkx.barabasi_albert_graph(60, 2, seed=5)
+        first = rustworkx.rich_club_coefficient(
+            graph, normalized=True, num_randomizations=3, swaps_per_edge=5, seed=1
+        )
+        second = rustworkx.rich_club_coefficient(
+            graph, normalized=True, num_randomizations=3, swaps_per_edge=5, seed=1
+        )
+        self.assertEqual(first, second)
+
+    def test_normalized_does_not_modify_graph(self):
+        graph = rustworkx.barabasi_albert_graph(30, 2, seed=5)
+        edges = graph.edge_list()
+        rustworkx.rich_club_coefficient(graph, normalized=True, seed=3)
+        self.assertEqual(graph.edge_list(), edges)
+
+    def test_normalized_zero_randomizations(self):
+        graph = rustworkx.barabasi_albert_graph(30, 2, seed=5)
+        with self.assertRaises(ValueError):
+            rustworkx.rich_club_coefficient(graph, normalized=True, num_randomizations=0)
+
+    def test_zero_randomizations_not_normalized(self):
+        graph = rustworkx.generators.complete_graph(4)
+        self.assertEqual(
+            rustworkx.rich_club_coefficient(graph, num_randomizations=0),
+            rustworkx.rich_club_coefficient(graph),
+        )
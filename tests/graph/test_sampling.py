@@ -0,0 +1,102 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+import unittest
+
+import rustworkx
+
+
+class TestSampling(unittest.TestCase):
+    def setUp(self):
+        self.graph = rustworkx.generators.grid_graph(8, 8)
+        for node in self.graph.node_indices():
+            self.graph[node] = node
+
+    def assertInducedSubgraph(self, sample, node_map):
+        self.assertEqual(sorted(node_map.keys()), list(sample.node_indices()))
+        originals = list(node_map.values())
+        self.assertEqual(originals, sorted(originals))
+        for node, original in node_map.items():
+            self.assertEqual(sample[node], self.graph[original])
+        expected = {
+            (node_map[a], node_map[b])
+            for a in sample.node_indices()
+            for b in sample.node_indices()
+            if a < b and self.graph.has_edge(node_map[a], node_map[b])
+        }
+        edges = {(node_map[a], node_map[b]) for a, b in sample.edge_list()}
+        self.assertEqual({tuple(sorted(edge)) for edge in edges}, expected)
+
+    def test_sample_nodes(self):
+        sample, node_map = rustworkx.graph_sample_nodes(self.graph, 20, seed=1)
+        self.assertIsInstance(sample, rustworkx.PyGraph)
+        self.assertEqual(sample.num_nodes(), 20)
+        self.assertInducedSubgraph(sample, node_map)
+        again, again_map = rustworkx.sample_nodes(self.graph, 20, seed=1)
+        self.assertEqual(dict(again_map), dict(node_map))
+        self.assertEqual(again.edge_list(), sample.edge_list())
+
+    def test_sample_edges(self):
+        sample, node_map = rustworkx.graph_sample_edges(self.graph, 10, seed=2)
+        self.assertGreaterEqual(sample.num_edges(), 10)
+        self.assertLessEqual(sample.num_nodes(), 20)
+        self.assertTrue(all(sample.degree(node) > 0 for node in sample.node_indices()))
+        self.assertInducedSubgraph(sample, node_map)
+
+    def test_random_walk_sample(self):
+        sample, node_map = rustworkx.graph_random_walk_sample(self.graph, 15, seed=3)
+        self.assertEqual(sample.num_nodes(), 15)
+        self.assertInducedSubgraph(sample, node_map)
+        self.assertTrue(rustworkx.is_connected(sample))
+
+    def test_forest_fire_sample(self):
+        sample, node_map = rustworkx.graph_forest_fire_sample(
+            self.graph, 15, burn_probability=0.5, seed=4
+        )
+        self.assertEqual(sample.num_nodes(), 15)
+        self.assertInducedSubgraph(sample, node_map)
+        again, _ = rustworkx.forest_fire_sample(self.graph, 15, burn_probability=0.5, seed=4)
+        self.assertEqual(again.nodes(), sample.nodes())
+
+    def test_removed_nodes(self):
+        self.graph.remove_nodes_from(range(0, 64, 2))
+        for sampler in (
+            rustworkx.sample_nodes,
+            rustworkx.random_walk_sample,
+            rustworkx.forest_fire_sample,
+        ):
+            sample, node_map = sampler(self.graph, 32, seed=5)
+            self.assertEqual(sorted(node_map.values()), list(range(1, 64, 2)))
+            self.assertInducedSubgraph(sample, node_map)
+
+    def test_whole_graph(self):
+        sample, _ = rustworkx.random_walk_sample(self.graph, 64, seed=6)
+        self.assertEqual(sample.num_edges(), self.graph.num_edges())
+        sample, _ = rustworkx.sample_edges(self.graph, self.graph.num_edges(), seed=6)
+        self.assertEqual(sample.num_nodes(), 64)
+
+    def test_invalid_arguments(self):
+        with self.assertRaises(ValueError):
+            rustworkx.sample_nodes(self.graph, 65)
+        with self.assertRaises(ValueError):
+            rustworkx.sample_edges(self.graph, self.graph.num_edges() + 1)
+        with self.assertRaises(ValueError):
+            rustworkx.random_walk_sample(self.graph, 10, restart_probability=-0.1)
+        with self.assertRaises(ValueError):
+            rustworkx.forest_fire_sample(self.graph, 10, burn_probability=1.0)
+        with self.assertRaises(TypeError):
+            rustworkx.sample_nodes(None, 1)
+
+    def test_empty_sample(self):
+        sample, node_map = rustworkx.sample_nodes(rustworkx.PyGraph(), 0)
+        self.assertEqual(sample.num_nodes(), 0)
+        self.assertEqual(len(node_map), 0)
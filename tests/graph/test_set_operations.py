@@ -0,0 +1,116 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+
+import unittest
+import rustworkx
+
+
+class TestSetOperations(unittest.TestCase):
+    def setUp(self):
+        self.first = rustworkx.PyGraph()
+        self.first.add_nodes_from(["a", "b", "c", "d"])
+        self.first.add_edges_from([(0, 1, 1), (1, 2, 2), (2, 3, 3)])
+        self.second = rustworkx.PyGraph()
+        self.second.add_nodes_from(["c", "b", "a"])
+        self.second.add_edges_from([(0, 1, 20), (1, 2, 10), (0, 2, 30)])
+
+    def test_intersection_by_index(self):
+        result = rustworkx.graph_intersection(self.first, self.second)
+        self.assertEqual(result.nodes(), ["a", "b", "c"])
+        self.assertEqual(list(result.weighted_edge_list()), [(0, 1, 1), (1, 2, 2)])
+
+    def test_intersection_by_weight(self):
+        result = rustworkx.graph_intersection(self.first, self.second, align_by="weight")
+        self.assertEqual(result.nodes(), ["a", "b", "c"])
+        # Edge payloads must be equal too
+        self.assertEqual(result.num_edges(), 0)
+
+    def test_intersection_merge_fns(self):
+        result = rustworkx.graph_intersection(
+            self.first,
+            self.second,
+            node_merge_fn=lambda a, b: a + b,
+            edge_merge_fn=lambda a, b: a + b,
+        )
+        self.assertEqual(result.nodes(), ["ac", "bb", "ca"])
+        self.assertEqual(list(result.weighted_edge_list()), [(0, 1, 21), (1, 2, 12)])
+
+    def test_intersection_parallel_edges(self):
+        first = rustworkx.PyGraph()
+        first.add_nodes_from([0, 1])
+        first.add_edges_from([(0, 1, "x"), (0, 1, "y")])
+        second = rustworkx.PyGraph()
+        second.add_nodes_from([0, 1])
+        second.add_edge(0, 1, "z")
+        result = rustworkx.graph_intersection(first, second)
+        self.assertEqual(result.edges(), ["x"])
+
+    def test_difference(self):
+        result = rustworkx.graph_difference(self.first, self.second)
+        self.assertEqual(result.nodes(), ["a", "b", "c", "d"])
+        self.assertEqual(list(result.weighted_edge_list()), [(2, 3, 3)])
+
+    def test_difference_by_weight(self):
+        second = rustworkx.PyGraph()
+        second.add_nodes_from(["d", "c"])
+        second.add_edge(1, 0, 3)
+        result = rustworkx.graph_difference(self.first, second, align_by="weight")
+        self.assertEqual(list(result.weighted_edge_list()), [(0, 1, 1), (1, 2, 2)])
+
+    def test_symmetric_difference(self):
+        result = rustworkx.graph_symmetric_difference(self.first, self.second)
+        self.assertEqual(result.nodes(), ["a", "b", "c", "d"])
+        self.assertEqual(list(result.weighted_edge_list()), [(2, 3, 3), (0, 2, 30)])
+
+    def test_symmetric_difference_by_weight(self):
+        second = rustworkx.PyGraph()
+        second.add_nodes_from(["b", "e"])
+        second.add_edge(0, 1, 4)
+        result = rustworkx.graph_symmetric_difference(
+            self.first, second, align_by="weight", node_merge_fn=lambda a, b: a.upper()
+        )
+        self.assertEqual(result.nodes(), ["a", "B", "c", "d", "e"])
+        self.assertEqual(result.num_edges(), 4)
+        self.assertTrue(result.has_edge(1, 4))
+
+    def test_full_join(self):
+        result = rustworkx.graph_full_join(self.first, self.second, join_fn=lambda a, b: a + b)
+        self.assertEqual(result.num_nodes(), 7)
+        self.assertEqual(result.num_edges(), 6 + 12)
+        self.assertEqual(result.get_edge_data(0, 4), "ac")
+        self.assertEqual(result.nodes(), ["a", "b", "c", "d", "c", "b", "a"])
+
+    def test_full_join_empty(self):
+        result = rustworkx.graph_full_join(self.first, rustworkx.PyGraph())
+        self.assertEqual(result.num_nodes(), 4)
+        self.assertEqual(result.num_edges(), 3)
+
+    def test_invalid_align_by(self):
+        with self.assertRaises(ValueError):
+            rustworkx.graph_intersection(self.first, self.second, align_by="payload")
+
+    def test_dispatch(self):
+        for func in [
+            rustworkx.intersection,
+            rustworkx.difference,
+            rustworkx.symmetric_difference,
+            rustworkx.full_join,
+        ]:
+            self.assertIsInstance(func(self.first, self.second), rustworkx.PyGraph)
+
+    def test_intersection_ignores_orientation(self):
+        second = rustworkx.PyGraph()
+        second.add_nodes_from(["a", "b"])
+        second.add_edge(1, 0, None)
+        result = rustworkx.graph_intersection(self.first, second)
+        self.assertEqual(result.edges(), [1])
@@ -0,0 +1,78 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+import unittest
+
+import rustworkx
+
+
+def ring_lattice(num_nodes, k):
+    graph = rustworkx.PyGraph()
+    graph.add_nodes_from(list(range(num_nodes)))
+    for i in range(num_nodes):
+        for j in range(1, k + 1):
+            graph.add_edge(i, (i + j) % num_nodes, None)
+    return graph
+
+
+class TestSmallWorld(unittest.TestCase):
+    def test_complete_graph(self):
+        # No edge swap is possible in a complete graph
+        graph = rustworkx.generators.complete_graph(6)
+        self.assertEqual(rustworkx.sigma(graph, seed=42), 1.0)
+        self.assertEqual(rustworkx.omega(graph, seed=42), 0.0)
+
+    def test_seed(self):
+        graph = ring_lattice(30, 2)
+        self.assertEqual(
+            rustworkx.sigma(graph, num_iterations=5, seed=7),
+            rustworkx.sigma(graph, num_iterations=5, seed=7),
+        )
+        self.assertEqual(rustworkx.omega(graph, seed=7), rustworkx.omega(graph, seed=7))
+
+    def test_small_world(self):
+        graph = ring_lattice(60, 3)
+        for u, v in [(0, 30), (10, 45), (20, 50), (5, 35), (15, 40)]:
+            graph.add_edge(u, v, None)
+        self.assertGreater(rustworkx.sigma(graph, num_iterations=10, seed=2), 1.0)
+
+    def test_lattice(self):
+        graph = ring_lattice(40, 3)
+        self.assertLess(rustworkx.omega(graph, num_randomizations=3, seed=11), -0.4)
+
+    def test_self_loops_and_parallel_edges_ignored(self):
+        graph = rustworkx.generators.complete_graph(5)
+        graph.add_edge(0, 0, None)
+        graph.add_edge(0, 1, None)
+        self.assertEqual(rustworkx.sigma(graph, seed=1), 1.0)
+
+    def test_too_few_nodes(self):
+        graph = rustworkx.generators.complete_graph(3)
+        with self.assertRaises(ValueError):
+            rustworkx.sigma(graph)
+        with self.assertRaises(ValueError):
+            rustworkx.omega(graph)
+
+    def test_disconnected(self):
+        graph = rustworkx.PyGraph()
+        graph.extend_from_edge_list([(0, 1), (1, 2), (3, 4), (4, 5)])
+        with self.assertRaises(ValueError):
+            rustworkx.sigma(graph)
+        with self.assertRaises(ValueError):
+            rustworkx.omega(graph)
+
+    def test_zero_randomizations(self):
+        graph = rustworkx.generators.cycle_graph(10)
+        with self.assertRaises(ValueError):
+            rustworkx.sigma(graph, num_randomizations=0)
+        with self.assertRaises(ValueError):
+            rustworkx.omega(graph, num_randomizations=0)
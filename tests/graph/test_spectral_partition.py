@@ -0,0 +1,75 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+
+import unittest
+
+import rustworkx
+
+
+class TestSpectralPartition(unittest.TestCase):
+    def clique_chain(self, count, size):
+        graph = rustworkx.PyGraph()
+        graph.add_nodes_from(range(count * size))
+        for c in range(count):
+            start = c * size
+            for a in range(start, start + size):
+                for b in range(a + 1, start + size):
+                    graph.add_edge(a, b, None)
+            if c > 0:
+                graph.add_edge(start - 1, start, None)
+        return graph
+
+    def test_cliques(self):
+        graph = self.clique_chain(4, 5)
+        for refine in [True, False]:
+            parts, edge_cut = rustworkx.spectral_partition(graph, 4, refine=refine, seed=42)
+            self.assertEqual(sorted(parts), [list(range(c * 5, c * 5 + 5)) for c in range(4)])
+            self.assertEqual(edge_cut, 3.0)
+
+    def test_balanced(self):
+        graph = rustworkx.generators.grid_graph(5, 5)
+        parts, _ = rustworkx.spectral_partition(graph, 3, seed=42)
+        self.assertEqual(sorted(node for part in parts for node in part), list(range(25)))
+        sizes = sorted(len(part) for part in parts)
+        self.assertLessEqual(sizes[-1] - sizes[0], 1)
+
+    def test_edge_cut_weights(self):
+        graph = rustworkx.PyGraph()
+        graph.add_nodes_from(range(4))
+        graph.add_edges_from([(0, 1, 5.0), (1, 2, 0.5), (2, 3, 5.0)])
+        parts, edge_cut = rustworkx.spectral_partition(graph, 2, weight_fn=float, seed=42)
+        self.assertEqual(sorted(parts), [[0, 1], [2, 3]])
+        self.assertEqual(edge_cut, 0.5)
+
+    def test_removed_node(self):
+        graph = rustworkx.generators.path_graph(5)
+        graph.remove_node(2)
+        parts, edge_cut = rustworkx.spectral_partition(graph, 2, seed=42)
+        self.assertEqual(sorted(parts), [[0, 1], [3, 4]])
+        self.assertEqual(edge_cut, 0.0)
+
+    def test_more_parts_than_nodes(self):
+        graph = rustworkx.generators.path_graph(2)
+        parts, edge_cut = rustworkx.spectral_partition(graph, 3)
+        self.assertEqual(sorted(parts), [[], [0], [1]])
+        self.assertEqual(edge_cut, 1.0)
+
+    def test_zero_parts(self):
+        graph = rustworkx.generators.path_graph(2)
+        with self.assertRaises(ValueError):
+            rustworkx.spectral_partition(graph, 0)
+
+    def test_negative_weight(self):
+        graph = rustworkx.generators.path_graph(3)
+        with self.assertRaises(ValueError):
+            rustworkx.spectral_partition(graph, 2, weight_fn=lambda _: -1.0)
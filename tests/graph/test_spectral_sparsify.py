@@ -0,0 +1,79 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+import unittest
+
+import rustworkx
+
+
+class TestSpectralSparsify(unittest.TestCase):
+    def test_keeps_nodes(self):
+        graph = rustworkx.generators.complete_graph(30)
+        graph.remove_node(3)
+        sparse = rustworkx.spectral_sparsify(graph, 100, seed=42)
+        self.assertEqual(graph.node_indices(), sparse.node_indices())
+        self.assertEqual(graph.nodes(), sparse.nodes())
+        self.assertLess(sparse.num_edges(), graph.num_edges())
+        edges = set(graph.edge_list())
+        for a, b in sparse.edge_list():
+            self.assertIn((a, b), edges)
+        self.assertTrue(all(weight > 1.0 for weight in sparse.edges()))
+        # The original graph is untouched
+        self.assertEqual(graph.num_edges(), 406)
+        self.assertEqual(graph.edges(), [None] * 406)
+
+    def test_approximates_cut(self):
+        graph = rustworkx.generators.complete_graph(40)
+        sparse = rustworkx.spectral_sparsify(graph, 300, seed=42)
+        self.assertGreater(sparse.num_edges(), 200)
+        self.assertLess(sparse.num_edges(), 400)
+        cut = sum(
+            weight
+            for (a, b), weight in zip(sparse.edge_list(), sparse.edges())
+            if (a < 20) != (b < 20)
+        )
+        self.assertAlmostEqual(cut, 400.0, delta=80.0)
+
+    def test_seed(self):
+        graph = rustworkx.generators.grid_graph(8, 8)
+        first = rustworkx.spectral_sparsify(graph, 60, seed=7)
+        second = rustworkx.spectral_sparsify(graph, 60, seed=7)
+        self.assertEqual(first.weighted_edge_list(), second.weighted_edge_list())
+
+    def test_weights(self):
+        graph = rustworkx.PyGraph()
+        graph.add_nodes_from(range(3))
+        graph.add_edges_from([(0, 1, 2.0), (1, 2, 0.0), (2, 0, 3.0), (1, 1, 1.0)])
+        sparse = rustworkx.spectral_sparsify(graph, 10, weight_fn=float)
+        self.assertEqual(sparse.weighted_edge_list(), [(0, 1, 2.0), (2, 0, 3.0)])
+        sparse = rustworkx.spectral_sparsify(graph, 10, weight_fn=[2.0, 0.0, 3.0, 1.0])
+        self.assertEqual(sparse.weighted_edge_list(), [(0, 1, 2.0), (2, 0, 3.0)])
+        sparse = rustworkx.spectral_sparsify(graph, 10, default_weight=0.5)
+        self.assertEqual(sparse.edges(), [0.5, 0.5, 0.5])
+
+    def test_tree(self):
+        graph = rustworkx.generators.path_graph(11)
+        sparse = rustworkx.spectral_sparsify(graph, 5, seed=1)
+        self.assertGreater(sparse.num_edges(), 0)
+        for weight in sparse.edges():
+            self.assertAlmostEqual(weight, 2.0)
+
+    def test_empty(self):
+        graph = rustworkx.PyGraph()
+        self.assertEqual(rustworkx.spectral_sparsify(graph, 5).num_nodes(), 0)
+        graph = rustworkx.generators.path_graph(4)
+        self.assertEqual(rustworkx.spectral_sparsify(graph, 0).num_edges(), 0)
+
+    def test_invalid_weight(self):
+        graph = rustworkx.generators.path_graph(4)
+        with self.assertRaises(ValueError):
+            rustworkx.spectral_sparsify(graph, 2, weight_fn=lambda _: -1.0)
@@ -148,3 +148,58 @@ class TestSubgraph(unittest.TestCase):
         self.assertEqual([(0, 1, 4)], subgraph.weighted_edge_list())
         self.assertEqual(["b", "d"], subgraph.nodes())
         self.assertEqual(graph.attrs, subgraph.attrs)
+
+    def test_subgraph_view_keeps_indices(self):
+        graph = rustworkx.PyGraph()
+        graph.add_nodes_from(["a", "b", "c", "d", "e"])
+        graph.add_edges_from([(0, 1, 1), (1, 2, 2), (2, 3, 3), (3, 4, 4), (0, 4, 5)])
+        view = graph.subgraph_view([1, 2, 3, 7])
+        self.assertEqual([1, 2, 3], list(view.node_indices()))
+        self.assertEqual([1, 2], list(view.edge_indices()))
+        self.assertEqual({1: (1, 2, 2), 2: (2, 3, 3)}, dict(view.edge_index_map()))
+        self.assertEqual("c", view[2])
+
+    def test_subgraph_view_edges(self):
+        graph = rustworkx.PyGraph()
+        graph.add_nodes_from(range(4))
+        graph.add_edges_from([(0, 1, "x"), (0, 1, "y"), (1, 2, "x"), (2, 3, "y")])
+        view = graph.subgraph_view(edges=graph.filter_edges(lambda edge: edge == "x"))
+        self.assertEqual([0, 1, 2, 3], list(view.node_indices()))
+        self.assertEqual([0, 2], list(view.edge_indices()))
+        view = graph.subgraph_view(nodes=[0, 1, 3], edges=[1, 2, 3])
+        self.assertEqual([1], list(view.edge_indices()))
+
+    def test_subgraph_view_defaults(self):
+        graph = rustworkx.PyGraph(attrs="My attribute")
+        graph.add_nodes_from(range(3))
+        graph.add_edges_from([(0, 1, None), (1, 2, None)])
+        view = graph.subgraph_view()
+        self.assertEqual(graph.edge_list(), view.edge_list())
+        self.assertIsNone(view.attrs)
+        view = graph.subgraph_view(preserve_attrs=True)
+        self.assertEqual(graph.attrs, view.attrs)
+
+    def test_subgraph_view_shares_payloads(self):
+        graph = rustworkx.PyGraph()
+        graph.add_nodes_from([[0], [1], [2]])
+        graph.add_edges_from([(0, 1, {"w": 1}), (1, 2, {"w": 2})])
+        view = graph.subgraph_view(graph.filter_nodes(lambda node: node[0] > 0))
+        self.assertIs(graph[1], view[1])
+        view[1].append(10)
+        self.assertEqual([1, 10], graph[1])
+        view[2] = "new"
+        self.assertEqual([2], graph[2])
+        view.get_edge_data(1, 2)["w"] = 20
+        self.assertEqual({"w": 20}, graph.get_edge_data(1, 2))
+
+    def test_subgraph_view_is_independent(self):
+        graph = rustworkx.PyGraph()
+        graph.add_nodes_from(range(4))
+        graph.add_edges_from_no_data([(0, 1), (1, 2), (2, 3)])
+        view = graph.subgraph_view([0, 2, 3])
+        new_node = view.add_node("new")
+        self.assertEqual(1, new_node)
+        view.add_edge(0, 2, None)
+        view.remove_node(3)
+        self.assertEqual(4, len(graph))
+        self.assertEqual([(0, 1), (1, 2), (2, 3)], graph.edge_list())
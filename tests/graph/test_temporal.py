@@ -0,0 +1,102 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+import unittest
+
+import rustworkx
+
+
+class TestTemporal(unittest.TestCase):
+    def setUp(self):
+        self.graph = rustworkx.PyGraph()
+        self.graph.add_nodes_from(range(5))
+        self.graph.add_edges_from(
+            [(0, 1, 2.0), (1, 2, 1.0), (1, 2, 4.0), (2, 3, 6.0), (3, 4, 5.0)]
+        )
+
+    def test_earliest_arrival_times(self):
+        res = rustworkx.earliest_arrival_times(self.graph, 0, lambda t: t)
+        self.assertEqual({1: 2.0, 2: 4.0, 3: 6.0}, dict(res))
+
+    def test_earliest_arrival_times_start_time(self):
+        res = rustworkx.graph_earliest_arrival_times(self.graph, 1, lambda t: t, start_time=3.0)
+        self.assertEqual({2: 4.0, 3: 6.0}, dict(res))
+
+    def test_earliest_arrival_times_end_time(self):
+        res = rustworkx.earliest_arrival_times(self.graph, 0, lambda t: t, end_time=5.0)
+        self.assertEqual({1: 2.0, 2: 4.0}, dict(res))
+
+    def test_undirected_both_directions(self):
+        res = rustworkx.earliest_arrival_times(self.graph, 4, lambda t: t)
+        self.assertEqual({3: 5.0, 2: 6.0}, dict(res))
+
+    def test_earliest_arrival_paths(self):
+        res = rustworkx.earliest_arrival_paths(self.graph, 0, lambda t: t)
+        self.assertEqual({1: [0, 1], 2: [0, 1, 2], 3: [0, 1, 2, 3]}, dict(res))
+
+    def test_intervals(self):
+        graph = rustworkx.PyGraph()
+        graph.add_nodes_from(range(3))
+        graph.add_edges_from([(0, 1, (1.0, 3.0)), (1, 2, (2.0, 4.0)), (1, 2, (3.0, 3.5))])
+        res = rustworkx.earliest_arrival_times(graph, 0, lambda t: t)
+        self.assertEqual({1: 3.0, 2: 3.5}, dict(res))
+
+    def test_fastest_path_durations(self):
+        res = rustworkx.fastest_path_durations(self.graph, 0, lambda t: t)
+        self.assertEqual({1: 0.0, 2: 2.0, 3: 4.0}, dict(res))
+
+    def test_fastest_path_durations_start_time(self):
+        graph = rustworkx.PyGraph()
+        graph.add_nodes_from(range(3))
+        graph.add_edges_from([(0, 1, 1.0), (0, 1, 3.0), (1, 2, 4.0)])
+        res = rustworkx.graph_fastest_path_durations(graph, 0, lambda t: t)
+        self.assertEqual({1: 0.0, 2: 1.0}, dict(res))
+        res = rustworkx.graph_fastest_path_durations(graph, 0, lambda t: t, start_time=3.5)
+        self.assertEqual({}, dict(res))
+
+    def test_temporal_reachability(self):
+        res = rustworkx.temporal_reachability(self.graph, 0, lambda t: t)
+        self.assertEqual({1, 2, 3}, res)
+        res = rustworkx.temporal_reachability(self.graph, 0, lambda t: t, start_time=2.5)
+        self.assertEqual(set(), res)
+
+    def test_temporal_snapshot(self):
+        res = rustworkx.temporal_snapshot(self.graph, lambda t: t, 4.0)
+        self.assertIsInstance(res, rustworkx.PyGraph)
+        self.assertEqual([(1, 2)], res.edge_list())
+        self.assertEqual(self.graph.node_indices(), res.node_indices())
+        res = rustworkx.graph_temporal_snapshot(self.graph, lambda t: t, 4.0, 5.0)
+        self.assertEqual([(1, 2), (3, 4)], res.edge_list())
+        self.assertEqual(5, len(self.graph.edge_list()))
+
+    def test_snapshot_invalid_window(self):
+        with self.assertRaises(ValueError):
+            rustworkx.temporal_snapshot(self.graph, lambda t: t, 4.0, 3.0)
+
+    def test_invalid_interval(self):
+        with self.assertRaises(ValueError):
+            rustworkx.earliest_arrival_times(self.graph, 0, lambda t: (t, t - 1))
+
+    def test_nan_time(self):
+        with self.assertRaises(ValueError):
+            rustworkx.earliest_arrival_times(self.graph, 0, lambda _: float("nan"))
+
+    def test_invalid_source(self):
+        with self.assertRaises(IndexError):
+            rustworkx.earliest_arrival_times(self.graph, 10, lambda t: t)
+
+    def test_time_fn_error(self):
+        def time_fn(_):
+            raise KeyError
+
+        with self.assertRaises(KeyError):
+            rustworkx.fastest_path_durations(self.graph, 0, time_fn)
@@ -110,3 +110,17 @@ class TestTensorProduct(unittest.TestCase):
         expected_edges = [(0, 3), (0, 2), (1, 2)]
         self.assertEqual(graph_product.num_edges(), 3)
         self.assertEqual(graph_product.edge_list(), expected_edges)
+
+    def test_combiner_callbacks_tensor(self):
+        graph_1 = rustworkx.PyGraph()
+        graph_1.add_nodes_from([1, 2])
+        graph_1.add_edge(0, 1, 3)
+        graph_2 = rustworkx.PyGraph()
+        graph_2.add_nodes_from([10, 20])
+        graph_2.add_edge(0, 1, 5)
+
+        graph_product, node_map = rustworkx.graph_tensor_product(
+            graph_1, graph_2, node_fn=lambda a, b: a * b, edge_fn=lambda a, b: a * b
+        )
+        self.assertEqual(graph_product[node_map[(1, 1)]], 40)
+        self.assertEqual(set(graph_product.edges()), {15})
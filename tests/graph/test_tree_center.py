@@ -0,0 +1,166 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+import unittest
+
+import rustworkx
+
+
+class TestTreeCenter(unittest.TestCase):
+    def setUp(self):
+        # A star with 5 leaves and a tail of 3 more nodes from leaf 4
+        self.graph = rustworkx.generators.star_graph(5)
+        self.graph.extend_from_edge_list([(4, 5), (5, 6), (6, 7)])
+
+    def test_center(self):
+        self.assertEqual([4, 5], list(rustworkx.tree_center(self.graph)))
+        self.assertEqual([2], list(rustworkx.tree_center(rustworkx.generators.path_graph(5))))
+
+    def test_centroid(self):
+        self.assertEqual([0, 4], list(rustworkx.tree_centroid(self.graph)))
+        self.assertEqual([0], list(rustworkx.tree_centroid(rustworkx.generators.star_graph(5))))
+
+    def test_center_matches_eccentricity(self):
+        graph = rustworkx.generators.binomial_tree_graph(4)
+        eccentricity = {
+            node: max(lengths.values())
+            for node, lengths in rustworkx.all_pairs_dijkstra_path_lengths(
+                graph, lambda _: 1.0
+            ).items()
+        }
+        radius = min(eccentricity.values())
+        expected = sorted(node for node, value in eccentricity.items() if value == radius)
+        self.assertEqual(expected, list(rustworkx.tree_center(graph)))
+
+    def test_removed_nodes(self):
+        graph = rustworkx.generators.path_graph(5)
+        graph.remove_node(0)
+        self.assertEqual([2, 3], list(rustworkx.tree_center(graph)))
+        self.assertEqual([2, 3], list(rustworkx.tree_centroid(graph)))
+
+    def test_single_node(self):
+        graph = rustworkx.PyGraph()
+        graph.add_node(None)
+        self.assertEqual([0], list(rustworkx.tree_center(graph)))
+        self.assertEqual([0], list(rustworkx.tree_centroid(graph)))
+
+    def test_not_a_tree(self):
+        for graph in [
+            rustworkx.generators.cycle_graph(4),
+            rustworkx.PyGraph(multigraph=True),
+            rustworkx.generators.path_graph(2),
+        ]:
+            graph.add_nodes_from([None, None])
+            with self.subTest(graph=graph), self.assertRaises(ValueError):
+                rustworkx.tree_center(graph)
+        graph = rustworkx.PyGraph()
+        graph.add_nodes_from(range(2))
+        graph.add_edges_from_no_data([(0, 1), (0, 1)])
+        graph.add_node(None)
+        with self.assertRaises(ValueError):
+            rustworkx.tree_centroid(graph)
+
+    def test_null_graph(self):
+        with self.assertRaises(rustworkx.NullGraph):
+            rustworkx.tree_center(rustworkx.PyGraph())
+        with self.assertRaises(rustworkx.NullGraph):
+            rustworkx.root_tree(rustworkx.PyGraph())
+
+
+class TestCentroidDecomposition(unittest.TestCase):
+    def test_path(self):
+        graph = rustworkx.generators.path_graph(7)
+        decomposition = rustworkx.centroid_decomposition(graph)
+        self.assertEqual(
+            {3: None, 1: 3, 0: 1, 2: 1, 5: 3, 4: 5, 6: 5},
+            decomposition,
+        )
+        self.assertEqual(3, next(iter(decomposition)))
+
+    def test_depth_is_logarithmic(self):
+        graph = rustworkx.generators.path_graph(100)
+        decomposition = rustworkx.centroid_decomposition(graph)
+        self.assertEqual(100, len(decomposition))
+        for node in graph.node_indices():
+            depth = 0
+            while decomposition[node] is not None:
+                node = decomposition[node]
+                depth += 1
+            self.assertLessEqual(depth, 7)
+
+    def test_subtrees_are_connected(self):
+        graph = rustworkx.generators.binomial_tree_graph(5)
+        decomposition = rustworkx.centroid_decomposition(graph)
+        children = {node: [] for node in decomposition}
+        for node, parent in decomposition.items():
+            if parent is not None:
+                children[parent].append(node)
+
+        def subtree(node):
+            nodes = [node]
+            for child in children[node]:
+                nodes.extend(subtree(child))
+            return nodes
+
+        for node in decomposition:
+            nodes = subtree(node)
+            self.assertTrue(rustworkx.is_connected(graph.subgraph(nodes)))
+            for child in children[node]:
+                self.assertLessEqual(2 * len(subtree(child)), len(nodes))
+
+
+class TestRootTree(unittest.TestCase):
+    def test_root(self):
+        graph = rustworkx.generators.star_graph(4)
+        parents, euler_tour = rustworkx.root_tree(graph, root=1)
+        self.assertEqual({1: None, 0: 1, 2: 0, 3: 0}, parents)
+        self.assertEqual([1, 0, 2, 0, 3, 0, 1], list(euler_tour))
+
+    def test_default_root_is_center(self):
+        graph = rustworkx.generators.path_graph(6)
+        parents, euler_tour = rustworkx.root_tree(graph)
+        self.assertEqual([2, 1, 0, 1, 2, 3, 4, 5, 4, 3, 2], list(euler_tour))
+        self.assertIsNone(parents[2])
+        self.assertEqual(2, next(iter(parents)))
+
+    def test_euler_tour_lowest_common_ancestor(self):
+        graph = rustworkx.generators.binomial_tree_graph(4)
+        parents, euler_tour = rustworkx.root_tree(graph, root=0)
+        depth = {}
+        for node, parent in parents.items():
+            depth[node] = 0 if parent is None else depth[parent] + 1
+        first = {}
+        for position, node in enumerate(euler_tour):
+            first.setdefault(node, position)
+        self.assertEqual(2 * len(graph) - 1, len(euler_tour))
+
+        def ancestors(node):
+            path = [node]
+            while parents[node] is not None:
+                node = parents[node]
+                path.append(node)
+            return path
+
+        for a in graph.node_indices():
+            for b in graph.node_indices():
+                low, high = sorted([first[a], first[b]])
+                lca = min(euler_tour[low : high + 1], key=depth.get)
+                expected = next(node for node in ancestors(a) if node in ancestors(b))
+                self.assertEqual(expected, lca)
+
+    def test_invalid_root(self):
+        graph = rustworkx.generators.path_graph(3)
+        with self.assertRaises(rustworkx.InvalidNode):
+            rustworkx.root_tree(graph, root=5)
+        graph.remove_node(2)
+        with self.assertRaises(rustworkx.InvalidNode):
+            rustworkx.root_tree(graph, root=2)
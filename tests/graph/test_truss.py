@@ -0,0 +1,80 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+import unittest
+
+import rustworkx
+
+
+class TestTruss(unittest.TestCase):
+    def setUp(self):
+        # A complete graph on nodes 0-3, a triangle on nodes 3-5 and a
+        # pendant edge from node 5 to node 6
+        self.graph = rustworkx.PyGraph()
+        self.graph.add_nodes_from(list(range(7)))
+        self.graph.add_edges_from_no_data(
+            [
+                (0, 1),
+                (0, 2),
+                (0, 3),
+                (1, 2),
+                (1, 3),
+                (2, 3),
+                (3, 4),
+                (4, 5),
+                (5, 3),
+                (5, 6),
+            ]
+        )
+
+    def test_truss_number(self):
+        res = rustworkx.truss_number(self.graph)
+        self.assertEqual(res, {0: 4, 1: 4, 2: 4, 3: 4, 4: 4, 5: 4, 6: 3, 7: 3, 8: 3, 9: 2})
+
+    def test_truss_number_empty(self):
+        self.assertEqual(rustworkx.truss_number(rustworkx.PyGraph()), {})
+
+    def test_truss_number_complete_graph(self):
+        graph = rustworkx.generators.complete_graph(7)
+        res = rustworkx.truss_number(graph)
+        self.assertEqual(set(res.values()), {7})
+        self.assertEqual(len(res), 21)
+
+    def test_truss_number_removed_edge(self):
+        self.graph.remove_edge_from_index(0)
+        res = rustworkx.truss_number(self.graph)
+        self.assertNotIn(0, res)
+        self.assertEqual(res, {1: 3, 2: 3, 3: 3, 4: 3, 5: 3, 6: 3, 7: 3, 8: 3, 9: 2})
+
+    def test_k_truss(self):
+        res = rustworkx.k_truss(self.graph, 4)
+        self.assertEqual(res.node_indices(), [0, 1, 2, 3])
+        self.assertEqual(res.edge_indices(), [0, 1, 2, 3, 4, 5])
+        res = rustworkx.k_truss(self.graph, 3)
+        self.assertEqual(res.node_indices(), [0, 1, 2, 3, 4, 5])
+        self.assertEqual(res.edge_list(), self.graph.edge_list()[:9])
+
+    def test_k_truss_preserves_data(self):
+        graph = rustworkx.PyGraph()
+        graph.add_nodes_from(["a", "b", "c", "d"])
+        graph.add_edges_from([(0, 1, 1), (1, 2, 2), (2, 0, 3), (2, 3, 4)])
+        res = rustworkx.k_truss(graph, 3)
+        self.assertEqual(res.nodes(), ["a", "b", "c"])
+        self.assertEqual(res.weighted_edge_list(), [(0, 1, 1), (1, 2, 2), (2, 0, 3)])
+
+    def test_k_truss_empty_result(self):
+        res = rustworkx.k_truss(self.graph, 5)
+        self.assertEqual(len(res), 0)
+        self.assertEqual(res.num_edges(), 0)
+        res = rustworkx.k_truss(self.graph, 2)
+        self.assertEqual(res.num_nodes(), 7)
+        self.assertEqual(res.num_edges(), 10)
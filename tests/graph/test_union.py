@@ -72,3 +72,89 @@ class TestUnion(unittest.TestCase):
 
         final = rustworkx.graph_union(first, second, merge_nodes=True, merge_edges=True)
         self.assertEqual(final.weighted_edge_list(), [(0, 1, "a"), (0, 2, "b")])
+
+    def test_union_key_fn(self):
+        first = rustworkx.PyGraph()
+        first.add_nodes_from([("a", 1), ("b", 2)])
+        first.add_edge(0, 1, "x")
+        second = rustworkx.PyGraph()
+        second.add_nodes_from([("b", 3), ("c", 4)])
+        second.add_edge(0, 1, "y")
+
+        final = rustworkx.graph_union(first, second, merge_nodes=True, key_fn=lambda n: n[0])
+        self.assertEqual(final.nodes(), [("a", 1), ("b", 2), ("c", 4)])
+        self.assertEqual(final.weighted_edge_list(), [(0, 1, "x"), (1, 2, "y")])
+
+    def test_union_key_fn_ignored_without_merge_nodes(self):
+        final = rustworkx.graph_union(self.graph, self.graph, key_fn=lambda n: 0)
+        self.assertEqual(len(final), 6)
+
+    def test_union_key_fn_unhashable(self):
+        with self.assertRaises(TypeError):
+            rustworkx.graph_union(self.graph, self.graph, merge_nodes=True, key_fn=lambda n: [n])
+
+    def test_union_with_mapping_no_merge(self):
+        final, mapping = rustworkx.graph_union_with_mapping(self.graph, self.graph)
+        self.assertEqual(len(final), 6)
+        self.assertEqual(dict(mapping.first_nodes), {0: 0, 1: 1, 2: 2})
+        self.assertEqual(dict(mapping.second_nodes), {0: 3, 1: 4, 2: 5})
+        self.assertEqual(dict(mapping.first_edges), {0: 0, 1: 1})
+        self.assertEqual(dict(mapping.second_edges), {0: 2, 1: 3})
+        self.assertEqual(list(mapping.merged_nodes), [])
+        self.assertEqual(list(mapping.merged_edges), [])
+
+    def test_union_with_mapping_merge_all(self):
+        final, mapping = rustworkx.graph_union_with_mapping(
+            self.graph, self.graph, merge_nodes=True, merge_edges=True
+        )
+        self.assertEqual(len(final), 3)
+        self.assertEqual(dict(mapping.second_nodes), {0: 0, 1: 1, 2: 2})
+        self.assertEqual(dict(mapping.second_edges), {0: 0, 1: 1})
+        self.assertEqual(list(mapping.merged_nodes), [0, 1, 2])
+        self.assertEqual(list(mapping.merged_edges), [0, 1])
+
+    def test_union_with_mapping_maps_into_output(self):
+        first = rustworkx.PyGraph()
+        first.add_nodes_from([("a", 1), ("b", 2), ("c", 3)])
+        first.add_edges_from([(0, 1, 1), (1, 2, 2)])
+        first.remove_node(0)
+        second = rustworkx.PyGraph()
+        dummy = second.add_node(("z", 0))
+        second.add_nodes_from([("c", 4), ("d", 5)])
+        second.add_edges_from([(1, 2, 3), (2, 1, 4)])
+        second.remove_node(dummy)
+
+        final, mapping = rustworkx.graph_union_with_mapping(
+            first, second, merge_nodes=True, key_fn=lambda n: n[0]
+        )
+        self.assertEqual(dict(mapping.first_nodes), {1: 1, 2: 2})
+        self.assertEqual(list(mapping.merged_nodes), [1])
+        for graph, nodes, edges in [
+            (first, mapping.first_nodes, mapping.first_edges),
+            (second, mapping.second_nodes, mapping.second_edges),
+        ]:
+            self.assertEqual(sorted(nodes.keys()), list(graph.node_indices()))
+            self.assertEqual(sorted(edges.keys()), list(graph.edge_indices()))
+            for edge, (source, target) in zip(graph.edge_indices(), graph.edge_list()):
+                new_edge = edges[edge]
+                self.assertEqual(
+                    final.get_edge_endpoints_by_index(new_edge), (nodes[source], nodes[target])
+                )
+                self.assertEqual(
+                    final.get_edge_data_by_index(new_edge), graph.get_edge_data_by_index(edge)
+                )
+
+    def test_union_with_mapping_key_fn_error(self):
+        def key_fn(_):
+            raise KeyError("boom")
+
+        with self.assertRaises(KeyError):
+            rustworkx.graph_union_with_mapping(
+                self.graph, self.graph, merge_nodes=True, key_fn=key_fn
+            )
+
+    def test_universal_union_with_mapping(self):
+        final, mapping = rustworkx.union_with_mapping(self.graph, self.graph, merge_nodes=True)
+        self.assertIsInstance(final, rustworkx.PyGraph)
+        self.assertIsInstance(mapping, rustworkx.UnionMapping)
+        self.assertEqual(len(final.edges()), 4)
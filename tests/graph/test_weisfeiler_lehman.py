@@ -0,0 +1,90 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+
+import unittest
+
+import numpy as np
+
+import rustworkx
+
+
+class TestWeisfeilerLehman(unittest.TestCase):
+    def setUp(self):
+        self.path = rustworkx.generators.path_graph(3)
+        self.triangle = rustworkx.generators.cycle_graph(3)
+
+    def test_features(self):
+        res = rustworkx.weisfeiler_lehman_subtree_features(
+            [self.path, self.triangle], iterations=1
+        )
+        self.assertEqual(len(res), 2)
+        self.assertEqual(sorted(res[0].values()), [1, 2, 3])
+        self.assertEqual(sorted(res[1].values()), [3, 3])
+        self.assertEqual(res[0][0], res[1][0])
+
+    def test_kernel(self):
+        res = rustworkx.weisfeiler_lehman_subtree_kernel([self.path, self.triangle], iterations=1)
+        np.testing.assert_array_equal(res, np.array([[14.0, 12.0], [12.0, 18.0]]))
+
+    def test_normalized_kernel(self):
+        res = rustworkx.weisfeiler_lehman_subtree_kernel(
+            [self.path, self.triangle], iterations=1, normalize=True
+        )
+        self.assertAlmostEqual(res[0][0], 1.0)
+        self.assertAlmostEqual(res[1][1], 1.0)
+        self.assertAlmostEqual(res[0][1], 12.0 / np.sqrt(14.0 * 18.0))
+
+    def test_node_label(self):
+        graph = rustworkx.PyGraph()
+        graph.add_nodes_from(["a", "b"])
+        graph.add_edge(0, 1, None)
+        other = rustworkx.PyGraph()
+        other.add_nodes_from(["a", "a"])
+        other.add_edge(0, 1, None)
+        res = rustworkx.weisfeiler_lehman_subtree_kernel(
+            [graph, other], node_label=lambda label: label, iterations=2
+        )
+        np.testing.assert_array_equal(res, np.array([[6.0, 2.0], [2.0, 12.0]]))
+
+    def test_isomorphic_graphs(self):
+        graph = rustworkx.PyGraph()
+        graph.add_nodes_from(range(4))
+        graph.add_edges_from_no_data([(3, 2), (2, 0), (0, 1), (2, 1)])
+        other = rustworkx.PyGraph()
+        other.add_nodes_from(range(4))
+        other.add_edges_from_no_data([(0, 1), (1, 2), (2, 3), (1, 3)])
+        res = rustworkx.weisfeiler_lehman_subtree_features([graph, other])
+        self.assertEqual(res[0], res[1])
+
+    def test_digraphs(self):
+        out_star = rustworkx.PyDiGraph()
+        out_star.extend_from_edge_list([(0, 1), (0, 2)])
+        in_star = rustworkx.PyDiGraph()
+        in_star.extend_from_edge_list([(1, 0), (2, 0)])
+        res = rustworkx.weisfeiler_lehman_subtree_features([out_star, in_star], iterations=1)
+        self.assertNotEqual(res[0], res[1])
+
+    def test_mixed_graph_types(self):
+        with self.assertRaises(TypeError):
+            rustworkx.weisfeiler_lehman_subtree_kernel([self.path, rustworkx.PyDiGraph()])
+
+    def test_empty_list(self):
+        res = rustworkx.weisfeiler_lehman_subtree_features([])
+        self.assertEqual(res, [])
+
+    def test_node_label_exception(self):
+        def fail(_):
+            raise IndexError
+
+        with self.assertRaises(IndexError):
+            rustworkx.weisfeiler_lehman_subtree_features([self.path], node_label=fail)
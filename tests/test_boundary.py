@@ -0,0 +1,108 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+
+import unittest
+
+import rustworkx
+
+
+class TestNodeBoundary(unittest.TestCase):
+    def test_path(self):
+        graph = rustworkx.generators.path_graph(5)
+        self.assertEqual([0, 3], rustworkx.graph_node_boundary(graph, [1, 2]))
+        self.assertEqual([3], rustworkx.node_boundary(graph, [1, 2], targets=[3, 4]))
+        self.assertEqual([], rustworkx.node_boundary(graph, [0, 1, 2, 3, 4]))
+
+    def test_targets_in_nodes_ignored(self):
+        graph = rustworkx.generators.cycle_graph(4)
+        self.assertEqual([3], rustworkx.node_boundary(graph, [0, 1], targets=[1, 3]))
+
+    def test_digraph(self):
+        graph = rustworkx.generators.directed_path_graph(5)
+        self.assertEqual([3], rustworkx.digraph_node_boundary(graph, [1, 2]))
+        self.assertEqual([], rustworkx.node_boundary(graph, [4]))
+
+    def test_invalid_node(self):
+        graph = rustworkx.generators.path_graph(3)
+        with self.assertRaises(IndexError):
+            rustworkx.node_boundary(graph, [5])
+        with self.assertRaises(IndexError):
+            rustworkx.node_boundary(graph, [0], targets=[5])
+
+    def test_invalid_type(self):
+        with self.assertRaises(TypeError):
+            rustworkx.node_boundary(None, [0])
+
+
+class TestEdgeBoundary(unittest.TestCase):
+    def test_cycle(self):
+        graph = rustworkx.generators.cycle_graph(5)
+        self.assertEqual([(0, 4), (1, 2)], rustworkx.graph_edge_boundary(graph, [0, 1]))
+        self.assertEqual([(1, 2)], rustworkx.edge_boundary(graph, [0, 1], targets=[2]))
+
+    def test_multigraph(self):
+        graph = rustworkx.PyGraph()
+        graph.add_nodes_from(range(3))
+        graph.add_edges_from_no_data([(0, 1), (0, 1), (1, 2), (2, 2)])
+        self.assertEqual([(1, 0), (1, 0)], rustworkx.edge_boundary(graph, [1, 2], [0]))
+
+    def test_digraph(self):
+        graph = rustworkx.generators.directed_cycle_graph(5)
+        self.assertEqual([(1, 2)], rustworkx.digraph_edge_boundary(graph, [0, 1]))
+        self.assertEqual([(4, 0)], rustworkx.edge_boundary(graph, [2, 3, 4]))
+
+
+class TestCutSizeAndVolume(unittest.TestCase):
+    def test_cut_size(self):
+        graph = rustworkx.generators.cycle_graph(5)
+        self.assertEqual(2.0, rustworkx.graph_cut_size(graph, [0, 1]))
+        self.assertEqual(1.0, rustworkx.cut_size(graph, [0, 1], targets=[2]))
+
+    def test_weighted_cut_size(self):
+        graph = rustworkx.PyGraph()
+        graph.add_nodes_from(range(4))
+        graph.add_edges_from([(0, 1, 2.0), (1, 2, 3.0), (2, 3, 4.0), (3, 0, 5.0)])
+        self.assertEqual(8.0, rustworkx.cut_size(graph, [0, 1], weight_fn=float))
+        self.assertEqual(7.0, rustworkx.cut_size(graph, [0, 1], weight_fn=[9, 3, 9, 4]))
+        self.assertEqual(4.0, rustworkx.cut_size(graph, [0, 1], default_weight=2.0))
+
+    def test_digraph_cut_size(self):
+        graph = rustworkx.generators.directed_cycle_graph(5)
+        self.assertEqual(2.0, rustworkx.digraph_cut_size(graph, [0, 1]))
+        self.assertEqual(1.0, rustworkx.cut_size(graph, [0, 1], targets=[4]))
+
+    def test_negative_weight(self):
+        graph = rustworkx.generators.path_graph(3)
+        with self.assertRaises(ValueError):
+            rustworkx.cut_size(graph, [0], weight_fn=lambda _: -1.0)
+
+    def test_volume(self):
+        graph = rustworkx.generators.cycle_graph(5)
+        graph.add_edge(0, 0, None)
+        self.assertEqual(6.0, rustworkx.graph_volume(graph, [0, 1]))
+        self.assertEqual(6.0, rustworkx.volume(graph, [0, 1, 0]))
+        self.assertEqual(3.0, rustworkx.volume(graph, [0], default_weight=0.75))
+
+    def test_digraph_volume(self):
+        graph = rustworkx.generators.directed_path_graph(4)
+        self.assertEqual(2.0, rustworkx.digraph_volume(graph, [0, 1]))
+        self.assertEqual(0.0, rustworkx.volume(graph, [3]))
+
+    def test_conductance(self):
+        graph = rustworkx.generators.barbell_graph(4, 0)
+        nodes = [0, 1, 2, 3]
+        rest = [4, 5, 6, 7]
+        conductance = rustworkx.cut_size(graph, nodes) / min(
+            rustworkx.volume(graph, nodes), rustworkx.volume(graph, rest)
+        )
+        self.assertAlmostEqual(1 / 13, conductance)
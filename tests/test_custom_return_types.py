@@ -1378,6 +1378,48 @@ class TestNodeMap(unittest.TestCase):
         self.assertEqual(first_iter, third_iter)
 
 
+class TestEdgeMap(unittest.TestCase):
+    def setUp(self):
+        graph = rustworkx.generators.path_graph(2)
+        _, mapping = rustworkx.graph_union_with_mapping(graph, graph)
+        self.edge_map = mapping.second_edges
+
+    def test__eq__match(self):
+        self.assertTrue(self.edge_map == {0: 1})
+
+    def test__eq__not_match_values(self):
+        self.assertFalse(self.edge_map == {0: 0})
+
+    def test__ne__different_length(self):
+        self.assertTrue(self.edge_map != {0: 1, 1: 2})
+
+    def test__len__(self):
+        self.assertEqual(1, len(self.edge_map))
+
+    def test_deepcopy(self):
+        self.assertEqual(self.edge_map, copy.deepcopy(self.edge_map))
+
+    def test_pickle(self):
+        edge_map_copy = pickle.loads(pickle.dumps(self.edge_map))
+        self.assertEqual(self.edge_map, edge_map_copy)
+
+    def test_str(self):
+        self.assertEqual("EdgeMap{0: 1}", str(self.edge_map))
+
+    def test_index_error(self):
+        with self.assertRaises(IndexError):
+            self.edge_map[42]
+
+    def test_items(self):
+        self.assertEqual([0], list(self.edge_map.keys()))
+        self.assertEqual([1], list(self.edge_map.values()))
+        self.assertEqual([(0, 1)], list(self.edge_map.items()))
+
+    def test_contains(self):
+        self.assertIn(0, self.edge_map)
+        self.assertNotIn(1, self.edge_map)
+
+
 class TestChainsComparisons(unittest.TestCase):
     def setUp(self):
         self.graph = rustworkx.generators.cycle_graph(3)
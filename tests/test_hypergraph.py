@@ -0,0 +1,131 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+import unittest
+
+import rustworkx
+
+
+class TestHypergraph(unittest.TestCase):
+    def setUp(self):
+        self.hypergraph = rustworkx.PyHypergraph()
+        self.hypergraph.add_nodes_from(["a", "b", "c", "d", "e"])
+        self.hypergraph.add_hyperedge([0, 1, 2], "x")
+        self.hypergraph.add_hyperedge([2, 3], "y")
+        self.hypergraph.add_hyperedge([0, 2, 3, 4], "z")
+
+    def test_accessors(self):
+        self.assertEqual(5, len(self.hypergraph))
+        self.assertEqual(5, self.hypergraph.num_nodes())
+        self.assertEqual(3, self.hypergraph.num_hyperedges())
+        self.assertEqual(["a", "b", "c", "d", "e"], self.hypergraph.nodes())
+        self.assertEqual(["x", "y", "z"], self.hypergraph.hyperedges())
+        self.assertEqual([0, 1, 2, 3, 4], self.hypergraph.node_indices())
+        self.assertEqual([0, 1, 2], self.hypergraph.hyperedge_indices())
+        self.assertEqual("c", self.hypergraph[2])
+        self.assertEqual("c", self.hypergraph.get_node_data(2))
+        self.assertEqual("y", self.hypergraph.get_hyperedge_data(1))
+        self.assertEqual([2, 3], self.hypergraph.hyperedge_nodes(1))
+        self.assertEqual([0, 1, 2], self.hypergraph.incident_hyperedges(2))
+        self.assertEqual(3, self.hypergraph.degree(2))
+        self.assertEqual([2, 0, 4], self.hypergraph.neighbors(3))
+
+    def test_invalid_indices(self):
+        with self.assertRaises(IndexError):
+            self.hypergraph.add_hyperedge([0, 5])
+        with self.assertRaises(IndexError):
+            self.hypergraph.get_node_data(5)
+        with self.assertRaises(IndexError):
+            self.hypergraph.get_hyperedge_data(3)
+        with self.assertRaises(IndexError):
+            self.hypergraph.hyperedge_nodes(3)
+        with self.assertRaises(IndexError):
+            self.hypergraph.neighbors(5)
+        with self.assertRaises(IndexError):
+            self.hypergraph[5]
+        self.assertEqual(3, self.hypergraph.num_hyperedges())
+
+    def test_default_payload_and_duplicates(self):
+        index = self.hypergraph.add_hyperedge([4, 1, 4])
+        self.assertIsNone(self.hypergraph.get_hyperedge_data(index))
+        self.assertEqual([4, 1], self.hypergraph.hyperedge_nodes(index))
+
+    def test_setitem(self):
+        self.hypergraph[1] = "B"
+        self.assertEqual("B", self.hypergraph[1])
+
+    def test_remove(self):
+        self.hypergraph.remove_node(2)
+        self.hypergraph.remove_node(2)
+        self.assertEqual([0, 1], self.hypergraph.hyperedge_nodes(0))
+        self.assertEqual([3], self.hypergraph.hyperedge_nodes(1))
+        self.hypergraph.remove_hyperedge(0)
+        self.assertEqual([2], self.hypergraph.incident_hyperedges(0))
+        self.assertEqual([0, 1, 3, 4], self.hypergraph.node_indices())
+        self.assertEqual([1, 2], self.hypergraph.hyperedge_indices())
+        del self.hypergraph[4]
+        self.assertEqual([0, 3], self.hypergraph.hyperedge_nodes(2))
+        with self.assertRaises(IndexError):
+            del self.hypergraph[4]
+        # Indices are not reused
+        self.assertEqual(5, self.hypergraph.add_node("f"))
+        self.assertEqual(3, self.hypergraph.add_hyperedge([5]))
+
+    def test_copy(self):
+        copy = self.hypergraph.copy()
+        copy.remove_node(0)
+        self.assertEqual(5, len(self.hypergraph))
+        self.assertEqual(4, len(copy))
+
+    def test_to_incidence_graph(self):
+        graph, node_map, hyperedge_map = self.hypergraph.to_incidence_graph()
+        self.assertIsInstance(graph, rustworkx.PyGraph)
+        self.assertEqual(["a", "b", "c", "d", "e", "x", "y", "z"], graph.nodes())
+        self.assertEqual(9, graph.num_edges())
+        self.assertTrue(rustworkx.is_bipartite(graph))
+        self.assertEqual({i: i for i in range(5)}, dict(node_map))
+        self.assertEqual({0: 5, 1: 6, 2: 7}, dict(hyperedge_map))
+        self.assertEqual({0, 2, 3, 4}, set(graph.neighbors(hyperedge_map[2])))
+
+    def test_incidence_round_trip(self):
+        self.hypergraph.remove_node(1)
+        graph, _, hyperedge_map = self.hypergraph.to_incidence_graph()
+        hypergraph = rustworkx.PyHypergraph.from_incidence_graph(
+            graph, set(hyperedge_map.values())
+        )
+        self.assertEqual(["a", "c", "d", "e"], hypergraph.nodes())
+        self.assertEqual(["x", "y", "z"], hypergraph.hyperedges())
+        members = [
+            sorted(hypergraph[node] for node in hypergraph.hyperedge_nodes(hyperedge))
+            for hyperedge in hypergraph.hyperedge_indices()
+        ]
+        self.assertEqual([["a", "c"], ["c", "d"], ["a", "c", "d", "e"]], members)
+
+    def test_from_incidence_graph(self):
+        graph = rustworkx.generators.star_graph(4)
+        hypergraph = rustworkx.PyHypergraph.from_incidence_graph(graph, {0})
+        self.assertEqual(3, hypergraph.num_nodes())
+        self.assertEqual(1, hypergraph.num_hyperedges())
+        self.assertEqual([0, 1, 2], hypergraph.hyperedge_nodes(0))
+
+    def test_to_clique_expansion(self):
+        graph, node_map = self.hypergraph.to_clique_expansion()
+        self.assertEqual(["a", "b", "c", "d", "e"], graph.nodes())
+        self.assertEqual(8, graph.num_edges())
+        self.assertEqual([1, 2], graph.get_edge_data(node_map[2], node_map[3]))
+        self.assertFalse(graph.has_edge(node_map[1], node_map[3]))
+
+    def test_clique_expansion_removed_node(self):
+        self.hypergraph.remove_node(0)
+        graph, node_map = self.hypergraph.to_clique_expansion()
+        self.assertEqual({1: 0, 2: 1, 3: 2, 4: 3}, dict(node_map))
+        self.assertEqual(["b", "c", "d", "e"], graph.nodes())
@@ -0,0 +1,81 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+
+import unittest
+
+import rustworkx
+
+
+class TestModularDecomposition(unittest.TestCase):
+    def tree_to_tuple(self, tree, node=0):
+        kind, nodes = tree[node]
+        if kind == "leaf":
+            return nodes[0]
+        return (kind, [self.tree_to_tuple(tree, child) for child in self.children(tree, node)])
+
+    def children(self, tree, node):
+        return sorted(tree.successor_indices(node), key=lambda child: tree[child][1][0])
+
+    def test_empty_graph(self):
+        tree = rustworkx.modular_decomposition(rustworkx.PyGraph())
+        self.assertEqual(len(tree), 0)
+        self.assertTrue(rustworkx.is_cograph(rustworkx.PyGraph()))
+
+    def test_single_node(self):
+        graph = rustworkx.PyGraph()
+        graph.add_node(None)
+        tree = rustworkx.modular_decomposition(graph)
+        self.assertEqual(tree.nodes(), [("leaf", [0])])
+
+    def test_star(self):
+        tree = rustworkx.modular_decomposition(rustworkx.generators.star_graph(4))
+        self.assertEqual(
+            self.tree_to_tuple(tree),
+            ("series", [0, ("parallel", [1, 2, 3])]),
+        )
+        self.assertEqual(tree[0], ("series", [0, 1, 2, 3]))
+        self.assertTrue(rustworkx.is_directed_acyclic_graph(tree))
+        self.assertEqual(tree.num_edges(), len(tree) - 1)
+
+    def test_prime(self):
+        graph = rustworkx.PyGraph()
+        graph.extend_from_edge_list(
+            [(0, 1), (0, 2), (1, 3), (2, 3), (3, 4), (3, 5), (4, 5)]
+        )
+        tree = rustworkx.modular_decomposition(graph)
+        self.assertEqual(
+            self.tree_to_tuple(tree),
+            ("prime", [0, ("parallel", [1, 2]), 3, ("series", [4, 5])]),
+        )
+        self.assertFalse(rustworkx.is_cograph(graph))
+
+    def test_leaves_are_graph_nodes(self):
+        graph = rustworkx.generators.generalized_petersen_graph(5, 2)
+        graph.remove_node(3)
+        tree = rustworkx.modular_decomposition(graph)
+        leaves = sorted(tree[node][1][0] for node in tree.node_indices() if tree[node][0] == "leaf")
+        self.assertEqual(leaves, list(graph.node_indices()))
+        for node in tree.node_indices():
+            kind, nodes = tree[node]
+            children = list(tree.successor_indices(node))
+            if kind == "leaf":
+                self.assertEqual(children, [])
+            else:
+                self.assertEqual(sorted(n for c in children for n in tree[c][1]), nodes)
+
+    def test_is_cograph(self):
+        self.assertTrue(rustworkx.is_cograph(rustworkx.generators.complete_graph(5)))
+        self.assertTrue(rustworkx.is_cograph(rustworkx.generators.cycle_graph(4)))
+        self.assertFalse(rustworkx.is_cograph(rustworkx.generators.cycle_graph(5)))
+        self.assertFalse(rustworkx.is_cograph(rustworkx.generators.path_graph(4)))
+        self.assertTrue(rustworkx.is_cograph(rustworkx.generators.path_graph(3)))
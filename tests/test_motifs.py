@@ -0,0 +1,122 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+
+import math
+import unittest
+
+import rustworkx
+
+TRIANGLE = ((0, 1), (0, 2), (1, 2))
+PATH = ((0, 1), (0, 2))
+
+
+class TestMotifs(unittest.TestCase):
+    def test_graph_motif_counts(self):
+        graph = rustworkx.PyGraph()
+        graph.extend_from_edge_list([(0, 1), (1, 2), (2, 0), (2, 3), (3, 3)])
+        self.assertEqual(rustworkx.motif_counts(graph), {PATH: 2.0, TRIANGLE: 1.0})
+        counts = rustworkx.graph_motif_counts(graph, size=4)
+        self.assertEqual(counts, {((0, 1), (0, 2), (0, 3), (1, 2)): 1.0})
+
+    def test_complete_graph(self):
+        graph = rustworkx.generators.complete_graph(6)
+        self.assertEqual(rustworkx.motif_counts(graph), {TRIANGLE: 20.0})
+        self.assertEqual(list(rustworkx.motif_counts(graph, 4).values()), [15.0])
+
+    def test_digraph_motif_counts(self):
+        graph = rustworkx.generators.directed_cycle_graph(3)
+        self.assertEqual(rustworkx.motif_counts(graph), {((0, 1), (1, 2), (2, 0)): 1.0})
+        graph = rustworkx.generators.directed_path_graph(5)
+        counts = rustworkx.digraph_motif_counts(graph, 3)
+        self.assertEqual(list(counts.values()), [3.0])
+        self.assertEqual(len(next(iter(counts))), 2)
+
+    def test_sampling(self):
+        graph = rustworkx.generators.complete_graph(12)
+        first = rustworkx.motif_counts(graph, probabilities=[1.0, 1.0, 0.5], seed=3)
+        second = rustworkx.motif_counts(graph, probabilities=[1.0, 1.0, 0.5], seed=3)
+        self.assertEqual(first, second)
+        self.assertLess(abs(first[TRIANGLE] - 220), 60)
+
+    def test_invalid_arguments(self):
+        graph = rustworkx.generators.path_graph(4)
+        with self.assertRaises(ValueError):
+            rustworkx.motif_counts(graph, 5)
+        with self.assertRaises(ValueError):
+            rustworkx.motif_counts(graph, 3, probabilities=[1.0, 1.0])
+        with self.assertRaises(ValueError):
+            rustworkx.motif_significance_profile(graph, 3, probabilities=[1.0, 0.0, 1.0])
+        with self.assertRaises(ValueError):
+            rustworkx.motif_significance_profile(graph, 3, num_randomizations=0)
+        with self.assertRaises(TypeError):
+            rustworkx.motif_counts([])
+
+    def test_significance_profile(self):
+        graph = rustworkx.undirected_gnm_random_graph(30, 60, seed=4)
+        for node in range(0, 27, 3):
+            graph.add_edges_from_no_data([(node, node + 1), (node + 1, node + 2), (node + 2, node)])
+        profile = rustworkx.motif_significance_profile(graph, num_randomizations=20, seed=1)
+        self.assertEqual(
+            profile, rustworkx.motif_significance_profile(graph, num_randomizations=20, seed=1)
+        )
+        counts = rustworkx.motif_counts(graph)
+        for motif, count in counts.items():
+            self.assertEqual(profile[motif]["count"], count)
+        triangle = profile[TRIANGLE]
+        self.assertGreater(triangle["z_score"], 0)
+        self.assertGreater(triangle["significance"], 0)
+        norm = sum(
+            stats["significance"] ** 2
+            for stats in profile.values()
+            if not math.isnan(stats["significance"])
+        )
+        self.assertAlmostEqual(norm, 1.0)
+
+    def test_digraph_significance_profile(self):
+        graph = rustworkx.directed_gnm_random_graph(20, 50, seed=2)
+        profile = rustworkx.digraph_motif_significance_profile(
+            graph, num_randomizations=5, seed=3
+        )
+        self.assertTrue(
+            all(
+                set(stats) == {"count", "random_mean", "random_std", "z_score", "significance"}
+                for stats in profile.values()
+            )
+        )
+
+
+class TestTriadCensus(unittest.TestCase):
+    def test_triad_census(self):
+        graph = rustworkx.PyDiGraph()
+        graph.extend_from_edge_list([(0, 1), (1, 2), (2, 0), (2, 3)])
+        census = rustworkx.triad_census(graph)
+        self.assertEqual(len(census), 16)
+        self.assertEqual(list(census)[:3], ["003", "012", "102"])
+        self.assertEqual(census["030C"], 1)
+        self.assertEqual(census["021C"], 1)
+        self.assertEqual(census["021D"], 1)
+        self.assertEqual(census["012"], 1)
+        self.assertEqual(sum(census.values()), 4)
+
+    def test_triad_census_total(self):
+        graph = rustworkx.directed_gnm_random_graph(25, 80, seed=5)
+        census = rustworkx.triad_census(graph)
+        self.assertEqual(sum(census.values()), 25 * 24 * 23 // 6)
+
+    def test_mutual_dyads(self):
+        graph = rustworkx.PyDiGraph()
+        graph.add_nodes_from(range(4))
+        graph.add_edges_from_no_data([(0, 1), (1, 0)])
+        census = rustworkx.triad_census(graph)
+        self.assertEqual(census["102"], 2)
+        self.assertEqual(census["003"], 2)
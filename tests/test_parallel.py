@@ -0,0 +1,77 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+import unittest
+
+import rustworkx
+
+
+class TestNumThreads(unittest.TestCase):
+    def tearDown(self):
+        rustworkx.set_num_threads(None)
+
+    def test_set_num_threads(self):
+        rustworkx.set_num_threads(2)
+        self.assertEqual(rustworkx.get_num_threads(), 2)
+        rustworkx.set_num_threads(None)
+        self.assertGreaterEqual(rustworkx.get_num_threads(), 1)
+
+    def test_set_zero_threads(self):
+        with self.assertRaises(ValueError):
+            rustworkx.set_num_threads(0)
+
+    def test_betweenness_centrality(self):
+        graph = rustworkx.generators.grid_graph(10, 10)
+        expected = rustworkx.betweenness_centrality(graph, parallel_threshold=1)
+        for num_threads in [1, 3]:
+            res = rustworkx.betweenness_centrality(
+                graph, parallel_threshold=1, num_threads=num_threads
+            )
+            for node, value in expected.items():
+                self.assertAlmostEqual(value, res[node])
+        rustworkx.set_num_threads(2)
+        res = rustworkx.betweenness_centrality(graph, parallel_threshold=1)
+        for node, value in expected.items():
+            self.assertAlmostEqual(value, res[node])
+
+    def test_edge_betweenness_centrality(self):
+        graph = rustworkx.generators.directed_grid_graph(8, 8)
+        expected = rustworkx.edge_betweenness_centrality(graph, parallel_threshold=1)
+        res = rustworkx.edge_betweenness_centrality(graph, parallel_threshold=1, num_threads=2)
+        for edge, value in expected.items():
+            self.assertAlmostEqual(value, res[edge])
+
+    def test_all_pairs_shortest_paths(self):
+        graph = rustworkx.generators.cycle_graph(20)
+        self.assertEqual(
+            rustworkx.all_pairs_dijkstra_path_lengths(graph, lambda _: 1.0),
+            rustworkx.all_pairs_dijkstra_path_lengths(graph, lambda _: 1.0, num_threads=2),
+        )
+        self.assertEqual(
+            rustworkx.all_pairs_dijkstra_shortest_paths(graph, lambda _: 1.0),
+            rustworkx.all_pairs_dijkstra_shortest_paths(graph, lambda _: 1.0, num_threads=1),
+        )
+        self.assertEqual(
+            rustworkx.all_pairs_bellman_ford_path_lengths(graph, lambda _: 1.0),
+            rustworkx.all_pairs_bellman_ford_path_lengths(graph, lambda _: 1.0, num_threads=2),
+        )
+        self.assertEqual(
+            rustworkx.all_pairs_bellman_ford_shortest_paths(graph, lambda _: 1.0),
+            rustworkx.all_pairs_bellman_ford_shortest_paths(graph, lambda _: 1.0, num_threads=3),
+        )
+
+    def test_invalid_num_threads(self):
+        graph = rustworkx.generators.path_graph(5)
+        with self.assertRaises(ValueError):
+            rustworkx.betweenness_centrality(graph, num_threads=0)
+        with self.assertRaises(ValueError):
+            rustworkx.all_pairs_dijkstra_path_lengths(graph, lambda _: 1.0, num_threads=0)
@@ -0,0 +1,132 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+
+import unittest
+
+import rustworkx
+
+
+class Cancelled(Exception):
+    pass
+
+
+class TestProgress(unittest.TestCase):
+    def setUp(self):
+        self.graph = rustworkx.generators.grid_graph(5, 5)
+        self.digraph = rustworkx.generators.directed_grid_graph(5, 5)
+
+    def test_betweenness_centrality(self):
+        expected = rustworkx.betweenness_centrality(self.graph)
+        for num_threads in [None, 2]:
+            calls = []
+            res = rustworkx.betweenness_centrality(
+                self.graph,
+                parallel_threshold=1,
+                num_threads=num_threads,
+                progress_fn=lambda done, total: calls.append((done, total)),
+                progress_interval=10,
+            )
+            self.assertEqual(calls, [(10, 25), (20, 25), (25, 25)])
+            for node, value in expected.items():
+                self.assertAlmostEqual(value, res[node])
+
+    def test_edge_betweenness_centrality_default_interval(self):
+        calls = []
+        expected = rustworkx.edge_betweenness_centrality(self.digraph)
+        res = rustworkx.edge_betweenness_centrality(
+            self.digraph, progress_fn=lambda done, total: calls.append((done, total))
+        )
+        self.assertEqual(len(calls), 25)
+        self.assertEqual(calls[-1], (25, 25))
+        for edge, value in expected.items():
+            self.assertAlmostEqual(value, res[edge])
+
+    def test_betweenness_centrality_cancel(self):
+        def progress_fn(done, total):
+            if done >= 5:
+                raise Cancelled()
+
+        with self.assertRaises(Cancelled):
+            rustworkx.digraph_betweenness_centrality(
+                self.digraph, parallel_threshold=1, progress_fn=progress_fn
+            )
+
+    def test_all_pairs_dijkstra(self):
+        calls = []
+        expected = rustworkx.all_pairs_dijkstra_path_lengths(self.graph, lambda _: 1.0)
+        res = rustworkx.all_pairs_dijkstra_path_lengths(
+            self.graph,
+            lambda _: 1.0,
+            progress_fn=lambda done, total: calls.append((done, total)),
+            progress_interval=7,
+        )
+        self.assertEqual(calls, [(7, 25), (14, 25), (21, 25), (25, 25)])
+        self.assertEqual(expected, res)
+        calls = []
+        res = rustworkx.digraph_all_pairs_dijkstra_shortest_paths(
+            self.digraph,
+            lambda _: 1.0,
+            num_threads=2,
+            progress_fn=lambda done, total: calls.append((done, total)),
+            progress_interval=20,
+        )
+        self.assertEqual(calls, [(20, 25), (25, 25)])
+        self.assertEqual(len(res[0][24]), 9)
+
+    def test_all_pairs_bellman_ford_cancel(self):
+        def progress_fn(done, total):
+            raise Cancelled()
+
+        with self.assertRaises(Cancelled):
+            rustworkx.all_pairs_bellman_ford_shortest_paths(
+                self.graph, lambda _: 1.0, progress_fn=progress_fn
+            )
+
+    def test_zero_interval(self):
+        with self.assertRaises(ValueError):
+            rustworkx.betweenness_centrality(
+                self.graph, progress_fn=lambda *_: None, progress_interval=0
+            )
+        with self.assertRaises(ValueError):
+            rustworkx.all_pairs_bellman_ford_path_lengths(
+                self.graph, lambda _: 1.0, progress_interval=0
+            )
+
+    def test_vf2(self):
+        calls = []
+        self.assertTrue(
+            rustworkx.is_isomorphic(
+                self.graph,
+                self.graph,
+                progress_fn=lambda states, total: calls.append((states, total)),
+                progress_interval=5,
+            )
+        )
+        self.assertEqual(calls, [(5, None), (10, None), (15, None), (20, None), (25, None)])
+
+    def test_vf2_mapping_cancel(self):
+        def progress_fn(states, total):
+            raise Cancelled()
+
+        first = rustworkx.generators.directed_path_graph(10)
+        second = rustworkx.generators.directed_path_graph(3)
+        mappings = rustworkx.vf2_mapping(
+            first, second, subgraph=True, progress_fn=progress_fn, progress_interval=1
+        )
+        with self.assertRaises(Cancelled):
+            next(mappings)
+        self.assertFalse(
+            rustworkx.is_subgraph_isomorphic(
+                first, second, progress_fn=lambda *_: None, call_limit=1
+            )
+        )
@@ -0,0 +1,69 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+
+import unittest
+
+import rustworkx
+
+
+class TestRewire(unittest.TestCase):
+    def test_graph_preserves_degrees(self):
+        graph = rustworkx.undirected_gnm_random_graph(30, 80, seed=7)
+        degrees = [graph.degree(node) for node in graph.node_indices()]
+        edges = set(graph.edge_list())
+        self.assertEqual(rustworkx.rewire(graph, 100, seed=42), 100)
+        self.assertEqual(degrees, [graph.degree(node) for node in graph.node_indices()])
+        self.assertEqual(len(graph.edge_list()), 80)
+        self.assertNotEqual(edges, set(graph.edge_list()))
+        normalized = {(min(edge), max(edge)) for edge in graph.edge_list()}
+        self.assertEqual(len(normalized), 80)
+        self.assertTrue(all(u != v for u, v in graph.edge_list()))
+
+    def test_digraph_preserves_degrees(self):
+        graph = rustworkx.directed_gnm_random_graph(30, 120, seed=7)
+        degrees = [(graph.in_degree(n), graph.out_degree(n)) for n in graph.node_indices()]
+        self.assertEqual(rustworkx.rewire(graph, 100, seed=42), 100)
+        self.assertEqual(
+            degrees, [(graph.in_degree(n), graph.out_degree(n)) for n in graph.node_indices()]
+        )
+        self.assertEqual(len(set(graph.edge_list())), 120)
+        self.assertTrue(all(u != v for u, v in graph.edge_list()))
+
+    def test_seed(self):
+        first = rustworkx.undirected_gnm_random_graph(20, 40, seed=1)
+        second = first.copy()
+        rustworkx.graph_rewire(first, 30, seed=5)
+        rustworkx.graph_rewire(second, 30, seed=5)
+        self.assertEqual(first.edge_list(), second.edge_list())
+
+    def test_keeps_weights(self):
+        graph = rustworkx.PyGraph()
+        graph.extend_from_weighted_edge_list([(0, 1, "a"), (2, 3, "b"), (4, 5, "c")])
+        rustworkx.rewire(graph, 10, seed=3)
+        self.assertEqual(sorted(graph.edges()), ["a", "b", "c"])
+
+    def test_max_tries(self):
+        graph = rustworkx.generators.star_graph(5)
+        self.assertEqual(rustworkx.rewire(graph, 10, seed=1), 0)
+        graph = rustworkx.undirected_gnm_random_graph(30, 80, seed=7)
+        self.assertLessEqual(rustworkx.rewire(graph, 100, max_tries=5, seed=1), 5)
+
+    def test_check_cycle(self):
+        dag = rustworkx.PyDAG(check_cycle=True)
+        dag.extend_from_edge_list([(0, 1), (2, 3)])
+        with self.assertRaises(rustworkx.DAGWouldCycle):
+            rustworkx.rewire(dag, 1)
+
+    def test_invalid_type(self):
+        with self.assertRaises(TypeError):
+            rustworkx.rewire([], 1)
@@ -0,0 +1,91 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+
+import unittest
+
+import rustworkx
+
+
+class TestTreewidth(unittest.TestCase):
+    def assertValidDecomposition(self, graph, width, decomposition):
+        bags = decomposition.nodes()
+        self.assertEqual(len(bags), len(graph))
+        self.assertEqual(max(len(bag) for bag in bags) - 1, width)
+        self.assertEqual(decomposition.num_edges(), len(graph) - 1)
+        self.assertTrue(rustworkx.is_connected(decomposition))
+        for u, v in graph.edge_list():
+            self.assertTrue(any(u in bag and v in bag for bag in bags))
+        for node in graph.node_indices():
+            containing = [i for i, bag in enumerate(bags) if node in bag]
+            subgraph = decomposition.subgraph(containing)
+            self.assertTrue(rustworkx.is_connected(subgraph))
+
+    def test_heuristics(self):
+        for function in [rustworkx.treewidth_min_degree, rustworkx.treewidth_min_fill_in]:
+            with self.subTest(function=function):
+                for graph, expected in [
+                    (rustworkx.generators.path_graph(6), 1),
+                    (rustworkx.generators.cycle_graph(6), 2),
+                    (rustworkx.generators.complete_graph(5), 4),
+                    (rustworkx.generators.binomial_tree_graph(4), 1),
+                ]:
+                    width, ordering, decomposition = function(graph)
+                    self.assertEqual(width, expected)
+                    self.assertEqual(sorted(ordering), list(graph.node_indices()))
+                    self.assertValidDecomposition(graph, width, decomposition)
+
+    def test_bags_match_ordering(self):
+        graph = rustworkx.generators.grid_graph(3, 4)
+        width, ordering, decomposition = rustworkx.treewidth_min_fill_in(graph)
+        self.assertEqual(width, 3)
+        for node, bag in zip(ordering, decomposition.nodes()):
+            self.assertIn(node, bag)
+            self.assertEqual(bag, sorted(bag))
+
+    def test_empty_graph(self):
+        width, ordering, decomposition = rustworkx.treewidth_min_degree(rustworkx.PyGraph())
+        self.assertEqual(width, 0)
+        self.assertEqual(len(ordering), 0)
+        self.assertEqual(len(decomposition), 0)
+
+    def test_removed_nodes(self):
+        graph = rustworkx.generators.cycle_graph(6)
+        graph.remove_node(2)
+        width, ordering, decomposition = rustworkx.treewidth_min_degree(graph)
+        self.assertEqual(width, 1)
+        self.assertNotIn(2, ordering)
+        self.assertValidDecomposition(graph, width, decomposition)
+
+    def test_tree_decomposition(self):
+        graph = rustworkx.generators.star_graph(5)
+        width, decomposition = rustworkx.tree_decomposition(graph, [1, 2, 3, 4, 0])
+        self.assertEqual(width, 1)
+        self.assertValidDecomposition(graph, width, decomposition)
+        width, decomposition = rustworkx.tree_decomposition(graph, [0, 1, 2, 3, 4])
+        self.assertEqual(width, 4)
+        self.assertEqual(decomposition[0], [0, 1, 2, 3, 4])
+        self.assertValidDecomposition(graph, width, decomposition)
+
+    def test_tree_decomposition_disconnected(self):
+        graph = rustworkx.PyGraph()
+        graph.extend_from_edge_list([(0, 1), (1, 2), (2, 0), (3, 4)])
+        width, decomposition = rustworkx.tree_decomposition(graph, [4, 3, 2, 1, 0])
+        self.assertEqual(width, 2)
+        self.assertValidDecomposition(graph, width, decomposition)
+
+    def test_tree_decomposition_bad_ordering(self):
+        graph = rustworkx.generators.path_graph(3)
+        for ordering in [[0, 1], [0, 1, 1], [0, 1, 5], [0, 1, 2, 0]]:
+            with self.subTest(ordering=ordering):
+                with self.assertRaises(ValueError):
+                    rustworkx.tree_decomposition(graph, ordering)
@@ -0,0 +1,74 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+
+import unittest
+
+import rustworkx
+
+
+class TestVoronoiCells(unittest.TestCase):
+    def test_path(self):
+        graph = rustworkx.generators.path_graph(5)
+        cells, distances = rustworkx.graph_voronoi_cells(graph, [0, 4])
+        self.assertEqual({0: [0, 1, 2], 4: [3, 4]}, cells)
+        self.assertEqual({0: 0.0, 1: 1.0, 2: 2.0, 3: 1.0, 4: 0.0}, distances)
+
+    def test_ties_follow_source_order(self):
+        graph = rustworkx.generators.path_graph(5)
+        cells, _ = rustworkx.voronoi_cells(graph, [4, 0])
+        self.assertEqual([4, 0], list(cells))
+        self.assertEqual({4: [2, 3, 4], 0: [0, 1]}, cells)
+
+    def test_weighted(self):
+        graph = rustworkx.PyGraph()
+        graph.add_nodes_from(range(5))
+        graph.add_edges_from([(0, 1, 1.0), (1, 2, 1.0), (2, 3, 3.0), (3, 4, 1.0)])
+        cells, distances = rustworkx.voronoi_cells(graph, [0, 4], weight_fn=float)
+        self.assertEqual({0: [0, 1, 2], 4: [3, 4]}, cells)
+        self.assertEqual(2.0, distances[2])
+        cells, distances = rustworkx.voronoi_cells(graph, [0, 4], weight_fn=[5, 1, 1, 1])
+        self.assertEqual({0: [0], 4: [1, 2, 3, 4]}, cells)
+        self.assertEqual(3.0, distances[1])
+        cells, distances = rustworkx.voronoi_cells(graph, [0, 4], default_weight=2.0)
+        self.assertEqual(4.0, distances[2])
+
+    def test_digraph_follows_edge_direction(self):
+        graph = rustworkx.generators.directed_path_graph(5)
+        graph.add_node(None)
+        cells, distances = rustworkx.digraph_voronoi_cells(graph, [3, 0])
+        self.assertEqual({3: [3, 4], 0: [0, 1, 2]}, cells)
+        self.assertNotIn(5, distances)
+        self.assertEqual(2.0, distances[2])
+
+    def test_removed_nodes_and_duplicates(self):
+        graph = rustworkx.generators.cycle_graph(6)
+        graph.remove_node(2)
+        cells, _ = rustworkx.voronoi_cells(graph, [1, 4, 1])
+        self.assertEqual({1: [0, 1], 4: [3, 4, 5]}, cells)
+
+    def test_invalid_source(self):
+        graph = rustworkx.generators.path_graph(3)
+        with self.assertRaises(IndexError):
+            rustworkx.voronoi_cells(graph, [0, 7])
+        digraph = rustworkx.generators.directed_path_graph(3)
+        with self.assertRaises(IndexError):
+            rustworkx.voronoi_cells(digraph, [7])
+
+    def test_negative_weight(self):
+        graph = rustworkx.generators.path_graph(3)
+        with self.assertRaises(ValueError):
+            rustworkx.voronoi_cells(graph, [0], weight_fn=lambda _: -1.0)
+
+    def test_no_sources(self):
+        graph = rustworkx.generators.path_graph(3)
+        self.assertEqual(({}, {}), rustworkx.voronoi_cells(graph, []))
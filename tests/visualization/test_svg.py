@@ -0,0 +1,142 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+import os
+import tempfile
+import unittest
+import xml.etree.ElementTree as ET
+
+import rustworkx
+from rustworkx.visualization import svg_draw
+
+SVG_NS = "{http://www.w3.org/2000/svg}"
+
+
+class TestSVGDraw(unittest.TestCase):
+    def parse(self, svg):
+        root = ET.fromstring(svg)
+        self.assertEqual(root.tag, SVG_NS + "svg")
+        groups = {group.get("class"): group for group in root.iter(SVG_NS + "g")}
+        return root, groups
+
+    def test_draw_graph(self):
+        graph = rustworkx.generators.cycle_graph(4)
+        pos = rustworkx.circular_layout(graph)
+        root, groups = self.parse(rustworkx.graph_to_svg(graph, pos, width=200, height=100))
+        self.assertEqual(root.get("width"), "200")
+        self.assertEqual(root.get("height"), "100")
+        self.assertEqual(len(groups["edges"].findall(SVG_NS + "line")), 4)
+        circles = groups["nodes"].findall(SVG_NS + "circle")
+        self.assertEqual(len(circles), 4)
+        for circle in circles:
+            self.assertTrue(0 <= float(circle.get("cx")) <= 200)
+            self.assertTrue(0 <= float(circle.get("cy")) <= 100)
+        self.assertNotIn("labels", groups)
+        self.assertIsNone(root.find(SVG_NS + "defs"))
+
+    def test_draw_digraph_arrows(self):
+        graph = rustworkx.generators.directed_path_graph(2)
+        pos = {0: (0.0, 0.0), 1: (1.0, 0.0)}
+        root, groups = self.parse(rustworkx.digraph_to_svg(graph, pos, width=200, height=200))
+        self.assertIsNotNone(root.find(f"{SVG_NS}defs/{SVG_NS}marker"))
+        [line] = groups["edges"].findall(SVG_NS + "line")
+        self.assertEqual(line.get("marker-end"), "url(#arrowhead)")
+        source, target = groups["nodes"].findall(SVG_NS + "circle")
+        # The edge ends at the boundary of the target node
+        self.assertAlmostEqual(
+            float(target.get("cx")) - float(line.get("x2")), float(target.get("r")), places=1
+        )
+        self.assertAlmostEqual(
+            float(line.get("x1")) - float(source.get("cx")), float(source.get("r")), places=1
+        )
+
+    def test_y_axis_points_up(self):
+        graph = rustworkx.generators.path_graph(2)
+        pos = {0: (0.0, 0.0), 1: (0.0, 1.0)}
+        _, groups = self.parse(rustworkx.graph_to_svg(graph, pos))
+        low, high = groups["nodes"].findall(SVG_NS + "circle")
+        self.assertGreater(float(low.get("cy")), float(high.get("cy")))
+
+    def test_attrs_and_labels(self):
+        graph = rustworkx.PyGraph()
+        graph.add_nodes_from(["a", "<b>"])
+        graph.add_edge(0, 1, "x & y")
+        svg = svg_draw(
+            graph,
+            {0: (0, 0), 1: (1, 1)},
+            node_attr_fn=lambda node: {"fill": "red", "r": "5", "label": node},
+            edge_attr_fn=lambda edge: {"stroke-width": "3", "label": edge},
+        )
+        _, groups = self.parse(svg)
+        for circle in groups["nodes"].findall(SVG_NS + "circle"):
+            self.assertEqual(circle.get("fill"), "red")
+            self.assertEqual(circle.get("r"), "5")
+            self.assertIsNone(circle.get("label"))
+        [line] = groups["edges"].findall(SVG_NS + "line")
+        self.assertEqual(line.get("stroke-width"), "3")
+        self.assertEqual(line.get("stroke"), "black")
+        texts = [text.text for text in groups["labels"].findall(SVG_NS + "text")]
+        self.assertEqual(sorted(texts), ["<b>", "a", "x & y"])
+
+    def test_with_labels(self):
+        graph = rustworkx.generators.path_graph(3)
+        svg = svg_draw(graph, rustworkx.random_layout(graph, seed=42), with_labels=True)
+        _, groups = self.parse(svg)
+        texts = [text.text for text in groups["labels"].findall(SVG_NS + "text")]
+        self.assertEqual(texts, ["0", "1", "2"])
+
+    def test_self_loop(self):
+        graph = rustworkx.PyDiGraph()
+        graph.add_node(None)
+        graph.add_edge(0, 0, None)
+        _, groups = self.parse(svg_draw(graph, {0: (0.5, 0.5)}))
+        [loop] = groups["edges"].findall(SVG_NS + "circle")
+        self.assertEqual(loop.get("fill"), "none")
+
+    def test_default_layout(self):
+        graph = rustworkx.generators.star_graph(5)
+        _, groups = self.parse(svg_draw(graph))
+        self.assertEqual(len(groups["nodes"].findall(SVG_NS + "circle")), 5)
+
+    def test_empty_graph(self):
+        _, groups = self.parse(svg_draw(rustworkx.PyGraph(), {}))
+        self.assertEqual(len(groups["nodes"]), 0)
+
+    def test_filename(self):
+        graph = rustworkx.generators.path_graph(3)
+        pos = rustworkx.circular_layout(graph)
+        with tempfile.TemporaryDirectory() as tmpdirname:
+            path = os.path.join(tmpdirname, "graph.svg")
+            self.assertIsNone(svg_draw(graph, pos, filename=path))
+            with open(path) as fd:
+                self.assertEqual(fd.read(), svg_draw(graph, pos))
+
+    def test_missing_position(self):
+        graph = rustworkx.generators.path_graph(3)
+        with self.assertRaises(KeyError):
+            svg_draw(graph, {0: (0, 0), 1: (1, 1)})
+
+    def test_invalid_attrs(self):
+        graph = rustworkx.generators.path_graph(2)
+        pos = {0: (0, 0), 1: (1, 1)}
+        with self.assertRaises(ValueError):
+            svg_draw(graph, pos, node_attr_fn=lambda _: {"cx": "1"})
+        with self.assertRaises(ValueError):
+            svg_draw(graph, pos, node_attr_fn=lambda _: {"bad name": "1"})
+        with self.assertRaises(ValueError):
+            svg_draw(graph, pos, node_attr_fn=lambda _: {"r": "big"})
+        with self.assertRaises(ValueError):
+            svg_draw(graph, pos, width=0)
+
+    def test_invalid_graph(self):
+        with self.assertRaises(TypeError):
+            svg_draw("not a graph", {})